@@ -10,6 +10,7 @@ pub(crate) mod context_pruning;
 pub mod council;
 pub(crate) mod fnv1a;
 pub(crate) mod loop_detection;
+pub mod pipeline;
 pub(crate) mod stagnation;
 pub(crate) mod stream_collector;
 pub mod structured_output;
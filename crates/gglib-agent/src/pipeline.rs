@@ -0,0 +1,214 @@
+//! Multi-stage pipeline runner.
+//!
+//! Runs a [`PipelineDefinition`] step by step: each prompt step renders its
+//! templates from the inputs and earlier outputs, sends one chat completion
+//! and keeps the text; each tool step renders its arguments and keeps the
+//! tool's output. There is no tool calling inside a prompt step and no
+//! branching — a pipeline is a straight line, which is what keeps it
+//! predictable enough to run unattended.
+//!
+//! Every step's output is persisted through the [`PipelineRunRepository`] as
+//! soon as it is produced, so a run that fails at step four still shows what
+//! steps one to three returned.
+//!
+//! # Models
+//!
+//! Steps may name different models. The runner asks a [`PipelineModels`]
+//! source for an [`LlmCompletionPort`] per prompt step; the source decides
+//! what "a different model" means — the HTTP API points every step at the
+//! proxy and lets it route by name, while the CLI swaps llama-server
+//! processes when the model changes.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use anyhow::{Result, anyhow};
+use async_trait::async_trait;
+
+use gglib_core::domain::InferenceConfig;
+use gglib_core::domain::agent::{AgentMessage, ToolCall};
+use gglib_core::domain::pipeline::{
+    INPUT_PREFIX, NewPipelineRun, PipelineDefinition, PipelineRun, PipelineRunStatus, PipelineStep,
+    render_args, render_template, resolve_inputs, validate_definition,
+};
+use gglib_core::ports::{
+    LlmCompletionPort, PipelineError, PipelineRunRepository, ToolExecutorPort,
+};
+
+use crate::structured_output::collect_text;
+
+/// Source of a completion port for each prompt step.
+#[async_trait]
+pub trait PipelineModels: Send + Sync {
+    /// A completion port for `model` (`None` for the default model) that
+    /// applies `sampling` to its requests.
+    async fn llm(
+        &self,
+        model: Option<&str>,
+        sampling: Option<InferenceConfig>,
+    ) -> Result<Arc<dyn LlmCompletionPort>>;
+}
+
+/// Progress reported while a pipeline runs.
+#[derive(Debug, Clone)]
+pub enum PipelineProgress {
+    /// The run was recorded and its first step is about to start.
+    RunStarted { run_id: i64 },
+    /// A step is about to run.
+    StepStarted {
+        step_id: String,
+        model: Option<String>,
+    },
+    /// A step finished and its output was persisted.
+    StepCompleted { step_id: String, output: String },
+    /// A step failed; the run stops here.
+    StepFailed { step_id: String, error: String },
+}
+
+/// Runs pipelines against injected model, tool and storage ports.
+pub struct PipelineRunner {
+    models: Arc<dyn PipelineModels>,
+    tools: Arc<dyn ToolExecutorPort>,
+    repo: Arc<dyn PipelineRunRepository>,
+}
+
+impl PipelineRunner {
+    /// Create a runner.
+    pub fn new(
+        models: Arc<dyn PipelineModels>,
+        tools: Arc<dyn ToolExecutorPort>,
+        repo: Arc<dyn PipelineRunRepository>,
+    ) -> Self {
+        Self {
+            models,
+            tools,
+            repo,
+        }
+    }
+
+    /// Validate and run `definition` with `inputs`, returning the stored run.
+    ///
+    /// A step failure is not an `Err`: the run is marked
+    /// [`PipelineRunStatus::Failed`] with the reason, and returned with the
+    /// outputs of the steps that completed.
+    ///
+    /// # Errors
+    ///
+    /// [`PipelineError::Invalid`] for a definition that fails validation or
+    /// is missing inputs — nothing is recorded in that case — and
+    /// [`PipelineError::Database`] when the run cannot be persisted.
+    pub async fn run(
+        &self,
+        definition: PipelineDefinition,
+        inputs: BTreeMap<String, String>,
+        on_progress: impl Fn(PipelineProgress) + Send + Sync,
+    ) -> Result<PipelineRun, PipelineError> {
+        validate_definition(&definition).map_err(PipelineError::Invalid)?;
+        let inputs = resolve_inputs(&definition, inputs).map_err(PipelineError::Invalid)?;
+
+        let run_id = self
+            .repo
+            .create_run(NewPipelineRun {
+                definition: definition.clone(),
+                inputs: inputs.clone(),
+            })
+            .await?;
+        on_progress(PipelineProgress::RunStarted { run_id });
+
+        let mut values: BTreeMap<String, String> = inputs
+            .into_iter()
+            .map(|(name, value)| (format!("{INPUT_PREFIX}{name}"), value))
+            .collect();
+        let mut failure = None;
+
+        for step in &definition.steps {
+            on_progress(PipelineProgress::StepStarted {
+                step_id: step.id.clone(),
+                model: step
+                    .prompt
+                    .as_ref()
+                    .and_then(|_| step.effective_model(&definition))
+                    .map(str::to_owned),
+            });
+            match self.run_step(&definition, step, &values).await {
+                Ok(output) => {
+                    self.repo.record_step(run_id, &step.id, &output).await?;
+                    on_progress(PipelineProgress::StepCompleted {
+                        step_id: step.id.clone(),
+                        output: output.clone(),
+                    });
+                    values.insert(step.id.clone(), output);
+                }
+                Err(e) => {
+                    let error = format!("{e:#}");
+                    tracing::warn!(run_id, step = %step.id, %error, "pipeline: step failed");
+                    on_progress(PipelineProgress::StepFailed {
+                        step_id: step.id.clone(),
+                        error: error.clone(),
+                    });
+                    failure = Some(format!("Step '{}' failed: {error}", step.id));
+                    break;
+                }
+            }
+        }
+
+        let status = if failure.is_some() {
+            PipelineRunStatus::Failed
+        } else {
+            PipelineRunStatus::Completed
+        };
+        self.repo
+            .finish_run(run_id, status, failure.as_deref())
+            .await?;
+        self.repo
+            .get(run_id)
+            .await?
+            .ok_or(PipelineError::NotFound(run_id))
+    }
+
+    async fn run_step(
+        &self,
+        definition: &PipelineDefinition,
+        step: &PipelineStep,
+        values: &BTreeMap<String, String>,
+    ) -> Result<String> {
+        if let Some(tool) = &step.tool {
+            let call = ToolCall {
+                id: format!("pipeline-{}", step.id),
+                name: tool.clone(),
+                arguments: step
+                    .args
+                    .as_ref()
+                    .map_or_else(|| serde_json::json!({}), |args| render_args(args, values)),
+            };
+            let result = self.tools.execute(&call).await?;
+            if !result.success {
+                return Err(anyhow!("tool '{tool}' failed: {}", result.content));
+            }
+            return Ok(result.content);
+        }
+
+        let prompt = step.prompt.as_deref().unwrap_or_default();
+        let mut messages = Vec::with_capacity(2);
+        if let Some(system) = &step.system {
+            messages.push(AgentMessage::System {
+                content: render_template(system, values),
+            });
+        }
+        messages.push(AgentMessage::User {
+            content: render_template(prompt, values),
+        });
+
+        let llm = self
+            .models
+            .llm(step.effective_model(definition), step.sampling.clone())
+            .await?;
+        let stream = llm.chat_stream(&messages, &[], None).await?;
+        let text = collect_text(stream).await?;
+        let text = text.trim();
+        if text.is_empty() {
+            return Err(anyhow!("the model returned no text"));
+        }
+        Ok(text.to_owned())
+    }
+}
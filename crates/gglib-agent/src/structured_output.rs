@@ -130,7 +130,7 @@ pub async fn get_structured<T: DeserializeOwned>(
 
 /// Collect only [`LlmStreamEvent::TextDelta`] events from a stream, discarding
 /// reasoning deltas, tool-call deltas, and other event kinds.
-async fn collect_text(
+pub(crate) async fn collect_text(
     stream: std::pin::Pin<
         Box<dyn futures_core::Stream<Item = anyhow::Result<LlmStreamEvent>> + Send>,
     >,
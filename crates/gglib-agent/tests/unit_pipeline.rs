@@ -0,0 +1,217 @@
+//! Tests for [`PipelineRunner`] against scripted model and tool ports and a
+//! real `SQLite` run repository.
+//!
+//! | Test | Scenario |
+//! |------|----------|
+//! | [`chains_outputs_across_models`] | Later prompts see earlier outputs; per-step models honoured |
+//! | [`failed_step_keeps_earlier_outputs`] | Tool args templated; a failure persists completed steps |
+//! | [`invalid_pipeline_records_nothing`] | Missing inputs fail before a run is created |
+
+#![allow(unused_crate_dependencies)]
+
+mod common;
+
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex};
+
+use anyhow::Result;
+use async_trait::async_trait;
+use gglib_agent::pipeline::{PipelineModels, PipelineProgress, PipelineRunner};
+use gglib_core::domain::InferenceConfig;
+use gglib_core::domain::agent::{AgentMessage, ToolDefinition};
+use gglib_core::domain::pipeline::{PipelineDefinition, PipelineRunStatus};
+use gglib_core::ports::{LlmCompletionPort, PipelineError, PipelineRunRepository};
+use gglib_db::repositories::SqlitePipelineRunRepository;
+use gglib_db::setup::setup_test_database;
+
+use common::mock_llm::{MockLlmPort, MockLlmResponse};
+use common::mock_tools::{MockToolBehavior, MockToolExecutorPort};
+
+/// Hands out one shared scripted LLM and records which model each step asked for.
+struct ScriptedModels {
+    llm: Arc<MockLlmPort>,
+    requested: Mutex<Vec<(Option<String>, Option<InferenceConfig>)>>,
+}
+
+impl ScriptedModels {
+    fn new(llm: MockLlmPort) -> Arc<Self> {
+        Arc::new(Self {
+            llm: Arc::new(llm),
+            requested: Mutex::new(Vec::new()),
+        })
+    }
+}
+
+#[async_trait]
+impl PipelineModels for ScriptedModels {
+    async fn llm(
+        &self,
+        model: Option<&str>,
+        sampling: Option<InferenceConfig>,
+    ) -> Result<Arc<dyn LlmCompletionPort>> {
+        self.requested
+            .lock()
+            .unwrap()
+            .push((model.map(str::to_owned), sampling));
+        Ok(Arc::clone(&self.llm) as Arc<dyn LlmCompletionPort>)
+    }
+}
+
+async fn repo() -> Arc<SqlitePipelineRunRepository> {
+    Arc::new(SqlitePipelineRunRepository::new(
+        setup_test_database().await.expect("setup_test_database"),
+    ))
+}
+
+fn definition(json: serde_json::Value) -> PipelineDefinition {
+    serde_json::from_value(json).expect("valid pipeline definition")
+}
+
+fn user_text(messages: &[AgentMessage]) -> &str {
+    messages
+        .iter()
+        .find_map(|m| match m {
+            AgentMessage::User { content } => Some(content.as_str()),
+            _ => None,
+        })
+        .unwrap()
+}
+
+#[tokio::test]
+async fn chains_outputs_across_models() {
+    let models = ScriptedModels::new(MockLlmPort::new().push_many([
+        MockLlmResponse::text("  A short summary.\n"),
+        MockLlmResponse::text("- fact one"),
+        MockLlmResponse::text("| fact |\n| one |"),
+    ]));
+    let repo = repo().await;
+    let runner = PipelineRunner::new(
+        Arc::clone(&models) as Arc<dyn PipelineModels>,
+        Arc::new(MockToolExecutorPort::new()),
+        Arc::clone(&repo) as Arc<dyn PipelineRunRepository>,
+    );
+
+    let progress = Mutex::new(Vec::new());
+    let run = runner
+        .run(
+            definition(serde_json::json!({
+                "name": "report",
+                "model": "qwen3",
+                "inputs": { "lang": "English" },
+                "steps": [
+                    { "id": "summary", "system": "Write in {{input.lang}}.", "prompt": "Summarise: {{input.text}}" },
+                    { "id": "facts", "model": "llama3", "sampling": { "temperature": 0.1 }, "prompt": "Facts in: {{summary}}" },
+                    { "id": "table", "prompt": "Tabulate {{ facts }} from {{summary}}" }
+                ]
+            })),
+            BTreeMap::from([("text".to_string(), "the report".to_string())]),
+            |p| progress.lock().unwrap().push(p),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(run.status, PipelineRunStatus::Completed);
+    assert_eq!(run.output(), Some("| fact |\n| one |"));
+    assert_eq!(
+        run.steps[0].output, "A short summary.",
+        "outputs are trimmed"
+    );
+    assert_eq!(repo.get(run.id).await.unwrap().unwrap(), run);
+
+    let sent = models.llm.messages_received().await;
+    assert!(matches!(
+        &sent[0][0],
+        AgentMessage::System { content } if content == "Write in English."
+    ));
+    assert_eq!(user_text(&sent[0]), "Summarise: the report");
+    assert_eq!(user_text(&sent[1]), "Facts in: A short summary.");
+    assert_eq!(
+        user_text(&sent[2]),
+        "Tabulate - fact one from A short summary."
+    );
+
+    let requested = std::mem::take(&mut *models.requested.lock().unwrap());
+    let names: Vec<_> = requested.iter().map(|(m, _)| m.as_deref()).collect();
+    assert_eq!(names, [Some("qwen3"), Some("llama3"), Some("qwen3")]);
+    assert_eq!(requested[1].1.as_ref().unwrap().temperature, Some(0.1));
+
+    let progress = progress.into_inner().unwrap();
+    assert!(matches!(progress[0], PipelineProgress::RunStarted { run_id } if run_id == run.id));
+    assert_eq!(progress.len(), 7);
+}
+
+#[tokio::test]
+async fn failed_step_keeps_earlier_outputs() {
+    let tools = MockToolExecutorPort::new().with_tool(
+        ToolDefinition::new("builtin:fetch_url"),
+        MockToolBehavior::Immediate {
+            content: "page text".into(),
+        },
+    );
+    let call_log = Arc::clone(&tools.call_log);
+    // No scripted responses: the prompt step's completion call fails.
+    let models = ScriptedModels::new(MockLlmPort::new());
+    let repo = repo().await;
+    let runner = PipelineRunner::new(
+        models,
+        Arc::new(tools),
+        Arc::clone(&repo) as Arc<dyn PipelineRunRepository>,
+    );
+
+    let run = runner
+        .run(
+            definition(serde_json::json!({
+                "name": "fetch",
+                "steps": [
+                    { "id": "page", "tool": "builtin:fetch_url", "args": { "url": "{{input.url}}", "maxTokens": 500 } },
+                    { "id": "gist", "prompt": "Gist of {{page}}" }
+                ]
+            })),
+            BTreeMap::from([("url".to_string(), "https://example.com".to_string())]),
+            |_| {},
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(
+        call_log.lock().await.as_slice(),
+        [(
+            "builtin:fetch_url".to_string(),
+            serde_json::json!({ "url": "https://example.com", "maxTokens": 500 })
+        )]
+    );
+    assert_eq!(run.status, PipelineRunStatus::Failed);
+    assert!(
+        run.error
+            .as_deref()
+            .unwrap()
+            .starts_with("Step 'gist' failed")
+    );
+    assert_eq!(run.steps.len(), 1);
+    assert_eq!(run.steps[0].output, "page text");
+}
+
+#[tokio::test]
+async fn invalid_pipeline_records_nothing() {
+    let repo = repo().await;
+    let runner = PipelineRunner::new(
+        ScriptedModels::new(MockLlmPort::new()),
+        Arc::new(MockToolExecutorPort::new()),
+        Arc::clone(&repo) as Arc<dyn PipelineRunRepository>,
+    );
+
+    let err = runner
+        .run(
+            definition(serde_json::json!({
+                "name": "needs-input",
+                "steps": [{ "id": "a", "prompt": "{{input.text}}" }]
+            })),
+            BTreeMap::new(),
+            |_| {},
+        )
+        .await
+        .unwrap_err();
+
+    assert!(matches!(err, PipelineError::Invalid(msg) if msg.contains("text")));
+    assert!(repo.list().await.unwrap().is_empty());
+}
@@ -0,0 +1,350 @@
+//! Backups to an S3-compatible bucket.
+//!
+//! A push uploads a fresh snapshot of the database and, when asked, every
+//! library model file the bucket does not already hold at the same size.
+//! A restore downloads the chosen model files into the models directory and
+//! stages the chosen database backup next to the live database, which
+//! `gglib-db` swaps in on the next start — an open database cannot be
+//! replaced underneath the running app.
+//!
+//! Transfers report [`AppEvent::BackupProgress`] events. The bucket layout
+//! is described in [`gglib_core::domain::backup`].
+
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use chrono::Utc;
+use gglib_core::domain::backup::{BackupConfig, BackupDirection, BackupObject};
+use gglib_core::events::AppEvent;
+use gglib_core::paths::{database_path, pending_restore_path, resolve_models_dir};
+use gglib_core::ports::{AppEventEmitter, BackupStorePort, DatabaseSnapshotPort};
+use gglib_core::services::AppCore;
+
+use crate::error::GuiError;
+use crate::types::{
+    BackupEntry, BackupListing, BackupPushRequest, BackupPushResult, BackupRestoreRequest,
+    BackupRestoreResult,
+};
+
+/// Minimum bytes between two progress events for the same object.
+const PROGRESS_STEP: u64 = 8 * 1024 * 1024;
+
+/// Dependencies for backups.
+pub struct BackupDeps {
+    pub core: Arc<AppCore>,
+    pub snapshot: Arc<dyn DatabaseSnapshotPort>,
+    pub emitter: Arc<dyn AppEventEmitter>,
+}
+
+/// Pushing backups to and restoring from the configured bucket.
+pub struct BackupOps {
+    deps: BackupDeps,
+}
+
+impl BackupOps {
+    pub fn new(deps: BackupDeps) -> Self {
+        Self { deps }
+    }
+
+    /// List the database backups and model files in the bucket.
+    pub async fn list(&self) -> Result<BackupListing, GuiError> {
+        let (config, store) = self.open().await?;
+        listing(&config, store.as_ref()).await
+    }
+
+    /// Back up the database and, optionally, the library's model files.
+    pub async fn push(&self, req: BackupPushRequest) -> Result<BackupPushResult, GuiError> {
+        let (config, store) = self.open().await?;
+
+        let timestamp = Utc::now().format("%Y%m%dT%H%M%SZ").to_string();
+        let key = config.database_key(&timestamp);
+        let name = format!("gglib-{timestamp}.db");
+        let snapshot = std::env::temp_dir().join(format!("{}-{name}", std::process::id()));
+        let uploaded = async {
+            self.deps.snapshot.snapshot(&snapshot).await?;
+            let progress = self.progress(BackupDirection::Upload, &key);
+            store.upload(&key, &snapshot, &progress).await
+        }
+        .await;
+        if let Err(e) = tokio::fs::remove_file(&snapshot).await {
+            tracing::debug!(path = %snapshot.display(), "Failed to remove snapshot: {e}");
+        }
+        uploaded?;
+
+        let mut result = BackupPushResult {
+            database: name,
+            models_uploaded: Vec::new(),
+            models_unchanged: 0,
+        };
+        if !req.include_models.unwrap_or(config.include_models) {
+            return Ok(result);
+        }
+
+        let stored = store.list(&config.models_prefix()).await?;
+        for (name, path) in self.model_files().await? {
+            let key = config.model_key(&name);
+            let size = tokio::fs::metadata(&path)
+                .await
+                .map_err(|e| GuiError::Internal(format!("{}: {e}", path.display())))?
+                .len();
+            if stored.iter().any(|o| o.key == key && o.size == size) {
+                result.models_unchanged += 1;
+                continue;
+            }
+            let progress = self.progress(BackupDirection::Upload, &key);
+            store.upload(&key, &path, &progress).await?;
+            result.models_uploaded.push(name);
+        }
+        Ok(result)
+    }
+
+    /// Restore the requested database backup and model files.
+    pub async fn restore(
+        &self,
+        req: BackupRestoreRequest,
+    ) -> Result<BackupRestoreResult, GuiError> {
+        let (config, store) = self.open().await?;
+        let listing = listing(&config, store.as_ref()).await?;
+
+        let database = match req.database.as_deref() {
+            None => None,
+            Some("latest") => {
+                Some(
+                    listing
+                        .databases
+                        .first()
+                        .ok_or_else(|| GuiError::NotFound {
+                            entity: "backup",
+                            id: "latest database".to_string(),
+                        })?,
+                )
+            }
+            Some(name) => Some(
+                listing
+                    .databases
+                    .iter()
+                    .find(|db| db.name == name)
+                    .ok_or_else(|| not_found(name))?,
+            ),
+        };
+        let models: Vec<&BackupEntry> = if req.all_models {
+            listing.models.iter().collect()
+        } else {
+            req.models
+                .iter()
+                .map(|name| {
+                    listing
+                        .models
+                        .iter()
+                        .find(|m| &m.name == name)
+                        .ok_or_else(|| not_found(name))
+                })
+                .collect::<Result<_, _>>()?
+        };
+        if database.is_none() && models.is_empty() {
+            return Err(GuiError::ValidationFailed(
+                "Nothing to restore: choose a database backup or model files".to_string(),
+            ));
+        }
+
+        let mut result = BackupRestoreResult {
+            database: None,
+            models: Vec::new(),
+        };
+        if !models.is_empty() {
+            let models_dir = resolve_models_dir(None)
+                .map_err(|e| GuiError::Internal(e.to_string()))?
+                .path;
+            for model in models {
+                let dest = models_dir.join(&model.name);
+                if let Some(parent) = dest.parent() {
+                    tokio::fs::create_dir_all(parent)
+                        .await
+                        .map_err(|e| GuiError::Internal(format!("{}: {e}", parent.display())))?;
+                }
+                let progress = self.progress(BackupDirection::Download, &model.key);
+                store.download(&model.key, &dest, &progress).await?;
+                result.models.push(model.name.clone());
+            }
+        }
+        if let Some(database) = database {
+            let db_path = database_path().map_err(|e| GuiError::Internal(e.to_string()))?;
+            let progress = self.progress(BackupDirection::Download, &database.key);
+            store
+                .download(&database.key, &pending_restore_path(&db_path), &progress)
+                .await?;
+            result.database = Some(database.name.clone());
+        }
+        Ok(result)
+    }
+
+    async fn open(&self) -> Result<(BackupConfig, Arc<dyn BackupStorePort>), GuiError> {
+        let config = self
+            .deps
+            .core
+            .settings()
+            .get_resolved()
+            .await?
+            .backup
+            .ok_or_else(|| GuiError::Unavailable("No backup bucket is configured".to_string()))?;
+        let store = gglib_backup::connect(&config)?;
+        Ok((config, store))
+    }
+
+    /// Every library file to back up, as (path below the models directory,
+    /// absolute path). Files outside the models directory are stored by
+    /// file name; files that no longer exist are skipped.
+    async fn model_files(&self) -> Result<Vec<(String, PathBuf)>, GuiError> {
+        let models_dir = resolve_models_dir(None)
+            .map_err(|e| GuiError::Internal(e.to_string()))?
+            .path;
+        let verification = self.deps.core.verification();
+
+        let mut files = Vec::new();
+        for model in self.deps.core.models().list().await? {
+            let shards = match verification {
+                Some(v) => v.model_files(model.id).await.unwrap_or_default(),
+                None => Vec::new(),
+            };
+            let paths: Vec<PathBuf> = if shards.is_empty() {
+                vec![model.file_path.clone()]
+            } else {
+                shards
+                    .into_iter()
+                    .map(|f| PathBuf::from(f.file_path))
+                    .collect()
+            };
+            for path in paths {
+                if !path.is_file() {
+                    continue;
+                }
+                if let Some(name) = relative_name(&models_dir, &path)
+                    && !files.iter().any(|(n, _)| n == &name)
+                {
+                    files.push((name, path));
+                }
+            }
+        }
+        Ok(files)
+    }
+
+    fn progress<'a>(
+        &'a self,
+        direction: BackupDirection,
+        key: &'a str,
+    ) -> impl Fn(u64, u64) + Send + Sync + 'a {
+        let last = AtomicU64::new(0);
+        move |transferred, total| {
+            if transferred < total
+                && transferred.saturating_sub(last.load(Ordering::Relaxed)) < PROGRESS_STEP
+            {
+                return;
+            }
+            last.store(transferred, Ordering::Relaxed);
+            self.deps.emitter.emit(AppEvent::backup_progress(
+                direction,
+                key,
+                transferred,
+                total,
+            ));
+        }
+    }
+}
+
+/// The bucket's contents: databases newest first, models by name.
+async fn listing(
+    config: &BackupConfig,
+    store: &dyn BackupStorePort,
+) -> Result<BackupListing, GuiError> {
+    let mut databases: Vec<BackupEntry> = store
+        .list(&config.database_prefix())
+        .await?
+        .into_iter()
+        .map(|object| entry(object.name().to_string(), object))
+        .collect();
+    // Names embed the timestamp, so name order is age order.
+    databases.sort_by(|a, b| b.name.cmp(&a.name));
+
+    let mut models: Vec<BackupEntry> = store
+        .list(&config.models_prefix())
+        .await?
+        .into_iter()
+        .filter_map(|object| {
+            let name = config.model_path(&object.key)?.to_string();
+            Some(entry(name, object))
+        })
+        .collect();
+    models.sort_by(|a, b| a.name.cmp(&b.name));
+
+    Ok(BackupListing { databases, models })
+}
+
+/// `path` relative to `models_dir` with `/` separators, or its file name
+/// when it lies outside.
+fn relative_name(models_dir: &Path, path: &Path) -> Option<String> {
+    let relative = path
+        .strip_prefix(models_dir)
+        .unwrap_or_else(|_| path.file_name().map_or(path, Path::new));
+    let parts: Option<Vec<&str>> = relative.iter().map(|p| p.to_str()).collect();
+    Some(parts?.join("/")).filter(|name| !name.is_empty())
+}
+
+fn entry(name: String, object: BackupObject) -> BackupEntry {
+    BackupEntry {
+        name,
+        key: object.key,
+        size: object.size,
+        last_modified: object.last_modified,
+    }
+}
+
+fn not_found(name: &str) -> GuiError {
+    GuiError::NotFound {
+        entity: "backup",
+        id: name.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::test_core;
+    use gglib_core::ports::{BackupStoreError, NoopEmitter};
+
+    struct NoSnapshot;
+
+    #[async_trait::async_trait]
+    impl DatabaseSnapshotPort for NoSnapshot {
+        async fn snapshot(&self, _dest: &Path) -> Result<(), BackupStoreError> {
+            Err(BackupStoreError::Unsupported)
+        }
+    }
+
+    #[tokio::test]
+    async fn operations_need_a_configured_bucket() {
+        let ops = BackupOps::new(BackupDeps {
+            core: test_core().await,
+            snapshot: Arc::new(NoSnapshot),
+            emitter: Arc::new(NoopEmitter::new()),
+        });
+        assert!(matches!(ops.list().await, Err(GuiError::Unavailable(_))));
+        assert!(matches!(
+            ops.push(BackupPushRequest::default()).await,
+            Err(GuiError::Unavailable(_))
+        ));
+    }
+
+    #[test]
+    fn model_files_are_named_by_their_path_below_the_models_dir() {
+        let models = Path::new("/models");
+        assert_eq!(
+            relative_name(models, Path::new("/models/org/repo/a.gguf")).as_deref(),
+            Some("org/repo/a.gguf")
+        );
+        assert_eq!(
+            relative_name(models, Path::new("/elsewhere/b.gguf")).as_deref(),
+            Some("b.gguf")
+        );
+    }
+}
@@ -559,11 +559,14 @@ mod tests {
             hf_filename: None,
             download_date: None,
             last_update_check: None,
+            license: None,
             tags: vec![],
             inference_defaults: None,
             server_defaults: None,
             capabilities: gglib_core::domain::capabilities::ModelCapabilities::default(),
+            capabilities_version: None,
             benchmark_summary: None,
+            card: None,
         }
     }
 }
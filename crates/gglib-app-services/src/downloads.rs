@@ -2,7 +2,10 @@
 
 use std::sync::Arc;
 
-use gglib_core::download::{DownloadId, QueueSnapshot};
+use gglib_core::download::{
+    CollectionQueueResult, DownloadHistoryFilter, DownloadId, GroupProgress, QueueRunSummary,
+    QueueSnapshot,
+};
 use gglib_core::ports::{
     DownloadManagerPort, HfClientPort, HfSearchOptions, ToolSupportDetectorPort,
 };
@@ -58,6 +61,22 @@ impl DownloadOps {
             .map_err(|e| GuiError::Internal(e.to_string()))
     }
 
+    /// Queue every GGUF repository in a `HuggingFace` collection.
+    ///
+    /// `quantization` filters every repo; without it each repo's best
+    /// quantization is auto-selected. Repos that cannot be queued are listed
+    /// in the result's `skipped`.
+    pub async fn queue_collection(
+        &self,
+        slug: String,
+        quantization: Option<String>,
+    ) -> Result<CollectionQueueResult, GuiError> {
+        Arc::clone(&self.downloads)
+            .queue_collection(slug, quantization)
+            .await
+            .map_err(|e| GuiError::Internal(e.to_string()))
+    }
+
     /// Cancel an in-flight download.
     pub async fn cancel_download(&self, model_id: &str) -> Result<(), GuiError> {
         let id: DownloadId = model_id
@@ -130,11 +149,62 @@ impl DownloadOps {
             .map_err(GuiError::from)
     }
 
+    /// Aggregate byte progress of a shard group.
+    pub async fn shard_group_progress(&self, group_id: &str) -> Result<GroupProgress, GuiError> {
+        self.downloads
+            .get_group_progress(group_id)
+            .await
+            .map_err(GuiError::from)
+    }
+
+    /// Past queue runs with their completion records, newest first.
+    pub async fn download_history(
+        &self,
+        filter: &DownloadHistoryFilter,
+    ) -> Result<Vec<QueueRunSummary>, GuiError> {
+        self.downloads
+            .get_history(filter)
+            .await
+            .map_err(GuiError::from)
+    }
+
     /// Clear all failed downloads from the list.
     pub async fn clear_failed(&self) {
         let _ = self.downloads.clear_failed().await;
     }
 
+    /// Queue a failed download again; returns its 1-based queue position.
+    ///
+    /// A shard group only fetches the shards that are not already on disk.
+    pub async fn retry_download(&self, model_id: &str) -> Result<u32, GuiError> {
+        let id: DownloadId = model_id
+            .parse()
+            .unwrap_or_else(|_| DownloadId::from_model(model_id));
+        self.downloads.retry(&id).await.map_err(GuiError::from)
+    }
+
+    /// How many downloads the queue holds.
+    pub async fn max_queue_size(&self) -> Result<u32, GuiError> {
+        self.downloads
+            .get_max_queue_size()
+            .await
+            .map_err(GuiError::from)
+    }
+
+    /// Change how many downloads the queue holds. Downloads already queued
+    /// are kept even when they exceed the new size.
+    pub async fn set_max_queue_size(&self, size: u32) -> Result<(), GuiError> {
+        if size == 0 {
+            return Err(GuiError::ValidationFailed(
+                "the queue must hold at least one download".to_string(),
+            ));
+        }
+        self.downloads
+            .set_max_queue_size(size)
+            .await
+            .map_err(GuiError::from)
+    }
+
     /// Cancel all active and queued downloads.
     pub async fn cancel_all(&self) {
         let _ = self.downloads.cancel_all().await;
@@ -175,17 +245,7 @@ impl DownloadOps {
             models: response
                 .items
                 .into_iter()
-                .map(|m| HfModelSummary {
-                    id: m.model_id,
-                    name: m.name,
-                    author: m.author,
-                    downloads: m.downloads,
-                    likes: m.likes,
-                    last_modified: m.last_modified,
-                    parameters_b: m.parameters_b,
-                    description: m.description,
-                    tags: m.tags,
-                })
+                .map(crate::mapping::hf_model_summary)
                 .collect(),
             has_more: response.has_more,
             page: response.page,
@@ -279,18 +339,7 @@ impl DownloadOps {
             )));
         }
 
-        // Map HfRepoInfo to HfModelSummary
-        Ok(HfModelSummary {
-            id: info.model_id,
-            name: info.name,
-            author: info.author,
-            downloads: info.downloads,
-            likes: info.likes,
-            last_modified: info.last_modified,
-            parameters_b: info.parameters_b,
-            description: info.description,
-            tags: info.tags,
-        })
+        Ok(crate::mapping::hf_model_summary(info))
     }
 }
 
@@ -359,6 +408,27 @@ mod tests {
         assert_eq!(result.unwrap(), 3);
     }
 
+    #[tokio::test]
+    async fn unknown_shard_group_progress_maps_to_not_found() {
+        let ops = make_ops(MockDownloadManager::new());
+        let result = ops.shard_group_progress("owner/repo:Q4_K_M:0").await;
+        assert!(
+            matches!(result, Err(GuiError::NotFound { .. })),
+            "expected GuiError::NotFound, got {result:?}"
+        );
+    }
+
+    #[tokio::test]
+    async fn queue_collection_returns_the_batch_result() {
+        let ops = make_ops(MockDownloadManager::new());
+        let result = ops
+            .queue_collection("org/family".to_string(), Some("Q4_K_M".to_string()))
+            .await
+            .unwrap();
+        assert_eq!(result.slug, "org/family");
+        assert!(result.queued.is_empty() && result.skipped.is_empty());
+    }
+
     #[tokio::test]
     async fn clear_failed_completes_without_error() {
         let ops = make_ops(MockDownloadManager::new());
@@ -366,6 +436,24 @@ mod tests {
         ops.clear_failed().await;
     }
 
+    #[tokio::test]
+    async fn retry_download_returns_the_queue_position() {
+        let ops = make_ops(MockDownloadManager::new());
+        assert_eq!(ops.retry_download("some/model:Q4_K_M").await.unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn zero_max_queue_size_is_rejected() {
+        let ops = make_ops(MockDownloadManager::new());
+        let result = ops.set_max_queue_size(0).await;
+        assert!(
+            matches!(result, Err(GuiError::ValidationFailed(_))),
+            "expected GuiError::ValidationFailed, got {result:?}"
+        );
+        ops.set_max_queue_size(4).await.unwrap();
+        assert_eq!(ops.max_queue_size().await.unwrap(), 10, "the mock's size");
+    }
+
     #[tokio::test]
     async fn cancel_all_completes_without_error() {
         let ops = make_ops(MockDownloadManager::new());
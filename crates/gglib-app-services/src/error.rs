@@ -96,6 +96,7 @@ impl From<gglib_core::download::DownloadError> for GuiError {
             DownloadError::AlreadyQueued { id } => {
                 Self::Conflict(format!("download already queued: {id}"))
             }
+            DownloadError::InvalidDestination { message } => Self::ValidationFailed(message),
             DownloadError::Cancelled => Self::Conflict("download cancelled".to_string()),
             DownloadError::QueueFull { max_size } => {
                 Self::Conflict(format!("queue full: max {max_size} downloads"))
@@ -123,3 +124,68 @@ impl From<gglib_core::McpServiceError> for GuiError {
         }
     }
 }
+
+impl From<gglib_core::ports::ProjectError> for GuiError {
+    fn from(err: gglib_core::ports::ProjectError) -> Self {
+        use gglib_core::ports::ProjectError;
+        match err {
+            ProjectError::NotFound(id) => Self::NotFound {
+                entity: "project",
+                id: id.to_string(),
+            },
+            ProjectError::ConversationNotFound(id) => Self::NotFound {
+                entity: "conversation",
+                id: id.to_string(),
+            },
+            ProjectError::Invalid(msg) => Self::ValidationFailed(msg),
+            ProjectError::Database(msg) => Self::Internal(msg),
+        }
+    }
+}
+
+impl From<gglib_core::ports::PipelineError> for GuiError {
+    fn from(err: gglib_core::ports::PipelineError) -> Self {
+        use gglib_core::ports::PipelineError;
+        match err {
+            PipelineError::NotFound(id) => Self::NotFound {
+                entity: "pipeline run",
+                id: id.to_string(),
+            },
+            PipelineError::Invalid(msg) => Self::ValidationFailed(msg),
+            PipelineError::Database(msg) => Self::Internal(msg),
+        }
+    }
+}
+
+impl From<gglib_core::ports::PresetError> for GuiError {
+    fn from(err: gglib_core::ports::PresetError) -> Self {
+        use gglib_core::ports::PresetError;
+        match err {
+            PresetError::NotFound(id) => Self::NotFound {
+                entity: "preset",
+                id: id.to_string(),
+            },
+            PresetError::AlreadyExists(name) => {
+                Self::Conflict(format!("Preset '{name}' already exists"))
+            }
+            PresetError::Invalid(msg) => Self::ValidationFailed(msg),
+            PresetError::Database(msg) => Self::Internal(msg),
+        }
+    }
+}
+
+impl From<gglib_core::ports::BackupStoreError> for GuiError {
+    fn from(err: gglib_core::ports::BackupStoreError) -> Self {
+        use gglib_core::ports::BackupStoreError;
+        match err {
+            BackupStoreError::Unsupported => Self::Unavailable(err.to_string()),
+            BackupStoreError::NotFound(key) => Self::NotFound {
+                entity: "backup",
+                id: key,
+            },
+            BackupStoreError::Io { .. } | BackupStoreError::Service(_) => {
+                Self::Internal(err.to_string())
+            }
+        }
+    }
+}
@@ -0,0 +1,139 @@
+//! Job operations: one view of every long-running operation.
+//!
+//! Thin wrapper around [`gglib_core::jobs::JobManager`]. Cancelling is the
+//! only part that needs more than the manager: downloads are tracked from
+//! their events, so their cancel also has to reach the download manager.
+
+use std::sync::Arc;
+
+use gglib_core::download::DownloadId;
+use gglib_core::jobs::{Job, JobError, JobKind, JobManager};
+use gglib_core::ports::DownloadManagerPort;
+
+use crate::error::GuiError;
+
+/// Dependencies for job operations.
+pub struct JobDeps {
+    /// The shared job registry.
+    pub jobs: Arc<JobManager>,
+    /// Download manager, for cancelling download jobs.
+    pub downloads: Arc<dyn DownloadManagerPort>,
+}
+
+/// Listing and cancelling tracked jobs.
+pub struct JobOps {
+    deps: JobDeps,
+}
+
+impl JobOps {
+    pub fn new(deps: JobDeps) -> Self {
+        Self { deps }
+    }
+
+    /// The shared registry, for adapters that register their own jobs.
+    pub fn manager(&self) -> &Arc<JobManager> {
+        &self.deps.jobs
+    }
+
+    /// Running and recently finished jobs, newest first.
+    pub fn list(&self) -> Vec<Job> {
+        self.deps.jobs.list()
+    }
+
+    /// A single job by ID.
+    pub fn get(&self, id: &str) -> Result<Job, GuiError> {
+        self.deps.jobs.get(id).ok_or_else(|| GuiError::NotFound {
+            entity: "job",
+            id: id.to_string(),
+        })
+    }
+
+    /// Ask a job to stop.
+    ///
+    /// Returns once the request is delivered; the job reports `cancelled`
+    /// when it has actually stopped.
+    pub async fn cancel(&self, id: &str) -> Result<Job, GuiError> {
+        let job = self.deps.jobs.cancel(id)?;
+        if job.kind == JobKind::Download
+            && let Some(subject) = &job.subject
+        {
+            let download_id: DownloadId = subject
+                .parse()
+                .unwrap_or_else(|_| DownloadId::from_model(subject.as_str()));
+            self.deps.downloads.cancel_download(&download_id).await?;
+        }
+        Ok(job)
+    }
+}
+
+impl From<JobError> for GuiError {
+    fn from(err: JobError) -> Self {
+        match err {
+            JobError::NotFound(id) => Self::NotFound { entity: "job", id },
+            JobError::AlreadyFinished(_) | JobError::NotCancellable(_) => {
+                Self::Conflict(err.to_string())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::test_support::MockDownloadManager;
+    use gglib_core::download::DownloadEvent;
+    use gglib_core::jobs::{DownloadJobTracker, JobSpec};
+    use gglib_core::ports::{DownloadEventEmitterPort, NoopDownloadEmitter, NoopEmitter};
+
+    fn make_ops(mgr: MockDownloadManager) -> JobOps {
+        JobOps::new(JobDeps {
+            jobs: Arc::new(JobManager::new(Arc::new(NoopEmitter))),
+            downloads: Arc::new(mgr),
+        })
+    }
+
+    #[tokio::test]
+    async fn unknown_and_fixed_jobs_are_rejected() {
+        let ops = make_ops(MockDownloadManager::new());
+        assert!(matches!(
+            ops.cancel("nope").await,
+            Err(GuiError::NotFound { entity: "job", .. })
+        ));
+
+        let build = ops
+            .manager()
+            .start(JobSpec::new(JobKind::LlamaBuild, "llama.cpp"));
+        assert!(matches!(
+            ops.cancel(build.id()).await,
+            Err(GuiError::Conflict(_))
+        ));
+        build.complete();
+    }
+
+    #[tokio::test]
+    async fn cancelling_a_download_job_reaches_the_download_manager() {
+        let ops = make_ops(MockDownloadManager::failing_cancel());
+        let tracker = DownloadJobTracker::new(
+            Arc::new(NoopDownloadEmitter::new()),
+            Arc::clone(ops.manager()),
+        );
+        tracker.emit(DownloadEvent::DownloadStarted {
+            id: "org/model:Q4_K_M".to_string(),
+            shard_index: None,
+            total_shards: None,
+        });
+        let job = ops.list().remove(0);
+
+        // The failing mock proves the cancel was forwarded.
+        assert!(matches!(
+            ops.cancel(&job.id).await,
+            Err(GuiError::NotFound {
+                entity: "download",
+                ..
+            })
+        ));
+        assert!(ops.get(&job.id).unwrap().cancel_requested);
+    }
+}
@@ -6,6 +6,8 @@
 use async_trait as _;
 use gglib_hf as _;
 #[cfg(test)]
+use gglib_test_support as _;
+#[cfg(test)]
 use tempfile as _;
 use thiserror as _;
 use tokio as _;
@@ -17,30 +19,49 @@ mod test_support;
 mod error;
 mod helpers;
 
+mod backup;
 pub mod benchmark;
 pub mod council_approvals;
 mod downloads;
+mod jobs;
+mod logs;
+mod mapping;
 mod mcp;
 mod models;
+mod onboarding;
+mod pipelines;
+mod presets;
+mod projects;
 mod proxy;
 mod servers;
 mod settings;
 pub mod setup;
+mod sync;
 pub mod types;
+mod warm_start;
 
 // Primary exports
 pub use council_approvals::CouncilApprovalRegistry;
 pub use error::GuiError;
 
 // Domain ops + their Deps
+pub use backup::{BackupDeps, BackupOps};
 pub use benchmark::{BenchmarkDeps, BenchmarkOps};
 pub use downloads::{DownloadDeps, DownloadOps};
+pub use jobs::{JobDeps, JobOps};
+pub use logs::{LogDeps, LogOps};
 pub use mcp::{McpDeps, McpOps};
 pub use models::{ModelDeps, ModelOps};
+pub use onboarding::{OnboardingDeps, OnboardingOps, OnboardingStepRequest};
+pub use pipelines::{PipelineDeps, PipelineOps};
+pub use presets::{PresetDeps, PresetOps};
+pub use projects::{ProjectDeps, ProjectOps};
 pub use proxy::{ProxyDeps, ProxyOps};
 pub use servers::{ServerDeps, ServerOps};
-pub use settings::{SettingsDeps, SettingsOps};
+pub use settings::{EXTERNAL_CHANGE_POLL_INTERVAL, SettingsDeps, SettingsOps};
 pub use setup::{SetupDeps, SetupOps};
+pub use sync::{SyncDeps, SyncOps};
+pub use warm_start::{WarmStartDeps, WarmStartOps};
 
 // Re-export commonly used types from gglib-core for convenience
 pub use gglib_core::ModelFilterOptions;
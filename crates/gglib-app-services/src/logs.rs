@@ -0,0 +1,119 @@
+//! Application log queries over the rotating log files.
+//!
+//! Thin async wrapper around [`gglib_runtime::log_query`]: file reads run on
+//! the blocking pool, and malformed queries are rejected as
+//! [`GuiError::ValidationFailed`] before any file is opened.
+
+use std::path::PathBuf;
+
+use gglib_runtime::log_query::{LogFile, LogPage, LogQuery, list_log_files, query_logs};
+
+use crate::error::GuiError;
+
+/// Dependencies for log operations.
+pub struct LogDeps {
+    /// Directory the tracing file appender writes to
+    /// ([`gglib_core::telemetry::log_dir`] in the running app).
+    pub log_dir: PathBuf,
+}
+
+/// Listing and querying the application logs.
+pub struct LogOps {
+    deps: LogDeps,
+}
+
+impl LogOps {
+    pub fn new(deps: LogDeps) -> Self {
+        Self { deps }
+    }
+
+    /// List the log files, oldest first.
+    pub async fn list_files(&self) -> Result<Vec<LogFile>, GuiError> {
+        let dir = self.deps.log_dir.clone();
+        tokio::task::spawn_blocking(move || list_log_files(&dir))
+            .await
+            .map_err(|e| GuiError::Internal(format!("Log listing task failed: {e}")))?
+            .map_err(|e| GuiError::Internal(format!("Failed to list log files: {e}")))
+    }
+
+    /// Read one page of log records matching `query`.
+    pub async fn query(&self, query: LogQuery) -> Result<LogPage, GuiError> {
+        if query.before.is_some() && query.after.is_some() {
+            return Err(GuiError::ValidationFailed(
+                "'before' and 'after' cannot be combined".to_string(),
+            ));
+        }
+        if let (Some(since), Some(until)) = (query.since, query.until)
+            && since >= until
+        {
+            return Err(GuiError::ValidationFailed(
+                "'since' must be earlier than 'until'".to_string(),
+            ));
+        }
+        if let Some(file) = &query.file
+            && !self.list_files().await?.iter().any(|f| &f.name == file)
+        {
+            return Err(GuiError::NotFound {
+                entity: "log file",
+                id: file.clone(),
+            });
+        }
+
+        let dir = self.deps.log_dir.clone();
+        tokio::task::spawn_blocking(move || query_logs(&dir, &query))
+            .await
+            .map_err(|e| GuiError::Internal(format!("Log query task failed: {e}")))?
+            .map_err(|e| GuiError::Internal(format!("Failed to read logs: {e}")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ops(dir: &std::path::Path) -> LogOps {
+        LogOps::new(LogDeps {
+            log_dir: dir.to_path_buf(),
+        })
+    }
+
+    #[tokio::test]
+    async fn missing_directory_has_no_logs() {
+        let dir = tempfile::tempdir().unwrap();
+        let ops = ops(&dir.path().join("absent"));
+        assert!(ops.list_files().await.unwrap().is_empty());
+        assert!(
+            ops.query(LogQuery::default())
+                .await
+                .unwrap()
+                .records
+                .is_empty()
+        );
+    }
+
+    #[tokio::test]
+    async fn rejects_malformed_queries() {
+        let dir = tempfile::tempdir().unwrap();
+        let ops = ops(dir.path());
+
+        let cursor = "gglib.log.2026-03-14:1".parse().ok();
+        let both = LogQuery {
+            before: cursor.clone(),
+            after: cursor,
+            ..Default::default()
+        };
+        assert!(matches!(
+            ops.query(both).await,
+            Err(GuiError::ValidationFailed(_))
+        ));
+
+        let unknown = LogQuery {
+            file: Some("gglib.log.1999-01-01".to_string()),
+            ..Default::default()
+        };
+        assert!(matches!(
+            ops.query(unknown).await,
+            Err(GuiError::NotFound { .. })
+        ));
+    }
+}
@@ -0,0 +1,610 @@
+//! Conversions between core domain types and the DTOs in [`crate::types`].
+//!
+//! Every function here destructures its source without `..`, so a field
+//! added to a domain type stops this module compiling until it is either
+//! mapped or listed as deliberately left out (`field: _`). The DTO side is
+//! covered by the struct literal, which must name every DTO field. Keep
+//! domain ↔ DTO conversions in this module so both halves stay checked;
+//! the tests below additionally compare serialized field names, so a
+//! mapping that quietly drops a value shows up as well.
+
+use gglib_core::McpServer;
+use gglib_core::domain::mcp::{McpEnvEntry, McpServerConfig};
+use gglib_core::domain::{
+    Model, generate_model_card, is_restrictive_license, license_from_hf_tags,
+};
+use gglib_core::ports::huggingface::HfRepoInfo;
+use gglib_core::settings::{Settings, SettingsUpdate};
+
+use crate::types::{
+    AppSettings, GuiModel, HfModelSummary, McpEnvEntryDto, McpServerConfigDto, McpServerDto,
+    ModelDetailDto, UpdateSettingsRequest,
+};
+
+/// Timestamp format of the model DTOs.
+const DISPLAY_TIME: &str = "%Y-%m-%d %H:%M:%S";
+
+/// The library-list view of a model.
+pub(crate) fn gui_model(model: Model, is_serving: bool, port: Option<u16>) -> GuiModel {
+    let Model {
+        id,
+        name,
+        model_key: _,
+        file_path,
+        param_count_b,
+        architecture,
+        quantization,
+        context_length,
+        // Detail-view only: MoE topology, provenance, raw metadata, card.
+        expert_count: _,
+        expert_used_count: _,
+        expert_shared_count: _,
+        metadata: _,
+        added_at,
+        hf_repo_id,
+        hf_commit_sha: _,
+        hf_filename: _,
+        download_date: _,
+        last_update_check: _,
+        license,
+        tags,
+        capabilities,
+        capabilities_version: _,
+        inference_defaults,
+        server_defaults,
+        card: _,
+        benchmark_summary,
+    } = model;
+    GuiModel {
+        id,
+        name,
+        file_path: file_path.to_string_lossy().to_string(),
+        param_count_b,
+        architecture,
+        quantization,
+        context_length,
+        added_at: added_at.format(DISPLAY_TIME).to_string(),
+        hf_repo_id,
+        license,
+        tags,
+        is_serving,
+        port,
+        inference_defaults,
+        server_defaults,
+        capabilities,
+        benchmark_summary,
+    }
+}
+
+/// The inspect view of a model. Models stored without a card (added before
+/// cards existed) get one generated from their metadata.
+pub(crate) fn model_detail(model: Model, is_serving: bool, port: Option<u16>) -> ModelDetailDto {
+    let generated_card = model
+        .card
+        .is_none()
+        .then(|| generate_model_card(&model.to_new_model()));
+    let Model {
+        id,
+        name,
+        model_key: _,
+        file_path,
+        param_count_b,
+        architecture,
+        quantization,
+        context_length,
+        expert_count,
+        expert_used_count,
+        expert_shared_count,
+        metadata,
+        added_at,
+        hf_repo_id,
+        hf_commit_sha,
+        hf_filename,
+        download_date,
+        last_update_check,
+        license,
+        tags,
+        capabilities,
+        capabilities_version: _,
+        inference_defaults,
+        // Served from the model's own endpoints and the list view.
+        server_defaults: _,
+        card,
+        benchmark_summary: _,
+    } = model;
+    ModelDetailDto {
+        id,
+        name,
+        file_path: file_path.to_string_lossy().to_string(),
+        param_count_b,
+        architecture,
+        quantization,
+        context_length,
+        expert_count,
+        expert_used_count,
+        expert_shared_count,
+        hf_repo_id,
+        hf_filename,
+        hf_commit_sha,
+        download_date: download_date.map(|d| d.format(DISPLAY_TIME).to_string()),
+        last_update_check: last_update_check.map(|d| d.format(DISPLAY_TIME).to_string()),
+        license,
+        tags,
+        capabilities,
+        inference_defaults,
+        added_at: added_at.format(DISPLAY_TIME).to_string(),
+        is_serving,
+        port,
+        metadata,
+        card: card.or(generated_card).unwrap_or_default(),
+    }
+}
+
+/// The browser view of a `HuggingFace` repository, with its license read
+/// from the tags.
+pub(crate) fn hf_model_summary(info: HfRepoInfo) -> HfModelSummary {
+    let HfRepoInfo {
+        model_id,
+        name,
+        author,
+        downloads,
+        likes,
+        parameters_b,
+        description,
+        last_modified,
+        // Only used for tool-support detection.
+        chat_template: _,
+        tags,
+    } = info;
+    let license = license_from_hf_tags(&tags);
+    HfModelSummary {
+        id: model_id,
+        name,
+        author,
+        downloads,
+        likes,
+        last_modified,
+        parameters_b,
+        description,
+        restrictive_license: license.as_deref().is_some_and(is_restrictive_license),
+        license,
+        tags,
+    }
+}
+
+/// The settings UI view of the stored settings at `version`.
+pub(crate) fn app_settings(
+    settings: Settings,
+    version: u64,
+    hf_token_configured: bool,
+) -> AppSettings {
+    let Settings {
+        default_download_path,
+        default_context_size,
+        proxy_port,
+        proxy_host,
+        proxy_allow_public,
+        proxy_allowed_origins,
+        proxy_preempt_background,
+        proxy_strip_reasoning,
+        llama_base_port,
+        max_download_queue_size,
+        partial_download_grace_hours,
+        trash_retention_days,
+        trash_max_size_gb,
+        watch_models_dir,
+        show_memory_fit_indicators,
+        warn_restrictive_licenses,
+        max_tool_iterations,
+        max_stagnation_steps,
+        default_model_id,
+        idle_shutdown_minutes,
+        startup_models,
+        cached_prompts,
+        inference_defaults,
+        inference_profiles,
+        otlp,
+        setup_completed,
+        // Served by the onboarding and sync endpoints.
+        onboarding: _,
+        title_generation_prompt,
+        auto_title_conversations,
+        encrypt_chat_history,
+        max_attachment_size_mb,
+        allowed_attachment_types,
+        locale,
+        web_search,
+        url_fetch,
+        moderation,
+        proxy_limits,
+        energy,
+        sync_peers: _,
+        backup,
+    } = settings;
+    AppSettings {
+        default_download_path,
+        default_context_size,
+        proxy_port,
+        proxy_host,
+        proxy_allow_public,
+        proxy_allowed_origins,
+        proxy_preempt_background,
+        proxy_strip_reasoning,
+        llama_base_port,
+        max_download_queue_size,
+        partial_download_grace_hours,
+        trash_retention_days,
+        trash_max_size_gb,
+        watch_models_dir,
+        show_memory_fit_indicators,
+        warn_restrictive_licenses,
+        max_tool_iterations,
+        max_stagnation_steps,
+        default_model_id,
+        idle_shutdown_minutes,
+        startup_models,
+        cached_prompts,
+        inference_defaults,
+        inference_profiles,
+        otlp,
+        setup_completed,
+        title_generation_prompt,
+        auto_title_conversations,
+        encrypt_chat_history,
+        max_attachment_size_mb,
+        allowed_attachment_types,
+        locale,
+        web_search,
+        url_fetch,
+        moderation,
+        proxy_limits,
+        energy,
+        backup,
+        hf_token_configured,
+        version,
+    }
+}
+
+/// The stored-settings update for a settings UI request.
+pub(crate) fn settings_update(request: UpdateSettingsRequest) -> SettingsUpdate {
+    let UpdateSettingsRequest {
+        default_download_path,
+        default_context_size,
+        proxy_port,
+        proxy_host,
+        proxy_allow_public,
+        proxy_allowed_origins,
+        proxy_preempt_background,
+        proxy_strip_reasoning,
+        llama_base_port,
+        max_download_queue_size,
+        partial_download_grace_hours,
+        trash_retention_days,
+        trash_max_size_gb,
+        watch_models_dir,
+        show_memory_fit_indicators,
+        warn_restrictive_licenses,
+        max_tool_iterations,
+        max_stagnation_steps,
+        default_model_id,
+        idle_shutdown_minutes,
+        startup_models,
+        cached_prompts,
+        inference_defaults,
+        inference_profiles,
+        otlp,
+        setup_completed,
+        title_generation_prompt,
+        auto_title_conversations,
+        encrypt_chat_history,
+        max_attachment_size_mb,
+        allowed_attachment_types,
+        locale,
+        web_search,
+        url_fetch,
+        moderation,
+        proxy_limits,
+        energy,
+        backup,
+        // Kept in the secret store, not the settings row.
+        hf_token: _,
+        // A precondition on the write, not a setting.
+        expected_version: _,
+    } = request;
+    SettingsUpdate {
+        default_download_path,
+        default_context_size,
+        proxy_port,
+        proxy_host,
+        proxy_allow_public,
+        proxy_allowed_origins,
+        proxy_preempt_background,
+        proxy_strip_reasoning,
+        llama_base_port,
+        max_download_queue_size,
+        partial_download_grace_hours,
+        trash_retention_days,
+        trash_max_size_gb,
+        watch_models_dir,
+        show_memory_fit_indicators,
+        warn_restrictive_licenses,
+        max_tool_iterations,
+        max_stagnation_steps,
+        default_model_id,
+        idle_shutdown_minutes,
+        startup_models,
+        cached_prompts,
+        inference_defaults,
+        inference_profiles,
+        otlp,
+        setup_completed,
+        onboarding: None,
+        title_generation_prompt,
+        auto_title_conversations,
+        encrypt_chat_history,
+        max_attachment_size_mb,
+        allowed_attachment_types,
+        locale,
+        web_search,
+        url_fetch,
+        moderation,
+        proxy_limits,
+        energy,
+        backup,
+    }
+}
+
+/// The GUI view of an MCP server.
+pub(crate) fn mcp_server(server: &McpServer) -> McpServerDto {
+    let McpServer {
+        id,
+        name,
+        server_type,
+        config,
+        enabled,
+        lifecycle,
+        env,
+        created_at,
+        last_connected_at,
+        is_valid,
+        last_error,
+    } = server;
+    let McpServerConfig {
+        command,
+        resolved_path_cache,
+        args,
+        working_dir,
+        path_extra,
+        url,
+    } = config;
+    McpServerDto {
+        id: *id,
+        name: name.clone(),
+        server_type: format!("{server_type:?}").to_lowercase(),
+        config: McpServerConfigDto {
+            command: command.clone(),
+            resolved_path_cache: resolved_path_cache.clone(),
+            args: args.clone(),
+            working_dir: working_dir.clone(),
+            path_extra: path_extra.clone(),
+            url: url.clone(),
+        },
+        enabled: *enabled,
+        lifecycle: *lifecycle,
+        env: env
+            .iter()
+            .map(|McpEnvEntry { key, value }| McpEnvEntryDto {
+                key: key.clone(),
+                value: value.clone(),
+            })
+            .collect(),
+        created_at: created_at.to_rfc3339(),
+        last_connected_at: last_connected_at.map(|dt| dt.to_rfc3339()),
+        is_valid: *is_valid,
+        last_error: last_error.clone(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{BTreeSet, HashMap};
+    use std::path::PathBuf;
+
+    use chrono::Utc;
+    use serde::Serialize;
+
+    use gglib_core::domain::benchmark::ModelBenchmarkSummary;
+    use gglib_core::domain::mcp::{McpLifecycle, McpServerType};
+    use gglib_core::domain::{InferenceConfig, ServerConfig};
+
+    use super::*;
+
+    /// Serialized field names of `value`, in `snake_case`, with the fields
+    /// that serialized as `null` listed separately.
+    fn fields(value: &impl Serialize) -> (BTreeSet<String>, BTreeSet<String>) {
+        let json = serde_json::to_value(value).unwrap();
+        let mut names = BTreeSet::new();
+        let mut nulls = BTreeSet::new();
+        for (key, value) in json.as_object().unwrap() {
+            let mut name = String::new();
+            for c in key.chars() {
+                if c.is_ascii_uppercase() {
+                    name.push('_');
+                }
+                name.push(c.to_ascii_lowercase());
+            }
+            if value.is_null() {
+                nulls.insert(name.clone());
+            }
+            names.insert(name);
+        }
+        (names, nulls)
+    }
+
+    /// Assert that `dto` has exactly the fields of `source`, less `dropped`,
+    /// plus `added`.
+    fn assert_fields(
+        source: &impl Serialize,
+        dto: &impl Serialize,
+        dropped: &[&str],
+        added: &[&str],
+    ) {
+        let (mut expected, _) = fields(source);
+        for name in dropped {
+            assert!(expected.remove(*name), "`{name}` is not a source field");
+        }
+        expected.extend(added.iter().map(ToString::to_string));
+        assert_eq!(fields(dto).0, expected);
+    }
+
+    /// A model with every optional field set.
+    fn full_model() -> Model {
+        Model {
+            id: 7,
+            name: "Llama".to_string(),
+            model_key: "hf:org/llama@abc#llama.gguf".to_string(),
+            file_path: PathBuf::from("/models/llama.gguf"),
+            param_count_b: 8.0,
+            architecture: Some("llama".to_string()),
+            quantization: Some("Q4_K_M".to_string()),
+            context_length: Some(8192),
+            expert_count: Some(8),
+            expert_used_count: Some(2),
+            expert_shared_count: Some(1),
+            metadata: HashMap::from([("general.name".to_string(), "Llama".to_string())]),
+            added_at: Utc::now(),
+            hf_repo_id: Some("org/llama".to_string()),
+            hf_commit_sha: Some("abc".to_string()),
+            hf_filename: Some("llama.gguf".to_string()),
+            download_date: Some(Utc::now()),
+            last_update_check: Some(Utc::now()),
+            license: Some("apache-2.0".to_string()),
+            tags: vec!["chat".to_string()],
+            capabilities: gglib_core::ModelCapabilities::default(),
+            capabilities_version: Some(gglib_core::domain::CAPABILITY_DETECTOR_VERSION),
+            inference_defaults: Some(InferenceConfig::default()),
+            server_defaults: Some(ServerConfig::default()),
+            card: Some("# Llama".to_string()),
+            benchmark_summary: Some(ModelBenchmarkSummary {
+                model_id: 7,
+                best_tg_tps: Some(40.0),
+                best_pp_tps: Some(900.0),
+                latest_tg_tps: Some(38.0),
+                latest_pp_tps: Some(880.0),
+                latest_backend: Some("cuda".to_string()),
+                perf_run_count: 1,
+                compare_run_count: 0,
+                last_benchmarked_at: Utc::now(),
+                updated_at: Utc::now(),
+            }),
+        }
+    }
+
+    #[test]
+    fn gui_model_maps_every_listed_field() {
+        let model = full_model();
+        let dto = gui_model(model.clone(), true, Some(9000));
+        assert_fields(
+            &model,
+            &dto,
+            &[
+                "model_key",
+                "expert_count",
+                "expert_used_count",
+                "expert_shared_count",
+                "metadata",
+                "hf_commit_sha",
+                "hf_filename",
+                "download_date",
+                "last_update_check",
+                "capabilities_version",
+                "card",
+            ],
+            &["is_serving", "port"],
+        );
+        assert!(fields(&dto).1.is_empty(), "a set field was dropped");
+    }
+
+    #[test]
+    fn model_detail_maps_every_listed_field() {
+        let model = full_model();
+        let dto = model_detail(model.clone(), true, Some(9000));
+        assert_fields(
+            &model,
+            &dto,
+            &[
+                "model_key",
+                "capabilities_version",
+                "server_defaults",
+                "benchmark_summary",
+            ],
+            &["is_serving", "port"],
+        );
+        assert!(fields(&dto).1.is_empty(), "a set field was dropped");
+        assert_eq!(dto.card, "# Llama");
+
+        let uncarded = model_detail(
+            Model {
+                card: None,
+                ..model
+            },
+            false,
+            None,
+        );
+        assert!(!uncarded.card.is_empty());
+    }
+
+    #[test]
+    fn app_settings_map_every_listed_field() {
+        let settings = Settings::default();
+        assert_fields(
+            &settings,
+            &app_settings(settings.clone(), 0, false),
+            &["onboarding", "sync_peers"],
+            &["hf_token_configured", "version"],
+        );
+    }
+
+    #[test]
+    fn settings_update_maps_every_listed_field() {
+        let request = UpdateSettingsRequest::default();
+        assert_fields(
+            &request,
+            &settings_update(request.clone()),
+            &["hf_token", "expected_version"],
+            &["onboarding"],
+        );
+    }
+
+    #[test]
+    fn mcp_server_maps_every_field() {
+        let server = McpServer {
+            id: 3,
+            name: "files".to_string(),
+            server_type: McpServerType::Stdio,
+            config: McpServerConfig {
+                command: Some("npx".to_string()),
+                resolved_path_cache: Some("/usr/bin/npx".to_string()),
+                args: Some(vec!["server".to_string()]),
+                working_dir: Some("/tmp".to_string()),
+                path_extra: Some("/opt/bin".to_string()),
+                url: Some("http://localhost:3000".to_string()),
+            },
+            enabled: true,
+            lifecycle: McpLifecycle::default(),
+            env: vec![McpEnvEntry {
+                key: "TOKEN".to_string(),
+                value: "x".to_string(),
+            }],
+            created_at: Utc::now(),
+            last_connected_at: Some(Utc::now()),
+            is_valid: true,
+            last_error: Some("boom".to_string()),
+        };
+        let dto = mcp_server(&server);
+        assert_fields(&server, &dto, &[], &[]);
+        assert_fields(&server.config, &dto.config, &[], &[]);
+        assert!(fields(&dto).1.is_empty(), "a set field was dropped");
+        assert_eq!(dto.server_type, "stdio");
+    }
+}
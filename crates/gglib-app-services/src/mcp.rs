@@ -8,9 +8,8 @@ use gglib_mcp::{
 
 use crate::error::GuiError;
 use crate::types::{
-    CreateMcpServerRequest, McpEnvEntryDto, McpServerConfigDto, McpServerDto, McpServerInfo,
-    McpServerStatusDto, McpToolCallRequest, McpToolCallResponse, McpToolInfo,
-    UpdateMcpServerRequest,
+    CreateMcpServerRequest, McpServerInfo, McpServerStatusDto, McpToolCallRequest,
+    McpToolCallResponse, McpToolInfo, UpdateMcpServerRequest,
 };
 
 /// Dependencies for MCP operations.
@@ -38,37 +37,6 @@ impl McpOps {
         }
     }
 
-    /// Convert core McpServer to DTO.
-    fn server_to_dto(server: &gglib_core::McpServer) -> McpServerDto {
-        McpServerDto {
-            id: server.id,
-            name: server.name.clone(),
-            server_type: format!("{:?}", server.server_type).to_lowercase(),
-            config: McpServerConfigDto {
-                command: server.config.command.clone(),
-                resolved_path_cache: server.config.resolved_path_cache.clone(),
-                args: server.config.args.clone(),
-                working_dir: server.config.working_dir.clone(),
-                path_extra: server.config.path_extra.clone(),
-                url: server.config.url.clone(),
-            },
-            enabled: server.enabled,
-            lifecycle: server.lifecycle,
-            env: server
-                .env
-                .iter()
-                .map(|e| McpEnvEntryDto {
-                    key: e.key.clone(),
-                    value: e.value.clone(),
-                })
-                .collect(),
-            created_at: server.created_at.to_rfc3339(),
-            last_connected_at: server.last_connected_at.map(|dt| dt.to_rfc3339()),
-            is_valid: server.is_valid,
-            last_error: server.last_error.clone(),
-        }
-    }
-
     /// Convert status to DTO.
     fn status_to_dto(status: McpServerStatus) -> McpServerStatusDto {
         match status {
@@ -90,7 +58,7 @@ impl McpOps {
         Ok(servers
             .into_iter()
             .map(|info| McpServerInfo {
-                server: Self::server_to_dto(&info.server),
+                server: crate::mapping::mcp_server(&info.server),
                 status: Self::status_to_dto(info.status),
                 tools: info.tools.iter().map(Self::tool_to_info).collect(),
             })
@@ -142,7 +110,7 @@ impl McpOps {
             .map_err(GuiError::from)?;
 
         Ok(McpServerInfo {
-            server: Self::server_to_dto(&server),
+            server: crate::mapping::mcp_server(&server),
             status: McpServerStatusDto::Stopped,
             tools: Vec::new(),
         })
@@ -204,7 +172,7 @@ impl McpOps {
         let status = self.mcp.get_server_status(id).await;
 
         Ok(McpServerInfo {
-            server: Self::server_to_dto(&server),
+            server: crate::mapping::mcp_server(&server),
             status: Self::status_to_dto(status),
             tools: Vec::new(),
         })
@@ -221,7 +189,7 @@ impl McpOps {
         let info = self.mcp.get_server_info(id).await.map_err(GuiError::from)?;
 
         Ok(McpServerInfo {
-            server: Self::server_to_dto(&info.server),
+            server: crate::mapping::mcp_server(&info.server),
             status: Self::status_to_dto(info.status),
             tools: tools.iter().map(Self::tool_to_info).collect(),
         })
@@ -233,7 +201,7 @@ impl McpOps {
         let info = self.mcp.get_server_info(id).await.map_err(GuiError::from)?;
 
         Ok(McpServerInfo {
-            server: Self::server_to_dto(&info.server),
+            server: crate::mapping::mcp_server(&info.server),
             status: Self::status_to_dto(info.status),
             tools: Vec::new(),
         })
@@ -3,17 +3,20 @@
 use std::path::PathBuf;
 use std::sync::Arc;
 
-use gglib_core::ports::{GgufParserPort, ProcessRunner};
-use gglib_core::services::AppCore;
+use chrono::{DateTime, Utc};
+use gglib_core::domain::trash::TrashEntry;
+use gglib_core::domain::usage::{DailyUsage, ModelUsage};
+use gglib_core::ports::{GgufParserPort, ProcessRunner, RepositoryError};
+use gglib_core::services::{AppCore, ModelTrashService, ModelUsageService};
 use gglib_core::{
-    ModelCapabilities, ModelFilterOptions,
-    domain::{ModelListQuery, apply_query},
+    CoreError, Model, ModelCapabilities, ModelFilterOptions,
+    domain::{ModelChanges, ModelListQuery, PageRequest, apply_query},
 };
 
 use crate::error::GuiError;
 use crate::types::{
-    AddModelRequest, GuiModel, ModelDetailDto, RemoveModelRequest, SetCapabilitiesRequest,
-    UpdateModelRequest,
+    AddModelRequest, GuiModel, GuiModelChanges, GuiModelPage, ModelDetailDto, RemoveModelRequest,
+    SetCapabilitiesRequest, UpdateModelRequest,
 };
 
 /// Dependencies for model operations.
@@ -92,6 +95,70 @@ impl ModelOps {
         Ok(gui_models)
     }
 
+    /// One page of the model list, filtered and sorted by `query`.
+    ///
+    /// The repository pages in storage, so only the returned models are
+    /// loaded and given their serving status.
+    pub async fn list_page(
+        &self,
+        query: ModelListQuery,
+        page: PageRequest,
+    ) -> Result<GuiModelPage, GuiError> {
+        let result = self
+            .deps
+            .core
+            .models()
+            .list_page(&query, page)
+            .await
+            .map_err(|e| GuiError::Internal(format!("Failed to list models: {e}")))?;
+
+        Ok(GuiModelPage {
+            items: self.with_status(result.items).await,
+            total: result.total as u64,
+            offset: page.offset as u64,
+            limit: page.limit as u64,
+        })
+    }
+
+    /// Models changed or removed since `since`; everything when it is `None`.
+    pub async fn changes_since(
+        &self,
+        since: Option<DateTime<Utc>>,
+    ) -> Result<GuiModelChanges, GuiError> {
+        let models = self.deps.core.models();
+        let changes = match since {
+            Some(since) => models.list_changed_since(since).await,
+            None => {
+                // Read before listing, so nothing written meanwhile is missed.
+                let as_of = Utc::now();
+                models.list().await.map(|changed| ModelChanges {
+                    changed,
+                    removed: Vec::new(),
+                    full: true,
+                    as_of,
+                })
+            }
+        }
+        .map_err(|e| GuiError::Internal(format!("Failed to list model changes: {e}")))?;
+
+        Ok(GuiModelChanges {
+            changed: self.with_status(changes.changed).await,
+            removed: changes.removed,
+            full: changes.full,
+            as_of: changes.as_of.to_rfc3339(),
+        })
+    }
+
+    /// Convert models to their GUI form with current serving status.
+    async fn with_status(&self, models: Vec<Model>) -> Vec<GuiModel> {
+        let mut gui_models = Vec::with_capacity(models.len());
+        for model in models {
+            let (is_serving, port) = self.get_server_status(model.id).await;
+            gui_models.push(GuiModel::from_model(model, is_serving, port));
+        }
+        gui_models
+    }
+
     /// Get a specific model by ID.
     pub async fn get(&self, id: i64) -> Result<GuiModel, GuiError> {
         let model = crate::helpers::resolve_model(self.deps.core.models(), id).await?;
@@ -158,18 +225,31 @@ impl ModelOps {
             Some(None) => model.server_defaults = None,
             None => {} // don't touch
         }
+        match request.card {
+            Some(Some(card)) => model.card = Some(card),
+            Some(None) => {
+                model.card = Some(gglib_core::domain::generate_model_card(
+                    &model.to_new_model(),
+                ));
+            }
+            None => {} // don't touch
+        }
 
         self.deps
             .core
             .models()
             .update(&model)
             .await
-            .map_err(|e| GuiError::Internal(format!("Failed to update model: {e}")))?;
+            .map_err(|e| match e {
+                CoreError::Validation(msg) => GuiError::ValidationFailed(msg),
+                e => GuiError::Internal(format!("Failed to update model: {e}")),
+            })?;
 
         Ok(GuiModel::from_domain(model))
     }
 
-    /// Remove a model from the database.
+    /// Remove a model. With the trash available its files move there and
+    /// it can be restored until purged; otherwise only its row is deleted.
     pub async fn remove(&self, id: i64, request: RemoveModelRequest) -> Result<String, GuiError> {
         let model = crate::helpers::resolve_model(self.deps.core.models(), id).await?;
 
@@ -187,6 +267,15 @@ impl ModelOps {
                 .map_err(|e| GuiError::Internal(format!("Failed to stop server: {e}")))?;
         }
 
+        if let Some(trash) = self.deps.core.trash() {
+            let entry = trash.trash(&model).await.map_err(trash_error)?;
+            return Ok(format!(
+                "Model '{}' moved to the trash (restore before {}).",
+                model.name,
+                entry.purge_after.format("%Y-%m-%d")
+            ));
+        }
+
         self.deps
             .core
             .models()
@@ -197,6 +286,48 @@ impl ModelOps {
         Ok(format!("Model '{}' removed successfully", model.name))
     }
 
+    /// List trashed models, oldest first.
+    pub async fn list_trash(&self) -> Result<Vec<TrashEntry>, GuiError> {
+        self.trash()?.list().await.map_err(trash_error)
+    }
+
+    /// Move a trashed model's files back and return it to the library.
+    pub async fn restore_trash(&self, id: i64) -> Result<GuiModel, GuiError> {
+        let model = self.trash()?.restore(id).await.map_err(trash_error)?;
+        Ok(GuiModel::from_domain(model))
+    }
+
+    /// Permanently delete every trashed model. Returns what was deleted.
+    pub async fn empty_trash(&self) -> Result<Vec<TrashEntry>, GuiError> {
+        self.trash()?.empty().await.map_err(trash_error)
+    }
+
+    fn trash(&self) -> Result<&ModelTrashService, GuiError> {
+        self.deps
+            .core
+            .trash()
+            .ok_or_else(|| GuiError::Unavailable("model trash is not configured".to_string()))
+    }
+
+    /// Every model ranked by usage over the last `days` days, or over all
+    /// recorded usage when `days` is `None`. Least used models come last.
+    pub async fn usage_ranking(&self, days: Option<u32>) -> Result<Vec<ModelUsage>, GuiError> {
+        Ok(self.usage()?.ranking(days).await?)
+    }
+
+    /// Usage across the library per day over the last `days` days, newest
+    /// first, with estimated energy and cost.
+    pub async fn usage_daily(&self, days: u32) -> Result<Vec<DailyUsage>, GuiError> {
+        Ok(self.usage()?.daily(days).await?)
+    }
+
+    fn usage(&self) -> Result<&ModelUsageService, GuiError> {
+        self.deps
+            .core
+            .usage()
+            .ok_or_else(|| GuiError::Unavailable("model usage is not configured".to_string()))
+    }
+
     /// List all unique tags.
     pub async fn list_tags(&self) -> Result<Vec<String>, GuiError> {
         self.deps
@@ -290,8 +421,16 @@ impl ModelOps {
         if let Some(v) = request.supports_reasoning {
             caps.set(ModelCapabilities::SUPPORTS_REASONING, v);
         }
+        if let Some(v) = request.embedding {
+            caps.set(ModelCapabilities::EMBEDDING, v);
+        }
+        if let Some(v) = request.reranker {
+            caps.set(ModelCapabilities::RERANKER, v);
+        }
 
         model.capabilities = caps;
+        // Hand-set flags are no longer the detector's; re-detection skips them.
+        model.capabilities_version = None;
 
         self.deps
             .core
@@ -304,6 +443,19 @@ impl ModelOps {
     }
 }
 
+/// Map trash failures: unknown entries are not found and a restore
+/// blocked by an existing file is a conflict.
+fn trash_error(err: CoreError) -> GuiError {
+    match err {
+        CoreError::Repository(RepositoryError::NotFound(id)) => GuiError::NotFound {
+            entity: "trashed model",
+            id,
+        },
+        CoreError::Validation(msg) => GuiError::Conflict(msg),
+        other => GuiError::from(other),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::sync::Arc;
@@ -313,8 +465,9 @@ mod tests {
 
     use super::*;
     use crate::error::GuiError;
-    use crate::test_support::{MockProcessRunner, test_core};
-    use gglib_core::ports::NoopGgufParser;
+    use crate::test_support::{MockProcessRunner, MockSystemProbePort, test_core};
+    use gglib_core::domain::usage::UsageDelta;
+    use gglib_core::ports::{ModelUsageSource, NoopGgufParser};
 
     fn make_ops(core: Arc<AppCore>) -> ModelOps {
         ModelOps::new(ModelDeps {
@@ -484,4 +637,173 @@ mod tests {
             "explicit JSON null must clear server_defaults"
         );
     }
+
+    /// A locally added model gets a generated card that the detail view
+    /// shows; user edits replace it and an explicit null regenerates it.
+    #[tokio::test]
+    async fn local_model_card_is_generated_editable_and_regenerable() {
+        let core = test_core().await;
+        let ops = make_ops(core);
+
+        let dir = tempdir().unwrap();
+        let gguf_path = dir.path().join("model.gguf");
+        fs::write(&gguf_path, b"placeholder").await.unwrap();
+        let gguf_path = gguf_path.canonicalize().unwrap();
+
+        let added = ops
+            .add(AddModelRequest {
+                file_path: gguf_path.to_str().unwrap().to_string(),
+            })
+            .await
+            .expect("add should succeed");
+        let generated = ops.get_detail(added.id).await.unwrap().card;
+        assert!(generated.starts_with("# "), "{generated}");
+
+        let edit: UpdateModelRequest =
+            serde_json::from_str(r##"{"card": "# Mine\n\nNotes."}"##).unwrap();
+        ops.update(added.id, edit).await.unwrap();
+        assert_eq!(
+            ops.get_detail(added.id).await.unwrap().card,
+            "# Mine\n\nNotes."
+        );
+
+        let regenerate: UpdateModelRequest = serde_json::from_str(r#"{"card": null}"#).unwrap();
+        ops.update(added.id, regenerate).await.unwrap();
+        assert_eq!(ops.get_detail(added.id).await.unwrap().card, generated);
+    }
+
+    /// Removing a model with the trash configured moves its file aside and
+    /// hides it; restoring puts both back, and emptying deletes for good.
+    #[tokio::test]
+    async fn removed_model_goes_to_trash_and_restores() {
+        let pool = gglib_db::setup_test_database().await.unwrap();
+        let dir = tempdir().unwrap();
+        let trash = Arc::new(ModelTrashService::new(
+            gglib_db::CoreFactory::model_repository(pool.clone()),
+            gglib_db::CoreFactory::model_trash_repository(pool.clone()),
+            gglib_db::CoreFactory::settings_repository(pool.clone()),
+            dir.path().join("trash"),
+        ));
+        let core = Arc::new(
+            gglib_db::CoreFactory::build_app_core(pool, Arc::new(MockProcessRunner))
+                .with_trash(trash),
+        );
+        let ops = make_ops(core);
+
+        let gguf_path = dir.path().join("model.gguf");
+        fs::write(&gguf_path, b"placeholder").await.unwrap();
+        let gguf_path = gguf_path.canonicalize().unwrap();
+        let added = ops
+            .add(AddModelRequest {
+                file_path: gguf_path.to_str().unwrap().to_string(),
+            })
+            .await
+            .unwrap();
+
+        let message = ops
+            .remove(added.id, RemoveModelRequest::default())
+            .await
+            .unwrap();
+        assert!(message.contains("trash"), "{message}");
+        assert!(!gguf_path.exists());
+        assert!(ops.list().await.unwrap().is_empty());
+        let entries = ops.list_trash().await.unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].size_bytes, 11);
+
+        // A new file at the original path blocks the restore.
+        fs::write(&gguf_path, b"other").await.unwrap();
+        assert!(matches!(
+            ops.restore_trash(added.id).await,
+            Err(GuiError::Conflict(_))
+        ));
+        fs::remove_file(&gguf_path).await.unwrap();
+
+        let restored = ops.restore_trash(added.id).await.unwrap();
+        assert_eq!(restored.id, added.id);
+        assert_eq!(fs::read(&gguf_path).await.unwrap(), b"placeholder");
+        assert!(ops.list_trash().await.unwrap().is_empty());
+
+        ops.remove(added.id, RemoveModelRequest::default())
+            .await
+            .unwrap();
+        assert_eq!(ops.empty_trash().await.unwrap().len(), 1);
+        assert!(ops.list_trash().await.unwrap().is_empty());
+        assert!(matches!(
+            ops.restore_trash(added.id).await,
+            Err(GuiError::NotFound { .. })
+        ));
+    }
+
+    /// The ranking lists every model, the used ones first, and the daily
+    /// summary sums the day, both from what was collected from the usage
+    /// source.
+    #[tokio::test]
+    async fn usage_ranking_lists_unused_models_last() {
+        struct OneRequest(i64);
+        impl ModelUsageSource for OneRequest {
+            fn drain_usage(&self) -> Vec<(i64, UsageDelta)> {
+                let delta = UsageDelta {
+                    requests: 1,
+                    prompt_tokens: 20,
+                    completion_tokens: 5,
+                    serve_seconds: 1800.0,
+                    busy_seconds: 60.0,
+                };
+                vec![(self.0, delta)]
+            }
+        }
+
+        let pool = gglib_db::setup_test_database().await.unwrap();
+        let usage = Arc::new(ModelUsageService::new(
+            gglib_db::CoreFactory::model_repository(pool.clone()),
+            gglib_db::CoreFactory::model_usage_repository(pool.clone()),
+            gglib_db::CoreFactory::settings_repository(pool.clone()),
+            Arc::new(MockSystemProbePort::default()),
+        ));
+        let core = Arc::new(
+            gglib_db::CoreFactory::build_app_core(pool, Arc::new(MockProcessRunner))
+                .with_usage(Arc::clone(&usage)),
+        );
+        let ops = make_ops(core);
+
+        let dir = tempdir().unwrap();
+        let mut ids = Vec::new();
+        for name in ["idle.gguf", "busy.gguf"] {
+            let path = dir.path().join(name);
+            fs::write(&path, b"placeholder").await.unwrap();
+            let added = ops
+                .add(AddModelRequest {
+                    file_path: path.to_str().unwrap().to_string(),
+                })
+                .await
+                .unwrap();
+            ids.push(added.id);
+        }
+        usage.collect(&OneRequest(ids[1])).await;
+
+        let ranking = ops.usage_ranking(Some(7)).await.unwrap();
+        assert_eq!(
+            ranking.iter().map(|u| u.model_id).collect::<Vec<_>>(),
+            [ids[1], ids[0]]
+        );
+        assert_eq!(ranking[0].total_tokens(), 25);
+        assert!((ranking[0].serve_hours - 0.5).abs() < f64::EPSILON);
+        assert_eq!(ranking[1].requests, 0);
+
+        // No `energy` setting: the mock probe reports no GPU, so the CPU
+        // draw is assumed and no cost is estimated.
+        let daily = ops.usage_daily(7).await.unwrap();
+        assert_eq!(daily.len(), 1);
+        assert_eq!(daily[0].requests, 1);
+        let expected_wh = gglib_core::domain::energy::CPU_WATTS / 60.0;
+        assert!((daily[0].energy.watt_hours - expected_wh).abs() < 1e-9);
+        assert_eq!(daily[0].energy.cost, None);
+
+        let unconfigured = make_ops(test_core().await);
+        assert!(matches!(
+            unconfigured.usage_ranking(None).await,
+            Err(GuiError::Unavailable(_))
+        ));
+    }
 }
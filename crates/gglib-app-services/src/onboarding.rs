@@ -0,0 +1,264 @@
+//! First-run onboarding orchestration for GUI backend.
+//!
+//! Pulls the scattered bootstrapping steps — llama.cpp install, models
+//! directory, first model download, proxy — behind one state machine (see
+//! [`gglib_core::domain::onboarding`]). Completion is observed from the
+//! system on every [`OnboardingOps::status`] call, so onboarding resumes
+//! where it stands after a restart; only skipped steps and the queued
+//! first-model download are persisted, in settings.
+//!
+//! Executing a step emits [`AppEvent::OnboardingProgress`] events (started,
+//! byte progress for the llama.cpp install, completed or failed) through the
+//! injected emitter. The first-model download itself reports through the
+//! usual download events.
+
+use std::net::{Ipv4Addr, SocketAddr};
+use std::sync::Arc;
+use std::time::Duration;
+
+use serde::Deserialize;
+use utoipa::ToSchema;
+
+use gglib_core::domain::onboarding::{
+    OnboardingPhase, OnboardingProgress, OnboardingStatus, OnboardingStep,
+};
+use gglib_core::events::AppEvent;
+use gglib_core::paths::{
+    DirectoryCreationStrategy, ensure_directory, persist_models_dir, resolve_models_dir,
+    verify_writable,
+};
+use gglib_core::ports::{AppEventEmitter, DownloadManagerPort};
+use gglib_core::services::AppCore;
+use gglib_core::settings::{DEFAULT_CONTEXT_SIZE, Settings, SettingsUpdate};
+use gglib_runtime::proxy::{ProxyConfig, ProxyStatus};
+
+use crate::error::GuiError;
+use crate::proxy::ProxyOps;
+use crate::setup::SetupOps;
+
+/// How long to wait for the proxy port to accept a connection.
+const PROXY_PROBE_TIMEOUT: Duration = Duration::from_millis(500);
+
+/// Inputs for [`OnboardingOps::run_step`]; each step reads only its own.
+#[derive(Debug, Clone, Default, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct OnboardingStepRequest {
+    /// `models_dir`: directory to use instead of the current one.
+    pub models_dir: Option<String>,
+    /// `first_model`: `HuggingFace` repository to download.
+    pub repo_id: Option<String>,
+    /// `first_model`: quantization (best available when omitted).
+    pub quantization: Option<String>,
+}
+
+/// Dependencies for onboarding operations.
+pub struct OnboardingDeps {
+    pub core: Arc<AppCore>,
+    pub setup: Arc<SetupOps>,
+    pub proxy: Arc<ProxyOps>,
+    pub downloads: Arc<dyn DownloadManagerPort>,
+    pub emitter: Arc<dyn AppEventEmitter>,
+}
+
+/// Onboarding state machine.
+pub struct OnboardingOps {
+    deps: OnboardingDeps,
+}
+
+impl OnboardingOps {
+    pub fn new(deps: OnboardingDeps) -> Self {
+        Self { deps }
+    }
+
+    /// Observe every step and combine it with the persisted progress.
+    pub async fn status(&self) -> Result<OnboardingStatus, GuiError> {
+        let settings = self.settings().await?;
+
+        let binaries = gglib_runtime::llama::check_llama_installed();
+        let binaries_detail = gglib_core::paths::llama_server_path()
+            .map(|p| p.display().to_string())
+            .unwrap_or_default();
+
+        let (models_dir, models_dir_detail) = match resolve_models_dir(None) {
+            Ok(r) => (
+                r.path.is_dir() && verify_writable(&r.path).is_ok(),
+                r.path.display().to_string(),
+            ),
+            Err(e) => (false, e.to_string()),
+        };
+
+        let model_count = self
+            .deps
+            .core
+            .models()
+            .list()
+            .await
+            .map_err(|e| GuiError::Internal(format!("Failed to list models: {e}")))?
+            .len();
+
+        let (proxy, proxy_detail) = self.proxy_reachable(&settings).await;
+
+        Ok(OnboardingStatus::from_checks(
+            [
+                (OnboardingStep::Binaries, binaries, binaries_detail),
+                (OnboardingStep::ModelsDir, models_dir, models_dir_detail),
+                (
+                    OnboardingStep::FirstModel,
+                    model_count > 0,
+                    format!("{model_count} model(s) in library"),
+                ),
+                (OnboardingStep::Proxy, proxy, proxy_detail),
+            ],
+            &settings.onboarding.unwrap_or_default(),
+        ))
+    }
+
+    /// Execute one step, emitting progress events, and return the new status.
+    pub async fn run_step(
+        &self,
+        step: OnboardingStep,
+        request: OnboardingStepRequest,
+    ) -> Result<OnboardingStatus, GuiError> {
+        self.emit(step, OnboardingPhase::Started, None);
+        match self.execute(step, request).await {
+            Ok(message) => self.emit(step, OnboardingPhase::Completed, Some(message)),
+            Err(e) => {
+                self.emit(step, OnboardingPhase::Failed, Some(e.to_string()));
+                return Err(e);
+            }
+        }
+        self.status().await
+    }
+
+    /// Skip a step so it no longer blocks completion.
+    pub async fn skip(&self, step: OnboardingStep) -> Result<OnboardingStatus, GuiError> {
+        self.update_progress(|p| p.skip(step)).await?;
+        self.status().await
+    }
+
+    async fn execute(
+        &self,
+        step: OnboardingStep,
+        request: OnboardingStepRequest,
+    ) -> Result<String, GuiError> {
+        match step {
+            OnboardingStep::Binaries => {
+                if gglib_runtime::llama::check_llama_installed() {
+                    return Ok("llama.cpp is already installed".to_string());
+                }
+                let emitter = Arc::clone(&self.deps.emitter);
+                self.deps
+                    .setup
+                    .install_llama(Box::new(move |downloaded, total| {
+                        emitter.emit(AppEvent::onboarding_bytes(
+                            OnboardingStep::Binaries,
+                            downloaded,
+                            total,
+                        ));
+                    }))
+                    .await?;
+                Ok("llama.cpp installed".to_string())
+            }
+            OnboardingStep::ModelsDir => {
+                let path = match request.models_dir {
+                    Some(dir) => std::path::PathBuf::from(dir),
+                    None => {
+                        resolve_models_dir(None)
+                            .map_err(|e| GuiError::Internal(e.to_string()))?
+                            .path
+                    }
+                };
+                ensure_directory(&path, DirectoryCreationStrategy::AutoCreate)
+                    .map_err(|e| GuiError::ValidationFailed(e.to_string()))?;
+                verify_writable(&path).map_err(|e| GuiError::ValidationFailed(e.to_string()))?;
+                persist_models_dir(&path).map_err(|e| GuiError::Internal(e.to_string()))?;
+                Ok(format!("Models directory: {}", path.display()))
+            }
+            OnboardingStep::FirstModel => {
+                let repo_id = request.repo_id.ok_or_else(|| {
+                    GuiError::ValidationFailed("repoId is required for first_model".to_string())
+                })?;
+                let (_, shards) = Arc::clone(&self.deps.downloads)
+                    .queue_smart(repo_id.clone(), request.quantization.clone())
+                    .await?;
+                let pending = match &request.quantization {
+                    Some(quant) => format!("{repo_id}:{quant}"),
+                    None => repo_id,
+                };
+                self.update_progress(|p| p.pending_download = Some(pending.clone()))
+                    .await?;
+                Ok(format!("Queued {pending} ({shards} file(s))"))
+            }
+            OnboardingStep::Proxy => {
+                let settings = self.settings().await?;
+                if let (true, detail) = self.proxy_reachable(&settings).await {
+                    return Ok(format!("Proxy already reachable at {detail}"));
+                }
+                let port = settings.effective_proxy_port();
+                let address = self
+                    .deps
+                    .proxy
+                    .start(ProxyConfig {
+                        host: settings.effective_proxy_host().to_string(),
+                        port,
+                        default_context: settings
+                            .default_context_size
+                            .unwrap_or(DEFAULT_CONTEXT_SIZE),
+                        ..Default::default()
+                    })
+                    .await?;
+                self.deps
+                    .emitter
+                    .emit(AppEvent::proxy_started(address.port()));
+                Ok(format!("Proxy listening on {address}"))
+            }
+        }
+    }
+
+    /// Running in this process, or something answering on the configured port
+    /// (e.g. a separate `gglib proxy`).
+    async fn proxy_reachable(&self, settings: &Settings) -> (bool, String) {
+        if let ProxyStatus::Running { address } = self.deps.proxy.status().await {
+            return (true, address.to_string());
+        }
+        let address = SocketAddr::from((Ipv4Addr::LOCALHOST, settings.effective_proxy_port()));
+        let reachable =
+            tokio::time::timeout(PROXY_PROBE_TIMEOUT, tokio::net::TcpStream::connect(address))
+                .await
+                .is_ok_and(|r| r.is_ok());
+        (reachable, address.to_string())
+    }
+
+    async fn update_progress(
+        &self,
+        change: impl FnOnce(&mut OnboardingProgress),
+    ) -> Result<(), GuiError> {
+        let mut progress = self.settings().await?.onboarding.unwrap_or_default();
+        change(&mut progress);
+        self.deps
+            .core
+            .settings()
+            .update(SettingsUpdate {
+                onboarding: Some(Some(progress)),
+                ..Default::default()
+            })
+            .await
+            .map_err(|e| GuiError::Internal(format!("Failed to save onboarding progress: {e}")))?;
+        Ok(())
+    }
+
+    async fn settings(&self) -> Result<Settings, GuiError> {
+        self.deps
+            .core
+            .settings()
+            .get()
+            .await
+            .map_err(|e| GuiError::Internal(format!("Failed to get settings: {e}")))
+    }
+
+    fn emit(&self, step: OnboardingStep, phase: OnboardingPhase, message: Option<String>) {
+        self.deps
+            .emitter
+            .emit(AppEvent::onboarding(step, phase, message));
+    }
+}
@@ -0,0 +1,38 @@
+//! Pipeline operations: persisted pipeline runs and their step outputs.
+//!
+//! Runs are executed by the `gglib-agent` pipeline runner, which writes
+//! through [`gglib_core::services::PipelineService::repo`]; these ops only
+//! read what it stored.
+
+use std::sync::Arc;
+
+use gglib_core::domain::pipeline::PipelineRun;
+use gglib_core::services::AppCore;
+
+use crate::error::GuiError;
+
+/// Dependencies for pipeline operations.
+pub struct PipelineDeps {
+    pub core: Arc<AppCore>,
+}
+
+/// Persisted pipeline runs.
+pub struct PipelineOps {
+    deps: PipelineDeps,
+}
+
+impl PipelineOps {
+    pub fn new(deps: PipelineDeps) -> Self {
+        Self { deps }
+    }
+
+    /// List runs, most recent first.
+    pub async fn list_runs(&self) -> Result<Vec<PipelineRun>, GuiError> {
+        Ok(self.deps.core.pipelines().list_runs().await?)
+    }
+
+    /// Get a run with every completed step's output.
+    pub async fn get_run(&self, id: i64) -> Result<PipelineRun, GuiError> {
+        Ok(self.deps.core.pipelines().get_run(id).await?)
+    }
+}
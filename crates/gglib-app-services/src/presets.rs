@@ -0,0 +1,140 @@
+//! Preset operations: CRUD for generation presets.
+//!
+//! Persistence and validation go through
+//! [`gglib_core::services::PresetService`]. The proxy reads presets straight
+//! from the repository when a request selects one; nothing here is involved
+//! at request time.
+
+use std::sync::Arc;
+
+use gglib_core::domain::preset::{NewPreset, Preset, PresetUpdate};
+use gglib_core::services::AppCore;
+
+use crate::error::GuiError;
+use crate::types::{CreatePresetRequest, UpdatePresetRequest};
+
+/// Dependencies for preset operations.
+pub struct PresetDeps {
+    pub core: Arc<AppCore>,
+}
+
+/// Named system prompt, sampling and tool bundles.
+pub struct PresetOps {
+    deps: PresetDeps,
+}
+
+/// Trim an optional text field, treating blank as unset.
+fn non_blank(value: Option<String>) -> Option<String> {
+    value
+        .map(|v| v.trim().to_string())
+        .filter(|v| !v.is_empty())
+}
+
+impl PresetOps {
+    pub fn new(deps: PresetDeps) -> Self {
+        Self { deps }
+    }
+
+    /// List all presets, ordered by name.
+    pub async fn list(&self) -> Result<Vec<Preset>, GuiError> {
+        Ok(self.deps.core.presets().list().await?)
+    }
+
+    /// Get a preset by ID.
+    pub async fn get(&self, id: i64) -> Result<Preset, GuiError> {
+        Ok(self.deps.core.presets().get(id).await?)
+    }
+
+    /// Create a preset.
+    pub async fn create(&self, req: CreatePresetRequest) -> Result<Preset, GuiError> {
+        let preset = NewPreset {
+            name: req.name.trim().to_string(),
+            description: non_blank(req.description),
+            system_prompt: non_blank(req.system_prompt),
+            sampling: req.sampling,
+            tools: req.tools,
+            mcp_server_ids: req.mcp_server_ids,
+            default_model: req.default_model,
+        };
+        Ok(self.deps.core.presets().create(preset).await?)
+    }
+
+    /// Update a preset; omitted fields are left unchanged.
+    pub async fn update(&self, id: i64, req: UpdatePresetRequest) -> Result<Preset, GuiError> {
+        let update = PresetUpdate {
+            name: req.name.map(|n| n.trim().to_string()),
+            description: req.description.map(non_blank),
+            system_prompt: req.system_prompt.map(non_blank),
+            sampling: req.sampling,
+            tools: req.tools,
+            mcp_server_ids: req.mcp_server_ids,
+            default_model: req.default_model,
+        };
+        Ok(self.deps.core.presets().update(id, update).await?)
+    }
+
+    /// Delete a preset.
+    pub async fn delete(&self, id: i64) -> Result<(), GuiError> {
+        Ok(self.deps.core.presets().delete(id).await?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::test_core;
+
+    fn create_request(name: &str) -> CreatePresetRequest {
+        CreatePresetRequest {
+            name: name.to_string(),
+            description: None,
+            system_prompt: Some("  You answer in haiku.  ".to_string()),
+            sampling: Default::default(),
+            tools: vec![],
+            mcp_server_ids: vec![],
+            default_model: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn crud_round_trip_and_errors() {
+        let ops = PresetOps::new(PresetDeps {
+            core: test_core().await,
+        });
+
+        let preset = ops.create(create_request("haiku")).await.unwrap();
+        assert_eq!(
+            preset.system_prompt.as_deref(),
+            Some("You answer in haiku.")
+        );
+
+        assert!(matches!(
+            ops.create(create_request("haiku")).await,
+            Err(GuiError::Conflict(_))
+        ));
+        assert!(matches!(
+            ops.create(create_request("Not A Slug")).await,
+            Err(GuiError::ValidationFailed(_))
+        ));
+
+        let updated = ops
+            .update(
+                preset.id,
+                UpdatePresetRequest {
+                    system_prompt: Some(None),
+                    tools: Some(vec!["builtin:time".to_string()]),
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap();
+        assert_eq!(updated.system_prompt, None);
+        assert_eq!(updated.tools, ["builtin:time"]);
+
+        ops.delete(preset.id).await.unwrap();
+        assert!(matches!(
+            ops.get(preset.id).await,
+            Err(GuiError::NotFound { .. })
+        ));
+    }
+}
@@ -0,0 +1,294 @@
+//! Project operations: CRUD, file trees and prompt context.
+//!
+//! Persistence goes through [`gglib_core::services::ProjectService`]; the
+//! directory walks and file reads in [`gglib_runtime::project_files`] run on
+//! the blocking pool. Roots and globs are checked here, before anything is
+//! stored, so a saved project always points at a directory that existed and
+//! globs that compile.
+
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use gglib_core::domain::project::{NewProject, Project, ProjectUpdate};
+use gglib_core::services::AppCore;
+use gglib_runtime::project_files::{
+    FileTree, IncludeRules, ProjectContext, build_context, list_file_tree,
+};
+
+use crate::error::GuiError;
+use crate::types::{CreateProjectRequest, UpdateProjectRequest};
+
+/// Dependencies for project operations.
+pub struct ProjectDeps {
+    pub core: Arc<AppCore>,
+}
+
+/// Projects and the context they inject into linked conversations.
+pub struct ProjectOps {
+    deps: ProjectDeps,
+}
+
+fn compile_rules(include: &[String], exclude: &[String]) -> Result<IncludeRules, GuiError> {
+    IncludeRules::new(include, exclude)
+        .map_err(|e| GuiError::ValidationFailed(format!("Invalid glob: {e}")))
+}
+
+async fn ensure_directory(root: &str) -> Result<(), GuiError> {
+    match tokio::fs::metadata(root).await {
+        Ok(meta) if meta.is_dir() => Ok(()),
+        Ok(_) => Err(GuiError::ValidationFailed(format!(
+            "Project root is not a directory: {root}"
+        ))),
+        Err(_) => Err(GuiError::ValidationFailed(format!(
+            "Project root does not exist: {root}"
+        ))),
+    }
+}
+
+impl ProjectOps {
+    pub fn new(deps: ProjectDeps) -> Self {
+        Self { deps }
+    }
+
+    /// List all projects, ordered by name.
+    pub async fn list(&self) -> Result<Vec<Project>, GuiError> {
+        Ok(self.deps.core.projects().list().await?)
+    }
+
+    /// Get a project by ID.
+    pub async fn get(&self, id: i64) -> Result<Project, GuiError> {
+        Ok(self.deps.core.projects().get(id).await?)
+    }
+
+    /// Create a project rooted at an existing directory.
+    pub async fn create(&self, req: CreateProjectRequest) -> Result<Project, GuiError> {
+        compile_rules(&req.include_globs, &req.exclude_globs)?;
+        ensure_directory(&req.root_path).await?;
+
+        let project = NewProject {
+            name: req.name.trim().to_string(),
+            root_path: req.root_path,
+            include_globs: req.include_globs,
+            exclude_globs: req.exclude_globs,
+            auto_include: req.auto_include,
+            token_budget: req.token_budget,
+        };
+        Ok(self.deps.core.projects().create(project).await?)
+    }
+
+    /// Update a project; omitted fields are left unchanged.
+    pub async fn update(&self, id: i64, req: UpdateProjectRequest) -> Result<Project, GuiError> {
+        let current = self.get(id).await?;
+        compile_rules(
+            req.include_globs.as_ref().unwrap_or(&current.include_globs),
+            req.exclude_globs.as_ref().unwrap_or(&current.exclude_globs),
+        )?;
+        if let Some(root) = &req.root_path {
+            ensure_directory(root).await?;
+        }
+
+        let update = ProjectUpdate {
+            name: req.name.map(|n| n.trim().to_string()),
+            root_path: req.root_path,
+            include_globs: req.include_globs,
+            exclude_globs: req.exclude_globs,
+            auto_include: req.auto_include,
+            token_budget: req.token_budget,
+        };
+        Ok(self.deps.core.projects().update(id, update).await?)
+    }
+
+    /// Delete a project, unlinking its conversations.
+    pub async fn delete(&self, id: i64) -> Result<(), GuiError> {
+        Ok(self.deps.core.projects().delete(id).await?)
+    }
+
+    /// List the project directory, flagging the files its globs select.
+    pub async fn file_tree(&self, id: i64) -> Result<FileTree, GuiError> {
+        let project = self.get(id).await?;
+        let rules = compile_rules(&project.include_globs, &project.exclude_globs)?;
+        let root = PathBuf::from(&project.root_path);
+        tokio::task::spawn_blocking(move || list_file_tree(&root, &rules))
+            .await
+            .map_err(|e| GuiError::Internal(format!("File tree task failed: {e}")))?
+            .map_err(|e| {
+                GuiError::Unavailable(format!(
+                    "Cannot read project directory {}: {e}",
+                    project.root_path
+                ))
+            })
+    }
+
+    /// Read the selected files of a project that fit in its token budget.
+    pub async fn context(&self, id: i64) -> Result<ProjectContext, GuiError> {
+        let project = self.get(id).await?;
+        Self::build(project).await
+    }
+
+    async fn build(project: Project) -> Result<ProjectContext, GuiError> {
+        let rules = compile_rules(&project.include_globs, &project.exclude_globs)?;
+        let root = PathBuf::from(&project.root_path);
+        let budget = project.effective_token_budget() as usize;
+        tokio::task::spawn_blocking(move || build_context(&root, &rules, budget))
+            .await
+            .map_err(|e| GuiError::Internal(format!("Project context task failed: {e}")))?
+            .map_err(|e| {
+                GuiError::Unavailable(format!(
+                    "Cannot read project directory {}: {e}",
+                    project.root_path
+                ))
+            })
+    }
+
+    /// Link a conversation to a project, replacing any previous link.
+    pub async fn link_conversation(
+        &self,
+        conversation_id: i64,
+        project_id: i64,
+    ) -> Result<Project, GuiError> {
+        let projects = self.deps.core.projects();
+        projects
+            .link_conversation(conversation_id, project_id)
+            .await?;
+        Ok(projects.get(project_id).await?)
+    }
+
+    /// Remove a conversation's project link.
+    pub async fn unlink_conversation(&self, conversation_id: i64) -> Result<(), GuiError> {
+        Ok(self
+            .deps
+            .core
+            .projects()
+            .unlink_conversation(conversation_id)
+            .await?)
+    }
+
+    /// The project a conversation is linked to, if any.
+    pub async fn conversation_project(
+        &self,
+        conversation_id: i64,
+    ) -> Result<Option<Project>, GuiError> {
+        Ok(self
+            .deps
+            .core
+            .projects()
+            .get_for_conversation(conversation_id)
+            .await?)
+    }
+
+    /// System prompt text carrying the project files of a conversation.
+    ///
+    /// `None` when the conversation has no project, its project has
+    /// `auto_include` off, or no selected file fits the budget.
+    pub async fn context_for_conversation(
+        &self,
+        conversation_id: i64,
+    ) -> Result<Option<String>, GuiError> {
+        let Some(project) = self.conversation_project(conversation_id).await? else {
+            return Ok(None);
+        };
+        if !project.auto_include {
+            return Ok(None);
+        }
+        let name = project.name.clone();
+        let context = Self::build(project).await?;
+        if context.files.is_empty() {
+            return Ok(None);
+        }
+        Ok(Some(format!(
+            "Files from the project \"{name}\" are included below for reference.\n\n{}",
+            context.render()
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::test_core;
+
+    fn create_request(root: &std::path::Path) -> CreateProjectRequest {
+        CreateProjectRequest {
+            name: "app".to_string(),
+            root_path: root.to_string_lossy().into_owned(),
+            include_globs: vec!["src/**/*.rs".to_string()],
+            exclude_globs: vec![],
+            auto_include: true,
+            token_budget: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn create_rejects_bad_roots_and_globs() {
+        let ops = ProjectOps::new(ProjectDeps {
+            core: test_core().await,
+        });
+        let dir = tempfile::tempdir().unwrap();
+
+        let mut req = create_request(&dir.path().join("missing"));
+        assert!(matches!(
+            ops.create(req.clone()).await,
+            Err(GuiError::ValidationFailed(_))
+        ));
+
+        req = create_request(dir.path());
+        req.include_globs = vec!["src/[".to_string()];
+        assert!(matches!(
+            ops.create(req).await,
+            Err(GuiError::ValidationFailed(_))
+        ));
+
+        let project = ops.create(create_request(dir.path())).await.unwrap();
+        assert_eq!(ops.list().await.unwrap().len(), 1);
+        assert!(matches!(
+            ops.update(
+                project.id,
+                UpdateProjectRequest {
+                    exclude_globs: Some(vec!["[".to_string()]),
+                    ..Default::default()
+                }
+            )
+            .await,
+            Err(GuiError::ValidationFailed(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn linked_conversation_gets_project_files() {
+        let core = test_core().await;
+        let ops = ProjectOps::new(ProjectDeps { core: core.clone() });
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir(dir.path().join("src")).unwrap();
+        std::fs::write(
+            dir.path().join("src/lib.rs"),
+            "pub fn answer() -> u32 { 42 }",
+        )
+        .unwrap();
+        std::fs::write(dir.path().join("notes.txt"), "not included").unwrap();
+
+        let project = ops.create(create_request(dir.path())).await.unwrap();
+        let cid = core
+            .chat_history()
+            .create_conversation("Chat".to_string(), None, None)
+            .await
+            .unwrap();
+
+        assert_eq!(ops.context_for_conversation(cid).await.unwrap(), None);
+        ops.link_conversation(cid, project.id).await.unwrap();
+
+        let text = ops.context_for_conversation(cid).await.unwrap().unwrap();
+        assert!(text.contains("<file path=\"src/lib.rs\">"));
+        assert!(!text.contains("notes.txt"));
+
+        ops.update(
+            project.id,
+            UpdateProjectRequest {
+                auto_include: Some(false),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+        assert_eq!(ops.context_for_conversation(cid).await.unwrap(), None);
+    }
+}
@@ -21,7 +21,9 @@ use gglib_mcp::McpService;
 use gglib_proxy::CouncilDeps;
 use gglib_runtime::CouncilRunnerAdapter;
 use gglib_runtime::ports_impl::CatalogPortImpl;
-use gglib_runtime::proxy::{ProxyConfig, ProxyStatus, ProxySupervisor, SupervisorError};
+use gglib_runtime::proxy::{
+    AccessPolicy, ProxyConfig, ProxyStatus, ProxySupervisor, SupervisorError,
+};
 use tracing::info;
 
 use crate::error::GuiError;
@@ -112,10 +114,26 @@ impl ProxyOps {
     /// Delegates to the supervisor using the shared `ModelRuntimePort`
     /// that was injected at construction time. Returns the bound address.
     ///
+    /// The allowed origins and the public-bind opt-in come from saved
+    /// settings, the API key from `GGLIB_PROXY_API_KEY`.
+    ///
     /// # Arguments
     ///
     /// * `config` - Proxy server configuration (host, port, default context)
     pub async fn start(&self, config: ProxyConfig) -> Result<SocketAddr, GuiError> {
+        let settings = self
+            .core
+            .settings()
+            .get()
+            .await
+            .map_err(|e| GuiError::Internal(format!("Failed to get settings: {e}")))?;
+        let config = ProxyConfig {
+            allow_public: config.allow_public || settings.proxy_allow_public == Some(true),
+            access: AccessPolicy::from_settings(&settings, AccessPolicy::api_key_from_env())
+                .with_background_keys(AccessPolicy::background_api_keys_from_env()),
+            ..config
+        };
+
         // Create catalog port from model repository (cheap wrapper; safe to
         // recreate per call — the underlying model repository is shared).
         let catalog: Arc<dyn ModelCatalogPort> =
@@ -151,6 +169,7 @@ impl ProxyOps {
                 self.mcp.clone(),
                 orchestrator,
                 self.core.settings().repo(),
+                self.core.presets().repo(),
             )
             .await
             .map_err(|e| match e {
@@ -160,6 +179,9 @@ impl ProxyOps {
                 SupervisorError::BindFailed { address, reason } => {
                     GuiError::Internal(format!("Failed to bind proxy to {}: {}", address, reason))
                 }
+                e @ SupervisorError::PublicBindNotAllowed(_) => {
+                    GuiError::ValidationFailed(e.to_string())
+                }
                 SupervisorError::NotRunning => {
                     GuiError::Internal("Proxy not running (unexpected)".to_string())
                 }
@@ -177,6 +199,9 @@ impl ProxyOps {
             SupervisorError::BindFailed { address, reason } => {
                 GuiError::Internal(format!("Unexpected bind error at {}: {}", address, reason))
             }
+            SupervisorError::PublicBindNotAllowed(host) => {
+                GuiError::Internal(format!("Unexpected bind refusal for {host}"))
+            }
             SupervisorError::Internal(msg) => GuiError::Internal(msg),
         })
     }
@@ -201,6 +226,13 @@ impl ProxyOps {
     pub fn exit_receiver(&self) -> tokio::sync::watch::Receiver<ProxyStatus> {
         self.supervisor.exit_receiver()
     }
+
+    /// Get a watch receiver for live config reloads of the running proxy.
+    pub fn reload_receiver(
+        &self,
+    ) -> tokio::sync::watch::Receiver<Option<gglib_proxy::AppliedConfig>> {
+        self.supervisor.reload_receiver()
+    }
 }
 #[cfg(test)]
 mod tests {
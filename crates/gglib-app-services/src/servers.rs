@@ -1,6 +1,7 @@
 //! Server lifecycle operations for GUI backend.
 
 use std::collections::HashMap;
+use std::path::PathBuf;
 use std::pin::pin;
 use std::sync::Arc;
 use std::sync::atomic::{AtomicI64, Ordering};
@@ -12,20 +13,31 @@ use tokio::task::JoinHandle;
 use tokio_util::sync::CancellationToken;
 use tracing::{debug, warn};
 
-use gglib_core::domain::Model;
-use gglib_core::events::{AppEvent, ServerSummary};
+use gglib_core::domain::{
+    CachedPrompt, Model, PromptPreview, PromptWarmResult, ServerStats, SlotInfo,
+    is_valid_prompt_name, kv_memory_is_partial,
+};
+use gglib_core::events::{AppEvent, ServerStopReason, ServerSummary};
 use gglib_core::ports::{
     AppEventEmitter, ProcessHandle, ProcessRunner, ServerHealthStatus, ToolSupportDetectorPort,
 };
-use gglib_core::server_config::{CacheRamSetting, resolve_context_size};
+use gglib_core::server_config::{CacheRamSetting, resolve_context_size_within};
 use gglib_core::services::AppCore;
-use gglib_runtime::llama::args::{resolve_cache_ram, resolve_kv_cache_types};
+use gglib_runtime::llama::args::{
+    resolve_cache_ram, resolve_kv_cache_types, resolve_parallel, resolve_slot_restore,
+};
 use gglib_runtime::ports_impl::total_model_bytes;
+use gglib_runtime::prompt_cache::PromptWarmer;
+use gglib_runtime::prompt_preview::PromptPreviewer;
 use gglib_runtime::server_config::{ServerConfigOptions, build_server_config};
 use gglib_runtime::system::total_system_ram_bytes;
+use gglib_runtime::{Escalation, HealthPolicy, IdleEvent, IdlePolicy};
 
 use crate::error::GuiError;
-use crate::types::{ServerInfo, StartServerRequest, StartServerResponse, ToolSupportResponse};
+use crate::types::{
+    PreviewPromptRequest, ServerInfo, StartServerRequest, StartServerResponse, ToolSupportResponse,
+    WarmPromptRequest,
+};
 
 /// Dependencies for server lifecycle operations.
 pub struct ServerDeps {
@@ -41,6 +53,8 @@ struct MonitorHandle {
     join_handle: JoinHandle<()>,
     cancel_token: CancellationToken,
     model_id: i64,
+    /// Latest status the monitor observed, `None` before its first probe.
+    health: Option<ServerHealthStatus>,
 }
 
 /// Registry for tracking active server health monitors.
@@ -79,6 +93,7 @@ impl ServerMonitorRegistry {
                 join_handle,
                 cancel_token,
                 model_id,
+                health: None,
             },
         );
         debug!(
@@ -95,6 +110,29 @@ impl ServerMonitorRegistry {
             .map(|(server_id, _)| *server_id)
     }
 
+    /// Record the latest health status observed by a monitor.
+    fn set_health(&mut self, server_id: i64, status: ServerHealthStatus) {
+        if let Some(handle) = self.monitors.get_mut(&server_id) {
+            handle.health = Some(status);
+        }
+    }
+
+    /// Latest health status of the server for `model_id`, if monitored.
+    fn health_for_model(&self, model_id: i64) -> Option<ServerHealthStatus> {
+        self.monitors
+            .values()
+            .find(|handle| handle.model_id == model_id)
+            .and_then(|handle| handle.health.clone())
+    }
+
+    /// Remove a monitor from the registry without cancelling it.
+    ///
+    /// Used by a monitor that is about to stop its own server: it cannot
+    /// [`cancel`](Self::cancel) itself, since that waits for its own task.
+    fn detach(&mut self, server_id: i64) -> bool {
+        self.monitors.remove(&server_id).is_some()
+    }
+
     /// Cancel and remove a monitor from the registry.
     async fn cancel(&mut self, server_id: i64) -> Result<(), GuiError> {
         if let Some(handle) = self.monitors.remove(&server_id) {
@@ -133,6 +171,94 @@ impl Drop for ServerMonitorRegistry {
     }
 }
 
+/// Stop a server whose idle timeout has passed, from inside its own monitor.
+///
+/// Mirrors [`ServerOps::stop`], except that the monitor detaches itself from
+/// the registry instead of being cancelled. If it is already gone, or its
+/// token was cancelled while waiting for the lock, a manual stop got there
+/// first and owns the shutdown.
+async fn stop_idle_server(
+    runner: &dyn ProcessRunner,
+    server_events: &dyn gglib_core::events::ServerEvents,
+    monitors: &Mutex<ServerMonitorRegistry>,
+    cancel_token: &CancellationToken,
+    server_id: i64,
+    handle: &ProcessHandle,
+    summary: &ServerSummary,
+) {
+    if !monitors.lock().await.detach(server_id) || cancel_token.is_cancelled() {
+        return;
+    }
+
+    debug!(
+        server_id,
+        model_id = handle.model_id,
+        "Stopping idle server"
+    );
+    server_events.stopping(summary);
+    if let Err(e) = runner.stop(handle).await {
+        warn!(server_id, error = %e, "Failed to stop idle server");
+        server_events.error(summary, &e.to_string());
+        return;
+    }
+    server_events.stopped(summary, ServerStopReason::Idle);
+}
+
+/// Restart a server its health monitor has given up on, from inside that
+/// monitor.
+///
+/// Returns the new handle, which the monitor goes on to watch. Returns
+/// `None` when a manual stop got there first or the relaunch failed; in the
+/// latter case the monitor detaches itself, as there is nothing left to
+/// watch.
+#[allow(clippy::too_many_arguments)]
+async fn restart_unhealthy_server(
+    runner: &dyn ProcessRunner,
+    server_events: &dyn gglib_core::events::ServerEvents,
+    monitors: &Mutex<ServerMonitorRegistry>,
+    cancel_token: &CancellationToken,
+    server_id: i64,
+    handle: &ProcessHandle,
+    summary: &mut ServerSummary,
+    config: &gglib_core::ports::ServerConfig,
+) -> Option<ProcessHandle> {
+    if cancel_token.is_cancelled() {
+        return None;
+    }
+
+    warn!(
+        server_id,
+        model_id = handle.model_id,
+        port = handle.port,
+        "Restarting unhealthy server"
+    );
+    server_events.stopping(summary);
+    if let Err(e) = runner.stop(handle).await {
+        // Usually the process is already gone; the relaunch below decides.
+        debug!(server_id, error = %e, "Stopping unhealthy server failed");
+    }
+    server_events.stopped(summary, ServerStopReason::Unhealthy);
+
+    if cancel_token.is_cancelled() {
+        return None;
+    }
+    match runner.start(config.clone()).await {
+        Ok(new_handle) => {
+            summary.port = new_handle.port;
+            summary.healthy = Some(true);
+            server_events.started(summary);
+            Some(new_handle)
+        }
+        Err(e) => {
+            warn!(server_id, error = %e, "Failed to restart unhealthy server");
+            summary.healthy = Some(false);
+            server_events.error(summary, &e.to_string());
+            monitors.lock().await.detach(server_id);
+            None
+        }
+    }
+}
+
 /// Server operations handler.
 pub struct ServerOps {
     deps: ServerDeps,
@@ -156,13 +282,18 @@ impl ServerOps {
     ///
     /// Context size precedence (4-level fallback chain): explicit request
     /// field → per-model `server_defaults.context_length` → global settings
-    /// default → hardcoded default.
+    /// default → hardcoded default. A requested context above the model's
+    /// maximum (stretched by the request's `RoPE` overrides) fails
+    /// validation; a default above it is lowered to it. Parallel slots follow
+    /// the request, then `server_defaults.parallel`, then `resolve_parallel`'s
+    /// memory-based default.
     fn build_config(
         model: &Model,
         request: &StartServerRequest,
         base_port: u16,
         default_context_size: Option<u64>,
-    ) -> gglib_core::ports::ServerConfig {
+        slot_save_path: Option<PathBuf>,
+    ) -> Result<gglib_core::ports::ServerConfig, GuiError> {
         let mut opts = ServerConfigOptions {
             context_size: request.context_length,
             model_server_ctx: model
@@ -176,11 +307,23 @@ impl ServerOps {
             mtp_draft_n_max: request.mtp_draft_n_max,
             mtp_draft_p_min: request.mtp_draft_p_min,
             inference_params: request.inference_params.clone(),
-            slot_save_path: None,
+            rope: request.rope,
+            slot_save_path,
             cache_ram_mb: None,
             cache_reuse: None,
             cache_type_k: None,
             cache_type_v: None,
+            threads: request.threads,
+            threads_batch: request.threads_batch,
+            cpu_affinity: request.cpu_affinity.clone(),
+            numa: request.numa,
+            split_mode: request.split_mode,
+            tensor_split: request.tensor_split.clone(),
+            main_gpu: request.main_gpu,
+            parallel: None,
+            cont_batching: request
+                .cont_batching
+                .or_else(|| model.server_defaults.as_ref()?.cont_batching),
         };
 
         // Resolve KV cache types once so the RAM budget below reflects the
@@ -190,32 +333,54 @@ impl ServerOps {
         opts.cache_type_k = Some(kv_types.k);
         opts.cache_type_v = Some(kv_types.v);
 
-        let launch_ctx = resolve_context_size(&opts);
+        let launch_ctx = resolve_context_size_within(&opts, model.max_context(opts.rope.as_ref()))
+            .map_err(|e| GuiError::ValidationFailed(e.to_string()))?;
+        opts.context_size = Some(launch_ctx);
         let kv_bytes_per_token = gglib_core::domain::estimate_kv_elems_per_token(
             &model.metadata,
             model.architecture.as_deref(),
         )
         .map(|elems| gglib_core::domain::kv_bytes_per_token(elems, kv_types.k, kv_types.v));
+        let total_ram = total_system_ram_bytes();
+        let model_bytes = total_model_bytes(&model.file_path);
+
+        // Slot count first: each slot holds a full context of KV, which the
+        // cache budget below has to leave room for.
+        let parallel = resolve_parallel(
+            request
+                .parallel
+                .or_else(|| model.server_defaults.as_ref()?.parallel),
+            total_ram,
+            model_bytes,
+            kv_bytes_per_token,
+            launch_ctx,
+            opts.slot_save_path.is_some(),
+        );
+        if let Some(explanation) = parallel.explain() {
+            debug!("{explanation}");
+        }
+        opts.parallel = Some(parallel.slots);
+
         let cache_ram = resolve_cache_ram(
             CacheRamSetting::Auto,
-            total_system_ram_bytes(),
-            total_model_bytes(&model.file_path),
+            total_ram,
+            model_bytes,
             kv_bytes_per_token,
-            launch_ctx,
+            launch_ctx.saturating_mul(u64::from(parallel.slots)),
         );
         if let Some(explanation) = cache_ram.explain() {
             debug!("{explanation}");
         }
         opts.cache_ram_mb = cache_ram.cache_ram_mb;
 
-        build_server_config(
+        Ok(build_server_config(
             model.id,
             model.name.clone(),
             model.file_path.clone(),
             base_port,
             &model.tags,
             opts,
-        )
+        ))
     }
 
     /// Start serving a model.
@@ -258,8 +423,33 @@ impl ServerOps {
             "Resolved llama-server base port for model serving"
         );
 
-        let config = Self::build_config(&model, &request, base_port, settings.default_context_size);
-        let handle = self.deps.runner.start(config).await.map_err(|e| {
+        // Models with cached prompts get a slot directory, so the warmed
+        // state can be saved and restored by the next start.
+        let prompts: Vec<CachedPrompt> = settings
+            .cached_prompts
+            .iter()
+            .flatten()
+            .filter(|p| p.model_id == id)
+            .cloned()
+            .collect();
+        let slot_dir = if prompts.is_empty() {
+            None
+        } else {
+            prompt_slot_dir(&model).filter(|dir| {
+                std::fs::create_dir_all(dir)
+                    .inspect_err(|e| warn!("Failed to create slot directory: {e}"))
+                    .is_ok()
+            })
+        };
+
+        let config = Self::build_config(
+            &model,
+            &request,
+            base_port,
+            settings.default_context_size,
+            slot_dir.clone(),
+        )?;
+        let handle = self.deps.runner.start(config.clone()).await.map_err(|e| {
             // Emit error event before mapping the error
             let error_summary = ServerSummary {
                 id: format!("server-{}", id),
@@ -333,8 +523,27 @@ impl ServerOps {
         };
         self.deps.server_events.started(&summary);
 
-        // Spawn health monitor after successful start
-        self.spawn_health_monitor(handle.clone(), id).await;
+        if !prompts.is_empty()
+            && let Ok(model_id) = u32::try_from(id)
+        {
+            let warmer = PromptWarmer::new(handle.port, model_id, slot_dir);
+            tokio::spawn(async move {
+                warmer.warm_configured(&prompts).await;
+            });
+        }
+
+        // Spawn health monitor after successful start. Idle shutdown follows
+        // the same fallback as context size: per-model default, then global.
+        let idle_policy = IdlePolicy::resolve(
+            model
+                .server_defaults
+                .as_ref()
+                .and_then(|d| d.idle_shutdown_minutes),
+            settings.idle_shutdown_minutes,
+        );
+        let health_policy = HealthPolicy::with_interval_secs(request.health_check_interval_secs);
+        self.spawn_health_monitor(handle.clone(), summary, config, health_policy, idle_policy)
+            .await;
 
         Ok(StartServerResponse {
             port: handle.port,
@@ -343,7 +552,23 @@ impl ServerOps {
     }
 
     /// Spawn a health monitoring task for a server.
-    async fn spawn_health_monitor(&self, handle: ProcessHandle, model_id: i64) {
+    ///
+    /// The task follows the health policy's escalation: it logs the first
+    /// unhealthy probes, emits `ServerHealthChanged` once a problem persists,
+    /// and finally restarts the server from `config` and keeps watching the
+    /// new process.
+    ///
+    /// With an idle policy the same task also watches for inactivity: it
+    /// emits `ServerIdleWarning` ahead of the timeout and, once it passes,
+    /// stops the server itself with [`ServerStopReason::Idle`].
+    async fn spawn_health_monitor(
+        &self,
+        handle: ProcessHandle,
+        summary: ServerSummary,
+        config: gglib_core::ports::ServerConfig,
+        health_policy: HealthPolicy,
+        idle_policy: Option<IdlePolicy>,
+    ) {
         let server_id = {
             let registry = self.monitors.lock().await;
             registry.generate_server_id()
@@ -351,47 +576,144 @@ impl ServerOps {
 
         let cancel_token = CancellationToken::new();
         let emitter = Arc::clone(&self.deps.emitter);
+        let runner = Arc::clone(&self.deps.runner);
+        let server_events = Arc::clone(&self.deps.server_events);
+        let monitors = Arc::clone(&self.monitors);
+        let model_id = handle.model_id;
         let port = handle.port;
 
-        // Create monitor with 10-second check interval
-        let monitor = gglib_runtime::ServerHealthMonitor::new(
-            handle,
-            std::time::Duration::from_secs(10),
-            cancel_token.clone(),
-        );
+        let idle_monitor = idle_policy.and_then(|policy| {
+            let model_id = u32::try_from(model_id).ok()?;
+            Some(gglib_runtime::ServerIdleMonitor::new(
+                gglib_runtime::get_stats_registry(),
+                model_id,
+                policy,
+                health_policy.interval,
+                cancel_token.clone(),
+            ))
+        });
+        let task_cancel = cancel_token.clone();
 
         // Spawn monitoring task
         let join_handle = tokio::spawn(async move {
-            let stream = monitor.monitor();
-            let mut stream = pin!(stream);
-
-            while let Some(status) = stream.next().await {
-                let timestamp = SystemTime::now()
-                    .duration_since(UNIX_EPOCH)
-                    .unwrap()
-                    .as_millis() as u64;
-
-                let detail = match &status {
-                    ServerHealthStatus::Degraded { reason } => Some(reason.clone()),
-                    ServerHealthStatus::Unreachable { last_error } => Some(last_error.clone()),
-                    _ => None,
-                };
-
-                debug!(
-                    server_id,
-                    model_id,
-                    port,
-                    ?status,
-                    "Health status changed, emitting event"
+            let mut handle = handle;
+            let mut summary = summary;
+            let mut idle = match idle_monitor {
+                Some(idle_monitor) => idle_monitor.monitor().boxed(),
+                None => futures_util::stream::pending().boxed(),
+            };
+            let mut emitted: Option<ServerHealthStatus> = None;
+
+            // One pass per server process; a health restart starts the next.
+            'process: loop {
+                let monitor = gglib_runtime::ServerHealthMonitor::with_policy(
+                    handle.clone(),
+                    health_policy,
+                    task_cancel.clone(),
                 );
+                let stream = monitor.monitor();
+                let mut stream = pin!(stream);
+
+                loop {
+                    let update = tokio::select! {
+                        update = stream.next() => match update {
+                            Some(update) => update,
+                            None => break 'process,
+                        },
+                        event = idle.next() => {
+                            match event {
+                                Some(IdleEvent::Warning { shutdown_in }) => {
+                                    emitter.emit(AppEvent::server_idle_warning(
+                                        model_id,
+                                        &summary.model_name,
+                                        shutdown_in.as_secs(),
+                                    ));
+                                }
+                                Some(IdleEvent::Expired) => {
+                                    stop_idle_server(
+                                        &*runner,
+                                        &*server_events,
+                                        &monitors,
+                                        &task_cancel,
+                                        server_id,
+                                        &handle,
+                                        &summary,
+                                    )
+                                    .await;
+                                    break 'process;
+                                }
+                                None => idle = futures_util::stream::pending().boxed(),
+                            }
+                            continue;
+                        }
+                    };
+                    let status = update.status;
+                    monitors.lock().await.set_health(server_id, status.clone());
+
+                    if update.escalation == Some(Escalation::Log) {
+                        warn!(
+                            server_id,
+                            model_id,
+                            port = handle.port,
+                            ?status,
+                            "Server health check failing"
+                        );
+                        continue;
+                    }
+
+                    if emitted.as_ref() != Some(&status) {
+                        let timestamp = SystemTime::now()
+                            .duration_since(UNIX_EPOCH)
+                            .unwrap()
+                            .as_millis() as u64;
+
+                        let detail = match &status {
+                            ServerHealthStatus::Degraded { reason } => Some(reason.clone()),
+                            ServerHealthStatus::Unreachable { last_error } => {
+                                Some(last_error.clone())
+                            }
+                            _ => None,
+                        };
+
+                        debug!(
+                            server_id,
+                            model_id,
+                            port = handle.port,
+                            ?status,
+                            "Health status changed, emitting event"
+                        );
+
+                        emitter.emit(AppEvent::ServerHealthChanged {
+                            server_id,
+                            model_id,
+                            status: status.clone(),
+                            detail,
+                            timestamp,
+                        });
+                        emitted = Some(status);
+                    }
 
-                emitter.emit(AppEvent::ServerHealthChanged {
-                    server_id,
-                    model_id,
-                    status,
-                    detail,
-                    timestamp,
-                });
+                    if update.escalation == Some(Escalation::Restart) {
+                        match restart_unhealthy_server(
+                            &*runner,
+                            &*server_events,
+                            &monitors,
+                            &task_cancel,
+                            server_id,
+                            &handle,
+                            &mut summary,
+                            &config,
+                        )
+                        .await
+                        {
+                            Some(new_handle) => {
+                                handle = new_handle;
+                                continue 'process;
+                            }
+                            None => break 'process,
+                        }
+                    }
+                }
             }
 
             debug!(server_id, model_id, "Health monitor task completed");
@@ -445,7 +767,9 @@ impl ServerOps {
         })?;
 
         // Emit stopped event after successful stop
-        self.deps.server_events.stopped(&summary);
+        self.deps
+            .server_events
+            .stopped(&summary, ServerStopReason::Requested);
 
         Ok(format!("Server for model {} stopped", id))
     }
@@ -524,14 +848,63 @@ impl ServerOps {
         }
     }
 
-    /// List all running servers as GUI DTOs.
+    /// List all running servers as GUI DTOs, with the latest status from
+    /// each server's health monitor.
     pub async fn list_servers(&self) -> Vec<ServerInfo> {
-        match self.deps.runner.list_running().await {
-            Ok(handles) => handles.iter().map(ServerInfo::from_handle).collect(),
-            Err(_) => Vec::new(),
+        let Ok(handles) = self.deps.runner.list_running().await else {
+            return Vec::new();
+        };
+        let registry = self.monitors.lock().await;
+        handles
+            .iter()
+            .map(|handle| ServerInfo {
+                health: registry.health_for_model(handle.model_id),
+                slots: u32::try_from(handle.model_id)
+                    .ok()
+                    .and_then(|id| gglib_runtime::get_slot_registry().get(id)),
+                ..ServerInfo::from_handle(handle)
+            })
+            .collect()
+    }
+
+    /// Record that a running server handled a request outside the proxy, so
+    /// the idle timeout restarts (e.g. GUI chat forwarded straight to it).
+    pub fn mark_active(&self, model_id: i64) {
+        if let Ok(id) = u32::try_from(model_id) {
+            gglib_runtime::get_stats_registry().touch(id);
         }
     }
 
+    /// Get latency and throughput stats for a running server's session.
+    ///
+    /// Returns `NotFound` when the model has no running server: stats are
+    /// scoped to a session and dropped when the server stops.
+    pub fn get_stats(&self, model_id: i64) -> Result<ServerStats, GuiError> {
+        u32::try_from(model_id)
+            .ok()
+            .and_then(|id| gglib_runtime::get_stats_registry().get(id))
+            .ok_or_else(|| GuiError::NotFound {
+                entity: "server",
+                id: model_id.to_string(),
+            })
+    }
+
+    /// Read what each slot of a running server is doing right now.
+    ///
+    /// Asks the server directly rather than returning the last poll, so a
+    /// client diagnosing a queued request sees the current state.
+    pub async fn get_slots(&self, model_id: i64) -> Result<Vec<SlotInfo>, GuiError> {
+        let handle = crate::helpers::find_handle(&*self.deps.runner, model_id)
+            .await
+            .ok_or_else(|| GuiError::NotFound {
+                entity: "server",
+                id: model_id.to_string(),
+            })?;
+        gglib_runtime::fetch_slots(handle.port)
+            .await
+            .map_err(|e| GuiError::Unavailable(format!("{e:#}")))
+    }
+
     /// Get logs for a specific server port.
     pub fn get_logs(&self, port: u16) -> Vec<crate::types::ServerLogEntry> {
         gglib_runtime::get_log_manager().get_logs(port)
@@ -548,6 +921,93 @@ impl ServerOps {
         gglib_runtime::get_log_manager().clear_logs(port);
     }
 
+    /// Warm a cached prompt on a running server now.
+    ///
+    /// With `system_prompt` set, that text is evaluated afresh under `name`;
+    /// without it, the model's configured cached prompt `name` is warmed,
+    /// restoring its saved state when there is one. Either way the state is
+    /// saved for the next start when the server has a slot directory.
+    pub async fn warm_prompt(
+        &self,
+        id: i64,
+        request: WarmPromptRequest,
+    ) -> Result<PromptWarmResult, GuiError> {
+        if !is_valid_prompt_name(&request.name) {
+            return Err(GuiError::ValidationFailed(format!(
+                "Invalid prompt name {:?}: use letters, digits, '-' or '_'",
+                request.name
+            )));
+        }
+        let handle = crate::helpers::find_handle(&*self.deps.runner, id)
+            .await
+            .ok_or_else(|| GuiError::NotFound {
+                entity: "server",
+                id: id.to_string(),
+            })?;
+        let model = crate::helpers::resolve_model(self.deps.core.models(), id).await?;
+
+        let (system_prompt, reuse_saved) =
+            match request.system_prompt {
+                Some(text) if text.trim().is_empty() => {
+                    return Err(GuiError::ValidationFailed(
+                        "System prompt cannot be empty".to_string(),
+                    ));
+                }
+                Some(text) => (text, false),
+                None => {
+                    let settings =
+                        self.deps.core.settings().get().await.map_err(|e| {
+                            GuiError::Internal(format!("Failed to load settings: {e}"))
+                        })?;
+                    let configured = settings
+                        .cached_prompts
+                        .into_iter()
+                        .flatten()
+                        .find(|p| p.model_id == id && p.name == request.name)
+                        .ok_or_else(|| GuiError::NotFound {
+                            entity: "cached prompt",
+                            id: request.name.clone(),
+                        })?;
+                    (configured.system_prompt, true)
+                }
+            };
+
+        let model_id = u32::try_from(id)
+            .map_err(|_| GuiError::ValidationFailed(format!("Invalid model id {id}")))?;
+        PromptWarmer::new(handle.port, model_id, prompt_slot_dir(&model))
+            .warm(&request.name, &system_prompt, reuse_saved)
+            .await
+            .map_err(|e| GuiError::Internal(format!("{e:#}")))
+    }
+
+    /// Render a conversation through a running model's chat template.
+    ///
+    /// The model's server does the rendering, so the prompt is exactly what
+    /// a chat request with the same messages would evaluate. Returns
+    /// `Conflict` when the model is not running.
+    pub async fn preview_prompt(
+        &self,
+        request: PreviewPromptRequest,
+    ) -> Result<PromptPreview, GuiError> {
+        if request.messages.is_empty() {
+            return Err(GuiError::ValidationFailed(
+                "At least one message is required".to_string(),
+            ));
+        }
+        let handle = crate::helpers::find_handle(&*self.deps.runner, request.model_id)
+            .await
+            .ok_or_else(|| {
+                GuiError::Conflict(format!(
+                    "Model {} is not running; start it to preview its prompt",
+                    request.model_id
+                ))
+            })?;
+        PromptPreviewer::new(handle.port)
+            .preview(&request.messages, &request.tools)
+            .await
+            .map_err(|e| GuiError::Unavailable(format!("{e:#}")))
+    }
+
     /// Get tool support detection for a running server's model.
     ///
     /// Sources `supports_tool_calls` from the model's `ModelCapabilities` bitflags
@@ -591,6 +1051,17 @@ impl ServerOps {
     }
 }
 
+/// Where `model`'s servers save cached prompts: the shared slot directory,
+/// or `None` for models whose KV memory cannot be restored from a slot file
+/// (see `gglib_runtime::llama::args::slot_restore`).
+fn prompt_slot_dir(model: &Model) -> Option<PathBuf> {
+    let partial = kv_memory_is_partial(&model.metadata, model.architecture.as_deref());
+    if !resolve_slot_restore(partial).enabled {
+        return None;
+    }
+    gglib_core::paths::slots_dir().ok()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -676,6 +1147,30 @@ mod tests {
         let _ = reg.cancel(server_id).await;
     }
 
+    #[tokio::test]
+    async fn registry_tracks_latest_health_by_model() {
+        let mut reg = ServerMonitorRegistry::new();
+
+        let token = CancellationToken::new();
+        let task_token = token.clone();
+        let handle = tokio::spawn(async move {
+            task_token.cancelled().await;
+        });
+
+        let server_id = reg.generate_server_id();
+        reg.add(server_id, handle, token, 8080, 42);
+        assert_eq!(reg.health_for_model(42), None);
+
+        let degraded = ServerHealthStatus::Degraded {
+            reason: "slow".to_string(),
+        };
+        reg.set_health(server_id, degraded.clone());
+        assert_eq!(reg.health_for_model(42), Some(degraded));
+        assert_eq!(reg.health_for_model(7), None);
+
+        let _ = reg.cancel(server_id).await;
+    }
+
     #[tokio::test]
     async fn registry_generate_server_id_is_unique() {
         let reg = ServerMonitorRegistry::new();
@@ -764,11 +1259,11 @@ mod tests {
                 .push(format!("stopping:{}", server.model_name));
         }
 
-        fn stopped(&self, server: &ServerSummary) {
+        fn stopped(&self, server: &ServerSummary, reason: ServerStopReason) {
             self.calls
                 .lock()
                 .unwrap()
-                .push(format!("stopped:{}", server.model_name));
+                .push(format!("stopped:{}:{reason:?}", server.model_name));
         }
 
         fn snapshot(&self, servers: &[ServerSummary]) {
@@ -802,14 +1297,14 @@ mod tests {
 
         recorder.started(&summary);
         recorder.stopping(&summary);
-        recorder.stopped(&summary);
+        recorder.stopped(&summary, ServerStopReason::Idle);
         recorder.error(&summary, "test error");
 
         let calls = recorder.get_calls();
         assert_eq!(calls.len(), 4);
         assert_eq!(calls[0], "started:TestModel");
         assert_eq!(calls[1], "stopping:TestModel");
-        assert_eq!(calls[2], "stopped:TestModel");
+        assert_eq!(calls[2], "stopped:TestModel:Idle");
         assert_eq!(calls[3], "error:TestModel:test error");
     }
 
@@ -850,4 +1345,69 @@ mod tests {
             "expected error stopping non-existent model"
         );
     }
+
+    #[tokio::test]
+    async fn preview_prompt_needs_messages_and_a_running_model() {
+        let ops = make_server_ops(test_core().await);
+        let request = |messages| PreviewPromptRequest {
+            model_id: 9999,
+            messages,
+            tools: Vec::new(),
+        };
+
+        let empty = ops.preview_prompt(request(Vec::new())).await;
+        assert!(matches!(empty, Err(GuiError::ValidationFailed(_))));
+
+        let message = serde_json::json!({"role": "user", "content": "hi"});
+        let stopped = ops.preview_prompt(request(vec![message])).await;
+        assert!(matches!(stopped, Err(GuiError::Conflict(_))));
+    }
+
+    /// A requested context above the model's maximum fails validation,
+    /// while a stored default above it is lowered to the maximum.
+    #[test]
+    fn build_config_holds_context_to_the_model_maximum() {
+        let model = Model {
+            id: 1,
+            name: "short-context".to_string(),
+            model_key: String::new(),
+            file_path: PathBuf::from("/tmp/model.gguf"),
+            param_count_b: 7.0,
+            architecture: Some("llama".to_string()),
+            quantization: None,
+            context_length: Some(4096),
+            expert_count: None,
+            expert_used_count: None,
+            expert_shared_count: None,
+            metadata: HashMap::from([("llama.context_length".to_string(), "4096".to_string())]),
+            added_at: chrono::Utc::now(),
+            hf_repo_id: None,
+            hf_commit_sha: None,
+            hf_filename: None,
+            download_date: None,
+            last_update_check: None,
+            license: None,
+            tags: vec![],
+            inference_defaults: None,
+            server_defaults: None,
+            capabilities: gglib_core::domain::capabilities::ModelCapabilities::default(),
+            capabilities_version: None,
+            benchmark_summary: None,
+            card: None,
+        };
+        let build = |context_length, default_ctx| {
+            let request = StartServerRequest {
+                context_length,
+                ..Default::default()
+            };
+            ServerOps::build_config(&model, &request, 9000, default_ctx, None)
+        };
+
+        assert!(matches!(
+            build(Some(8192), None),
+            Err(GuiError::ValidationFailed(msg)) if msg.contains("4096")
+        ));
+        assert_eq!(build(None, Some(16_384)).unwrap().context_size, Some(4096));
+        assert_eq!(build(Some(2048), None).unwrap().context_size, Some(2048));
+    }
 }
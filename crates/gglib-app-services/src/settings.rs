@@ -1,12 +1,23 @@
 //! Settings operations for GUI backend.
+//!
+//! Every save emits [`AppEvent::SettingsChanged`]. Saves made by another
+//! process sharing the database (the CLI next to an open GUI) are picked up
+//! by [`SettingsOps::watch_external_changes`], which compares the stored
+//! settings version against the last one this process saw.
 
-use std::sync::Arc;
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::Duration;
 
-use gglib_core::SettingsUpdate;
+use gglib_core::Settings;
+use gglib_core::events::AppEvent;
 use gglib_core::paths::{ModelsDirSource, resolve_models_dir};
-use gglib_core::ports::{DownloadManagerPort, SystemProbePort};
+use gglib_core::ports::{
+    AppEventEmitter, CoreError, DownloadManagerPort, HF_TOKEN_SECRET, RepositoryError,
+    SystemProbePort,
+};
 use gglib_core::services::AppCore;
 use gglib_core::utils::system::SystemMemoryInfo;
+use gglib_runtime::proxy::ProxySupervisor;
 
 use crate::error::GuiError;
 use crate::types::{AppSettings, ModelsDirectoryInfo, UpdateSettingsRequest};
@@ -20,21 +31,43 @@ fn format_source(source: ModelsDirSource) -> &'static str {
     }
 }
 
+/// The error for an update whose `expected_version` is out of date.
+fn stale_version(current: u64, expected: u64) -> GuiError {
+    GuiError::Conflict(format!(
+        "settings were changed elsewhere (now at version {current}, expected {expected}); \
+         reload and try again"
+    ))
+}
+
 /// Dependencies for settings operations.
 pub struct SettingsDeps {
     pub core: Arc<AppCore>,
     pub system_probe: Arc<dyn SystemProbePort>,
     pub downloads: Arc<dyn DownloadManagerPort>,
+    /// Receives every saved settings snapshot, for live proxy reload.
+    pub proxy: Arc<ProxySupervisor>,
+    /// Receives a `SettingsChanged` event for every save.
+    pub emitter: Arc<dyn AppEventEmitter>,
 }
 
+/// How often [`SettingsOps::watch_external_changes`] checks the stored
+/// settings version.
+pub const EXTERNAL_CHANGE_POLL_INTERVAL: Duration = Duration::from_secs(2);
+
 /// Settings operations handler.
 pub struct SettingsOps {
     deps: SettingsDeps,
+    /// The newest settings version this process has seen, with the settings
+    /// at that version, to tell which keys another process changed.
+    seen: Mutex<Option<(u64, Settings)>>,
 }
 
 impl SettingsOps {
     pub fn new(deps: SettingsDeps) -> Self {
-        Self { deps }
+        Self {
+            deps,
+            seen: Mutex::new(None),
+        }
     }
 
     /// Return current models directory information for the settings UI.
@@ -95,78 +128,182 @@ impl SettingsOps {
 
     /// Get current application settings.
     pub async fn get(&self) -> Result<AppSettings, GuiError> {
-        let settings = self
+        let (settings, version) = self
             .deps
             .core
             .settings()
-            .get()
+            .get_versioned()
             .await
             .map_err(|e| GuiError::Internal(format!("Failed to get settings: {e}")))?;
 
-        Ok(AppSettings {
-            default_download_path: settings.default_download_path,
-            default_context_size: settings.default_context_size,
-            proxy_port: settings.proxy_port,
-            llama_base_port: settings.llama_base_port,
-            max_download_queue_size: settings.max_download_queue_size,
-            show_memory_fit_indicators: settings.show_memory_fit_indicators,
-            max_tool_iterations: settings.max_tool_iterations,
-            max_stagnation_steps: settings.max_stagnation_steps,
-            default_model_id: settings.default_model_id,
-            inference_defaults: settings.inference_defaults,
-            inference_profiles: settings.inference_profiles,
-            setup_completed: settings.setup_completed,
-            title_generation_prompt: settings.title_generation_prompt,
-        })
+        Ok(crate::mapping::app_settings(
+            settings,
+            version,
+            self.hf_token_configured(),
+        ))
     }
 
     /// Update application settings with validation.
+    ///
+    /// Credentials (the web search API key, the `HuggingFace` token) go to
+    /// the secret store; the settings row only keeps references to them.
+    ///
+    /// Changing `encrypt_chat_history` rewrites stored messages first, so a
+    /// failed migration leaves the setting as it was.
+    ///
+    /// With `expected_version` set, the update fails with
+    /// [`GuiError::Conflict`] if the settings were saved since that version
+    /// was read; nothing is changed in that case.
+    ///
+    /// The saved settings are published to the proxy, which applies them
+    /// without a restart, and announced with [`AppEvent::SettingsChanged`].
     pub async fn update(&self, request: UpdateSettingsRequest) -> Result<AppSettings, GuiError> {
-        let update = SettingsUpdate {
-            default_download_path: request.default_download_path,
-            default_context_size: request.default_context_size,
-            proxy_port: request.proxy_port,
-            llama_base_port: request.llama_base_port,
-            max_download_queue_size: request.max_download_queue_size,
-            show_memory_fit_indicators: request.show_memory_fit_indicators,
-            max_tool_iterations: request.max_tool_iterations,
-            max_stagnation_steps: request.max_stagnation_steps,
-            default_model_id: request.default_model_id,
-            inference_defaults: request.inference_defaults,
-            inference_profiles: request.inference_profiles,
-            setup_completed: request.setup_completed,
-            title_generation_prompt: request.title_generation_prompt,
-        };
+        let expected = request.expected_version;
+        // Checked up front so a stale request has no side effects; the save
+        // below re-checks atomically.
+        if let Some(expected) = expected {
+            let current =
+                self.deps.core.settings().version().await.map_err(|e| {
+                    GuiError::Internal(format!("Failed to read settings version: {e}"))
+                })?;
+            if current != expected {
+                return Err(stale_version(current, expected));
+            }
+        }
+
+        if let Some(token) = &request.hf_token {
+            self.set_hf_token(token.as_deref())?;
+        }
+        if let Some(encrypt) = request.encrypt_chat_history {
+            self.deps
+                .core
+                .chat_history()
+                .apply_encryption(encrypt.unwrap_or(false))
+                .await
+                .map_err(|e| GuiError::Internal(format!("Failed to apply encryption: {e}")))?;
+        }
 
-        let settings = self
+        let queue_size = request.max_download_queue_size;
+        let otlp_changed = request.otlp.is_some();
+        let update = crate::mapping::settings_update(request);
+
+        let write = self
             .deps
             .core
             .settings()
-            .update(update)
+            .update_if_version(update, expected)
             .await
-            .map_err(|e| GuiError::Internal(format!("Failed to update settings: {e}")))?;
+            .map_err(|e| match e {
+                CoreError::Repository(RepositoryError::Conflict(msg)) => GuiError::Conflict(msg),
+                e => GuiError::Internal(format!("Failed to update settings: {e}")),
+            })?;
 
-        if let Some(Some(queue_size)) = request.max_download_queue_size {
+        if let Some(Some(queue_size)) = queue_size {
             let _ = self.deps.downloads.set_max_queue_size(queue_size).await;
         }
 
-        Ok(AppSettings {
-            default_download_path: settings.default_download_path,
-            default_context_size: settings.default_context_size,
-            proxy_port: settings.proxy_port,
-            llama_base_port: settings.llama_base_port,
-            max_download_queue_size: settings.max_download_queue_size,
-            show_memory_fit_indicators: settings.show_memory_fit_indicators,
-            max_tool_iterations: settings.max_tool_iterations,
-            max_stagnation_steps: settings.max_stagnation_steps,
-            default_model_id: settings.default_model_id,
-            inference_defaults: settings.inference_defaults,
-            inference_profiles: settings.inference_profiles,
-            setup_completed: settings.setup_completed,
-            title_generation_prompt: settings.title_generation_prompt,
+        if otlp_changed {
+            gglib_runtime::otlp::apply(write.settings.otlp.as_ref());
+        }
+
+        self.mark_seen(write.version, &write.settings);
+        self.deps.proxy.publish_settings(write.settings.clone());
+        self.deps
+            .emitter
+            .emit(AppEvent::settings_changed(write.changed_keys));
+
+        Ok(crate::mapping::app_settings(
+            write.settings,
+            write.version,
+            self.hf_token_configured(),
+        ))
+    }
+
+    /// Check once for settings saved by another process since the last
+    /// version this process saw. Returns whether any were found.
+    ///
+    /// New settings are published to the proxy and announced with
+    /// [`AppEvent::SettingsChanged`]. The first call only records the
+    /// current version.
+    pub async fn sync_external_changes(&self) -> Result<bool, GuiError> {
+        let (settings, version) = self
+            .deps
+            .core
+            .settings()
+            .get_versioned()
+            .await
+            .map_err(|e| GuiError::Internal(format!("Failed to get settings: {e}")))?;
+
+        let keys = {
+            let mut seen = self.seen.lock().unwrap_or_else(PoisonError::into_inner);
+            let keys = match seen.as_ref() {
+                // Seen already, or older than a save this process just made.
+                Some((seen_version, _)) if version <= *seen_version => return Ok(false),
+                Some((_, before)) => Some(before.changed_keys(&settings)),
+                None => None,
+            };
+            *seen = Some((version, settings.clone()));
+            keys
+        };
+        let Some(keys) = keys else {
+            return Ok(false);
+        };
+
+        tracing::debug!(version, ?keys, "settings changed by another process");
+        self.deps.proxy.publish_settings(settings);
+        self.deps.emitter.emit(AppEvent::settings_changed(keys));
+        Ok(true)
+    }
+
+    /// Run [`sync_external_changes`](Self::sync_external_changes) every
+    /// `interval`, forever. Spawn this from the adapter's bootstrap.
+    pub async fn watch_external_changes(&self, interval: Duration) {
+        let mut ticker = tokio::time::interval(interval);
+        ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        loop {
+            ticker.tick().await;
+            if let Err(e) = self.sync_external_changes().await {
+                tracing::warn!("Failed to check for external settings changes: {e}");
+            }
+        }
+    }
+
+    /// Record `settings` at `version` as seen, unless a newer version
+    /// already was.
+    fn mark_seen(&self, version: u64, settings: &Settings) {
+        let mut seen = self.seen.lock().unwrap_or_else(PoisonError::into_inner);
+        if seen
+            .as_ref()
+            .is_none_or(|(seen_version, _)| version > *seen_version)
+        {
+            *seen = Some((version, settings.clone()));
+        }
+    }
+
+    /// Whether a `HuggingFace` token is stored. Unreadable stores count as
+    /// no token.
+    fn hf_token_configured(&self) -> bool {
+        self.deps.core.settings().secrets().is_some_and(|secrets| {
+            secrets
+                .get(HF_TOKEN_SECRET)
+                .inspect_err(|e| tracing::warn!("Could not read stored HF token: {e}"))
+                .is_ok_and(|token| token.is_some())
         })
     }
 
+    /// Store `token` as the `HuggingFace` token, or delete it for `None` or
+    /// a blank value.
+    fn set_hf_token(&self, token: Option<&str>) -> Result<(), GuiError> {
+        let secrets = self.deps.core.settings().secrets().ok_or_else(|| {
+            GuiError::ValidationFailed("No secret store is configured".to_string())
+        })?;
+        let result = match token.map(str::trim).filter(|t| !t.is_empty()) {
+            Some(token) => secrets.set(HF_TOKEN_SECRET, token),
+            None => secrets.delete(HF_TOKEN_SECRET).map(drop),
+        };
+        result.map_err(|e| GuiError::Internal(format!("Failed to store HF token: {e}")))
+    }
+
     /// Get system memory information.
     ///
     /// Returns None if memory information is unavailable (probe failed, too small, etc.).
@@ -193,17 +330,145 @@ impl SettingsOps {
 mod tests {
     use std::sync::Arc;
 
+    use gglib_core::ports::NoopEmitter;
+
     use super::*;
-    use crate::test_support::{MockDownloadManager, MockSystemProbePort, test_core};
+    use crate::test_support::{
+        MockDownloadManager, MockSystemProbePort, test_core, test_core_with_secrets,
+    };
 
     fn make_ops(core: Arc<AppCore>, probe: MockSystemProbePort) -> SettingsOps {
+        ops_with_emitter(core, probe, Arc::new(NoopEmitter::new()))
+    }
+
+    fn ops_with_emitter(
+        core: Arc<AppCore>,
+        probe: MockSystemProbePort,
+        emitter: Arc<dyn AppEventEmitter>,
+    ) -> SettingsOps {
         SettingsOps::new(SettingsDeps {
             core,
             system_probe: Arc::new(probe),
             downloads: Arc::new(MockDownloadManager::new()),
+            proxy: Arc::new(ProxySupervisor::new()),
+            emitter,
         })
     }
 
+    /// Records the keys of every `SettingsChanged` event.
+    #[derive(Clone, Default)]
+    struct Recorder(Arc<Mutex<Vec<Vec<String>>>>);
+
+    impl Recorder {
+        fn changes(&self) -> Vec<Vec<String>> {
+            self.0.lock().unwrap().clone()
+        }
+    }
+
+    impl AppEventEmitter for Recorder {
+        fn emit(&self, event: AppEvent) {
+            if let AppEvent::SettingsChanged { keys } = event {
+                self.0.lock().unwrap().push(keys);
+            }
+        }
+
+        fn clone_box(&self) -> Box<dyn AppEventEmitter> {
+            Box::new(self.clone())
+        }
+    }
+
+    #[tokio::test]
+    async fn update_emits_the_changed_keys() {
+        let recorder = Recorder::default();
+        let ops = ops_with_emitter(
+            test_core().await,
+            MockSystemProbePort::default(),
+            Arc::new(recorder.clone()),
+        );
+
+        let before = ops.get().await.unwrap();
+        let updated = ops
+            .update(UpdateSettingsRequest {
+                proxy_port: Some(Some(9191)),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(updated.version, before.version + 1);
+        assert_eq!(recorder.changes(), vec![vec!["proxy_port".to_string()]]);
+    }
+
+    /// A writer holding an old version is refused instead of overwriting
+    /// the newer settings.
+    #[tokio::test]
+    async fn stale_expected_version_is_a_conflict() {
+        let ops = make_ops(test_core().await, MockSystemProbePort::default());
+        let read = ops.get().await.unwrap();
+
+        let first = ops
+            .update(UpdateSettingsRequest {
+                proxy_port: Some(Some(9191)),
+                expected_version: Some(read.version),
+                ..Default::default()
+            })
+            .await
+            .expect("current version should be accepted");
+
+        let err = ops
+            .update(UpdateSettingsRequest {
+                proxy_port: Some(Some(9292)),
+                expected_version: Some(read.version),
+                ..Default::default()
+            })
+            .await
+            .unwrap_err();
+        assert!(matches!(err, GuiError::Conflict(_)), "{err}");
+
+        let now = ops.get().await.unwrap();
+        assert_eq!(now.proxy_port, Some(9191));
+        assert_eq!(now.version, first.version);
+    }
+
+    /// Saves made through another handle on the same database (as the CLI
+    /// does) are announced once, with the keys they changed.
+    #[tokio::test]
+    async fn external_saves_are_announced() {
+        let recorder = Recorder::default();
+        let core = test_core().await;
+        let ops = ops_with_emitter(
+            Arc::clone(&core),
+            MockSystemProbePort::default(),
+            Arc::new(recorder.clone()),
+        );
+        assert!(!ops.sync_external_changes().await.unwrap());
+
+        core.settings()
+            .update(gglib_core::SettingsUpdate {
+                default_context_size: Some(Some(8192)),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+
+        assert!(ops.sync_external_changes().await.unwrap());
+        assert!(!ops.sync_external_changes().await.unwrap());
+        assert_eq!(
+            recorder.changes(),
+            vec![vec!["default_context_size".to_string()]]
+        );
+
+        // The process's own saves are not reported a second time.
+        ops.update(UpdateSettingsRequest {
+            proxy_port: Some(Some(9191)),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+        assert!(!ops.sync_external_changes().await.unwrap());
+        assert_eq!(recorder.changes().len(), 2);
+    }
+
     #[tokio::test]
     async fn get_returns_default_settings() {
         let core = test_core().await;
@@ -298,6 +563,103 @@ mod tests {
         );
     }
 
+    /// Credentials reach the secret store, never the settings row or the
+    /// response.
+    #[tokio::test]
+    async fn credentials_are_kept_out_of_settings() {
+        use gglib_core::domain::{WebSearchConfig, WebSearchProvider};
+        use gglib_core::ports::{MemorySecretStore, SecretStorePort, WEB_SEARCH_API_KEY_SECRET};
+
+        let secrets = Arc::new(MemorySecretStore::new());
+        let core = test_core_with_secrets(secrets.clone()).await;
+        let ops = make_ops(Arc::clone(&core), MockSystemProbePort::default());
+        assert!(!ops.get().await.unwrap().hf_token_configured);
+
+        let updated = ops
+            .update(UpdateSettingsRequest {
+                web_search: Some(Some(WebSearchConfig::new(WebSearchProvider::Brave {
+                    api_key: "brave-key".to_owned(),
+                }))),
+                hf_token: Some(Some("hf_abc".to_owned())),
+                ..Default::default()
+            })
+            .await
+            .expect("update should succeed");
+        assert!(updated.hf_token_configured);
+        let reference = updated
+            .web_search
+            .unwrap()
+            .provider
+            .api_key()
+            .unwrap()
+            .to_owned();
+        assert!(reference.starts_with(&format!("secret:{WEB_SEARCH_API_KEY_SECRET}.")));
+        assert_eq!(
+            secrets.get(HF_TOKEN_SECRET).unwrap().as_deref(),
+            Some("hf_abc")
+        );
+        assert_eq!(secrets.resolve(&reference).unwrap(), "brave-key");
+        let resolved = core.settings().get_resolved().await.unwrap();
+        assert_eq!(
+            resolved.web_search.unwrap().provider,
+            WebSearchProvider::Brave {
+                api_key: "brave-key".to_owned()
+            }
+        );
+
+        let cleared = ops
+            .update(UpdateSettingsRequest {
+                hf_token: Some(None),
+                ..Default::default()
+            })
+            .await
+            .expect("update should succeed");
+        assert!(!cleared.hf_token_configured);
+        assert_eq!(secrets.get(HF_TOKEN_SECRET).unwrap(), None);
+    }
+
+    /// Toggling encryption migrates existing messages both ways.
+    #[tokio::test]
+    async fn encryption_toggle_migrates_messages() {
+        use gglib_core::domain::chat::{MessageRole, NewMessage};
+        use gglib_core::ports::{CHAT_HISTORY_KEY_SECRET, MemorySecretStore, SecretStorePort};
+
+        let secrets = Arc::new(MemorySecretStore::new());
+        let core = test_core_with_secrets(secrets.clone()).await;
+        let ops = make_ops(Arc::clone(&core), MockSystemProbePort::default());
+        let chats = core.chat_history();
+        let cid = chats
+            .create_conversation("Private".to_owned(), None, None)
+            .await
+            .unwrap();
+        chats
+            .save_message(NewMessage {
+                conversation_id: cid,
+                role: MessageRole::User,
+                content: "hello".to_owned(),
+                metadata: None,
+            })
+            .await
+            .unwrap();
+
+        let toggle = |on: bool| UpdateSettingsRequest {
+            encrypt_chat_history: Some(Some(on)),
+            ..Default::default()
+        };
+        let enabled = ops.update(toggle(true)).await.expect("enable");
+        assert_eq!(enabled.encrypt_chat_history, Some(true));
+        assert!(secrets.get(CHAT_HISTORY_KEY_SECRET).unwrap().is_some());
+        assert_eq!(chats.get_messages(cid).await.unwrap()[0].content, "hello");
+
+        ops.update(toggle(false)).await.expect("disable");
+        assert_eq!(chats.get_messages(cid).await.unwrap()[0].content, "hello");
+
+        // Without a secret store there is nowhere to keep the key.
+        let bare = make_ops(test_core().await, MockSystemProbePort::default());
+        assert!(bare.update(toggle(true)).await.is_err());
+        assert_eq!(bare.get().await.unwrap().encrypt_chat_history, None);
+    }
+
     /// The HTTP handlers pass these DTOs through verbatim, so their serde
     /// shape *is* the wire contract the frontend codes against. Pin it here
     /// rather than discovering a rename in the browser.
@@ -307,16 +669,43 @@ mod tests {
             default_download_path: None,
             default_context_size: None,
             proxy_port: None,
+            proxy_host: None,
+            proxy_allow_public: None,
+            proxy_allowed_origins: None,
+            proxy_preempt_background: None,
+            proxy_strip_reasoning: None,
             llama_base_port: None,
             max_download_queue_size: None,
+            partial_download_grace_hours: None,
+            trash_retention_days: None,
+            trash_max_size_gb: None,
+            watch_models_dir: None,
             show_memory_fit_indicators: None,
+            warn_restrictive_licenses: None,
             max_tool_iterations: None,
             max_stagnation_steps: None,
             default_model_id: None,
+            idle_shutdown_minutes: None,
+            startup_models: None,
+            cached_prompts: None,
             inference_defaults: None,
             inference_profiles: Some(vec![profile("coding", 0.2)]),
+            otlp: None,
             setup_completed: None,
             title_generation_prompt: None,
+            auto_title_conversations: None,
+            encrypt_chat_history: None,
+            max_attachment_size_mb: None,
+            allowed_attachment_types: None,
+            locale: None,
+            web_search: None,
+            url_fetch: None,
+            moderation: None,
+            proxy_limits: None,
+            energy: None,
+            backup: None,
+            hf_token_configured: false,
+            version: 0,
         };
 
         let json = serde_json::to_value(&settings).expect("serializes");
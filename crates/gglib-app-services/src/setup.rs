@@ -1,13 +1,19 @@
 //! Setup wizard operations for GUI backend.
 //!
 //! Handles first-run system status checks, llama.cpp installation,
-//! and Python fast-download helper provisioning.
+//! Python fast-download helper provisioning, and hardware-aware model
+//! recommendations for the onboarding screen.
 
 use std::sync::Arc;
 
 use serde::Serialize;
+use utoipa::ToSchema;
 
+use gglib_core::domain::recommend::{ModelTask, RecommendationReport, builtin_catalog, recommend};
 use gglib_core::ports::SystemProbePort;
+use gglib_core::readiness::{
+    ReadinessReport, SubsystemCheck, check_database, check_llama_binary, check_models_dir,
+};
 use gglib_core::services::AppCore;
 
 use crate::error::GuiError;
@@ -15,7 +21,7 @@ use crate::error::GuiError;
 /// Combined setup status returned by the setup-status endpoint.
 ///
 /// Provides everything the frontend wizard needs to render its initial state.
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct SetupStatus {
     /// Whether the setup wizard has been completed previously.
@@ -39,7 +45,7 @@ pub struct SetupStatus {
 }
 
 /// GPU detection results.
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct GpuInfoDto {
     pub has_metal: bool,
@@ -52,7 +58,7 @@ pub struct GpuInfoDto {
 }
 
 /// Models directory status.
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct ModelsDirectoryDto {
     pub path: String,
@@ -61,7 +67,7 @@ pub struct ModelsDirectoryDto {
 }
 
 /// System memory summary.
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct SystemMemoryDto {
     pub total_ram_bytes: u64,
@@ -215,8 +221,46 @@ impl SetupOps {
             .await
             .map_err(|e| GuiError::Internal(format!("Failed to setup Python environment: {e}")))
     }
+
+    /// Rank the built-in catalog for this machine's memory, optionally
+    /// restricted to one task, with the best quantization that fits each.
+    pub fn recommend_models(&self, task: Option<ModelTask>, limit: usize) -> RecommendationReport {
+        let memory = self.deps.system_probe.get_system_memory_info();
+        recommend(&builtin_catalog(), task, &memory, limit)
+    }
+
+    /// Per-subsystem readiness for `/readyz`: database, llama.cpp binary,
+    /// models directory and the download helper.
+    ///
+    /// Downloads are not needed to serve installed models, so a missing
+    /// helper only degrades the report.
+    pub async fn readiness(&self) -> ReadinessReport {
+        let downloader = if is_python_venv_ready() {
+            SubsystemCheck::ok(DOWNLOADER, "fast download helper ready")
+        } else {
+            match gglib_download::cli_exec::preflight_fast_helper().await {
+                Ok(python) => SubsystemCheck::ok(
+                    DOWNLOADER,
+                    format!("helper is provisioned on first download (python: {python})"),
+                ),
+                Err(e) => {
+                    SubsystemCheck::degraded(DOWNLOADER, format!("downloads unavailable: {e}"))
+                }
+            }
+        };
+
+        ReadinessReport::new(vec![
+            check_database(self.deps.core.settings().get().await),
+            check_llama_binary(),
+            check_models_dir(),
+            downloader,
+        ])
+    }
 }
 
+/// Name of the download-helper readiness check.
+const DOWNLOADER: &str = "downloader";
+
 /// Check if the Python fast-download venv is already provisioned.
 ///
 /// Does a quick file-existence check for the venv Python binary
@@ -252,4 +296,41 @@ mod tests {
         let result = ops.get_status().await;
         assert!(result.is_ok(), "get_status should not fail, got {result:?}");
     }
+
+    #[tokio::test]
+    async fn readiness_reports_every_subsystem() {
+        let core = test_core().await;
+        let ops = SetupOps::new(SetupDeps {
+            core,
+            system_probe: Arc::new(MockSystemProbePort::default()),
+        });
+        let report = ops.readiness().await;
+        let names: Vec<_> = report.checks.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(
+            names,
+            ["database", "llama_binary", "models_dir", "downloader"]
+        );
+        assert_eq!(
+            report.checks[0].status,
+            gglib_core::readiness::CheckStatus::Ok
+        );
+    }
+
+    #[tokio::test]
+    async fn recommend_models_fits_the_probed_memory() {
+        let core = test_core().await;
+        let ops = SetupOps::new(SetupDeps {
+            core,
+            system_probe: Arc::new(MockSystemProbePort::default()),
+        });
+        let report = ops.recommend_models(Some(ModelTask::Embedding), 10);
+        assert!(!report.items.is_empty());
+        assert!(
+            report
+                .items
+                .iter()
+                .all(|r| r.tasks.contains(&ModelTask::Embedding)
+                    && r.estimated_bytes <= report.budget_bytes)
+        );
+    }
 }
@@ -0,0 +1,355 @@
+//! Library sync with paired gglib instances.
+//!
+//! Pairing checks a peer's share token against its library endpoint and
+//! stores the peer in settings, where the token moves to the secret store on
+//! save. Given this instance's own URL and token, the peer is asked to pair
+//! back so either side can pull from the other.
+//!
+//! A pull compares the peer's library with this one by content hash (see
+//! [`gglib_core::domain::sync`]) and queues each missing model on the
+//! download manager with a [`PeerSource`]. Transfers therefore report
+//! ordinary download progress events and are validated and registered like
+//! any other download.
+
+use std::path::Path;
+use std::sync::Arc;
+
+use gglib_core::domain::sync::{
+    LIBRARY_PATH, LibraryEntry, PAIR_PATH, PairRequest, SyncPeer, missing_models, model_file_url,
+    validate_peer_name,
+};
+use gglib_core::download::Quantization;
+use gglib_core::ports::{DownloadManagerPort, DownloadRequest, PeerSource};
+use gglib_core::services::AppCore;
+use gglib_core::utils::shard_filename::base_shard_filename;
+
+use crate::error::GuiError;
+use crate::types::{PairPeerRequest, SyncPeerInfo, SyncPullResult, SyncSkipped};
+
+/// Dependencies for library sync.
+pub struct SyncDeps {
+    pub core: Arc<AppCore>,
+    pub downloads: Arc<dyn DownloadManagerPort>,
+    pub http: reqwest::Client,
+}
+
+/// Pairing with other instances and pulling their models.
+pub struct SyncOps {
+    deps: SyncDeps,
+}
+
+impl SyncOps {
+    pub fn new(deps: SyncDeps) -> Self {
+        Self { deps }
+    }
+
+    /// List paired instances.
+    pub async fn peers(&self) -> Result<Vec<SyncPeerInfo>, GuiError> {
+        let settings = self.deps.core.settings().get().await?;
+        Ok(settings
+            .sync_peers
+            .unwrap_or_default()
+            .into_iter()
+            .map(|peer| SyncPeerInfo {
+                name: peer.name,
+                url: peer.url,
+            })
+            .collect())
+    }
+
+    /// Pair with the instance at `req.url`, replacing any peer of the same
+    /// name.
+    ///
+    /// The token is checked by reading the peer's library before anything is
+    /// stored. With `pair_back`, the peer stores this instance in turn.
+    pub async fn pair(&self, req: PairPeerRequest) -> Result<SyncPeerInfo, GuiError> {
+        let peer = SyncPeer {
+            name: req.name.trim().to_string(),
+            url: req.url.trim().trim_end_matches('/').to_string(),
+            token: req.token,
+        };
+        validate_peer_name(&peer.name).map_err(GuiError::ValidationFailed)?;
+        self.fetch_library(&peer).await?;
+
+        if let Some(pair_back) = &req.pair_back {
+            let response = self
+                .deps
+                .http
+                .post(format!("{}{PAIR_PATH}", peer.url))
+                .bearer_auth(&peer.token)
+                .json(pair_back)
+                .send()
+                .await
+                .map_err(|e| unreachable(&peer, &e))?;
+            check_status(&peer, response.status())?;
+        }
+
+        self.store_peer(peer).await
+    }
+
+    /// Store a peer that paired with this instance and sent its own token.
+    pub async fn accept_pair(&self, req: PairRequest) -> Result<SyncPeerInfo, GuiError> {
+        let peer = SyncPeer {
+            name: req.name.trim().to_string(),
+            url: req.url.trim().trim_end_matches('/').to_string(),
+            token: req.token,
+        };
+        validate_peer_name(&peer.name).map_err(GuiError::ValidationFailed)?;
+        if peer.url.is_empty() || peer.token.is_empty() {
+            return Err(GuiError::ValidationFailed(
+                "Pairing needs a URL and a share token".to_string(),
+            ));
+        }
+        self.store_peer(peer).await
+    }
+
+    /// Forget a paired instance and its stored token.
+    pub async fn unpair(&self, name: &str) -> Result<(), GuiError> {
+        let mut settings = self.deps.core.settings().get().await?;
+        let mut peers = settings.sync_peers.take().unwrap_or_default();
+        let before = peers.len();
+        peers.retain(|p| p.name != name);
+        if peers.len() == before {
+            return Err(not_paired(name));
+        }
+        settings.sync_peers = Some(peers).filter(|p| !p.is_empty());
+        // Saving without the peer also drops its token from the store.
+        self.deps.core.settings().save(&settings).await?;
+        Ok(())
+    }
+
+    /// This instance's library, as listed to peers.
+    pub async fn library(&self) -> Result<Vec<LibraryEntry>, GuiError> {
+        let models = self.deps.core.models().list().await?;
+        let verification = self.deps.core.verification();
+        let mut entries = Vec::with_capacity(models.len());
+        for model in models {
+            let Some(file_name) = model.file_path.file_name().and_then(|n| n.to_str()) else {
+                continue;
+            };
+            // A model whose file is gone cannot be served.
+            let Ok(meta) = tokio::fs::metadata(&model.file_path).await else {
+                continue;
+            };
+            let files = match verification {
+                Some(v) => v.model_files(model.id).await.unwrap_or_default(),
+                None => Vec::new(),
+            };
+            entries.push(LibraryEntry {
+                model_id: model.id,
+                name: model.name.clone(),
+                file_name: file_name.to_string(),
+                size: meta.len(),
+                sha256: files.first().and_then(|f| f.hf_oid.clone()),
+                hf_repo_id: model.hf_repo_id.clone(),
+                quantization: model.quantization.clone(),
+                sharded: files.len() > 1 || base_shard_filename(file_name) != file_name,
+            });
+        }
+        Ok(entries)
+    }
+
+    /// The models `peer` has that this library lacks.
+    pub async fn diff(&self, peer: &str) -> Result<Vec<LibraryEntry>, GuiError> {
+        let peer = self.peer(peer).await?;
+        let remote = self.fetch_library(&peer).await?;
+        let local = self.library().await?;
+        Ok(missing_models(&local, &remote)
+            .into_iter()
+            .cloned()
+            .collect())
+    }
+
+    /// Queue every model `peer` has that this library lacks.
+    pub async fn pull(&self, peer: &str) -> Result<SyncPullResult, GuiError> {
+        let peer = self.peer(peer).await?;
+        let remote = self.fetch_library(&peer).await?;
+        let local = self.library().await?;
+        let missing = missing_models(&local, &remote);
+
+        let mut result = SyncPullResult {
+            peer: peer.name.clone(),
+            queued: Vec::new(),
+            skipped: Vec::new(),
+            already_present: remote.len() - missing.len(),
+        };
+        for entry in missing {
+            match self.queue_pull(&peer, entry).await {
+                Ok(id) => result.queued.push(id),
+                Err(reason) => result.skipped.push(SyncSkipped {
+                    name: entry.name.clone(),
+                    reason,
+                }),
+            }
+        }
+        Ok(result)
+    }
+
+    /// Queue one peer model, or say why it cannot be.
+    async fn queue_pull(&self, peer: &SyncPeer, entry: &LibraryEntry) -> Result<String, String> {
+        if entry.sharded {
+            return Err("sharded models cannot be pulled from a peer".to_string());
+        }
+        let file_name = entry
+            .safe_file_name()
+            .ok_or_else(|| format!("unusable file name '{}'", entry.file_name))?;
+        let quantization = entry
+            .quantization
+            .as_deref()
+            .and_then(|q| q.parse().ok())
+            .unwrap_or_else(|| Quantization::from_filename(file_name));
+        let repo_id = entry.hf_repo_id.clone().unwrap_or_else(|| {
+            Path::new(file_name)
+                .file_stem()
+                .and_then(|s| s.to_str())
+                .unwrap_or(file_name)
+                .to_string()
+        });
+
+        let request = DownloadRequest::new(repo_id, quantization).with_peer(PeerSource {
+            url: model_file_url(&peer.url, entry.model_id),
+            token: peer.token.clone(),
+            file_name: file_name.to_string(),
+            size: entry.size,
+            sha256: entry.sha256.clone(),
+        });
+        Arc::clone(&self.deps.downloads)
+            .queue_and_process(request)
+            .await
+            .map(|id| id.to_string())
+            .map_err(|e| e.to_string())
+    }
+
+    /// The stored peer named `name`, with its token resolved.
+    async fn peer(&self, name: &str) -> Result<SyncPeer, GuiError> {
+        let settings = self.deps.core.settings().get().await?;
+        let mut peer = settings
+            .sync_peers
+            .unwrap_or_default()
+            .into_iter()
+            .find(|p| p.name == name)
+            .ok_or_else(|| not_paired(name))?;
+        if let Some(secrets) = self.deps.core.settings().secrets() {
+            peer.token = secrets.resolve(&peer.token).map_err(|e| {
+                GuiError::Unavailable(format!("Token for peer '{name}' unavailable: {e}"))
+            })?;
+        }
+        Ok(peer)
+    }
+
+    async fn store_peer(&self, peer: SyncPeer) -> Result<SyncPeerInfo, GuiError> {
+        let info = SyncPeerInfo {
+            name: peer.name.clone(),
+            url: peer.url.clone(),
+        };
+        let mut settings = self.deps.core.settings().get().await?;
+        let peers = settings.sync_peers.get_or_insert_with(Vec::new);
+        peers.retain(|p| p.name != peer.name);
+        peers.push(peer);
+        self.deps.core.settings().save(&settings).await?;
+        Ok(info)
+    }
+
+    async fn fetch_library(&self, peer: &SyncPeer) -> Result<Vec<LibraryEntry>, GuiError> {
+        let response = self
+            .deps
+            .http
+            .get(format!("{}{LIBRARY_PATH}", peer.url))
+            .bearer_auth(&peer.token)
+            .send()
+            .await
+            .map_err(|e| unreachable(peer, &e))?;
+        check_status(peer, response.status())?;
+        response.json().await.map_err(|e| {
+            GuiError::Unavailable(format!("Peer '{}' sent an invalid library: {e}", peer.name))
+        })
+    }
+}
+
+fn not_paired(name: &str) -> GuiError {
+    GuiError::NotFound {
+        entity: "sync peer",
+        id: name.to_string(),
+    }
+}
+
+fn unreachable(peer: &SyncPeer, e: &reqwest::Error) -> GuiError {
+    GuiError::Unavailable(format!(
+        "Peer '{}' at {} is unreachable: {e}",
+        peer.name, peer.url
+    ))
+}
+
+fn check_status(peer: &SyncPeer, status: reqwest::StatusCode) -> Result<(), GuiError> {
+    match status {
+        s if s.is_success() => Ok(()),
+        reqwest::StatusCode::UNAUTHORIZED => Err(GuiError::ValidationFailed(format!(
+            "Peer '{}' rejected the share token",
+            peer.name
+        ))),
+        reqwest::StatusCode::FORBIDDEN => Err(GuiError::Unavailable(format!(
+            "Peer '{}' has model sharing disabled; start it with a share token",
+            peer.name
+        ))),
+        s => Err(GuiError::Unavailable(format!(
+            "Peer '{}' answered {s}",
+            peer.name
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{MockDownloadManager, test_core};
+
+    async fn ops() -> SyncOps {
+        SyncOps::new(SyncDeps {
+            core: test_core().await,
+            downloads: Arc::new(MockDownloadManager::new()),
+            http: reqwest::Client::new(),
+        })
+    }
+
+    fn pair_request(name: &str) -> PairRequest {
+        PairRequest {
+            name: name.to_string(),
+            url: "http://studio.local:9887/".to_string(),
+            token: "t0ken".to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn accepted_peers_are_listed_and_unpaired() {
+        let ops = ops().await;
+        assert!(matches!(
+            ops.accept_pair(pair_request("no spaces")).await,
+            Err(GuiError::ValidationFailed(_))
+        ));
+
+        ops.accept_pair(pair_request("studio")).await.unwrap();
+        // Pairing again under the same name replaces the peer.
+        ops.accept_pair(pair_request("studio")).await.unwrap();
+        let peers = ops.peers().await.unwrap();
+        assert_eq!(peers.len(), 1);
+        assert_eq!(peers[0].url, "http://studio.local:9887");
+        assert_eq!(ops.peer("studio").await.unwrap().token, "t0ken");
+
+        ops.unpair("studio").await.unwrap();
+        assert!(ops.peers().await.unwrap().is_empty());
+        assert!(matches!(
+            ops.unpair("studio").await,
+            Err(GuiError::NotFound { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn pull_from_unknown_peer_is_not_found() {
+        let ops = ops().await;
+        assert!(ops.library().await.unwrap().is_empty());
+        assert!(matches!(
+            ops.pull("nowhere").await,
+            Err(GuiError::NotFound { .. })
+        ));
+    }
+}
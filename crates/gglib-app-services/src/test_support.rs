@@ -6,12 +6,15 @@
 use std::sync::Arc;
 
 use async_trait::async_trait;
-use gglib_core::download::{DownloadError, DownloadId, QueueSnapshot};
+use gglib_core::download::{
+    CollectionQueueResult, DownloadError, DownloadHistoryFilter, DownloadId, GroupProgress,
+    QueueRunSummary, QueueSnapshot,
+};
 use gglib_core::ports::{
-    DownloadManagerPort, DownloadRequest, HfClientPort, HfFileInfo, HfPortError, HfQuantInfo,
-    HfRepoInfo, HfSearchOptions, HfSearchResult, ProcessError, ProcessHandle, ProcessRunner,
-    ServerConfig, ServerHealth, SystemProbePort, ToolSupportDetection, ToolSupportDetectionInput,
-    ToolSupportDetectorPort,
+    DownloadManagerPort, DownloadRequest, HfClientPort, HfCollection, HfFileInfo, HfPortError,
+    HfQuantInfo, HfRepoInfo, HfSearchOptions, HfSearchResult, ModelHub, ModelHubPort, ProcessError,
+    ProcessHandle, ProcessRunner, ServerConfig, ServerHealth, SystemProbePort,
+    ToolSupportDetection, ToolSupportDetectionInput, ToolSupportDetectorPort,
 };
 use gglib_core::services::AppCore;
 use gglib_core::utils::system::{Dependency, GpuInfo, SystemMemoryInfo};
@@ -113,6 +116,19 @@ impl DownloadManagerPort for MockDownloadManager {
         Ok((1, 1))
     }
 
+    async fn queue_collection(
+        self: Arc<Self>,
+        slug: String,
+        _quantization: Option<String>,
+    ) -> Result<CollectionQueueResult, DownloadError> {
+        Ok(CollectionQueueResult {
+            title: slug.clone(),
+            slug,
+            queued: vec![],
+            skipped: vec![],
+        })
+    }
+
     async fn get_queue_snapshot(&self) -> Result<QueueSnapshot, DownloadError> {
         Ok(QueueSnapshot::default())
     }
@@ -159,6 +175,10 @@ impl DownloadManagerPort for MockDownloadManager {
         Ok(())
     }
 
+    async fn get_group_progress(&self, group_id: &str) -> Result<GroupProgress, DownloadError> {
+        Err(DownloadError::not_in_queue(group_id))
+    }
+
     async fn retry(&self, _id: &DownloadId) -> Result<u32, DownloadError> {
         Ok(1)
     }
@@ -167,6 +187,13 @@ impl DownloadManagerPort for MockDownloadManager {
         Ok(())
     }
 
+    async fn get_history(
+        &self,
+        _filter: &DownloadHistoryFilter,
+    ) -> Result<Vec<QueueRunSummary>, DownloadError> {
+        Ok(vec![])
+    }
+
     async fn set_max_queue_size(&self, _size: u32) -> Result<(), DownloadError> {
         Ok(())
     }
@@ -184,7 +211,11 @@ impl DownloadManagerPort for MockDownloadManager {
 pub(crate) struct MockHfClient;
 
 #[async_trait]
-impl HfClientPort for MockHfClient {
+impl ModelHubPort for MockHfClient {
+    fn hub(&self) -> ModelHub {
+        ModelHub::HuggingFace
+    }
+
     async fn search(&self, _options: &HfSearchOptions) -> Result<HfSearchResult, HfPortError> {
         Ok(HfSearchResult {
             items: vec![],
@@ -232,6 +263,22 @@ impl HfClientPort for MockHfClient {
             tags: vec![],
         })
     }
+
+    fn file_url(&self, model_id: &str, _revision: Option<&str>, path: &str) -> String {
+        format!("https://huggingface.co/{model_id}/resolve/main/{path}")
+    }
+}
+
+#[async_trait]
+impl HfClientPort for MockHfClient {
+    async fn get_collection(&self, slug: &str) -> Result<HfCollection, HfPortError> {
+        Ok(HfCollection {
+            slug: slug.to_string(),
+            title: slug.to_string(),
+            description: None,
+            model_ids: vec![],
+        })
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -310,3 +357,11 @@ pub(crate) async fn test_core() -> Arc<AppCore> {
         Arc::new(MockProcessRunner),
     ))
 }
+
+/// Like [`test_core`], with credentials kept in an in-memory secret store.
+pub(crate) async fn test_core_with_secrets(
+    secrets: Arc<dyn gglib_core::ports::SecretStorePort>,
+) -> Arc<AppCore> {
+    let pool = setup_test_database().await.expect("in-memory DB");
+    Arc::new(CoreFactory::build_app_core(pool, Arc::new(MockProcessRunner)).with_secrets(secrets))
+}
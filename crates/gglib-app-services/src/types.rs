@@ -1,19 +1,27 @@
 //! GUI-specific DTOs for frontend communication.
 //!
 //! These types are cross-adapter (used by both Tauri and Axum).
-//! They map between domain types and frontend-friendly representations.
+//! They map between domain types and frontend-friendly representations;
+//! the conversions themselves live in `mapping`, which checks at compile
+//! time that no domain field goes unaccounted for.
+//!
+//! Every DTO derives `ts_rs::TS`; its TypeScript declaration is checked in
+//! under `src/types/generated/` (see `tests/wire_types.rs`).
 
 use gglib_core::domain::Model;
+use gglib_core::domain::PairRequest;
 use gglib_core::domain::mcp::McpLifecycle;
 use gglib_core::ports::ProcessHandle;
 use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+use utoipa::ToSchema;
 
 // ============================================================================
 // HuggingFace Browser Types
 // ============================================================================
 
 /// Summary of a HuggingFace model from the search API.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, TS, ToSchema)]
 pub struct HfModelSummary {
     /// Model ID (e.g., "TheBloke/Llama-2-7B-GGUF")
     pub id: String,
@@ -34,10 +42,18 @@ pub struct HfModelSummary {
     /// Model tags
     #[serde(default)]
     pub tags: Vec<String>,
+    /// License from the `license:*` tag (e.g. `apache-2.0`), when present
+    #[serde(default)]
+    pub license: Option<String>,
+    /// Whether the license forbids commercial use or limits the model to
+    /// research; the GUI warns before downloading such models when
+    /// `warn_restrictive_licenses` is on
+    #[serde(default)]
+    pub restrictive_license: bool,
 }
 
 /// Sort field options for HuggingFace model search.
-#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, Eq)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, Eq, TS, ToSchema)]
 #[serde(rename_all = "lowercase")]
 pub enum HfSortField {
     #[default]
@@ -50,7 +66,7 @@ pub enum HfSortField {
 }
 
 /// Request for searching HuggingFace models.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, TS, ToSchema)]
 pub struct HfSearchRequest {
     pub query: Option<String>,
     pub min_params_b: Option<f64>,
@@ -78,7 +94,7 @@ impl Default for HfSearchRequest {
 }
 
 /// Response from HuggingFace model search.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, TS, ToSchema)]
 pub struct HfSearchResponse {
     pub models: Vec<HfModelSummary>,
     pub has_more: bool,
@@ -87,7 +103,7 @@ pub struct HfSearchResponse {
 }
 
 /// Information about a specific quantization variant.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, TS, ToSchema)]
 pub struct HfQuantization {
     pub name: String,
     pub file_path: String,
@@ -98,7 +114,7 @@ pub struct HfQuantization {
 }
 
 /// Response containing available quantizations for a model.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, TS, ToSchema)]
 pub struct HfQuantizationsResponse {
     pub model_id: String,
     pub quantizations: Vec<HfQuantization>,
@@ -107,7 +123,7 @@ pub struct HfQuantizationsResponse {
 /// Response for tool/function calling support detection.
 ///
 /// Used for both HuggingFace model metadata and local running server queries.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, TS, ToSchema)]
 pub struct ToolSupportResponse {
     pub supports_tool_calls: bool,
     pub confidence: f32,
@@ -129,7 +145,7 @@ impl From<gglib_core::ports::ToolSupportDetection> for ToolSupportResponse {
 // ============================================================================
 
 /// Frontend-friendly model structure.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, TS, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct GuiModel {
     pub id: i64,
@@ -141,16 +157,23 @@ pub struct GuiModel {
     pub context_length: Option<u64>,
     pub added_at: String,
     pub hf_repo_id: Option<String>,
+    /// License identifier (e.g. `apache-2.0`), when known.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[ts(optional)]
+    pub license: Option<String>,
     #[serde(default)]
     pub tags: Vec<String>,
     #[serde(default)]
     pub is_serving: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[ts(optional)]
     pub port: Option<u16>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[ts(optional)]
     pub inference_defaults: Option<gglib_core::domain::InferenceConfig>,
     /// Per-model server defaults (port, URL overrides, etc.).
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[ts(optional)]
     pub server_defaults: Option<gglib_core::domain::ServerConfig>,
     /// Capability flags stored for this model.
     ///
@@ -158,35 +181,21 @@ pub struct GuiModel {
     /// and may display individual flags; the `PATCH /api/models/{id}/capabilities`
     /// endpoint lets the user override them.
     #[serde(default)]
+    #[ts(as = "u32")]
+    #[schema(value_type = u32)]
     pub capabilities: gglib_core::ModelCapabilities,
     /// Denormalised benchmark summary (speed badges).
     ///
     /// `None` if the model has never been benchmarked.
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[ts(optional)]
     pub benchmark_summary: Option<gglib_core::domain::benchmark::ModelBenchmarkSummary>,
 }
 
 impl GuiModel {
     /// Convert a domain Model to GuiModel format.
     pub fn from_model(model: Model, is_serving: bool, port: Option<u16>) -> Self {
-        Self {
-            id: model.id,
-            name: model.name,
-            file_path: model.file_path.to_string_lossy().to_string(),
-            param_count_b: model.param_count_b,
-            architecture: model.architecture,
-            quantization: model.quantization,
-            context_length: model.context_length,
-            added_at: model.added_at.format("%Y-%m-%d %H:%M:%S").to_string(),
-            hf_repo_id: model.hf_repo_id,
-            tags: model.tags,
-            is_serving,
-            port,
-            inference_defaults: model.inference_defaults,
-            server_defaults: model.server_defaults,
-            capabilities: model.capabilities,
-            benchmark_summary: model.benchmark_summary,
-        }
+        crate::mapping::gui_model(model, is_serving, port)
     }
 
     /// Convert from Model with default serving status (not serving).
@@ -201,6 +210,37 @@ impl From<Model> for GuiModel {
     }
 }
 
+/// One page of the filtered, sorted model list.
+#[derive(Debug, Clone, Serialize, Deserialize, TS, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct GuiModelPage {
+    /// The models on this page, in query order.
+    pub items: Vec<GuiModel>,
+    /// Models matching the query across all pages.
+    pub total: u64,
+    /// Models skipped before this page.
+    pub offset: u64,
+    /// Page size the server applied (after clamping).
+    pub limit: u64,
+}
+
+/// What changed in the model list since the client's last sync.
+///
+/// Drop the `removed` ids first, then upsert `changed` by id; when `full`
+/// is set, `changed` replaces the whole list instead.
+#[derive(Debug, Clone, Serialize, Deserialize, TS, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct GuiModelChanges {
+    /// Models added or updated since then.
+    pub changed: Vec<GuiModel>,
+    /// Ids of models removed since then.
+    pub removed: Vec<i64>,
+    /// `changed` is the whole list rather than a delta.
+    pub full: bool,
+    /// RFC 3339 time to send as `since` on the next request.
+    pub as_of: String,
+}
+
 // ============================================================================
 // Model Inspect DTO
 // ============================================================================
@@ -214,7 +254,7 @@ impl From<Model> for GuiModel {
 /// - CLI: `gglib model inspect` (human-readable or `--json`)
 /// - Axum: `GET /api/models/:id/detail`
 /// - GUI frontend: model detail panel
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, TS, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct ModelDetailDto {
     // ── Core identity ─────────────────────────────────────────────────────────
@@ -228,49 +268,68 @@ pub struct ModelDetailDto {
     pub param_count_b: f64,
     /// Model architecture (e.g. `"llama"`, `"mistral"`).
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[ts(optional)]
     pub architecture: Option<String>,
     /// Quantization type (e.g. `"Q4_K_M"`, `"F16"`).
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[ts(optional)]
     pub quantization: Option<String>,
     /// Maximum context length in tokens.
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[ts(optional)]
     pub context_length: Option<u64>,
     // ── MoE topology (omitted for non-MoE models) ─────────────────────────────
     /// Total number of experts (MoE models only).
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[ts(optional)]
     pub expert_count: Option<u32>,
     /// Experts activated per token (MoE models only).
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[ts(optional)]
     pub expert_used_count: Option<u32>,
     /// Shared experts that are always active (MoE models only).
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[ts(optional)]
     pub expert_shared_count: Option<u32>,
     // ── HuggingFace provenance ────────────────────────────────────────────────
     /// HuggingFace repository ID (e.g. `"bartowski/Llama-3.1-8B-GGUF"`).
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[ts(optional)]
     pub hf_repo_id: Option<String>,
     /// Original filename on HuggingFace Hub.
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[ts(optional)]
     pub hf_filename: Option<String>,
     /// Git commit SHA from HuggingFace Hub.
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[ts(optional)]
     pub hf_commit_sha: Option<String>,
     /// When the model was downloaded from HuggingFace (`"%Y-%m-%d %H:%M:%S"`).
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[ts(optional)]
     pub download_date: Option<String>,
     /// Last time an update check was performed (`"%Y-%m-%d %H:%M:%S"`).
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[ts(optional)]
     pub last_update_check: Option<String>,
+    /// License identifier (e.g. `apache-2.0`), from the HuggingFace tags or
+    /// the GGUF header.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[ts(optional)]
+    pub license: Option<String>,
     // ── Organisation ──────────────────────────────────────────────────────────
     /// User-defined and auto-generated tags.
     #[serde(default)]
     pub tags: Vec<String>,
     /// Capability flags serialized as a `u32` bit-field.
     #[serde(default)]
+    #[ts(as = "u32")]
+    #[schema(value_type = u32)]
     pub capabilities: gglib_core::ModelCapabilities,
     // ── Inference defaults ────────────────────────────────────────────────────
     /// Per-model inference parameter overrides.
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[ts(optional)]
     pub inference_defaults: Option<gglib_core::domain::InferenceConfig>,
     // ── Timestamps ────────────────────────────────────────────────────────────
     /// When the model was first added to the database (`"%Y-%m-%d %H:%M:%S"`).
@@ -281,6 +340,7 @@ pub struct ModelDetailDto {
     pub is_serving: bool,
     /// Port the model is served on, if currently serving.
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[ts(optional)]
     pub port: Option<u16>,
     // ── Raw GGUF key-value pairs ──────────────────────────────────────────────
     /// All raw key-value pairs stored from the GGUF file.
@@ -288,6 +348,10 @@ pub struct ModelDetailDto {
     /// Presentation layers decide whether to surface this.  The CLI gates it
     /// behind `--metadata`; the GUI may show it in a collapsible panel.
     pub metadata: std::collections::HashMap<String, String>,
+    // ── Model card ────────────────────────────────────────────────────────────
+    /// Markdown model card. Models stored without one (added before cards
+    /// existed) get a card generated on the fly from their metadata.
+    pub card: String,
 }
 
 impl ModelDetailDto {
@@ -297,34 +361,7 @@ impl ModelDetailDto {
     /// access to the running-process list.  Pass `false` / `None` from
     /// contexts where serving state is not relevant (e.g. the CLI).
     pub fn from_model(model: Model, is_serving: bool, port: Option<u16>) -> Self {
-        Self {
-            id: model.id,
-            name: model.name,
-            file_path: model.file_path.to_string_lossy().to_string(),
-            param_count_b: model.param_count_b,
-            architecture: model.architecture,
-            quantization: model.quantization,
-            context_length: model.context_length,
-            expert_count: model.expert_count,
-            expert_used_count: model.expert_used_count,
-            expert_shared_count: model.expert_shared_count,
-            hf_repo_id: model.hf_repo_id,
-            hf_filename: model.hf_filename,
-            hf_commit_sha: model.hf_commit_sha,
-            download_date: model
-                .download_date
-                .map(|d| d.format("%Y-%m-%d %H:%M:%S").to_string()),
-            last_update_check: model
-                .last_update_check
-                .map(|d| d.format("%Y-%m-%d %H:%M:%S").to_string()),
-            tags: model.tags,
-            capabilities: model.capabilities,
-            inference_defaults: model.inference_defaults,
-            added_at: model.added_at.format("%Y-%m-%d %H:%M:%S").to_string(),
-            is_serving,
-            port,
-            metadata: model.metadata,
-        }
+        crate::mapping::model_detail(model, is_serving, port)
     }
 }
 
@@ -333,7 +370,7 @@ impl ModelDetailDto {
 // ============================================================================
 
 /// Request body for starting a server.
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default, TS, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct StartServerRequest {
     pub context_length: Option<u64>,
@@ -356,23 +393,108 @@ pub struct StartServerRequest {
     /// Inference parameters for this serve session (overrides model/global defaults).
     #[serde(default)]
     pub inference_params: Option<gglib_core::domain::InferenceConfig>,
+    /// `RoPE` scaling overrides (`--rope-scaling`, `--rope-freq-base`,
+    /// `--rope-freq-scale`, `--yarn-orig-ctx`). `None` = use GGUF metadata.
+    #[serde(default)]
+    pub rope: Option<gglib_core::domain::RopeConfig>,
+    /// Generation thread count (`--threads`). `None` = physical performance
+    /// cores.
+    #[serde(default)]
+    pub threads: Option<u32>,
+    /// Prompt-processing thread count (`--threads-batch`). `None` = same as
+    /// `threads`.
+    #[serde(default)]
+    pub threads_batch: Option<u32>,
+    /// CPUs to pin to, as a `taskset -c` list (`0-7,12`). `None` = performance
+    /// cores on a hybrid CPU, otherwise unpinned.
+    #[serde(default)]
+    #[ts(as = "Option<String>")]
+    #[schema(value_type = Option<String>)]
+    pub cpu_affinity: Option<gglib_core::cpu_config::CpuSet>,
+    /// NUMA placement strategy (`--numa`). `None` = no flag.
+    #[serde(default)]
+    pub numa: Option<gglib_core::cpu_config::NumaStrategy>,
+    /// Multi-GPU split mode (`--split-mode`). `None` = llama-server's
+    /// default (`layer`).
+    #[serde(default)]
+    pub split_mode: Option<gglib_core::gpu_config::SplitMode>,
+    /// Per-GPU share of the model (`--tensor-split`), as a list such as
+    /// `3,1`. `None` = proportional to free VRAM on a multi-GPU machine.
+    #[serde(default)]
+    #[ts(as = "Option<String>")]
+    #[schema(value_type = Option<String>)]
+    pub tensor_split: Option<gglib_core::gpu_config::TensorSplit>,
+    /// Main GPU index (`--main-gpu`). `None` = the GPU with the most free
+    /// VRAM under split mode `none`, otherwise no flag.
+    #[serde(default)]
+    pub main_gpu: Option<u32>,
+    /// Parallel request slots (`--parallel`), each with the full context.
+    /// `None` = the model's launch defaults, then a default derived from
+    /// the context size and free memory.
+    #[serde(default)]
+    pub parallel: Option<u32>,
+    /// Continuous batching (`--cont-batching`). `None` = the model's launch
+    /// defaults, then llama-server's default (on).
+    #[serde(default)]
+    pub cont_batching: Option<bool>,
+    /// Seconds between health probes of this server. `None` = 10.
+    #[serde(default)]
+    pub health_check_interval_secs: Option<u64>,
 }
 
 /// Response for starting a server.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, TS, ToSchema)]
 pub struct StartServerResponse {
     pub port: u16,
     pub message: String,
 }
 
+/// Request body for warming a cached prompt on a running server.
+#[derive(Debug, Clone, Serialize, Deserialize, TS, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct WarmPromptRequest {
+    /// Name the prompt's KV state is saved under.
+    pub name: String,
+    /// Text to evaluate afresh. Omit to warm the model's configured cached
+    /// prompt `name`, restoring its saved state when there is one.
+    #[serde(default)]
+    pub system_prompt: Option<String>,
+}
+
+/// Request body for previewing how a model's chat template renders a
+/// conversation.
+#[derive(Debug, Clone, Serialize, Deserialize, TS, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PreviewPromptRequest {
+    /// Model whose running server renders the prompt.
+    pub model_id: i64,
+    /// Conversation in OpenAI chat format, as it would be sent to
+    /// `/v1/chat/completions`.
+    pub messages: Vec<serde_json::Value>,
+    /// Tool definitions in OpenAI format, rendered into the prompt the way
+    /// the template presents them.
+    #[serde(default)]
+    pub tools: Vec<serde_json::Value>,
+}
+
 /// Information about a running model server (GUI DTO).
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, TS, ToSchema)]
 pub struct ServerInfo {
     pub model_id: i64,
     pub model_name: String,
     pub pid: Option<u32>,
     pub port: u16,
     pub started_at: u64,
+    /// Latest status reported by the server's health monitor. `None` until
+    /// the first probe, or for servers started outside the GUI backend.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[ts(optional)]
+    pub health: Option<gglib_core::ports::ServerHealthStatus>,
+    /// Slot occupancy from the latest poll. `None` until the first read, or
+    /// when the server does not expose its slots.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[ts(optional)]
+    pub slots: Option<gglib_core::domain::SlotOccupancy>,
 }
 
 impl ServerInfo {
@@ -384,29 +506,58 @@ impl ServerInfo {
             pid: handle.pid,
             port: handle.port,
             started_at: handle.started_at,
+            health: None,
+            slots: None,
         }
     }
 }
 
+/// What happened to one startup model during warm start or reconciliation.
+#[derive(Debug, Clone, Serialize, Deserialize, TS, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct StartupOutcome {
+    pub model_id: i64,
+    /// `None` when the model could not be loaded from the library.
+    pub model_name: Option<String>,
+    #[serde(flatten)]
+    pub result: StartupResult,
+}
+
+/// Result of launching one startup model.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, TS, ToSchema)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum StartupResult {
+    /// Launched on `port`.
+    Started { port: u16 },
+    /// Stopped and launched again on `port` (reconciliation).
+    Restarted { port: u16 },
+    /// Already serving on `port`; left untouched.
+    AlreadyRunning { port: u16 },
+    /// Not launched, e.g. over the memory budget or missing from the library.
+    Skipped { reason: String },
+    /// The launch was attempted and failed.
+    Failed { error: String },
+}
+
 // ============================================================================
 // Model Request Types
 // ============================================================================
 
 /// Request body for adding a model.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, TS, ToSchema)]
 pub struct AddModelRequest {
     pub file_path: String,
 }
 
 /// Request body for removing a model.
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default, TS, ToSchema)]
 pub struct RemoveModelRequest {
     #[serde(default)]
     pub force: bool,
 }
 
 /// Request body for updating a model.
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default, TS, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct UpdateModelRequest {
     pub name: Option<String>,
@@ -418,7 +569,15 @@ pub struct UpdateModelRequest {
     /// - Some(None) — clear the override (NULL in DB, revert to global default)
     /// - None — don't touch this field (key omitted from payload)
     #[serde(default, with = "serde_with::rust::double_option")]
+    #[ts(as = "Option<gglib_core::domain::ServerConfig>", optional = nullable)]
     pub server_defaults: Option<Option<gglib_core::domain::ServerConfig>>,
+    /// Markdown model card.
+    /// - Some(Some(text)) — replace the card with the user's text
+    /// - Some(None) — regenerate the card from the model's metadata
+    /// - None — don't touch this field (key omitted from payload)
+    #[serde(default, with = "serde_with::rust::double_option")]
+    #[ts(as = "Option<String>", optional = nullable)]
+    pub card: Option<Option<String>>,
 }
 
 /// Request body for overriding a model's capability flags.
@@ -435,7 +594,7 @@ pub struct UpdateModelRequest {
 /// ```json
 /// { "requiresStrictTurns": true }
 /// ```
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default, TS, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct SetCapabilitiesRequest {
     /// Override whether the model supports a `system` role in the chat template.
@@ -446,6 +605,10 @@ pub struct SetCapabilitiesRequest {
     pub supports_tool_calls: Option<bool>,
     /// Override whether the model produces reasoning/thinking output.
     pub supports_reasoning: Option<bool>,
+    /// Override whether the model is an embedding model.
+    pub embedding: Option<bool>,
+    /// Override whether the model is a reranker.
+    pub reranker: Option<bool>,
 }
 
 // ============================================================================
@@ -453,7 +616,7 @@ pub struct SetCapabilitiesRequest {
 // ============================================================================
 
 /// Current configuration for the models directory shown in settings UI.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, TS, ToSchema)]
 pub struct ModelsDirectoryInfo {
     pub path: String,
     pub source: String,
@@ -463,26 +626,88 @@ pub struct ModelsDirectoryInfo {
 }
 
 /// Application settings for the settings UI.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, TS, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct AppSettings {
     pub default_download_path: Option<String>,
     pub default_context_size: Option<u64>,
     pub proxy_port: Option<u16>,
+    /// Proxy bind address; `None` = `127.0.0.1`.
+    pub proxy_host: Option<String>,
+    /// Opt-in for a non-loopback `proxy_host`; `None` = disabled.
+    pub proxy_allow_public: Option<bool>,
+    /// Origins browsers may call the proxy from; `None` = any.
+    pub proxy_allowed_origins: Option<Vec<String>>,
+    /// Cut background streams short for waiting interactive requests;
+    /// `None` = disabled.
+    pub proxy_preempt_background: Option<bool>,
+    /// Drop reasoning from streamed responses instead of sending it as
+    /// `reasoning_content`; `None` = disabled.
+    pub proxy_strip_reasoning: Option<bool>,
     pub llama_base_port: Option<u16>,
     pub max_download_queue_size: Option<u32>,
+    /// Hours an orphaned partial download is kept (default 24; read at startup).
+    pub partial_download_grace_hours: Option<u32>,
+    /// Days a removed model stays in the trash (default 30).
+    pub trash_retention_days: Option<u32>,
+    /// Trash size cap in GB; the oldest entries go first. `None` = no cap.
+    pub trash_max_size_gb: Option<u32>,
+    /// Pick up `.gguf` files added to or deleted from the models directory
+    /// while `gglib web` runs (default off; read at startup).
+    pub watch_models_dir: Option<bool>,
     pub show_memory_fit_indicators: Option<bool>,
+    /// Warn before downloading non-commercial or research-only models
+    /// (default off).
+    pub warn_restrictive_licenses: Option<bool>,
     pub max_tool_iterations: Option<u32>,
     pub max_stagnation_steps: Option<u32>,
     /// Default model ID for quick commands (e.g., `gglib question`).
     pub default_model_id: Option<i64>,
+    /// Minutes without a request before a server is stopped (`0` = never).
+    pub idle_shutdown_minutes: Option<u32>,
+    /// Models launched automatically at startup, in order.
+    pub startup_models: Option<Vec<gglib_core::domain::StartupModel>>,
+    /// System prompts kept warm per model across server restarts.
+    pub cached_prompts: Option<Vec<gglib_core::domain::CachedPrompt>>,
     pub inference_defaults: Option<gglib_core::domain::InferenceConfig>,
     /// Named sampling profiles, selectable per request as `{model}:{profile}`.
     pub inference_profiles: Option<Vec<gglib_core::domain::InferenceProfile>>,
+    /// OTLP trace export; `None` = disabled.
+    pub otlp: Option<gglib_core::telemetry::OtlpConfig>,
     // Setup wizard
     pub setup_completed: Option<bool>,
     // Title generation
     pub title_generation_prompt: Option<String>,
+    pub auto_title_conversations: Option<bool>,
+    /// Whether message content is encrypted at rest; `None` = disabled.
+    pub encrypt_chat_history: Option<bool>,
+    // Attachments
+    pub max_attachment_size_mb: Option<u32>,
+    pub allowed_attachment_types: Option<Vec<String>>,
+    /// Language tag for user-facing text; `None` follows the environment.
+    pub locale: Option<String>,
+    /// Built-in web search provider and filters; `None` = disabled.
+    pub web_search: Option<gglib_core::domain::WebSearchConfig>,
+    /// Built-in page fetch limits; `None` = disabled.
+    pub url_fetch: Option<gglib_core::domain::UrlFetchConfig>,
+    /// Moderation rules and classifier; `None` = disabled.
+    pub moderation: Option<gglib_core::domain::ModerationConfig>,
+    /// Caps on proxied generations; `None` = unbounded.
+    pub proxy_limits: Option<gglib_core::domain::ProxyLimits>,
+    /// Draw and price for energy estimates; `None` = detected draw, no cost.
+    pub energy: Option<gglib_core::domain::EnergyConfig>,
+    /// Cloud backup bucket; `None` = disabled. The secret access key is
+    /// returned as its `secret:` reference when a secret store is in use.
+    #[serde(default)]
+    pub backup: Option<gglib_core::domain::BackupConfig>,
+    /// Whether a `HuggingFace` token is kept in the secret store. The token
+    /// itself is never sent back.
+    #[serde(default)]
+    pub hf_token_configured: bool,
+    /// Version of the stored settings, bumped by every save from any
+    /// process. Send it back as `expectedVersion` to update conditionally.
+    #[serde(default)]
+    pub version: u64,
 }
 
 /// Request body for updating application settings.
@@ -491,41 +716,156 @@ pub struct AppSettings {
 /// so an explicit JSON `null` (clear the setting) is distinguished from an
 /// omitted key (leave unchanged) — the same pattern used by
 /// [`UpdateModelRequest::server_defaults`].
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default, TS, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct UpdateSettingsRequest {
     #[serde(default, with = "serde_with::rust::double_option")]
+    #[ts(as = "Option<String>", optional = nullable)]
     pub default_download_path: Option<Option<String>>,
     #[serde(default, with = "serde_with::rust::double_option")]
+    #[ts(as = "Option<u64>", optional = nullable)]
     pub default_context_size: Option<Option<u64>>,
     #[serde(default, with = "serde_with::rust::double_option")]
+    #[ts(as = "Option<u16>", optional = nullable)]
     pub proxy_port: Option<Option<u16>>,
     #[serde(default, with = "serde_with::rust::double_option")]
+    #[ts(as = "Option<String>", optional = nullable)]
+    pub proxy_host: Option<Option<String>>,
+    #[serde(default, with = "serde_with::rust::double_option")]
+    #[ts(as = "Option<bool>", optional = nullable)]
+    pub proxy_allow_public: Option<Option<bool>>,
+    #[serde(default, with = "serde_with::rust::double_option")]
+    #[ts(as = "Option<Vec<String>>", optional = nullable)]
+    pub proxy_allowed_origins: Option<Option<Vec<String>>>,
+    #[serde(default, with = "serde_with::rust::double_option")]
+    #[ts(as = "Option<bool>", optional = nullable)]
+    pub proxy_preempt_background: Option<Option<bool>>,
+    #[serde(default, with = "serde_with::rust::double_option")]
+    #[ts(as = "Option<bool>", optional = nullable)]
+    pub proxy_strip_reasoning: Option<Option<bool>>,
+    #[serde(default, with = "serde_with::rust::double_option")]
+    #[ts(as = "Option<u16>", optional = nullable)]
     pub llama_base_port: Option<Option<u16>>,
     #[serde(default, with = "serde_with::rust::double_option")]
+    #[ts(as = "Option<u32>", optional = nullable)]
     pub max_download_queue_size: Option<Option<u32>>,
     #[serde(default, with = "serde_with::rust::double_option")]
+    #[ts(as = "Option<u32>", optional = nullable)]
+    pub partial_download_grace_hours: Option<Option<u32>>,
+    #[serde(default, with = "serde_with::rust::double_option")]
+    #[ts(as = "Option<u32>", optional = nullable)]
+    pub trash_retention_days: Option<Option<u32>>,
+    #[serde(default, with = "serde_with::rust::double_option")]
+    #[ts(as = "Option<u32>", optional = nullable)]
+    pub trash_max_size_gb: Option<Option<u32>>,
+    #[serde(default, with = "serde_with::rust::double_option")]
+    #[ts(as = "Option<bool>", optional = nullable)]
+    pub watch_models_dir: Option<Option<bool>>,
+    #[serde(default, with = "serde_with::rust::double_option")]
+    #[ts(as = "Option<bool>", optional = nullable)]
     pub show_memory_fit_indicators: Option<Option<bool>>,
     #[serde(default, with = "serde_with::rust::double_option")]
+    #[ts(as = "Option<bool>", optional = nullable)]
+    pub warn_restrictive_licenses: Option<Option<bool>>,
+    #[serde(default, with = "serde_with::rust::double_option")]
+    #[ts(as = "Option<u32>", optional = nullable)]
     pub max_tool_iterations: Option<Option<u32>>,
     #[serde(default, with = "serde_with::rust::double_option")]
+    #[ts(as = "Option<u32>", optional = nullable)]
     pub max_stagnation_steps: Option<Option<u32>>,
     /// Default model ID for quick commands (e.g., `gglib question`).
     #[serde(default, with = "serde_with::rust::double_option")]
+    #[ts(as = "Option<i64>", optional = nullable)]
     pub default_model_id: Option<Option<i64>>,
+    /// Minutes without a request before a server is stopped (`0` = never).
     #[serde(default, with = "serde_with::rust::double_option")]
+    #[ts(as = "Option<u32>", optional = nullable)]
+    pub idle_shutdown_minutes: Option<Option<u32>>,
+    /// Replaces the whole startup list; `null` clears it.
+    #[serde(default, with = "serde_with::rust::double_option")]
+    #[ts(as = "Option<Vec<gglib_core::domain::StartupModel>>", optional = nullable)]
+    pub startup_models: Option<Option<Vec<gglib_core::domain::StartupModel>>>,
+    /// Replaces the whole cached-prompt list; `null` clears it.
+    #[serde(default, with = "serde_with::rust::double_option")]
+    #[ts(as = "Option<Vec<gglib_core::domain::CachedPrompt>>", optional = nullable)]
+    pub cached_prompts: Option<Option<Vec<gglib_core::domain::CachedPrompt>>>,
+    #[serde(default, with = "serde_with::rust::double_option")]
+    #[ts(as = "Option<gglib_core::domain::InferenceConfig>", optional = nullable)]
     pub inference_defaults: Option<Option<gglib_core::domain::InferenceConfig>>,
     /// Replaces the whole profile list. `null` clears it; an omitted key leaves
     /// it untouched, so a client updating an unrelated setting cannot drop
     /// profiles it never knew about.
     #[serde(default, with = "serde_with::rust::double_option")]
+    #[ts(as = "Option<Vec<gglib_core::domain::InferenceProfile>>", optional = nullable)]
     pub inference_profiles: Option<Option<Vec<gglib_core::domain::InferenceProfile>>>,
+    /// OTLP trace export; `null` disables it. Applied without a restart.
+    #[serde(default, with = "serde_with::rust::double_option")]
+    #[ts(as = "Option<gglib_core::telemetry::OtlpConfig>", optional = nullable)]
+    pub otlp: Option<Option<gglib_core::telemetry::OtlpConfig>>,
     // Setup wizard
     #[serde(default, with = "serde_with::rust::double_option")]
+    #[ts(as = "Option<bool>", optional = nullable)]
     pub setup_completed: Option<Option<bool>>,
     // Title generation
     #[serde(default, with = "serde_with::rust::double_option")]
+    #[ts(as = "Option<String>", optional = nullable)]
     pub title_generation_prompt: Option<Option<String>>,
+    #[serde(default, with = "serde_with::rust::double_option")]
+    #[ts(as = "Option<bool>", optional = nullable)]
+    pub auto_title_conversations: Option<Option<bool>>,
+    /// Encrypts (or decrypts) every stored message before the setting is
+    /// saved.
+    #[serde(default, with = "serde_with::rust::double_option")]
+    #[ts(as = "Option<bool>", optional = nullable)]
+    pub encrypt_chat_history: Option<Option<bool>>,
+    // Attachments
+    #[serde(default, with = "serde_with::rust::double_option")]
+    #[ts(as = "Option<u32>", optional = nullable)]
+    pub max_attachment_size_mb: Option<Option<u32>>,
+    #[serde(default, with = "serde_with::rust::double_option")]
+    #[ts(as = "Option<Vec<String>>", optional = nullable)]
+    pub allowed_attachment_types: Option<Option<Vec<String>>>,
+    /// Language tag for user-facing text (e.g. `de-DE`); `null` follows the
+    /// environment.
+    #[serde(default, with = "serde_with::rust::double_option")]
+    #[ts(as = "Option<String>", optional = nullable)]
+    pub locale: Option<Option<String>>,
+    /// Built-in web search settings; `null` disables the tool.
+    #[serde(default, with = "serde_with::rust::double_option")]
+    #[ts(as = "Option<gglib_core::domain::WebSearchConfig>", optional = nullable)]
+    pub web_search: Option<Option<gglib_core::domain::WebSearchConfig>>,
+    /// Built-in page fetch limits; `null` disables the tool.
+    #[serde(default, with = "serde_with::rust::double_option")]
+    #[ts(as = "Option<gglib_core::domain::UrlFetchConfig>", optional = nullable)]
+    pub url_fetch: Option<Option<gglib_core::domain::UrlFetchConfig>>,
+    /// Moderation rules and classifier; `null` disables moderation.
+    #[serde(default, with = "serde_with::rust::double_option")]
+    #[ts(as = "Option<gglib_core::domain::ModerationConfig>", optional = nullable)]
+    pub moderation: Option<Option<gglib_core::domain::ModerationConfig>>,
+    /// Caps on proxied generations; `null` removes them all.
+    #[serde(default, with = "serde_with::rust::double_option")]
+    #[ts(as = "Option<gglib_core::domain::ProxyLimits>", optional = nullable)]
+    pub proxy_limits: Option<Option<gglib_core::domain::ProxyLimits>>,
+    /// Draw and price for energy estimates; `null` falls back to the
+    /// detected draw without costs.
+    #[serde(default, with = "serde_with::rust::double_option")]
+    #[ts(as = "Option<gglib_core::domain::EnergyConfig>", optional = nullable)]
+    pub energy: Option<Option<gglib_core::domain::EnergyConfig>>,
+    /// Cloud backup bucket and credentials; `null` disables backups.
+    #[serde(default, with = "serde_with::rust::double_option")]
+    #[ts(as = "Option<gglib_core::domain::BackupConfig>", optional = nullable)]
+    pub backup: Option<Option<gglib_core::domain::BackupConfig>>,
+    /// `HuggingFace` token, written to the secret store rather than the
+    /// settings row; `null` deletes it. Downloads pick it up on restart.
+    #[serde(default, with = "serde_with::rust::double_option")]
+    #[ts(as = "Option<String>", optional = nullable)]
+    pub hf_token: Option<Option<String>>,
+    /// Apply the update only if the settings are still at this
+    /// [`AppSettings::version`]; otherwise fail with a conflict instead of
+    /// overwriting another writer's changes. Omitted = unconditional.
+    #[serde(default)]
+    #[ts(optional)]
+    pub expected_version: Option<u64>,
 }
 
 // ============================================================================
@@ -533,7 +873,7 @@ pub struct UpdateSettingsRequest {
 // ============================================================================
 
 /// MCP server DTO for serialization.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, TS, ToSchema)]
 pub struct McpServerDto {
     pub id: i64,
     pub name: String,
@@ -544,45 +884,53 @@ pub struct McpServerDto {
     pub env: Vec<McpEnvEntryDto>,
     pub created_at: String,
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[ts(optional)]
     pub last_connected_at: Option<String>,
     /// Whether the server configuration is valid
     pub is_valid: bool,
     /// Last validation or runtime error
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[ts(optional)]
     pub last_error: Option<String>,
 }
 
 /// MCP server configuration DTO.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, TS, ToSchema)]
 pub struct McpServerConfigDto {
     /// Command/basename to resolve (e.g., "npx" or "/usr/local/bin/python3")
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[ts(optional)]
     pub command: Option<String>,
     /// Cached absolute path (auto-resolved from command)
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[ts(optional)]
     pub resolved_path_cache: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[ts(optional)]
     pub args: Option<Vec<String>>,
     /// Working directory (must be absolute if specified)
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[ts(optional)]
     pub working_dir: Option<String>,
     /// Additional PATH entries for child process
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[ts(optional)]
     pub path_extra: Option<String>,
     /// URL for SSE connection (required for sse)
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[ts(optional)]
     pub url: Option<String>,
 }
 
 /// MCP environment variable DTO.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, TS, ToSchema)]
 pub struct McpEnvEntryDto {
     pub key: String,
     pub value: String,
 }
 
 /// MCP server status DTO.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, TS, ToSchema)]
 #[serde(rename_all = "lowercase")]
 pub enum McpServerStatusDto {
     Stopped,
@@ -592,7 +940,7 @@ pub enum McpServerStatusDto {
 }
 
 /// MCP server info for GUI display (nested structure matching TS expectations).
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, TS, ToSchema)]
 pub struct McpServerInfo {
     pub server: McpServerDto,
     pub status: McpServerStatusDto,
@@ -601,7 +949,7 @@ pub struct McpServerInfo {
 }
 
 /// Request to create a new MCP server.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, TS, ToSchema)]
 pub struct CreateMcpServerRequest {
     pub name: String,
     pub server_type: String,
@@ -618,7 +966,7 @@ pub struct CreateMcpServerRequest {
 }
 
 /// Request to update an MCP server.
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default, TS, ToSchema)]
 pub struct UpdateMcpServerRequest {
     pub name: Option<String>,
     pub command: Option<String>,
@@ -632,31 +980,256 @@ pub struct UpdateMcpServerRequest {
 }
 
 /// MCP tool information for GUI display.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, TS, ToSchema)]
 pub struct McpToolInfo {
     pub name: String,
     pub description: Option<String>,
     pub input_schema: Option<serde_json::Value>,
     /// Human-readable display title from MCP `annotations.title`.
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[ts(optional)]
     pub title: Option<String>,
 }
 
 /// Request to call an MCP tool.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, TS, ToSchema)]
 pub struct McpToolCallRequest {
     pub tool_name: String,
     pub arguments: std::collections::HashMap<String, serde_json::Value>,
 }
 
 /// Response from an MCP tool call.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, TS, ToSchema)]
 pub struct McpToolCallResponse {
     pub success: bool,
     pub data: Option<serde_json::Value>,
     pub error: Option<String>,
 }
 
+// ============================================================================
+// Project Types
+// ============================================================================
+
+/// Request to create a project.
+#[derive(Debug, Clone, Serialize, Deserialize, TS, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateProjectRequest {
+    pub name: String,
+    /// Absolute path of an existing directory.
+    pub root_path: String,
+    #[serde(default)]
+    pub include_globs: Vec<String>,
+    #[serde(default)]
+    pub exclude_globs: Vec<String>,
+    #[serde(default = "default_auto_include")]
+    pub auto_include: bool,
+    /// Token budget for injected files; omitted uses the default.
+    #[serde(default)]
+    pub token_budget: Option<u32>,
+}
+
+const fn default_auto_include() -> bool {
+    true
+}
+
+/// Request to update a project; omitted fields are left unchanged.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, TS, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateProjectRequest {
+    pub name: Option<String>,
+    pub root_path: Option<String>,
+    pub include_globs: Option<Vec<String>>,
+    pub exclude_globs: Option<Vec<String>>,
+    pub auto_include: Option<bool>,
+    /// `null` resets the budget to the default.
+    #[serde(default, with = "serde_with::rust::double_option")]
+    #[ts(as = "Option<u32>", optional = nullable)]
+    pub token_budget: Option<Option<u32>>,
+}
+
+/// Request to link a conversation to a project.
+#[derive(Debug, Clone, Serialize, Deserialize, TS, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct LinkProjectRequest {
+    pub project_id: i64,
+}
+
+pub use gglib_runtime::project_files::{
+    ContextFile, FileTree, FileTreeEntry, ProjectContext, SkipReason, SkippedFile,
+};
+
+// ============================================================================
+// Preset Types
+// ============================================================================
+
+/// Request to create a preset.
+#[derive(Debug, Clone, Serialize, Deserialize, TS, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CreatePresetRequest {
+    pub name: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub system_prompt: Option<String>,
+    /// Sparse sampling overrides; omitted fields fall through to defaults.
+    #[serde(default)]
+    pub sampling: gglib_core::domain::InferenceConfig,
+    /// Tool names the model may call; empty allows all.
+    #[serde(default)]
+    pub tools: Vec<String>,
+    #[serde(default)]
+    pub mcp_server_ids: Vec<i64>,
+    #[serde(default)]
+    pub default_model: Option<String>,
+}
+
+/// Request to update a preset; omitted fields are left unchanged.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, TS, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdatePresetRequest {
+    pub name: Option<String>,
+    /// `null` clears the description.
+    #[serde(default, with = "serde_with::rust::double_option")]
+    #[ts(as = "Option<String>", optional = nullable)]
+    pub description: Option<Option<String>>,
+    /// `null` clears the system prompt.
+    #[serde(default, with = "serde_with::rust::double_option")]
+    #[ts(as = "Option<String>", optional = nullable)]
+    pub system_prompt: Option<Option<String>>,
+    pub sampling: Option<gglib_core::domain::InferenceConfig>,
+    pub tools: Option<Vec<String>>,
+    pub mcp_server_ids: Option<Vec<i64>>,
+    /// `null` clears the default model.
+    #[serde(default, with = "serde_with::rust::double_option")]
+    #[ts(as = "Option<String>", optional = nullable)]
+    pub default_model: Option<Option<String>>,
+}
+
+// ============================================================================
+// Library Sync Types
+// ============================================================================
+
+/// Request to pair with another gglib instance.
+#[derive(Debug, Clone, Serialize, Deserialize, TS, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PairPeerRequest {
+    /// Local name for the peer.
+    pub name: String,
+    /// Base URL of the peer's web server.
+    pub url: String,
+    /// The peer's model share token.
+    pub token: String,
+    /// This instance's own URL, name and share token. When given, the peer
+    /// is asked to pair back so it can pull from this instance too.
+    #[serde(default)]
+    pub pair_back: Option<PairRequest>,
+}
+
+/// A paired instance, without its token.
+#[derive(Debug, Clone, Serialize, Deserialize, TS, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SyncPeerInfo {
+    pub name: String,
+    pub url: String,
+}
+
+/// A peer model that a pull did not queue, and why.
+#[derive(Debug, Clone, Serialize, Deserialize, TS, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SyncSkipped {
+    pub name: String,
+    pub reason: String,
+}
+
+/// Outcome of pulling a peer's missing models.
+#[derive(Debug, Clone, Serialize, Deserialize, TS, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SyncPullResult {
+    /// Name of the peer pulled from.
+    pub peer: String,
+    /// Download IDs of the queued models; progress arrives as ordinary
+    /// download events.
+    pub queued: Vec<String>,
+    /// Missing models that could not be queued.
+    pub skipped: Vec<SyncSkipped>,
+    /// Peer models this library already has.
+    pub already_present: usize,
+}
+
+// ============================================================================
+// Backup Types
+// ============================================================================
+
+/// An object in the backup bucket.
+#[derive(Debug, Clone, Serialize, Deserialize, TS, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct BackupEntry {
+    /// Name to restore it by: `gglib-<timestamp>.db` for a database backup,
+    /// the path below the models directory for a model file.
+    pub name: String,
+    pub key: String,
+    pub size: u64,
+    pub last_modified: Option<String>,
+}
+
+/// What the backup bucket holds.
+#[derive(Debug, Clone, Serialize, Deserialize, TS, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct BackupListing {
+    /// Database backups, newest first.
+    pub databases: Vec<BackupEntry>,
+    /// Model files, by path.
+    pub models: Vec<BackupEntry>,
+}
+
+/// Request to back up the database and, optionally, model files.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, TS, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct BackupPushRequest {
+    /// Upload model files too. Defaults to the config's `includeModels`.
+    #[serde(default)]
+    pub include_models: Option<bool>,
+}
+
+/// Outcome of a backup.
+#[derive(Debug, Clone, Serialize, Deserialize, TS, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct BackupPushResult {
+    /// Name of the database backup written.
+    pub database: String,
+    /// Model files uploaded.
+    pub models_uploaded: Vec<String>,
+    /// Model files the bucket already held at the same size.
+    pub models_unchanged: usize,
+}
+
+/// Request to restore from the backup bucket.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, TS, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct BackupRestoreRequest {
+    /// Database backup to restore by name, or `latest`. `None` leaves the
+    /// database alone.
+    #[serde(default)]
+    pub database: Option<String>,
+    /// Model files to restore, by name as listed.
+    #[serde(default)]
+    pub models: Vec<String>,
+    /// Restore every model file in the bucket.
+    #[serde(default)]
+    pub all_models: bool,
+}
+
+/// Outcome of a restore.
+#[derive(Debug, Clone, Serialize, Deserialize, TS, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct BackupRestoreResult {
+    /// Name of the database backup staged, if any. It replaces the live
+    /// database the next time gglib starts.
+    pub database: Option<String>,
+    /// Model files written to the models directory.
+    pub models: Vec<String>,
+}
+
 // ============================================================================
 // Server Log Types
 // ============================================================================
@@ -664,6 +1237,12 @@ pub struct McpToolCallResponse {
 // Re-export from gglib-runtime for cross-adapter use
 pub use gglib_runtime::ServerLogEntry;
 
+// ============================================================================
+// Application Log Types
+// ============================================================================
+
+pub use gglib_runtime::log_query::{LogCursor, LogFile, LogLevel, LogPage, LogQuery, LogRecord};
+
 #[cfg(test)]
 mod update_model_request_tests {
     //! JSON-boundary tests for `UpdateModelRequest.server_defaults`.
@@ -704,7 +1283,8 @@ mod update_model_request_tests {
         assert_eq!(
             req.server_defaults,
             Some(Some(ServerConfig {
-                context_length: Some(8192)
+                context_length: Some(8192),
+                ..Default::default()
             })),
             "populated object must resolve to Some(Some(config))"
         );
@@ -0,0 +1,388 @@
+//! Startup warm-start: launch the `startup_models` list at boot.
+//!
+//! Launches are sequential and in list order, so each server is up (and its
+//! memory committed) before the next is considered. Which entries fit is
+//! decided up front by [`plan_startup`] against total RAM minus what running
+//! servers already hold; entries that do not fit are reported as skipped.
+//!
+//! [`WarmStartOps::reconcile`] runs after llama.cpp is installed or rebuilt:
+//! servers still running the old binary are stopped and relaunched with
+//! their startup profile so the upgrade takes effect without a restart.
+
+use std::sync::Arc;
+
+use tracing::{info, warn};
+
+use gglib_core::domain::{
+    Model, StartupCandidate, StartupModel, StartupStep, estimate_kv_bytes_for_context,
+    estimate_kv_elems_per_token, kv_bytes_per_token, plan_startup,
+};
+use gglib_core::services::AppCore;
+use gglib_core::settings::{DEFAULT_CONTEXT_SIZE, Settings};
+use gglib_runtime::llama::args::resolve_kv_cache_types;
+use gglib_runtime::ports_impl::total_model_bytes;
+use gglib_runtime::system::total_system_ram_bytes;
+
+use crate::error::GuiError;
+use crate::servers::ServerOps;
+use crate::types::{StartServerRequest, StartupOutcome, StartupResult};
+
+/// Dependencies for warm-start operations.
+pub struct WarmStartDeps {
+    pub core: Arc<AppCore>,
+    pub servers: Arc<ServerOps>,
+}
+
+/// Startup model launch and reconciliation.
+pub struct WarmStartOps {
+    deps: WarmStartDeps,
+}
+
+impl WarmStartOps {
+    pub fn new(deps: WarmStartDeps) -> Self {
+        Self { deps }
+    }
+
+    /// Launch every startup model that is not already running, in order and
+    /// within the memory budget.
+    pub async fn warm_start(&self) -> Result<Vec<StartupOutcome>, GuiError> {
+        let settings = self.settings().await?;
+        let entries = settings.startup_models.clone().unwrap_or_default();
+        if entries.is_empty() {
+            return Ok(Vec::new());
+        }
+        Ok(self.launch(&settings, &entries, &[]).await)
+    }
+
+    /// Relaunch running startup models after a llama.cpp upgrade.
+    ///
+    /// Startup models that are running are stopped and started again with
+    /// their startup profile; ones that are not running are launched as in
+    /// [`Self::warm_start`]. Servers started by hand are left alone.
+    pub async fn reconcile(&self) -> Result<Vec<StartupOutcome>, GuiError> {
+        let settings = self.settings().await?;
+        let entries = settings.startup_models.clone().unwrap_or_default();
+        if entries.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let running = self.deps.servers.list_servers().await;
+        let mut stopped = Vec::new();
+        for entry in &entries {
+            if running.iter().any(|s| s.model_id == entry.model_id) {
+                match self.deps.servers.stop(entry.model_id).await {
+                    Ok(_) => stopped.push(entry.model_id),
+                    Err(e) => warn!(
+                        model_id = entry.model_id,
+                        error = %e,
+                        "Failed to stop startup model for reconciliation"
+                    ),
+                }
+            }
+        }
+        info!(restarted = stopped.len(), "Reconciling startup models");
+
+        Ok(self.launch(&settings, &entries, &stopped).await)
+    }
+
+    async fn settings(&self) -> Result<Settings, GuiError> {
+        self.deps
+            .core
+            .settings()
+            .get()
+            .await
+            .map_err(|e| GuiError::Internal(format!("Failed to load settings: {e}")))
+    }
+
+    /// Plan and launch `entries`. Models in `restarted` report
+    /// [`StartupResult::Restarted`] instead of `Started`.
+    async fn launch(
+        &self,
+        settings: &Settings,
+        entries: &[StartupModel],
+        restarted: &[i64],
+    ) -> Vec<StartupOutcome> {
+        let running = self.deps.servers.list_servers().await;
+        let mut outcomes = Vec::with_capacity(entries.len());
+        let mut pending = Vec::new();
+        let mut candidates = Vec::new();
+
+        for entry in entries {
+            if let Some(server) = running.iter().find(|s| s.model_id == entry.model_id) {
+                outcomes.push(StartupOutcome {
+                    model_id: entry.model_id,
+                    model_name: Some(server.model_name.clone()),
+                    result: StartupResult::AlreadyRunning { port: server.port },
+                });
+                continue;
+            }
+            match self.deps.core.models().get_by_id(entry.model_id).await {
+                Ok(Some(model)) => {
+                    candidates.push(StartupCandidate {
+                        model_id: entry.model_id,
+                        estimated_bytes: estimate_bytes(&model, entry, settings),
+                    });
+                    pending.push((entry, model));
+                }
+                Ok(None) => outcomes.push(StartupOutcome {
+                    model_id: entry.model_id,
+                    model_name: None,
+                    result: StartupResult::Skipped {
+                        reason: "model not found".to_string(),
+                    },
+                }),
+                Err(e) => outcomes.push(StartupOutcome {
+                    model_id: entry.model_id,
+                    model_name: None,
+                    result: StartupResult::Failed {
+                        error: e.to_string(),
+                    },
+                }),
+            }
+        }
+
+        let mut resident_bytes = 0;
+        for server in &running {
+            if let Ok(Some(model)) = self.deps.core.models().get_by_id(server.model_id).await {
+                resident_bytes += total_model_bytes(&model.file_path);
+            }
+        }
+        let plan = plan_startup(&candidates, total_system_ram_bytes(), resident_bytes);
+
+        for ((entry, model), (_, step)) in pending.into_iter().zip(plan) {
+            let result = match step {
+                StartupStep::OverBudget {
+                    needed_bytes,
+                    available_bytes,
+                } => StartupResult::Skipped {
+                    reason: format!(
+                        "needs {} MiB but only {} MiB of the memory budget is left",
+                        needed_bytes / (1024 * 1024),
+                        available_bytes / (1024 * 1024)
+                    ),
+                },
+                StartupStep::Launch => {
+                    let request = startup_request(entry, settings);
+                    match self.deps.servers.start(entry.model_id, request).await {
+                        Ok(response) if restarted.contains(&entry.model_id) => {
+                            StartupResult::Restarted {
+                                port: response.port,
+                            }
+                        }
+                        Ok(response) => StartupResult::Started {
+                            port: response.port,
+                        },
+                        Err(e) => StartupResult::Failed {
+                            error: e.to_string(),
+                        },
+                    }
+                }
+            };
+            match &result {
+                StartupResult::Skipped { reason } => {
+                    warn!(model_id = entry.model_id, %reason, "Skipped startup model");
+                }
+                StartupResult::Failed { error } => {
+                    warn!(model_id = entry.model_id, %error, "Failed to launch startup model");
+                }
+                _ => info!(model_id = entry.model_id, "Launched startup model"),
+            }
+            outcomes.push(StartupOutcome {
+                model_id: entry.model_id,
+                model_name: Some(model.name),
+                result,
+            });
+        }
+
+        // Report in list order regardless of which pass produced the outcome.
+        outcomes.sort_by_key(|o| entries.iter().position(|e| e.model_id == o.model_id));
+        outcomes
+    }
+}
+
+/// Build the launch request for a startup entry. An unknown inference
+/// profile is logged and the model launches with its usual defaults.
+fn startup_request(entry: &StartupModel, settings: &Settings) -> StartServerRequest {
+    let inference_params = entry.inference_profile.as_deref().and_then(|name| {
+        let profile = settings
+            .inference_profiles
+            .as_deref()
+            .unwrap_or_default()
+            .iter()
+            .find(|p| p.name == name);
+        if profile.is_none() {
+            warn!(
+                model_id = entry.model_id,
+                profile = name,
+                "Startup model references an unknown inference profile"
+            );
+        }
+        profile.map(|p| p.config.clone())
+    });
+    StartServerRequest {
+        context_length: entry.context_length,
+        jinja: entry.jinja,
+        inference_params,
+        threads: entry.threads,
+        threads_batch: entry.threads_batch,
+        cpu_affinity: entry.cpu_affinity.clone(),
+        numa: entry.numa,
+        split_mode: entry.split_mode,
+        tensor_split: entry.tensor_split.clone(),
+        main_gpu: entry.main_gpu,
+        ..Default::default()
+    }
+}
+
+/// Weights plus the KV cache at the context the model will launch with,
+/// following the same precedence as [`ServerOps::start`].
+fn estimate_bytes(model: &Model, entry: &StartupModel, settings: &Settings) -> u64 {
+    let context = entry
+        .context_length
+        .or_else(|| {
+            model
+                .server_defaults
+                .as_ref()
+                .and_then(|d| d.context_length)
+                .map(|v| v as u64)
+        })
+        .or(settings.default_context_size)
+        .unwrap_or(DEFAULT_CONTEXT_SIZE);
+    // Only an explicit slot count is counted; the automatic default fits
+    // extra slots into memory left over by the plan.
+    let slots = model
+        .server_defaults
+        .as_ref()
+        .and_then(|d| d.parallel)
+        .map_or(1, |n| u64::from(n.max(1)));
+    let kv_types = resolve_kv_cache_types(None, None);
+    let kv_bytes = estimate_kv_elems_per_token(&model.metadata, model.architecture.as_deref())
+        .map_or(0, |elems| {
+            estimate_kv_bytes_for_context(
+                kv_bytes_per_token(elems, kv_types.k, kv_types.v),
+                context.saturating_mul(slots),
+            )
+        });
+    total_model_bytes(&model.file_path) + kv_bytes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use gglib_core::domain::InferenceConfig;
+    use gglib_core::events::NoopServerEvents;
+    use gglib_core::ports::NoopEmitter;
+    use gglib_core::settings::SettingsUpdate;
+
+    use crate::servers::ServerDeps;
+    use crate::test_support::{MockProcessRunner, MockToolSupportDetector, test_core};
+
+    fn make_ops(core: Arc<AppCore>) -> WarmStartOps {
+        let servers = Arc::new(ServerOps::new(ServerDeps {
+            core: core.clone(),
+            runner: Arc::new(MockProcessRunner),
+            emitter: Arc::new(NoopEmitter::new()),
+            server_events: Arc::new(NoopServerEvents),
+            tool_detector: Arc::new(MockToolSupportDetector),
+        }));
+        WarmStartOps::new(WarmStartDeps { core, servers })
+    }
+
+    #[tokio::test]
+    async fn empty_startup_list_launches_nothing() {
+        let ops = make_ops(test_core().await);
+        assert!(ops.warm_start().await.unwrap().is_empty());
+        assert!(ops.reconcile().await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn unknown_models_are_skipped() {
+        let core = test_core().await;
+        core.settings()
+            .update(SettingsUpdate {
+                startup_models: Some(Some(vec![StartupModel {
+                    model_id: 9999,
+                    ..Default::default()
+                }])),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+
+        let outcomes = make_ops(core).warm_start().await.unwrap();
+        assert_eq!(outcomes.len(), 1);
+        assert!(matches!(outcomes[0].result, StartupResult::Skipped { .. }));
+    }
+
+    #[test]
+    fn request_uses_named_profile() {
+        let settings = Settings {
+            inference_profiles: Some(vec![gglib_core::domain::InferenceProfile {
+                name: "precise".to_string(),
+                description: None,
+                config: InferenceConfig {
+                    temperature: Some(0.1),
+                    ..Default::default()
+                },
+                list_in_models: false,
+            }]),
+            ..Settings::with_defaults()
+        };
+        let entry = StartupModel {
+            model_id: 1,
+            context_length: Some(8192),
+            inference_profile: Some("precise".to_string()),
+            ..Default::default()
+        };
+
+        let request = startup_request(&entry, &settings);
+        assert_eq!(request.context_length, Some(8192));
+        assert_eq!(
+            request.inference_params.and_then(|p| p.temperature),
+            Some(0.1)
+        );
+
+        let missing = StartupModel {
+            inference_profile: Some("gone".to_string()),
+            ..entry
+        };
+        assert!(
+            startup_request(&missing, &settings)
+                .inference_params
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn request_carries_cpu_options() {
+        let entry = StartupModel {
+            model_id: 1,
+            threads: Some(6),
+            cpu_affinity: Some("0-11".parse().unwrap()),
+            numa: Some(gglib_core::cpu_config::NumaStrategy::Distribute),
+            ..Default::default()
+        };
+
+        let request = startup_request(&entry, &Settings::with_defaults());
+        assert_eq!(request.threads, Some(6));
+        assert_eq!(request.threads_batch, None);
+        assert_eq!(request.cpu_affinity, entry.cpu_affinity);
+        assert_eq!(request.numa, entry.numa);
+    }
+
+    #[test]
+    fn request_carries_gpu_options() {
+        let entry = StartupModel {
+            model_id: 1,
+            split_mode: Some(gglib_core::gpu_config::SplitMode::Row),
+            tensor_split: Some("3,1".parse().unwrap()),
+            main_gpu: Some(1),
+            ..Default::default()
+        };
+
+        let request = startup_request(&entry, &Settings::with_defaults());
+        assert_eq!(request.split_mode, entry.split_mode);
+        assert_eq!(request.tensor_split, entry.tensor_split);
+        assert_eq!(request.main_gpu, Some(1));
+    }
+}
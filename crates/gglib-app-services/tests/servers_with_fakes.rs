@@ -0,0 +1,109 @@
+//! Server lifecycle through `ServerOps`, end to end against the in-memory
+//! fakes from `gglib-test-support`: models come from a map and servers are
+//! simulated llama-servers answering HTTP on loopback.
+
+use std::path::Path;
+use std::sync::Arc;
+
+use chrono::Utc;
+use gglib_app_services::types::StartServerRequest;
+use gglib_app_services::{GuiError, ServerDeps, ServerOps};
+use gglib_core::events::NoopServerEvents;
+use gglib_core::ports::{
+    ModelRepository, NoopEmitter, ProcessRunner, ToolSupportDetection, ToolSupportDetectionInput,
+    ToolSupportDetectorPort,
+};
+use gglib_core::{Model, NewModel};
+use gglib_test_support::Fakes;
+
+struct NoToolSupport;
+
+impl ToolSupportDetectorPort for NoToolSupport {
+    fn detect(&self, _input: ToolSupportDetectionInput<'_>) -> ToolSupportDetection {
+        ToolSupportDetection {
+            supports_tool_calling: false,
+            confidence: 0.0,
+            detected_format: None,
+        }
+    }
+}
+
+fn server_ops(fakes: &Fakes) -> ServerOps {
+    ServerOps::new(ServerDeps {
+        core: fakes.core(),
+        runner: fakes.runner.clone(),
+        emitter: Arc::new(NoopEmitter::new()),
+        server_events: Arc::new(NoopServerEvents),
+        tool_detector: Arc::new(NoToolSupport),
+    })
+}
+
+async fn add_model(fakes: &Fakes, dir: &Path) -> Model {
+    let path = dir.join("qwen3-8b-Q4_K_M.gguf");
+    std::fs::write(&path, b"GGUF").unwrap();
+    fakes
+        .models
+        .insert(&NewModel::new(
+            "Qwen3 8B".to_string(),
+            path,
+            8.0,
+            Utc::now(),
+        ))
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn servers_start_serve_and_stop() {
+    let dir = tempfile::tempdir().unwrap();
+    let fakes = Fakes::new();
+    let model = add_model(&fakes, dir.path()).await;
+    let ops = server_ops(&fakes);
+
+    let request = StartServerRequest {
+        context_length: Some(2048),
+        ..Default::default()
+    };
+    let started = ops.start(model.id, request.clone()).await.unwrap();
+    assert_eq!(fakes.runner.starts()[0].context_size, Some(2048));
+
+    let health = reqwest::get(format!("http://127.0.0.1:{}/health", started.port))
+        .await
+        .unwrap();
+    assert!(health.status().is_success());
+
+    let again = ops.start(model.id, request).await.unwrap();
+    assert_eq!(again.port, started.port);
+    assert_eq!(fakes.runner.starts().len(), 1);
+
+    let servers = ops.list_servers().await;
+    assert_eq!(servers.len(), 1);
+    assert_eq!(servers[0].model_name, "Qwen3 8B");
+
+    ops.stop(model.id).await.unwrap();
+    assert!(ops.list_servers().await.is_empty());
+    assert!(matches!(
+        ops.stop(model.id).await,
+        Err(GuiError::NotFound { .. })
+    ));
+}
+
+#[tokio::test]
+async fn a_missing_binary_is_reported_as_not_installed() {
+    let dir = tempfile::tempdir().unwrap();
+    let fakes = Fakes::new();
+    let model = add_model(&fakes, dir.path()).await;
+    fakes
+        .runner
+        .fail_start(model.id, "llama-server binary not found at /opt/llama");
+
+    let result = server_ops(&fakes)
+        .start(model.id, StartServerRequest::default())
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(GuiError::LlamaServerNotInstalled { .. })
+    ));
+    assert!(fakes.runner.list_running().await.unwrap().is_empty());
+}
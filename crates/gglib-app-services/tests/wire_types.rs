@@ -0,0 +1,125 @@
+//! Golden check for the TypeScript declarations of the wire types.
+//!
+//! Every `AppEvent` and every DTO in `gglib_app_services::types` derives
+//! `ts_rs::TS`. This test exports their declarations and compares them with
+//! the committed copies in `src/types/generated/`, so a change to the wire
+//! format fails CI until the declarations are regenerated:
+//!
+//! ```text
+//! GGLIB_UPDATE_BINDINGS=1 cargo test -p gglib-app-services --test wire_types
+//! ```
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use gglib_app_services::types::*;
+use gglib_core::domain::trash::TrashEntry;
+use gglib_core::domain::usage::{DailyUsage, ModelUsage};
+use gglib_core::domain::{PromptPreview, PromptWarmResult, SlotInfo};
+use gglib_core::download::DownloadEvent;
+use gglib_core::events::AppEvent;
+use ts_rs::{Config, TS};
+
+/// Export `types` (and everything they reference) into `dir`.
+macro_rules! export {
+    ($cfg:expr; $($ty:ty),* $(,)?) => {
+        $( <$ty as TS>::export_all($cfg).expect(concat!("export ", stringify!($ty))); )*
+    };
+}
+
+fn generated_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("../../src/types/generated")
+}
+
+fn export_to(dir: &Path) {
+    // Every integer the backend sends fits in a JS number.
+    let cfg = Config::new().with_large_int("number").with_out_dir(dir);
+    export!(&cfg;
+        AppEvent,
+        DownloadEvent,
+        // Hugging Face
+        HfModelSummary, HfSortField, HfSearchRequest, HfSearchResponse, HfQuantization,
+        HfQuantizationsResponse, ToolSupportResponse,
+        // Models
+        GuiModel, GuiModelPage, GuiModelChanges, ModelDetailDto, AddModelRequest, RemoveModelRequest, UpdateModelRequest,
+        SetCapabilitiesRequest, TrashEntry, ModelUsage, DailyUsage,
+        // Servers
+        StartServerRequest, StartServerResponse, ServerInfo, StartupOutcome, StartupResult,
+        WarmPromptRequest, PromptWarmResult, SlotInfo, PreviewPromptRequest, PromptPreview,
+        // Settings
+        ModelsDirectoryInfo, AppSettings, UpdateSettingsRequest,
+        // MCP
+        McpServerDto, McpServerConfigDto, McpEnvEntryDto, McpServerStatusDto, McpServerInfo,
+        CreateMcpServerRequest, UpdateMcpServerRequest, McpToolInfo, McpToolCallRequest,
+        McpToolCallResponse,
+        // Projects and presets
+        CreateProjectRequest, UpdateProjectRequest, LinkProjectRequest, ProjectContext,
+        FileTree, CreatePresetRequest, UpdatePresetRequest,
+        // Sync and backups
+        PairPeerRequest, SyncPeerInfo, SyncSkipped, SyncPullResult, BackupEntry, BackupListing,
+        BackupPushRequest, BackupPushResult, BackupRestoreRequest, BackupRestoreResult,
+        // Logs
+        ServerLogEntry, LogQuery, LogPage, LogFile,
+    );
+}
+
+/// Every `.ts` file below `dir`, keyed by its path relative to `dir`.
+fn read_tree(dir: &Path) -> BTreeMap<PathBuf, String> {
+    fn walk(root: &Path, dir: &Path, files: &mut BTreeMap<PathBuf, String>) {
+        let Ok(entries) = std::fs::read_dir(dir) else {
+            return;
+        };
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if path.is_dir() {
+                walk(root, &path, files);
+            } else if path.extension().is_some_and(|ext| ext == "ts") {
+                let content = std::fs::read_to_string(&path).expect("read declaration");
+                files.insert(path.strip_prefix(root).unwrap().to_path_buf(), content);
+            }
+        }
+    }
+    let mut files = BTreeMap::new();
+    walk(dir, dir, &mut files);
+    files
+}
+
+#[test]
+fn generated_declarations_match_the_wire_types() {
+    let fresh = tempfile::tempdir().unwrap();
+    export_to(fresh.path());
+    let expected = read_tree(fresh.path());
+    assert!(!expected.is_empty(), "no declarations were exported");
+
+    let committed_dir = generated_dir();
+    if std::env::var_os("GGLIB_UPDATE_BINDINGS").is_some() {
+        for path in read_tree(&committed_dir).keys() {
+            std::fs::remove_file(committed_dir.join(path)).unwrap();
+        }
+        for (path, content) in &expected {
+            let dest = committed_dir.join(path);
+            std::fs::create_dir_all(dest.parent().unwrap()).unwrap();
+            std::fs::write(dest, content).unwrap();
+        }
+        return;
+    }
+
+    let committed = read_tree(&committed_dir);
+    let mut problems = Vec::new();
+    for (path, content) in &expected {
+        match committed.get(path) {
+            None => problems.push(format!("missing: {}", path.display())),
+            Some(old) if old != content => problems.push(format!("changed: {}", path.display())),
+            Some(_) => {}
+        }
+    }
+    for path in committed.keys().filter(|p| !expected.contains_key(*p)) {
+        problems.push(format!("stale: {}", path.display()));
+    }
+    assert!(
+        problems.is_empty(),
+        "src/types/generated is out of date with the Rust wire types:\n  {}\n\
+         Regenerate with: GGLIB_UPDATE_BINDINGS=1 cargo test -p gglib-app-services --test wire_types",
+        problems.join("\n  ")
+    );
+}
@@ -0,0 +1,28 @@
+//! API keys presented by clients, and which of them this server checked.
+//!
+//! Any client can put any string in `Authorization: Bearer` or
+//! `X-API-Key`, so a presented key says nothing about who sent it. Only
+//! middleware that compared the key against one the server holds — the
+//! embedded server's token check, or the rate limiter's configured keys —
+//! marks the request with [`VerifiedApiKey`]; audit and rate limiting
+//! trust a key only then.
+
+use std::sync::Arc;
+
+use axum::http::{HeaderMap, header};
+
+/// Request extension carrying a key the server has checked.
+#[derive(Debug, Clone)]
+pub struct VerifiedApiKey(pub Arc<str>);
+
+/// The key a request presents, verified or not: the `Authorization: Bearer`
+/// token, otherwise `X-API-Key`.
+#[must_use]
+pub fn presented(headers: &HeaderMap) -> Option<&str> {
+    headers
+        .get(header::AUTHORIZATION)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.strip_prefix("Bearer "))
+        .or_else(|| headers.get("x-api-key").and_then(|v| v.to_str().ok()))
+        .filter(|key| !key.is_empty())
+}
@@ -0,0 +1,337 @@
+//! Audit trail of administrative API requests.
+//!
+//! [`record`] wraps the API and, for each request to one of
+//! [`AUDITED_ROUTES`] that succeeds, stores who made it, what it touched
+//! and a summary of its JSON body through
+//! [`AuditLogService`](gglib_core::services::AuditLogService). Failed and
+//! rejected requests change nothing and are not recorded.
+//!
+//! The actor is always the peer IP address. A presented API key's
+//! fingerprint (`Authorization: Bearer` or `X-API-Key`) follows it, marked
+//! `unverified` unless the server checked the key (see
+//! [`VerifiedApiKey`]), and the `X-Gglib-User` header prefixes it when the
+//! client sends one. Unverified keys and that header are taken on trust;
+//! they label requests, they do not authenticate them.
+
+use std::net::SocketAddr;
+
+use axum::body::{Body, to_bytes};
+use axum::extract::{ConnectInfo, Request, State};
+use axum::http::{HeaderMap, Method, header};
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
+use serde_json::Value;
+
+use gglib_core::domain::audit::{AuditAction, api_key_actor};
+
+use crate::api_key::{self, VerifiedApiKey};
+use crate::error::HttpError;
+use crate::state::AppState;
+
+/// Header naming the person behind a request, for the audit trail.
+pub const USER_HEADER: &str = "x-gglib-user";
+
+/// Requests (relative to `/api`) recorded in the audit log. `{…}` matches
+/// one path segment, which becomes the entry's target. The first match
+/// wins, so fixed paths come before patterns that would also match them.
+pub const AUDITED_ROUTES: &[(Method, &str, AuditAction)] = &[
+    (Method::DELETE, "/models/trash", AuditAction::TrashEmptied),
+    (Method::DELETE, "/models/{id}", AuditAction::ModelRemoved),
+    (
+        Method::PUT,
+        "/config/settings",
+        AuditAction::SettingsChanged,
+    ),
+    (
+        Method::PATCH,
+        "/config/settings",
+        AuditAction::SettingsChanged,
+    ),
+    (
+        Method::PUT,
+        "/config/system/models-directory",
+        AuditAction::SettingsChanged,
+    ),
+    (Method::POST, "/servers/start", AuditAction::ServerStarted),
+    (
+        Method::POST,
+        "/servers/{id}/start",
+        AuditAction::ServerStarted,
+    ),
+    (Method::POST, "/servers/stop", AuditAction::ServerStopped),
+    (
+        Method::POST,
+        "/servers/{id}/stop",
+        AuditAction::ServerStopped,
+    ),
+    (
+        Method::POST,
+        "/models/downloads/queue",
+        AuditAction::DownloadQueued,
+    ),
+    (
+        Method::POST,
+        "/models/downloads/collection",
+        AuditAction::DownloadQueued,
+    ),
+    (
+        Method::POST,
+        "/models/downloads/reorder",
+        AuditAction::DownloadReordered,
+    ),
+    (
+        Method::POST,
+        "/models/downloads/reorder-full",
+        AuditAction::DownloadReordered,
+    ),
+    (
+        Method::POST,
+        "/models/downloads/failed/clear",
+        AuditAction::DownloadFailuresCleared,
+    ),
+    (
+        Method::POST,
+        "/models/downloads/{id}/cancel",
+        AuditAction::DownloadCancelled,
+    ),
+    (
+        Method::POST,
+        "/models/downloads/shard-group/{id}/cancel",
+        AuditAction::DownloadCancelled,
+    ),
+    (
+        Method::DELETE,
+        "/models/downloads/{id}",
+        AuditAction::DownloadRemoved,
+    ),
+];
+
+/// Largest request body summarised; bigger ones, and bodies without a
+/// `Content-Length`, are recorded without one.
+const MAX_SUMMARY_BODY_BYTES: usize = 64 * 1024;
+
+/// Longest value kept in a summary, in characters.
+const MAX_VALUE_CHARS: usize = 64;
+
+/// Longest summary kept, in characters.
+const MAX_SUMMARY_CHARS: usize = 500;
+
+/// The action and target of a request, if it is audited.
+#[must_use]
+pub fn audited(method: &Method, path: &str) -> Option<(AuditAction, Option<String>)> {
+    let path = path.strip_suffix('/').unwrap_or(path);
+    AUDITED_ROUTES
+        .iter()
+        .filter(|(m, _, _)| m == method)
+        .find_map(|(_, pattern, action)| match_pattern(pattern, path).map(|t| (*action, t)))
+}
+
+/// Match `path` against `pattern`, returning the `{…}` segment if any.
+fn match_pattern(pattern: &str, path: &str) -> Option<Option<String>> {
+    let mut target = None;
+    let mut segments = path.split('/');
+    for expected in pattern.split('/') {
+        let segment = segments.next()?;
+        if expected.starts_with('{') {
+            if segment.is_empty() {
+                return None;
+            }
+            target = Some(segment.to_string());
+        } else if expected != segment {
+            return None;
+        }
+    }
+    segments.next().is_none().then_some(target)
+}
+
+/// Who made a request, as recorded in the audit log. `verified` is the key
+/// the server checked, if any.
+#[must_use]
+pub fn actor(headers: &HeaderMap, peer: Option<SocketAddr>, verified: Option<&str>) -> String {
+    let mut client = peer.map_or_else(|| "unknown".to_string(), |addr| format!("ip:{}", addr.ip()));
+    match (verified, api_key::presented(headers)) {
+        (Some(key), _) => client = format!("{client}, {}", api_key_actor(key)),
+        (None, Some(key)) => client = format!("{client}, unverified {}", api_key_actor(key)),
+        (None, None) => {}
+    }
+    let user = headers
+        .get(USER_HEADER)
+        .and_then(|v| v.to_str().ok())
+        .map(str::trim)
+        .filter(|user| !user.is_empty());
+    match user {
+        Some(user) => format!("{} ({client})", truncate(user, MAX_VALUE_CHARS)),
+        None => client,
+    }
+}
+
+/// `key=value` pairs for a JSON object body's top-level fields. Values of
+/// fields that look like credentials are masked, nested values are only
+/// counted, and long values are cut short.
+#[must_use]
+pub fn summarize(body: &Value) -> Option<String> {
+    let fields = body.as_object()?;
+    let pairs: Vec<String> = fields
+        .iter()
+        .map(|(key, value)| {
+            let value = if is_secret(key) && !value.is_null() {
+                "***".to_string()
+            } else {
+                match value {
+                    Value::String(s) => truncate(s, MAX_VALUE_CHARS),
+                    Value::Array(items) => format!("[{} items]", items.len()),
+                    Value::Object(_) => "{…}".to_string(),
+                    other => other.to_string(),
+                }
+            };
+            format!("{key}={value}")
+        })
+        .collect();
+    (!pairs.is_empty()).then(|| truncate(&pairs.join(", "), MAX_SUMMARY_CHARS))
+}
+
+fn is_secret(key: &str) -> bool {
+    let key = key.to_ascii_lowercase();
+    ["token", "key", "secret", "password"]
+        .iter()
+        .any(|word| key.contains(word))
+}
+
+fn truncate(text: &str, max_chars: usize) -> String {
+    match text.char_indices().nth(max_chars) {
+        Some((end, _)) => format!("{}…", &text[..end]),
+        None => text.to_string(),
+    }
+}
+
+/// Middleware recording successful audited requests.
+pub(crate) async fn record(State(state): State<AppState>, req: Request, next: Next) -> Response {
+    let Some((action, mut target)) = state
+        .core
+        .audit()
+        .and_then(|_| audited(req.method(), req.uri().path()))
+    else {
+        return next.run(req).await;
+    };
+
+    let peer = req
+        .extensions()
+        .get::<ConnectInfo<SocketAddr>>()
+        .map(|ConnectInfo(addr)| *addr);
+    let verified = req
+        .extensions()
+        .get::<VerifiedApiKey>()
+        .map(|VerifiedApiKey(key)| key.as_ref());
+    let actor = actor(req.headers(), peer, verified);
+
+    // Buffer small bodies to summarise them, then hand them on unchanged.
+    // Bodies of unknown or large size stream through unread.
+    let declared_len = req
+        .headers()
+        .get(header::CONTENT_LENGTH)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.parse::<usize>().ok());
+    let (req, body) = match declared_len {
+        Some(len) if len <= MAX_SUMMARY_BODY_BYTES => {
+            let (parts, body) = req.into_parts();
+            let Ok(bytes) = to_bytes(body, MAX_SUMMARY_BODY_BYTES).await else {
+                return HttpError::BadRequest("Could not read request body".into()).into_response();
+            };
+            let json = serde_json::from_slice::<Value>(&bytes).ok();
+            (Request::from_parts(parts, Body::from(bytes)), json)
+        }
+        _ => (req, None),
+    };
+    let summary = body.as_ref().and_then(summarize);
+    if target.is_none() {
+        target = body.as_ref().and_then(body_target);
+    }
+
+    let response = next.run(req).await;
+    if response.status().is_success()
+        && let Some(audit) = state.core.audit()
+    {
+        audit.record(actor, action, target, summary).await;
+    }
+    response
+}
+
+/// The model or server a body-addressed request names.
+fn body_target(body: &Value) -> Option<String> {
+    ["model_id", "id"]
+        .iter()
+        .find_map(|key| match body.get(key)? {
+            Value::String(s) => Some(s.clone()),
+            Value::Number(n) => Some(n.to_string()),
+            _ => None,
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn routes_match_with_their_targets() {
+        assert_eq!(
+            audited(&Method::DELETE, "/models/12"),
+            Some((AuditAction::ModelRemoved, Some("12".to_string())))
+        );
+        assert_eq!(
+            audited(&Method::DELETE, "/models/trash"),
+            Some((AuditAction::TrashEmptied, None))
+        );
+        assert_eq!(
+            audited(&Method::POST, "/models/downloads/abc/cancel"),
+            Some((AuditAction::DownloadCancelled, Some("abc".to_string())))
+        );
+        assert_eq!(
+            audited(&Method::PUT, "/config/settings/"),
+            Some((AuditAction::SettingsChanged, None))
+        );
+        assert_eq!(audited(&Method::GET, "/models/12"), None);
+        assert_eq!(audited(&Method::DELETE, "/models/12/tags/x"), None);
+    }
+
+    #[test]
+    fn summaries_mask_credentials() {
+        let body = json!({
+            "default_context_size": 8192,
+            "hf_token": "hf_secret",
+            "web_search_api_key": null,
+            "tags": ["a", "b"],
+        });
+        assert_eq!(
+            summarize(&body).unwrap(),
+            "default_context_size=8192, hf_token=***, tags=[2 items], web_search_api_key=null"
+        );
+        assert_eq!(summarize(&json!([1, 2])), None);
+        assert_eq!(body_target(&json!({"model_id": 4})), Some("4".to_string()));
+    }
+
+    #[test]
+    fn actors_are_peers_labelled_with_keys_and_user_names() {
+        let peer: SocketAddr = "10.0.0.7:5000".parse().unwrap();
+        let mut headers = HeaderMap::new();
+        assert_eq!(actor(&headers, Some(peer), None), "ip:10.0.0.7");
+        assert_eq!(actor(&headers, None, None), "unknown");
+
+        // A key nothing checked can't stand in for the peer: anyone could
+        // send someone else's.
+        headers.insert(header::AUTHORIZATION, "Bearer s3cret".parse().unwrap());
+        headers.insert(USER_HEADER, "alice".parse().unwrap());
+        assert_eq!(
+            actor(&headers, Some(peer), None),
+            format!(
+                "alice (ip:10.0.0.7, unverified {})",
+                api_key_actor("s3cret")
+            )
+        );
+
+        assert_eq!(
+            actor(&headers, Some(peer), Some("s3cret")),
+            format!("alice (ip:10.0.0.7, {})", api_key_actor("s3cret"))
+        );
+    }
+}
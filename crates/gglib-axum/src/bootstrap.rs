@@ -13,9 +13,11 @@ use std::sync::Arc;
 
 use anyhow::Result;
 use gglib_app_services::{
-    BenchmarkDeps, BenchmarkOps, CouncilApprovalRegistry, DownloadDeps, DownloadOps, McpDeps,
-    McpOps, ModelDeps, ModelOps, ProxyDeps, ProxyOps, ServerDeps, ServerOps, SettingsDeps,
-    SettingsOps, SetupDeps, SetupOps,
+    BackupDeps, BackupOps, BenchmarkDeps, BenchmarkOps, CouncilApprovalRegistry, DownloadDeps,
+    DownloadOps, JobDeps, JobOps, LogDeps, LogOps, McpDeps, McpOps, ModelDeps, ModelOps,
+    OnboardingDeps, OnboardingOps, PipelineDeps, PipelineOps, PresetDeps, PresetOps, ProjectDeps,
+    ProjectOps, ProxyDeps, ProxyOps, ServerDeps, ServerOps, SettingsDeps, SettingsOps, SetupDeps,
+    SetupOps, SyncDeps, SyncOps, WarmStartDeps, WarmStartOps,
 };
 use gglib_bootstrap::{BootstrapConfig, BuiltCore, CoreBootstrap};
 use gglib_core::ports::{
@@ -23,9 +25,12 @@ use gglib_core::ports::{
     ModelRuntimePort, ProcessRunner,
 };
 use gglib_core::server_config::CacheRamSetting;
-use gglib_core::services::AppCore;
+use gglib_core::services::{AppCore, LibraryScanService, ModelDirectorySync};
 use gglib_db::cleanup_zombie_benchmark_runs;
-use gglib_db::{SqliteBenchmarkRepository, SqliteCouncilRepository};
+use gglib_db::{
+    SqliteBenchmarkRepository, SqliteCouncilRepository, SqliteDatabaseSnapshot,
+    SqliteGgufMetadataCache,
+};
 use gglib_gguf::ToolSupportDetector;
 use gglib_mcp::McpService;
 use reqwest::Client;
@@ -35,7 +40,9 @@ use gglib_runtime::process::ProcessManager;
 use gglib_runtime::proxy::ProxySupervisor;
 use gglib_runtime::system::DefaultSystemProbe;
 
+use crate::rate_limit::{RateLimitConfig, RateLimiter};
 use crate::sse::SseBroadcaster;
+use crate::static_files::SpaFallback;
 
 // Path utilities from core
 use gglib_core::paths::{
@@ -71,6 +78,15 @@ pub struct ServerConfig {
     pub max_concurrent_agent_loops: usize,
     /// Optional path to static assets for SPA serving.
     pub static_dir: Option<PathBuf>,
+    /// How unmatched paths are answered when serving static assets.
+    pub spa_fallback: SpaFallback,
+    /// Bearer token other machines present to download model files from
+    /// `GET /api/models/{id}/file`. `None` turns the endpoint off.
+    pub model_share_token: Option<String>,
+    /// Per-client API rate limits; `None` leaves the API unlimited.
+    pub rate_limit: Option<RateLimitConfig>,
+    /// Directory the log endpoints read the daily log files from.
+    pub log_dir: PathBuf,
     /// CORS configuration.
     pub cors: CorsConfig,
 }
@@ -85,6 +101,10 @@ impl ServerConfig {
             max_concurrent: 4,
             max_concurrent_agent_loops: 4,
             static_dir: None,
+            spa_fallback: SpaFallback::Index,
+            model_share_token: None,
+            rate_limit: None,
+            log_dir: gglib_core::telemetry::log_dir(),
             cors: CorsConfig::default(),
         })
     }
@@ -96,6 +116,27 @@ impl ServerConfig {
         self
     }
 
+    /// Set how unmatched paths are answered when serving static assets.
+    #[must_use]
+    pub fn with_spa_fallback(mut self, fallback: SpaFallback) -> Self {
+        self.spa_fallback = fallback;
+        self
+    }
+
+    /// Allow model file downloads for clients presenting `token`.
+    #[must_use]
+    pub fn with_model_share_token(mut self, token: impl Into<String>) -> Self {
+        self.model_share_token = Some(token.into());
+        self
+    }
+
+    /// Limit each client's API requests per `limits`.
+    #[must_use]
+    pub fn with_rate_limit(mut self, limits: RateLimitConfig) -> Self {
+        self.rate_limit = Some(limits);
+        self
+    }
+
     /// Set CORS to allow specific origins.
     #[must_use]
     pub fn with_allowed_origins(mut self, origins: Vec<String>) -> Self {
@@ -118,6 +159,24 @@ pub struct AxumContext {
     pub mcp_ops: Arc<McpOps>,
     pub proxy: Arc<ProxyOps>,
     pub setup: Arc<SetupOps>,
+    /// First-run onboarding state machine.
+    pub onboarding: Arc<OnboardingOps>,
+    /// Startup models: launched at boot, relaunched after llama.cpp upgrades.
+    pub warm_start: Arc<WarmStartOps>,
+    /// Running and recent long-running operations.
+    pub jobs: Arc<JobOps>,
+    /// Application log file queries.
+    pub logs: Arc<LogOps>,
+    /// Projects and the file context they inject into chats.
+    pub projects: Arc<ProjectOps>,
+    /// Generation presets selectable per proxy request.
+    pub presets: Arc<PresetOps>,
+    /// Persisted multi-stage pipeline runs.
+    pub pipelines: Arc<PipelineOps>,
+    /// Library sync with paired gglib instances.
+    pub sync: Arc<SyncOps>,
+    /// Backups to an S3-compatible bucket.
+    pub backup: Arc<BackupOps>,
     /// The core application facade.
     pub core: Arc<AppCore>,
     /// MCP service for managing MCP servers.
@@ -163,6 +222,11 @@ pub struct AxumContext {
     /// capabilities, tags and inference defaults; resolving them through this
     /// port is what keeps those surfaces in step with the proxy.
     pub catalog: Arc<dyn ModelCatalogPort>,
+    /// Bearer token required by `GET /api/models/{id}/file`; `None` when
+    /// model sharing is off.
+    pub model_share_token: Option<Arc<str>>,
+    /// Per-client API rate limiter; `None` when rate limiting is off.
+    pub rate_limiter: Option<Arc<RateLimiter>>,
     /// Per-run queues for conversational steering notes (keyed by run_id).
     #[allow(clippy::type_complexity)]
     pub steering_note_queues:
@@ -197,7 +261,7 @@ pub async fn bootstrap(config: ServerConfig) -> Result<AxumContext> {
         db_path,
         llama_server_path: config.llama_server_path.clone(),
         max_concurrent: config.max_concurrent,
-        models_dir: models_resolution.path,
+        models_dir: models_resolution.path.clone(),
         hf_token: None,
     };
     let emitter: Arc<dyn AppEventEmitter> = sse.clone();
@@ -210,6 +274,7 @@ pub async fn bootstrap(config: ServerConfig) -> Result<AxumContext> {
         repos,
         model_registrar: _,
         pool,
+        jobs,
     } = CoreBootstrap::build(bootstrap_config, emitter).await?;
 
     // 3. Bootstrap capabilities for existing models (idempotent; fine to run
@@ -230,6 +295,69 @@ pub async fn bootstrap(config: ServerConfig) -> Result<AxumContext> {
         tracing::warn!("Failed to clean up zombie benchmark runs on startup: {e}");
     }
 
+    // 3c. Bring stored model metadata up to date with the files on disk, in
+    //     the background. Files unchanged since the last scan come from the
+    //     metadata cache and aren't read. Then re-run capability detection,
+    //     as a tracked job, on models an older detector classified.
+    tokio::spawn({
+        let scanner = LibraryScanService::new(
+            repos.models.clone(),
+            Arc::clone(&gguf_parser),
+            Arc::new(SqliteGgufMetadataCache::new(pool.clone())),
+        );
+        let core = Arc::clone(&core);
+        let gguf_parser = Arc::clone(&gguf_parser);
+        let jobs = Arc::clone(&jobs);
+        async move {
+            match scanner.refresh_library().await {
+                Ok(summary) => tracing::info!(
+                    models = summary.models,
+                    cached = summary.cached,
+                    parsed = summary.parsed,
+                    updated = summary.updated,
+                    failed = summary.failed,
+                    "Scanned model library"
+                ),
+                Err(e) => tracing::warn!("Failed to scan the model library: {e}"),
+            }
+            match core
+                .models()
+                .migrate_capabilities(gguf_parser.as_ref(), &jobs)
+                .await
+            {
+                Ok(summary) if summary.outdated > 0 => tracing::info!(
+                    outdated = summary.outdated,
+                    changed = summary.changed,
+                    failed = summary.failed,
+                    "Re-detected model capabilities"
+                ),
+                Ok(_) => {}
+                Err(e) => tracing::warn!("Failed to re-detect model capabilities: {e}"),
+            }
+        }
+    });
+
+    // 3d. Opt-in models directory watcher: files copied into or deleted from
+    //     the models directory reach the library without a rescan. Read once
+    //     here; the watcher task lives as long as the process.
+    let watch_models_dir = core
+        .settings()
+        .get()
+        .await
+        .map(|s| s.watch_models_dir == Some(true))
+        .unwrap_or_default();
+    if watch_models_dir {
+        let sync = Arc::new(ModelDirectorySync::new(
+            Arc::clone(&core),
+            Arc::clone(&gguf_parser),
+            sse.clone(),
+            models_resolution.path,
+        ));
+        if let Err(e) = gglib_runtime::models_watcher::spawn(sync) {
+            tracing::warn!("Failed to watch the models directory: {e}");
+        }
+    }
+
     // 4. MCP service with SSE emitter.
     let mcp = Arc::new(McpService::new(
         repos.mcp_servers.clone(),
@@ -259,16 +387,19 @@ pub async fn bootstrap(config: ServerConfig) -> Result<AxumContext> {
     // — it must never gain a prompt cache, which would perturb prefill timings
     // and RAM footprint — while still sharing this same SingleSwap manager, so
     // only one llama-server ever runs system-wide.
-    let process_manager = Arc::new(ProcessManager::new_single_swap(
-        config.base_port,
-        config.llama_server_path.to_string_lossy().into_owned(),
-        catalog_for_runtime,
-        None,
-        CacheRamSetting::Auto,
-        None,
-        None,
-        None,
-    ));
+    let process_manager = Arc::new(
+        ProcessManager::new_single_swap(
+            config.base_port,
+            config.llama_server_path.to_string_lossy().into_owned(),
+            catalog_for_runtime,
+            None,
+            CacheRamSetting::Auto,
+            None,
+            None,
+            None,
+        )
+        .with_cached_prompts(repos.settings.clone()),
+    );
     let runtime: Arc<dyn ModelRuntimePort> =
         Arc::new(RuntimePortImpl::new(Arc::clone(&process_manager)));
     let benchmark_runtime: Arc<dyn ModelRuntimePort> = Arc::new(RuntimePortImpl::with_cache_ram(
@@ -303,6 +434,8 @@ pub async fn bootstrap(config: ServerConfig) -> Result<AxumContext> {
         core: Arc::clone(&core),
         system_probe: system_probe.clone(),
         downloads: downloads.clone(),
+        proxy: Arc::clone(&proxy_supervisor),
+        emitter: sse.clone(),
     }));
 
     let mcp_ops = Arc::new(McpOps::new(McpDeps { mcp: mcp.clone() }));
@@ -344,6 +477,52 @@ pub async fn bootstrap(config: ServerConfig) -> Result<AxumContext> {
         system_probe,
     }));
 
+    let onboarding = Arc::new(OnboardingOps::new(OnboardingDeps {
+        core: Arc::clone(&core),
+        setup: Arc::clone(&setup),
+        proxy: Arc::clone(&proxy),
+        downloads: downloads.clone(),
+        emitter: sse.clone(),
+    }));
+
+    let warm_start = Arc::new(WarmStartOps::new(WarmStartDeps {
+        core: Arc::clone(&core),
+        servers: Arc::clone(&servers),
+    }));
+
+    let jobs = Arc::new(JobOps::new(JobDeps {
+        jobs,
+        downloads: downloads.clone(),
+    }));
+
+    let logs = Arc::new(LogOps::new(LogDeps {
+        log_dir: config.log_dir.clone(),
+    }));
+
+    let projects = Arc::new(ProjectOps::new(ProjectDeps {
+        core: Arc::clone(&core),
+    }));
+
+    let presets = Arc::new(PresetOps::new(PresetDeps {
+        core: Arc::clone(&core),
+    }));
+
+    let pipelines = Arc::new(PipelineOps::new(PipelineDeps {
+        core: Arc::clone(&core),
+    }));
+
+    let sync = Arc::new(SyncOps::new(SyncDeps {
+        core: Arc::clone(&core),
+        downloads: downloads.clone(),
+        http: Client::new(),
+    }));
+
+    let backup = Arc::new(BackupOps::new(BackupDeps {
+        core: Arc::clone(&core),
+        snapshot: Arc::new(SqliteDatabaseSnapshot::new(pool.clone())),
+        emitter: sse.clone(),
+    }));
+
     // Emit initial server snapshot after initialization
     tokio::spawn({
         let servers = Arc::clone(&servers);
@@ -352,6 +531,28 @@ pub async fn bootstrap(config: ServerConfig) -> Result<AxumContext> {
         }
     });
 
+    // Bring up the configured startup models in the background so a long
+    // list does not hold up the API becoming available.
+    tokio::spawn({
+        let warm_start = Arc::clone(&warm_start);
+        async move {
+            if let Err(e) = warm_start.warm_start().await {
+                tracing::warn!("Failed to launch startup models: {e}");
+            }
+        }
+    });
+
+    // Announce settings saved by other processes (e.g. the CLI) so open
+    // clients and the proxy pick them up.
+    tokio::spawn({
+        let settings = Arc::clone(&settings);
+        async move {
+            settings
+                .watch_external_changes(gglib_app_services::EXTERNAL_CHANGE_POLL_INTERVAL)
+                .await;
+        }
+    });
+
     // Spawn proxy crash watcher — emits ProxyCrashed when the task exits unexpectedly.
     // Uses the watch channel from ProxySupervisor (zero polling).
     tokio::spawn({
@@ -369,6 +570,24 @@ pub async fn bootstrap(config: ServerConfig) -> Result<AxumContext> {
         }
     });
 
+    // Spawn proxy reload watcher — confirms each live settings reload with
+    // ProxyConfigReloaded, mirroring the crash watcher above.
+    tokio::spawn({
+        let mut rx = proxy.reload_receiver();
+        let sse = Arc::clone(&sse);
+        async move {
+            while rx.changed().await.is_ok() {
+                let applied = rx.borrow().clone();
+                if let Some(applied) = applied {
+                    sse.emit(gglib_core::events::AppEvent::proxy_config_reloaded(
+                        applied.default_ctx,
+                        applied.profiles,
+                    ));
+                }
+            }
+        }
+    });
+
     Ok(AxumContext {
         models,
         servers,
@@ -377,6 +596,15 @@ pub async fn bootstrap(config: ServerConfig) -> Result<AxumContext> {
         mcp_ops,
         proxy,
         setup,
+        onboarding,
+        warm_start,
+        jobs,
+        logs,
+        projects,
+        presets,
+        pipelines,
+        sync,
+        backup,
         core,
         mcp,
         hf_client,
@@ -392,6 +620,10 @@ pub async fn bootstrap(config: ServerConfig) -> Result<AxumContext> {
         benchmark,
         runtime,
         catalog,
+        model_share_token: config.model_share_token.as_deref().map(Arc::from),
+        rate_limiter: config
+            .rate_limit
+            .map(|limits| Arc::new(RateLimiter::new(limits))),
         steering_note_queues: Arc::new(tokio::sync::Mutex::new(HashMap::new())),
     })
 }
@@ -409,7 +641,12 @@ pub async fn start_server(config: ServerConfig) -> Result<()> {
     // Choose router based on whether static serving is configured
     let app = if let Some(ref static_dir) = config.static_dir {
         info!("Serving static assets from: {}", static_dir.display());
-        crate::routes::create_spa_router(ctx, static_dir, &config.cors)
+        crate::routes::create_spa_router_with_fallback(
+            ctx,
+            static_dir,
+            &config.cors,
+            &config.spa_fallback,
+        )
     } else {
         crate::routes::create_router(ctx, &config.cors)
     };
@@ -423,6 +660,11 @@ pub async fn start_server(config: ServerConfig) -> Result<()> {
         info!("gglib web server (API only) listening on http://{}", addr);
     }
 
-    axum::serve(listener, app).await?;
+    // Peer addresses identify clients for rate limiting.
+    axum::serve(
+        listener,
+        app.into_make_service_with_connect_info::<std::net::SocketAddr>(),
+    )
+    .await?;
     Ok(())
 }
@@ -15,21 +15,47 @@ use axum::{Json, Router};
 use futures_util::StreamExt;
 use reqwest::Client;
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
 use crate::error::HttpError;
+use crate::handlers::attachments::attachments;
 use crate::state::AppState;
-use gglib_core::domain::chat::{Conversation, Message, MessageRole, NewMessage};
+use gglib_core::domain::MessageContent;
+use gglib_core::domain::chat::{
+    Conversation, ConversationSettings, Message, MessageRevision, MessageRole, ModelBindingWarning,
+    NewConversation, NewMessage,
+};
+use gglib_core::services::ChatHistoryService;
+
+/// Response header carrying the [`ModelBindingWarning`] message of the
+/// conversation a chat request continues.
+pub const MODEL_WARNING_HEADER: &str = "x-gglib-model-warning";
 
 // ─────────────────────────────────────────────────────────────────────────────
 // Request/Response DTOs
 // ─────────────────────────────────────────────────────────────────────────────
 
 /// Request body for creating a new conversation.
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 pub struct CreateConversationRequest {
     pub title: Option<String>,
     pub model_id: Option<i64>,
     pub system_prompt: Option<String>,
+    /// Sampling and session parameters to resume with.
+    #[serde(default)]
+    pub settings: Option<ConversationSettings>,
+    /// Keep the conversation in memory only, never in the database.
+    #[serde(default)]
+    pub incognito: bool,
+}
+
+/// A conversation with a warning when its model has been deleted.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ConversationResponse {
+    #[serde(flatten)]
+    pub conversation: Conversation,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub model_warning: Option<ModelBindingWarning>,
 }
 
 /// Request body for updating a conversation.
@@ -38,24 +64,29 @@ pub struct CreateConversationRequest {
 /// JSON `null` (clear the system prompt) is distinguished from an omitted
 /// key (leave unchanged) — without it, `PUT /api/conversations/:id` with
 /// `{"system_prompt": null}` silently no-ops instead of clearing the prompt.
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 pub struct UpdateConversationRequest {
     pub title: Option<String>,
     #[serde(default, with = "serde_with::rust::double_option")]
     pub system_prompt: Option<Option<String>>,
+    /// Rebind the conversation to this model.
+    pub model_id: Option<i64>,
 }
 
 /// Request body for saving a new message.
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 pub struct SaveMessageRequest {
     pub conversation_id: i64,
     pub role: String,
     pub content: String,
     pub metadata: Option<serde_json::Value>,
+    /// Uploaded attachments to link to the saved message.
+    #[serde(default)]
+    pub attachment_ids: Vec<i64>,
 }
 
-/// Request body for updating a message.
-#[derive(Debug, Deserialize)]
+/// Request body for updating, regenerating or editing a message.
+#[derive(Debug, Deserialize, ToSchema)]
 pub struct UpdateMessageRequest {
     pub content: String,
     pub metadata: Option<serde_json::Value>,
@@ -95,15 +126,21 @@ pub struct ChatProxyRequest {
     /// Optional tool choice strategy.
     #[serde(default)]
     pub tool_choice: Option<serde_json::Value>,
+    /// Conversation being continued; when it is linked to a project, the
+    /// project's files are injected into the system prompt.
+    #[serde(default)]
+    pub conversation_id: Option<i64>,
 }
 
 /// A chat message in the request/response.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ChatMessage {
     pub role: String,
-    /// Content is optional when tool_calls are present (OpenAI API spec)
+    /// Content is optional when tool_calls are present (OpenAI API spec).
+    /// Either a string or content parts; `attachment` parts are resolved
+    /// before the request is forwarded.
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub content: Option<String>,
+    pub content: Option<MessageContent>,
     /// Tool call ID (for tool role messages returning results).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tool_call_id: Option<String>,
@@ -151,6 +188,9 @@ pub struct ChatUsage {
 /// - `/api/conversations/{id}/messages` - Get messages for conversation
 /// - `/api/messages` - Save new message
 /// - `/api/messages/{id}` - Update/delete message
+/// - `/api/messages/{id}/regenerate` - Replace an assistant reply, keeping the old one
+/// - `/api/messages/{id}/edit` - Replace a user message, invalidating later messages
+/// - `/api/messages/{id}/versions` - List every version of a message
 /// - `/api/chat` - Proxy chat completions to llama-server (streaming supported)
 ///
 /// # Arguments
@@ -188,6 +228,9 @@ pub(crate) fn chat_routes_no_prefix() -> Router<AppState> {
         .route("/conversations/{id}/messages", get(get_messages))
         .route("/messages", post(save_message))
         .route("/messages/{id}", put(update_message).delete(delete_message))
+        .route("/messages/{id}/regenerate", post(regenerate_message))
+        .route("/messages/{id}/edit", post(edit_message))
+        .route("/messages/{id}/versions", get(get_message_versions))
         // Chat completion proxy (forwards to llama-server)
         .route("/chat", post(proxy_chat))
 }
@@ -197,7 +240,12 @@ pub(crate) fn chat_routes_no_prefix() -> Router<AppState> {
 // ─────────────────────────────────────────────────────────────────────────────
 
 /// List all conversations.
-/// GET /api/conversations
+#[utoipa::path(
+    get,
+    path = "/conversations",
+    tag = "conversations",
+    responses((status = 200, body = Vec<Conversation>))
+)]
 pub async fn list_conversations(
     State(state): State<AppState>,
 ) -> Result<Json<Vec<Conversation>>, HttpError> {
@@ -206,52 +254,125 @@ pub async fn list_conversations(
 }
 
 /// Create a new conversation.
-/// POST /api/conversations
+#[utoipa::path(
+    post,
+    path = "/conversations",
+    tag = "conversations",
+    request_body = CreateConversationRequest,
+    responses((status = 200, description = "ID of the new conversation", body = i64))
+)]
 pub async fn create_conversation(
     State(state): State<AppState>,
     Json(req): Json<CreateConversationRequest>,
 ) -> Result<Json<i64>, HttpError> {
     let title = req.title.unwrap_or_else(|| "New Conversation".to_string());
+    let mut settings = req.settings;
+    if let Some(model_id) = req.model_id {
+        let model = state
+            .core
+            .models()
+            .get_by_id(model_id)
+            .await?
+            .ok_or_else(|| HttpError::NotFound(format!("Model not found: {model_id}")))?;
+        settings.get_or_insert_default().bind_model(&model);
+    }
     let id = state
         .core
         .chat_history()
-        .create_conversation(title, req.model_id, req.system_prompt)
+        .create_conversation_with_settings(NewConversation {
+            title,
+            model_id: req.model_id,
+            system_prompt: req.system_prompt,
+            settings,
+            incognito: req.incognito,
+        })
         .await?;
     Ok(Json(id))
 }
 
 /// Get a single conversation by ID.
-/// GET /api/conversations/:id
+#[utoipa::path(
+    get,
+    path = "/conversations/{id}",
+    tag = "conversations",
+    params(("id" = i64, Path, description = "Conversation ID")),
+    responses((status = 200, body = ConversationResponse))
+)]
 pub async fn get_conversation(
     State(state): State<AppState>,
     Path(id): Path<i64>,
-) -> Result<Json<Conversation>, HttpError> {
+) -> Result<Json<ConversationResponse>, HttpError> {
     let conversation = state
         .core
         .chat_history()
         .get_conversation(id)
         .await?
         .ok_or_else(|| HttpError::NotFound(format!("Conversation not found: {}", id)))?;
-    Ok(Json(conversation))
+    let model_warning = model_warning(&state, &conversation).await;
+    Ok(Json(ConversationResponse {
+        conversation,
+        model_warning,
+    }))
+}
+
+/// Check a conversation's model binding, treating a failed check as fine.
+async fn model_warning(
+    state: &AppState,
+    conversation: &Conversation,
+) -> Option<ModelBindingWarning> {
+    state
+        .core
+        .models()
+        .binding_warning(conversation)
+        .await
+        .unwrap_or_else(|e| {
+            tracing::warn!(
+                conversation_id = conversation.id,
+                error = %e,
+                "Failed to check conversation model binding"
+            );
+            None
+        })
 }
 
 /// Update a conversation.
-/// PUT /api/conversations/:id
+#[utoipa::path(
+    put,
+    path = "/conversations/{id}",
+    tag = "conversations",
+    params(("id" = i64, Path, description = "Conversation ID")),
+    request_body = UpdateConversationRequest,
+    responses((status = 200, description = "Done"))
+)]
 pub async fn update_conversation(
     State(state): State<AppState>,
     Path(id): Path<i64>,
     Json(req): Json<UpdateConversationRequest>,
 ) -> Result<(), HttpError> {
-    state
-        .core
-        .chat_history()
+    let history = state.core.chat_history();
+    if let Some(model_id) = req.model_id {
+        let model = state
+            .core
+            .models()
+            .get_by_id(model_id)
+            .await?
+            .ok_or_else(|| HttpError::NotFound(format!("Model not found: {model_id}")))?;
+        history.bind_model(id, &model).await?;
+    }
+    history
         .update_conversation(id, req.title, req.system_prompt)
         .await?;
     Ok(())
 }
 
 /// Delete a conversation and all its messages.
-/// DELETE /api/conversations/:id
+#[utoipa::path(
+    delete,
+    path = "/conversations/{id}",
+    tag = "conversations",
+    params(("id" = i64, Path, description = "Conversation ID")),
+    responses((status = 200, description = "Done"))
+)]
 pub async fn delete_conversation(
     State(state): State<AppState>,
     Path(id): Path<i64>,
@@ -265,7 +386,13 @@ pub async fn delete_conversation(
 // ─────────────────────────────────────────────────────────────────────────────
 
 /// Get all messages for a conversation.
-/// GET /api/conversations/:id/messages
+#[utoipa::path(
+    get,
+    path = "/conversations/{id}/messages",
+    tag = "conversations",
+    params(("id" = i64, Path, description = "Conversation ID")),
+    responses((status = 200, body = Vec<Message>))
+)]
 pub async fn get_messages(
     State(state): State<AppState>,
     Path(conversation_id): Path<i64>,
@@ -279,7 +406,13 @@ pub async fn get_messages(
 }
 
 /// Save a new message.
-/// POST /api/messages
+#[utoipa::path(
+    post,
+    path = "/messages",
+    tag = "conversations",
+    request_body = SaveMessageRequest,
+    responses((status = 200, description = "ID of the new message", body = i64))
+)]
 pub async fn save_message(
     State(state): State<AppState>,
     Json(req): Json<SaveMessageRequest>,
@@ -287,6 +420,21 @@ pub async fn save_message(
     let role = MessageRole::parse(&req.role)
         .ok_or_else(|| HttpError::BadRequest(format!("Invalid message role: {}", req.role)))?;
 
+    // Check the attachments exist before saving so a bad ID cannot leave
+    // a message behind without them.
+    if !req.attachment_ids.is_empty() {
+        // Attachment files live on disk, which an incognito chat must not touch.
+        if ChatHistoryService::is_incognito(req.conversation_id) {
+            return Err(HttpError::BadRequest(
+                "Incognito conversations cannot have attachments".to_string(),
+            ));
+        }
+        let attachments = attachments(&state)?;
+        for &attachment_id in &req.attachment_ids {
+            attachments.get(attachment_id).await?;
+        }
+    }
+
     let id = state
         .core
         .chat_history()
@@ -297,11 +445,24 @@ pub async fn save_message(
             metadata: req.metadata,
         })
         .await?;
+
+    if !req.attachment_ids.is_empty() {
+        attachments(&state)?
+            .link_to_message(id, &req.attachment_ids)
+            .await?;
+    }
     Ok(Json(id))
 }
 
 /// Update a message's content.
-/// PUT /api/messages/:id
+#[utoipa::path(
+    put,
+    path = "/messages/{id}",
+    tag = "conversations",
+    params(("id" = i64, Path, description = "Message ID")),
+    request_body = UpdateMessageRequest,
+    responses((status = 200, description = "Done"))
+)]
 pub async fn update_message(
     State(state): State<AppState>,
     Path(id): Path<i64>,
@@ -316,7 +477,13 @@ pub async fn update_message(
 }
 
 /// Delete a message and all subsequent messages in the conversation.
-/// DELETE /api/messages/:id
+#[utoipa::path(
+    delete,
+    path = "/messages/{id}",
+    tag = "conversations",
+    params(("id" = i64, Path, description = "Message ID")),
+    responses((status = 200, description = "Number of messages deleted", body = i64))
+)]
 pub async fn delete_message(
     State(state): State<AppState>,
     Path(id): Path<i64>,
@@ -329,12 +496,112 @@ pub async fn delete_message(
     Ok(Json(deleted_count))
 }
 
+/// Store a regenerated assistant reply in place of an earlier one.
+#[utoipa::path(
+    post,
+    path = "/messages/{id}/regenerate",
+    tag = "conversations",
+    params(("id" = i64, Path, description = "Message ID")),
+    request_body = UpdateMessageRequest,
+    responses((status = 200, body = MessageRevision))
+)]
+pub async fn regenerate_message(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+    Json(req): Json<UpdateMessageRequest>,
+) -> Result<Json<MessageRevision>, HttpError> {
+    let revision = state
+        .core
+        .chat_history()
+        .regenerate_message(id, req.content, req.metadata)
+        .await?;
+    Ok(Json(revision))
+}
+
+/// Store an edited user message and invalidate everything after it.
+#[utoipa::path(
+    post,
+    path = "/messages/{id}/edit",
+    tag = "conversations",
+    params(("id" = i64, Path, description = "Message ID")),
+    request_body = UpdateMessageRequest,
+    responses((status = 200, body = MessageRevision))
+)]
+pub async fn edit_message(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+    Json(req): Json<UpdateMessageRequest>,
+) -> Result<Json<MessageRevision>, HttpError> {
+    let revision = state
+        .core
+        .chat_history()
+        .edit_message(id, req.content, req.metadata)
+        .await?;
+    Ok(Json(revision))
+}
+
+/// List every version of a message, oldest first.
+#[utoipa::path(
+    get,
+    path = "/messages/{id}/versions",
+    tag = "conversations",
+    params(("id" = i64, Path, description = "Message ID")),
+    responses((status = 200, body = Vec<Message>))
+)]
+pub async fn get_message_versions(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+) -> Result<Json<Vec<Message>>, HttpError> {
+    let versions = state.core.chat_history().get_message_versions(id).await?;
+    Ok(Json(versions))
+}
+
 // ─────────────────────────────────────────────────────────────────────────────
 // Chat Proxy Handler
 // ─────────────────────────────────────────────────────────────────────────────
 
 use crate::handlers::port_utils::validate_port;
 
+/// Resolve `attachment` content parts, leaving other content untouched.
+async fn resolve_attachments(
+    state: &AppState,
+    content: MessageContent,
+) -> Result<MessageContent, HttpError> {
+    if content.attachment_ids().is_empty() {
+        return Ok(content);
+    }
+    Ok(attachments(state)?.resolve_content(content).await?)
+}
+
+/// Add project context to the system prompt, creating one when the
+/// conversation has none.
+fn inject_project_context(messages: &mut Vec<ChatMessage>, context: String) {
+    if let Some(first) = messages.first_mut()
+        && first.role == "system"
+    {
+        match &mut first.content {
+            Some(MessageContent::Text(text)) => {
+                text.push_str("\n\n");
+                text.push_str(&context);
+            }
+            Some(MessageContent::Parts(parts)) => {
+                parts.push(serde_json::json!({"type": "text", "text": context}));
+            }
+            None => first.content = Some(MessageContent::Text(context)),
+        }
+        return;
+    }
+    messages.insert(
+        0,
+        ChatMessage {
+            role: "system".to_string(),
+            content: Some(MessageContent::Text(context)),
+            tool_call_id: None,
+            tool_calls: None,
+        },
+    );
+}
+
 /// Inject tools and tool_choice into the forwarded request body, gated on
 /// whether the model advertises `SUPPORTS_TOOL_CALLS`.
 ///
@@ -392,6 +659,12 @@ pub async fn proxy_chat(
     let servers = state.servers.list_servers().await;
     let server = servers.iter().find(|s| s.port == request.port);
 
+    // Chat goes straight to the server, bypassing the proxy's timing, so
+    // report it separately to keep the idle timeout from firing mid-chat.
+    if let Some(server) = server {
+        state.servers.mark_active(server.model_id);
+    }
+
     let (capabilities, model_defaults) = if let Some(server) = server {
         // Found the server, fetch the model to get its capabilities and inference_defaults
         match state.core.models().get_by_id(server.model_id).await {
@@ -433,6 +706,29 @@ pub async fn proxy_chat(
         (gglib_core::domain::ModelCapabilities::default(), None)
     };
 
+    // A continued conversation supplies its saved model and sampling.
+    let conversation = match request.conversation_id {
+        Some(id) => state
+            .core
+            .chat_history()
+            .get_conversation(id)
+            .await
+            .ok()
+            .flatten(),
+        None => None,
+    };
+    let saved = conversation.as_ref().and_then(|c| c.settings.as_ref());
+    let saved_sampling = saved.map(ConversationSettings::sampling);
+    let model_name = if request.model.is_empty() {
+        saved.and_then(|s| s.model_name.clone()).unwrap_or_default()
+    } else {
+        request.model.clone()
+    };
+    let warning = match &conversation {
+        Some(conversation) => model_warning(&state, conversation).await,
+        None => None,
+    };
+
     // Load global settings for inference defaults
     let global_defaults = state
         .core
@@ -442,8 +738,8 @@ pub async fn proxy_chat(
         .ok()
         .and_then(|s| s.inference_defaults);
 
-    // Resolve inference parameters using the 4-level hierarchy:
-    // Request → Model → Global → Hardcoded defaults
+    // Resolve inference parameters using the 5-level hierarchy:
+    // Request → Conversation → Model → Global → Hardcoded defaults
     let resolved = gglib_core::domain::InferenceConfig {
         temperature: request.temperature,
         top_p: request.top_p,
@@ -453,7 +749,11 @@ pub async fn proxy_chat(
         presence_penalty: request.presence_penalty,
         min_p: request.min_p,
     }
-    .resolve_with_defaults(model_defaults.as_ref(), global_defaults.as_ref());
+    .resolve_with_profile(
+        saved_sampling.as_ref(),
+        model_defaults.as_ref(),
+        global_defaults.as_ref(),
+    );
 
     tracing::debug!(
         port = request.port,
@@ -470,18 +770,18 @@ pub async fn proxy_chat(
     // Filter out messages with empty or whitespace-only content
     // EXCEPT: tool role messages (they return results) and assistant messages with tool_calls
     // This prevents Jinja template errors in llama-server
-    let valid_messages: Vec<_> = request
+    let mut valid_messages: Vec<_> = request
         .messages
         .into_iter()
         .filter(|m| {
             // Keep if content is non-empty
-            if let Some(content) = &m.content
-                && !content.trim().is_empty()
-            {
-                return true;
-            }
+            let has_content = match &m.content {
+                Some(MessageContent::Text(text)) => !text.trim().is_empty(),
+                Some(MessageContent::Parts(parts)) => !parts.is_empty(),
+                None => false,
+            };
             // Keep tool messages and messages with tool_calls even if content is empty/null
-            m.role == "tool" || m.tool_calls.is_some()
+            has_content || m.role == "tool" || m.tool_calls.is_some()
         })
         .collect();
 
@@ -491,6 +791,30 @@ pub async fn proxy_chat(
         ));
     }
 
+    // Swap attachment references for image/text parts the model can read.
+    for message in &mut valid_messages {
+        if let Some(content) = message.content.take() {
+            message.content = Some(resolve_attachments(&state, content).await?);
+        }
+    }
+
+    // A project that cannot be read should not block the chat itself.
+    if let Some(conversation_id) = request.conversation_id {
+        match state
+            .projects
+            .context_for_conversation(conversation_id)
+            .await
+        {
+            Ok(Some(context)) => inject_project_context(&mut valid_messages, context),
+            Ok(None) => {}
+            Err(e) => tracing::warn!(
+                conversation_id,
+                error = %e,
+                "Failed to load project context; sending chat without it"
+            ),
+        }
+    }
+
     // Convert to ChatMessage format and apply capability-aware transformations.
     //
     // `tool_call_id` travels in the core type's catch-all rather than being
@@ -506,7 +830,7 @@ pub async fn proxy_chat(
             }
             gglib_core::ChatMessage {
                 role: m.role,
-                content: m.content.map(gglib_core::MessageContent::Text),
+                content: m.content,
                 tool_calls: m.tool_calls.map(serde_json::Value::Array),
                 extra,
             }
@@ -520,7 +844,7 @@ pub async fn proxy_chat(
         .into_iter()
         .map(|mut m| ChatMessage {
             role: m.role,
-            content: m.content.map(|c| c.into_string()),
+            content: m.content,
             tool_calls: m.tool_calls.and_then(|v| {
                 if let serde_json::Value::Array(arr) = v {
                     Some(arr)
@@ -540,7 +864,7 @@ pub async fn proxy_chat(
 
     // Build the forwarded request body with resolved inference parameters
     let mut forward_body = serde_json::json!({
-        "model": request.model,
+        "model": model_name,
         "messages": final_messages,
         "stream": request.stream,
         "max_tokens": resolved.max_tokens,
@@ -606,7 +930,7 @@ pub async fn proxy_chat(
         )));
     }
 
-    if request.stream {
+    let mut response = if request.stream {
         // Streaming mode: pass through SSE stream unchanged
         let stream = response
             .bytes_stream()
@@ -614,22 +938,29 @@ pub async fn proxy_chat(
 
         let body = Body::from_stream(stream);
 
-        Ok(Response::builder()
+        Response::builder()
             .status(StatusCode::OK)
             .header(header::CONTENT_TYPE, "text/event-stream")
             .header(header::CACHE_CONTROL, "no-cache")
             .header(header::CONNECTION, "keep-alive")
             .body(body)
             .unwrap()
-            .into_response())
+            .into_response()
     } else {
         // Non-streaming mode: parse and return JSON
         let completion: ChatCompletionResponse = response.json().await.map_err(|e| {
             HttpError::Internal(format!("Failed to parse llama-server response: {}", e))
         })?;
 
-        Ok(Json(completion).into_response())
+        Json(completion).into_response()
+    };
+
+    // Header values must be visible ASCII; a model name outside that range
+    // drops the header rather than failing the chat.
+    if let Some(value) = warning.and_then(|w| header::HeaderValue::from_str(&w.message).ok()) {
+        response.headers_mut().insert(MODEL_WARNING_HEADER, value);
     }
+    Ok(response)
 }
 
 // ─────────────────────────────────────────────────────────────────────────────
@@ -710,6 +1041,33 @@ mod tests {
     /// JSON-boundary tests for `UpdateConversationRequest.system_prompt`,
     /// mirroring the coverage added for `UpdateModelRequest.server_defaults`
     /// and `UpdateSettingsRequest`. Deserializes raw JSON to prove
+    fn message(role: &str, text: &str) -> ChatMessage {
+        ChatMessage {
+            role: role.to_string(),
+            content: Some(MessageContent::Text(text.to_string())),
+            tool_call_id: None,
+            tool_calls: None,
+        }
+    }
+
+    #[test]
+    fn project_context_extends_existing_system_prompt() {
+        let mut messages = vec![message("system", "Be brief."), message("user", "Hi")];
+        inject_project_context(&mut messages, "<file path=\"a.rs\">\n</file>".to_string());
+        assert_eq!(messages.len(), 2);
+        assert_eq!(
+            messages[0].content,
+            Some(MessageContent::Text(
+                "Be brief.\n\n<file path=\"a.rs\">\n</file>".to_string()
+            ))
+        );
+
+        let mut messages = vec![message("user", "Hi")];
+        inject_project_context(&mut messages, "ctx".to_string());
+        assert_eq!(messages[0].role, "system");
+        assert_eq!(messages[1].role, "user");
+    }
+
     /// `serde_with::rust::double_option` distinguishes an omitted key from
     /// an explicit `null` — without it, `PUT /api/conversations/:id` with
     /// `{"system_prompt": null}` (the frontend's "clear system prompt"
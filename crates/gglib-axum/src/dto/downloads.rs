@@ -0,0 +1,31 @@
+//! Download queue control DTOs.
+
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// Response from retrying a failed download.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct RetryDownloadResponse {
+    /// 1-based position in the queue; 1 is downloading now.
+    pub position: u32,
+}
+
+/// How many downloads the queue holds, pending and active together.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct QueueSizeDto {
+    /// Maximum number of queued downloads (at least 1).
+    pub max_size: u32,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn queue_size_round_trips_with_snapshot_field_name() {
+        let dto: QueueSizeDto =
+            serde_json::from_value(serde_json::json!({ "max_size": 4 })).unwrap();
+        assert_eq!(dto.max_size, 4);
+        assert_eq!(serde_json::to_value(&dto).unwrap()["max_size"], 4);
+    }
+}
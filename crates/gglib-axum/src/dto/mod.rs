@@ -1,4 +1,6 @@
 #![doc = include_str!("README.md")]
+pub mod downloads;
 pub mod system;
 
+pub use downloads::{QueueSizeDto, RetryDownloadResponse};
 pub use system::SystemMemoryInfoDto;
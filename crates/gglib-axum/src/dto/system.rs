@@ -3,12 +3,13 @@
 use gglib_core::utils::system::SystemMemoryInfo;
 use gglib_runtime::llama::{MissingPackage, VulkanStatus};
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
 /// System memory information DTO for HTTP API.
 ///
 /// This DTO ensures stable JSON field names (camelCase) for frontend consumption.
 /// Uses the memory field most useful for model fit calculations.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct SystemMemoryInfoDto {
     /// Total system RAM in bytes.
@@ -96,7 +97,7 @@ mod tests {
 ///
 /// Mirrors [`VulkanStatus`] from `gglib-runtime` with stable camelCase
 /// JSON field names for frontend consumption.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct VulkanStatusDto {
     /// Vulkan runtime loader is present and working.
@@ -128,7 +129,7 @@ impl From<VulkanStatus> for VulkanStatusDto {
 }
 
 /// A missing Vulkan build component with install hints.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct MissingPackageDto {
     /// Machine-readable identifier for the missing component.
@@ -163,7 +164,7 @@ impl From<MissingPackage> for MissingPackageDto {
 }
 
 /// Distro-specific install command.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct InstallHintDto {
     /// Distribution label (e.g. "Ubuntu/Debian", "Arch").
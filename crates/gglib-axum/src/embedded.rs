@@ -91,12 +91,14 @@ use axum::{
     response::Response,
     routing::get,
 };
+use subtle::ConstantTimeEq;
 use tokio::task::JoinHandle;
 use tower_http::cors::CorsLayer;
 
 use crate::{
+    api_key::VerifiedApiKey,
     bootstrap::AxumContext,
-    routes::{api_routes, health_check},
+    routes::{api_routes, health_check, nest_api, readiness_check},
     state::AppState,
 };
 
@@ -156,7 +158,7 @@ pub fn default_embedded_cors_origins() -> Vec<String> {
 /// - Binds to `127.0.0.1:0` (ephemeral port, localhost only)
 /// - Generates a random UUID token for Bearer authentication
 /// - All `/api/*` endpoints require `Authorization: Bearer {token}` header
-/// - `/health` endpoint is unauthenticated
+/// - `/health`, `/healthz` and `/readyz` are unauthenticated
 /// - CORS restricted to `cors_origins` only
 ///
 /// # Example
@@ -178,10 +180,10 @@ pub async fn start_embedded_server(
 ) -> Result<(EmbeddedApiInfo, JoinHandle<()>), Box<dyn std::error::Error + Send + Sync>> {
     let state: AppState = Arc::new(ctx);
 
-    // Generate token and store full "Bearer <token>" for middleware
-    // This avoids per-request format!() allocation in the auth check
+    // Generate token; the middleware shares it rather than copying it
+    // per request
     let token: String = uuid::Uuid::new_v4().to_string();
-    let expected_header: Arc<str> = Arc::from(format!("Bearer {}", token));
+    let expected_token: Arc<str> = Arc::from(token.as_str());
 
     // Log token info based on build profile
     #[cfg(debug_assertions)]
@@ -203,15 +205,16 @@ pub async fn start_embedded_server(
     let cors = build_cors(&cfg.cors_origins)?;
 
     let auth_layer = middleware::from_fn(move |req: Request, next: Next| {
-        let expected = expected_header.clone();
+        let expected = expected_token.clone();
         async move { validate_bearer(expected, req, next).await }
     });
 
     // IMPORTANT: apply auth + CORS only on /api
     let app = Router::new()
         .route("/health", get(health_check))
-        .nest("/api", api_routes().route_layer(auth_layer).layer(cors))
-        .with_state(state);
+        .route("/healthz", get(health_check))
+        .route("/readyz", get(readiness_check));
+    let app = nest_api(app, api_routes().route_layer(auth_layer).layer(cors)).with_state(state);
 
     let listener = tokio::net::TcpListener::bind(SocketAddr::from(([127, 0, 0, 1], 0))).await?;
     let port = listener.local_addr()?.port();
@@ -235,36 +238,35 @@ pub async fn start_embedded_server(
 ///
 /// Requires `Authorization: Bearer {token}` header.
 /// Returns 401 Unauthorized with `WWW-Authenticate: Bearer` on failure.
-///
-/// # Performance
-///
-/// The `expected` parameter contains the full "Bearer <token>" string,
-/// so we can do a direct string comparison without allocating.
+/// The token is compared in constant time, and an accepted request carries
+/// it as a [`VerifiedApiKey`] for the layers inside.
 async fn validate_bearer(
     expected: Arc<str>,
-    req: Request,
+    mut req: Request,
     next: Next,
 ) -> Result<Response, StatusCode> {
-    let auth = req
+    let valid = req
         .headers()
         .get(header::AUTHORIZATION)
-        .and_then(|v| v.to_str().ok());
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.strip_prefix("Bearer "))
+        .is_some_and(|token| bool::from(token.as_bytes().ct_eq(expected.as_bytes())));
 
-    match auth {
-        Some(h) if h == expected.as_ref() => Ok(next.run(req).await),
-        _ => {
-            tracing::warn!(
-                path = %req.uri().path(),
-                "Unauthorized API request - missing or invalid token"
-            );
-            let mut res = Response::new(axum::body::Body::empty());
-            *res.status_mut() = StatusCode::UNAUTHORIZED;
-            res.headers_mut().insert(
-                header::WWW_AUTHENTICATE,
-                header::HeaderValue::from_static("Bearer"),
-            );
-            Ok(res)
-        }
+    if valid {
+        req.extensions_mut().insert(VerifiedApiKey(expected));
+        Ok(next.run(req).await)
+    } else {
+        tracing::warn!(
+            path = %req.uri().path(),
+            "Unauthorized API request - missing or invalid token"
+        );
+        let mut res = Response::new(axum::body::Body::empty());
+        *res.status_mut() = StatusCode::UNAUTHORIZED;
+        res.headers_mut().insert(
+            header::WWW_AUTHENTICATE,
+            header::HeaderValue::from_static("Bearer"),
+        );
+        Ok(res)
     }
 }
 
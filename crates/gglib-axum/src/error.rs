@@ -6,10 +6,12 @@
 use axum::http::StatusCode;
 use axum::response::{IntoResponse, Response};
 use gglib_app_services::GuiError;
+use gglib_core::ports::AttachmentError;
 use gglib_core::ports::chat_history::ChatHistoryError;
 use gglib_core::{CoreError, ProcessError, RepositoryError};
 use serde::Serialize;
 use thiserror::Error;
+use utoipa::ToSchema;
 
 /// Axum-specific error type.
 #[derive(Debug, Error)]
@@ -22,6 +24,14 @@ pub enum HttpError {
     #[error("Bad request: {0}")]
     BadRequest(String),
 
+    /// Missing or wrong credentials (401, with `WWW-Authenticate: Bearer`).
+    #[error("Unauthorized: {0}")]
+    Unauthorized(String),
+
+    /// The endpoint is turned off by configuration (403).
+    #[error("Forbidden: {0}")]
+    Forbidden(String),
+
     /// Conflict (resource already exists).
     #[error("Conflict: {0}")]
     Conflict(String),
@@ -50,9 +60,11 @@ pub enum HttpError {
 }
 
 /// JSON error response body.
-#[derive(Serialize)]
-struct ErrorBody {
+#[derive(Serialize, ToSchema)]
+pub(crate) struct ErrorBody {
+    /// Human-readable message
     error: String,
+    /// HTTP status code, repeated for clients that only see the body
     status: u16,
     /// Stable error type discriminant for client-side handling
     #[serde(skip_serializing_if = "Option::is_none", rename = "type")]
@@ -67,6 +79,8 @@ impl IntoResponse for HttpError {
         let (status, message, error_type, metadata) = match &self {
             HttpError::NotFound(msg) => (StatusCode::NOT_FOUND, msg.clone(), None, None),
             HttpError::BadRequest(msg) => (StatusCode::BAD_REQUEST, msg.clone(), None, None),
+            HttpError::Unauthorized(msg) => (StatusCode::UNAUTHORIZED, msg.clone(), None, None),
+            HttpError::Forbidden(msg) => (StatusCode::FORBIDDEN, msg.clone(), None, None),
             HttpError::Conflict(msg) => (StatusCode::CONFLICT, msg.clone(), None, None),
             HttpError::ServiceUnavailable(msg) => {
                 (StatusCode::SERVICE_UNAVAILABLE, msg.clone(), None, None)
@@ -106,7 +120,14 @@ impl IntoResponse for HttpError {
             metadata,
         };
 
-        (status, axum::Json(body)).into_response()
+        let mut response = (status, axum::Json(body)).into_response();
+        if status == StatusCode::UNAUTHORIZED {
+            response.headers_mut().insert(
+                axum::http::header::WWW_AUTHENTICATE,
+                axum::http::HeaderValue::from_static("Bearer"),
+            );
+        }
+        response
     }
 }
 
@@ -134,6 +155,7 @@ impl From<RepositoryError> for HttpError {
                 HttpError::Internal(format!("Serialization: {}", msg))
             }
             RepositoryError::Constraint(msg) => HttpError::BadRequest(msg),
+            RepositoryError::Conflict(msg) => HttpError::Conflict(msg),
         }
     }
 }
@@ -194,9 +216,32 @@ impl From<ChatHistoryError> for HttpError {
             ChatHistoryError::InvalidRole(role) => {
                 HttpError::BadRequest(format!("Invalid message role: {}", role))
             }
+            err @ ChatHistoryError::UnexpectedRole { .. } => HttpError::BadRequest(err.to_string()),
+            err @ ChatHistoryError::MessageSuperseded(_) => HttpError::Conflict(err.to_string()),
             ChatHistoryError::Database(msg) => {
                 HttpError::Internal(format!("Database error: {}", msg))
             }
+            err @ ChatHistoryError::Encryption(_) => HttpError::Internal(err.to_string()),
+            err @ ChatHistoryError::NotShareable(_) => HttpError::BadRequest(err.to_string()),
+            err @ ChatHistoryError::ShareNotFound => HttpError::NotFound(err.to_string()),
+        }
+    }
+}
+
+impl From<AttachmentError> for HttpError {
+    fn from(err: AttachmentError) -> Self {
+        match err {
+            AttachmentError::NotFound(id) => {
+                HttpError::NotFound(format!("Attachment not found: {}", id))
+            }
+            err @ (AttachmentError::TooLarge { .. } | AttachmentError::UnsupportedType(_)) => {
+                HttpError::BadRequest(err.to_string())
+            }
+            AttachmentError::Unavailable => HttpError::ServiceUnavailable(err.to_string()),
+            AttachmentError::Storage(msg) => HttpError::Internal(format!("Storage: {}", msg)),
+            AttachmentError::Database(msg) => {
+                HttpError::Internal(format!("Database error: {}", msg))
+            }
         }
     }
 }
@@ -22,6 +22,7 @@ use gglib_core::AGENT_EVENT_CHANNEL_CAPACITY;
 use gglib_core::domain::agent::{AgentConfig, AgentEvent};
 use gglib_core::ports::AgentError;
 use gglib_core::request_pipeline;
+use gglib_mcp::UrlFetcher;
 use gglib_runtime::compose_agent_loop;
 
 use guard::AgentTaskGuard;
@@ -81,6 +82,16 @@ pub async fn chat(
     let tool_filter: Option<HashSet<String>> = req.tool_filter.map(|f| f.into_iter().collect());
     let model_context =
         request_pipeline::resolve(state.catalog.as_ref(), req.model.as_deref()).await;
+    // Web tools are opt-in; an unreadable settings row just leaves them off.
+    let settings = state
+        .core
+        .settings()
+        .get_resolved()
+        .await
+        .unwrap_or_default();
+    let url_fetch = settings
+        .url_fetch
+        .map(|config| UrlFetcher::new(config).with_history(&req.messages));
     let agent_loop = compose_agent_loop(
         format!("http://127.0.0.1:{}", req.port),
         state.http_client.clone(),
@@ -91,6 +102,8 @@ pub async fn chat(
         // GUI chat runs in the same process as the embedded proxy; report its
         // reuse to the shared agent-path store behind `agent_usage`.
         Some(state.proxy.agent_metrics()),
+        settings.web_search,
+        url_fetch,
     );
 
     let messages = req.messages;
@@ -0,0 +1,138 @@
+//! Chat attachment handlers - upload, download and delete attachments.
+//!
+//! `POST /api/attachments?name=photo.png` takes the raw file as the request
+//! body, typed by its `Content-Type` header, and returns the stored
+//! [`Attachment`]. Reference it from a message with an `attachment` content
+//! part (see [`gglib_core::domain::attachment`]) or pass its ID in
+//! `attachment_ids` when saving the message.
+
+use axum::Json;
+use axum::body::Bytes;
+use axum::extract::{Path, Query, State};
+use axum::http::{HeaderMap, header};
+use axum::response::IntoResponse;
+use serde::Deserialize;
+use utoipa::IntoParams;
+
+use crate::error::HttpError;
+use crate::state::AppState;
+use gglib_core::domain::attachment::{Attachment, MAX_ATTACHMENT_SIZE_MB};
+use gglib_core::services::AttachmentService;
+
+/// Request body cap for uploads; the configured limit is checked separately.
+pub const MAX_UPLOAD_BODY_BYTES: usize = MAX_ATTACHMENT_SIZE_MB as usize * 1024 * 1024;
+
+/// MIME type assumed when an upload has no `Content-Type` header.
+const FALLBACK_MIME_TYPE: &str = "application/octet-stream";
+
+/// Query parameters for an upload.
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct UploadQuery {
+    /// Original file name.
+    pub name: String,
+}
+
+/// The attachment service, or 503 when the server was built without one.
+pub(crate) fn attachments(state: &AppState) -> Result<&AttachmentService, HttpError> {
+    state
+        .core
+        .attachments()
+        .ok_or_else(|| HttpError::ServiceUnavailable("Attachments are not available".into()))
+}
+
+/// Upload an attachment.
+#[utoipa::path(
+    post,
+    path = "/attachments",
+    tag = "attachments",
+    params(UploadQuery),
+    request_body(content = Vec<u8>, description = "The raw file, typed by `Content-Type`", content_type = "*/*"),
+    responses((status = 200, body = Attachment))
+)]
+pub async fn upload(
+    State(state): State<AppState>,
+    Query(query): Query<UploadQuery>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> Result<Json<Attachment>, HttpError> {
+    let mime_type = headers
+        .get(header::CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or(FALLBACK_MIME_TYPE);
+    let attachment = attachments(&state)?
+        .upload(&query.name, mime_type, &body)
+        .await?;
+    Ok(Json(attachment))
+}
+
+/// Get attachment metadata.
+#[utoipa::path(
+    get,
+    path = "/attachments/{id}",
+    tag = "attachments",
+    params(("id" = i64, Path, description = "Attachment ID")),
+    responses((status = 200, body = Attachment))
+)]
+pub async fn get(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+) -> Result<Json<Attachment>, HttpError> {
+    Ok(Json(attachments(&state)?.get(id).await?))
+}
+
+/// Download an attachment's contents.
+#[utoipa::path(
+    get,
+    path = "/attachments/{id}/content",
+    tag = "attachments",
+    params(("id" = i64, Path, description = "Attachment ID")),
+    responses((status = 200, description = "The file, with its stored `Content-Type`", content_type = "application/octet-stream"))
+)]
+pub async fn content(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+) -> Result<impl IntoResponse, HttpError> {
+    let (attachment, bytes) = attachments(&state)?.read(id).await?;
+    let disposition = format!(
+        "inline; filename=\"{}\"",
+        attachment.file_name.replace(['"', '\\'], "_")
+    );
+    Ok((
+        [
+            (header::CONTENT_TYPE, attachment.mime_type),
+            (header::CONTENT_DISPOSITION, disposition),
+        ],
+        bytes,
+    ))
+}
+
+/// Delete an attachment.
+#[utoipa::path(
+    delete,
+    path = "/attachments/{id}",
+    tag = "attachments",
+    params(("id" = i64, Path, description = "Attachment ID")),
+    responses((status = 200, description = "Done"))
+)]
+pub async fn delete(State(state): State<AppState>, Path(id): Path<i64>) -> Result<(), HttpError> {
+    attachments(&state)?.delete(id).await?;
+    Ok(())
+}
+
+/// List the attachments of a message.
+#[utoipa::path(
+    get,
+    path = "/messages/{id}/attachments",
+    tag = "attachments",
+    params(("id" = i64, Path, description = "Message ID")),
+    responses((status = 200, body = Vec<Attachment>))
+)]
+pub async fn list_for_message(
+    State(state): State<AppState>,
+    Path(message_id): Path<i64>,
+) -> Result<Json<Vec<Attachment>>, HttpError> {
+    Ok(Json(
+        attachments(&state)?.list_for_message(message_id).await?,
+    ))
+}
@@ -0,0 +1,55 @@
+//! Audit log handler - who changed what on a shared instance.
+//!
+//! Entries are written by the [`crate::audit`] middleware; this endpoint
+//! only reads them back.
+
+use axum::Json;
+use axum::extract::{Query, State};
+use chrono::{Duration, Utc};
+use serde::Deserialize;
+use utoipa::IntoParams;
+
+use crate::error::HttpError;
+use crate::state::AppState;
+use gglib_core::domain::audit::{AuditAction, AuditEntry, AuditFilter};
+
+/// Query parameters for `GET /api/admin/audit`.
+#[derive(Debug, Default, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct AuditQuery {
+    /// Only entries for this action.
+    pub action: Option<AuditAction>,
+    /// Only entries whose actor contains this text (case-insensitive).
+    pub actor: Option<String>,
+    /// Only entries from the last `days` days.
+    pub days: Option<u32>,
+    /// At most this many entries; 100 when omitted.
+    pub limit: Option<u32>,
+}
+
+/// Recorded administrative actions, newest first.
+#[utoipa::path(
+    get,
+    path = "/admin/audit",
+    tag = "admin",
+    params(AuditQuery),
+    responses((status = 200, body = Vec<AuditEntry>))
+)]
+pub async fn list(
+    State(state): State<AppState>,
+    Query(query): Query<AuditQuery>,
+) -> Result<Json<Vec<AuditEntry>>, HttpError> {
+    let audit = state
+        .core
+        .audit()
+        .ok_or_else(|| HttpError::ServiceUnavailable("Audit log not available".into()))?;
+    let filter = AuditFilter {
+        action: query.action,
+        actor: query.actor,
+        since: query
+            .days
+            .map(|days| Utc::now() - Duration::days(i64::from(days))),
+        limit: query.limit,
+    };
+    Ok(Json(audit.list(&filter).await?))
+}
@@ -0,0 +1,55 @@
+//! Backup handlers - pushing to and restoring from the S3-compatible bucket
+//! configured in settings.
+//!
+//! Transfers report progress through `backup:progress` events. A restored
+//! database is staged and takes effect the next time the server starts.
+
+use axum::Json;
+use axum::extract::State;
+
+use crate::error::HttpError;
+use crate::state::AppState;
+use gglib_app_services::types::{
+    BackupListing, BackupPushRequest, BackupPushResult, BackupRestoreRequest, BackupRestoreResult,
+};
+
+/// List database backups and model files in the bucket.
+#[utoipa::path(
+    get,
+    path = "/backup",
+    tag = "backup",
+    responses((status = 200, body = BackupListing))
+)]
+pub async fn list(State(state): State<AppState>) -> Result<Json<BackupListing>, HttpError> {
+    Ok(Json(state.backup.list().await?))
+}
+
+/// Back up the database and, optionally, model files.
+#[utoipa::path(
+    post,
+    path = "/backup/push",
+    tag = "backup",
+    request_body = BackupPushRequest,
+    responses((status = 200, body = BackupPushResult))
+)]
+pub async fn push(
+    State(state): State<AppState>,
+    Json(req): Json<BackupPushRequest>,
+) -> Result<Json<BackupPushResult>, HttpError> {
+    Ok(Json(state.backup.push(req).await?))
+}
+
+/// Restore a database backup and/or model files.
+#[utoipa::path(
+    post,
+    path = "/backup/restore",
+    tag = "backup",
+    request_body = BackupRestoreRequest,
+    responses((status = 200, body = BackupRestoreResult))
+)]
+pub async fn restore(
+    State(state): State<AppState>,
+    Json(req): Json<BackupRestoreRequest>,
+) -> Result<Json<BackupRestoreResult>, HttpError> {
+    Ok(Json(state.backup.restore(req).await?))
+}
@@ -9,6 +9,12 @@ use gglib_mcp::BuiltinToolExecutorAdapter;
 /// The frontend uses this endpoint to register built-in tools into the
 /// `ToolRegistry` under the `"builtin"` source, replacing the stale
 /// TypeScript-defined list.  No state is needed — built-ins are static.
+#[utoipa::path(
+    get,
+    path = "/builtin/tools",
+    tag = "mcp",
+    responses((status = 200, body = Vec<McpTool>))
+)]
 pub async fn list_builtin_tools() -> Json<Vec<McpTool>> {
     Json(BuiltinToolExecutorAdapter::bare_definitions())
 }
@@ -0,0 +1,58 @@
+//! Onboarding handlers - first-run step status, execution and skipping.
+
+use axum::Json;
+use axum::extract::{Path, State};
+
+use crate::error::HttpError;
+use crate::state::AppState;
+use gglib_app_services::OnboardingStepRequest;
+use gglib_core::domain::onboarding::{OnboardingStatus, OnboardingStep};
+
+/// Observed status of every onboarding step.
+#[utoipa::path(
+    get,
+    path = "/config/system/onboarding",
+    tag = "system",
+    operation_id = "onboarding_status",
+    responses((status = 200, body = OnboardingStatus))
+)]
+pub async fn status(State(state): State<AppState>) -> Result<Json<OnboardingStatus>, HttpError> {
+    Ok(Json(state.onboarding.status().await?))
+}
+
+/// Execute one step. Progress is streamed as `onboarding:progress` events.
+#[utoipa::path(
+    post,
+    path = "/config/system/onboarding/{step}",
+    tag = "system",
+    params(("step" = OnboardingStep, Path, description = "Onboarding step")),
+    request_body = Option<OnboardingStepRequest>,
+    responses((status = 200, body = OnboardingStatus))
+)]
+pub async fn run_step(
+    State(state): State<AppState>,
+    Path(step): Path<OnboardingStep>,
+    Json(request): Json<Option<OnboardingStepRequest>>,
+) -> Result<Json<OnboardingStatus>, HttpError> {
+    Ok(Json(
+        state
+            .onboarding
+            .run_step(step, request.unwrap_or_default())
+            .await?,
+    ))
+}
+
+/// Skip one step so it no longer blocks completion.
+#[utoipa::path(
+    post,
+    path = "/config/system/onboarding/{step}/skip",
+    tag = "system",
+    params(("step" = OnboardingStep, Path, description = "Onboarding step")),
+    responses((status = 200, body = OnboardingStatus))
+)]
+pub async fn skip_step(
+    State(state): State<AppState>,
+    Path(step): Path<OnboardingStep>,
+) -> Result<Json<OnboardingStatus>, HttpError> {
+    Ok(Json(state.onboarding.skip(step).await?))
+}
@@ -1,7 +1,15 @@
 //! Settings handlers - application configuration.
+//!
+//! Settings responses carry the settings version as an `ETag`. A `PUT` with
+//! `If-Match` (or `expectedVersion` in the body) is only applied if the
+//! settings are still at that version, and fails with `409 Conflict`
+//! otherwise, so two clients editing at once cannot silently undo each
+//! other's changes.
 
 use axum::Json;
 use axum::extract::State;
+use axum::http::{HeaderMap, HeaderValue, header};
+use utoipa::ToSchema;
 
 use crate::dto::SystemMemoryInfoDto;
 use crate::error::HttpError;
@@ -9,16 +17,74 @@ use crate::state::AppState;
 use gglib_app_services::types::{AppSettings, ModelsDirectoryInfo, UpdateSettingsRequest};
 
 /// Get application settings.
-pub async fn get(State(state): State<AppState>) -> Result<Json<AppSettings>, HttpError> {
-    Ok(Json(state.settings.get().await?))
+#[utoipa::path(
+    get,
+    path = "/config/settings",
+    tag = "settings",
+    responses((status = 200, body = AppSettings, headers(("ETag" = String, description = "Settings version"))))
+)]
+pub async fn get(
+    State(state): State<AppState>,
+) -> Result<(HeaderMap, Json<AppSettings>), HttpError> {
+    let settings = state.settings.get().await?;
+    Ok((etag(settings.version), Json(settings)))
 }
 
 /// Update application settings.
+#[utoipa::path(
+    put,
+    path = "/config/settings",
+    tag = "settings",
+    params(("If-Match" = Option<String>, Header, description = "Only update if the settings are still at this `ETag`")),
+    request_body = UpdateSettingsRequest,
+    responses(
+        (status = 200, body = AppSettings, headers(("ETag" = String, description = "Settings version"))),
+        (status = 409, description = "The settings were changed since the given version")
+    )
+)]
 pub async fn update(
     State(state): State<AppState>,
-    Json(req): Json<UpdateSettingsRequest>,
-) -> Result<Json<AppSettings>, HttpError> {
-    Ok(Json(state.settings.update(req).await?))
+    headers: HeaderMap,
+    Json(mut req): Json<UpdateSettingsRequest>,
+) -> Result<(HeaderMap, Json<AppSettings>), HttpError> {
+    if let Some(version) = if_match_version(&headers)? {
+        if req.expected_version.is_some_and(|v| v != version) {
+            return Err(HttpError::BadRequest(
+                "If-Match and expectedVersion disagree".to_string(),
+            ));
+        }
+        req.expected_version = Some(version);
+    }
+    let settings = state.settings.update(req).await?;
+    Ok((etag(settings.version), Json(settings)))
+}
+
+/// Response headers carrying `version` as a strong `ETag`.
+fn etag(version: u64) -> HeaderMap {
+    let mut headers = HeaderMap::new();
+    if let Ok(value) = HeaderValue::from_str(&format!("\"{version}\"")) {
+        headers.insert(header::ETAG, value);
+    }
+    headers
+}
+
+/// The settings version named by `If-Match`, if any. `*` matches every
+/// version, so it makes the update unconditional.
+fn if_match_version(headers: &HeaderMap) -> Result<Option<u64>, HttpError> {
+    let Some(value) = headers.get(header::IF_MATCH) else {
+        return Ok(None);
+    };
+    let invalid = || HttpError::BadRequest("If-Match must be a settings ETag or *".to_string());
+    let value = value.to_str().map_err(|_| invalid())?.trim();
+    if value == "*" {
+        return Ok(None);
+    }
+    value
+        .strip_prefix('"')
+        .and_then(|v| v.strip_suffix('"'))
+        .and_then(|v| v.parse().ok())
+        .map(Some)
+        .ok_or_else(invalid)
 }
 
 /// Get system memory information.
@@ -26,6 +92,12 @@ pub async fn update(
 /// Returns null if memory information cannot be determined (probe failed,
 /// insufficient permissions, or suspiciously low values). Clients should
 /// treat null as "unknown" rather than an error.
+#[utoipa::path(
+    get,
+    path = "/config/system/memory",
+    tag = "system",
+    responses((status = 200, body = Option<SystemMemoryInfoDto>))
+)]
 pub async fn memory(
     State(state): State<AppState>,
 ) -> Result<Json<Option<SystemMemoryInfoDto>>, HttpError> {
@@ -34,6 +106,12 @@ pub async fn memory(
 }
 
 /// Get models directory information.
+#[utoipa::path(
+    get,
+    path = "/config/system/models-directory",
+    tag = "system",
+    responses((status = 200, body = ModelsDirectoryInfo))
+)]
 pub async fn models_directory(
     State(state): State<AppState>,
 ) -> Result<Json<ModelsDirectoryInfo>, HttpError> {
@@ -41,15 +119,43 @@ pub async fn models_directory(
 }
 
 /// Update request for models directory.
-#[derive(serde::Deserialize)]
+#[derive(serde::Deserialize, ToSchema)]
 pub struct UpdateModelsDirectoryRequest {
     pub path: String,
 }
 
 /// Update models directory.
+#[utoipa::path(
+    put,
+    path = "/config/system/models-directory",
+    tag = "system",
+    request_body = UpdateModelsDirectoryRequest,
+    responses((status = 200, body = ModelsDirectoryInfo))
+)]
 pub async fn update_models_directory(
     State(state): State<AppState>,
     Json(req): Json<UpdateModelsDirectoryRequest>,
 ) -> Result<Json<ModelsDirectoryInfo>, HttpError> {
     Ok(Json(state.settings.update_models_directory(req.path)?))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn if_match(value: &str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(header::IF_MATCH, HeaderValue::from_str(value).unwrap());
+        headers
+    }
+
+    #[test]
+    fn if_match_reads_the_etag_version() {
+        assert_eq!(if_match_version(&HeaderMap::new()).unwrap(), None);
+        assert_eq!(if_match_version(&if_match("\"42\"")).unwrap(), Some(42));
+        assert_eq!(if_match_version(&if_match("*")).unwrap(), None);
+        assert!(if_match_version(&if_match("42")).is_err());
+        assert!(if_match_version(&if_match("W/\"42\"")).is_err());
+        assert_eq!(etag(42)[header::ETAG], "\"42\"");
+    }
+}
@@ -3,27 +3,74 @@
 use std::convert::Infallible;
 
 use axum::Json;
-use axum::extract::State;
+use axum::extract::{Query, State};
 use axum::response::sse::{Event, Sse};
 use futures_util::StreamExt;
 use futures_util::stream::Stream;
 use serde::{Deserialize, Serialize};
+use tokio_util::sync::CancellationToken;
+use utoipa::{IntoParams, ToSchema};
 
 use crate::dto::system::VulkanStatusDto;
 use crate::error::HttpError;
 use crate::state::AppState;
+use gglib_app_services::WarmStartOps;
 use gglib_app_services::setup::SetupStatus;
+use gglib_core::domain::recommend::{ModelTask, RecommendationReport};
+use gglib_core::jobs::{JobHandle, JobKind, JobSpec};
 use gglib_core::paths::{llama_cpp_dir, llama_server_path};
 use gglib_runtime::llama::{
-    Acceleration, BuildEvent, detect_optimal_acceleration, run_llama_source_build, vulkan_status,
+    Acceleration, BuildEvent, BuildPhase, LlamaError, LlamaSource, detect_optimal_acceleration,
+    run_llama_source_build, vulkan_status,
 };
 
 /// Get the full system setup status for the first-run wizard.
+#[utoipa::path(
+    get,
+    path = "/config/system/setup-status",
+    tag = "system",
+    responses((status = 200, body = SetupStatus))
+)]
 pub async fn status(State(state): State<AppState>) -> Result<Json<SetupStatus>, HttpError> {
     Ok(Json(state.setup.get_status().await?))
 }
 
+/// Query parameters for [`recommendations`].
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct RecommendationsQuery {
+    /// Restrict to one task (`coding`, `chat`, `vision`, `embedding`).
+    pub task: Option<ModelTask>,
+    /// Maximum number of recommendations (default 5).
+    pub limit: Option<usize>,
+}
+
+/// Rank curated models for this machine's memory ("what can I run?").
+#[utoipa::path(
+    get,
+    path = "/config/system/recommendations",
+    tag = "system",
+    params(RecommendationsQuery),
+    responses((status = 200, body = RecommendationReport))
+)]
+pub async fn recommendations(
+    State(state): State<AppState>,
+    Query(query): Query<RecommendationsQuery>,
+) -> Json<RecommendationReport> {
+    Json(
+        state
+            .setup
+            .recommend_models(query.task, query.limit.unwrap_or(5)),
+    )
+}
+
 /// Get Vulkan build-readiness status.
+#[utoipa::path(
+    get,
+    path = "/config/system/vulkan-status",
+    tag = "system",
+    responses((status = 200, body = VulkanStatusDto))
+)]
 pub async fn vulkan_status_handler() -> Json<VulkanStatusDto> {
     Json(vulkan_status().into())
 }
@@ -34,11 +81,18 @@ pub async fn vulkan_status_handler() -> Json<VulkanStatusDto> {
 /// - `progress`: `{ "downloaded": <bytes>, "total": <bytes> }`
 /// - `complete`: `{}`
 /// - `error`: `{ "message": "<error>" }`
+#[utoipa::path(
+    post,
+    path = "/config/system/install-llama",
+    tag = "system",
+    responses((status = 200, description = "`progress`, `complete` and `error` events", content_type = "text/event-stream"))
+)]
 pub async fn install_llama(
     State(state): State<AppState>,
 ) -> Sse<impl Stream<Item = Result<Event, Infallible>> + Send + 'static> {
     let (tx, rx) = tokio::sync::mpsc::channel::<LlamaProgressEvent>(64);
     let setup = state.setup.clone();
+    let warm_start = state.warm_start.clone();
 
     tokio::spawn(async move {
         let tx_progress = tx.clone();
@@ -51,6 +105,7 @@ pub async fn install_llama(
         match setup.install_llama(callback).await {
             Ok(()) => {
                 let _ = tx.send(LlamaProgressEvent::Complete).await;
+                reconcile_startup_models(&warm_start).await;
             }
             Err(e) => {
                 let _ = tx
@@ -80,6 +135,12 @@ pub async fn install_llama(
 }
 
 /// Set up the Python fast-download helper environment.
+#[utoipa::path(
+    post,
+    path = "/config/system/setup-python",
+    tag = "system",
+    responses((status = 200, description = "Done"))
+)]
 pub async fn setup_python(State(state): State<AppState>) -> Result<Json<()>, HttpError> {
     state.setup.setup_python_env().await?;
     Ok(Json(()))
@@ -102,7 +163,7 @@ enum LlamaProgressEvent {
 }
 
 /// Optional request body for [`build_llama_from_source`].
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct BuildLlamaRequest {
     /// Acceleration backend override. If omitted, auto-detection is used.
@@ -119,10 +180,37 @@ pub struct BuildLlamaRequest {
 /// - `phase_completed`: `{ "type": "phase_completed", "phase": "<phase>" }`
 /// - `completed`: `{ "type": "completed", "version": "<ver>", "acceleration": "<accel>" }`
 /// - `failed`: `{ "type": "failed", "message": "<error>" }`
+/// - `cancelled`: `{ "type": "cancelled" }`
+///
+/// `progress` events also carry the `phase` they belong to and, while
+/// compiling, the cmake `target` being built. The build is tracked as a
+/// cancellable `llama_build` job; `POST /api/jobs/{id}/cancel` kills the
+/// running cmake process tree and ends the stream with `cancelled`.
+#[utoipa::path(
+    post,
+    path = "/config/system/build-llama-from-source",
+    tag = "system",
+    request_body = BuildLlamaRequest,
+    responses((status = 200, description = "Build phase, progress and log events", content_type = "text/event-stream"))
+)]
 pub async fn build_llama_from_source(
+    State(state): State<AppState>,
     Json(req): Json<BuildLlamaRequest>,
 ) -> Sse<impl Stream<Item = Result<Event, Infallible>> + Send + 'static> {
     let (tx, rx) = tokio::sync::mpsc::channel::<BuildEvent>(64);
+    let warm_start = state.warm_start.clone();
+
+    // Build events pass through a job tracker on their way to the client,
+    // so the build stays visible in `/api/jobs` even if the client leaves.
+    // Cancelling the job cancels the build.
+    let (build_tx, build_rx) = tokio::sync::mpsc::channel::<BuildEvent>(64);
+    let cancel = CancellationToken::new();
+    let job = state
+        .jobs
+        .manager()
+        .start(JobSpec::new(JobKind::LlamaBuild, "llama.cpp").cancellable());
+    tokio::spawn(track_build_job(job, build_rx, tx, cancel.clone()));
+    let tx = build_tx;
 
     tokio::spawn(async move {
         let llama_dir = match llama_cpp_dir() {
@@ -166,14 +254,26 @@ pub async fn build_llama_from_source(
             },
         };
 
-        if let Err(e) =
-            run_llama_source_build(acceleration, llama_dir, server_path, tx.clone()).await
+        match run_llama_source_build(
+            acceleration,
+            LlamaSource::recorded(),
+            llama_dir,
+            server_path,
+            tx.clone(),
+            cancel,
+        )
+        .await
         {
-            let _ = tx
-                .send(BuildEvent::Failed {
-                    message: e.to_string(),
-                })
-                .await;
+            Ok(()) => reconcile_startup_models(&warm_start).await,
+            // The pipeline has already reported `cancelled`.
+            Err(e) if LlamaError::is_cancellation(&e) => {}
+            Err(e) => {
+                let _ = tx
+                    .send(BuildEvent::Failed {
+                        message: e.to_string(),
+                    })
+                    .await;
+            }
         }
     });
 
@@ -186,6 +286,77 @@ pub async fn build_llama_from_source(
     )
 }
 
+/// How a tracked build ended, as reported by its last terminal event.
+enum BuildOutcome {
+    Completed,
+    Failed(String),
+    Cancelled,
+}
+
+/// Mirror build events into `job` while forwarding them to `client`, and
+/// pass a cancel of the job on to the build through `cancel`.
+///
+/// The job finishes when the build's sender is dropped, with the outcome of
+/// the last `completed`, `failed` or `cancelled` event.
+async fn track_build_job(
+    job: JobHandle,
+    mut events: tokio::sync::mpsc::Receiver<BuildEvent>,
+    client: tokio::sync::mpsc::Sender<BuildEvent>,
+    cancel: CancellationToken,
+) {
+    let mut outcome = None;
+    loop {
+        let event = tokio::select! {
+            event = events.recv() => match event {
+                Some(event) => event,
+                None => break,
+            },
+            () = job.cancelled(), if !cancel.is_cancelled() => {
+                cancel.cancel();
+                continue;
+            }
+        };
+        match &event {
+            BuildEvent::PhaseStarted { phase } => job.message(phase.label()),
+            BuildEvent::Progress {
+                phase,
+                current,
+                total,
+                target,
+            } => {
+                // The job's bar follows the compile; configure is quick.
+                if *phase == BuildPhase::Compile {
+                    job.progress(*current, Some(*total));
+                }
+                if let Some(target) = target {
+                    job.message(format!("{}: {target}", phase.label()));
+                }
+            }
+            BuildEvent::Completed { .. } => outcome = Some(BuildOutcome::Completed),
+            BuildEvent::Failed { message } => {
+                outcome = Some(BuildOutcome::Failed(message.clone()));
+            }
+            BuildEvent::Cancelled => outcome = Some(BuildOutcome::Cancelled),
+            BuildEvent::Log { .. } | BuildEvent::PhaseCompleted { .. } => {}
+        }
+        // A client that went away must not stop the build.
+        let _ = client.send(event).await;
+    }
+    match outcome {
+        Some(BuildOutcome::Completed) => job.complete(),
+        Some(BuildOutcome::Failed(message)) => job.fail(message),
+        Some(BuildOutcome::Cancelled) => job.cancelled_by_request(),
+        None => job.fail("build ended without a result"),
+    }
+}
+
+/// Relaunch running startup models on the freshly installed llama-server.
+async fn reconcile_startup_models(warm_start: &WarmStartOps) {
+    if let Err(e) = warm_start.reconcile().await {
+        tracing::warn!("Failed to reconcile startup models after llama.cpp upgrade: {e}");
+    }
+}
+
 fn build_event_to_sse(event: BuildEvent) -> Result<Event, Infallible> {
     let event_type = match &event {
         BuildEvent::PhaseStarted { .. } => "phase_started",
@@ -194,6 +365,7 @@ fn build_event_to_sse(event: BuildEvent) -> Result<Event, Infallible> {
         BuildEvent::PhaseCompleted { .. } => "phase_completed",
         BuildEvent::Completed { .. } => "completed",
         BuildEvent::Failed { .. } => "failed",
+        BuildEvent::Cancelled => "cancelled",
     };
     let data = serde_json::to_string(&event).unwrap_or_default();
     Ok(Event::default().event(event_type).data(data))
@@ -1,23 +1,97 @@
 //! SSE events handler - real-time event streaming.
 //!
-//! Streams application events (downloads, servers, etc.) to connected clients.
+//! Streams application events (downloads, servers, etc.) to connected clients,
+//! optionally only those of the topics the client asks for.
 
 use std::convert::Infallible;
 
-use axum::extract::State;
+use axum::extract::{Query, State};
 use axum::response::sse::{Event, Sse};
 use futures_util::stream::Stream;
+use gglib_core::events::EVENT_TOPICS;
+use serde::Deserialize;
+use utoipa::IntoParams;
 
+use crate::error::HttpError;
 use crate::state::AppState;
 
+/// Query parameters for `GET /api/events`.
+///
+/// ```text
+/// GET /api/events?topics=download,server
+/// ```
+#[derive(Debug, Default, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct EventsQuery {
+    /// Comma-separated event topics (the part of an event name before the
+    /// colon, e.g. `download`, `server`, `model`, `mcp`, `proxy`, `job`);
+    /// every topic when omitted.
+    pub topics: Option<String>,
+}
+
 /// SSE events stream endpoint.
 ///
 /// Clients connect to this endpoint to receive real-time updates about:
 /// - Download progress and completion
 /// - Server start/stop events
 /// - MCP server events
+///
+/// With `topics`, only events of those topics are sent.
+#[utoipa::path(
+    get,
+    path = "/events",
+    tag = "events",
+    params(EventsQuery),
+    responses(
+        (status = 200, description = "One `AppEvent` per event, named by its `type`", content_type = "text/event-stream"),
+        (status = 400, description = "Unknown topic")
+    )
+)]
 pub async fn stream(
     State(state): State<AppState>,
-) -> Sse<impl Stream<Item = Result<Event, Infallible>> + Send + 'static> {
-    state.sse.clone().subscribe()
+    Query(query): Query<EventsQuery>,
+) -> Result<Sse<impl Stream<Item = Result<Event, Infallible>> + Send + 'static>, HttpError> {
+    let topics = match query.topics.as_deref() {
+        None => EVENT_TOPICS.to_vec(),
+        Some(list) => parse_topics(list)?,
+    };
+    Ok(state.sse.subscribe_topics(&topics))
+}
+
+/// Split a comma-separated topic list, rejecting unknown topics.
+fn parse_topics(list: &str) -> Result<Vec<&str>, HttpError> {
+    let mut topics = Vec::new();
+    for topic in list.split(',').map(str::trim).filter(|t| !t.is_empty()) {
+        if !EVENT_TOPICS.contains(&topic) {
+            return Err(HttpError::BadRequest(format!(
+                "Unknown event topic '{topic}' (expected one of: {})",
+                EVENT_TOPICS.join(", ")
+            )));
+        }
+        topics.push(topic);
+    }
+    if topics.is_empty() {
+        return Err(HttpError::BadRequest(
+            "`topics` names no event topic".to_string(),
+        ));
+    }
+    Ok(topics)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn topic_lists_are_validated() {
+        assert_eq!(
+            parse_topics("download, server").unwrap(),
+            ["download", "server"]
+        );
+        assert!(matches!(
+            parse_topics("download,voice"),
+            Err(HttpError::BadRequest(msg)) if msg.contains("'voice'")
+        ));
+        assert!(parse_topics(" , ").is_err());
+    }
 }
@@ -0,0 +1,56 @@
+//! Job handlers - one view of downloads, verifications and builds in flight.
+//!
+//! Every change to a job is also pushed as a `job:updated` event, so clients
+//! list once and then follow the event stream.
+
+use axum::Json;
+use axum::extract::{Path, State};
+
+use crate::error::HttpError;
+use crate::state::AppState;
+use gglib_core::jobs::Job;
+
+/// List running and recently finished jobs, newest first.
+#[utoipa::path(
+    get,
+    path = "/jobs",
+    tag = "jobs",
+    responses((status = 200, body = Vec<Job>))
+)]
+pub async fn list(State(state): State<AppState>) -> Json<Vec<Job>> {
+    Json(state.jobs.list())
+}
+
+/// Get a single job.
+#[utoipa::path(
+    get,
+    path = "/jobs/{id}",
+    tag = "jobs",
+    params(("id" = String, Path, description = "Job ID")),
+    responses((status = 200, body = Job))
+)]
+pub async fn get(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> Result<Json<Job>, HttpError> {
+    Ok(Json(state.jobs.get(&id)?))
+}
+
+/// Ask a running job to stop.
+///
+/// Answers `409` for jobs that have finished or cannot be cancelled. The
+/// returned job is still `running`; its `job:updated` event reports
+/// `cancelled` once it has stopped.
+#[utoipa::path(
+    post,
+    path = "/jobs/{id}/cancel",
+    tag = "jobs",
+    params(("id" = String, Path, description = "Job ID")),
+    responses((status = 200, body = Job))
+)]
+pub async fn cancel(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> Result<Json<Job>, HttpError> {
+    Ok(Json(state.jobs.cancel(&id).await?))
+}
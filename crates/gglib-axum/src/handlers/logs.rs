@@ -0,0 +1,106 @@
+//! Application log handlers - query and follow the rotating log files.
+//!
+//! `GET /api/logs` takes the [`LogQuery`] fields as query parameters
+//! (`level`, `target`, `contains`, `since`, `until`, `file`, `before`,
+//! `after`, `limit`) and returns one [`LogPage`]. `GET /api/logs/stream`
+//! applies the same filters and pushes each new record over SSE.
+
+use std::convert::Infallible;
+use std::time::Duration;
+
+use axum::Json;
+use axum::extract::{Query, State};
+use axum::response::sse::{Event, KeepAlive, Sse};
+use futures_util::StreamExt;
+use futures_util::stream::{self, Stream};
+
+use crate::error::HttpError;
+use crate::state::AppState;
+use gglib_app_services::types::{LogFile, LogPage, LogQuery};
+
+/// How often the follow stream checks the log files for new records.
+const FOLLOW_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Query one page of application log records.
+#[utoipa::path(
+    get,
+    path = "/logs",
+    tag = "logs",
+    params(LogQuery),
+    responses((status = 200, body = LogPage))
+)]
+pub async fn query(
+    State(state): State<AppState>,
+    Query(query): Query<LogQuery>,
+) -> Result<Json<LogPage>, HttpError> {
+    Ok(Json(state.logs.query(query).await?))
+}
+
+/// List the application log files, oldest first.
+#[utoipa::path(
+    get,
+    path = "/logs/files",
+    tag = "logs",
+    responses((status = 200, body = Vec<LogFile>))
+)]
+pub async fn files(State(state): State<AppState>) -> Result<Json<Vec<LogFile>>, HttpError> {
+    Ok(Json(state.logs.list_files().await?))
+}
+
+/// Follow the application logs via SSE.
+///
+/// Starts at `after` when given, otherwise at the current end of the logs,
+/// and emits each matching record written from then on as a JSON event.
+#[utoipa::path(
+    get,
+    path = "/logs/stream",
+    tag = "logs",
+    params(LogQuery),
+    responses((status = 200, description = "One `LogRecord` per event", content_type = "text/event-stream"))
+)]
+pub async fn follow(
+    State(state): State<AppState>,
+    Query(mut query): Query<LogQuery>,
+) -> Result<Sse<impl Stream<Item = Result<Event, Infallible>> + Send + 'static>, HttpError> {
+    query.before = None;
+    if query.after.is_none() {
+        let tail = LogQuery {
+            limit: Some(1),
+            ..query.clone()
+        };
+        query.after = state.logs.query(tail).await?.latest;
+    }
+
+    let stream = stream::unfold((state, query), |(state, mut query)| async move {
+        tokio::time::sleep(FOLLOW_POLL_INTERVAL).await;
+        let events: Vec<Result<Event, Infallible>> = match state.logs.query(query.clone()).await {
+            Ok(page) => {
+                if page.latest.is_some() {
+                    query.after = page.latest;
+                }
+                page.records
+                    .iter()
+                    .filter_map(|record| match serde_json::to_string(record) {
+                        Ok(json) => Some(Ok(Event::default().data(json))),
+                        Err(e) => {
+                            tracing::warn!("Failed to serialize log record: {}", e);
+                            None
+                        }
+                    })
+                    .collect()
+            }
+            Err(e) => {
+                tracing::debug!("Log follow poll failed: {}", e);
+                Vec::new()
+            }
+        };
+        Some((stream::iter(events), (state, query)))
+    })
+    .flatten();
+
+    Ok(Sse::new(stream).keep_alive(
+        KeepAlive::new()
+            .interval(Duration::from_secs(30))
+            .text("ping"),
+    ))
+}
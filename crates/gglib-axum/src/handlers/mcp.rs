@@ -3,6 +3,7 @@
 use axum::Json;
 use axum::extract::{Path, State};
 use serde::Deserialize;
+use utoipa::ToSchema;
 
 use crate::error::HttpError;
 use crate::state::AppState;
@@ -12,11 +13,24 @@ use gglib_app_services::types::{
 };
 
 /// List all MCP servers.
+#[utoipa::path(
+    get,
+    path = "/mcp/servers",
+    tag = "mcp",
+    responses((status = 200, body = Vec<McpServerInfo>))
+)]
 pub async fn list(State(state): State<AppState>) -> Result<Json<Vec<McpServerInfo>>, HttpError> {
     Ok(Json(state.mcp_ops.list().await?))
 }
 
 /// Add a new MCP server.
+#[utoipa::path(
+    post,
+    path = "/mcp/servers",
+    tag = "mcp",
+    request_body = CreateMcpServerRequest,
+    responses((status = 200, body = McpServerInfo))
+)]
 pub async fn add(
     State(state): State<AppState>,
     Json(req): Json<CreateMcpServerRequest>,
@@ -25,6 +39,14 @@ pub async fn add(
 }
 
 /// Update an MCP server.
+#[utoipa::path(
+    put,
+    path = "/mcp/servers/{id}",
+    tag = "mcp",
+    params(("id" = i64, Path, description = "MCP server ID")),
+    request_body = UpdateMcpServerRequest,
+    responses((status = 200, body = McpServerInfo))
+)]
 pub async fn update(
     State(state): State<AppState>,
     Path(id): Path<i64>,
@@ -34,12 +56,26 @@ pub async fn update(
 }
 
 /// Remove an MCP server.
+#[utoipa::path(
+    delete,
+    path = "/mcp/servers/{id}",
+    tag = "mcp",
+    params(("id" = i64, Path, description = "MCP server ID")),
+    responses((status = 200, description = "Done"))
+)]
 pub async fn remove(State(state): State<AppState>, Path(id): Path<i64>) -> Result<(), HttpError> {
     state.mcp_ops.remove(id).await?;
     Ok(())
 }
 
 /// Start an MCP server.
+#[utoipa::path(
+    post,
+    path = "/mcp/servers/{id}/start",
+    tag = "mcp",
+    params(("id" = i64, Path, description = "MCP server ID")),
+    responses((status = 200, body = McpServerInfo))
+)]
 pub async fn start(
     State(state): State<AppState>,
     Path(id): Path<i64>,
@@ -48,6 +84,13 @@ pub async fn start(
 }
 
 /// Stop an MCP server.
+#[utoipa::path(
+    post,
+    path = "/mcp/servers/{id}/stop",
+    tag = "mcp",
+    params(("id" = i64, Path, description = "MCP server ID")),
+    responses((status = 200, body = McpServerInfo))
+)]
 pub async fn stop(
     State(state): State<AppState>,
     Path(id): Path<i64>,
@@ -56,6 +99,13 @@ pub async fn stop(
 }
 
 /// List tools from a running MCP server.
+#[utoipa::path(
+    get,
+    path = "/mcp/servers/{id}/tools",
+    tag = "mcp",
+    params(("id" = i64, Path, description = "MCP server ID")),
+    responses((status = 200, body = Vec<McpToolInfo>))
+)]
 pub async fn list_tools(
     State(state): State<AppState>,
     Path(id): Path<i64>,
@@ -64,7 +114,7 @@ pub async fn list_tools(
 }
 
 /// Request body for calling an MCP tool (includes server ID).
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 pub struct CallToolRequest {
     pub server_id: i64,
     #[serde(flatten)]
@@ -72,6 +122,13 @@ pub struct CallToolRequest {
 }
 
 /// Call a tool on an MCP server.
+#[utoipa::path(
+    post,
+    path = "/mcp/tools/call",
+    tag = "mcp",
+    request_body = CallToolRequest,
+    responses((status = 200, body = McpToolCallResponse))
+)]
 pub async fn call_tool(
     State(state): State<AppState>,
     Json(req): Json<CallToolRequest>,
@@ -84,6 +141,13 @@ pub async fn call_tool(
 /// Resolve MCP server executable path (for diagnostics/auto-fix).
 ///
 /// Returns 200 with success:false for resolution failures (not a 404/500).
+#[utoipa::path(
+    post,
+    path = "/mcp/servers/{id}/resolve",
+    tag = "mcp",
+    params(("id" = i64, Path, description = "MCP server ID")),
+    responses((status = 200, body = gglib_core::ports::ResolutionStatus))
+)]
 pub async fn resolve_path(
     State(state): State<AppState>,
     Path(id): Path<i64>,
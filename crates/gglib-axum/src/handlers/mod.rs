@@ -1,12 +1,22 @@
 #![doc = include_str!("README.md")]
 pub mod agent;
+pub mod attachments;
+pub mod audit;
+pub mod backup;
 pub mod benchmark;
 pub mod builtin;
 pub mod config;
 pub mod council;
 pub mod events;
+pub mod jobs;
+pub mod logs;
 pub mod mcp;
 pub mod model;
+pub mod pipelines;
 pub mod port_utils;
+pub mod presets;
+pub mod projects;
 pub mod proxy;
 pub mod servers;
+pub mod shares;
+pub mod sync;
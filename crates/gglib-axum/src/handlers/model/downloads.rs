@@ -1,15 +1,20 @@
 //! Download handlers - queue management and HF downloads.
 
 use axum::Json;
-use axum::extract::{Path, State};
+use axum::extract::{Path, Query, State};
 use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
 
+use crate::dto::{QueueSizeDto, RetryDownloadResponse};
 use crate::error::HttpError;
 use crate::state::AppState;
-use gglib_core::download::QueueSnapshot;
+use gglib_core::download::{
+    CollectionQueueResult, CompletionKind, DownloadHistoryFilter, GroupProgress, QueueRunSummary,
+    QueueSnapshot,
+};
 
 /// Request to queue a download.
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 pub struct QueueDownloadRequest {
     pub model_id: String,
     /// Quantization to download. Accepts both "quant" and "quantization" field names
@@ -20,7 +25,7 @@ pub struct QueueDownloadRequest {
 
 /// Response from queue_download.
 /// Canonical shape returned to all clients - never a tuple.
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct QueueDownloadResponse {
     /// Position in the queue (0 = downloading now).
     pub position: usize,
@@ -28,20 +33,68 @@ pub struct QueueDownloadResponse {
     pub shard_count: usize,
 }
 
+/// Request to queue every GGUF repository in a HuggingFace collection.
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct QueueCollectionRequest {
+    pub slug: String,
+    /// Quantization filter applied to every repository.
+    #[serde(alias = "quant")]
+    pub quantization: Option<String>,
+}
+
 /// Request to reorder a single download.
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 pub struct ReorderRequest {
     pub model_id: String,
     pub position: usize,
 }
 
 /// Request to reorder the entire queue.
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 pub struct ReorderFullRequest {
     pub ids: Vec<String>,
 }
 
+/// Query parameters for `GET /api/models/downloads/history`.
+///
+/// ```text
+/// GET /api/models/downloads/history?result=failed&since_ms=1767225600000
+/// GET /api/models/downloads/history?search=llama&limit=5
+/// ```
+#[derive(Debug, Default, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct DownloadHistoryQueryParams {
+    /// Only runs completed at or after this time (ms since epoch).
+    pub since_ms: Option<u64>,
+    /// Only runs completed at or before this time (ms since epoch).
+    pub until_ms: Option<u64>,
+    /// Only records whose last result is this kind.
+    pub result: Option<CompletionKind>,
+    /// Case-insensitive substring of the record's display name.
+    pub search: Option<String>,
+    /// Maximum number of runs (default 50).
+    pub limit: Option<u32>,
+}
+
+impl From<DownloadHistoryQueryParams> for DownloadHistoryFilter {
+    fn from(p: DownloadHistoryQueryParams) -> Self {
+        Self {
+            since_ms: p.since_ms,
+            until_ms: p.until_ms,
+            result: p.result,
+            search: p.search.filter(|s| !s.is_empty()),
+            limit: p.limit,
+        }
+    }
+}
+
 /// Get the current download queue.
+#[utoipa::path(
+    get,
+    path = "/models/downloads",
+    tag = "downloads",
+    responses((status = 200, body = QueueSnapshot))
+)]
 pub async fn list(State(state): State<AppState>) -> Json<QueueSnapshot> {
     let snapshot = state.downloads.get_queue_snapshot().await;
 
@@ -58,6 +111,13 @@ pub async fn list(State(state): State<AppState>) -> Json<QueueSnapshot> {
 }
 
 /// Queue a new download.
+#[utoipa::path(
+    post,
+    path = "/models/downloads/queue",
+    tag = "downloads",
+    request_body = QueueDownloadRequest,
+    responses((status = 200, body = QueueDownloadResponse))
+)]
 pub async fn queue(
     State(state): State<AppState>,
     Json(req): Json<QueueDownloadRequest>,
@@ -72,7 +132,34 @@ pub async fn queue(
     }))
 }
 
+/// Queue every GGUF repository in a collection.
+#[utoipa::path(
+    post,
+    path = "/models/downloads/collection",
+    tag = "downloads",
+    request_body = QueueCollectionRequest,
+    responses((status = 200, body = CollectionQueueResult))
+)]
+pub async fn queue_collection(
+    State(state): State<AppState>,
+    Json(req): Json<QueueCollectionRequest>,
+) -> Result<Json<CollectionQueueResult>, HttpError> {
+    Ok(Json(
+        state
+            .downloads
+            .queue_collection(req.slug, req.quantization)
+            .await?,
+    ))
+}
+
 /// Remove a pending download from the queue.
+#[utoipa::path(
+    delete,
+    path = "/models/downloads/{id}",
+    tag = "downloads",
+    params(("id" = String, Path, description = "Download ID")),
+    responses((status = 200, description = "Done"))
+)]
 pub async fn remove(
     State(state): State<AppState>,
     Path(id): Path<String>,
@@ -86,6 +173,13 @@ pub async fn remove(
 /// This endpoint is idempotent: returns 204 No Content whether or not
 /// the download exists. This prevents client-side errors during race
 /// conditions (e.g., SSE removes download while cancel is in-flight).
+#[utoipa::path(
+    post,
+    path = "/models/downloads/{id}/cancel",
+    tag = "downloads",
+    params(("id" = String, Path, description = "Download ID")),
+    responses((status = 200, description = "Cancelled, or already gone"))
+)]
 pub async fn cancel(
     State(state): State<AppState>,
     Path(id): Path<String>,
@@ -99,6 +193,13 @@ pub async fn cancel(
 }
 
 /// Reorder a single download in the queue.
+#[utoipa::path(
+    post,
+    path = "/models/downloads/reorder",
+    tag = "downloads",
+    request_body = ReorderRequest,
+    responses((status = 200, description = "The new position", body = usize))
+)]
 pub async fn reorder(
     State(state): State<AppState>,
     Json(req): Json<ReorderRequest>,
@@ -112,6 +213,13 @@ pub async fn reorder(
 }
 
 /// Reorder the entire download queue.
+#[utoipa::path(
+    post,
+    path = "/models/downloads/reorder-full",
+    tag = "downloads",
+    request_body = ReorderFullRequest,
+    responses((status = 200, description = "Done"))
+)]
 pub async fn reorder_full(
     State(state): State<AppState>,
     Json(req): Json<ReorderFullRequest>,
@@ -121,6 +229,13 @@ pub async fn reorder_full(
 }
 
 /// Cancel all shards in a shard group.
+#[utoipa::path(
+    post,
+    path = "/models/downloads/shard-group/{id}/cancel",
+    tag = "downloads",
+    params(("id" = String, Path, description = "Shard group ID")),
+    responses((status = 200, description = "Done"))
+)]
 pub async fn cancel_shard_group(
     State(state): State<AppState>,
     Path(id): Path<String>,
@@ -129,11 +244,95 @@ pub async fn cancel_shard_group(
     Ok(())
 }
 
+/// Aggregate byte progress of a shard group.
+#[utoipa::path(
+    get,
+    path = "/models/downloads/shard-group/{id}/progress",
+    tag = "downloads",
+    params(("id" = String, Path, description = "Shard group ID")),
+    responses((status = 200, body = GroupProgress))
+)]
+pub async fn shard_group_progress(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> Result<Json<GroupProgress>, HttpError> {
+    Ok(Json(state.downloads.shard_group_progress(&id).await?))
+}
+
 /// Clear all failed downloads.
+#[utoipa::path(
+    post,
+    path = "/models/downloads/failed/clear",
+    tag = "downloads",
+    responses((status = 200, description = "Done"))
+)]
 pub async fn clear_failed(State(state): State<AppState>) {
     state.downloads.clear_failed().await;
 }
 
+/// Queue a failed download again.
+///
+/// A sharded download only fetches the shards not already on disk.
+#[utoipa::path(
+    post,
+    path = "/models/downloads/{id}/retry",
+    tag = "downloads",
+    params(("id" = String, Path, description = "Download ID")),
+    responses((status = 200, body = RetryDownloadResponse))
+)]
+pub async fn retry(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> Result<Json<RetryDownloadResponse>, HttpError> {
+    let position = state.downloads.retry_download(&id).await?;
+    Ok(Json(RetryDownloadResponse { position }))
+}
+
+/// How many downloads the queue holds.
+#[utoipa::path(
+    get,
+    path = "/models/downloads/max-size",
+    tag = "downloads",
+    responses((status = 200, body = QueueSizeDto))
+)]
+pub async fn max_size(State(state): State<AppState>) -> Result<Json<QueueSizeDto>, HttpError> {
+    let max_size = state.downloads.max_queue_size().await?;
+    Ok(Json(QueueSizeDto { max_size }))
+}
+
+/// Change how many downloads the queue holds.
+#[utoipa::path(
+    put,
+    path = "/models/downloads/max-size",
+    tag = "downloads",
+    request_body = QueueSizeDto,
+    responses((status = 200, body = QueueSizeDto))
+)]
+pub async fn set_max_size(
+    State(state): State<AppState>,
+    Json(req): Json<QueueSizeDto>,
+) -> Result<Json<QueueSizeDto>, HttpError> {
+    state.downloads.set_max_queue_size(req.max_size).await?;
+    Ok(Json(req))
+}
+
+/// Past queue runs with their completion records, newest first.
+#[utoipa::path(
+    get,
+    path = "/models/downloads/history",
+    tag = "downloads",
+    params(DownloadHistoryQueryParams),
+    responses((status = 200, body = Vec<QueueRunSummary>))
+)]
+pub async fn history(
+    State(state): State<AppState>,
+    Query(params): Query<DownloadHistoryQueryParams>,
+) -> Result<Json<Vec<QueueRunSummary>>, HttpError> {
+    Ok(Json(
+        state.downloads.download_history(&params.into()).await?,
+    ))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
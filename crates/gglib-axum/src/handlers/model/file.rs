@@ -0,0 +1,112 @@
+//! Model file download for other machines on the network.
+//!
+//! `GET /api/models/{id}/file` streams the model's GGUF so another machine,
+//! or a remote gglib instance, can copy it from this library instead of
+//! downloading it again from HuggingFace. `Range` requests are honoured, so
+//! an interrupted transfer resumes where it stopped, and `HEAD` reports the
+//! size without sending the file.
+//!
+//! The endpoint is off unless a model share token is configured
+//! ([`crate::ServerConfig::model_share_token`]), and every request must
+//! carry it as `Authorization: Bearer <token>`. Sharded models are served as
+//! their first shard, the file the library records.
+
+use axum::body::Body;
+use axum::extract::{Path, Request, State};
+use axum::http::{HeaderMap, HeaderValue, header};
+use axum::response::Response;
+use subtle::ConstantTimeEq;
+use tower_http::services::ServeFile;
+
+use crate::error::HttpError;
+use crate::state::AppState;
+
+/// Stream a model's file, honouring `Range` and conditional headers.
+#[utoipa::path(
+    get,
+    path = "/models/{id}/file",
+    tag = "models",
+    params(("id" = i64, Path, description = "Model ID")),
+    security(("share_token" = [])),
+    responses((status = 200, description = "The model file", content_type = "application/octet-stream"), (status = 206, description = "The requested byte range", content_type = "application/octet-stream"))
+)]
+pub async fn download(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+    request: Request,
+) -> Result<Response, HttpError> {
+    authorize(state.model_share_token.as_deref(), request.headers())?;
+
+    let model = state.models.get(id).await?;
+    let path = std::path::PathBuf::from(&model.file_path);
+    let response = ServeFile::new(&path)
+        .try_call(request)
+        .await
+        .map_err(|e| HttpError::Internal(format!("Failed to read model file: {e}")))?;
+
+    let mut response = response.map(Body::new);
+    let disposition = path
+        .file_name()
+        .and_then(|name| name.to_str())
+        .and_then(|name| HeaderValue::from_str(&format!("attachment; filename=\"{name}\"")).ok());
+    if let Some(disposition) = disposition {
+        response
+            .headers_mut()
+            .insert(header::CONTENT_DISPOSITION, disposition);
+    }
+    Ok(response)
+}
+
+/// Check the request's bearer token against the configured share token.
+///
+/// Compared in constant time, so response timing reveals nothing about how
+/// much of a guessed token was right.
+pub(crate) fn authorize(expected: Option<&str>, headers: &HeaderMap) -> Result<(), HttpError> {
+    let Some(expected) = expected else {
+        return Err(HttpError::Forbidden(
+            "Model sharing is disabled; start the server with a share token".to_string(),
+        ));
+    };
+    let presented = headers
+        .get(header::AUTHORIZATION)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.strip_prefix("Bearer "));
+    if presented.is_some_and(|token| bool::from(token.as_bytes().ct_eq(expected.as_bytes()))) {
+        Ok(())
+    } else {
+        Err(HttpError::Unauthorized(
+            "Missing or invalid model share token".to_string(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bearer(token: &str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            header::AUTHORIZATION,
+            HeaderValue::from_str(&format!("Bearer {token}")).unwrap(),
+        );
+        headers
+    }
+
+    #[test]
+    fn authorize_requires_the_configured_token() {
+        assert!(authorize(Some("s3cret"), &bearer("s3cret")).is_ok());
+        assert!(matches!(
+            authorize(Some("s3cret"), &bearer("guess")),
+            Err(HttpError::Unauthorized(_))
+        ));
+        assert!(matches!(
+            authorize(Some("s3cret"), &HeaderMap::new()),
+            Err(HttpError::Unauthorized(_))
+        ));
+        assert!(matches!(
+            authorize(None, &bearer("s3cret")),
+            Err(HttpError::Forbidden(_))
+        ));
+    }
+}
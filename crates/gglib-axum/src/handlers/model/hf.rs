@@ -10,6 +10,13 @@ use gglib_app_services::types::{
 };
 
 /// Search HuggingFace for GGUF models.
+#[utoipa::path(
+    post,
+    path = "/models/hf/search",
+    tag = "huggingface",
+    request_body = HfSearchRequest,
+    responses((status = 200, body = HfSearchResponse))
+)]
 pub async fn search(
     State(state): State<AppState>,
     Json(req): Json<HfSearchRequest>,
@@ -18,6 +25,13 @@ pub async fn search(
 }
 
 /// Get available quantizations for a model.
+#[utoipa::path(
+    get,
+    path = "/models/hf/quantizations/{model_id}",
+    tag = "huggingface",
+    params(("model_id" = String, Path, description = "HuggingFace repository, `owner/repo`")),
+    responses((status = 200, body = HfQuantizationsResponse))
+)]
 pub async fn quantizations(
     State(state): State<AppState>,
     Path(model_id): Path<String>,
@@ -28,6 +42,13 @@ pub async fn quantizations(
 }
 
 /// Check if a model supports tool/function calling.
+#[utoipa::path(
+    get,
+    path = "/models/hf/tool-support/{model_id}",
+    tag = "huggingface",
+    params(("model_id" = String, Path, description = "HuggingFace repository, `owner/repo`")),
+    responses((status = 200, body = ToolSupportResponse))
+)]
 pub async fn tool_support(
     State(state): State<AppState>,
     Path(model_id): Path<String>,
@@ -38,6 +59,13 @@ pub async fn tool_support(
 /// Get model summary by exact repo ID (direct API lookup).
 ///
 /// Uses wildcard path to capture the full `owner/repo` format including slashes.
+#[utoipa::path(
+    get,
+    path = "/models/hf/model/{model_id}",
+    tag = "huggingface",
+    params(("model_id" = String, Path, description = "HuggingFace repository, `owner/repo`")),
+    responses((status = 200, body = HfModelSummary))
+)]
 pub async fn model_summary(
     State(state): State<AppState>,
     Path(model_id): Path<String>,
@@ -1,5 +1,6 @@
 #![doc = include_str!("README.md")]
 pub mod downloads;
+pub mod file;
 pub mod hf;
 pub mod models;
 pub mod verification;
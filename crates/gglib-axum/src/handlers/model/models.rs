@@ -2,15 +2,18 @@
 
 use axum::Json;
 use axum::extract::{Path, Query, State};
+use utoipa::{IntoParams, ToSchema};
 
 use crate::error::HttpError;
 use crate::state::AppState;
 use gglib_app_services::types::{
-    AddModelRequest, GuiModel, ModelDetailDto, RemoveModelRequest, SetCapabilitiesRequest,
-    UpdateModelRequest,
+    AddModelRequest, GuiModel, GuiModelChanges, GuiModelPage, ModelDetailDto, RemoveModelRequest,
+    SetCapabilitiesRequest, UpdateModelRequest,
 };
 use gglib_core::ModelFilterOptions;
-use gglib_core::domain::{ModelListQuery, ModelSortBy, SortOrder};
+use gglib_core::domain::trash::TrashEntry;
+use gglib_core::domain::usage::{DEFAULT_SUMMARY_DAYS, DailyUsage, ModelUsage};
+use gglib_core::domain::{DEFAULT_PAGE_SIZE, ModelListQuery, ModelSortBy, PageRequest, SortOrder};
 
 // ─────────────────────────────────────────────────────────────────────────────
 // Query-parameter struct for GET /api/models
@@ -19,7 +22,7 @@ use gglib_core::domain::{ModelListQuery, ModelSortBy, SortOrder};
 /// Flat, HTTP-friendly representation of [`ModelListQuery`].
 ///
 /// Axum's `Query` extractor uses `serde_urlencoded`, which handles primitive
-/// fields cleanly.  Multi-value lists (tags, quantizations) are expressed as
+/// fields cleanly.  Multi-value lists (tags, quantizations, licenses) are expressed as
 /// comma-separated strings so no extra crate is required.
 ///
 /// Examples:
@@ -28,8 +31,10 @@ use gglib_core::domain::{ModelListQuery, ModelSortBy, SortOrder};
 /// GET /api/models?sort=latest_tg_tps&order=desc&min_speed=30
 /// GET /api/models?sort=param_count&order=asc&tags=chat,code
 /// GET /api/models?quantizations=Q4_K_M,Q8_0&min_params=7&max_params=70
+/// GET /api/models?licenses=apache-2.0,mit
 /// ```
-#[derive(Debug, Default, serde::Deserialize)]
+#[derive(Debug, Default, serde::Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
 pub struct ModelListQueryParams {
     /// Sort field. One of `added_at` | `name` | `param_count` | `latest_tg_tps`.
     pub sort: Option<ModelSortBy>,
@@ -41,6 +46,8 @@ pub struct ModelListQueryParams {
     pub max_context: Option<f64>,
     /// Comma-separated quantization allowlist (e.g. `Q4_K_M,Q8_0`).
     pub quantizations: Option<String>,
+    /// Comma-separated license allowlist (e.g. `apache-2.0,mit`).
+    pub licenses: Option<String>,
     /// Comma-separated required tags (AND semantics, e.g. `chat,code`).
     pub tags: Option<String>,
     pub min_speed: Option<f64>,
@@ -59,6 +66,9 @@ impl From<ModelListQueryParams> for ModelListQuery {
             quantizations: p
                 .quantizations
                 .map(|s| s.split(',').map(str::to_string).collect()),
+            licenses: p
+                .licenses
+                .map(|s| s.split(',').map(str::to_string).collect()),
             tags: p.tags.map(|s| s.split(',').map(str::to_string).collect()),
             min_speed: p.min_speed,
             max_speed: p.max_speed,
@@ -70,7 +80,42 @@ impl From<ModelListQueryParams> for ModelListQuery {
 // Handlers
 // ─────────────────────────────────────────────────────────────────────────────
 
+/// Paging parameters for `GET /api/models/page`, alongside the list filters.
+#[derive(Debug, Default, serde::Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct PageParams {
+    /// Models to skip; 0 when omitted.
+    pub offset: Option<usize>,
+    /// Page size, clamped to 1..=500; 50 when omitted.
+    pub limit: Option<usize>,
+}
+
+impl From<PageParams> for PageRequest {
+    fn from(p: PageParams) -> Self {
+        Self::new(
+            p.offset.unwrap_or_default(),
+            p.limit.unwrap_or(DEFAULT_PAGE_SIZE),
+        )
+    }
+}
+
+/// Query parameters for `GET /api/models/changes`.
+#[derive(Debug, Default, serde::Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct ChangesQuery {
+    /// RFC 3339 time of the last sync, normally the previous response's
+    /// `asOf`. The full list is returned when omitted.
+    pub since: Option<String>,
+}
+
 /// List models, optionally filtered and sorted via query parameters.
+#[utoipa::path(
+    get,
+    path = "/models",
+    tag = "models",
+    params(ModelListQueryParams),
+    responses((status = 200, body = Vec<GuiModel>))
+)]
 pub async fn list(
     State(state): State<AppState>,
     Query(params): Query<ModelListQueryParams>,
@@ -78,7 +123,55 @@ pub async fn list(
     Ok(Json(state.models.list_with_query(params.into()).await?))
 }
 
+/// One page of the model list, filtered and sorted like `GET /api/models`.
+#[utoipa::path(
+    get,
+    path = "/models/page",
+    tag = "models",
+    params(ModelListQueryParams, PageParams),
+    responses((status = 200, body = GuiModelPage))
+)]
+pub async fn page(
+    State(state): State<AppState>,
+    Query(params): Query<ModelListQueryParams>,
+    Query(page): Query<PageParams>,
+) -> Result<Json<GuiModelPage>, HttpError> {
+    Ok(Json(
+        state.models.list_page(params.into(), page.into()).await?,
+    ))
+}
+
+/// Models added, updated or removed since the client's last sync.
+#[utoipa::path(
+    get,
+    path = "/models/changes",
+    tag = "models",
+    params(ChangesQuery),
+    responses((status = 200, body = GuiModelChanges))
+)]
+pub async fn changes(
+    State(state): State<AppState>,
+    Query(query): Query<ChangesQuery>,
+) -> Result<Json<GuiModelChanges>, HttpError> {
+    let since = query
+        .since
+        .map(|since| {
+            chrono::DateTime::parse_from_rfc3339(&since)
+                .map(|t| t.to_utc())
+                .map_err(|e| HttpError::BadRequest(format!("Invalid since '{since}': {e}")))
+        })
+        .transpose()?;
+    Ok(Json(state.models.changes_since(since).await?))
+}
+
 /// Get a single model by ID.
+#[utoipa::path(
+    get,
+    path = "/models/{id}",
+    tag = "models",
+    params(("id" = i64, Path, description = "Model ID")),
+    responses((status = 200, body = GuiModel))
+)]
 pub async fn get(
     State(state): State<AppState>,
     Path(id): Path<i64>,
@@ -87,6 +180,13 @@ pub async fn get(
 }
 
 /// Add a new model from a local file.
+#[utoipa::path(
+    post,
+    path = "/models",
+    tag = "models",
+    request_body = AddModelRequest,
+    responses((status = 200, body = GuiModel))
+)]
 pub async fn add(
     State(state): State<AppState>,
     Json(req): Json<AddModelRequest>,
@@ -101,6 +201,14 @@ pub async fn add(
 /// `server_defaults` additionally supports explicit-`null` clearing via
 /// double-`Option` semantics — i.e. this handler already behaves like a
 /// partial update regardless of which verb the caller uses.
+#[utoipa::path(
+    put,
+    path = "/models/{id}",
+    tag = "models",
+    params(("id" = i64, Path, description = "Model ID")),
+    request_body = UpdateModelRequest,
+    responses((status = 200, body = GuiModel))
+)]
 pub async fn update(
     State(state): State<AppState>,
     Path(id): Path<i64>,
@@ -109,7 +217,15 @@ pub async fn update(
     Ok(Json(state.models.update(id, req).await?))
 }
 
-/// Remove a model.
+/// Remove a model, moving it to the trash.
+#[utoipa::path(
+    delete,
+    path = "/models/{id}",
+    tag = "models",
+    params(("id" = i64, Path, description = "Model ID")),
+    request_body = RemoveModelRequest,
+    responses((status = 200, description = "Confirmation message", body = String))
+)]
 pub async fn remove(
     State(state): State<AppState>,
     Path(id): Path<i64>,
@@ -118,12 +234,115 @@ pub async fn remove(
     Ok(Json(state.models.remove(id, req).await?))
 }
 
+/// List trashed models, oldest first.
+#[utoipa::path(
+    get,
+    path = "/models/trash",
+    tag = "models",
+    responses((status = 200, body = Vec<TrashEntry>))
+)]
+pub async fn list_trash(State(state): State<AppState>) -> Result<Json<Vec<TrashEntry>>, HttpError> {
+    Ok(Json(state.models.list_trash().await?))
+}
+
+/// Restore a trashed model to the library. Conflicts when a file now
+/// exists at one of its original paths.
+#[utoipa::path(
+    post,
+    path = "/models/trash/{id}/restore",
+    tag = "models",
+    params(("id" = i64, Path, description = "Model ID")),
+    responses((status = 200, body = GuiModel))
+)]
+pub async fn restore_trash(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+) -> Result<Json<GuiModel>, HttpError> {
+    Ok(Json(state.models.restore_trash(id).await?))
+}
+
+/// Permanently delete every trashed model.
+#[utoipa::path(
+    delete,
+    path = "/models/trash",
+    tag = "models",
+    responses((status = 200, description = "The deleted entries", body = Vec<TrashEntry>))
+)]
+pub async fn empty_trash(
+    State(state): State<AppState>,
+) -> Result<Json<Vec<TrashEntry>>, HttpError> {
+    Ok(Json(state.models.empty_trash().await?))
+}
+
+/// Query parameters for `GET /api/models/usage`.
+#[derive(Debug, Default, serde::Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct UsageQuery {
+    /// Only count the last `days` days (today included); all recorded usage
+    /// when omitted.
+    pub days: Option<u32>,
+}
+
+/// Every model ranked by usage — requests, then tokens, then serve time.
+/// Models never used are listed last.
+#[utoipa::path(
+    get,
+    path = "/models/usage",
+    tag = "models",
+    params(UsageQuery),
+    responses((status = 200, body = Vec<ModelUsage>))
+)]
+pub async fn usage(
+    State(state): State<AppState>,
+    Query(query): Query<UsageQuery>,
+) -> Result<Json<Vec<ModelUsage>>, HttpError> {
+    Ok(Json(state.models.usage_ranking(query.days).await?))
+}
+
+/// Query parameters for `GET /api/models/usage/daily`.
+#[derive(Debug, Default, serde::Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct DailyUsageQuery {
+    /// Days to list (today included); 30 when omitted.
+    pub days: Option<u32>,
+}
+
+/// Usage across the library per day, newest first, with estimated energy
+/// and cost for the time servers spent on requests.
+#[utoipa::path(
+    get,
+    path = "/models/usage/daily",
+    tag = "models",
+    params(DailyUsageQuery),
+    responses((status = 200, body = Vec<DailyUsage>))
+)]
+pub async fn usage_daily(
+    State(state): State<AppState>,
+    Query(query): Query<DailyUsageQuery>,
+) -> Result<Json<Vec<DailyUsage>>, HttpError> {
+    let days = query.days.unwrap_or(DEFAULT_SUMMARY_DAYS);
+    Ok(Json(state.models.usage_daily(days).await?))
+}
+
 /// Get all unique tags.
+#[utoipa::path(
+    get,
+    path = "/models/tags",
+    tag = "models",
+    responses((status = 200, body = Vec<String>))
+)]
 pub async fn list_tags(State(state): State<AppState>) -> Result<Json<Vec<String>>, HttpError> {
     Ok(Json(state.models.list_tags().await?))
 }
 
 /// Get tags for a specific model.
+#[utoipa::path(
+    get,
+    path = "/models/{id}/tags",
+    tag = "models",
+    params(("id" = i64, Path, description = "Model ID")),
+    responses((status = 200, body = Vec<String>))
+)]
 pub async fn get_model_tags(
     State(state): State<AppState>,
     Path(id): Path<i64>,
@@ -132,6 +351,13 @@ pub async fn get_model_tags(
 }
 
 /// Add a tag to a model.
+#[utoipa::path(
+    post,
+    path = "/models/{id}/tags/{tag}",
+    tag = "models",
+    params(("id" = i64, Path, description = "Model ID"), ("tag" = String, Path, description = "Tag")),
+    responses((status = 200, description = "Done"))
+)]
 pub async fn add_tag(
     State(state): State<AppState>,
     Path((id, tag)): Path<(i64, String)>,
@@ -141,12 +367,20 @@ pub async fn add_tag(
 }
 
 /// Request body for adding a tag via POST to /api/models/{id}/tags
-#[derive(serde::Deserialize)]
+#[derive(serde::Deserialize, ToSchema)]
 pub struct AddTagRequest {
     pub tag: String,
 }
 
 /// Add a tag to a model (body-based version for frontend transport).
+#[utoipa::path(
+    post,
+    path = "/models/{id}/tags",
+    tag = "models",
+    params(("id" = i64, Path, description = "Model ID")),
+    request_body = AddTagRequest,
+    responses((status = 200, description = "Done"))
+)]
 pub async fn add_tag_body(
     State(state): State<AppState>,
     Path(id): Path<i64>,
@@ -157,6 +391,13 @@ pub async fn add_tag_body(
 }
 
 /// Remove a tag from a model.
+#[utoipa::path(
+    delete,
+    path = "/models/{id}/tags/{tag}",
+    tag = "models",
+    params(("id" = i64, Path, description = "Model ID"), ("tag" = String, Path, description = "Tag")),
+    responses((status = 200, description = "Done"))
+)]
 pub async fn remove_tag(
     State(state): State<AppState>,
     Path((id, tag)): Path<(i64, String)>,
@@ -166,6 +407,13 @@ pub async fn remove_tag(
 }
 
 /// Get models with a specific tag.
+#[utoipa::path(
+    get,
+    path = "/models/tags/{tag}",
+    tag = "models",
+    params(("tag" = String, Path, description = "Tag")),
+    responses((status = 200, description = "IDs of the tagged models", body = Vec<i64>))
+)]
 pub async fn get_by_tag(
     State(state): State<AppState>,
     Path(tag): Path<String>,
@@ -174,12 +422,26 @@ pub async fn get_by_tag(
 }
 
 /// Get filter options for the model list UI.
+#[utoipa::path(
+    get,
+    path = "/models/filter-options",
+    tag = "models",
+    responses((status = 200, body = ModelFilterOptions))
+)]
 pub async fn filter_options(
     State(state): State<AppState>,
 ) -> Result<Json<ModelFilterOptions>, HttpError> {
     Ok(Json(state.models.get_filter_options().await?))
 }
 
+#[utoipa::path(
+    patch,
+    path = "/models/{id}/capabilities",
+    tag = "models",
+    params(("id" = i64, Path, description = "Model ID")),
+    request_body = SetCapabilitiesRequest,
+    responses((status = 200, body = GuiModel))
+)]
 pub async fn set_capabilities(
     State(state): State<AppState>,
     Path(id): Path<i64>,
@@ -194,6 +456,13 @@ pub async fn set_capabilities(
 /// the [`GuiModel`] returned by `GET /api/models/{id}`.  Includes raw GGUF
 /// metadata, MoE topology, full HuggingFace provenance, capability flags,
 /// inference defaults, and timestamps.
+#[utoipa::path(
+    get,
+    path = "/models/{id}/detail",
+    tag = "models",
+    params(("id" = i64, Path, description = "Model ID")),
+    responses((status = 200, body = ModelDetailDto))
+)]
 pub async fn detail(
     State(state): State<AppState>,
     Path(id): Path<i64>,
@@ -3,44 +3,51 @@
 use axum::Json;
 use axum::extract::{Path, State};
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
 use crate::error::HttpError;
 use crate::state::AppState;
+use gglib_core::jobs::{JobKind, JobSpec};
 use gglib_core::ports::AppEventEmitter;
 use gglib_core::services::{UpdateCheckResult, VerificationReport};
 
 /// Response from verify endpoint.
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct VerifyResponse {
     pub report: VerificationReport,
 }
 
 /// Response from check updates endpoint.
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct CheckUpdatesResponse {
     pub result: UpdateCheckResult,
     pub message: String,
 }
 
 /// Request body for repair endpoint.
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 pub struct RepairRequest {
     /// Optional list of shard indices to repair. If None, repairs all corrupt shards.
     pub shards: Option<Vec<usize>>,
 }
 
 /// Response from repair endpoint.
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct RepairResponse {
     pub message: String,
 }
 
 /// Verify model integrity.
 ///
-/// POST /api/models/{id}/verify
-///
 /// This endpoint streams progress via SSE and returns a verification report.
 /// Clients should subscribe to SSE events to receive real-time progress updates.
+#[utoipa::path(
+    post,
+    path = "/models/{id}/verify",
+    tag = "models",
+    params(("id" = i64, Path, description = "Model ID")),
+    responses((status = 200, body = VerifyResponse))
+)]
 pub async fn verify(
     State(state): State<AppState>,
     Path(id): Path<i64>,
@@ -72,8 +79,30 @@ pub async fn verify(
         .await
         .map_err(|e| HttpError::Internal(format!("Failed to start verification: {}", e)))?;
 
+    // Track it as a job; a cancel aborts the task and closes the progress
+    // channel, which stops the hashing in flight.
+    let job = state.jobs.manager().start(
+        JobSpec::new(JobKind::Verification, model.name.clone())
+            .with_subject(id.to_string())
+            .cancellable(),
+    );
+
     // Stream progress via SSE
-    while let Some(progress) = progress_rx.recv().await {
+    loop {
+        let progress = tokio::select! {
+            progress = progress_rx.recv() => match progress {
+                Some(progress) => progress,
+                None => break,
+            },
+            () = job.cancelled() => {
+                handle.abort();
+                drop(progress_rx);
+                job.cancelled_by_request();
+                tracing::info!(target: "gglib.verification", model_id = id, "Verification cancelled");
+                return Err(HttpError::Conflict("Verification cancelled".to_string()));
+            }
+        };
+
         // Extract progress information from shard_progress
         let (bytes_processed, total_bytes) = match &progress.shard_progress {
             gglib_core::services::ShardProgress::Hashing {
@@ -95,14 +124,17 @@ pub async fn verify(
 
         // Emit progress event via SSE
         // Note: shard_name will be constructed from shard_index on the frontend
+        let shard_name = format!(
+            "Shard {}/{}",
+            progress.shard_index + 1,
+            progress.total_shards
+        );
+        job.message(shard_name.clone());
+        job.progress(bytes_processed, Some(total_bytes));
         let event = gglib_core::events::AppEvent::VerificationProgress {
             model_id: id,
             model_name: model.name.clone(),
-            shard_name: format!(
-                "Shard {}/{}",
-                progress.shard_index + 1,
-                progress.total_shards
-            ),
+            shard_name,
             bytes_processed,
             total_bytes,
         };
@@ -110,10 +142,21 @@ pub async fn verify(
     }
 
     // Wait for verification to complete
-    let report = handle
-        .await
-        .map_err(|e| HttpError::Internal(format!("Verification task failed: {}", e)))?
-        .map_err(|e| HttpError::Internal(format!("Verification failed: {}", e)))?;
+    let report = match handle.await {
+        Ok(Ok(report)) => report,
+        Ok(Err(e)) => {
+            job.fail(e.to_string());
+            return Err(HttpError::Internal(format!("Verification failed: {}", e)));
+        }
+        Err(e) => {
+            job.fail(e.to_string());
+            return Err(HttpError::Internal(format!(
+                "Verification task failed: {}",
+                e
+            )));
+        }
+    };
+    job.complete();
 
     tracing::info!(
         target: "gglib.verification",
@@ -135,8 +178,13 @@ pub async fn verify(
 }
 
 /// Check for model updates on HuggingFace.
-///
-/// GET /api/models/{id}/updates
+#[utoipa::path(
+    get,
+    path = "/models/{id}/updates",
+    tag = "models",
+    params(("id" = i64, Path, description = "Model ID")),
+    responses((status = 200, body = CheckUpdatesResponse))
+)]
 pub async fn check_updates(
     State(state): State<AppState>,
     Path(id): Path<i64>,
@@ -193,8 +241,14 @@ pub async fn check_updates(
 }
 
 /// Repair model by re-downloading corrupt shards.
-///
-/// POST /api/models/{id}/repair
+#[utoipa::path(
+    post,
+    path = "/models/{id}/repair",
+    tag = "models",
+    params(("id" = i64, Path, description = "Model ID")),
+    request_body = RepairRequest,
+    responses((status = 200, body = RepairResponse))
+)]
 pub async fn repair(
     State(state): State<AppState>,
     Path(id): Path<i64>,
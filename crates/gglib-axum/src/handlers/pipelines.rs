@@ -0,0 +1,108 @@
+//! Pipeline handlers - run multi-stage pipelines and inspect past runs.
+//!
+//! `POST /api/pipelines/run` executes a definition to completion and returns
+//! the stored run. Without a `port` the steps go through the running proxy,
+//! which routes each step to the model it names; with a `port` every step is
+//! sent to that llama-server.
+
+use std::collections::BTreeMap;
+use std::net::{IpAddr, Ipv4Addr};
+use std::sync::Arc;
+
+use axum::Json;
+use axum::extract::{Path, State};
+use serde::Deserialize;
+
+use gglib_app_services::GuiError;
+use gglib_core::domain::pipeline::{PipelineDefinition, PipelineRun};
+use gglib_runtime::proxy::ProxyStatus;
+use gglib_runtime::{EndpointPipelineModels, compose_pipeline_runner};
+
+use crate::error::HttpError;
+use crate::handlers::port_utils::validate_port;
+use crate::state::AppState;
+
+/// Request body for `POST /api/pipelines/run`.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RunPipelineRequest {
+    pub definition: PipelineDefinition,
+    /// Values for `{{input.name}}` placeholders, over the definition's defaults.
+    #[serde(default)]
+    pub inputs: BTreeMap<String, String>,
+    /// Send every step to this llama-server instead of the proxy.
+    #[serde(default)]
+    pub port: Option<u16>,
+}
+
+/// Run a pipeline to completion.
+/// POST /api/pipelines/run
+///
+/// A failing step does not fail the request: the returned run has status
+/// `failed`, the reason, and the outputs of the steps before it.
+pub async fn run(
+    State(state): State<AppState>,
+    Json(req): Json<RunPipelineRequest>,
+) -> Result<Json<PipelineRun>, HttpError> {
+    let _permit = state
+        .agent_semaphore
+        .clone()
+        .try_acquire_owned()
+        .map_err(|_| {
+            HttpError::TooManyRequests("all agent loop slots are in use; try again later".into())
+        })?;
+
+    let base_url = match req.port {
+        Some(port) => {
+            validate_port(&state, port).await?;
+            format!("http://127.0.0.1:{port}")
+        }
+        None => match state.proxy.status().await {
+            ProxyStatus::Running { mut address } => {
+                if address.ip().is_unspecified() {
+                    address.set_ip(IpAddr::V4(Ipv4Addr::LOCALHOST));
+                }
+                format!("http://{address}")
+            }
+            _ => {
+                return Err(HttpError::ServiceUnavailable(
+                    "The proxy is not running. Start it, or pass the port of a running server."
+                        .into(),
+                ));
+            }
+        },
+    };
+
+    let models = EndpointPipelineModels::new(
+        base_url,
+        state.http_client.clone(),
+        Arc::clone(&state.catalog),
+        Some(state.proxy.agent_metrics()),
+    );
+    let runner = compose_pipeline_runner(
+        Arc::new(models),
+        Arc::clone(&state.mcp),
+        None,
+        state.core.pipelines().repo(),
+    );
+    let run = runner
+        .run(req.definition, req.inputs, |_| {})
+        .await
+        .map_err(GuiError::from)?;
+    Ok(Json(run))
+}
+
+/// List pipeline runs, most recent first.
+/// GET /api/pipelines/runs
+pub async fn list_runs(State(state): State<AppState>) -> Result<Json<Vec<PipelineRun>>, HttpError> {
+    Ok(Json(state.pipelines.list_runs().await?))
+}
+
+/// Get a pipeline run with its step outputs.
+/// GET /api/pipelines/runs/:id
+pub async fn get_run(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+) -> Result<Json<PipelineRun>, HttpError> {
+    Ok(Json(state.pipelines.get_run(id).await?))
+}
@@ -0,0 +1,83 @@
+//! Preset handlers - generation preset CRUD.
+//!
+//! Proxy clients select a preset by name with the `x-gglib-preset` header or
+//! a `preset` field in a chat completion request.
+
+use axum::Json;
+use axum::extract::{Path, State};
+
+use crate::error::HttpError;
+use crate::state::AppState;
+use gglib_app_services::types::{CreatePresetRequest, UpdatePresetRequest};
+use gglib_core::domain::preset::Preset;
+
+/// List all presets.
+#[utoipa::path(
+    get,
+    path = "/presets",
+    tag = "presets",
+    responses((status = 200, body = Vec<Preset>))
+)]
+pub async fn list(State(state): State<AppState>) -> Result<Json<Vec<Preset>>, HttpError> {
+    Ok(Json(state.presets.list().await?))
+}
+
+/// Create a preset.
+#[utoipa::path(
+    post,
+    path = "/presets",
+    tag = "presets",
+    request_body = CreatePresetRequest,
+    responses((status = 200, body = Preset))
+)]
+pub async fn create(
+    State(state): State<AppState>,
+    Json(req): Json<CreatePresetRequest>,
+) -> Result<Json<Preset>, HttpError> {
+    Ok(Json(state.presets.create(req).await?))
+}
+
+/// Get a preset.
+#[utoipa::path(
+    get,
+    path = "/presets/{id}",
+    tag = "presets",
+    params(("id" = i64, Path, description = "Preset ID")),
+    responses((status = 200, body = Preset))
+)]
+pub async fn get(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+) -> Result<Json<Preset>, HttpError> {
+    Ok(Json(state.presets.get(id).await?))
+}
+
+/// Update a preset.
+#[utoipa::path(
+    put,
+    path = "/presets/{id}",
+    tag = "presets",
+    params(("id" = i64, Path, description = "Preset ID")),
+    request_body = UpdatePresetRequest,
+    responses((status = 200, body = Preset))
+)]
+pub async fn update(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+    Json(req): Json<UpdatePresetRequest>,
+) -> Result<Json<Preset>, HttpError> {
+    Ok(Json(state.presets.update(id, req).await?))
+}
+
+/// Delete a preset.
+#[utoipa::path(
+    delete,
+    path = "/presets/{id}",
+    tag = "presets",
+    params(("id" = i64, Path, description = "Preset ID")),
+    responses((status = 200, description = "Done"))
+)]
+pub async fn delete(State(state): State<AppState>, Path(id): Path<i64>) -> Result<(), HttpError> {
+    state.presets.delete(id).await?;
+    Ok(())
+}
@@ -0,0 +1,171 @@
+//! Project handlers - project CRUD, file trees and conversation links.
+//!
+//! A conversation linked to a project gets the project's selected files in
+//! its system prompt when `conversationId` is passed to `POST /api/chat`.
+//! `GET /api/projects/{id}/context` previews exactly what would be injected.
+
+use axum::Json;
+use axum::extract::{Path, State};
+
+use crate::error::HttpError;
+use crate::state::AppState;
+use gglib_app_services::types::{
+    CreateProjectRequest, FileTree, LinkProjectRequest, ProjectContext, UpdateProjectRequest,
+};
+use gglib_core::domain::project::Project;
+
+/// List all projects.
+#[utoipa::path(
+    get,
+    path = "/projects",
+    tag = "projects",
+    responses((status = 200, body = Vec<Project>))
+)]
+pub async fn list(State(state): State<AppState>) -> Result<Json<Vec<Project>>, HttpError> {
+    Ok(Json(state.projects.list().await?))
+}
+
+/// Create a project.
+#[utoipa::path(
+    post,
+    path = "/projects",
+    tag = "projects",
+    request_body = CreateProjectRequest,
+    responses((status = 200, body = Project))
+)]
+pub async fn create(
+    State(state): State<AppState>,
+    Json(req): Json<CreateProjectRequest>,
+) -> Result<Json<Project>, HttpError> {
+    Ok(Json(state.projects.create(req).await?))
+}
+
+/// Get a project.
+#[utoipa::path(
+    get,
+    path = "/projects/{id}",
+    tag = "projects",
+    params(("id" = i64, Path, description = "Project ID")),
+    responses((status = 200, body = Project))
+)]
+pub async fn get(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+) -> Result<Json<Project>, HttpError> {
+    Ok(Json(state.projects.get(id).await?))
+}
+
+/// Update a project.
+#[utoipa::path(
+    put,
+    path = "/projects/{id}",
+    tag = "projects",
+    params(("id" = i64, Path, description = "Project ID")),
+    request_body = UpdateProjectRequest,
+    responses((status = 200, body = Project))
+)]
+pub async fn update(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+    Json(req): Json<UpdateProjectRequest>,
+) -> Result<Json<Project>, HttpError> {
+    Ok(Json(state.projects.update(id, req).await?))
+}
+
+/// Delete a project.
+#[utoipa::path(
+    delete,
+    path = "/projects/{id}",
+    tag = "projects",
+    params(("id" = i64, Path, description = "Project ID")),
+    responses((status = 200, description = "Done"))
+)]
+pub async fn delete(State(state): State<AppState>, Path(id): Path<i64>) -> Result<(), HttpError> {
+    state.projects.delete(id).await?;
+    Ok(())
+}
+
+/// List the project directory.
+#[utoipa::path(
+    get,
+    path = "/projects/{id}/tree",
+    tag = "projects",
+    params(("id" = i64, Path, description = "Project ID")),
+    responses((status = 200, body = FileTree))
+)]
+pub async fn tree(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+) -> Result<Json<FileTree>, HttpError> {
+    Ok(Json(state.projects.file_tree(id).await?))
+}
+
+/// Preview the files injected into linked conversations.
+#[utoipa::path(
+    get,
+    path = "/projects/{id}/context",
+    tag = "projects",
+    params(("id" = i64, Path, description = "Project ID")),
+    responses((status = 200, body = ProjectContext))
+)]
+pub async fn context(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+) -> Result<Json<ProjectContext>, HttpError> {
+    Ok(Json(state.projects.context(id).await?))
+}
+
+/// Get the project a conversation is linked to.
+#[utoipa::path(
+    get,
+    path = "/conversations/{id}/project",
+    tag = "projects",
+    params(("id" = i64, Path, description = "Conversation ID")),
+    responses((status = 200, body = Option<Project>))
+)]
+pub async fn get_for_conversation(
+    State(state): State<AppState>,
+    Path(conversation_id): Path<i64>,
+) -> Result<Json<Option<Project>>, HttpError> {
+    Ok(Json(
+        state.projects.conversation_project(conversation_id).await?,
+    ))
+}
+
+/// Link a conversation to a project.
+#[utoipa::path(
+    put,
+    path = "/conversations/{id}/project",
+    tag = "projects",
+    params(("id" = i64, Path, description = "Conversation ID")),
+    request_body = LinkProjectRequest,
+    responses((status = 200, body = Project))
+)]
+pub async fn link(
+    State(state): State<AppState>,
+    Path(conversation_id): Path<i64>,
+    Json(req): Json<LinkProjectRequest>,
+) -> Result<Json<Project>, HttpError> {
+    Ok(Json(
+        state
+            .projects
+            .link_conversation(conversation_id, req.project_id)
+            .await?,
+    ))
+}
+
+/// Unlink a conversation from its project.
+#[utoipa::path(
+    delete,
+    path = "/conversations/{id}/project",
+    tag = "projects",
+    params(("id" = i64, Path, description = "Conversation ID")),
+    responses((status = 200, description = "Done"))
+)]
+pub async fn unlink(
+    State(state): State<AppState>,
+    Path(conversation_id): Path<i64>,
+) -> Result<(), HttpError> {
+    state.projects.unlink_conversation(conversation_id).await?;
+    Ok(())
+}
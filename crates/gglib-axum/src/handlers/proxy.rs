@@ -1,17 +1,18 @@
 //! Proxy handlers - OpenAI-compatible proxy management.
 
 use axum::{Json, extract::State};
+use utoipa::ToSchema;
 
 use crate::{error::HttpError, state::AppState};
 use gglib_core::ports::AppEventEmitter;
 use gglib_core::server_config::{ServerConfigOptions, resolve_context_size};
-use gglib_core::settings::DEFAULT_PROXY_PORT;
+use gglib_core::settings::{DEFAULT_PROXY_HOST, DEFAULT_PROXY_PORT};
 use gglib_runtime::proxy::ProxyConfig as RuntimeProxyConfig;
 use gglib_runtime::proxy::ProxyStatus as RuntimeProxyStatus;
 
 /// Proxy status response.
 /// Matches Tauri's ProxyStatus for frontend compatibility.
-#[derive(Debug, Clone, serde::Serialize)]
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
 pub struct ProxyStatus {
     pub running: bool,
     pub port: Option<u16>,
@@ -20,8 +21,10 @@ pub struct ProxyStatus {
 }
 
 /// Optional configuration for starting the proxy.
-#[derive(Debug, Clone, Default, serde::Deserialize)]
+#[derive(Debug, Clone, Default, serde::Deserialize, ToSchema)]
 pub struct StartProxyConfig {
+    /// Bind address; defaults to the `proxy_host` setting, then `127.0.0.1`.
+    /// A public address also needs the `proxy_allow_public` setting.
     pub host: Option<String>,
     pub port: Option<u16>,
     pub llama_base_port: Option<u16>,
@@ -62,6 +65,7 @@ async fn fetch_status(state: &AppState) -> ProxyStatus {
 fn to_runtime_config(
     cfg: &StartProxyConfig,
     settings_default: Option<u64>,
+    settings_host: Option<&str>,
 ) -> Result<RuntimeProxyConfig, HttpError> {
     let default_context = resolve_context_size(&ServerConfigOptions {
         context_size: cfg.default_context,
@@ -70,7 +74,12 @@ fn to_runtime_config(
     });
 
     Ok(RuntimeProxyConfig {
-        host: cfg.host.clone().unwrap_or_else(|| "127.0.0.1".to_string()),
+        host: cfg
+            .host
+            .as_deref()
+            .or(settings_host)
+            .unwrap_or(DEFAULT_PROXY_HOST)
+            .to_string(),
         port: cfg.port.unwrap_or(DEFAULT_PROXY_PORT),
         default_context,
         // GUI cache toggle is out of scope until Step 7 — always disabled here.
@@ -81,11 +90,24 @@ fn to_runtime_config(
 }
 
 /// Get current proxy status.
+#[utoipa::path(
+    get,
+    path = "/proxy/status",
+    tag = "proxy",
+    responses((status = 200, body = ProxyStatus))
+)]
 pub async fn status(State(state): State<AppState>) -> Json<ProxyStatus> {
     Json(fetch_status(&state).await)
 }
 
 /// Start the proxy (idempotent).
+#[utoipa::path(
+    post,
+    path = "/proxy/start",
+    tag = "proxy",
+    request_body = Option<StartProxyConfig>,
+    responses((status = 200, body = ProxyStatus))
+)]
 pub async fn start(
     State(state): State<AppState>,
     Json(cfg): Json<Option<StartProxyConfig>>,
@@ -95,7 +117,11 @@ pub async fn start(
     // Resolve context size through the shared 3-level fallback chain
     // (flag > settings default > hard-coded default), matching CLI behavior.
     let settings = state.settings.get().await?;
-    let runtime_cfg = to_runtime_config(&cfg, settings.default_context_size)?;
+    let runtime_cfg = to_runtime_config(
+        &cfg,
+        settings.default_context_size,
+        settings.proxy_host.as_deref(),
+    )?;
 
     // Idempotent: if already running (Conflict), treat as success
     match state.proxy.start(runtime_cfg).await {
@@ -123,6 +149,12 @@ pub async fn start(
 }
 
 /// Stop the proxy (idempotent).
+#[utoipa::path(
+    post,
+    path = "/proxy/stop",
+    tag = "proxy",
+    responses((status = 200, body = ProxyStatus))
+)]
 pub async fn stop(State(state): State<AppState>) -> Result<Json<ProxyStatus>, HttpError> {
     // Idempotent: if not running (Conflict), treat as success
     match state.proxy.stop().await {
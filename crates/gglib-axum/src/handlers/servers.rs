@@ -2,14 +2,23 @@
 
 use axum::Json;
 use axum::extract::{Path, State};
+use utoipa::ToSchema;
 
 use crate::error::HttpError;
 use crate::state::AppState;
 use gglib_app_services::types::{
-    ServerInfo, StartServerRequest, StartServerResponse, ToolSupportResponse,
+    PreviewPromptRequest, ServerInfo, StartServerRequest, StartServerResponse, ToolSupportResponse,
+    WarmPromptRequest,
 };
+use gglib_core::domain::{PromptPreview, PromptWarmResult, ServerStats, SlotInfo};
 
 /// List all running servers.
+#[utoipa::path(
+    get,
+    path = "/servers",
+    tag = "servers",
+    responses((status = 200, body = Vec<ServerInfo>))
+)]
 pub async fn list(State(state): State<AppState>) -> Json<Vec<ServerInfo>> {
     Json(state.servers.list_servers().await)
 }
@@ -20,6 +29,13 @@ pub async fn list(State(state): State<AppState>) -> Json<Vec<ServerInfo>> {
 /// bitflag in the database. `confidence` and `detected_format` are
 /// derived from the chat template stored in model metadata — no GGUF
 /// file parsing required.
+#[utoipa::path(
+    get,
+    path = "/servers/{id}/tool-support",
+    tag = "servers",
+    params(("id" = i64, Path, description = "ID of the served model")),
+    responses((status = 200, body = ToolSupportResponse))
+)]
 pub async fn tool_support(
     State(state): State<AppState>,
     Path(id): Path<i64>,
@@ -27,11 +43,99 @@ pub async fn tool_support(
     Ok(Json(state.servers.get_server_tool_support(id).await?))
 }
 
+/// Get latency and throughput stats for a running server's session.
+///
+/// Covers requests forwarded by the proxy since the server was last spawned:
+/// queue wait, time-to-first-token, and decode tokens/sec, as session-wide
+/// and recent-window means.
+#[utoipa::path(
+    get,
+    path = "/servers/{id}/stats",
+    tag = "servers",
+    params(("id" = i64, Path, description = "ID of the served model")),
+    responses((status = 200, body = ServerStats))
+)]
+pub async fn stats(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+) -> Result<Json<ServerStats>, HttpError> {
+    Ok(Json(state.servers.get_stats(id)?))
+}
+
+/// Get the state of each of a running server's slots.
+///
+/// Read live from llama-server: which slots are busy, how much of each
+/// slot's context is in use, and how far the current generation has got.
+/// A request waits in the server's queue while every slot is busy.
+#[utoipa::path(
+    get,
+    path = "/servers/{id}/slots",
+    tag = "servers",
+    params(("id" = i64, Path, description = "ID of the served model")),
+    responses((status = 200, body = Vec<SlotInfo>))
+)]
+pub async fn slots(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+) -> Result<Json<Vec<SlotInfo>>, HttpError> {
+    Ok(Json(state.servers.get_slots(id).await?))
+}
+
+/// Warm a cached prompt on a running server.
+///
+/// Leaves the prompt's KV state in the server's cache so the next request
+/// starting with it skips most of the prefill, and saves it for the next
+/// start when the server has a slot directory. Returns once the prompt is
+/// warm, which for a long prompt on a cold cache can take a while.
+#[utoipa::path(
+    post,
+    path = "/servers/{id}/prompts/warm",
+    tag = "servers",
+    params(("id" = i64, Path, description = "ID of the served model")),
+    request_body = WarmPromptRequest,
+    responses((status = 200, body = PromptWarmResult))
+)]
+pub async fn warm_prompt(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+    Json(req): Json<WarmPromptRequest>,
+) -> Result<Json<PromptWarmResult>, HttpError> {
+    Ok(Json(state.servers.warm_prompt(id, req).await?))
+}
+
+/// Render a conversation through a running model's chat template.
+///
+/// Returns the exact prompt the model's server would evaluate for these
+/// messages and tools, its token count, and a warning when it leaves no
+/// room in the context for a reply. Useful when a model ignores its system
+/// prompt or runs past its turn. The model must be running.
+#[utoipa::path(
+    post,
+    path = "/preview-prompt",
+    tag = "servers",
+    request_body = PreviewPromptRequest,
+    responses((status = 200, body = PromptPreview))
+)]
+pub async fn preview_prompt(
+    State(state): State<AppState>,
+    Json(req): Json<PreviewPromptRequest>,
+) -> Result<Json<PromptPreview>, HttpError> {
+    Ok(Json(state.servers.preview_prompt(req).await?))
+}
+
 // ============================================================================
 // Path-based handlers (legacy: /api/servers/{id}/start, /api/servers/{id}/stop)
 // ============================================================================
 
 /// Start a model server (path-based: model ID in URL).
+#[utoipa::path(
+    post,
+    path = "/servers/{id}/start",
+    tag = "servers",
+    params(("id" = i64, Path, description = "ID of the served model")),
+    request_body = StartServerRequest,
+    responses((status = 200, body = StartServerResponse))
+)]
 pub async fn start(
     State(state): State<AppState>,
     Path(id): Path<i64>,
@@ -41,6 +145,13 @@ pub async fn start(
 }
 
 /// Stop a model server (path-based: model ID in URL).
+#[utoipa::path(
+    post,
+    path = "/servers/{id}/stop",
+    tag = "servers",
+    params(("id" = i64, Path, description = "ID of the served model")),
+    responses((status = 200, description = "Confirmation message", body = String))
+)]
 pub async fn stop(
     State(state): State<AppState>,
     Path(id): Path<i64>,
@@ -55,7 +166,7 @@ pub async fn stop(
 
 /// Request body for starting a server via collection route.
 /// Maps frontend's `id` field to backend's `model_id`.
-#[derive(Debug, serde::Deserialize)]
+#[derive(Debug, serde::Deserialize, ToSchema)]
 pub struct StartServerBody {
     /// Model ID - frontend sends as `id`
     #[serde(alias = "id")]
@@ -65,6 +176,13 @@ pub struct StartServerBody {
 }
 
 /// Start a model server (body-based: model ID in request body).
+#[utoipa::path(
+    post,
+    path = "/servers/start",
+    tag = "servers",
+    request_body = StartServerBody,
+    responses((status = 200, body = StartServerResponse))
+)]
 pub async fn start_body(
     State(state): State<AppState>,
     Json(body): Json<StartServerBody>,
@@ -76,12 +194,19 @@ pub async fn start_body(
 }
 
 /// Request body for stopping a server via collection route.
-#[derive(Debug, serde::Deserialize)]
+#[derive(Debug, serde::Deserialize, ToSchema)]
 pub struct StopServerBody {
     pub model_id: i64,
 }
 
 /// Stop a model server (body-based: model ID in request body).
+#[utoipa::path(
+    post,
+    path = "/servers/stop",
+    tag = "servers",
+    request_body = StopServerBody,
+    responses((status = 200, description = "Confirmation message", body = String))
+)]
 pub async fn stop_body(
     State(state): State<AppState>,
     Json(body): Json<StopServerBody>,
@@ -100,6 +225,13 @@ use tokio_stream::StreamExt;
 use tokio_stream::wrappers::BroadcastStream;
 
 /// Get initial server logs for a specific port (REST endpoint).
+#[utoipa::path(
+    get,
+    path = "/servers/{port}/logs",
+    tag = "servers",
+    params(("port" = u16, Path, description = "Port of the llama-server instance")),
+    responses((status = 200, body = Vec<gglib_app_services::types::ServerLogEntry>))
+)]
 pub async fn get_logs(
     State(state): State<AppState>,
     Path(port): Path<u16>,
@@ -111,6 +243,13 @@ pub async fn get_logs(
 ///
 /// Subscribes to the global log broadcast and filters by port.
 /// Includes keep-alive pings every 30 seconds to prevent proxy timeouts.
+#[utoipa::path(
+    get,
+    path = "/servers/{port}/logs/stream",
+    tag = "servers",
+    params(("port" = u16, Path, description = "Port of the llama-server instance")),
+    responses((status = 200, description = "One `ServerLogEntry` per event", content_type = "text/event-stream"))
+)]
 pub async fn stream_logs(
     State(state): State<AppState>,
     Path(port): Path<u16>,
@@ -149,6 +288,13 @@ pub async fn stream_logs(
 }
 
 /// Clear logs for a specific server port (DELETE endpoint).
+#[utoipa::path(
+    delete,
+    path = "/servers/{port}/logs",
+    tag = "servers",
+    params(("port" = u16, Path, description = "Port of the llama-server instance")),
+    responses((status = 200, description = "Confirmation message", body = String))
+)]
 pub async fn clear_logs(State(state): State<AppState>, Path(port): Path<u16>) -> Json<String> {
     state.servers.clear_logs(port);
     Json(format!("Logs cleared for port {}", port))
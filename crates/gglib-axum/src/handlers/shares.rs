@@ -0,0 +1,161 @@
+//! Conversation share handlers - read-only links to a transcript.
+//!
+//! The owner creates, lists and revokes links under `/api`; the links
+//! themselves open at `/share/{token}` (a minimal HTML page) and
+//! `/share/{token}/json`, outside `/api` so they need no API token. See
+//! [`gglib_core::domain::chat_share`] for how tokens are signed.
+
+use std::fmt::Write as _;
+
+use axum::Json;
+use axum::extract::{Path, State};
+use axum::response::Html;
+use serde::Deserialize;
+use utoipa::ToSchema;
+
+use crate::error::HttpError;
+use crate::state::AppState;
+use gglib_core::domain::chat::MessageRole;
+use gglib_core::domain::chat_share::{ConversationShare, SharedConversation};
+
+/// Request body for creating a share link.
+#[derive(Debug, Default, Deserialize, ToSchema)]
+pub struct CreateShareRequest {
+    /// Days the link stays valid; 7 when omitted, at most 90.
+    #[serde(default)]
+    pub days: Option<u32>,
+}
+
+/// Create a read-only share link to a conversation.
+///
+/// The link opens at `/share/{token}`, or `/share/{token}/json` for the
+/// transcript as JSON.
+#[utoipa::path(
+    post,
+    path = "/conversations/{id}/shares",
+    tag = "conversations",
+    params(("id" = i64, Path, description = "Conversation ID")),
+    request_body = CreateShareRequest,
+    responses((status = 200, body = ConversationShare))
+)]
+pub async fn create(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+    Json(req): Json<CreateShareRequest>,
+) -> Result<Json<ConversationShare>, HttpError> {
+    let share = state.core.chat_history().create_share(id, req.days).await?;
+    Ok(Json(share))
+}
+
+/// List the share links to a conversation, newest first.
+#[utoipa::path(
+    get,
+    path = "/conversations/{id}/shares",
+    tag = "conversations",
+    params(("id" = i64, Path, description = "Conversation ID")),
+    responses((status = 200, body = Vec<ConversationShare>))
+)]
+pub async fn list(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+) -> Result<Json<Vec<ConversationShare>>, HttpError> {
+    Ok(Json(state.core.chat_history().list_shares(id).await?))
+}
+
+/// Revoke a share link; it stops opening at once.
+#[utoipa::path(
+    delete,
+    path = "/shares/{id}",
+    tag = "conversations",
+    params(("id" = i64, Path, description = "Share ID")),
+    responses((status = 200, description = "Done"))
+)]
+pub async fn revoke(State(state): State<AppState>, Path(id): Path<i64>) -> Result<(), HttpError> {
+    state.core.chat_history().revoke_share(id).await?;
+    Ok(())
+}
+
+/// The shared conversation as JSON (public).
+pub async fn view_json(
+    State(state): State<AppState>,
+    Path(token): Path<String>,
+) -> Result<Json<SharedConversation>, HttpError> {
+    Ok(Json(state.core.chat_history().open_share(&token).await?))
+}
+
+/// The shared conversation as a minimal HTML page (public).
+pub async fn view_html(
+    State(state): State<AppState>,
+    Path(token): Path<String>,
+) -> Result<Html<String>, HttpError> {
+    let shared = state.core.chat_history().open_share(&token).await?;
+    Ok(Html(render_html(&shared)))
+}
+
+fn render_html(shared: &SharedConversation) -> String {
+    let title = escape(&shared.title);
+    let mut html = format!(
+        "<!doctype html>\n<html>\n<head>\n  <meta charset=\"utf-8\">\n  \
+         <meta name=\"viewport\" content=\"width=device-width, initial-scale=1\">\n  \
+         <meta name=\"robots\" content=\"noindex\">\n  <title>{title}</title>\n  \
+         <style>{SHARE_CSS}</style>\n</head>\n<body>\n  <h1>{title}</h1>\n"
+    );
+    for message in &shared.messages {
+        let (class, who) = match message.role {
+            MessageRole::User => ("user", "User"),
+            _ => ("assistant", "Assistant"),
+        };
+        let _ = writeln!(
+            html,
+            "  <section class=\"{class}\"><h2>{who}</h2><pre>{}</pre></section>",
+            escape(&message.content)
+        );
+    }
+    let _ = write!(
+        html,
+        "  <footer>Shared read-only from gglib · link expires {}</footer>\n</body>\n</html>\n",
+        shared.expires_at.format("%Y-%m-%d %H:%M UTC")
+    );
+    html
+}
+
+const SHARE_CSS: &str = "body{font-family:system-ui,sans-serif;max-width:48rem;margin:2rem auto;\
+padding:0 1rem;line-height:1.5}section{border-radius:.5rem;padding:.5rem 1rem;margin:1rem 0}\
+.user{background:#eef3fb}.assistant{background:#f5f5f5}h2{font-size:.85rem;margin:.25rem 0}\
+pre{white-space:pre-wrap;font-family:inherit;margin:0}footer{color:#777;font-size:.8rem}";
+
+/// Escape `text` for HTML element content and attribute values.
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&#39;")
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+    use gglib_core::domain::chat_share::SharedMessage;
+
+    use super::*;
+
+    #[test]
+    fn html_view_escapes_the_transcript() {
+        let shared = SharedConversation {
+            title: "Tags <b>".to_string(),
+            messages: vec![SharedMessage {
+                role: MessageRole::Assistant,
+                content: "Use <script>alert(1)</script> & see".to_string(),
+                created_at: String::new(),
+            }],
+            shared_at: Utc.with_ymd_and_hms(2026, 5, 1, 0, 0, 0).unwrap(),
+            expires_at: Utc.with_ymd_and_hms(2026, 5, 8, 0, 0, 0).unwrap(),
+        };
+        let html = render_html(&shared);
+        assert!(html.contains("<title>Tags &lt;b&gt;</title>"));
+        assert!(html.contains("Use &lt;script&gt;alert(1)&lt;/script&gt; &amp; see"));
+        assert!(!html.contains("<script>"));
+        assert!(html.contains("link expires 2026-05-08 00:00 UTC"));
+    }
+}
@@ -0,0 +1,125 @@
+//! Library sync handlers - pairing with other instances and pulling models.
+//!
+//! Two groups of endpoints live here. `GET /api/sync/library` and
+//! `POST /api/sync/pair` are called by *peers* and, like the model file
+//! endpoint, require this instance's model share token. The `/api/sync/peers`
+//! endpoints manage the peers this instance pulls from; queued pulls report
+//! progress through the ordinary download events.
+
+use axum::Json;
+use axum::extract::{Path, State};
+use axum::http::HeaderMap;
+
+use crate::error::HttpError;
+use crate::handlers::model::file::authorize;
+use crate::state::AppState;
+use gglib_app_services::types::{PairPeerRequest, SyncPeerInfo, SyncPullResult};
+use gglib_core::domain::sync::{LibraryEntry, PairRequest};
+
+/// This library, as listed to peers.
+#[utoipa::path(
+    get,
+    path = "/sync/library",
+    tag = "sync",
+    security(("share_token" = [])),
+    responses((status = 200, body = Vec<LibraryEntry>))
+)]
+pub async fn library(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+) -> Result<Json<Vec<LibraryEntry>>, HttpError> {
+    authorize(state.model_share_token.as_deref(), &headers)?;
+    Ok(Json(state.sync.library().await?))
+}
+
+/// Store a peer that paired with this instance.
+#[utoipa::path(
+    post,
+    path = "/sync/pair",
+    tag = "sync",
+    request_body = PairRequest,
+    security(("share_token" = [])),
+    responses((status = 200, body = SyncPeerInfo))
+)]
+pub async fn accept_pair(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Json(req): Json<PairRequest>,
+) -> Result<Json<SyncPeerInfo>, HttpError> {
+    authorize(state.model_share_token.as_deref(), &headers)?;
+    Ok(Json(state.sync.accept_pair(req).await?))
+}
+
+/// List paired instances.
+#[utoipa::path(
+    get,
+    path = "/sync/peers",
+    tag = "sync",
+    responses((status = 200, body = Vec<SyncPeerInfo>))
+)]
+pub async fn list_peers(
+    State(state): State<AppState>,
+) -> Result<Json<Vec<SyncPeerInfo>>, HttpError> {
+    Ok(Json(state.sync.peers().await?))
+}
+
+/// Pair with another instance.
+#[utoipa::path(
+    post,
+    path = "/sync/peers",
+    tag = "sync",
+    request_body = PairPeerRequest,
+    responses((status = 200, body = SyncPeerInfo))
+)]
+pub async fn pair(
+    State(state): State<AppState>,
+    Json(req): Json<PairPeerRequest>,
+) -> Result<Json<SyncPeerInfo>, HttpError> {
+    Ok(Json(state.sync.pair(req).await?))
+}
+
+/// Forget a paired instance.
+#[utoipa::path(
+    delete,
+    path = "/sync/peers/{name}",
+    tag = "sync",
+    params(("name" = String, Path, description = "Peer name")),
+    responses((status = 200, description = "Done"))
+)]
+pub async fn unpair(
+    State(state): State<AppState>,
+    Path(name): Path<String>,
+) -> Result<(), HttpError> {
+    state.sync.unpair(&name).await?;
+    Ok(())
+}
+
+/// The peer's models this library lacks.
+#[utoipa::path(
+    get,
+    path = "/sync/peers/{name}/diff",
+    tag = "sync",
+    params(("name" = String, Path, description = "Peer name")),
+    responses((status = 200, body = Vec<LibraryEntry>))
+)]
+pub async fn diff(
+    State(state): State<AppState>,
+    Path(name): Path<String>,
+) -> Result<Json<Vec<LibraryEntry>>, HttpError> {
+    Ok(Json(state.sync.diff(&name).await?))
+}
+
+/// Queue the peer's missing models for download.
+#[utoipa::path(
+    post,
+    path = "/sync/peers/{name}/pull",
+    tag = "sync",
+    params(("name" = String, Path, description = "Peer name")),
+    responses((status = 200, body = SyncPullResult))
+)]
+pub async fn pull(
+    State(state): State<AppState>,
+    Path(name): Path<String>,
+) -> Result<Json<SyncPullResult>, HttpError> {
+    Ok(Json(state.sync.pull(&name).await?))
+}
@@ -29,19 +29,28 @@ use tracing as _;
 use tracing_subscriber as _; // Used by main.rs binary
 use uuid as _; // Will be used by embedded module
 
+pub mod api_key;
+pub mod audit;
 pub mod bootstrap;
 pub mod chat_api;
 pub mod dto;
 pub mod embedded;
 pub mod error;
 pub mod handlers;
+pub mod openapi;
+pub mod rate_limit;
 pub mod routes;
 pub mod sse;
 pub mod state;
+pub mod static_files;
 
 // Re-export primary types
 pub use bootstrap::{AxumContext, CorsConfig, ServerConfig, bootstrap, start_server};
 pub use embedded::{EmbeddedApiInfo, EmbeddedServerConfig, start_embedded_server};
 pub use error::HttpError;
-pub use routes::{create_router, create_spa_router};
+pub use rate_limit::{RateLimit, RateLimitConfig};
+pub use routes::{
+    API_V1_PREFIX, create_router, create_spa_router, create_spa_router_with_fallback,
+};
 pub use state::AppState;
+pub use static_files::SpaFallback;
@@ -0,0 +1,366 @@
+//! OpenAPI document for the versioned REST API.
+//!
+//! [`ApiDoc`] collects the `#[utoipa::path]` annotations on the handlers into
+//! one OpenAPI 3.1 document, served as `/api/v1/openapi.json` (and, like
+//! every route, under the unversioned `/api` alias). Debug builds also serve
+//! a Swagger UI page at `/api/v1/docs`.
+//!
+//! # Compatibility policy
+//!
+//! Operations listed in the document are the stable contract of `/api/v1`.
+//! Within v1 they only change additively: new endpoints, new optional
+//! request fields, new response fields and new enum values or event types.
+//! Clients must ignore fields and event types they do not know. Removing or
+//! renaming anything, changing a type, or making a request field required
+//! needs a new version prefix, and `/api/v1` keeps being served next to it
+//! for at least two minor releases.
+//!
+//! The unversioned `/api` prefix always serves the newest version; the
+//! bundled web UI and desktop app use it because they ship in lockstep with
+//! the server. Endpoints left out of the document (agent, council, voice,
+//! benchmark, pipelines and the `/chat` proxy) are experimental and may
+//! change in any release.
+//!
+//! `openapi-v1.json` next to this crate's manifest is the committed copy of
+//! the document; the `openapi_document_matches_committed_copy` test fails
+//! when a change alters the contract without updating it.
+
+use std::sync::LazyLock;
+
+use axum::Json;
+#[cfg(debug_assertions)]
+use axum::response::Html;
+use utoipa::OpenApi;
+use utoipa::openapi::response::ResponseBuilder;
+use utoipa::openapi::security::{Http, HttpAuthScheme, SecurityScheme};
+use utoipa::openapi::{ContentBuilder, PathItem, Ref};
+
+use crate::chat_api;
+use crate::error::ErrorBody;
+use crate::handlers;
+use gglib_core::domain::{ModelSortBy, SortOrder};
+use gglib_core::download::CompletionKind;
+use gglib_core::events::AppEvent;
+
+/// The v1 API contract.
+#[derive(OpenApi)]
+#[openapi(
+    info(
+        title = "gglib",
+        version = "1",
+        description = "Local GGUF model library, llama-server orchestration and chat history. \
+                       Errors are returned as `ErrorBody`; events arrive as `AppEvent`s on `/events`."
+    ),
+    servers((url = "/api/v1")),
+    paths(
+        // Models
+        handlers::model::models::list,
+        handlers::model::models::page,
+        handlers::model::models::changes,
+        handlers::model::models::add,
+        handlers::model::models::get,
+        handlers::model::models::update,
+        handlers::model::models::remove,
+        handlers::model::models::list_trash,
+        handlers::model::models::restore_trash,
+        handlers::model::models::empty_trash,
+        handlers::model::models::usage,
+        handlers::model::models::usage_daily,
+        handlers::model::models::detail,
+        handlers::model::models::set_capabilities,
+        handlers::model::models::list_tags,
+        handlers::model::models::get_by_tag,
+        handlers::model::models::get_model_tags,
+        handlers::model::models::add_tag_body,
+        handlers::model::models::add_tag,
+        handlers::model::models::remove_tag,
+        handlers::model::models::filter_options,
+        handlers::model::file::download,
+        handlers::model::verification::verify,
+        handlers::model::verification::check_updates,
+        handlers::model::verification::repair,
+        // Downloads
+        handlers::model::downloads::list,
+        handlers::model::downloads::queue,
+        handlers::model::downloads::queue_collection,
+        handlers::model::downloads::remove,
+        handlers::model::downloads::cancel,
+        handlers::model::downloads::reorder,
+        handlers::model::downloads::reorder_full,
+        handlers::model::downloads::cancel_shard_group,
+        handlers::model::downloads::shard_group_progress,
+        handlers::model::downloads::clear_failed,
+        handlers::model::downloads::retry,
+        handlers::model::downloads::max_size,
+        handlers::model::downloads::set_max_size,
+        handlers::model::downloads::history,
+        // HuggingFace
+        handlers::model::hf::search,
+        handlers::model::hf::model_summary,
+        handlers::model::hf::quantizations,
+        handlers::model::hf::tool_support,
+        // Servers
+        handlers::servers::list,
+        handlers::servers::start_body,
+        handlers::servers::stop_body,
+        handlers::servers::start,
+        handlers::servers::stop,
+        handlers::servers::tool_support,
+        handlers::servers::stats,
+        handlers::servers::slots,
+        handlers::servers::warm_prompt,
+        handlers::servers::preview_prompt,
+        handlers::servers::get_logs,
+        handlers::servers::clear_logs,
+        handlers::servers::stream_logs,
+        // Proxy
+        handlers::proxy::status,
+        handlers::proxy::start,
+        handlers::proxy::stop,
+        // Settings and system
+        handlers::config::settings::get,
+        handlers::config::settings::update,
+        handlers::config::settings::memory,
+        handlers::config::settings::models_directory,
+        handlers::config::settings::update_models_directory,
+        handlers::config::setup::status,
+        handlers::config::setup::recommendations,
+        handlers::config::setup::vulkan_status_handler,
+        handlers::config::setup::install_llama,
+        handlers::config::setup::build_llama_from_source,
+        handlers::config::setup::setup_python,
+        handlers::config::onboarding::status,
+        handlers::config::onboarding::run_step,
+        handlers::config::onboarding::skip_step,
+        // Conversations
+        chat_api::list_conversations,
+        chat_api::create_conversation,
+        chat_api::get_conversation,
+        chat_api::update_conversation,
+        chat_api::delete_conversation,
+        chat_api::get_messages,
+        chat_api::save_message,
+        chat_api::update_message,
+        chat_api::delete_message,
+        chat_api::regenerate_message,
+        chat_api::edit_message,
+        chat_api::get_message_versions,
+        handlers::shares::create,
+        handlers::shares::list,
+        handlers::shares::revoke,
+        // Attachments
+        handlers::attachments::upload,
+        handlers::attachments::get,
+        handlers::attachments::content,
+        handlers::attachments::delete,
+        handlers::attachments::list_for_message,
+        // Projects and presets
+        handlers::projects::list,
+        handlers::projects::create,
+        handlers::projects::get,
+        handlers::projects::update,
+        handlers::projects::delete,
+        handlers::projects::tree,
+        handlers::projects::context,
+        handlers::projects::get_for_conversation,
+        handlers::projects::link,
+        handlers::projects::unlink,
+        handlers::presets::list,
+        handlers::presets::create,
+        handlers::presets::get,
+        handlers::presets::update,
+        handlers::presets::delete,
+        // MCP
+        handlers::mcp::list,
+        handlers::mcp::add,
+        handlers::mcp::update,
+        handlers::mcp::remove,
+        handlers::mcp::start,
+        handlers::mcp::stop,
+        handlers::mcp::resolve_path,
+        handlers::mcp::list_tools,
+        handlers::mcp::call_tool,
+        handlers::builtin::list_builtin_tools,
+        // Jobs
+        handlers::jobs::list,
+        handlers::jobs::get,
+        handlers::jobs::cancel,
+        // Audit log
+        handlers::audit::list,
+        // Logs
+        handlers::logs::query,
+        handlers::logs::files,
+        handlers::logs::follow,
+        // Sync and backups
+        handlers::sync::library,
+        handlers::sync::accept_pair,
+        handlers::sync::list_peers,
+        handlers::sync::pair,
+        handlers::sync::unpair,
+        handlers::sync::diff,
+        handlers::sync::pull,
+        handlers::backup::list,
+        handlers::backup::push,
+        handlers::backup::restore,
+        // Events
+        handlers::events::stream,
+    ),
+    // Types only reachable through query parameters are not collected
+    // automatically, so they are listed here with the shared ones.
+    components(schemas(AppEvent, CompletionKind, ErrorBody, ModelSortBy, SortOrder)),
+    modifiers(&SharedConventions),
+    tags(
+        (name = "models", description = "The local model library"),
+        (name = "downloads", description = "The download queue"),
+        (name = "huggingface", description = "HuggingFace discovery"),
+        (name = "servers", description = "llama-server instances"),
+        (name = "proxy", description = "The OpenAI-compatible proxy"),
+        (name = "settings", description = "Application settings"),
+        (name = "system", description = "System probes, setup and onboarding"),
+        (name = "conversations", description = "Chat history"),
+        (name = "attachments", description = "Chat attachments"),
+        (name = "projects", description = "Projects linked to conversations"),
+        (name = "presets", description = "Generation presets"),
+        (name = "mcp", description = "MCP servers and built-in tools"),
+        (name = "jobs", description = "Long-running operations in flight"),
+        (name = "admin", description = "Audit log of administrative actions"),
+        (name = "logs", description = "Application logs"),
+        (name = "sync", description = "Library sync between instances"),
+        (name = "backup", description = "Backups to an S3-compatible bucket"),
+        (name = "events", description = "Server-sent application events"),
+    )
+)]
+pub struct ApiDoc;
+
+/// Adds what every operation shares: the `ErrorBody` error response, an
+/// operation id qualified by its tag (handler names such as `list` repeat
+/// across modules) and the bearer scheme of the model share token.
+struct SharedConventions;
+
+impl utoipa::Modify for SharedConventions {
+    fn modify(&self, openapi: &mut utoipa::openapi::OpenApi) {
+        let error = ResponseBuilder::new()
+            .description("Error, described by `ErrorBody`")
+            .content(
+                "application/json",
+                ContentBuilder::new()
+                    .schema(Some(Ref::from_schema_name("ErrorBody")))
+                    .build(),
+            )
+            .build();
+        for item in openapi.paths.paths.values_mut() {
+            for operation in operations(item) {
+                if let (Some(id), Some(tag)) = (&mut operation.operation_id, &operation.tags)
+                    && let Some(tag) = tag.first()
+                {
+                    *id = format!("{tag}_{id}");
+                }
+                operation
+                    .responses
+                    .responses
+                    .entry("default".to_string())
+                    .or_insert_with(|| error.clone().into());
+            }
+        }
+
+        if let Some(components) = openapi.components.as_mut() {
+            components.add_security_scheme(
+                "share_token",
+                SecurityScheme::Http(Http::new(HttpAuthScheme::Bearer)),
+            );
+        }
+    }
+}
+
+fn operations(item: &mut PathItem) -> impl Iterator<Item = &mut utoipa::openapi::path::Operation> {
+    [
+        item.get.as_mut(),
+        item.put.as_mut(),
+        item.post.as_mut(),
+        item.delete.as_mut(),
+        item.patch.as_mut(),
+    ]
+    .into_iter()
+    .flatten()
+}
+
+static DOCUMENT: LazyLock<utoipa::openapi::OpenApi> = LazyLock::new(ApiDoc::openapi);
+
+/// Serve the OpenAPI document.
+pub(crate) async fn document() -> Json<&'static utoipa::openapi::OpenApi> {
+    Json(&DOCUMENT)
+}
+
+/// Serve a Swagger UI page for the document next to it.
+///
+/// Only routed in debug builds; the page loads Swagger UI from a CDN.
+#[cfg(debug_assertions)]
+pub(crate) async fn swagger_ui() -> Html<&'static str> {
+    Html(SWAGGER_UI)
+}
+
+#[cfg(debug_assertions)]
+const SWAGGER_UI: &str = r##"<!doctype html>
+<html>
+<head>
+  <meta charset="utf-8">
+  <title>gglib API</title>
+  <link rel="stylesheet" href="https://unpkg.com/swagger-ui-dist@5/swagger-ui.css">
+</head>
+<body>
+  <div id="swagger-ui"></div>
+  <script src="https://unpkg.com/swagger-ui-dist@5/swagger-ui-bundle.js"></script>
+  <script>
+    SwaggerUIBundle({ url: "openapi.json", dom_id: "#swagger-ui" });
+  </script>
+</body>
+</html>
+"##;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_operation_documents_the_error_body() {
+        let mut doc = ApiDoc::openapi();
+        for item in doc.paths.paths.values_mut() {
+            for operation in operations(item) {
+                assert!(
+                    operation.responses.responses.contains_key("default"),
+                    "{:?} has no error response",
+                    operation.operation_id
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn operation_ids_are_unique() {
+        let mut doc = ApiDoc::openapi();
+        let mut seen = std::collections::HashSet::new();
+        for item in doc.paths.paths.values_mut() {
+            for operation in operations(item) {
+                let id = operation.operation_id.clone().expect("operation id");
+                assert!(seen.insert(id.clone()), "duplicate operation id {id}");
+            }
+        }
+    }
+
+    #[test]
+    fn openapi_document_matches_committed_copy() {
+        let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("openapi-v1.json");
+        let fresh = format!("{}\n", DOCUMENT.to_pretty_json().unwrap());
+        if std::env::var_os("GGLIB_UPDATE_BINDINGS").is_some() {
+            std::fs::write(&path, fresh).unwrap();
+            return;
+        }
+        let committed = std::fs::read_to_string(&path).unwrap_or_default();
+        assert!(
+            committed == fresh,
+            "openapi-v1.json is out of date with the handlers. If the change keeps the \
+             compatibility policy in src/openapi.rs, regenerate it with: \
+             GGLIB_UPDATE_BINDINGS=1 cargo test -p gglib-axum --lib openapi"
+        );
+    }
+}
@@ -0,0 +1,444 @@
+//! Per-client request rate limiting for the HTTP API.
+//!
+//! Each client has a token bucket per [`RateClass`]: every request takes a
+//! token and buckets refill continuously, so a client can burst up to the
+//! bucket size and then sustain the per-minute rate. Heavy endpoints —
+//! uploads and chat, which hold a model or disk busy — draw from their own,
+//! smaller bucket, so one client streaming chats can't starve others of the
+//! cheap listing calls the UI polls.
+//!
+//! Clients are told apart by peer IP address, except that a request whose
+//! API key the server verified gets buckets of its own for that key: the
+//! embedded server's token, or one of [`RateLimitConfig::api_keys`]. Any
+//! other key, and `X-Forwarded-For`, is ignored — nothing checks them, so a
+//! client could send a fresh value each time to dodge the limit.
+//!
+//! Every limited response carries `X-RateLimit-Limit`,
+//! `X-RateLimit-Remaining` and `X-RateLimit-Reset` (seconds until the bucket
+//! is full again). Rejected requests get `429` with `Retry-After`.
+
+use std::collections::HashMap;
+use std::hash::{BuildHasher, RandomState};
+use std::net::{IpAddr, SocketAddr};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use axum::extract::{ConnectInfo, Request, State};
+use axum::http::{HeaderMap, HeaderName, HeaderValue, Method, header};
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
+use subtle::ConstantTimeEq;
+
+use crate::api_key::{self, VerifiedApiKey};
+use crate::error::HttpError;
+
+/// `X-RateLimit-Limit`: the bucket size.
+pub const LIMIT_HEADER: HeaderName = HeaderName::from_static("x-ratelimit-limit");
+/// `X-RateLimit-Remaining`: requests left before the limit is hit.
+pub const REMAINING_HEADER: HeaderName = HeaderName::from_static("x-ratelimit-remaining");
+/// `X-RateLimit-Reset`: seconds until the bucket is full again.
+pub const RESET_HEADER: HeaderName = HeaderName::from_static("x-ratelimit-reset");
+
+/// `POST` endpoints (relative to `/api`) limited as [`RateClass::Heavy`].
+const HEAVY_PATHS: &[&str] = &[
+    "/attachments",
+    "/chat",
+    "/agent/chat",
+    "/council/plan",
+    "/council/run",
+];
+
+/// Buckets kept before idle ones are dropped.
+const PRUNE_THRESHOLD: usize = 4096;
+
+/// One token bucket's size and refill rate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimit {
+    /// Requests refilled per minute.
+    pub per_minute: u32,
+    /// Bucket size: requests allowed back to back.
+    pub burst: u32,
+}
+
+impl RateLimit {
+    /// `per_minute` requests a minute, all of which may come at once.
+    #[must_use]
+    pub const fn per_minute(per_minute: u32) -> Self {
+        Self {
+            per_minute,
+            burst: per_minute,
+        }
+    }
+
+    fn capacity(self) -> f64 {
+        f64::from(self.burst.max(1))
+    }
+
+    fn tokens_per_sec(self) -> f64 {
+        f64::from(self.per_minute.max(1)) / 60.0
+    }
+}
+
+/// Limits applied to each client.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RateLimitConfig {
+    /// Every API endpoint not listed as heavy.
+    pub general: RateLimit,
+    /// Uploads and chat.
+    pub heavy: RateLimit,
+    /// Keys clients may present (`Authorization: Bearer` or `X-API-Key`)
+    /// to be limited per key instead of per IP address, so people behind
+    /// one address don't share buckets. They don't gate access: a request
+    /// with no key, or one not listed here, is still served and limited by
+    /// its IP address.
+    pub api_keys: Vec<String>,
+}
+
+impl Default for RateLimitConfig {
+    fn default() -> Self {
+        Self {
+            general: RateLimit::per_minute(300),
+            heavy: RateLimit::per_minute(30),
+            api_keys: Vec::new(),
+        }
+    }
+}
+
+/// Which bucket a request draws from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RateClass {
+    /// Cheap reads and small writes.
+    General,
+    /// Uploads and chat.
+    Heavy,
+}
+
+impl RateClass {
+    /// Classify a request by method and path (as seen inside `/api`).
+    #[must_use]
+    pub fn of(method: &Method, path: &str) -> Self {
+        let path = path.strip_suffix('/').unwrap_or(path);
+        if method == Method::POST && HEAVY_PATHS.contains(&path) {
+            Self::Heavy
+        } else {
+            Self::General
+        }
+    }
+}
+
+/// Who a bucket belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum ClientId {
+    Ip(IpAddr),
+    /// A verified API key, hashed.
+    Key(u64),
+    /// No peer address (e.g. served without connect info).
+    Unknown,
+}
+
+#[derive(Debug)]
+struct Bucket {
+    tokens: f64,
+    updated: Instant,
+}
+
+/// The outcome of one request against its bucket.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Decision {
+    /// Whether the request may proceed.
+    pub allowed: bool,
+    /// Bucket size.
+    pub limit: u32,
+    /// Whole requests left in the bucket.
+    pub remaining: u32,
+    /// Seconds until the bucket is full.
+    pub reset_secs: u64,
+    /// Seconds until the next request would be allowed (0 when allowed).
+    pub retry_after_secs: u64,
+}
+
+impl Decision {
+    fn write_headers(&self, headers: &mut HeaderMap) {
+        headers.insert(LIMIT_HEADER, HeaderValue::from(self.limit));
+        headers.insert(REMAINING_HEADER, HeaderValue::from(self.remaining));
+        headers.insert(RESET_HEADER, HeaderValue::from(self.reset_secs));
+        if !self.allowed {
+            headers.insert(
+                header::RETRY_AFTER,
+                HeaderValue::from(self.retry_after_secs),
+            );
+        }
+    }
+}
+
+/// Token buckets for every client seen recently.
+#[derive(Debug)]
+pub struct RateLimiter {
+    config: RateLimitConfig,
+    key_hasher: RandomState,
+    buckets: Mutex<HashMap<(ClientId, RateClass), Bucket>>,
+}
+
+impl RateLimiter {
+    /// A limiter enforcing `config`.
+    #[must_use]
+    pub fn new(config: RateLimitConfig) -> Self {
+        Self {
+            config,
+            key_hasher: RandomState::new(),
+            buckets: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// The limits this limiter enforces.
+    #[must_use]
+    pub fn config(&self) -> &RateLimitConfig {
+        &self.config
+    }
+
+    fn limit(&self, class: RateClass) -> RateLimit {
+        match class {
+            RateClass::General => self.config.general,
+            RateClass::Heavy => self.config.heavy,
+        }
+    }
+
+    /// Whose buckets `req` draws from. A key found in
+    /// [`RateLimitConfig::api_keys`] is marked verified on the request, so
+    /// the layers inside (the audit log) trust it too.
+    fn client_id(&self, req: &mut Request) -> ClientId {
+        if let Some(VerifiedApiKey(key)) = req.extensions().get::<VerifiedApiKey>() {
+            return ClientId::Key(self.key_hasher.hash_one(key.as_bytes()));
+        }
+        let configured = api_key::presented(req.headers()).and_then(|presented| {
+            self.config
+                .api_keys
+                .iter()
+                .find(|key| bool::from(key.as_bytes().ct_eq(presented.as_bytes())))
+        });
+        if let Some(key) = configured {
+            req.extensions_mut()
+                .insert(VerifiedApiKey(Arc::from(key.as_str())));
+            return ClientId::Key(self.key_hasher.hash_one(key.as_bytes()));
+        }
+        req.extensions()
+            .get::<ConnectInfo<SocketAddr>>()
+            .map_or(ClientId::Unknown, |ConnectInfo(addr)| {
+                ClientId::Ip(addr.ip())
+            })
+    }
+
+    /// Take a token from `client`'s `class` bucket at `now`.
+    fn check(&self, client: ClientId, class: RateClass, now: Instant) -> Decision {
+        let limit = self.limit(class);
+        let (capacity, rate) = (limit.capacity(), limit.tokens_per_sec());
+
+        let mut buckets = self.buckets.lock().unwrap_or_else(|e| e.into_inner());
+        if buckets.len() >= PRUNE_THRESHOLD {
+            // A bucket idle long enough to have refilled is the same as none.
+            buckets.retain(|(_, class), bucket| {
+                let limit = self.limit(*class);
+                let full_after = Duration::from_secs_f64(limit.capacity() / limit.tokens_per_sec());
+                now.duration_since(bucket.updated) < full_after
+            });
+        }
+        let bucket = buckets.entry((client, class)).or_insert(Bucket {
+            tokens: capacity,
+            updated: now,
+        });
+
+        let elapsed = now.duration_since(bucket.updated).as_secs_f64();
+        bucket.tokens = (bucket.tokens + elapsed * rate).min(capacity);
+        bucket.updated = now;
+
+        let allowed = bucket.tokens >= 1.0;
+        if allowed {
+            bucket.tokens -= 1.0;
+        }
+        Decision {
+            allowed,
+            limit: limit.burst.max(1),
+            remaining: bucket.tokens.floor() as u32,
+            reset_secs: ((capacity - bucket.tokens) / rate).ceil() as u64,
+            retry_after_secs: if allowed {
+                0
+            } else {
+                ((1.0 - bucket.tokens) / rate).ceil() as u64
+            },
+        }
+    }
+}
+
+/// Middleware enforcing the limiter on every request it wraps.
+pub(crate) async fn enforce(
+    State(limiter): State<Arc<RateLimiter>>,
+    mut req: Request,
+    next: Next,
+) -> Response {
+    let class = RateClass::of(req.method(), req.uri().path());
+    let client = limiter.client_id(&mut req);
+    let decision = limiter.check(client, class, Instant::now());
+
+    let mut response = if decision.allowed {
+        next.run(req).await
+    } else {
+        tracing::warn!(
+            path = %req.uri().path(),
+            ?class,
+            retry_after = decision.retry_after_secs,
+            "Rate limit exceeded"
+        );
+        HttpError::TooManyRequests(format!(
+            "Rate limit exceeded; retry in {}s",
+            decision.retry_after_secs
+        ))
+        .into_response()
+    };
+    decision.write_headers(response.headers_mut());
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::Router;
+    use axum::body::Body;
+    use axum::http::StatusCode;
+    use axum::routing::{get, post};
+    use tower::ServiceExt;
+
+    fn limiter(general: u32, heavy: u32) -> RateLimiter {
+        RateLimiter::new(RateLimitConfig {
+            general: RateLimit::per_minute(general),
+            heavy: RateLimit::per_minute(heavy),
+            api_keys: vec!["team-key".to_string()],
+        })
+    }
+
+    #[test]
+    fn buckets_drain_then_refill() {
+        let limiter = limiter(60, 60);
+        let client = ClientId::Ip([10, 0, 0, 1].into());
+        let start = Instant::now();
+
+        for left in (0..60).rev() {
+            let decision = limiter.check(client, RateClass::General, start);
+            assert!(decision.allowed);
+            assert_eq!(decision.remaining, left);
+        }
+        let denied = limiter.check(client, RateClass::General, start);
+        assert!(!denied.allowed);
+        assert_eq!(denied.retry_after_secs, 1);
+        assert_eq!(denied.reset_secs, 60);
+
+        // One request a second comes back.
+        let later = start + Duration::from_secs(1);
+        assert!(limiter.check(client, RateClass::General, later).allowed);
+    }
+
+    #[test]
+    fn clients_and_classes_have_separate_buckets() {
+        let limiter = limiter(1, 1);
+        let now = Instant::now();
+        let a = ClientId::Ip([10, 0, 0, 1].into());
+        let b = ClientId::Ip([10, 0, 0, 2].into());
+
+        assert!(limiter.check(a, RateClass::Heavy, now).allowed);
+        assert!(!limiter.check(a, RateClass::Heavy, now).allowed);
+        assert!(limiter.check(a, RateClass::General, now).allowed);
+        assert!(limiter.check(b, RateClass::Heavy, now).allowed);
+    }
+
+    #[test]
+    fn uploads_and_chat_are_heavy() {
+        assert_eq!(RateClass::of(&Method::POST, "/chat"), RateClass::Heavy);
+        assert_eq!(
+            RateClass::of(&Method::POST, "/attachments/"),
+            RateClass::Heavy
+        );
+        assert_eq!(
+            RateClass::of(&Method::GET, "/attachments"),
+            RateClass::General
+        );
+        assert_eq!(RateClass::of(&Method::GET, "/models"), RateClass::General);
+    }
+
+    #[tokio::test]
+    async fn middleware_sets_headers_and_rejects_per_client() {
+        let limiter = Arc::new(limiter(10, 1));
+        let router = Router::new()
+            .route("/models", get(|| async { "ok" }))
+            .route("/chat", post(|| async { "ok" }))
+            .layer(axum::middleware::from_fn_with_state(limiter, enforce));
+        let chat = |ip: [u8; 4], key: &str| {
+            let mut req = Request::post("/chat")
+                .header(header::AUTHORIZATION, format!("Bearer {key}"))
+                .body(Body::empty())
+                .unwrap();
+            req.extensions_mut()
+                .insert(ConnectInfo(SocketAddr::from((ip, 40_000))));
+            req
+        };
+
+        let ok = router
+            .clone()
+            .oneshot(chat([10, 0, 0, 1], "alice"))
+            .await
+            .unwrap();
+        assert_eq!(ok.status(), StatusCode::OK);
+        assert_eq!(ok.headers()[&LIMIT_HEADER], "1");
+        assert_eq!(ok.headers()[&REMAINING_HEADER], "0");
+
+        let limited = router
+            .clone()
+            .oneshot(chat([10, 0, 0, 1], "alice"))
+            .await
+            .unwrap();
+        assert_eq!(limited.status(), StatusCode::TOO_MANY_REQUESTS);
+        assert_eq!(limited.headers()[header::RETRY_AFTER], "60");
+
+        // An unverified key from the same address draws from the same
+        // bucket.
+        let rotated = router
+            .clone()
+            .oneshot(chat([10, 0, 0, 1], "bob"))
+            .await
+            .unwrap();
+        assert_eq!(rotated.status(), StatusCode::TOO_MANY_REQUESTS);
+
+        let other = router
+            .clone()
+            .oneshot(chat([10, 0, 0, 2], "alice"))
+            .await
+            .unwrap();
+        assert_eq!(other.status(), StatusCode::OK);
+
+        // A configured key has its own bucket wherever it comes from, so
+        // it isn't held to the exhausted address's.
+        let keyed = router
+            .clone()
+            .oneshot(chat([10, 0, 0, 1], "team-key"))
+            .await
+            .unwrap();
+        assert_eq!(keyed.status(), StatusCode::OK);
+        let keyed_again = router
+            .clone()
+            .oneshot(chat([10, 0, 0, 3], "team-key"))
+            .await
+            .unwrap();
+        assert_eq!(keyed_again.status(), StatusCode::TOO_MANY_REQUESTS);
+
+        // So does a key the embedded server's token check verified.
+        let mut embedded = chat([10, 0, 0, 1], "embedded-token");
+        embedded
+            .extensions_mut()
+            .insert(VerifiedApiKey(Arc::from("embedded-token")));
+        let embedded = router.clone().oneshot(embedded).await.unwrap();
+        assert_eq!(embedded.status(), StatusCode::OK);
+
+        let list = Request::get("/models").body(Body::empty()).unwrap();
+        let listed = router.oneshot(list).await.unwrap();
+        assert_eq!(listed.status(), StatusCode::OK);
+        assert_eq!(listed.headers()[&LIMIT_HEADER], "10");
+    }
+}
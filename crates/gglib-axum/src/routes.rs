@@ -5,18 +5,31 @@
 
 use axum::Json;
 use axum::Router;
-use axum::extract::DefaultBodyLimit;
+use axum::extract::{DefaultBodyLimit, State};
+use axum::http::StatusCode;
+use axum::middleware;
 use axum::routing::{delete, get, post, put};
 use serde_json::{Value, json};
 use std::path::Path;
 use std::sync::Arc;
 use tower_http::cors::{Any, CorsLayer};
-use tower_http::services::{ServeDir, ServeFile};
 
+use gglib_core::readiness::ReadinessReport;
+
+use crate::audit;
 use crate::bootstrap::{AxumContext, CorsConfig};
 use crate::chat_api::chat_routes_no_prefix;
 use crate::handlers;
+use crate::rate_limit;
 use crate::state::AppState;
+use crate::static_files::{SpaFallback, static_router};
+
+/// Headers browser code may read to pace itself against the rate limit.
+const RATE_LIMIT_HEADERS: [axum::http::HeaderName; 3] = [
+    rate_limit::LIMIT_HEADER,
+    rate_limit::REMAINING_HEADER,
+    rate_limit::RESET_HEADER,
+];
 
 /// Build CORS layer from configuration.
 fn build_cors_layer(config: &CorsConfig) -> CorsLayer {
@@ -24,7 +37,8 @@ fn build_cors_layer(config: &CorsConfig) -> CorsLayer {
         CorsConfig::AllowAll => CorsLayer::new()
             .allow_origin(Any)
             .allow_methods(Any)
-            .allow_headers(Any),
+            .allow_headers(Any)
+            .expose_headers(RATE_LIMIT_HEADERS),
         CorsConfig::AllowOrigins(origins) => {
             use axum::http::HeaderValue;
             let allowed: Vec<HeaderValue> = origins.iter().filter_map(|o| o.parse().ok()).collect();
@@ -32,22 +46,35 @@ fn build_cors_layer(config: &CorsConfig) -> CorsLayer {
                 .allow_origin(allowed)
                 .allow_methods(Any)
                 .allow_headers(Any)
+                .expose_headers(RATE_LIMIT_HEADERS)
         }
     }
 }
 
+/// Prefix of the stable v1 API; see [`crate::openapi`] for its compatibility policy.
+pub const API_V1_PREFIX: &str = "/api/v1";
+
+/// Mount `api` under `/api/v1` and under the unversioned `/api` alias.
+///
+/// The alias always serves the newest version and is what the bundled UI
+/// calls; third-party clients should pin the versioned prefix.
+pub(crate) fn nest_api(router: Router<AppState>, api: Router<AppState>) -> Router<AppState> {
+    router.nest(API_V1_PREFIX, api.clone()).nest("/api", api)
+}
+
 /// Build all API routes without `/api` prefix (for nesting under /api).
 ///
 /// Returns a router typed as `Router<AppState>` (state inferred from handlers)
 /// but WITHOUT `.with_state()` applied. The caller must apply `.with_state()` before
 /// nesting. All endpoints are defined without the `/api` prefix since this router
-/// will be nested under `/api` by the caller.
+/// will be nested under `/api/v1` and `/api` by [`nest_api`].
 ///
 /// Routes are organized into domain groups:
 /// - `/models/*`  — CRUD, tags, verification, downloads, HuggingFace discovery
 /// - `/config/*`  — settings, system setup
 pub(crate) fn api_routes() -> Router<AppState> {
-    Router::new()
+    let router = Router::new()
+        .route("/openapi.json", get(crate::openapi::document))
         .nest("/models", model_routes())
         .nest("/config", config_routes())
         // Servers API
@@ -60,6 +87,13 @@ pub(crate) fn api_routes() -> Router<AppState> {
             "/servers/{id}/tool-support",
             get(handlers::servers::tool_support),
         )
+        .route("/servers/{id}/stats", get(handlers::servers::stats))
+        .route("/servers/{id}/slots", get(handlers::servers::slots))
+        .route(
+            "/servers/{id}/prompts/warm",
+            post(handlers::servers::warm_prompt),
+        )
+        .route("/preview-prompt", post(handlers::servers::preview_prompt))
         .route(
             "/servers/{port}/logs",
             get(handlers::servers::get_logs).delete(handlers::servers::clear_logs),
@@ -68,6 +102,91 @@ pub(crate) fn api_routes() -> Router<AppState> {
             "/servers/{port}/logs/stream",
             get(handlers::servers::stream_logs),
         )
+        // Jobs API: long-running operations, followed via `job:updated` events
+        .route("/jobs", get(handlers::jobs::list))
+        .route("/jobs/{id}", get(handlers::jobs::get))
+        .route("/jobs/{id}/cancel", post(handlers::jobs::cancel))
+        // Audit log of administrative actions
+        .route("/admin/audit", get(handlers::audit::list))
+        // Application logs API
+        .route("/logs", get(handlers::logs::query))
+        .route("/logs/files", get(handlers::logs::files))
+        .route("/logs/stream", get(handlers::logs::follow))
+        // Chat attachments API. The body cap is the largest configurable
+        // limit; the configured one is enforced by the attachment service.
+        .route(
+            "/attachments",
+            post(handlers::attachments::upload).layer(DefaultBodyLimit::max(
+                handlers::attachments::MAX_UPLOAD_BODY_BYTES,
+            )),
+        )
+        .route(
+            "/attachments/{id}",
+            get(handlers::attachments::get).delete(handlers::attachments::delete),
+        )
+        .route(
+            "/attachments/{id}/content",
+            get(handlers::attachments::content),
+        )
+        .route(
+            "/messages/{id}/attachments",
+            get(handlers::attachments::list_for_message),
+        )
+        // Library sync API: `library` and `pair` are for peers and take the
+        // model share token; `peers` manages the instances this one pulls from
+        .route("/sync/library", get(handlers::sync::library))
+        .route("/sync/pair", post(handlers::sync::accept_pair))
+        .route(
+            "/sync/peers",
+            get(handlers::sync::list_peers).post(handlers::sync::pair),
+        )
+        .route("/sync/peers/{name}", delete(handlers::sync::unpair))
+        .route("/sync/peers/{name}/diff", get(handlers::sync::diff))
+        .route("/sync/peers/{name}/pull", post(handlers::sync::pull))
+        // Backup API: transfers report `backup:progress` events
+        .route("/backup", get(handlers::backup::list))
+        .route("/backup/push", post(handlers::backup::push))
+        .route("/backup/restore", post(handlers::backup::restore))
+        // Projects API
+        .route(
+            "/projects",
+            get(handlers::projects::list).post(handlers::projects::create),
+        )
+        .route(
+            "/projects/{id}",
+            get(handlers::projects::get)
+                .put(handlers::projects::update)
+                .delete(handlers::projects::delete),
+        )
+        .route("/projects/{id}/tree", get(handlers::projects::tree))
+        .route("/projects/{id}/context", get(handlers::projects::context))
+        // Read-only share links; the links themselves open at `/share/*`
+        .route(
+            "/conversations/{id}/shares",
+            get(handlers::shares::list).post(handlers::shares::create),
+        )
+        .route("/shares/{id}", delete(handlers::shares::revoke))
+        .route(
+            "/conversations/{id}/project",
+            get(handlers::projects::get_for_conversation)
+                .put(handlers::projects::link)
+                .delete(handlers::projects::unlink),
+        )
+        // Presets API
+        .route(
+            "/presets",
+            get(handlers::presets::list).post(handlers::presets::create),
+        )
+        .route(
+            "/presets/{id}",
+            get(handlers::presets::get)
+                .put(handlers::presets::update)
+                .delete(handlers::presets::delete),
+        )
+        // Pipelines API
+        .route("/pipelines/run", post(handlers::pipelines::run))
+        .route("/pipelines/runs", get(handlers::pipelines::list_runs))
+        .route("/pipelines/runs/{id}", get(handlers::pipelines::get_run))
         // Built-in tools API
         .route("/builtin/tools", get(handlers::builtin::list_builtin_tools))
         // MCP API
@@ -161,7 +280,13 @@ pub(crate) fn api_routes() -> Router<AppState> {
             get(handlers::benchmark::history::get_run),
         )
         // Chat routes (merged without prefix since we're already building /api)
-        .merge(chat_routes_no_prefix())
+        .merge(chat_routes_no_prefix());
+
+    // Interactive API docs are a development aid, not part of release builds.
+    #[cfg(debug_assertions)]
+    let router = router.route("/docs", get(crate::openapi::swagger_ui));
+
+    router
 }
 
 /// Model domain routes: CRUD, tags, verification, downloads, HuggingFace.
@@ -181,6 +306,22 @@ fn model_routes() -> Router<AppState> {
                 .patch(handlers::model::models::update)
                 .delete(handlers::model::models::remove),
         )
+        // Trash: removed models until restored or purged
+        .route(
+            "/trash",
+            get(handlers::model::models::list_trash).delete(handlers::model::models::empty_trash),
+        )
+        .route(
+            "/trash/{id}/restore",
+            post(handlers::model::models::restore_trash),
+        )
+        // Usage ranking: GET /api/models/usage?days=30
+        .route("/usage", get(handlers::model::models::usage))
+        // Daily summary with energy estimates: GET /api/models/usage/daily?days=7
+        .route("/usage/daily", get(handlers::model::models::usage_daily))
+        // Paged list and change deltas for large libraries
+        .route("/page", get(handlers::model::models::page))
+        .route("/changes", get(handlers::model::models::changes))
         // Capability override: PATCH /api/models/{id}/capabilities
         // Independently set/clear individual ModelCapabilities flags without
         // touching any other model metadata.
@@ -192,6 +333,9 @@ fn model_routes() -> Router<AppState> {
         // Returns ModelDetailDto — superset of GuiModel with raw GGUF metadata,
         // MoE topology, HuggingFace provenance, inference defaults, and timestamps.
         .route("/{id}/detail", get(handlers::model::models::detail))
+        // Model file for other machines, with Range support:
+        // GET /api/models/{id}/file (bearer model share token)
+        .route("/{id}/file", get(handlers::model::file::download))
         // Benchmark history for this model
         .route(
             "/{id}/benchmark",
@@ -230,6 +374,10 @@ fn model_routes() -> Router<AppState> {
             "/downloads/queue",
             get(handlers::model::downloads::list).post(handlers::model::downloads::queue),
         )
+        .route(
+            "/downloads/collection",
+            post(handlers::model::downloads::queue_collection),
+        )
         .route(
             "/downloads/{id}",
             delete(handlers::model::downloads::remove),
@@ -238,6 +386,14 @@ fn model_routes() -> Router<AppState> {
             "/downloads/{id}/cancel",
             post(handlers::model::downloads::cancel),
         )
+        .route(
+            "/downloads/{id}/retry",
+            post(handlers::model::downloads::retry),
+        )
+        .route(
+            "/downloads/max-size",
+            get(handlers::model::downloads::max_size).put(handlers::model::downloads::set_max_size),
+        )
         .route(
             "/downloads/reorder",
             post(handlers::model::downloads::reorder),
@@ -250,10 +406,18 @@ fn model_routes() -> Router<AppState> {
             "/downloads/shard-group/{id}/cancel",
             post(handlers::model::downloads::cancel_shard_group),
         )
+        .route(
+            "/downloads/shard-group/{id}/progress",
+            get(handlers::model::downloads::shard_group_progress),
+        )
         .route(
             "/downloads/failed/clear",
             post(handlers::model::downloads::clear_failed),
         )
+        .route(
+            "/downloads/history",
+            get(handlers::model::downloads::history),
+        )
         // HuggingFace discovery
         .route("/hf/search", post(handlers::model::hf::search))
         .route(
@@ -290,6 +454,22 @@ fn config_routes() -> Router<AppState> {
                 .put(handlers::config::settings::update_models_directory),
         )
         .route("/system/setup-status", get(handlers::config::setup::status))
+        .route(
+            "/system/onboarding",
+            get(handlers::config::onboarding::status),
+        )
+        .route(
+            "/system/onboarding/{step}",
+            post(handlers::config::onboarding::run_step),
+        )
+        .route(
+            "/system/onboarding/{step}/skip",
+            post(handlers::config::onboarding::skip_step),
+        )
+        .route(
+            "/system/recommendations",
+            get(handlers::config::setup::recommendations),
+        )
         .route(
             "/system/vulkan-status",
             get(handlers::config::setup::vulkan_status_handler),
@@ -314,22 +494,49 @@ fn config_routes() -> Router<AppState> {
 /// use [`create_spa_router`] which includes both API routes and
 /// static file serving with SPA fallback.
 ///
+/// When the context carries a rate limiter, every `/api` route is limited
+/// per client (see [`crate::rate_limit`]). Administrative requests are
+/// recorded in the audit log (see [`crate::audit`]).
+///
 /// # Path Parameter Syntax
 /// Axum 0.8 uses brace syntax for path parameters: `{id}`, `{tag}`
 pub fn create_router(ctx: AxumContext, cors_config: &CorsConfig) -> Router {
     let state: AppState = Arc::new(ctx);
     let cors = build_cors_layer(cors_config);
 
-    Router::new()
+    // Successful administrative requests are recorded in the audit log.
+    let api = api_routes().layer(middleware::from_fn_with_state(
+        Arc::clone(&state),
+        audit::record,
+    ));
+
+    // Health probes stay unlimited; only the API is rate limited.
+    let api = match &state.rate_limiter {
+        Some(limiter) => api.layer(middleware::from_fn_with_state(
+            Arc::clone(limiter),
+            rate_limit::enforce,
+        )),
+        None => api,
+    };
+
+    // Share links are public: the signed token is the credential.
+    let router = Router::new()
         .route("/health", get(health_check))
-        .nest("/api", api_routes().with_state(state).layer(cors))
+        .route("/healthz", get(health_check))
+        .route("/readyz", get(readiness_check))
+        .route("/share/{token}", get(handlers::shares::view_html))
+        .route("/share/{token}/json", get(handlers::shares::view_json));
+    nest_api(router, api.layer(cors)).with_state(state)
 }
 
 /// Create a router with API routes and static asset serving.
 ///
 /// This creates a complete SPA-ready router that:
-/// 1. Serves API routes under `/api/*` and `/health`
-/// 2. Serves static assets from `static_dir` for matching files
+/// 1. Serves API routes under `/api/*` plus `/health`, `/healthz`, `/readyz`
+///    and the public `/share/*` links
+/// 2. Serves static assets from `static_dir` for matching files, preferring
+///    precompressed `.br`/`.gz` variants and setting cache headers (see
+///    [`crate::static_files`])
 /// 3. Falls back to `index.html` for client-side routing (SPA mode)
 ///
 /// # Arguments
@@ -349,19 +556,22 @@ pub fn create_spa_router<P: AsRef<Path>>(
     static_dir: P,
     cors_config: &CorsConfig,
 ) -> Router {
-    let static_path = static_dir.as_ref();
-    let index_path = static_path.join("index.html");
-
-    // Static file serving with SPA fallback to index.html for unmatched paths
-    // Using .fallback() on ServeDir makes it return index.html for missing files
-    let serve_dir = ServeDir::new(static_path).fallback(ServeFile::new(&index_path));
+    create_spa_router_with_fallback(ctx, static_dir, cors_config, &SpaFallback::Index)
+}
 
+/// Like [`create_spa_router`], answering unmatched paths per `fallback`.
+pub fn create_spa_router_with_fallback<P: AsRef<Path>>(
+    ctx: AxumContext,
+    static_dir: P,
+    cors_config: &CorsConfig,
+    fallback: &SpaFallback,
+) -> Router {
     // API routes (without fallback - they should 404 on unknown API paths)
     let api = create_router(ctx, cors_config);
 
     // Merge API routes with static serving as fallback
     // API routes take priority, then fallback to static/SPA serving
-    api.fallback_service(serve_dir)
+    api.fallback_service(static_router(static_dir.as_ref(), fallback))
 }
 
 /// Health check endpoint.
@@ -372,3 +582,20 @@ pub fn create_spa_router<P: AsRef<Path>>(
 pub(crate) async fn health_check() -> Json<Value> {
     Json(json!({ "service": "gglib-daemon", "status": "ok" }))
 }
+
+/// Readiness endpoint.
+///
+/// Reports database, llama.cpp binary, models directory and downloader
+/// status. Responds `503 Service Unavailable` when any subsystem is down so
+/// probes can tell "listening" apart from "able to serve a model".
+pub(crate) async fn readiness_check(
+    State(state): State<AppState>,
+) -> (StatusCode, Json<ReadinessReport>) {
+    let report = state.setup.readiness().await;
+    let status = if report.ready {
+        StatusCode::OK
+    } else {
+        StatusCode::SERVICE_UNAVAILABLE
+    };
+    (status, Json(report))
+}
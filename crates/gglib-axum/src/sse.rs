@@ -8,23 +8,34 @@
 //! `gglib-sse` crate (a dependency-free leaf); this module just wraps it to
 //! implement the `AppEventEmitter` port, keeping that port-implementation
 //! glue in the adapter layer where it belongs.
+//!
+//! Each [`AppEvent::topic`] gets its own broadcast channel. A client
+//! subscribed to a few topics only receives (and only pays to serialize)
+//! those, and a burst on one topic can only make a slow client miss events
+//! of that topic: the others have their own buffers. Order is kept within
+//! a topic, not across topics.
 
+use std::collections::HashMap;
 use std::convert::Infallible;
 use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
 
 use axum::response::sse::{Event, Sse};
-use futures_util::stream::Stream;
-use gglib_core::events::{AppEvent, ServerEvents, ServerSummary};
+use futures_util::StreamExt as _;
+use futures_util::stream::{self, Stream};
+use gglib_core::events::{AppEvent, EVENT_TOPICS, ServerEvents, ServerStopReason, ServerSummary};
 use gglib_core::ports::AppEventEmitter;
-use gglib_sse::{Broadcaster, SseOptions};
+use gglib_sse::{Broadcaster, SseOptions, into_sse};
 
 /// SSE broadcaster that implements event emitter ports.
 ///
-/// Events are sent via a broadcast channel and streamed to connected clients.
-/// Multiple clients can receive the same events simultaneously.
+/// Events are sent via one broadcast channel per topic and streamed to
+/// connected clients. Multiple clients can receive the same events
+/// simultaneously.
 #[derive(Clone)]
 pub struct SseBroadcaster {
-    inner: Arc<Broadcaster<AppEvent>>,
+    topics: Arc<HashMap<&'static str, Broadcaster<AppEvent>>>,
+    subscribers: Arc<AtomicUsize>,
 }
 
 impl std::fmt::Debug for SseBroadcaster {
@@ -40,41 +51,89 @@ impl SseBroadcaster {
     ///
     /// # Arguments
     ///
-    /// * `capacity` - Maximum number of events that can be buffered.
-    ///   Slow clients may miss events if the buffer overflows.
+    /// * `capacity` - Maximum number of events that can be buffered per
+    ///   topic. Slow clients may miss events of a topic if its buffer
+    ///   overflows.
     #[must_use]
     pub fn new(capacity: usize) -> Self {
+        let topics = EVENT_TOPICS
+            .iter()
+            .map(|&topic| (topic, Broadcaster::new(capacity)))
+            .collect();
         Self {
-            inner: Arc::new(Broadcaster::new(capacity)),
+            topics: Arc::new(topics),
+            subscribers: Arc::new(AtomicUsize::new(0)),
         }
     }
 
-    /// Create a new SSE broadcaster with default capacity (256 events).
+    /// Create a new SSE broadcaster with default capacity (256 events per topic).
     #[must_use]
     pub fn with_defaults() -> Self {
         Self::new(256)
     }
 
-    /// Create an SSE stream for a new client connection.
+    /// Create an SSE stream of every topic for a new client connection.
     ///
     /// Returns an Axum SSE response that streams events to the client.
     /// Includes a keep-alive ping every 30 seconds to prevent proxy timeouts.
     pub fn subscribe(
         &self,
     ) -> Sse<impl Stream<Item = Result<Event, Infallible>> + Send + 'static + use<>> {
-        self.inner.clone().subscribe(SseOptions::default())
+        self.subscribe_topics(EVENT_TOPICS)
+    }
+
+    /// Create an SSE stream of the events in `topics` for a new client
+    /// connection. Names that are not in [`EVENT_TOPICS`] are ignored.
+    pub fn subscribe_topics(
+        &self,
+        topics: &[&str],
+    ) -> Sse<impl Stream<Item = Result<Event, Infallible>> + Send + 'static + use<>> {
+        into_sse(self.event_stream(topics), SseOptions::default())
+    }
+
+    /// The unencoded events of `topics`, counted as one subscriber until
+    /// dropped.
+    fn event_stream(&self, topics: &[&str]) -> impl Stream<Item = AppEvent> + Send + use<> {
+        let streams = topics
+            .iter()
+            .filter_map(|topic| self.topics.get(topic))
+            .map(|channel| channel.subscribe_events().boxed());
+        let guard = SubscriberGuard::new(&self.subscribers);
+        stream::select_all(streams).map(move |event| {
+            let _ = &guard;
+            event
+        })
     }
 
-    /// Get the number of active subscribers.
+    /// Get the number of connected clients.
     #[must_use]
     pub fn subscriber_count(&self) -> usize {
-        self.inner.subscriber_count()
+        self.subscribers.load(Ordering::Relaxed)
+    }
+}
+
+/// Counts a client from subscription until its stream is dropped.
+struct SubscriberGuard(Arc<AtomicUsize>);
+
+impl SubscriberGuard {
+    fn new(count: &Arc<AtomicUsize>) -> Self {
+        count.fetch_add(1, Ordering::Relaxed);
+        Self(Arc::clone(count))
+    }
+}
+
+impl Drop for SubscriberGuard {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::Relaxed);
     }
 }
 
 impl AppEventEmitter for SseBroadcaster {
     fn emit(&self, event: AppEvent) {
-        self.inner.send(event);
+        match self.topics.get(event.topic()) {
+            Some(channel) => channel.send(event),
+            None => tracing::warn!("No SSE channel for event {}", event.event_name()),
+        }
     }
 
     fn clone_box(&self) -> Box<dyn AppEventEmitter> {
@@ -123,8 +182,8 @@ impl ServerEvents for AxumServerEvents {
         );
     }
 
-    fn stopped(&self, server: &ServerSummary) {
-        let event = AppEvent::from_server_stopped(server);
+    fn stopped(&self, server: &ServerSummary, reason: ServerStopReason) {
+        let event = AppEvent::from_server_stopped(server, reason);
         self.broadcaster.emit(event);
     }
 
@@ -158,10 +217,8 @@ mod tests {
 
     #[tokio::test]
     async fn test_subscriber_receives_events() {
-        use tokio_stream::StreamExt as _;
-
         let broadcaster = SseBroadcaster::with_defaults();
-        let mut stream = broadcaster.inner.subscribe_events();
+        let mut stream = broadcaster.event_stream(EVENT_TOPICS);
 
         AppEventEmitter::emit(&broadcaster, AppEvent::model_removed(42));
 
@@ -171,4 +228,23 @@ mod tests {
             _ => panic!("Unexpected event type"),
         }
     }
+
+    #[tokio::test]
+    async fn topic_subscribers_only_see_their_topics() {
+        let broadcaster = SseBroadcaster::new(4);
+        let mut servers = broadcaster.event_stream(&["server"]);
+        assert_eq!(broadcaster.subscriber_count(), 1);
+
+        // Flooding another topic past its buffer leaves this one untouched.
+        for i in 0..20 {
+            AppEventEmitter::emit(&broadcaster, AppEvent::model_removed(i));
+        }
+        AppEventEmitter::emit(&broadcaster, AppEvent::server_started(1, "m", 8080));
+
+        let event = servers.next().await.unwrap();
+        assert_eq!(event.event_name(), "server:started");
+
+        drop(servers);
+        assert_eq!(broadcaster.subscriber_count(), 0);
+    }
 }
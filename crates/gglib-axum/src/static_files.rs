@@ -0,0 +1,267 @@
+//! Static asset serving for the web UI.
+//!
+//! [`static_router`] wraps `tower-http`'s `ServeDir` with what a browser on
+//! the LAN needs to load the UI quickly on repeat visits:
+//!
+//! - **Precompressed assets** — `app.js.br` or `app.js.gz` next to `app.js`
+//!   is served instead when the client accepts that encoding, so the server
+//!   never compresses at request time.
+//! - **Cache-Control** — Vite writes content-hashed file names under
+//!   `assets/`, so those are `immutable` for a year. Everything else,
+//!   `index.html` above all, is `no-cache`: always revalidated, so a new
+//!   build is picked up on the next load.
+//! - **`ETag`** — a weak validator from the served file's size, modification
+//!   time and encoding. A matching `If-None-Match` gets an empty `304`.
+//!
+//! Unmatched paths are answered according to [`SpaFallback`].
+
+use std::path::{Path, PathBuf};
+
+use axum::{
+    Router,
+    extract::Request,
+    http::{HeaderMap, HeaderValue, StatusCode, header},
+    middleware::{self, Next},
+    response::{IntoResponse, Response},
+};
+use tower_http::services::{ServeDir, ServeFile};
+
+/// Path prefix Vite writes content-hashed build output under.
+const HASHED_ASSETS_PREFIX: &str = "/assets/";
+
+const IMMUTABLE: HeaderValue = HeaderValue::from_static("public, max-age=31536000, immutable");
+const NO_CACHE: HeaderValue = HeaderValue::from_static("no-cache");
+
+/// How a request for a path with no matching file is answered.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum SpaFallback {
+    /// Serve `index.html`, letting the client-side router handle the path.
+    #[default]
+    Index,
+    /// Serve this file instead, relative to the static directory.
+    File(PathBuf),
+    /// Respond `404 Not Found`.
+    NotFound,
+}
+
+impl std::str::FromStr for SpaFallback {
+    type Err = std::convert::Infallible;
+
+    /// `index`, `none` for a 404, or a file path relative to the static
+    /// directory.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "index" => Self::Index,
+            "none" => Self::NotFound,
+            path => Self::File(path.into()),
+        })
+    }
+}
+
+/// Router serving `static_dir`, meant as the fallback behind the API routes.
+pub fn static_router(static_dir: &Path, fallback: &SpaFallback) -> Router {
+    let serve_dir = ServeDir::new(static_dir)
+        .precompressed_br()
+        .precompressed_gzip();
+    let fallback_file = match fallback {
+        SpaFallback::Index => Some(static_dir.join("index.html")),
+        SpaFallback::File(path) => Some(static_dir.join(path)),
+        SpaFallback::NotFound => None,
+    };
+
+    let router = match fallback_file {
+        Some(file) => Router::new().fallback_service(
+            serve_dir.fallback(ServeFile::new(file).precompressed_br().precompressed_gzip()),
+        ),
+        None => Router::new().fallback_service(serve_dir),
+    };
+    router.layer(middleware::from_fn(cache_headers))
+}
+
+/// Add `Cache-Control`, `Vary` and `ETag` to a static response, turning it
+/// into a `304` when the client's `If-None-Match` already matches.
+async fn cache_headers(request: Request, next: Next) -> Response {
+    let hashed = request.uri().path().starts_with(HASHED_ASSETS_PREFIX);
+    let if_none_match = request.headers().get(header::IF_NONE_MATCH).cloned();
+
+    let mut response = next.run(request).await;
+    if !response.status().is_success() {
+        return response;
+    }
+
+    // A missing hashed asset falls back to index.html, which must not be
+    // pinned for a year under that URL.
+    let cache_control = if hashed && !is_html(response.headers()) {
+        IMMUTABLE
+    } else {
+        NO_CACHE
+    };
+    let headers = response.headers_mut();
+    headers.insert(header::CACHE_CONTROL, cache_control.clone());
+    headers.insert(header::VARY, HeaderValue::from_static("accept-encoding"));
+
+    // A range response's length is the range's, not the file's.
+    if response.status() != StatusCode::OK {
+        return response;
+    }
+    let Some(etag) = etag(response.headers()) else {
+        return response;
+    };
+    if if_none_match.is_some_and(|v| etag_matches(&v, &etag)) {
+        return (
+            StatusCode::NOT_MODIFIED,
+            [
+                (header::ETAG, etag),
+                (header::CACHE_CONTROL, cache_control),
+                (header::VARY, HeaderValue::from_static("accept-encoding")),
+            ],
+        )
+            .into_response();
+    }
+    response.headers_mut().insert(header::ETAG, etag);
+    response
+}
+
+fn is_html(headers: &HeaderMap) -> bool {
+    headers
+        .get(header::CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .is_some_and(|v| v.starts_with("text/html"))
+}
+
+/// Weak `ETag` from the response's length, `Last-Modified` and encoding,
+/// e.g. `W/"1f40-65a1b2c3-br"`. `None` when either of the first two is
+/// missing.
+fn etag(headers: &HeaderMap) -> Option<HeaderValue> {
+    let text = |name| {
+        headers
+            .get(name)
+            .and_then(|v: &HeaderValue| v.to_str().ok())
+    };
+    let length: u64 = text(header::CONTENT_LENGTH)?.parse().ok()?;
+    let modified = chrono::DateTime::parse_from_rfc2822(text(header::LAST_MODIFIED)?).ok()?;
+    let encoding = text(header::CONTENT_ENCODING)
+        .map(|e| format!("-{e}"))
+        .unwrap_or_default();
+    HeaderValue::from_str(&format!(
+        "W/\"{length:x}-{:x}{encoding}\"",
+        modified.timestamp()
+    ))
+    .ok()
+}
+
+/// Whether an `If-None-Match` value names `etag`, using the weak comparison
+/// RFC 9110 prescribes for it.
+fn etag_matches(if_none_match: &HeaderValue, etag: &HeaderValue) -> bool {
+    let opaque = |tag: &str| tag.trim().trim_start_matches("W/").to_owned();
+    let (Ok(candidates), Ok(etag)) = (if_none_match.to_str(), etag.to_str()) else {
+        return false;
+    };
+    let etag = opaque(etag);
+    candidates
+        .split(',')
+        .any(|tag| tag.trim() == "*" || opaque(tag) == etag)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::body::Body;
+    use http_body_util::BodyExt;
+    use tower::ServiceExt;
+
+    fn site() -> tempfile::TempDir {
+        let dir = tempfile::TempDir::new().unwrap();
+        std::fs::create_dir(dir.path().join("assets")).unwrap();
+        std::fs::write(dir.path().join("index.html"), "<html>SPA</html>").unwrap();
+        std::fs::write(dir.path().join("assets/app-4f3a9c1d.js"), "plain").unwrap();
+        std::fs::write(dir.path().join("assets/app-4f3a9c1d.js.br"), "brotli").unwrap();
+        dir
+    }
+
+    async fn get(router: &Router, uri: &str, headers: &[(&str, &str)]) -> Response {
+        let mut request = Request::builder().uri(uri);
+        for (name, value) in headers {
+            request = request.header(*name, *value);
+        }
+        router
+            .clone()
+            .oneshot(request.body(Body::empty()).unwrap())
+            .await
+            .unwrap()
+    }
+
+    async fn body(response: Response) -> String {
+        let bytes = response.into_body().collect().await.unwrap().to_bytes();
+        String::from_utf8(bytes.to_vec()).unwrap()
+    }
+
+    #[tokio::test]
+    async fn hashed_assets_are_immutable_and_precompressed() {
+        let dir = site();
+        let router = static_router(dir.path(), &SpaFallback::Index);
+
+        let response = get(
+            &router,
+            "/assets/app-4f3a9c1d.js",
+            &[("accept-encoding", "br")],
+        )
+        .await;
+        assert_eq!(response.headers()[header::CACHE_CONTROL], IMMUTABLE);
+        assert_eq!(response.headers()[header::CONTENT_ENCODING], "br");
+        assert_eq!(body(response).await, "brotli");
+
+        let response = get(&router, "/assets/app-4f3a9c1d.js", &[]).await;
+        assert_eq!(body(response).await, "plain");
+
+        // A missing asset falls back to index.html, which stays revalidated.
+        let response = get(&router, "/assets/gone-00000000.js", &[]).await;
+        assert_eq!(response.headers()[header::CACHE_CONTROL], NO_CACHE);
+    }
+
+    #[tokio::test]
+    async fn matching_etag_is_not_modified() {
+        let dir = site();
+        let router = static_router(dir.path(), &SpaFallback::Index);
+
+        let response = get(&router, "/", &[]).await;
+        assert_eq!(response.headers()[header::CACHE_CONTROL], NO_CACHE);
+        let etag = response.headers()[header::ETAG]
+            .to_str()
+            .unwrap()
+            .to_owned();
+
+        let response = get(&router, "/", &[("if-none-match", &etag)]).await;
+        assert_eq!(response.status(), StatusCode::NOT_MODIFIED);
+        assert!(body(response).await.is_empty());
+
+        let response = get(&router, "/", &[("if-none-match", "W/\"0-0\"")]).await;
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn fallback_is_configurable() {
+        let dir = site();
+        std::fs::write(dir.path().join("offline.html"), "offline").unwrap();
+
+        let router = static_router(dir.path(), &SpaFallback::NotFound);
+        let response = get(&router, "/some/route", &[]).await;
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+        let router = static_router(dir.path(), &SpaFallback::File("offline.html".into()));
+        let response = get(&router, "/some/route", &[]).await;
+        assert_eq!(body(response).await, "offline");
+    }
+
+    #[test]
+    fn etag_comparison_is_weak() {
+        let etag = HeaderValue::from_static("W/\"a-b\"");
+        assert!(etag_matches(&HeaderValue::from_static("\"a-b\""), &etag));
+        assert!(etag_matches(
+            &HeaderValue::from_static("\"x\", W/\"a-b\""),
+            &etag
+        ));
+        assert!(etag_matches(&HeaderValue::from_static("*"), &etag));
+        assert!(!etag_matches(&HeaderValue::from_static("W/\"a-c\""), &etag));
+    }
+}
@@ -0,0 +1,17 @@
+//! Log directory for test server configurations.
+//!
+//! Keeps the log endpoints pointed at a throwaway directory so test runs
+//! never create `logs/` inside the crate.
+
+use std::path::PathBuf;
+use std::sync::LazyLock;
+
+use tempfile::TempDir;
+
+static LOG_DIR: LazyLock<TempDir> =
+    LazyLock::new(|| TempDir::new().expect("create temporary log directory"));
+
+/// Temporary log directory shared by every test in the binary.
+pub fn test_log_dir() -> PathBuf {
+    LOG_DIR.path().to_path_buf()
+}
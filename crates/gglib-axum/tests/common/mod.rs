@@ -1,3 +1,4 @@
 //! Common test utilities for gglib-axum.
 
+pub mod logs;
 pub mod ports;
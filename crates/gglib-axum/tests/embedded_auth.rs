@@ -10,6 +10,7 @@ mod common;
 
 use common::ports::{TEST_BASE_PORT, TEST_CORS_ORIGIN};
 use gglib_axum::{
+    SpaFallback,
     bootstrap::{CorsConfig, ServerConfig, bootstrap},
     embedded::{EmbeddedServerConfig, start_embedded_server},
 };
@@ -23,6 +24,10 @@ fn test_config() -> ServerConfig {
         max_concurrent: 1,
         max_concurrent_agent_loops: 1,
         static_dir: None,
+        spa_fallback: SpaFallback::Index,
+        model_share_token: None,
+        rate_limit: None,
+        log_dir: common::logs::test_log_dir(),
         cors: CorsConfig::AllowAll,
     }
 }
@@ -10,6 +10,7 @@ use http_body_util::BodyExt;
 use tower::ServiceExt;
 
 use common::ports::{TEST_BASE_PORT, TEST_MODEL_PORT};
+use gglib_axum::SpaFallback;
 use gglib_axum::bootstrap::{CorsConfig, ServerConfig, bootstrap};
 use gglib_axum::routes::create_router;
 
@@ -22,6 +23,10 @@ fn test_config() -> ServerConfig {
         max_concurrent: 1,
         max_concurrent_agent_loops: 1,
         static_dir: None,
+        spa_fallback: SpaFallback::Index,
+        model_share_token: None,
+        rate_limit: None,
+        log_dir: common::logs::test_log_dir(),
         cors: CorsConfig::AllowAll,
     }
 }
@@ -86,6 +91,90 @@ async fn models_endpoint_returns_json() {
     );
 }
 
+#[tokio::test]
+async fn models_page_endpoint_clamps_the_limit() {
+    let ctx = match bootstrap(test_config()).await {
+        Ok(ctx) => ctx,
+        Err(_) => return,
+    };
+
+    let app = create_router(ctx, &CorsConfig::AllowAll);
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .uri("/api/models/page?sort=name&order=asc&offset=0&limit=100000")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(json["offset"], 0);
+    assert_eq!(json["limit"], 500);
+    assert!(json["items"].is_array());
+    assert!(json["total"].is_u64());
+}
+
+#[tokio::test]
+async fn models_changes_endpoint_validates_since() {
+    let ctx = match bootstrap(test_config()).await {
+        Ok(ctx) => ctx,
+        Err(_) => return,
+    };
+
+    let app = create_router(ctx, &CorsConfig::AllowAll);
+
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .uri("/api/models/changes")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(json["full"], true);
+    let as_of = json["asOf"].as_str().unwrap().to_string();
+
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .uri(format!(
+                    "/api/models/changes?since={}",
+                    as_of.replace('+', "%2B")
+                ))
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(json["full"], false);
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .uri("/api/models/changes?since=yesterday")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
 #[tokio::test]
 async fn servers_endpoint_returns_json_array() {
     let ctx = match bootstrap(test_config()).await {
@@ -139,6 +228,89 @@ async fn downloads_endpoint_returns_queue_snapshot() {
     assert!(body_str.contains("max_size"));
 }
 
+#[tokio::test]
+async fn downloads_history_starts_empty_and_validates_result() {
+    let ctx = match bootstrap(test_config()).await {
+        Ok(ctx) => ctx,
+        Err(_) => return,
+    };
+
+    let app = create_router(ctx, &CorsConfig::AllowAll);
+
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .uri("/api/models/downloads/history?result=failed&search=llama")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    assert_eq!(&body[..], b"[]");
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .uri("/api/models/downloads/history?result=exploded")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn downloads_max_size_and_retry_control_the_queue() {
+    let ctx = match bootstrap(test_config()).await {
+        Ok(ctx) => ctx,
+        Err(_) => return,
+    };
+
+    let app = create_router(ctx, &CorsConfig::AllowAll);
+    let set_max_size = |size: u32| {
+        Request::builder()
+            .method("PUT")
+            .uri("/api/models/downloads/max-size")
+            .header("content-type", "application/json")
+            .body(Body::from(format!(r#"{{"max_size":{size}}}"#)))
+            .unwrap()
+    };
+
+    let response = app.clone().oneshot(set_max_size(3)).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let response = app.clone().oneshot(set_max_size(0)).await.unwrap();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .uri("/api/models/downloads/max-size")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    assert_eq!(&body[..], br#"{"max_size":3}"#);
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/api/models/downloads/org%2Fmodel:Q4_K_M/retry")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
 #[tokio::test]
 async fn events_endpoint_returns_sse_stream() {
     let ctx = match bootstrap(test_config()).await {
@@ -920,3 +1092,336 @@ async fn proxy_start_fallback_to_hardcoded_default_when_no_settings() {
         "POST /api/proxy/start with empty body should not return 400 when falling back to hard-coded default"
     );
 }
+
+// ============================================================================
+// Model File Sharing
+// ============================================================================
+
+#[tokio::test]
+async fn model_file_requires_share_token() {
+    let ctx = match bootstrap(test_config()).await {
+        Ok(ctx) => ctx,
+        Err(_) => return,
+    };
+    let app = create_router(ctx, &CorsConfig::AllowAll);
+
+    // test_config() configures no share token, so sharing is off entirely.
+    let response = app
+        .oneshot(
+            Request::builder()
+                .uri("/api/models/1/file")
+                .header("authorization", "Bearer anything")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+}
+
+/// Register a model backed by a small file in `dir` and return its ID.
+async fn add_shared_model(ctx: &gglib_axum::AxumContext, dir: &tempfile::TempDir) -> i64 {
+    let path = dir.path().join("shared-Q4_K_M.gguf");
+    std::fs::write(&path, b"GGUF0123456789").unwrap();
+    ctx.core
+        .models()
+        .add(gglib_core::NewModel::new(
+            format!("share-test-{}", dir.path().display()),
+            path,
+            1.0,
+            chrono::Utc::now(),
+        ))
+        .await
+        .unwrap()
+        .id
+}
+
+#[tokio::test]
+async fn model_file_downloads_with_the_share_token() {
+    let mut config = test_config();
+    config.model_share_token = Some("s3cret".to_string());
+    let ctx = match bootstrap(config).await {
+        Ok(ctx) => ctx,
+        Err(_) => return,
+    };
+    let dir = tempfile::TempDir::new().unwrap();
+    let id = add_shared_model(&ctx, &dir).await;
+    let core = ctx.core.clone();
+    let app = create_router(ctx, &CorsConfig::AllowAll);
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .uri(format!("/api/models/{id}/file"))
+                .header("authorization", "Bearer s3cret")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    core.models().delete(id).await.unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        response.headers()["content-disposition"],
+        "attachment; filename=\"shared-Q4_K_M.gguf\""
+    );
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    assert_eq!(&body[..], b"GGUF0123456789");
+}
+
+#[tokio::test]
+async fn model_file_serves_a_requested_range() {
+    let mut config = test_config();
+    config.model_share_token = Some("s3cret".to_string());
+    let ctx = match bootstrap(config).await {
+        Ok(ctx) => ctx,
+        Err(_) => return,
+    };
+    let dir = tempfile::TempDir::new().unwrap();
+    let id = add_shared_model(&ctx, &dir).await;
+    let core = ctx.core.clone();
+    let app = create_router(ctx, &CorsConfig::AllowAll);
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .uri(format!("/api/models/{id}/file"))
+                .header("authorization", "Bearer s3cret")
+                .header("range", "bytes=4-7")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    core.models().delete(id).await.unwrap();
+
+    assert_eq!(response.status(), StatusCode::PARTIAL_CONTENT);
+    assert_eq!(response.headers()["content-range"], "bytes 4-7/14");
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    assert_eq!(&body[..], b"0123");
+}
+
+// ============================================================================
+// Library Sync
+// ============================================================================
+
+#[tokio::test]
+async fn sync_library_requires_share_token() {
+    let mut config = test_config();
+    config.model_share_token = Some("s3cret".to_string());
+    let ctx = match bootstrap(config).await {
+        Ok(ctx) => ctx,
+        Err(_) => return,
+    };
+    let app = create_router(ctx, &CorsConfig::AllowAll);
+
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .uri("/api/sync/library")
+                .header("authorization", "Bearer guess")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .uri("/api/sync/library")
+                .header("authorization", "Bearer s3cret")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+}
+
+// ============================================================================
+// Conversation Share Links
+// ============================================================================
+
+#[tokio::test]
+async fn share_link_with_a_forged_token_is_not_found() {
+    let ctx = match bootstrap(test_config()).await {
+        Ok(ctx) => ctx,
+        Err(_) => return,
+    };
+    let app = create_router(ctx, &CorsConfig::AllowAll);
+
+    for uri in ["/share/1.Zm9yZ2Vk", "/share/1.Zm9yZ2Vk/json"] {
+        let response = app
+            .clone()
+            .oneshot(Request::builder().uri(uri).body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND, "{uri}");
+    }
+}
+
+#[tokio::test]
+async fn events_stream_rejects_unknown_topics() {
+    let ctx = match bootstrap(test_config()).await {
+        Ok(ctx) => ctx,
+        Err(_) => return,
+    };
+    let app = create_router(ctx, &CorsConfig::AllowAll);
+
+    for (uri, status) in [
+        ("/api/events?topics=download,server", StatusCode::OK),
+        ("/api/events?topics=download,bogus", StatusCode::BAD_REQUEST),
+    ] {
+        let response = app
+            .clone()
+            .oneshot(Request::builder().uri(uri).body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(response.status(), status, "{uri}");
+    }
+}
+
+#[tokio::test]
+async fn settings_changes_are_recorded_in_the_audit_log() {
+    let ctx = match bootstrap(test_config()).await {
+        Ok(ctx) => ctx,
+        Err(_) => return,
+    };
+    let app = create_router(ctx, &CorsConfig::AllowAll);
+    let user = format!("audit-test-{}", std::process::id());
+
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method("PUT")
+                .uri("/api/config/settings")
+                .header("content-type", "application/json")
+                .header("x-gglib-user", &user)
+                .body(Body::from("{}"))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .uri(format!(
+                    "/api/admin/audit?action=settings_changed&actor={user}"
+                ))
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    let entries: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    let entries = entries.as_array().unwrap();
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0]["action"], "settings_changed");
+    assert!(entries[0]["actor"].as_str().unwrap().starts_with(&user));
+}
+
+#[tokio::test]
+async fn versioned_prefix_serves_the_same_routes() {
+    let ctx = match bootstrap(test_config()).await {
+        Ok(ctx) => ctx,
+        Err(_) => return,
+    };
+    let app = create_router(ctx, &CorsConfig::AllowAll);
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .uri("/api/v1/models")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    let json: serde_json::Value = serde_json::from_slice(&body).expect("valid JSON");
+    assert!(json.is_array(), "Expected JSON array, got: {json}");
+}
+
+#[tokio::test]
+async fn openapi_document_is_served_under_both_prefixes() {
+    let ctx = match bootstrap(test_config()).await {
+        Ok(ctx) => ctx,
+        Err(_) => return,
+    };
+    let app = create_router(ctx, &CorsConfig::AllowAll);
+
+    for uri in ["/api/v1/openapi.json", "/api/openapi.json"] {
+        let response = app
+            .clone()
+            .oneshot(Request::builder().uri(uri).body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK, "{uri}");
+
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let doc: serde_json::Value = serde_json::from_slice(&body).expect("valid JSON");
+        assert_eq!(doc["servers"][0]["url"], "/api/v1");
+        assert!(doc["paths"]["/models"]["get"].is_object(), "{uri}");
+    }
+}
+
+#[tokio::test]
+async fn rate_limit_applies_to_api_routes_only() {
+    use gglib_axum::{RateLimit, RateLimitConfig};
+
+    let config = test_config().with_rate_limit(RateLimitConfig {
+        general: RateLimit::per_minute(100),
+        heavy: RateLimit::per_minute(1),
+        api_keys: Vec::new(),
+    });
+    let ctx = match bootstrap(config).await {
+        Ok(ctx) => ctx,
+        Err(_) => return,
+    };
+    let app = create_router(ctx, &CorsConfig::AllowAll);
+    let request = |method: &str, uri: &str| {
+        Request::builder()
+            .method(method)
+            .uri(uri)
+            .header("authorization", "Bearer test-client")
+            .body(Body::empty())
+            .unwrap()
+    };
+
+    let health = app
+        .clone()
+        .oneshot(request("GET", "/health"))
+        .await
+        .unwrap();
+    assert!(health.headers().get("x-ratelimit-limit").is_none());
+
+    let models = app
+        .clone()
+        .oneshot(request("GET", "/api/v1/models"))
+        .await
+        .unwrap();
+    assert_eq!(models.headers()["x-ratelimit-limit"], "100");
+
+    // Chat draws from the heavy bucket, whatever the request's fate.
+    let first = app
+        .clone()
+        .oneshot(request("POST", "/api/chat"))
+        .await
+        .unwrap();
+    assert_eq!(first.headers()["x-ratelimit-limit"], "1");
+    let second = app.oneshot(request("POST", "/api/chat")).await.unwrap();
+    assert_eq!(second.status(), StatusCode::TOO_MANY_REQUESTS);
+    assert!(second.headers().get("retry-after").is_some());
+}
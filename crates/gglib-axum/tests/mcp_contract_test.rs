@@ -12,6 +12,7 @@ use serde_json::json;
 use tower::ServiceExt;
 
 use common::ports::TEST_BASE_PORT;
+use gglib_axum::SpaFallback;
 use gglib_axum::bootstrap::{CorsConfig, ServerConfig, bootstrap};
 use gglib_axum::routes::create_router;
 
@@ -24,6 +25,10 @@ fn test_config() -> ServerConfig {
         max_concurrent: 1,
         max_concurrent_agent_loops: 1,
         static_dir: None,
+        spa_fallback: SpaFallback::Index,
+        model_share_token: None,
+        rate_limit: None,
+        log_dir: common::logs::test_log_dir(),
         cors: CorsConfig::AllowAll,
     }
 }
@@ -0,0 +1,38 @@
+#![doc = include_str!(concat!(env!("OUT_DIR"), "/README_GENERATED.md"))]
+#![deny(unsafe_code)]
+
+//! S3-compatible backup store for gglib.
+//!
+//! [`connect`] turns a [`BackupConfig`] into a
+//! [`BackupStorePort`](gglib_core::ports::BackupStorePort) backed by AWS S3,
+//! `MinIO` or any other S3-compatible service. The client is behind the `s3`
+//! feature — without it [`connect`] reports
+//! [`BackupStoreError::Unsupported`].
+
+use std::sync::Arc;
+
+use gglib_core::domain::BackupConfig;
+use gglib_core::ports::{BackupStoreError, BackupStorePort};
+
+#[cfg(feature = "s3")]
+mod s3;
+#[cfg(not(feature = "s3"))]
+#[path = "s3_disabled.rs"]
+mod s3;
+
+pub use s3::S3BackupStore;
+
+/// Whether this build can talk to a backup bucket.
+pub const S3_SUPPORTED: bool = cfg!(feature = "s3");
+
+/// Open the bucket described by `config`.
+///
+/// `config.secret_access_key` must already be resolved from the secret
+/// store. No request is made until the store is used.
+///
+/// # Errors
+///
+/// [`BackupStoreError::Unsupported`] when built without the `s3` feature.
+pub fn connect(config: &BackupConfig) -> Result<Arc<dyn BackupStorePort>, BackupStoreError> {
+    Ok(Arc::new(S3BackupStore::new(config)?))
+}
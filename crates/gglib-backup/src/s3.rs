@@ -0,0 +1,344 @@
+//! AWS SDK client for S3-compatible backup buckets.
+//!
+//! Files up to [`PART_SIZE`] go up in a single `PutObject`; larger ones use a
+//! multipart upload so multi-gigabyte models neither sit in memory nor hit
+//! the single-request size limit, and progress is reported once per part.
+//! An upload that fails part-way is aborted so the bucket is not left
+//! holding orphaned parts.
+
+use std::path::{Path, PathBuf};
+
+use async_trait::async_trait;
+use aws_sdk_s3::Client;
+use aws_sdk_s3::config::{
+    BehaviorVersion, Builder, Credentials, Region, RequestChecksumCalculation,
+    ResponseChecksumValidation,
+};
+use aws_sdk_s3::error::DisplayErrorContext;
+use aws_sdk_s3::operation::get_object::GetObjectError;
+use aws_sdk_s3::primitives::{ByteStream, DateTimeFormat};
+use aws_sdk_s3::types::{CompletedMultipartUpload, CompletedPart};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+use gglib_core::domain::{BackupConfig, BackupObject};
+use gglib_core::ports::{BackupProgress, BackupStoreError, BackupStorePort};
+
+/// Multipart part size. S3 requires at least 5 MiB for every part but the
+/// last and allows at most 10 000 parts, so this covers files up to ~156 GiB.
+const PART_SIZE: usize = 16 * 1024 * 1024;
+
+/// A bucket on AWS S3 or an S3-compatible service.
+pub struct S3BackupStore {
+    client: Client,
+    bucket: String,
+}
+
+impl S3BackupStore {
+    /// Build a client for the bucket in `config`.
+    pub fn new(config: &BackupConfig) -> Result<Self, BackupStoreError> {
+        let credentials = Credentials::new(
+            &config.access_key_id,
+            &config.secret_access_key,
+            None,
+            None,
+            "gglib-settings",
+        );
+        // Only checksum when S3 requires it: several S3-compatible services
+        // reject the CRC trailers the SDK sends by default.
+        let mut builder = Builder::new()
+            .behavior_version(BehaviorVersion::latest())
+            .region(Region::new(config.effective_region().to_string()))
+            .credentials_provider(credentials)
+            .force_path_style(config.path_style)
+            .request_checksum_calculation(RequestChecksumCalculation::WhenRequired)
+            .response_checksum_validation(ResponseChecksumValidation::WhenRequired);
+        if let Some(endpoint) = &config.endpoint {
+            builder = builder.endpoint_url(endpoint);
+        }
+
+        Ok(Self {
+            client: Client::from_conf(builder.build()),
+            bucket: config.bucket.clone(),
+        })
+    }
+
+    async fn upload_multipart(
+        &self,
+        key: &str,
+        path: &Path,
+        total: u64,
+        progress: BackupProgress<'_>,
+    ) -> Result<(), BackupStoreError> {
+        let upload = self
+            .client
+            .create_multipart_upload()
+            .bucket(&self.bucket)
+            .key(key)
+            .send()
+            .await
+            .map_err(service)?;
+        let upload_id = upload
+            .upload_id()
+            .ok_or_else(|| BackupStoreError::Service("no multipart upload ID returned".into()))?
+            .to_string();
+
+        match self
+            .upload_parts(key, &upload_id, path, total, progress)
+            .await
+        {
+            Ok(parts) => {
+                self.client
+                    .complete_multipart_upload()
+                    .bucket(&self.bucket)
+                    .key(key)
+                    .upload_id(&upload_id)
+                    .multipart_upload(
+                        CompletedMultipartUpload::builder()
+                            .set_parts(Some(parts))
+                            .build(),
+                    )
+                    .send()
+                    .await
+                    .map_err(service)?;
+                Ok(())
+            }
+            Err(e) => {
+                if let Err(abort) = self
+                    .client
+                    .abort_multipart_upload()
+                    .bucket(&self.bucket)
+                    .key(key)
+                    .upload_id(&upload_id)
+                    .send()
+                    .await
+                {
+                    tracing::warn!(
+                        key,
+                        error = %DisplayErrorContext(&abort),
+                        "Failed to abort multipart upload"
+                    );
+                }
+                Err(e)
+            }
+        }
+    }
+
+    async fn upload_parts(
+        &self,
+        key: &str,
+        upload_id: &str,
+        path: &Path,
+        total: u64,
+        progress: BackupProgress<'_>,
+    ) -> Result<Vec<CompletedPart>, BackupStoreError> {
+        let mut file = tokio::fs::File::open(path)
+            .await
+            .map_err(|e| io(path, &e))?;
+        let mut parts = Vec::new();
+        let mut sent = 0u64;
+
+        for part_number in 1.. {
+            let chunk = read_chunk(&mut file, PART_SIZE)
+                .await
+                .map_err(|e| io(path, &e))?;
+            if chunk.is_empty() {
+                break;
+            }
+            let len = chunk.len() as u64;
+            let part = self
+                .client
+                .upload_part()
+                .bucket(&self.bucket)
+                .key(key)
+                .upload_id(upload_id)
+                .part_number(part_number)
+                .body(ByteStream::from(chunk))
+                .send()
+                .await
+                .map_err(service)?;
+            parts.push(
+                CompletedPart::builder()
+                    .set_e_tag(part.e_tag().map(str::to_string))
+                    .part_number(part_number)
+                    .build(),
+            );
+            sent += len;
+            progress(sent, total);
+        }
+
+        Ok(parts)
+    }
+}
+
+#[async_trait]
+impl BackupStorePort for S3BackupStore {
+    async fn upload(
+        &self,
+        key: &str,
+        path: &Path,
+        progress: BackupProgress<'_>,
+    ) -> Result<(), BackupStoreError> {
+        let total = tokio::fs::metadata(path)
+            .await
+            .map_err(|e| io(path, &e))?
+            .len();
+        if total > PART_SIZE as u64 {
+            return self.upload_multipart(key, path, total, progress).await;
+        }
+
+        let body = ByteStream::from_path(path)
+            .await
+            .map_err(|e| BackupStoreError::Io {
+                path: path.display().to_string(),
+                message: e.to_string(),
+            })?;
+        self.client
+            .put_object()
+            .bucket(&self.bucket)
+            .key(key)
+            .body(body)
+            .send()
+            .await
+            .map_err(service)?;
+        progress(total, total);
+        Ok(())
+    }
+
+    async fn download(
+        &self,
+        key: &str,
+        dest: &Path,
+        progress: BackupProgress<'_>,
+    ) -> Result<(), BackupStoreError> {
+        let object = self
+            .client
+            .get_object()
+            .bucket(&self.bucket)
+            .key(key)
+            .send()
+            .await
+            .map_err(|e| {
+                if e.as_service_error()
+                    .is_some_and(GetObjectError::is_no_such_key)
+                {
+                    BackupStoreError::NotFound(key.to_string())
+                } else {
+                    service(e)
+                }
+            })?;
+        let total = object
+            .content_length()
+            .and_then(|len| u64::try_from(len).ok())
+            .unwrap_or(0);
+
+        // Stream into a sibling file and rename, so an interrupted restore
+        // never leaves a truncated file under the real name.
+        let partial = partial_path(dest);
+        let mut file = tokio::fs::File::create(&partial)
+            .await
+            .map_err(|e| io(&partial, &e))?;
+        let mut body = object.body;
+        let mut received = 0u64;
+        while let Some(bytes) = body.try_next().await.map_err(|e| BackupStoreError::Io {
+            path: partial.display().to_string(),
+            message: e.to_string(),
+        })? {
+            file.write_all(&bytes).await.map_err(|e| io(&partial, &e))?;
+            received += bytes.len() as u64;
+            progress(received, total.max(received));
+        }
+        file.flush().await.map_err(|e| io(&partial, &e))?;
+        drop(file);
+
+        tokio::fs::rename(&partial, dest)
+            .await
+            .map_err(|e| io(dest, &e))
+    }
+
+    async fn list(&self, prefix: &str) -> Result<Vec<BackupObject>, BackupStoreError> {
+        let mut pages = self
+            .client
+            .list_objects_v2()
+            .bucket(&self.bucket)
+            .prefix(prefix)
+            .into_paginator()
+            .send();
+
+        let mut objects = Vec::new();
+        while let Some(page) = pages.next().await {
+            let page = page.map_err(service)?;
+            for object in page.contents() {
+                let Some(key) = object.key() else { continue };
+                objects.push(BackupObject {
+                    key: key.to_string(),
+                    size: object
+                        .size()
+                        .and_then(|size| u64::try_from(size).ok())
+                        .unwrap_or(0),
+                    last_modified: object
+                        .last_modified()
+                        .and_then(|t| t.fmt(DateTimeFormat::DateTime).ok()),
+                });
+            }
+        }
+        Ok(objects)
+    }
+}
+
+/// Read up to `limit` bytes, stopping early only at end of file.
+async fn read_chunk(file: &mut tokio::fs::File, limit: usize) -> std::io::Result<Vec<u8>> {
+    let mut chunk = Vec::with_capacity(limit);
+    while chunk.len() < limit {
+        let read = (&mut *file)
+            .take((limit - chunk.len()) as u64)
+            .read_to_end(&mut chunk)
+            .await?;
+        if read == 0 {
+            break;
+        }
+    }
+    Ok(chunk)
+}
+
+fn partial_path(dest: &Path) -> PathBuf {
+    let mut name = dest.file_name().unwrap_or_default().to_os_string();
+    name.push(".part");
+    dest.with_file_name(name)
+}
+
+fn service(e: impl std::error::Error) -> BackupStoreError {
+    BackupStoreError::Service(DisplayErrorContext(e).to_string())
+}
+
+fn io(path: &Path, e: &std::io::Error) -> BackupStoreError {
+    BackupStoreError::Io {
+        path: path.display().to_string(),
+        message: e.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn read_chunk_fills_each_part_until_end_of_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("chunks.bin");
+        std::fs::write(&path, vec![7u8; 10]).unwrap();
+
+        let mut file = tokio::fs::File::open(&path).await.unwrap();
+        assert_eq!(read_chunk(&mut file, 4).await.unwrap().len(), 4);
+        assert_eq!(read_chunk(&mut file, 4).await.unwrap().len(), 4);
+        assert_eq!(read_chunk(&mut file, 4).await.unwrap().len(), 2);
+        assert!(read_chunk(&mut file, 4).await.unwrap().is_empty());
+    }
+
+    #[test]
+    fn downloads_stage_next_to_the_destination() {
+        assert_eq!(
+            partial_path(Path::new("/models/a.gguf")),
+            Path::new("/models/a.gguf.part")
+        );
+    }
+}
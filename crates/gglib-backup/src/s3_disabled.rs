@@ -0,0 +1,52 @@
+//! Stand-in for the S3 client when the `s3` feature is off.
+//!
+//! Construction always fails with [`BackupStoreError::Unsupported`], so no
+//! [`S3BackupStore`] can exist and the call paths are unreachable.
+
+use std::convert::Infallible;
+use std::path::Path;
+
+use async_trait::async_trait;
+use gglib_core::domain::{BackupConfig, BackupObject};
+use gglib_core::ports::{BackupProgress, BackupStoreError, BackupStorePort};
+
+/// Uninhabited: this build cannot reach a backup bucket.
+pub struct S3BackupStore {
+    never: Infallible,
+}
+
+impl S3BackupStore {
+    /// Always fails: this build has no S3 client.
+    ///
+    /// # Errors
+    ///
+    /// Always [`BackupStoreError::Unsupported`].
+    pub const fn new(_config: &BackupConfig) -> Result<Self, BackupStoreError> {
+        Err(BackupStoreError::Unsupported)
+    }
+}
+
+#[async_trait]
+impl BackupStorePort for S3BackupStore {
+    async fn upload(
+        &self,
+        _key: &str,
+        _path: &Path,
+        _progress: BackupProgress<'_>,
+    ) -> Result<(), BackupStoreError> {
+        match self.never {}
+    }
+
+    async fn download(
+        &self,
+        _key: &str,
+        _dest: &Path,
+        _progress: BackupProgress<'_>,
+    ) -> Result<(), BackupStoreError> {
+        match self.never {}
+    }
+
+    async fn list(&self, _prefix: &str) -> Result<Vec<BackupObject>, BackupStoreError> {
+        match self.never {}
+    }
+}
@@ -1,21 +1,33 @@
 //! [`CoreBootstrap`] — the shared composition root for all gglib adapters.
 
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
+use std::time::Duration;
 
 use anyhow::Result;
+use sqlx::SqlitePool;
 
 use gglib_core::ModelRegistrar;
+use gglib_core::domain::trash::TRASH_DIR;
+use gglib_core::jobs::{DownloadJobTracker, JobManager};
 use gglib_core::ports::{
     AppEventBridge, AppEventEmitter, DownloadManagerConfig, DownloadManagerPort, GgufParserPort,
-    HfClientPort, ModelRegistrarPort, ModelRepository, ProcessRunner,
+    HF_TOKEN_SECRET, HfClientPort, ModelRegistrarPort, ModelRepository, ProcessRunner, Repos,
 };
-use gglib_core::services::{AppCore, ModelVerificationService};
+use gglib_core::services::{
+    AppCore, AttachmentService, AuditLogService, ModelTrashService, ModelUsageService,
+    ModelVerificationService,
+};
+use gglib_core::settings::DEFAULT_PARTIAL_DOWNLOAD_GRACE_HOURS;
+use gglib_core::utils::clock::SystemClock;
+use gglib_core::utils::fs::RealFs;
 use gglib_db::{CoreFactory, ModelFilesRepository, setup_database};
 use gglib_download::{DownloadManagerDeps, build_download_manager};
 // GGUF_BOOTSTRAP_EXCEPTION: Parser injected at composition root only
 use gglib_gguf::GgufParser;
-use gglib_hf::{DefaultHfClient, HfClientConfig};
-use gglib_runtime::LlamaServerRunner;
+use gglib_hf::{DefaultHfClient, DefaultModelScopeClient, HfClientConfig};
+use gglib_runtime::llama::InstalledArchitectureSupport;
+use gglib_runtime::{LlamaServerRunner, RunningServerTitleGenerator};
 
 use crate::built::BuiltCore;
 use crate::config::BootstrapConfig;
@@ -78,42 +90,68 @@ impl CoreBootstrap {
         ));
         let model_registrar: Arc<dyn ModelRegistrarPort> = model_registrar_concrete.clone();
 
-        // 6. Download manager configuration
-        let download_config = {
-            let mut cfg = DownloadManagerConfig::new(config.models_dir);
-            if let Some(token) = config.hf_token {
-                cfg = cfg.with_hf_token(Some(token));
-            }
-            cfg
-        };
-
-        // 7. HuggingFace client
-        let hf_client_concrete = Arc::new(DefaultHfClient::new(&HfClientConfig::default()));
+        // 6. Secret store — keyring, or an encrypted file next to the
+        //    database. An explicit token wins over the stored one.
+        let data_dir = config
+            .db_path
+            .parent()
+            .map_or_else(|| ".".into(), std::path::Path::to_path_buf);
+        let secrets = gglib_secrets::open(&data_dir);
+        let hf_token = config.hf_token.or_else(|| {
+            secrets
+                .get(HF_TOKEN_SECRET)
+                .inspect_err(|e| tracing::warn!("Could not read stored HF token: {e}"))
+                .ok()
+                .flatten()
+        });
+
+        // 7. Download manager configuration.
+        let trash_dir = config.models_dir.join(TRASH_DIR);
+        let download_config = download_config(config.models_dir, hf_token.clone(), &repos).await;
+
+        // 8. HuggingFace client — authenticated with the same token so
+        //    gated repos resolve as well as download.
+        let hf_client_concrete = Arc::new(DefaultHfClient::new(
+            &HfClientConfig::default().with_optional_token(hf_token),
+        ));
         let hf_client: Arc<dyn HfClientPort> = hf_client_concrete.clone();
 
-        // 8. Download state repository
+        // 9. Download state repository
         let download_repo = CoreFactory::download_state_repository(pool.clone());
 
-        // 9. Download manager — `DownloadManagerDeps<R,..>` requires R: Sized,
+        // 10. Download manager — `DownloadManagerDeps<R,..>` requires R: Sized,
         //    so we pass the concrete registrar. The emitter is bridged from the
         //    adapter's AppEventEmitter to satisfy DownloadEventEmitterPort.
-        let download_emitter = Arc::new(AppEventBridge::new(Arc::clone(&emitter)));
-        let downloads: Arc<dyn DownloadManagerPort> =
-            Arc::new(build_download_manager(DownloadManagerDeps {
+        //    Every download is also tracked as a job on the way, and each
+        //    registered model is checked against the installed llama.cpp.
+        let jobs = Arc::new(JobManager::new(Arc::clone(&emitter)));
+        let bridge = Arc::new(AppEventBridge::new(Arc::clone(&emitter)));
+        let download_emitter = Arc::new(DownloadJobTracker::new(bridge, Arc::clone(&jobs)));
+        //    `modelscope:` references are downloaded from ModelScope.
+        //    The janitor sweeps orphaned partial downloads from here on.
+        let download_manager = Arc::new(
+            build_download_manager(DownloadManagerDeps {
                 model_registrar: model_registrar_concrete,
                 download_repo,
                 hf_client: hf_client_concrete,
                 event_emitter: download_emitter,
+                arch_support: Some(Arc::new(InstalledArchitectureSupport::new())),
+                clock: Arc::new(SystemClock),
+                fs: Arc::new(RealFs),
                 config: download_config,
-            }));
+            })
+            .with_hub(Arc::new(DefaultModelScopeClient::default_client())),
+        );
+        download_manager.start_janitor();
+        let downloads: Arc<dyn DownloadManagerPort> = download_manager;
 
-        // 10. Download trigger adapter (bridges DownloadManagerPort →
+        // 11. Download trigger adapter (bridges DownloadManagerPort →
         //     DownloadTriggerPort for ModelVerificationService)
         let download_trigger = Arc::new(DownloadTriggerAdapter {
             download_manager: Arc::clone(&downloads),
         });
 
-        // 11. Model verification service
+        // 12. Model verification service
         let model_repo: Arc<dyn ModelRepository> = repos.models.clone();
         let verification_service = Arc::new(ModelVerificationService::new(
             Arc::clone(&model_repo),
@@ -122,12 +160,34 @@ impl CoreBootstrap {
             download_trigger,
         ));
 
-        // 12. AppCore — fully wired with verification
+        // 13. Attachment service and model trash, each with its own
+        //     directory; the trash purger starts here, as does the usage
+        //     collector fed by the runtime's per-server stats.
+        let attachments = attachment_service(&config.db_path, &pool, &repos);
+        let trash = trash_service(trash_dir, &pool, &repos);
+
+        // 14. AppCore — fully wired with secrets, share links, verification,
+        //     attachments, trash, usage, the audit log and auto-titling. Titles come from
+        //     whichever server the runner has up and are announced through
+        //     the adapter's emitter.
+        let title_generator = Arc::new(RunningServerTitleGenerator::new(Arc::clone(&runner)));
         let app = Arc::new(
             AppCore::new(repos.clone(), Arc::clone(&runner))
-                .with_verification(verification_service),
+                .with_secrets(secrets)
+                .with_chat_shares(CoreFactory::conversation_share_repository(pool.clone()))
+                .with_verification(verification_service)
+                .with_attachments(attachments)
+                .with_trash(trash)
+                .with_usage(usage_service(&pool, &repos))
+                .with_audit(Arc::new(AuditLogService::new(
+                    CoreFactory::audit_log_repository(pool.clone()),
+                )))
+                .with_auto_title(title_generator, Arc::clone(&emitter)),
         );
 
+        // 15. Stored settings that act once at startup.
+        apply_startup_settings(&app).await;
+
         tracing::debug!(
             db_path = %config.db_path.display(),
             "CoreBootstrap: infrastructure wired successfully"
@@ -142,6 +202,95 @@ impl CoreBootstrap {
             repos,
             model_registrar,
             pool,
+            jobs,
         })
     }
 }
+
+/// Attachment service — blobs sit in `attachments/` next to the database so
+/// they share its data root (and its test tempdirs).
+fn attachment_service(db_path: &Path, pool: &SqlitePool, repos: &Repos) -> Arc<AttachmentService> {
+    let attachments_dir = db_path
+        .parent()
+        .map_or_else(|| "attachments".into(), |dir| dir.join("attachments"));
+    Arc::new(AttachmentService::new(
+        CoreFactory::attachment_repository(pool.clone()),
+        Arc::clone(&repos.settings),
+        attachments_dir,
+    ))
+}
+
+/// Download manager configuration. The partial-download grace period is
+/// read from the stored settings here, at startup.
+async fn download_config(
+    models_dir: PathBuf,
+    hf_token: Option<String>,
+    repos: &Repos,
+) -> DownloadManagerConfig {
+    let grace_hours = repos
+        .settings
+        .load()
+        .await
+        .map_or(DEFAULT_PARTIAL_DOWNLOAD_GRACE_HOURS, |settings| {
+            settings.effective_partial_download_grace_hours()
+        });
+    DownloadManagerConfig::new(models_dir)
+        .with_hf_token(hf_token)
+        .with_partial_grace_period(Duration::from_hours(u64::from(grace_hours)))
+}
+
+/// Model trash — removed models wait in `trash_dir` until the purger,
+/// started here, deletes them for good.
+fn trash_service(trash_dir: PathBuf, pool: &SqlitePool, repos: &Repos) -> Arc<ModelTrashService> {
+    let trash = Arc::new(ModelTrashService::new(
+        repos.models.clone(),
+        CoreFactory::model_trash_repository(pool.clone()),
+        Arc::clone(&repos.settings),
+        trash_dir,
+    ));
+    trash.start_purger();
+    trash
+}
+
+/// Model usage — the runtime's stats registry counts requests, tokens and
+/// serve and busy time per model; the collector started here stores them
+/// daily. The system probe supplies the fallback draw for energy estimates.
+fn usage_service(pool: &SqlitePool, repos: &Repos) -> Arc<ModelUsageService> {
+    let usage = Arc::new(ModelUsageService::new(
+        repos.models.clone(),
+        CoreFactory::model_usage_repository(pool.clone()),
+        Arc::clone(&repos.settings),
+        Arc::new(gglib_runtime::DefaultSystemProbe::new()),
+    ));
+    usage.start_collector(gglib_runtime::process::get_stats_registry());
+    usage
+}
+
+/// Apply the stored settings that take effect at startup rather than per
+/// request. Failures are logged; none of them stops the app from starting.
+async fn apply_startup_settings(app: &AppCore) {
+    // Trace export — the subscriber was installed before the database was
+    // open, so the `otlp` setting is applied only now.
+    let settings = match app.settings().get().await {
+        Ok(settings) => settings,
+        Err(e) => {
+            tracing::warn!("Could not read settings at startup: {e}");
+            return;
+        }
+    };
+    gglib_runtime::otlp::apply(settings.otlp.as_ref());
+
+    // Credentials left in the settings row by older versions move into the
+    // secret store.
+    if let Err(e) = app.settings().migrate_secrets().await {
+        tracing::warn!("Could not migrate credentials to the secret store: {e}");
+    }
+
+    // Message content is brought in line with `encrypt_chat_history`.
+    // Running this on every start also finishes a pass that an earlier
+    // process was interrupted in.
+    let encrypt = settings.encrypt_chat_history.unwrap_or(false);
+    if let Err(e) = app.chat_history().apply_encryption(encrypt).await {
+        tracing::warn!("Could not apply chat history encryption: {e}");
+    }
+}
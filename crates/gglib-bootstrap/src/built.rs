@@ -2,6 +2,7 @@
 
 use std::sync::Arc;
 
+use gglib_core::jobs::JobManager;
 use gglib_core::ports::{
     DownloadManagerPort, GgufParserPort, HfClientPort, ModelRegistrarPort, ProcessRunner, Repos,
 };
@@ -37,4 +38,7 @@ pub struct BuiltCore {
     /// orchestrator repository in the Axum adapter) can use this pool
     /// directly rather than re-opening the database.
     pub pool: SqlitePool,
+    /// Registry of long-running operations. Downloads are tracked in it
+    /// already; adapters register the operations they start themselves.
+    pub jobs: Arc<JobManager>,
 }
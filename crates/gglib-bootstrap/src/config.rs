@@ -19,5 +19,7 @@ pub struct BootstrapConfig {
     /// Absolute path to the directory where model files are stored.
     pub models_dir: PathBuf,
     /// Optional `HuggingFace` API token for authenticated downloads.
+    ///
+    /// When `None`, the token kept in the secret store (if any) is used.
     pub hf_token: Option<String>,
 }
@@ -51,7 +51,10 @@ mod tests {
 
     use std::sync::Mutex;
 
-    use gglib_core::download::{DownloadError, DownloadId, Quantization, QueueSnapshot};
+    use gglib_core::download::{
+        CollectionQueueResult, DownloadError, DownloadHistoryFilter, DownloadId, GroupProgress,
+        Quantization, QueueRunSummary, QueueSnapshot,
+    };
     use gglib_core::ports::{DownloadManagerPort, DownloadRequest};
 
     // ── Minimal mock ──────────────────────────────────────────────────────────
@@ -116,6 +119,14 @@ mod tests {
             unimplemented!()
         }
 
+        async fn queue_collection(
+            self: Arc<Self>,
+            _slug: String,
+            _quantization: Option<String>,
+        ) -> Result<CollectionQueueResult, DownloadError> {
+            unimplemented!()
+        }
+
         async fn get_queue_snapshot(&self) -> Result<QueueSnapshot, DownloadError> {
             unimplemented!()
         }
@@ -156,6 +167,13 @@ mod tests {
             unimplemented!()
         }
 
+        async fn get_group_progress(
+            &self,
+            _group_id: &str,
+        ) -> Result<GroupProgress, DownloadError> {
+            unimplemented!()
+        }
+
         async fn retry(&self, _id: &DownloadId) -> Result<u32, DownloadError> {
             unimplemented!()
         }
@@ -164,6 +182,13 @@ mod tests {
             unimplemented!()
         }
 
+        async fn get_history(
+            &self,
+            _filter: &DownloadHistoryFilter,
+        ) -> Result<Vec<QueueRunSummary>, DownloadError> {
+            unimplemented!()
+        }
+
         async fn set_max_queue_size(&self, _size: u32) -> Result<(), DownloadError> {
             unimplemented!()
         }
@@ -6,7 +6,8 @@
 //! 1. Database pool + repository set
 //! 2. Process runner (`LlamaServerRunner`)
 //! 3. GGUF parser + model-files repository + model registrar
-//! 4. Download manager (accepting an injected event emitter)
+//! 4. Download manager (accepting an injected event emitter), each download
+//!    tracked as a job in the shared `JobManager`
 //! 5. `DownloadTriggerAdapter` (bridges `DownloadManagerPort` → `DownloadTriggerPort`)
 //! 6. `ModelVerificationService` + fully wired `AppCore`
 //!
@@ -0,0 +1,38 @@
+# gglib CLI messages — de.
+#
+# Ids missing here fall back to the en-US catalog.
+
+## Relative times (presentation/tables.rs)
+
+time-just-now = gerade eben
+time-minutes-ago = vor { $count } Min.
+time-hours-ago =
+    { $count ->
+        [one] vor 1 Stunde
+       *[other] vor { $count } Stunden
+    }
+time-yesterday = gestern
+time-days-ago = vor { $count } Tagen
+
+## gglib doctor
+
+doctor-heading = gglib-Installation wird geprüft...
+doctor-summary = { $passed } bestanden, { $warned } Warnung(en), { $failed } fehlgeschlagen
+doctor-suggest-fix = { $command } ausführen, um { $count } vorgeschlagene Korrektur(en) anzuwenden.
+doctor-nothing-to-fix = Nichts zu korrigieren.
+doctor-fix-skipped = Übersprungen.
+doctor-rerun = Zur Bestätigung { $command } erneut ausführen.
+doctor-failed = { $count } Prüfung(en) fehlgeschlagen
+
+## gglib recommend
+
+recommend-budget = Budget: { $size } { $source }
+recommend-source-gpu = GPU-Speicher
+recommend-source-cpu = Arbeitsspeicher (CPU-Inferenz)
+recommend-none-fit = Kein Katalogmodell passt auf diesen Rechner.
+recommend-download-hint = Herunterladen mit: { $command }
+
+## gglib config settings
+
+settings-current = Aktuelle Anwendungseinstellungen:
+settings-updated = ✓ Einstellungen aktualisiert:
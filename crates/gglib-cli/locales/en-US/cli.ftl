@@ -0,0 +1,39 @@
+# gglib CLI messages — en-US (the reference catalog).
+#
+# Every other catalog falls back to this one for ids it does not define.
+# Numbers and sizes are passed in already formatted for the locale.
+
+## Relative times (presentation/tables.rs)
+
+time-just-now = just now
+time-minutes-ago = { $count } min ago
+time-hours-ago =
+    { $count ->
+        [one] 1 hour ago
+       *[other] { $count } hours ago
+    }
+time-yesterday = yesterday
+time-days-ago = { $count } days ago
+
+## gglib doctor
+
+doctor-heading = Checking gglib installation...
+doctor-summary = { $passed } passed, { $warned } warning(s), { $failed } failed
+doctor-suggest-fix = Run { $command } to apply { $count } suggested fix(es).
+doctor-nothing-to-fix = Nothing to fix.
+doctor-fix-skipped = Skipped.
+doctor-rerun = Re-run { $command } to confirm.
+doctor-failed = { $count } check(s) failed
+
+## gglib recommend
+
+recommend-budget = Budget: { $size } of { $source }
+recommend-source-gpu = GPU memory
+recommend-source-cpu = system RAM (CPU inference)
+recommend-none-fit = No catalog models fit this machine.
+recommend-download-hint = Download with: { $command }
+
+## gglib config settings
+
+settings-current = Current application settings:
+settings-updated = ✓ Settings updated successfully:
@@ -13,11 +13,11 @@ use anyhow::Result;
 use gglib_app_services::CouncilApprovalRegistry;
 use gglib_bootstrap::{BootstrapConfig, BuiltCore, CoreBootstrap};
 use gglib_core::ports::{
-    AppEventEmitter, DownloadManagerPort, GgufParserPort, ModelCatalogPort, ModelRegistrarPort,
-    ModelRepository, NoopEmitter, ProcessRunner, Repos, SettingsRepository,
+    AppEventEmitter, DownloadManagerPort, GgufParserPort, HF_TOKEN_SECRET, ModelCatalogPort,
+    ModelRegistrarPort, ModelRepository, NoopEmitter, ProcessRunner, Repos, SettingsRepository,
 };
 use gglib_core::services::AppCore;
-use gglib_db::{SqliteBenchmarkRepository, SqliteCouncilRepository};
+use gglib_db::{SqliteBenchmarkRepository, SqliteCouncilRepository, SqliteDatabaseSnapshot};
 use gglib_download::CliDownloadEventEmitter;
 use gglib_mcp::McpService;
 use gglib_runtime::CatalogPortImpl;
@@ -91,6 +91,8 @@ pub struct CliContext {
     pub council_repo: Arc<SqliteCouncilRepository>,
     /// Benchmark run repository for compare and perf results.
     pub bench_repo: Arc<SqliteBenchmarkRepository>,
+    /// Consistent copies of the live database, for `gglib backup push`.
+    pub db_snapshot: Arc<SqliteDatabaseSnapshot>,
     /// Settings repository for user preferences and inference defaults.
     pub settings_repo: Arc<dyn SettingsRepository>,
     /// Orchestrator approval registry for HITL gates.
@@ -103,6 +105,22 @@ pub struct CliContext {
     pub download_emitter: Arc<CliDownloadEventEmitter>,
 }
 
+impl CliContext {
+    /// `HuggingFace` token: `HF_TOKEN` if set, else the one kept in the
+    /// secret store — the same precedence the download manager uses.
+    pub fn hf_token(&self) -> Option<String> {
+        std::env::var("HF_TOKEN").ok().or_else(|| {
+            self.app
+                .settings()
+                .secrets()?
+                .get(HF_TOKEN_SECRET)
+                .inspect_err(|e| tracing::warn!("Could not read stored HF token: {e}"))
+                .ok()
+                .flatten()
+        })
+    }
+}
+
 /// Bootstrap the CLI application.
 ///
 /// Delegates all shared wiring to [`CoreBootstrap::build`] and adds the
@@ -138,9 +156,11 @@ pub async fn bootstrap(config: CliConfig) -> Result<CliContext> {
         repos,
         model_registrar,
         pool,
+        jobs: _,
     } = CoreBootstrap::build(bootstrap_config, emitter).await?;
 
     let bench_repo = Arc::new(SqliteBenchmarkRepository::new(pool.clone()));
+    let db_snapshot = Arc::new(SqliteDatabaseSnapshot::new(pool.clone()));
     let council_repo = Arc::new(SqliteCouncilRepository::new(pool));
     let approval_registry = Arc::new(CouncilApprovalRegistry::new());
 
@@ -165,6 +185,7 @@ pub async fn bootstrap(config: CliConfig) -> Result<CliContext> {
         http_client: reqwest::Client::new(),
         council_repo,
         bench_repo,
+        db_snapshot,
         settings_repo: repos.settings,
         approval_registry,
         download_emitter,
@@ -203,6 +224,7 @@ pub fn bootstrap_with(
         http_client: reqwest::Client::new(),
         council_repo: Arc::new(SqliteCouncilRepository::new_in_memory_blocking()),
         bench_repo: Arc::new(SqliteBenchmarkRepository::new_in_memory_blocking()),
+        db_snapshot: Arc::new(SqliteDatabaseSnapshot::new_in_memory()),
         settings_repo: repos.settings.clone(),
         approval_registry: Arc::new(CouncilApprovalRegistry::new()),
         download_emitter: Arc::new(CliDownloadEventEmitter::new()),
@@ -5,15 +5,25 @@
 //! under [`ConfigCommand`]; inference commands stay top-level for
 //! ergonomic direct access.
 
-use clap::Subcommand;
+use clap::{Subcommand, ValueEnum};
 use clap_complete::Shell;
 use gglib_core::cache_config::KvCacheType;
+use gglib_core::domain::audit::AuditAction;
+use gglib_core::domain::chat_import::ChatExportFormat;
+use gglib_core::domain::recommend::ModelTask;
+use gglib_core::download::CompletionKind;
 
 use crate::benchmark_commands::BenchmarkCommand;
 use crate::config_commands::ConfigCommand;
 use crate::mcp_commands::McpCommand;
 use crate::model_commands::ModelCommand;
-use crate::shared_args::{ContextArgs, MtpArgs, SamplingArgs, ServeOptions};
+use crate::plugin_commands::PluginsCommand;
+use crate::shared_args::{
+    ContextArgs, CpuArgs, GpuArgs, MtpArgs, RopeArgs, SamplingArgs, ServeOptions,
+};
+use crate::stats_commands::StatsCommand;
+use crate::template_commands::TemplateCommand;
+use crate::trash_commands::TrashCommand;
 
 /// Subcommands available under `gglib council`.
 #[derive(Subcommand)]
@@ -135,6 +145,40 @@ pub enum CouncilCmd {
     },
 }
 
+/// Subcommands available under `gglib pipeline`.
+#[derive(Subcommand)]
+pub enum PipelineCmd {
+    /// Run a pipeline definition (YAML or JSON)
+    #[command(display_order = 1)]
+    Run {
+        /// Path to the pipeline definition
+        file: std::path::PathBuf,
+        /// Value for an `{{input.NAME}}` placeholder, as NAME=VALUE (can be repeated)
+        #[arg(short, long = "input", value_name = "NAME=VALUE")]
+        inputs: Vec<String>,
+        /// Send every step to an already-running llama-server on this port
+        /// (skips auto-start and per-step model swaps)
+        #[arg(long)]
+        port: Option<u16>,
+        /// Print the stored run as JSON instead of the final output
+        #[arg(long)]
+        json: bool,
+        #[command(flatten)]
+        context: ContextArgs,
+    },
+
+    /// List past pipeline runs
+    #[command(display_order = 2)]
+    List,
+
+    /// Show the step outputs of a pipeline run
+    #[command(display_order = 3)]
+    Show {
+        /// ID of the run to inspect
+        run_id: i64,
+    },
+}
+
 /// Subcommands available under `gglib chat`.
 #[derive(Subcommand)]
 pub enum ChatCommand {
@@ -144,6 +188,32 @@ pub enum ChatCommand {
         #[arg(short = 'n', long, default_value = "20")]
         limit: usize,
     },
+    /// Import conversations from a ChatGPT or Claude data export
+    Import {
+        /// The app the export came from
+        #[arg(long, value_enum)]
+        format: ChatExportFormatArg,
+        /// The export's conversations.json, or the unzipped export folder
+        file: std::path::PathBuf,
+    },
+}
+
+/// Export formats accepted by `gglib chat import --format`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum ChatExportFormatArg {
+    /// OpenAI ChatGPT data export
+    Chatgpt,
+    /// Anthropic Claude data export
+    Claude,
+}
+
+impl From<ChatExportFormatArg> for ChatExportFormat {
+    fn from(arg: ChatExportFormatArg) -> Self {
+        match arg {
+            ChatExportFormatArg::Chatgpt => Self::ChatGpt,
+            ChatExportFormatArg::Claude => Self::Claude,
+        }
+    }
 }
 
 /// Subcommands available under `gglib proxy`.
@@ -181,6 +251,212 @@ pub enum ProxyCommand {
         #[arg(long)]
         session_id: Option<String>,
     },
+    /// List recent moderation decisions on an already-running proxy
+    ///
+    /// Reads `GET /v1/proxy/moderation`: which rule or classifier fired on
+    /// which request, the action taken, and the matched text.
+    Moderation {
+        /// Host of the already-running proxy to connect to
+        #[arg(long, default_value = "127.0.0.1")]
+        host: String,
+        /// Port of the already-running proxy to connect to
+        #[arg(short, long, default_value = "8080")]
+        port: u16,
+    },
+}
+
+/// Subcommands available under `gglib downloads`.
+#[derive(Subcommand)]
+pub enum DownloadsCommand {
+    /// Inspect or empty the quarantine of downloads that failed validation
+    Quarantine {
+        #[command(subcommand)]
+        command: QuarantineCommand,
+    },
+    /// Show past download runs: what was fetched, when, and after how many attempts
+    History {
+        /// Only runs completed within the last N days
+        #[arg(long)]
+        days: Option<u32>,
+        /// Only entries whose last result is this (downloaded, failed, cancelled, already_present)
+        #[arg(long, value_parser = parse_completion_kind)]
+        result: Option<CompletionKind>,
+        /// Only entries whose name contains this text (case-insensitive)
+        #[arg(long)]
+        search: Option<String>,
+        /// Maximum number of runs to show
+        #[arg(long, default_value_t = 20)]
+        limit: u32,
+    },
+}
+
+fn parse_completion_kind(s: &str) -> Result<CompletionKind, String> {
+    CompletionKind::parse(s).ok_or_else(|| {
+        format!("unknown result '{s}' (expected downloaded, failed, cancelled or already_present)")
+    })
+}
+
+/// Subcommands available under `gglib downloads quarantine`.
+#[derive(Subcommand)]
+pub enum QuarantineCommand {
+    /// List quarantined downloads with their failure reasons
+    List,
+    /// Delete quarantined downloads
+    ///
+    /// Without a name every entry is deleted.
+    Clear {
+        /// Entry name as shown by `gglib downloads quarantine list`
+        name: Option<String>,
+    },
+}
+
+/// Subcommands available under `gglib sync`.
+#[derive(Subcommand)]
+pub enum SyncCommand {
+    /// Pair with another gglib instance started with `gglib web --share-token`
+    ///
+    /// The token is checked against the peer before the peer is stored.
+    /// With `--pair-back` the peer stores this instance too, so it can pull
+    /// from here in turn.
+    Pair {
+        /// Local name for the peer (letters, digits, '-', '_' or '.')
+        name: String,
+        /// Base URL of the peer's web server, e.g. `http://studio.local:9887`
+        url: String,
+        /// The peer's model share token
+        #[arg(long, env = "GGLIB_PEER_SHARE_TOKEN", hide_env_values = true)]
+        token: String,
+        /// This instance's URL as reachable from the peer; asks the peer to
+        /// pair back
+        #[arg(long, requires_all = ["share_token", "pair_back_name"])]
+        pair_back: Option<String>,
+        /// This instance's model share token, sent with `--pair-back`
+        #[arg(long, env = "GGLIB_MODEL_SHARE_TOKEN", hide_env_values = true)]
+        share_token: Option<String>,
+        /// Name the peer should store this instance under
+        #[arg(long = "as", value_name = "NAME")]
+        pair_back_name: Option<String>,
+    },
+    /// List paired instances
+    List,
+    /// Forget a paired instance
+    Unpair {
+        /// Peer name as shown by `gglib sync list`
+        name: String,
+    },
+    /// Download the models a peer has that this library lacks
+    ///
+    /// Models are compared by content hash, so a model registered under a
+    /// different name on either side is not copied again.
+    Pull {
+        /// Peer name as shown by `gglib sync list`
+        peer: String,
+        /// Only list the missing models
+        #[arg(long)]
+        dry_run: bool,
+        /// Never prompt or read keys while downloading
+        #[arg(short = 'y', long)]
+        yes: bool,
+    },
+}
+
+/// Subcommands available under `gglib backup`.
+#[derive(Subcommand)]
+pub enum BackupCommand {
+    /// Set the S3-compatible bucket backups go to
+    ///
+    /// The secret access key is moved to the system keychain when one is
+    /// available. Use `--endpoint` and `--path-style` for MinIO.
+    Configure {
+        /// Bucket name; it must already exist
+        bucket: String,
+        /// Endpoint of an S3-compatible service, e.g. `http://nas.local:9000`
+        #[arg(long)]
+        endpoint: Option<String>,
+        /// Bucket region (default `us-east-1`)
+        #[arg(long)]
+        region: Option<String>,
+        /// Key prefix for all backup objects (default `gglib`)
+        #[arg(long)]
+        prefix: Option<String>,
+        /// Access key ID
+        #[arg(long, env = "GGLIB_BACKUP_ACCESS_KEY_ID")]
+        access_key_id: String,
+        /// Secret access key
+        #[arg(long, env = "GGLIB_BACKUP_SECRET_ACCESS_KEY", hide_env_values = true)]
+        secret_access_key: String,
+        /// Address the bucket as `<endpoint>/<bucket>` (needed for MinIO)
+        #[arg(long)]
+        path_style: bool,
+        /// Upload model files with every `gglib backup push`
+        #[arg(long)]
+        include_models: bool,
+    },
+    /// Remove the backup configuration (the bucket is left untouched)
+    Disable,
+    /// Back up the database and, optionally, model files
+    ///
+    /// Model files the bucket already holds at the same size are skipped.
+    Push {
+        /// Upload model files too
+        #[arg(long, conflicts_with = "no_models")]
+        models: bool,
+        /// Skip model files even if the configuration includes them
+        #[arg(long)]
+        no_models: bool,
+    },
+    /// List database backups and model files in the bucket
+    List,
+    /// Restore a database backup and/or model files
+    ///
+    /// A restored database replaces the current one the next time gglib
+    /// starts; the current one is kept as `gglib.db.pre-restore`.
+    Restore {
+        /// Database backup to restore: a name from `gglib backup list`, or
+        /// `latest`
+        #[arg(long, value_name = "NAME")]
+        database: Option<String>,
+        /// Model file to restore, by name from `gglib backup list`
+        /// (repeatable)
+        #[arg(long = "model", value_name = "NAME")]
+        models: Vec<String>,
+        /// Restore every model file in the bucket
+        #[arg(long, conflicts_with = "models")]
+        all_models: bool,
+    },
+}
+
+/// Subcommands available under `gglib daemon`.
+#[derive(Subcommand)]
+pub enum DaemonCommand {
+    /// Start `gglib web` at login via the per-user service manager
+    ///
+    /// Uses a systemd user unit on Linux, a launchd agent on macOS and a
+    /// logon task in Task Scheduler on Windows. Re-running replaces the
+    /// existing registration.
+    InstallService {
+        /// Port the daemon serves the web GUI and API on
+        #[arg(short, long, default_value = "9887")]
+        port: u16,
+        /// Base port for llama-server instances
+        #[arg(long, default_value = "9000")]
+        base_port: u16,
+        /// Serve API endpoints only (do not serve static UI assets)
+        #[arg(long)]
+        api_only: bool,
+    },
+    /// Stop the daemon service and remove its registration
+    UninstallService,
+}
+
+/// Subcommands available under `gglib jobs`.
+#[derive(Subcommand)]
+pub enum JobsCommand {
+    /// Ask a running job to stop
+    Cancel {
+        /// ID of the job, as shown by `gglib jobs`
+        id: String,
+    },
 }
 
 /// Top-level commands for the GGUF library management tool.
@@ -193,6 +469,12 @@ pub enum Commands {
         #[command(subcommand)]
         command: ModelCommand,
     },
+    /// Restore or permanently delete removed models
+    #[command(display_order = 1)]
+    Trash {
+        #[command(subcommand)]
+        command: TrashCommand,
+    },
 
     /// Manage configuration, tooling, and system settings
     #[command(display_order = 2)]
@@ -208,6 +490,77 @@ pub enum Commands {
         command: McpCommand,
     },
 
+    /// Manage sandboxed WASM plugins that add tools for agents
+    #[command(display_order = 3)]
+    Plugins {
+        #[command(subcommand)]
+        command: PluginsCommand,
+    },
+
+    /// Review past downloads and manage those that failed validation
+    #[command(display_order = 4)]
+    Downloads {
+        #[command(subcommand)]
+        command: DownloadsCommand,
+    },
+
+    /// Show downloads, verifications and builds running in the daemon
+    ///
+    /// Lists the jobs of the `gglib web` daemon (or desktop app backend)
+    /// listening on `--port`, newest first. Without a subcommand, lists.
+    #[command(display_order = 4)]
+    Jobs {
+        /// Port of the running daemon
+        #[arg(short, long, default_value = "9887", global = true)]
+        port: u16,
+        #[command(subcommand)]
+        command: Option<JobsCommand>,
+    },
+
+    /// Pair with other gglib instances and pull their models
+    #[command(display_order = 4)]
+    Sync {
+        #[command(subcommand)]
+        command: SyncCommand,
+    },
+
+    /// Back up the database and models to an S3-compatible bucket
+    #[command(display_order = 4)]
+    Backup {
+        #[command(subcommand)]
+        command: BackupCommand,
+    },
+
+    /// Recommend models that fit this machine ("what can I run?")
+    ///
+    /// Ranks a curated catalog against detected GPU memory (or system RAM)
+    /// and shows the best quantization that fits each model.
+    #[command(display_order = 5)]
+    Recommend {
+        /// Only recommend models for this task (coding, chat, vision, embedding)
+        #[arg(short, long)]
+        task: Option<ModelTask>,
+        /// Maximum number of recommendations
+        #[arg(short = 'n', long, default_value = "5")]
+        limit: usize,
+    },
+
+    /// Diagnose the installation and offer fixes
+    ///
+    /// Checks llama.cpp binaries, the models directory, database integrity,
+    /// default ports, the Python download helper and GPU driver/CUDA
+    /// compatibility, reporting pass, warn or fail for each. Exits non-zero
+    /// when any check fails.
+    #[command(display_order = 5)]
+    Doctor {
+        /// Offer to repair each problem gglib can fix itself
+        #[arg(long)]
+        fix: bool,
+        /// Apply fixes without asking (with --fix)
+        #[arg(short, long, requires = "fix")]
+        yes: bool,
+    },
+
     // ── Inference ────────────────────────────────────────────────────────
     /// Serve a GGUF model with llama-server
     #[command(display_order = 10)]
@@ -222,6 +575,12 @@ pub enum Commands {
         sampling: SamplingArgs,
         #[command(flatten)]
         mtp: MtpArgs,
+        #[command(flatten)]
+        rope: RopeArgs,
+        #[command(flatten)]
+        cpu: CpuArgs,
+        #[command(flatten)]
+        gpu: GpuArgs,
     },
 
     /// Chat with a model interactively, or manage chat history
@@ -265,6 +624,10 @@ pub enum Commands {
         /// Resume a previous conversation by ID (use `gglib chat history` to find IDs)
         #[arg(long = "continue", alias = "c")]
         continue_id: Option<i64>,
+        /// Keep this conversation in memory only: it is never saved to
+        /// history and is gone when the session ends
+        #[arg(long, conflicts_with = "continue_id")]
+        incognito: bool,
         /// Observation-only tool name patterns for the dual-threshold loop guard.
         /// A tool whose name ends with or contains any pattern is classified as
         /// observation-only and subject to the higher --max-observation-steps limit.
@@ -336,6 +699,35 @@ pub enum Commands {
         #[arg(long = "max-observation-steps")]
         max_observation_steps: Option<usize>,
     },
+    /// Stream a one-shot answer to stdout (pipe-friendly; stdin is context)
+    #[command(
+        display_order = 12,
+        after_help = "EXAMPLES:\n    gglib ask coder \"Write a bash one-liner to count lines\"\n    cat diff.patch | gglib ask coder \"review this\"\n    git log -5 | gglib ask coder \"Summarize: {}\" --raw > summary.txt\n    gglib ask coder \"Name three sorting algorithms\" --json | jq -r .content"
+    )]
+    Ask {
+        /// Model ID or name (started automatically unless --port is given)
+        model: String,
+        /// Prompt; piped stdin becomes its context (use {} to place it), or
+        /// the whole prompt when this is omitted
+        prompt: Option<String>,
+        /// Set a system prompt
+        #[arg(long = "system-prompt", short = 's')]
+        system_prompt: Option<String>,
+        /// Port of a running llama-server to reuse (skips auto-start)
+        #[arg(long)]
+        port: Option<u16>,
+        #[command(flatten)]
+        context: ContextArgs,
+        #[command(flatten)]
+        sampling: SamplingArgs,
+        /// Print one JSON object (content, reasoning, finish_reason, usage)
+        /// instead of streaming text
+        #[arg(long, conflicts_with = "raw")]
+        json: bool,
+        /// Print only the answer text: no banner, no reasoning
+        #[arg(long)]
+        raw: bool,
+    },
     /// Run benchmark comparisons and performance tests across local models
     ///
     /// Compare outputs side-by-side (same prompt through N models) or measure
@@ -345,6 +737,35 @@ pub enum Commands {
         #[command(subcommand)]
         command: BenchmarkCommand,
     },
+    /// Rank models by how much they are used, to find ones worth pruning
+    #[command(display_order = 13)]
+    Stats {
+        #[command(subcommand)]
+        command: StatsCommand,
+    },
+    /// Show who removed models, changed settings, started servers or
+    /// edited the download queue through the web API
+    #[command(display_order = 13)]
+    Audit {
+        /// Only entries for this action (e.g. model_removed, settings_changed)
+        #[arg(long)]
+        action: Option<AuditAction>,
+        /// Only entries whose actor contains this text (case-insensitive)
+        #[arg(long)]
+        actor: Option<String>,
+        /// Only entries from the last N days
+        #[arg(long, value_name = "N", value_parser = clap::value_parser!(u32).range(1..))]
+        days: Option<u32>,
+        /// Show at most this many entries
+        #[arg(long, short = 'n', default_value_t = 100)]
+        limit: u32,
+    },
+    /// Check how a model's chat template renders conversations
+    #[command(display_order = 13)]
+    Template {
+        #[command(subcommand)]
+        command: TemplateCommand,
+    },
     /// Decompose a goal into a validated task graph (planning only, no execution)
     #[command(display_order = 14)]
     Plan {
@@ -370,6 +791,13 @@ pub enum Commands {
         cmd: CouncilCmd,
     },
 
+    /// Run multi-step pipelines that chain model calls and tools
+    #[command(display_order = 16)]
+    Pipeline {
+        #[command(subcommand)]
+        cmd: PipelineCmd,
+    },
+
     // ── Interfaces ──────────────────────────────────────────────────────
     /// Launch the Tauri desktop GUI
     #[command(display_order = 20)]
@@ -398,8 +826,47 @@ pub enum Commands {
         /// Path to the directory containing built frontend assets (e.g., ./web_ui/dist)
         #[arg(long)]
         static_dir: Option<std::path::PathBuf>,
+        /// How unknown non-API paths are answered: `index` (SPA routing),
+        /// `none` (404), or a file relative to the static directory
+        #[arg(long, default_value = "index")]
+        spa_fallback: gglib_axum::SpaFallback,
+        /// Let other machines download model files from
+        /// `/api/models/{id}/file` with this bearer token
+        #[arg(long, env = "GGLIB_MODEL_SHARE_TOKEN", hide_env_values = true)]
+        share_token: Option<String>,
+        /// Limit each client to this many API requests per minute; clients
+        /// are told apart by IP address or a --rate-limit-key
+        #[arg(long, value_name = "PER_MINUTE", value_parser = clap::value_parser!(u32).range(1..))]
+        rate_limit: Option<u32>,
+        /// Limit each client to this many uploads and chat requests per
+        /// minute (default 30 when only --rate-limit is given)
+        #[arg(long, value_name = "PER_MINUTE", value_parser = clap::value_parser!(u32).range(1..))]
+        heavy_rate_limit: Option<u32>,
+        /// API key that is rate-limited on its own rather than by IP
+        /// address (repeatable). Keys don't gate access.
+        #[arg(
+            long = "rate-limit-key",
+            value_name = "KEY",
+            env = "GGLIB_RATE_LIMIT_KEYS",
+            value_delimiter = ',',
+            hide_env_values = true
+        )]
+        rate_limit_keys: Vec<String>,
     },
 
+    /// Run the web server as a background service
+    #[command(display_order = 21)]
+    Daemon {
+        #[command(subcommand)]
+        command: DaemonCommand,
+    },
+
+    /// Full-screen terminal console: library, servers, downloads and chat
+    ///
+    /// Servers started from the console are stopped when it exits.
+    #[command(display_order = 23)]
+    Tui,
+
     /// Generate shell completion scripts (bash, zsh, fish, elvish, powershell)
     ///
     /// Prints a completion script to stdout. Pipe it into your shell's config:
@@ -431,9 +898,35 @@ pub enum Commands {
     /// CLI — all surfaces go through the same canonical config builder.
     #[command(display_order = 22)]
     Proxy {
-        /// Host to bind to
-        #[arg(long, default_value = "127.0.0.1")]
-        host: String,
+        /// Host to bind to. Falls back to the `proxy_host` setting, then
+        /// `127.0.0.1`. Addresses other machines can reach (`0.0.0.0`, a LAN
+        /// address) also need `--allow-public`.
+        #[arg(long)]
+        host: Option<String>,
+        /// Allow binding a host other machines can reach, e.g. `0.0.0.0` so
+        /// containers can connect. Also enabled by the `proxy_allow_public`
+        /// setting.
+        #[arg(long)]
+        allow_public: bool,
+        /// Require clients to send `Authorization: Bearer <key>`. Strongly
+        /// recommended with `--allow-public`.
+        #[arg(long, env = "GGLIB_PROXY_API_KEY", hide_env_values = true)]
+        api_key: Option<String>,
+        /// Further keys that are accepted but always run at background
+        /// priority, behind interactive requests (comma-separated)
+        #[arg(
+            long = "background-api-key",
+            value_name = "KEY",
+            env = "GGLIB_PROXY_BACKGROUND_API_KEYS",
+            hide_env_values = true,
+            value_delimiter = ','
+        )]
+        background_api_keys: Vec<String>,
+        /// Origin browsers may call the proxy from (repeatable), replacing the
+        /// `proxy_allowed_origins` setting. Without either, any origin is
+        /// allowed.
+        #[arg(long = "allow-origin", value_name = "ORIGIN")]
+        allow_origins: Vec<String>,
         /// Port to bind the proxy to
         #[arg(short, long, default_value = "8080")]
         port: u16,
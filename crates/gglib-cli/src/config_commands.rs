@@ -4,7 +4,7 @@
 //! models directory, llama.cpp toolchain, assistant-ui, system
 //! dependency checks, and resolved path inspection.
 
-use clap::Subcommand;
+use clap::{Args, Subcommand, ValueEnum};
 
 use crate::assistant_ui_commands::AssistantUiCommand;
 use crate::llama_commands::LlamaCommand;
@@ -137,6 +137,205 @@ pub enum ProfileCommand {
     },
 }
 
+/// Web search providers selectable with `--web-search-provider`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum WebSearchProviderArg {
+    /// A SearxNG instance (needs --web-search-url)
+    Searxng,
+    /// The Brave Search API (needs --web-search-api-key)
+    Brave,
+    /// DuckDuckGo's HTML endpoint (no account needed)
+    Duckduckgo,
+}
+
+/// `--web-search-*` flags of `config settings set`.
+#[derive(Debug, Args)]
+pub struct WebSearchArgs {
+    /// Enable the built-in web_search tool with this provider
+    #[arg(long, value_enum)]
+    pub web_search_provider: Option<WebSearchProviderArg>,
+    /// SearxNG instance URL (e.g. http://localhost:8888)
+    #[arg(long)]
+    pub web_search_url: Option<String>,
+    /// Brave Search API key (kept in the OS keyring, not the settings database)
+    #[arg(long)]
+    pub web_search_api_key: Option<String>,
+    /// Results returned per web search (1-20, default 5)
+    #[arg(long)]
+    pub web_search_max_results: Option<u32>,
+    /// Comma-separated domains web search results are limited to
+    #[arg(long, value_delimiter = ',')]
+    pub web_search_allow_domains: Option<Vec<String>>,
+    /// Comma-separated domains dropped from web search results
+    #[arg(long, value_delimiter = ',')]
+    pub web_search_deny_domains: Option<Vec<String>>,
+    /// Turn the web_search tool off
+    #[arg(long, conflicts_with_all = [
+        "web_search_provider",
+        "web_search_url",
+        "web_search_api_key",
+        "web_search_max_results",
+        "web_search_allow_domains",
+        "web_search_deny_domains",
+    ])]
+    pub no_web_search: bool,
+}
+
+/// `--trash-*` and `--watch-models-dir` flags of `config settings set`.
+#[derive(Debug, Args)]
+pub struct TrashArgs {
+    /// Days a removed model stays in the trash before it is purged
+    /// (default 30)
+    #[arg(long)]
+    pub trash_retention_days: Option<u32>,
+    /// Trash size cap in GB; the oldest removed models are purged first
+    #[arg(long)]
+    pub trash_max_size_gb: Option<u32>,
+    /// Add `.gguf` files copied into the models directory, and trash models
+    /// whose file is deleted, while `gglib web` runs (read at startup)
+    #[arg(long)]
+    pub watch_models_dir: Option<bool>,
+}
+
+/// `--proxy-*` network access and scheduling flags of `config settings set`.
+#[derive(Debug, Args)]
+pub struct ProxyArgs {
+    /// Address the proxy binds to (an IP address or localhost); addresses
+    /// other machines can reach, such as 0.0.0.0, need --proxy-allow-public
+    #[arg(long)]
+    pub proxy_host: Option<String>,
+    /// Allow the proxy to bind an address other machines can reach
+    #[arg(long)]
+    pub proxy_allow_public: Option<bool>,
+    /// Comma-separated origins browsers may call the proxy from
+    /// (e.g. http://localhost:3000); any origin when unset
+    #[arg(long, value_delimiter = ',')]
+    pub proxy_allowed_origins: Option<Vec<String>>,
+    /// Cut background-priority streams short when interactive requests are
+    /// waiting for a slot
+    #[arg(long)]
+    pub proxy_preempt_background: Option<bool>,
+    /// Drop reasoning models' chain of thought from streamed responses
+    /// instead of passing it through as reasoning_content
+    #[arg(long)]
+    pub proxy_strip_reasoning: Option<bool>,
+    #[command(flatten)]
+    pub moderation: ModerationArgs,
+    #[command(flatten)]
+    pub limits: ProxyLimitArgs,
+}
+
+/// `--proxy-max-*` generation limit flags of `config settings set`, part of
+/// [`ProxyArgs`]. A value of 0 removes that limit.
+#[derive(Debug, Args)]
+pub struct ProxyLimitArgs {
+    /// Most tokens one completion may generate; larger or missing max_tokens
+    /// are lowered to it
+    #[arg(long, value_name = "N")]
+    pub proxy_max_tokens: Option<u32>,
+    /// Longest a request may run on the server, in seconds; streams are then
+    /// ended with finish_reason "time_limit"
+    #[arg(long, value_name = "SECONDS")]
+    pub proxy_max_request_seconds: Option<u32>,
+    /// Most streams one API key (or, without a proxy key, one IP address)
+    /// may have open at once; further ones get 429
+    #[arg(long, value_name = "N")]
+    pub proxy_max_streams_per_key: Option<u32>,
+    /// Remove all generation limits
+    #[arg(long, conflicts_with_all = [
+        "proxy_max_tokens",
+        "proxy_max_request_seconds",
+        "proxy_max_streams_per_key",
+    ])]
+    pub no_proxy_limits: bool,
+}
+
+/// `--url-fetch*` flags of `config settings set`.
+#[derive(Debug, Args)]
+pub struct UrlFetchArgs {
+    /// Enable the built-in fetch_url tool (keeps current limits)
+    #[arg(long)]
+    pub url_fetch: bool,
+    /// Page text kept per fetch, in estimated tokens (1-32000, default 4000)
+    #[arg(long)]
+    pub url_fetch_max_tokens: Option<u32>,
+    /// Pages the model may fetch in one conversation (1-100, default 10)
+    #[arg(long)]
+    pub url_fetch_max_per_conversation: Option<u32>,
+    /// Turn the fetch_url tool off
+    #[arg(long, conflicts_with_all = [
+        "url_fetch",
+        "url_fetch_max_tokens",
+        "url_fetch_max_per_conversation",
+    ])]
+    pub no_url_fetch: bool,
+}
+
+/// `--moderation*` flags of `config settings set`, part of [`ProxyArgs`].
+#[derive(Debug, Args)]
+pub struct ModerationArgs {
+    /// JSON file with the moderation rules and classifier, replacing the
+    /// stored configuration (format in the proxy README)
+    #[arg(long, value_name = "PATH")]
+    pub moderation_file: Option<String>,
+    /// Turn moderation off
+    #[arg(long, conflicts_with = "moderation_file")]
+    pub no_moderation: bool,
+}
+
+/// `--otlp-*` flags of `config settings set`.
+#[derive(Debug, Args)]
+pub struct OtlpArgs {
+    /// OTLP/HTTP collector to export traces to (e.g. http://localhost:4318)
+    #[arg(long)]
+    pub otlp_endpoint: Option<String>,
+    /// Service name reported with exported traces (default: gglib)
+    #[arg(long)]
+    pub otlp_service_name: Option<String>,
+    /// Fraction of traces to export (0.0-1.0, default 1.0)
+    #[arg(long)]
+    pub otlp_sample_ratio: Option<f64>,
+    /// Turn OTLP trace export off
+    #[arg(long, conflicts_with_all = ["otlp_endpoint", "otlp_service_name", "otlp_sample_ratio"])]
+    pub no_otlp: bool,
+    #[command(flatten)]
+    pub energy: EnergyArgs,
+}
+
+/// `--energy-*` flags of `config settings set`, part of [`OtlpArgs`].
+#[derive(Debug, Args)]
+pub struct EnergyArgs {
+    /// Machine power draw while generating, in watts, for energy estimates
+    /// (default: a rough figure for the detected GPU or CPU)
+    #[arg(long)]
+    pub energy_watts: Option<f64>,
+    /// Electricity price per kWh; usage stats show costs when set
+    #[arg(long)]
+    pub energy_price_per_kwh: Option<f64>,
+    /// Currency label shown with costs (e.g. EUR)
+    #[arg(long)]
+    pub energy_currency: Option<String>,
+    /// Forget the configured draw and price
+    #[arg(long, conflicts_with_all = [
+        "energy_watts",
+        "energy_price_per_kwh",
+        "energy_currency",
+    ])]
+    pub no_energy: bool,
+}
+
+/// `--hf-token` flags of `config settings set`.
+#[derive(Debug, Args)]
+pub struct HfTokenArgs {
+    /// HuggingFace token, kept in the OS keyring (or encrypted file).
+    /// The HF_TOKEN environment variable still takes precedence.
+    #[arg(long, value_name = "TOKEN")]
+    pub hf_token: Option<String>,
+    /// Delete the stored HuggingFace token
+    #[arg(long, conflicts_with = "hf_token")]
+    pub no_hf_token: bool,
+}
+
 /// Settings command variants.
 #[derive(Subcommand)]
 pub enum SettingsCommand {
@@ -151,12 +350,20 @@ pub enum SettingsCommand {
         /// Port for the OpenAI-compatible proxy server (>= 1024)
         #[arg(long)]
         proxy_port: Option<u16>,
+        #[command(flatten)]
+        proxy: Box<ProxyArgs>,
         /// Base port for llama-server instances (>= 1024)
         #[arg(long)]
         llama_base_port: Option<u16>,
         /// Maximum number of downloads that can be queued (1-50)
         #[arg(long)]
         max_download_queue_size: Option<u32>,
+        /// Hours an orphaned partial download is kept before it is deleted
+        /// (default 24; applies from the next start)
+        #[arg(long)]
+        partial_download_grace_hours: Option<u32>,
+        #[command(flatten)]
+        trash: Box<TrashArgs>,
         /// Default download path for models
         #[arg(long)]
         default_download_path: Option<String>,
@@ -169,6 +376,38 @@ pub enum SettingsCommand {
         /// Show memory fit indicators in HuggingFace browser
         #[arg(long)]
         show_memory_fit_indicators: Option<bool>,
+        /// Warn before downloading models whose license forbids commercial
+        /// use or limits them to research
+        #[arg(long)]
+        warn_restrictive_licenses: Option<bool>,
+        /// Let the model title new chats after the first exchange
+        #[arg(long)]
+        auto_title_conversations: Option<bool>,
+        /// Encrypt chat message content at rest, keyed from the OS keyring.
+        /// Existing messages are encrypted (or decrypted) straight away.
+        #[arg(long)]
+        encrypt_chat_history: Option<bool>,
+        /// Largest chat attachment accepted, in MiB (1-512)
+        #[arg(long)]
+        max_attachment_size_mb: Option<u32>,
+        /// Comma-separated MIME types accepted for chat attachments (e.g. image/*,text/plain)
+        #[arg(long, value_delimiter = ',')]
+        allowed_attachment_types: Option<Vec<String>>,
+        /// Stop servers after this many minutes without a request (0 = never)
+        #[arg(long)]
+        idle_shutdown_minutes: Option<u32>,
+        /// Language for CLI messages and number formatting (e.g. de-DE); overrides
+        /// GGLIB_LOCALE and LANG
+        #[arg(long)]
+        locale: Option<String>,
+        #[command(flatten)]
+        otlp: Box<OtlpArgs>,
+        #[command(flatten)]
+        hf_token: Box<HfTokenArgs>,
+        #[command(flatten)]
+        web_search: Box<WebSearchArgs>,
+        #[command(flatten)]
+        url_fetch: Box<UrlFetchArgs>,
     },
     /// Reset all settings to defaults
     Reset {
@@ -16,6 +16,7 @@ use gglib_core::server_config::{ServerConfigOptions, resolve_context_size};
 use crate::bootstrap::CliContext;
 use crate::commands::Commands;
 use crate::handlers;
+use crate::presentation::OutputFormat;
 
 /// Route a parsed command to its handler.
 ///
@@ -25,11 +26,21 @@ use crate::handlers;
 /// * `command` — The command to execute, as parsed by Clap.
 /// * `verbose` — Value of the global `--verbose` flag; forwarded only to
 ///   handlers that expose a verbosity knob.
-pub async fn dispatch(ctx: &CliContext, command: Commands, verbose: bool) -> Result<()> {
+/// * `output`  — Value of the global `--output` flag; forwarded only to
+///   list-style handlers that can render JSON.
+pub async fn dispatch(
+    ctx: &CliContext,
+    command: Commands,
+    verbose: bool,
+    output: OutputFormat,
+) -> Result<()> {
     match command {
         // ── Grouped: model management ───────────────────────────────────────
         Commands::Model { command } => {
-            handlers::model::dispatch(ctx, command).await?;
+            handlers::model::dispatch(ctx, command, output).await?;
+        }
+        Commands::Trash { command } => {
+            handlers::trash::dispatch(ctx, command, output).await?;
         }
 
         // ── Grouped: configuration & system ─────────────────────────────────
@@ -37,6 +48,43 @@ pub async fn dispatch(ctx: &CliContext, command: Commands, verbose: bool) -> Res
             handlers::config::dispatch(ctx, command).await?;
         }
 
+        Commands::Downloads { command } => match command {
+            crate::commands::DownloadsCommand::Quarantine { command } => {
+                handlers::downloads_quarantine::execute(command, output)?;
+            }
+            crate::commands::DownloadsCommand::History {
+                days,
+                result,
+                search,
+                limit,
+            } => {
+                let filter = handlers::downloads_history::filter(days, result, search, limit);
+                handlers::downloads_history::execute(ctx, &filter, output).await?;
+            }
+        },
+
+        Commands::Jobs { port, command } => {
+            handlers::jobs::execute(port, command, output).await?;
+        }
+
+        Commands::Sync { command } => {
+            handlers::sync::execute(ctx, command, output).await?;
+        }
+
+        Commands::Backup { command } => {
+            handlers::backup::execute(ctx, command, output).await?;
+        }
+
+        Commands::Recommend { task, limit } => {
+            let probe = gglib_runtime::DefaultSystemProbe::new();
+            handlers::recommend::execute(&probe, task, limit, output)?;
+        }
+
+        Commands::Doctor { fix, yes } => {
+            let probe = gglib_runtime::DefaultSystemProbe::new();
+            handlers::doctor::execute(&probe, fix, yes, output).await?;
+        }
+
         // ── Inference (top-level for ergonomic access) ──────────────────────
         Commands::Serve {
             id,
@@ -44,9 +92,14 @@ pub async fn dispatch(ctx: &CliContext, command: Commands, verbose: bool) -> Res
             options,
             sampling,
             mtp,
+            rope,
+            cpu,
+            gpu,
         } => {
-            handlers::inference::serve::execute(ctx, id, context, options, sampling, mtp, verbose)
-                .await?;
+            handlers::inference::serve::execute(
+                ctx, id, context, options, sampling, mtp, rope, cpu, gpu, verbose,
+            )
+            .await?;
         }
         Commands::Chat {
             identifier,
@@ -61,6 +114,7 @@ pub async fn dispatch(ctx: &CliContext, command: Commands, verbose: bool) -> Res
             max_parallel,
             model,
             continue_id,
+            incognito,
             observation_tools,
             max_observation_steps,
             command,
@@ -69,7 +123,10 @@ pub async fn dispatch(ctx: &CliContext, command: Commands, verbose: bool) -> Res
             if let Some(sub) = command {
                 match sub {
                     crate::commands::ChatCommand::History { limit } => {
-                        handlers::history::execute(ctx, limit).await?;
+                        handlers::history::execute(ctx, limit, output).await?;
+                    }
+                    crate::commands::ChatCommand::Import { format, file } => {
+                        handlers::chat_import::execute(ctx, format.into(), &file, output).await?;
                     }
                 }
             } else {
@@ -87,6 +144,7 @@ pub async fn dispatch(ctx: &CliContext, command: Commands, verbose: bool) -> Res
                     verbose, // global flag forwarded here
                     model,
                     continue_id,
+                    incognito,
                     observation_tools,
                     max_observation_steps,
                 };
@@ -139,6 +197,29 @@ pub async fn dispatch(ctx: &CliContext, command: Commands, verbose: bool) -> Res
             .await?;
         }
 
+        Commands::Ask {
+            model,
+            prompt,
+            system_prompt,
+            port,
+            context,
+            sampling,
+            json,
+            raw,
+        } => {
+            let args = handlers::inference::ask::AskArgs {
+                model,
+                prompt,
+                system_prompt,
+                port,
+                context,
+                sampling,
+                json,
+                raw,
+            };
+            handlers::inference::ask::execute(ctx, args).await?;
+        }
+
         // ── GUI / web interfaces ────────────────────────────────────────────
         Commands::Plan {
             goal,
@@ -183,7 +264,7 @@ pub async fn dispatch(ctx: &CliContext, command: Commands, verbose: bool) -> Res
                     .await?;
                 }
                 CouncilCmd::List { status } => {
-                    handlers::council::list::execute(ctx, status.as_deref()).await?;
+                    handlers::council::list::execute(ctx, status.as_deref(), output).await?;
                 }
                 CouncilCmd::Show { run_id } => {
                     handlers::council::show::execute(ctx, &run_id).await?;
@@ -239,19 +320,72 @@ pub async fn dispatch(ctx: &CliContext, command: Commands, verbose: bool) -> Res
             }
         }
 
+        Commands::Pipeline { cmd } => {
+            use crate::commands::PipelineCmd;
+            match cmd {
+                PipelineCmd::Run {
+                    file,
+                    inputs,
+                    port,
+                    json,
+                    context,
+                } => {
+                    handlers::pipeline::run::execute(
+                        ctx,
+                        &file,
+                        &inputs,
+                        port,
+                        context.ctx_size,
+                        json,
+                    )
+                    .await?;
+                }
+                PipelineCmd::List => {
+                    handlers::pipeline::list::execute(ctx, output).await?;
+                }
+                PipelineCmd::Show { run_id } => {
+                    handlers::pipeline::show::execute(ctx, run_id, output).await?;
+                }
+            }
+        }
+
         Commands::Gui { dev } => {
             handlers::gui::execute(dev)?;
         }
+        Commands::Daemon { command } => {
+            handlers::daemon::execute(command)?;
+        }
         Commands::Web {
             port,
             base_port,
             api_only,
             static_dir,
+            spa_fallback,
+            share_token,
+            rate_limit,
+            heavy_rate_limit,
+            rate_limit_keys,
         } => {
-            handlers::web::execute(port, base_port, api_only, static_dir).await?;
+            handlers::web::execute(
+                port,
+                base_port,
+                api_only,
+                static_dir,
+                spa_fallback,
+                share_token,
+                handlers::web::rate_limit_config(rate_limit, heavy_rate_limit, rate_limit_keys),
+            )
+            .await?;
+        }
+        Commands::Tui => {
+            handlers::tui::execute(ctx).await?;
         }
         Commands::Proxy {
             host,
+            allow_public,
+            api_key,
+            background_api_keys,
+            allow_origins,
             port,
             llama_port,
             default_context,
@@ -293,6 +427,12 @@ pub async fn dispatch(ctx: &CliContext, command: Commands, verbose: bool) -> Res
                         )
                         .await?;
                     }
+                    crate::commands::ProxyCommand::Moderation {
+                        host: log_host,
+                        port: log_port,
+                    } => {
+                        handlers::proxy_moderation::execute(&log_host, log_port).await?;
+                    }
                 }
                 return Ok(());
             }
@@ -325,8 +465,15 @@ pub async fn dispatch(ctx: &CliContext, command: Commands, verbose: bool) -> Res
             } else {
                 None
             };
+            let mut access = gglib_runtime::proxy::AccessPolicy::from_settings(&settings, api_key)
+                .with_background_keys(background_api_keys);
+            if !allow_origins.is_empty() {
+                access.allowed_origins = Some(allow_origins);
+            }
             gglib_runtime::proxy::start_proxy_standalone(
-                host,
+                host.unwrap_or_else(|| settings.effective_proxy_host().to_string()),
+                allow_public || settings.proxy_allow_public == Some(true),
+                access,
                 port,
                 llama_port,
                 ctx.llama_server_path.clone(),
@@ -334,6 +481,7 @@ pub async fn dispatch(ctx: &CliContext, command: Commands, verbose: bool) -> Res
                 effective_context,
                 ctx.mcp.clone(),
                 ctx.app.settings().repo(),
+                ctx.app.presets().repo(),
                 inference_override,
                 cache,
                 slot_dir,
@@ -351,11 +499,42 @@ pub async fn dispatch(ctx: &CliContext, command: Commands, verbose: bool) -> Res
             handlers::mcp_cli::dispatch(ctx, command).await?;
         }
 
+        // ── WASM tool plugins ───────────────────────────────────────────────
+        Commands::Plugins { command } => {
+            handlers::plugins::dispatch(command, output)?;
+        }
+
         // ── Benchmarking ────────────────────────────────────────────────────
         Commands::Benchmark { command } => {
             handlers::benchmark::dispatch(ctx, command).await?;
         }
 
+        // ── Usage statistics ────────────────────────────────────────────────
+        Commands::Stats { command } => {
+            handlers::stats::dispatch(ctx, command, output).await?;
+        }
+
+        // ── Audit log ───────────────────────────────────────────────────────
+        Commands::Audit {
+            action,
+            actor,
+            days,
+            limit,
+        } => {
+            let args = handlers::audit::AuditArgs {
+                action,
+                actor,
+                days,
+                limit,
+            };
+            handlers::audit::execute(ctx, args, output).await?;
+        }
+
+        // ── Chat template checks ────────────────────────────────────────────
+        Commands::Template { command } => {
+            handlers::template::dispatch(ctx, command).await?;
+        }
+
         // ── Shell completions ───────────────────────────────────────────────
         Commands::Completions { shell } => {
             handlers::completions::execute(shell)?;
@@ -4,6 +4,7 @@
 //! from CoreError to exit codes and user-facing messages.
 
 use gglib_core::CoreError;
+use gglib_core::download::DownloadError;
 use thiserror::Error;
 
 /// CLI-specific error type.
@@ -32,6 +33,15 @@ pub enum CliError {
     /// Process execution error.
     #[error("Process error: {0}")]
     Process(String),
+
+    /// Download failure, carrying the exit code for its failure class.
+    #[error("{message}")]
+    Download {
+        /// Error message.
+        message: String,
+        /// Exit code for the failure class (see [`download_exit_code`]).
+        code: i32,
+    },
 }
 
 impl CliError {
@@ -50,10 +60,65 @@ impl CliError {
             CliError::Config(_) => 78,   // EX_CONFIG
             CliError::Database(_) => 73, // EX_CANTCREAT (closest fit)
             CliError::Process(_) => 71,  // EX_OSERR
+            CliError::Download { code, .. } => *code,
+        }
+    }
+
+    /// Build a [`CliError::Download`] from a failure recorded by the download
+    /// queue.
+    ///
+    /// The queue keeps runtime failures as the `Display` text of the
+    /// [`DownloadError`] that caused them, so the class is recovered from the
+    /// message prefix; unrecognised messages get the general exit code 1.
+    pub fn from_download_failure(message: &str) -> Self {
+        const PREFIXES: &[(&str, i32)] = &[
+            ("Not found:", 66),
+            ("Invalid quantization:", 66),
+            ("Resolution failed:", 66),
+            ("Network error:", 69),
+            ("I/O error", 74),
+            ("Integrity check failed:", 65),
+            ("Validation failed:", 65),
+            ("Download interrupted", 75),
+            ("Download cancelled", 130),
+        ];
+        let code = PREFIXES
+            .iter()
+            .find(|(prefix, _)| message.starts_with(prefix))
+            .map_or(1, |(_, code)| *code);
+        CliError::Download {
+            message: message.to_string(),
+            code,
         }
     }
 }
 
+/// Exit code for a [`DownloadError`], one per failure class.
+///
+/// - 66 `EX_NOINPUT`: the repo, file or quantization does not exist
+/// - 69 `EX_UNAVAILABLE`: network or HTTP failure
+/// - 74 `EX_IOERR`: local file operation failed
+/// - 65 `EX_DATAERR`: checksum mismatch or failed validation (quarantined)
+/// - 75 `EX_TEMPFAIL`: interrupted or queue full; retrying may succeed
+/// - 130: cancelled
+/// - 1: anything else
+pub const fn download_exit_code(err: &DownloadError) -> i32 {
+    match err {
+        DownloadError::NotFound { .. }
+        | DownloadError::InvalidQuantization { .. }
+        | DownloadError::ResolutionFailed { .. } => 66,
+        DownloadError::Network { .. } => 69,
+        DownloadError::Io { .. } => 74,
+        DownloadError::IntegrityFailed { .. } | DownloadError::Quarantined { .. } => 65,
+        DownloadError::Interrupted { .. } | DownloadError::QueueFull { .. } => 75,
+        DownloadError::Cancelled => 130,
+        DownloadError::AlreadyQueued { .. }
+        | DownloadError::NotInQueue { .. }
+        | DownloadError::InvalidDestination { .. }
+        | DownloadError::Other { .. } => 1,
+    }
+}
+
 impl From<CoreError> for CliError {
     fn from(err: CoreError) -> Self {
         match err {
@@ -73,3 +138,55 @@ impl From<std::io::Error> for CliError {
         CliError::Io(err.to_string())
     }
 }
+
+impl From<DownloadError> for CliError {
+    fn from(err: DownloadError) -> Self {
+        CliError::Download {
+            code: download_exit_code(&err),
+            message: err.to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A runtime failure recorded as the error's `Display` text must exit
+    /// with the same code as the typed error would.
+    #[test]
+    fn failure_messages_map_to_the_same_code_as_their_error() {
+        let errors = [
+            DownloadError::not_found("owner/repo"),
+            DownloadError::invalid_quantization("Q9"),
+            DownloadError::resolution_failed("no GGUF files"),
+            DownloadError::network_with_status("forbidden", 403),
+            DownloadError::io("PermissionDenied", "denied"),
+            DownloadError::integrity_failed("abc", "def"),
+            DownloadError::quarantined("bad magic", "/tmp/q"),
+            DownloadError::Interrupted {
+                bytes_downloaded: 10,
+            },
+            DownloadError::Cancelled,
+            DownloadError::other("boom"),
+        ];
+        for err in errors {
+            let from_message = CliError::from_download_failure(&err.to_string());
+            assert_eq!(from_message.exit_code(), download_exit_code(&err), "{err}");
+        }
+    }
+
+    #[test]
+    fn download_failure_classes_have_distinct_codes() {
+        assert_eq!(
+            CliError::from(DownloadError::not_found("x")).exit_code(),
+            66
+        );
+        assert_eq!(CliError::from(DownloadError::network("x")).exit_code(), 69);
+        assert_eq!(CliError::from(DownloadError::Cancelled).exit_code(), 130);
+        assert_eq!(
+            CliError::from_download_failure("Registration failed: x").exit_code(),
+            1
+        );
+    }
+}
@@ -15,6 +15,7 @@ use gglib_core::domain::InferenceConfig;
 use gglib_core::ports::AgentLoopPort;
 use gglib_core::request_pipeline;
 use gglib_core::server_config::parse_ctx_size_flag;
+use gglib_mcp::UrlFetcher;
 use gglib_runtime::compose_agent_loop_with_sampling;
 use gglib_runtime::server_config::{ServerConfigOptions, build_server_config};
 
@@ -138,6 +139,8 @@ pub async fn compose(
     let base_url = format!("http://127.0.0.1:{port}");
     let model_context =
         request_pipeline::resolve(ctx.catalog.as_ref(), Some(&params.model_identifier)).await;
+    // Web tools are opt-in; an unreadable settings row just leaves them off.
+    let settings = ctx.app.settings().get_resolved().await.unwrap_or_default();
     let agent = compose_agent_loop_with_sampling(
         base_url,
         ctx.http_client.clone(),
@@ -149,22 +152,23 @@ pub async fn compose(
         resolved_sampling,
         // No proxy dashboard in the CLI process — nowhere to report reuse.
         None,
+        settings.web_search,
+        settings.url_fetch.map(UrlFetcher::new),
     );
 
     Ok((agent, maybe_handle))
 }
 
-// =============================================================================
-// Private helpers
-// =============================================================================
-
 /// Return `(port, maybe_handle)`.
 ///
 /// When a port is supplied the server is treated as externally managed
 /// and no `ProcessHandle` is returned.  Otherwise a llama-server is spawned
 /// via [`CliContext::runner`] and the resulting handle is returned so the
 /// caller can stop it on exit.
-async fn resolve_port(
+///
+/// Also used directly by `gglib ask`, which streams a plain completion and
+/// needs the server but not an agent loop.
+pub async fn resolve_port(
     ctx: &CliContext,
     params: &AgentSessionParams,
     banner: &BannerInfo,
@@ -241,6 +245,10 @@ async fn resolve_port(
     Ok((handle.port, Some(handle)))
 }
 
+// =============================================================================
+// Private helpers
+// =============================================================================
+
 /// Print non-default sampling parameter lines in the info banner.
 fn print_sampling_lines(s: &InferenceConfig) {
     if let Some(v) = s.temperature {
@@ -8,17 +8,25 @@ pub mod repl;
 mod thinking_dispatch;
 mod tool_format;
 
+use std::sync::Arc;
+
 use anyhow::{Result, bail};
+use async_trait::async_trait;
+use tokio::sync::Mutex;
 
+use gglib_core::ProcessHandle;
+use gglib_core::domain::InferenceConfig;
 use gglib_core::domain::agent::AgentMessage;
 use gglib_core::domain::chat::ConversationSettings;
+use gglib_core::ports::AgentLoopPort;
 
 use crate::bootstrap::CliContext;
 use crate::handlers::inference::chat::ChatArgs;
 use crate::presentation::style;
 use crate::shared_args::ConversationSettingsBuilder;
 
-use self::persistence::Conversation;
+use self::persistence::{Conversation, bind_library_model};
+use self::repl::ModelSwitcher;
 
 /// Entry point: start the interactive agentic REPL.
 ///
@@ -68,35 +76,91 @@ pub async fn run(ctx: &CliContext, args: &ChatArgs) -> Result<()> {
         },
     };
     let params = config::AgentSessionParams::from(&args);
-    let (agent, maybe_handle) = config::compose(ctx, &params, None, sampling, &banner).await?;
+    let (agent, maybe_handle) =
+        config::compose(ctx, &params, None, sampling.clone(), &banner).await?;
 
-    let result = repl::run_repl_with_prior(agent, &args, persistence, prior_messages).await;
+    let models = SessionModels {
+        ctx,
+        sampling,
+        state: Mutex::new((params, maybe_handle)),
+    };
+    let result =
+        repl::run_repl_with_prior(agent, &args, persistence, prior_messages, Some(&models)).await;
 
-    if let Some(ref handle) = maybe_handle
-        && let Err(e) = ctx.runner.stop(handle).await
-    {
-        tracing::warn!("failed to stop llama-server after agent chat: {e}");
+    models.stop_server().await;
+    if args.incognito {
+        ctx.app.chat_history().discard_incognito();
     }
-
     result
 }
 
+/// Backs `/model` for `gglib chat`: restarts llama-server for the new model,
+/// or re-targets the request model name when the user supplied `--port`.
+struct SessionModels<'a> {
+    ctx: &'a CliContext,
+    sampling: Option<InferenceConfig>,
+    /// Current session parameters and the server this session started, if any.
+    state: Mutex<(config::AgentSessionParams, Option<ProcessHandle>)>,
+}
+
+impl SessionModels<'_> {
+    /// Stop the llama-server this session auto-started, if any.
+    async fn stop_server(&self) {
+        if let Some(handle) = self.state.lock().await.1.take()
+            && let Err(e) = self.ctx.runner.stop(&handle).await
+        {
+            tracing::warn!("failed to stop llama-server after agent chat: {e}");
+        }
+    }
+}
+
+#[async_trait]
+impl ModelSwitcher for SessionModels<'_> {
+    async fn current(&self) -> String {
+        self.state.lock().await.0.model_identifier.clone()
+    }
+
+    async fn switch(&self, identifier: &str) -> Result<Arc<dyn AgentLoopPort>> {
+        let mut params = self.state.lock().await.0.clone();
+        params.model_identifier = identifier.to_string();
+        if params.port.is_some() {
+            // Externally managed server: only the routed model name changes.
+            params.model_name = Some(identifier.to_string());
+        }
+        let banner = config::BannerInfo {
+            quiet: false,
+            sampling: self.sampling.clone(),
+            prior_history_chars: None,
+        };
+
+        // Start the new model before stopping the old one, so a failed
+        // switch leaves the session on the model it already had.
+        let (agent, handle) =
+            config::compose(self.ctx, &params, None, self.sampling.clone(), &banner).await?;
+        self.stop_server().await;
+        *self.state.lock().await = (params, handle);
+        Ok(agent)
+    }
+}
+
 /// Create a new conversation for a fresh session.
 async fn new_conversation<'a>(
     ctx: &'a CliContext,
     args: &ChatArgs,
 ) -> (Option<Conversation<'a>>, Vec<AgentMessage>) {
-    let settings = ConversationSettingsBuilder::new(&args.sampling, &args.context)
+    let mut settings = ConversationSettingsBuilder::new(&args.sampling, &args.context)
         .model_name(&args.identifier)
         .tools(args.tools.clone(), args.no_tools)
         .agent_params(args.max_iterations, args.tool_timeout_ms, args.max_parallel)
         .build();
+    let model_id = bind_library_model(ctx.app.models(), &args.identifier, &mut settings).await;
 
     let persistence = match Conversation::create(
         ctx.app.chat_history(),
         args.system_prompt.clone(),
-        None,
+        model_id,
         Some(settings),
+        args.incognito,
     )
     .await
     {
@@ -140,6 +204,18 @@ async fn resume_conversation<'a>(
         print_memory_jogger(&db_messages, &conv.title);
     }
 
+    if let Ok(Some(warning)) = ctx.app.models().binding_warning(&conv).await {
+        println!("{}⚠ {}{}", style::WARNING, warning.message, style::RESET);
+        if let Some(suggestion) = &warning.suggestion
+            && args.identifier.is_empty()
+        {
+            println!(
+                "  Resume with it: gglib chat {} --continue {conv_id}",
+                suggestion.model_name
+            );
+        }
+    }
+
     // Merge saved settings into a copy of the current args.
     let merged = apply_saved_settings(args, &conv.system_prompt, &conv.settings);
 
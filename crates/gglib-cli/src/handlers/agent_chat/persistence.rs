@@ -8,10 +8,29 @@ use chrono::Local;
 
 use gglib_core::domain::agent::AgentMessage;
 use gglib_core::domain::chat::{ConversationSettings, MessageRole, NewConversation, NewMessage};
-use gglib_core::services::ChatHistoryService;
+use gglib_core::services::{ChatHistoryService, ModelService};
+
+/// Record the library model behind `identifier` in `settings`.
+///
+/// Returns the model's ID for the conversation row. An identifier that is
+/// not a library model (e.g. a name routed to an external `--port` server)
+/// leaves `settings` as it is.
+pub async fn bind_library_model(
+    models: &ModelService,
+    identifier: &str,
+    settings: &mut ConversationSettings,
+) -> Option<i64> {
+    let model = models.get(identifier).await.ok().flatten()?;
+    settings.bind_model(&model);
+    Some(model.id)
+}
 
 /// Tracks a persisted conversation and the number of messages already saved,
 /// so subsequent calls to [`Conversation::save_new`] only write the delta.
+///
+/// The count covers non-system messages only, matching the rows in
+/// `chat_messages`, so inserting or replacing the system prompt mid-session
+/// (`/system`) never shifts the delta.
 pub struct Conversation<'a> {
     service: &'a ChatHistoryService,
     pub id: i64,
@@ -20,11 +39,15 @@ pub struct Conversation<'a> {
 
 impl<'a> Conversation<'a> {
     /// Create a new conversation with a timestamp-based title.
+    ///
+    /// An `incognito` conversation is held in memory by the service and
+    /// never reaches the database.
     pub async fn create(
         service: &'a ChatHistoryService,
         system_prompt: Option<String>,
         model_id: Option<i64>,
         settings: Option<ConversationSettings>,
+        incognito: bool,
     ) -> Result<Conversation<'a>> {
         let title = format!("Agent session {}", Local::now().format("%Y-%m-%d %H:%M"));
         let id = service
@@ -33,6 +56,7 @@ impl<'a> Conversation<'a> {
                 model_id,
                 system_prompt,
                 settings,
+                incognito,
             })
             .await?;
         Ok(Conversation {
@@ -42,6 +66,11 @@ impl<'a> Conversation<'a> {
         })
     }
 
+    /// Whether this conversation is held in memory only.
+    pub const fn is_incognito(&self) -> bool {
+        ChatHistoryService::is_incognito(self.id)
+    }
+
     /// Resume an existing conversation for continued persistence.
     ///
     /// Loads the existing message count so [`save_new`] only persists the delta.
@@ -67,16 +96,48 @@ impl<'a> Conversation<'a> {
     /// Errors are logged as warnings and swallowed — persistence must never
     /// break the interactive session.
     pub async fn save_new(&mut self, messages: &[AgentMessage]) {
-        for msg in messages.iter().skip(self.saved) {
-            if matches!(msg, AgentMessage::System { .. }) {
-                continue;
-            }
+        let persisted = messages
+            .iter()
+            .filter(|msg| !matches!(msg, AgentMessage::System { .. }));
+        for msg in persisted.skip(self.saved) {
             let new_msg = to_new_message(msg, self.id);
             if let Err(e) = self.service.save_message(new_msg).await {
                 tracing::warn!("failed to persist agent message: {e}");
             }
+            self.saved += 1;
         }
-        self.saved = messages.len();
+    }
+
+    /// Store a new system prompt on the conversation row.
+    pub async fn set_system_prompt(&self, prompt: &str) -> Result<()> {
+        self.service
+            .update_conversation(self.id, None, Some(Some(prompt.to_string())))
+            .await?;
+        Ok(())
+    }
+
+    /// Rename the conversation.
+    pub async fn set_title(&self, title: &str) -> Result<()> {
+        self.service
+            .update_conversation(self.id, Some(title.to_string()), None)
+            .await?;
+        Ok(())
+    }
+
+    /// Record `model` in the saved session settings so `--continue` resumes
+    /// with it.
+    pub async fn set_model(&self, model: &str) -> Result<()> {
+        let mut settings = self
+            .service
+            .get_conversation(self.id)
+            .await?
+            .and_then(|c| c.settings)
+            .unwrap_or_default();
+        settings.model_name = Some(model.to_string());
+        self.service
+            .update_conversation_settings(self.id, Some(settings))
+            .await?;
+        Ok(())
     }
 }
 
@@ -24,10 +24,18 @@
 //! [`ReadlineError::Interrupted`]; the REPL prints a hint and continues to
 //! the next prompt instead of exiting (keeping behaviour consistent with the
 //! help text — `/quit` or Ctrl+D are the intended exit paths).
+//!
+//! ## Slash commands
+//!
+//! `/system` and `/save` only touch the message list and the persisted
+//! conversation.  `/model` needs a new agent loop (and usually a new
+//! llama-server), which the REPL cannot build itself; callers that support
+//! switching pass a [`ModelSwitcher`].
 
 use std::sync::{Arc, Mutex};
 
 use anyhow::Result;
+use async_trait::async_trait;
 use rustyline::DefaultEditor;
 use rustyline::error::ReadlineError;
 use tokio::sync::mpsc;
@@ -47,12 +55,58 @@ use super::persistence::Conversation;
 // =============================================================================
 
 const REPL_HELP: &str = "\
-  /help     print this message
-  /quit     exit the session
-  /exit     exit the session
+  /help            print this message
+  /model [name]    show the current model, or switch to another one
+  /system [text]   show the system prompt, or replace it
+  /save [title]    save the conversation now, optionally renaming it
+  /quit            exit the session
+  /exit            exit the session
   Ctrl+C    cancel the current agent response (return to prompt)
   Ctrl+D    exit the session (EOF)";
 
+// =============================================================================
+// Slash commands
+// =============================================================================
+
+/// A `/`-prefixed REPL command.
+#[derive(Debug, PartialEq, Eq)]
+enum SlashCommand<'a> {
+    Help,
+    Quit,
+    Model(Option<&'a str>),
+    System(Option<&'a str>),
+    Save(Option<&'a str>),
+    Unknown(&'a str),
+}
+
+/// Parse `input` as a slash command; `None` for a normal chat message.
+fn parse_command(input: &str) -> Option<SlashCommand<'_>> {
+    let rest = input.strip_prefix('/')?;
+    let (name, arg) = match rest.split_once(char::is_whitespace) {
+        Some((name, arg)) => (name, Some(arg.trim()).filter(|a| !a.is_empty())),
+        None => (rest, None),
+    };
+    Some(match name {
+        "help" => SlashCommand::Help,
+        "quit" | "exit" => SlashCommand::Quit,
+        "model" => SlashCommand::Model(arg),
+        "system" => SlashCommand::System(arg),
+        "save" => SlashCommand::Save(arg),
+        _ => SlashCommand::Unknown(name),
+    })
+}
+
+/// Swaps the model behind a running session, backing `/model`.
+#[async_trait]
+pub trait ModelSwitcher: Send + Sync {
+    /// Name or ID of the model currently in use.
+    async fn current(&self) -> String;
+
+    /// Compose an agent loop for `identifier`, releasing the previous
+    /// model's server.
+    async fn switch(&self, identifier: &str) -> Result<Arc<dyn AgentLoopPort>>;
+}
+
 // =============================================================================
 // Public entry point
 // =============================================================================
@@ -66,8 +120,9 @@ pub async fn run_repl(
     agent_loop: Arc<dyn AgentLoopPort>,
     args: &ChatArgs,
     persistence: Option<Conversation<'_>>,
+    switcher: Option<&dyn ModelSwitcher>,
 ) -> Result<()> {
-    run_repl_with_prior(agent_loop, args, persistence, Vec::new()).await
+    run_repl_with_prior(agent_loop, args, persistence, Vec::new(), switcher).await
 }
 
 /// Run the interactive agent REPL with optional prior messages from a resumed
@@ -81,6 +136,7 @@ pub async fn run_repl_with_prior(
     args: &ChatArgs,
     persistence: Option<Conversation<'_>>,
     prior_messages: Vec<AgentMessage>,
+    switcher: Option<&dyn ModelSwitcher>,
 ) -> Result<()> {
     let config = AgentConfig::from_user_params(
         Some(
@@ -108,7 +164,15 @@ pub async fn run_repl_with_prior(
         prior_messages
     };
 
-    run_repl_with_history(agent_loop, messages, config, args.verbose, persistence).await
+    run_repl_with_history(
+        agent_loop,
+        messages,
+        config,
+        args.verbose,
+        persistence,
+        switcher,
+    )
+    .await
 }
 
 /// Run the interactive agent REPL with a pre-populated conversation history.
@@ -118,12 +182,16 @@ pub async fn run_repl_with_prior(
 /// also called directly by `gglib q --agent` to transition from a single-turn
 /// question into an interactive session, carrying the full conversation
 /// history forward.
+///
+/// Without a `switcher`, `/model` only reports that switching is
+/// unavailable in this session.
 pub async fn run_repl_with_history(
-    agent_loop: Arc<dyn AgentLoopPort>,
+    mut agent_loop: Arc<dyn AgentLoopPort>,
     mut messages: Vec<AgentMessage>,
     config: AgentConfig,
     verbose: bool,
     mut persistence: Option<Conversation<'_>>,
+    switcher: Option<&dyn ModelSwitcher>,
 ) -> Result<()> {
     // Wrap the editor in Arc<Mutex> so it can be moved into spawn_blocking
     // on each turn while retaining readline history across turns.
@@ -168,14 +236,29 @@ pub async fn run_repl_with_history(
 
         let input = input.trim().to_owned();
 
-        match input.as_str() {
-            "" => continue,
-            "/quit" | "/exit" => break,
-            "/help" => {
-                println!("{REPL_HELP}");
-                continue;
+        if input.is_empty() {
+            continue;
+        }
+        if let Some(command) = parse_command(&input) {
+            match command {
+                SlashCommand::Quit => break,
+                SlashCommand::Help => println!("{REPL_HELP}"),
+                SlashCommand::Model(name) => {
+                    if let Some(agent) = switch_model(switcher, name, persistence.as_ref()).await {
+                        agent_loop = agent;
+                    }
+                }
+                SlashCommand::System(prompt) => {
+                    set_system_prompt(&mut messages, prompt, persistence.as_ref()).await;
+                }
+                SlashCommand::Save(title) => {
+                    save_conversation(&messages, title, persistence.as_mut()).await;
+                }
+                SlashCommand::Unknown(name) => {
+                    println!("Unknown command /{name} — type /help for the list.");
+                }
             }
-            _ => {}
+            continue;
         }
 
         messages.push(AgentMessage::User { content: input });
@@ -198,7 +281,15 @@ pub async fn run_repl_with_history(
         }
     }
 
-    if let Some(ref conv) = persistence {
+    if let Some(ref conv) = persistence
+        && conv.is_incognito()
+    {
+        println!(
+            "{}Incognito session ended; nothing was saved.{}",
+            crate::presentation::style::DIM,
+            crate::presentation::style::RESET,
+        );
+    } else if let Some(ref conv) = persistence {
         println!(
             "{}Session #{} saved. Resume with: gglib chat --continue {}{}",
             crate::presentation::style::DIM,
@@ -215,6 +306,99 @@ pub async fn run_repl_with_history(
 // Private helpers
 // =============================================================================
 
+/// Handle `/model [name]`; returns the new agent loop on a successful switch.
+async fn switch_model(
+    switcher: Option<&dyn ModelSwitcher>,
+    name: Option<&str>,
+    persistence: Option<&Conversation<'_>>,
+) -> Option<Arc<dyn AgentLoopPort>> {
+    let Some(switcher) = switcher else {
+        println!("Switching models is not available in this session.");
+        return None;
+    };
+    let Some(name) = name else {
+        println!("Model: {}", switcher.current().await);
+        return None;
+    };
+    match switcher.switch(name).await {
+        Ok(agent) => {
+            println!("Switched to {name}; the conversation carries over.");
+            if let Some(conv) = persistence
+                && let Err(e) = conv.set_model(name).await
+            {
+                tracing::warn!("failed to record model switch: {e}");
+            }
+            Some(agent)
+        }
+        Err(e) => {
+            eprintln!("Failed to switch to {name}: {e:#}");
+            None
+        }
+    }
+}
+
+/// Handle `/system [text]`: print the current prompt, or replace it (the
+/// system message always sits first in the history).
+async fn set_system_prompt(
+    messages: &mut Vec<AgentMessage>,
+    prompt: Option<&str>,
+    persistence: Option<&Conversation<'_>>,
+) {
+    let current = match messages.first_mut() {
+        Some(AgentMessage::System { content }) => Some(content),
+        _ => None,
+    };
+    let Some(prompt) = prompt else {
+        match current {
+            Some(content) => println!("System prompt: {content}"),
+            None => println!("No system prompt set."),
+        }
+        return;
+    };
+    match current {
+        Some(content) => *content = prompt.to_string(),
+        None => messages.insert(
+            0,
+            AgentMessage::System {
+                content: prompt.to_string(),
+            },
+        ),
+    }
+    if let Some(conv) = persistence
+        && let Err(e) = conv.set_system_prompt(prompt).await
+    {
+        tracing::warn!("failed to persist system prompt: {e}");
+    }
+    println!("System prompt updated.");
+}
+
+/// Handle `/save [title]`: flush unsaved messages and optionally rename.
+async fn save_conversation(
+    messages: &[AgentMessage],
+    title: Option<&str>,
+    persistence: Option<&mut Conversation<'_>>,
+) {
+    let Some(conv) = persistence else {
+        println!("Conversation history is unavailable in this session.");
+        return;
+    };
+    if conv.is_incognito() {
+        println!("Incognito session — nothing is saved to history.");
+        return;
+    }
+    conv.save_new(messages).await;
+    if let Some(title) = title
+        && let Err(e) = conv.set_title(title).await
+    {
+        eprintln!("Failed to rename session #{}: {e}", conv.id);
+        return;
+    }
+    println!(
+        "Saved session #{} — resume with: gglib chat --continue {}",
+        conv.id, conv.id
+    );
+}
+
 /// Run one agent turn: spawn the loop task, consume events, handle Ctrl+C,
 /// and return the updated conversation history.
 ///
@@ -270,3 +454,29 @@ async fn run_single_turn(
     }
     pre_turn
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn plain_text_is_not_a_command() {
+        assert_eq!(parse_command("hello /model"), None);
+    }
+
+    #[test]
+    fn commands_parse_with_and_without_arguments() {
+        assert_eq!(parse_command("/exit"), Some(SlashCommand::Quit));
+        assert_eq!(parse_command("/model"), Some(SlashCommand::Model(None)));
+        assert_eq!(
+            parse_command("/model qwen3-8b"),
+            Some(SlashCommand::Model(Some("qwen3-8b")))
+        );
+        assert_eq!(
+            parse_command("/system  Be terse.  "),
+            Some(SlashCommand::System(Some("Be terse.")))
+        );
+        assert_eq!(parse_command("/save "), Some(SlashCommand::Save(None)));
+        assert_eq!(parse_command("/nope"), Some(SlashCommand::Unknown("nope")));
+    }
+}
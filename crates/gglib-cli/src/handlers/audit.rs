@@ -0,0 +1,71 @@
+//! CLI handler for `gglib audit` — the administrative audit trail.
+//!
+//! Entries are written by the web server when an API client removes a
+//! model, changes settings, starts or stops a server or edits the download
+//! queue; see [`gglib_core::domain::audit`]. This command only reads them.
+
+use anyhow::{Result, anyhow};
+use chrono::{Duration, Local, Utc};
+
+use gglib_core::domain::audit::{AuditAction, AuditFilter};
+
+use crate::bootstrap::CliContext;
+use crate::presentation::style;
+use crate::presentation::{OutputFormat, print_json, print_separator, truncate_string};
+
+/// Arguments of `gglib audit`.
+pub struct AuditArgs {
+    pub action: Option<AuditAction>,
+    pub actor: Option<String>,
+    pub days: Option<u32>,
+    pub limit: u32,
+}
+
+/// List audit entries, newest first.
+pub async fn execute(ctx: &CliContext, args: AuditArgs, output: OutputFormat) -> Result<()> {
+    let audit = ctx
+        .app
+        .audit()
+        .ok_or_else(|| anyhow!("The audit log is not available"))?;
+    let filter = AuditFilter {
+        action: args.action,
+        actor: args.actor,
+        since: args
+            .days
+            .map(|days| Utc::now() - Duration::days(i64::from(days))),
+        limit: Some(args.limit),
+    };
+    let entries = audit.list(&filter).await?;
+    if output.is_json() {
+        return print_json(&entries);
+    }
+    if entries.is_empty() {
+        println!("No audit entries.");
+        return Ok(());
+    }
+
+    println!(
+        "{:<19} {:<28} {:<26} {:<10} Details",
+        "When", "Actor", "Action", "Target"
+    );
+    print_separator(110);
+    for entry in &entries {
+        println!(
+            "{:<19} {:<28} {:<26} {:<10} {}",
+            entry.at.with_timezone(&Local).format("%Y-%m-%d %H:%M:%S"),
+            truncate_string(&entry.actor, 28),
+            entry.action,
+            truncate_string(entry.target.as_deref().unwrap_or("-"), 10),
+            entry.summary.as_deref().unwrap_or(""),
+        );
+    }
+    if entries.len() == args.limit as usize {
+        println!(
+            "\n{}Showing the newest {}; use --limit for more.{}",
+            style::MUTED,
+            args.limit,
+            style::RESET
+        );
+    }
+    Ok(())
+}
@@ -0,0 +1,211 @@
+//! `gglib backup` — back up to and restore from an S3-compatible bucket.
+//!
+//! Delegates to [`BackupOps`], the same operations the web API exposes.
+//! Transfer progress arrives as `BackupProgress` events, which
+//! [`BackupProgressBars`] renders as one progress bar per object.
+
+use std::sync::{Arc, Mutex};
+
+use anyhow::Result;
+use gglib_app_services::types::{BackupPushRequest, BackupRestoreRequest};
+use gglib_app_services::{BackupDeps, BackupOps};
+use gglib_core::domain::BackupConfig;
+use gglib_core::events::AppEvent;
+use gglib_core::ports::AppEventEmitter;
+use gglib_core::settings::SettingsUpdate;
+use indicatif::{ProgressBar, ProgressStyle};
+
+use crate::bootstrap::CliContext;
+use crate::commands::BackupCommand;
+use crate::presentation::{
+    OutputFormat, format_bytes, print_json, print_separator, truncate_string,
+};
+
+/// Execute a `gglib backup` subcommand.
+///
+/// With `--output json`, `list`, `push` and `restore` print their results
+/// as JSON and draw no progress bars.
+pub async fn execute(ctx: &CliContext, command: BackupCommand, output: OutputFormat) -> Result<()> {
+    let emitter: Arc<dyn AppEventEmitter> = if output.is_json() {
+        Arc::new(gglib_core::ports::NoopEmitter::new())
+    } else {
+        Arc::new(BackupProgressBars::default())
+    };
+    let ops = BackupOps::new(BackupDeps {
+        core: Arc::clone(&ctx.app),
+        snapshot: ctx.db_snapshot.clone(),
+        emitter,
+    });
+
+    match command {
+        BackupCommand::Configure {
+            bucket,
+            endpoint,
+            region,
+            prefix,
+            access_key_id,
+            secret_access_key,
+            path_style,
+            include_models,
+        } => {
+            let config = BackupConfig {
+                bucket,
+                endpoint,
+                region,
+                prefix,
+                access_key_id,
+                secret_access_key,
+                path_style,
+                include_models,
+            };
+            ctx.app
+                .settings()
+                .update(SettingsUpdate {
+                    backup: Some(Some(config.clone())),
+                    ..Default::default()
+                })
+                .await?;
+            println!(
+                "Backups go to s3://{}/{}/",
+                config.bucket,
+                config.effective_prefix()
+            );
+            if !gglib_backup::S3_SUPPORTED {
+                println!(
+                    "Note: this build has no S3 support; rebuild with `--features s3-backup` \
+                     to push or restore."
+                );
+            }
+        }
+        BackupCommand::Disable => {
+            ctx.app
+                .settings()
+                .update(SettingsUpdate {
+                    backup: Some(None),
+                    ..Default::default()
+                })
+                .await?;
+            println!("Backups disabled. Objects already in the bucket were left in place.");
+        }
+        BackupCommand::Push { models, no_models } => {
+            let include_models = if models {
+                Some(true)
+            } else if no_models {
+                Some(false)
+            } else {
+                None
+            };
+            let result = ops.push(BackupPushRequest { include_models }).await?;
+            if output.is_json() {
+                return print_json(&result);
+            }
+            println!("Database backed up as {}.", result.database);
+            if !result.models_uploaded.is_empty() || result.models_unchanged > 0 {
+                println!(
+                    "{} model file(s) uploaded, {} already up to date.",
+                    result.models_uploaded.len(),
+                    result.models_unchanged
+                );
+            }
+        }
+        BackupCommand::List => {
+            let listing = ops.list().await?;
+            if output.is_json() {
+                return print_json(&listing);
+            }
+            println!("Databases:");
+            if listing.databases.is_empty() {
+                println!("  (none — create one with: gglib backup push)");
+            }
+            for db in &listing.databases {
+                println!("  {:<30} {:>10}", db.name, format_bytes(db.size));
+            }
+            println!();
+            println!("{:<60} {:>10}", "Model file", "Size");
+            print_separator(71);
+            for model in &listing.models {
+                println!(
+                    "{:<60} {:>10}",
+                    truncate_string(&model.name, 59),
+                    format_bytes(model.size)
+                );
+            }
+        }
+        BackupCommand::Restore {
+            database,
+            models,
+            all_models,
+        } => {
+            let result = ops
+                .restore(BackupRestoreRequest {
+                    database,
+                    models,
+                    all_models,
+                })
+                .await?;
+            if output.is_json() {
+                return print_json(&result);
+            }
+            for model in &result.models {
+                println!("Restored {model}");
+            }
+            if let Some(database) = &result.database {
+                println!(
+                    "Database {database} staged; it replaces the current database the next \
+                     time gglib starts."
+                );
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Renders `BackupProgress` events as one progress bar per object.
+#[derive(Clone, Default)]
+struct BackupProgressBars {
+    active: Arc<Mutex<Option<(String, ProgressBar)>>>,
+}
+
+impl AppEventEmitter for BackupProgressBars {
+    fn emit(&self, event: AppEvent) {
+        let AppEvent::BackupProgress {
+            key,
+            transferred,
+            total,
+            ..
+        } = event
+        else {
+            return;
+        };
+        let Ok(mut active) = self.active.lock() else {
+            return;
+        };
+
+        if active.as_ref().is_none_or(|(current, _)| *current != key) {
+            if let Some((_, bar)) = active.take() {
+                bar.finish();
+            }
+            let bar = ProgressBar::new(total);
+            bar.set_style(
+                ProgressStyle::default_bar()
+                    .template("[{bar:30.cyan/blue}] {bytes}/{total_bytes} {msg}")
+                    .expect("valid bar template")
+                    .progress_chars("#>-"),
+            );
+            bar.set_message(key.rsplit('/').next().unwrap_or(&key).to_string());
+            *active = Some((key, bar));
+        }
+        if let Some((_, bar)) = active.as_ref() {
+            bar.set_length(total);
+            bar.set_position(transferred);
+            if transferred >= total {
+                bar.finish();
+            }
+        }
+    }
+
+    fn clone_box(&self) -> Box<dyn AppEventEmitter> {
+        Box::new(self.clone())
+    }
+}
@@ -0,0 +1,85 @@
+//! CLI handler for `gglib chat import`.
+//!
+//! Reads the `conversations.json` of a ChatGPT or Claude data export,
+//! parses it with [`gglib_core::domain::chat_import`] and stores every
+//! conversation in chat history with its original timestamps.
+
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use gglib_core::domain::chat_import::{ChatExportFormat, parse_export};
+use serde::Serialize;
+
+use crate::bootstrap::CliContext;
+use crate::presentation::style;
+use crate::presentation::{OutputFormat, print_json};
+
+/// File holding the conversations in both apps' exports.
+const CONVERSATIONS_FILE: &str = "conversations.json";
+
+/// JSON row for `--output json`: one imported conversation.
+#[derive(Serialize)]
+struct ImportedRow<'a> {
+    id: i64,
+    title: &'a str,
+    message_count: usize,
+}
+
+/// Execute the chat import command.
+///
+/// `file` may be the unzipped export folder, in which case its
+/// `conversations.json` is read.
+pub async fn execute(
+    ctx: &CliContext,
+    format: ChatExportFormat,
+    file: &Path,
+    output: OutputFormat,
+) -> Result<()> {
+    let path = if file.is_dir() {
+        file.join(CONVERSATIONS_FILE)
+    } else {
+        file.to_path_buf()
+    };
+    let json = std::fs::read_to_string(&path)
+        .with_context(|| format!("Failed to read {}", path.display()))?;
+    let conversations = parse_export(format, &json)
+        .with_context(|| format!("Failed to parse {} as a {format} export", path.display()))?;
+
+    let ids = ctx
+        .app
+        .chat_history()
+        .import_conversations(&conversations)
+        .await?;
+
+    if output.is_json() {
+        let rows: Vec<ImportedRow> = ids
+            .iter()
+            .zip(&conversations)
+            .map(|(&id, conversation)| ImportedRow {
+                id,
+                title: &conversation.title,
+                message_count: conversation.messages.len(),
+            })
+            .collect();
+        return print_json(&rows);
+    }
+
+    if conversations.is_empty() {
+        println!(
+            "No conversations with messages found in {}.",
+            path.display()
+        );
+        return Ok(());
+    }
+    let messages: usize = conversations.iter().map(|c| c.messages.len()).sum();
+    println!(
+        "{}✓{} Imported {} conversation(s) with {messages} message(s) from {}",
+        style::SUCCESS,
+        style::RESET,
+        conversations.len(),
+        path.display()
+    );
+    println!("List them with: gglib chat history");
+
+    Ok(())
+}
@@ -14,7 +14,7 @@ use super::llama_install;
 /// Dispatch a `llama` sub-command to the appropriate `gglib_runtime` handler.
 pub async fn dispatch(command: LlamaCommand) -> Result<()> {
     use gglib_runtime::llama::{
-        handle_check_updates, handle_status, handle_uninstall, handle_update,
+        LlamaSource, handle_check_updates, handle_status, handle_uninstall, handle_update,
     };
 
     match command {
@@ -24,8 +24,12 @@ pub async fn dispatch(command: LlamaCommand) -> Result<()> {
             vulkan,
             force,
             build,
+            repo,
+            git_ref,
         } => {
-            llama_install::handle_install(cuda, metal, vulkan, force, build).await?;
+            let source =
+                (repo.is_some() || git_ref.is_some()).then(|| LlamaSource::new(repo, git_ref));
+            llama_install::handle_install(cuda, metal, vulkan, force, build, source).await?;
         }
         LlamaCommand::CheckUpdates => {
             handle_check_updates().await?;
@@ -41,7 +45,7 @@ pub async fn dispatch(command: LlamaCommand) -> Result<()> {
             metal,
             vulkan,
         } => {
-            llama_install::handle_install(cuda, metal, vulkan, true, true).await?;
+            llama_install::handle_install(cuda, metal, vulkan, true, true, None).await?;
         }
         LlamaCommand::Uninstall { force } => {
             handle_uninstall(force).await?;
@@ -1,19 +1,23 @@
 //! llama.cpp source-build installation — CLI surface adapter.
 //!
 //! Wraps [`run_llama_source_build`] with CLI concerns: dependency checks,
-//! the interactive Y/n prompt, and `indicatif` progress rendering.
+//! the interactive Y/n prompt, and `indicatif` progress rendering (or plain
+//! status lines, see [`gglib_core::progress`]).
 //! Surface-agnostic build logic lives in `gglib-runtime::llama`.
 
 use anyhow::{Result, bail};
 use indicatif::{ProgressBar, ProgressStyle};
 use std::io::{self, Write};
-use std::time::Duration;
+use std::time::{Duration, Instant};
 use tokio::sync::mpsc;
 
+use gglib_core::download::format_duration;
+use gglib_core::progress::{StatusThrottle, percent};
+
 use gglib_core::paths::{gglib_data_dir, is_prebuilt_binary, llama_cpp_dir, llama_server_path};
 use gglib_runtime::llama::{
-    Acceleration, BuildEvent, BuildPhase, PrebuiltAvailability, check_dependencies,
-    check_disk_space, check_prebuilt_availability, detect_optimal_acceleration,
+    Acceleration, BuildEvent, BuildPhase, CtrlCCancel, LlamaSource, PrebuiltAvailability,
+    check_dependencies, check_disk_space, check_prebuilt_availability, detect_optimal_acceleration,
     download_prebuilt_binaries, run_llama_source_build, vulkan_status,
 };
 
@@ -24,16 +28,20 @@ fn path_err<T>(r: Result<T, gglib_core::paths::PathError>) -> Result<T> {
 /// Handle the install command.
 ///
 /// Installation method is determined by context:
-/// - `--build` flag: Always build from source
+/// - `--build` flag, or an explicit `source` (`--repo` / `--ref`): Always build from source
 /// - Running from source repo: Build from source (existing behavior)
 /// - Pre-built binary + macOS/Windows: Download pre-built binaries
 /// - Pre-built binary + Linux: Build from source (CUDA requires compilation)
+///
+/// Source builds use `source` when given, otherwise the repository and ref
+/// recorded by the previous install (upstream on a first install).
 pub async fn handle_install(
     cuda: bool,
     metal: bool,
     vulkan: bool,
     force: bool,
     build_from_source: bool,
+    source: Option<LlamaSource>,
 ) -> Result<()> {
     // Check if already installed
     let server_path = path_err(llama_server_path())?;
@@ -49,6 +57,7 @@ pub async fn handle_install(
 
     // Determine installation method
     let should_build = build_from_source
+        || source.is_some()
         || !is_prebuilt_binary() // Running from source repo
         || cuda
         || metal
@@ -73,14 +82,21 @@ pub async fn handle_install(
     }
 
     // Build from source
-    build_from_source_impl(cuda, metal, vulkan, force).await
+    let source = source.unwrap_or_else(LlamaSource::recorded);
+    build_from_source_impl(cuda, metal, vulkan, force, source).await
 }
 
 /// CLI-only wrapper for the source-build pipeline.
 ///
 /// Performs dependency checks and the interactive Y/n prompt (CLI concerns), then
 /// delegates the actual build work to [`run_llama_source_build`].
-async fn build_from_source_impl(cuda: bool, metal: bool, vulkan: bool, force: bool) -> Result<()> {
+async fn build_from_source_impl(
+    cuda: bool,
+    metal: bool,
+    vulkan: bool,
+    force: bool,
+    source: LlamaSource,
+) -> Result<()> {
     // Step 1: Check dependencies.
     check_dependencies()?;
     println!();
@@ -161,7 +177,7 @@ async fn build_from_source_impl(cuda: bool, metal: bool, vulkan: bool, force: bo
 
     // Step 3: Interactive pre-flight prompt.
     if !force {
-        print_preflight_info(&acceleration)?;
+        print_preflight_info(&acceleration, &source)?;
         print!("Continue? [Y/n]: ");
         io::stdout().flush()?;
         let mut input = String::new();
@@ -176,13 +192,21 @@ async fn build_from_source_impl(cuda: bool, metal: bool, vulkan: bool, force: bo
     let llama_dir = path_err(llama_cpp_dir())?;
     let server_path = path_err(llama_server_path())?;
     let (tx, rx) = mpsc::channel::<BuildEvent>(64);
+    // Ctrl-C stops the build and the compilers it started.
+    let ctrl_c = CtrlCCancel::install();
     let build = tokio::spawn(run_llama_source_build(
         acceleration,
+        source,
         llama_dir,
         server_path,
         tx,
+        ctrl_c.token(),
     ));
-    consume_build_events_cli(rx).await;
+    if gglib_core::progress::is_plain() {
+        consume_build_events_plain(rx).await;
+    } else {
+        consume_build_events_cli(rx).await;
+    }
     build.await??;
 
     Ok(())
@@ -218,7 +242,7 @@ fn determine_acceleration(cuda: bool, metal: bool, vulkan: bool) -> Result<Accel
     }
 }
 
-fn print_preflight_info(acceleration: &Acceleration) -> Result<()> {
+fn print_preflight_info(acceleration: &Acceleration, source: &LlamaSource) -> Result<()> {
     println!("Pre-flight check:");
     println!("✓ Build dependencies installed");
 
@@ -230,7 +254,11 @@ fn print_preflight_info(acceleration: &Acceleration) -> Result<()> {
     println!("✓ Detected: {}", acceleration.display_name());
     println!();
     println!("This will:");
-    println!("  1. Clone llama.cpp repository (~150 MB)");
+    if source.is_upstream() {
+        println!("  1. Clone llama.cpp repository (~150 MB)");
+    } else {
+        println!("  1. Clone llama.cpp from {} (~150 MB)", source);
+    }
     println!(
         "  2. Configure with CMake ({} enabled)",
         acceleration.display_name()
@@ -284,12 +312,7 @@ async fn consume_build_events_cli(mut rx: mpsc::Receiver<BuildEvent>) {
                         continue;
                     }
                     _ => {
-                        let msg = match phase {
-                            BuildPhase::CloneOrUpdateRepo => "Cloning llama.cpp repository...",
-                            BuildPhase::Configure => "Configuring with CMake...",
-                            BuildPhase::InstallBinaries => "Installing binaries...",
-                            _ => unreachable!(),
-                        };
+                        let msg = phase_message(phase);
                         let pb = ProgressBar::new_spinner();
                         pb.set_style(spinner_style.clone());
                         pb.set_message(msg);
@@ -304,10 +327,21 @@ async fn consume_build_events_cli(mut rx: mpsc::Receiver<BuildEvent>) {
                     pb.finish_and_clear();
                 }
             }
-            BuildEvent::Progress { current, total } => {
-                if let Some(pb) = &active {
+            BuildEvent::Progress {
+                phase,
+                current,
+                total,
+                target,
+            } => {
+                // The configure estimate would only make the spinner jump.
+                if phase == BuildPhase::Compile
+                    && let Some(pb) = &active
+                {
                     pb.set_length(total);
                     pb.set_position(current);
+                    if let Some(target) = target {
+                        pb.set_message(format!("Compiling {target}"));
+                    }
                 }
             }
             BuildEvent::Log { message } => {
@@ -317,25 +351,95 @@ async fn consume_build_events_cli(mut rx: mpsc::Receiver<BuildEvent>) {
                     println!("{}", message);
                 }
             }
-            BuildEvent::Completed {
-                version,
-                acceleration,
-            } => {
+            event @ (BuildEvent::Completed { .. }
+            | BuildEvent::Failed { .. }
+            | BuildEvent::Cancelled) => {
                 if let Some(pb) = active.take() {
                     pb.finish_and_clear();
                 }
-                println!();
-                println!("✓ llama.cpp installed successfully!");
-                println!("  Version:       {}", version);
-                println!("  Acceleration:  {}", acceleration);
-                println!("You can now use 'gglib serve', 'gglib proxy', and 'gglib chat'.");
+                print_build_outcome(&event);
             }
-            BuildEvent::Failed { message } => {
-                if let Some(pb) = active.take() {
-                    pb.finish_and_clear();
+        }
+    }
+}
+
+/// Plain-mode counterpart of [`consume_build_events_cli`]: one line per
+/// phase, a compile status line every few seconds, and a summary.
+async fn consume_build_events_plain(mut rx: mpsc::Receiver<BuildEvent>) {
+    let started = Instant::now();
+    let mut phase_started = Instant::now();
+    let mut throttle = StatusThrottle::default();
+
+    while let Some(event) = rx.recv().await {
+        match event {
+            // The dependency check prints its own output before the channel opens.
+            BuildEvent::PhaseStarted {
+                phase: BuildPhase::DependencyCheck,
+            } => {}
+            BuildEvent::PhaseStarted { phase } => {
+                phase_started = Instant::now();
+                throttle = StatusThrottle::default();
+                println!("{}", phase_message(phase));
+            }
+            BuildEvent::PhaseCompleted { phase } => {
+                if phase != BuildPhase::DependencyCheck {
+                    let elapsed = phase_started.elapsed().as_secs_f64();
+                    println!("  done in {}", format_duration(Some(elapsed)));
+                }
+            }
+            BuildEvent::Progress {
+                phase: BuildPhase::Compile,
+                current,
+                total,
+                target,
+            } => {
+                if throttle.ready(Instant::now()) {
+                    let pct = percent(current, total).unwrap_or_default();
+                    let target = target.map(|t| format!(" ({t})")).unwrap_or_default();
+                    println!("  Compiling: {current}/{total} ({pct:.0}%){target}");
                 }
-                eprintln!("✗ Build failed: {}", message);
             }
+            BuildEvent::Progress { .. } => {}
+            BuildEvent::Log { message } => println!("{message}"),
+            event @ (BuildEvent::Completed { .. }
+            | BuildEvent::Failed { .. }
+            | BuildEvent::Cancelled) => {
+                print_build_outcome(&event);
+                if matches!(event, BuildEvent::Completed { .. }) {
+                    let elapsed = started.elapsed().as_secs_f64();
+                    println!("Total build time: {}", format_duration(Some(elapsed)));
+                }
+            }
+        }
+    }
+}
+
+/// What the user is told when `phase` starts.
+fn phase_message(phase: BuildPhase) -> &'static str {
+    match phase {
+        BuildPhase::CloneOrUpdateRepo => "Cloning llama.cpp repository...",
+        BuildPhase::Configure => "Configuring with CMake...",
+        BuildPhase::InstallBinaries => "Installing binaries...",
+        BuildPhase::Compile => "Compiling...",
+        BuildPhase::DependencyCheck => "Checking dependencies...",
+    }
+}
+
+/// Print the result of a finished build; other events are ignored.
+fn print_build_outcome(event: &BuildEvent) {
+    match event {
+        BuildEvent::Completed {
+            version,
+            acceleration,
+        } => {
+            println!();
+            println!("✓ llama.cpp installed successfully!");
+            println!("  Version:       {}", version);
+            println!("  Acceleration:  {}", acceleration);
+            println!("You can now use 'gglib serve', 'gglib proxy', and 'gglib chat'.");
         }
+        BuildEvent::Failed { message } => eprintln!("✗ Build failed: {}", message),
+        BuildEvent::Cancelled => eprintln!("✗ Build cancelled"),
+        _ => {}
     }
 }
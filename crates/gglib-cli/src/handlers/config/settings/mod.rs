@@ -4,15 +4,24 @@ mod settings_display;
 
 use std::collections::BTreeSet;
 
-use anyhow::Result;
+use anyhow::{Context, Result};
 
 use crate::bootstrap::CliContext;
-use crate::config_commands::{ModelsDirCommand, SettingsCommand};
+use crate::config_commands::{
+    EnergyArgs, ModelsDirCommand, ModerationArgs, OtlpArgs, ProxyArgs, ProxyLimitArgs,
+    SettingsCommand, TrashArgs, UrlFetchArgs, WebSearchArgs, WebSearchProviderArg,
+};
+use crate::tr;
 use crate::utils::input::prompt_string_with_default;
+use gglib_core::domain::{
+    EnergyConfig, ModerationConfig, ProxyLimits, UrlFetchConfig, WebSearchConfig, WebSearchProvider,
+};
 use gglib_core::paths::{
     DirectoryCreationStrategy, default_models_dir, ensure_directory, persist_models_dir,
     resolve_models_dir,
 };
+use gglib_core::ports::HF_TOKEN_SECRET;
+use gglib_core::telemetry::OtlpConfig;
 use gglib_core::{Settings, SettingsUpdate, validate_settings};
 
 use settings_display::{
@@ -34,6 +43,192 @@ async fn resolve_model_display(ctx: &CliContext, settings: &Settings) -> Result<
     }
 }
 
+/// Overlay the `--otlp-*` flags on the stored export settings.
+///
+/// Flags that are not passed keep their stored value; an endpoint is
+/// required when export is not configured yet.
+fn merge_otlp(
+    current: Option<OtlpConfig>,
+    endpoint: Option<String>,
+    service_name: Option<String>,
+    sample_ratio: Option<f64>,
+) -> Result<OtlpConfig> {
+    let mut config = match (current, endpoint) {
+        (Some(mut config), Some(endpoint)) => {
+            config.endpoint = endpoint;
+            config
+        }
+        (Some(config), None) => config,
+        (None, Some(endpoint)) => OtlpConfig {
+            endpoint,
+            service_name: None,
+            sample_ratio: None,
+        },
+        (None, None) => anyhow::bail!("--otlp-endpoint is required to enable trace export"),
+    };
+    if service_name.is_some() {
+        config.service_name = service_name;
+    }
+    if sample_ratio.is_some() {
+        config.sample_ratio = sample_ratio;
+    }
+    Ok(config)
+}
+
+/// Whether any `--web-search-*` flag other than `--no-web-search` was passed.
+const fn has_web_search_flags(args: &WebSearchArgs) -> bool {
+    args.web_search_provider.is_some()
+        || args.web_search_url.is_some()
+        || args.web_search_api_key.is_some()
+        || args.web_search_max_results.is_some()
+        || args.web_search_allow_domains.is_some()
+        || args.web_search_deny_domains.is_some()
+}
+
+/// Overlay the `--web-search-*` flags on the stored web search settings.
+///
+/// Flags that are not passed keep their stored value. A provider is
+/// required when web search is not configured yet, and switching provider
+/// needs that provider's URL or API key unless the flag supplies it.
+fn merge_web_search(
+    current: Option<WebSearchConfig>,
+    args: WebSearchArgs,
+) -> Result<WebSearchConfig> {
+    let current_provider = current.as_ref().map(|c| c.provider.clone());
+    let provider = match (args.web_search_provider, current_provider) {
+        (Some(WebSearchProviderArg::Searxng), current) => {
+            let url = args.web_search_url.or(match current {
+                Some(WebSearchProvider::Searxng { url }) => Some(url),
+                _ => None,
+            });
+            let Some(url) = url else {
+                anyhow::bail!("--web-search-url is required for the searxng provider");
+            };
+            WebSearchProvider::Searxng { url }
+        }
+        (Some(WebSearchProviderArg::Brave), current) => {
+            let api_key = args.web_search_api_key.or(match current {
+                Some(WebSearchProvider::Brave { api_key }) => Some(api_key),
+                _ => None,
+            });
+            let Some(api_key) = api_key else {
+                anyhow::bail!("--web-search-api-key is required for the brave provider");
+            };
+            WebSearchProvider::Brave { api_key }
+        }
+        (Some(WebSearchProviderArg::Duckduckgo), _) => WebSearchProvider::DuckDuckGo,
+        (None, Some(WebSearchProvider::Searxng { url })) => WebSearchProvider::Searxng {
+            url: args.web_search_url.unwrap_or(url),
+        },
+        (None, Some(WebSearchProvider::Brave { api_key })) => WebSearchProvider::Brave {
+            api_key: args.web_search_api_key.unwrap_or(api_key),
+        },
+        (None, Some(WebSearchProvider::DuckDuckGo)) => WebSearchProvider::DuckDuckGo,
+        (None, None) => {
+            anyhow::bail!("--web-search-provider is required to enable web search")
+        }
+    };
+
+    let mut config = current.unwrap_or_else(|| WebSearchConfig::new(provider.clone()));
+    config.provider = provider;
+    if args.web_search_max_results.is_some() {
+        config.max_results = args.web_search_max_results;
+    }
+    if let Some(domains) = args.web_search_allow_domains {
+        config.allow_domains = domains;
+    }
+    if let Some(domains) = args.web_search_deny_domains {
+        config.deny_domains = domains;
+    }
+    Ok(config)
+}
+
+/// Overlay the `--url-fetch*` flags on the stored limits, or the defaults
+/// when fetching is not enabled yet. `None` when no flag enables it.
+fn merge_url_fetch(current: Option<UrlFetchConfig>, args: &UrlFetchArgs) -> Option<UrlFetchConfig> {
+    if !args.url_fetch
+        && args.url_fetch_max_tokens.is_none()
+        && args.url_fetch_max_per_conversation.is_none()
+    {
+        return None;
+    }
+    let mut config = current.unwrap_or_default();
+    if args.url_fetch_max_tokens.is_some() {
+        config.max_tokens = args.url_fetch_max_tokens;
+    }
+    if args.url_fetch_max_per_conversation.is_some() {
+        config.max_fetches_per_conversation = args.url_fetch_max_per_conversation;
+    }
+    Some(config)
+}
+
+/// Overlay the `--energy-*` flags on the stored energy settings. `None` when
+/// no flag was passed.
+fn merge_energy(current: Option<EnergyConfig>, args: EnergyArgs) -> Option<EnergyConfig> {
+    if args.energy_watts.is_none()
+        && args.energy_price_per_kwh.is_none()
+        && args.energy_currency.is_none()
+    {
+        return None;
+    }
+    let mut config = current.unwrap_or_default();
+    if args.energy_watts.is_some() {
+        config.watts = args.energy_watts;
+    }
+    if args.energy_price_per_kwh.is_some() {
+        config.price_per_kwh = args.energy_price_per_kwh;
+    }
+    if args.energy_currency.is_some() {
+        config.currency = args.energy_currency;
+    }
+    Some(config)
+}
+
+/// Overlay the `--proxy-max-*` flags on the stored limits; 0 removes a
+/// limit. `None` when no flag was passed, `Some(None)` when no limit is left.
+fn merge_proxy_limits(
+    current: Option<ProxyLimits>,
+    args: &ProxyLimitArgs,
+) -> Option<Option<ProxyLimits>> {
+    if args.no_proxy_limits {
+        return Some(None);
+    }
+    if args.proxy_max_tokens.is_none()
+        && args.proxy_max_request_seconds.is_none()
+        && args.proxy_max_streams_per_key.is_none()
+    {
+        return None;
+    }
+    let overlay = |current: Option<u32>, flag: Option<u32>| match flag {
+        Some(0) => None,
+        Some(v) => Some(v),
+        None => current,
+    };
+    let current = current.unwrap_or_default();
+    let limits = ProxyLimits {
+        max_tokens: overlay(current.max_tokens, args.proxy_max_tokens),
+        max_request_seconds: overlay(current.max_request_seconds, args.proxy_max_request_seconds),
+        max_streams_per_key: overlay(current.max_streams_per_key, args.proxy_max_streams_per_key),
+    };
+    Some((!limits.is_empty()).then_some(limits))
+}
+
+/// Read the `--moderation-file` JSON, or `Some(None)` for `--no-moderation`.
+/// `None` when neither flag was passed.
+fn read_moderation(args: &ModerationArgs) -> Result<Option<Option<ModerationConfig>>> {
+    if args.no_moderation {
+        return Ok(Some(None));
+    }
+    let Some(path) = &args.moderation_file else {
+        return Ok(None);
+    };
+    let json = std::fs::read_to_string(path)
+        .with_context(|| format!("failed to read moderation file {path}"))?;
+    let config = serde_json::from_str(&json)
+        .with_context(|| format!("{path} is not a valid moderation configuration"))?;
+    Ok(Some(Some(config)))
+}
+
 /// Handle the `config default` command for managing the default model.
 ///
 /// - No args: show current default
@@ -132,25 +327,55 @@ pub fn handle_models_dir(command: ModelsDirCommand) -> Result<()> {
     }
 }
 
+/// Store the `HuggingFace` token in the secret store, or delete it for `None`.
+fn set_hf_token(ctx: &CliContext, token: Option<&str>) -> Result<()> {
+    let Some(secrets) = ctx.app.settings().secrets() else {
+        anyhow::bail!("no secret store is configured");
+    };
+    match token {
+        Some(token) => {
+            secrets.set(HF_TOKEN_SECRET, token)?;
+            println!("✓ HuggingFace token stored ({})", secrets.backend());
+        }
+        None if secrets.delete(HF_TOKEN_SECRET)? => println!("✓ HuggingFace token removed"),
+        None => println!("No HuggingFace token was stored"),
+    }
+    Ok(())
+}
+
 pub async fn handle_settings(ctx: &CliContext, command: SettingsCommand) -> Result<()> {
     match command {
         SettingsCommand::Show => {
             let settings = ctx.app.settings().get().await?;
             let model_display = resolve_model_display(ctx, &settings).await?;
             let rows = settings_display_rows(&settings, model_display);
-            println!("Current application settings:");
+            println!("{}", tr!("settings-current"));
             print_sections(&settings_to_sections(&rows));
             Ok(())
         }
         SettingsCommand::Set {
             default_context_size,
             proxy_port,
+            proxy,
             llama_base_port,
             max_download_queue_size,
+            partial_download_grace_hours,
+            trash,
             default_download_path,
             max_tool_iterations,
             max_stagnation_steps,
             show_memory_fit_indicators,
+            warn_restrictive_licenses,
+            auto_title_conversations,
+            encrypt_chat_history,
+            max_attachment_size_mb,
+            allowed_attachment_types,
+            idle_shutdown_minutes,
+            locale,
+            otlp: otlp_args,
+            hf_token: hf_token_args,
+            web_search: web_search_args,
+            url_fetch: url_fetch_args,
         } => {
             // Collect the kebab-case keys of every flag that was provided.
             let mut changed: BTreeSet<&str> = BTreeSet::new();
@@ -163,12 +388,53 @@ pub async fn handle_settings(ctx: &CliContext, command: SettingsCommand) -> Resu
             if proxy_port.is_some() {
                 changed.insert("proxy-port");
             }
+            let ProxyArgs {
+                proxy_host,
+                proxy_allow_public,
+                proxy_allowed_origins,
+                proxy_preempt_background,
+                proxy_strip_reasoning,
+                moderation: moderation_args,
+                limits: limit_args,
+            } = *proxy;
+            if proxy_host.is_some() {
+                changed.insert("proxy-host");
+            }
+            if proxy_allow_public.is_some() {
+                changed.insert("proxy-allow-public");
+            }
+            if proxy_allowed_origins.is_some() {
+                changed.insert("proxy-allowed-origins");
+            }
+            if proxy_preempt_background.is_some() {
+                changed.insert("proxy-preempt-background");
+            }
+            if proxy_strip_reasoning.is_some() {
+                changed.insert("proxy-strip-reasoning");
+            }
             if llama_base_port.is_some() {
                 changed.insert("llama-base-port");
             }
             if max_download_queue_size.is_some() {
                 changed.insert("max-download-queue-size");
             }
+            if partial_download_grace_hours.is_some() {
+                changed.insert("partial-download-grace-hours");
+            }
+            let TrashArgs {
+                trash_retention_days,
+                trash_max_size_gb,
+                watch_models_dir,
+            } = *trash;
+            if trash_retention_days.is_some() {
+                changed.insert("trash-retention-days");
+            }
+            if trash_max_size_gb.is_some() {
+                changed.insert("trash-max-size-gb");
+            }
+            if watch_models_dir.is_some() {
+                changed.insert("watch-models-dir");
+            }
             if max_tool_iterations.is_some() {
                 changed.insert("max-tool-iterations");
             }
@@ -178,31 +444,153 @@ pub async fn handle_settings(ctx: &CliContext, command: SettingsCommand) -> Resu
             if show_memory_fit_indicators.is_some() {
                 changed.insert("show-memory-fit-indicators");
             }
+            if warn_restrictive_licenses.is_some() {
+                changed.insert("warn-restrictive-licenses");
+            }
+            if auto_title_conversations.is_some() {
+                changed.insert("auto-title-conversations");
+            }
+            if encrypt_chat_history.is_some() {
+                changed.insert("encrypt-chat-history");
+            }
+            if max_attachment_size_mb.is_some() {
+                changed.insert("max-attachment-size-mb");
+            }
+            if allowed_attachment_types.is_some() {
+                changed.insert("allowed-attachment-types");
+            }
+            if idle_shutdown_minutes.is_some() {
+                changed.insert("idle-shutdown-minutes");
+            }
+            if locale.is_some() {
+                changed.insert("locale");
+            }
+            let OtlpArgs {
+                otlp_endpoint,
+                otlp_service_name,
+                otlp_sample_ratio,
+                no_otlp,
+                energy: energy_args,
+            } = *otlp_args;
+            let otlp_flags = otlp_endpoint.is_some()
+                || otlp_service_name.is_some()
+                || otlp_sample_ratio.is_some();
+            if otlp_flags || no_otlp {
+                changed.insert("otlp");
+            }
+            let no_web_search = web_search_args.no_web_search;
+            let web_search_set = has_web_search_flags(&web_search_args);
+            if web_search_set || no_web_search {
+                changed.insert("web-search");
+            }
+            let mut prospective = ctx.app.settings().get().await?;
+            let url_fetch = if url_fetch_args.no_url_fetch {
+                Some(None)
+            } else {
+                merge_url_fetch(prospective.url_fetch, &url_fetch_args).map(Some)
+            };
+            if url_fetch.is_some() {
+                changed.insert("url-fetch");
+            }
+            let moderation = read_moderation(&moderation_args)?;
+            if moderation.is_some() {
+                changed.insert("moderation");
+            }
+            let proxy_limits = merge_proxy_limits(prospective.proxy_limits.clone(), &limit_args);
+            if proxy_limits.is_some() {
+                changed.insert("proxy-limits");
+            }
+            let energy = if energy_args.no_energy {
+                Some(None)
+            } else {
+                merge_energy(prospective.energy.clone(), energy_args).map(Some)
+            };
+            if energy.is_some() {
+                changed.insert("energy");
+            }
 
+            let hf_token = if hf_token_args.no_hf_token {
+                Some(None)
+            } else {
+                hf_token_args.hf_token.map(Some)
+            };
             if changed.is_empty() {
-                println!("No settings provided. Use --help to see available options.");
+                match hf_token {
+                    Some(token) => set_hf_token(ctx, token.as_deref())?,
+                    None => {
+                        println!("No settings provided. Use --help to see available options.");
+                    }
+                }
                 return Ok(());
             }
 
+            let otlp = if no_otlp {
+                Some(None)
+            } else if otlp_flags {
+                Some(Some(merge_otlp(
+                    prospective.otlp.clone(),
+                    otlp_endpoint,
+                    otlp_service_name,
+                    otlp_sample_ratio,
+                )?))
+            } else {
+                None
+            };
+            let web_search = if no_web_search {
+                Some(None)
+            } else if web_search_set {
+                Some(Some(merge_web_search(
+                    prospective.web_search.clone(),
+                    *web_search_args,
+                )?))
+            } else {
+                None
+            };
+
             let update = SettingsUpdate {
                 default_download_path: default_download_path.map(Some),
                 default_context_size: default_context_size.map(Some),
                 proxy_port: proxy_port.map(Some),
+                proxy_host: proxy_host.map(Some),
+                proxy_allow_public: proxy_allow_public.map(Some),
+                proxy_allowed_origins: proxy_allowed_origins.map(Some),
+                proxy_preempt_background: proxy_preempt_background.map(Some),
+                proxy_strip_reasoning: proxy_strip_reasoning.map(Some),
                 llama_base_port: llama_base_port.map(Some),
                 max_download_queue_size: max_download_queue_size.map(Some),
+                partial_download_grace_hours: partial_download_grace_hours.map(Some),
+                trash_retention_days: trash_retention_days.map(Some),
+                trash_max_size_gb: trash_max_size_gb.map(Some),
+                watch_models_dir: watch_models_dir.map(Some),
                 show_memory_fit_indicators: show_memory_fit_indicators.map(Some),
+                warn_restrictive_licenses: warn_restrictive_licenses.map(Some),
                 max_tool_iterations: max_tool_iterations.map(Some),
                 max_stagnation_steps: max_stagnation_steps.map(Some),
                 default_model_id: None,
+                idle_shutdown_minutes: idle_shutdown_minutes.map(Some),
+                startup_models: None,
+                cached_prompts: None,
+                otlp: otlp.clone(),
                 inference_defaults: None,
                 inference_profiles: None,
                 setup_completed: None,
+                onboarding: None,
                 title_generation_prompt: None,
+                auto_title_conversations: auto_title_conversations.map(Some),
+                encrypt_chat_history: encrypt_chat_history.map(Some),
+                max_attachment_size_mb: max_attachment_size_mb.map(Some),
+                allowed_attachment_types: allowed_attachment_types.map(Some),
+                locale: locale.map(Some),
+                web_search: web_search.clone(),
+                url_fetch,
+                moderation: moderation.clone(),
+                proxy_limits: proxy_limits.clone(),
+                energy: energy.clone(),
+                backup: None,
             };
 
             // Pre-validate: merge the prospective update into a local copy and validate
             // before persisting, so the user gets a clear error without a partial write.
-            let mut prospective = ctx.app.settings().get().await?;
             if let Some(Some(v)) = &update.default_download_path {
                 prospective.default_download_path = Some(v.clone());
             }
@@ -212,12 +600,39 @@ pub async fn handle_settings(ctx: &CliContext, command: SettingsCommand) -> Resu
             if let Some(Some(v)) = update.proxy_port {
                 prospective.proxy_port = Some(v);
             }
+            if let Some(Some(v)) = &update.proxy_host {
+                prospective.proxy_host = Some(v.clone());
+            }
+            if let Some(Some(v)) = update.proxy_allow_public {
+                prospective.proxy_allow_public = Some(v);
+            }
+            if let Some(Some(v)) = &update.proxy_allowed_origins {
+                prospective.proxy_allowed_origins = Some(v.clone());
+            }
+            if let Some(Some(v)) = update.proxy_preempt_background {
+                prospective.proxy_preempt_background = Some(v);
+            }
+            if let Some(Some(v)) = update.proxy_strip_reasoning {
+                prospective.proxy_strip_reasoning = Some(v);
+            }
             if let Some(Some(v)) = update.llama_base_port {
                 prospective.llama_base_port = Some(v);
             }
             if let Some(Some(v)) = update.max_download_queue_size {
                 prospective.max_download_queue_size = Some(v);
             }
+            if let Some(Some(v)) = update.partial_download_grace_hours {
+                prospective.partial_download_grace_hours = Some(v);
+            }
+            if let Some(Some(v)) = update.trash_retention_days {
+                prospective.trash_retention_days = Some(v);
+            }
+            if let Some(Some(v)) = update.trash_max_size_gb {
+                prospective.trash_max_size_gb = Some(v);
+            }
+            if let Some(Some(v)) = update.watch_models_dir {
+                prospective.watch_models_dir = Some(v);
+            }
             if let Some(Some(v)) = update.max_tool_iterations {
                 prospective.max_tool_iterations = Some(v);
             }
@@ -227,8 +642,48 @@ pub async fn handle_settings(ctx: &CliContext, command: SettingsCommand) -> Resu
             if let Some(Some(v)) = update.show_memory_fit_indicators {
                 prospective.show_memory_fit_indicators = Some(v);
             }
+            if let Some(Some(v)) = update.warn_restrictive_licenses {
+                prospective.warn_restrictive_licenses = Some(v);
+            }
+            if let Some(Some(v)) = update.idle_shutdown_minutes {
+                prospective.idle_shutdown_minutes = Some(v);
+            }
+            if let Some(Some(v)) = update.max_attachment_size_mb {
+                prospective.max_attachment_size_mb = Some(v);
+            }
+            if let Some(Some(v)) = &update.allowed_attachment_types {
+                prospective.allowed_attachment_types = Some(v.clone());
+            }
+            if let Some(Some(v)) = &update.locale {
+                prospective.locale = Some(v.clone());
+            }
+            if let Some(v) = otlp {
+                prospective.otlp = v;
+            }
+            if let Some(v) = web_search {
+                prospective.web_search = v;
+            }
+            if let Some(v) = url_fetch {
+                prospective.url_fetch = v;
+            }
+            if let Some(v) = moderation {
+                prospective.moderation = v;
+            }
+            if let Some(v) = proxy_limits {
+                prospective.proxy_limits = v;
+            }
+            if let Some(v) = energy {
+                prospective.energy = v;
+            }
             validate_settings(&prospective)?;
 
+            // Rewrite stored messages before saving the toggle, so a failure
+            // leaves the setting as it was.
+            let resealed = match encrypt_chat_history {
+                Some(enabled) => ctx.app.chat_history().apply_encryption(enabled).await?,
+                None => 0,
+            };
+
             let updated = ctx.app.settings().update(update).await?;
             let model_display = resolve_model_display(ctx, &updated).await?;
             let all_rows = settings_display_rows(&updated, model_display);
@@ -244,8 +699,19 @@ pub async fn handle_settings(ctx: &CliContext, command: SettingsCommand) -> Resu
                 })
                 .collect();
 
-            println!("✓ Settings updated successfully:");
+            println!("{}", tr!("settings-updated"));
             print_display_rows(&changed_rows);
+            if resealed > 0 {
+                let action = if encrypt_chat_history == Some(true) {
+                    "Encrypted"
+                } else {
+                    "Decrypted"
+                };
+                println!("{action} {resealed} stored chat messages");
+            }
+            if let Some(token) = hf_token {
+                set_hf_token(ctx, token.as_deref())?;
+            }
             Ok(())
         }
         SettingsCommand::Reset { force } => {
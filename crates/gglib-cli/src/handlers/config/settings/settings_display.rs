@@ -10,6 +10,9 @@ use gglib_core::Settings;
 /// user-facing output.
 const HIDDEN_KEYS: &[&str] = &["setup-completed"];
 
+/// Key suffixes whose values are credentials and are shown masked.
+const SECRET_KEY_SUFFIXES: &[&str] = &[".api-key", ".secret-access-key"];
+
 /// A labeled group of display rows used by [`print_sections`].
 pub(super) struct DisplaySection {
     pub title: &'static str,
@@ -66,7 +69,7 @@ fn collect_rows(parent_key: &str, val: serde_json::Value, rows: &mut Vec<(String
 ///
 /// Keys are kebab-case. Nested structs are expanded into dot-notation sub-rows
 /// (e.g. `inference-defaults.temperature`). Rows for [`HIDDEN_KEYS`] are
-/// silently dropped.
+/// silently dropped, and values under [`SECRET_KEY_SUFFIXES`] are masked.
 ///
 /// `default-model-id` is substituted with the pre-resolved `model_display`
 /// string (or `"None"`) to avoid a DB round-trip inside this pure function.
@@ -96,6 +99,12 @@ pub(super) fn settings_display_rows(
         }
     }
 
+    for (key, val) in &mut rows {
+        if SECRET_KEY_SUFFIXES.iter().any(|s| key.ends_with(s)) {
+            *val = "********".to_owned();
+        }
+    }
+
     rows.sort_by(|a, b| a.0.cmp(&b.0));
     rows
 }
@@ -230,6 +239,24 @@ mod tests {
         );
     }
 
+    #[test]
+    fn web_search_api_key_is_masked() {
+        use gglib_core::domain::{WebSearchConfig, WebSearchProvider};
+
+        let settings = Settings {
+            web_search: Some(WebSearchConfig::new(WebSearchProvider::Brave {
+                api_key: "secret-token".to_owned(),
+            })),
+            ..Default::default()
+        };
+        let rows = settings_display_rows(&settings, None);
+        let key_row = rows
+            .iter()
+            .find(|(k, _)| k == "web-search.provider.api-key")
+            .expect("api key row should be present");
+        assert_eq!(key_row.1, "********");
+    }
+
     #[test]
     fn settings_display_rows_model_display_override() {
         let settings = Settings {
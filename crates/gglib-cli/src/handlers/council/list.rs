@@ -6,10 +6,10 @@ use gglib_core::domain::council::run::CouncilRunStatus;
 use gglib_core::ports::CouncilRepositoryPort as _;
 
 use crate::bootstrap::CliContext;
-use crate::presentation::{style, tables};
+use crate::presentation::{OutputFormat, print_json, style, tables};
 
 /// List past runs, optionally filtered by status.
-pub async fn execute(ctx: &CliContext, status: Option<&str>) -> Result<()> {
+pub async fn execute(ctx: &CliContext, status: Option<&str>, output: OutputFormat) -> Result<()> {
     let filter = status.map(parse_status).transpose()?;
     let runs = ctx
         .council_repo
@@ -17,6 +17,10 @@ pub async fn execute(ctx: &CliContext, status: Option<&str>) -> Result<()> {
         .await
         .map_err(|e| anyhow!("failed to list runs: {e}"))?;
 
+    if output.is_json() {
+        return print_json(&runs);
+    }
+
     if runs.is_empty() {
         eprintln!("{}No orchestrator runs found.{}", style::DIM, style::RESET);
         return Ok(());
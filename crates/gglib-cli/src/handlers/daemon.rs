@@ -0,0 +1,61 @@
+//! `gglib daemon` — run `gglib web` as a per-user background service.
+//!
+//! Delegates to [`gglib_runtime::service`], which writes the systemd unit,
+//! launchd agent or Task Scheduler task for the current platform.
+
+use anyhow::{Context, Result};
+use gglib_runtime::service::{ServiceSpec, install_service, uninstall_service};
+
+use crate::commands::DaemonCommand;
+use crate::presentation::style;
+
+/// Execute a `gglib daemon` subcommand.
+pub fn execute(command: DaemonCommand) -> Result<()> {
+    match command {
+        DaemonCommand::InstallService {
+            port,
+            base_port,
+            api_only,
+        } => {
+            let program = std::env::current_exe()
+                .and_then(|p| p.canonicalize())
+                .context("Failed to locate the gglib executable")?;
+            let mut args = vec![
+                "web".to_string(),
+                "--port".to_string(),
+                port.to_string(),
+                "--base-port".to_string(),
+                base_port.to_string(),
+            ];
+            if api_only {
+                args.push("--api-only".to_string());
+            }
+
+            let installed = install_service(&ServiceSpec { program, args })
+                .context("Failed to install the gglib service")?;
+            println!(
+                "{}✓{} Installed gglib service ({}): {}",
+                style::SUCCESS,
+                style::RESET,
+                installed.manager.display_name(),
+                installed.location
+            );
+            println!(
+                "  {}Starts at login and serves on port {port}{}",
+                style::MUTED,
+                style::RESET
+            );
+        }
+        DaemonCommand::UninstallService => {
+            let removed = uninstall_service().context("Failed to uninstall the gglib service")?;
+            println!(
+                "{}✓{} Removed gglib service ({}): {}",
+                style::SUCCESS,
+                style::RESET,
+                removed.manager.display_name(),
+                removed.location
+            );
+        }
+    }
+    Ok(())
+}
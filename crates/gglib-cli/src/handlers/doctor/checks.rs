@@ -0,0 +1,288 @@
+//! The individual `gglib doctor` checks.
+//!
+//! Each check returns a [`Finding`]: its [`CheckResult`] plus, when the
+//! problem is one gglib can repair itself, the [`Fix`] `--fix` offers.
+
+use std::fs;
+use std::net::TcpListener;
+use std::path::{Path, PathBuf};
+
+use gglib_core::domain::doctor::{CheckResult, cuda_toolkit_exceeds_driver};
+use gglib_core::paths::{
+    data_root, llama_bench_path, llama_config_path, llama_server_path, resolve_models_dir,
+};
+use gglib_core::ports::SystemProbePort;
+use gglib_core::settings::{DEFAULT_LLAMA_BASE_PORT, DEFAULT_PROXY_PORT};
+use gglib_download::cli_exec::preflight_fast_helper;
+use gglib_runtime::llama::BuildConfig;
+
+/// Default port of `gglib web`.
+const DEFAULT_WEB_PORT: u16 = 9887;
+
+/// A repair `gglib doctor --fix` can carry out.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) enum Fix {
+    /// Install llama.cpp (`gglib config llama install`).
+    InstallLlama,
+    /// Rebuild llama.cpp, for CUDA or for the detected hardware.
+    RebuildLlama { cuda: bool },
+    /// Set the executable bits on a binary.
+    MakeExecutable(PathBuf),
+    /// Create the models directory.
+    CreateModelsDir(PathBuf),
+}
+
+impl Fix {
+    /// What the fix does, phrased for a confirmation prompt.
+    pub(super) fn describe(&self) -> String {
+        match self {
+            Self::InstallLlama => "Install llama.cpp".to_string(),
+            Self::RebuildLlama { cuda: true } => "Rebuild llama.cpp with CUDA".to_string(),
+            Self::RebuildLlama { cuda: false } => {
+                "Rebuild llama.cpp for the detected hardware".to_string()
+            }
+            Self::MakeExecutable(path) => format!("Make {} executable", path.display()),
+            Self::CreateModelsDir(path) => format!("Create {}", path.display()),
+        }
+    }
+}
+
+/// A check result and the repair for it, if gglib has one.
+pub(super) struct Finding {
+    pub(super) result: CheckResult,
+    pub(super) fix: Option<Fix>,
+}
+
+impl From<CheckResult> for Finding {
+    fn from(result: CheckResult) -> Self {
+        Self { result, fix: None }
+    }
+}
+
+impl Finding {
+    fn with_fix(result: CheckResult, fix: Fix) -> Self {
+        Self {
+            result,
+            fix: Some(fix),
+        }
+    }
+}
+
+/// `llama-server` is installed and executable.
+pub(super) fn llama_server() -> Finding {
+    const NAME: &str = "llama-server";
+    let path = match llama_server_path() {
+        Ok(path) => path,
+        Err(e) => return CheckResult::fail(NAME, e.to_string()).into(),
+    };
+    if !path.exists() {
+        return Finding::with_fix(
+            CheckResult::fail(NAME, format!("not found at {}", path.display()))
+                .with_hint("gglib config llama install"),
+            Fix::InstallLlama,
+        );
+    }
+    if !is_executable(&path) {
+        return Finding::with_fix(
+            CheckResult::fail(NAME, format!("{} is not executable", path.display()))
+                .with_hint(format!("chmod +x {}", path.display())),
+            Fix::MakeExecutable(path),
+        );
+    }
+    CheckResult::pass(NAME, path.display().to_string()).into()
+}
+
+/// `llama-bench` is present; only benchmarking needs it.
+pub(super) fn llama_bench() -> Finding {
+    const NAME: &str = "llama-bench";
+    let Ok(path) = llama_bench_path() else {
+        return CheckResult::warn(NAME, "install location unknown").into();
+    };
+    if !path.exists() {
+        return CheckResult::warn(NAME, "not installed; `gglib benchmark` is unavailable")
+            .with_hint("gglib config llama install --force")
+            .into();
+    }
+    if !is_executable(&path) {
+        return Finding::with_fix(
+            CheckResult::warn(NAME, format!("{} is not executable", path.display()))
+                .with_hint(format!("chmod +x {}", path.display())),
+            Fix::MakeExecutable(path),
+        );
+    }
+    CheckResult::pass(NAME, path.display().to_string()).into()
+}
+
+/// The models directory exists and accepts new files.
+pub(super) fn models_dir() -> Finding {
+    const NAME: &str = "models-dir";
+    let path = match resolve_models_dir(None) {
+        Ok(resolution) => resolution.path,
+        Err(e) => return CheckResult::fail(NAME, e.to_string()).into(),
+    };
+    if !path.exists() {
+        return Finding::with_fix(
+            CheckResult::warn(NAME, format!("{} does not exist yet", path.display()))
+                .with_hint(format!("mkdir -p {}", path.display())),
+            Fix::CreateModelsDir(path),
+        );
+    }
+    match probe_writable(&path) {
+        Ok(()) => CheckResult::pass(NAME, format!("{} is writable", path.display())).into(),
+        Err(e) => CheckResult::fail(NAME, format!("{} is not writable: {e}", path.display()))
+            .with_hint("fix its permissions, or point GGLIB_MODELS_DIR at a writable directory")
+            .into(),
+    }
+}
+
+/// The database passes `PRAGMA integrity_check`.
+pub(super) async fn database() -> Finding {
+    const NAME: &str = "database";
+    // `database_path()` creates the data directory; a health check shouldn't.
+    let path = match data_root() {
+        Ok(root) => root.join("data").join("gglib.db"),
+        Err(e) => return CheckResult::fail(NAME, e.to_string()).into(),
+    };
+    if !path.exists() {
+        return CheckResult::pass(NAME, "not created yet; it will be on first use").into();
+    }
+    match gglib_db::check_integrity(&path).await {
+        Ok(problems) if problems.is_empty() => {
+            CheckResult::pass(NAME, format!("{} passed integrity check", path.display())).into()
+        }
+        Ok(problems) => CheckResult::fail(
+            NAME,
+            format!(
+                "integrity check reported {} problem(s), first: {}",
+                problems.len(),
+                problems[0]
+            ),
+        )
+        .with_hint("restore a backup with `gglib backup restore --database latest`")
+        .into(),
+        Err(e) => CheckResult::fail(NAME, format!("cannot open {}: {e}", path.display()))
+            .with_hint("restore a backup with `gglib backup restore --database latest`")
+            .into(),
+    }
+}
+
+/// The default proxy, web and llama-server ports are free.
+///
+/// A port in use is only a warning: it is usually a gglib instance that is
+/// already running.
+pub(super) fn ports() -> Vec<Finding> {
+    [
+        ("port-proxy", DEFAULT_PROXY_PORT, "gglib proxy", "--port"),
+        ("port-web", DEFAULT_WEB_PORT, "gglib web", "--port"),
+        (
+            "port-llama",
+            DEFAULT_LLAMA_BASE_PORT,
+            "llama-server",
+            "the llama base port setting",
+        ),
+    ]
+    .into_iter()
+    .map(|(name, port, user, option)| {
+        match TcpListener::bind(("127.0.0.1", port)) {
+            Ok(_) => CheckResult::pass(name, format!("{port} is free for {user}")),
+            Err(e) => CheckResult::warn(name, format!("{port} ({user}) is unavailable: {e}"))
+                .with_hint(format!(
+                    "stop whatever is using it (possibly a running gglib), or choose another with {option}"
+                )),
+        }
+        .into()
+    })
+    .collect()
+}
+
+/// The Python interpreter behind fast downloads works.
+pub(super) async fn python_helper() -> Finding {
+    const NAME: &str = "python-helper";
+    match preflight_fast_helper().await {
+        Ok(python) => CheckResult::pass(NAME, format!("using {python}")).into(),
+        Err(e) => CheckResult::warn(NAME, format!("unusable: {e}"))
+            .with_hint("install Python 3 and re-run `gglib doctor`")
+            .into(),
+    }
+}
+
+/// The GPU driver, CUDA toolkit and llama.cpp build agree.
+pub(super) fn gpu(probe: &dyn SystemProbePort) -> Finding {
+    const NAME: &str = "gpu";
+    let gpu = probe.detect_gpu_info();
+    let driver = probe.driver_cuda_version();
+    let acceleration = llama_config_path()
+        .ok()
+        .and_then(|path| BuildConfig::load(&path).ok())
+        .map(|config| config.acceleration);
+    let cuda_build = acceleration.as_deref() == Some("CUDA");
+
+    if cuda_build && !gpu.has_nvidia_gpu {
+        return Finding::with_fix(
+            CheckResult::fail(
+                NAME,
+                "llama.cpp was built for CUDA but no NVIDIA GPU was found",
+            )
+            .with_hint("gglib config llama rebuild"),
+            Fix::RebuildLlama { cuda: false },
+        );
+    }
+    if gpu.has_nvidia_gpu && driver.is_none() {
+        return CheckResult::warn(NAME, "NVIDIA GPU found but nvidia-smi did not respond")
+            .with_hint("install or reload the NVIDIA driver")
+            .into();
+    }
+    if let (Some(driver), Some(toolkit)) = (&driver, &gpu.cuda_version)
+        && cuda_toolkit_exceeds_driver(driver, toolkit)
+    {
+        let result = CheckResult::fail(
+            NAME,
+            format!("CUDA toolkit {toolkit} is newer than the driver supports (CUDA {driver})"),
+        )
+        .with_hint(format!(
+            "upgrade the NVIDIA driver, or install a CUDA toolkit ≤ {driver} and run `gglib config llama rebuild`"
+        ));
+        return if cuda_build {
+            Finding::with_fix(result, Fix::RebuildLlama { cuda: true })
+        } else {
+            result.into()
+        };
+    }
+    if gpu.has_nvidia_gpu && acceleration.as_deref() == Some("CPU") {
+        return Finding::with_fix(
+            CheckResult::warn(
+                NAME,
+                "NVIDIA GPU found but llama.cpp was built for CPU only",
+            )
+            .with_hint("gglib config llama rebuild --cuda"),
+            Fix::RebuildLlama { cuda: true },
+        );
+    }
+
+    let summary = match (&driver, &acceleration) {
+        (Some(driver), Some(accel)) => format!("CUDA {driver} driver, llama.cpp built for {accel}"),
+        (Some(driver), None) => format!("CUDA {driver} driver"),
+        (None, Some(accel)) => format!("llama.cpp built for {accel}"),
+        (None, None) if gpu.has_metal => "Metal".to_string(),
+        (None, None) => "no GPU acceleration detected".to_string(),
+    };
+    CheckResult::pass(NAME, summary).into()
+}
+
+#[cfg(unix)]
+fn is_executable(path: &Path) -> bool {
+    use std::os::unix::fs::PermissionsExt;
+    fs::metadata(path).is_ok_and(|meta| meta.permissions().mode() & 0o111 != 0)
+}
+
+#[cfg(not(unix))]
+fn is_executable(path: &Path) -> bool {
+    path.is_file()
+}
+
+/// Create and remove a scratch file in `dir`.
+fn probe_writable(dir: &Path) -> std::io::Result<()> {
+    let probe = dir.join(".gglib-doctor-write-test");
+    fs::write(&probe, b"")?;
+    fs::remove_file(&probe)
+}
@@ -0,0 +1,155 @@
+#![doc = include_str!("README.md")]
+
+//! `gglib doctor` — diagnose the installation and offer fixes.
+//!
+//! Runs before the composition root is bootstrapped, so it still works when
+//! the database is what's broken.
+
+mod checks;
+
+use std::io::{self, Write};
+
+use anyhow::{Context, Result};
+use gglib_core::domain::doctor::{CheckStatus, DoctorReport};
+use gglib_core::ports::SystemProbePort;
+
+use checks::{Finding, Fix};
+
+use crate::error::CliError;
+use crate::handlers::config::llama_install::handle_install;
+use crate::presentation::style::{BOLD, DANGER, INFO, RESET, SUCCESS, WARNING};
+use crate::presentation::{OutputFormat, print_json};
+use crate::tr;
+
+/// Execute `gglib doctor`.
+///
+/// With `fix`, offers each available repair in turn (without asking when
+/// `yes` is set). Fails with a non-zero exit code when any check fails.
+pub async fn execute(
+    probe: &dyn SystemProbePort,
+    fix: bool,
+    yes: bool,
+    output: OutputFormat,
+) -> Result<()> {
+    let findings = run_checks(probe).await;
+    let report = DoctorReport {
+        checks: findings.iter().map(|f| f.result.clone()).collect(),
+    };
+
+    if output.is_json() {
+        print_json(&report)?;
+    } else {
+        render(&findings, &report);
+        let fixes: Vec<&Fix> = findings.iter().filter_map(|f| f.fix.as_ref()).collect();
+        if fix {
+            apply_fixes(&fixes, yes).await?;
+        } else if !fixes.is_empty() {
+            let command = format!("{INFO}gglib doctor --fix{RESET}");
+            println!(
+                "\n{}",
+                tr!("doctor-suggest-fix", command = command, count = fixes.len())
+            );
+        }
+    }
+
+    let failed = report.count(CheckStatus::Fail);
+    if failed > 0 {
+        return Err(CliError::Core(tr!("doctor-failed", count = failed)).into());
+    }
+    Ok(())
+}
+
+async fn run_checks(probe: &dyn SystemProbePort) -> Vec<Finding> {
+    let mut findings = vec![
+        checks::llama_server(),
+        checks::llama_bench(),
+        checks::models_dir(),
+        checks::database().await,
+    ];
+    findings.extend(checks::ports());
+    findings.push(checks::python_helper().await);
+    findings.push(checks::gpu(probe));
+    findings
+}
+
+fn render(findings: &[Finding], report: &DoctorReport) {
+    println!("{BOLD}{INFO}{}{RESET}\n", tr!("doctor-heading"));
+    for finding in findings {
+        let result = &finding.result;
+        let (mark, colour) = match result.status {
+            CheckStatus::Pass => ("✓", SUCCESS),
+            CheckStatus::Warn => ("!", WARNING),
+            CheckStatus::Fail => ("✗", DANGER),
+        };
+        println!(
+            "{colour}{mark}{RESET} {:<15} {}",
+            result.name, result.message
+        );
+        if let Some(hint) = &result.hint {
+            println!("  {:<15} → {hint}", "");
+        }
+    }
+
+    println!(
+        "\n{}",
+        tr!(
+            "doctor-summary",
+            passed = report.count(CheckStatus::Pass),
+            warned = report.count(CheckStatus::Warn),
+            failed = report.count(CheckStatus::Fail),
+        )
+    );
+}
+
+async fn apply_fixes(fixes: &[&Fix], yes: bool) -> Result<()> {
+    if fixes.is_empty() {
+        println!("\n{}", tr!("doctor-nothing-to-fix"));
+        return Ok(());
+    }
+    for fix in fixes {
+        let description = fix.describe();
+        if !yes && !confirm(&description)? {
+            println!("{}", tr!("doctor-fix-skipped"));
+            continue;
+        }
+        match apply(fix).await {
+            Ok(()) => println!("{SUCCESS}✓{RESET} {description}"),
+            Err(e) => eprintln!("{DANGER}✗{RESET} {description}: {e:#}"),
+        }
+    }
+    let command = format!("{INFO}gglib doctor{RESET}");
+    println!("\n{}", tr!("doctor-rerun", command = command));
+    Ok(())
+}
+
+fn confirm(description: &str) -> Result<bool> {
+    print!("\n{description}? [Y/n] ");
+    io::stdout().flush()?;
+    let mut input = String::new();
+    io::stdin().read_line(&mut input)?;
+    Ok(!input.trim().eq_ignore_ascii_case("n"))
+}
+
+async fn apply(fix: &Fix) -> Result<()> {
+    match fix {
+        Fix::InstallLlama => handle_install(false, false, false, false, false, None).await,
+        Fix::RebuildLlama { cuda } => handle_install(*cuda, false, false, true, true, None).await,
+        Fix::MakeExecutable(path) => make_executable(path),
+        Fix::CreateModelsDir(path) => std::fs::create_dir_all(path)
+            .with_context(|| format!("Failed to create {}", path.display())),
+    }
+}
+
+#[cfg(unix)]
+fn make_executable(path: &std::path::Path) -> Result<()> {
+    use std::os::unix::fs::PermissionsExt;
+    let mut permissions = std::fs::metadata(path)?.permissions();
+    permissions.set_mode(permissions.mode() | 0o755);
+    std::fs::set_permissions(path, permissions)
+        .with_context(|| format!("Failed to chmod {}", path.display()))
+}
+
+#[cfg(not(unix))]
+fn make_executable(_path: &std::path::Path) -> Result<()> {
+    Ok(())
+}
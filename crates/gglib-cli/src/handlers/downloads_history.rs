@@ -0,0 +1,86 @@
+//! `gglib downloads history` — past download runs.
+//!
+//! Each time the download queue drains, the manager records the run and one
+//! entry per model it touched. This handler reads those records back through
+//! [`DownloadManagerPort::get_history`](gglib_core::ports::DownloadManagerPort::get_history).
+
+use anyhow::Result;
+use chrono::{DateTime, Local, Utc};
+use gglib_core::download::{CompletionKind, DownloadHistoryFilter};
+
+use crate::bootstrap::CliContext;
+use crate::presentation::{OutputFormat, print_json, print_separator, truncate_string};
+
+const DAY_MS: u64 = 24 * 60 * 60 * 1000;
+
+/// Build the history filter from the command-line arguments.
+pub fn filter(
+    days: Option<u32>,
+    result: Option<CompletionKind>,
+    search: Option<String>,
+    limit: u32,
+) -> DownloadHistoryFilter {
+    let now_ms = u64::try_from(Utc::now().timestamp_millis()).unwrap_or(0);
+    DownloadHistoryFilter {
+        since_ms: days.map(|d| now_ms.saturating_sub(u64::from(d) * DAY_MS)),
+        until_ms: None,
+        result,
+        search,
+        limit: Some(limit),
+    }
+}
+
+/// Execute `gglib downloads history`.
+///
+/// With `--output json`, prints the runs as a JSON array of
+/// `QueueRunSummary` objects.
+pub async fn execute(
+    ctx: &CliContext,
+    filter: &DownloadHistoryFilter,
+    output: OutputFormat,
+) -> Result<()> {
+    let runs = ctx.downloads.get_history(filter).await?;
+    if output.is_json() {
+        return print_json(&runs);
+    }
+    if runs.is_empty() {
+        println!("No download history.");
+        return Ok(());
+    }
+
+    for run in &runs {
+        println!(
+            "Run finished {} ({} downloaded, {} failed, {} cancelled)",
+            format_ms(run.completed_at_ms),
+            run.unique_models_downloaded,
+            run.unique_models_failed,
+            run.unique_models_cancelled,
+        );
+        println!(
+            "  {:<50} {:<16} {:>8} {:<16}",
+            "Model", "Result", "Attempts", "Finished"
+        );
+        print_separator(94);
+        for item in &run.items {
+            println!(
+                "  {:<50} {:<16} {:>8} {:<16}",
+                truncate_string(&item.display_name, 50),
+                item.last_result.as_str(),
+                item.attempt_counts.total(),
+                format_ms(item.last_completed_at_ms),
+            );
+        }
+        println!();
+    }
+
+    Ok(())
+}
+
+/// Format a millisecond Unix timestamp in local time.
+fn format_ms(ms: u64) -> String {
+    i64::try_from(ms)
+        .ok()
+        .and_then(DateTime::<Utc>::from_timestamp_millis)
+        .map(|t| t.with_timezone(&Local).format("%Y-%m-%d %H:%M").to_string())
+        .unwrap_or_else(|| "-".to_string())
+}
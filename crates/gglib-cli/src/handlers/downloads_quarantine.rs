@@ -0,0 +1,64 @@
+//! `gglib downloads quarantine` — list and clear quarantined downloads.
+//!
+//! Downloads that fail post-download validation (GGUF magic, size, optional
+//! SHA-256) are moved to `<models_dir>/quarantine/` with a `reason.json`
+//! instead of being registered. This handler reads and empties that
+//! directory; it needs no database access.
+
+use anyhow::{Context, Result};
+use gglib_core::paths::resolve_models_dir;
+use gglib_download::quarantine::{clear_quarantine, list_quarantine};
+
+use crate::commands::QuarantineCommand;
+use crate::presentation::{
+    OutputFormat, format_bytes, print_json, print_separator, truncate_string,
+};
+
+/// Execute a `gglib downloads quarantine` subcommand.
+///
+/// With `--output json`, `list` prints the entries and `clear` the removed
+/// entries as a JSON array.
+pub fn execute(command: QuarantineCommand, output: OutputFormat) -> Result<()> {
+    let models_dir = resolve_models_dir(None)?.path;
+
+    match command {
+        QuarantineCommand::List => {
+            let entries =
+                list_quarantine(&models_dir).context("Failed to read quarantine directory")?;
+            if output.is_json() {
+                return print_json(&entries);
+            }
+            if entries.is_empty() {
+                println!("No quarantined downloads.");
+                return Ok(());
+            }
+
+            println!("{:<45} {:>10} {:<40}", "Name", "Size", "Reason");
+            print_separator(97);
+            for entry in &entries {
+                println!(
+                    "{:<45} {:>10} {:<40}",
+                    truncate_string(&entry.name, 44),
+                    format_bytes(entry.size_bytes),
+                    truncate_string(&entry.record.reason, 40),
+                );
+            }
+            println!("\nDelete with: gglib downloads quarantine clear [NAME]");
+        }
+        QuarantineCommand::Clear { name } => {
+            let removed = clear_quarantine(&models_dir, name.as_deref())
+                .context("Failed to clear quarantine")?;
+            if output.is_json() {
+                return print_json(&removed);
+            }
+            let freed: u64 = removed.iter().map(|e| e.size_bytes).sum();
+            println!(
+                "Removed {} quarantined download(s), freeing {}.",
+                removed.len(),
+                format_bytes(freed)
+            );
+        }
+    }
+
+    Ok(())
+}
@@ -1,20 +1,33 @@
 //! History command handler.
 //!
-//! Lists past chat conversations with message counts and relative timestamps.
+//! Lists past chat conversations with message counts and relative timestamps,
+//! or as a JSON array with `--output json`.
 
 use anyhow::Result;
+use gglib_core::domain::chat::Conversation;
+use serde::Serialize;
 
 use crate::bootstrap::CliContext;
-use crate::presentation::{format_relative_time, print_separator, truncate_string};
+use crate::presentation::{
+    OutputFormat, format_relative_time, print_json, print_separator, truncate_string,
+};
+
+/// JSON row for `--output json`: the conversation plus its message count.
+#[derive(Serialize)]
+struct HistoryRow<'a> {
+    #[serde(flatten)]
+    conversation: &'a Conversation,
+    message_count: i64,
+}
 
 /// Execute the history command.
 ///
 /// Retrieves and displays past conversations with message counts
 /// and relative timestamps for quick browsing.
-pub async fn execute(ctx: &CliContext, limit: usize) -> Result<()> {
+pub async fn execute(ctx: &CliContext, limit: usize, output: OutputFormat) -> Result<()> {
     let conversations = ctx.app.chat_history().list_conversations().await?;
 
-    if conversations.is_empty() {
+    if conversations.is_empty() && !output.is_json() {
         println!("No conversations found.");
         println!("Start one with: gglib chat <model>");
         return Ok(());
@@ -29,6 +42,17 @@ pub async fn execute(ctx: &CliContext, limit: usize) -> Result<()> {
         rows.push((conv, count));
     }
 
+    if output.is_json() {
+        let rows: Vec<HistoryRow> = rows
+            .iter()
+            .map(|(conversation, message_count)| HistoryRow {
+                conversation,
+                message_count: *message_count,
+            })
+            .collect();
+        return print_json(&rows);
+    }
+
     println!(
         "{:<5} {:<35} {:<6} {:<15} {:<15}",
         "ID", "Title", "Msgs", "Model", "Updated"
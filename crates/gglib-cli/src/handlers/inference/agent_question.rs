@@ -18,9 +18,9 @@ use gglib_core::domain::agent::{AgentConfig, AgentEvent, AgentMessage};
 use crate::bootstrap::CliContext;
 use crate::handlers::agent_chat::config::{AgentSessionParams, compose};
 use crate::handlers::agent_chat::drain::drain_event_stream;
-use crate::handlers::agent_chat::persistence::Conversation;
+use crate::handlers::agent_chat::persistence::{Conversation, bind_library_model};
 use crate::handlers::agent_chat::repl::run_repl_with_history;
-use crate::handlers::inference::shared::resolve_max_iterations;
+use crate::handlers::inference::shared::{merge_context, read_piped_stdin, resolve_max_iterations};
 use crate::shared_args::{ContextArgs, SamplingArgs};
 
 /// System prompt for the agentic question mode.
@@ -169,7 +169,7 @@ pub async fn execute(
     let mut persistence = None;
     if completed && let Some(ref history) = history {
         let system_prompt = format!("{}\n\nWorking directory: {}", SYSTEM_PROMPT, cwd.display());
-        let settings = crate::shared_args::ConversationSettingsBuilder::new(
+        let mut settings = crate::shared_args::ConversationSettingsBuilder::new(
             &SamplingArgs::default(),
             &crate::shared_args::ContextArgs::default(),
         )
@@ -177,11 +177,14 @@ pub async fn execute(
         .tools(tools.clone(), false)
         .agent_params(max_iterations, tool_timeout_ms, max_parallel)
         .build();
+        let model_id =
+            bind_library_model(ctx.app.models(), &params.model_identifier, &mut settings).await;
         match Conversation::create(
             ctx.app.chat_history(),
             Some(system_prompt),
-            None,
+            model_id,
             Some(settings),
+            false,
         )
         .await
         {
@@ -205,7 +208,7 @@ pub async fn execute(
         if let Some(history) = history
             && ask_continue()?
         {
-            run_repl_with_history(agent, history, config, verbose, persistence).await?;
+            run_repl_with_history(agent, history, config, verbose, persistence, None).await?;
         }
     } else if !completed {
         // Defer error until after potential server cleanup
@@ -255,8 +258,6 @@ async fn stop_server(ctx: &CliContext, maybe_handle: &Option<gglib_core::Process
 
 /// Build the user message, incorporating piped stdin or `--file` content.
 fn build_user_message(question: &str, file: Option<&str>, verbose: bool) -> Result<String> {
-    use std::io::{self, IsTerminal, Read};
-
     // --file takes precedence over piped stdin.
     let context = if let Some(path) = file {
         let content = std::fs::read_to_string(path)
@@ -267,33 +268,10 @@ fn build_user_message(question: &str, file: Option<&str>, verbose: bool) -> Resu
             Some(content)
         }
     } else {
-        let stdin = io::stdin();
-        if !stdin.is_terminal() {
-            let mut buffer = String::new();
-            stdin
-                .lock()
-                .read_to_string(&mut buffer)
-                .map_err(|e| anyhow!("failed to read from stdin: {e}"))?;
-            if buffer.is_empty() {
-                None
-            } else {
-                Some(buffer)
-            }
-        } else {
-            None
-        }
+        read_piped_stdin()?
     };
 
-    let user_message = match context {
-        Some(input) => {
-            if question.contains("{}") {
-                question.replace("{}", &input)
-            } else {
-                format!("<context>\n{}\n</context>\n\n{}", input.trim(), question)
-            }
-        }
-        None => question.to_string(),
-    };
+    let user_message = merge_context(question, context.as_deref());
 
     if verbose {
         eprintln!("─── User Message ───");
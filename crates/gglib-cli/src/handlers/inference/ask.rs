@@ -0,0 +1,220 @@
+//! One-shot completion handler for `gglib ask`.
+//!
+//! Unlike `gglib q` there is no agent loop, no tools and no continuation
+//! prompt: the prompt (plus any piped stdin) goes to the model once and the
+//! answer streams to stdout, so the command composes with shell pipelines:
+//!
+//! ```text
+//! cat diff.patch | gglib ask coder "review this" > review.md
+//! ```
+//!
+//! The server banner and reasoning tokens go to stderr, never stdout.
+//! `--raw` drops them entirely; `--json` buffers the reply and prints a
+//! single JSON object instead of streaming.
+
+use std::io::{self, Write};
+
+use anyhow::{Result, anyhow, bail};
+use futures_util::StreamExt;
+use serde::Serialize;
+
+use gglib_core::domain::agent::{AgentMessage, LlmStreamEvent};
+use gglib_core::ports::LlmCompletionPort;
+use gglib_core::request_pipeline;
+use gglib_runtime::LlmCompletionAdapter;
+
+use crate::bootstrap::CliContext;
+use crate::handlers::agent_chat::config::{AgentSessionParams, BannerInfo, resolve_port};
+use crate::handlers::inference::shared::{
+    merge_context, read_piped_stdin, resolve_inference_config,
+};
+use crate::presentation::{print_json, style};
+use crate::shared_args::{ContextArgs, SamplingArgs};
+
+/// Arguments for `gglib ask`.
+#[derive(Debug, Clone)]
+pub struct AskArgs {
+    /// Model name or ID.
+    pub model: String,
+    /// Prompt text; may be omitted when the prompt is piped on stdin.
+    pub prompt: Option<String>,
+    pub system_prompt: Option<String>,
+    /// Reuse an already-running llama-server on this port.
+    pub port: Option<u16>,
+    pub context: ContextArgs,
+    pub sampling: SamplingArgs,
+    /// Print one JSON object instead of streaming text.
+    pub json: bool,
+    /// Print only the answer text.
+    pub raw: bool,
+}
+
+/// Token counts reported by the server for the completion.
+#[derive(Debug, Serialize)]
+struct AskUsage {
+    prompt_tokens: u32,
+    completion_tokens: u32,
+    total_tokens: u32,
+}
+
+/// The `--json` output document.
+#[derive(Debug, Default, Serialize)]
+struct AskOutput {
+    model: String,
+    content: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reasoning: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    finish_reason: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    usage: Option<AskUsage>,
+}
+
+/// Execute `gglib ask`.
+///
+/// Starts the model unless `--port` points at a running server, streams
+/// the reply, and stops the server again on the way out.
+pub async fn execute(ctx: &CliContext, args: AskArgs) -> Result<()> {
+    let prompt = build_prompt(args.prompt.as_deref(), read_piped_stdin()?)?;
+
+    let params = AgentSessionParams {
+        model_identifier: args.model.clone(),
+        ctx_size: args.context.ctx_size.clone(),
+        port: args.port,
+        tools: Vec::new(),
+        model_name: None,
+    };
+    let banner = BannerInfo {
+        quiet: args.raw || args.json,
+        ..BannerInfo::default()
+    };
+    let (port, maybe_handle) = resolve_port(ctx, &params, &banner).await?;
+
+    let result = tokio::select! {
+        result = complete(ctx, &args, port, prompt) => result,
+        _ = tokio::signal::ctrl_c() => Err(anyhow!("cancelled")),
+    };
+
+    if let Some(handle) = maybe_handle
+        && let Err(e) = ctx.runner.stop(&handle).await
+    {
+        tracing::warn!("failed to stop llama-server: {e}");
+    }
+    result
+}
+
+/// Combine the positional prompt with piped stdin.
+///
+/// Either may stand alone; with both, stdin is the context for the prompt
+/// (same `{}` / `<context>` rules as `gglib q`).
+fn build_prompt(prompt: Option<&str>, stdin: Option<String>) -> Result<String> {
+    match (prompt.filter(|p| !p.trim().is_empty()), stdin) {
+        (Some(prompt), stdin) => Ok(merge_context(prompt, stdin.as_deref())),
+        (None, Some(stdin)) => Ok(stdin),
+        (None, None) => bail!("no prompt given: pass one as an argument or pipe it on stdin"),
+    }
+}
+
+/// Stream one completion from the server on `port`.
+async fn complete(ctx: &CliContext, args: &AskArgs, port: u16, prompt: String) -> Result<()> {
+    let sampling = args.sampling.clone().into_inference_config();
+    let sampling = match ctx.app.models().find_by_identifier(&args.model).await {
+        Ok(model) => resolve_inference_config(ctx, sampling, &model).await?,
+        Err(_) => sampling,
+    };
+    let model_context = request_pipeline::resolve(ctx.catalog.as_ref(), Some(&args.model)).await;
+    let llm = LlmCompletionAdapter::with_client(
+        format!("http://127.0.0.1:{port}"),
+        ctx.http_client.clone(),
+        None,
+    )
+    .with_sampling(Some(sampling))
+    .with_model_context(model_context);
+
+    let mut messages = Vec::new();
+    if let Some(system) = &args.system_prompt {
+        messages.push(AgentMessage::System {
+            content: system.clone(),
+        });
+    }
+    messages.push(AgentMessage::User { content: prompt });
+
+    let mut output = AskOutput {
+        model: args.model.clone(),
+        ..AskOutput::default()
+    };
+    let mut stdout = io::stdout();
+    let mut stream = llm.chat_stream(&messages, &[], None).await?;
+    while let Some(event) = stream.next().await {
+        match event? {
+            LlmStreamEvent::TextDelta { content } => {
+                if !args.json {
+                    write!(stdout, "{content}")?;
+                    stdout.flush()?;
+                }
+                output.content.push_str(&content);
+            }
+            LlmStreamEvent::ReasoningDelta { content } => {
+                if !args.json && !args.raw {
+                    eprint!("{}{content}{}", style::DIM, style::RESET);
+                }
+                output
+                    .reasoning
+                    .get_or_insert_with(String::new)
+                    .push_str(&content);
+            }
+            LlmStreamEvent::Usage {
+                prompt_tokens,
+                completion_tokens,
+                total_tokens,
+                ..
+            } => {
+                output.usage = Some(AskUsage {
+                    prompt_tokens,
+                    completion_tokens,
+                    total_tokens,
+                });
+            }
+            LlmStreamEvent::Done { finish_reason } => {
+                output.finish_reason = Some(finish_reason);
+                break;
+            }
+            LlmStreamEvent::UpstreamError { message, .. } => bail!("model error: {message}"),
+            _ => {}
+        }
+    }
+
+    if args.json {
+        print_json(&output)
+    } else {
+        if !output.content.ends_with('\n') {
+            writeln!(stdout)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn piped_stdin_alone_is_the_prompt() {
+        let prompt = build_prompt(None, Some("explain ownership".into())).unwrap();
+        assert_eq!(prompt, "explain ownership");
+    }
+
+    #[test]
+    fn piped_stdin_becomes_context_for_the_prompt() {
+        let prompt = build_prompt(Some("review this"), Some("diff --git a b\n".into())).unwrap();
+        assert_eq!(
+            prompt,
+            "<context>\ndiff --git a b\n</context>\n\nreview this"
+        );
+    }
+
+    #[test]
+    fn missing_prompt_is_an_error() {
+        assert!(build_prompt(Some("  "), None).is_err());
+    }
+}
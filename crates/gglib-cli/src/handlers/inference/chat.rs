@@ -27,6 +27,8 @@ pub struct ChatArgs {
     pub model: Option<String>,
     /// Resume a previous conversation by ID.
     pub continue_id: Option<i64>,
+    /// Keep the conversation in memory only.
+    pub incognito: bool,
     /// Observation-tool name patterns for the dual-threshold loop guard.
     /// An empty vec means "use defaults" (see `AgentConfig::observation_tools`).
     pub observation_tools: Vec<String>,
@@ -1,5 +1,6 @@
 #![doc = include_str!("README.md")]
 pub mod agent_question;
+pub mod ask;
 pub mod chat;
 pub mod serve;
 pub mod shared;
@@ -7,11 +7,17 @@ use std::process::Stdio;
 
 use crate::bootstrap::CliContext;
 use crate::presentation::style;
-use crate::shared_args::{ContextArgs, MtpArgs, SamplingArgs, ServeOptions};
-use gglib_core::server_config::{ServerConfigOptions, parse_ctx_size_flag, resolve_context_size};
+use crate::shared_args::{
+    ContextArgs, CpuArgs, GpuArgs, MtpArgs, RopeArgs, SamplingArgs, ServeOptions,
+};
+use gglib_core::server_config::{
+    ServerConfigOptions, parse_ctx_size_flag, resolve_context_size_within,
+};
+use gglib_runtime::llama::args::{resolve_cpu_args, resolve_gpu_args};
 use gglib_runtime::llama::{
     LlamaCommandBuilder, ensure_llama_initialized, resolve_llama_server, resolve_mtp_args,
 };
+use gglib_runtime::system::{cpu_topology, gpu_devices};
 
 use super::shared::{
     log_command_execution, log_inference_info, log_mlock_info, resolve_inference_config,
@@ -20,6 +26,7 @@ use super::shared::{
 /// Execute the serve command.
 ///
 /// Starts llama-server with the specified model.
+#[allow(clippy::too_many_arguments)]
 pub async fn execute(
     ctx: &CliContext,
     id: u32,
@@ -27,6 +34,9 @@ pub async fn execute(
     options: ServeOptions,
     sampling: SamplingArgs,
     mtp: MtpArgs,
+    rope: RopeArgs,
+    cpu: CpuArgs,
+    gpu: GpuArgs,
     verbose: bool,
 ) -> Result<()> {
     // Ensure llama.cpp is installed
@@ -53,24 +63,64 @@ pub async fn execute(
     eprintln!("  Using model: {} (ID: {})", model.name, model.id);
     eprintln!("  File: {}", model.file_path.display());
 
+    // RoPE overrides change how far the model can stretch, so the maximum
+    // context is computed against them rather than the raw GGUF value.
+    let rope = Some(rope.into_rope_config()).filter(|r| !r.is_empty());
+    if let Some(ref rope) = rope {
+        eprintln!("  RoPE overrides: {}", rope.to_cli_args().join(" "));
+    }
+    let max_ctx = model.max_context(rope.as_ref());
+
     // Handle context size.
     // The raw flag is parsed (shape-validated) independently of the model,
     // then resolved against the model's GGUF context length now that it's
-    // available — this is what makes `--ctx-size max` work.
+    // available — this is what makes `--ctx-size max` work. Without the
+    // flag, the model's own default applies (initialized from its metadata
+    // when it was added), then the global setting.
     let settings = ctx.app.settings().get().await?;
     let ctx_arg = parse_ctx_size_flag(context.ctx_size.as_deref())?;
-    let effective_ctx = resolve_context_size(&ServerConfigOptions {
-        context_size: ctx_arg.and_then(|arg| arg.resolve(model.context_length)),
-        model_server_ctx: model
-            .server_defaults
-            .as_ref()
-            .and_then(|s| s.context_length),
-        global_default_ctx: settings.default_context_size,
-        ..Default::default()
-    });
+    let effective_ctx = resolve_context_size_within(
+        &ServerConfigOptions {
+            context_size: ctx_arg.and_then(|arg| arg.resolve(max_ctx)),
+            model_server_ctx: model
+                .server_defaults
+                .as_ref()
+                .and_then(|s| s.context_length),
+            global_default_ctx: settings.default_context_size,
+            ..Default::default()
+        },
+        max_ctx,
+    )
+    .map_err(|e| {
+        anyhow::anyhow!("{e}. Use --ctx-size max, or stretch it with --rope-freq-scale")
+    })?;
     eprintln!("  Context size: {} (resolved)", effective_ctx);
+    if let Some(max_ctx) = max_ctx {
+        eprintln!("  Max context: {}", max_ctx);
+    }
     log_mlock_info(context.mlock);
 
+    // Parallel slots: the flag, then the model's launch defaults, else one.
+    // Each slot keeps the full context, so the pool scales with the count.
+    let defaults = model.server_defaults.as_ref();
+    let slots = options
+        .parallel
+        .or_else(|| defaults.and_then(|d| d.parallel))
+        .unwrap_or(1)
+        .max(1);
+    if slots > 1 {
+        eprintln!(
+            "  Parallel slots: {} (context pool {})",
+            slots,
+            effective_ctx * u64::from(slots)
+        );
+    }
+    let cont_batching = if options.no_cont_batching {
+        Some(false)
+    } else {
+        defaults.and_then(|d| d.cont_batching)
+    };
+
     // Resolve inference parameters using 3-level hierarchy
     let inference_config =
         resolve_inference_config(ctx, sampling.into_inference_config(), &model).await?;
@@ -90,6 +140,27 @@ pub async fn execute(
         );
     }
 
+    // Resolve threads and CPU pinning against the host topology
+    let cpu = resolve_cpu_args(
+        cpu.threads,
+        cpu.threads_batch,
+        cpu.cpu_affinity,
+        cpu.numa,
+        cpu_topology(),
+    );
+    eprintln!("  {}", cpu.explain());
+
+    // Resolve multi-GPU placement against the GPUs present now
+    let gpu = resolve_gpu_args(
+        gpu.split_mode,
+        gpu.tensor_split,
+        gpu.main_gpu,
+        &gpu_devices(),
+    );
+    if let Some(explanation) = gpu.explain() {
+        eprintln!("  {explanation}");
+    }
+
     eprintln!(
         "  Server will be available on http://localhost:{}",
         options.port
@@ -98,15 +169,30 @@ pub async fn execute(
 
     // Build llama-server command
     let mut builder = LlamaCommandBuilder::new(&llama_path, &model.file_path)
-        .context_size(effective_ctx)
+        .context_size(effective_ctx * u64::from(slots))
         .mlock(context.mlock)
         .inference_config(inference_config)
         .arg_with_value("--port", options.port.to_string());
 
+    if let Some(rope) = rope {
+        builder = builder.rope(rope);
+    }
+
     if options.jinja {
         builder = builder.flag("--jinja");
     }
 
+    if slots > 1 {
+        builder = builder
+            .arg_with_value("--parallel", slots.to_string())
+            .flag("--kv-unified");
+    }
+    match cont_batching {
+        Some(true) => builder = builder.flag("--cont-batching"),
+        Some(false) => builder = builder.flag("--no-cont-batching"),
+        None => {}
+    }
+
     if mtp.enabled {
         builder = builder
             .arg_with_value("--spec-type", "draft-mtp".to_string())
@@ -114,6 +200,29 @@ pub async fn execute(
             .arg_with_value("--spec-draft-p-min", mtp.draft_p_min.to_string());
     }
 
+    builder = builder.arg_with_value("--threads", cpu.threads.to_string());
+    if let Some(n) = cpu.threads_batch {
+        builder = builder.arg_with_value("--threads-batch", n.to_string());
+    }
+    if let Some(ref cpus) = cpu.cpu_affinity {
+        builder = builder
+            .arg_with_value("--cpu-mask", cpus.to_hex_mask())
+            .arg_with_value("--cpu-strict", "1");
+    }
+    if let Some(numa) = cpu.numa {
+        builder = builder.arg_with_value("--numa", numa.as_llama_arg());
+    }
+
+    if let Some(mode) = gpu.split_mode {
+        builder = builder.arg_with_value("--split-mode", mode.as_llama_arg());
+    }
+    if let Some(ref split) = gpu.tensor_split {
+        builder = builder.arg_with_value("--tensor-split", split.to_string());
+    }
+    if let Some(index) = gpu.main_gpu {
+        builder = builder.arg_with_value("--main-gpu", index.to_string());
+    }
+
     // Suppress llama-server's own INFO-level startup chatter unless --verbose.
     // -lv 1 = errors only; -lv 3 = INFO (llama-server default).
     let log_verbosity = if verbose { "3" } else { "1" };
@@ -121,6 +230,13 @@ pub async fn execute(
 
     let mut cmd = builder.build();
 
+    // Number GPUs the way nvidia-smi does, which the split above assumes
+    if (gpu.tensor_split.is_some() || gpu.main_gpu.is_some())
+        && std::env::var_os("CUDA_DEVICE_ORDER").is_none()
+    {
+        cmd.env("CUDA_DEVICE_ORDER", "PCI_BUS_ID");
+    }
+
     // Set up stdio to inherit from parent
     cmd.stdin(Stdio::inherit())
         .stdout(Stdio::inherit())
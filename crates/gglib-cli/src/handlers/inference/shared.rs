@@ -1,9 +1,12 @@
 //! Shared inference utilities.
 //!
-//! Functions used by `serve`, `chat`, and `question` handlers to resolve
-//! inference parameters via the 3-level merge hierarchy and log diagnostics.
+//! Functions used by `serve`, `chat`, `question` and `ask` handlers to resolve
+//! inference parameters via the 3-level merge hierarchy, read piped context,
+//! and log diagnostics.
 
-use anyhow::Result;
+use std::io::{self, IsTerminal, Read};
+
+use anyhow::{Result, anyhow};
 
 use crate::bootstrap::CliContext;
 use gglib_core::Settings;
@@ -36,6 +39,32 @@ pub fn resolve_max_iterations(cli_override: Option<usize>, settings: &Settings)
         .unwrap_or(DEFAULT_MAX_ITERATIONS)
 }
 
+/// Read all of stdin when it is piped; `None` for a terminal or empty input.
+pub fn read_piped_stdin() -> Result<Option<String>> {
+    let stdin = io::stdin();
+    if stdin.is_terminal() {
+        return Ok(None);
+    }
+    let mut buffer = String::new();
+    stdin
+        .lock()
+        .read_to_string(&mut buffer)
+        .map_err(|e| anyhow!("failed to read from stdin: {e}"))?;
+    Ok(Some(buffer).filter(|b| !b.is_empty()))
+}
+
+/// Combine a question with piped or file context.
+///
+/// A `{}` in the question is replaced by the context; otherwise the context
+/// is prepended inside `<context>` tags.
+pub fn merge_context(question: &str, context: Option<&str>) -> String {
+    match context {
+        Some(input) if question.contains("{}") => question.replace("{}", input),
+        Some(input) => format!("<context>\n{}\n</context>\n\n{}", input.trim(), question),
+        None => question.to_string(),
+    }
+}
+
 /// Log mlock status to stderr.
 pub fn log_mlock_info(mlock: bool) {
     if mlock {
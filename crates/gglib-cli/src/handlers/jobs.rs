@@ -0,0 +1,106 @@
+//! `gglib jobs` — long-running operations in the daemon.
+//!
+//! Downloads, verifications and llama.cpp builds started from the GUI run
+//! inside the daemon, so this handler asks it over `GET /api/jobs` rather
+//! than reading local state. Operations run by other CLI invocations are not
+//! tracked there.
+
+use std::time::Duration;
+
+use anyhow::{Context, Result, bail};
+use chrono::{DateTime, Local};
+use gglib_core::jobs::Job;
+
+use crate::commands::JobsCommand;
+use crate::presentation::style;
+use crate::presentation::{OutputFormat, print_json, print_separator, truncate_string};
+
+/// Execute `gglib jobs` against the daemon on `port`.
+pub async fn execute(port: u16, command: Option<JobsCommand>, output: OutputFormat) -> Result<()> {
+    let base = format!("http://127.0.0.1:{port}/api/jobs");
+    let client = reqwest::Client::builder()
+        .timeout(Duration::from_secs(5))
+        .build()?;
+
+    match command {
+        None => {
+            let response = client.get(&base).send().await.with_context(|| {
+                format!("No gglib daemon on port {port} (start one with `gglib web`)")
+            })?;
+            let jobs: Vec<Job> = response.error_for_status()?.json().await?;
+            if output.is_json() {
+                return print_json(&jobs);
+            }
+            render_table(&jobs);
+        }
+        Some(JobsCommand::Cancel { id }) => {
+            let response = client
+                .post(format!("{base}/{id}/cancel"))
+                .send()
+                .await
+                .with_context(|| format!("No gglib daemon on port {port}"))?;
+            if !response.status().is_success() {
+                let status = response.status();
+                let body = response.text().await.unwrap_or_default();
+                bail!("Cancel failed ({status}): {body}");
+            }
+            let job: Job = response.json().await?;
+            if output.is_json() {
+                return print_json(&job);
+            }
+            println!(
+                "{}✓{} Cancel requested for {} ({})",
+                style::SUCCESS,
+                style::RESET,
+                job.label,
+                job.kind.as_str()
+            );
+        }
+    }
+    Ok(())
+}
+
+fn render_table(jobs: &[Job]) {
+    if jobs.is_empty() {
+        println!("No jobs.");
+        return;
+    }
+
+    println!(
+        "{:<36} {:<12} {:<36} {:<10} {:>5} {:<16}",
+        "ID", "Kind", "Label", "State", "%", "Started"
+    );
+    print_separator(120);
+    for job in jobs {
+        let percent = job
+            .progress
+            .percent()
+            .map_or_else(|| "-".to_string(), |p| p.to_string());
+        let state = if job.cancel_requested && !job.state.is_finished() {
+            "cancelling"
+        } else {
+            job.state.as_str()
+        };
+        println!(
+            "{:<36} {:<12} {:<36} {:<10} {:>5} {:<16}",
+            job.id,
+            job.kind.as_str(),
+            truncate_string(&job.label, 36),
+            state,
+            percent,
+            format_ms(job.started_at_ms),
+        );
+        if let Some(detail) = job.error.as_ref().or(job.progress.message.as_ref()) {
+            println!("  {}{}{}", style::MUTED, detail, style::RESET);
+        }
+    }
+}
+
+/// Format a millisecond Unix timestamp in local time.
+fn format_ms(ms: u64) -> String {
+    i64::try_from(ms)
+        .ok()
+        .and_then(DateTime::from_timestamp_millis)
+        .map(|t| t.with_timezone(&Local).format("%Y-%m-%d %H:%M").to_string())
+        .unwrap_or_else(|| "-".to_string())
+}
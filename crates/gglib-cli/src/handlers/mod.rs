@@ -8,23 +8,54 @@
 //! - [`config`]    — settings, llama management, assistant-ui, paths, dep checks
 //! - [`inference`] — serve, chat, question (shared resolve & logging)
 //! - [`model`]     — add, list, remove, update, download, verify, search, browse
+//! - [`pipeline`]  — run, list, show multi-step pipelines
 //!
 //! Top-level handlers for commands that stand alone:
 //! - [`gui`]       — Tauri desktop GUI launcher
 //! - [`web`]       — Axum web-server GUI launcher
+//! - [`daemon`]    — register `gglib web` as a per-user background service
+//! - [`doctor`]    — installation health checks with guided fixes
+//! - [`audit`]     — who changed models, settings, servers and downloads via the API
+//! - [`chat_import`] — import conversations from ChatGPT and Claude data exports
+//! - [`downloads_history`] — past download runs with per-model results and attempts
+//! - [`downloads_quarantine`] — list/clear downloads that failed validation
+//! - [`jobs`]      — list and cancel the daemon's long-running operations
+//! - [`recommend`] — hardware-aware model recommendations by task
+//! - [`sync`]      — pair with other instances and pull their missing models
+//! - [`backup`]    — push to and restore from an S3-compatible bucket
+//! - [`plugins`]   — install, list, remove sandboxed WASM tool plugins
 //! - [`proxy_dashboard`] — live terminal view of a running proxy's dashboard stream
+//! - [`proxy_moderation`] — recent moderation decisions of a running proxy
+//! - [`tui`]       — full-screen console: library, servers, downloads, chat
 
 pub mod agent_chat;
+pub mod audit;
+pub mod backup;
 pub mod benchmark;
+pub mod chat_import;
 pub mod completions;
 pub mod config;
 pub mod council;
+pub mod daemon;
+pub mod doctor;
+pub mod downloads_history;
+pub mod downloads_quarantine;
 pub mod gui;
 pub mod history;
 pub mod inference;
+pub mod jobs;
 pub mod mcp_cli;
 pub mod model;
+pub mod pipeline;
 pub mod plan;
+pub mod plugins;
 pub mod proxy_cache_clear;
 pub mod proxy_dashboard;
+pub mod proxy_moderation;
+pub mod recommend;
+pub mod stats;
+pub mod sync;
+pub mod template;
+pub mod trash;
+pub mod tui;
 pub mod web;
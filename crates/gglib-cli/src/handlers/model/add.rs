@@ -10,7 +10,9 @@ use crate::bootstrap::CliContext;
 use crate::presentation::{ModelSummaryOpts, display_model_summary};
 use crate::utils::input;
 
+use gglib_core::ports::ArchitectureSupportPort;
 use gglib_core::utils::validation;
+use gglib_runtime::llama::InstalledArchitectureSupport;
 
 /// Execute the add command.
 ///
@@ -57,6 +59,15 @@ pub async fn execute(ctx: &CliContext, file_path: &str) -> Result<()> {
         println!("  Context Length: {context}");
     }
 
+    // Warn before saving when the installed llama.cpp can't load the model
+    if let Some(ref arch) = gguf_metadata.architecture
+        && let Some(warning) = InstalledArchitectureSupport::new()
+            .check(arch)
+            .warning(arch)
+    {
+        println!("\n⚠️  {warning}");
+    }
+
     // Prompt for parameter count override (CLI-specific interactive UX)
     let param_count_override = if let Some(params) = gguf_metadata.param_count_b {
         let user_input =
@@ -70,11 +70,13 @@ fn apply_flag(req: &mut SetCapabilitiesRequest, flag: &str, value: bool) -> Resu
         "requires-strict-turns" => req.requires_strict_turns = Some(value),
         "supports-tool-calls" => req.supports_tool_calls = Some(value),
         "supports-reasoning" => req.supports_reasoning = Some(value),
+        "embedding" => req.embedding = Some(value),
+        "reranker" => req.reranker = Some(value),
         other => {
             return Err(anyhow!(
                 "Unknown capability flag '{other}'.\n\
                  Valid flags: supports-system-role, requires-strict-turns, \
-                 supports-tool-calls, supports-reasoning"
+                 supports-tool-calls, supports-reasoning, embedding, reranker"
             ));
         }
     }
@@ -100,6 +102,14 @@ fn print_capabilities(id: i64, name: &str, caps: ModelCapabilities) {
         "  supports-reasoning    : {}",
         flag_str(caps.contains(ModelCapabilities::SUPPORTS_REASONING))
     );
+    println!(
+        "  embedding             : {}",
+        flag_str(caps.contains(ModelCapabilities::EMBEDDING))
+    );
+    println!(
+        "  reranker              : {}",
+        flag_str(caps.contains(ModelCapabilities::RERANKER))
+    );
     if caps.is_empty() {
         println!("  (all flags unset — pass-through mode)");
     }
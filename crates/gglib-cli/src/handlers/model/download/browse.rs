@@ -3,7 +3,7 @@
 //! This command doesn't require AppCore - it's pure HF API calls.
 
 use anyhow::{Result, anyhow};
-use gglib_core::ports::huggingface::HfClientPort;
+use gglib_core::ports::ModelHubPort;
 use gglib_hf::{DefaultHfClient, HfClientConfig};
 
 /// Execute the browse command.
@@ -3,6 +3,18 @@
 //! Queues the initial model via [`DownloadManagerPort::queue_smart`] (the same
 //! path used by the GUI) and then delegates to [`interactive::run_interactive_monitor`]
 //! for progress rendering and optional interactive queue management.
+//! `--collection` queues every GGUF repo of a collection through
+//! [`DownloadManagerPort::queue_collection`] instead, and `--subdir` /
+//! `--filename` queue a [`DownloadRequest`] carrying a
+//! [`DestinationOverride`].
+//!
+//! With `warn_restrictive_licenses` on, a repo whose license forbids
+//! commercial use or limits it to research is only queued after the user
+//! confirms; `--yes` and JSON progress print the warning and go ahead.
+//!
+//! `--progress json` switches the emitter to NDJSON on stdout and runs the
+//! monitor non-interactively; failures become a [`CliError::Download`] whose
+//! exit code names the failure class.
 //!
 //! Model registration after download is handled internally by the download
 //! manager via the shared [`ModelRegistrarPort`], giving full parity with
@@ -11,9 +23,18 @@
 use std::sync::Arc;
 
 use anyhow::Result;
+use gglib_core::domain::{is_restrictive_license, license_from_hf_tags};
+use gglib_core::download::{
+    CollectionQueueResult, DestinationOverride, DownloadError, Quantization,
+};
+use gglib_core::ports::{DownloadRequest, ModelHub};
 use gglib_download::cli_exec::list_quantizations;
+use gglib_hf::hub_client;
 
 use crate::bootstrap::CliContext;
+use crate::error::CliError;
+use crate::model_commands::DownloadProgressFormat;
+use crate::utils::input;
 use gglib_core::paths::resolve_models_dir;
 
 use super::interactive;
@@ -21,7 +42,13 @@ use super::interactive;
 /// Download command arguments passed from CLI.
 pub struct DownloadArgs<'a> {
     pub model_id: &'a str,
+    /// HuggingFace collection slug; queues every GGUF repo in it instead of
+    /// `model_id`.
+    pub collection: Option<&'a str>,
     pub quantization: Option<&'a str>,
+    /// Where to store the files instead of the default layout; requires
+    /// `quantization`.
+    pub destination: Option<DestinationOverride>,
     pub list_quants: bool,
     pub force: bool,
     /// HuggingFace token for private models.
@@ -30,6 +57,10 @@ pub struct DownloadArgs<'a> {
     /// environment variable which is read at startup and wired into the
     /// download manager config, mirroring how the GUI handles authentication.
     pub token: Option<&'a str>,
+    /// Progress bars or NDJSON events on stdout.
+    pub progress: DownloadProgressFormat,
+    /// Never prompt or read keys (implied by JSON progress).
+    pub yes: bool,
 }
 
 /// Execute the download command.
@@ -37,26 +68,127 @@ pub struct DownloadArgs<'a> {
 /// Queues `model_id` via the shared [`DownloadManagerPort`] and enters the
 /// interactive monitor loop. The monitor exits when all queued downloads
 /// complete or the user presses `[q]`.
+///
+/// Returns a [`CliError::Download`] for the first failed download, or for
+/// a failure to queue at all, so the process exits with its class's code.
 pub async fn execute(ctx: &CliContext, args: DownloadArgs<'_>) -> Result<()> {
     let models_dir = resolve_models_dir(None)?.path;
 
     // --list-quants: show available quantizations and exit (uses cli_exec directly).
     if args.list_quants {
-        list_quantizations(args.model_id, &models_dir, args.token.map(String::from)).await?;
+        let token = args.token.map(String::from).or_else(|| ctx.hf_token());
+        list_quantizations(args.model_id, &models_dir, token).await?;
+        return Ok(());
+    }
+
+    let json = args.progress == DownloadProgressFormat::Json;
+    if json {
+        ctx.download_emitter.set_json_output(true);
+    }
+
+    if args.collection.is_none() && !accept_license(ctx, args.model_id, args.yes || json).await? {
+        println!("Download cancelled.");
         return Ok(());
     }
 
     // Queue the initial download via the shared manager (same code path as GUI).
     let quant = args.quantization.map(String::from);
-    Arc::clone(&ctx.downloads)
-        .queue_smart(args.model_id.to_string(), quant)
-        .await?;
+    if let Some(slug) = args.collection {
+        let result = Arc::clone(&ctx.downloads)
+            .queue_collection(slug.to_string(), quant)
+            .await
+            .map_err(CliError::from)?;
+        print_collection_result(&result, json);
+        if result.queued.is_empty() {
+            return Ok(());
+        }
+    } else if let Some(destination) = args.destination {
+        let quantization = quant.as_deref().unwrap_or_default();
+        let quantization = quantization
+            .parse::<Quantization>()
+            .map_err(|()| CliError::from(DownloadError::invalid_quantization(quantization)))?;
+        let request =
+            DownloadRequest::new(args.model_id, quantization).with_destination(destination);
+        Arc::clone(&ctx.downloads)
+            .queue_and_process(request)
+            .await
+            .map_err(CliError::from)?;
+    } else {
+        Arc::clone(&ctx.downloads)
+            .queue_smart(args.model_id.to_string(), quant)
+            .await
+            .map_err(CliError::from)?;
+    }
 
     // Hand off to the interactive monitor — all progress rendering, keypress
     // handling, TTY/non-TTY detection, and failure reporting live there.
-    interactive::run_interactive_monitor(
+    let failures = interactive::run_interactive_monitor(
         Arc::clone(&ctx.downloads),
         Arc::clone(&ctx.download_emitter),
+        !(args.yes || json),
     )
-    .await
+    .await?;
+
+    match failures.first() {
+        Some(failure) => Err(CliError::from_download_failure(&failure.error).into()),
+        None => Ok(()),
+    }
+}
+
+/// Whether to go ahead with downloading `model_id` as far as its license is
+/// concerned.
+///
+/// Only asks when `warn_restrictive_licenses` is on and the repo's license
+/// tag is restrictive. Without a prompt (`assume_yes`) the warning goes to
+/// stderr and the answer is yes. A repo whose info can't be fetched is let
+/// through: queueing reports the real error.
+async fn accept_license(ctx: &CliContext, model_id: &str, assume_yes: bool) -> Result<bool> {
+    let settings = ctx.app.settings().get().await?;
+    if settings.warn_restrictive_licenses != Some(true) {
+        return Ok(true);
+    }
+    let (hub, repo_id) = ModelHub::split(model_id);
+    let client = hub_client(hub, ctx.hf_token());
+    let Ok(info) = client.get_model_info(repo_id).await else {
+        return Ok(true);
+    };
+    let Some(license) =
+        license_from_hf_tags(&info.tags).filter(|license| is_restrictive_license(license))
+    else {
+        return Ok(true);
+    };
+
+    eprintln!("⚠ '{model_id}' is licensed under {license}, which restricts commercial use.");
+    if assume_yes {
+        return Ok(true);
+    }
+    input::prompt_confirmation("Download it anyway?")
+}
+
+/// Print which collection repos were queued and which were skipped.
+///
+/// Goes to stderr in JSON mode, where stdout carries only download events.
+fn print_collection_result(result: &CollectionQueueResult, json: bool) {
+    let print = |line: String| {
+        if json {
+            eprintln!("{line}");
+        } else {
+            println!("{line}");
+        }
+    };
+    print(format!(
+        "Collection '{}': queued {} of {} repos",
+        result.title,
+        result.queued.len(),
+        result.queued.len() + result.skipped.len()
+    ));
+    for repo in &result.queued {
+        print(format!(
+            "  + {} ({}, {} file(s))",
+            repo.repo_id, repo.quantization, repo.shard_count
+        ));
+    }
+    for repo in &result.skipped {
+        print(format!("  - {}: {}", repo.repo_id, repo.reason));
+    }
 }
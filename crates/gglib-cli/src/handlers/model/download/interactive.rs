@@ -9,7 +9,7 @@
 //! | Environment | Behaviour |
 //! |---|---|
 //! | TTY (normal terminal) | Single-keystroke `[a]` / `[q]` hotkeys via `console::Term` |
-//! | Non-TTY (CI, pipe)    | Plain 250 ms polling loop; exits when queue empties |
+//! | Non-TTY (CI, pipe), `--plain`, `--yes` or `--progress json` | Plain 250 ms polling loop; exits when queue empties |
 //!
 //! # Why `console`, not `crossterm` raw mode
 //!
@@ -59,7 +59,7 @@ use console::{Key, Term};
 use indicatif::{ProgressBar, ProgressStyle};
 use tokio::sync::mpsc;
 
-use gglib_core::download::QueueSnapshot;
+use gglib_core::download::{FailedDownload, QueueSnapshot};
 use gglib_core::ports::DownloadManagerPort;
 use gglib_download::CliDownloadEventEmitter;
 
@@ -68,14 +68,17 @@ use gglib_download::CliDownloadEventEmitter;
 /// Run the interactive download monitor.
 ///
 /// Blocks until all queued downloads complete, fail, or are cancelled.
-/// Failures encountered during the session are printed to stderr on exit.
+/// Failures encountered during the session are printed to stderr on exit
+/// and returned so the caller can pick an exit code.
 ///
+/// With `interactive` false the hotkeys are never offered, even on a TTY.
 /// The calling `execute()` handler simply awaits this future — all queue
 /// interaction and progress rendering is encapsulated here.
 pub async fn run_interactive_monitor(
     downloads: Arc<dyn DownloadManagerPort>,
     emitter: Arc<CliDownloadEventEmitter>,
-) -> Result<()> {
+    interactive: bool,
+) -> Result<Vec<FailedDownload>> {
     // Checks stderr, not stdout: the progress bars draw to stderr (indicatif's
     // `MultiProgress` default, used by `CliDownloadEventEmitter`), so that is
     // what decides whether we're actually interactive. Checking stdout would
@@ -83,7 +86,8 @@ pub async fn run_interactive_monitor(
     // hotkeys, since `console::Term::stderr()`'s own TTY check follows the same
     // stream — whenever stdout was redirected but the terminal remained
     // attached via stderr.
-    if std::io::stderr().is_terminal() {
+    // Plain mode has no bars to pin the `[a]`/`[q]` hint under.
+    if interactive && std::io::stderr().is_terminal() && !gglib_core::progress::is_plain() {
         run_tty_monitor(downloads, emitter).await
     } else {
         run_plain_monitor(downloads).await
@@ -94,15 +98,15 @@ pub async fn run_interactive_monitor(
 
 /// Plain polling loop for non-interactive environments (CI, pipes).
 ///
-/// indicatif degrades gracefully on non-TTY stdout, so progress bars
-/// still emit periodic lines. We just poll for completion.
+/// Progress is the emitter's business — bars, or status lines in plain
+/// mode, which non-TTY runs get automatically. We just poll for completion.
 ///
 /// The loop will not exit until it has observed at least one non-empty
 /// snapshot (`seen_items`), which prevents a premature exit caused by
 /// the Tokio runner task not yet being scheduled when the first poll
 /// fires. Fast-fail exits early if `recent_failures` appears before any
 /// items were ever seen active (e.g. instant auth error).
-async fn run_plain_monitor(downloads: Arc<dyn DownloadManagerPort>) -> Result<()> {
+async fn run_plain_monitor(downloads: Arc<dyn DownloadManagerPort>) -> Result<Vec<FailedDownload>> {
     // Brief initial yield so the async runner task can be scheduled and
     // move the queued item from pending → active before we first poll.
     tokio::time::sleep(Duration::from_millis(50)).await;
@@ -123,7 +127,7 @@ async fn run_plain_monitor(downloads: Arc<dyn DownloadManagerPort>) -> Result<()
 
         if (seen_items || has_failure) && is_queue_finished(&snapshot) {
             print_failures(&snapshot);
-            return Ok(());
+            return Ok(snapshot.recent_failures);
         }
     }
 }
@@ -146,7 +150,7 @@ enum ReaderCmd {
 async fn run_tty_monitor(
     downloads: Arc<dyn DownloadManagerPort>,
     emitter: Arc<CliDownloadEventEmitter>,
-) -> Result<()> {
+) -> Result<Vec<FailedDownload>> {
     let mp = emitter.multi_progress();
 
     // ── Channels ───────────────────────────────────────────────────────────
@@ -219,7 +223,7 @@ async fn run_tty_monitor(
     tick.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
     tick.tick().await;
 
-    let result: Result<()> = loop {
+    let result: Result<Vec<FailedDownload>> = loop {
         tokio::select! {
             // ── Keystroke from reader thread ───────────────────────────────
             maybe_key = key_rx.recv() => {
@@ -237,7 +241,7 @@ async fn run_tty_monitor(
                             // Second press → force quit.
                             downloads.cancel_all().await.ok();
                             let _ = cmd_tx.send(ReaderCmd::Stop).await;
-                            break Ok(());
+                            break Ok(Vec::new());
                         }
                         // First press → arm drain mode and update the hint.
                         quitting = true;
@@ -263,7 +267,7 @@ async fn run_tty_monitor(
                 if quitting {
                     downloads.cancel_all().await.ok();
                     let _ = cmd_tx.send(ReaderCmd::Stop).await;
-                    break Ok(());
+                    break Ok(Vec::new());
                 }
                 quitting = true;
                 hint_bar.set_message(
@@ -299,7 +303,7 @@ async fn run_tty_monitor(
                 if (seen_items || has_failure) && is_queue_finished(&snapshot) {
                     print_failures(&snapshot);
                     let _ = cmd_tx.send(ReaderCmd::Stop).await;
-                    break Ok(());
+                    break Ok(snapshot.recent_failures);
                 }
             }
         }
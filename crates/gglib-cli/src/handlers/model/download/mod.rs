@@ -16,5 +16,6 @@ mod update_model;
 pub use browse::execute as browse;
 pub use check_updates::execute as check_updates;
 pub use exec::{DownloadArgs, execute as download};
+pub(crate) use interactive::run_interactive_monitor;
 pub use search::execute as search;
 pub use update_model::execute as update_model;
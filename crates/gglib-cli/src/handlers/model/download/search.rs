@@ -1,19 +1,40 @@
-//! Search handler for HuggingFace Hub.
+//! Search handler for HuggingFace Hub and ModelScope.
 //!
-//! This command doesn't require AppCore - it's pure HF API calls.
+//! This command doesn't require AppCore - it's pure model hub API calls.
 
 use anyhow::{Result, anyhow};
-use gglib_core::ports::huggingface::HfClientPort;
-use gglib_hf::{DefaultHfClient, HfClientConfig};
+use gglib_core::ports::ModelHub;
+use gglib_core::ports::huggingface::HfRepoInfo;
+use gglib_hf::hub_client;
+use serde::Serialize;
+
+use crate::presentation::{OutputFormat, print_json};
+
+/// JSON row for `--output json`: the repository plus its quantizations.
+#[derive(Serialize)]
+struct SearchHit<'a> {
+    #[serde(flatten)]
+    repo: &'a HfRepoInfo,
+    quantizations: &'a [String],
+}
 
 /// Execute the search command.
 ///
-/// Searches HuggingFace Hub for models matching the query.
+/// Searches `hub` for models matching the query.
 /// No database access required.
-pub async fn execute(query: String, limit: u32, sort: String, gguf_only: bool) -> Result<()> {
-    println!("🔍 Searching HuggingFace Hub for: '{}'...", query);
+pub async fn execute(
+    query: String,
+    limit: u32,
+    sort: String,
+    gguf_only: bool,
+    hub: ModelHub,
+    output: OutputFormat,
+) -> Result<()> {
+    if !output.is_json() {
+        println!("🔍 Searching {} for: '{}'...", hub.display_name(), query);
+    }
 
-    let client = DefaultHfClient::new(&HfClientConfig::default());
+    let client = hub_client(hub, None);
 
     // Default to GGUF filtering unless explicitly disabled
     let filter_gguf = gguf_only;
@@ -71,6 +92,17 @@ pub async fn execute(query: String, limit: u32, sort: String, gguf_only: bool) -
         }
     }
 
+    if output.is_json() {
+        let hits: Vec<SearchHit> = filtered_models
+            .iter()
+            .map(|(repo, quantizations)| SearchHit {
+                repo,
+                quantizations,
+            })
+            .collect();
+        return print_json(&hits);
+    }
+
     if filtered_models.is_empty() {
         if filter_gguf {
             println!("No GGUF models found for query: '{}'", query);
@@ -91,7 +123,7 @@ pub async fn execute(query: String, limit: u32, sort: String, gguf_only: bool) -
         println!(
             " {}. {} (↓{} ❤{})",
             i + 1,
-            model.model_id,
+            hub.qualify(&model.model_id),
             format_number(model.downloads),
             model.likes
         );
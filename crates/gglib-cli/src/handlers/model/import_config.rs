@@ -0,0 +1,192 @@
+//! Import-config command handler.
+//!
+//! Reads another local-LLM manager's configuration — a llama-swap
+//! `config.yaml` or an Ollama `Modelfile` — with
+//! [`gglib_runtime::config_import`], adds each GGUF it names to the library
+//! and stores the launch flags it was served with as the model's server and
+//! sampling defaults. Files already in the library are left alone.
+
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use gglib_core::domain::{Model, ServerConfig};
+use gglib_runtime::config_import::{ConfigFormat, ImportedLaunch, parse_config};
+use serde::Serialize;
+
+use crate::bootstrap::CliContext;
+use crate::presentation::style;
+use crate::presentation::{OutputFormat, print_json};
+
+/// JSON row for `--output json`: what happened to one config entry.
+#[derive(Serialize)]
+struct ImportRow {
+    name: Option<String>,
+    file_path: Option<PathBuf>,
+    /// `added`, `exists`, `skipped` or `failed`; `would_add` with `--dry-run`.
+    status: &'static str,
+    id: Option<i64>,
+    detail: Option<String>,
+    ignored: Vec<String>,
+}
+
+/// Execute the import-config command.
+///
+/// `format` defaults to a guess from the file name (see
+/// [`ConfigFormat::detect`]).
+pub async fn execute(
+    ctx: &CliContext,
+    file: &Path,
+    format: Option<ConfigFormat>,
+    dry_run: bool,
+    output: OutputFormat,
+) -> Result<()> {
+    let format = format.unwrap_or_else(|| ConfigFormat::detect(file));
+    let text = std::fs::read_to_string(file)
+        .with_context(|| format!("Failed to read {}", file.display()))?;
+    let base_dir = file.parent().unwrap_or_else(|| Path::new("."));
+    let import = parse_config(format, &text, base_dir)
+        .with_context(|| format!("Failed to parse {} as a {format} config", file.display()))?;
+
+    let existing: HashSet<PathBuf> = ctx
+        .app
+        .models()
+        .list()
+        .await
+        .context("failed to list models")?
+        .into_iter()
+        .map(|m| m.file_path)
+        .collect();
+
+    let mut rows: Vec<ImportRow> = import
+        .skipped
+        .into_iter()
+        .map(|entry| ImportRow {
+            name: Some(entry.name),
+            file_path: None,
+            status: "skipped",
+            id: None,
+            detail: Some(entry.reason),
+            ignored: Vec::new(),
+        })
+        .collect();
+    for launch in import.launches {
+        let mut row = ImportRow {
+            name: launch.name.clone(),
+            file_path: Some(launch.file_path.clone()),
+            status: "added",
+            id: None,
+            detail: None,
+            ignored: launch.ignored.clone(),
+        };
+        if existing.contains(&launch.file_path) {
+            row.status = "exists";
+        } else if dry_run {
+            row.status = "would_add";
+        } else {
+            match add_launch(ctx, &launch).await {
+                Ok((model, warning)) => {
+                    row.name = Some(model.name);
+                    row.id = Some(model.id);
+                    row.detail = warning;
+                }
+                Err(e) => {
+                    row.status = "failed";
+                    row.detail = Some(format!("{e:#}"));
+                }
+            }
+        }
+        rows.push(row);
+    }
+
+    if output.is_json() {
+        return print_json(&rows);
+    }
+    print_rows(&rows, file, dry_run);
+    Ok(())
+}
+
+/// Add `launch`'s GGUF to the library and store its launch settings.
+///
+/// Returns the model and, when the settings could not be stored, why.
+async fn add_launch(ctx: &CliContext, launch: &ImportedLaunch) -> Result<(Model, Option<String>)> {
+    let models = ctx.app.models();
+    let mut model = models
+        .import_from_file(&launch.file_path, ctx.gguf_parser.as_ref(), None)
+        .await?;
+
+    if let Some(ref name) = launch.name {
+        model.name.clone_from(name);
+    }
+    // Imported flags take precedence over the defaults derived from the GGUF.
+    let initial = model.server_defaults.take().unwrap_or_default();
+    let server = ServerConfig {
+        context_length: launch.server.context_length.or(initial.context_length),
+        idle_shutdown_minutes: launch
+            .server
+            .idle_shutdown_minutes
+            .or(initial.idle_shutdown_minutes),
+        parallel: launch.server.parallel.or(initial.parallel),
+        cont_batching: launch.server.cont_batching.or(initial.cont_batching),
+    };
+    model.server_defaults = (server != ServerConfig::default()).then_some(server);
+    if launch.sampling != gglib_core::domain::InferenceConfig::default() {
+        model.inference_defaults = Some(launch.sampling.clone());
+    }
+
+    let warning = models
+        .update(&model)
+        .await
+        .err()
+        .map(|e| format!("added, but its launch settings were not kept: {e}"));
+    Ok((model, warning))
+}
+
+fn print_rows(rows: &[ImportRow], file: &Path, dry_run: bool) {
+    if rows.is_empty() {
+        println!("No models found in {}.", file.display());
+        return;
+    }
+    for row in rows {
+        let name = row.name.as_deref().unwrap_or("?");
+        let path = row
+            .file_path
+            .as_deref()
+            .map(|p| format!(" ({})", p.display()))
+            .unwrap_or_default();
+        match row.status {
+            "added" => println!(
+                "  {}✓{} [{}] {name}{path}",
+                style::SUCCESS,
+                style::RESET,
+                row.id.unwrap_or_default()
+            ),
+            "would_add" => println!("  + {name}{path}"),
+            "exists" => println!("  = {name}{path} — already in the library"),
+            status => println!(
+                "  {}✗{} {name}{path} — {status}: {}",
+                style::WARNING,
+                style::RESET,
+                row.detail.as_deref().unwrap_or_default()
+            ),
+        }
+        if row.status == "added"
+            && let Some(ref detail) = row.detail
+        {
+            println!("      {detail}");
+        }
+        if !row.ignored.is_empty() {
+            println!("      not imported: {}", row.ignored.join(" · "));
+        }
+    }
+
+    let added = rows
+        .iter()
+        .filter(|r| matches!(r.status, "added" | "would_add"))
+        .count();
+    if dry_run {
+        println!("Dry run: {added} model(s) would be added.");
+    } else {
+        println!("Done. {added} model(s) added.");
+    }
+}
@@ -10,8 +10,9 @@
 //!   filtered in-process via [`gglib_core::domain::apply_query`].
 //!
 //! Both paths produce a `Vec<GuiModel>` that is rendered by a single table
-//! function.  A speed column (`⚡ t/s`) is shown only when at least one
-//! returned model has benchmark data.
+//! function, or printed as a JSON array with `--output json`.  A speed column
+//! (`⚡ t/s`) is shown only when at least one returned model has benchmark
+//! data.
 
 use std::time::Duration;
 
@@ -21,7 +22,7 @@ use gglib_core::domain::{ModelListQuery, apply_query};
 
 use crate::bootstrap::CliContext;
 use crate::model_commands::{CliModelSortBy, CliSortOrder};
-use crate::presentation::{print_separator, truncate_string};
+use crate::presentation::{OutputFormat, print_json, print_separator, truncate_string};
 
 // ─────────────────────────────────────────────────────────────────────────────
 // Public surface
@@ -36,11 +37,15 @@ pub struct ListArgs {
     pub min_speed: Option<f64>,
     pub max_speed: Option<f64>,
     pub tags: Vec<String>,
+    pub licenses: Vec<String>,
 }
 
 /// Execute the list command.
-pub async fn execute(ctx: &CliContext, args: ListArgs) -> Result<()> {
+pub async fn execute(ctx: &CliContext, args: ListArgs, output: OutputFormat) -> Result<()> {
     let models = fetch_models(ctx, &args).await?;
+    if output.is_json() {
+        return print_json(&models);
+    }
 
     if models.is_empty() {
         println!("No models found.");
@@ -91,6 +96,9 @@ async fn fetch_from_daemon(port: u16, args: &ListArgs) -> Result<Vec<GuiModel>>
     if !args.tags.is_empty() {
         url.push_str(&format!("&tags={}", args.tags.join(",")));
     }
+    if !args.licenses.is_empty() {
+        url.push_str(&format!("&licenses={}", args.licenses.join(",")));
+    }
 
     let client = reqwest::Client::builder()
         .timeout(Duration::from_secs(5))
@@ -113,6 +121,11 @@ fn build_query(args: &ListArgs) -> ModelListQuery {
         } else {
             Some(args.tags.clone())
         },
+        licenses: if args.licenses.is_empty() {
+            None
+        } else {
+            Some(args.licenses.clone())
+        },
         ..Default::default()
     }
 }
@@ -159,21 +172,22 @@ fn render_table(models: &[GuiModel]) {
 
     if show_speed {
         println!(
-            "{:<3} {:<25} {:<8} {:<10} {:<12} {:<8} {:<10} {:<20} File Path",
-            "ID", "Name", "Params", "⚡ t/s", "Arch", "Quant", "Context", "Added"
+            "{:<3} {:<25} {:<8} {:<10} {:<12} {:<8} {:<10} {:<14} {:<20} File Path",
+            "ID", "Name", "Params", "⚡ t/s", "Arch", "Quant", "Context", "License", "Added"
         );
-        print_separator(128);
+        print_separator(143);
     } else {
         println!(
-            "{:<3} {:<25} {:<8} {:<12} {:<8} {:<10} {:<20} File Path",
-            "ID", "Name", "Params", "Arch", "Quant", "Context", "Added"
+            "{:<3} {:<25} {:<8} {:<12} {:<8} {:<10} {:<14} {:<20} File Path",
+            "ID", "Name", "Params", "Arch", "Quant", "Context", "License", "Added"
         );
-        print_separator(115);
+        print_separator(130);
     }
 
     for model in models {
         let arch = model.architecture.as_deref().unwrap_or("--");
         let quant = model.quantization.as_deref().unwrap_or("--");
+        let license = model.license.as_deref().unwrap_or("--");
         let context = model
             .context_length
             .map(|c| c.to_string())
@@ -187,7 +201,7 @@ fn render_table(models: &[GuiModel]) {
                 .map(|t| format!("{t:.1}"))
                 .unwrap_or_else(|| "--".to_string());
             println!(
-                "{:<3} {:<25} {:<8.1} {:<10} {:<12} {:<8} {:<10} {:<20} {}",
+                "{:<3} {:<25} {:<8.1} {:<10} {:<12} {:<8} {:<10} {:<14} {:<20} {}",
                 model.id,
                 truncate_string(&model.name, 24),
                 model.param_count_b,
@@ -195,18 +209,20 @@ fn render_table(models: &[GuiModel]) {
                 truncate_string(arch, 11),
                 truncate_string(quant, 7),
                 truncate_string(&context, 9),
+                truncate_string(license, 13),
                 model.added_at,
                 model.file_path,
             );
         } else {
             println!(
-                "{:<3} {:<25} {:<8.1} {:<12} {:<8} {:<10} {:<20} {}",
+                "{:<3} {:<25} {:<8.1} {:<12} {:<8} {:<10} {:<14} {:<20} {}",
                 model.id,
                 truncate_string(&model.name, 24),
                 model.param_count_b,
                 truncate_string(arch, 11),
                 truncate_string(quant, 7),
                 truncate_string(&context, 9),
+                truncate_string(license, 13),
                 model.added_at,
                 model.file_path,
             );
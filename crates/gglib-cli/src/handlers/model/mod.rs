@@ -2,8 +2,10 @@
 pub mod add;
 pub mod capabilities;
 pub mod download;
+pub mod import_config;
 pub mod inspect;
 pub mod list;
+pub mod redetect;
 pub mod remove;
 pub mod resolver;
 pub mod retag;
@@ -11,16 +13,28 @@ pub mod update;
 pub mod verification;
 
 use anyhow::Result;
+use gglib_core::download::DestinationOverride;
 
 use crate::bootstrap::CliContext;
 use crate::model_commands::ModelCommand;
+use crate::presentation::OutputFormat;
 
 /// Dispatch a `model` subcommand to its handler.
-pub async fn dispatch(ctx: &CliContext, command: ModelCommand) -> Result<()> {
+///
+/// `output` (the global `--output` flag) applies to `list`, `search` and
+/// `import-config`.
+pub async fn dispatch(ctx: &CliContext, command: ModelCommand, output: OutputFormat) -> Result<()> {
     match command {
         ModelCommand::Add { file_path } => {
             add::execute(ctx, &file_path).await?;
         }
+        ModelCommand::ImportConfig {
+            file,
+            format,
+            dry_run,
+        } => {
+            import_config::execute(ctx, &file, format.map(Into::into), dry_run, output).await?;
+        }
         ModelCommand::List {
             sort,
             order,
@@ -29,6 +43,7 @@ pub async fn dispatch(ctx: &CliContext, command: ModelCommand) -> Result<()> {
             min_speed,
             max_speed,
             tags,
+            licenses,
         } => {
             list::execute(
                 ctx,
@@ -40,7 +55,9 @@ pub async fn dispatch(ctx: &CliContext, command: ModelCommand) -> Result<()> {
                     min_speed,
                     max_speed,
                     tags,
+                    licenses,
                 },
+                output,
             )
             .await?;
         }
@@ -54,6 +71,7 @@ pub async fn dispatch(ctx: &CliContext, command: ModelCommand) -> Result<()> {
             architecture,
             quantization,
             context_length,
+            default_ctx_size,
             metadata,
             remove_metadata,
             replace_metadata,
@@ -75,6 +93,7 @@ pub async fn dispatch(ctx: &CliContext, command: ModelCommand) -> Result<()> {
                 architecture,
                 quantization,
                 context_length,
+                default_ctx_size,
                 metadata,
                 remove_metadata,
                 replace_metadata,
@@ -98,6 +117,13 @@ pub async fn dispatch(ctx: &CliContext, command: ModelCommand) -> Result<()> {
         } => {
             retag::execute(ctx, identifier, all, full).await?;
         }
+        ModelCommand::Redetect {
+            identifier,
+            all,
+            force,
+        } => {
+            redetect::execute(ctx, identifier, all, force).await?;
+        }
         ModelCommand::Verify {
             identifier,
             verbose,
@@ -114,17 +140,30 @@ pub async fn dispatch(ctx: &CliContext, command: ModelCommand) -> Result<()> {
         ModelCommand::Download {
             model_id,
             quantization,
+            collection,
+            subdir,
+            filename,
             list_quants,
             skip_db: _skip_db,
             token,
             force,
+            progress,
+            yes,
         } => {
             let args = download::DownloadArgs {
-                model_id: &model_id,
+                model_id: model_id.as_deref().unwrap_or_default(),
+                collection: collection.as_deref(),
                 quantization: quantization.as_deref(),
+                destination: Some(DestinationOverride {
+                    subdirectory: subdir,
+                    filename,
+                })
+                .filter(|d| !d.is_empty()),
                 list_quants,
                 force,
                 token: token.as_deref(),
+                progress,
+                yes,
             };
             download::download(ctx, args).await?;
         }
@@ -142,8 +181,9 @@ pub async fn dispatch(ctx: &CliContext, command: ModelCommand) -> Result<()> {
             limit,
             sort,
             gguf_only,
+            hub,
         } => {
-            download::search(query, limit, sort, gguf_only).await?;
+            download::search(query, limit, sort, gguf_only, hub, output).await?;
         }
         ModelCommand::Browse {
             category,
@@ -0,0 +1,91 @@
+//! Redetect command handler.
+//!
+//! Re-runs capability detection (system role, strict turns, tool calling,
+//! reasoning, embedding/reranker) for models from their persisted GGUF
+//! metadata, replacing the stored flags and stamping the current detector
+//! version. The daemon does this in the background for models an older
+//! detector classified; this is the manual way to run it.
+//!
+//! With no arguments only those outdated models are re-detected. Flags set
+//! by hand (`gglib model capabilities`) are skipped unless `--force` is
+//! given.
+
+use anyhow::{Context, Result};
+use gglib_core::ModelCapabilities;
+use gglib_core::services::RedetectOutcome;
+
+use crate::bootstrap::CliContext;
+
+/// Execute the redetect command.
+pub async fn execute(
+    ctx: &CliContext,
+    identifier: Option<String>,
+    all: bool,
+    force: bool,
+) -> Result<()> {
+    let models = ctx.app.models();
+    let parser = ctx.gguf_parser.as_ref();
+
+    let targets = if let Some(id) = identifier {
+        let m = models
+            .find_by_identifier(&id)
+            .await
+            .context("failed to look up model")?;
+        vec![m]
+    } else if all {
+        models.list().await.context("failed to list models")?
+    } else {
+        models
+            .outdated_capabilities()
+            .await
+            .context("failed to list models")?
+    }
+    .into_iter()
+    .map(|m| (m.id, m.name))
+    .collect::<Vec<_>>();
+
+    if targets.is_empty() {
+        println!("All models were detected by the current detector.");
+        return Ok(());
+    }
+
+    println!(
+        "Re-detecting capabilities of {} model(s) ...",
+        targets.len()
+    );
+
+    let mut total_changed = 0usize;
+    for (id, name) in targets {
+        match models.redetect_capabilities(id, parser, force).await {
+            Ok(RedetectOutcome::Changed { before, after }) => {
+                total_changed += 1;
+                println!(
+                    "  [{id}] {name} — {} → {}",
+                    flag_names(before),
+                    flag_names(after)
+                );
+            }
+            Ok(RedetectOutcome::Unchanged) => {
+                println!("  [{id}] {name} — unchanged");
+            }
+            Ok(RedetectOutcome::Manual) => {
+                println!("  [{id}] {name} — set by hand, skipped (use --force)");
+            }
+            Err(e) => {
+                eprintln!("  [{id}] {name} — FAILED: {e}");
+            }
+        }
+    }
+
+    println!("Done. {total_changed} model(s) changed.");
+    Ok(())
+}
+
+fn flag_names(caps: ModelCapabilities) -> String {
+    let names: Vec<_> = caps.iter_names().map(|(n, _)| n).collect();
+    if names.is_empty() {
+        "(none)".to_string()
+    } else {
+        names.join(", ")
+    }
+}
@@ -1,7 +1,9 @@
 //! Remove command handler.
 //!
-//! Removes a GGUF model from the database. The actual model file
-//! remains on disk unchanged - only the database entry is removed.
+//! Removes a GGUF model. Its files move to the trash under the models
+//! directory, from where `gglib trash restore` brings it back until the
+//! scheduled purge deletes it; without a trash only the database entry is
+//! removed and the file stays where it is.
 
 use anyhow::Result;
 
@@ -13,7 +15,7 @@ use crate::utils::input;
 ///
 /// Searches for a model matching the provided identifier (ID or name),
 /// confirms the deletion with the user (unless force flag is used),
-/// and removes the model entry from the database.
+/// and moves the model to the trash.
 ///
 /// # Arguments
 ///
@@ -30,7 +32,7 @@ use crate::utils::input;
 /// This function will return an error if:
 /// - Model not found
 /// - User input fails
-/// - Database removal operation fails
+/// - Moving the files to the trash or the database removal fails
 pub async fn execute(ctx: &CliContext, identifier: &str, force: bool) -> Result<()> {
     // First, try to find the model to show it to the user
     let model = match ctx.app.models().get(identifier).await? {
@@ -46,16 +48,28 @@ pub async fn execute(ctx: &CliContext, identifier: &str, force: bool) -> Result<
         display_model_summary(&model, ModelSummaryOpts::for_removal());
         println!();
 
-        let confirm = input::prompt_confirmation(
-            "Are you sure you want to remove this model from the database?",
-        )?;
+        let confirm = input::prompt_confirmation("Are you sure you want to remove this model?")?;
         if !confirm {
             println!("Remove operation cancelled.");
             return Ok(());
         }
     }
 
-    // Remove the model
+    if let Some(trash) = ctx.app.trash() {
+        let entry = trash.trash(&model).await?;
+        println!(
+            "✓ Model '{}' (ID {}) moved to the trash.",
+            model.name, model.id
+        );
+        println!(
+            "Restore it with 'gglib trash restore {}' before {}.",
+            model.id,
+            entry.purge_after.format("%Y-%m-%d")
+        );
+        return Ok(());
+    }
+
+    // No trash configured: remove the database entry only
     let removed = ctx.app.models().remove(identifier).await?;
 
     println!(
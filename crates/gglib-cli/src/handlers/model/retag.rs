@@ -7,7 +7,8 @@
 //!
 //! Default behaviour is additive: missing tags are appended, nothing is
 //! removed. `--full` drops and re-derives the entire auto-generated
-//! namespace while still preserving user-curated tags.
+//! namespace while still preserving user-curated tags, and re-derives the
+//! embedding/reranker capability flags.
 
 use anyhow::{Context, Result};
 
@@ -63,6 +64,15 @@ pub async fn execute(
                 if !diff.removed.is_empty() {
                     println!("  [{id}] {name} — removed: {}", diff.removed.join(", "));
                 }
+                if let Some(caps) = diff.capabilities {
+                    let names: Vec<_> = caps.iter_names().map(|(n, _)| n).collect();
+                    let names = if names.is_empty() {
+                        "(none)".to_string()
+                    } else {
+                        names.join(", ")
+                    };
+                    println!("  [{id}] {name} — capabilities: {names}");
+                }
             }
             Err(e) => {
                 eprintln!("  [{id}] {name} — FAILED: {e}");
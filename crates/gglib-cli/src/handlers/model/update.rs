@@ -19,6 +19,7 @@ pub struct UpdateArgs {
     pub architecture: Option<String>,
     pub quantization: Option<String>,
     pub context_length: Option<u64>,
+    pub default_ctx_size: Option<usize>,
     pub metadata: Vec<String>,
     pub remove_metadata: Option<String>,
     pub replace_metadata: bool,
@@ -164,6 +165,12 @@ pub fn create_updated_model(
     if let Some(context_length) = args.context_length {
         updated.context_length = Some(context_length);
     }
+    if let Some(ctx_size) = args.default_ctx_size {
+        updated
+            .server_defaults
+            .get_or_insert_with(Default::default)
+            .context_length = Some(ctx_size);
+    }
 
     // Handle metadata updates
     if args.replace_metadata {
@@ -256,6 +263,11 @@ fn show_changes_preview(existing: &Model, updated: &Model) {
         &format_option_u64(&existing.context_length),
         &format_option_u64(&updated.context_length),
     );
+    show_field_change(
+        "Default Context",
+        &format_option_u64(&default_ctx(existing)),
+        &format_option_u64(&default_ctx(updated)),
+    );
 
     // Show metadata changes
     show_metadata_changes(&existing.metadata, &updated.metadata);
@@ -418,6 +430,11 @@ fn format_option(opt: &Option<String>) -> String {
     opt.as_deref().unwrap_or("--").to_string()
 }
 
+fn default_ctx(model: &Model) -> Option<u64> {
+    let ctx = model.server_defaults.as_ref()?.context_length?;
+    Some(ctx as u64)
+}
+
 fn format_option_u64(opt: &Option<u64>) -> String {
     opt.map(|v| v.to_string())
         .unwrap_or_else(|| "--".to_string())
@@ -480,10 +497,13 @@ mod tests {
             hf_filename: None,
             download_date: None,
             capabilities: gglib_core::ModelCapabilities::default(),
+            capabilities_version: None,
             last_update_check: None,
+            license: None,
             tags: Vec::new(),
             server_defaults: None,
             benchmark_summary: None,
+            card: None,
         }
     }
 
@@ -532,6 +552,7 @@ mod tests {
             architecture: Some("mistral".to_string()),
             quantization: None,
             context_length: Some(8192),
+            default_ctx_size: Some(4096),
             metadata: vec!["new.key=new.value".to_string()],
             remove_metadata: Some("test.key".to_string()),
             replace_metadata: false,
@@ -558,6 +579,7 @@ mod tests {
         assert_eq!(updated.architecture, Some("mistral".to_string()));
         assert_eq!(updated.quantization, Some("Q4_0".to_string())); // Unchanged
         assert_eq!(updated.context_length, Some(8192));
+        assert_eq!(default_ctx(&updated), Some(4096));
         assert!(updated.metadata.contains_key("new.key"));
         assert!(!updated.metadata.contains_key("test.key")); // Removed
     }
@@ -0,0 +1,52 @@
+//! `gglib pipeline list` — list past pipeline runs.
+
+use anyhow::{Result, anyhow};
+
+use crate::bootstrap::CliContext;
+use crate::presentation::{OutputFormat, print_json, style, tables};
+
+/// List past runs, most recent first.
+pub async fn execute(ctx: &CliContext, output: OutputFormat) -> Result<()> {
+    let runs = ctx
+        .app
+        .pipelines()
+        .list_runs()
+        .await
+        .map_err(|e| anyhow!("failed to list pipeline runs: {e}"))?;
+
+    if output.is_json() {
+        return print_json(&runs);
+    }
+
+    if runs.is_empty() {
+        eprintln!("{}No pipeline runs found.{}", style::DIM, style::RESET);
+        return Ok(());
+    }
+
+    println!(
+        "{}{:<6}  {:<10}  {:<7}  {:<12}  NAME{}",
+        style::BOLD,
+        "ID",
+        "STATUS",
+        "STEPS",
+        "CREATED",
+        style::RESET
+    );
+    tables::print_separator(80);
+
+    for run in &runs {
+        let steps = format!("{}/{}", run.steps.len(), run.definition.steps.len());
+        println!(
+            "{:<6}  {}{:<10}{}  {:<7}  {:<12}  {}",
+            run.id,
+            super::status_color(run.status),
+            run.status.as_str(),
+            style::RESET,
+            steps,
+            tables::format_relative_time(&run.created_at),
+            tables::truncate_string(&run.name, 40),
+        );
+    }
+
+    Ok(())
+}
@@ -0,0 +1,19 @@
+#![doc = include_str!("README.md")]
+pub mod list;
+pub mod run;
+pub mod show;
+
+use gglib_core::domain::pipeline::PipelineRunStatus;
+
+use crate::presentation::style;
+
+/// Map a [`PipelineRunStatus`] to a stable ANSI colour constant.
+///
+/// Shared by [`list`] and [`show`].
+pub(crate) fn status_color(status: PipelineRunStatus) -> &'static str {
+    match status {
+        PipelineRunStatus::Running => style::INFO,
+        PipelineRunStatus::Completed => style::SUCCESS,
+        PipelineRunStatus::Failed => style::DANGER,
+    }
+}
@@ -0,0 +1,292 @@
+//! `gglib pipeline run <file>` — run a pipeline definition to completion.
+//!
+//! The definition is read as YAML, which also accepts JSON. Progress goes to
+//! stderr and the last step's output to stdout, so the result can be piped;
+//! `--json` prints the stored run instead. Every step's output is persisted
+//! and can be inspected later with `gglib pipeline show <id>`.
+
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::sync::Arc;
+
+use anyhow::{Context as _, Result, anyhow};
+use async_trait::async_trait;
+use tokio::sync::Mutex;
+
+use gglib_agent::pipeline::{PipelineModels, PipelineProgress};
+use gglib_core::ProcessHandle;
+use gglib_core::domain::InferenceConfig;
+use gglib_core::domain::pipeline::{PipelineDefinition, PipelineRunStatus};
+use gglib_core::ports::{LlmCompletionPort, ModelCatalogPort, ProcessRunner};
+use gglib_core::server_config::parse_ctx_size_flag;
+use gglib_core::services::AppCore;
+use gglib_runtime::server_config::{ServerConfigOptions, build_server_config};
+use gglib_runtime::{EndpointPipelineModels, compose_pipeline_runner};
+
+use crate::bootstrap::CliContext;
+use crate::presentation::{print_json, style};
+
+/// Run the pipeline defined in `file`.
+///
+/// `inputs` are `name=value` pairs for `{{input.name}}` placeholders. Exits
+/// with an error when a step fails, after printing which one.
+pub async fn execute(
+    ctx: &CliContext,
+    file: &Path,
+    inputs: &[String],
+    port: Option<u16>,
+    ctx_size: Option<String>,
+    json: bool,
+) -> Result<()> {
+    let text = std::fs::read_to_string(file)
+        .with_context(|| format!("failed to read {}", file.display()))?;
+    let definition: PipelineDefinition = serde_yaml_ng::from_str(&text)
+        .with_context(|| format!("invalid pipeline definition in {}", file.display()))?;
+    let inputs = parse_inputs(inputs)?;
+
+    if let Err(e) = ctx.mcp.initialize().await {
+        tracing::warn!("MCP initialisation failed: {e}");
+    }
+
+    let models = Arc::new(CliPipelineModels::new(ctx, port, ctx_size));
+    let runner = compose_pipeline_runner(
+        Arc::clone(&models) as Arc<dyn PipelineModels>,
+        Arc::clone(&ctx.mcp),
+        std::env::current_dir().ok(),
+        ctx.app.pipelines().repo(),
+    );
+
+    if !json {
+        style::print_info_banner("Pipeline", "\u{1f517}");
+        eprintln!(
+            "  {} \u{2014} {} step(s)",
+            definition.name,
+            definition.steps.len()
+        );
+        style::print_banner_close();
+    }
+
+    let result = runner
+        .run(definition, inputs, |progress| {
+            if !json {
+                render_progress(&progress);
+            }
+        })
+        .await;
+    models.shutdown().await;
+    let run = result.map_err(|e| anyhow!("{e}"))?;
+
+    if json {
+        print_json(&run)?;
+    } else if run.status == PipelineRunStatus::Completed
+        && let Some(output) = run.output()
+    {
+        eprintln!();
+        println!("{output}");
+    }
+
+    match run.status {
+        PipelineRunStatus::Failed => Err(anyhow!(
+            "{} (run {})",
+            run.error.as_deref().unwrap_or("pipeline failed"),
+            run.id
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// Parse repeated `--input name=value` flags; a later value wins.
+fn parse_inputs(pairs: &[String]) -> Result<BTreeMap<String, String>> {
+    pairs
+        .iter()
+        .map(|pair| {
+            pair.split_once('=')
+                .map(|(name, value)| (name.trim().to_string(), value.to_string()))
+                .ok_or_else(|| anyhow!("--input must be NAME=VALUE, got: {pair}"))
+        })
+        .collect()
+}
+
+fn render_progress(progress: &PipelineProgress) {
+    match progress {
+        PipelineProgress::RunStarted { run_id } => {
+            eprintln!("{}  Run {run_id}{}", style::DIM, style::RESET);
+        }
+        PipelineProgress::StepStarted { step_id, model } => match model {
+            Some(model) => eprintln!(
+                "{}\u{25b6}{} {step_id} {}({model}){}",
+                style::INFO,
+                style::RESET,
+                style::DIM,
+                style::RESET
+            ),
+            None => eprintln!("{}\u{25b6}{} {step_id}", style::INFO, style::RESET),
+        },
+        PipelineProgress::StepCompleted { step_id, output } => {
+            eprintln!(
+                "{}\u{2713}{} {step_id} {}({} chars){}",
+                style::SUCCESS,
+                style::RESET,
+                style::DIM,
+                output.chars().count(),
+                style::RESET
+            );
+        }
+        PipelineProgress::StepFailed { step_id, error } => {
+            eprintln!(
+                "{}\u{2717} {step_id}: {error}{}",
+                style::DANGER,
+                style::RESET
+            );
+        }
+    }
+}
+
+// ─── Model source ───────────────────────────────────────────────────────────
+
+/// Serves each prompt step from a local llama-server.
+///
+/// With `--port` every step goes to that server. Otherwise one server is
+/// auto-started and kept while consecutive steps use the same model; a step
+/// naming a different model stops it and starts one for the new model.
+struct CliPipelineModels {
+    app: Arc<AppCore>,
+    runner: Arc<dyn ProcessRunner>,
+    catalog: Arc<dyn ModelCatalogPort>,
+    http_client: reqwest::Client,
+    base_port: u16,
+    port: Option<u16>,
+    ctx_size: Option<String>,
+    /// The auto-started server and the model name it was started for.
+    server: Mutex<Option<(Option<String>, ProcessHandle)>>,
+}
+
+impl CliPipelineModels {
+    fn new(ctx: &CliContext, port: Option<u16>, ctx_size: Option<String>) -> Self {
+        Self {
+            app: Arc::clone(&ctx.app),
+            runner: Arc::clone(&ctx.runner),
+            catalog: Arc::clone(&ctx.catalog),
+            http_client: ctx.http_client.clone(),
+            base_port: ctx.base_port,
+            port,
+            ctx_size,
+            server: Mutex::new(None),
+        }
+    }
+
+    /// Port of a server for `model`, starting or swapping one when needed.
+    async fn server_port(&self, model: Option<&str>) -> Result<u16> {
+        let mut server = self.server.lock().await;
+        if let Some((name, handle)) = server.as_ref()
+            && name.as_deref() == model
+        {
+            return Ok(handle.port);
+        }
+        if let Some((_, handle)) = server.take() {
+            self.stop(&handle).await;
+        }
+        let handle = self.start(model).await?;
+        let port = handle.port;
+        *server = Some((model.map(str::to_owned), handle));
+        Ok(port)
+    }
+
+    async fn start(&self, model: Option<&str>) -> Result<ProcessHandle> {
+        let model = match model {
+            Some(name) => self
+                .app
+                .models()
+                .find_by_identifier(name)
+                .await
+                .with_context(|| format!("failed to look up model '{name}'"))?,
+            None => {
+                let settings = self
+                    .app
+                    .settings()
+                    .get()
+                    .await
+                    .map_err(|e| anyhow!("failed to load settings: {e}"))?;
+                let default_id = settings.default_model_id.ok_or_else(|| {
+                    anyhow!(
+                        "The pipeline names no model and no default model is set.\n\
+                         Add `model:` to the pipeline or set a default:\n  \
+                         gglib config default <name>"
+                    )
+                })?;
+                self.app
+                    .models()
+                    .get_by_id(default_id)
+                    .await
+                    .map_err(|e| anyhow!("failed to load default model: {e}"))?
+                    .ok_or_else(|| anyhow!("default model (ID: {default_id}) not found"))?
+            }
+        };
+
+        let settings = self.app.settings().get().await.unwrap_or_default();
+        let ctx_arg = parse_ctx_size_flag(self.ctx_size.as_deref())?;
+        let server_config = build_server_config(
+            model.id,
+            model.name.clone(),
+            model.file_path.clone(),
+            self.base_port,
+            &model.tags,
+            ServerConfigOptions {
+                context_size: ctx_arg.and_then(|arg| arg.resolve(model.context_length)),
+                model_server_ctx: model
+                    .server_defaults
+                    .as_ref()
+                    .and_then(|s| s.context_length),
+                global_default_ctx: settings.default_context_size,
+                ..Default::default()
+            },
+        );
+
+        eprintln!(
+            "{}  Starting llama-server for '{}' \u{2026}{}",
+            style::DIM,
+            model.name,
+            style::RESET
+        );
+        self.runner
+            .start(server_config)
+            .await
+            .context("failed to start llama-server")
+    }
+
+    async fn stop(&self, handle: &ProcessHandle) {
+        if let Err(e) = self.runner.stop(handle).await {
+            tracing::warn!("failed to stop llama-server: {e}");
+        }
+    }
+
+    /// Stop the auto-started server, if any.
+    async fn shutdown(&self) {
+        if let Some((_, handle)) = self.server.lock().await.take() {
+            self.stop(&handle).await;
+        }
+    }
+}
+
+#[async_trait]
+impl PipelineModels for CliPipelineModels {
+    async fn llm(
+        &self,
+        model: Option<&str>,
+        sampling: Option<InferenceConfig>,
+    ) -> Result<Arc<dyn LlmCompletionPort>> {
+        let port = match self.port {
+            Some(port) => port,
+            None => self.server_port(model).await?,
+        };
+        EndpointPipelineModels::new(
+            format!("http://127.0.0.1:{port}"),
+            self.http_client.clone(),
+            Arc::clone(&self.catalog),
+            // No proxy dashboard in the CLI process — nowhere to report reuse.
+            None,
+        )
+        .llm(model, sampling)
+        .await
+    }
+}
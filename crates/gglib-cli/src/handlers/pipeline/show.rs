@@ -0,0 +1,61 @@
+//! `gglib pipeline show <run-id>` — inspect a pipeline run.
+//!
+//! Prints run metadata and the inputs to stderr, then each completed step's
+//! output to stdout in order.
+
+use anyhow::{Result, anyhow};
+
+use crate::bootstrap::CliContext;
+use crate::presentation::{OutputFormat, print_json, style, tables};
+
+/// Print full details for run `run_id`.
+pub async fn execute(ctx: &CliContext, run_id: i64, output: OutputFormat) -> Result<()> {
+    let run = ctx
+        .app
+        .pipelines()
+        .get_run(run_id)
+        .await
+        .map_err(|e| anyhow!("failed to load pipeline run: {e}"))?;
+
+    if output.is_json() {
+        return print_json(&run);
+    }
+
+    style::print_info_banner("Pipeline run", "\u{1f50d}");
+    eprintln!("  {}ID:{}      {}", style::BOLD, style::RESET, run.id);
+    eprintln!("  {}Name:{}    {}", style::BOLD, style::RESET, run.name);
+    eprintln!(
+        "  {}Status:{}  {}{}{}",
+        style::BOLD,
+        style::RESET,
+        super::status_color(run.status),
+        run.status.as_str(),
+        style::RESET
+    );
+    eprintln!(
+        "  {}Created:{} {} (updated {})",
+        style::BOLD,
+        style::RESET,
+        tables::format_relative_time(&run.created_at),
+        tables::format_relative_time(&run.updated_at)
+    );
+    for (name, value) in &run.inputs {
+        eprintln!(
+            "  {}input.{name}:{} {}",
+            style::BOLD,
+            style::RESET,
+            tables::truncate_string(value, 60)
+        );
+    }
+    if let Some(error) = &run.error {
+        eprintln!("  {}Error:{}   {error}", style::BOLD, style::RESET);
+    }
+    style::print_banner_close();
+
+    for step in &run.steps {
+        eprintln!("\n{}─── {} ───{}", style::BOLD, step.step_id, style::RESET);
+        println!("{}", step.output);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,116 @@
+//! WASM plugin management command handlers.
+//!
+//! All handlers delegate to `PluginStore` in `gglib-plugins` — no business
+//! logic lives here, only CLI input parsing and output formatting. Agents
+//! pick installed plugins up the next time their tools are composed.
+
+use anyhow::{Result, anyhow};
+use gglib_core::paths::plugins_dir;
+use gglib_plugins::{InstallOptions, PluginLimits, PluginStore, qualified_tool_name};
+
+use crate::plugin_commands::PluginsCommand;
+use crate::presentation::{OutputFormat, print_json, print_separator, style, truncate_string};
+
+/// Dispatch a plugins subcommand to its handler.
+pub fn dispatch(cmd: PluginsCommand, output: OutputFormat) -> Result<()> {
+    let store = PluginStore::new(plugins_dir().map_err(|e| anyhow!("{e}"))?);
+    match cmd {
+        PluginsCommand::Install {
+            file,
+            name,
+            memory_mb,
+            fuel,
+            timeout_ms,
+            force,
+        } => {
+            let manifest = store.install(
+                &file,
+                InstallOptions {
+                    name,
+                    limits: PluginLimits {
+                        memory_mb,
+                        fuel,
+                        timeout_ms,
+                    },
+                    force,
+                },
+            )?;
+            if output.is_json() {
+                return print_json(&manifest);
+            }
+            println!(
+                "{}\u{2713}{} Installed plugin '{}' with {} tool(s):",
+                style::SUCCESS,
+                style::RESET,
+                manifest.name,
+                manifest.tools.len()
+            );
+            for tool in &manifest.tools {
+                println!("  {}", qualified_tool_name(&manifest.name, &tool.name));
+            }
+            Ok(())
+        }
+        PluginsCommand::List => list(&store, output),
+        PluginsCommand::Remove { name } => {
+            let manifest = store.remove(&name)?;
+            println!(
+                "{}\u{2713}{} Removed plugin '{}'",
+                style::SUCCESS,
+                style::RESET,
+                manifest.name
+            );
+            Ok(())
+        }
+    }
+}
+
+fn list(store: &PluginStore, output: OutputFormat) -> Result<()> {
+    let plugins = store.list()?;
+    if output.is_json() {
+        return print_json(&plugins);
+    }
+
+    if plugins.is_empty() {
+        println!("No plugins installed.");
+        println!("Use 'gglib plugins install <file.wasm>' to add one.");
+        return Ok(());
+    }
+
+    println!(
+        "Found {} plugin(s) in {}:\n",
+        plugins.len(),
+        store.root().display()
+    );
+    println!(
+        "{:<20} {:<6} {:<9} {:<14} {:<10}",
+        "Name", "Tools", "Memory", "Fuel", "Timeout"
+    );
+    print_separator(63);
+    for plugin in &plugins {
+        println!(
+            "{:<20} {:<6} {:<9} {:<14} {:<10}",
+            truncate_string(&plugin.name, 19),
+            plugin.tools.len(),
+            format!("{} MiB", plugin.limits.memory_mb),
+            plugin.limits.fuel,
+            format!("{} ms", plugin.limits.timeout_ms),
+        );
+        for tool in &plugin.tools {
+            println!(
+                "  {}{}{} {}",
+                style::DIM,
+                qualified_tool_name(&plugin.name, &tool.name),
+                style::RESET,
+                truncate_string(tool.description.as_deref().unwrap_or_default(), 50)
+            );
+        }
+    }
+    if !gglib_plugins::WASM_SUPPORTED {
+        println!(
+            "\n{}This build has no WASM support; plugins are not loaded.{}",
+            style::DIM,
+            style::RESET
+        );
+    }
+    Ok(())
+}
@@ -50,6 +50,8 @@ use crossterm::{cursor, execute, terminal};
 use futures_util::StreamExt;
 use serde::Deserialize;
 
+use crate::presentation::NumberFormat;
+
 /// Width (in bar cells) of every progress bar drawn by this dashboard.
 const BAR_WIDTH: usize = 20;
 
@@ -441,18 +443,10 @@ fn ram_budget_label(cache: &CacheStatus) -> Option<String> {
     }
 }
 
-/// Format an integer with `,` thousands separators, so six-figure token
-/// counts stay readable in a dense terminal frame.
+/// Format an integer with the locale's thousands separators, so six-figure
+/// token counts stay readable in a dense terminal frame.
 fn thousands(value: u64) -> String {
-    let digits = value.to_string();
-    let mut out = String::with_capacity(digits.len() + digits.len() / 3);
-    for (i, ch) in digits.chars().enumerate() {
-        if i > 0 && (digits.len() - i).is_multiple_of(3) {
-            out.push(',');
-        }
-        out.push(ch);
-    }
-    out
+    NumberFormat::current().integer(value)
 }
 
 /// Truncate to at most `max_chars` characters, appending `…` when cut short.
@@ -0,0 +1,70 @@
+//! `gglib proxy moderation` — review a running proxy's moderation decisions.
+//!
+//! Fetches `GET /v1/proxy/moderation` from a running `gglib proxy` (or
+//! `gglib web`) instance and prints one line per decision, newest first.
+//! As with [`crate::handlers::proxy_dashboard`], the response is read through
+//! a local `Deserialize` mirror rather than the `gglib-proxy` type.
+
+use anyhow::{Context, Result};
+use serde::Deserialize;
+
+/// Body of `GET /v1/proxy/moderation`.
+#[derive(Debug, Deserialize)]
+struct ModerationLog {
+    decisions: Vec<Decision>,
+}
+
+/// One recorded decision, mirroring `gglib_proxy::moderation::ModerationDecision`.
+#[derive(Debug, Deserialize)]
+struct Decision {
+    request_id: String,
+    model: String,
+    stage: String,
+    source: String,
+    action: String,
+    applied: bool,
+    excerpt: String,
+}
+
+/// Print the proxy's recent moderation decisions.
+pub async fn execute(host: &str, port: u16) -> Result<()> {
+    let url = format!("http://{}:{}/v1/proxy/moderation", host, port);
+    let response = reqwest::Client::new()
+        .get(&url)
+        .send()
+        .await
+        .context(format!(
+            "Failed to connect to proxy at {} — is it running?",
+            url
+        ))?
+        .error_for_status()?;
+    let log: ModerationLog = response
+        .json()
+        .await
+        .context("Unexpected response from the moderation endpoint")?;
+
+    if log.decisions.is_empty() {
+        println!("No moderation decisions recorded.");
+        return Ok(());
+    }
+    for d in &log.decisions {
+        let applied = if d.applied { "" } else { " (not applied)" };
+        println!(
+            "{}  {}  {} {} by {}{}",
+            d.request_id, d.model, d.stage, d.action, d.source, applied
+        );
+        println!("    {}", d.excerpt.replace('\n', " "));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn execute_unreachable_proxy_returns_error() {
+        let result = execute("127.0.0.1", 59998).await;
+        assert!(result.is_err(), "Expected error when proxy is unreachable");
+    }
+}
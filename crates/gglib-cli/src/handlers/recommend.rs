@@ -0,0 +1,73 @@
+//! `gglib recommend` — models that fit this machine, ranked by task.
+//!
+//! Reads GPU memory (or system RAM) from the system probe and ranks the
+//! built-in curated catalog against it; no database or network access.
+
+use anyhow::Result;
+use gglib_core::domain::recommend::{ModelTask, RecommendTarget, builtin_catalog, recommend};
+use gglib_core::ports::SystemProbePort;
+
+use crate::presentation::{
+    OutputFormat, format_bytes, format_decimal, print_json, print_separator, truncate_string,
+};
+use crate::tr;
+
+const GIB: f64 = 1024.0 * 1024.0 * 1024.0;
+
+/// Execute `gglib recommend`; `--output json` prints the full report.
+pub fn execute(
+    probe: &dyn SystemProbePort,
+    task: Option<ModelTask>,
+    limit: usize,
+    output: OutputFormat,
+) -> Result<()> {
+    let memory = probe.get_system_memory_info();
+    let report = recommend(&builtin_catalog(), task, &memory, limit);
+    if output.is_json() {
+        return print_json(&report);
+    }
+
+    let source = match report.target {
+        RecommendTarget::Gpu => tr!("recommend-source-gpu"),
+        RecommendTarget::Cpu => tr!("recommend-source-cpu"),
+    };
+    println!(
+        "{}",
+        tr!(
+            "recommend-budget",
+            size = format_bytes(report.budget_bytes),
+            source = source,
+        )
+    );
+
+    if report.items.is_empty() {
+        println!("\n{}", tr!("recommend-none-fit"));
+        return Ok(());
+    }
+
+    println!(
+        "\n{:<45} {:>7} {:<8} {:>9} {:<20}",
+        "Repository", "Params", "Quant", "Est. GiB", "Tasks"
+    );
+    print_separator(93);
+    for item in &report.items {
+        let tasks: Vec<&str> = item.tasks.iter().map(|t| t.as_str()).collect();
+        println!(
+            "{:<45} {:>6}B {:<8} {:>9} {:<20}",
+            truncate_string(&item.repo_id, 44),
+            format_decimal(item.params_b, 1),
+            item.quantization,
+            format_decimal(item.estimated_bytes as f64 / GIB, 1),
+            tasks.join(", "),
+        );
+    }
+
+    if let Some(top) = report.items.first() {
+        let command = format!(
+            "gglib model download {} --quantization {}",
+            top.repo_id, top.quantization
+        );
+        println!("\n{}", tr!("recommend-download-hint", command = command));
+    }
+    Ok(())
+}
@@ -0,0 +1,149 @@
+//! CLI handler for `gglib stats` — per-model usage.
+//!
+//! Usage is counted by the proxy and the server runtime and stored as daily
+//! aggregates; `gglib stats models` ranks the whole library by it through
+//! [`gglib_core::services::ModelUsageService`], so models that are never
+//! used show up at the bottom, and `gglib stats daily` sums it per day.
+//! Both show the estimated energy of the time servers spent on requests,
+//! and its cost when a price is configured.
+
+use anyhow::{Result, anyhow};
+
+use gglib_core::domain::{EnergyEstimate, EnergyRate};
+
+use crate::bootstrap::CliContext;
+use crate::presentation::style;
+use crate::presentation::{OutputFormat, print_json, print_separator, truncate_string};
+use crate::stats_commands::StatsCommand;
+
+/// Dispatch a `gglib stats` subcommand.
+pub async fn dispatch(ctx: &CliContext, command: StatsCommand, output: OutputFormat) -> Result<()> {
+    let usage = ctx
+        .app
+        .usage()
+        .ok_or_else(|| anyhow!("Model usage tracking is not available"))?;
+    match command {
+        StatsCommand::Models { days } => {
+            let ranking = usage.ranking(days).await?;
+            if output.is_json() {
+                return print_json(&ranking);
+            }
+            if ranking.is_empty() {
+                println!("No models in the library.");
+                return Ok(());
+            }
+            let rate = usage.rate().await?;
+
+            println!(
+                "{:<6} {:<40} {:>9} {:>12} {:>8} {:>10} {:>10} {:<10}",
+                "ID", "Name", "Requests", "Tokens", "Hours", "Energy", "Cost", "Last used"
+            );
+            print_separator(112);
+            for model in &ranking {
+                println!(
+                    "{:<6} {:<40} {:>9} {:>12} {:>8.1} {:>10} {:>10} {:<10}",
+                    model.model_id,
+                    truncate_string(&model.name, 40),
+                    model.requests,
+                    model.total_tokens(),
+                    model.serve_hours,
+                    format_energy(&model.energy),
+                    format_cost(&model.energy),
+                    model
+                        .last_used
+                        .map_or_else(|| "never".to_string(), |day| day.to_string()),
+                );
+            }
+            let period = days.map_or_else(
+                || "all recorded usage".to_string(),
+                |days| format!("the last {days} day(s)"),
+            );
+            println!(
+                "\n{}Over {period}. {}{}",
+                style::MUTED,
+                describe_rate(&rate),
+                style::RESET
+            );
+            Ok(())
+        }
+        StatsCommand::Daily { days } => {
+            let summary = usage.daily(days).await?;
+            if output.is_json() {
+                return print_json(&summary);
+            }
+            if summary.is_empty() {
+                println!("No usage recorded in the last {days} day(s).");
+                return Ok(());
+            }
+            let rate = usage.rate().await?;
+
+            println!(
+                "{:<10} {:>9} {:>12} {:>12} {:>10} {:>10} {:>10}",
+                "Day", "Requests", "Prompt", "Completion", "Busy (h)", "Energy", "Cost"
+            );
+            print_separator(80);
+            for day in &summary {
+                println!(
+                    "{:<10} {:>9} {:>12} {:>12} {:>10.2} {:>10} {:>10}",
+                    day.day.to_string(),
+                    day.requests,
+                    day.prompt_tokens,
+                    day.completion_tokens,
+                    day.busy_hours,
+                    format_energy(&day.energy),
+                    format_cost(&day.energy),
+                );
+            }
+            let total = EnergyEstimate {
+                watt_hours: summary.iter().map(|d| d.energy.watt_hours).sum(),
+                cost: summary.iter().map(|d| d.energy.cost).sum(),
+            };
+            print_separator(80);
+            println!(
+                "{:<10} {:>9} {:>12} {:>12} {:>10.2} {:>10} {:>10}",
+                "Total",
+                summary.iter().map(|d| d.requests).sum::<u64>(),
+                summary.iter().map(|d| d.prompt_tokens).sum::<u64>(),
+                summary.iter().map(|d| d.completion_tokens).sum::<u64>(),
+                summary.iter().map(|d| d.busy_hours).sum::<f64>(),
+                format_energy(&total),
+                format_cost(&total),
+            );
+            println!("\n{}{}{}", style::MUTED, describe_rate(&rate), style::RESET);
+            Ok(())
+        }
+    }
+}
+
+/// Energy in Wh, or kWh from 1000 Wh up.
+fn format_energy(estimate: &EnergyEstimate) -> String {
+    if estimate.watt_hours >= 1000.0 {
+        format!("{:.2} kWh", estimate.watt_hours / 1000.0)
+    } else {
+        format!("{:.1} Wh", estimate.watt_hours)
+    }
+}
+
+fn format_cost(estimate: &EnergyEstimate) -> String {
+    estimate
+        .cost
+        .map_or_else(|| "-".to_string(), |cost| format!("{cost:.2}"))
+}
+
+/// One line saying what the estimates assume, and how to change it.
+fn describe_rate(rate: &EnergyRate) -> String {
+    let watts = if rate.detected {
+        format!(
+            "{:.0} W assumed for the detected hardware (set --energy-watts to override)",
+            rate.watts
+        )
+    } else {
+        format!("{:.0} W configured", rate.watts)
+    };
+    let price = match (rate.price_per_kwh, rate.currency.as_deref()) {
+        (Some(price), Some(currency)) => format!("{price} {currency} per kWh"),
+        (Some(price), None) => format!("{price} per kWh"),
+        (None, _) => "no price set (--energy-price-per-kwh)".to_string(),
+    };
+    format!("Energy estimated at {watts}; {price}.")
+}
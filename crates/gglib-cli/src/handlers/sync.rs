@@ -0,0 +1,144 @@
+//! `gglib sync` — pair with other gglib instances and pull their models.
+//!
+//! Delegates to [`SyncOps`], the same operations the web API exposes. A
+//! pull queues every missing model on the shared download manager and then
+//! hands off to the interactive download monitor, so peer transfers render
+//! exactly like `gglib model download`.
+
+use std::sync::Arc;
+
+use anyhow::Result;
+use gglib_app_services::types::PairPeerRequest;
+use gglib_app_services::{SyncDeps, SyncOps};
+use gglib_core::domain::PairRequest;
+
+use crate::bootstrap::CliContext;
+use crate::commands::SyncCommand;
+use crate::error::CliError;
+use crate::handlers::model::download::run_interactive_monitor;
+use crate::presentation::{
+    OutputFormat, format_bytes, print_json, print_separator, truncate_string,
+};
+
+/// Execute a `gglib sync` subcommand.
+///
+/// With `--output json`, `list`, `pull --dry-run` and `pull` print their
+/// results as JSON.
+pub async fn execute(ctx: &CliContext, command: SyncCommand, output: OutputFormat) -> Result<()> {
+    let ops = SyncOps::new(SyncDeps {
+        core: Arc::clone(&ctx.app),
+        downloads: Arc::clone(&ctx.downloads),
+        http: ctx.http_client.clone(),
+    });
+
+    match command {
+        SyncCommand::Pair {
+            name,
+            url,
+            token,
+            pair_back,
+            share_token,
+            pair_back_name,
+        } => {
+            let pair_back = match (pair_back, share_token, pair_back_name) {
+                (Some(url), Some(token), Some(name)) => Some(PairRequest { name, url, token }),
+                _ => None,
+            };
+            let paired_back = pair_back.is_some();
+            let peer = ops
+                .pair(PairPeerRequest {
+                    name,
+                    url,
+                    token,
+                    pair_back,
+                })
+                .await?;
+            println!("Paired with '{}' at {}.", peer.name, peer.url);
+            if paired_back {
+                println!("'{}' can now pull from this instance too.", peer.name);
+            }
+            println!("Pull its models with: gglib sync pull {}", peer.name);
+        }
+        SyncCommand::List => {
+            let peers = ops.peers().await?;
+            if output.is_json() {
+                return print_json(&peers);
+            }
+            if peers.is_empty() {
+                println!(
+                    "No paired instances. Pair with: gglib sync pair <NAME> <URL> --token <TOKEN>"
+                );
+                return Ok(());
+            }
+            println!("{:<24} {:<50}", "Name", "URL");
+            print_separator(75);
+            for peer in &peers {
+                println!(
+                    "{:<24} {:<50}",
+                    truncate_string(&peer.name, 23),
+                    truncate_string(&peer.url, 50)
+                );
+            }
+        }
+        SyncCommand::Unpair { name } => {
+            ops.unpair(&name).await?;
+            println!("Unpaired '{name}'.");
+        }
+        SyncCommand::Pull {
+            peer,
+            dry_run: true,
+            ..
+        } => {
+            let missing = ops.diff(&peer).await?;
+            if output.is_json() {
+                return print_json(&missing);
+            }
+            if missing.is_empty() {
+                println!("This library already has every model '{peer}' has.");
+                return Ok(());
+            }
+            println!("{:<45} {:>10} {:<12}", "Name", "Size", "Quant");
+            print_separator(69);
+            for entry in &missing {
+                println!(
+                    "{:<45} {:>10} {:<12}",
+                    truncate_string(&entry.name, 44),
+                    format_bytes(entry.size),
+                    entry.quantization.as_deref().unwrap_or("-"),
+                );
+            }
+            println!("\nDownload them with: gglib sync pull {peer}");
+        }
+        SyncCommand::Pull { peer, yes, .. } => {
+            let result = ops.pull(&peer).await?;
+            if output.is_json() {
+                print_json(&result)?;
+            } else {
+                println!(
+                    "'{}': {} model(s) queued, {} already present",
+                    result.peer,
+                    result.queued.len(),
+                    result.already_present
+                );
+                for skipped in &result.skipped {
+                    println!("  - {}: {}", skipped.name, skipped.reason);
+                }
+            }
+            if result.queued.is_empty() {
+                return Ok(());
+            }
+
+            let failures = run_interactive_monitor(
+                Arc::clone(&ctx.downloads),
+                Arc::clone(&ctx.download_emitter),
+                !(yes || output.is_json()),
+            )
+            .await?;
+            if let Some(failure) = failures.first() {
+                return Err(CliError::from_download_failure(&failure.error).into());
+            }
+        }
+    }
+
+    Ok(())
+}
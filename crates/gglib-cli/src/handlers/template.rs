@@ -0,0 +1,116 @@
+//! CLI handler for `gglib template test` — check a model's chat template.
+//!
+//! Renders the golden conversations of
+//! [`gglib_core::domain::template_check`] through the model's llama-server
+//! and prints a pass/fail line per conversation with the anomalies found.
+//! A llama-server is started automatically when `--port` is omitted.
+
+use anyhow::{Context as _, Result, bail};
+
+use gglib_core::ProcessHandle;
+use gglib_core::domain::Model;
+use gglib_core::domain::template_check::BosPolicy;
+use gglib_runtime::prompt_preview::PromptPreviewer;
+use gglib_runtime::server_config::{ServerConfigOptions, build_server_config};
+
+use crate::bootstrap::CliContext;
+use crate::presentation::style::{self, DANGER, DIM, MUTED, RESET, SUCCESS, WARNING};
+use crate::template_commands::TemplateCommand;
+
+/// Dispatch a `gglib template` subcommand.
+pub async fn dispatch(ctx: &CliContext, command: TemplateCommand) -> Result<()> {
+    match command {
+        TemplateCommand::Test {
+            model,
+            port,
+            show_prompts,
+        } => test(ctx, &model, port, show_prompts).await,
+    }
+}
+
+/// Run `gglib template test <model>`.
+async fn test(ctx: &CliContext, identifier: &str, port: Option<u16>, show: bool) -> Result<()> {
+    let model = ctx
+        .app
+        .models()
+        .find_by_identifier(identifier)
+        .await
+        .context("failed to look up model")?;
+
+    if !model.metadata.contains_key("tokenizer.chat_template") {
+        eprintln!(
+            "{WARNING}!{RESET} '{}' has no chat template in its GGUF; llama-server falls back \
+             to a built-in format, which may not be the one the model was trained on.",
+            model.name
+        );
+    }
+
+    let (port, handle) = match port {
+        Some(p) => (p, None),
+        None => {
+            let h = start_server(ctx, &model).await?;
+            (h.port, Some(h))
+        }
+    };
+
+    let checks = PromptPreviewer::new(port)
+        .check_template(BosPolicy::from_metadata(&model.metadata))
+        .await;
+
+    if let Some(h) = handle
+        && let Err(e) = ctx.runner.stop(&h).await
+    {
+        tracing::warn!("failed to stop llama-server: {e}");
+    }
+
+    for check in &checks {
+        if check.passed() {
+            println!("{SUCCESS}✓{RESET} {}", check.conversation);
+        } else {
+            println!("{DANGER}✗{RESET} {}", check.conversation);
+            for anomaly in &check.anomalies {
+                println!("    {MUTED}-{RESET} {anomaly}");
+            }
+        }
+        if show && let Some(prompt) = &check.prompt {
+            println!("{DIM}{prompt}{RESET}");
+        }
+    }
+
+    let failed = checks.iter().filter(|c| !c.passed()).count();
+    if failed > 0 {
+        bail!(
+            "{failed} of {} conversations rendered with anomalies",
+            checks.len()
+        );
+    }
+    Ok(())
+}
+
+async fn start_server(ctx: &CliContext, model: &Model) -> Result<ProcessHandle> {
+    let settings = ctx.app.settings().get().await.unwrap_or_default();
+    let server_config = build_server_config(
+        model.id,
+        model.name.clone(),
+        model.file_path.clone(),
+        ctx.base_port,
+        &model.tags,
+        ServerConfigOptions {
+            model_server_ctx: model
+                .server_defaults
+                .as_ref()
+                .and_then(|s| s.context_length),
+            global_default_ctx: settings.default_context_size,
+            ..Default::default()
+        },
+    );
+
+    style::print_info_banner("Template", "\u{1f9ea}");
+    eprintln!("  Starting llama-server for '{}' \u{2026}", model.name);
+    style::print_banner_close();
+
+    ctx.runner
+        .start(server_config)
+        .await
+        .context("failed to start llama-server")
+}
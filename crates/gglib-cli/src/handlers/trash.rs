@@ -0,0 +1,111 @@
+//! CLI handler for `gglib trash` — models removed with `gglib model remove`.
+//!
+//! Removed models wait under `<models_dir>/trash/` until the retention
+//! period or the size cap purges them; this handler lists, restores and
+//! empties them through [`gglib_core::services::ModelTrashService`].
+
+use anyhow::{Result, anyhow};
+use chrono::Local;
+
+use gglib_core::domain::trash::TrashEntry;
+use gglib_core::services::ModelTrashService;
+
+use crate::bootstrap::CliContext;
+use crate::presentation::style;
+use crate::presentation::{
+    OutputFormat, format_bytes, print_json, print_separator, truncate_string,
+};
+use crate::trash_commands::TrashCommand;
+use crate::utils::input;
+
+/// Dispatch a `gglib trash` subcommand.
+pub async fn dispatch(ctx: &CliContext, command: TrashCommand, output: OutputFormat) -> Result<()> {
+    let trash = ctx
+        .app
+        .trash()
+        .ok_or_else(|| anyhow!("The model trash is not available"))?;
+    match command {
+        TrashCommand::List => list(trash, output).await,
+        TrashCommand::Restore { id } => {
+            let model = trash.restore(id).await?;
+            println!(
+                "{}✓{} Restored '{}' to {}",
+                style::SUCCESS,
+                style::RESET,
+                model.name,
+                model.file_path.display()
+            );
+            Ok(())
+        }
+        TrashCommand::Empty { force } => empty(trash, force).await,
+    }
+}
+
+async fn list(trash: &ModelTrashService, output: OutputFormat) -> Result<()> {
+    let entries = trash.list().await?;
+    if output.is_json() {
+        return print_json(&entries);
+    }
+    if entries.is_empty() {
+        println!("The trash is empty.");
+        return Ok(());
+    }
+
+    println!(
+        "{:<6} {:<40} {:>10} {:<16} {:<10}",
+        "ID", "Name", "Size", "Removed", "Purge after"
+    );
+    print_separator(86);
+    for entry in &entries {
+        println!(
+            "{:<6} {:<40} {:>10} {:<16} {:<10}",
+            entry.model_id,
+            truncate_string(&entry.name, 40),
+            format_bytes(entry.size_bytes),
+            entry
+                .deleted_at
+                .with_timezone(&Local)
+                .format("%Y-%m-%d %H:%M"),
+            entry.purge_after.with_timezone(&Local).format("%Y-%m-%d"),
+        );
+    }
+    println!(
+        "\n{}Total: {}{}",
+        style::MUTED,
+        format_bytes(total_bytes(&entries)),
+        style::RESET
+    );
+    Ok(())
+}
+
+async fn empty(trash: &ModelTrashService, force: bool) -> Result<()> {
+    let entries = trash.list().await?;
+    if entries.is_empty() {
+        println!("The trash is empty.");
+        return Ok(());
+    }
+    if !force
+        && !input::prompt_confirmation(&format!(
+            "Permanently delete {} model(s) ({})?",
+            entries.len(),
+            format_bytes(total_bytes(&entries))
+        ))?
+    {
+        println!("Cancelled.");
+        return Ok(());
+    }
+
+    let deleted = trash.empty().await?;
+    println!(
+        "{}✓{} Deleted {} model(s), freeing {}",
+        style::SUCCESS,
+        style::RESET,
+        deleted.len(),
+        format_bytes(total_bytes(&deleted))
+    );
+    Ok(())
+}
+
+fn total_bytes(entries: &[TrashEntry]) -> u64 {
+    entries.iter().map(|e| e.size_bytes).sum()
+}
@@ -0,0 +1,428 @@
+//! Console state and key handling, independent of the terminal.
+//!
+//! [`App`] holds the last refreshed snapshots (library, servers, download
+//! queue) plus the chat transcript. Key presses are turned into [`Action`]s
+//! for the event loop to run against the backend, so everything here can be
+//! exercised without a terminal or a running server.
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+use gglib_app_services::types::GuiModel;
+use gglib_core::domain::agent::{AgentMessage, AssistantContent};
+use gglib_core::download::{FailedDownload, QueuedDownload};
+
+/// The four panes, in tab order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Pane {
+    Library,
+    Servers,
+    Downloads,
+    Chat,
+}
+
+impl Pane {
+    pub const ALL: [Self; 4] = [Self::Library, Self::Servers, Self::Downloads, Self::Chat];
+
+    pub const fn title(self) -> &'static str {
+        match self {
+            Self::Library => "Library",
+            Self::Servers => "Servers",
+            Self::Downloads => "Downloads",
+            Self::Chat => "Chat",
+        }
+    }
+
+    const fn index(self) -> usize {
+        match self {
+            Self::Library => 0,
+            Self::Servers => 1,
+            Self::Downloads => 2,
+            Self::Chat => 3,
+        }
+    }
+
+    fn next(self) -> Self {
+        Self::ALL[(self.index() + 1) % Self::ALL.len()]
+    }
+
+    fn previous(self) -> Self {
+        Self::ALL[(self.index() + Self::ALL.len() - 1) % Self::ALL.len()]
+    }
+}
+
+/// A llama-server managed by this console, with its last health check.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ServerRow {
+    pub model_id: i64,
+    pub model_name: String,
+    pub port: u16,
+    pub healthy: bool,
+    pub context_size: Option<u64>,
+    /// Why the server is unhealthy, when known.
+    pub message: Option<String>,
+}
+
+/// Server the chat pane talks to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChatTarget {
+    pub model_id: i64,
+    pub model_name: String,
+    pub port: u16,
+}
+
+/// Work for the event loop; [`App`] never touches the backend itself.
+#[derive(Debug, Clone)]
+pub enum Action {
+    Quit,
+    StartServer(i64),
+    StopServer(i64),
+    CancelDownload(String),
+    /// Queue `repo[:quant]` typed into the download prompt.
+    QueueDownload {
+        repo_id: String,
+        quantization: Option<String>,
+    },
+    /// Stream a reply to the full transcript from `target`.
+    SendChat {
+        target: ChatTarget,
+        messages: Vec<AgentMessage>,
+    },
+}
+
+/// Line editor currently capturing keys.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Prompt {
+    /// Chat message for the selected server.
+    Chat,
+    /// `repo[:quant]` to download.
+    Download,
+}
+
+/// Console state.
+#[derive(Debug)]
+pub struct App {
+    pub pane: Pane,
+    pub models: Vec<GuiModel>,
+    pub servers: Vec<ServerRow>,
+    pub downloads: Vec<QueuedDownload>,
+    pub failed_downloads: Vec<FailedDownload>,
+    /// Selected row in the library, servers and downloads panes.
+    selected: [usize; 3],
+    pub chat_target: Option<ChatTarget>,
+    pub transcript: Vec<AgentMessage>,
+    /// Reply being streamed, appended to the transcript when done.
+    pub reply: Option<String>,
+    pub prompt: Option<Prompt>,
+    pub input: String,
+    /// Last notice or error, shown in the footer.
+    pub status: String,
+}
+
+impl Default for App {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl App {
+    pub fn new() -> Self {
+        Self {
+            pane: Pane::Library,
+            models: Vec::new(),
+            servers: Vec::new(),
+            downloads: Vec::new(),
+            failed_downloads: Vec::new(),
+            selected: [0; 3],
+            chat_target: None,
+            transcript: Vec::new(),
+            reply: None,
+            prompt: None,
+            input: String::new(),
+            status: String::from("Tab: switch pane · ↑/↓: select · q: quit"),
+        }
+    }
+
+    /// Selected row of `pane`, clamped to its current length.
+    pub fn selected(&self, pane: Pane) -> Option<usize> {
+        let len = self.len(pane);
+        let index = *self.selected.get(pane.index())?;
+        (len > 0).then(|| index.min(len - 1))
+    }
+
+    fn len(&self, pane: Pane) -> usize {
+        match pane {
+            Pane::Library => self.models.len(),
+            Pane::Servers => self.servers.len(),
+            Pane::Downloads => self.downloads.len(),
+            Pane::Chat => 0,
+        }
+    }
+
+    fn move_selection(&mut self, down: bool) {
+        let len = self.len(self.pane);
+        let Some(slot) = self.selected.get_mut(self.pane.index()) else {
+            return;
+        };
+        if len == 0 {
+            *slot = 0;
+        } else if down {
+            *slot = (*slot + 1).min(len - 1);
+        } else {
+            *slot = (*slot).min(len - 1).saturating_sub(1);
+        }
+    }
+
+    /// Replace the server list, dropping the chat target if it stopped.
+    pub fn set_servers(&mut self, servers: Vec<ServerRow>) {
+        if let Some(target) = &self.chat_target
+            && !servers.iter().any(|s| s.model_id == target.model_id)
+        {
+            self.chat_target = None;
+        }
+        self.servers = servers;
+    }
+
+    /// Append a streamed fragment to the reply in progress.
+    pub fn push_reply(&mut self, fragment: &str) {
+        self.reply
+            .get_or_insert_with(String::new)
+            .push_str(fragment);
+    }
+
+    /// Move the finished reply into the transcript.
+    pub fn finish_reply(&mut self) {
+        if let Some(text) = self.reply.take() {
+            self.transcript.push(AgentMessage::Assistant {
+                content: AssistantContent {
+                    text: Some(text),
+                    tool_calls: Vec::new(),
+                },
+            });
+        }
+    }
+
+    /// Drop the reply in progress and report why.
+    pub fn fail_reply(&mut self, message: &str) {
+        self.reply = None;
+        self.status = format!("Chat failed: {message}");
+    }
+
+    /// Handle one key press.
+    pub fn handle_key(&mut self, key: KeyEvent) -> Option<Action> {
+        if key.code == KeyCode::Char('c') && key.modifiers.contains(KeyModifiers::CONTROL) {
+            return Some(Action::Quit);
+        }
+        if let Some(prompt) = self.prompt {
+            return self.handle_prompt_key(prompt, key);
+        }
+
+        match key.code {
+            KeyCode::Char('q') => return Some(Action::Quit),
+            KeyCode::Tab => self.pane = self.pane.next(),
+            KeyCode::BackTab => self.pane = self.pane.previous(),
+            KeyCode::Char(c @ '1'..='4') => {
+                self.pane = Pane::ALL[usize::from(c as u8 - b'1')];
+            }
+            KeyCode::Up | KeyCode::Char('k') => self.move_selection(false),
+            KeyCode::Down | KeyCode::Char('j') => self.move_selection(true),
+            _ => return self.handle_pane_key(key.code),
+        }
+        None
+    }
+
+    fn handle_pane_key(&mut self, code: KeyCode) -> Option<Action> {
+        match (self.pane, code) {
+            (Pane::Library, KeyCode::Char('s') | KeyCode::Enter) => {
+                let model = &self.models[self.selected(Pane::Library)?];
+                Some(Action::StartServer(model.id))
+            }
+            (Pane::Servers, KeyCode::Char('x')) => {
+                let server = &self.servers[self.selected(Pane::Servers)?];
+                Some(Action::StopServer(server.model_id))
+            }
+            (Pane::Servers, KeyCode::Char('c') | KeyCode::Enter) => {
+                let server = &self.servers[self.selected(Pane::Servers)?];
+                let target = ChatTarget {
+                    model_id: server.model_id,
+                    model_name: server.model_name.clone(),
+                    port: server.port,
+                };
+                if self.chat_target.as_ref() != Some(&target) {
+                    self.transcript.clear();
+                    self.chat_target = Some(target);
+                }
+                self.pane = Pane::Chat;
+                self.prompt = Some(Prompt::Chat);
+                None
+            }
+            (Pane::Downloads, KeyCode::Char('x')) => {
+                let download = &self.downloads[self.selected(Pane::Downloads)?];
+                Some(Action::CancelDownload(download.id.clone()))
+            }
+            (Pane::Downloads, KeyCode::Char('a')) => {
+                self.prompt = Some(Prompt::Download);
+                None
+            }
+            (Pane::Chat, KeyCode::Char('i') | KeyCode::Enter) => {
+                self.prompt = Some(Prompt::Chat);
+                None
+            }
+            _ => None,
+        }
+    }
+
+    fn handle_prompt_key(&mut self, prompt: Prompt, key: KeyEvent) -> Option<Action> {
+        match key.code {
+            KeyCode::Esc => {
+                self.prompt = None;
+                self.input.clear();
+            }
+            KeyCode::Backspace => {
+                self.input.pop();
+            }
+            KeyCode::Char(c) => self.input.push(c),
+            KeyCode::Enter => {
+                let line = std::mem::take(&mut self.input);
+                let line = line.trim();
+                if line.is_empty() {
+                    return None;
+                }
+                return match prompt {
+                    Prompt::Chat => self.send_chat(line),
+                    Prompt::Download => {
+                        self.prompt = None;
+                        let (repo_id, quantization) = match line.split_once(':') {
+                            Some((repo, quant)) => (repo, Some(quant.to_string())),
+                            None => (line, None),
+                        };
+                        Some(Action::QueueDownload {
+                            repo_id: repo_id.to_string(),
+                            quantization,
+                        })
+                    }
+                };
+            }
+            _ => {}
+        }
+        None
+    }
+
+    fn send_chat(&mut self, line: &str) -> Option<Action> {
+        if self.reply.is_some() {
+            self.status = String::from("Wait for the current reply to finish");
+            return None;
+        }
+        let Some(target) = self.chat_target.clone().or_else(|| {
+            self.servers.first().map(|s| ChatTarget {
+                model_id: s.model_id,
+                model_name: s.model_name.clone(),
+                port: s.port,
+            })
+        }) else {
+            self.status = String::from("No running server — start one from the Library pane");
+            return None;
+        };
+
+        self.chat_target = Some(target.clone());
+        self.transcript.push(AgentMessage::User {
+            content: line.to_string(),
+        });
+        self.reply = Some(String::new());
+        Some(Action::SendChat {
+            target,
+            messages: self.transcript.clone(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn press(app: &mut App, code: KeyCode) -> Option<Action> {
+        app.handle_key(KeyEvent::new(code, KeyModifiers::NONE))
+    }
+
+    fn server(model_id: i64) -> ServerRow {
+        ServerRow {
+            model_id,
+            model_name: format!("model-{model_id}"),
+            port: 9000 + model_id as u16,
+            healthy: true,
+            context_size: None,
+            message: None,
+        }
+    }
+
+    #[test]
+    fn tab_cycles_panes_and_selection_stays_in_bounds() {
+        let mut app = App::new();
+        press(&mut app, KeyCode::BackTab);
+        assert_eq!(app.pane, Pane::Chat);
+        press(&mut app, KeyCode::Tab);
+        press(&mut app, KeyCode::Tab);
+        assert_eq!(app.pane, Pane::Servers);
+
+        app.set_servers(vec![server(1), server(2)]);
+        for _ in 0..5 {
+            press(&mut app, KeyCode::Down);
+        }
+        assert_eq!(app.selected(Pane::Servers), Some(1));
+
+        app.set_servers(vec![server(1)]);
+        assert_eq!(app.selected(Pane::Servers), Some(0));
+        assert!(matches!(
+            press(&mut app, KeyCode::Char('x')),
+            Some(Action::StopServer(1))
+        ));
+    }
+
+    #[test]
+    fn chat_sends_the_transcript_to_the_selected_server() {
+        let mut app = App::new();
+        app.set_servers(vec![server(1), server(2)]);
+        app.pane = Pane::Servers;
+        press(&mut app, KeyCode::Down);
+        press(&mut app, KeyCode::Char('c'));
+        assert_eq!(app.pane, Pane::Chat);
+
+        for c in "hi".chars() {
+            press(&mut app, KeyCode::Char(c));
+        }
+        let Some(Action::SendChat { target, messages }) = press(&mut app, KeyCode::Enter) else {
+            panic!("expected a chat action");
+        };
+        assert_eq!(target.model_id, 2);
+        assert_eq!(messages.len(), 1);
+
+        // 'q' is text while the prompt is open.
+        assert!(press(&mut app, KeyCode::Char('q')).is_none());
+        app.push_reply("hello");
+        app.finish_reply();
+        assert_eq!(app.transcript.len(), 2);
+
+        // Stopping the server drops the target.
+        app.set_servers(vec![server(1)]);
+        assert!(app.chat_target.is_none());
+    }
+
+    #[test]
+    fn download_prompt_splits_the_quantization() {
+        let mut app = App::new();
+        app.pane = Pane::Downloads;
+        press(&mut app, KeyCode::Char('a'));
+        for c in "org/Model-GGUF:Q4_K_M".chars() {
+            press(&mut app, KeyCode::Char(c));
+        }
+        let Some(Action::QueueDownload {
+            repo_id,
+            quantization,
+        }) = press(&mut app, KeyCode::Enter)
+        else {
+            panic!("expected a download action");
+        };
+        assert_eq!(repo_id, "org/Model-GGUF");
+        assert_eq!(quantization.as_deref(), Some("Q4_K_M"));
+        assert!(app.prompt.is_none());
+    }
+}
@@ -0,0 +1,258 @@
+#![doc = include_str!("README.md")]
+pub mod app;
+mod ui;
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+
+use anyhow::Result;
+use crossterm::event::{self, Event, KeyEventKind};
+use futures_util::StreamExt;
+use tokio::sync::mpsc;
+
+use gglib_app_services::types::StartServerRequest;
+use gglib_app_services::{ModelDeps, ModelOps, ServerDeps, ServerOps};
+use gglib_core::domain::agent::{AgentMessage, LlmStreamEvent};
+use gglib_core::download::DownloadId;
+use gglib_core::events::NoopServerEvents;
+use gglib_core::ports::{LlmCompletionPort, NoopEmitter};
+use gglib_gguf::ToolSupportDetector;
+use gglib_runtime::LlmCompletionAdapter;
+
+use crate::bootstrap::CliContext;
+
+use self::app::{Action, App, ChatTarget, ServerRow};
+
+/// How often the library, servers and download queue are re-read.
+const REFRESH_INTERVAL: Duration = Duration::from_secs(1);
+
+/// How long the input thread waits for a key before checking for shutdown.
+const INPUT_POLL: Duration = Duration::from_millis(100);
+
+/// Updates from a streaming chat reply.
+enum ChatUpdate {
+    Delta(String),
+    Done,
+    Failed(String),
+}
+
+/// The same app-services ops the GUI backends compose, wired to this
+/// process's runner and download manager.
+struct Backend<'a> {
+    ctx: &'a CliContext,
+    models: ModelOps,
+    servers: ServerOps,
+}
+
+impl<'a> Backend<'a> {
+    fn new(ctx: &'a CliContext) -> Self {
+        Self {
+            ctx,
+            models: ModelOps::new(ModelDeps {
+                core: Arc::clone(&ctx.app),
+                runner: Arc::clone(&ctx.runner),
+                gguf_parser: Arc::clone(&ctx.gguf_parser),
+            }),
+            servers: ServerOps::new(ServerDeps {
+                core: Arc::clone(&ctx.app),
+                runner: Arc::clone(&ctx.runner),
+                emitter: Arc::new(NoopEmitter::new()),
+                server_events: Arc::new(NoopServerEvents),
+                tool_detector: Arc::new(ToolSupportDetector::new()),
+            }),
+        }
+    }
+
+    /// Re-read every pane's data into `app`.
+    async fn refresh(&self, app: &mut App) {
+        match self.models.list().await {
+            Ok(models) => app.models = models,
+            Err(e) => app.status = format!("Failed to list models: {e}"),
+        }
+
+        let mut servers = Vec::new();
+        for handle in self.ctx.runner.list_running().await.unwrap_or_default() {
+            let (healthy, context_size, message) = match self.ctx.runner.health(&handle).await {
+                Ok(h) => (h.healthy, h.context_size, h.message),
+                Err(e) => (false, None, Some(e.to_string())),
+            };
+            servers.push(ServerRow {
+                model_id: handle.model_id,
+                model_name: handle.model_name,
+                port: handle.port,
+                healthy,
+                context_size,
+                message,
+            });
+        }
+        app.set_servers(servers);
+
+        if let Ok(snapshot) = self.ctx.downloads.get_queue_snapshot().await {
+            app.downloads = snapshot.items;
+            app.failed_downloads = snapshot.recent_failures;
+        }
+    }
+
+    async fn run(&self, action: Action, app: &mut App, chat_tx: &mpsc::Sender<ChatUpdate>) {
+        let result = match action {
+            Action::Quit => return,
+            Action::StartServer(id) => self
+                .servers
+                .start(id, StartServerRequest::default())
+                .await
+                .map(|r| r.message)
+                .map_err(|e| e.to_string()),
+            Action::StopServer(id) => self.servers.stop(id).await.map_err(|e| e.to_string()),
+            Action::CancelDownload(id) => match id.parse::<DownloadId>() {
+                Ok(download_id) => self
+                    .ctx
+                    .downloads
+                    .cancel_download(&download_id)
+                    .await
+                    .map(|()| format!("Cancelled {id}"))
+                    .map_err(|e| e.to_string()),
+                Err(e) => Err(e.to_string()),
+            },
+            Action::QueueDownload {
+                repo_id,
+                quantization,
+            } => Arc::clone(&self.ctx.downloads)
+                .queue_smart(repo_id.clone(), quantization)
+                .await
+                .map(|(_, shards)| format!("Queued {repo_id} ({shards} file(s))"))
+                .map_err(|e| e.to_string()),
+            Action::SendChat { target, messages } => {
+                self.spawn_chat(target, messages, chat_tx.clone());
+                return;
+            }
+        };
+        app.status = result.unwrap_or_else(|e| format!("Error: {e}"));
+        self.refresh(app).await;
+    }
+
+    /// Stream a reply from `target` into `tx` on a background task.
+    fn spawn_chat(
+        &self,
+        target: ChatTarget,
+        messages: Vec<AgentMessage>,
+        tx: mpsc::Sender<ChatUpdate>,
+    ) {
+        let llm = LlmCompletionAdapter::with_client(
+            format!("http://127.0.0.1:{}", target.port),
+            self.ctx.http_client.clone(),
+            Some(target.model_name),
+        );
+        self.servers.mark_active(target.model_id);
+
+        tokio::spawn(async move {
+            let mut stream = match llm.chat_stream(&messages, &[], None).await {
+                Ok(stream) => stream,
+                Err(e) => {
+                    let _ = tx.send(ChatUpdate::Failed(e.to_string())).await;
+                    return;
+                }
+            };
+            while let Some(event) = stream.next().await {
+                let update = match event {
+                    Ok(LlmStreamEvent::TextDelta { content }) => ChatUpdate::Delta(content),
+                    Ok(LlmStreamEvent::Done { .. }) => break,
+                    Ok(LlmStreamEvent::UpstreamError { message, .. }) => {
+                        ChatUpdate::Failed(message)
+                    }
+                    Ok(_) => continue,
+                    Err(e) => ChatUpdate::Failed(e.to_string()),
+                };
+                let failed = matches!(update, ChatUpdate::Failed(_));
+                if tx.send(update).await.is_err() || failed {
+                    return;
+                }
+            }
+            let _ = tx.send(ChatUpdate::Done).await;
+        });
+    }
+}
+
+/// Forward key events from a blocking reader thread until `stop` is set.
+fn spawn_input(stop: Arc<AtomicBool>) -> mpsc::UnboundedReceiver<Event> {
+    let (tx, rx) = mpsc::unbounded_channel();
+    std::thread::spawn(move || {
+        while !stop.load(Ordering::Relaxed) {
+            match event::poll(INPUT_POLL) {
+                Ok(true) => match event::read() {
+                    Ok(ev) => {
+                        if tx.send(ev).is_err() {
+                            return;
+                        }
+                    }
+                    Err(_) => return,
+                },
+                Ok(false) => {}
+                Err(_) => return,
+            }
+        }
+    });
+    rx
+}
+
+/// Execute `gglib tui`.
+///
+/// Takes over the terminal until the user quits; servers started from the
+/// console are stopped on the way out.
+pub async fn execute(ctx: &CliContext) -> Result<()> {
+    let backend = Backend::new(ctx);
+    let mut app = App::new();
+    backend.refresh(&mut app).await;
+
+    // Download bars and log lines would draw over the alternate screen;
+    // route log lines to the footer instead.
+    ctx.download_emitter
+        .multi_progress()
+        .set_draw_target(indicatif::ProgressDrawTarget::hidden());
+    let (log_tx, mut log_rx) = mpsc::unbounded_channel::<String>();
+    gglib_core::telemetry::set_console_hook(Arc::new(move |line: &str| {
+        let _ = log_tx.send(line.to_string());
+    }));
+
+    let stop = Arc::new(AtomicBool::new(false));
+    let mut input = spawn_input(Arc::clone(&stop));
+    let (chat_tx, mut chat_rx) = mpsc::channel(64);
+    let mut refresh = tokio::time::interval(REFRESH_INTERVAL);
+
+    let mut terminal = ratatui::init();
+    let result: Result<()> = async {
+        loop {
+            terminal.draw(|frame| ui::draw(frame, &app))?;
+            tokio::select! {
+                Some(ev) = input.recv() => {
+                    let Event::Key(key) = ev else { continue };
+                    if key.kind != KeyEventKind::Press {
+                        continue;
+                    }
+                    match app.handle_key(key) {
+                        Some(Action::Quit) => break,
+                        Some(action) => backend.run(action, &mut app, &chat_tx).await,
+                        None => {}
+                    }
+                }
+                Some(update) = chat_rx.recv() => match update {
+                    ChatUpdate::Delta(text) => app.push_reply(&text),
+                    ChatUpdate::Done => app.finish_reply(),
+                    ChatUpdate::Failed(message) => app.fail_reply(&message),
+                },
+                Some(line) = log_rx.recv() => app.status = line,
+                _ = refresh.tick() => backend.refresh(&mut app).await,
+            }
+        }
+        Ok(())
+    }
+    .await;
+    ratatui::restore();
+
+    stop.store(true, Ordering::Relaxed);
+    gglib_core::telemetry::clear_console_hook();
+    if let Err(e) = backend.servers.stop_all().await {
+        eprintln!("Failed to stop servers: {e}");
+    }
+    result
+}
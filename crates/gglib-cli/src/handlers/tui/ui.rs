@@ -0,0 +1,251 @@
+//! Rendering of [`App`] with ratatui widgets.
+
+use ratatui::Frame;
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Cell, LineGauge, Paragraph, Row, Table, TableState, Tabs, Wrap};
+
+use gglib_core::domain::agent::AgentMessage;
+use gglib_core::download::DownloadStatus;
+
+use super::app::{App, Pane, Prompt};
+
+const MIB: f64 = 1024.0 * 1024.0;
+
+fn highlight() -> Style {
+    Style::default()
+        .add_modifier(Modifier::REVERSED)
+        .add_modifier(Modifier::BOLD)
+}
+
+/// Draw the whole console.
+pub fn draw(frame: &mut Frame, app: &App) {
+    let [tabs, body, input, footer] = Layout::vertical([
+        Constraint::Length(1),
+        Constraint::Min(3),
+        Constraint::Length(u16::from(app.prompt.is_some()) * 3),
+        Constraint::Length(1),
+    ])
+    .areas(frame.area());
+
+    let titles = Pane::ALL
+        .iter()
+        .enumerate()
+        .map(|(i, pane)| format!("{} {}", i + 1, pane.title()));
+    let selected = Pane::ALL.iter().position(|p| *p == app.pane);
+    frame.render_widget(
+        Tabs::new(titles)
+            .select(selected)
+            .highlight_style(highlight()),
+        tabs,
+    );
+
+    match app.pane {
+        Pane::Library => draw_library(frame, app, body),
+        Pane::Servers => draw_servers(frame, app, body),
+        Pane::Downloads => draw_downloads(frame, app, body),
+        Pane::Chat => draw_chat(frame, app, body),
+    }
+
+    if let Some(prompt) = app.prompt {
+        let title = match prompt {
+            Prompt::Chat => " Message (Enter: send · Esc: close) ",
+            Prompt::Download => " Download repo[:quant] (Enter: queue · Esc: cancel) ",
+        };
+        frame.render_widget(
+            Paragraph::new(format!("{}█", app.input)).block(Block::bordered().title(title)),
+            input,
+        );
+    }
+
+    frame.render_widget(
+        Paragraph::new(app.status.as_str()).style(Style::default().fg(Color::DarkGray)),
+        footer,
+    );
+}
+
+fn draw_library(frame: &mut Frame, app: &App, area: Rect) {
+    let rows = app.models.iter().map(|m| {
+        Row::new([
+            Cell::from(m.id.to_string()),
+            Cell::from(m.name.clone()),
+            Cell::from(format!("{:.1}B", m.param_count_b)),
+            Cell::from(m.quantization.clone().unwrap_or_default()),
+            Cell::from(m.architecture.clone().unwrap_or_default()),
+            Cell::from(m.port.map(|p| format!("serving :{p}")).unwrap_or_default()),
+        ])
+    });
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Length(5),
+            Constraint::Min(24),
+            Constraint::Length(8),
+            Constraint::Length(10),
+            Constraint::Length(12),
+            Constraint::Length(14),
+        ],
+    )
+    .header(
+        Row::new(["ID", "Name", "Params", "Quant", "Arch", "Status"])
+            .style(Style::default().add_modifier(Modifier::BOLD)),
+    )
+    .row_highlight_style(highlight())
+    .block(Block::bordered().title(" Library (s: serve) "));
+
+    let mut state = TableState::default().with_selected(app.selected(Pane::Library));
+    frame.render_stateful_widget(table, area, &mut state);
+}
+
+fn draw_servers(frame: &mut Frame, app: &App, area: Rect) {
+    let rows = app.servers.iter().map(|s| {
+        let (health, color) = if s.healthy {
+            ("healthy", Color::Green)
+        } else {
+            ("unhealthy", Color::Red)
+        };
+        Row::new([
+            Cell::from(s.model_name.clone()),
+            Cell::from(s.port.to_string()),
+            Cell::from(health).style(Style::default().fg(color)),
+            Cell::from(s.context_size.map(|c| c.to_string()).unwrap_or_default()),
+            Cell::from(s.message.clone().unwrap_or_default()),
+        ])
+    });
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Min(24),
+            Constraint::Length(6),
+            Constraint::Length(10),
+            Constraint::Length(8),
+            Constraint::Min(20),
+        ],
+    )
+    .header(
+        Row::new(["Model", "Port", "Health", "Context", "Message"])
+            .style(Style::default().add_modifier(Modifier::BOLD)),
+    )
+    .row_highlight_style(highlight())
+    .block(Block::bordered().title(" Servers (c: chat · x: stop) "));
+
+    let mut state = TableState::default().with_selected(app.selected(Pane::Servers));
+    frame.render_stateful_widget(table, area, &mut state);
+}
+
+fn draw_downloads(frame: &mut Frame, app: &App, area: Rect) {
+    let block = Block::bordered().title(" Downloads (a: add · x: cancel) ");
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    if app.downloads.is_empty() && app.failed_downloads.is_empty() {
+        frame.render_widget(Paragraph::new("Queue is empty."), inner);
+        return;
+    }
+
+    let selected = app.selected(Pane::Downloads);
+    let lines = Layout::vertical(
+        std::iter::repeat_n(Constraint::Length(1), app.downloads.len())
+            .chain(std::iter::once(Constraint::Min(0))),
+    )
+    .split(inner);
+
+    for (i, (item, line)) in app.downloads.iter().zip(lines.iter()).enumerate() {
+        let ratio = if item.total_bytes > 0 {
+            (item.downloaded_bytes as f64 / item.total_bytes as f64).clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
+        let speed = item
+            .speed_bps
+            .map(|b| format!(" {:.1} MB/s", b / MIB))
+            .unwrap_or_default();
+        let label = format!(
+            "{} [{}] {:.0}/{:.0} MB{speed}",
+            item.display_name,
+            item.status.as_str(),
+            item.downloaded_bytes as f64 / MIB,
+            item.total_bytes as f64 / MIB,
+        );
+        let color = match item.status {
+            DownloadStatus::Failed | DownloadStatus::Cancelled => Color::Red,
+            DownloadStatus::Completed => Color::Green,
+            _ => Color::Cyan,
+        };
+        let mut label_style = Style::default();
+        if selected == Some(i) {
+            label_style = highlight();
+        }
+        frame.render_widget(
+            LineGauge::default()
+                .ratio(ratio)
+                .label(Span::styled(label, label_style))
+                .filled_style(Style::default().fg(color)),
+            *line,
+        );
+    }
+
+    if let Some(rest) = lines.last()
+        && !app.failed_downloads.is_empty()
+    {
+        let failures: Vec<Line> = std::iter::once(Line::from(""))
+            .chain(app.failed_downloads.iter().map(|f| {
+                Line::styled(
+                    format!("✗ {}: {}", f.display_name, f.error),
+                    Style::default().fg(Color::Red),
+                )
+            }))
+            .collect();
+        frame.render_widget(Paragraph::new(failures).wrap(Wrap { trim: true }), *rest);
+    }
+}
+
+fn draw_chat(frame: &mut Frame, app: &App, area: Rect) {
+    let title = match &app.chat_target {
+        Some(t) => format!(" Chat — {} :{} ", t.model_name, t.port),
+        None => String::from(" Chat (Enter: type · pick a server with c) "),
+    };
+    let block = Block::bordered().title(title);
+    let inner = block.inner(area);
+
+    let mut lines = Vec::new();
+    for message in &app.transcript {
+        match message {
+            AgentMessage::User { content } => push_turn(&mut lines, "you", Color::Cyan, content),
+            AgentMessage::Assistant { content } => push_turn(
+                &mut lines,
+                "model",
+                Color::Green,
+                content.text.as_deref().unwrap_or_default(),
+            ),
+            _ => {}
+        }
+    }
+    if let Some(reply) = &app.reply {
+        let text = if reply.is_empty() { "…" } else { reply };
+        push_turn(&mut lines, "model", Color::Green, text);
+    }
+
+    // Keep the newest text in view: approximate the wrapped height.
+    let width = usize::from(inner.width.max(1));
+    let height: usize = lines.iter().map(|l| l.width().max(1).div_ceil(width)).sum();
+    let scroll = height.saturating_sub(usize::from(inner.height));
+
+    frame.render_widget(
+        Paragraph::new(lines)
+            .block(block)
+            .wrap(Wrap { trim: false })
+            .scroll((u16::try_from(scroll).unwrap_or(u16::MAX), 0)),
+        area,
+    );
+}
+
+fn push_turn<'a>(lines: &mut Vec<Line<'a>>, who: &'a str, color: Color, text: &'a str) {
+    lines.push(Line::styled(
+        format!("{who}>"),
+        Style::default().fg(color).add_modifier(Modifier::BOLD),
+    ));
+    lines.extend(text.lines().map(Line::from));
+    lines.push(Line::from(""));
+}
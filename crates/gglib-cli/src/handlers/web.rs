@@ -7,6 +7,7 @@
 use std::path::PathBuf;
 
 use anyhow::Result;
+use gglib_axum::{RateLimit, RateLimitConfig, SpaFallback};
 
 use crate::presentation::style;
 
@@ -23,11 +24,20 @@ use crate::presentation::style;
 /// * `api_only`   — When `true`, skip static-file serving regardless of flags.
 /// * `static_dir` — Explicit path to a built frontend; takes priority over
 ///   auto-discovery when `api_only` is `false`.
+/// * `spa_fallback` — How unknown non-API paths are answered when serving
+///   static files.
+/// * `share_token` — Bearer token that enables model file downloads for
+///   other machines; `None` leaves them off.
+/// * `rate_limit` — Per-client API rate limits; `None` leaves the API
+///   unlimited.
 pub async fn execute(
     port: u16,
     base_port: u16,
     api_only: bool,
     static_dir: Option<PathBuf>,
+    spa_fallback: SpaFallback,
+    share_token: Option<String>,
+    rate_limit: Option<RateLimitConfig>,
 ) -> Result<()> {
     use gglib_axum::{CorsConfig, ServerConfig, start_server};
     use gglib_core::paths::llama_server_path;
@@ -50,6 +60,10 @@ pub async fn execute(
         max_concurrent: 4,
         max_concurrent_agent_loops: 4,
         static_dir: None,
+        spa_fallback,
+        model_share_token: share_token,
+        rate_limit,
+        log_dir: gglib_core::telemetry::log_dir(),
         cors: CorsConfig::AllowAll,
     };
 
@@ -79,6 +93,10 @@ pub async fn execute(
             "  \u{1f4ca} Status:  http://localhost:{}/v1/proxy/status",
             port
         );
+        if config.model_share_token.is_some() {
+            eprintln!("  \u{1f4e6} Sharing model files at /api/models/{{id}}/file");
+        }
+        print_rate_limit(config.rate_limit.as_ref());
         eprintln!();
         eprintln!("  Press Ctrl+C to stop");
         style::print_banner_close();
@@ -89,6 +107,10 @@ pub async fn execute(
             "  \u{1f4ca} Status:  http://localhost:{}/v1/proxy/status",
             port
         );
+        if config.model_share_token.is_some() {
+            eprintln!("  \u{1f4e6} Sharing model files at /api/models/{{id}}/file");
+        }
+        print_rate_limit(config.rate_limit.as_ref());
         eprintln!();
         eprintln!("  \u{1f4a1} Tip: Use --static-dir to serve a frontend build");
         style::print_banner_close();
@@ -97,3 +119,37 @@ pub async fn execute(
     start_server(config).await?;
     Ok(())
 }
+
+/// Rate limits from `--rate-limit`, `--heavy-rate-limit` and
+/// `--rate-limit-key`; any of them turns limiting on, with the default for
+/// the others.
+pub fn rate_limit_config(
+    general: Option<u32>,
+    heavy: Option<u32>,
+    api_keys: Vec<String>,
+) -> Option<RateLimitConfig> {
+    if general.is_none() && heavy.is_none() && api_keys.is_empty() {
+        return None;
+    }
+    let defaults = RateLimitConfig::default();
+    Some(RateLimitConfig {
+        general: general.map_or(defaults.general, RateLimit::per_minute),
+        heavy: heavy.map_or(defaults.heavy, RateLimit::per_minute),
+        api_keys,
+    })
+}
+
+fn print_rate_limit(limits: Option<&RateLimitConfig>) {
+    if let Some(limits) = limits {
+        eprintln!(
+            "  \u{23f1}\u{fe0f} Rate limit: {}/min per client, {}/min for uploads and chat",
+            limits.general.per_minute, limits.heavy.per_minute
+        );
+        if !limits.api_keys.is_empty() {
+            eprintln!(
+                "     {} API key(s) limited on their own",
+                limits.api_keys.len()
+            );
+        }
+    }
+}
@@ -35,8 +35,12 @@ pub mod llama_commands;
 pub mod mcp_commands;
 pub mod model_commands;
 pub mod parser;
+pub mod plugin_commands;
 pub mod presentation;
 pub mod shared_args;
+pub mod stats_commands;
+pub mod template_commands;
+pub mod trash_commands;
 pub mod utils;
 
 // Re-export primary types for convenient access
@@ -24,12 +24,18 @@ pub enum LlamaCommand {
         /// Force building from source instead of downloading pre-built binaries
         #[arg(long)]
         build: bool,
+        /// Build from this llama.cpp fork instead of upstream (implies --build)
+        #[arg(long, value_name = "URL")]
+        repo: Option<String>,
+        /// Branch, tag or commit to build (implies --build)
+        #[arg(long = "ref", value_name = "BRANCH|SHA")]
+        git_ref: Option<String>,
     },
 
     /// Check for llama.cpp updates
     CheckUpdates,
 
-    /// Update llama.cpp to latest version
+    /// Update llama.cpp to the latest commit of the installed repository and ref
     Update,
 
     /// Show llama.cpp build information and status
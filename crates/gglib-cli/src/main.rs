@@ -2,13 +2,23 @@
 //!
 //! Initialises the tracing subscriber and environment, parses CLI arguments,
 //! bootstraps the composition root, and delegates to the command dispatcher.
+//! `gglib doctor` runs without bootstrapping so it can diagnose a broken
+//! database. Progress renders as plain status lines with `--plain` or when
+//! stdout is not a terminal.
+//! The message catalog's locale is chosen from the `locale` setting (or the
+//! environment, when there is no database to read it from).
+//! A [`gglib_cli::CliError`] surfacing from a command sets the process exit
+//! code via [`gglib_cli::CliError::exit_code`].
 //!
 //! See [`gglib_cli::dispatch`] for command routing and
 //! [`gglib_cli::bootstrap`] for dependency wiring.
 
+use std::io::IsTerminal;
+
 use clap::Parser;
 
-use gglib_cli::{Cli, CliConfig, bootstrap, dispatch};
+use gglib_cli::presentation::i18n;
+use gglib_cli::{Cli, CliConfig, CliError, Commands, bootstrap, dispatch};
 
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
@@ -16,8 +26,7 @@ async fn main() -> anyhow::Result<()> {
 
     let cli = Cli::parse();
     gglib_core::telemetry::init_tracing(cli.verbose)?;
-    let config = CliConfig::with_defaults()?;
-    let ctx = bootstrap(config).await?;
+    gglib_core::progress::set_plain(cli.plain || !std::io::stdout().is_terminal());
 
     let Some(command) = cli.command else {
         use clap::CommandFactory;
@@ -25,5 +34,26 @@ async fn main() -> anyhow::Result<()> {
         return Ok(());
     };
 
-    dispatch(&ctx, command, cli.verbose).await
+    let result = if let Commands::Doctor { fix, yes } = command {
+        // Skips bootstrap: a corrupt database must not stop the command that
+        // diagnoses it.
+        i18n::init(None);
+        let probe = gglib_runtime::DefaultSystemProbe::new();
+        gglib_cli::handlers::doctor::execute(&probe, fix, yes, cli.output).await
+    } else {
+        let config = CliConfig::with_defaults()?;
+        let ctx = bootstrap(config).await?;
+        let locale = ctx.app.settings().get().await.ok().and_then(|s| s.locale);
+        i18n::init(locale.as_deref());
+        dispatch(&ctx, command, cli.verbose, cli.output).await
+    };
+    // Flush spans still queued for the OTLP collector before exiting.
+    gglib_runtime::otlp::shutdown();
+    if let Err(err) = &result
+        && let Some(cli_err) = err.downcast_ref::<CliError>()
+    {
+        eprintln!("Error: {err:?}");
+        std::process::exit(cli_err.exit_code());
+    }
+    result
 }
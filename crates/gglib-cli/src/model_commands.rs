@@ -5,6 +5,7 @@
 
 use clap::{Subcommand, ValueEnum};
 use gglib_core::domain::{ModelSortBy, SortOrder};
+use gglib_runtime::config_import::ConfigFormat;
 
 // ─────────────────────────────────────────────────────────────────────────────
 // CLI-friendly sort types (ValueEnum)
@@ -79,6 +80,34 @@ impl From<CliSortOrder> for SortOrder {
     }
 }
 
+/// Progress rendering for `gglib model download`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum, Default)]
+pub enum DownloadProgressFormat {
+    /// Live progress bars on stderr.
+    #[default]
+    Bars,
+    /// One JSON download event per line (NDJSON) on stdout.
+    Json,
+}
+
+/// Config formats accepted by `gglib model import-config --format`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum ConfigFormatArg {
+    /// llama-swap config.yaml
+    LlamaSwap,
+    /// Ollama Modelfile
+    Modelfile,
+}
+
+impl From<ConfigFormatArg> for ConfigFormat {
+    fn from(arg: ConfigFormatArg) -> Self {
+        match arg {
+            ConfigFormatArg::LlamaSwap => Self::LlamaSwap,
+            ConfigFormatArg::Modelfile => Self::Modelfile,
+        }
+    }
+}
+
 /// Model management commands.
 ///
 /// Covers the full lifecycle of GGUF models: adding, listing, removing,
@@ -92,6 +121,28 @@ pub enum ModelCommand {
         file_path: String,
     },
 
+    /// Add the models from a llama-swap config or Ollama Modelfile
+    ///
+    /// Each local GGUF the file names is added to the library, and the flags
+    /// it was launched with — context size, parallel slots, idle timeout and
+    /// sampling — become the model's defaults. Settings gglib has no
+    /// per-model equivalent for are listed but not imported.
+    ///
+    /// # Examples
+    ///
+    ///   gglib model import-config ~/llama-swap/config.yaml --dry-run
+    ///   gglib model import-config ./Modelfile
+    ImportConfig {
+        /// The llama-swap config.yaml or Modelfile to read
+        file: std::path::PathBuf,
+        /// Format of the file; guessed from its name when omitted
+        #[arg(long, value_enum)]
+        format: Option<ConfigFormatArg>,
+        /// List what would be added without changing the library
+        #[arg(long)]
+        dry_run: bool,
+    },
+
     /// List GGUF models in the database
     List {
         /// Field to sort by.
@@ -117,6 +168,10 @@ pub enum ModelCommand {
         /// Only show models that have this tag (repeatable: AND semantics).
         #[arg(long = "tag", action = clap::ArgAction::Append)]
         tags: Vec<String>,
+        /// Only show models under this license, e.g. `apache-2.0`
+        /// (repeatable: any of them).
+        #[arg(long = "license", action = clap::ArgAction::Append)]
+        licenses: Vec<String>,
     },
 
     /// Remove a GGUF model from the database
@@ -147,6 +202,10 @@ pub enum ModelCommand {
         /// Update context length
         #[arg(short, long)]
         context_length: Option<u64>,
+        /// Set the context size `serve` and the proxy launch this model with
+        /// (at most the model's maximum context)
+        #[arg(long, value_name = "N")]
+        default_ctx_size: Option<usize>,
         /// Add or update metadata (format: key=value)
         #[arg(short, long, action = clap::ArgAction::Append)]
         metadata: Vec<String>,
@@ -212,6 +271,24 @@ pub enum ModelCommand {
         full: bool,
     },
 
+    /// Re-run capability detection for installed models from their persisted
+    /// GGUF metadata.
+    ///
+    /// The daemon re-detects models classified by an older detector in the
+    /// background at startup; with no arguments this does the same now.
+    /// Capabilities set by hand are skipped unless `--force` is given.
+    Redetect {
+        /// Re-detect a single model by id, name, or HF identifier, even if
+        /// the current detector already classified it.
+        identifier: Option<String>,
+        /// Re-detect every model in the catalog.
+        #[arg(long, conflicts_with = "identifier")]
+        all: bool,
+        /// Also replace capabilities that were set by hand.
+        #[arg(long)]
+        force: bool,
+    },
+
     /// Verify model integrity by computing SHA256 hashes
     Verify {
         /// Name or ID of the model to verify
@@ -247,13 +324,48 @@ pub enum ModelCommand {
     /// While a download is in flight the bar surfaces the lifecycle phase:
     /// `Downloading` → `Finalizing` (gathering HF metadata) → `Registering`
     /// (writing model row) → terminal `Completed` / `Failed` / `Cancelled`.
+    ///
+    /// For CI, `--progress json` prints each download event as a JSON line on
+    /// stdout instead, implies `--yes`, and the exit code names the failure
+    /// class (66 not found, 69 network, 74 I/O, 65 integrity/validation,
+    /// 75 retryable, 130 cancelled, 1 anything else).
     Download {
-        /// HuggingFace model repository (e.g., "bartowski/Qwen2.5-7B-Instruct-GGUF")
-        model_id: String,
+        /// HuggingFace model repository (e.g., "bartowski/Qwen2.5-7B-Instruct-GGUF"),
+        /// or a ModelScope one prefixed with `modelscope:`
+        /// (e.g., "modelscope:Qwen/Qwen3-8B-GGUF")
+        #[arg(required_unless_present = "collection")]
+        model_id: Option<String>,
         /// Specific quantization to download (e.g., "Q4_K_M", "F16", or an
         /// Unsloth Dynamic quant like "UD-Q4_K_M")
+        ///
+        /// With `--collection`, repos lacking this quantization are skipped.
         #[arg(short, long)]
         quantization: Option<String>,
+        /// Download every GGUF repo in a HuggingFace collection
+        /// (e.g., "unsloth/qwen3-680edabfb790c8c34a242f95")
+        #[arg(long, conflicts_with_all = ["model_id", "list_quants"])]
+        collection: Option<String>,
+        /// Store the files in this directory under the models directory
+        /// instead of `<owner>_<repo>/`; may use `{owner}`, `{repo}` and
+        /// `{quant}` (needs --quantization)
+        #[arg(
+            long,
+            value_name = "TEMPLATE",
+            requires = "quantization",
+            conflicts_with = "collection"
+        )]
+        subdir: Option<String>,
+        /// Name the files from this template instead of the repository's
+        /// names; may use `{owner}`, `{repo}`, `{quant}` and `{name}` (the
+        /// original name), keeps shard suffixes and adds `.gguf` (needs
+        /// --quantization)
+        #[arg(
+            long,
+            value_name = "TEMPLATE",
+            requires = "quantization",
+            conflicts_with = "collection"
+        )]
+        filename: Option<String>,
         /// List available quantizations for the model
         #[arg(long)]
         list_quants: bool,
@@ -262,14 +374,21 @@ pub enum ModelCommand {
         skip_db: bool,
         /// HuggingFace token for private models (for `--list-quants` only).
         ///
-        /// For downloads, set the `HF_TOKEN` environment variable instead.
-        /// It is read at startup and wired into the download manager config,
+        /// For downloads, store one with `gglib config settings set
+        /// --hf-token` or set the `HF_TOKEN` environment variable. Either is
+        /// read at startup and wired into the download manager config,
         /// mirroring how the GUI handles authentication.
         #[arg(long)]
         token: Option<String>,
         /// Skip confirmation prompt
         #[arg(short, long)]
         force: bool,
+        /// How to report progress: `bars` or `json` (NDJSON events on stdout)
+        #[arg(long, value_enum, default_value = "bars")]
+        progress: DownloadProgressFormat,
+        /// Never prompt or read keys; wait for the queue to finish
+        #[arg(short, long)]
+        yes: bool,
     },
 
     /// Check for updates to downloaded models
@@ -291,7 +410,7 @@ pub enum ModelCommand {
         force: bool,
     },
 
-    /// Search HuggingFace Hub for GGUF models
+    /// Search HuggingFace Hub (or ModelScope) for GGUF models
     Search {
         /// Search query (model name, author, or keywords)
         query: String,
@@ -304,6 +423,9 @@ pub enum ModelCommand {
         /// Only show models with GGUF files
         #[arg(long)]
         gguf_only: bool,
+        /// Model hub to search: "hf" or "modelscope"
+        #[arg(long, default_value = "hf")]
+        hub: gglib_core::ports::ModelHub,
     },
 
     /// Browse popular GGUF models on HuggingFace Hub
@@ -344,7 +466,7 @@ pub enum ModelCommand {
         /// Set a capability flag (can be repeated).
         ///
         /// Accepted values: `supports-system-role`, `requires-strict-turns`,
-        /// `supports-tool-calls`, `supports-reasoning`.
+        /// `supports-tool-calls`, `supports-reasoning`, `embedding`, `reranker`.
         #[arg(long = "set", value_name = "FLAG", action = clap::ArgAction::Append)]
         set: Vec<String>,
         /// Clear a capability flag (can be repeated).
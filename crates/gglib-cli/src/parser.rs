@@ -5,6 +5,7 @@
 use clap::Parser;
 
 use crate::commands::Commands;
+use crate::presentation::OutputFormat;
 
 /// Command-line interface definition for the GGUF library management tool.
 ///
@@ -26,11 +27,13 @@ mcp             Manage MCP (Model Context Protocol) tool servers\n\n\
 Inference:\n  \
 serve           Serve a GGUF model with llama-server\n  \
 chat            Chat with a model interactively (also: chat council)\n  \
-question        Ask a question with optional context from stdin or file\n\n\
+question        Ask a question with optional context from stdin or file\n  \
+ask             Stream a one-shot answer to stdout (pipe-friendly)\n\n\
 Interfaces:\n  \
 gui             Launch the Tauri desktop GUI\n  \
 web             Start the web-based GUI server\n  \
-proxy           Start OpenAI-compatible proxy with MCP tool gateway\n\n\
+proxy           Start OpenAI-compatible proxy with MCP tool gateway\n  \
+tui             Full-screen terminal console (library, servers, downloads, chat)\n\n\
 Options:\n{options}{after-help}"
 )]
 pub struct Cli {
@@ -42,6 +45,15 @@ pub struct Cli {
     #[arg(short = 'v', long = "verbose", global = true)]
     pub verbose: bool,
 
+    /// Output format for list-style commands (list, search, history, ...)
+    #[arg(long, global = true, value_enum, default_value_t = OutputFormat::Table)]
+    pub output: OutputFormat,
+
+    /// Print periodic status lines instead of progress bars (for CI logs and
+    /// screen readers); on automatically when stdout is not a terminal
+    #[arg(long, global = true)]
+    pub plain: bool,
+
     #[command(subcommand)]
     pub command: Option<Commands>,
 }
@@ -70,5 +82,136 @@ mod tests {
         ]);
         assert!(cli.verbose);
         assert_eq!(cli.models_dir, Some("/tmp/models".to_string()));
+        assert_eq!(cli.output, OutputFormat::Table);
+    }
+
+    #[test]
+    fn test_output_flag_is_global() {
+        use clap::Parser;
+        let cli = Cli::parse_from(["gglib", "model", "list", "--output", "json"]);
+        assert_eq!(cli.output, OutputFormat::Json);
+        assert!(Cli::try_parse_from(["gglib", "--output", "yaml", "model", "list"]).is_err());
+    }
+
+    #[test]
+    fn test_plain_flag_is_global() {
+        use clap::Parser;
+        let cli = Cli::parse_from(["gglib", "model", "download", "org/repo", "--plain"]);
+        assert!(cli.plain);
+        assert!(!Cli::parse_from(["gglib", "model", "list"]).plain);
+    }
+
+    #[test]
+    fn test_download_progress_json_flag() {
+        use crate::commands::Commands;
+        use crate::model_commands::{DownloadProgressFormat, ModelCommand};
+        use clap::Parser;
+        let cli = Cli::parse_from([
+            "gglib",
+            "model",
+            "download",
+            "owner/repo",
+            "--progress",
+            "json",
+            "-y",
+        ]);
+        let Some(Commands::Model {
+            command: ModelCommand::Download { progress, yes, .. },
+        }) = cli.command
+        else {
+            panic!("expected model download");
+        };
+        assert_eq!(progress, DownloadProgressFormat::Json);
+        assert!(yes);
+    }
+
+    #[test]
+    fn test_jobs_port_is_accepted_after_the_subcommand() {
+        use crate::commands::JobsCommand;
+        use clap::Parser;
+        let cli = Cli::parse_from(["gglib", "jobs", "cancel", "abc", "--port", "9999"]);
+        let Some(Commands::Jobs {
+            port,
+            command: Some(JobsCommand::Cancel { id }),
+        }) = cli.command
+        else {
+            panic!("expected jobs cancel");
+        };
+        assert_eq!(port, 9999);
+        assert_eq!(id, "abc");
+        assert!(Cli::try_parse_from(["gglib", "jobs"]).is_ok());
+    }
+
+    #[test]
+    fn test_doctor_yes_requires_fix() {
+        use clap::Parser;
+        let cli = Cli::parse_from(["gglib", "doctor", "--fix", "--yes"]);
+        assert!(matches!(
+            cli.command,
+            Some(Commands::Doctor {
+                fix: true,
+                yes: true
+            })
+        ));
+        assert!(Cli::try_parse_from(["gglib", "doctor", "--yes"]).is_err());
+    }
+
+    #[test]
+    fn test_llama_install_fork_source() {
+        use crate::config_commands::ConfigCommand;
+        use crate::llama_commands::LlamaCommand;
+        use clap::Parser;
+        let cli = Cli::parse_from([
+            "gglib",
+            "config",
+            "llama",
+            "install",
+            "--repo",
+            "https://github.com/me/llama.cpp",
+            "--ref",
+            "my-patches",
+        ]);
+        let Some(Commands::Config {
+            command:
+                ConfigCommand::Llama {
+                    command: LlamaCommand::Install { repo, git_ref, .. },
+                },
+        }) = cli.command
+        else {
+            panic!("expected config llama install");
+        };
+        assert_eq!(repo.as_deref(), Some("https://github.com/me/llama.cpp"));
+        assert_eq!(git_ref.as_deref(), Some("my-patches"));
+    }
+
+    #[test]
+    fn test_sync_pair_back_requires_share_token_and_name() {
+        use clap::Parser;
+        let base = ["gglib", "sync", "pair", "studio", "http://studio:9887"];
+        assert!(Cli::try_parse_from(base.iter().chain(&["--token", "t"])).is_ok());
+        assert!(
+            Cli::try_parse_from(base.iter().chain(&[
+                "--token",
+                "t",
+                "--pair-back",
+                "http://laptop:9887",
+                "--as",
+                "laptop",
+            ]))
+            .is_err()
+        );
+        assert!(
+            Cli::try_parse_from(base.iter().chain(&[
+                "--token",
+                "t",
+                "--pair-back",
+                "http://laptop:9887",
+                "--as",
+                "laptop",
+                "--share-token",
+                "s",
+            ]))
+            .is_ok()
+        );
     }
 }
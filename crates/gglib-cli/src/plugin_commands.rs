@@ -0,0 +1,48 @@
+//! WASM plugin management subcommands.
+//!
+//! This module defines the commands that install, list and remove the
+//! sandboxed plugins whose tools are offered to agents.
+
+use std::path::PathBuf;
+
+use clap::Subcommand;
+use gglib_plugins::PluginLimits;
+
+/// WASM plugin management commands.
+#[derive(Subcommand)]
+pub enum PluginsCommand {
+    /// Validate a .wasm module and install it
+    Install {
+        /// Path to the plugin module
+        file: PathBuf,
+
+        /// Plugin name (defaults to the file name without extension)
+        #[arg(long)]
+        name: Option<String>,
+
+        /// Maximum linear memory per call, in MiB
+        #[arg(long, default_value_t = PluginLimits::DEFAULT_MEMORY_MB)]
+        memory_mb: u32,
+
+        /// Fuel per call (roughly the number of WASM instructions)
+        #[arg(long, default_value_t = PluginLimits::DEFAULT_FUEL)]
+        fuel: u64,
+
+        /// Wall-clock limit per call, in milliseconds
+        #[arg(long, default_value_t = PluginLimits::DEFAULT_TIMEOUT_MS)]
+        timeout_ms: u64,
+
+        /// Replace an installed plugin of the same name
+        #[arg(long)]
+        force: bool,
+    },
+
+    /// List installed plugins and their tools
+    List,
+
+    /// Remove an installed plugin
+    Remove {
+        /// Name of the plugin to remove
+        name: String,
+    },
+}
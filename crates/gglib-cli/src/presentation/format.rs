@@ -0,0 +1,165 @@
+//! Locale-aware number, size and duration formatting.
+//!
+//! Decimal and grouping separators follow the locale selected in
+//! [`i18n`](super::i18n); unit symbols (`GiB`, `ms`) are the same
+//! everywhere. Sizes use binary (IEC) units, matching how llama.cpp and the
+//! GUI report memory.
+
+use std::time::Duration;
+
+use unic_langid::LanguageIdentifier;
+
+use super::i18n;
+
+/// Languages that write `1.234,5`.
+const COMMA_DECIMAL_DOT_GROUP: &[&str] = &[
+    "de", "es", "it", "nl", "pt", "da", "id", "tr", "el", "ro", "hr", "sl", "sr",
+];
+
+/// Languages that write `1 234,5` (with a no-break space).
+const COMMA_DECIMAL_SPACE_GROUP: &[&str] = &[
+    "fr", "ru", "pl", "sv", "fi", "nb", "nn", "no", "cs", "sk", "uk", "hu", "bg", "et", "lt", "lv",
+];
+
+/// Decimal and digit-group separators for one locale.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NumberFormat {
+    decimal: char,
+    group: char,
+}
+
+impl NumberFormat {
+    /// `1,234.5`
+    pub const EN: Self = Self {
+        decimal: '.',
+        group: ',',
+    };
+
+    /// Separators for `locale`; languages not listed use [`Self::EN`].
+    pub fn for_locale(locale: &LanguageIdentifier) -> Self {
+        let language = locale.language.as_str();
+        if COMMA_DECIMAL_DOT_GROUP.contains(&language) {
+            Self {
+                decimal: ',',
+                group: '.',
+            }
+        } else if COMMA_DECIMAL_SPACE_GROUP.contains(&language) {
+            Self {
+                decimal: ',',
+                group: '\u{a0}',
+            }
+        } else {
+            Self::EN
+        }
+    }
+
+    /// Separators for the process locale.
+    pub fn current() -> Self {
+        Self::for_locale(i18n::catalog().locale())
+    }
+
+    /// An integer with digit grouping: `70,008`.
+    pub fn integer(self, value: u64) -> String {
+        let digits = value.to_string();
+        let mut out = String::with_capacity(digits.len() + digits.len() / 3);
+        for (i, ch) in digits.chars().enumerate() {
+            if i > 0 && (digits.len() - i).is_multiple_of(3) {
+                out.push(self.group);
+            }
+            out.push(ch);
+        }
+        out
+    }
+
+    /// A decimal with `precision` fraction digits and digit grouping.
+    pub fn decimal(self, value: f64, precision: usize) -> String {
+        let fixed = format!("{:.*}", precision, value.abs());
+        let (whole, fraction) = fixed.split_once('.').unwrap_or((&fixed, ""));
+        let mut out = String::new();
+        if value.is_sign_negative() && fixed.chars().any(|c| c.is_ascii_digit() && c != '0') {
+            out.push('-');
+        }
+        out.push_str(&self.integer(whole.parse().unwrap_or(0)));
+        if !fraction.is_empty() {
+            out.push(self.decimal);
+            out.push_str(fraction);
+        }
+        out
+    }
+
+    /// A byte count in the largest binary unit that keeps it at least 1:
+    /// `512 B`, `1.5 KiB`, `4.2 GiB`.
+    pub fn bytes(self, bytes: u64) -> String {
+        const UNITS: [&str; 5] = ["KiB", "MiB", "GiB", "TiB", "PiB"];
+        if bytes < 1024 {
+            return format!("{bytes} B");
+        }
+        let mut value = bytes as f64 / 1024.0;
+        let mut unit = 0;
+        while value >= 1024.0 && unit < UNITS.len() - 1 {
+            value /= 1024.0;
+            unit += 1;
+        }
+        format!("{} {}", self.decimal(value, 1), UNITS[unit])
+    }
+}
+
+/// [`NumberFormat::bytes`] in the process locale.
+pub fn format_bytes(bytes: u64) -> String {
+    NumberFormat::current().bytes(bytes)
+}
+
+/// [`NumberFormat::decimal`] in the process locale.
+pub fn format_decimal(value: f64, precision: usize) -> String {
+    NumberFormat::current().decimal(value, precision)
+}
+
+/// A duration in its two most significant units: `850 ms`, `42s`,
+/// `3m 05s`, `1h 02m`.
+///
+/// From one second up this is the download renderers'
+/// [`gglib_core::download::format_duration`], so elapsed times and ETAs read
+/// the same.
+pub fn format_duration(duration: Duration) -> String {
+    if duration < Duration::from_secs(1) {
+        format!("{} ms", duration.as_millis())
+    } else {
+        gglib_core::download::format_duration(Some(duration.as_secs_f64()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn locale(tag: &str) -> NumberFormat {
+        NumberFormat::for_locale(&tag.parse().unwrap())
+    }
+
+    #[test]
+    fn separators_follow_the_locale() {
+        assert_eq!(locale("en-US").decimal(1234.56, 1), "1,234.6");
+        assert_eq!(locale("de-DE").decimal(1234.56, 1), "1.234,6");
+        assert_eq!(locale("fr").decimal(1234.56, 2), "1\u{a0}234,56");
+        assert_eq!(locale("ja").integer(70_008), "70,008");
+        assert_eq!(NumberFormat::EN.decimal(-0.04, 1), "0.0");
+        assert_eq!(NumberFormat::EN.decimal(-2.5, 1), "-2.5");
+    }
+
+    #[test]
+    fn bytes_use_binary_units() {
+        let en = NumberFormat::EN;
+        assert_eq!(en.bytes(512), "512 B");
+        assert_eq!(en.bytes(1536), "1.5 KiB");
+        assert_eq!(en.bytes(4_509_715_660), "4.2 GiB");
+        assert_eq!(locale("de").bytes(4_509_715_660), "4,2 GiB");
+    }
+
+    #[test]
+    fn durations_keep_two_units() {
+        assert_eq!(format_duration(Duration::from_millis(850)), "850 ms");
+        assert_eq!(format_duration(Duration::from_secs(42)), "42s");
+        assert_eq!(format_duration(Duration::from_secs(185)), "3m 05s");
+        assert_eq!(format_duration(Duration::from_secs(3720)), "1h 02m");
+    }
+}
@@ -0,0 +1,226 @@
+//! Message catalog for user-facing CLI strings.
+//!
+//! Messages are [Fluent](https://projectfluent.org) files under
+//! `crates/gglib-cli/locales/<tag>/cli.ftl`, compiled into the binary.
+//! `en-US` is the reference catalog: every id exists there, and any id a
+//! translation lacks falls back to it.
+//!
+//! The locale is chosen once at startup by [`init`], from the `locale`
+//! setting, then `GGLIB_LOCALE`, `LC_ALL`, `LC_MESSAGES` and `LANG`. Until
+//! then (and in tests) messages render in `en-US`.
+//!
+//! Look messages up with the [`tr!`](crate::tr) macro:
+//!
+//! ```rust
+//! let line = gglib_cli::tr!("doctor-summary", passed = 3, warned = 1, failed = 0);
+//! assert_eq!(line, "3 passed, 1 warning(s), 0 failed");
+//! ```
+
+use std::sync::OnceLock;
+
+pub use fluent_bundle::FluentArgs;
+use fluent_bundle::FluentResource;
+use fluent_bundle::concurrent::FluentBundle;
+use unic_langid::LanguageIdentifier;
+
+/// Locale every catalog falls back to.
+pub const FALLBACK_LOCALE: &str = "en-US";
+
+/// Bundled catalogs as `(language tag, Fluent source)`; the first is the
+/// fallback.
+const CATALOGS: &[(&str, &str)] = &[
+    (FALLBACK_LOCALE, include_str!("../../locales/en-US/cli.ftl")),
+    ("de", include_str!("../../locales/de/cli.ftl")),
+];
+
+/// Environment variables consulted, in order, when no locale is configured.
+const LOCALE_ENV_VARS: &[&str] = &["GGLIB_LOCALE", "LC_ALL", "LC_MESSAGES", "LANG"];
+
+static CATALOG: OnceLock<Catalog> = OnceLock::new();
+
+/// The selected locale's messages, backed by the `en-US` ones.
+///
+/// The locale is kept even when no catalog translates it, so numbers are
+/// still formatted for it.
+pub struct Catalog {
+    locale: LanguageIdentifier,
+    primary: Option<FluentBundle<FluentResource>>,
+    fallback: FluentBundle<FluentResource>,
+}
+
+impl Catalog {
+    /// Build the catalog for `locale`, translated by the bundled catalog of
+    /// the same language when there is one.
+    pub fn new(locale: &LanguageIdentifier) -> Self {
+        let primary = CATALOGS
+            .iter()
+            .skip(1)
+            .find(|(tag, _)| {
+                tag.parse::<LanguageIdentifier>()
+                    .is_ok_and(|id| id.language == locale.language)
+            })
+            .map(|&(tag, source)| bundle(tag, source));
+
+        Self {
+            locale: locale.clone(),
+            primary,
+            fallback: bundle(CATALOGS[0].0, CATALOGS[0].1),
+        }
+    }
+
+    /// The locale numbers are formatted for.
+    pub fn locale(&self) -> &LanguageIdentifier {
+        &self.locale
+    }
+
+    /// Format message `id`, falling back to `en-US` and then to the id
+    /// itself, so a missing message is visible rather than silent.
+    pub fn message(&self, id: &str, args: Option<&FluentArgs>) -> String {
+        self.primary
+            .iter()
+            .chain(std::iter::once(&self.fallback))
+            .find_map(|bundle| {
+                let pattern = bundle.get_message(id)?.value()?;
+                let mut errors = Vec::new();
+                Some(
+                    bundle
+                        .format_pattern(pattern, args, &mut errors)
+                        .into_owned(),
+                )
+            })
+            .unwrap_or_else(|| id.to_string())
+    }
+}
+
+fn bundle(tag: &str, source: &str) -> FluentBundle<FluentResource> {
+    let id: LanguageIdentifier = tag.parse().expect("bundled locale tag is valid");
+    let resource = FluentResource::try_new(source.to_string()).expect("bundled catalog parses");
+    let mut bundle = FluentBundle::new_concurrent(vec![id]);
+    // Unicode isolation marks around placeables show up as stray glyphs in
+    // most terminals.
+    bundle.set_use_isolating(false);
+    bundle
+        .add_resource(resource)
+        .expect("bundled catalog has no duplicate ids");
+    bundle
+}
+
+/// Select the locale for this process.
+///
+/// `configured` is the `locale` setting; without one the locale comes from
+/// the environment. Only the first call has an effect.
+pub fn init(configured: Option<&str>) {
+    let locale = resolve_locale(configured, |var| std::env::var(var).ok());
+    let _ = CATALOG.set(Catalog::new(&locale));
+}
+
+/// The process-wide catalog (`en-US` until [`init`] runs).
+pub fn catalog() -> &'static Catalog {
+    CATALOG.get_or_init(|| Catalog::new(&FALLBACK_LOCALE.parse().expect("valid")))
+}
+
+/// Pick the locale from the setting, then the first usable environment
+/// variable, then `en-US`.
+///
+/// POSIX values such as `de_DE.UTF-8` or `fr_FR@euro` are accepted; `C` and
+/// `POSIX` mean "no preference".
+pub fn resolve_locale(
+    configured: Option<&str>,
+    env: impl Fn(&str) -> Option<String>,
+) -> LanguageIdentifier {
+    configured
+        .and_then(parse_locale)
+        .or_else(|| {
+            LOCALE_ENV_VARS
+                .iter()
+                .filter_map(|var| env(var))
+                .find_map(|value| parse_locale(&value))
+        })
+        .unwrap_or_else(|| FALLBACK_LOCALE.parse().expect("valid"))
+}
+
+fn parse_locale(value: &str) -> Option<LanguageIdentifier> {
+    let tag = value.split(['.', '@']).next()?.trim().replace('_', "-");
+    if tag.is_empty() || tag.eq_ignore_ascii_case("c") || tag.eq_ignore_ascii_case("posix") {
+        return None;
+    }
+    tag.parse().ok()
+}
+
+/// Format a catalog message, with optional `name = value` arguments.
+///
+/// Arguments accept anything convertible to a Fluent value: strings are
+/// substituted as-is, numbers also drive plural selection.
+#[macro_export]
+macro_rules! tr {
+    ($id:expr) => {
+        $crate::presentation::i18n::catalog().message($id, None)
+    };
+    ($id:expr, $($name:ident = $value:expr),+ $(,)?) => {{
+        let mut args = $crate::presentation::i18n::FluentArgs::new();
+        $(args.set(stringify!($name), $value);)+
+        $crate::presentation::i18n::catalog().message($id, Some(&args))
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn catalog_for(tag: &str) -> Catalog {
+        Catalog::new(&tag.parse().unwrap())
+    }
+
+    /// Message ids: unindented `id =` lines (comments and terms excluded).
+    fn ids(source: &str) -> Vec<&str> {
+        source
+            .lines()
+            .filter(|line| line.starts_with(|c: char| c.is_ascii_alphabetic()))
+            .filter_map(|line| line.split_once('=').map(|(id, _)| id.trim()))
+            .collect()
+    }
+
+    #[test]
+    fn translations_only_use_ids_from_the_reference_catalog() {
+        let reference = ids(CATALOGS[0].1);
+        for (tag, source) in &CATALOGS[1..] {
+            for id in ids(source) {
+                assert!(reference.contains(&id), "{tag} defines unknown id {id}");
+            }
+        }
+    }
+
+    #[test]
+    fn plurals_and_arguments_are_applied() {
+        let en = catalog_for("en-US");
+        let mut args = FluentArgs::new();
+        args.set("count", 1);
+        assert_eq!(en.message("time-hours-ago", Some(&args)), "1 hour ago");
+        args.set("count", 3);
+        assert_eq!(en.message("time-hours-ago", Some(&args)), "3 hours ago");
+
+        let de = catalog_for("de-AT");
+        assert_eq!(de.locale().to_string(), "de-AT");
+        assert_eq!(de.message("time-hours-ago", Some(&args)), "vor 3 Stunden");
+    }
+
+    #[test]
+    fn unknown_locales_and_ids_fall_back() {
+        let ja = catalog_for("ja-JP");
+        assert_eq!(ja.locale().to_string(), "ja-JP");
+        assert_eq!(ja.message("time-yesterday", None), "yesterday");
+        assert_eq!(ja.message("no-such-id", None), "no-such-id");
+    }
+
+    #[test]
+    fn locale_comes_from_setting_then_environment() {
+        let env = |var: &str| match var {
+            "LC_ALL" => Some("C".to_string()),
+            "LANG" => Some("de_DE.UTF-8".to_string()),
+            _ => None,
+        };
+        assert_eq!(resolve_locale(Some("fr-CA"), env).to_string(), "fr-CA");
+        assert_eq!(resolve_locale(None, env).to_string(), "de-DE");
+        assert_eq!(resolve_locale(None, |_| None).to_string(), FALLBACK_LOCALE);
+    }
+}
@@ -33,6 +33,9 @@ pub fn print_model_detail(dto: &ModelDetailDto, show_metadata: bool) {
     if let Some(ctx) = dto.context_length {
         println!("  Context Length : {ctx} tokens");
     }
+    if let Some(license) = &dto.license {
+        println!("  License        : {license}");
+    }
     if dto.is_serving {
         let port_str = dto.port.map(|p| format!(" (port {p})")).unwrap_or_default();
         println!("  Serving        : yes{port_str}");
@@ -106,6 +109,14 @@ pub fn print_model_detail(dto: &ModelDetailDto, show_metadata: bool) {
         "  supports-reasoning    : {}",
         flag_str(caps.contains(ModelCapabilities::SUPPORTS_REASONING))
     );
+    println!(
+        "  embedding             : {}",
+        flag_str(caps.contains(ModelCapabilities::EMBEDDING))
+    );
+    println!(
+        "  reranker              : {}",
+        flag_str(caps.contains(ModelCapabilities::RERANKER))
+    );
 
     // ── Inference Defaults ────────────────────────────────────────────────────
     if let Some(inf) = &dto.inference_defaults {
@@ -11,12 +11,17 @@
 //! - Domain transforms belong in core services or CLI-local view-model helpers
 
 pub mod dag;
+pub mod format;
+pub mod i18n;
 pub mod input;
 pub mod inspect_display;
 pub mod model_display;
+pub mod output;
 pub mod style;
 pub mod tables;
 
 // Re-export commonly used items
+pub use format::{NumberFormat, format_bytes, format_decimal, format_duration};
 pub use model_display::{DisplayStyle, ModelSummaryOpts, display_model_summary};
+pub use output::{OutputFormat, print_json};
 pub use tables::{format_optional, format_relative_time, print_separator, truncate_string};
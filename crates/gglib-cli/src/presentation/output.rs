@@ -0,0 +1,35 @@
+//! Output format selection for list-style commands.
+//!
+//! The global `--output json|table` flag picks between the usual
+//! human-readable tables and machine-readable JSON on stdout. In JSON mode a
+//! command prints exactly one JSON document — an array for lists, even when
+//! empty — and no hints, banners or emoji, so the output can be piped
+//! straight into `jq`.
+
+use anyhow::Result;
+use clap::ValueEnum;
+use serde::Serialize;
+
+/// How list-style commands render their results.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    /// Human-readable tables (default)
+    #[default]
+    Table,
+    /// A single JSON document on stdout
+    Json,
+}
+
+impl OutputFormat {
+    /// Whether results should be printed as JSON.
+    #[must_use]
+    pub const fn is_json(self) -> bool {
+        matches!(self, Self::Json)
+    }
+}
+
+/// Print `value` to stdout as pretty-printed JSON.
+pub fn print_json<T: Serialize + ?Sized>(value: &T) -> Result<()> {
+    println!("{}", serde_json::to_string_pretty(value)?);
+    Ok(())
+}
@@ -5,7 +5,8 @@ use chrono::{NaiveDateTime, Utc};
 /// Format a SQLite datetime string as a human-readable relative time.
 ///
 /// Returns strings like "just now", "5 min ago", "3 hours ago", "2 days ago",
-/// or the original date if more than 30 days old.
+/// or the original date if more than 30 days old. Phrases come from the
+/// message catalog, so they follow the CLI locale.
 ///
 /// Falls back to the raw string on parse failure.
 pub fn format_relative_time(datetime_str: &str) -> String {
@@ -21,27 +22,13 @@ pub fn format_relative_time(datetime_str: &str) -> String {
     }
 
     match secs {
-        0..=59 => "just now".to_string(),
-        60..=3599 => {
-            let m = secs / 60;
-            format!("{m} min ago")
-        }
-        3600..=86399 => {
-            let h = secs / 3600;
-            if h == 1 {
-                "1 hour ago".to_string()
-            } else {
-                format!("{h} hours ago")
-            }
-        }
-        86400..=2_591_999 => {
-            let d = secs / 86400;
-            if d == 1 {
-                "yesterday".to_string()
-            } else {
-                format!("{d} days ago")
-            }
-        }
+        0..=59 => crate::tr!("time-just-now"),
+        60..=3599 => crate::tr!("time-minutes-ago", count = secs / 60),
+        3600..=86399 => crate::tr!("time-hours-ago", count = secs / 3600),
+        86400..=2_591_999 => match secs / 86400 {
+            1 => crate::tr!("time-yesterday"),
+            days => crate::tr!("time-days-ago", count = days),
+        },
         _ => dt.format("%Y-%m-%d").to_string(),
     }
 }
@@ -42,6 +42,7 @@ pub struct ContextArgs {
     /// Context size override (number or 'max' for model metadata).
     /// Resolved through a 4-level fallback chain:
     /// runtime flag → per-model server_defaults (from DB) → global default → hardcoded 4096.
+    /// `serve` rejects a size above the model's maximum.
     #[arg(short, long)]
     pub ctx_size: Option<String>,
     /// Enable memory lock
@@ -80,6 +81,77 @@ pub struct MtpArgs {
     pub mtp_draft_p_min: Option<f32>,
 }
 
+/// `RoPE` scaling overrides for the `serve` command.
+///
+/// Lets a model run past the context length its GGUF advertises. Every flag
+/// is optional; omitted flags leave llama-server to read the GGUF metadata.
+#[derive(Args, Debug, Clone, Default)]
+pub struct RopeArgs {
+    /// `RoPE` scaling method: none, linear, or yarn
+    #[arg(long)]
+    pub rope_scaling: Option<gglib_core::domain::RopeScalingType>,
+    /// `RoPE` base frequency (overrides the GGUF value)
+    #[arg(long)]
+    pub rope_freq_base: Option<f32>,
+    /// `RoPE` frequency scale factor; 0.25 stretches the usable context 4×
+    #[arg(long)]
+    pub rope_freq_scale: Option<f32>,
+    /// Original training context for `YaRN` scaling
+    #[arg(long)]
+    pub yarn_orig_ctx: Option<u64>,
+}
+
+impl RopeArgs {
+    /// Convert into a [`RopeConfig`](gglib_core::domain::RopeConfig).
+    pub fn into_rope_config(self) -> gglib_core::domain::RopeConfig {
+        gglib_core::domain::RopeConfig {
+            scaling: self.rope_scaling,
+            freq_base: self.rope_freq_base,
+            freq_scale: self.rope_freq_scale,
+            yarn_orig_ctx: self.yarn_orig_ctx,
+        }
+    }
+}
+
+/// Thread-count and CPU-placement overrides for the `serve` command.
+///
+/// Omitted flags resolve from the host CPU topology: one thread per
+/// physical performance core, pinned to the performance cores on a hybrid
+/// CPU (set `GGLIB_DISABLE_CPU_PINNING=1` to skip the pinning).
+#[derive(Args, Debug, Clone, Default)]
+pub struct CpuArgs {
+    /// Generation threads (default: physical performance cores)
+    #[arg(long)]
+    pub threads: Option<u32>,
+    /// Prompt-processing threads (default: same as --threads)
+    #[arg(long)]
+    pub threads_batch: Option<u32>,
+    /// CPUs to pin threads to, as a list like 0-7,12
+    #[arg(long)]
+    pub cpu_affinity: Option<gglib_core::cpu_config::CpuSet>,
+    /// NUMA strategy: distribute, isolate, or numactl
+    #[arg(long)]
+    pub numa: Option<gglib_core::cpu_config::NumaStrategy>,
+}
+
+/// Multi-GPU placement overrides for the `serve` command.
+///
+/// With two or more GPUs and no `--tensor-split`, the model is split in
+/// proportion to each GPU's free VRAM (set `GGLIB_DISABLE_TENSOR_SPLIT=1`
+/// to leave the split to llama-server).
+#[derive(Args, Debug, Clone, Default)]
+pub struct GpuArgs {
+    /// How to spread the model over GPUs: none, layer, or row
+    #[arg(long)]
+    pub split_mode: Option<gglib_core::gpu_config::SplitMode>,
+    /// Share of the model per GPU, as a list like 3,1 (default: free VRAM)
+    #[arg(long)]
+    pub tensor_split: Option<gglib_core::gpu_config::TensorSplit>,
+    /// GPU to run on with --split-mode none (default: most free VRAM)
+    #[arg(long)]
+    pub main_gpu: Option<u32>,
+}
+
 /// Serve-command options that don't belong to another group.
 #[derive(Args, Debug, Clone)]
 pub struct ServeOptions {
@@ -89,6 +161,13 @@ pub struct ServeOptions {
     /// Port to serve on
     #[arg(short, long, default_value = "8080")]
     pub port: u16,
+    /// Parallel request slots; each slot gets the full context size
+    /// (overrides the model's launch defaults, default: 1)
+    #[arg(long)]
+    pub parallel: Option<u32>,
+    /// Disable continuous batching of concurrent requests
+    #[arg(long)]
+    pub no_cont_batching: bool,
 }
 
 impl Default for ServeOptions {
@@ -96,6 +175,8 @@ impl Default for ServeOptions {
         Self {
             jinja: false,
             port: 8080,
+            parallel: None,
+            no_cont_batching: false,
         }
     }
 }
@@ -0,0 +1,29 @@
+//! Subcommands for `gglib stats`.
+
+use clap::Subcommand;
+
+use gglib_core::domain::usage::DEFAULT_SUMMARY_DAYS;
+
+/// Subcommands available under `gglib stats`.
+#[derive(Clone, Subcommand)]
+pub enum StatsCommand {
+    /// Rank models by usage — requests, tokens, serve hours and energy
+    #[command(display_order = 1)]
+    Models {
+        /// Only count the last N days (default: all recorded usage)
+        #[arg(long, value_name = "N", value_parser = clap::value_parser!(u32).range(1..))]
+        days: Option<u32>,
+    },
+    /// Summarize each day's usage with estimated energy and cost
+    #[command(display_order = 2)]
+    Daily {
+        /// Number of days to list, today included
+        #[arg(
+            long,
+            value_name = "N",
+            default_value_t = DEFAULT_SUMMARY_DAYS,
+            value_parser = clap::value_parser!(u32).range(1..)
+        )]
+        days: u32,
+    },
+}
@@ -0,0 +1,26 @@
+//! Subcommands for `gglib template`.
+
+use clap::Subcommand;
+
+/// Subcommands available under `gglib template`.
+#[derive(Clone, Subcommand)]
+pub enum TemplateCommand {
+    /// Render golden conversations through a model's chat template and
+    /// report anomalies
+    ///
+    /// Runs a system + user exchange, a multi-turn exchange and a tool call
+    /// through the model's template and checks each rendering for dropped
+    /// messages, unbalanced turn markers, and a missing or doubled BOS
+    /// token. Exits non-zero when any conversation shows an anomaly.
+    #[command(display_order = 1)]
+    Test {
+        /// Model name or ID
+        model: String,
+        /// Reuse an already-running llama-server on this port (skips auto-start)
+        #[arg(long)]
+        port: Option<u16>,
+        /// Print each rendered prompt
+        #[arg(long)]
+        show_prompts: bool,
+    },
+}
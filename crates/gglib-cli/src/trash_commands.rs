@@ -0,0 +1,24 @@
+//! Subcommands for `gglib trash`.
+
+use clap::Subcommand;
+
+/// Subcommands available under `gglib trash`.
+#[derive(Clone, Subcommand)]
+pub enum TrashCommand {
+    /// List removed models waiting in the trash
+    #[command(display_order = 1)]
+    List,
+    /// Move a removed model's files back and return it to the library
+    #[command(display_order = 2)]
+    Restore {
+        /// ID of the trashed model (see `gglib trash list`)
+        id: i64,
+    },
+    /// Permanently delete every model in the trash
+    #[command(display_order = 3)]
+    Empty {
+        /// Skip confirmation prompt
+        #[arg(short, long)]
+        force: bool,
+    },
+}
@@ -0,0 +1,249 @@
+//! CPU placement types for llama-server launches: NUMA strategy and CPU sets.
+//!
+//! Kept beside `cache_config` as a standalone module so thread/affinity
+//! resolution (in `gglib-runtime`) and the launch DTOs that carry these
+//! values share one definition.
+
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+use utoipa::ToSchema;
+
+// =============================================================================
+// NUMA strategy (`--numa`)
+// =============================================================================
+
+/// A llama.cpp NUMA placement strategy, as accepted by `--numa`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, TS, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum NumaStrategy {
+    /// Spread execution evenly over all nodes.
+    Distribute,
+    /// Only spawn threads on CPUs of the node execution started on.
+    Isolate,
+    /// Use the CPU map provided by `numactl`.
+    Numactl,
+}
+
+impl NumaStrategy {
+    /// The value passed on the command line.
+    #[must_use]
+    pub const fn as_llama_arg(self) -> &'static str {
+        match self {
+            Self::Distribute => "distribute",
+            Self::Isolate => "isolate",
+            Self::Numactl => "numactl",
+        }
+    }
+}
+
+impl FromStr for NumaStrategy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "distribute" => Ok(Self::Distribute),
+            "isolate" => Ok(Self::Isolate),
+            "numactl" => Ok(Self::Numactl),
+            other => Err(format!(
+                "unknown NUMA strategy {other:?} (expected one of: distribute, isolate, numactl)"
+            )),
+        }
+    }
+}
+
+// =============================================================================
+// CPU set (`--cpu-mask`)
+// =============================================================================
+
+/// Highest logical CPU index accepted in a [`CpuSet`].
+///
+/// llama.cpp's `--cpu-mask` holds at most 512 CPUs (`GGML_MAX_N_THREADS`).
+pub const MAX_CPU_INDEX: u32 = 511;
+
+/// A set of logical CPU indices that llama-server threads are pinned to.
+///
+/// Written and parsed in the `taskset -c` list form (`0-7,12,14-15`) and
+/// serialized as that string. Always sorted and free of duplicates.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct CpuSet(Vec<u32>);
+
+impl CpuSet {
+    /// Build a set from CPU indices in any order.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the set is empty or an index exceeds
+    /// [`MAX_CPU_INDEX`].
+    pub fn new(cpus: impl IntoIterator<Item = u32>) -> Result<Self, String> {
+        let mut cpus: Vec<u32> = cpus.into_iter().collect();
+        cpus.sort_unstable();
+        cpus.dedup();
+        if cpus.is_empty() {
+            return Err("CPU set cannot be empty".to_string());
+        }
+        if let Some(&max) = cpus.last()
+            && max > MAX_CPU_INDEX
+        {
+            return Err(format!(
+                "CPU index {max} is out of range (maximum {MAX_CPU_INDEX})"
+            ));
+        }
+        Ok(Self(cpus))
+    }
+
+    /// The CPU indices, ascending.
+    #[must_use]
+    pub fn cpus(&self) -> &[u32] {
+        &self.0
+    }
+
+    /// Number of CPUs in the set.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Always `false`: a set holds at least one CPU.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// The set as the hex bitmask `--cpu-mask` expects (`0xff` for CPUs 0-7).
+    #[must_use]
+    pub fn to_hex_mask(&self) -> String {
+        let max = self.0.last().copied().unwrap_or(0);
+        let mut nibbles = vec![0u8; max as usize / 4 + 1];
+        for &cpu in &self.0 {
+            nibbles[cpu as usize / 4] |= 1 << (cpu % 4);
+        }
+        let digits: String = nibbles
+            .iter()
+            .rev()
+            .map(|n| char::from_digit(u32::from(*n), 16).unwrap_or('0'))
+            .collect();
+        format!("0x{digits}")
+    }
+}
+
+impl fmt::Display for CpuSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut first = true;
+        let mut i = 0;
+        while i < self.0.len() {
+            let start = self.0[i];
+            let mut end = start;
+            while i + 1 < self.0.len() && self.0[i + 1] == end + 1 {
+                i += 1;
+                end = self.0[i];
+            }
+            if !first {
+                f.write_str(",")?;
+            }
+            first = false;
+            if start == end {
+                write!(f, "{start}")?;
+            } else {
+                write!(f, "{start}-{end}")?;
+            }
+            i += 1;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for CpuSet {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parse = |part: &str| {
+            part.trim()
+                .parse::<u32>()
+                .map_err(|_| format!("invalid CPU index {:?} in CPU list {s:?}", part.trim()))
+        };
+        let mut cpus = Vec::new();
+        for part in s.split(',').filter(|p| !p.trim().is_empty()) {
+            if let Some((lo, hi)) = part.split_once('-') {
+                let (lo, hi) = (parse(lo)?, parse(hi)?);
+                if lo > hi {
+                    return Err(format!("descending CPU range {:?}", part.trim()));
+                }
+                if hi > MAX_CPU_INDEX {
+                    return Err(format!(
+                        "CPU index {hi} is out of range (maximum {MAX_CPU_INDEX})"
+                    ));
+                }
+                cpus.extend(lo..=hi);
+            } else {
+                cpus.push(parse(part)?);
+            }
+        }
+        Self::new(cpus)
+    }
+}
+
+impl TryFrom<String> for CpuSet {
+    type Error = String;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl From<CpuSet> for String {
+    fn from(set: CpuSet) -> Self {
+        set.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn numa_from_str_round_trips_through_as_llama_arg() {
+        for s in [
+            NumaStrategy::Distribute,
+            NumaStrategy::Isolate,
+            NumaStrategy::Numactl,
+        ] {
+            assert_eq!(NumaStrategy::from_str(s.as_llama_arg()), Ok(s));
+        }
+        assert!(NumaStrategy::from_str("interleave").is_err());
+    }
+
+    #[test]
+    fn cpu_set_parses_ranges_and_singles() {
+        let set: CpuSet = "8-11, 0-3,2,14".parse().unwrap();
+        assert_eq!(set.cpus(), &[0, 1, 2, 3, 8, 9, 10, 11, 14]);
+        assert_eq!(set.to_string(), "0-3,8-11,14");
+    }
+
+    #[test]
+    fn cpu_set_rejects_bad_input() {
+        assert!("".parse::<CpuSet>().is_err());
+        assert!("3-1".parse::<CpuSet>().is_err());
+        assert!("0-a".parse::<CpuSet>().is_err());
+        assert!("512".parse::<CpuSet>().is_err());
+    }
+
+    #[test]
+    fn cpu_set_hex_mask_sets_one_bit_per_cpu() {
+        assert_eq!(CpuSet::new(0..8).unwrap().to_hex_mask(), "0xff");
+        assert_eq!(CpuSet::new([0, 4]).unwrap().to_hex_mask(), "0x11");
+        assert_eq!(CpuSet::new([13]).unwrap().to_hex_mask(), "0x2000");
+    }
+
+    #[test]
+    fn cpu_set_serializes_as_list_string() {
+        let set = CpuSet::new([0, 1, 2, 5]).unwrap();
+        let json = serde_json::to_string(&set).unwrap();
+        assert_eq!(json, "\"0-2,5\"");
+        assert_eq!(serde_json::from_str::<CpuSet>(&json).unwrap(), set);
+        assert!(serde_json::from_str::<CpuSet>("\"x\"").is_err());
+    }
+}
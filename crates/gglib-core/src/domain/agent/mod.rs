@@ -14,4 +14,4 @@ pub use config::{
 };
 pub use events::{AGENT_EVENT_CHANNEL_CAPACITY, AgentEvent, LlmStreamEvent};
 pub use messages::{AgentMessage, AssistantContent};
-pub use tool_types::{ToolCall, ToolDefinition, ToolResult};
+pub use tool_types::{PLUGIN_PREFIX, ToolCall, ToolDefinition, ToolResult};
@@ -10,7 +10,7 @@
 
 /// Strip the routing prefix from a qualified tool name.
 ///
-/// Tool names carry a `"builtin:"` or `"{server_id}:"` prefix for
+/// Tool names carry a `"builtin:"`, `"plugin:"` or `"{server_id}:"` prefix for
 /// O(1) dispatch routing in `CombinedToolExecutor`.  This function
 /// removes that prefix for display purposes only.
 ///
@@ -19,6 +19,7 @@
 ///
 /// assert_eq!(strip_tool_prefix("builtin:read_file"), "read_file");
 /// assert_eq!(strip_tool_prefix("3:some_tool"), "some_tool");
+/// assert_eq!(strip_tool_prefix("plugin:jq.query"), "jq.query");
 /// assert_eq!(strip_tool_prefix("plain_name"), "plain_name");
 /// ```
 pub fn strip_tool_prefix(name: &str) -> &str {
@@ -2,6 +2,13 @@
 
 use serde::{Deserialize, Serialize};
 
+/// Name prefix of tools exported by installed WASM plugins.
+///
+/// Plugin tools are listed as `"plugin:<plugin>.<tool>"`; the prefix routes
+/// calls to the plugin host the same way `"builtin:"` routes to the
+/// in-process tools.
+pub const PLUGIN_PREFIX: &str = "plugin:";
+
 // =============================================================================
 // Tool schema
 // =============================================================================
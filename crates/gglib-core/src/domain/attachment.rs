@@ -0,0 +1,149 @@
+//! Chat attachment domain types.
+//!
+//! Attachments are files (images, documents) uploaded alongside chat
+//! messages. The bytes live in a content-addressed blob store keyed by their
+//! SHA-256, so uploading the same file twice stores it once; an
+//! [`Attachment`] row records the name, type and owning message.
+//!
+//! Messages refer to attachments with an `attachment` content part:
+//!
+//! ```json
+//! [{"type": "text", "text": "What is in this picture?"},
+//!  {"type": "attachment", "attachment_id": 7}]
+//! ```
+//!
+//! The part is resolved into model-ready content (an `image_url` data URL
+//! or inline text) just before the request reaches llama-server.
+
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// `type` tag of an attachment reference content part.
+pub const ATTACHMENT_PART_TYPE: &str = "attachment";
+
+/// Default upload limit, in MiB, when `Settings::max_attachment_size_mb`
+/// is unset.
+pub const DEFAULT_MAX_ATTACHMENT_SIZE_MB: u32 = 20;
+
+/// Upper bound accepted for `Settings::max_attachment_size_mb`.
+pub const MAX_ATTACHMENT_SIZE_MB: u32 = 512;
+
+/// MIME types accepted when `Settings::allowed_attachment_types` is unset.
+///
+/// A trailing `/*` matches every subtype.
+pub const DEFAULT_ALLOWED_ATTACHMENT_TYPES: &[&str] =
+    &["image/*", "text/*", "application/json", "application/pdf"];
+
+/// Metadata for a stored attachment.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct Attachment {
+    pub id: i64,
+    /// Owning message; `None` until the attachment is sent with one.
+    pub message_id: Option<i64>,
+    /// Lowercase hex SHA-256 of the contents; the blob store key.
+    pub sha256: String,
+    pub file_name: String,
+    pub mime_type: String,
+    pub size_bytes: i64,
+    pub created_at: String,
+}
+
+/// Data for recording a newly stored attachment.
+#[derive(Debug, Clone)]
+pub struct NewAttachment {
+    pub sha256: String,
+    pub file_name: String,
+    pub mime_type: String,
+    pub size_bytes: i64,
+}
+
+/// Build an attachment reference content part for `attachment_id`.
+#[must_use]
+pub fn attachment_part(attachment_id: i64) -> serde_json::Value {
+    serde_json::json!({ "type": ATTACHMENT_PART_TYPE, "attachment_id": attachment_id })
+}
+
+/// The attachment ID referenced by `part`, if it is an attachment part.
+#[must_use]
+pub fn attachment_part_id(part: &serde_json::Value) -> Option<i64> {
+    if part.get("type").and_then(serde_json::Value::as_str) != Some(ATTACHMENT_PART_TYPE) {
+        return None;
+    }
+    part.get("attachment_id")
+        .and_then(serde_json::Value::as_i64)
+}
+
+/// Whether `mime_type` is matched by any of `allowed`.
+///
+/// Patterns are exact MIME types or `type/*` wildcards; matching ignores
+/// case and any `;charset=…` parameters.
+#[must_use]
+pub fn mime_type_allowed<S: AsRef<str>>(mime_type: &str, allowed: &[S]) -> bool {
+    let essence = mime_type
+        .split(';')
+        .next()
+        .unwrap_or_default()
+        .trim()
+        .to_ascii_lowercase();
+    allowed.iter().any(|pattern| {
+        let pattern = pattern.as_ref().trim().to_ascii_lowercase();
+        pattern.strip_suffix("/*").map_or_else(
+            || essence == pattern,
+            |top| {
+                essence
+                    .split_once('/')
+                    .is_some_and(|(t, sub)| t == top && !sub.is_empty())
+            },
+        )
+    })
+}
+
+/// Whether attachments of `mime_type` are inlined into prompts as text.
+#[must_use]
+pub fn is_text_mime_type(mime_type: &str) -> bool {
+    mime_type_allowed(
+        mime_type,
+        &[
+            "text/*",
+            "application/json",
+            "application/xml",
+            "application/x-yaml",
+        ],
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wildcard_and_exact_types_match() {
+        assert!(mime_type_allowed(
+            "image/png",
+            DEFAULT_ALLOWED_ATTACHMENT_TYPES
+        ));
+        assert!(mime_type_allowed(
+            "Text/Plain; charset=utf-8",
+            DEFAULT_ALLOWED_ATTACHMENT_TYPES
+        ));
+        assert!(mime_type_allowed(
+            "application/pdf",
+            DEFAULT_ALLOWED_ATTACHMENT_TYPES
+        ));
+        assert!(!mime_type_allowed(
+            "application/zip",
+            DEFAULT_ALLOWED_ATTACHMENT_TYPES
+        ));
+        assert!(!mime_type_allowed(
+            "image",
+            DEFAULT_ALLOWED_ATTACHMENT_TYPES
+        ));
+    }
+
+    #[test]
+    fn attachment_parts_round_trip() {
+        assert_eq!(attachment_part_id(&attachment_part(7)), Some(7));
+        let text = serde_json::json!({"type": "text", "text": "hi"});
+        assert_eq!(attachment_part_id(&text), None);
+    }
+}
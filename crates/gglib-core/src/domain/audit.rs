@@ -0,0 +1,175 @@
+//! Audit trail of administrative actions.
+//!
+//! When several people share one instance, the audit log answers "who
+//! removed that model?": each [`AuditEntry`] records when an action
+//! happened, who took it and what it touched. Actors are labels, not
+//! accounts — `ip:<address>`, followed by `key:<fingerprint>` when the
+//! client presented an API key (see [`api_key_actor`]), optionally prefixed
+//! with the user name the client sent.
+
+use std::fmt;
+use std::str::FromStr;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use utoipa::ToSchema;
+
+/// Entries returned when no limit is asked for.
+pub const DEFAULT_AUDIT_LIMIT: u32 = 100;
+
+/// An administrative action worth recording.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum AuditAction {
+    /// A model was removed from the library (moved to the trash).
+    ModelRemoved,
+    /// The model trash was emptied.
+    TrashEmptied,
+    /// Application settings were changed.
+    SettingsChanged,
+    /// A model server was started.
+    ServerStarted,
+    /// A model server was stopped.
+    ServerStopped,
+    /// Downloads were added to the queue.
+    DownloadQueued,
+    /// A queued or running download was cancelled.
+    DownloadCancelled,
+    /// A download was removed from the queue.
+    DownloadRemoved,
+    /// The download queue was reordered.
+    DownloadReordered,
+    /// Failed downloads were cleared from the queue.
+    DownloadFailuresCleared,
+}
+
+impl AuditAction {
+    /// Every action, in declaration order.
+    pub const ALL: [Self; 10] = [
+        Self::ModelRemoved,
+        Self::TrashEmptied,
+        Self::SettingsChanged,
+        Self::ServerStarted,
+        Self::ServerStopped,
+        Self::DownloadQueued,
+        Self::DownloadCancelled,
+        Self::DownloadRemoved,
+        Self::DownloadReordered,
+        Self::DownloadFailuresCleared,
+    ];
+
+    /// The name stored and shown for this action.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::ModelRemoved => "model_removed",
+            Self::TrashEmptied => "trash_emptied",
+            Self::SettingsChanged => "settings_changed",
+            Self::ServerStarted => "server_started",
+            Self::ServerStopped => "server_stopped",
+            Self::DownloadQueued => "download_queued",
+            Self::DownloadCancelled => "download_cancelled",
+            Self::DownloadRemoved => "download_removed",
+            Self::DownloadReordered => "download_reordered",
+            Self::DownloadFailuresCleared => "download_failures_cleared",
+        }
+    }
+
+    /// Parse a name produced by [`as_str`](Self::as_str).
+    #[must_use]
+    pub fn parse(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|action| action.as_str() == name)
+    }
+}
+
+impl fmt::Display for AuditAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for AuditAction {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s).ok_or_else(|| {
+            let names: Vec<_> = Self::ALL.iter().map(|a| a.as_str()).collect();
+            format!(
+                "unknown audit action '{s}' (expected one of: {})",
+                names.join(", ")
+            )
+        })
+    }
+}
+
+/// One recorded action.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct AuditEntry {
+    pub id: i64,
+    pub at: DateTime<Utc>,
+    /// Who took the action.
+    pub actor: String,
+    pub action: AuditAction,
+    /// What the action touched — a model, server or download ID — when it
+    /// names one.
+    pub target: Option<String>,
+    /// The request's parameters, with secret values masked.
+    pub summary: Option<String>,
+}
+
+/// An action to record.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NewAuditEntry {
+    pub actor: String,
+    pub action: AuditAction,
+    pub target: Option<String>,
+    pub summary: Option<String>,
+}
+
+/// Which entries to list, newest first.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AuditFilter {
+    pub action: Option<AuditAction>,
+    /// Case-insensitive substring of the actor.
+    pub actor: Option<String>,
+    /// Only entries at or after this time.
+    pub since: Option<DateTime<Utc>>,
+    /// At most this many entries ([`DEFAULT_AUDIT_LIMIT`] when `None`).
+    pub limit: Option<u32>,
+}
+
+/// The actor label for a client presenting `key`: a short fingerprint that
+/// tells keys apart without revealing them.
+#[must_use]
+pub fn api_key_actor(key: &str) -> String {
+    let digest = Sha256::digest(key.as_bytes());
+    let fingerprint = u32::from_be_bytes([digest[0], digest[1], digest[2], digest[3]]);
+    format!("key:{fingerprint:08x}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn action_names_round_trip() {
+        for action in AuditAction::ALL {
+            assert_eq!(action.as_str().parse::<AuditAction>(), Ok(action));
+            assert_eq!(
+                serde_json::to_value(action).unwrap(),
+                serde_json::Value::from(action.as_str())
+            );
+        }
+        assert!("model_deleted".parse::<AuditAction>().is_err());
+    }
+
+    #[test]
+    fn key_actors_are_stable_and_do_not_leak_the_key() {
+        let actor = api_key_actor("secret-token");
+        assert_eq!(actor, api_key_actor("secret-token"));
+        assert_ne!(actor, api_key_actor("other-token"));
+        assert!(actor.starts_with("key:") && actor.len() == 12);
+        assert!(!actor.contains("secret"));
+    }
+}
@@ -0,0 +1,256 @@
+//! Cloud backup domain types.
+//!
+//! Backups go to an S3-compatible bucket (AWS S3, `MinIO`, R2, ...) configured
+//! by the `backup` setting. Everything lives under the config's prefix:
+//!
+//! ```text
+//! <prefix>/db/gglib-20261017T093000Z.db   one object per database backup
+//! <prefix>/models/<relative path>         one object per model file
+//! ```
+//!
+//! Model objects are keyed by their path below the models directory, so a
+//! restore puts each file back where the library expects it, and pushing
+//! the library again only uploads files the bucket does not already hold at
+//! the same size.
+
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+use utoipa::ToSchema;
+
+/// Region used when the config does not set one. `MinIO` ignores it, but the
+/// request signature still needs a value.
+pub const DEFAULT_BACKUP_REGION: &str = "us-east-1";
+
+/// Key prefix used when the config does not set one.
+pub const DEFAULT_BACKUP_PREFIX: &str = "gglib";
+
+/// Cloud backup settings.
+///
+/// Stored as the `backup` setting; `None` there disables backups.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, TS, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct BackupConfig {
+    /// Bucket the backups are written to. It must already exist.
+    pub bucket: String,
+    /// Endpoint of an S3-compatible service, e.g. `http://nas.local:9000`
+    /// for `MinIO`. `None` means AWS S3.
+    #[serde(default)]
+    pub endpoint: Option<String>,
+    /// Region of the bucket. Defaults to [`DEFAULT_BACKUP_REGION`].
+    #[serde(default)]
+    pub region: Option<String>,
+    /// Key prefix all backup objects are stored under. Defaults to
+    /// [`DEFAULT_BACKUP_PREFIX`].
+    #[serde(default)]
+    pub prefix: Option<String>,
+    /// Access key ID.
+    pub access_key_id: String,
+    /// Secret access key, or a `secret:` reference to it.
+    pub secret_access_key: String,
+    /// Address the bucket as `<endpoint>/<bucket>` instead of
+    /// `<bucket>.<endpoint>`. `MinIO` needs this.
+    #[serde(default)]
+    pub path_style: bool,
+    /// Upload model files along with the database by default.
+    #[serde(default)]
+    pub include_models: bool,
+}
+
+impl BackupConfig {
+    /// The effective region (with default fallback).
+    #[must_use]
+    pub fn effective_region(&self) -> &str {
+        self.region.as_deref().unwrap_or(DEFAULT_BACKUP_REGION)
+    }
+
+    /// The effective key prefix, without surrounding slashes.
+    #[must_use]
+    pub fn effective_prefix(&self) -> &str {
+        self.prefix
+            .as_deref()
+            .map_or(DEFAULT_BACKUP_PREFIX, |p| p.trim_matches('/'))
+    }
+
+    /// Key prefix of database backups, ending in `/`.
+    #[must_use]
+    pub fn database_prefix(&self) -> String {
+        self.join("db/")
+    }
+
+    /// Key prefix of model files, ending in `/`.
+    #[must_use]
+    pub fn models_prefix(&self) -> String {
+        self.join("models/")
+    }
+
+    /// Key of a database backup taken at `timestamp` (`%Y%m%dT%H%M%SZ`).
+    #[must_use]
+    pub fn database_key(&self, timestamp: &str) -> String {
+        format!("{}gglib-{timestamp}.db", self.database_prefix())
+    }
+
+    /// Key of the model file at `relative_path` below the models directory.
+    #[must_use]
+    pub fn model_key(&self, relative_path: &str) -> String {
+        format!("{}{relative_path}", self.models_prefix())
+    }
+
+    /// The path below the models directory that a model object restores to,
+    /// or `None` if `key` is not a model object. Keys that would escape the
+    /// models directory are rejected.
+    #[must_use]
+    pub fn model_path<'a>(&self, key: &'a str) -> Option<&'a str> {
+        let path = key.strip_prefix(&self.models_prefix())?;
+        let safe = !path.is_empty()
+            && !path.starts_with('/')
+            && path
+                .split(['/', '\\'])
+                .all(|part| !part.is_empty() && part != "..");
+        safe.then_some(path)
+    }
+
+    fn join(&self, path: &str) -> String {
+        match self.effective_prefix() {
+            "" => path.to_string(),
+            prefix => format!("{prefix}/{path}"),
+        }
+    }
+
+    /// Check the bucket, credentials and endpoint are usable.
+    ///
+    /// # Errors
+    ///
+    /// Returns a human-readable description of the first problem found.
+    pub fn validate(&self) -> Result<(), String> {
+        if self.bucket.trim().is_empty() {
+            return Err("bucket is required".to_string());
+        }
+        if self.access_key_id.trim().is_empty() || self.secret_access_key.trim().is_empty() {
+            return Err("access key ID and secret access key are required".to_string());
+        }
+        if let Some(endpoint) = &self.endpoint
+            && !(endpoint.starts_with("http://") || endpoint.starts_with("https://"))
+        {
+            return Err(format!(
+                "endpoint must be an http:// or https:// URL, got '{endpoint}'"
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// What a backup object holds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BackupObjectKind {
+    Database,
+    Model,
+}
+
+/// An object in the backup bucket.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BackupObject {
+    /// Full object key.
+    pub key: String,
+    /// Size in bytes.
+    pub size: u64,
+    /// Last modification time (RFC 3339), when the service reports it.
+    #[serde(default)]
+    pub last_modified: Option<String>,
+}
+
+impl BackupObject {
+    /// The last segment of the key: a model's file name, or
+    /// `gglib-<timestamp>.db` for a database backup.
+    #[must_use]
+    pub fn name(&self) -> &str {
+        self.key.rsplit('/').next().unwrap_or(&self.key)
+    }
+}
+
+/// Direction of a backup transfer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, TS, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum BackupDirection {
+    Upload,
+    Download,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config() -> BackupConfig {
+        BackupConfig {
+            bucket: "archive".to_string(),
+            endpoint: Some("http://nas.local:9000".to_string()),
+            region: None,
+            prefix: None,
+            access_key_id: "minio".to_string(),
+            secret_access_key: "minio123".to_string(),
+            path_style: true,
+            include_models: false,
+        }
+    }
+
+    #[test]
+    fn keys_live_under_the_prefix() {
+        let mut config = config();
+        assert_eq!(
+            config.database_key("20261017T093000Z"),
+            "gglib/db/gglib-20261017T093000Z.db"
+        );
+        assert_eq!(config.model_key("a.gguf"), "gglib/models/a.gguf");
+        assert_eq!(
+            config.model_path("gglib/models/org/a.gguf"),
+            Some("org/a.gguf")
+        );
+        assert_eq!(config.model_path("gglib/models/../a.gguf"), None);
+        assert_eq!(config.model_path("gglib/db/gglib-1.db"), None);
+
+        config.prefix = Some("/home/lab/".to_string());
+        assert_eq!(config.models_prefix(), "home/lab/models/");
+        config.prefix = Some(String::new());
+        assert_eq!(config.database_prefix(), "db/");
+    }
+
+    #[test]
+    fn validate_rejects_missing_fields_and_bad_endpoints() {
+        assert!(config().validate().is_ok());
+        assert!(
+            BackupConfig {
+                bucket: " ".to_string(),
+                ..config()
+            }
+            .validate()
+            .is_err()
+        );
+        assert!(
+            BackupConfig {
+                secret_access_key: String::new(),
+                ..config()
+            }
+            .validate()
+            .is_err()
+        );
+        assert!(
+            BackupConfig {
+                endpoint: Some("nas.local:9000".to_string()),
+                ..config()
+            }
+            .validate()
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn object_name_is_the_last_key_segment() {
+        let object = BackupObject {
+            key: "gglib/models/a.gguf".to_string(),
+            size: 1,
+            last_modified: None,
+        };
+        assert_eq!(object.name(), "a.gguf");
+    }
+}
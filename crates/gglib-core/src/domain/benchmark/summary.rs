@@ -2,12 +2,14 @@
 
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+use utoipa::ToSchema;
 
 /// Denormalised benchmark summary for a single model.
 ///
 /// Upserted in the same transaction as each new result so that the model list
 /// query can LEFT JOIN this table and show speed badges without extra round-trips.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, TS, ToSchema)]
 pub struct ModelBenchmarkSummary {
     /// Foreign key → `models.id`.
     pub model_id: i64,
@@ -16,9 +16,19 @@
 //! | Architecture registry | [`capabilities_from_architecture`] | At model import — reads `general.architecture` as a backstop when the GGUF ships without a chat template |
 //! | Request rewriting | [`transform_messages_for_capabilities`] | At proxy time — merges consecutive same-role messages for models that require strict turn alternation |
 //!
-//! The result of Layer 1 and Layer 2 is **OR-combined** and stored in
-//! `Model.capabilities`.  The proxy reads this value once per request via a
-//! single catalog lookup.
+//! The result of Layer 1 and Layer 2 is **OR-combined** (see
+//! [`detect_capabilities`]) and stored in `Model.capabilities`, stamped with
+//! [`CAPABILITY_DETECTOR_VERSION`].  The proxy reads this value once per
+//! request via a single catalog lookup.
+//!
+//! ## Detector versions
+//!
+//! Every model records which detector version produced its flags, or none
+//! when the flags were set by hand.  When detection improves, bump
+//! [`CAPABILITY_DETECTOR_VERSION`]: models stamped with an older version are
+//! re-detected from their stored metadata in the background at the next
+//! startup (and by `gglib model redetect`).  Hand-set flags are never
+//! re-detected unless asked for explicitly.
 //!
 //! ## 2. Response-side normalization pipeline
 //!
@@ -72,9 +82,25 @@
 //! [`infer_from_chat_template`] handles the request side.  Its response-side
 //! `<tool_call>` XML is handled by the `format:qwen-xml` tag pipeline.
 
+use std::collections::HashMap;
+use std::hash::BuildHasher;
+
 use bitflags::bitflags;
 use serde::{Deserialize, Serialize};
 
+use super::gguf::GgufCapabilities;
+
+/// Version of the capability detection heuristics.
+///
+/// Bump this whenever [`infer_from_chat_template`],
+/// [`capabilities_from_architecture`] or [`capabilities_from_gguf`] change
+/// in a way that should reach models imported earlier.
+///
+/// History:
+/// - 1: first versioned detector (template, architecture and GGUF purpose
+///   flags).
+pub const CAPABILITY_DETECTOR_VERSION: u32 = 1;
+
 bitflags! {
     /// Model capabilities inferred from chat template analysis.
     ///
@@ -106,6 +132,18 @@ bitflags! {
         /// When set: model may produce <think> tags or reasoning_content.
         /// When unset: model produces only standard responses.
         const SUPPORTS_REASONING      = 0b0000_1000;
+
+        /// Model is an embedding model.
+        ///
+        /// When set: the model serves `/v1/embeddings` and is hidden from
+        /// chat model listings.
+        const EMBEDDING               = 0b0001_0000;
+
+        /// Model is a reranker.
+        ///
+        /// When set: the model serves `/v1/rerank` and is hidden from chat
+        /// model listings.
+        const RERANKER                = 0b0010_0000;
     }
 }
 
@@ -158,6 +196,21 @@ impl ModelCapabilities {
     pub const fn supports_reasoning(self) -> bool {
         self.contains(Self::SUPPORTS_REASONING)
     }
+
+    /// Check if model is an embedding model.
+    pub const fn is_embedding(self) -> bool {
+        self.contains(Self::EMBEDDING)
+    }
+
+    /// Check if model is a reranker.
+    pub const fn is_reranker(self) -> bool {
+        self.contains(Self::RERANKER)
+    }
+
+    /// Check if model can serve chat completions (neither embedding nor reranker).
+    pub const fn is_chat_model(self) -> bool {
+        !self.is_embedding() && !self.is_reranker()
+    }
 }
 
 /// Infer model capabilities from chat template Jinja source and model name.
@@ -375,6 +428,44 @@ pub fn capabilities_from_architecture(arch: Option<&str>) -> ModelCapabilities {
     }
 }
 
+/// Run every detection layer over a model's GGUF metadata.
+///
+/// `metadata` is the raw key/value map stored with the model and `gguf` the
+/// parser's capability detection over it. The chat-template, architecture
+/// and purpose layers are OR-combined, so any one signal is sufficient.
+/// This is what import, registration and re-detection all store.
+#[must_use]
+pub fn detect_capabilities<S: BuildHasher>(
+    metadata: &HashMap<String, String, S>,
+    gguf: &GgufCapabilities,
+) -> ModelCapabilities {
+    let template = metadata.get("tokenizer.chat_template");
+    let name = metadata.get("general.name");
+    let arch = metadata.get("general.architecture");
+    infer_from_chat_template(template.map(String::as_str), name.map(String::as_str))
+        | capabilities_from_architecture(arch.map(String::as_str))
+        | capabilities_from_gguf(gguf)
+}
+
+/// Map GGUF-detected model-purpose flags onto [`ModelCapabilities`].
+///
+/// Embedding and reranker detection lives in `gglib-gguf` (pooling metadata,
+/// encoder-only architectures); this lifts those flags onto the persisted
+/// model record so consumers such as the proxy's `/v1/models` listing can
+/// filter without re-parsing metadata.  OR-combined with the template and
+/// architecture layers at import time.
+#[must_use]
+pub fn capabilities_from_gguf(gguf: &GgufCapabilities) -> ModelCapabilities {
+    let mut caps = ModelCapabilities::empty();
+    if gguf.has_embedding() {
+        caps |= ModelCapabilities::EMBEDDING;
+    }
+    if gguf.has_reranker() {
+        caps |= ModelCapabilities::RERANKER;
+    }
+    caps
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -513,6 +604,26 @@ mod tests {
         assert!(capabilities_from_architecture(Some("future-arch-xyz")).is_empty());
     }
 
+    #[test]
+    fn test_gguf_embedding_flags_lift_onto_model_capabilities() {
+        use crate::domain::gguf::CapabilityFlags;
+
+        let gguf = GgufCapabilities {
+            flags: CapabilityFlags::EMBEDDING | CapabilityFlags::REASONING,
+            ..GgufCapabilities::empty()
+        };
+        let caps = capabilities_from_gguf(&gguf);
+        assert_eq!(caps, ModelCapabilities::EMBEDDING);
+        assert!(!caps.is_chat_model());
+
+        let gguf = GgufCapabilities {
+            flags: CapabilityFlags::RERANKER,
+            ..GgufCapabilities::empty()
+        };
+        assert!(capabilities_from_gguf(&gguf).is_reranker());
+        assert!(capabilities_from_gguf(&GgufCapabilities::empty()).is_chat_model());
+    }
+
     #[test]
     fn test_arch_mistral3_strict_turns_and_system_role() {
         let caps = capabilities_from_architecture(Some("mistral3"));
@@ -636,6 +747,19 @@ impl MessageContent {
         }
     }
 
+    /// IDs of the attachments referenced by `attachment` parts, in order.
+    ///
+    /// See [`crate::domain::attachment`] for the part shape.
+    pub fn attachment_ids(&self) -> Vec<i64> {
+        match self {
+            Self::Text(_) => Vec::new(),
+            Self::Parts(parts) => parts
+                .iter()
+                .filter_map(super::attachment::attachment_part_id)
+                .collect(),
+        }
+    }
+
     /// Consume into a single flat `String`.
     ///
     /// For [`Text`] the string is returned as-is.  For [`Parts`] all
@@ -7,13 +7,16 @@
 //! context, tools) so conversations can be faithfully resumed.
 
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
 use super::agent::messages::AgentMessage;
 use super::agent::messages::AssistantContent;
 use super::agent::tool_types::ToolCall;
+use super::inference::InferenceConfig;
+use super::model::Model;
 
 /// A chat conversation.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct Conversation {
     pub id: i64,
     pub title: String,
@@ -24,10 +27,14 @@ pub struct Conversation {
     pub settings: Option<ConversationSettings>,
     pub created_at: String,
     pub updated_at: String,
+    /// Held in memory only: never written to the database and discarded
+    /// when the app exits.
+    #[serde(default)]
+    pub incognito: bool,
 }
 
 /// A chat message within a conversation.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct Message {
     pub id: i64,
     pub conversation_id: i64,
@@ -37,6 +44,12 @@ pub struct Message {
     /// Optional JSON metadata for tool usage, etc.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub metadata: Option<serde_json::Value>,
+    /// ID of the original message when this one is a regeneration or edit.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version_of: Option<i64>,
+    /// ID of the revision that replaced this message; `None` while current.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub superseded_by: Option<i64>,
 }
 
 impl Message {
@@ -89,7 +102,7 @@ impl Message {
 }
 
 /// The role of a message sender.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "lowercase")]
 pub enum MessageRole {
     System,
@@ -137,6 +150,8 @@ pub struct NewConversation {
     pub system_prompt: Option<String>,
     /// Session parameters to persist for resume.
     pub settings: Option<ConversationSettings>,
+    /// Keep the conversation in memory only; see [`Conversation::incognito`].
+    pub incognito: bool,
 }
 
 /// Data for creating a new message.
@@ -149,6 +164,19 @@ pub struct NewMessage {
     pub metadata: Option<serde_json::Value>,
 }
 
+/// Outcome of regenerating or editing a message.
+///
+/// The replaced message and every message after it are kept but marked
+/// superseded, so they drop out of the active history while remaining
+/// reachable through the version list.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct MessageRevision {
+    /// ID of the newly stored message.
+    pub message_id: i64,
+    /// How many messages were superseded, including the replaced one.
+    pub superseded: i64,
+}
+
 /// Data for updating an existing conversation.
 #[derive(Debug, Clone, Default)]
 pub struct ConversationUpdate {
@@ -157,13 +185,15 @@ pub struct ConversationUpdate {
     pub system_prompt: Option<Option<String>>,
     /// Use `Some(Some(settings))` to set, `Some(None)` to clear, `None` to leave unchanged.
     pub settings: Option<Option<ConversationSettings>>,
+    /// Model to rebind the conversation to; `None` leaves it unchanged.
+    pub model_id: Option<i64>,
 }
 
 /// Session parameters captured at conversation creation for resume.
 ///
 /// Stores sampling, context, and tool configuration so a CLI or GUI session
 /// can be faithfully restored. Serialized as a JSON column in the database.
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default, ToSchema)]
 pub struct ConversationSettings {
     /// Model name or identifier used for this session.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -204,4 +234,63 @@ pub struct ConversationSettings {
     /// Whether tools were disabled entirely.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub no_tools: Option<bool>,
+    /// `HuggingFace` repo of the model, so a replacement can be suggested
+    /// if the model is deleted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hf_repo_id: Option<String>,
+    /// Quantization of the model (e.g. `Q4_K_M`).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quantization: Option<String>,
+}
+
+impl ConversationSettings {
+    /// Record `model` as the one this conversation runs on.
+    ///
+    /// Keeps its name, repo and quantization alongside the conversation's
+    /// `model_id`, which is all that is left to go on once the model row is
+    /// deleted.
+    pub fn bind_model(&mut self, model: &Model) {
+        self.model_name = Some(model.name.clone());
+        self.hf_repo_id.clone_from(&model.hf_repo_id);
+        self.quantization.clone_from(&model.quantization);
+    }
+
+    /// The saved sampling parameters, as a layer for inference resolution.
+    #[must_use]
+    pub const fn sampling(&self) -> InferenceConfig {
+        InferenceConfig {
+            temperature: self.temperature,
+            top_p: self.top_p,
+            top_k: self.top_k,
+            max_tokens: self.max_tokens,
+            repeat_penalty: self.repeat_penalty,
+            presence_penalty: None,
+            min_p: None,
+        }
+    }
+}
+
+/// A model offered in place of one that no longer exists.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct ModelSuggestion {
+    pub model_id: i64,
+    pub model_name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quantization: Option<String>,
+}
+
+/// Warning that the model a conversation was bound to has been deleted.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct ModelBindingWarning {
+    /// ID the conversation was bound to, if it recorded one.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub model_id: Option<i64>,
+    /// Name the model had when the conversation was bound to it.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub model_name: Option<String>,
+    /// Closest quantization of the same repo still in the library.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub suggestion: Option<ModelSuggestion>,
+    /// Human-readable summary for display.
+    pub message: String,
 }
@@ -0,0 +1,561 @@
+//! Conversations imported from other chat apps' data exports.
+//!
+//! [`parse_export`] reads the `conversations.json` file found in an `OpenAI`
+//! `ChatGPT` data export or an Anthropic Claude data export and turns each
+//! conversation into an [`ImportedConversation`] carrying its original
+//! timestamps, ready to be stored by the chat history repository.
+//!
+//! Only the visible text of a conversation comes across. In `ChatGPT` exports
+//! that is the branch leading to the conversation's current message — edits
+//! and regenerations left behind are dropped — with code cells and their
+//! output rendered as fenced code blocks. Files cannot be imported, so each
+//! message lists the ones it had under `metadata.imported_attachments`, and
+//! the text Claude extracted from a document attachment is appended to the
+//! message it was sent with.
+
+use std::collections::HashSet;
+use std::fmt::{self, Write as _};
+use std::str::FromStr;
+
+use chrono::{DateTime, Utc};
+use serde_json::{Value, json};
+use thiserror::Error;
+
+use super::chat::MessageRole;
+
+/// Title given to imported conversations that have none.
+const UNTITLED: &str = "Imported conversation";
+
+/// The app an export file came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChatExportFormat {
+    /// `OpenAI` `ChatGPT` data export (`conversations.json`).
+    ChatGpt,
+    /// Anthropic Claude data export (`conversations.json`).
+    Claude,
+}
+
+impl ChatExportFormat {
+    /// The name used on the command line.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::ChatGpt => "chatgpt",
+            Self::Claude => "claude",
+        }
+    }
+}
+
+impl fmt::Display for ChatExportFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for ChatExportFormat {
+    type Err = ChatImportError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "chatgpt" | "openai" => Ok(Self::ChatGpt),
+            "claude" | "anthropic" => Ok(Self::Claude),
+            other => Err(ChatImportError::Format(format!(
+                "unknown export format '{other}' (expected chatgpt or claude)"
+            ))),
+        }
+    }
+}
+
+/// Why an export file could not be read.
+#[derive(Debug, Error)]
+pub enum ChatImportError {
+    #[error("Export file is not valid JSON: {0}")]
+    Json(#[from] serde_json::Error),
+
+    #[error("Unexpected export file contents: {0}")]
+    Format(String),
+}
+
+/// A conversation read from an export file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImportedConversation {
+    pub title: String,
+    pub system_prompt: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub messages: Vec<ImportedMessage>,
+}
+
+/// A message read from an export file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImportedMessage {
+    pub role: MessageRole,
+    pub content: String,
+    pub created_at: DateTime<Utc>,
+    /// Attachments the message had, which were not imported.
+    pub metadata: Option<Value>,
+}
+
+/// Read every conversation with at least one message from the
+/// `conversations.json` of an export in `format`.
+///
+/// # Errors
+///
+/// Fails when `json` is not valid JSON or not shaped like that export.
+pub fn parse_export(
+    format: ChatExportFormat,
+    json: &str,
+) -> Result<Vec<ImportedConversation>, ChatImportError> {
+    let root: Value = serde_json::from_str(json)?;
+    let conversations = root.as_array().ok_or_else(|| {
+        ChatImportError::Format("expected a JSON array of conversations".to_string())
+    })?;
+    let parse = match format {
+        ChatExportFormat::ChatGpt => chatgpt_conversation,
+        ChatExportFormat::Claude => claude_conversation,
+    };
+    conversations
+        .iter()
+        .filter_map(|conversation| parse(conversation).transpose())
+        .collect()
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// ChatGPT
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// A `ChatGPT` conversation is a tree of nodes in `mapping`; `current_node`
+/// is the leaf the user last saw.
+fn chatgpt_conversation(value: &Value) -> Result<Option<ImportedConversation>, ChatImportError> {
+    let mapping = value
+        .get("mapping")
+        .and_then(Value::as_object)
+        .ok_or_else(|| {
+            ChatImportError::Format("ChatGPT conversation without a `mapping`".to_string())
+        })?;
+    let created_at = unix_time(value.get("create_time")).unwrap_or_else(Utc::now);
+    let updated_at = unix_time(value.get("update_time")).unwrap_or(created_at);
+
+    let mut branch = Vec::new();
+    let mut seen = HashSet::new();
+    let mut node_id = value
+        .get("current_node")
+        .and_then(Value::as_str)
+        .or_else(|| last_leaf(mapping));
+    while let Some(id) = node_id {
+        if !seen.insert(id) {
+            break;
+        }
+        let Some(node) = mapping.get(id) else { break };
+        branch.push(node);
+        node_id = node.get("parent").and_then(Value::as_str);
+    }
+    branch.reverse();
+
+    let mut system_prompt = None;
+    let mut messages = Vec::new();
+    for message in branch.iter().filter_map(|node| node.get("message")) {
+        if message.is_null() || is_hidden(message) {
+            continue;
+        }
+        let role = message
+            .pointer("/author/role")
+            .and_then(Value::as_str)
+            .unwrap_or_default();
+        let content = message.get("content").unwrap_or(&Value::Null);
+        let content_type = content
+            .get("content_type")
+            .and_then(Value::as_str)
+            .unwrap_or("text");
+        let role = match (role, content_type) {
+            ("user", _) => MessageRole::User,
+            // Code interpreter output reads as part of the reply.
+            ("assistant", _) | ("tool", "execution_output") => MessageRole::Assistant,
+            ("system", _) => {
+                let text = chatgpt_text(content);
+                if !text.is_empty() {
+                    system_prompt = Some(text);
+                }
+                continue;
+            }
+            _ => continue,
+        };
+        let text = chatgpt_text(content);
+        let attachments = chatgpt_attachments(message);
+        if text.is_empty() && attachments.is_empty() {
+            continue;
+        }
+        messages.push(ImportedMessage {
+            role,
+            content: text,
+            created_at: unix_time(message.get("create_time")).unwrap_or(created_at),
+            metadata: attachments_metadata(&attachments),
+        });
+    }
+    if messages.is_empty() {
+        return Ok(None);
+    }
+
+    Ok(Some(ImportedConversation {
+        title: title(value.get("title")),
+        system_prompt,
+        created_at,
+        updated_at,
+        messages,
+    }))
+}
+
+/// Without `current_node`, the most recently created leaf.
+fn last_leaf(mapping: &serde_json::Map<String, Value>) -> Option<&str> {
+    mapping
+        .iter()
+        .filter(|(_, node)| {
+            node.get("children")
+                .and_then(Value::as_array)
+                .is_none_or(Vec::is_empty)
+        })
+        .max_by(|(_, a), (_, b)| {
+            let time = |n: &Value| {
+                n.pointer("/message/create_time")
+                    .and_then(Value::as_f64)
+                    .unwrap_or_default()
+            };
+            time(a).total_cmp(&time(b))
+        })
+        .map(|(id, _)| id.as_str())
+}
+
+fn is_hidden(message: &Value) -> bool {
+    message
+        .pointer("/metadata/is_visually_hidden_from_conversation")
+        .and_then(Value::as_bool)
+        .unwrap_or(false)
+}
+
+/// The visible text of a `ChatGPT` message `content` object.
+fn chatgpt_text(content: &Value) -> String {
+    let content_type = content
+        .get("content_type")
+        .and_then(Value::as_str)
+        .unwrap_or("text");
+    let text = content.get("text").and_then(Value::as_str).unwrap_or("");
+    match content_type {
+        "code" => {
+            let language = content
+                .get("language")
+                .and_then(Value::as_str)
+                .filter(|l| *l != "unknown")
+                .unwrap_or("");
+            fence(language, text)
+        }
+        "execution_output" => fence("", text),
+        "text" | "multimodal_text" => content
+            .get("parts")
+            .and_then(Value::as_array)
+            .map(|parts| {
+                parts
+                    .iter()
+                    .filter_map(Value::as_str)
+                    .filter(|part| !part.trim().is_empty())
+                    .collect::<Vec<_>>()
+                    .join("\n\n")
+            })
+            .unwrap_or_default(),
+        // Custom instructions, browsing internals and the like.
+        _ => String::new(),
+    }
+    .trim()
+    .to_string()
+}
+
+/// Files attached to a `ChatGPT` message, by name.
+fn chatgpt_attachments(message: &Value) -> Vec<Value> {
+    message
+        .pointer("/metadata/attachments")
+        .and_then(Value::as_array)
+        .map(|files| {
+            files
+                .iter()
+                .filter_map(|file| {
+                    let name = file.get("name").and_then(Value::as_str)?;
+                    Some(json!({
+                        "name": name,
+                        "mime_type": file.get("mime_type").and_then(Value::as_str),
+                    }))
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Claude
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// A Claude conversation is a flat `chat_messages` list.
+fn claude_conversation(value: &Value) -> Result<Option<ImportedConversation>, ChatImportError> {
+    let chat_messages = value
+        .get("chat_messages")
+        .and_then(Value::as_array)
+        .ok_or_else(|| {
+            ChatImportError::Format("Claude conversation without `chat_messages`".to_string())
+        })?;
+    let created_at = rfc3339_time(value.get("created_at")).unwrap_or_else(Utc::now);
+    let updated_at = rfc3339_time(value.get("updated_at")).unwrap_or(created_at);
+
+    let mut messages = Vec::new();
+    for message in chat_messages {
+        let role = match message.get("sender").and_then(Value::as_str) {
+            Some("human") => MessageRole::User,
+            Some("assistant") => MessageRole::Assistant,
+            _ => continue,
+        };
+        let mut text = claude_text(message);
+        let mut attachments = Vec::new();
+        for file in ["attachments", "files"]
+            .iter()
+            .filter_map(|key| message.get(key).and_then(Value::as_array))
+            .flatten()
+        {
+            let Some(name) = file.get("file_name").and_then(Value::as_str) else {
+                continue;
+            };
+            attachments.push(json!({
+                "name": name,
+                "mime_type": file.get("file_type").and_then(Value::as_str),
+            }));
+            if let Some(extracted) = file
+                .get("extracted_content")
+                .and_then(Value::as_str)
+                .filter(|c| !c.trim().is_empty())
+            {
+                if !text.is_empty() {
+                    text.push_str("\n\n");
+                }
+                let _ = write!(text, "**{name}**\n\n{}", fence("", extracted));
+            }
+        }
+        if text.is_empty() && attachments.is_empty() {
+            continue;
+        }
+        messages.push(ImportedMessage {
+            role,
+            content: text,
+            created_at: rfc3339_time(message.get("created_at")).unwrap_or(created_at),
+            metadata: attachments_metadata(&attachments),
+        });
+    }
+    if messages.is_empty() {
+        return Ok(None);
+    }
+
+    Ok(Some(ImportedConversation {
+        title: title(value.get("name")),
+        system_prompt: None,
+        created_at,
+        updated_at,
+        messages,
+    }))
+}
+
+/// The text blocks of a Claude message, or its flat `text` in older exports.
+fn claude_text(message: &Value) -> String {
+    let blocks: Vec<&str> = message
+        .get("content")
+        .and_then(Value::as_array)
+        .map(|blocks| {
+            blocks
+                .iter()
+                .filter(|b| b.get("type").and_then(Value::as_str) == Some("text"))
+                .filter_map(|b| b.get("text").and_then(Value::as_str))
+                .filter(|t| !t.trim().is_empty())
+                .collect()
+        })
+        .unwrap_or_default();
+    if blocks.is_empty() {
+        message
+            .get("text")
+            .and_then(Value::as_str)
+            .unwrap_or("")
+            .trim()
+            .to_string()
+    } else {
+        blocks.join("\n\n").trim().to_string()
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Shared helpers
+// ─────────────────────────────────────────────────────────────────────────────
+
+fn title(value: Option<&Value>) -> String {
+    value
+        .and_then(Value::as_str)
+        .map(str::trim)
+        .filter(|t| !t.is_empty())
+        .unwrap_or(UNTITLED)
+        .to_string()
+}
+
+fn fence(language: &str, text: &str) -> String {
+    let text = text.trim_end();
+    if text.is_empty() {
+        return String::new();
+    }
+    // A longer fence than any backtick run inside keeps the block intact.
+    let longest = text.split(|c| c != '`').map(str::len).max().unwrap_or(0);
+    let ticks = "`".repeat(longest.max(2) + 1);
+    format!("{ticks}{language}\n{text}\n{ticks}")
+}
+
+fn attachments_metadata(attachments: &[Value]) -> Option<Value> {
+    (!attachments.is_empty()).then(|| json!({ "imported_attachments": attachments }))
+}
+
+/// Seconds since the epoch, as `ChatGPT` writes timestamps.
+fn unix_time(value: Option<&Value>) -> Option<DateTime<Utc>> {
+    let seconds = value?.as_f64()?;
+    #[allow(clippy::cast_possible_truncation)]
+    DateTime::from_timestamp_millis((seconds * 1000.0) as i64)
+}
+
+/// RFC 3339, as Claude writes timestamps.
+fn rfc3339_time(value: Option<&Value>) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(value?.as_str()?)
+        .ok()
+        .map(|t| t.with_timezone(&Utc))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CHATGPT: &str = r#"[{
+        "title": "Plot a sine",
+        "create_time": 1714564800.5,
+        "update_time": 1714565000.0,
+        "current_node": "d",
+        "mapping": {
+            "root": {"id": "root", "message": null, "parent": null, "children": ["sys"]},
+            "sys": {"id": "sys", "parent": "root", "children": ["a"], "message": {
+                "author": {"role": "system"}, "create_time": null,
+                "content": {"content_type": "text", "parts": [""]},
+                "metadata": {"is_visually_hidden_from_conversation": true}}},
+            "a": {"id": "a", "parent": "sys", "children": ["old", "b"], "message": {
+                "author": {"role": "user"}, "create_time": 1714564801.0,
+                "content": {"content_type": "multimodal_text", "parts": [
+                    {"content_type": "image_asset_pointer", "asset_pointer": "file-service://x"},
+                    "Plot sin(x)"]},
+                "metadata": {"attachments": [{"name": "axes.png", "mime_type": "image/png"}]}}},
+            "old": {"id": "old", "parent": "a", "children": [], "message": {
+                "author": {"role": "assistant"}, "create_time": 1714564802.0,
+                "content": {"content_type": "text", "parts": ["Abandoned answer"]}}},
+            "b": {"id": "b", "parent": "a", "children": ["c"], "message": {
+                "author": {"role": "assistant"}, "create_time": 1714564803.0,
+                "content": {"content_type": "code", "language": "python", "text": "plot(sin)"}}},
+            "c": {"id": "c", "parent": "b", "children": ["d"], "message": {
+                "author": {"role": "tool", "name": "python"}, "create_time": 1714564804.0,
+                "content": {"content_type": "execution_output", "text": "<Figure>"}}},
+            "d": {"id": "d", "parent": "c", "children": [], "message": {
+                "author": {"role": "assistant"}, "create_time": 1714564805.0,
+                "content": {"content_type": "text", "parts": ["Here is the plot."]}}}
+        }
+    }]"#;
+
+    const CLAUDE: &str = r#"[
+        {"uuid": "1", "name": "", "created_at": "2024-05-01T12:00:00.000000Z",
+         "updated_at": "2024-05-01T12:05:00Z", "chat_messages": []},
+        {"uuid": "2", "name": "Review my notes",
+         "created_at": "2024-05-02T09:00:00Z", "updated_at": "2024-05-02T09:10:00Z",
+         "chat_messages": [
+            {"sender": "human", "text": "Summarise this", "created_at": "2024-05-02T09:00:01Z",
+             "content": [{"type": "text", "text": "Summarise this"}],
+             "attachments": [{"file_name": "notes.txt", "file_type": "text/plain",
+                              "extracted_content": "buy milk"}],
+             "files": [{"file_name": "photo.jpg"}]},
+            {"sender": "assistant", "text": "ignored flat text",
+             "created_at": "2024-05-02T09:00:05Z",
+             "content": [{"type": "text", "text": "You need milk."},
+                         {"type": "tool_use", "name": "search"}]}
+         ]}
+    ]"#;
+
+    #[test]
+    fn chatgpt_follows_the_current_branch_and_fences_code() {
+        let conversations = parse_export(ChatExportFormat::ChatGpt, CHATGPT).unwrap();
+        assert_eq!(conversations.len(), 1);
+        let conversation = &conversations[0];
+        assert_eq!(conversation.title, "Plot a sine");
+        assert_eq!(conversation.system_prompt, None);
+        assert_eq!(
+            conversation.created_at.timestamp_millis(),
+            1_714_564_800_500
+        );
+
+        let contents: Vec<&str> = conversation
+            .messages
+            .iter()
+            .map(|m| m.content.as_str())
+            .collect();
+        assert_eq!(
+            contents,
+            [
+                "Plot sin(x)",
+                "```python\nplot(sin)\n```",
+                "```\n<Figure>\n```",
+                "Here is the plot."
+            ]
+        );
+        assert_eq!(conversation.messages[0].role, MessageRole::User);
+        assert_eq!(conversation.messages[2].role, MessageRole::Assistant);
+        assert_eq!(
+            conversation.messages[0].metadata,
+            Some(json!({"imported_attachments": [{"name": "axes.png", "mime_type": "image/png"}]}))
+        );
+        assert_eq!(
+            conversation.messages[3].created_at.timestamp(),
+            1_714_564_805
+        );
+    }
+
+    #[test]
+    fn claude_skips_empty_conversations_and_inlines_extracted_text() {
+        let conversations = parse_export(ChatExportFormat::Claude, CLAUDE).unwrap();
+        assert_eq!(conversations.len(), 1);
+        let conversation = &conversations[0];
+        assert_eq!(conversation.title, "Review my notes");
+        assert_eq!(
+            conversation.messages[0].content,
+            "Summarise this\n\n**notes.txt**\n\n```\nbuy milk\n```"
+        );
+        let attachments =
+            &conversation.messages[0].metadata.as_ref().unwrap()["imported_attachments"];
+        assert_eq!(attachments.as_array().unwrap().len(), 2);
+        assert_eq!(conversation.messages[1].content, "You need milk.");
+        assert_eq!(
+            conversation.updated_at,
+            "2024-05-02T09:10:00Z".parse::<DateTime<Utc>>().unwrap()
+        );
+    }
+
+    #[test]
+    fn rejects_files_of_the_wrong_shape() {
+        assert!(matches!(
+            parse_export(ChatExportFormat::Claude, r#"{"chat_messages": []}"#),
+            Err(ChatImportError::Format(_))
+        ));
+        assert!(matches!(
+            parse_export(ChatExportFormat::ChatGpt, CLAUDE),
+            Err(ChatImportError::Format(_))
+        ));
+        assert!(matches!(
+            parse_export(ChatExportFormat::ChatGpt, "not json"),
+            Err(ChatImportError::Json(_))
+        ));
+    }
+
+    #[test]
+    fn fences_outgrow_backticks_in_the_text() {
+        assert_eq!(fence("md", "a ``` b"), "````md\na ``` b\n````");
+    }
+}
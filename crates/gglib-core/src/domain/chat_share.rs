@@ -0,0 +1,149 @@
+//! Read-only share links for conversations.
+//!
+//! Sharing a conversation stores a [`ConversationShare`] row and hands out a
+//! token of the form `<share id>.<signature>`, the signature being an
+//! HMAC-SHA256 of the share ID under a key held in the secret store. The
+//! public share route checks the signature before touching the database, so
+//! share IDs cannot be guessed, and then the row itself — deleting the row
+//! revokes the link, and a row past its `expires_at` no longer opens.
+//!
+//! What a link shows is a [`SharedConversation`]: the title and the user
+//! and assistant messages, without the system prompt, tool traffic or
+//! settings.
+
+use base64::Engine;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use chrono::{DateTime, Utc};
+use hmac::{Hmac, Mac};
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+use utoipa::ToSchema;
+
+use super::chat::{Conversation, Message, MessageRole};
+
+/// Days a share link stays valid when no expiry is asked for.
+pub const DEFAULT_SHARE_DAYS: u32 = 7;
+
+/// Longest validity a share link can be given, in days.
+pub const MAX_SHARE_DAYS: u32 = 90;
+
+/// A stored share of one conversation.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct ShareRecord {
+    pub id: i64,
+    pub conversation_id: i64,
+    pub created_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+}
+
+impl ShareRecord {
+    /// Whether the link has expired at `now`.
+    #[must_use]
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        self.expires_at <= now
+    }
+}
+
+/// A share as shown to its owner, with the token that opens it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct ConversationShare {
+    #[serde(flatten)]
+    pub record: ShareRecord,
+    pub token: String,
+}
+
+/// One message of a shared conversation.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct SharedMessage {
+    pub role: MessageRole,
+    pub content: String,
+    pub created_at: String,
+}
+
+/// The read-only view a share link opens.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct SharedConversation {
+    pub title: String,
+    pub messages: Vec<SharedMessage>,
+    pub shared_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+}
+
+impl SharedConversation {
+    /// The shareable part of `conversation` and its active `messages`.
+    #[must_use]
+    pub fn new(conversation: &Conversation, messages: &[Message], share: &ShareRecord) -> Self {
+        Self {
+            title: conversation.title.clone(),
+            messages: messages
+                .iter()
+                .filter(|m| matches!(m.role, MessageRole::User | MessageRole::Assistant))
+                .filter(|m| !m.content.trim().is_empty())
+                .map(|m| SharedMessage {
+                    role: m.role,
+                    content: m.content.clone(),
+                    created_at: m.created_at.clone(),
+                })
+                .collect(),
+            shared_at: share.created_at,
+            expires_at: share.expires_at,
+        }
+    }
+}
+
+/// Signs share IDs into tokens and checks tokens back.
+pub struct ShareSigner {
+    key: [u8; 32],
+}
+
+impl ShareSigner {
+    /// A signer using `key`.
+    #[must_use]
+    pub const fn new(key: [u8; 32]) -> Self {
+        Self { key }
+    }
+
+    fn mac(&self, share_id: i64) -> Hmac<Sha256> {
+        let mut mac =
+            Hmac::<Sha256>::new_from_slice(&self.key).expect("HMAC accepts keys of any length");
+        mac.update(b"conversation-share:");
+        mac.update(share_id.to_string().as_bytes());
+        mac
+    }
+
+    /// The token opening share `share_id`.
+    #[must_use]
+    pub fn sign(&self, share_id: i64) -> String {
+        let signature = self.mac(share_id).finalize().into_bytes();
+        format!("{share_id}.{}", URL_SAFE_NO_PAD.encode(signature))
+    }
+
+    /// The share ID `token` was signed for, or `None` when the token is
+    /// malformed or its signature does not match.
+    #[must_use]
+    pub fn verify(&self, token: &str) -> Option<i64> {
+        let (id, signature) = token.split_once('.')?;
+        let share_id: i64 = id.parse().ok()?;
+        let signature = URL_SAFE_NO_PAD.decode(signature).ok()?;
+        self.mac(share_id).verify_slice(&signature).ok()?;
+        Some(share_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tokens_verify_only_under_the_signing_key() {
+        let signer = ShareSigner::new([7; 32]);
+        let token = signer.sign(42);
+        assert_eq!(signer.verify(&token), Some(42));
+
+        assert_eq!(ShareSigner::new([8; 32]).verify(&token), None);
+        let (_, signature) = token.split_once('.').unwrap();
+        assert_eq!(signer.verify(&format!("43.{signature}")), None);
+        assert_eq!(signer.verify("42"), None);
+        assert_eq!(signer.verify("42.not-base64!"), None);
+    }
+}
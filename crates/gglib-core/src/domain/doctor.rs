@@ -0,0 +1,157 @@
+//! Installation health checks (`gglib doctor`).
+//!
+//! Each check reports a [`CheckStatus`] with a one-line message and, when
+//! something is wrong, a hint describing the fix. The checks themselves
+//! probe the filesystem, network and hardware and live in the adapters;
+//! this module holds the result types and the pure comparisons they share.
+
+use serde::{Deserialize, Serialize};
+
+/// Outcome of one check, ordered from best to worst.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CheckStatus {
+    /// Working as expected.
+    Pass,
+    /// Usable, but something is degraded or will need attention.
+    Warn,
+    /// Broken: some gglib feature will not work until it is fixed.
+    Fail,
+}
+
+impl CheckStatus {
+    /// Lowercase name, as serialized.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Pass => "pass",
+            Self::Warn => "warn",
+            Self::Fail => "fail",
+        }
+    }
+}
+
+/// Result of a single check.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CheckResult {
+    /// Short, stable check name (`llama-server`, `database`, …).
+    pub name: String,
+    /// Outcome.
+    pub status: CheckStatus,
+    /// What was found.
+    pub message: String,
+    /// How to fix it, when the status isn't [`CheckStatus::Pass`].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hint: Option<String>,
+}
+
+impl CheckResult {
+    /// A passing check.
+    pub fn pass(name: impl Into<String>, message: impl Into<String>) -> Self {
+        Self::new(name, CheckStatus::Pass, message)
+    }
+
+    /// A check that found something degraded.
+    pub fn warn(name: impl Into<String>, message: impl Into<String>) -> Self {
+        Self::new(name, CheckStatus::Warn, message)
+    }
+
+    /// A failing check.
+    pub fn fail(name: impl Into<String>, message: impl Into<String>) -> Self {
+        Self::new(name, CheckStatus::Fail, message)
+    }
+
+    fn new(name: impl Into<String>, status: CheckStatus, message: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            status,
+            message: message.into(),
+            hint: None,
+        }
+    }
+
+    /// Attach a fix hint.
+    #[must_use]
+    pub fn with_hint(mut self, hint: impl Into<String>) -> Self {
+        self.hint = Some(hint.into());
+        self
+    }
+}
+
+/// Every check from one `doctor` run.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DoctorReport {
+    /// Check results, in the order they ran.
+    pub checks: Vec<CheckResult>,
+}
+
+impl DoctorReport {
+    /// The worst status of any check; [`CheckStatus::Pass`] when empty.
+    #[must_use]
+    pub fn overall(&self) -> CheckStatus {
+        self.checks
+            .iter()
+            .map(|check| check.status)
+            .max()
+            .unwrap_or(CheckStatus::Pass)
+    }
+
+    /// Number of checks with `status`.
+    #[must_use]
+    pub fn count(&self, status: CheckStatus) -> usize {
+        self.checks.iter().filter(|c| c.status == status).count()
+    }
+}
+
+/// Whether a CUDA toolkit is too new for the installed driver.
+///
+/// `driver` is the highest CUDA version the driver supports (the
+/// `CUDA Version` nvidia-smi prints) and `toolkit` the nvcc release.
+/// Binaries built with a newer toolkit than the driver supports fail at
+/// launch with "CUDA driver version is insufficient". Unparseable versions
+/// are not reported as a mismatch.
+#[must_use]
+pub fn cuda_toolkit_exceeds_driver(driver: &str, toolkit: &str) -> bool {
+    match (major_minor(driver), major_minor(toolkit)) {
+        (Some(driver), Some(toolkit)) => toolkit > driver,
+        _ => false,
+    }
+}
+
+fn major_minor(version: &str) -> Option<(u32, u32)> {
+    let mut parts = version.trim().split('.');
+    let major = parts.next()?.parse().ok()?;
+    let minor = parts.next().map_or(Some(0), |m| m.parse().ok())?;
+    Some((major, minor))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn overall_is_the_worst_status() {
+        let mut report = DoctorReport::default();
+        assert_eq!(report.overall(), CheckStatus::Pass);
+
+        report.checks.push(CheckResult::pass("a", "ok"));
+        report
+            .checks
+            .push(CheckResult::warn("b", "meh").with_hint("fix b"));
+        assert_eq!(report.overall(), CheckStatus::Warn);
+
+        report.checks.push(CheckResult::fail("c", "broken"));
+        assert_eq!(report.overall(), CheckStatus::Fail);
+        assert_eq!(report.count(CheckStatus::Warn), 1);
+    }
+
+    #[test]
+    fn toolkit_newer_than_driver_is_a_mismatch() {
+        assert!(cuda_toolkit_exceeds_driver("12.2", "12.4"));
+        assert!(cuda_toolkit_exceeds_driver("11.8", "12.0"));
+        assert!(!cuda_toolkit_exceeds_driver("12.4", "12.4"));
+        assert!(!cuda_toolkit_exceeds_driver("12.6", "12.0"));
+        assert!(!cuda_toolkit_exceeds_driver("12", "11.8"));
+        assert!(!cuda_toolkit_exceeds_driver("unknown", "12.4"));
+    }
+}
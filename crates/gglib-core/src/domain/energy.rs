@@ -0,0 +1,198 @@
+//! Energy and cost estimation.
+//!
+//! The proxy measures how long the server spent on each request — prompt
+//! processing plus generation, leaving out time queued behind others — and
+//! the usage aggregates sum it as busy time. Multiplying busy time by the
+//! machine's power draw gives an approximate energy figure, and a price per
+//! kWh turns that into a cost.
+//!
+//! The draw comes from the `energy` setting ([`EnergyConfig`]) when it names
+//! one, otherwise from a rough figure for the detected hardware
+//! ([`detected_watts`]). Either way it is the whole machine's draw while
+//! generating, so the estimates are ballpark figures, not measurements.
+
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+use utoipa::ToSchema;
+
+use crate::utils::system::SystemMemoryInfo;
+
+/// Draw assumed while generating on a machine with an NVIDIA GPU.
+pub const NVIDIA_GPU_WATTS: f64 = 300.0;
+
+/// Draw assumed while generating on Apple Silicon.
+pub const APPLE_SILICON_WATTS: f64 = 40.0;
+
+/// Draw assumed while generating on the CPU alone.
+pub const CPU_WATTS: f64 = 65.0;
+
+/// Upper bound accepted for [`EnergyConfig::watts`].
+pub const MAX_WATTS: f64 = 10_000.0;
+
+/// Energy estimation settings.
+///
+/// Stored as the `energy` setting. Estimates are made without it too, using
+/// [`detected_watts`] and no price.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, TS, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct EnergyConfig {
+    /// Machine draw while generating, in watts. Defaults to
+    /// [`detected_watts`].
+    #[serde(default)]
+    pub watts: Option<f64>,
+    /// Electricity price per kWh; costs are only estimated when set.
+    #[serde(default)]
+    pub price_per_kwh: Option<f64>,
+    /// Currency label shown with costs, e.g. `EUR`.
+    #[serde(default)]
+    pub currency: Option<String>,
+}
+
+impl EnergyConfig {
+    /// Check the draw and price are usable.
+    ///
+    /// # Errors
+    ///
+    /// Returns a human-readable description of the first problem found.
+    pub fn validate(&self) -> Result<(), String> {
+        if let Some(watts) = self.watts
+            && !(watts > 0.0 && watts <= MAX_WATTS)
+        {
+            return Err(format!(
+                "watts must be above 0 and at most {MAX_WATTS}, got {watts}"
+            ));
+        }
+        if let Some(price) = self.price_per_kwh
+            && !(price.is_finite() && price >= 0.0)
+        {
+            return Err(format!("price per kWh must not be negative, got {price}"));
+        }
+        Ok(())
+    }
+}
+
+/// A rough draw for the detected hardware while generating.
+#[must_use]
+pub const fn detected_watts(memory: &SystemMemoryInfo) -> f64 {
+    if memory.is_apple_silicon {
+        APPLE_SILICON_WATTS
+    } else if memory.has_nvidia_gpu {
+        NVIDIA_GPU_WATTS
+    } else {
+        CPU_WATTS
+    }
+}
+
+/// The draw and price estimates are made with.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, TS, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct EnergyRate {
+    /// Machine draw while generating, in watts.
+    pub watts: f64,
+    /// Whether `watts` is the [`detected_watts`] fallback rather than a
+    /// configured figure.
+    pub detected: bool,
+    #[serde(default)]
+    pub price_per_kwh: Option<f64>,
+    #[serde(default)]
+    pub currency: Option<String>,
+}
+
+impl EnergyRate {
+    /// The configured rate, with `detected` watts as the fallback.
+    #[must_use]
+    pub fn resolve(config: Option<&EnergyConfig>, detected: f64) -> Self {
+        let configured = config.and_then(|c| c.watts);
+        Self {
+            watts: configured.unwrap_or(detected),
+            detected: configured.is_none(),
+            price_per_kwh: config.and_then(|c| c.price_per_kwh),
+            currency: config.and_then(|c| c.currency.clone()),
+        }
+    }
+
+    /// The configured rate, or `None` when the setting names no draw.
+    #[must_use]
+    pub fn configured(config: Option<&EnergyConfig>) -> Option<Self> {
+        config
+            .and_then(|c| c.watts)
+            .map(|watts| Self::resolve(config, watts))
+    }
+
+    /// Estimate the energy and cost of `busy_seconds` of work.
+    #[must_use]
+    pub fn estimate(&self, busy_seconds: f64) -> EnergyEstimate {
+        let watt_hours = self.watts * busy_seconds.max(0.0) / 3600.0;
+        EnergyEstimate {
+            watt_hours,
+            cost: self.price_per_kwh.map(|price| watt_hours / 1000.0 * price),
+        }
+    }
+}
+
+/// Approximate energy used by some amount of work.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, TS, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct EnergyEstimate {
+    pub watt_hours: f64,
+    /// In the rate's currency; `None` when no price is configured.
+    #[serde(default)]
+    pub cost: Option<f64>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn memory(is_apple_silicon: bool, has_nvidia_gpu: bool) -> SystemMemoryInfo {
+        SystemMemoryInfo {
+            total_ram_bytes: 0,
+            gpu_memory_bytes: None,
+            is_apple_silicon,
+            has_nvidia_gpu,
+        }
+    }
+
+    #[test]
+    fn detected_watts_follow_the_hardware() {
+        assert!((detected_watts(&memory(true, false)) - APPLE_SILICON_WATTS).abs() < f64::EPSILON);
+        assert!((detected_watts(&memory(false, true)) - NVIDIA_GPU_WATTS).abs() < f64::EPSILON);
+        assert!((detected_watts(&memory(false, false)) - CPU_WATTS).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn configured_watts_and_price_override_detection() {
+        let config = EnergyConfig {
+            watts: Some(450.0),
+            price_per_kwh: Some(0.30),
+            currency: Some("EUR".to_string()),
+        };
+        let rate = EnergyRate::resolve(Some(&config), CPU_WATTS);
+        assert!(!rate.detected);
+
+        // 450 W for 8 minutes is 60 Wh, or 0.06 kWh at 0.30 per kWh.
+        let estimate = rate.estimate(480.0);
+        assert!((estimate.watt_hours - 60.0).abs() < 1e-9);
+        assert!((estimate.cost.unwrap() - 0.018).abs() < 1e-9);
+
+        let fallback = EnergyRate::resolve(None, CPU_WATTS);
+        assert!(fallback.detected);
+        assert_eq!(fallback.estimate(3600.0).cost, None);
+        assert_eq!(EnergyRate::configured(None), None);
+    }
+
+    #[test]
+    fn validate_rejects_unusable_figures() {
+        let bad_watts = EnergyConfig {
+            watts: Some(0.0),
+            ..EnergyConfig::default()
+        };
+        assert!(bad_watts.validate().is_err());
+        let bad_price = EnergyConfig {
+            price_per_kwh: Some(-1.0),
+            ..EnergyConfig::default()
+        };
+        assert!(bad_price.validate().is_err());
+        assert!(EnergyConfig::default().validate().is_ok());
+    }
+}
@@ -32,6 +32,13 @@ bitflags::bitflags! {
         /// Detected via the `{arch}.nextn_predict_layers > 0` GGUF metadata key.
         /// Enables `--spec-type draft-mtp` speculative decoding in llama-server.
         const MTP = 0b0010_0000;
+        /// Model is an embedding model (pooling head or encoder-only architecture).
+        ///
+        /// Detected via a non-zero `{arch}.pooling_type` or an encoder-only
+        /// `general.architecture` such as `bert` / `nomic-bert`.
+        const EMBEDDING = 0b0100_0000;
+        /// Model is a reranker (`{arch}.pooling_type` = rank).
+        const RERANKER = 0b1000_0000;
     }
 }
 
@@ -81,6 +88,18 @@ impl GgufCapabilities {
         self.flags.contains(CapabilityFlags::MTP)
     }
 
+    /// Check if the model is an embedding model.
+    #[must_use]
+    pub const fn has_embedding(&self) -> bool {
+        self.flags.contains(CapabilityFlags::EMBEDDING)
+    }
+
+    /// Check if the model is a reranker.
+    #[must_use]
+    pub const fn has_reranker(&self) -> bool {
+        self.flags.contains(CapabilityFlags::RERANKER)
+    }
+
     /// Convert capabilities to tag strings for model metadata.
     ///
     /// Returns tags like "reasoning", "agent" (for tool calling), etc.
@@ -108,6 +127,12 @@ impl GgufCapabilities {
             // "mtp" tag triggers --spec-type draft-mtp auto-enable
             tags.push("mtp".to_string());
         }
+        if self.has_embedding() {
+            tags.push("embedding".to_string());
+        }
+        if self.has_reranker() {
+            tags.push("reranker".to_string());
+        }
 
         // Add extension tags
         for ext in &self.extensions {
@@ -18,6 +18,8 @@
 //! of one.
 
 use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+use utoipa::ToSchema;
 
 /// Inference parameters for LLM sampling.
 ///
@@ -57,7 +59,7 @@ use serde::{Deserialize, Serialize};
 ///     ..Default::default()
 /// };
 /// ```
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default, TS, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct InferenceConfig {
     /// Sampling temperature (0.0 - 2.0).
@@ -27,6 +27,8 @@
 //! See [`InferenceConfig::resolve_with_profile`] for the full merge order.
 
 use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+use utoipa::ToSchema;
 
 use crate::domain::InferenceConfig;
 
@@ -70,7 +72,7 @@ pub enum ProfileNameError {
 }
 
 /// A named sampling profile applied on top of a model's own defaults.
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, TS, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct InferenceProfile {
     /// Profile slug, used as the `:{suffix}` on a model id.
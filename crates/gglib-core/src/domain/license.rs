@@ -0,0 +1,109 @@
+//! Model licenses.
+//!
+//! A model's license comes from the `license:*` tag on its `HuggingFace`
+//! repository or, for files added from disk, from the GGUF header. The
+//! identifier is kept as written (usually an SPDX-style id such as
+//! `apache-2.0` or `cc-by-nc-4.0`) and lowercased so filters compare
+//! reliably.
+//!
+//! [`is_restrictive_license`] flags licenses that forbid commercial use or
+//! limit it to research, for the `warn_restrictive_licenses` setting.
+
+use std::collections::HashMap;
+use std::hash::BuildHasher;
+
+/// GGUF keys a license may be recorded under, most specific first.
+pub const LICENSE_KEYS: &[&str] = &["general.license", "general.license.name"];
+
+/// Prefix of the `HuggingFace` tag carrying a repository's license.
+const HF_LICENSE_TAG_PREFIX: &str = "license:";
+
+/// Lowercase and trim a license identifier; `None` when it is blank.
+#[must_use]
+pub fn normalize_license(license: &str) -> Option<String> {
+    let license = license.trim().to_lowercase();
+    (!license.is_empty()).then_some(license)
+}
+
+/// The license named by a `license:*` tag in `tags`, if any.
+#[must_use]
+pub fn license_from_hf_tags(tags: &[String]) -> Option<String> {
+    tags.iter().find_map(|tag| {
+        let prefix = tag.get(..HF_LICENSE_TAG_PREFIX.len())?;
+        if prefix.eq_ignore_ascii_case(HF_LICENSE_TAG_PREFIX) {
+            normalize_license(&tag[HF_LICENSE_TAG_PREFIX.len()..])
+        } else {
+            None
+        }
+    })
+}
+
+/// The license recorded in a GGUF header's metadata, if any.
+#[must_use]
+pub fn license_from_metadata<S: BuildHasher>(
+    metadata: &HashMap<String, String, S>,
+) -> Option<String> {
+    LICENSE_KEYS
+        .iter()
+        .filter_map(|key| metadata.get(*key))
+        .find_map(|license| normalize_license(license))
+}
+
+/// Whether `license` forbids commercial use or restricts the model to
+/// research.
+///
+/// Matches the Creative Commons non-commercial variants (`cc-by-nc-*`),
+/// licenses that say so in their name (`*-noncommercial`, `*-research`),
+/// and the research-only model licenses published on the Hub. Unknown and
+/// custom (`other`) licenses are not flagged: there is nothing to go on.
+#[must_use]
+pub fn is_restrictive_license(license: &str) -> bool {
+    const RESTRICTIVE: &[&str] = &["apple-ascl", "deepfloyd-if-license", "llama2-research"];
+
+    let license = license.trim().to_lowercase();
+    license.split(['-', '_', '.']).any(|part| part == "nc")
+        || license.contains("noncommercial")
+        || license.contains("non-commercial")
+        || license.contains("research")
+        || RESTRICTIVE.contains(&license.as_str())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn license_is_read_from_hf_tags_and_gguf_metadata() {
+        let tags = vec!["gguf".to_string(), "License:Apache-2.0".to_string()];
+        assert_eq!(license_from_hf_tags(&tags).as_deref(), Some("apache-2.0"));
+        assert_eq!(license_from_hf_tags(&["license:".to_string()]), None);
+
+        let mut metadata = HashMap::new();
+        metadata.insert("general.license.name".to_string(), "MIT".to_string());
+        assert_eq!(license_from_metadata(&metadata).as_deref(), Some("mit"));
+        metadata.insert("general.license".to_string(), "  ".to_string());
+        assert_eq!(license_from_metadata(&metadata).as_deref(), Some("mit"));
+    }
+
+    #[test]
+    fn non_commercial_and_research_licenses_are_restrictive() {
+        for license in [
+            "cc-by-nc-4.0",
+            "CC-BY-NC-SA-4.0",
+            "qwen-research",
+            "apple-ascl",
+        ] {
+            assert!(is_restrictive_license(license), "{license}");
+        }
+        for license in [
+            "apache-2.0",
+            "mit",
+            "llama3.1",
+            "gemma",
+            "other",
+            "cc-by-4.0",
+        ] {
+            assert!(!is_restrictive_license(license), "{license}");
+        }
+    }
+}
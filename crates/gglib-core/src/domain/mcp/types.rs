@@ -4,11 +4,13 @@
 
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+use utoipa::ToSchema;
 
 /// Startup lifecycle policy for an MCP server.
 ///
 /// Controls when gglib automatically starts the server process.
-#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, TS, ToSchema)]
 #[serde(rename_all = "lowercase")]
 pub enum McpLifecycle {
     /// Start the server at host initialisation (proxy startup, GUI launch, web server boot).
@@ -373,7 +375,7 @@ pub struct UpdateMcpServer {
 /// Tool definition from an MCP server.
 ///
 /// This matches the `OpenAI` tool format used by the frontend Tool Registry.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct McpTool {
     /// Tool name (function name).
     pub name: String,
@@ -1,20 +1,48 @@
 #![doc = include_str!("README.md")]
 pub mod agent;
+pub mod attachment;
+pub mod audit;
+pub mod backup;
 pub mod benchmark;
 pub mod cache_budget;
 pub mod capabilities;
 pub mod chat;
+pub mod chat_import;
+pub mod chat_share;
 pub mod council;
+pub mod doctor;
+pub mod energy;
 pub mod gguf;
 pub mod inference;
 pub mod inference_profile;
 pub mod kv_estimate;
 pub mod kv_memory;
+pub mod license;
 pub mod mcp;
 mod model;
+pub mod model_card;
+pub mod moderation;
+pub mod onboarding;
+pub mod pipeline;
+pub mod preset;
+pub mod project;
+pub mod prompt_cache;
+pub mod prompt_preview;
+pub mod proxy_limits;
 pub mod query;
+pub mod recommend;
+pub mod rope;
 mod server_config;
+pub mod server_slots;
+pub mod server_stats;
 pub mod slot_eviction;
+pub mod startup;
+pub mod sync;
+pub mod template_check;
+pub mod trash;
+pub mod url_fetch;
+pub mod usage;
+pub mod web_search;
 
 // Re-export model types at the domain level for convenience
 pub use model::{
@@ -22,8 +50,19 @@ pub use model::{
     is_system_tag,
 };
 
+// Re-export license detection at the domain level for convenience
+pub use license::{
+    is_restrictive_license, license_from_hf_tags, license_from_metadata, normalize_license,
+};
+
+// Re-export local model card generation at the domain level for convenience
+pub use model_card::generate_model_card;
+
 // Re-export query types at the domain level for convenience
-pub use query::{ModelListQuery, ModelSortBy, SortOrder, apply_query};
+pub use query::{
+    DEFAULT_PAGE_SIZE, MAX_PAGE_SIZE, ModelChanges, ModelListQuery, ModelPage, ModelSortBy,
+    PageRequest, SortOrder, apply_query, page_of,
+};
 
 // Re-export benchmark types at the domain level for convenience
 pub use benchmark::{
@@ -47,7 +86,36 @@ pub use kv_estimate::{
 
 // Re-export KV memory-shape detection at the domain level for convenience
 pub use kv_memory::kv_memory_is_partial;
-pub use server_config::ServerConfig;
+
+// Re-export onboarding state machine types at the domain level for convenience
+pub use onboarding::{
+    OnboardingPhase, OnboardingProgress, OnboardingStatus, OnboardingStep, OnboardingStepStatus,
+};
+
+// Re-export installation health-check types at the domain level for convenience
+pub use doctor::{CheckResult, CheckStatus, DoctorReport, cuda_toolkit_exceeds_driver};
+
+// Re-export hardware-aware recommendations at the domain level for convenience
+pub use recommend::{
+    CatalogEntry, ModelTask, RecommendTarget, Recommendation, RecommendationReport,
+    builtin_catalog, recommend,
+};
+
+// Re-export cached prompts at the domain level for convenience
+pub use prompt_cache::{
+    CachedPrompt, PromptWarmResult, PromptWarmSource, is_valid_prompt_name, validate_cached_prompts,
+};
+pub use prompt_preview::PromptPreview;
+
+// Re-export RoPE overrides and context suggestion at the domain level for convenience
+pub use rope::{RopeConfig, RopeScalingType, suggest_max_context};
+pub use server_config::{INITIAL_CONTEXT_CAP, ServerConfig};
+pub use server_slots::{SlotInfo, SlotOccupancy};
+pub use server_stats::{RequestTiming, ServerStats};
+pub use startup::{
+    STARTUP_RAM_HEADROOM_BYTES, StartupCandidate, StartupModel, StartupStep, plan_startup,
+    validate_startup_models,
+};
 
 // Re-export cache-RAM budget math at the domain level for convenience
 pub use cache_budget::{
@@ -67,9 +135,47 @@ pub use mcp::{
     McpToolResult, NewMcpServer, SEARCH_RESULTS_CAP, ToolIndex, ToolSummary, UpdateMcpServer,
 };
 
+// Re-export attachment types at the domain level for convenience
+pub use attachment::{Attachment, NewAttachment};
+
+// Re-export pipeline types at the domain level for convenience
+pub use pipeline::{
+    NewPipelineRun, PipelineDefinition, PipelineRun, PipelineRunStatus, PipelineStep,
+    PipelineStepResult,
+};
+
+// Re-export preset types at the domain level for convenience
+pub use preset::{NewPreset, Preset, PresetUpdate};
+
+// Re-export project types at the domain level for convenience
+pub use project::{NewProject, Project, ProjectUpdate};
+
+// Re-export backup types at the domain level for convenience
+pub use backup::{BackupConfig, BackupObject};
+
+// Re-export library sync types at the domain level for convenience
+pub use sync::{LibraryEntry, PairRequest, SyncPeer};
+
+// Re-export energy estimation types at the domain level for convenience
+pub use energy::{EnergyConfig, EnergyEstimate, EnergyRate};
+
+// Re-export moderation types at the domain level for convenience
+pub use moderation::{
+    ModerationAction, ModerationClassifier, ModerationConfig, ModerationMatch, ModerationRule,
+    ModerationScope,
+};
+
+// Re-export proxy generation limits at the domain level for convenience
+pub use proxy_limits::{ProxyLimits, TIME_LIMIT_FINISH_REASON};
+
+// Re-export web search types at the domain level for convenience
+pub use url_fetch::UrlFetchConfig;
+pub use web_search::{WebSearchConfig, WebSearchProvider, WebSearchResult};
+
 // Re-export chat types at the domain level for convenience
 pub use chat::{
-    Conversation, ConversationUpdate, Message, MessageRole, NewConversation, NewMessage,
+    Conversation, ConversationUpdate, Message, MessageRevision, MessageRole, ModelBindingWarning,
+    ModelSuggestion, NewConversation, NewMessage,
 };
 
 // Re-export GGUF types at the domain level for convenience
@@ -89,7 +195,8 @@ pub use agent::{
 
 // Re-export capability types at the domain level for convenience
 pub use capabilities::{
-    ChatMessage, MessageContent, ModelCapabilities, capabilities_from_architecture,
+    CAPABILITY_DETECTOR_VERSION, ChatMessage, MessageContent, ModelCapabilities,
+    capabilities_from_architecture, capabilities_from_gguf, detect_capabilities,
     infer_from_chat_template, transform_messages_for_capabilities,
 };
 
@@ -7,9 +7,11 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::path::PathBuf;
+use utoipa::ToSchema;
 
 use super::capabilities::ModelCapabilities;
 use super::inference::InferenceConfig;
+use super::rope::{RopeConfig, suggest_max_context};
 use super::server_config::ServerConfig;
 
 // ─────────────────────────────────────────────────────────────────────────────
@@ -40,7 +42,7 @@ pub fn is_system_tag(tag: &str) -> bool {
 ///
 /// Contains aggregate data about available models for building
 /// dynamic filter controls.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct ModelFilterOptions {
     /// All distinct quantization types present in the library.
     pub quantizations: Vec<String>,
@@ -51,10 +53,13 @@ pub struct ModelFilterOptions {
     /// Minimum and maximum `latest_tg_tps` from benchmark summaries.
     /// `None` when no models have been benchmarked.
     pub speed_range: Option<RangeValues>,
+    /// All distinct licenses present in the library.
+    #[serde(default)]
+    pub licenses: Vec<String>,
 }
 
 /// A range of numeric values with min and max.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct RangeValues {
     pub min: f64,
     pub max: f64,
@@ -107,11 +112,19 @@ pub struct Model {
     pub download_date: Option<DateTime<Utc>>,
     /// Last time we checked for updates on `HuggingFace`.
     pub last_update_check: Option<DateTime<Utc>>,
+    /// License identifier, lowercased (see [`super::license`]), from the
+    /// `HuggingFace` repository's tags or the GGUF header.
+    #[serde(default)]
+    pub license: Option<String>,
     /// User-defined tags for organizing models.
     pub tags: Vec<String>,
     /// Model capabilities inferred from chat template analysis.
     #[serde(default)]
     pub capabilities: ModelCapabilities,
+    /// Version of the detector that set `capabilities`, or `None` when they
+    /// were set by hand (see [`super::capabilities::CAPABILITY_DETECTOR_VERSION`]).
+    #[serde(default)]
+    pub capabilities_version: Option<u32>,
     /// Per-model inference parameter defaults.
     ///
     /// These are preferred over global settings when making inference requests.
@@ -124,6 +137,10 @@ pub struct Model {
     /// be overridden at request time. Part of the 4-level fallback chain.
     #[serde(default)]
     pub server_defaults: Option<ServerConfig>,
+    /// Markdown model card (see [`super::model_card`]). Generated when a
+    /// local file is imported; user-editable afterwards.
+    #[serde(default)]
+    pub card: Option<String>,
     /// Denormalised benchmark summary joined from `model_benchmark_summaries`.
     ///
     /// `None` when no benchmark has been run for this model yet, or when the
@@ -170,6 +187,10 @@ pub struct NewModel {
     pub download_date: Option<DateTime<Utc>>,
     /// Last time we checked for updates on `HuggingFace`.
     pub last_update_check: Option<DateTime<Utc>>,
+    /// License identifier, lowercased (see [`super::license`]), from the
+    /// `HuggingFace` repository's tags or the GGUF header.
+    #[serde(default)]
+    pub license: Option<String>,
     /// User-defined tags for organizing models.
     pub tags: Vec<String>,
     /// Ordered list of all file paths for sharded models (None for single-file models).
@@ -186,6 +207,9 @@ pub struct NewModel {
     /// Per-model server startup defaults.
     #[serde(default)]
     pub server_defaults: Option<ServerConfig>,
+    /// Markdown model card (see [`super::model_card`]).
+    #[serde(default)]
+    pub card: Option<String>,
 }
 
 // ─────────────────────────────────────────────────────────────────────────────
@@ -277,16 +301,29 @@ impl NewModel {
             hf_filename: None,
             download_date: None,
             last_update_check: None,
+            license: None,
             tags: Vec::new(),
             file_paths: None,
             capabilities: ModelCapabilities::default(),
             inference_defaults: None,
             server_defaults: None,
+            card: None,
         }
     }
 }
 
 impl Model {
+    /// The largest context this model can be served with: the GGUF context,
+    /// stretched by `rope` frequency scaling when given (see
+    /// [`suggest_max_context`]). Falls back to the recorded
+    /// [`context_length`](Self::context_length) when the metadata lacks one;
+    /// `None` when neither is known.
+    #[must_use]
+    pub fn max_context(&self, rope: Option<&RopeConfig>) -> Option<u64> {
+        suggest_max_context(&self.metadata, self.architecture.as_deref(), rope)
+            .or(self.context_length)
+    }
+
     /// Convert this model to a `NewModel` (drops the ID).
     ///
     /// Useful when you need to clone a model's data without the ID.
@@ -309,11 +346,13 @@ impl Model {
             hf_filename: self.hf_filename.clone(),
             download_date: self.download_date,
             last_update_check: self.last_update_check,
+            license: self.license.clone(),
             tags: self.tags.clone(),
             file_paths: None, // Not preserved in conversion
             capabilities: self.capabilities,
             inference_defaults: self.inference_defaults.clone(),
             server_defaults: self.server_defaults.clone(),
+            card: self.card.clone(),
         }
     }
 }
@@ -359,11 +398,14 @@ mod tests {
             hf_filename: None,
             download_date: None,
             last_update_check: None,
+            license: None,
             tags: vec!["chat".to_string()],
             capabilities: ModelCapabilities::default(),
+            capabilities_version: None,
             inference_defaults: None,
             server_defaults: None,
             benchmark_summary: None,
+            card: None,
         };
 
         let new_model = model.to_new_model();
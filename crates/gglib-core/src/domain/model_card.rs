@@ -0,0 +1,124 @@
+//! Local model cards.
+//!
+//! Models downloaded from `HuggingFace` come with a repository and its
+//! README; a GGUF added from disk comes with nothing but its own header.
+//! [`generate_model_card`] turns that header — architecture, size,
+//! quantization, context, license — and the detected capabilities into a
+//! short Markdown card, which is stored on the model and can be edited
+//! like any other field afterwards.
+
+use super::license::LICENSE_KEYS;
+use super::model::NewModel;
+
+/// Render the Markdown card for `model` from its GGUF metadata and
+/// detected capabilities. Facts the file does not record are left out
+/// rather than shown as unknown.
+#[must_use]
+pub fn generate_model_card(model: &NewModel) -> String {
+    let mut facts: Vec<(&str, String)> = Vec::new();
+    if let Some(arch) = &model.architecture {
+        facts.push(("Architecture", arch.clone()));
+    }
+    if model.param_count_b > 0.0 {
+        facts.push(("Parameters", format!("{:.1}B", model.param_count_b)));
+    }
+    if let Some(quant) = &model.quantization {
+        facts.push(("Quantization", quant.clone()));
+    }
+    if let Some(ctx) = model.context_length {
+        facts.push(("Context length", format!("{ctx} tokens")));
+    }
+    if let Some(experts) = model.expert_count {
+        let active = model
+            .expert_used_count
+            .map_or_else(String::new, |used| format!(" ({used} active)"));
+        facts.push(("Experts", format!("{experts}{active}")));
+    }
+    if let Some(license) = LICENSE_KEYS
+        .iter()
+        .find_map(|key| model.metadata.get(*key))
+        .filter(|l| !l.trim().is_empty())
+    {
+        facts.push(("License", license.trim().to_string()));
+    }
+
+    let mut card = format!("# {}\n", model.name);
+    if let Some(description) = model
+        .metadata
+        .get("general.description")
+        .map(|d| d.trim())
+        .filter(|d| !d.is_empty())
+    {
+        card += &["\n", description, "\n"].concat();
+    }
+    if !facts.is_empty() {
+        card += "\n| | |\n|---|---|\n";
+        for (label, value) in &facts {
+            card += &["| ", label, " | ", value, " |\n"].concat();
+        }
+    }
+
+    let capabilities = capability_names(model);
+    if !capabilities.is_empty() {
+        card += &["\n**Capabilities:** ", &capabilities.join(", "), "\n"].concat();
+    }
+    card
+}
+
+/// Human-readable names of the capabilities worth advertising.
+fn capability_names(model: &NewModel) -> Vec<&'static str> {
+    let caps = model.capabilities;
+    [
+        (caps.is_embedding(), "embeddings"),
+        (caps.is_reranker(), "reranking"),
+        (caps.supports_tool_calls(), "tool calling"),
+        (caps.supports_reasoning(), "reasoning"),
+        (caps.supports_system_role(), "system prompts"),
+    ]
+    .into_iter()
+    .filter_map(|(has, name)| has.then_some(name))
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use chrono::Utc;
+
+    use super::*;
+    use crate::domain::capabilities::ModelCapabilities;
+
+    #[test]
+    fn card_lists_what_the_gguf_records_and_skips_the_rest() {
+        let mut model = NewModel::new(
+            "Qwen3 8B".into(),
+            PathBuf::from("/models/qwen3-8b.gguf"),
+            8.2,
+            Utc::now(),
+        );
+        model.architecture = Some("qwen3".into());
+        model.quantization = Some("Q4_K_M".into());
+        model.context_length = Some(40960);
+        model
+            .metadata
+            .insert("general.license".into(), "apache-2.0".into());
+        model.capabilities =
+            ModelCapabilities::SUPPORTS_TOOL_CALLS | ModelCapabilities::SUPPORTS_REASONING;
+
+        let card = generate_model_card(&model);
+        assert!(card.starts_with("# Qwen3 8B\n"), "{card}");
+        assert!(card.contains("| Architecture | qwen3 |"), "{card}");
+        assert!(card.contains("| Parameters | 8.2B |"), "{card}");
+        assert!(card.contains("| Context length | 40960 tokens |"), "{card}");
+        assert!(card.contains("| License | apache-2.0 |"), "{card}");
+        assert!(
+            card.contains("**Capabilities:** tool calling, reasoning"),
+            "{card}"
+        );
+        assert!(!card.contains("Experts"), "{card}");
+
+        let bare = NewModel::new("bare".into(), PathBuf::from("/m.gguf"), 0.0, Utc::now());
+        assert_eq!(generate_model_card(&bare), "# bare\n");
+    }
+}
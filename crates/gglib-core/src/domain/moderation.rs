@@ -0,0 +1,261 @@
+//! Moderation domain types.
+//!
+//! Moderation is opt-in: the `moderation` setting holds a
+//! [`ModerationConfig`], and the proxy only inspects traffic while it is set.
+//! Two kinds of check are available, both applied to prompts, responses or
+//! both:
+//!
+//! - [`ModerationRule`]s match a keyword or regular expression;
+//! - a [`ModerationClassifier`] asks a small local model, served like any
+//!   other, to label the text.
+//!
+//! Each check carries the [`ModerationAction`] taken when it fires.
+
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+use utoipa::ToSchema;
+
+/// Label a classifier reply must contain to count as a hit when
+/// [`ModerationClassifier::flagged_labels`] is empty.
+pub const DEFAULT_FLAGGED_LABEL: &str = "unsafe";
+
+/// Instruction sent to the classifier when
+/// [`ModerationClassifier::instruction`] is unset.
+pub const DEFAULT_CLASSIFIER_INSTRUCTION: &str = "Classify the following text. Reply with the \
+     single word `safe` or `unsafe`, nothing else.";
+
+/// What happens when a check fires.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, TS, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum ModerationAction {
+    /// Refuse the prompt, or cut the response short.
+    Block,
+    /// Record the decision without touching the traffic.
+    Flag,
+    /// Record the decision and append a notice to the response.
+    Annotate,
+}
+
+/// Which side of the exchange a check looks at.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, TS, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum ModerationScope {
+    /// The latest user message.
+    Prompt,
+    /// The assistant's reply.
+    Response,
+    /// Both.
+    #[default]
+    Both,
+}
+
+impl ModerationScope {
+    /// Whether prompts are checked.
+    #[must_use]
+    pub const fn prompts(self) -> bool {
+        matches!(self, Self::Prompt | Self::Both)
+    }
+
+    /// Whether responses are checked.
+    #[must_use]
+    pub const fn responses(self) -> bool {
+        matches!(self, Self::Response | Self::Both)
+    }
+}
+
+/// How a rule's pattern is matched.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, TS, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum ModerationMatch {
+    /// Case-insensitive substring.
+    #[default]
+    Keyword,
+    /// Regular expression (Rust `regex` syntax).
+    Regex,
+}
+
+/// A keyword or pattern check.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, TS, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ModerationRule {
+    /// Name recorded with each decision the rule makes.
+    pub name: String,
+    /// The keyword or regular expression.
+    pub pattern: String,
+    #[serde(default, rename = "match")]
+    pub match_kind: ModerationMatch,
+    #[serde(default)]
+    pub scope: ModerationScope,
+    pub action: ModerationAction,
+}
+
+/// A local model that labels text.
+///
+/// The model is served like any other (for example with `gglib serve`) and
+/// reached through its OpenAI-compatible endpoint; the proxy never loads it
+/// itself, so checking a request does not swap out the model answering it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, TS, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ModerationClassifier {
+    /// Base URL of the server, e.g. `http://127.0.0.1:9100`.
+    pub url: String,
+    /// Model name sent in the request; `None` lets the server choose.
+    #[serde(default)]
+    pub model: Option<String>,
+    /// Instruction placed before the text. Defaults to
+    /// [`DEFAULT_CLASSIFIER_INSTRUCTION`].
+    #[serde(default)]
+    pub instruction: Option<String>,
+    /// Reply labels that count as a hit, compared case-insensitively against
+    /// the reply's words. Defaults to [`DEFAULT_FLAGGED_LABEL`].
+    #[serde(default)]
+    pub flagged_labels: Vec<String>,
+    #[serde(default)]
+    pub scope: ModerationScope,
+    pub action: ModerationAction,
+}
+
+impl ModerationClassifier {
+    /// The effective instruction (with default fallback).
+    #[must_use]
+    pub fn effective_instruction(&self) -> &str {
+        self.instruction
+            .as_deref()
+            .unwrap_or(DEFAULT_CLASSIFIER_INSTRUCTION)
+    }
+
+    /// Whether `reply` carries one of the flagged labels.
+    #[must_use]
+    pub fn is_hit(&self, reply: &str) -> bool {
+        let words: Vec<String> = reply
+            .split(|c: char| !c.is_alphanumeric() && c != '_' && c != '-')
+            .filter(|w| !w.is_empty())
+            .map(str::to_lowercase)
+            .collect();
+        let is_flagged = |label: &str| words.iter().any(|w| *w == label.to_lowercase());
+        if self.flagged_labels.is_empty() {
+            is_flagged(DEFAULT_FLAGGED_LABEL)
+        } else {
+            self.flagged_labels.iter().any(|l| is_flagged(l))
+        }
+    }
+}
+
+/// Moderation settings.
+///
+/// Stored as the `moderation` setting; `None` there disables moderation.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, TS, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ModerationConfig {
+    /// Keyword and pattern checks, applied in order.
+    #[serde(default)]
+    pub rules: Vec<ModerationRule>,
+    /// Model-based check, applied after the rules.
+    #[serde(default)]
+    pub classifier: Option<ModerationClassifier>,
+}
+
+impl ModerationConfig {
+    /// Check rule names are unique, patterns compile and the classifier URL
+    /// is usable.
+    ///
+    /// # Errors
+    ///
+    /// Returns a human-readable description of the first problem found.
+    pub fn validate(&self) -> Result<(), String> {
+        for (i, rule) in self.rules.iter().enumerate() {
+            if rule.name.trim().is_empty() {
+                return Err(format!("rule {} has no name", i + 1));
+            }
+            if self.rules[..i].iter().any(|r| r.name == rule.name) {
+                return Err(format!("rule name '{}' is used more than once", rule.name));
+            }
+            if rule.pattern.is_empty() {
+                return Err(format!("rule '{}' has an empty pattern", rule.name));
+            }
+            if rule.match_kind == ModerationMatch::Regex
+                && let Err(e) = regex::Regex::new(&rule.pattern)
+            {
+                return Err(format!("rule '{}' has an invalid pattern: {e}", rule.name));
+            }
+        }
+        if let Some(classifier) = &self.classifier {
+            let url = classifier.url.trim();
+            if !(url.starts_with("http://") || url.starts_with("https://")) {
+                return Err(format!(
+                    "classifier URL must be an http:// or https:// URL, got '{url}'"
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rule(name: &str, pattern: &str, match_kind: ModerationMatch) -> ModerationRule {
+        ModerationRule {
+            name: name.to_string(),
+            pattern: pattern.to_string(),
+            match_kind,
+            scope: ModerationScope::Both,
+            action: ModerationAction::Flag,
+        }
+    }
+
+    fn classifier(labels: &[&str]) -> ModerationClassifier {
+        ModerationClassifier {
+            url: "http://127.0.0.1:9100".to_string(),
+            model: None,
+            instruction: None,
+            flagged_labels: labels.iter().map(|l| (*l).to_string()).collect(),
+            scope: ModerationScope::Both,
+            action: ModerationAction::Flag,
+        }
+    }
+
+    #[test]
+    fn validate_rejects_bad_rules_and_urls() {
+        let mut config = ModerationConfig {
+            rules: vec![rule("secrets", "api[_-]key", ModerationMatch::Regex)],
+            classifier: Some(classifier(&[])),
+        };
+        assert!(config.validate().is_ok());
+
+        config
+            .rules
+            .push(rule("secrets", "password", ModerationMatch::Keyword));
+        assert!(config.validate().unwrap_err().contains("more than once"));
+
+        config.rules[1] = rule("broken", "(", ModerationMatch::Regex);
+        assert!(config.validate().unwrap_err().contains("invalid pattern"));
+
+        config.rules.pop();
+        config.classifier = Some(ModerationClassifier {
+            url: "localhost:9100".to_string(),
+            ..classifier(&[])
+        });
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn classifier_hits_match_whole_words() {
+        let default = classifier(&[]);
+        assert!(default.is_hit("Unsafe."));
+        assert!(!default.is_hit("safe"));
+        assert!(!default.is_hit("unsafety"));
+
+        let custom = classifier(&["S1", "s2"]);
+        assert!(custom.is_hit("unsafe\nS1"));
+        assert!(!custom.is_hit("unsafe"));
+    }
+
+    #[test]
+    fn scope_covers_the_right_sides() {
+        assert!(ModerationScope::Both.prompts() && ModerationScope::Both.responses());
+        assert!(!ModerationScope::Prompt.responses());
+        assert!(!ModerationScope::Response.prompts());
+    }
+}
@@ -0,0 +1,245 @@
+//! First-run onboarding state machine.
+//!
+//! Onboarding walks a new install through four steps, in order: install the
+//! llama.cpp binaries, settle on a writable models directory, get at least
+//! one model into the library, and reach the proxy. Whether a step is done
+//! is always *observed* from the system (binary present, directory writable,
+//! model count, proxy answering) rather than remembered, so an install that
+//! was finished by hand — or half-finished before the app was closed — is
+//! picked up exactly where it stands on the next launch.
+//!
+//! The only thing persisted is [`OnboardingProgress`]: steps the user chose
+//! to skip and the first-model download that was queued, stored in settings.
+
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+use utoipa::ToSchema;
+
+/// One onboarding step, in the order they are offered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, TS, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum OnboardingStep {
+    /// llama.cpp binaries are installed.
+    Binaries,
+    /// The models directory exists and is writable.
+    ModelsDir,
+    /// At least one model is in the library.
+    FirstModel,
+    /// The OpenAI-compatible proxy answers on its configured port.
+    Proxy,
+}
+
+impl OnboardingStep {
+    /// Every step, in order.
+    pub const ALL: [Self; 4] = [
+        Self::Binaries,
+        Self::ModelsDir,
+        Self::FirstModel,
+        Self::Proxy,
+    ];
+
+    /// `snake_case` name, as used in the API and accepted by [`FromStr`].
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Binaries => "binaries",
+            Self::ModelsDir => "models_dir",
+            Self::FirstModel => "first_model",
+            Self::Proxy => "proxy",
+        }
+    }
+}
+
+impl fmt::Display for OnboardingStep {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for OnboardingStep {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|step| step.as_str() == s)
+            .ok_or_else(|| format!("unknown onboarding step '{s}'"))
+    }
+}
+
+/// Persisted onboarding choices, resumed on the next launch.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OnboardingProgress {
+    /// Steps the user chose to skip; they no longer block completion.
+    #[serde(default)]
+    pub skipped: Vec<OnboardingStep>,
+    /// Download queued by the first-model step (`repo:quant`), so the
+    /// screen can keep showing it after a restart.
+    #[serde(default)]
+    pub pending_download: Option<String>,
+}
+
+impl OnboardingProgress {
+    /// Whether `step` was skipped.
+    #[must_use]
+    pub fn is_skipped(&self, step: OnboardingStep) -> bool {
+        self.skipped.contains(&step)
+    }
+
+    /// Mark `step` as skipped (idempotent).
+    pub fn skip(&mut self, step: OnboardingStep) {
+        if !self.is_skipped(step) {
+            self.skipped.push(step);
+        }
+    }
+}
+
+/// Observed state of one step.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct OnboardingStepStatus {
+    pub step: OnboardingStep,
+    /// Observed as done.
+    pub complete: bool,
+    /// Skipped by the user (only meaningful while not complete).
+    pub skipped: bool,
+    /// What was observed, e.g. the models path or the proxy address.
+    pub detail: String,
+}
+
+/// Where onboarding stands.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct OnboardingStatus {
+    /// Every step, in order.
+    pub steps: Vec<OnboardingStepStatus>,
+    /// First step that is neither complete nor skipped.
+    pub next: Option<OnboardingStep>,
+    /// No step is left to do.
+    pub finished: bool,
+    /// First-model download queued in an earlier session, if any.
+    pub pending_download: Option<String>,
+}
+
+impl OnboardingStatus {
+    /// Combine observed `(step, complete, detail)` checks with the persisted
+    /// progress. Checks may arrive in any order; steps are reported in
+    /// [`OnboardingStep::ALL`] order, and a step without a check counts as
+    /// not complete.
+    #[must_use]
+    pub fn from_checks(
+        checks: impl IntoIterator<Item = (OnboardingStep, bool, String)>,
+        progress: &OnboardingProgress,
+    ) -> Self {
+        let mut checks: Vec<_> = checks.into_iter().collect();
+        let steps: Vec<OnboardingStepStatus> = OnboardingStep::ALL
+            .into_iter()
+            .map(|step| {
+                let (complete, detail) = checks.iter().position(|(s, _, _)| *s == step).map_or(
+                    (false, String::new()),
+                    |i| {
+                        let (_, complete, detail) = checks.swap_remove(i);
+                        (complete, detail)
+                    },
+                );
+                OnboardingStepStatus {
+                    step,
+                    complete,
+                    skipped: !complete && progress.is_skipped(step),
+                    detail,
+                }
+            })
+            .collect();
+
+        let next = steps
+            .iter()
+            .find(|s| !s.complete && !s.skipped)
+            .map(|s| s.step);
+        let first_model_done = steps
+            .iter()
+            .any(|s| s.step == OnboardingStep::FirstModel && s.complete);
+
+        Self {
+            steps,
+            next,
+            finished: next.is_none(),
+            pending_download: progress
+                .pending_download
+                .clone()
+                .filter(|_| !first_model_done),
+        }
+    }
+}
+
+/// Phase of a step being executed, reported through
+/// [`AppEvent::OnboardingProgress`](crate::events::AppEvent::OnboardingProgress).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, TS, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum OnboardingPhase {
+    Started,
+    Progress,
+    Completed,
+    Failed,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn checks(done: &[OnboardingStep]) -> Vec<(OnboardingStep, bool, String)> {
+        OnboardingStep::ALL
+            .into_iter()
+            .rev()
+            .map(|step| (step, done.contains(&step), String::new()))
+            .collect()
+    }
+
+    #[test]
+    fn next_is_the_first_incomplete_step_in_order() {
+        let status = OnboardingStatus::from_checks(
+            checks(&[OnboardingStep::Binaries, OnboardingStep::Proxy]),
+            &OnboardingProgress::default(),
+        );
+        assert_eq!(status.steps[0].step, OnboardingStep::Binaries);
+        assert_eq!(status.next, Some(OnboardingStep::ModelsDir));
+        assert!(!status.finished);
+    }
+
+    #[test]
+    fn skipped_steps_do_not_block_completion() {
+        let mut progress = OnboardingProgress::default();
+        progress.skip(OnboardingStep::Proxy);
+        progress.skip(OnboardingStep::Proxy);
+        assert_eq!(progress.skipped.len(), 1);
+
+        let status = OnboardingStatus::from_checks(
+            checks(&[
+                OnboardingStep::Binaries,
+                OnboardingStep::ModelsDir,
+                OnboardingStep::FirstModel,
+            ]),
+            &progress,
+        );
+        assert!(status.finished);
+        assert!(status.steps[3].skipped);
+    }
+
+    #[test]
+    fn pending_download_clears_once_a_model_exists() {
+        let progress = OnboardingProgress {
+            skipped: Vec::new(),
+            pending_download: Some("org/Model-GGUF:Q4_K_M".to_string()),
+        };
+        let waiting = OnboardingStatus::from_checks(checks(&[]), &progress);
+        assert_eq!(
+            waiting.pending_download.as_deref(),
+            Some("org/Model-GGUF:Q4_K_M")
+        );
+
+        let done = OnboardingStatus::from_checks(checks(&[OnboardingStep::FirstModel]), &progress);
+        assert!(done.pending_download.is_none());
+    }
+}
@@ -0,0 +1,484 @@
+//! Multi-stage pipeline domain types.
+//!
+//! A pipeline is a named list of steps run one after another. Each step is
+//! either a prompt sent to a model or a call to a tool, and its output can
+//! be referenced by any later step through a `{{step_id}}` placeholder.
+//! Values supplied when the pipeline is started are referenced as
+//! `{{input.name}}`.
+//!
+//! ```yaml
+//! name: triage
+//! model: qwen3
+//! steps:
+//!   - id: summary
+//!     prompt: "Summarise this report:\n\n{{input.report}}"
+//!   - id: issues
+//!     model: llama3
+//!     prompt: "List every defect mentioned in:\n\n{{summary}}"
+//!   - id: table
+//!     prompt: "Format as a markdown table:\n\n{{issues}}"
+//! ```
+//!
+//! Definitions are validated before anything runs (see
+//! [`validate_definition`]), so a typo in a placeholder fails up front
+//! rather than after the first model call. Each run is persisted together
+//! with every step's output as a [`PipelineRun`].
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use serde::{Deserialize, Serialize};
+
+use crate::domain::InferenceConfig;
+
+/// Placeholder prefix for values supplied when a pipeline is started.
+pub const INPUT_PREFIX: &str = "input.";
+
+/// A pipeline definition, as read from a YAML or JSON file.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct PipelineDefinition {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Model for prompt steps that do not name their own.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub model: Option<String>,
+    /// Default values for `{{input.name}}` placeholders.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub inputs: BTreeMap<String, String>,
+    pub steps: Vec<PipelineStep>,
+}
+
+/// One step of a pipeline: a prompt or a tool call.
+///
+/// Exactly one of `prompt` and `tool` is set. `system`, `model` and
+/// `sampling` only apply to prompt steps; `args` only to tool steps.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct PipelineStep {
+    /// Name later steps reference this step's output by.
+    pub id: String,
+    /// User message template.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub prompt: Option<String>,
+    /// System message template.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub system: Option<String>,
+    /// Model override for this step.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub model: Option<String>,
+    /// Sparse sampling overrides for this step.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sampling: Option<InferenceConfig>,
+    /// Tool to call, as listed by the tool executor (e.g. `builtin:fetch_url`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tool: Option<String>,
+    /// Tool arguments; string values are templates.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub args: Option<serde_json::Value>,
+}
+
+impl PipelineStep {
+    /// The model this step runs on, falling back to the pipeline default.
+    #[must_use]
+    pub fn effective_model<'a>(&'a self, pipeline: &'a PipelineDefinition) -> Option<&'a str> {
+        self.model.as_deref().or(pipeline.model.as_deref())
+    }
+
+    /// Every template string in the step: prompt, system and string args.
+    fn templates(&self) -> Vec<&str> {
+        let mut out: Vec<&str> = self
+            .prompt
+            .iter()
+            .chain(self.system.iter())
+            .map(String::as_str)
+            .collect();
+        if let Some(args) = &self.args {
+            collect_strings(args, &mut out);
+        }
+        out
+    }
+}
+
+fn collect_strings<'a>(value: &'a serde_json::Value, out: &mut Vec<&'a str>) {
+    match value {
+        serde_json::Value::String(s) => out.push(s),
+        serde_json::Value::Array(items) => items.iter().for_each(|v| collect_strings(v, out)),
+        serde_json::Value::Object(map) => map.values().for_each(|v| collect_strings(v, out)),
+        _ => {}
+    }
+}
+
+/// Lifecycle status of a pipeline run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PipelineRunStatus {
+    Running,
+    Completed,
+    Failed,
+}
+
+impl PipelineRunStatus {
+    /// Stable string form, used for storage.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Running => "running",
+            Self::Completed => "completed",
+            Self::Failed => "failed",
+        }
+    }
+
+    /// Parse the [`as_str`](Self::as_str) form.
+    #[must_use]
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "running" => Some(Self::Running),
+            "completed" => Some(Self::Completed),
+            "failed" => Some(Self::Failed),
+            _ => None,
+        }
+    }
+}
+
+/// The output of one completed step.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PipelineStepResult {
+    pub step_id: String,
+    pub output: String,
+    pub created_at: String,
+}
+
+/// A persisted pipeline run with its intermediate results.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PipelineRun {
+    pub id: i64,
+    pub name: String,
+    pub definition: PipelineDefinition,
+    /// Resolved inputs, defaults included.
+    pub inputs: BTreeMap<String, String>,
+    pub status: PipelineRunStatus,
+    /// Why the run failed, naming the step.
+    pub error: Option<String>,
+    /// Outputs of the steps that completed, in order.
+    pub steps: Vec<PipelineStepResult>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+impl PipelineRun {
+    /// Output of the last completed step.
+    #[must_use]
+    pub fn output(&self) -> Option<&str> {
+        self.steps.last().map(|s| s.output.as_str())
+    }
+}
+
+/// Data for recording the start of a run.
+#[derive(Debug, Clone)]
+pub struct NewPipelineRun {
+    pub definition: PipelineDefinition,
+    pub inputs: BTreeMap<String, String>,
+}
+
+/// Placeholder names in `template`, trimmed, in order of appearance.
+///
+/// # Errors
+///
+/// Returns a message for an unclosed `{{` or an empty `{{}}`.
+pub fn placeholders(template: &str) -> Result<Vec<&str>, String> {
+    let mut names = Vec::new();
+    let mut rest = template;
+    while let Some(start) = rest.find("{{") {
+        let after = &rest[start + 2..];
+        let end = after
+            .find("}}")
+            .ok_or_else(|| format!("Unclosed '{{{{' in template: {template:?}"))?;
+        let name = after[..end].trim();
+        if name.is_empty() {
+            return Err(format!("Empty placeholder in template: {template:?}"));
+        }
+        names.push(name);
+        rest = &after[end + 2..];
+    }
+    Ok(names)
+}
+
+/// Substitute `{{name}}` placeholders from `values`.
+///
+/// Unknown names are left as written; [`validate_definition`] rules them
+/// out before a run starts.
+#[must_use]
+pub fn render_template(template: &str, values: &BTreeMap<String, String>) -> String {
+    let mut out = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find("{{") {
+        let after = &rest[start + 2..];
+        let Some(end) = after.find("}}") else {
+            break;
+        };
+        out.push_str(&rest[..start]);
+        match values.get(after[..end].trim()) {
+            Some(value) => out.push_str(value),
+            None => out.push_str(&rest[start..start + 2 + end + 2]),
+        }
+        rest = &after[end + 2..];
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Render every string in a tool-argument value.
+#[must_use]
+pub fn render_args(
+    args: &serde_json::Value,
+    values: &BTreeMap<String, String>,
+) -> serde_json::Value {
+    match args {
+        serde_json::Value::String(s) => serde_json::Value::String(render_template(s, values)),
+        serde_json::Value::Array(items) => {
+            serde_json::Value::Array(items.iter().map(|v| render_args(v, values)).collect())
+        }
+        serde_json::Value::Object(map) => serde_json::Value::Object(
+            map.iter()
+                .map(|(k, v)| (k.clone(), render_args(v, values)))
+                .collect(),
+        ),
+        other => other.clone(),
+    }
+}
+
+/// Input names referenced anywhere in the definition.
+#[must_use]
+pub fn referenced_inputs(definition: &PipelineDefinition) -> BTreeSet<String> {
+    definition
+        .steps
+        .iter()
+        .flat_map(PipelineStep::templates)
+        .filter_map(|t| placeholders(t).ok())
+        .flatten()
+        .filter_map(|name| name.strip_prefix(INPUT_PREFIX))
+        .map(str::to_owned)
+        .collect()
+}
+
+/// Check a definition before running it.
+///
+/// Step ids are lowercase alphanumerics, `-` and `_`, and unique. Every
+/// placeholder names an input or a step that runs earlier, so a run never
+/// reaches a step whose template cannot be filled.
+///
+/// # Errors
+///
+/// Returns a human-readable message naming the offending step.
+pub fn validate_definition(definition: &PipelineDefinition) -> Result<(), String> {
+    if definition.name.trim().is_empty() {
+        return Err("Pipeline name cannot be empty".to_string());
+    }
+    if definition.steps.is_empty() {
+        return Err("Pipeline has no steps".to_string());
+    }
+    let mut seen = BTreeSet::new();
+    for step in &definition.steps {
+        let id = &step.id;
+        if id.is_empty()
+            || !id
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-' || c == '_')
+        {
+            return Err(format!(
+                "Step id '{id}' must be lowercase letters, digits, '-' and '_'"
+            ));
+        }
+        if id == "input" {
+            return Err("Step id 'input' is reserved".to_string());
+        }
+        match (&step.prompt, &step.tool) {
+            (Some(_), Some(_)) | (None, None) => {
+                return Err(format!("Step '{id}' needs exactly one of prompt or tool"));
+            }
+            (None, Some(tool)) => {
+                if tool.trim().is_empty() {
+                    return Err(format!("Step '{id}' has an empty tool name"));
+                }
+                if step.system.is_some() || step.model.is_some() || step.sampling.is_some() {
+                    return Err(format!(
+                        "Step '{id}' is a tool step; system, model and sampling apply to prompts"
+                    ));
+                }
+            }
+            (Some(_), None) => {
+                if step.args.is_some() {
+                    return Err(format!("Step '{id}' is a prompt step; args apply to tools"));
+                }
+                if let Some(sampling) = &step.sampling {
+                    crate::settings::validate_inference_config(sampling)
+                        .map_err(|e| format!("Step '{id}' sampling: {e}"))?;
+                }
+            }
+        }
+        for template in step.templates() {
+            for name in placeholders(template).map_err(|e| format!("Step '{id}': {e}"))? {
+                if name.strip_prefix(INPUT_PREFIX).is_some() {
+                    continue;
+                }
+                if !seen.contains(name) {
+                    return Err(format!(
+                        "Step '{id}' references '{{{{{name}}}}}', which is not an earlier step"
+                    ));
+                }
+            }
+        }
+        if !seen.insert(id.as_str()) {
+            return Err(format!("Duplicate step id '{id}'"));
+        }
+    }
+    Ok(())
+}
+
+/// Merge run-time inputs over the definition's defaults.
+///
+/// # Errors
+///
+/// Returns a message listing referenced inputs that have no value.
+pub fn resolve_inputs(
+    definition: &PipelineDefinition,
+    supplied: BTreeMap<String, String>,
+) -> Result<BTreeMap<String, String>, String> {
+    let mut inputs = definition.inputs.clone();
+    inputs.extend(supplied);
+    let missing: Vec<String> = referenced_inputs(definition)
+        .into_iter()
+        .filter(|name| !inputs.contains_key(name))
+        .collect();
+    if !missing.is_empty() {
+        return Err(format!("Missing pipeline inputs: {}", missing.join(", ")));
+    }
+    Ok(inputs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn prompt_step(id: &str, prompt: &str) -> PipelineStep {
+        PipelineStep {
+            id: id.to_string(),
+            prompt: Some(prompt.to_string()),
+            system: None,
+            model: None,
+            sampling: None,
+            tool: None,
+            args: None,
+        }
+    }
+
+    fn pipeline(steps: Vec<PipelineStep>) -> PipelineDefinition {
+        PipelineDefinition {
+            name: "triage".to_string(),
+            description: None,
+            model: Some("qwen3".to_string()),
+            inputs: BTreeMap::new(),
+            steps,
+        }
+    }
+
+    #[test]
+    fn renders_steps_and_inputs() {
+        let values = BTreeMap::from([
+            ("summary".to_string(), "short".to_string()),
+            ("input.lang".to_string(), "French".to_string()),
+        ]);
+        assert_eq!(
+            render_template("{{ summary }} in {{input.lang}}; {{other}}", &values),
+            "short in French; {{other}}"
+        );
+        assert_eq!(
+            render_args(&serde_json::json!({ "q": ["{{summary}}", 3] }), &values),
+            serde_json::json!({ "q": ["short", 3] })
+        );
+        assert!(placeholders("{{open").is_err());
+        assert!(placeholders("{{ }}").is_err());
+    }
+
+    #[test]
+    fn validates_references_and_step_shape() {
+        let ok = pipeline(vec![
+            prompt_step("summary", "Summarise {{input.text}}"),
+            prompt_step("facts", "Extract from {{summary}}"),
+        ]);
+        assert!(validate_definition(&ok).is_ok());
+
+        let forward = pipeline(vec![
+            prompt_step("facts", "Extract from {{summary}}"),
+            prompt_step("summary", "Summarise"),
+        ]);
+        assert!(
+            validate_definition(&forward)
+                .unwrap_err()
+                .contains("earlier")
+        );
+
+        let own = pipeline(vec![prompt_step("loop", "{{loop}}")]);
+        assert!(validate_definition(&own).is_err());
+
+        let duplicate = pipeline(vec![prompt_step("a", "x"), prompt_step("a", "y")]);
+        assert!(
+            validate_definition(&duplicate)
+                .unwrap_err()
+                .contains("Duplicate")
+        );
+
+        let mut both = prompt_step("a", "x");
+        both.tool = Some("builtin:time".to_string());
+        assert!(validate_definition(&pipeline(vec![both])).is_err());
+
+        let mut tool_with_model = prompt_step("a", "x");
+        tool_with_model.prompt = None;
+        tool_with_model.tool = Some("builtin:time".to_string());
+        tool_with_model.model = Some("qwen3".to_string());
+        assert!(validate_definition(&pipeline(vec![tool_with_model])).is_err());
+    }
+
+    #[test]
+    fn inputs_merge_over_defaults_and_missing_ones_are_named() {
+        let mut def = pipeline(vec![prompt_step("a", "{{input.text}} in {{input.lang}}")]);
+        def.inputs.insert("lang".to_string(), "English".to_string());
+
+        let err = resolve_inputs(&def, BTreeMap::new()).unwrap_err();
+        assert_eq!(err, "Missing pipeline inputs: text");
+
+        let inputs = resolve_inputs(
+            &def,
+            BTreeMap::from([("text".to_string(), "hello".to_string())]),
+        )
+        .unwrap();
+        assert_eq!(inputs["lang"], "English");
+        assert_eq!(inputs["text"], "hello");
+    }
+
+    #[test]
+    fn parses_camel_case_json_and_rejects_unknown_fields() {
+        let def: PipelineDefinition = serde_json::from_value(serde_json::json!({
+            "name": "fetch",
+            "steps": [
+                { "id": "page", "tool": "builtin:fetch_url", "args": { "url": "{{input.url}}" } },
+                { "id": "gist", "prompt": "{{page}}", "sampling": { "temperature": 0.2 } }
+            ]
+        }))
+        .unwrap();
+        assert!(validate_definition(&def).is_ok());
+        assert_eq!(
+            def.steps[1].sampling.as_ref().unwrap().temperature,
+            Some(0.2)
+        );
+
+        let typo = serde_json::from_value::<PipelineDefinition>(serde_json::json!({
+            "name": "x",
+            "steps": [{ "id": "a", "promt": "hi" }]
+        }));
+        assert!(typo.is_err());
+    }
+}
@@ -0,0 +1,223 @@
+//! Generation preset domain types.
+//!
+//! A preset bundles what makes a "behaviour" — a persona system prompt,
+//! sampling parameters, the tools the model may call, and a default model —
+//! under one name. Proxy clients select one per request with the
+//! `x-gglib-preset` header or a `preset` field in the request body, so an
+//! external client gets a curated setup without configuring any of it.
+//!
+//! The sampling config is sparse in the same way as an
+//! [`InferenceProfile`](super::inference_profile::InferenceProfile): unset
+//! fields fall through to the per-model and global defaults.
+
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::domain::InferenceConfig;
+
+/// Longest preset name accepted, in characters.
+pub const MAX_PRESET_NAME_LEN: usize = 64;
+
+/// A named bundle of system prompt, sampling and tool selection.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct Preset {
+    pub id: i64,
+    /// Slug clients select the preset by; see [`validate_preset`].
+    pub name: String,
+    pub description: Option<String>,
+    /// Persona prompt placed ahead of the request's own system message.
+    pub system_prompt: Option<String>,
+    /// Sparse sampling overrides.
+    pub sampling: InferenceConfig,
+    /// Tool names the model may call; empty leaves the request's tools alone.
+    pub tools: Vec<String>,
+    /// MCP servers whose tools the preset enables.
+    pub mcp_server_ids: Vec<i64>,
+    /// Model used when the request leaves `model` empty.
+    pub default_model: Option<String>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+impl Preset {
+    /// Whether the preset lets the model call `tool`.
+    #[must_use]
+    pub fn allows_tool(&self, tool: &str) -> bool {
+        self.tools.is_empty() || self.tools.iter().any(|t| t == tool)
+    }
+}
+
+/// Data for creating a new preset.
+#[derive(Debug, Clone, Default)]
+pub struct NewPreset {
+    pub name: String,
+    pub description: Option<String>,
+    pub system_prompt: Option<String>,
+    pub sampling: InferenceConfig,
+    pub tools: Vec<String>,
+    pub mcp_server_ids: Vec<i64>,
+    pub default_model: Option<String>,
+}
+
+/// Partial update for a preset; `None` fields are left unchanged.
+///
+/// Optional fields use `Some(None)` to clear.
+#[derive(Debug, Clone, Default)]
+pub struct PresetUpdate {
+    pub name: Option<String>,
+    pub description: Option<Option<String>>,
+    pub system_prompt: Option<Option<String>>,
+    pub sampling: Option<InferenceConfig>,
+    pub tools: Option<Vec<String>>,
+    pub mcp_server_ids: Option<Vec<i64>>,
+    pub default_model: Option<Option<String>>,
+}
+
+impl PresetUpdate {
+    /// Whether the update changes nothing.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.name.is_none()
+            && self.description.is_none()
+            && self.system_prompt.is_none()
+            && self.sampling.is_none()
+            && self.tools.is_none()
+            && self.mcp_server_ids.is_none()
+            && self.default_model.is_none()
+    }
+
+    /// Apply the update to `preset`.
+    pub fn apply(self, preset: &mut Preset) {
+        if let Some(name) = self.name {
+            preset.name = name;
+        }
+        if let Some(description) = self.description {
+            preset.description = description;
+        }
+        if let Some(system_prompt) = self.system_prompt {
+            preset.system_prompt = system_prompt;
+        }
+        if let Some(sampling) = self.sampling {
+            preset.sampling = sampling;
+        }
+        if let Some(tools) = self.tools {
+            preset.tools = tools;
+        }
+        if let Some(ids) = self.mcp_server_ids {
+            preset.mcp_server_ids = ids;
+        }
+        if let Some(model) = self.default_model {
+            preset.default_model = model;
+        }
+    }
+}
+
+/// Check the fields of a preset.
+///
+/// Names follow the inference profile rules — lowercase alphanumerics and
+/// `-`, no leading or trailing `-` — because they travel in an HTTP header.
+///
+/// # Errors
+///
+/// Returns a human-readable message for a bad name, a blank tool name or
+/// default model, or sampling values outside their valid ranges.
+pub fn validate_preset(preset: &Preset) -> Result<(), String> {
+    let name = &preset.name;
+    if name.is_empty() {
+        return Err("Preset name cannot be empty".to_string());
+    }
+    if name.len() > MAX_PRESET_NAME_LEN {
+        return Err(format!(
+            "Preset name must be at most {MAX_PRESET_NAME_LEN} characters"
+        ));
+    }
+    if !name
+        .chars()
+        .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+        || name.starts_with('-')
+        || name.ends_with('-')
+    {
+        return Err(format!(
+            "Preset name '{name}' must be lowercase letters, digits and '-', \
+             without a leading or trailing '-'"
+        ));
+    }
+    if preset.tools.iter().any(|t| t.trim().is_empty()) {
+        return Err("Preset tool names cannot be empty".to_string());
+    }
+    if preset
+        .default_model
+        .as_deref()
+        .is_some_and(|m| m.trim().is_empty())
+    {
+        return Err("Preset default model cannot be empty".to_string());
+    }
+    crate::settings::validate_inference_config(&preset.sampling)
+        .map_err(|e| format!("Invalid preset sampling: {e}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn preset() -> Preset {
+        Preset {
+            id: 1,
+            name: "code-review".to_string(),
+            description: None,
+            system_prompt: Some("You review Rust code.".to_string()),
+            sampling: InferenceConfig {
+                temperature: Some(0.2),
+                ..Default::default()
+            },
+            tools: vec![],
+            mcp_server_ids: vec![],
+            default_model: None,
+            created_at: String::new(),
+            updated_at: String::new(),
+        }
+    }
+
+    #[test]
+    fn validates_fields() {
+        assert!(validate_preset(&preset()).is_ok());
+
+        for bad in ["", "Code", "-code", "code review"] {
+            let mut p = preset();
+            p.name = bad.to_string();
+            assert!(validate_preset(&p).is_err(), "{bad:?} accepted");
+        }
+
+        let mut p = preset();
+        p.tools = vec![" ".to_string()];
+        assert!(validate_preset(&p).is_err());
+
+        let mut p = preset();
+        p.sampling.temperature = Some(5.0);
+        assert!(validate_preset(&p).is_err());
+    }
+
+    #[test]
+    fn empty_tool_list_allows_everything() {
+        let mut p = preset();
+        assert!(p.allows_tool("builtin:time"));
+        p.tools = vec!["builtin:time".to_string()];
+        assert!(p.allows_tool("builtin:time"));
+        assert!(!p.allows_tool("builtin:web_search"));
+    }
+
+    #[test]
+    fn update_applies_only_set_fields() {
+        let mut p = preset();
+        PresetUpdate {
+            system_prompt: Some(None),
+            default_model: Some(Some("qwen3".to_string())),
+            ..Default::default()
+        }
+        .apply(&mut p);
+        assert_eq!(p.system_prompt, None);
+        assert_eq!(p.default_model.as_deref(), Some("qwen3"));
+        assert_eq!(p.sampling.temperature, Some(0.2));
+    }
+}
@@ -0,0 +1,210 @@
+//! Project domain types.
+//!
+//! A project links chat conversations to a local directory. Its include and
+//! exclude globs pick the files that are injected into the prompt of every
+//! linked conversation, up to a token budget, so coding-assistant chats see
+//! the relevant sources without the user pasting them in.
+//!
+//! Globs are matched against paths relative to the project root with `/`
+//! separators: `src/**/*.rs` selects every Rust file under `src`, and
+//! `*.md` only the Markdown files at the top level.
+
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// Token budget for injected files when a project does not set one.
+pub const DEFAULT_PROJECT_TOKEN_BUDGET: u32 = 8_192;
+
+/// Upper bound accepted for [`Project::token_budget`].
+pub const MAX_PROJECT_TOKEN_BUDGET: u32 = 262_144;
+
+/// Longest project name accepted, in characters.
+pub const MAX_PROJECT_NAME_LEN: usize = 100;
+
+/// A local directory that conversations can draw context from.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct Project {
+    pub id: i64,
+    pub name: String,
+    /// Absolute path of the project directory.
+    pub root_path: String,
+    /// Globs selecting the files injected into linked conversations.
+    pub include_globs: Vec<String>,
+    /// Globs removing files from the include set.
+    pub exclude_globs: Vec<String>,
+    /// Whether linked conversations get the included files automatically.
+    pub auto_include: bool,
+    /// Token budget for injected files; `None` means
+    /// [`DEFAULT_PROJECT_TOKEN_BUDGET`].
+    pub token_budget: Option<u32>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+impl Project {
+    /// The effective token budget (with default fallback).
+    #[must_use]
+    pub fn effective_token_budget(&self) -> u32 {
+        self.token_budget.unwrap_or(DEFAULT_PROJECT_TOKEN_BUDGET)
+    }
+}
+
+/// Data for creating a new project.
+#[derive(Debug, Clone)]
+pub struct NewProject {
+    pub name: String,
+    pub root_path: String,
+    pub include_globs: Vec<String>,
+    pub exclude_globs: Vec<String>,
+    pub auto_include: bool,
+    pub token_budget: Option<u32>,
+}
+
+/// Partial update for a project; `None` fields are left unchanged.
+#[derive(Debug, Clone, Default)]
+pub struct ProjectUpdate {
+    pub name: Option<String>,
+    pub root_path: Option<String>,
+    pub include_globs: Option<Vec<String>>,
+    pub exclude_globs: Option<Vec<String>>,
+    pub auto_include: Option<bool>,
+    /// Use `Some(Some(n))` to set, `Some(None)` to reset to the default.
+    pub token_budget: Option<Option<u32>>,
+}
+
+impl ProjectUpdate {
+    /// Whether the update changes nothing.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.name.is_none()
+            && self.root_path.is_none()
+            && self.include_globs.is_none()
+            && self.exclude_globs.is_none()
+            && self.auto_include.is_none()
+            && self.token_budget.is_none()
+    }
+
+    /// Apply the update to `project`.
+    pub fn apply(self, project: &mut Project) {
+        if let Some(name) = self.name {
+            project.name = name;
+        }
+        if let Some(root_path) = self.root_path {
+            project.root_path = root_path;
+        }
+        if let Some(globs) = self.include_globs {
+            project.include_globs = globs;
+        }
+        if let Some(globs) = self.exclude_globs {
+            project.exclude_globs = globs;
+        }
+        if let Some(auto_include) = self.auto_include {
+            project.auto_include = auto_include;
+        }
+        if let Some(budget) = self.token_budget {
+            project.token_budget = budget;
+        }
+    }
+}
+
+/// Check the fields of a project that do not depend on the filesystem.
+///
+/// # Errors
+///
+/// Returns a human-readable message for an empty or overlong name, a
+/// relative root path, a blank glob, or a token budget outside
+/// `1..=MAX_PROJECT_TOKEN_BUDGET`.
+pub fn validate_project(project: &Project) -> Result<(), String> {
+    let name = project.name.trim();
+    if name.is_empty() {
+        return Err("Project name cannot be empty".to_string());
+    }
+    if name.chars().count() > MAX_PROJECT_NAME_LEN {
+        return Err(format!(
+            "Project name must be at most {MAX_PROJECT_NAME_LEN} characters"
+        ));
+    }
+    if !std::path::Path::new(&project.root_path).is_absolute() {
+        return Err(format!(
+            "Project root must be an absolute path, got {:?}",
+            project.root_path
+        ));
+    }
+    if project
+        .include_globs
+        .iter()
+        .chain(&project.exclude_globs)
+        .any(|g| g.trim().is_empty())
+    {
+        return Err("Project globs cannot be empty".to_string());
+    }
+    if let Some(budget) = project.token_budget
+        && !(1..=MAX_PROJECT_TOKEN_BUDGET).contains(&budget)
+    {
+        return Err(format!(
+            "Token budget must be between 1 and {MAX_PROJECT_TOKEN_BUDGET}, got {budget}"
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn project() -> Project {
+        Project {
+            id: 1,
+            name: "gglib".to_string(),
+            root_path: if cfg!(windows) {
+                "C:\\src\\gglib"
+            } else {
+                "/src/gglib"
+            }
+            .to_string(),
+            include_globs: vec!["src/**/*.rs".to_string()],
+            exclude_globs: vec![],
+            auto_include: true,
+            token_budget: None,
+            created_at: String::new(),
+            updated_at: String::new(),
+        }
+    }
+
+    #[test]
+    fn validates_fields() {
+        assert!(validate_project(&project()).is_ok());
+
+        let mut p = project();
+        p.name = "  ".to_string();
+        assert!(validate_project(&p).is_err());
+
+        let mut p = project();
+        p.root_path = "relative/dir".to_string();
+        assert!(validate_project(&p).is_err());
+
+        let mut p = project();
+        p.token_budget = Some(0);
+        assert!(validate_project(&p).is_err());
+
+        let mut p = project();
+        p.exclude_globs = vec![String::new()];
+        assert!(validate_project(&p).is_err());
+    }
+
+    #[test]
+    fn update_applies_only_set_fields() {
+        let mut p = project();
+        p.token_budget = Some(100);
+        ProjectUpdate {
+            auto_include: Some(false),
+            token_budget: Some(None),
+            ..Default::default()
+        }
+        .apply(&mut p);
+        assert!(!p.auto_include);
+        assert_eq!(p.token_budget, None);
+        assert_eq!(p.effective_token_budget(), DEFAULT_PROJECT_TOKEN_BUDGET);
+        assert_eq!(p.name, "gglib");
+    }
+}
@@ -0,0 +1,131 @@
+//! Cached prompts: long fixed prefixes kept warm in llama-server's KV cache.
+//!
+//! Agents with a large, unchanging system prompt pay its full prefill on the
+//! first request after every server start. The `cached_prompts` setting
+//! names such prompts per model; the runtime evaluates each once, saves the
+//! resulting KV state to the slot directory, and restores it the next time
+//! that model starts, so the first real request only prefills what follows
+//! the prefix.
+
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+use utoipa::ToSchema;
+
+/// Longest accepted cached-prompt name, matching the session-id limit of the
+/// slot cache.
+pub const MAX_PROMPT_NAME_LEN: usize = 64;
+
+/// A system prompt to keep warm for one model.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, TS, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CachedPrompt {
+    /// ID of the model the prompt is warmed on.
+    pub model_id: i64,
+    /// Name the saved KV state is stored under; see [`is_valid_prompt_name`].
+    pub name: String,
+    /// The system prompt text, exactly as clients send it.
+    pub system_prompt: String,
+}
+
+/// Where a warmed prompt's KV state came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, TS, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum PromptWarmSource {
+    /// Loaded from the state saved on an earlier run.
+    Restored,
+    /// Prefilled by llama-server from the prompt text.
+    Evaluated,
+}
+
+/// Outcome of warming one cached prompt on a running server.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, TS, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PromptWarmResult {
+    /// Name of the prompt.
+    pub name: String,
+    /// Where the KV state came from.
+    pub source: PromptWarmSource,
+    /// Whether the state is saved for the next start. `false` when the
+    /// server has no slot directory, the model cannot restore slots, or the
+    /// save failed.
+    pub persisted: bool,
+    /// Wall time the warm took, in milliseconds.
+    pub elapsed_ms: u64,
+}
+
+/// Whether `name` can name a cached prompt: 1 to [`MAX_PROMPT_NAME_LEN`]
+/// ASCII letters, digits, `-` or `_`.
+///
+/// The name ends up in a file name llama-server validates, so anything that
+/// could be a path separator or a dot is refused here rather than there.
+#[must_use]
+pub fn is_valid_prompt_name(name: &str) -> bool {
+    !name.is_empty()
+        && name.len() <= MAX_PROMPT_NAME_LEN
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+}
+
+/// Validate a cached-prompt list: valid names, non-empty prompts, and each
+/// name used once per model.
+///
+/// # Errors
+///
+/// Returns a human-readable description of the first problem found.
+pub fn validate_cached_prompts(prompts: &[CachedPrompt]) -> Result<(), String> {
+    let mut seen: Vec<(i64, &str)> = Vec::with_capacity(prompts.len());
+    for prompt in prompts {
+        if !is_valid_prompt_name(&prompt.name) {
+            return Err(format!(
+                "invalid name {:?}: use 1-{MAX_PROMPT_NAME_LEN} letters, digits, '-' or '_'",
+                prompt.name
+            ));
+        }
+        if prompt.system_prompt.trim().is_empty() {
+            return Err(format!("prompt {:?} is empty", prompt.name));
+        }
+        let key = (prompt.model_id, prompt.name.as_str());
+        if seen.contains(&key) {
+            return Err(format!(
+                "prompt {:?} is listed twice for model {}",
+                prompt.name, prompt.model_id
+            ));
+        }
+        seen.push(key);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn prompt(model_id: i64, name: &str) -> CachedPrompt {
+        CachedPrompt {
+            model_id,
+            name: name.to_string(),
+            system_prompt: "You are a careful code reviewer.".to_string(),
+        }
+    }
+
+    #[test]
+    fn names_are_restricted_to_file_safe_characters() {
+        assert!(is_valid_prompt_name("reviewer_v2-long"));
+        assert!(!is_valid_prompt_name(""));
+        assert!(!is_valid_prompt_name("../etc"));
+        assert!(!is_valid_prompt_name("a.b"));
+        assert!(!is_valid_prompt_name(&"x".repeat(MAX_PROMPT_NAME_LEN + 1)));
+    }
+
+    #[test]
+    fn names_are_unique_per_model() {
+        assert!(validate_cached_prompts(&[prompt(1, "agent"), prompt(2, "agent")]).is_ok());
+        let err = validate_cached_prompts(&[prompt(1, "agent"), prompt(1, "agent")]).unwrap_err();
+        assert!(err.contains("twice"), "{err}");
+
+        let mut empty = prompt(1, "blank");
+        empty.system_prompt = "  ".to_string();
+        assert!(validate_cached_prompts(&[empty]).is_err());
+    }
+}
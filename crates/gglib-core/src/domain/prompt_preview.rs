@@ -0,0 +1,72 @@
+//! Rendered prompt previews.
+//!
+//! A model only ever sees the string its chat template makes of a
+//! conversation. When a model ignores its system prompt or rambles past its
+//! turn, that string — not the message list — is what needs looking at. A
+//! [`PromptPreview`] is that string as the running server rendered it, with
+//! its token count measured against the server's context.
+
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+use utoipa::ToSchema;
+
+/// A conversation rendered through a model's chat template.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, TS, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PromptPreview {
+    /// The prompt exactly as the server would evaluate it, generation
+    /// prompt for the assistant's reply included.
+    pub prompt: String,
+    /// Tokens in `prompt`, special tokens included.
+    pub token_count: u64,
+    /// Context size of one slot of the server, in tokens. `None` when the
+    /// server does not report it.
+    #[serde(default)]
+    #[ts(optional = nullable)]
+    pub context_size: Option<u64>,
+    /// Set when the prompt fills or overflows the context, leaving no room
+    /// for a reply.
+    #[serde(default)]
+    #[ts(optional = nullable)]
+    pub warning: Option<String>,
+}
+
+impl PromptPreview {
+    /// Preview of `prompt`, warning when `token_count` leaves no room in
+    /// `context_size` for the reply.
+    #[must_use]
+    pub fn new(prompt: String, token_count: u64, context_size: Option<u64>) -> Self {
+        let warning = context_size.filter(|&ctx| token_count >= ctx).map(|ctx| {
+            format!(
+                "Prompt is {token_count} tokens but the context holds {ctx}; the server \
+                 will reject or truncate it. Shorten the conversation or start the model \
+                 with a larger context."
+            )
+        });
+        Self {
+            prompt,
+            token_count,
+            context_size,
+            warning,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn warns_only_when_the_prompt_fills_the_context() {
+        assert!(
+            PromptPreview::new("hi".into(), 10, Some(4096))
+                .warning
+                .is_none()
+        );
+        assert!(PromptPreview::new("hi".into(), 10, None).warning.is_none());
+
+        let full = PromptPreview::new("hi".into(), 4096, Some(4096));
+        let warning = full.warning.unwrap();
+        assert!(warning.contains("4096 tokens"), "{warning}");
+    }
+}
@@ -0,0 +1,126 @@
+//! Server-side caps on proxied generations.
+//!
+//! A misconfigured client can ask for 100k tokens and hold a server's slot
+//! for minutes. The `proxy_limits` setting ([`ProxyLimits`]) bounds what the
+//! proxy lets one request do:
+//!
+//! - `max_tokens` lowers a larger (or missing) `max_tokens` in the request,
+//!   so the server stops with `finish_reason: "length"`;
+//! - `max_request_seconds` ends a stream once that long has passed since it
+//!   reached the server, with `finish_reason` [`TIME_LIMIT_FINISH_REASON`];
+//! - `max_streams_per_key` turns away further streams from an API key (or,
+//!   when the proxy requires none, an IP address) that already has that
+//!   many open.
+
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+use utoipa::ToSchema;
+
+/// `finish_reason` of a stream the proxy ended at
+/// [`ProxyLimits::max_request_seconds`].
+pub const TIME_LIMIT_FINISH_REASON: &str = "time_limit";
+
+/// Upper bound accepted for [`ProxyLimits::max_request_seconds`] (a day).
+pub const MAX_REQUEST_SECONDS: u32 = 86_400;
+
+/// Limits the proxy enforces on each chat completion.
+///
+/// Stored as the `proxy_limits` setting; every limit is optional and read
+/// per request, so changes apply without a restart.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, TS, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ProxyLimits {
+    /// Most tokens one completion may generate.
+    #[serde(default)]
+    pub max_tokens: Option<u32>,
+    /// Longest a request may run on the server, in seconds.
+    #[serde(default)]
+    pub max_request_seconds: Option<u32>,
+    /// Most streams one API key may have open at once. When the proxy
+    /// requires no key, tokens go unchecked and streams are counted per
+    /// client IP address instead.
+    #[serde(default)]
+    pub max_streams_per_key: Option<u32>,
+}
+
+impl ProxyLimits {
+    /// Whether no limit is set.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.max_tokens.is_none()
+            && self.max_request_seconds.is_none()
+            && self.max_streams_per_key.is_none()
+    }
+
+    /// Check every set limit is usable.
+    ///
+    /// # Errors
+    ///
+    /// Returns a human-readable description of the first problem found.
+    pub fn validate(&self) -> Result<(), String> {
+        if self.max_tokens == Some(0) {
+            return Err("max tokens must be at least 1".to_string());
+        }
+        if let Some(seconds) = self.max_request_seconds
+            && !(1..=MAX_REQUEST_SECONDS).contains(&seconds)
+        {
+            return Err(format!(
+                "max request seconds must be between 1 and {MAX_REQUEST_SECONDS}, got {seconds}"
+            ));
+        }
+        if self.max_streams_per_key == Some(0) {
+            return Err("max streams per key must be at least 1".to_string());
+        }
+        Ok(())
+    }
+
+    /// The token limit to send upstream for a request that asked for
+    /// `requested`: the ceiling when the request set none, a negative
+    /// (unbounded) one or one above it. `None` leaves the request alone.
+    #[must_use]
+    pub fn clamp_max_tokens(&self, requested: Option<i64>) -> Option<u32> {
+        let ceiling = self.max_tokens?;
+        match requested {
+            Some(requested) if (0..=i64::from(ceiling)).contains(&requested) => None,
+            _ => Some(ceiling),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn clamp_lowers_only_requests_over_the_ceiling() {
+        let limits = ProxyLimits {
+            max_tokens: Some(4096),
+            ..ProxyLimits::default()
+        };
+        assert_eq!(limits.clamp_max_tokens(Some(100_000)), Some(4096));
+        assert_eq!(limits.clamp_max_tokens(None), Some(4096));
+        assert_eq!(limits.clamp_max_tokens(Some(-1)), Some(4096));
+        assert_eq!(limits.clamp_max_tokens(Some(512)), None);
+        assert_eq!(ProxyLimits::default().clamp_max_tokens(None), None);
+    }
+
+    #[test]
+    fn validate_rejects_zero_and_overlong_limits() {
+        let zero_tokens = ProxyLimits {
+            max_tokens: Some(0),
+            ..ProxyLimits::default()
+        };
+        assert!(zero_tokens.validate().is_err());
+        let too_long = ProxyLimits {
+            max_request_seconds: Some(MAX_REQUEST_SECONDS + 1),
+            ..ProxyLimits::default()
+        };
+        assert!(too_long.validate().is_err());
+        let zero_streams = ProxyLimits {
+            max_streams_per_key: Some(0),
+            ..ProxyLimits::default()
+        };
+        assert!(zero_streams.validate().is_err());
+        assert!(ProxyLimits::default().validate().is_ok());
+    }
+}
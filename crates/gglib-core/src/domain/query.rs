@@ -1,12 +1,15 @@
-//! Model list query, filter, and sort types.
+//! Model list query, filter, sort, and paging types.
 //!
 //! This module is the **single source of truth** for model filtering and
-//! sorting logic. Both the CLI (direct-mode) and the Axum HTTP handler
+//! sorting logic; repositories that filter in storage must match
+//! [`apply_query`]. Both the CLI (direct-mode) and the Axum HTTP handler
 //! delegate here; the GUI sends HTTP query parameters that are deserialized
 //! into [`ModelListQuery`] on the server side. No filter/sort logic is
 //! duplicated in the frontend.
 
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
 use crate::domain::Model;
 
@@ -15,7 +18,7 @@ use crate::domain::Model;
 // ─────────────────────────────────────────────────────────────────────────────
 
 /// The field to sort the model list by.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum ModelSortBy {
     /// Sort by when the model was added (most recent first by default).
@@ -30,7 +33,7 @@ pub enum ModelSortBy {
 }
 
 /// Direction for sorting.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum SortOrder {
     /// Largest / most-recent first.
@@ -73,6 +76,11 @@ pub struct ModelListQuery {
     /// any listed value (case-sensitive). Models with no quantization are
     /// excluded when this filter is active.
     pub quantizations: Option<Vec<String>>,
+    /// License allowlist. A model passes if its license matches any listed
+    /// value, ignoring case. Models with no recorded license are excluded
+    /// when this filter is active.
+    #[serde(default)]
+    pub licenses: Option<Vec<String>>,
     /// Required tags. A model passes only if it has **all** listed tags.
     pub tags: Option<Vec<String>>,
     /// Inclusive minimum `latest_tg_tps`. Models with no benchmark data are
@@ -100,6 +108,9 @@ pub struct ModelListQuery {
 /// - **`quantizations`**: model's quantization must match one of the listed
 ///   values. Models with no quantization are excluded when this filter is
 ///   active.
+/// - **`licenses`**: model's license must match one of the listed values,
+///   ignoring case. Models with no license are excluded when this filter is
+///   active.
 /// - **`tags`**: model must have *all* listed tags (AND semantics).
 /// - **`min_speed`/`max_speed`**: model's `benchmark_summary.latest_tg_tps`
 ///   must be within the range. Models with no benchmark data are **excluded**
@@ -192,6 +203,16 @@ fn matches_query(m: &Model, query: &ModelListQuery) -> bool {
         }
     }
 
+    // License allowlist
+    if let Some(licenses) = &query.licenses {
+        if !licenses.is_empty() {
+            match &m.license {
+                Some(l) if licenses.iter().any(|x| x.eq_ignore_ascii_case(l)) => {}
+                _ => return false,
+            }
+        }
+    }
+
     // Tags — model must carry ALL listed tags
     if let Some(tags) = &query.tags {
         if !tags.is_empty() && !tags.iter().all(|t| m.tags.contains(t)) {
@@ -243,6 +264,84 @@ fn cmp_tps_desc(a: Option<f64>, b: Option<f64>) -> std::cmp::Ordering {
     }
 }
 
+// ─────────────────────────────────────────────────────────────────────────────
+// Paging and deltas
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Models per page when the caller doesn't say.
+pub const DEFAULT_PAGE_SIZE: usize = 50;
+
+/// Most models a single page may hold.
+pub const MAX_PAGE_SIZE: usize = 500;
+
+/// Which slice of a sorted, filtered model list to return.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PageRequest {
+    /// Models to skip.
+    pub offset: usize,
+    /// Models to return, between 1 and [`MAX_PAGE_SIZE`].
+    pub limit: usize,
+}
+
+impl PageRequest {
+    /// A page of up to `limit` models after the first `offset`; `limit` is
+    /// clamped to `1..=MAX_PAGE_SIZE`.
+    #[must_use]
+    pub fn new(offset: usize, limit: usize) -> Self {
+        Self {
+            offset,
+            limit: limit.clamp(1, MAX_PAGE_SIZE),
+        }
+    }
+}
+
+impl Default for PageRequest {
+    fn default() -> Self {
+        Self::new(0, DEFAULT_PAGE_SIZE)
+    }
+}
+
+/// One page of the model list.
+#[derive(Debug, Clone, Default)]
+pub struct ModelPage {
+    /// The models on this page, in query order.
+    pub items: Vec<Model>,
+    /// Models matching the query across all pages.
+    pub total: usize,
+}
+
+/// Cut `page` out of `models` after applying `query` to them.
+///
+/// This is what a repository that can't page in storage returns.
+#[must_use]
+pub fn page_of(models: Vec<Model>, query: &ModelListQuery, page: PageRequest) -> ModelPage {
+    let models = apply_query(models, query);
+    let total = models.len();
+    let items = models
+        .into_iter()
+        .skip(page.offset)
+        .take(page.limit)
+        .collect();
+    ModelPage { items, total }
+}
+
+/// What changed in the model list since a point in time.
+///
+/// Apply `removed` before `changed`: a removed model's id can be reused by a
+/// model added later.
+#[derive(Debug, Clone)]
+pub struct ModelChanges {
+    /// Models added or updated since then.
+    pub changed: Vec<Model>,
+    /// Ids of models removed since then.
+    pub removed: Vec<i64>,
+    /// `changed` is the whole list, not a delta: replace rather than merge.
+    pub full: bool,
+    /// Pass as the next `since`. It may be slightly earlier than now, so a
+    /// change can be reported twice but is never missed.
+    pub as_of: DateTime<Utc>,
+}
+
 // ─────────────────────────────────────────────────────────────────────────────
 // Tests
 // ─────────────────────────────────────────────────────────────────────────────
@@ -276,11 +375,14 @@ mod tests {
             hf_filename: None,
             download_date: None,
             last_update_check: None,
+            license: None,
             tags: vec![],
             capabilities: ModelCapabilities::default(),
+            capabilities_version: None,
             inference_defaults: None,
             server_defaults: None,
             benchmark_summary: None,
+            card: None,
         }
     }
 
@@ -289,6 +391,11 @@ mod tests {
         m
     }
 
+    fn with_license(mut m: Model, license: &str) -> Model {
+        m.license = Some(license.to_string());
+        m
+    }
+
     fn with_tags(mut m: Model, tags: &[&str]) -> Model {
         m.tags = tags.iter().map(ToString::to_string).collect();
         m
@@ -352,6 +459,22 @@ mod tests {
         );
     }
 
+    #[test]
+    fn license_filter_ignores_case_and_drops_unlicensed_models() {
+        let licensed = vec![
+            with_license(make_model(1, "a", 7.0), "apache-2.0"),
+            with_license(make_model(2, "b", 13.0), "cc-by-nc-4.0"),
+            make_model(3, "c", 70.0),
+        ];
+        let query = ModelListQuery {
+            licenses: Some(vec!["Apache-2.0".to_string()]),
+            ..Default::default()
+        };
+        let result = apply_query(licensed, &query);
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].name, "a");
+    }
+
     #[test]
     fn tag_filter_uses_and_semantics() {
         let tagged = vec![
@@ -424,4 +547,23 @@ mod tests {
         let result = apply_query(models(), &query);
         assert_eq!(result.len(), 3);
     }
+
+    #[test]
+    fn page_of_slices_the_filtered_sorted_list() {
+        let query = ModelListQuery {
+            sort_by: ModelSortBy::Name,
+            order: SortOrder::Asc,
+            ..Default::default()
+        };
+        let page = page_of(models(), &query, PageRequest::new(1, 1));
+        assert_eq!(page.total, 3);
+        assert_eq!(page.items.len(), 1);
+        assert_eq!(page.items[0].name, "beta");
+
+        let past_the_end = page_of(models(), &query, PageRequest::new(5, 10));
+        assert_eq!(past_the_end.total, 3);
+        assert!(past_the_end.items.is_empty());
+        assert_eq!(PageRequest::new(0, 0).limit, 1);
+        assert_eq!(PageRequest::new(0, usize::MAX).limit, MAX_PAGE_SIZE);
+    }
 }
@@ -0,0 +1,405 @@
+//! Hardware-aware model recommendations ("what can I run?").
+//!
+//! Combines a curated catalog of GGUF repositories, each tagged with the
+//! tasks it is good at, with the machine's memory to pick, for every entry,
+//! the highest-quality quantization that fits. Sizes are *estimates* from
+//! parameter count and the quantization's average bits per weight, plus
+//! headroom for the KV cache and runtime buffers — close enough to rank and
+//! filter, and available without touching the network.
+//!
+//! The memory budget is GPU memory when a GPU (or Apple unified memory) was
+//! detected, otherwise three quarters of system RAM for CPU inference.
+
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::download::Quantization;
+use crate::utils::system::SystemMemoryInfo;
+
+/// Task a catalog entry is suited to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum ModelTask {
+    Coding,
+    Chat,
+    Vision,
+    Embedding,
+}
+
+impl ModelTask {
+    /// Every category, in display order.
+    pub const ALL: [Self; 4] = [Self::Coding, Self::Chat, Self::Vision, Self::Embedding];
+
+    /// Lowercase name, as accepted by [`FromStr`].
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Coding => "coding",
+            Self::Chat => "chat",
+            Self::Vision => "vision",
+            Self::Embedding => "embedding",
+        }
+    }
+}
+
+impl fmt::Display for ModelTask {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for ModelTask {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|task| task.as_str().eq_ignore_ascii_case(s.trim()))
+            .ok_or_else(|| {
+                format!(
+                    "unknown task '{s}' (expected one of: {})",
+                    Self::ALL.map(Self::as_str).join(", ")
+                )
+            })
+    }
+}
+
+/// One curated repository in the recommendation catalog.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CatalogEntry {
+    /// `HuggingFace` repository ID.
+    pub repo_id: String,
+    /// Display name.
+    pub name: String,
+    /// Parameter count in billions.
+    pub params_b: f64,
+    /// Tasks the model is suited to.
+    pub tasks: Vec<ModelTask>,
+    /// Free-form tags (family, licence, ...).
+    #[serde(default)]
+    pub tags: Vec<String>,
+}
+
+impl CatalogEntry {
+    fn new(repo_id: &str, name: &str, params_b: f64, tasks: &[ModelTask], tags: &[&str]) -> Self {
+        Self {
+            repo_id: repo_id.to_string(),
+            name: name.to_string(),
+            params_b,
+            tasks: tasks.to_vec(),
+            tags: tags.iter().map(ToString::to_string).collect(),
+        }
+    }
+}
+
+/// The built-in curated catalog.
+#[must_use]
+pub fn builtin_catalog() -> Vec<CatalogEntry> {
+    use ModelTask::{Chat, Coding, Embedding, Vision};
+    vec![
+        CatalogEntry::new(
+            "unsloth/Qwen3-32B-GGUF",
+            "Qwen3 32B",
+            32.8,
+            &[Chat, Coding],
+            &["qwen"],
+        ),
+        CatalogEntry::new(
+            "unsloth/Qwen3-14B-GGUF",
+            "Qwen3 14B",
+            14.8,
+            &[Chat, Coding],
+            &["qwen"],
+        ),
+        CatalogEntry::new(
+            "unsloth/Qwen3-8B-GGUF",
+            "Qwen3 8B",
+            8.2,
+            &[Chat, Coding],
+            &["qwen"],
+        ),
+        CatalogEntry::new("unsloth/Qwen3-4B-GGUF", "Qwen3 4B", 4.0, &[Chat], &["qwen"]),
+        CatalogEntry::new(
+            "unsloth/Qwen2.5-Coder-32B-Instruct-GGUF",
+            "Qwen2.5 Coder 32B",
+            32.8,
+            &[Coding],
+            &["qwen"],
+        ),
+        CatalogEntry::new(
+            "unsloth/Qwen2.5-Coder-7B-Instruct-GGUF",
+            "Qwen2.5 Coder 7B",
+            7.6,
+            &[Coding],
+            &["qwen"],
+        ),
+        CatalogEntry::new(
+            "unsloth/Qwen2.5-Coder-1.5B-Instruct-GGUF",
+            "Qwen2.5 Coder 1.5B",
+            1.5,
+            &[Coding],
+            &["qwen"],
+        ),
+        CatalogEntry::new(
+            "unsloth/gemma-3-27b-it-GGUF",
+            "Gemma 3 27B",
+            27.4,
+            &[Chat, Vision],
+            &["gemma"],
+        ),
+        CatalogEntry::new(
+            "unsloth/gemma-3-12b-it-GGUF",
+            "Gemma 3 12B",
+            12.2,
+            &[Chat, Vision],
+            &["gemma"],
+        ),
+        CatalogEntry::new(
+            "unsloth/gemma-3-4b-it-GGUF",
+            "Gemma 3 4B",
+            4.3,
+            &[Chat, Vision],
+            &["gemma"],
+        ),
+        CatalogEntry::new(
+            "unsloth/Llama-3.2-3B-Instruct-GGUF",
+            "Llama 3.2 3B",
+            3.2,
+            &[Chat],
+            &["llama"],
+        ),
+        CatalogEntry::new(
+            "unsloth/Qwen2.5-VL-7B-Instruct-GGUF",
+            "Qwen2.5 VL 7B",
+            8.3,
+            &[Vision],
+            &["qwen"],
+        ),
+        CatalogEntry::new(
+            "nomic-ai/nomic-embed-text-v1.5-GGUF",
+            "Nomic Embed Text v1.5",
+            0.14,
+            &[Embedding],
+            &["nomic"],
+        ),
+        CatalogEntry::new(
+            "Qwen/Qwen3-Embedding-4B-GGUF",
+            "Qwen3 Embedding 4B",
+            4.0,
+            &[Embedding],
+            &["qwen"],
+        ),
+    ]
+}
+
+/// Quantizations considered, best quality first, with average bits per weight.
+const QUANT_LADDER: &[(Quantization, f64)] = &[
+    (Quantization::Q8_0, 8.5),
+    (Quantization::Q6K, 6.56),
+    (Quantization::Q5KM, 5.69),
+    (Quantization::Q4KM, 4.85),
+    (Quantization::Iq4Xs, 4.25),
+    (Quantization::Q3KM, 3.91),
+    (Quantization::Q2K, 3.35),
+];
+
+/// Fixed allowance for the KV cache and runtime buffers at a default context.
+const RUNTIME_OVERHEAD_BYTES: u64 = 1024 * 1024 * 1024;
+
+/// Share of system RAM usable for CPU inference.
+const CPU_RAM_FRACTION: f64 = 0.75;
+
+/// Estimated memory to load and run `params_b` billion weights at `bits_per_weight`.
+#[must_use]
+#[allow(
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss,
+    clippy::cast_precision_loss
+)]
+pub fn estimate_runtime_bytes(params_b: f64, bits_per_weight: f64) -> u64 {
+    let weights = params_b * 1e9 * bits_per_weight / 8.0;
+    // 10% on top of the weights for tensors kept at higher precision.
+    (weights * 1.1) as u64 + RUNTIME_OVERHEAD_BYTES
+}
+
+/// Where recommended models are expected to run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum RecommendTarget {
+    /// GPU memory (discrete VRAM or Apple unified memory).
+    Gpu,
+    /// System RAM.
+    Cpu,
+}
+
+/// Memory budget for recommendations and where it comes from.
+#[must_use]
+#[allow(
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss,
+    clippy::cast_precision_loss
+)]
+pub fn memory_budget(memory: &SystemMemoryInfo) -> (u64, RecommendTarget) {
+    match memory.gpu_memory_bytes {
+        Some(gpu) if gpu > 0 => (gpu, RecommendTarget::Gpu),
+        _ => (
+            (memory.total_ram_bytes as f64 * CPU_RAM_FRACTION) as u64,
+            RecommendTarget::Cpu,
+        ),
+    }
+}
+
+/// A catalog entry with the quantization that fits this machine.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct Recommendation {
+    pub repo_id: String,
+    pub name: String,
+    pub params_b: f64,
+    pub tasks: Vec<ModelTask>,
+    pub tags: Vec<String>,
+    /// Best quantization that fits (e.g. `Q4_K_M`).
+    pub quantization: String,
+    /// Estimated memory needed at that quantization.
+    pub estimated_bytes: u64,
+}
+
+/// Ranked recommendations plus the budget they were chosen against.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct RecommendationReport {
+    pub task: Option<ModelTask>,
+    pub budget_bytes: u64,
+    pub target: RecommendTarget,
+    pub items: Vec<Recommendation>,
+}
+
+/// Rank `catalog` entries for `task` on a machine with `memory`.
+///
+/// Each entry gets the highest-quality quantization whose estimate fits the
+/// budget; entries where nothing fits are dropped. Larger models rank first
+/// (more parameters beat a higher-precision quantization of a smaller model),
+/// with quantization quality breaking ties.
+#[must_use]
+pub fn recommend(
+    catalog: &[CatalogEntry],
+    task: Option<ModelTask>,
+    memory: &SystemMemoryInfo,
+    limit: usize,
+) -> RecommendationReport {
+    let (budget_bytes, target) = memory_budget(memory);
+
+    let mut ranked: Vec<(usize, Recommendation)> = catalog
+        .iter()
+        .filter(|entry| task.is_none_or(|t| entry.tasks.contains(&t)))
+        .filter_map(|entry| {
+            QUANT_LADDER
+                .iter()
+                .enumerate()
+                .map(|(rank, (quant, bpw))| {
+                    (rank, quant, estimate_runtime_bytes(entry.params_b, *bpw))
+                })
+                .find(|(_, _, bytes)| *bytes <= budget_bytes)
+                .map(|(rank, quant, estimated_bytes)| {
+                    (
+                        rank,
+                        Recommendation {
+                            repo_id: entry.repo_id.clone(),
+                            name: entry.name.clone(),
+                            params_b: entry.params_b,
+                            tasks: entry.tasks.clone(),
+                            tags: entry.tags.clone(),
+                            quantization: quant.to_string(),
+                            estimated_bytes,
+                        },
+                    )
+                })
+        })
+        .collect();
+
+    ranked.sort_by(|(rank_a, a), (rank_b, b)| {
+        b.params_b
+            .total_cmp(&a.params_b)
+            .then_with(|| rank_a.cmp(rank_b))
+    });
+
+    RecommendationReport {
+        task,
+        budget_bytes,
+        target,
+        items: ranked.into_iter().map(|(_, r)| r).take(limit).collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const GIB: u64 = 1024 * 1024 * 1024;
+
+    fn memory(ram_gib: u64, gpu_gib: Option<u64>) -> SystemMemoryInfo {
+        SystemMemoryInfo {
+            total_ram_bytes: ram_gib * GIB,
+            gpu_memory_bytes: gpu_gib.map(|g| g * GIB),
+            is_apple_silicon: false,
+            has_nvidia_gpu: gpu_gib.is_some(),
+        }
+    }
+
+    #[test]
+    fn picks_the_best_quantization_that_fits() {
+        let catalog = [CatalogEntry::new(
+            "org/Model-8B-GGUF",
+            "8B",
+            8.0,
+            &[ModelTask::Chat],
+            &[],
+        )];
+
+        // 24 GiB of VRAM fits Q8_0 for an 8B model.
+        let roomy = recommend(&catalog, None, &memory(64, Some(24)), 5);
+        assert_eq!(roomy.target, RecommendTarget::Gpu);
+        assert_eq!(roomy.items[0].quantization, "Q8_0");
+
+        // 6 GiB needs a 4-bit quant, and the estimate must fit the budget.
+        let tight = recommend(&catalog, None, &memory(64, Some(6)), 5);
+        assert_eq!(tight.items[0].quantization, "Q4_K_M");
+        assert!(tight.items[0].estimated_bytes <= tight.budget_bytes);
+
+        // Nothing fits in 2 GiB.
+        assert!(
+            recommend(&catalog, None, &memory(64, Some(2)), 5)
+                .items
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn filters_by_task_and_ranks_larger_models_first() {
+        let report = recommend(
+            &builtin_catalog(),
+            Some(ModelTask::Coding),
+            &memory(32, None),
+            3,
+        );
+
+        assert_eq!(report.target, RecommendTarget::Cpu);
+        assert_eq!(report.items.len(), 3);
+        assert!(
+            report
+                .items
+                .iter()
+                .all(|r| r.tasks.contains(&ModelTask::Coding))
+        );
+        assert!(report.items[0].params_b >= report.items[1].params_b);
+    }
+
+    #[test]
+    fn task_names_parse_case_insensitively() {
+        assert_eq!("Coding".parse::<ModelTask>(), Ok(ModelTask::Coding));
+        assert!("painting".parse::<ModelTask>().is_err());
+    }
+}
@@ -0,0 +1,282 @@
+//! `RoPE` scaling overrides and context-length suggestions.
+//!
+//! Some GGUFs advertise a trained context (`{arch}.context_length`) shorter
+//! than what they can practically run once `RoPE` frequency scaling (linear or
+//! `YaRN`) is applied at launch. [`RopeConfig`] carries the llama-server
+//! `--rope-*` / `--yarn-*` overrides through every launch surface, and
+//! [`suggest_max_context`] turns GGUF metadata plus those overrides into a
+//! context size that is safe to request.
+//!
+//! Inputs come from the raw GGUF key/value map in
+//! [`crate::domain::Model::metadata`], the same way
+//! [`crate::domain::kv_estimate`] reads it. The suggestion returns `None`
+//! rather than guessing when the metadata doesn't carry a context length.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::hash::BuildHasher;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+use utoipa::ToSchema;
+
+/// Suggested context sizes are rounded down to a multiple of this, so the
+/// value shown to the user is a clean number llama-server will accept as-is.
+const CONTEXT_SUGGESTION_ALIGN: u64 = 256;
+
+/// `RoPE` frequency scaling method (`--rope-scaling`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, TS, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum RopeScalingType {
+    /// Disable scaling, even if the GGUF metadata requests it.
+    None,
+    /// Linear position interpolation.
+    Linear,
+    /// `YaRN` (NTK-aware interpolation with attention temperature).
+    Yarn,
+}
+
+impl RopeScalingType {
+    /// The value llama-server expects for `--rope-scaling`.
+    #[must_use]
+    pub const fn as_llama_arg(self) -> &'static str {
+        match self {
+            Self::None => "none",
+            Self::Linear => "linear",
+            Self::Yarn => "yarn",
+        }
+    }
+}
+
+impl fmt::Display for RopeScalingType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_llama_arg())
+    }
+}
+
+impl FromStr for RopeScalingType {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "none" => Ok(Self::None),
+            "linear" => Ok(Self::Linear),
+            "yarn" => Ok(Self::Yarn),
+            other => Err(format!(
+                "Invalid RoPE scaling '{other}'. Use 'none', 'linear', or 'yarn'"
+            )),
+        }
+    }
+}
+
+/// Launch-time `RoPE` overrides forwarded to llama-server.
+///
+/// All fields are optional; `None` leaves llama-server to read the value
+/// from the GGUF metadata. A config with every field `None` emits no flags.
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize, TS, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct RopeConfig {
+    /// Scaling method (`--rope-scaling`).
+    #[serde(default)]
+    pub scaling: Option<RopeScalingType>,
+    /// Base frequency (`--rope-freq-base`).
+    #[serde(default)]
+    pub freq_base: Option<f32>,
+    /// Frequency scale factor (`--rope-freq-scale`). A value of `0.25`
+    /// stretches positions 4×, i.e. roughly quadruples the usable context.
+    #[serde(default)]
+    pub freq_scale: Option<f32>,
+    /// Original training context for `YaRN` (`--yarn-orig-ctx`).
+    #[serde(default)]
+    pub yarn_orig_ctx: Option<u64>,
+}
+
+impl RopeConfig {
+    /// `true` when no override is set.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Convert the overrides into llama-server command-line arguments.
+    #[must_use]
+    pub fn to_cli_args(&self) -> Vec<String> {
+        let mut args = Vec::new();
+        if let Some(scaling) = self.scaling {
+            args.push("--rope-scaling".to_owned());
+            args.push(scaling.as_llama_arg().to_owned());
+        }
+        if let Some(base) = self.freq_base {
+            args.push("--rope-freq-base".to_owned());
+            args.push(base.to_string());
+        }
+        if let Some(scale) = self.freq_scale {
+            args.push("--rope-freq-scale".to_owned());
+            args.push(scale.to_string());
+        }
+        if let Some(orig) = self.yarn_orig_ctx {
+            args.push("--yarn-orig-ctx".to_owned());
+            args.push(orig.to_string());
+        }
+        args
+    }
+}
+
+/// Look up an architecture-prefixed integer GGUF key (`{arch}.{suffix}`).
+fn lookup<S: BuildHasher>(
+    metadata: &HashMap<String, String, S>,
+    arch: &str,
+    suffix: &str,
+) -> Option<u64> {
+    metadata
+        .get(&format!("{arch}.{suffix}"))
+        .and_then(|v| v.trim().parse::<u64>().ok())
+        .filter(|v| *v > 0)
+}
+
+/// Suggest the largest context size that is safe to request for a model.
+///
+/// Without overrides (or with `scaling = none`) this is the context the GGUF
+/// advertises in `{arch}.context_length`. With a `RoPE` scale override the
+/// suggestion is the original training context (`yarn_orig_ctx`, else
+/// `{arch}.rope.scaling.original_context_length`, else the advertised
+/// context) multiplied by `1 / freq_scale`. The result is rounded down to a
+/// multiple of 256 and never falls below the advertised context.
+///
+/// Returns `None` when the architecture or its context length is unknown.
+#[must_use]
+pub fn suggest_max_context<S: BuildHasher>(
+    metadata: &HashMap<String, String, S>,
+    architecture: Option<&str>,
+    rope: Option<&RopeConfig>,
+) -> Option<u64> {
+    let arch = architecture.or_else(|| metadata.get("general.architecture").map(String::as_str))?;
+    let trained = lookup(metadata, arch, "context_length")?;
+
+    let scale = rope
+        .filter(|r| r.scaling != Some(RopeScalingType::None))
+        .and_then(|r| r.freq_scale)
+        .filter(|s| s.is_finite() && *s > 0.0 && *s < 1.0);
+    let Some(scale) = scale else {
+        return Some(trained);
+    };
+
+    let original = rope
+        .and_then(|r| r.yarn_orig_ctx)
+        .or_else(|| lookup(metadata, arch, "rope.scaling.original_context_length"))
+        .unwrap_or(trained);
+    // Context lengths are far below 2^52 and `scale` is in (0, 1), so the
+    // round trip through f64 is exact enough and the result is positive.
+    #[allow(
+        clippy::cast_precision_loss,
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss
+    )]
+    let scaled = (original as f64 / f64::from(scale)) as u64;
+    let aligned = scaled - scaled % CONTEXT_SUGGESTION_ALIGN;
+
+    Some(aligned.max(trained))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn meta(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+        pairs
+            .iter()
+            .map(|(k, v)| ((*k).to_string(), (*v).to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn empty_config_emits_no_args() {
+        assert!(RopeConfig::default().is_empty());
+        assert!(RopeConfig::default().to_cli_args().is_empty());
+    }
+
+    #[test]
+    fn config_emits_llama_server_flags() {
+        let rope = RopeConfig {
+            scaling: Some(RopeScalingType::Yarn),
+            freq_base: Some(1_000_000.0),
+            freq_scale: Some(0.25),
+            yarn_orig_ctx: Some(32_768),
+        };
+        assert_eq!(
+            rope.to_cli_args(),
+            vec![
+                "--rope-scaling",
+                "yarn",
+                "--rope-freq-base",
+                "1000000",
+                "--rope-freq-scale",
+                "0.25",
+                "--yarn-orig-ctx",
+                "32768",
+            ]
+        );
+    }
+
+    #[test]
+    fn scaling_type_parses_case_insensitively() {
+        assert_eq!("YaRN".parse(), Ok(RopeScalingType::Yarn));
+        assert_eq!("linear".parse(), Ok(RopeScalingType::Linear));
+        assert!("ntk".parse::<RopeScalingType>().is_err());
+    }
+
+    #[test]
+    fn suggestion_without_overrides_is_trained_context() {
+        let m = meta(&[("qwen2.context_length", "32768")]);
+        assert_eq!(suggest_max_context(&m, Some("qwen2"), None), Some(32_768));
+    }
+
+    #[test]
+    fn suggestion_scales_trained_context_by_inverse_freq_scale() {
+        let m = meta(&[("qwen2.context_length", "32768")]);
+        let rope = RopeConfig {
+            scaling: Some(RopeScalingType::Yarn),
+            freq_scale: Some(0.25),
+            ..Default::default()
+        };
+        assert_eq!(
+            suggest_max_context(&m, Some("qwen2"), Some(&rope)),
+            Some(131_072)
+        );
+    }
+
+    #[test]
+    fn suggestion_prefers_original_context_from_metadata() {
+        let m = meta(&[
+            ("general.architecture", "llama"),
+            ("llama.context_length", "16384"),
+            ("llama.rope.scaling.original_context_length", "8192"),
+        ]);
+        let rope = RopeConfig {
+            freq_scale: Some(0.125),
+            ..Default::default()
+        };
+        assert_eq!(suggest_max_context(&m, None, Some(&rope)), Some(65_536));
+    }
+
+    #[test]
+    fn suggestion_ignores_scale_when_scaling_disabled() {
+        let m = meta(&[("llama.context_length", "8192")]);
+        let rope = RopeConfig {
+            scaling: Some(RopeScalingType::None),
+            freq_scale: Some(0.5),
+            ..Default::default()
+        };
+        assert_eq!(
+            suggest_max_context(&m, Some("llama"), Some(&rope)),
+            Some(8192)
+        );
+    }
+
+    #[test]
+    fn suggestion_unknown_without_context_length() {
+        let m = meta(&[("general.architecture", "llama")]);
+        assert_eq!(suggest_max_context(&m, None, None), None);
+    }
+}
@@ -14,6 +14,15 @@
 //! 4. Hardcoded default (lowest priority)
 
 use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+use utoipa::ToSchema;
+
+/// Largest context a newly added model's default is initialized to.
+///
+/// Long-context GGUFs advertise 128k tokens or more, whose KV cache rarely
+/// fits next to the weights; the default starts no higher than this and can
+/// be raised per model up to the model's own maximum.
+pub const INITIAL_CONTEXT_CAP: usize = 8192;
 
 /// Server-level defaults for a specific model.
 ///
@@ -36,9 +45,10 @@ use serde::{Deserialize, Serialize};
 /// // Override only the context length for a long-context model
 /// let config = ServerConfig {
 ///     context_length: Some(32768),
+///     ..Default::default()
 /// };
 /// ```
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Default, TS, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct ServerConfig {
     /// Context length (number of tokens) for the model server.
@@ -46,4 +56,52 @@ pub struct ServerConfig {
     /// Controls the maximum context window the server will use.
     /// Common values: 4096 (default), 8192, 32768, 131072
     pub context_length: Option<usize>,
+
+    /// Minutes without a request before the server is stopped.
+    ///
+    /// Overrides the global `idle_shutdown_minutes` setting; `0` keeps this
+    /// model's server running even when a global timeout is configured.
+    pub idle_shutdown_minutes: Option<u32>,
+
+    /// Parallel request slots (`--parallel`) the model's server runs.
+    ///
+    /// Each slot can serve one request at a time with the full context, so
+    /// more slots let several chats stream at once for a matching multiple
+    /// of KV cache memory. Unset, a default is derived from the context
+    /// size and free memory.
+    pub parallel: Option<u32>,
+
+    /// Continuous batching (`--cont-batching`). Unset leaves llama-server's
+    /// default (on); `false` disables it.
+    pub cont_batching: Option<bool>,
+}
+
+impl ServerConfig {
+    /// Defaults for a newly added model: the context its GGUF metadata
+    /// advertises (`trained_ctx`), capped at [`INITIAL_CONTEXT_CAP`].
+    ///
+    /// `None` when the metadata carries no context length, leaving the model
+    /// on the global default.
+    #[must_use]
+    pub fn initial(trained_ctx: Option<u64>) -> Option<Self> {
+        let trained = usize::try_from(trained_ctx.filter(|ctx| *ctx > 0)?).ok()?;
+        Some(Self {
+            context_length: Some(trained.min(INITIAL_CONTEXT_CAP)),
+            ..Default::default()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn initial_context_follows_metadata_up_to_the_cap() {
+        let context = |trained| ServerConfig::initial(trained).and_then(|c| c.context_length);
+        assert_eq!(context(Some(2048)), Some(2048));
+        assert_eq!(context(Some(131_072)), Some(INITIAL_CONTEXT_CAP));
+        assert_eq!(context(Some(0)), None);
+        assert_eq!(context(None), None);
+    }
 }
@@ -0,0 +1,110 @@
+//! Slot occupancy of a running llama-server.
+//!
+//! llama-server serves requests from a fixed number of slots (`--parallel`);
+//! a request that arrives while every slot is busy waits in its queue. These
+//! types describe what each slot is doing, as reported by the server's
+//! `/slots` endpoint, and summarise it per server so a client can tell why a
+//! request is queued.
+
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+use utoipa::ToSchema;
+
+/// One llama-server slot.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, TS, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SlotInfo {
+    /// Slot index, `0..parallel`.
+    pub id: i64,
+    /// Whether the slot is serving a request right now.
+    pub processing: bool,
+    /// Context size of the slot, in tokens. `None` when the server build
+    /// does not report it.
+    #[serde(default)]
+    #[ts(optional = nullable)]
+    pub context_size: Option<u64>,
+    /// Tokens in use in the slot's context: prompt plus generated so far.
+    /// `None` when the server build does not report it.
+    #[serde(default)]
+    #[ts(optional = nullable)]
+    pub context_used: Option<u64>,
+}
+
+/// Summary of a server's slots at one point in time.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize, TS, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SlotOccupancy {
+    /// Slots serving a request.
+    pub processing: u32,
+    /// Slots free to take the next request.
+    pub idle: u32,
+    /// Tokens held across all slots, when the server reports it.
+    #[serde(default)]
+    #[ts(optional = nullable)]
+    pub context_used: Option<u64>,
+    /// Context size across all slots, in tokens, when the server reports it.
+    #[serde(default)]
+    #[ts(optional = nullable)]
+    pub context_size: Option<u64>,
+    /// Unix timestamp in milliseconds when the slots were read.
+    pub updated_at: u64,
+}
+
+impl SlotOccupancy {
+    /// Summarise `slots` as read at `updated_at`.
+    #[must_use]
+    pub fn from_slots(slots: &[SlotInfo], updated_at: u64) -> Self {
+        let processing = slots.iter().filter(|s| s.processing).count();
+        let processing = u32::try_from(processing).unwrap_or(u32::MAX);
+        let total = u32::try_from(slots.len()).unwrap_or(u32::MAX);
+        Self {
+            processing,
+            idle: total - processing,
+            context_used: slots.iter().map(|s| s.context_used).sum(),
+            context_size: slots.iter().map(|s| s.context_size).sum(),
+            updated_at,
+        }
+    }
+
+    /// Total number of slots.
+    #[must_use]
+    pub const fn total(&self) -> u32 {
+        self.processing + self.idle
+    }
+
+    /// Whether every slot is busy, so the next request has to queue.
+    #[must_use]
+    pub const fn saturated(&self) -> bool {
+        self.idle == 0 && self.processing > 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn slot(id: i64, processing: bool, context_used: Option<u64>) -> SlotInfo {
+        SlotInfo {
+            id,
+            processing,
+            context_size: Some(4096),
+            context_used,
+        }
+    }
+
+    #[test]
+    fn occupancy_counts_busy_slots_and_sums_context() {
+        let occupancy =
+            SlotOccupancy::from_slots(&[slot(0, true, Some(1000)), slot(1, false, Some(24))], 7);
+        assert_eq!(occupancy.processing, 1);
+        assert_eq!(occupancy.idle, 1);
+        assert_eq!(occupancy.context_used, Some(1024));
+        assert_eq!(occupancy.context_size, Some(8192));
+        assert!(!occupancy.saturated());
+
+        let full = SlotOccupancy::from_slots(&[slot(0, true, None)], 7);
+        assert!(full.saturated());
+        // One slot without a figure makes the total unknown.
+        assert_eq!(full.context_used, None);
+    }
+}
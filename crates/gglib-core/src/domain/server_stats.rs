@@ -0,0 +1,170 @@
+//! Per-request latency and throughput figures for a serving session.
+//!
+//! The proxy measures each forwarded request as a [`RequestTiming`] and hands
+//! it to a [`crate::ports::ServerStatsSink`]; the runtime folds those into a
+//! [`ServerStats`] per running server, reset whenever the server is spawned
+//! again. Comparing the whole-session averages with the recent-window ones is
+//! how a slowdown over a long session shows up — a growing KV cache or a
+//! fragmented prompt cache drags the recent figures below the session's.
+//!
+//! Every figure is one the proxy actually observed. Fields that could not be
+//! measured for a request (e.g. time-to-first-token on a non-streaming
+//! response, where nothing arrives until generation has finished) stay `None`
+//! rather than being filled with an estimate.
+
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// Timing of one completed request, as observed by the proxy.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct RequestTiming {
+    /// Time from forwarding the request until llama-server sent response
+    /// headers. llama-server withholds headers until a slot is assigned, so
+    /// for streaming requests this is the slot-queue wait. `None` on the
+    /// non-streaming path, where headers only arrive with the full body.
+    pub queue_wait: Option<Duration>,
+    /// Time from forwarding the request until the first generated token
+    /// (content, reasoning, or tool call). `None` on the non-streaming path
+    /// and when the stream produced no tokens.
+    pub ttft: Option<Duration>,
+    /// Time spent generating: from the first token to the end of the stream,
+    /// or the whole round trip on the non-streaming path.
+    pub generation: Option<Duration>,
+    /// `usage.prompt_tokens` reported by the upstream, when present.
+    pub prompt_tokens: Option<u32>,
+    /// `usage.completion_tokens` reported by the upstream, when present.
+    pub completion_tokens: Option<u32>,
+}
+
+impl RequestTiming {
+    /// Decode throughput in tokens per second.
+    ///
+    /// When a time-to-first-token was measured, the first token is excluded
+    /// from the count because its latency is already in [`Self::ttft`];
+    /// otherwise (non-streaming) every completion token is counted against
+    /// the full round trip. `None` when either input is missing or the
+    /// window is empty.
+    #[must_use]
+    pub fn tokens_per_sec(&self) -> Option<f64> {
+        let tokens = self.completion_tokens?;
+        let tokens = if self.ttft.is_some() {
+            tokens.saturating_sub(1)
+        } else {
+            tokens
+        };
+        let secs = self.generation?.as_secs_f64();
+        (tokens > 0 && secs > 0.0).then(|| f64::from(tokens) / secs)
+    }
+
+    /// Time the server spent working on the request: prompt processing and
+    /// generation, without the slot-queue wait. On the non-streaming path
+    /// this is the whole round trip. `None` when nothing was measured.
+    #[must_use]
+    pub fn busy_time(&self) -> Option<Duration> {
+        let prompt = self
+            .ttft
+            .map(|ttft| ttft.saturating_sub(self.queue_wait.unwrap_or_default()));
+        match (prompt, self.generation) {
+            (None, None) => None,
+            (prompt, generation) => {
+                Some(prompt.unwrap_or_default() + generation.unwrap_or_default())
+            }
+        }
+    }
+}
+
+/// Aggregated latency and throughput for one server session.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ServerStats {
+    /// Unix timestamp in milliseconds when this session's figures started.
+    pub session_started_at: u64,
+    /// Unix timestamp in milliseconds of the most recent recorded request.
+    #[serde(default)]
+    pub last_request_at: Option<u64>,
+    /// Requests recorded in this session.
+    pub requests: u64,
+    /// Completion tokens across every recorded request that reported them.
+    pub completion_tokens: u64,
+    /// Mean slot-queue wait across the session, in milliseconds.
+    #[serde(default)]
+    pub avg_queue_wait_ms: Option<f64>,
+    /// Mean time-to-first-token across the session, in milliseconds.
+    #[serde(default)]
+    pub avg_ttft_ms: Option<f64>,
+    /// Mean decode throughput across the session, in tokens per second.
+    #[serde(default)]
+    pub avg_tokens_per_sec: Option<f64>,
+    /// Mean time-to-first-token over the most recent requests.
+    #[serde(default)]
+    pub recent_ttft_ms: Option<f64>,
+    /// Mean decode throughput over the most recent requests.
+    #[serde(default)]
+    pub recent_tokens_per_sec: Option<f64>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn streaming_throughput_excludes_first_token() {
+        let timing = RequestTiming {
+            ttft: Some(Duration::from_millis(500)),
+            generation: Some(Duration::from_secs(2)),
+            prompt_tokens: None,
+            completion_tokens: Some(41),
+            ..Default::default()
+        };
+        assert_eq!(timing.tokens_per_sec(), Some(20.0));
+    }
+
+    #[test]
+    fn non_streaming_throughput_counts_every_token() {
+        let timing = RequestTiming {
+            generation: Some(Duration::from_secs(4)),
+            prompt_tokens: None,
+            completion_tokens: Some(40),
+            ..Default::default()
+        };
+        assert_eq!(timing.tokens_per_sec(), Some(10.0));
+    }
+
+    #[test]
+    fn throughput_unknown_without_tokens_or_time() {
+        let no_tokens = RequestTiming {
+            generation: Some(Duration::from_secs(1)),
+            ..Default::default()
+        };
+        assert_eq!(no_tokens.tokens_per_sec(), None);
+
+        let single_streamed_token = RequestTiming {
+            ttft: Some(Duration::from_millis(100)),
+            generation: Some(Duration::ZERO),
+            prompt_tokens: None,
+            completion_tokens: Some(1),
+            ..Default::default()
+        };
+        assert_eq!(single_streamed_token.tokens_per_sec(), None);
+    }
+
+    #[test]
+    fn busy_time_leaves_out_the_queue_wait() {
+        let streamed = RequestTiming {
+            queue_wait: Some(Duration::from_millis(300)),
+            ttft: Some(Duration::from_millis(800)),
+            generation: Some(Duration::from_secs(2)),
+            ..Default::default()
+        };
+        assert_eq!(streamed.busy_time(), Some(Duration::from_millis(2500)));
+
+        let buffered = RequestTiming {
+            generation: Some(Duration::from_secs(3)),
+            ..Default::default()
+        };
+        assert_eq!(buffered.busy_time(), Some(Duration::from_secs(3)));
+        assert_eq!(RequestTiming::default().busy_time(), None);
+    }
+}
@@ -0,0 +1,199 @@
+//! Startup models: servers launched automatically when the app starts.
+//!
+//! The `startup_models` setting lists models to warm up at boot, each with
+//! its own launch profile. [`plan_startup`] decides, in list order, which of
+//! them fit in memory alongside whatever is already running, so a long list
+//! degrades to "the first few that fit" instead of pushing the machine into
+//! swap.
+
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+use utoipa::ToSchema;
+
+use crate::cpu_config::{CpuSet, NumaStrategy};
+use crate::gpu_config::{SplitMode, TensorSplit};
+
+/// RAM kept free for the OS and other applications when planning startup
+/// launches.
+pub const STARTUP_RAM_HEADROOM_BYTES: u64 = 4 * 1024 * 1024 * 1024;
+
+/// A model to launch at startup, with the options to launch it with.
+///
+/// Unset fields fall through the usual chain (per-model `server_defaults`,
+/// then global settings), exactly as a manual launch would.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize, TS, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct StartupModel {
+    /// ID of the model to launch.
+    pub model_id: i64,
+    /// Context size override.
+    #[serde(default)]
+    pub context_length: Option<u64>,
+    /// Jinja chat-template override (`None` = auto-detect).
+    #[serde(default)]
+    pub jinja: Option<bool>,
+    /// Name of an inference profile whose parameters the session uses.
+    #[serde(default)]
+    pub inference_profile: Option<String>,
+    /// Generation thread count (`None` = physical performance cores).
+    #[serde(default)]
+    pub threads: Option<u32>,
+    /// Prompt-processing thread count (`None` = same as `threads`).
+    #[serde(default)]
+    pub threads_batch: Option<u32>,
+    /// CPUs to pin to, as a `taskset -c` list such as `0-7,12` (`None` =
+    /// performance cores on a hybrid CPU, otherwise unpinned).
+    #[serde(default)]
+    #[ts(as = "Option<String>")]
+    #[schema(value_type = Option<String>)]
+    pub cpu_affinity: Option<CpuSet>,
+    /// NUMA placement strategy (`None` = no `--numa` flag).
+    #[serde(default)]
+    pub numa: Option<NumaStrategy>,
+    /// Multi-GPU split mode (`None` = llama-server's default, `layer`).
+    #[serde(default)]
+    pub split_mode: Option<SplitMode>,
+    /// Per-GPU share of the model, as a list such as `3,1` (`None` = split
+    /// by free VRAM on a multi-GPU machine).
+    #[serde(default)]
+    #[ts(as = "Option<String>")]
+    #[schema(value_type = Option<String>)]
+    pub tensor_split: Option<TensorSplit>,
+    /// Main GPU index (`None` = the GPU with the most free VRAM under split
+    /// mode `none`, otherwise device `0`).
+    #[serde(default)]
+    pub main_gpu: Option<u32>,
+}
+
+/// A startup model with its estimated resident memory.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StartupCandidate {
+    /// ID of the model to launch.
+    pub model_id: i64,
+    /// Weights plus KV cache at the launch context, in bytes.
+    pub estimated_bytes: u64,
+}
+
+/// What to do with one startup model.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StartupStep {
+    /// Launch it.
+    Launch,
+    /// Skip it: it does not fit in what is left of the budget.
+    OverBudget {
+        /// Estimated memory the model needs, in bytes.
+        needed_bytes: u64,
+        /// Budget left when it was considered, in bytes.
+        available_bytes: u64,
+    },
+}
+
+/// Decide which startup candidates to launch, in order.
+///
+/// The budget is `total_ram_bytes` minus [`STARTUP_RAM_HEADROOM_BYTES`] and
+/// the `resident_bytes` already held by running servers. A candidate that
+/// does not fit is skipped without stopping the plan, so a smaller model
+/// later in the list can still launch. A `total_ram_bytes` of `0` means the
+/// platform could not report it; every candidate is then launched.
+#[must_use]
+pub fn plan_startup(
+    candidates: &[StartupCandidate],
+    total_ram_bytes: u64,
+    resident_bytes: u64,
+) -> Vec<(i64, StartupStep)> {
+    if total_ram_bytes == 0 {
+        return candidates
+            .iter()
+            .map(|c| (c.model_id, StartupStep::Launch))
+            .collect();
+    }
+
+    let mut available = total_ram_bytes
+        .saturating_sub(STARTUP_RAM_HEADROOM_BYTES)
+        .saturating_sub(resident_bytes);
+    candidates
+        .iter()
+        .map(|c| {
+            let step = if c.estimated_bytes <= available {
+                available -= c.estimated_bytes;
+                StartupStep::Launch
+            } else {
+                StartupStep::OverBudget {
+                    needed_bytes: c.estimated_bytes,
+                    available_bytes: available,
+                }
+            };
+            (c.model_id, step)
+        })
+        .collect()
+}
+
+/// Validate a startup list: each model may appear only once.
+///
+/// # Errors
+///
+/// Returns a human-readable description of the first duplicate found.
+pub fn validate_startup_models(models: &[StartupModel]) -> Result<(), String> {
+    let mut seen: Vec<i64> = Vec::with_capacity(models.len());
+    for entry in models {
+        if seen.contains(&entry.model_id) {
+            return Err(format!("model {} is listed twice", entry.model_id));
+        }
+        seen.push(entry.model_id);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const GIB: u64 = 1024 * 1024 * 1024;
+
+    fn candidate(model_id: i64, gib: u64) -> StartupCandidate {
+        StartupCandidate {
+            model_id,
+            estimated_bytes: gib * GIB,
+        }
+    }
+
+    #[test]
+    fn launches_in_order_until_budget_runs_out() {
+        // 32 GiB total - 4 GiB headroom - 4 GiB resident = 24 GiB budget.
+        let plan = plan_startup(
+            &[candidate(1, 16), candidate(2, 16), candidate(3, 6)],
+            32 * GIB,
+            4 * GIB,
+        );
+
+        assert_eq!(plan[0], (1, StartupStep::Launch));
+        assert_eq!(
+            plan[1],
+            (
+                2,
+                StartupStep::OverBudget {
+                    needed_bytes: 16 * GIB,
+                    available_bytes: 8 * GIB,
+                }
+            )
+        );
+        // A later, smaller model still gets the leftover budget.
+        assert_eq!(plan[2], (3, StartupStep::Launch));
+    }
+
+    #[test]
+    fn unknown_ram_launches_everything() {
+        let plan = plan_startup(&[candidate(1, 500)], 0, 0);
+        assert_eq!(plan, vec![(1, StartupStep::Launch)]);
+    }
+
+    #[test]
+    fn duplicate_models_are_rejected() {
+        let entry = StartupModel {
+            model_id: 3,
+            ..Default::default()
+        };
+        assert!(validate_startup_models(std::slice::from_ref(&entry)).is_ok());
+        assert!(validate_startup_models(&[entry.clone(), entry]).is_err());
+    }
+}
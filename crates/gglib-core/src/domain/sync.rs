@@ -0,0 +1,218 @@
+//! Library sync: pairing gglib instances and comparing their libraries.
+//!
+//! Two instances pair by exchanging model share tokens: each stores the
+//! other as a [`SyncPeer`] (URL plus token) and can then read its library
+//! and copy models from it. A library is described to peers as a list of
+//! [`LibraryEntry`] values, and [`missing_models`] decides which of a peer's
+//! models this library lacks.
+//!
+//! Models are matched by content rather than by name, since the same GGUF is
+//! often registered under different names on two machines. The SHA-256 from
+//! the file's LFS OID is used when both sides recorded one; models added from
+//! a local file have none, and fall back to file name plus size.
+
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+use utoipa::ToSchema;
+
+/// Path of a peer's library listing, relative to its base URL.
+pub const LIBRARY_PATH: &str = "/api/sync/library";
+
+/// Path a peer accepts [`PairRequest`]s on, relative to its base URL.
+pub const PAIR_PATH: &str = "/api/sync/pair";
+
+/// URL of model `model_id`'s file on the peer at `base_url`.
+#[must_use]
+pub fn model_file_url(base_url: &str, model_id: i64) -> String {
+    format!(
+        "{}/api/models/{model_id}/file",
+        base_url.trim_end_matches('/')
+    )
+}
+
+/// A paired gglib instance this library can pull models from.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SyncPeer {
+    /// Local name for the peer, as used by `gglib sync pull <peer>`.
+    pub name: String,
+    /// Base URL of the peer's web server, e.g. `http://studio.local:9887`.
+    pub url: String,
+    /// The peer's model share token, or a `secret:` reference to it.
+    pub token: String,
+}
+
+/// Sent to a peer to pair with it, so it can pull from this instance in
+/// turn.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, TS, ToSchema)]
+pub struct PairRequest {
+    /// Name the peer should store this instance under.
+    pub name: String,
+    /// Base URL of this instance's web server, as reachable from the peer.
+    pub url: String,
+    /// This instance's model share token.
+    pub token: String,
+}
+
+/// One model of a library, as listed to peers.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct LibraryEntry {
+    /// Model ID on the instance that listed it.
+    pub model_id: i64,
+    /// Display name of the model.
+    pub name: String,
+    /// File name of the model's GGUF (the first shard for sharded models).
+    pub file_name: String,
+    /// Size of that file in bytes.
+    pub size: u64,
+    /// SHA-256 of that file, when known.
+    #[serde(default)]
+    pub sha256: Option<String>,
+    /// `HuggingFace` repository the model came from, if any.
+    #[serde(default)]
+    pub hf_repo_id: Option<String>,
+    /// Quantization label, e.g. `Q4_K_M`.
+    #[serde(default)]
+    pub quantization: Option<String>,
+    /// Whether the model spans several files. Only the first shard is
+    /// served, so sharded models cannot be pulled.
+    #[serde(default)]
+    pub sharded: bool,
+}
+
+impl LibraryEntry {
+    /// Whether `self` and `other` hold the same file.
+    ///
+    /// Compares SHA-256 when both are known, file name and size otherwise.
+    #[must_use]
+    pub fn same_content(&self, other: &Self) -> bool {
+        match (self.sha256(), other.sha256()) {
+            (Some(a), Some(b)) => a.eq_ignore_ascii_case(b),
+            _ => self.file_name == other.file_name && self.size == other.size,
+        }
+    }
+
+    /// The SHA-256, ignoring values that are not one (such as a 40-character
+    /// git blob SHA).
+    fn sha256(&self) -> Option<&str> {
+        self.sha256.as_deref().filter(|h| h.len() == 64)
+    }
+
+    /// The file name with any directory components removed, or `None` when
+    /// nothing usable is left. Entries come from another machine, so their
+    /// file names are not trusted as paths.
+    #[must_use]
+    pub fn safe_file_name(&self) -> Option<&str> {
+        std::path::Path::new(&self.file_name)
+            .file_name()
+            .and_then(|name| name.to_str())
+            .filter(|name| !name.starts_with('.'))
+    }
+}
+
+/// The models in `remote` that `local` has no copy of, in `remote`'s order.
+#[must_use]
+pub fn missing_models<'a>(
+    local: &[LibraryEntry],
+    remote: &'a [LibraryEntry],
+) -> Vec<&'a LibraryEntry> {
+    remote
+        .iter()
+        .filter(|theirs| !local.iter().any(|ours| ours.same_content(theirs)))
+        .collect()
+}
+
+/// Secret store key for the share token of the peer named `name`, before
+/// the unique suffix each settings save adds.
+#[must_use]
+pub fn peer_token_secret(name: &str) -> String {
+    format!("sync_peer_{name}")
+}
+
+/// Check that `name` can be used as a peer name: non-empty, and only
+/// letters, digits, `-`, `_` and `.`.
+///
+/// # Errors
+///
+/// Returns a message describing the problem.
+pub fn validate_peer_name(name: &str) -> Result<(), String> {
+    if name.is_empty() {
+        return Err("Peer name cannot be empty".to_string());
+    }
+    if !name
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+    {
+        return Err(format!(
+            "Invalid peer name '{name}': use letters, digits, '-', '_' or '.'"
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(file_name: &str, size: u64, sha256: Option<&str>) -> LibraryEntry {
+        LibraryEntry {
+            model_id: 1,
+            name: file_name.to_string(),
+            file_name: file_name.to_string(),
+            size,
+            sha256: sha256.map(str::to_string),
+            hf_repo_id: None,
+            quantization: None,
+            sharded: false,
+        }
+    }
+
+    #[test]
+    fn matches_by_hash_then_by_name_and_size() {
+        let hash = "a".repeat(64);
+        let local = vec![
+            entry("renamed.gguf", 10, Some(&hash)),
+            entry("local.gguf", 20, None),
+        ];
+        let remote = vec![
+            entry("original.gguf", 10, Some(&hash.to_uppercase())),
+            entry("local.gguf", 20, Some(&"b".repeat(64))),
+            entry("local.gguf", 21, None),
+            entry("other.gguf", 10, Some(&"c".repeat(64))),
+        ];
+
+        let missing: Vec<_> = missing_models(&local, &remote)
+            .into_iter()
+            .map(|e| (e.file_name.as_str(), e.size))
+            .collect();
+        assert_eq!(missing, vec![("local.gguf", 21), ("other.gguf", 10)]);
+    }
+
+    #[test]
+    fn same_hash_wins_over_different_names() {
+        let hash = "d".repeat(64);
+        let a = entry("a.gguf", 1, Some(&hash));
+        let b = entry("b.gguf", 2, Some(&hash));
+        assert!(a.same_content(&b));
+        // A git blob SHA is not a content hash; fall back to name and size.
+        let c = entry("a.gguf", 1, Some(&"e".repeat(40)));
+        assert!(a.same_content(&c));
+    }
+
+    #[test]
+    fn file_names_from_peers_are_sanitised() {
+        assert_eq!(
+            entry("../../etc/model.gguf", 1, None).safe_file_name(),
+            Some("model.gguf")
+        );
+        assert_eq!(entry("..", 1, None).safe_file_name(), None);
+        assert_eq!(entry(".hidden", 1, None).safe_file_name(), None);
+    }
+
+    #[test]
+    fn peer_names_are_validated() {
+        assert!(validate_peer_name("studio.local").is_ok());
+        assert!(validate_peer_name("").is_err());
+        assert!(validate_peer_name("a b").is_err());
+        assert_eq!(peer_token_secret("studio"), "sync_peer_studio");
+    }
+}
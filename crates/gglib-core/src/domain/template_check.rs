@@ -0,0 +1,378 @@
+//! Golden conversations for checking a model's chat template.
+//!
+//! A model that ignores its system prompt, drifts into the user's turn, or
+//! emits garbage from the first token is often a victim of its chat
+//! template rather than its weights: the template drops a role, leaves a
+//! turn unterminated, or the prompt starts without (or with two) BOS
+//! tokens. This module holds a fixed suite of [`GoldenConversation`]s
+//! covering the shapes templates get wrong and [`check_rendered`], which
+//! inspects how one of them came out.
+//!
+//! Rendering itself happens elsewhere — llama-server applies the template
+//! (see `gglib_runtime::prompt_preview`) — so everything here is a pure
+//! function of the rendered prompt and its tokens.
+
+use std::collections::HashMap;
+use std::fmt;
+
+use serde::Serialize;
+use serde_json::{Value, json};
+
+/// Text placed in each golden message so its presence in the rendered
+/// prompt can be checked without knowing the template's format.
+const SYSTEM_MARKER: &str = "gglib-system-marker";
+const USER_MARKER: &str = "gglib-user-marker";
+const ASSISTANT_MARKER: &str = "gglib-assistant-marker";
+const SECOND_USER_MARKER: &str = "gglib-followup-marker";
+const TOOL_ARGUMENT_MARKER: &str = "gglib-tool-argument-marker";
+const TOOL_RESULT_MARKER: &str = "gglib-tool-result-marker";
+
+/// Turn markers of common template families: opening text, closing text,
+/// and how many more openings than closings a rendering with a generation
+/// prompt has (the assistant's turn is opened but not closed).
+const ROLE_MARKERS: &[(&str, &str, usize)] = &[
+    ("<|im_start|>", "<|im_end|>", 1),
+    ("<start_of_turn>", "<end_of_turn>", 1),
+    ("<|start_header_id|>", "<|end_header_id|>", 0),
+    ("[INST]", "[/INST]", 0),
+];
+
+/// A canonical conversation every chat template should render faithfully.
+#[derive(Debug, Clone)]
+pub struct GoldenConversation {
+    /// Short identifier, e.g. `system-user`.
+    pub name: &'static str,
+    /// Messages in `OpenAI` chat format.
+    pub messages: Vec<Value>,
+    /// Tool definitions in `OpenAI` format; empty for plain chats.
+    pub tools: Vec<Value>,
+    /// Text that must survive rendering, with what it stands for.
+    pub expected: Vec<(&'static str, &'static str)>,
+}
+
+/// The golden conversation suite: system + user, a multi-turn exchange,
+/// and a tool call with its result.
+#[must_use]
+pub fn golden_conversations() -> Vec<GoldenConversation> {
+    let system = json!({"role": "system", "content": format!("Reply tersely. {SYSTEM_MARKER}")});
+    let user = json!({"role": "user", "content": format!("Hello. {USER_MARKER}")});
+    vec![
+        GoldenConversation {
+            name: "system-user",
+            messages: vec![system.clone(), user.clone()],
+            tools: Vec::new(),
+            expected: vec![
+                ("system prompt", SYSTEM_MARKER),
+                ("user message", USER_MARKER),
+            ],
+        },
+        GoldenConversation {
+            name: "multi-turn",
+            messages: vec![
+                system.clone(),
+                user.clone(),
+                json!({"role": "assistant", "content": format!("Hi. {ASSISTANT_MARKER}")}),
+                json!({"role": "user", "content": format!("And again. {SECOND_USER_MARKER}")}),
+            ],
+            tools: Vec::new(),
+            expected: vec![
+                ("system prompt", SYSTEM_MARKER),
+                ("first user message", USER_MARKER),
+                ("assistant reply", ASSISTANT_MARKER),
+                ("second user message", SECOND_USER_MARKER),
+            ],
+        },
+        GoldenConversation {
+            name: "tool-call",
+            messages: vec![
+                system,
+                user,
+                json!({
+                    "role": "assistant",
+                    "content": "",
+                    "tool_calls": [{
+                        "id": "call_0",
+                        "type": "function",
+                        "function": {
+                            "name": "lookup",
+                            "arguments": json!({"query": TOOL_ARGUMENT_MARKER}).to_string(),
+                        },
+                    }],
+                }),
+                json!({"role": "tool", "tool_call_id": "call_0", "content": TOOL_RESULT_MARKER}),
+            ],
+            tools: vec![json!({
+                "type": "function",
+                "function": {
+                    "name": "lookup",
+                    "description": "Look something up.",
+                    "parameters": {
+                        "type": "object",
+                        "properties": {"query": {"type": "string"}},
+                        "required": ["query"],
+                    },
+                },
+            })],
+            expected: vec![
+                ("system prompt", SYSTEM_MARKER),
+                ("tool call arguments", TOOL_ARGUMENT_MARKER),
+                ("tool result", TOOL_RESULT_MARKER),
+            ],
+        },
+    ]
+}
+
+/// What the model's tokenizer metadata says about the BOS token.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct BosPolicy {
+    /// `tokenizer.ggml.bos_token_id`, when the model has one.
+    pub bos_id: Option<i64>,
+    /// Whether the model wants prompts to start with BOS: its tokenizer adds
+    /// one (`tokenizer.ggml.add_bos_token`) or its template emits one.
+    pub expects_bos: bool,
+}
+
+impl BosPolicy {
+    /// Read the policy from a model's GGUF metadata.
+    #[must_use]
+    pub fn from_metadata(metadata: &HashMap<String, String>) -> Self {
+        let bos_id = metadata
+            .get("tokenizer.ggml.bos_token_id")
+            .and_then(|v| v.trim().parse().ok());
+        let adds_bos = metadata
+            .get("tokenizer.ggml.add_bos_token")
+            .is_some_and(|v| v.trim().eq_ignore_ascii_case("true"));
+        let template_emits_bos = metadata
+            .get("tokenizer.chat_template")
+            .is_some_and(|t| t.contains("bos_token"));
+        Self {
+            bos_id,
+            expects_bos: adds_bos || template_emits_bos,
+        }
+    }
+}
+
+/// A problem found in one rendered golden conversation.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum TemplateAnomaly {
+    /// The server could not render the conversation at all.
+    RenderFailed { error: String },
+    /// The model wants a leading BOS token and the prompt has none.
+    MissingBos,
+    /// The prompt starts with two BOS tokens: the template emits one and
+    /// the tokenizer adds another.
+    DuplicateBos,
+    /// Part of the conversation is missing from the prompt, so the model
+    /// never sees it.
+    MissingContent { what: String },
+    /// A template family's turn markers do not pair up.
+    UnbalancedRoleTokens {
+        open: String,
+        close: String,
+        opens: usize,
+        closes: usize,
+    },
+}
+
+impl fmt::Display for TemplateAnomaly {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::RenderFailed { error } => write!(f, "template failed to render: {error}"),
+            Self::MissingBos => write!(f, "prompt does not start with the BOS token"),
+            Self::DuplicateBos => write!(f, "prompt starts with two BOS tokens"),
+            Self::MissingContent { what } => {
+                write!(
+                    f,
+                    "{what} is missing from the prompt; the model never sees it"
+                )
+            }
+            Self::UnbalancedRoleTokens {
+                open,
+                close,
+                opens,
+                closes,
+            } => write!(
+                f,
+                "unbalanced turn markers: {opens}× {open} but {closes}× {close}"
+            ),
+        }
+    }
+}
+
+/// Outcome of rendering one golden conversation.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TemplateCheck {
+    /// [`GoldenConversation::name`].
+    pub conversation: String,
+    /// The rendered prompt; `None` when rendering failed.
+    pub prompt: Option<String>,
+    /// Problems found, empty when the rendering looks right.
+    pub anomalies: Vec<TemplateAnomaly>,
+}
+
+impl TemplateCheck {
+    /// A check whose conversation could not be rendered.
+    #[must_use]
+    pub fn render_failed(conversation: &GoldenConversation, error: String) -> Self {
+        Self {
+            conversation: conversation.name.to_string(),
+            prompt: None,
+            anomalies: vec![TemplateAnomaly::RenderFailed { error }],
+        }
+    }
+
+    /// Whether the rendering showed no anomalies.
+    #[must_use]
+    pub const fn passed(&self) -> bool {
+        self.anomalies.is_empty()
+    }
+}
+
+/// Inspect how `conversation` rendered: `prompt` is the template's output
+/// and `tokens` the prompt as the server evaluates it (special tokens
+/// parsed, BOS added per the tokenizer).
+#[must_use]
+pub fn check_rendered(
+    conversation: &GoldenConversation,
+    prompt: String,
+    tokens: &[i64],
+    bos: BosPolicy,
+) -> TemplateCheck {
+    let mut anomalies = Vec::new();
+
+    if let Some(bos_id) = bos.bos_id {
+        let leading = tokens.iter().take_while(|&&t| t == bos_id).count();
+        if leading == 0 && bos.expects_bos {
+            anomalies.push(TemplateAnomaly::MissingBos);
+        } else if leading > 1 {
+            anomalies.push(TemplateAnomaly::DuplicateBos);
+        }
+    }
+
+    for (what, marker) in &conversation.expected {
+        if !prompt.contains(marker) {
+            anomalies.push(TemplateAnomaly::MissingContent {
+                what: (*what).to_string(),
+            });
+        }
+    }
+
+    for &(open, close, unclosed) in ROLE_MARKERS {
+        let opens = prompt.matches(open).count();
+        let closes = prompt.matches(close).count();
+        if (opens > 0 || closes > 0) && opens != closes + unclosed {
+            anomalies.push(TemplateAnomaly::UnbalancedRoleTokens {
+                open: open.to_string(),
+                close: close.to_string(),
+                opens,
+                closes,
+            });
+        }
+    }
+
+    TemplateCheck {
+        conversation: conversation.name.to_string(),
+        prompt: Some(prompt),
+        anomalies,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Render `conversation` the way a well-behaved `ChatML` template does.
+    fn chatml(conversation: &GoldenConversation) -> String {
+        let mut out = String::new();
+        for message in &conversation.messages {
+            let mut content = message["content"].as_str().unwrap_or_default().to_string();
+            if let Some(calls) = message["tool_calls"].as_array() {
+                content.push_str(&calls[0]["function"]["arguments"].to_string());
+            }
+            let role = message["role"].as_str().unwrap();
+            out += &["<|im_start|>", role, "\n", &content, "<|im_end|>\n"].concat();
+        }
+        out + "<|im_start|>assistant\n"
+    }
+
+    const BOS: BosPolicy = BosPolicy {
+        bos_id: Some(1),
+        expects_bos: true,
+    };
+
+    #[test]
+    fn a_faithful_rendering_passes_every_golden_conversation() {
+        for conversation in golden_conversations() {
+            let check = check_rendered(&conversation, chatml(&conversation), &[1, 5, 6], BOS);
+            assert!(
+                check.passed(),
+                "{}: {:?}",
+                check.conversation,
+                check.anomalies
+            );
+        }
+    }
+
+    #[test]
+    fn flags_a_dropped_system_prompt_and_an_unclosed_turn() {
+        let conversation = &golden_conversations()[0];
+        let prompt = format!("<|im_start|>user\nHello. {USER_MARKER}\n<|im_start|>assistant\n");
+        let check = check_rendered(conversation, prompt, &[1, 5], BOS);
+        assert_eq!(
+            check.anomalies,
+            [
+                TemplateAnomaly::MissingContent {
+                    what: "system prompt".into()
+                },
+                TemplateAnomaly::UnbalancedRoleTokens {
+                    open: "<|im_start|>".into(),
+                    close: "<|im_end|>".into(),
+                    opens: 2,
+                    closes: 0,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn checks_the_leading_bos_tokens() {
+        let conversation = &golden_conversations()[0];
+        let prompt = chatml(conversation);
+        let anomalies = |tokens: &[i64], bos| {
+            check_rendered(conversation, prompt.clone(), tokens, bos).anomalies
+        };
+
+        assert_eq!(anomalies(&[5, 6], BOS), [TemplateAnomaly::MissingBos]);
+        assert_eq!(anomalies(&[1, 1, 5], BOS), [TemplateAnomaly::DuplicateBos]);
+        // A model that does not ask for BOS is fine without one.
+        let optional = BosPolicy {
+            bos_id: Some(1),
+            expects_bos: false,
+        };
+        assert!(anomalies(&[5, 6], optional).is_empty());
+    }
+
+    #[test]
+    fn bos_policy_reads_tokenizer_metadata_and_template() {
+        let metadata = HashMap::from([
+            ("tokenizer.ggml.bos_token_id".to_string(), "2".to_string()),
+            (
+                "tokenizer.ggml.add_bos_token".to_string(),
+                "false".to_string(),
+            ),
+            (
+                "tokenizer.chat_template".to_string(),
+                "{{ bos_token }}{% for m in messages %}{% endfor %}".to_string(),
+            ),
+        ]);
+        let policy = BosPolicy::from_metadata(&metadata);
+        assert_eq!(policy.bos_id, Some(2));
+        assert!(policy.expects_bos);
+        assert_eq!(
+            BosPolicy::from_metadata(&HashMap::new()),
+            BosPolicy::default()
+        );
+    }
+}
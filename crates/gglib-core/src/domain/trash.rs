@@ -0,0 +1,122 @@
+//! Trash for removed models.
+//!
+//! Removing a model moves its files to `<models_dir>/trash/<entry>/` and
+//! marks its database row deleted instead of erasing either, so a mistaken
+//! `remove` can be undone with `gglib trash restore`. Entries are purged
+//! once they outlive the retention period, and the oldest go first when
+//! the trash grows past its size cap; [`select_for_purge`] decides which.
+
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+use utoipa::ToSchema;
+
+/// Directory under the models directory holding trashed model files.
+pub const TRASH_DIR: &str = "trash";
+
+/// One file moved to the trash, and where it came from.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, TS, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TrashedFile {
+    /// Path the file had before removal; restore puts it back here.
+    pub original: String,
+    /// Path of the file inside the trash.
+    pub trashed: String,
+}
+
+/// What a removal did, stored with the deleted row.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TrashRecord {
+    pub deleted_at: DateTime<Utc>,
+    /// Trash entry directory holding `files`.
+    pub dir: String,
+    /// Files moved. Empty when the model's files were already gone.
+    pub files: Vec<TrashedFile>,
+    pub size_bytes: u64,
+}
+
+/// A removed model waiting in the trash.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, TS, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TrashEntry {
+    pub model_id: i64,
+    pub name: String,
+    pub deleted_at: DateTime<Utc>,
+    /// When the scheduled purge will delete the entry for good.
+    pub purge_after: DateTime<Utc>,
+    /// Total size of the trashed files.
+    pub size_bytes: u64,
+    pub files: Vec<TrashedFile>,
+}
+
+impl TrashEntry {
+    /// Entry for a model removed as described by `record`, kept for
+    /// `retention_days`.
+    #[must_use]
+    pub fn new(model_id: i64, name: String, record: TrashRecord, retention_days: u32) -> Self {
+        Self {
+            model_id,
+            name,
+            deleted_at: record.deleted_at,
+            purge_after: record.deleted_at + Duration::days(i64::from(retention_days)),
+            size_bytes: record.size_bytes,
+            files: record.files,
+        }
+    }
+}
+
+/// Ids of the entries a purge at `now` should delete: every expired entry,
+/// then the oldest remaining ones until the rest fit in `max_bytes`.
+#[must_use]
+pub fn select_for_purge(
+    entries: &[TrashEntry],
+    now: DateTime<Utc>,
+    max_bytes: Option<u64>,
+) -> Vec<i64> {
+    let mut by_age: Vec<&TrashEntry> = entries.iter().collect();
+    by_age.sort_by_key(|e| e.deleted_at);
+
+    let mut kept_bytes: u64 = entries.iter().map(|e| e.size_bytes).sum();
+    let mut purge = Vec::new();
+    for entry in by_age {
+        let over_cap = max_bytes.is_some_and(|max| kept_bytes > max);
+        if entry.purge_after <= now || over_cap {
+            kept_bytes -= entry.size_bytes;
+            purge.push(entry.model_id);
+        }
+    }
+    purge
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(model_id: i64, days_ago: i64, size_bytes: u64, now: DateTime<Utc>) -> TrashEntry {
+        let record = TrashRecord {
+            deleted_at: now - Duration::days(days_ago),
+            dir: String::new(),
+            files: Vec::new(),
+            size_bytes,
+        };
+        TrashEntry::new(model_id, format!("m{model_id}"), record, 30)
+    }
+
+    #[test]
+    fn purges_expired_entries_then_oldest_over_the_cap() {
+        let now = Utc::now();
+        let entries = [
+            entry(1, 40, 10, now),
+            entry(2, 20, 10, now),
+            entry(3, 10, 10, now),
+            entry(4, 1, 10, now),
+        ];
+
+        // Only the expired entry without a cap.
+        assert_eq!(select_for_purge(&entries, now, None), vec![1]);
+        // The cap then takes the oldest of the rest until 20 bytes remain.
+        assert_eq!(select_for_purge(&entries, now, Some(20)), vec![1, 2]);
+        // A cap smaller than any entry empties the trash.
+        assert_eq!(select_for_purge(&entries, now, Some(0)), vec![1, 2, 3, 4]);
+    }
+}
@@ -0,0 +1,75 @@
+//! URL fetch domain types.
+//!
+//! Like web search, fetching pages is opt-in: the built-in `fetch_url` tool
+//! only exists while the `url_fetch` setting holds a [`UrlFetchConfig`].
+
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+use utoipa::ToSchema;
+
+/// Tokens of page text returned per fetch when the config does not set one.
+pub const DEFAULT_URL_FETCH_MAX_TOKENS: u32 = 4_000;
+
+/// Upper bound accepted for [`UrlFetchConfig::max_tokens`].
+pub const MAX_URL_FETCH_MAX_TOKENS: u32 = 32_000;
+
+/// Fetches allowed in one conversation when the config does not set a limit.
+pub const DEFAULT_URL_FETCHES_PER_CONVERSATION: u32 = 10;
+
+/// Upper bound accepted for [`UrlFetchConfig::max_fetches_per_conversation`].
+pub const MAX_URL_FETCHES_PER_CONVERSATION: u32 = 100;
+
+/// Page fetch settings.
+///
+/// Stored as the `url_fetch` setting; `None` there disables the tool.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, TS, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct UrlFetchConfig {
+    /// Page text kept per fetch, in estimated tokens. Defaults to
+    /// [`DEFAULT_URL_FETCH_MAX_TOKENS`].
+    #[serde(default)]
+    pub max_tokens: Option<u32>,
+    /// Fetches the model may make in one conversation. Defaults to
+    /// [`DEFAULT_URL_FETCHES_PER_CONVERSATION`].
+    #[serde(default)]
+    pub max_fetches_per_conversation: Option<u32>,
+}
+
+impl UrlFetchConfig {
+    /// The effective token budget per page (with default fallback).
+    #[must_use]
+    pub fn effective_max_tokens(&self) -> u32 {
+        self.max_tokens.unwrap_or(DEFAULT_URL_FETCH_MAX_TOKENS)
+    }
+
+    /// The effective per-conversation fetch limit (with default fallback).
+    #[must_use]
+    pub fn effective_max_fetches(&self) -> u32 {
+        self.max_fetches_per_conversation
+            .unwrap_or(DEFAULT_URL_FETCHES_PER_CONVERSATION)
+    }
+
+    /// Check both limits are in range.
+    ///
+    /// # Errors
+    ///
+    /// Returns a human-readable description of the first problem found.
+    pub fn validate(&self) -> Result<(), String> {
+        if let Some(n) = self.max_tokens
+            && !(1..=MAX_URL_FETCH_MAX_TOKENS).contains(&n)
+        {
+            return Err(format!(
+                "max tokens must be between 1 and {MAX_URL_FETCH_MAX_TOKENS}, got {n}"
+            ));
+        }
+        if let Some(n) = self.max_fetches_per_conversation
+            && !(1..=MAX_URL_FETCHES_PER_CONVERSATION).contains(&n)
+        {
+            return Err(format!(
+                "fetches per conversation must be between 1 and \
+                 {MAX_URL_FETCHES_PER_CONVERSATION}, got {n}"
+            ));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,278 @@
+//! Per-model usage analytics.
+//!
+//! The runtime counts what each model's server did — requests, tokens and
+//! busy time seen by the proxy, seconds the server was up — as
+//! [`UsageDelta`]s; the usage service folds them into one row per model per
+//! day. Reading the rows back as [`UsageTotals`], [`rank_models`] orders the
+//! whole library by how much each model is used, so the ones never touched
+//! end up at the bottom, ready to prune. [`daily_summary`] reads them per
+//! day instead. Both attach an [`EnergyEstimate`] for the busy time.
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+use utoipa::ToSchema;
+
+use super::Model;
+use super::energy::{EnergyEstimate, EnergyRate};
+
+/// Usage accumulated for one model since it was last collected.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct UsageDelta {
+    /// Chat completions the proxy forwarded to the model's server.
+    pub requests: u64,
+    pub prompt_tokens: u64,
+    pub completion_tokens: u64,
+    /// Time the model's server was running.
+    pub serve_seconds: f64,
+    /// Time the server spent on requests (see
+    /// [`RequestTiming::busy_time`](super::RequestTiming::busy_time)).
+    pub busy_seconds: f64,
+}
+
+impl UsageDelta {
+    /// Whether nothing was recorded.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.requests == 0 && self.serve_seconds <= 0.0
+    }
+
+    /// Add `other` to this delta.
+    pub fn add(&mut self, other: &Self) {
+        self.requests += other.requests;
+        self.prompt_tokens += other.prompt_tokens;
+        self.completion_tokens += other.completion_tokens;
+        self.serve_seconds += other.serve_seconds;
+        self.busy_seconds += other.busy_seconds;
+    }
+}
+
+/// One model's stored usage summed over a period.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct UsageTotals {
+    pub model_id: i64,
+    pub usage: UsageDelta,
+    /// Days in the period with any recorded usage.
+    pub days_used: u32,
+    /// Latest day with any recorded usage.
+    pub last_used: Option<NaiveDate>,
+}
+
+/// Days covered by a [`daily_summary`] when the caller names none.
+pub const DEFAULT_SUMMARY_DAYS: u32 = 30;
+
+/// The whole library's stored usage on one day.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DailyTotals {
+    pub day: NaiveDate,
+    pub usage: UsageDelta,
+}
+
+/// A model's place in the usage ranking.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, TS, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ModelUsage {
+    pub model_id: i64,
+    pub name: String,
+    pub requests: u64,
+    pub prompt_tokens: u64,
+    pub completion_tokens: u64,
+    /// Hours the model's server was running.
+    pub serve_hours: f64,
+    /// Hours the server spent on requests.
+    pub busy_hours: f64,
+    /// Estimated energy for the busy time.
+    pub energy: EnergyEstimate,
+    /// Days with any recorded usage.
+    pub days_used: u32,
+    /// Latest day with any recorded usage; `None` when never used.
+    pub last_used: Option<NaiveDate>,
+}
+
+impl ModelUsage {
+    /// Prompt and completion tokens together.
+    #[must_use]
+    pub const fn total_tokens(&self) -> u64 {
+        self.prompt_tokens + self.completion_tokens
+    }
+}
+
+/// One day of usage across the library, as listed by [`daily_summary`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, TS, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct DailyUsage {
+    pub day: NaiveDate,
+    pub requests: u64,
+    pub prompt_tokens: u64,
+    pub completion_tokens: u64,
+    /// Hours servers spent on requests.
+    pub busy_hours: f64,
+    /// Estimated energy for the busy time.
+    pub energy: EnergyEstimate,
+}
+
+/// Rank every model in `models` by usage: most requests first, then most
+/// tokens, then longest served. Models without totals are included with
+/// zero usage, sorted last by name.
+///
+/// Energy is estimated at `rate`.
+#[must_use]
+pub fn rank_models(models: &[Model], totals: &[UsageTotals], rate: &EnergyRate) -> Vec<ModelUsage> {
+    let mut ranking: Vec<ModelUsage> = models
+        .iter()
+        .map(|model| {
+            let totals = totals.iter().find(|t| t.model_id == model.id);
+            let usage = totals.map(|t| t.usage).unwrap_or_default();
+            ModelUsage {
+                model_id: model.id,
+                name: model.name.clone(),
+                requests: usage.requests,
+                prompt_tokens: usage.prompt_tokens,
+                completion_tokens: usage.completion_tokens,
+                serve_hours: usage.serve_seconds / 3600.0,
+                busy_hours: usage.busy_seconds / 3600.0,
+                energy: rate.estimate(usage.busy_seconds),
+                days_used: totals.map_or(0, |t| t.days_used),
+                last_used: totals.and_then(|t| t.last_used),
+            }
+        })
+        .collect();
+    ranking.sort_by(|a, b| {
+        b.requests
+            .cmp(&a.requests)
+            .then_with(|| b.total_tokens().cmp(&a.total_tokens()))
+            .then_with(|| b.serve_hours.total_cmp(&a.serve_hours))
+            .then_with(|| a.name.cmp(&b.name))
+    });
+    ranking
+}
+
+/// One [`DailyUsage`] per day in `totals`, newest first, with energy
+/// estimated at `rate`.
+#[must_use]
+pub fn daily_summary(totals: &[DailyTotals], rate: &EnergyRate) -> Vec<DailyUsage> {
+    let mut days: Vec<DailyUsage> = totals
+        .iter()
+        .map(|t| DailyUsage {
+            day: t.day,
+            requests: t.usage.requests,
+            prompt_tokens: t.usage.prompt_tokens,
+            completion_tokens: t.usage.completion_tokens,
+            busy_hours: t.usage.busy_seconds / 3600.0,
+            energy: rate.estimate(t.usage.busy_seconds),
+        })
+        .collect();
+    days.sort_by_key(|d| std::cmp::Reverse(d.day));
+    days
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::path::PathBuf;
+
+    use chrono::Utc;
+
+    use super::*;
+    use crate::domain::capabilities::ModelCapabilities;
+
+    fn model(id: i64, name: &str) -> Model {
+        Model {
+            id,
+            name: name.to_string(),
+            model_key: String::new(),
+            file_path: PathBuf::from(format!("/models/{name}.gguf")),
+            param_count_b: 7.0,
+            architecture: None,
+            quantization: None,
+            context_length: None,
+            expert_count: None,
+            expert_used_count: None,
+            expert_shared_count: None,
+            metadata: HashMap::new(),
+            added_at: Utc::now(),
+            hf_repo_id: None,
+            hf_commit_sha: None,
+            hf_filename: None,
+            download_date: None,
+            last_update_check: None,
+            license: None,
+            tags: vec![],
+            capabilities: ModelCapabilities::default(),
+            capabilities_version: None,
+            inference_defaults: None,
+            server_defaults: None,
+            benchmark_summary: None,
+            card: None,
+        }
+    }
+
+    fn totals(model_id: i64, requests: u64, tokens: u64, serve_seconds: f64) -> UsageTotals {
+        UsageTotals {
+            model_id,
+            usage: UsageDelta {
+                requests,
+                prompt_tokens: tokens,
+                completion_tokens: 0,
+                serve_seconds,
+                busy_seconds: 0.0,
+            },
+            days_used: 1,
+            last_used: NaiveDate::from_ymd_opt(2026, 1, 2),
+        }
+    }
+
+    #[test]
+    fn ranks_by_requests_then_tokens_and_keeps_unused_models_last() {
+        let models = [
+            model(1, "never"),
+            model(2, "light"),
+            model(3, "heavy"),
+            model(4, "served"),
+            model(5, "also-never"),
+        ];
+        let stored = [
+            totals(2, 3, 100, 60.0),
+            totals(3, 3, 900, 60.0),
+            totals(4, 0, 0, 7200.0),
+        ];
+
+        let ranking = rank_models(&models, &stored, &EnergyRate::resolve(None, 100.0));
+        let names: Vec<&str> = ranking.iter().map(|u| u.name.as_str()).collect();
+        assert_eq!(names, ["heavy", "light", "served", "also-never", "never"]);
+        assert!((ranking[2].serve_hours - 2.0).abs() < f64::EPSILON);
+        assert_eq!(ranking[4].last_used, None);
+        assert_eq!(ranking[4].days_used, 0);
+    }
+
+    #[test]
+    fn daily_summary_lists_newest_first_with_energy() {
+        let day = |d| NaiveDate::from_ymd_opt(2026, 1, d).unwrap();
+        let usage = |busy_seconds| UsageDelta {
+            requests: 2,
+            busy_seconds,
+            ..UsageDelta::default()
+        };
+        let totals = [
+            DailyTotals {
+                day: day(1),
+                usage: usage(1800.0),
+            },
+            DailyTotals {
+                day: day(2),
+                usage: usage(36.0),
+            },
+        ];
+        let config = crate::domain::EnergyConfig {
+            watts: Some(200.0),
+            price_per_kwh: Some(0.5),
+            currency: None,
+        };
+
+        let days = daily_summary(&totals, &EnergyRate::resolve(Some(&config), 0.0));
+        assert_eq!(days[0].day, day(2));
+        assert!((days[1].busy_hours - 0.5).abs() < f64::EPSILON);
+        assert!((days[1].energy.watt_hours - 100.0).abs() < 1e-9);
+        assert!((days[1].energy.cost.unwrap() - 0.05).abs() < 1e-9);
+    }
+}
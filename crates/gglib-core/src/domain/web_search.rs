@@ -0,0 +1,256 @@
+//! Web search domain types.
+//!
+//! Web search is opt-in: the `web_search` setting holds a
+//! [`WebSearchConfig`], and the built-in `web_search` tool only exists while
+//! it is set. Queries go to the configured provider, never to a default one,
+//! so a local-only install sends nothing to the network unless asked to.
+
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+use utoipa::ToSchema;
+
+/// Results returned per query when the config does not set a limit.
+pub const DEFAULT_WEB_SEARCH_MAX_RESULTS: u32 = 5;
+
+/// Upper bound accepted for [`WebSearchConfig::max_results`].
+pub const MAX_WEB_SEARCH_RESULTS: u32 = 20;
+
+/// Snippet length, in characters, when the config does not set one.
+pub const DEFAULT_WEB_SEARCH_SNIPPET_CHARS: u32 = 300;
+
+/// Upper bound accepted for [`WebSearchConfig::max_snippet_chars`].
+pub const MAX_WEB_SEARCH_SNIPPET_CHARS: u32 = 2_000;
+
+/// Where search queries are sent.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, TS, ToSchema)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum WebSearchProvider {
+    /// A `SearxNG` instance with the JSON output format enabled.
+    Searxng {
+        /// Base URL of the instance, e.g. `http://localhost:8888`.
+        url: String,
+    },
+    /// The Brave Search API.
+    #[serde(rename_all = "camelCase")]
+    Brave {
+        /// Subscription token sent as `X-Subscription-Token`, or a
+        /// `secret:` reference to it.
+        api_key: String,
+    },
+    /// `DuckDuckGo`'s HTML endpoint; needs no account.
+    #[serde(rename = "duckduckgo")]
+    DuckDuckGo,
+}
+
+impl WebSearchProvider {
+    /// Short provider name reported with results.
+    #[must_use]
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::Searxng { .. } => "searxng",
+            Self::Brave { .. } => "brave",
+            Self::DuckDuckGo => "duckduckgo",
+        }
+    }
+
+    /// The provider's credential, if it takes one.
+    ///
+    /// Settings store a `secret:` reference here once the key has been moved
+    /// into the secret store.
+    #[must_use]
+    pub fn api_key(&self) -> Option<&str> {
+        match self {
+            Self::Brave { api_key } => Some(api_key),
+            Self::Searxng { .. } | Self::DuckDuckGo => None,
+        }
+    }
+
+    /// Mutable access to the provider's credential, if it takes one.
+    #[must_use]
+    pub const fn api_key_mut(&mut self) -> Option<&mut String> {
+        match self {
+            Self::Brave { api_key } => Some(api_key),
+            Self::Searxng { .. } | Self::DuckDuckGo => None,
+        }
+    }
+}
+
+/// Web search settings.
+///
+/// Stored as the `web_search` setting; `None` there disables the tool.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, TS, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct WebSearchConfig {
+    pub provider: WebSearchProvider,
+    /// Results returned per query. Defaults to
+    /// [`DEFAULT_WEB_SEARCH_MAX_RESULTS`].
+    #[serde(default)]
+    pub max_results: Option<u32>,
+    /// Longest snippet kept per result, in characters. Defaults to
+    /// [`DEFAULT_WEB_SEARCH_SNIPPET_CHARS`].
+    #[serde(default)]
+    pub max_snippet_chars: Option<u32>,
+    /// When non-empty, only results from these domains (or their
+    /// subdomains) are returned.
+    #[serde(default)]
+    pub allow_domains: Vec<String>,
+    /// Results from these domains (or their subdomains) are dropped.
+    #[serde(default)]
+    pub deny_domains: Vec<String>,
+}
+
+impl WebSearchConfig {
+    /// A config using `provider` with default limits and no domain lists.
+    #[must_use]
+    pub const fn new(provider: WebSearchProvider) -> Self {
+        Self {
+            provider,
+            max_results: None,
+            max_snippet_chars: None,
+            allow_domains: Vec::new(),
+            deny_domains: Vec::new(),
+        }
+    }
+
+    /// The effective result limit (with default fallback).
+    #[must_use]
+    pub fn effective_max_results(&self) -> u32 {
+        self.max_results.unwrap_or(DEFAULT_WEB_SEARCH_MAX_RESULTS)
+    }
+
+    /// The effective snippet length (with default fallback).
+    #[must_use]
+    pub fn effective_snippet_chars(&self) -> u32 {
+        self.max_snippet_chars
+            .unwrap_or(DEFAULT_WEB_SEARCH_SNIPPET_CHARS)
+    }
+
+    /// Whether results from `host` pass the allow and deny lists.
+    #[must_use]
+    pub fn allows_host(&self, host: &str) -> bool {
+        let host = host.trim_end_matches('.').to_ascii_lowercase();
+        let matches = |domain: &String| {
+            let domain = domain.trim().trim_start_matches('.').to_ascii_lowercase();
+            host == domain || host.ends_with(&format!(".{domain}"))
+        };
+        (self.allow_domains.is_empty() || self.allow_domains.iter().any(matches))
+            && !self.deny_domains.iter().any(matches)
+    }
+
+    /// Check the provider details, limits and domain lists.
+    ///
+    /// # Errors
+    ///
+    /// Returns a human-readable description of the first problem found.
+    pub fn validate(&self) -> Result<(), String> {
+        match &self.provider {
+            WebSearchProvider::Searxng { url } => {
+                let url = url.trim();
+                if !(url.starts_with("http://") || url.starts_with("https://")) {
+                    return Err(format!(
+                        "SearxNG URL must be an http:// or https:// URL, got '{url}'"
+                    ));
+                }
+            }
+            WebSearchProvider::Brave { api_key } => {
+                if api_key.trim().is_empty() {
+                    return Err("Brave API key cannot be empty".to_string());
+                }
+            }
+            WebSearchProvider::DuckDuckGo => {}
+        }
+        if let Some(n) = self.max_results
+            && !(1..=MAX_WEB_SEARCH_RESULTS).contains(&n)
+        {
+            return Err(format!(
+                "max results must be between 1 and {MAX_WEB_SEARCH_RESULTS}, got {n}"
+            ));
+        }
+        if let Some(n) = self.max_snippet_chars
+            && !(1..=MAX_WEB_SEARCH_SNIPPET_CHARS).contains(&n)
+        {
+            return Err(format!(
+                "max snippet length must be between 1 and {MAX_WEB_SEARCH_SNIPPET_CHARS}, got {n}"
+            ));
+        }
+        if let Some(bad) = self
+            .allow_domains
+            .iter()
+            .chain(&self.deny_domains)
+            .find(|d| !is_domain(d))
+        {
+            return Err(format!("'{bad}' is not a domain name"));
+        }
+        Ok(())
+    }
+}
+
+/// Whether `domain` looks like a bare host name such as `example.com`.
+fn is_domain(domain: &str) -> bool {
+    let domain = domain.trim().trim_start_matches('.');
+    !domain.is_empty()
+        && domain
+            .chars()
+            .all(|c| c.is_alphanumeric() || c == '.' || c == '-')
+}
+
+/// One search hit.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WebSearchResult {
+    pub title: String,
+    pub url: String,
+    pub snippet: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn domain_lists_match_subdomains() {
+        let mut config = WebSearchConfig::new(WebSearchProvider::DuckDuckGo);
+        assert!(config.allows_host("example.com"));
+
+        config.allow_domains = vec!["rust-lang.org".to_string()];
+        config.deny_domains = vec!["blog.rust-lang.org".to_string()];
+        assert!(config.allows_host("doc.rust-lang.org"));
+        assert!(config.allows_host("RUST-LANG.org"));
+        assert!(!config.allows_host("blog.rust-lang.org"));
+        assert!(!config.allows_host("notrust-lang.org"));
+    }
+
+    #[test]
+    fn validate_rejects_bad_provider_and_limits() {
+        let config = WebSearchConfig::new(WebSearchProvider::Searxng {
+            url: "localhost:8888".to_string(),
+        });
+        assert!(config.validate().is_err());
+
+        let config = WebSearchConfig::new(WebSearchProvider::Brave {
+            api_key: " ".to_string(),
+        });
+        assert!(config.validate().is_err());
+
+        let mut config = WebSearchConfig::new(WebSearchProvider::DuckDuckGo);
+        assert!(config.validate().is_ok());
+        config.max_results = Some(0);
+        assert!(config.validate().is_err());
+
+        config.max_results = None;
+        config.deny_domains = vec!["https://example.com/".to_string()];
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn provider_serializes_with_type_tag() {
+        let json = serde_json::to_value(WebSearchProvider::Brave {
+            api_key: "k".to_string(),
+        })
+        .unwrap();
+        assert_eq!(json, serde_json::json!({"type": "brave", "apiKey": "k"}));
+
+        let parsed: WebSearchProvider =
+            serde_json::from_value(serde_json::json!({"type": "duckduckgo"})).unwrap();
+        assert_eq!(parsed, WebSearchProvider::DuckDuckGo);
+    }
+}
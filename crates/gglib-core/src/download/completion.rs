@@ -6,6 +6,8 @@
 
 use serde::{Deserialize, Serialize};
 use std::fmt;
+use ts_rs::TS;
+use utoipa::ToSchema;
 use uuid::Uuid;
 
 use super::types::DownloadId;
@@ -23,7 +25,7 @@ use super::types::DownloadId;
 /// - All shards in a group → same key (one entry)
 /// - Failures before metadata available → key still valid
 /// - Survives cancellations and retries
-#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, TS, ToSchema)]
 #[serde(tag = "kind", rename_all = "snake_case")]
 pub enum CompletionKey {
     /// `HuggingFace` model file.
@@ -40,6 +42,7 @@ pub enum CompletionKey {
         /// Quantization type (e.g., "`Q4_K_M`").
         /// Optional since some downloads may not have a meaningful quantization.
         #[serde(skip_serializing_if = "Option::is_none")]
+        #[ts(optional)]
         quantization: Option<String>,
     },
 
@@ -86,7 +89,7 @@ impl fmt::Display for CompletionKey {
 }
 
 /// Result kind for a completion attempt.
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, TS, ToSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum CompletionKind {
     /// Successfully downloaded and registered.
@@ -99,8 +102,33 @@ pub enum CompletionKind {
     AlreadyPresent,
 }
 
+impl CompletionKind {
+    /// Wire and storage representation.
+    #[must_use]
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Downloaded => "downloaded",
+            Self::Failed => "failed",
+            Self::Cancelled => "cancelled",
+            Self::AlreadyPresent => "already_present",
+        }
+    }
+
+    /// Parse the representation produced by [`Self::as_str`].
+    #[must_use]
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "downloaded" => Some(Self::Downloaded),
+            "failed" => Some(Self::Failed),
+            "cancelled" => Some(Self::Cancelled),
+            "already_present" => Some(Self::AlreadyPresent),
+            _ => None,
+        }
+    }
+}
+
 /// Counts of attempts by result kind.
-#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize, TS, ToSchema)]
 pub struct AttemptCounts {
     /// Number of successful downloads.
     pub downloaded: u32,
@@ -165,7 +193,7 @@ impl AttemptCounts {
 }
 
 /// Details for a single completed artifact in a queue run.
-#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, TS, ToSchema)]
 pub struct CompletionDetail {
     /// Stable artifact identity key.
     pub key: CompletionKey,
@@ -186,7 +214,7 @@ pub struct CompletionDetail {
 ///
 /// Emitted when the queue transitions from busy → idle, capturing all
 /// completions that occurred during the run regardless of timing.
-#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, TS, ToSchema)]
 pub struct QueueRunSummary {
     /// Unique identifier for this queue run.
     pub run_id: Uuid,
@@ -356,6 +384,8 @@ mod tests {
             );
             let parsed: CompletionKind = serde_json::from_str(&json).unwrap();
             assert_eq!(parsed, kind);
+            assert_eq!(kind.as_str(), expected_wire);
+            assert_eq!(CompletionKind::parse(expected_wire), Some(kind));
         }
     }
 
@@ -0,0 +1,188 @@
+//! Per-download destination overrides.
+//!
+//! By default a download lands in `<models_dir>/<owner>_<repo>/` under the
+//! file names the repository uses. A [`DestinationOverride`] replaces either
+//! half: `subdirectory` is a directory relative to the models directory and
+//! `filename` a file name template. Both may use the placeholders
+//!
+//! - `{owner}` / `{repo}` - the two halves of the repository ID, without a
+//!   hub prefix such as `modelscope:`
+//! - `{quant}` - the quantization (e.g. `Q4_K_M`)
+//! - `{name}` - the file's own name, without `.gguf` or its shard suffix
+//!
+//! A shard keeps its `-00001-of-00003` suffix after the rendered name so
+//! llama-server still finds the rest of the model, and `.gguf` is appended
+//! when the template leaves it out.
+//!
+//! Rendered paths must stay inside the models directory and keep out of
+//! hidden directories (the staging area and download cache) and the trash.
+
+use std::path::{Component, Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use super::{DownloadError, DownloadId};
+use crate::domain::trash::TRASH_DIR;
+use crate::ports::ModelHub;
+use crate::utils::shard_filename::base_shard_filename;
+
+/// Where one download is stored, when not in its default place.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DestinationOverride {
+    /// Directory under the models directory, e.g. `llm/{owner}/{repo}`.
+    pub subdirectory: Option<String>,
+    /// File name template, e.g. `{repo}.{quant}`.
+    pub filename: Option<String>,
+}
+
+impl DestinationOverride {
+    /// Whether neither half is overridden.
+    pub const fn is_empty(&self) -> bool {
+        self.subdirectory.is_none() && self.filename.is_none()
+    }
+
+    /// Directory `id`'s files go in, relative to the models directory, or
+    /// `None` for the default.
+    pub fn target_dir(&self, id: &DownloadId) -> Result<Option<PathBuf>, DownloadError> {
+        let Some(template) = &self.subdirectory else {
+            return Ok(None);
+        };
+        let rendered = render(template, id, "");
+        let path = PathBuf::from(&rendered);
+        check_relative(&path, &rendered)?;
+        Ok(Some(path))
+    }
+
+    /// Path `file` (as named in the repository) is stored under, relative
+    /// to the download's directory.
+    pub fn target_file(&self, id: &DownloadId, file: &str) -> Result<String, DownloadError> {
+        let Some(template) = &self.filename else {
+            return Ok(file.to_string());
+        };
+        let original = Path::new(file)
+            .file_name()
+            .and_then(|n| n.to_str())
+            .unwrap_or(file);
+        let base = base_shard_filename(original);
+        let stem = base.strip_suffix(".gguf").unwrap_or(&base);
+        // What the shard pattern stripped: `-00001-of-00003`, or nothing.
+        let shard = original
+            .strip_suffix(".gguf")
+            .and_then(|s| s.strip_prefix(stem))
+            .unwrap_or_default();
+
+        let rendered = render(template, id, stem);
+        let rendered = rendered.strip_suffix(".gguf").unwrap_or(&rendered);
+        let name = format!("{rendered}{shard}.gguf");
+        if rendered.is_empty() || name.contains(['/', '\\']) {
+            return Err(DownloadError::invalid_destination(format!(
+                "file name template must render to a plain file name, got '{name}'"
+            )));
+        }
+        check_relative(Path::new(&name), &name)?;
+        Ok(name)
+    }
+}
+
+#[allow(clippy::literal_string_with_formatting_args)] // template placeholders
+fn render(template: &str, id: &DownloadId, name: &str) -> String {
+    let (_, repo_id) = ModelHub::split(id.model_id());
+    let (owner, repo) = repo_id.split_once('/').unwrap_or(("", repo_id));
+    template
+        .replace("{owner}", owner)
+        .replace("{repo}", repo)
+        .replace("{quant}", id.quantization().unwrap_or_default())
+        .replace("{name}", name)
+}
+
+/// A relative path of plain components, none hidden or the trash.
+fn check_relative(path: &Path, rendered: &str) -> Result<(), DownloadError> {
+    let mut components = path.components().peekable();
+    if components.peek().is_none() {
+        return Err(DownloadError::invalid_destination(
+            "destination renders to an empty path",
+        ));
+    }
+    for component in components {
+        let Component::Normal(part) = component else {
+            return Err(DownloadError::invalid_destination(format!(
+                "'{rendered}' must be a relative path inside the models directory"
+            )));
+        };
+        let part = part.to_string_lossy();
+        if part.starts_with('.') || part == TRASH_DIR || part.contains(['{', '}']) {
+            return Err(DownloadError::invalid_destination(format!(
+                "'{rendered}' uses '{part}', which is reserved or an unknown placeholder"
+            )));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn id() -> DownloadId {
+        DownloadId::new("unsloth/Qwen3-8B-GGUF", Some("Q4_K_M"))
+    }
+
+    #[test]
+    fn renders_placeholders_and_keeps_shard_suffixes() {
+        let dest = DestinationOverride {
+            subdirectory: Some("llm/{owner}/{repo}".into()),
+            filename: Some("{repo}.{quant}".into()),
+        };
+        assert_eq!(
+            dest.target_dir(&id()).unwrap(),
+            Some(PathBuf::from("llm/unsloth/Qwen3-8B-GGUF"))
+        );
+        assert_eq!(
+            dest.target_file(&id(), "Q4_K_M/Qwen3-8B-Q4_K_M-00002-of-00003.gguf")
+                .unwrap(),
+            "Qwen3-8B-GGUF.Q4_K_M-00002-of-00003.gguf"
+        );
+
+        let by_name = DestinationOverride {
+            filename: Some("{name}.gguf".into()),
+            ..Default::default()
+        };
+        assert_eq!(
+            by_name.target_file(&id(), "Qwen3-8B-Q4_K_M.gguf").unwrap(),
+            "Qwen3-8B-Q4_K_M.gguf"
+        );
+        assert_eq!(
+            DestinationOverride::default()
+                .target_file(&id(), "Q4_K_M/model.gguf")
+                .unwrap(),
+            "Q4_K_M/model.gguf"
+        );
+    }
+
+    #[test]
+    fn rejects_paths_leaving_the_models_directory() {
+        let dir = |s: &str| DestinationOverride {
+            subdirectory: Some(s.into()),
+            filename: None,
+        };
+        for bad in [
+            "../elsewhere",
+            "/abs",
+            "a/../../b",
+            ".cache/x",
+            "trash",
+            "{model}",
+            "",
+        ] {
+            assert!(dir(bad).target_dir(&id()).is_err(), "{bad}");
+        }
+
+        let file = |s: &str| DestinationOverride {
+            subdirectory: None,
+            filename: Some(s.into()),
+        };
+        for bad in ["../{repo}", "sub/{repo}", ".hidden", ""] {
+            assert!(file(bad).target_file(&id(), "m.gguf").is_err(), "{bad}");
+        }
+    }
+}
@@ -60,6 +60,13 @@ pub enum DownloadError {
         max_size: u32,
     },
 
+    /// A destination override renders to a path that is not allowed.
+    #[error("Invalid destination: {message}")]
+    InvalidDestination {
+        /// What is wrong with the destination.
+        message: String,
+    },
+
     /// Download is already queued.
     #[error("Already queued: {id}")]
     AlreadyQueued {
@@ -94,6 +101,15 @@ pub enum DownloadError {
         actual: String,
     },
 
+    /// Post-download validation failed; the file was moved to quarantine.
+    #[error("Validation failed: {reason} (quarantined at {path})")]
+    Quarantined {
+        /// Why validation failed.
+        reason: String,
+        /// Quarantine directory holding the file and its reason file.
+        path: String,
+    },
+
     /// General/uncategorized error.
     #[error("{message}")]
     Other {
@@ -166,6 +182,13 @@ impl DownloadError {
         Self::QueueFull { max_size }
     }
 
+    /// Create an invalid destination error.
+    pub fn invalid_destination(message: impl Into<String>) -> Self {
+        Self::InvalidDestination {
+            message: message.into(),
+        }
+    }
+
     /// Create an already queued error.
     pub fn already_queued(id: impl Into<String>) -> Self {
         Self::AlreadyQueued { id: id.into() }
@@ -184,6 +207,14 @@ impl DownloadError {
         }
     }
 
+    /// Create a quarantined-download error.
+    pub fn quarantined(reason: impl Into<String>, path: impl Into<String>) -> Self {
+        Self::Quarantined {
+            reason: reason.into(),
+            path: path.into(),
+        }
+    }
+
     /// Create a generic error.
     pub fn other(message: impl Into<String>) -> Self {
         Self::Other {
@@ -228,6 +259,9 @@ impl DownloadError {
                     "Download queue is full (max {max_size} items). Wait for a download to complete."
                 )
             }
+            Self::InvalidDestination { message } => {
+                format!("Invalid download destination: {message}")
+            }
             Self::AlreadyQueued { id } => {
                 format!("Download '{id}' is already in the queue.")
             }
@@ -241,6 +275,9 @@ impl DownloadError {
             Self::IntegrityFailed { .. } => {
                 "File integrity check failed. The download may be corrupted.".to_string()
             }
+            Self::Quarantined { reason, path } => {
+                format!("Downloaded file failed validation ({reason}) and was moved to {path}.")
+            }
             Self::Other { message } => message.clone(),
         }
     }
@@ -3,9 +3,15 @@
 use super::completion::QueueRunSummary;
 use super::types::ShardInfo;
 use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+use utoipa::ToSchema;
 
 /// A summary of a download in the queue (for snapshots and API responses).
-#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+///
+/// The byte, rate and timing fields let a client that connects mid-download
+/// render the active item straight from the snapshot. They are zero or absent
+/// for items that are not transferring.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, TS, ToSchema)]
 pub struct DownloadSummary {
     /// Canonical ID string (`model_id:quantization` or just `model_id`).
     pub id: String,
@@ -17,17 +23,38 @@ pub struct DownloadSummary {
     pub position: u32,
     /// Error message if status is Failed.
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[ts(optional)]
     pub error: Option<String>,
     /// Group ID for sharded downloads (all shards share the same `group_id`).
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[ts(optional)]
     pub group_id: Option<String>,
     /// Shard information if this is part of a sharded model.
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[ts(optional)]
     pub shard_info: Option<ShardInfo>,
+    /// Bytes downloaded so far (across the whole shard group when sharded).
+    #[serde(default)]
+    pub downloaded_bytes: u64,
+    /// Total bytes to download; `0` while unknown.
+    #[serde(default)]
+    pub total_bytes: u64,
+    /// Current speed from the manager's `RateEstimator`; absent while it warms up.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[ts(optional)]
+    pub speed_bps: Option<f64>,
+    /// Estimated seconds remaining; absent when unknown.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[ts(optional)]
+    pub eta_seconds: Option<f64>,
+    /// Seconds since the transfer (or its first shard) started.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[ts(optional)]
+    pub elapsed_seconds: Option<f64>,
 }
 
 /// Status of a download.
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, TS, ToSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum DownloadStatus {
     /// Waiting in the queue.
@@ -91,6 +118,36 @@ impl DownloadStatus {
     }
 }
 
+/// Phase of a running download, reported by [`DownloadEvent::PhaseProgress`].
+///
+/// Finer-grained than [`DownloadStatus`]: a download stays `Downloading`
+/// while its bytes are hashed, but moves from `Transferring` to `Verifying`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, TS, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum DownloadPhase {
+    /// Locating the file and preparing the transfer; no bytes yet.
+    Resolving,
+    /// Bytes are arriving; detail is in the progress events.
+    Transferring,
+    /// Checking the file on disk (GGUF header, size, SHA-256).
+    Verifying,
+    /// Collecting metadata and writing the model to the database.
+    Registering,
+}
+
+impl DownloadPhase {
+    /// Human-readable label for UI display.
+    #[must_use]
+    pub const fn label(&self) -> &'static str {
+        match self {
+            Self::Resolving => "Resolving",
+            Self::Transferring => "Downloading",
+            Self::Verifying => "Verifying",
+            Self::Registering => "Registering",
+        }
+    }
+}
+
 /// Single discriminated union for all download events.
 ///
 /// The frontend handles this as a TypeScript discriminated union:
@@ -106,7 +163,10 @@ impl DownloadStatus {
 ///   | { type: "download_completed"; id: string }
 ///   | { type: "download_failed"; id: string; error: string }
 ///   | { type: "download_cancelled"; id: string }
-///   | { type: "download_notice"; id: string; message: string };
+///   | { type: "download_notice"; id: string; message: string }
+///   | { type: "phase_progress"; id: string; phase: DownloadPhase;
+///       shard_index?: number; total_shards?: number;
+///       processed: number; total: number; percentage?: number };
 /// ```
 ///
 /// `speed_bps` and `eta_seconds` are **optional and omitted when unknown** — a
@@ -115,7 +175,7 @@ impl DownloadStatus {
 /// must never compute a rate of their own from successive `downloaded` values;
 /// the manager's `RateEstimator` is the only source. The mirrored TypeScript
 /// declaration lives in `src/services/transport/types/events.ts`.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize, TS, ToSchema)]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum DownloadEvent {
     /// Snapshot of the entire queue state.
@@ -132,9 +192,11 @@ pub enum DownloadEvent {
         id: String,
         /// Current shard index (0-based), present only for sharded downloads.
         #[serde(skip_serializing_if = "Option::is_none")]
+        #[ts(optional)]
         shard_index: Option<u32>,
         /// Total number of shards, present only for sharded downloads.
         #[serde(skip_serializing_if = "Option::is_none")]
+        #[ts(optional)]
         total_shards: Option<u32>,
     },
 
@@ -152,9 +214,11 @@ pub enum DownloadEvent {
         /// `0.0`: zero is a real reading meaning "stalled", and conflating the
         /// two is what rendered `ETA: 0s` on a healthy download.
         #[serde(skip_serializing_if = "Option::is_none")]
+        #[ts(optional)]
         speed_bps: Option<f64>,
         /// Estimated time remaining in seconds; absent when not yet known.
         #[serde(skip_serializing_if = "Option::is_none")]
+        #[ts(optional)]
         eta_seconds: Option<f64>,
         /// Progress percentage (0.0 - 100.0).
         percentage: f64,
@@ -182,9 +246,11 @@ pub enum DownloadEvent {
         ///
         /// Measured across the whole shard group, not reset per shard.
         #[serde(skip_serializing_if = "Option::is_none")]
+        #[ts(optional)]
         speed_bps: Option<f64>,
         /// Estimated time remaining in seconds; absent when not yet known.
         #[serde(skip_serializing_if = "Option::is_none")]
+        #[ts(optional)]
         eta_seconds: Option<f64>,
         /// Aggregate progress percentage (0.0 - 100.0).
         percentage: f64,
@@ -196,6 +262,7 @@ pub enum DownloadEvent {
         id: String,
         /// Optional success message.
         #[serde(skip_serializing_if = "Option::is_none")]
+        #[ts(optional)]
         message: Option<String>,
     },
 
@@ -242,6 +309,35 @@ pub enum DownloadEvent {
         message: String,
     },
 
+    /// The download entered a phase, or made progress within one.
+    ///
+    /// Emitted once at every phase boundary, and on every progress tick
+    /// while a phase other than `Transferring` has bytes to work through —
+    /// hashing a 40 GB file takes long enough to need its own bar.
+    /// Transfer progress itself stays on `DownloadProgress`/`ShardProgress`.
+    PhaseProgress {
+        /// Canonical ID of the download.
+        id: String,
+        /// The phase the download is in.
+        phase: DownloadPhase,
+        /// Current shard index (0-based), present only for sharded downloads.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        #[ts(optional)]
+        shard_index: Option<u32>,
+        /// Total number of shards, present only for sharded downloads.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        #[ts(optional)]
+        total_shards: Option<u32>,
+        /// Bytes processed so far in this phase.
+        processed: u64,
+        /// Bytes this phase has to process; `0` when it has no byte progress.
+        total: u64,
+        /// Phase progress percentage (0.0 - 100.0); absent when `total` is 0.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        #[ts(optional)]
+        percentage: Option<f64>,
+    },
+
     /// Queue run completed (all downloads in the queue finished).
     ///
     /// Emitted when the download queue transitions from busy → idle,
@@ -251,6 +347,23 @@ pub enum DownloadEvent {
         /// Complete summary of the queue run.
         summary: QueueRunSummary,
     },
+
+    /// Result of a sweep for orphaned partial downloads.
+    ///
+    /// Partials are `.incomplete`/`.part` files and staging directories that
+    /// no queued or active job refers to. Ones older than the grace period
+    /// are deleted; the rest are reported as reclaimable. Emitted only when
+    /// the sweep found something.
+    PartialCleanup {
+        /// Orphaned partials still within the grace period.
+        reclaimable_files: u32,
+        /// Bytes held by those partials.
+        reclaimable_bytes: u64,
+        /// Partials deleted by this sweep.
+        removed_files: u32,
+        /// Bytes freed by this sweep.
+        removed_bytes: u64,
+    },
 }
 
 impl DownloadEvent {
@@ -280,7 +393,7 @@ impl DownloadEvent {
 
     /// Percentage complete, clamped to 0-100.
     #[allow(clippy::cast_precision_loss)]
-    fn percent_of(downloaded: u64, total: u64) -> f64 {
+    pub(crate) fn percent_of(downloaded: u64, total: u64) -> f64 {
         if total == 0 {
             return 0.0;
         }
@@ -370,6 +483,27 @@ impl DownloadEvent {
         }
     }
 
+    /// Create a phase progress event.
+    ///
+    /// `shard` is `(shard_index, total_shards)` for sharded downloads.
+    pub fn phase_progress(
+        id: impl Into<String>,
+        phase: DownloadPhase,
+        shard: Option<(u32, u32)>,
+        processed: u64,
+        total: u64,
+    ) -> Self {
+        Self::PhaseProgress {
+            id: id.into(),
+            phase,
+            shard_index: shard.map(|(index, _)| index),
+            total_shards: shard.map(|(_, total)| total),
+            processed,
+            total,
+            percentage: (total > 0).then(|| Self::percent_of(processed, total)),
+        }
+    }
+
     /// Create a queue run complete event.
     pub const fn queue_run_complete(summary: QueueRunSummary) -> Self {
         Self::QueueRunComplete { summary }
@@ -379,7 +513,9 @@ impl DownloadEvent {
     #[must_use]
     pub fn id(&self) -> Option<&str> {
         match self {
-            Self::QueueSnapshot { .. } | Self::QueueRunComplete { .. } => None,
+            Self::QueueSnapshot { .. }
+            | Self::QueueRunComplete { .. }
+            | Self::PartialCleanup { .. } => None,
             Self::DownloadStarted { id, .. }
             | Self::DownloadProgress { id, .. }
             | Self::ShardProgress { id, .. }
@@ -387,7 +523,8 @@ impl DownloadEvent {
             | Self::DownloadFailed { id, .. }
             | Self::DownloadCancelled { id }
             | Self::DownloadStatusChanged { id, .. }
-            | Self::DownloadNotice { id, .. } => Some(id),
+            | Self::DownloadNotice { id, .. }
+            | Self::PhaseProgress { id, .. } => Some(id),
         }
     }
 
@@ -407,7 +544,9 @@ impl DownloadEvent {
             Self::DownloadCancelled { .. } => "download:cancelled",
             Self::DownloadStatusChanged { .. } => "download:status_changed",
             Self::DownloadNotice { .. } => "download:notice",
+            Self::PhaseProgress { .. } => "download:phase",
             Self::QueueRunComplete { .. } => "download:queue_run_complete",
+            Self::PartialCleanup { .. } => "download:partial_cleanup",
         }
     }
 }
@@ -458,6 +597,42 @@ mod tests {
         }
     }
 
+    #[test]
+    fn phase_without_byte_progress_has_no_percentage() {
+        let resolving = DownloadEvent::phase_progress("id", DownloadPhase::Resolving, None, 0, 0);
+        let json = serde_json::to_string(&resolving).expect("serializes");
+        assert!(json.contains(r#""phase":"resolving""#), "{json}");
+        assert!(
+            !json.contains("percentage") && !json.contains("shard_index"),
+            "{json}"
+        );
+
+        let verifying =
+            DownloadEvent::phase_progress("id", DownloadPhase::Verifying, Some((1, 3)), 25, 100);
+        match verifying {
+            DownloadEvent::PhaseProgress {
+                percentage,
+                shard_index,
+                total_shards,
+                ..
+            } => {
+                assert_eq!(percentage, Some(25.0));
+                assert_eq!((shard_index, total_shards), (Some(1), Some(3)));
+            }
+            _ => panic!("Expected PhaseProgress"),
+        }
+    }
+
+    #[test]
+    fn snapshot_items_from_older_servers_still_decode() {
+        // Summaries sent before the byte and rate fields existed.
+        let json = r#"{"id":"a/b:Q4_K_M","display_name":"b","status":"queued","position":2}"#;
+        let summary: DownloadSummary = serde_json::from_str(json).expect("decodes");
+        assert_eq!(summary.downloaded_bytes, 0);
+        assert_eq!(summary.speed_bps, None);
+        assert_eq!(summary.elapsed_seconds, None);
+    }
+
     #[test]
     fn test_event_id_extraction() {
         assert_eq!(DownloadEvent::started("test").id(), Some("test"));
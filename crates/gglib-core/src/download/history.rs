@@ -0,0 +1,43 @@
+//! Download history query types.
+//!
+//! Every drained queue run is persisted as a [`QueueRunSummary`] with its
+//! full, untruncated list of completion records. [`DownloadHistoryFilter`]
+//! selects which runs and records come back when that history is read.
+//!
+//! [`QueueRunSummary`]: super::QueueRunSummary
+
+use super::completion::CompletionKind;
+
+/// Default number of runs returned when no limit is given.
+pub const DEFAULT_HISTORY_LIMIT: u32 = 50;
+
+/// Filter for reading the download history.
+///
+/// Time bounds select runs by completion time; `result` and `search` select
+/// records within them. Runs left without any matching record are omitted
+/// whenever a record filter is set.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DownloadHistoryFilter {
+    /// Only runs completed at or after this time (ms since epoch).
+    pub since_ms: Option<u64>,
+    /// Only runs completed at or before this time (ms since epoch).
+    pub until_ms: Option<u64>,
+    /// Only records whose most recent result is this kind.
+    pub result: Option<CompletionKind>,
+    /// Case-insensitive substring of the record's display name.
+    pub search: Option<String>,
+    /// Maximum number of runs, newest first. Defaults to
+    /// [`DEFAULT_HISTORY_LIMIT`].
+    pub limit: Option<u32>,
+}
+
+impl DownloadHistoryFilter {
+    /// Effective run limit.
+    #[must_use]
+    pub const fn limit(&self) -> u32 {
+        match self.limit {
+            Some(limit) => limit,
+            None => DEFAULT_HISTORY_LIMIT,
+        }
+    }
+}
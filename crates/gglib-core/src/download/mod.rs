@@ -1,8 +1,10 @@
 #![doc = include_str!("README.md")]
 pub mod completion;
+pub mod destination;
 pub mod errors;
 pub mod events;
 pub mod format;
+pub mod history;
 pub mod queue;
 pub mod rate;
 pub mod types;
@@ -11,9 +13,14 @@ pub mod types;
 pub use completion::{
     AttemptCounts, CompletionDetail, CompletionKey, CompletionKind, QueueRunSummary,
 };
+pub use destination::DestinationOverride;
 pub use errors::{DownloadError, DownloadResult};
-pub use events::{DownloadEvent, DownloadStatus, DownloadSummary};
+pub use events::{DownloadEvent, DownloadPhase, DownloadStatus, DownloadSummary};
 pub use format::{format_duration, format_rate};
+pub use history::{DEFAULT_HISTORY_LIMIT, DownloadHistoryFilter};
 pub use queue::{FailedDownload, QueueSnapshot, QueuedDownload};
 pub use rate::RateEstimator;
-pub use types::{DownloadId, Quantization, ShardInfo};
+pub use types::{
+    CollectionQueueResult, CollectionQueuedRepo, CollectionSkippedRepo, DownloadId, GroupProgress,
+    Quantization, ShardInfo,
+};
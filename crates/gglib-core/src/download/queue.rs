@@ -8,9 +8,10 @@ use super::events::DownloadStatus;
 use super::format::{format_duration, format_rate};
 use super::types::{Quantization, ShardInfo};
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
 /// Snapshot of the entire download queue for API responses.
-#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[derive(Clone, Debug, Default, Serialize, Deserialize, ToSchema)]
 pub struct QueueSnapshot {
     /// Items currently in the queue.
     pub items: Vec<QueuedDownload>,
@@ -62,7 +63,7 @@ impl QueueSnapshot {
 }
 
 /// A single download in the queue.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
 pub struct QueuedDownload {
     /// Canonical ID (`model_id:quantization` or `model_id`).
     pub id: String,
@@ -97,6 +98,10 @@ pub struct QueuedDownload {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub eta_seconds: Option<f64>,
 
+    /// Seconds since the transfer (or its first shard) started.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub elapsed_seconds: Option<f64>,
+
     /// Progress as percentage (0.0 - 100.0).
     pub progress_percent: f64,
 
@@ -136,6 +141,7 @@ impl QueuedDownload {
             total_bytes: 0,
             speed_bps: None,
             eta_seconds: None,
+            elapsed_seconds: None,
             progress_percent: 0.0,
             queued_at,
             started_at: None,
@@ -222,7 +228,7 @@ impl QueuedDownload {
 }
 
 /// A failed download kept for display purposes.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
 pub struct FailedDownload {
     /// Canonical ID of the failed download.
     pub id: String,
@@ -241,6 +247,18 @@ pub struct FailedDownload {
 
     /// Bytes downloaded before failure.
     pub downloaded_bytes: u64,
+
+    /// Shard group of a sharded download, which fails as a unit.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub group_id: Option<String>,
+
+    /// Number of shards in the group.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total_shards: Option<u32>,
+
+    /// Shards of the group already downloaded; a retry fetches only the rest.
+    #[serde(default)]
+    pub completed_shards: u32,
 }
 
 impl FailedDownload {
@@ -258,6 +276,9 @@ impl FailedDownload {
             failed_at,
             recoverable: false,
             downloaded_bytes: 0,
+            group_id: None,
+            total_shards: None,
+            completed_shards: 0,
         }
     }
 
@@ -274,6 +295,20 @@ impl FailedDownload {
         self.downloaded_bytes = bytes;
         self
     }
+
+    /// Set the shard group that failed and how much of it is done.
+    #[must_use]
+    pub fn with_shard_group(
+        mut self,
+        group_id: String,
+        total_shards: u32,
+        completed_shards: u32,
+    ) -> Self {
+        self.group_id = Some(group_id);
+        self.total_shards = Some(total_shards);
+        self.completed_shards = completed_shards;
+        self
+    }
 }
 
 #[cfg(test)]
@@ -88,6 +88,8 @@ pub struct RateEstimator {
     prev_at: Instant,
     /// Smoothed seconds remaining; `None` when unknown or complete.
     smoothed_eta: Option<f64>,
+    /// When the transfer this estimator measures began.
+    started_at: Instant,
 }
 
 impl RateEstimator {
@@ -100,6 +102,7 @@ impl RateEstimator {
             prev_bytes: None,
             prev_at: now,
             smoothed_eta: None,
+            started_at: now,
         }
     }
 
@@ -168,6 +171,15 @@ impl RateEstimator {
         self.smoothed_eta
     }
 
+    /// Seconds since the transfer began, as of `now`.
+    ///
+    /// Measured from the estimator's creation, which for a sharded download is
+    /// the start of its first shard.
+    #[must_use]
+    pub fn elapsed_seconds(&self, now: Instant) -> f64 {
+        now.saturating_duration_since(self.started_at).as_secs_f64()
+    }
+
     /// Fold the latest raw ETA into the smoothed one.
     fn update_eta(&mut self, downloaded: u64, total: u64, dt: f64) {
         let Some(rate) = self.rate_bps() else {
@@ -375,4 +387,16 @@ mod tests {
         );
         assert_eq!(est.eta_seconds(), None, "unknown total means no ETA");
     }
+
+    #[test]
+    fn elapsed_spans_rebaselines() {
+        let start = Instant::now();
+        let mut est = RateEstimator::new(start);
+        est.record(0, 0, start);
+        let now = drive(&mut est, start, 0, 50_000_000.0, 0.25, 40);
+        // Next shard: the counter restarts, the clock does not.
+        est.record(0, 0, now);
+        let elapsed = est.elapsed_seconds(now);
+        assert!((elapsed - 10.0).abs() < 0.01, "expected 10s, got {elapsed}");
+    }
 }
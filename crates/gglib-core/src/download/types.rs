@@ -5,12 +5,14 @@
 use serde::{Deserialize, Serialize};
 use std::fmt;
 use std::str::FromStr;
+use ts_rs::TS;
+use utoipa::ToSchema;
 
 /// Canonical identifier for a download.
 ///
 /// Represents a unique download as `model_id:quantization` (or just `model_id` if no quantization).
 /// This is the single identifier format used throughout the system.
-#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, TS, ToSchema)]
 pub struct DownloadId {
     model_id: String,
     quantization: Option<String>,
@@ -109,7 +111,7 @@ impl FromStr for DownloadId {
 /// how the "UD-" modifier is detected.
 ///
 /// [`from_filename`]: Self::from_filename
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default, ToSchema)]
 pub enum Quantization {
     // 1-bit quantizations
     Iq1S,
@@ -514,7 +516,7 @@ impl FromStr for Quantization {
 /// a smaller final shard, and estimating the group total as
 /// `this_shard_size * shard_count` made the percentage both wrong and
 /// discontinuous at every shard boundary.
-#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, TS, ToSchema)]
 pub struct ShardInfo {
     /// 0-based index of this shard.
     pub shard_index: u32,
@@ -524,12 +526,15 @@ pub struct ShardInfo {
     pub filename: String,
     /// Size of this shard file in bytes (if known).
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[ts(optional)]
     pub file_size: Option<u64>,
     /// Summed size of every shard before this one (if all sizes are known).
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[ts(optional)]
     pub preceding_bytes: Option<u64>,
     /// Summed size of every shard in the group (if all sizes are known).
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[ts(optional)]
     pub group_total_bytes: Option<u64>,
 }
 
@@ -592,6 +597,83 @@ impl ShardInfo {
     }
 }
 
+/// Aggregate progress of one shard group, summed from the actual shard sizes.
+///
+/// Shards whose size is still unknown are estimated at the mean of the known
+/// ones until their transfer reports a total; [`exact`](Self::exact) says
+/// whether any estimate remains in [`total_bytes`](Self::total_bytes).
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct GroupProgress {
+    /// Shard group ID, as in `QueuedDownload.group_id`.
+    pub group_id: String,
+    pub total_shards: u32,
+    /// Shards fully downloaded so far.
+    pub completed_shards: u32,
+    pub downloaded_bytes: u64,
+    pub total_bytes: u64,
+    /// `true` once every shard's size is known.
+    pub exact: bool,
+    /// Progress percentage (0.0 - 100.0).
+    pub percentage: f64,
+}
+
+impl GroupProgress {
+    /// Build a snapshot; the percentage is derived from the byte counts.
+    #[must_use]
+    pub fn new(
+        group_id: impl Into<String>,
+        total_shards: u32,
+        completed_shards: u32,
+        downloaded_bytes: u64,
+        total_bytes: u64,
+        exact: bool,
+    ) -> Self {
+        Self {
+            group_id: group_id.into(),
+            total_shards,
+            completed_shards,
+            downloaded_bytes,
+            total_bytes,
+            exact,
+            percentage: super::DownloadEvent::percent_of(downloaded_bytes, total_bytes),
+        }
+    }
+}
+
+/// Outcome of queueing every GGUF repository in a `HuggingFace` collection.
+///
+/// The downloads themselves run as one queue run, so their combined result
+/// arrives in the usual `QueueRunComplete` summary once the queue drains.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct CollectionQueueResult {
+    /// Collection slug.
+    pub slug: String,
+    /// Collection title.
+    pub title: String,
+    /// Repositories that were queued, in collection order.
+    pub queued: Vec<CollectionQueuedRepo>,
+    /// Repositories that were not queued, with the reason.
+    pub skipped: Vec<CollectionSkippedRepo>,
+}
+
+/// A collection repository that was queued.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct CollectionQueuedRepo {
+    pub repo_id: String,
+    /// Quantization that was queued (requested or auto-selected).
+    pub quantization: String,
+    /// Number of files (shards) queued.
+    pub shard_count: usize,
+}
+
+/// A collection repository that was skipped.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct CollectionSkippedRepo {
+    pub repo_id: String,
+    /// Why it was skipped (no GGUF files, quantization missing, already queued, ...).
+    pub reason: String,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
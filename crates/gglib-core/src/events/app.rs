@@ -1,13 +1,16 @@
-//! Application-level events (model lifecycle).
+//! Application-level events (model lifecycle, conversation metadata,
+//! settings changes).
 
 use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+use utoipa::ToSchema;
 
 use super::AppEvent;
 
 /// Summary of a model for event payloads.
 ///
 /// This is a lightweight representation for events — not the full `Model`.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, TS, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct ModelSummary {
     /// Database ID of the model.
@@ -18,9 +21,11 @@ pub struct ModelSummary {
     pub file_path: String,
     /// Model architecture (e.g., "llama").
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[ts(optional)]
     pub architecture: Option<String>,
     /// Quantization type (e.g., "`Q4_0`").
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[ts(optional)]
     pub quantization: Option<String>,
 }
 
@@ -58,4 +63,17 @@ impl AppEvent {
     pub const fn model_updated(model: ModelSummary) -> Self {
         Self::ModelUpdated { model }
     }
+
+    /// Create a conversation updated event.
+    pub fn conversation_updated(conversation_id: i64, title: impl Into<String>) -> Self {
+        Self::ConversationUpdated {
+            conversation_id,
+            title: title.into(),
+        }
+    }
+
+    /// Create a settings changed event.
+    pub const fn settings_changed(keys: Vec<String>) -> Self {
+        Self::SettingsChanged { keys }
+    }
 }
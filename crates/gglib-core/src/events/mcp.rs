@@ -1,6 +1,8 @@
 //! MCP server lifecycle events.
 
 use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+use utoipa::ToSchema;
 
 use super::AppEvent;
 use crate::ports::McpErrorInfo;
@@ -8,7 +10,7 @@ use crate::ports::McpErrorInfo;
 /// Summary of an MCP server for event payloads.
 ///
 /// This is a lightweight representation for events — not the full `McpServer`.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, TS, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct McpServerSummary {
     /// Database ID of the MCP server.
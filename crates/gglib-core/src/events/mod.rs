@@ -5,23 +5,30 @@ mod mcp;
 mod server;
 
 use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+use utoipa::ToSchema;
 
 use crate::ports::McpErrorInfo;
 
 // Re-export event types
 pub use app::ModelSummary;
 pub use mcp::McpServerSummary;
-pub use server::{NoopServerEvents, ServerEvents, ServerSnapshotEntry, ServerSummary};
+pub use server::{
+    NoopServerEvents, ServerEvents, ServerSnapshotEntry, ServerStopReason, ServerSummary,
+};
 
 // Import download types for AppEvent::Download wrapper
+use crate::domain::backup::BackupDirection;
+use crate::domain::onboarding::{OnboardingPhase, OnboardingStep};
 use crate::download::DownloadEvent;
+use crate::jobs::Job;
 
 /// Canonical event types for all adapters.
 ///
 /// This enum unifies server, download, and model events into a single
 /// discriminated union. Each variant includes all necessary context
 /// for the event to be self-describing.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, TS, ToSchema)]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum AppEvent {
     // ========== Server Events ==========
@@ -45,6 +52,24 @@ pub enum AppEvent {
         /// Name of the model that was being served.
         #[serde(rename = "modelName")]
         model_name: String,
+        /// Why the server stopped.
+        #[serde(default)]
+        reason: ServerStopReason,
+    },
+
+    /// A model server will be stopped soon because it has gone unused.
+    ///
+    /// Any request before the deadline keeps the server running.
+    ServerIdleWarning {
+        /// ID of the model being served.
+        #[serde(rename = "modelId")]
+        model_id: i64,
+        /// Name of the model being served.
+        #[serde(rename = "modelName")]
+        model_name: String,
+        /// Seconds until the server is stopped if no request arrives.
+        #[serde(rename = "shutdownInSecs")]
+        shutdown_in_secs: u64,
     },
 
     /// A model server encountered an error.
@@ -143,6 +168,7 @@ pub enum AppEvent {
         status: crate::ports::ServerHealthStatus,
         /// Optional detail message (e.g., error description).
         #[serde(skip_serializing_if = "Option::is_none")]
+        #[ts(optional)]
         detail: Option<String>,
         /// Unix timestamp in milliseconds when status changed.
         timestamp: u64,
@@ -200,6 +226,80 @@ pub enum AppEvent {
 
     /// The proxy crashed (task exited without cancellation).
     ProxyCrashed,
+
+    /// A running proxy applied changed settings without restarting.
+    ProxyConfigReloaded {
+        /// Context size models are now launched with when a request does
+        /// not ask for one.
+        #[serde(rename = "defaultContext")]
+        default_context: u64,
+        /// Inference profiles now selectable as `{model}:{profile}`.
+        profiles: Vec<String>,
+    },
+
+    // ========== Onboarding Events ==========
+    /// Progress executing a first-run onboarding step.
+    OnboardingProgress {
+        /// Step being executed.
+        step: OnboardingStep,
+        /// Started, progress, completed or failed.
+        phase: OnboardingPhase,
+        /// Human-readable detail (or the error, when failed).
+        #[serde(skip_serializing_if = "Option::is_none")]
+        #[ts(optional)]
+        message: Option<String>,
+        /// Bytes done so far, for steps that transfer data.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        #[ts(optional)]
+        downloaded: Option<u64>,
+        /// Total bytes, when known.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        #[ts(optional)]
+        total: Option<u64>,
+    },
+
+    // ========== Backup Events ==========
+    /// Bytes moved so far by a backup upload or restore download.
+    ///
+    /// Emitted once per part while uploading and periodically while
+    /// downloading; `transferred == total` marks the object as done.
+    BackupProgress {
+        /// Upload to or download from the bucket.
+        direction: BackupDirection,
+        /// Object key being transferred.
+        key: String,
+        /// Bytes transferred so far.
+        transferred: u64,
+        /// Size of the object in bytes.
+        total: u64,
+    },
+
+    // ========== Job Events ==========
+    /// A tracked long-running operation started, progressed or finished.
+    ///
+    /// Carries the whole job so listeners can upsert it by `id`.
+    JobUpdated {
+        /// The job as it stands after the change.
+        job: Job,
+    },
+
+    // ========== Conversation Events ==========
+    /// A conversation's metadata changed outside the UI (e.g. auto-title).
+    ConversationUpdated {
+        /// ID of the updated conversation.
+        #[serde(rename = "conversationId")]
+        conversation_id: i64,
+        /// The conversation's new title.
+        title: String,
+    },
+
+    // ========== Settings Events ==========
+    /// Application settings were saved, by this process or another one
+    /// (e.g. the CLI writing next to an open GUI).
+    SettingsChanged {
+        /// Stored names of the changed settings (e.g. `proxy_port`), sorted.
+        keys: Vec<String>,
+    },
 }
 
 impl AppEvent {
@@ -210,6 +310,7 @@ impl AppEvent {
         match self {
             Self::ServerStarted { .. } => "server:started",
             Self::ServerStopped { .. } => "server:stopped",
+            Self::ServerIdleWarning { .. } => "server:idle_warning",
             Self::ServerError { .. } => "server:error",
             Self::ServerSnapshot { .. } => "server:snapshot",
             Self::ServerHealthChanged { .. } => "server:health_changed",
@@ -227,10 +328,41 @@ impl AppEvent {
             Self::ProxyStarted { .. } => "proxy:started",
             Self::ProxyStopped => "proxy:stopped",
             Self::ProxyCrashed => "proxy:crashed",
+            Self::ProxyConfigReloaded { .. } => "proxy:config_reloaded",
+            Self::OnboardingProgress { .. } => "onboarding:progress",
+            Self::BackupProgress { .. } => "backup:progress",
+            Self::JobUpdated { .. } => "job:updated",
+            Self::ConversationUpdated { .. } => "conversation:updated",
+            Self::SettingsChanged { .. } => "settings:changed",
         }
     }
+
+    /// Get the event's topic: the part of its [`event_name`](Self::event_name)
+    /// before the colon, one of [`EVENT_TOPICS`].
+    ///
+    /// Clients subscribe to topics rather than single events.
+    #[must_use]
+    pub fn topic(&self) -> &'static str {
+        let name = self.event_name();
+        name.split_once(':').map_or(name, |(topic, _)| topic)
+    }
 }
 
+/// Every [`AppEvent::topic`], for subscribing to a subset of events.
+pub const EVENT_TOPICS: &[&str] = &[
+    "server",
+    "download",
+    "model",
+    "verification",
+    "mcp",
+    "proxy",
+    "onboarding",
+    "backup",
+    "job",
+    "conversation",
+    "settings",
+];
+
 impl AppEvent {
     /// Create a [`ProxyStarted`] event.
     pub const fn proxy_started(port: u16) -> Self {
@@ -246,6 +378,60 @@ impl AppEvent {
     pub const fn proxy_crashed() -> Self {
         Self::ProxyCrashed
     }
+
+    /// Create a [`ProxyConfigReloaded`] event.
+    pub const fn proxy_config_reloaded(default_context: u64, profiles: Vec<String>) -> Self {
+        Self::ProxyConfigReloaded {
+            default_context,
+            profiles,
+        }
+    }
+
+    /// Create an [`OnboardingProgress`] event without byte counts.
+    pub fn onboarding(
+        step: OnboardingStep,
+        phase: OnboardingPhase,
+        message: Option<impl Into<String>>,
+    ) -> Self {
+        Self::OnboardingProgress {
+            step,
+            phase,
+            message: message.map(Into::into),
+            downloaded: None,
+            total: None,
+        }
+    }
+
+    /// Create a [`BackupProgress`] event.
+    pub fn backup_progress(
+        direction: BackupDirection,
+        key: impl Into<String>,
+        transferred: u64,
+        total: u64,
+    ) -> Self {
+        Self::BackupProgress {
+            direction,
+            key: key.into(),
+            transferred,
+            total,
+        }
+    }
+
+    /// Create a [`JobUpdated`] event.
+    pub const fn job_updated(job: Job) -> Self {
+        Self::JobUpdated { job }
+    }
+
+    /// Create an [`OnboardingProgress`] byte-progress event.
+    pub const fn onboarding_bytes(step: OnboardingStep, downloaded: u64, total: u64) -> Self {
+        Self::OnboardingProgress {
+            step,
+            phase: OnboardingPhase::Progress,
+            message: None,
+            downloaded: Some(downloaded),
+            total: Some(total),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -272,6 +458,48 @@ mod tests {
             "download:started"
         );
         assert_eq!(AppEvent::model_removed(1).event_name(), "model:removed");
+        assert_eq!(
+            AppEvent::onboarding_bytes(OnboardingStep::Binaries, 1, 2).event_name(),
+            "onboarding:progress"
+        );
+        assert_eq!(
+            AppEvent::conversation_updated(7, "Rust lifetimes").event_name(),
+            "conversation:updated"
+        );
+        assert_eq!(
+            AppEvent::settings_changed(vec!["proxy_port".to_string()]).event_name(),
+            "settings:changed"
+        );
+        assert_eq!(
+            AppEvent::backup_progress(BackupDirection::Upload, "k", 1, 2).event_name(),
+            "backup:progress"
+        );
+    }
+
+    #[test]
+    fn topics_are_event_name_prefixes() {
+        let events = [
+            AppEvent::server_started(1, "test", 8080),
+            AppEvent::download_started("id", "name"),
+            AppEvent::model_removed(1),
+            AppEvent::proxy_stopped(),
+            AppEvent::conversation_updated(7, "Rust lifetimes"),
+            AppEvent::settings_changed(Vec::new()),
+        ];
+        for event in &events {
+            assert!(EVENT_TOPICS.contains(&event.topic()), "{}", event.topic());
+            assert!(event.event_name().starts_with(event.topic()));
+        }
+        assert_eq!(events[1].topic(), "download");
+    }
+
+    #[test]
+    fn conversation_updated_uses_camel_case_id() {
+        let json =
+            serde_json::to_value(AppEvent::conversation_updated(7, "Rust lifetimes")).unwrap();
+        assert_eq!(json["type"], "conversation_updated");
+        assert_eq!(json["conversationId"], 7);
+        assert_eq!(json["title"], "Rust lifetimes");
     }
 
     /// Lock down download event names to prevent frontend subscription mismatches.
@@ -296,6 +524,18 @@ mod tests {
             ),
             (AppEvent::download_failed("id", "error"), "download:failed"),
             (AppEvent::download_cancelled("id"), "download:cancelled"),
+            (
+                AppEvent::Download {
+                    event: DownloadEvent::phase_progress(
+                        "id",
+                        crate::download::DownloadPhase::Verifying,
+                        None,
+                        0,
+                        100,
+                    ),
+                },
+                "download:phase",
+            ),
         ];
 
         for (event, expected_name) in cases {
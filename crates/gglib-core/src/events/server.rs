@@ -1,6 +1,8 @@
 //! Model server lifecycle events.
 
 use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+use utoipa::ToSchema;
 
 use super::AppEvent;
 
@@ -34,6 +36,19 @@ impl ServerSummary {
     }
 }
 
+/// Why a model server stopped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, TS, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ServerStopReason {
+    /// A user or client asked for the server to stop.
+    #[default]
+    Requested,
+    /// The server went unused for its configured idle timeout.
+    Idle,
+    /// The health monitor gave up on the server and restarted it.
+    Unhealthy,
+}
+
 /// Port for emitting server lifecycle events.
 ///
 /// This trait decouples the core server lifecycle logic from transport-specific
@@ -49,7 +64,7 @@ impl ServerSummary {
 /// # Example
 ///
 /// ```rust
-/// use gglib_core::events::{ServerEvents, ServerSummary};
+/// use gglib_core::events::{ServerEvents, ServerStopReason, ServerSummary};
 ///
 /// struct LoggingEvents;
 ///
@@ -60,8 +75,8 @@ impl ServerSummary {
 ///     fn stopping(&self, server: &ServerSummary) {
 ///         println!("Stopping server {}", server.model_name);
 ///     }
-///     fn stopped(&self, server: &ServerSummary) {
-///         println!("Server {} stopped", server.model_name);
+///     fn stopped(&self, server: &ServerSummary, reason: ServerStopReason) {
+///         println!("Server {} stopped ({reason:?})", server.model_name);
 ///     }
 ///     fn snapshot(&self, servers: &[ServerSummary]) {
 ///         println!("Server snapshot: {} running", servers.len());
@@ -78,8 +93,8 @@ pub trait ServerEvents: Send + Sync {
     /// Called just before stopping a server.
     fn stopping(&self, server: &ServerSummary);
 
-    /// Called after a server has stopped.
-    fn stopped(&self, server: &ServerSummary);
+    /// Called after a server has stopped, with the reason it was stopped.
+    fn stopped(&self, server: &ServerSummary, reason: ServerStopReason);
 
     /// Called to broadcast the current state of all running servers.
     fn snapshot(&self, servers: &[ServerSummary]);
@@ -98,13 +113,13 @@ pub struct NoopServerEvents;
 impl ServerEvents for NoopServerEvents {
     fn started(&self, _server: &ServerSummary) {}
     fn stopping(&self, _server: &ServerSummary) {}
-    fn stopped(&self, _server: &ServerSummary) {}
+    fn stopped(&self, _server: &ServerSummary, _reason: ServerStopReason) {}
     fn snapshot(&self, _servers: &[ServerSummary]) {}
     fn error(&self, _server: &ServerSummary, _error: &str) {}
 }
 
 /// Entry in a server snapshot.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, TS, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct ServerSnapshotEntry {
     /// Model ID being served.
@@ -130,10 +145,28 @@ impl AppEvent {
     }
 
     /// Create a server stopped event.
-    pub fn server_stopped(model_id: i64, model_name: impl Into<String>) -> Self {
+    pub fn server_stopped(
+        model_id: i64,
+        model_name: impl Into<String>,
+        reason: ServerStopReason,
+    ) -> Self {
         Self::ServerStopped {
             model_id,
             model_name: model_name.into(),
+            reason,
+        }
+    }
+
+    /// Create a server idle warning event.
+    pub fn server_idle_warning(
+        model_id: i64,
+        model_name: impl Into<String>,
+        shutdown_in_secs: u64,
+    ) -> Self {
+        Self::ServerIdleWarning {
+            model_id,
+            model_name: model_name.into(),
+            shutdown_in_secs,
         }
     }
 
@@ -162,9 +195,9 @@ impl AppEvent {
     }
 
     /// Build a `ServerStopped` event from a `ServerSummary`.
-    pub fn from_server_stopped(server: &ServerSummary) -> Self {
+    pub fn from_server_stopped(server: &ServerSummary, reason: ServerStopReason) -> Self {
         let model_id = server.model_id.parse::<i64>().unwrap_or(0);
-        Self::server_stopped(model_id, &server.model_name)
+        Self::server_stopped(model_id, &server.model_name, reason)
     }
 
     /// Build a `ServerError` event from a `ServerSummary`.
@@ -228,19 +261,45 @@ mod tests {
     #[test]
     fn test_from_server_stopped() {
         let server = make_server("srv-1", "42", "test-model", 8080);
-        let event = AppEvent::from_server_stopped(&server);
+        let event = AppEvent::from_server_stopped(&server, ServerStopReason::Idle);
         match event {
             AppEvent::ServerStopped {
                 model_id,
                 model_name,
+                reason,
             } => {
                 assert_eq!(model_id, 42);
                 assert_eq!(model_name, "test-model");
+                assert_eq!(reason, ServerStopReason::Idle);
             }
             _ => panic!("expected ServerStopped"),
         }
     }
 
+    #[test]
+    fn test_server_stopped_reason_defaults_to_requested() {
+        let json = r#"{"type":"server_stopped","modelId":1,"modelName":"m"}"#;
+        let event: AppEvent = serde_json::from_str(json).expect("deserializes");
+        assert!(matches!(
+            event,
+            AppEvent::ServerStopped {
+                reason: ServerStopReason::Requested,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_server_idle_warning_serialization() {
+        let event = AppEvent::server_idle_warning(7, "test-model", 60);
+        assert_eq!(event.event_name(), "server:idle_warning");
+
+        let json = serde_json::to_value(&event).expect("serializes");
+        assert_eq!(json["type"], "server_idle_warning");
+        assert_eq!(json["modelId"], 7);
+        assert_eq!(json["shutdownInSecs"], 60);
+    }
+
     #[test]
     fn test_from_server_error() {
         let server = make_server("srv-1", "42", "test-model", 8080);
@@ -0,0 +1,209 @@
+//! Multi-GPU placement types for llama-server launches: split mode and
+//! tensor split.
+//!
+//! Kept beside `cpu_config` as a standalone module so device-split
+//! resolution (in `gglib-runtime`) and the launch DTOs that carry these
+//! values share one definition.
+
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+use utoipa::ToSchema;
+
+// =============================================================================
+// Split mode (`--split-mode`)
+// =============================================================================
+
+/// How llama.cpp spreads a model over several GPUs, as accepted by
+/// `--split-mode`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, TS, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum SplitMode {
+    /// Run on a single GPU, the one chosen by `--main-gpu`.
+    None,
+    /// Give each GPU a contiguous share of the layers (llama.cpp's default).
+    Layer,
+    /// Split each layer's weights across the GPUs by rows.
+    Row,
+}
+
+impl SplitMode {
+    /// The value passed on the command line.
+    #[must_use]
+    pub const fn as_llama_arg(self) -> &'static str {
+        match self {
+            Self::None => "none",
+            Self::Layer => "layer",
+            Self::Row => "row",
+        }
+    }
+}
+
+impl FromStr for SplitMode {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "none" => Ok(Self::None),
+            "layer" => Ok(Self::Layer),
+            "row" => Ok(Self::Row),
+            other => Err(format!(
+                "unknown split mode {other:?} (expected one of: none, layer, row)"
+            )),
+        }
+    }
+}
+
+// =============================================================================
+// Tensor split (`--tensor-split`)
+// =============================================================================
+
+/// Most devices a [`TensorSplit`] may name.
+///
+/// llama.cpp reads at most 16 proportions (`LLAMA_MAX_DEVICES`).
+pub const MAX_SPLIT_DEVICES: usize = 16;
+
+/// The share of the model each GPU receives, in device order.
+///
+/// Written and parsed in llama.cpp's comma-separated form (`3,1` or
+/// `0.75,0.25`) and serialized as that string. Proportions are relative:
+/// llama.cpp normalizes them, so only their ratios matter.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct TensorSplit(Vec<f32>);
+
+// Every proportion is finite (enforced by `new`), so equality is total.
+impl Eq for TensorSplit {}
+
+impl TensorSplit {
+    /// Build a split from per-device proportions.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there are no proportions or more than
+    /// [`MAX_SPLIT_DEVICES`], if any proportion is negative or not finite,
+    /// or if they are all zero.
+    pub fn new(proportions: impl IntoIterator<Item = f32>) -> Result<Self, String> {
+        let proportions: Vec<f32> = proportions.into_iter().collect();
+        if proportions.is_empty() {
+            return Err("tensor split cannot be empty".to_string());
+        }
+        if proportions.len() > MAX_SPLIT_DEVICES {
+            return Err(format!(
+                "tensor split names {} devices (maximum {MAX_SPLIT_DEVICES})",
+                proportions.len()
+            ));
+        }
+        if let Some(bad) = proportions.iter().find(|p| !p.is_finite() || **p < 0.0) {
+            return Err(format!("invalid tensor split proportion {bad}"));
+        }
+        if proportions.iter().all(|p| *p == 0.0) {
+            return Err("tensor split gives every device a zero share".to_string());
+        }
+        Ok(Self(proportions))
+    }
+
+    /// The proportions, in device order.
+    #[must_use]
+    pub fn proportions(&self) -> &[f32] {
+        &self.0
+    }
+
+    /// Number of devices the split names.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Always `false`: a split names at least one device.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl fmt::Display for TensorSplit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, p) in self.0.iter().enumerate() {
+            if i > 0 {
+                f.write_str(",")?;
+            }
+            write!(f, "{p}")?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for TensorSplit {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let proportions = s
+            .split([',', '/'])
+            .map(|part| {
+                part.trim().parse::<f32>().map_err(|_| {
+                    format!("invalid proportion {:?} in tensor split {s:?}", part.trim())
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Self::new(proportions)
+    }
+}
+
+impl TryFrom<String> for TensorSplit {
+    type Error = String;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl From<TensorSplit> for String {
+    fn from(split: TensorSplit) -> Self {
+        split.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn split_mode_from_str_round_trips_through_as_llama_arg() {
+        for m in [SplitMode::None, SplitMode::Layer, SplitMode::Row] {
+            assert_eq!(SplitMode::from_str(m.as_llama_arg()), Ok(m));
+        }
+        assert!(SplitMode::from_str("tensor").is_err());
+    }
+
+    #[test]
+    fn tensor_split_parses_commas_and_slashes() {
+        let split: TensorSplit = "3, 1".parse().unwrap();
+        assert_eq!(split.proportions(), &[3.0, 1.0]);
+        assert_eq!(split.to_string(), "3,1");
+        assert_eq!(
+            "0.6/0.4".parse::<TensorSplit>().unwrap().to_string(),
+            "0.6,0.4"
+        );
+    }
+
+    #[test]
+    fn tensor_split_rejects_bad_input() {
+        assert!("".parse::<TensorSplit>().is_err());
+        assert!("1,x".parse::<TensorSplit>().is_err());
+        assert!("1,-1".parse::<TensorSplit>().is_err());
+        assert!("0,0".parse::<TensorSplit>().is_err());
+        assert!("1,NaN".parse::<TensorSplit>().is_err());
+        assert!(TensorSplit::new(vec![1.0; MAX_SPLIT_DEVICES + 1]).is_err());
+    }
+
+    #[test]
+    fn tensor_split_serializes_as_list_string() {
+        let split = TensorSplit::new([2.0, 1.0, 1.0]).unwrap();
+        let json = serde_json::to_string(&split).unwrap();
+        assert_eq!(json, "\"2,1,1\"");
+        assert_eq!(serde_json::from_str::<TensorSplit>(&json).unwrap(), split);
+    }
+}
@@ -0,0 +1,730 @@
+//! Long-running operations tracked in one place.
+//!
+//! Downloads, model verification, llama.cpp builds and capability
+//! re-detection each report progress through their own channels.
+//! [`JobManager`] gives them a shared record on top of that: every operation
+//! registers a [`Job`] when it starts, reports progress and its outcome
+//! through the returned [`JobHandle`], and every change is announced as
+//! [`AppEvent::JobUpdated`]. The subsystem-specific
+//! events keep flowing unchanged; jobs are the one view of everything in
+//! flight (`GET /api/jobs`, `gglib jobs`).
+//!
+//! Cancellation is cooperative. [`JobManager::cancel`] only flags the job;
+//! the worker observes it through [`JobHandle::cancelled`], stops, and
+//! reports [`JobHandle::cancelled_by_request`]. Jobs started without
+//! [`JobSpec::cancellable`] refuse to be cancelled.
+//!
+//! Downloads are adopted from the outside: [`DownloadJobTracker`] sits in
+//! front of the download manager's event emitter and turns its events into
+//! job updates, so the manager itself knows nothing about jobs.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use tokio::sync::watch;
+use ts_rs::TS;
+use utoipa::ToSchema;
+
+use crate::download::DownloadEvent;
+use crate::events::AppEvent;
+use crate::ports::{AppEventEmitter, DownloadEventEmitterPort};
+
+/// Finished jobs kept for listing; older ones are dropped first.
+pub const MAX_FINISHED_JOBS: usize = 50;
+
+// ============================================================================
+// Wire types
+// ============================================================================
+
+/// The subsystem running a job.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, TS, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum JobKind {
+    /// A model download from the queue.
+    Download,
+    /// SHA256 verification of a model's files.
+    Verification,
+    /// A llama.cpp build from source.
+    LlamaBuild,
+    /// Re-running capability detection on models an older detector saw.
+    CapabilityDetection,
+}
+
+impl JobKind {
+    /// Short label for tables and logs.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Download => "download",
+            Self::Verification => "verification",
+            Self::LlamaBuild => "llama_build",
+            Self::CapabilityDetection => "capability_detection",
+        }
+    }
+}
+
+/// Where a job is in its lifecycle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, TS, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum JobState {
+    /// Still working.
+    Running,
+    /// Finished successfully.
+    Completed,
+    /// Finished with an error; see [`Job::error`].
+    Failed,
+    /// Stopped at a user's request.
+    Cancelled,
+}
+
+impl JobState {
+    /// Whether the job has finished, one way or another.
+    #[must_use]
+    pub const fn is_finished(self) -> bool {
+        !matches!(self, Self::Running)
+    }
+
+    /// Short label for tables and logs.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Running => "running",
+            Self::Completed => "completed",
+            Self::Failed => "failed",
+            Self::Cancelled => "cancelled",
+        }
+    }
+}
+
+/// How far a job has got.
+///
+/// `current` and `total` share whatever unit the subsystem counts in —
+/// bytes for downloads and verification, compiled files for builds.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, TS, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct JobProgress {
+    /// Units done so far.
+    pub current: u64,
+    /// Units in total; `None` while unknown.
+    pub total: Option<u64>,
+    /// What the job is doing right now, e.g. a phase name.
+    pub message: Option<String>,
+}
+
+impl JobProgress {
+    /// Whole percent complete, when the total is known.
+    #[must_use]
+    pub fn percent(&self) -> Option<u8> {
+        let total = self.total.filter(|&t| t > 0)?;
+        let percent = self.current.min(total).saturating_mul(100) / total;
+        Some(u8::try_from(percent).unwrap_or(100))
+    }
+}
+
+/// A snapshot of one tracked operation.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, TS, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct Job {
+    /// Unique ID assigned when the job started.
+    pub id: String,
+    /// Subsystem running the job.
+    pub kind: JobKind,
+    /// Human-readable description, e.g. the model being downloaded.
+    pub label: String,
+    /// The subsystem's own ID for the work (download ID, model ID), if any.
+    pub subject: Option<String>,
+    /// Lifecycle state.
+    pub state: JobState,
+    /// Latest progress report.
+    pub progress: JobProgress,
+    /// Whether [`JobManager::cancel`] is accepted for this job.
+    pub cancellable: bool,
+    /// Set once a cancel has been requested, until the job stops.
+    pub cancel_requested: bool,
+    /// Error message when the job failed.
+    pub error: Option<String>,
+    /// When the job started (milliseconds since epoch).
+    pub started_at_ms: u64,
+    /// When the job finished (milliseconds since epoch).
+    pub finished_at_ms: Option<u64>,
+}
+
+/// Errors from [`JobManager::cancel`].
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum JobError {
+    /// No job with this ID is known (it may have aged out).
+    #[error("Job not found: {0}")]
+    NotFound(String),
+    /// The job has already finished.
+    #[error("Job {0} has already finished")]
+    AlreadyFinished(String),
+    /// The job's subsystem cannot stop it part-way.
+    #[error("Job {0} cannot be cancelled")]
+    NotCancellable(String),
+}
+
+// ============================================================================
+// JobSpec
+// ============================================================================
+
+/// Description of a job about to start.
+#[derive(Debug, Clone)]
+pub struct JobSpec {
+    kind: JobKind,
+    label: String,
+    subject: Option<String>,
+    cancellable: bool,
+}
+
+impl JobSpec {
+    /// A job of `kind`, described by `label`.
+    pub fn new(kind: JobKind, label: impl Into<String>) -> Self {
+        Self {
+            kind,
+            label: label.into(),
+            subject: None,
+            cancellable: false,
+        }
+    }
+
+    /// Record the subsystem's own ID for the work.
+    #[must_use]
+    pub fn with_subject(mut self, subject: impl Into<String>) -> Self {
+        self.subject = Some(subject.into());
+        self
+    }
+
+    /// Accept cancel requests. The worker must watch
+    /// [`JobHandle::cancelled`] for them.
+    #[must_use]
+    pub const fn cancellable(mut self) -> Self {
+        self.cancellable = true;
+        self
+    }
+}
+
+// ============================================================================
+// JobManager
+// ============================================================================
+
+struct Entry {
+    job: Job,
+    cancel: watch::Sender<bool>,
+    /// Last percent announced, so byte-level progress does not flood
+    /// listeners with an event per chunk.
+    announced_percent: Option<u8>,
+}
+
+/// Registry of running and recently finished jobs.
+pub struct JobManager {
+    /// Insertion-ordered: oldest first.
+    entries: Mutex<Vec<Entry>>,
+    emitter: Arc<dyn AppEventEmitter>,
+}
+
+impl JobManager {
+    /// Create a manager that announces job changes through `emitter`.
+    pub fn new(emitter: Arc<dyn AppEventEmitter>) -> Self {
+        Self {
+            entries: Mutex::new(Vec::new()),
+            emitter,
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Vec<Entry>> {
+        // A panic while holding the lock cannot leave a half-written entry
+        // behind, so a poisoned table is still safe to use.
+        self.entries
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+
+    /// Register a new running job and return the handle its worker reports
+    /// through.
+    pub fn start(self: &Arc<Self>, spec: JobSpec) -> JobHandle {
+        let (cancel, cancel_rx) = watch::channel(false);
+        let job = Job {
+            id: uuid::Uuid::new_v4().to_string(),
+            kind: spec.kind,
+            label: spec.label,
+            subject: spec.subject,
+            state: JobState::Running,
+            progress: JobProgress::default(),
+            cancellable: spec.cancellable,
+            cancel_requested: false,
+            error: None,
+            started_at_ms: now_ms(),
+            finished_at_ms: None,
+        };
+        let id = job.id.clone();
+        self.lock().push(Entry {
+            job: job.clone(),
+            cancel,
+            announced_percent: None,
+        });
+        tracing::debug!(job_id = %id, kind = job.kind.as_str(), label = %job.label, "job started");
+        self.emitter.emit(AppEvent::job_updated(job));
+
+        JobHandle {
+            id,
+            manager: Arc::clone(self),
+            cancel_rx,
+            finished: false,
+        }
+    }
+
+    /// Every known job, newest first.
+    pub fn list(&self) -> Vec<Job> {
+        self.lock().iter().rev().map(|e| e.job.clone()).collect()
+    }
+
+    /// The job with `id`, if it is still known.
+    pub fn get(&self, id: &str) -> Option<Job> {
+        self.lock()
+            .iter()
+            .find(|e| e.job.id == id)
+            .map(|e| e.job.clone())
+    }
+
+    /// Ask a running job to stop.
+    ///
+    /// Returns the job as it stands; it keeps [`JobState::Running`] until
+    /// its worker has actually stopped.
+    pub fn cancel(&self, id: &str) -> Result<Job, JobError> {
+        let mut entries = self.lock();
+        let entry = entries
+            .iter_mut()
+            .find(|e| e.job.id == id)
+            .ok_or_else(|| JobError::NotFound(id.to_owned()))?;
+        if entry.job.state.is_finished() {
+            return Err(JobError::AlreadyFinished(id.to_owned()));
+        }
+        if !entry.job.cancellable {
+            return Err(JobError::NotCancellable(id.to_owned()));
+        }
+        if entry.job.cancel_requested {
+            return Ok(entry.job.clone());
+        }
+        entry.job.cancel_requested = true;
+        entry.cancel.send_replace(true);
+        let job = entry.job.clone();
+        drop(entries);
+
+        tracing::info!(job_id = %id, kind = job.kind.as_str(), "job cancel requested");
+        self.emitter.emit(AppEvent::job_updated(job.clone()));
+        Ok(job)
+    }
+
+    /// Apply `update` to a running job and announce it unless `update`
+    /// says the change is not worth an event.
+    fn update(&self, id: &str, update: impl FnOnce(&mut Entry) -> bool) {
+        let mut entries = self.lock();
+        let Some(entry) = entries
+            .iter_mut()
+            .find(|e| e.job.id == id && !e.job.state.is_finished())
+        else {
+            return;
+        };
+        let announced = update(entry).then(|| entry.job.clone());
+        drop(entries);
+
+        if let Some(job) = announced {
+            self.emitter.emit(AppEvent::job_updated(job));
+        }
+    }
+
+    fn finish(&self, id: &str, state: JobState, error: Option<String>) {
+        let mut entries = self.lock();
+        let Some(entry) = entries
+            .iter_mut()
+            .find(|e| e.job.id == id && !e.job.state.is_finished())
+        else {
+            return;
+        };
+        entry.job.state = state;
+        entry.job.error = error;
+        entry.job.finished_at_ms = Some(now_ms());
+        if state == JobState::Completed
+            && let Some(total) = entry.job.progress.total
+        {
+            entry.job.progress.current = total;
+        }
+        let job = entry.job.clone();
+        prune_finished(&mut entries);
+        drop(entries);
+
+        tracing::debug!(job_id = %id, state = state.as_str(), "job finished");
+        self.emitter.emit(AppEvent::job_updated(job));
+    }
+}
+
+/// Drop the oldest finished jobs beyond [`MAX_FINISHED_JOBS`].
+fn prune_finished(entries: &mut Vec<Entry>) {
+    let finished = entries.iter().filter(|e| e.job.state.is_finished()).count();
+    let mut excess = finished.saturating_sub(MAX_FINISHED_JOBS);
+    entries.retain(|e| {
+        if excess > 0 && e.job.state.is_finished() {
+            excess -= 1;
+            false
+        } else {
+            true
+        }
+    });
+}
+
+fn now_ms() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_millis().try_into().unwrap_or(u64::MAX))
+}
+
+// ============================================================================
+// JobHandle
+// ============================================================================
+
+/// A worker's end of a [`Job`].
+///
+/// Report the outcome with [`complete`](Self::complete),
+/// [`fail`](Self::fail) or [`cancelled_by_request`](Self::cancelled_by_request).
+/// A handle dropped without any of them marks the job failed, so a worker
+/// that bails out early never leaves a job running forever.
+pub struct JobHandle {
+    id: String,
+    manager: Arc<JobManager>,
+    cancel_rx: watch::Receiver<bool>,
+    finished: bool,
+}
+
+impl JobHandle {
+    /// The job's ID.
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Report progress. Events are announced when the whole percent changes
+    /// (or, with no total, when `current` does), not on every call.
+    pub fn progress(&self, current: u64, total: Option<u64>) {
+        self.manager.update(&self.id, |entry| {
+            let progress = &mut entry.job.progress;
+            let changed = progress.current != current || progress.total != total;
+            progress.current = current;
+            progress.total = total;
+            let percent = progress.percent();
+            if percent.is_none() {
+                return changed;
+            }
+            let announce = percent != entry.announced_percent;
+            entry.announced_percent = percent;
+            announce
+        });
+    }
+
+    /// Describe what the job is doing now.
+    pub fn message(&self, message: impl Into<String>) {
+        let message = message.into();
+        self.manager.update(&self.id, |entry| {
+            if entry.job.progress.message.as_deref() == Some(message.as_str()) {
+                return false;
+            }
+            entry.job.progress.message = Some(message);
+            true
+        });
+    }
+
+    /// Whether a cancel has been requested.
+    pub fn is_cancel_requested(&self) -> bool {
+        *self.cancel_rx.borrow()
+    }
+
+    /// Resolve once a cancel is requested. Never resolves for jobs that are
+    /// not cancellable.
+    pub async fn cancelled(&self) {
+        let mut rx = self.cancel_rx.clone();
+        if rx.wait_for(|&cancelled| cancelled).await.is_err() {
+            // The manager outlives its handles; should the sender go away
+            // anyway, no cancel can arrive any more.
+            std::future::pending::<()>().await;
+        }
+    }
+
+    /// The job finished successfully.
+    pub fn complete(mut self) {
+        self.finish(JobState::Completed, None);
+    }
+
+    /// The job failed with `error`.
+    pub fn fail(mut self, error: impl Into<String>) {
+        self.finish(JobState::Failed, Some(error.into()));
+    }
+
+    /// The job stopped because a cancel was requested.
+    pub fn cancelled_by_request(mut self) {
+        self.finish(JobState::Cancelled, None);
+    }
+
+    fn finish(&mut self, state: JobState, error: Option<String>) {
+        self.finished = true;
+        self.manager.finish(&self.id, state, error);
+    }
+}
+
+impl Drop for JobHandle {
+    fn drop(&mut self) {
+        if !self.finished {
+            self.finish(
+                JobState::Failed,
+                Some("stopped without reporting a result".to_owned()),
+            );
+        }
+    }
+}
+
+// ============================================================================
+// Download adoption
+// ============================================================================
+
+/// Download event emitter that also tracks each download as a [`Job`].
+///
+/// Wraps the emitter handed to the download manager: every event is
+/// forwarded unchanged, and the per-download ones drive a job keyed by the
+/// download's canonical ID. Cancelling such a job has to go through the
+/// download manager, which is what `subject` is for.
+#[derive(Clone)]
+pub struct DownloadJobTracker {
+    inner: Arc<dyn DownloadEventEmitterPort>,
+    jobs: Arc<JobManager>,
+    active: Arc<Mutex<HashMap<String, JobHandle>>>,
+}
+
+impl DownloadJobTracker {
+    /// Track downloads in `jobs` while forwarding their events to `inner`.
+    pub fn new(inner: Arc<dyn DownloadEventEmitterPort>, jobs: Arc<JobManager>) -> Self {
+        Self {
+            inner,
+            jobs,
+            active: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    fn track(&self, event: &DownloadEvent) {
+        let mut active = self
+            .active
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        match event {
+            DownloadEvent::DownloadStarted { id, .. } => {
+                // Sharded downloads announce a start per shard; one job
+                // covers the whole group.
+                active.entry(id.clone()).or_insert_with(|| {
+                    self.jobs.start(
+                        JobSpec::new(JobKind::Download, id.clone())
+                            .with_subject(id.clone())
+                            .cancellable(),
+                    )
+                });
+            }
+            DownloadEvent::DownloadProgress {
+                id,
+                downloaded,
+                total,
+                ..
+            } => {
+                if let Some(job) = active.get(id) {
+                    job.progress(*downloaded, Some(*total));
+                }
+            }
+            DownloadEvent::ShardProgress {
+                id,
+                aggregate_downloaded,
+                aggregate_total,
+                ..
+            } => {
+                if let Some(job) = active.get(id) {
+                    job.progress(*aggregate_downloaded, Some(*aggregate_total));
+                }
+            }
+            DownloadEvent::PhaseProgress { id, phase, .. } => {
+                if let Some(job) = active.get(id) {
+                    job.message(phase.label());
+                }
+            }
+            DownloadEvent::DownloadNotice { id, message } => {
+                if let Some(job) = active.get(id) {
+                    job.message(message.clone());
+                }
+            }
+            DownloadEvent::DownloadCompleted { id, .. } => {
+                if let Some(job) = active.remove(id) {
+                    job.complete();
+                }
+            }
+            DownloadEvent::DownloadFailed { id, error } => {
+                if let Some(job) = active.remove(id) {
+                    job.fail(error.clone());
+                }
+            }
+            DownloadEvent::DownloadCancelled { id } => {
+                if let Some(job) = active.remove(id) {
+                    job.cancelled_by_request();
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+impl DownloadEventEmitterPort for DownloadJobTracker {
+    fn emit(&self, event: DownloadEvent) {
+        self.track(&event);
+        self.inner.emit(event);
+    }
+
+    fn clone_box(&self) -> Box<dyn DownloadEventEmitterPort> {
+        Box::new(self.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::ports::NoopDownloadEmitter;
+
+    /// Records every emitted job snapshot.
+    #[derive(Clone, Default)]
+    struct Recorder(Arc<Mutex<Vec<Job>>>);
+
+    impl Recorder {
+        fn jobs(&self) -> Vec<Job> {
+            self.0.lock().unwrap().clone()
+        }
+    }
+
+    impl AppEventEmitter for Recorder {
+        fn emit(&self, event: AppEvent) {
+            if let AppEvent::JobUpdated { job } = event {
+                self.0.lock().unwrap().push(job);
+            }
+        }
+
+        fn clone_box(&self) -> Box<dyn AppEventEmitter> {
+            Box::new(self.clone())
+        }
+    }
+
+    fn manager() -> (Arc<JobManager>, Recorder) {
+        let recorder = Recorder::default();
+        (
+            Arc::new(JobManager::new(Arc::new(recorder.clone()))),
+            recorder,
+        )
+    }
+
+    #[test]
+    fn progress_is_announced_per_whole_percent() {
+        let (jobs, recorder) = manager();
+        let job = jobs.start(JobSpec::new(JobKind::Verification, "model"));
+        for bytes in 0..=1000 {
+            job.progress(bytes, Some(1000));
+        }
+        job.complete();
+
+        let events = recorder.jobs();
+        // started + 101 percent steps + completed
+        assert_eq!(events.len(), 103);
+        let last = events.last().unwrap();
+        assert_eq!(last.state, JobState::Completed);
+        assert_eq!(last.progress.percent(), Some(100));
+        assert!(last.finished_at_ms.is_some());
+    }
+
+    #[test]
+    fn dropped_handle_fails_the_job() {
+        let (jobs, _) = manager();
+        let id = {
+            let job = jobs.start(JobSpec::new(JobKind::LlamaBuild, "build"));
+            job.id().to_owned()
+        };
+        let job = jobs.get(&id).unwrap();
+        assert_eq!(job.state, JobState::Failed);
+        assert!(job.error.is_some());
+    }
+
+    #[tokio::test]
+    async fn cancel_is_cooperative() {
+        let (jobs, _) = manager();
+        let fixed = jobs.start(JobSpec::new(JobKind::LlamaBuild, "build"));
+        assert_eq!(
+            jobs.cancel(fixed.id()),
+            Err(JobError::NotCancellable(fixed.id().to_owned()))
+        );
+
+        let job = jobs.start(JobSpec::new(JobKind::Verification, "model").cancellable());
+        let requested = jobs.cancel(job.id()).unwrap();
+        assert!(requested.cancel_requested);
+        assert_eq!(requested.state, JobState::Running);
+
+        job.cancelled().await;
+        let id = job.id().to_owned();
+        job.cancelled_by_request();
+        assert_eq!(jobs.get(&id).unwrap().state, JobState::Cancelled);
+        assert_eq!(jobs.cancel(&id), Err(JobError::AlreadyFinished(id)));
+        fixed.complete();
+    }
+
+    #[test]
+    fn finished_jobs_are_pruned_oldest_first() {
+        let (jobs, _) = manager();
+        let running = jobs.start(JobSpec::new(JobKind::LlamaBuild, "still going"));
+        for i in 0..MAX_FINISHED_JOBS + 5 {
+            jobs.start(JobSpec::new(JobKind::Verification, format!("job {i}")))
+                .complete();
+        }
+
+        let listed = jobs.list();
+        assert_eq!(listed.len(), MAX_FINISHED_JOBS + 1);
+        assert_eq!(listed[0].label, format!("job {}", MAX_FINISHED_JOBS + 4));
+        assert!(listed.iter().any(|j| j.id == running.id()));
+        assert!(!listed.iter().any(|j| j.label == "job 4"));
+        running.complete();
+    }
+
+    #[test]
+    fn download_events_drive_one_job_per_download() {
+        let (jobs, _) = manager();
+        let tracker = DownloadJobTracker::new(Arc::new(NoopDownloadEmitter::new()), jobs.clone());
+
+        for shard in 0..2 {
+            tracker.emit(DownloadEvent::DownloadStarted {
+                id: "org/model:Q4_K_M".to_owned(),
+                shard_index: Some(shard),
+                total_shards: Some(2),
+            });
+        }
+        tracker.emit(DownloadEvent::DownloadProgress {
+            id: "org/model:Q4_K_M".to_owned(),
+            downloaded: 50,
+            total: 200,
+            speed_bps: None,
+            eta_seconds: None,
+            percentage: 25.0,
+        });
+
+        let listed = jobs.list();
+        assert_eq!(listed.len(), 1);
+        assert_eq!(listed[0].kind, JobKind::Download);
+        assert_eq!(listed[0].subject.as_deref(), Some("org/model:Q4_K_M"));
+        assert_eq!(listed[0].progress.percent(), Some(25));
+
+        tracker.emit(DownloadEvent::DownloadFailed {
+            id: "org/model:Q4_K_M".to_owned(),
+            error: "disk full".to_owned(),
+        });
+        let job = jobs.get(&listed[0].id).unwrap();
+        assert_eq!(job.state, JobState::Failed);
+        assert_eq!(job.error.as_deref(), Some("disk full"));
+    }
+}
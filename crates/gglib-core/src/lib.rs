@@ -4,12 +4,17 @@
 pub mod cache_config;
 pub mod cache_metrics;
 pub mod contracts;
+pub mod cpu_config;
 pub mod domain;
 pub mod download;
 pub mod events;
+pub mod gpu_config;
+pub mod jobs;
 pub mod normalize;
 pub mod paths;
 pub mod ports;
+pub mod progress;
+pub mod readiness;
 pub mod request_pipeline;
 pub mod server_config;
 pub mod services;
@@ -30,12 +35,13 @@ pub use domain::{
     ModelFilterOptions, NewConversation, NewMcpServer, NewMessage, NewModel, NodeId, NodeStatus,
     RangeValues, SEARCH_RESULTS_CAP, TaskGraph, TaskGraphError, TaskNode, ToolCall, ToolDefinition,
     ToolIndex, ToolResult, ToolSummary, UpdateMcpServer, capabilities_from_architecture,
-    infer_from_chat_template, transform_messages_for_capabilities,
+    capabilities_from_gguf, infer_from_chat_template, transform_messages_for_capabilities,
 };
 pub use download::{
-    AttemptCounts, CompletionDetail, CompletionKey, CompletionKind, DownloadError, DownloadEvent,
-    DownloadId, DownloadResult, DownloadStatus, DownloadSummary, FailedDownload, Quantization,
-    QueueRunSummary, QueueSnapshot, QueuedDownload, ShardInfo,
+    AttemptCounts, CollectionQueueResult, CompletionDetail, CompletionKey, CompletionKind,
+    DownloadError, DownloadEvent, DownloadHistoryFilter, DownloadId, DownloadPhase, DownloadResult,
+    DownloadStatus, DownloadSummary, FailedDownload, GroupProgress, Quantization, QueueRunSummary,
+    QueueSnapshot, QueuedDownload, ShardInfo,
 };
 pub use events::{AppEvent, McpServerSummary, ModelSummary, ServerSnapshotEntry};
 pub use ports::{
@@ -45,11 +51,11 @@ pub use ports::{
     DownloadStateRepositoryPort, EmptyToolExecutor, FilteredToolExecutor, GgufCapabilities,
     GgufMetadata, GgufParseError, GgufParserPort, HfClientPort, HfFileInfo, HfPortError,
     HfQuantInfo, HfRepoInfo, HfSearchOptions, HfSearchResult, LlmCompletionPort, McpErrorCategory,
-    McpErrorInfo, McpRepositoryError, McpServerRepository, McpServiceError, ModelRegistrarPort,
-    ModelRepository, NoopDownloadEmitter, NoopEmitter, NoopGgufParser, ProcessError, ProcessHandle,
-    ProcessRunner, QuantizationResolver, Repos, RepositoryError, Resolution, ResolvedFile,
-    ResponseFormat, ServerConfig, ServerHealth, SettingsRepository, StructuredOutputError,
-    TOOL_NOT_AVAILABLE_MSG, ToolExecutorPort,
+    McpErrorInfo, McpRepositoryError, McpServerRepository, McpServiceError, ModelHub, ModelHubPort,
+    ModelRegistrarPort, ModelRepository, ModelTrashRepository, NoopDownloadEmitter, NoopEmitter,
+    NoopGgufParser, ProcessError, ProcessHandle, ProcessRunner, QuantizationResolver, Repos,
+    RepositoryError, Resolution, ResolvedFile, ResponseFormat, ServerConfig, ServerHealth,
+    SettingsRepository, StructuredOutputError, TOOL_NOT_AVAILABLE_MSG, ToolExecutorPort,
 };
 pub use services::{ChatHistoryService, ModelRegistrar};
 pub use settings::{
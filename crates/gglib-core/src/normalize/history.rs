@@ -73,7 +73,7 @@ pub fn strip_thinking_debt(messages: &mut [Value]) -> usize {
 /// Matching is case-sensitive: each `<think>` is paired with the next
 /// `</think>` that follows it. An unclosed `<think>` is left intact (the
 /// upstream model is responsible for closing it).
-fn strip_think_blocks(s: &str) -> Option<String> {
+pub(crate) fn strip_think_blocks(s: &str) -> Option<String> {
     const OPEN: &str = "<think>";
     const CLOSE: &str = "</think>";
 
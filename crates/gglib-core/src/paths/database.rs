@@ -3,7 +3,7 @@
 //! Provides the canonical path to the gglib `SQLite` database file.
 
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use super::error::PathError;
 use super::platform::data_root;
@@ -25,6 +25,17 @@ pub fn database_path() -> Result<PathBuf, PathError> {
     Ok(data_dir.join("gglib.db"))
 }
 
+/// Path a restored database is staged at until the next start.
+///
+/// The live database cannot be replaced while it is open, so a restore
+/// writes `gglib.db.restore` next to it and database setup swaps it in.
+#[must_use]
+pub fn pending_restore_path(db_path: &Path) -> PathBuf {
+    let mut name = db_path.file_name().unwrap_or_default().to_os_string();
+    name.push(".restore");
+    db_path.with_file_name(name)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -36,4 +47,12 @@ mod tests {
         let path = result.unwrap();
         assert!(path.to_string_lossy().ends_with("gglib.db"));
     }
+
+    #[test]
+    fn test_pending_restore_path_sits_next_to_the_database() {
+        assert_eq!(
+            pending_restore_path(Path::new("/data/gglib.db")),
+            Path::new("/data/gglib.db.restore")
+        );
+    }
 }
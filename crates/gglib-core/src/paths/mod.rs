@@ -7,6 +7,7 @@ mod llama;
 mod models;
 mod pids;
 mod platform;
+mod plugins;
 mod resolver;
 mod slots;
 
@@ -22,7 +23,7 @@ pub use error::PathError;
 pub use platform::{data_root, is_prebuilt_binary, resource_root};
 
 // Database
-pub use database::database_path;
+pub use database::{database_path, pending_restore_path};
 
 // Llama binaries
 pub use llama::{
@@ -37,6 +38,9 @@ pub use models::{ModelsDirResolution, ModelsDirSource, default_models_dir, resol
 // PID tracking
 pub use pids::pids_dir;
 
+// WASM plugins
+pub use plugins::plugins_dir;
+
 // Directory operations
 pub use ensure::{DirectoryCreationStrategy, ensure_directory, verify_writable};
 
@@ -48,5 +52,6 @@ pub use resolver::ResolvedPaths;
 
 // Slot cache paths
 pub use slots::{
-    slot_bin_path, slot_file_name, slot_model_prefix, slot_session_from_stem, slot_tmp_file_name,
+    prompt_cache_file_name, slot_bin_path, slot_file_name, slot_model_prefix,
+    slot_session_from_stem, slot_tmp_file_name, slots_dir,
 };
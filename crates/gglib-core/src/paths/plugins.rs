@@ -0,0 +1,34 @@
+//! WASM plugin directory path resolution.
+//!
+//! Provides the canonical location where `gglib plugins install` copies
+//! plugin modules and their manifests.
+
+use std::path::PathBuf;
+
+use super::PathError;
+use super::platform::data_root;
+
+/// Returns the directory where installed WASM plugins are stored.
+///
+/// Location: `~/.gglib/plugins/` (or equivalent data root)
+///
+/// Each plugin occupies one sub-directory named after the plugin, holding
+/// the module and its manifest.
+pub fn plugins_dir() -> Result<PathBuf, PathError> {
+    Ok(data_root()?.join("plugins"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::paths::test_utils::ENV_LOCK;
+
+    #[test]
+    fn plugins_dir_is_under_data_root() {
+        let _guard = ENV_LOCK.lock().unwrap();
+        let plugins = plugins_dir().expect("plugins_dir failed");
+        let data = data_root().expect("data_root failed");
+        assert!(plugins.starts_with(&data));
+        assert!(plugins.ends_with("plugins"));
+    }
+}
@@ -16,6 +16,14 @@
 
 use std::path::{Path, PathBuf};
 
+use super::PathError;
+use super::platform::data_root;
+
+/// Default slot cache directory (`--slot-save-path`): `{data_root}/slots`.
+pub fn slots_dir() -> Result<PathBuf, PathError> {
+    Ok(data_root()?.join("slots"))
+}
+
 /// Filename for a model+session slot cache file: `{model_id}__{session_id}.bin`.
 ///
 /// This is both the on-disk name (directly under `slot_dir`) and the
@@ -52,6 +60,15 @@ pub fn slot_tmp_file_name(model_id: u32, session_id: &str, nonce: u64) -> String
     format!("{model_id}__{session_id}.{nonce}.tmp")
 }
 
+/// Filename for a model's saved cached prompt: `prompt__{model_id}__{name}.bin`.
+///
+/// Deliberately *not* under [`slot_model_prefix`]: session files are purged
+/// whenever the model restarts, while a cached prompt's state is what gets
+/// restored on the next start (see [`crate::domain::prompt_cache`]).
+pub fn prompt_cache_file_name(model_id: u32, name: &str) -> String {
+    format!("prompt__{model_id}__{name}.bin")
+}
+
 /// Recover the session id from a slot file stem (`{model_id}__{session}`).
 ///
 /// Splits on the **first** `__`. Model ids are numeric and contain no `__`, so
@@ -89,6 +106,13 @@ mod tests {
         assert!("1__x.bin".starts_with(&slot_model_prefix(1)));
     }
 
+    #[test]
+    fn prompt_cache_file_survives_the_model_purge_prefix() {
+        let name = prompt_cache_file_name(42, "reviewer");
+        assert_eq!(name, "prompt__42__reviewer.bin");
+        assert!(!name.starts_with(&slot_model_prefix(42)));
+    }
+
     #[test]
     fn slot_session_from_stem_recovers_session() {
         assert_eq!(slot_session_from_stem("42__planner"), Some("planner"));
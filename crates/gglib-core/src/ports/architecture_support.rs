@@ -0,0 +1,85 @@
+//! Architecture support port: can the installed llama.cpp load a model?
+//!
+//! A GGUF whose `general.architecture` is newer than the installed
+//! llama.cpp registers fine and only fails at server launch, with a
+//! loader error that doesn't say "upgrade llama.cpp". This port lets
+//! download and import flows warn up front.
+//!
+//! # Design Notes
+//!
+//! - Core owns the trait and result type (pure)
+//! - Runtime owns the implementation (reads the llama.cpp checkout or
+//!   queries `llama-server --version`)
+//! - Adapters inject it; without one no check is made
+
+/// What the installed llama.cpp is known to support for one architecture.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ArchitectureSupport {
+    /// The installed llama.cpp recognises the architecture.
+    Supported,
+
+    /// The installed llama.cpp predates the architecture.
+    Unsupported {
+        /// Installed llama.cpp version (release tag or commit).
+        installed: String,
+        /// First release known to support the architecture, when known.
+        required: Option<String>,
+        /// Command that upgrades this install.
+        upgrade_command: String,
+    },
+
+    /// Support can't be determined: llama.cpp isn't installed, or the
+    /// architecture isn't in the version table.
+    Unknown,
+}
+
+impl ArchitectureSupport {
+    /// User-facing warning for `architecture`, or `None` unless unsupported.
+    #[must_use]
+    pub fn warning(&self, architecture: &str) -> Option<String> {
+        let Self::Unsupported {
+            installed,
+            required,
+            upgrade_command,
+        } = self
+        else {
+            return None;
+        };
+        let needs = required.as_ref().map_or_else(String::new, |required| {
+            format!(" (needs {required} or newer)")
+        });
+        Some(format!(
+            "Architecture '{architecture}' is not supported by the installed llama.cpp \
+             {installed}{needs}; the model will fail to load. Upgrade with '{upgrade_command}'."
+        ))
+    }
+}
+
+/// Port for checking a GGUF architecture against the installed llama.cpp.
+pub trait ArchitectureSupportPort: Send + Sync {
+    /// Check whether the installed llama.cpp can load `architecture`
+    /// (the GGUF `general.architecture` value).
+    fn check(&self, architecture: &str) -> ArchitectureSupport;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_unsupported_warns() {
+        assert_eq!(ArchitectureSupport::Supported.warning("llama"), None);
+        assert_eq!(ArchitectureSupport::Unknown.warning("llama"), None);
+
+        let warning = ArchitectureSupport::Unsupported {
+            installed: "b4800".to_string(),
+            required: Some("b5074".to_string()),
+            upgrade_command: "gglib config llama install --force".to_string(),
+        }
+        .warning("llama4")
+        .unwrap();
+        assert!(warning.contains("'llama4'"));
+        assert!(warning.contains("b4800 (needs b5074 or newer)"));
+        assert!(warning.contains("gglib config llama install --force"));
+    }
+}
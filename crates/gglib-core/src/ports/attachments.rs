@@ -0,0 +1,57 @@
+//! Attachment repository port definition.
+//!
+//! This port covers attachment *metadata*; the bytes themselves live in the
+//! content-addressed blob store managed by
+//! [`AttachmentService`](crate::services::AttachmentService).
+
+use async_trait::async_trait;
+use thiserror::Error;
+
+use crate::domain::attachment::{Attachment, NewAttachment};
+
+/// Errors that can occur in attachment operations.
+#[derive(Debug, Error)]
+pub enum AttachmentError {
+    #[error("Attachment not found: {0}")]
+    NotFound(i64),
+
+    #[error("Attachment is {size} bytes, the limit is {max} bytes")]
+    TooLarge { size: u64, max: u64 },
+
+    #[error("Attachment type not allowed: {0}")]
+    UnsupportedType(String),
+
+    #[error("Attachment service is not configured")]
+    Unavailable,
+
+    #[error("Blob storage error: {0}")]
+    Storage(String),
+
+    #[error("Database error: {0}")]
+    Database(String),
+}
+
+/// Port for attachment metadata persistence.
+#[async_trait]
+pub trait AttachmentRepository: Send + Sync {
+    /// Record a stored attachment. Returns the new attachment ID.
+    async fn insert(&self, attachment: NewAttachment) -> Result<i64, AttachmentError>;
+
+    /// Get an attachment by ID.
+    async fn get(&self, id: i64) -> Result<Option<Attachment>, AttachmentError>;
+
+    /// Attach `ids` to `message_id`.
+    ///
+    /// Fails with [`AttachmentError::NotFound`] for an unknown ID, leaving
+    /// every attachment unlinked.
+    async fn link_to_message(&self, ids: &[i64], message_id: i64) -> Result<(), AttachmentError>;
+
+    /// List the attachments of a message, in upload order.
+    async fn list_for_message(&self, message_id: i64) -> Result<Vec<Attachment>, AttachmentError>;
+
+    /// Delete an attachment record.
+    async fn delete(&self, id: i64) -> Result<(), AttachmentError>;
+
+    /// Number of attachment records that share the blob `sha256`.
+    async fn count_by_sha256(&self, sha256: &str) -> Result<i64, AttachmentError>;
+}
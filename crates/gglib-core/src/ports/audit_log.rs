@@ -0,0 +1,19 @@
+//! Audit log port definition.
+//!
+//! Stores the [`AuditEntry`]s behind
+//! [`AuditLogService`](crate::services::AuditLogService).
+
+use async_trait::async_trait;
+
+use super::RepositoryError;
+use crate::domain::audit::{AuditEntry, AuditFilter, NewAuditEntry};
+
+/// Storage for the audit trail of administrative actions.
+#[async_trait]
+pub trait AuditLogRepository: Send + Sync {
+    /// Record `entry`, stamped with the current time.
+    async fn record(&self, entry: &NewAuditEntry) -> Result<AuditEntry, RepositoryError>;
+
+    /// Entries matching `filter`, newest first.
+    async fn list(&self, filter: &AuditFilter) -> Result<Vec<AuditEntry>, RepositoryError>;
+}
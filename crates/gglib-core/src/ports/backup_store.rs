@@ -0,0 +1,66 @@
+//! Backup store and database snapshot ports.
+//!
+//! `gglib-backup` implements [`BackupStorePort`] for S3-compatible buckets;
+//! `gglib-db` implements [`DatabaseSnapshotPort`] on the live `SQLite` pool.
+//! Keys and object layout are described in [`crate::domain::backup`].
+
+use std::path::Path;
+
+use async_trait::async_trait;
+use thiserror::Error;
+
+use crate::domain::backup::BackupObject;
+
+/// Errors from a backup store.
+#[derive(Debug, Error)]
+pub enum BackupStoreError {
+    /// This build has no backup client.
+    #[error("this build of gglib has no S3 backup support (rebuild with the `s3` feature)")]
+    Unsupported,
+
+    /// The object does not exist in the bucket.
+    #[error("backup object '{0}' not found")]
+    NotFound(String),
+
+    /// Reading or writing a local file failed.
+    #[error("I/O error at {path}: {message}")]
+    Io { path: String, message: String },
+
+    /// The storage service rejected or failed the request.
+    #[error("backup service error: {0}")]
+    Service(String),
+}
+
+/// Transfer progress callback: `(bytes transferred, total bytes)`.
+pub type BackupProgress<'a> = &'a (dyn Fn(u64, u64) + Send + Sync);
+
+/// Object storage that backups are written to and restored from.
+#[async_trait]
+pub trait BackupStorePort: Send + Sync {
+    /// Upload the file at `path` as `key`, replacing any existing object.
+    async fn upload(
+        &self,
+        key: &str,
+        path: &Path,
+        progress: BackupProgress<'_>,
+    ) -> Result<(), BackupStoreError>;
+
+    /// Download `key` to `dest`, replacing any existing file.
+    async fn download(
+        &self,
+        key: &str,
+        dest: &Path,
+        progress: BackupProgress<'_>,
+    ) -> Result<(), BackupStoreError>;
+
+    /// List every object whose key starts with `prefix`.
+    async fn list(&self, prefix: &str) -> Result<Vec<BackupObject>, BackupStoreError>;
+}
+
+/// Consistent copies of the live database.
+#[async_trait]
+pub trait DatabaseSnapshotPort: Send + Sync {
+    /// Write a self-contained copy of the database to `dest`, which must
+    /// not exist yet. Safe to call while the database is in use.
+    async fn snapshot(&self, dest: &Path) -> Result<(), BackupStoreError>;
+}
@@ -2,13 +2,23 @@
 //!
 //! This port defines the interface for persisting and retrieving chat
 //! conversations and messages.
+//!
+//! Repositories may encrypt message content at rest with a
+//! [`ChatContentKey`]; see
+//! [`set_content_encryption`](ChatHistoryRepository::set_content_encryption).
+
+use std::fmt;
 
 use async_trait::async_trait;
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
 use thiserror::Error;
 
 use crate::domain::chat::{
-    Conversation, ConversationUpdate, Message, MessageRole, NewConversation, NewMessage,
+    Conversation, ConversationUpdate, Message, MessageRevision, MessageRole, NewConversation,
+    NewMessage,
 };
+use crate::domain::chat_import::ImportedConversation;
 
 /// Errors that can occur in chat history operations.
 #[derive(Debug, Error)]
@@ -22,8 +32,83 @@ pub enum ChatHistoryError {
     #[error("Invalid message role: {0}")]
     InvalidRole(String),
 
+    #[error("Message {id} is a {actual} message, expected {expected}")]
+    UnexpectedRole {
+        id: i64,
+        expected: MessageRole,
+        actual: MessageRole,
+    },
+
+    #[error("Message {0} has already been superseded")]
+    MessageSuperseded(i64),
+
     #[error("Database error: {0}")]
     Database(String),
+
+    #[error("Chat history encryption error: {0}")]
+    Encryption(String),
+
+    #[error("Cannot share conversation: {0}")]
+    NotShareable(String),
+
+    /// The share link is malformed, expired or revoked.
+    #[error("Share link is invalid, expired or revoked")]
+    ShareNotFound,
+}
+
+/// 256-bit key for message content encryption.
+///
+/// `Debug` never prints the key bytes.
+#[derive(Clone, PartialEq, Eq)]
+pub struct ChatContentKey([u8; 32]);
+
+impl ChatContentKey {
+    /// A fresh key from the OS random number generator.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the OS cannot supply random bytes.
+    pub fn generate() -> Result<Self, ChatHistoryError> {
+        let mut bytes = [0u8; 32];
+        getrandom::fill(&mut bytes).map_err(|e| {
+            ChatHistoryError::Encryption(format!("generating chat history key: {e}"))
+        })?;
+        Ok(Self(bytes))
+    }
+
+    /// Decode a key stored with [`to_base64`](Self::to_base64).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error unless `text` is 32 bytes of standard base64.
+    pub fn from_base64(text: &str) -> Result<Self, ChatHistoryError> {
+        let bytes = STANDARD
+            .decode(text.trim())
+            .ok()
+            .and_then(|bytes| <[u8; 32]>::try_from(bytes).ok())
+            .ok_or_else(|| {
+                ChatHistoryError::Encryption("stored chat history key is malformed".to_string())
+            })?;
+        Ok(Self(bytes))
+    }
+
+    /// Standard base64 of the key, for the secret store.
+    #[must_use]
+    pub fn to_base64(&self) -> String {
+        STANDARD.encode(self.0)
+    }
+
+    /// The raw key bytes.
+    #[must_use]
+    pub const fn as_bytes(&self) -> &[u8; 32] {
+        &self.0
+    }
+}
+
+impl fmt::Debug for ChatContentKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("ChatContentKey(..)")
+    }
 }
 
 /// Port for chat history persistence operations.
@@ -55,9 +140,15 @@ pub trait ChatHistoryRepository: Send + Sync {
     /// Get conversation count.
     async fn get_conversation_count(&self) -> Result<i64, ChatHistoryError>;
 
-    /// Get all messages for a conversation, ordered chronologically.
+    /// Get the active messages for a conversation, ordered chronologically.
+    ///
+    /// Superseded messages are excluded; see
+    /// [`get_message_versions`](Self::get_message_versions).
     async fn get_messages(&self, conversation_id: i64) -> Result<Vec<Message>, ChatHistoryError>;
 
+    /// Get a single message by ID, superseded or not.
+    async fn get_message(&self, id: i64) -> Result<Option<Message>, ChatHistoryError>;
+
     /// Save a new message and update conversation timestamp.
     async fn save_message(&self, msg: NewMessage) -> Result<i64, ChatHistoryError>;
 
@@ -73,8 +164,84 @@ pub trait ChatHistoryRepository: Send + Sync {
     /// Returns the number of messages deleted.
     async fn delete_message_and_subsequent(&self, id: i64) -> Result<i64, ChatHistoryError>;
 
-    /// Get message count for a conversation.
+    /// Store a new revision of message `id` in one transaction.
+    ///
+    /// The revision keeps the original's conversation and role. The
+    /// original and every later active message in the conversation are
+    /// marked superseded by it. Fails with
+    /// [`ChatHistoryError::MessageSuperseded`] if `id` is not active.
+    async fn supersede_message(
+        &self,
+        id: i64,
+        content: String,
+        metadata: Option<serde_json::Value>,
+    ) -> Result<MessageRevision, ChatHistoryError>;
+
+    /// Get every version of the message slot `id` belongs to, oldest first.
+    async fn get_message_versions(&self, id: i64) -> Result<Vec<Message>, ChatHistoryError>;
+
+    /// Get active message count for a conversation.
     async fn get_message_count(&self, conversation_id: i64) -> Result<i64, ChatHistoryError>;
+
+    /// Configure at-rest encryption of message content.
+    ///
+    /// `key` opens content sealed earlier; with `encrypt` set, content
+    /// written from now on is sealed with it too. Plaintext content always
+    /// reads back as-is, so enabling encryption never strands old rows.
+    ///
+    /// The default implementation stores plaintext only and rejects any
+    /// attempt to encrypt.
+    fn set_content_encryption(
+        &self,
+        key: Option<ChatContentKey>,
+        encrypt: bool,
+    ) -> Result<(), ChatHistoryError> {
+        let _ = key;
+        if encrypt {
+            return Err(ChatHistoryError::Encryption(
+                "this repository cannot encrypt message content".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Rewrite stored message content to match the current encryption mode.
+    ///
+    /// Seals plaintext rows when encrypting and opens sealed rows when not.
+    /// Returns the number of rows rewritten.
+    async fn reseal_messages(&self) -> Result<u64, ChatHistoryError> {
+        Ok(0)
+    }
+
+    /// Store a conversation read from another app's export, returning its ID.
+    ///
+    /// Implementations should keep the original timestamps. The default
+    /// implementation creates the conversation and saves each message, so
+    /// everything is stamped with the time of the import.
+    async fn import_conversation(
+        &self,
+        conversation: &ImportedConversation,
+    ) -> Result<i64, ChatHistoryError> {
+        let id = self
+            .create_conversation(NewConversation {
+                title: conversation.title.clone(),
+                model_id: None,
+                system_prompt: conversation.system_prompt.clone(),
+                settings: None,
+                incognito: false,
+            })
+            .await?;
+        for message in &conversation.messages {
+            self.save_message(NewMessage {
+                conversation_id: id,
+                role: message.role,
+                content: message.content.clone(),
+                metadata: message.metadata.clone(),
+            })
+            .await?;
+        }
+        Ok(id)
+    }
 }
 
 /// Validate a message role string.
@@ -0,0 +1,33 @@
+//! Conversation share port definition.
+//!
+//! Stores the [`ShareRecord`]s behind read-only share links; see
+//! [`chat_share`](crate::domain::chat_share) for how links are signed.
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+use super::chat_history::ChatHistoryError;
+use crate::domain::chat_share::ShareRecord;
+
+/// Storage for conversation shares.
+#[async_trait]
+pub trait ConversationShareRepository: Send + Sync {
+    /// Store a share of `conversation_id` valid until `expires_at`.
+    async fn create(
+        &self,
+        conversation_id: i64,
+        expires_at: DateTime<Utc>,
+    ) -> Result<ShareRecord, ChatHistoryError>;
+
+    /// The share with `id`, if it exists.
+    async fn get(&self, id: i64) -> Result<Option<ShareRecord>, ChatHistoryError>;
+
+    /// Every share of `conversation_id`, newest first.
+    async fn list(&self, conversation_id: i64) -> Result<Vec<ShareRecord>, ChatHistoryError>;
+
+    /// Delete the share with `id`; returns whether it existed.
+    async fn delete(&self, id: i64) -> Result<bool, ChatHistoryError>;
+
+    /// Delete every share expired at `now`; returns how many were deleted.
+    async fn delete_expired(&self, now: DateTime<Utc>) -> Result<u64, ChatHistoryError>;
+}
@@ -12,8 +12,12 @@
 
 use async_trait::async_trait;
 use std::path::PathBuf;
+use std::time::Duration;
 
-use crate::download::{DownloadError, DownloadId, Quantization, QueueSnapshot};
+use crate::download::{
+    CollectionQueueResult, DestinationOverride, DownloadError, DownloadHistoryFilter, DownloadId,
+    GroupProgress, Quantization, QueueRunSummary, QueueSnapshot,
+};
 
 /// Request to queue a new download.
 ///
@@ -32,6 +36,40 @@ pub struct DownloadRequest {
     pub force: bool,
     /// Add to local model database after download.
     pub add_to_db: bool,
+    /// Fetch the file from a paired gglib instance instead of `HuggingFace`.
+    pub peer: Option<PeerSource>,
+    /// Store the files somewhere other than the default layout.
+    pub destination: Option<DestinationOverride>,
+}
+
+/// A model file served by another gglib instance.
+///
+/// Used by library sync: the file is fetched over HTTP from the peer's
+/// `/api/models/{id}/file` endpoint rather than resolved on `HuggingFace`.
+#[derive(Clone)]
+pub struct PeerSource {
+    /// Full URL of the file on the peer.
+    pub url: String,
+    /// The peer's model share token, sent as a bearer token.
+    pub token: String,
+    /// File name to store the model under.
+    pub file_name: String,
+    /// Size of the file in bytes.
+    pub size: u64,
+    /// SHA-256 of the file, when the peer knows it.
+    pub sha256: Option<String>,
+}
+
+impl std::fmt::Debug for PeerSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PeerSource")
+            .field("url", &self.url)
+            .field("token", &"<redacted>")
+            .field("file_name", &self.file_name)
+            .field("size", &self.size)
+            .field("sha256", &self.sha256)
+            .finish()
+    }
 }
 
 impl DownloadRequest {
@@ -43,9 +81,28 @@ impl DownloadRequest {
             revision: None,
             force: false,
             add_to_db: true,
+            peer: None,
+            destination: None,
         }
     }
 
+    /// Fetch the file from a peer instead of `HuggingFace`.
+    #[must_use]
+    pub fn with_peer(mut self, peer: PeerSource) -> Self {
+        self.peer = Some(peer);
+        self
+    }
+
+    /// Store the files under `destination` instead of the default layout.
+    ///
+    /// Checked when the download is queued; an invalid override fails with
+    /// [`DownloadError::InvalidDestination`].
+    #[must_use]
+    pub fn with_destination(mut self, destination: DestinationOverride) -> Self {
+        self.destination = Some(destination).filter(|d| !d.is_empty());
+        self
+    }
+
     /// Set the revision/commit SHA.
     #[must_use]
     pub fn with_revision(mut self, revision: impl Into<String>) -> Self {
@@ -82,6 +139,19 @@ pub struct DownloadManagerConfig {
     pub max_queue_size: u32,
     /// `HuggingFace` authentication token (for private repos).
     pub hf_token: Option<String>,
+    /// Download into a staging directory and validate each file before it
+    /// is moved into the models directory; failures go to quarantine.
+    pub verify_before_register: bool,
+    /// Also check each file's SHA-256 against its LFS OID during validation.
+    /// Requires `verify_before_register`; costs a full read of every file.
+    pub verify_sha256: bool,
+    /// How long an orphaned partial download (one no queued job refers to)
+    /// is left untouched before the janitor deletes it.
+    pub partial_grace_period: Duration,
+    /// Replace transfers with synthetic ones that fail on purpose, for
+    /// chaos testing. Only honoured when `gglib-download` is built with its
+    /// `fault-injection` feature; `None` in every normal configuration.
+    pub fault_injection: Option<DownloadFaultConfig>,
 }
 
 impl Default for DownloadManagerConfig {
@@ -91,6 +161,12 @@ impl Default for DownloadManagerConfig {
             max_concurrent: 1,
             max_queue_size: 10,
             hf_token: None,
+            verify_before_register: true,
+            verify_sha256: false,
+            partial_grace_period: Duration::from_hours(u64::from(
+                crate::settings::DEFAULT_PARTIAL_DOWNLOAD_GRACE_HOURS,
+            )),
+            fault_injection: None,
         }
     }
 }
@@ -125,6 +201,74 @@ impl DownloadManagerConfig {
         self.hf_token = token;
         self
     }
+
+    /// Enable or disable staged download validation.
+    #[must_use]
+    pub const fn with_verify_before_register(mut self, verify: bool) -> Self {
+        self.verify_before_register = verify;
+        self
+    }
+
+    /// Enable or disable SHA-256 checks during staged validation.
+    #[must_use]
+    pub const fn with_verify_sha256(mut self, verify: bool) -> Self {
+        self.verify_sha256 = verify;
+        self
+    }
+
+    /// Set how long orphaned partial downloads are kept.
+    #[must_use]
+    pub const fn with_partial_grace_period(mut self, grace: Duration) -> Self {
+        self.partial_grace_period = grace;
+        self
+    }
+
+    /// Inject transfer faults; see [`DownloadFaultConfig`].
+    #[must_use]
+    pub const fn with_fault_injection(mut self, faults: DownloadFaultConfig) -> Self {
+        self.fault_injection = Some(faults);
+        self
+    }
+}
+
+/// Faults injected into download transfers by `gglib-download`'s
+/// `fault-injection` feature.
+///
+/// Each transfer attempt draws at most one fault, with the given
+/// probabilities, from a generator seeded with `seed`; rates should sum to
+/// at most 1. Transfers under fault injection are synthetic and never reach
+/// the network.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DownloadFaultConfig {
+    /// Seed for the fault generator; equal seeds replay equal runs.
+    pub seed: u64,
+    /// Chance the stream stops partway, leaving a partial file to resume.
+    pub connection_reset_rate: f64,
+    /// Chance every chunk is delayed by `slow_chunk_delay`.
+    pub slow_stream_rate: f64,
+    /// Chance the transfer ends short yet reports success.
+    pub truncate_rate: f64,
+    /// Chance the attempt is refused with HTTP 429.
+    pub rate_limit_rate: f64,
+    /// Bytes written per chunk of a synthetic transfer.
+    pub chunk_size: u64,
+    /// Delay before each chunk of a slow stream.
+    pub slow_chunk_delay: Duration,
+}
+
+impl Default for DownloadFaultConfig {
+    /// No faults; synthetic transfers in 64 KiB chunks.
+    fn default() -> Self {
+        Self {
+            seed: 0,
+            connection_reset_rate: 0.0,
+            slow_stream_rate: 0.0,
+            truncate_rate: 0.0,
+            rate_limit_rate: 0.0,
+            chunk_size: 64 * 1024,
+            slow_chunk_delay: Duration::from_millis(20),
+        }
+    }
 }
 
 /// Port for managing downloads.
@@ -203,6 +347,24 @@ pub trait DownloadManagerPort: Send + Sync {
         quantization: Option<String>,
     ) -> Result<(usize, usize), DownloadError>;
 
+    /// Queue every GGUF repository in a `HuggingFace` collection.
+    ///
+    /// Each model repository goes through the same selection as
+    /// [`queue_smart`](Self::queue_smart): with `quantization` set, repos
+    /// lacking it are skipped; without it, each repo's best quantization is
+    /// auto-selected. Repos that cannot be queued (no GGUF files, already
+    /// queued, ...) are reported in `skipped` rather than failing the batch.
+    ///
+    /// # Arguments
+    ///
+    /// * `slug` - Collection slug (e.g., `unsloth/qwen3-680edabfb790c8c34a242f95`)
+    /// * `quantization` - Optional quantization filter applied to every repo
+    async fn queue_collection(
+        self: Arc<Self>,
+        slug: String,
+        quantization: Option<String>,
+    ) -> Result<CollectionQueueResult, DownloadError>;
+
     /// Get a snapshot of the current queue state.
     ///
     /// Returns all queued, active, and recently completed/failed downloads.
@@ -254,6 +416,12 @@ pub trait DownloadManagerPort: Send + Sync {
     /// queued together. The `group_id` matches `QueuedDownload.group_id`.
     async fn cancel_group(&self, group_id: &str) -> Result<(), DownloadError>;
 
+    /// Aggregate progress of a shard group, summed from the actual shard
+    /// sizes.
+    ///
+    /// Returns `NotInQueue` for a group that is not queued or in flight.
+    async fn get_group_progress(&self, group_id: &str) -> Result<GroupProgress, DownloadError>;
+
     /// Retry a failed download.
     ///
     /// Moves the download from the failures list back to the queue.
@@ -263,6 +431,15 @@ pub trait DownloadManagerPort: Send + Sync {
     /// Clear all failed downloads from the failures list.
     async fn clear_failed(&self) -> Result<(), DownloadError>;
 
+    /// Past queue runs, newest first, narrowed by `filter`.
+    ///
+    /// Unlike the `QueueRunComplete` event, runs read back from history
+    /// carry every matching completion record and are never truncated.
+    async fn get_history(
+        &self,
+        filter: &DownloadHistoryFilter,
+    ) -> Result<Vec<QueueRunSummary>, DownloadError>;
+
     /// Update the maximum queue size.
     ///
     /// Downloads already in queue are not affected, but new downloads
@@ -13,7 +13,9 @@
 use async_trait::async_trait;
 
 use super::RepositoryError;
-use crate::download::{DownloadId, DownloadStatus, QueuedDownload};
+use crate::download::{
+    DownloadHistoryFilter, DownloadId, DownloadStatus, QueueRunSummary, QueuedDownload,
+};
 
 /// Port for persisting download queue state.
 ///
@@ -25,6 +27,7 @@ use crate::download::{DownloadId, DownloadStatus, QueuedDownload};
 /// **Persisted:**
 /// - Queued downloads (survive restarts)
 /// - Terminal results (completed/failed with enough info for history)
+/// - Summaries of drained queue runs with their completion records
 /// - Retry counts (if retries are supported)
 ///
 /// **In-memory only:**
@@ -79,4 +82,18 @@ pub trait DownloadStateRepositoryPort: Send + Sync {
     ///
     /// This is optional cleanup to prevent unbounded growth.
     async fn prune_completed(&self, older_than_days: u32) -> Result<u32, RepositoryError>;
+
+    /// Record the summary of a drained queue run.
+    ///
+    /// `run.items` is expected to be the full list of completion records;
+    /// `truncated` is not stored.
+    async fn record_run(&self, run: &QueueRunSummary) -> Result<(), RepositoryError>;
+
+    /// Load recorded queue runs, newest first, narrowed by `filter`.
+    ///
+    /// Returned runs carry every matching record, sorted newest first.
+    async fn load_history(
+        &self,
+        filter: &DownloadHistoryFilter,
+    ) -> Result<Vec<QueueRunSummary>, RepositoryError>;
 }
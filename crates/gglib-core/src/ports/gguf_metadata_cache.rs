@@ -0,0 +1,72 @@
+//! GGUF metadata cache port definition.
+//!
+//! Parsing a GGUF header means reading it from disk, which for a large
+//! library adds up. The cache keeps each file's parsed metadata under a
+//! [`FileStamp`] — its size and modification time — so a file that hasn't
+//! changed since it was last parsed is never read again. A stamp that no
+//! longer matches the file is a miss, and the next store replaces it.
+
+use std::path::Path;
+use std::time::UNIX_EPOCH;
+
+use async_trait::async_trait;
+
+use super::RepositoryError;
+use crate::domain::gguf::GgufMetadata;
+
+/// What identifies one version of a file's contents: its size and
+/// modification time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FileStamp {
+    /// File size in bytes.
+    pub size: u64,
+    /// Modification time in milliseconds since the Unix epoch.
+    pub modified_ms: i64,
+}
+
+impl FileStamp {
+    /// Stamp the file at `path` as it is now.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file can't be inspected, or if the platform
+    /// doesn't report modification times.
+    pub fn of(path: &Path) -> std::io::Result<Self> {
+        let meta = std::fs::metadata(path)?;
+        let modified = meta.modified()?;
+        // Times before the epoch come out negative rather than failing.
+        let modified_ms = match modified.duration_since(UNIX_EPOCH) {
+            Ok(after) => i64::try_from(after.as_millis()).unwrap_or(i64::MAX),
+            Err(e) => -i64::try_from(e.duration().as_millis()).unwrap_or(i64::MAX),
+        };
+        Ok(Self {
+            size: meta.len(),
+            modified_ms,
+        })
+    }
+}
+
+/// Storage for parsed GGUF metadata, keyed by file path and [`FileStamp`].
+#[async_trait]
+pub trait GgufMetadataCache: Send + Sync {
+    /// The metadata stored for `path`, if it was stored under `stamp`.
+    ///
+    /// An entry stored under any other stamp is stale and reads as `None`.
+    async fn get(
+        &self,
+        path: &Path,
+        stamp: FileStamp,
+    ) -> Result<Option<GgufMetadata>, RepositoryError>;
+
+    /// Store `metadata` for `path` under `stamp`, replacing any entry
+    /// stored for `path` before.
+    async fn put(
+        &self,
+        path: &Path,
+        stamp: FileStamp,
+        metadata: &GgufMetadata,
+    ) -> Result<(), RepositoryError>;
+
+    /// Drop the entry for `path`, if there is one.
+    async fn remove(&self, path: &Path) -> Result<(), RepositoryError>;
+}
@@ -5,4 +5,6 @@ mod types;
 
 pub use client::HfClientPort;
 pub use error::{HfPortError, HfPortResult};
-pub use types::{HfFileInfo, HfQuantInfo, HfRepoInfo, HfSearchOptions, HfSearchResult};
+pub use types::{
+    HfCollection, HfFileInfo, HfQuantInfo, HfRepoInfo, HfSearchOptions, HfSearchResult,
+};
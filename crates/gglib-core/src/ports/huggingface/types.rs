@@ -46,6 +46,20 @@ pub struct HfFileInfo {
     pub oid: Option<String>,
 }
 
+/// A `HuggingFace` collection, reduced to the model repositories it lists.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HfCollection {
+    /// Collection slug (e.g., `unsloth/qwen3-680edabfb790c8c34a242f95`)
+    pub slug: String,
+    /// Display title
+    pub title: String,
+    /// Optional description
+    pub description: Option<String>,
+    /// Model repository IDs, in collection order. Datasets, spaces and
+    /// papers are dropped.
+    pub model_ids: Vec<String>,
+}
+
 /// Information about a quantization variant.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct HfQuantInfo {
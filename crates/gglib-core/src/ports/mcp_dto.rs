@@ -4,12 +4,13 @@
 //! details like `PathBuf` crossing the wire.
 
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
 /// Result of executable path resolution for a server.
 ///
 /// This is the contract between backend (Rust) and frontend (TypeScript/Axum clients).
 /// All fields are String-based to ensure clean JSON serialization.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "snake_case")]
 pub struct ResolutionStatus {
     /// Whether resolution succeeded.
@@ -37,7 +38,7 @@ pub struct ResolutionStatus {
 }
 
 /// A single resolution attempt for diagnostics.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "snake_case")]
 pub struct ResolutionAttempt {
     /// The candidate path that was tried.
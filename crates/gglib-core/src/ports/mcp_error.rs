@@ -3,6 +3,8 @@
 //! This module defines service-level errors for MCP operations.
 
 use thiserror::Error;
+use ts_rs::TS;
+use utoipa::ToSchema;
 
 use super::McpRepositoryError;
 
@@ -49,11 +51,12 @@ pub enum McpServiceError {
 ///
 /// This type is used in `AppEvent::McpServerError` to provide error details
 /// that are safe to display to users (no raw process/SQL errors).
-#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, TS, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct McpErrorInfo {
     /// ID of the MCP server (if known).
     #[serde(skip_serializing_if = "Option::is_none")]
+    #[ts(optional)]
     pub server_id: Option<i64>,
 
     /// Name of the MCP server.
@@ -67,7 +70,7 @@ pub struct McpErrorInfo {
 }
 
 /// Categories of MCP errors for UI handling.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize, TS, ToSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum McpErrorCategory {
     /// Server process lifecycle error.
@@ -1,8 +1,13 @@
 #![doc = include_str!("README.md")]
 pub mod agent;
+pub mod architecture_support;
+pub mod attachments;
+pub mod audit_log;
+pub mod backup_store;
 pub mod benchmark;
 pub mod cache_metrics_sink;
 pub mod chat_history;
+pub mod chat_share;
 pub mod council_approvals;
 pub mod council_repository;
 pub mod download;
@@ -10,6 +15,7 @@ pub mod download_event_emitter;
 pub mod download_manager;
 pub mod download_state;
 pub mod event_emitter;
+pub mod gguf_metadata_cache;
 pub mod gguf_parser;
 pub mod huggingface;
 pub mod llm_completion;
@@ -17,15 +23,24 @@ pub mod mcp_dto;
 pub mod mcp_error;
 pub mod mcp_repository;
 pub mod model_catalog;
+pub mod model_hub;
 pub mod model_registrar;
 pub mod model_repository;
 pub mod model_runtime;
+pub mod model_trash;
+pub mod model_usage;
+pub mod pipelines;
+pub mod presets;
 pub mod process_runner;
+pub mod projects;
+pub mod secret_store;
 pub mod server_health;
 pub mod server_log_sink;
+pub mod server_stats_sink;
 pub mod settings_repository;
 pub mod structured_llm;
 pub mod system_probe;
+pub mod title_generator;
 pub mod tool_executor_filter;
 pub mod tool_support;
 
@@ -42,34 +57,56 @@ pub use structured_llm::StructuredOutputError;
 pub use tool_executor_filter::{EmptyToolExecutor, FilteredToolExecutor, TOOL_NOT_AVAILABLE_MSG};
 
 // Re-export repository traits for convenience
+pub use architecture_support::{ArchitectureSupport, ArchitectureSupportPort};
+pub use attachments::{AttachmentError, AttachmentRepository};
+pub use audit_log::AuditLogRepository;
+pub use backup_store::{BackupProgress, BackupStoreError, BackupStorePort, DatabaseSnapshotPort};
 pub use benchmark::BenchmarkRepositoryPort;
 pub use cache_metrics_sink::CacheMetricsSink;
-pub use chat_history::{ChatHistoryError, ChatHistoryRepository};
+pub use chat_history::{ChatContentKey, ChatHistoryError, ChatHistoryRepository};
+pub use chat_share::ConversationShareRepository;
 pub use council_approvals::{ApprovalDecision, CouncilApprovalRegistryPort};
 pub use council_repository::CouncilRepositoryPort;
 pub use download::{QuantizationResolver, Resolution, ResolvedFile};
 pub use download_event_emitter::{AppEventBridge, DownloadEventEmitterPort, NoopDownloadEmitter};
-pub use download_manager::{DownloadManagerConfig, DownloadManagerPort, DownloadRequest};
+pub use download_manager::{
+    DownloadFaultConfig, DownloadManagerConfig, DownloadManagerPort, DownloadRequest, PeerSource,
+};
 pub use download_state::DownloadStateRepositoryPort;
 pub use event_emitter::{AppEventEmitter, NoopEmitter};
+pub use gguf_metadata_cache::{FileStamp, GgufMetadataCache};
 pub use gguf_parser::{
     GgufCapabilities, GgufMetadata, GgufParseError, GgufParserPort, NoopGgufParser,
 };
 pub use huggingface::{
-    HfClientPort, HfFileInfo, HfPortError, HfQuantInfo, HfRepoInfo, HfSearchOptions, HfSearchResult,
+    HfClientPort, HfCollection, HfFileInfo, HfPortError, HfQuantInfo, HfRepoInfo, HfSearchOptions,
+    HfSearchResult,
 };
 pub use mcp_dto::{ResolutionAttempt, ResolutionStatus};
 pub use mcp_error::{McpErrorCategory, McpErrorInfo, McpServiceError};
 pub use mcp_repository::{McpRepositoryError, McpServerRepository};
 pub use model_catalog::{CatalogError, ModelCatalogPort, ModelLaunchSpec, ModelSummary};
+pub use model_hub::{ModelHub, ModelHubPort};
 pub use model_registrar::{CompletedDownload, ModelRegistrarPort};
 pub use model_repository::ModelRepository;
 pub use model_runtime::{ModelRuntimeError, ModelRuntimePort, RunningTarget};
+pub use model_trash::ModelTrashRepository;
+pub use model_usage::{ModelUsageRepository, ModelUsageSource};
+pub use pipelines::{PipelineError, PipelineRunRepository};
+pub use presets::{PresetError, PresetRepository};
 pub use process_runner::{ProcessHandle, ProcessRunner, ServerConfig, ServerHealth};
+pub use projects::{ProjectError, ProjectRepository};
+pub use secret_store::{
+    BACKUP_SECRET_ACCESS_KEY_SECRET, CHAT_HISTORY_KEY_SECRET, CHAT_SHARE_KEY_SECRET,
+    HF_TOKEN_SECRET, MemorySecretStore, SECRET_REF_PREFIX, SecretStoreError, SecretStorePort,
+    SecretStoreResult, WEB_SEARCH_API_KEY_SECRET, parse_secret_ref, secret_ref,
+};
 pub use server_health::ServerHealthStatus;
 pub use server_log_sink::ServerLogSinkPort;
+pub use server_stats_sink::ServerStatsSink;
 pub use settings_repository::SettingsRepository;
 pub use system_probe::{SystemProbeError, SystemProbePort, SystemProbeResult};
+pub use title_generator::{DEFAULT_TITLE_GENERATION_PROMPT, TITLE_MAX_TOKENS, TitleGeneratorPort};
 pub use tool_support::{
     ModelSource, ToolFormat, ToolSupportDetection, ToolSupportDetectionInput,
     ToolSupportDetectorPort,
@@ -101,6 +138,12 @@ pub struct Repos {
     pub mcp_servers: Arc<dyn McpServerRepository>,
     /// Chat history repository for conversations and messages.
     pub chat_history: Arc<dyn ChatHistoryRepository>,
+    /// Project repository for projects and their conversation links.
+    pub projects: Arc<dyn ProjectRepository>,
+    /// Preset repository for generation presets.
+    pub presets: Arc<dyn PresetRepository>,
+    /// Pipeline run repository for runs and their step outputs.
+    pub pipeline_runs: Arc<dyn PipelineRunRepository>,
 }
 
 impl Repos {
@@ -110,12 +153,18 @@ impl Repos {
         settings: Arc<dyn SettingsRepository>,
         mcp_servers: Arc<dyn McpServerRepository>,
         chat_history: Arc<dyn ChatHistoryRepository>,
+        projects: Arc<dyn ProjectRepository>,
+        presets: Arc<dyn PresetRepository>,
+        pipeline_runs: Arc<dyn PipelineRunRepository>,
     ) -> Self {
         Self {
             models,
             settings,
             mcp_servers,
             chat_history,
+            projects,
+            presets,
+            pipeline_runs,
         }
     }
 }
@@ -145,6 +194,10 @@ pub enum RepositoryError {
     /// A constraint was violated (e.g., foreign key, unique constraint).
     #[error("Constraint violation: {0}")]
     Constraint(String),
+
+    /// A conditional write lost to a concurrent one (stale version).
+    #[error("Conflict: {0}")]
+    Conflict(String),
 }
 
 /// Domain-specific errors for process runner operations.
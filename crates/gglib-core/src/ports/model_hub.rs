@@ -0,0 +1,194 @@
+//! Source-agnostic model hub port.
+//!
+//! A model hub is anywhere GGUF repositories can be searched, inspected and
+//! downloaded from. `HuggingFace` is the default; `ModelScope` mirrors much
+//! of it and is reachable where `HuggingFace` is not. Consumers that only
+//! need search, quantization resolution and file listings take a
+//! [`ModelHubPort`]; [`HfClientPort`](super::HfClientPort) extends it with
+//! `HuggingFace`-only features such as collections.
+//!
+//! Repositories from a hub other than `HuggingFace` are referred to with a
+//! prefix (`modelscope:org/repo`). Bare `org/repo` references keep meaning
+//! `HuggingFace`, so every existing model ID stays valid.
+
+use std::fmt;
+use std::str::FromStr;
+
+use async_trait::async_trait;
+
+use super::huggingface::{
+    HfFileInfo, HfPortResult, HfQuantInfo, HfRepoInfo, HfSearchOptions, HfSearchResult,
+};
+
+/// A model hub a repository reference can point at.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ModelHub {
+    /// `huggingface.co` (the default for unprefixed references).
+    #[default]
+    HuggingFace,
+    /// `modelscope.cn`.
+    ModelScope,
+}
+
+impl ModelHub {
+    /// Every supported hub.
+    pub const ALL: [Self; 2] = [Self::HuggingFace, Self::ModelScope];
+
+    /// The canonical reference prefix (`hf`, `modelscope`).
+    #[must_use]
+    pub const fn prefix(self) -> &'static str {
+        match self {
+            Self::HuggingFace => "hf",
+            Self::ModelScope => "modelscope",
+        }
+    }
+
+    /// Human-readable hub name.
+    #[must_use]
+    pub const fn display_name(self) -> &'static str {
+        match self {
+            Self::HuggingFace => "HuggingFace",
+            Self::ModelScope => "ModelScope",
+        }
+    }
+
+    /// Split a repository reference into its hub and bare repository ID.
+    ///
+    /// `modelscope:org/repo` and `ms:org/repo` point at `ModelScope`,
+    /// `hf:org/repo` and plain `org/repo` at `HuggingFace`. Prefixes are
+    /// case-insensitive; an unknown prefix is left in the ID.
+    #[must_use]
+    pub fn split(reference: &str) -> (Self, &str) {
+        let reference = reference.trim();
+        if let Some((prefix, rest)) = reference.split_once(':')
+            && let Ok(hub) = prefix.parse::<Self>()
+        {
+            return (hub, rest.trim());
+        }
+        (Self::HuggingFace, reference)
+    }
+
+    /// The reference stored for `repo_id` on this hub: the bare ID for
+    /// `HuggingFace`, `prefix:repo_id` otherwise.
+    #[must_use]
+    pub fn qualify(self, repo_id: &str) -> String {
+        match self {
+            Self::HuggingFace => repo_id.to_string(),
+            Self::ModelScope => format!("{}:{repo_id}", self.prefix()),
+        }
+    }
+}
+
+impl fmt::Display for ModelHub {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.prefix())
+    }
+}
+
+impl FromStr for ModelHub {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "hf" | "huggingface" => Ok(Self::HuggingFace),
+            "ms" | "modelscope" => Ok(Self::ModelScope),
+            other => Err(format!(
+                "unknown model hub {other:?} (expected one of: hf, modelscope)"
+            )),
+        }
+    }
+}
+
+/// Port trait for model hub operations shared by every hub.
+///
+/// Model IDs passed to these methods are bare (`org/repo`); the hub prefix
+/// has already selected the implementation. DTOs and errors are the
+/// `HuggingFace` port's, which already carry nothing hub-specific.
+#[async_trait]
+pub trait ModelHubPort: Send + Sync {
+    /// The hub this client talks to.
+    fn hub(&self) -> ModelHub;
+
+    /// Search for GGUF models.
+    async fn search(&self, options: &HfSearchOptions) -> HfPortResult<HfSearchResult>;
+
+    /// List available quantizations for a model.
+    ///
+    /// # Arguments
+    ///
+    /// * `model_id` - Full model ID (e.g., `TheBloke/Llama-2-7B-GGUF`)
+    async fn list_quantizations(&self, model_id: &str) -> HfPortResult<Vec<HfQuantInfo>>;
+
+    /// List all GGUF files in a model repository.
+    ///
+    /// # Arguments
+    ///
+    /// * `model_id` - Full model ID
+    async fn list_gguf_files(&self, model_id: &str) -> HfPortResult<Vec<HfFileInfo>>;
+
+    /// Get files for a specific quantization.
+    ///
+    /// Returns file information including OIDs for all files in the quantization,
+    /// sorted for correct shard ordering.
+    ///
+    /// # Arguments
+    ///
+    /// * `model_id` - Full model ID
+    /// * `quantization` - Quantization name (e.g., `Q4_K_M`)
+    async fn get_quantization_files(
+        &self,
+        model_id: &str,
+        quantization: &str,
+    ) -> HfPortResult<Vec<HfFileInfo>>;
+
+    /// Get the current commit SHA for a model.
+    ///
+    /// Used for version tracking and update detection.
+    async fn get_commit_sha(&self, model_id: &str) -> HfPortResult<String>;
+
+    /// Get detailed information about a model.
+    async fn get_model_info(&self, model_id: &str) -> HfPortResult<HfRepoInfo>;
+
+    /// Direct download URL for `path` in `model_id` at `revision`
+    /// (the default branch when `None`).
+    fn file_url(&self, model_id: &str, revision: Option<&str>, path: &str) -> String;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+
+    // Verify the trait is object-safe
+    fn _assert_object_safe(_: Arc<dyn ModelHubPort>) {}
+
+    #[test]
+    fn split_recognizes_hub_prefixes() {
+        assert_eq!(
+            ModelHub::split("modelscope:Qwen/Qwen3-8B-GGUF"),
+            (ModelHub::ModelScope, "Qwen/Qwen3-8B-GGUF")
+        );
+        assert_eq!(
+            ModelHub::split("MS:Qwen/Qwen3-8B-GGUF"),
+            (ModelHub::ModelScope, "Qwen/Qwen3-8B-GGUF")
+        );
+        assert_eq!(
+            ModelHub::split("hf:unsloth/Qwen3-8B-GGUF"),
+            (ModelHub::HuggingFace, "unsloth/Qwen3-8B-GGUF")
+        );
+        assert_eq!(
+            ModelHub::split("unsloth/Qwen3-8B-GGUF"),
+            (ModelHub::HuggingFace, "unsloth/Qwen3-8B-GGUF")
+        );
+    }
+
+    #[test]
+    fn qualify_round_trips_through_split() {
+        for hub in ModelHub::ALL {
+            let reference = hub.qualify("org/repo");
+            assert_eq!(ModelHub::split(&reference), (hub, "org/repo"));
+        }
+        assert_eq!(ModelHub::HuggingFace.qualify("org/repo"), "org/repo");
+        assert!("gitlab".parse::<ModelHub>().is_err());
+    }
+}
@@ -4,9 +4,10 @@
 //! Implementations must handle all storage details internally.
 
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 
 use super::RepositoryError;
-use crate::domain::{Model, NewModel};
+use crate::domain::{Model, ModelChanges, ModelListQuery, ModelPage, NewModel, PageRequest};
 
 /// Repository for model persistence operations.
 ///
@@ -18,9 +19,12 @@ use crate::domain::{Model, NewModel};
 /// - No `sqlx` types in signatures
 /// - CRUD-only: list, get, insert, update, delete
 /// - Tags and search logic belong in `ModelService`, not here
-/// - The one exception is [`ModelRepository::get_by_identifier`], a *provided*
-///   method: identifier resolution is a lookup-key policy, and it lives here
-///   precisely so that every facade over the repository shares one copy of it.
+/// - The exceptions are *provided* methods. [`ModelRepository::get_by_identifier`]
+///   is a lookup-key policy, and it lives here precisely so that every facade
+///   over the repository shares one copy of it.
+///   [`ModelRepository::list_page`] and [`ModelRepository::list_changed_since`]
+///   work from [`ModelRepository::list`] by default, and storage that can
+///   answer them cheaply overrides them.
 #[async_trait]
 pub trait ModelRepository: Send + Sync {
     /// List all models in the repository.
@@ -53,6 +57,37 @@ pub trait ModelRepository: Send + Sync {
     /// Returns `Err(RepositoryError::NotFound)` if the model doesn't exist.
     async fn delete(&self, id: i64) -> Result<(), RepositoryError>;
 
+    /// One page of the models matching `query`, in its sort order.
+    ///
+    /// The default filters and sorts all of [`list`](Self::list) with
+    /// [`page_of`](crate::domain::page_of); an override must return the same
+    /// models in the same order.
+    async fn list_page(
+        &self,
+        query: &ModelListQuery,
+        page: PageRequest,
+    ) -> Result<ModelPage, RepositoryError> {
+        Ok(crate::domain::page_of(self.list().await?, query, page))
+    }
+
+    /// The models added, updated or removed since `since`.
+    ///
+    /// The default can't tell what changed, so it returns every model with
+    /// [`ModelChanges::full`] set.
+    async fn list_changed_since(
+        &self,
+        since: DateTime<Utc>,
+    ) -> Result<ModelChanges, RepositoryError> {
+        let _ = since;
+        let as_of = Utc::now();
+        Ok(ModelChanges {
+            changed: self.list().await?,
+            removed: Vec::new(),
+            full: true,
+            as_of,
+        })
+    }
+
     /// Resolve a model by user-facing identifier: numeric database id first,
     /// then exact name.
     ///
@@ -114,11 +149,14 @@ mod tests {
             hf_filename: None,
             download_date: None,
             last_update_check: None,
+            license: None,
             tags: vec![],
             capabilities: ModelCapabilities::default(),
+            capabilities_version: None,
             inference_defaults: None,
             server_defaults: None,
             benchmark_summary: None,
+            card: None,
         }
     }
 
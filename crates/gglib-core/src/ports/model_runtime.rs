@@ -121,6 +121,11 @@ pub enum ModelRuntimeError {
     #[error("Model file not found: {0}")]
     ModelFileNotFound(String),
 
+    /// The request asked for a larger context than the model can serve.
+    /// Callers should return 400 Bad Request.
+    #[error("{0}")]
+    ContextTooLarge(String),
+
     /// Internal error during runtime operations.
     #[error("Internal error: {0}")]
     Internal(String),
@@ -140,6 +145,7 @@ impl ModelRuntimeError {
         match self {
             Self::ModelLoading | Self::ContentionTimeout(_) => 503,
             Self::ModelNotFound(_) | Self::ModelFileNotFound(_) => 404,
+            Self::ContextTooLarge(_) => 400,
             Self::SpawnFailed(_) | Self::HealthCheckFailed(_) | Self::Internal(_) => 500,
         }
     }
@@ -0,0 +1,29 @@
+//! Model trash port definition.
+//!
+//! Soft deletion for the model table: a trashed model keeps its row, marked
+//! deleted and hidden from [`ModelRepository`](super::ModelRepository)
+//! lookups, until it is restored or purged. Moving the files is the job of
+//! [`ModelTrashService`](crate::services::ModelTrashService).
+
+use async_trait::async_trait;
+
+use super::RepositoryError;
+use crate::domain::Model;
+use crate::domain::trash::TrashRecord;
+
+/// Port for marking models deleted and bringing them back.
+#[async_trait]
+pub trait ModelTrashRepository: Send + Sync {
+    /// Mark model `id` deleted, storing what its removal did.
+    ///
+    /// Returns `Err(RepositoryError::NotFound)` if no live model has that id.
+    async fn mark_deleted(&self, id: i64, record: &TrashRecord) -> Result<(), RepositoryError>;
+
+    /// Every deleted model with its record, oldest deletion first.
+    async fn list_deleted(&self) -> Result<Vec<(Model, TrashRecord)>, RepositoryError>;
+
+    /// Clear the deleted mark of model `id`.
+    ///
+    /// Returns `Err(RepositoryError::NotFound)` if no deleted model has that id.
+    async fn restore(&self, id: i64) -> Result<(), RepositoryError>;
+}
@@ -0,0 +1,40 @@
+//! Model usage port definitions.
+//!
+//! Usage flows in from whatever counts it — the runtime's per-server stats
+//! registry, behind [`ModelUsageSource`] — and is stored as daily aggregates
+//! behind [`ModelUsageRepository`].
+//! [`ModelUsageService`](crate::services::ModelUsageService) moves it from
+//! one to the other.
+
+use async_trait::async_trait;
+use chrono::NaiveDate;
+
+use super::RepositoryError;
+use crate::domain::usage::{DailyTotals, UsageDelta, UsageTotals};
+
+/// Storage for daily per-model usage aggregates.
+#[async_trait]
+pub trait ModelUsageRepository: Send + Sync {
+    /// Add `delta` to `model_id`'s aggregate for `day`, creating it if needed.
+    async fn add(
+        &self,
+        model_id: i64,
+        day: NaiveDate,
+        delta: &UsageDelta,
+    ) -> Result<(), RepositoryError>;
+
+    /// Per-model totals over every day from `since` (inclusive), or over all
+    /// recorded days when `since` is `None`. Models without usage are absent.
+    async fn totals(&self, since: Option<NaiveDate>) -> Result<Vec<UsageTotals>, RepositoryError>;
+
+    /// Usage summed over all models for each day from `since` (inclusive).
+    /// Days without usage are absent.
+    async fn daily(&self, since: NaiveDate) -> Result<Vec<DailyTotals>, RepositoryError>;
+}
+
+/// Where usage is counted before it is stored.
+pub trait ModelUsageSource: Send + Sync {
+    /// Take the usage counted per model since the previous call, leaving the
+    /// counters at zero. Models with nothing counted are left out.
+    fn drain_usage(&self) -> Vec<(i64, UsageDelta)>;
+}
@@ -0,0 +1,52 @@
+//! Pipeline run repository port definition.
+//!
+//! This port defines the interface for persisting pipeline runs and the
+//! output of each step as it completes, so a run's intermediate results
+//! survive a failure later in the pipeline.
+
+use async_trait::async_trait;
+use thiserror::Error;
+
+use crate::domain::pipeline::{NewPipelineRun, PipelineRun, PipelineRunStatus};
+
+/// Errors that can occur in pipeline operations.
+#[derive(Debug, Error)]
+pub enum PipelineError {
+    #[error("Pipeline run not found: {0}")]
+    NotFound(i64),
+
+    #[error("Invalid pipeline: {0}")]
+    Invalid(String),
+
+    #[error("Database error: {0}")]
+    Database(String),
+}
+
+/// Port for pipeline run persistence operations.
+#[async_trait]
+pub trait PipelineRunRepository: Send + Sync {
+    /// Record a new run in the running state. Returns the new run ID.
+    async fn create_run(&self, run: NewPipelineRun) -> Result<i64, PipelineError>;
+
+    /// Append the output of a completed step.
+    async fn record_step(
+        &self,
+        run_id: i64,
+        step_id: &str,
+        output: &str,
+    ) -> Result<(), PipelineError>;
+
+    /// Set the final status of a run, with the failure reason if any.
+    async fn finish_run(
+        &self,
+        run_id: i64,
+        status: PipelineRunStatus,
+        error: Option<&str>,
+    ) -> Result<(), PipelineError>;
+
+    /// Get a run with its step outputs.
+    async fn get(&self, id: i64) -> Result<Option<PipelineRun>, PipelineError>;
+
+    /// List runs with their step outputs, most recent first.
+    async fn list(&self) -> Result<Vec<PipelineRun>, PipelineError>;
+}
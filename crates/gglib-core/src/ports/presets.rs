@@ -0,0 +1,47 @@
+//! Preset repository port definition.
+//!
+//! This port defines the interface for persisting generation presets.
+//! Preset names are unique, since clients select presets by name.
+
+use async_trait::async_trait;
+use thiserror::Error;
+
+use crate::domain::preset::{NewPreset, Preset, PresetUpdate};
+
+/// Errors that can occur in preset operations.
+#[derive(Debug, Error)]
+pub enum PresetError {
+    #[error("Preset not found: {0}")]
+    NotFound(i64),
+
+    #[error("Preset already exists: {0}")]
+    AlreadyExists(String),
+
+    #[error("Invalid preset: {0}")]
+    Invalid(String),
+
+    #[error("Database error: {0}")]
+    Database(String),
+}
+
+/// Port for preset persistence operations.
+#[async_trait]
+pub trait PresetRepository: Send + Sync {
+    /// Create a new preset. Returns the new preset ID.
+    async fn create(&self, preset: NewPreset) -> Result<i64, PresetError>;
+
+    /// List all presets, ordered by name.
+    async fn list(&self) -> Result<Vec<Preset>, PresetError>;
+
+    /// Get a preset by ID.
+    async fn get(&self, id: i64) -> Result<Option<Preset>, PresetError>;
+
+    /// Get a preset by name.
+    async fn get_by_name(&self, name: &str) -> Result<Option<Preset>, PresetError>;
+
+    /// Update preset fields.
+    async fn update(&self, id: i64, update: PresetUpdate) -> Result<(), PresetError>;
+
+    /// Delete a preset.
+    async fn delete(&self, id: i64) -> Result<(), PresetError>;
+}
@@ -8,7 +8,7 @@ use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
 
 use super::ProcessError;
-use crate::domain::InferenceConfig;
+use crate::domain::{InferenceConfig, RopeConfig};
 
 /// Configuration for starting a model server.
 ///
@@ -50,6 +50,13 @@ pub struct ServerConfig {
     pub spec_draft_p_min: Option<f32>,
     /// Inference sampling parameters (temperature, `top_p`, etc.).
     pub inference_config: Option<InferenceConfig>,
+    /// `RoPE` scaling overrides (`--rope-scaling`, `--rope-freq-base`,
+    /// `--rope-freq-scale`, `--yarn-orig-ctx`).
+    ///
+    /// `None` means no flag is passed and llama-server reads the `RoPE`
+    /// parameters from the GGUF metadata.
+    #[serde(default)]
+    pub rope: Option<RopeConfig>,
     /// Additional server-specific options (escape hatch).
     pub extra_args: Vec<String>,
     /// Directory for llama-server KV cache slot persistence (`--slot-save-path`).
@@ -81,6 +88,46 @@ pub struct ServerConfig {
     /// V cache element type (`--cache-type-v`). Same semantics as
     /// [`Self::cache_type_k`].
     pub cache_type_v: Option<crate::cache_config::KvCacheType>,
+    /// Generation thread count (`--threads`). `None` means no flag is
+    /// passed and llama-server picks its own default.
+    #[serde(default)]
+    pub threads: Option<u32>,
+    /// Prompt-processing thread count (`--threads-batch`). `None` means
+    /// llama-server uses the same value as `--threads`.
+    #[serde(default)]
+    pub threads_batch: Option<u32>,
+    /// Logical CPUs the server's threads are pinned to (`--cpu-mask`, with
+    /// `--cpu-strict 1`). `None` leaves placement to the OS scheduler.
+    #[serde(default)]
+    pub cpu_affinity: Option<crate::cpu_config::CpuSet>,
+    /// NUMA placement strategy (`--numa`). `None` means no flag is passed.
+    #[serde(default)]
+    pub numa: Option<crate::cpu_config::NumaStrategy>,
+    /// How the model is spread over several GPUs (`--split-mode`). `None`
+    /// means no flag is passed — llama-server's own default (`layer`)
+    /// applies.
+    #[serde(default)]
+    pub split_mode: Option<crate::gpu_config::SplitMode>,
+    /// Per-GPU share of the model (`--tensor-split`). `None` means no flag
+    /// is passed and llama-server splits by its own rule.
+    #[serde(default)]
+    pub tensor_split: Option<crate::gpu_config::TensorSplit>,
+    /// GPU used for the whole model under `--split-mode none`, and for
+    /// intermediate results under `row` (`--main-gpu`). `None` means no
+    /// flag is passed — device `0`.
+    #[serde(default)]
+    pub main_gpu: Option<u32>,
+    /// Parallel request slots (`--parallel`). `None` means one slot.
+    ///
+    /// Each slot gets the full [`Self::context_size`]: the context passed to
+    /// llama-server is multiplied by the slot count.
+    #[serde(default)]
+    pub parallel: Option<u32>,
+    /// Continuous batching (`--cont-batching` / `--no-cont-batching`).
+    /// `None` means no flag is passed — llama-server's own default (on)
+    /// applies.
+    #[serde(default)]
+    pub cont_batching: Option<bool>,
 }
 
 impl ServerConfig {
@@ -105,12 +152,22 @@ impl ServerConfig {
             spec_draft_n_max: None,
             spec_draft_p_min: None,
             inference_config: None,
+            rope: None,
             extra_args: Vec::new(),
             slot_save_path: None,
             cache_ram_mb: None,
             cache_reuse: None,
             cache_type_k: None,
             cache_type_v: None,
+            threads: None,
+            threads_batch: None,
+            cpu_affinity: None,
+            numa: None,
+            split_mode: None,
+            tensor_split: None,
+            main_gpu: None,
+            parallel: None,
+            cont_batching: None,
         }
     }
 
@@ -177,6 +234,13 @@ impl ServerConfig {
         self
     }
 
+    /// Set `RoPE` scaling overrides.
+    #[must_use]
+    pub const fn with_rope(mut self, rope: RopeConfig) -> Self {
+        self.rope = Some(rope);
+        self
+    }
+
     /// Add extra arguments to pass to the server.
     #[must_use]
     pub fn with_extra_args(mut self, args: Vec<String>) -> Self {
@@ -224,6 +288,69 @@ impl ServerConfig {
         self.cache_type_v = Some(t);
         self
     }
+
+    /// Set the generation thread count (`--threads`).
+    #[must_use]
+    pub const fn with_threads(mut self, n: u32) -> Self {
+        self.threads = Some(n);
+        self
+    }
+
+    /// Set the prompt-processing thread count (`--threads-batch`).
+    #[must_use]
+    pub const fn with_threads_batch(mut self, n: u32) -> Self {
+        self.threads_batch = Some(n);
+        self
+    }
+
+    /// Pin the server's threads to a set of logical CPUs (`--cpu-mask`).
+    #[must_use]
+    pub fn with_cpu_affinity(mut self, cpus: crate::cpu_config::CpuSet) -> Self {
+        self.cpu_affinity = Some(cpus);
+        self
+    }
+
+    /// Set the NUMA placement strategy (`--numa`).
+    #[must_use]
+    pub const fn with_numa(mut self, numa: crate::cpu_config::NumaStrategy) -> Self {
+        self.numa = Some(numa);
+        self
+    }
+
+    /// Set how the model is spread over several GPUs (`--split-mode`).
+    #[must_use]
+    pub const fn with_split_mode(mut self, mode: crate::gpu_config::SplitMode) -> Self {
+        self.split_mode = Some(mode);
+        self
+    }
+
+    /// Set each GPU's share of the model (`--tensor-split`).
+    #[must_use]
+    pub fn with_tensor_split(mut self, split: crate::gpu_config::TensorSplit) -> Self {
+        self.tensor_split = Some(split);
+        self
+    }
+
+    /// Set the main GPU (`--main-gpu`).
+    #[must_use]
+    pub const fn with_main_gpu(mut self, gpu: u32) -> Self {
+        self.main_gpu = Some(gpu);
+        self
+    }
+
+    /// Set the number of parallel request slots.
+    #[must_use]
+    pub const fn with_parallel(mut self, slots: u32) -> Self {
+        self.parallel = Some(slots);
+        self
+    }
+
+    /// Turn continuous batching on or off explicitly.
+    #[must_use]
+    pub const fn with_cont_batching(mut self, enabled: bool) -> Self {
+        self.cont_batching = Some(enabled);
+        self
+    }
 }
 
 /// Handle to a running server process.
@@ -349,4 +476,24 @@ pub trait ProcessRunner: Send + Sync {
     ///
     /// This is needed for snapshot behavior (e.g., `server:snapshot` events).
     async fn list_running(&self) -> Result<Vec<ProcessHandle>, ProcessError>;
+
+    /// Re-attach a server that a previous session left running.
+    ///
+    /// `handle` describes the live process; once adopted it is listed,
+    /// health-checked and stopped like a server this runner started.
+    /// `context_size` is what the server was launched with, if known.
+    ///
+    /// Runners that do not manage local processes keep the default, which
+    /// declines, so the caller stops the orphan instead.
+    async fn adopt(
+        &self,
+        handle: ProcessHandle,
+        context_size: Option<u64>,
+    ) -> Result<(), ProcessError> {
+        let _ = context_size;
+        Err(ProcessError::Internal(format!(
+            "this runner cannot adopt running servers (model {})",
+            handle.model_id
+        )))
+    }
 }
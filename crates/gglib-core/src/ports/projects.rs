@@ -0,0 +1,61 @@
+//! Project repository port definition.
+//!
+//! This port defines the interface for persisting projects and the links
+//! between projects and chat conversations. A conversation belongs to at
+//! most one project.
+
+use async_trait::async_trait;
+use thiserror::Error;
+
+use crate::domain::project::{NewProject, Project, ProjectUpdate};
+
+/// Errors that can occur in project operations.
+#[derive(Debug, Error)]
+pub enum ProjectError {
+    #[error("Project not found: {0}")]
+    NotFound(i64),
+
+    #[error("Conversation not found: {0}")]
+    ConversationNotFound(i64),
+
+    #[error("Invalid project: {0}")]
+    Invalid(String),
+
+    #[error("Database error: {0}")]
+    Database(String),
+}
+
+/// Port for project persistence operations.
+#[async_trait]
+pub trait ProjectRepository: Send + Sync {
+    /// Create a new project. Returns the new project ID.
+    async fn create(&self, project: NewProject) -> Result<i64, ProjectError>;
+
+    /// List all projects, ordered by name.
+    async fn list(&self) -> Result<Vec<Project>, ProjectError>;
+
+    /// Get a project by ID.
+    async fn get(&self, id: i64) -> Result<Option<Project>, ProjectError>;
+
+    /// Update project fields.
+    async fn update(&self, id: i64, update: ProjectUpdate) -> Result<(), ProjectError>;
+
+    /// Delete a project, unlinking its conversations.
+    async fn delete(&self, id: i64) -> Result<(), ProjectError>;
+
+    /// Link a conversation to a project, replacing any previous link.
+    async fn link_conversation(
+        &self,
+        conversation_id: i64,
+        project_id: i64,
+    ) -> Result<(), ProjectError>;
+
+    /// Remove a conversation's project link, if it has one.
+    async fn unlink_conversation(&self, conversation_id: i64) -> Result<(), ProjectError>;
+
+    /// The project a conversation is linked to.
+    async fn get_for_conversation(
+        &self,
+        conversation_id: i64,
+    ) -> Result<Option<Project>, ProjectError>;
+}
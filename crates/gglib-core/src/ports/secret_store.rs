@@ -0,0 +1,212 @@
+//! Secret storage port for tokens and API keys.
+//!
+//! Settings never hold a credential in plaintext. A field that needs one
+//! stores a reference such as `secret:web_search_api_key`, and the value
+//! itself lives behind [`SecretStorePort`] — the OS keyring, or an encrypted
+//! file where no keyring is available (see `gglib-secrets`).
+//!
+//! A field that still holds a plaintext value (written before secrets were
+//! moved out of the database) resolves to itself, so existing installs keep
+//! working until the value is migrated.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use thiserror::Error;
+
+/// Prefix marking a settings value as a reference into the secret store.
+pub const SECRET_REF_PREFIX: &str = "secret:";
+
+/// Secret key of the `HuggingFace` access token.
+pub const HF_TOKEN_SECRET: &str = "hf_token";
+
+/// Secret key of the web search provider's API key. Settings saves store
+/// each new key under this name plus a unique suffix.
+pub const WEB_SEARCH_API_KEY_SECRET: &str = "web_search_api_key";
+
+/// Secret key of the backup bucket's secret access key, suffixed like
+/// [`WEB_SEARCH_API_KEY_SECRET`].
+pub const BACKUP_SECRET_ACCESS_KEY_SECRET: &str = "backup_secret_access_key";
+
+/// Secret key of the chat history encryption key (base64).
+pub const CHAT_HISTORY_KEY_SECRET: &str = "chat_history_key";
+
+/// Secret key of the conversation share link signing key (base64).
+pub const CHAT_SHARE_KEY_SECRET: &str = "chat_share_key";
+
+/// Errors that can occur while reading or writing secrets.
+#[derive(Debug, Error)]
+pub enum SecretStoreError {
+    /// A settings value references a secret that is not stored.
+    #[error("Secret '{0}' is not stored")]
+    NotFound(String),
+
+    /// The backend could not be reached or refused the operation.
+    #[error("Secret store error: {0}")]
+    Backend(String),
+
+    /// Stored secrets could not be decrypted or parsed.
+    #[error("Secret store is corrupt: {0}")]
+    Corrupt(String),
+}
+
+/// Result type for secret store operations.
+pub type SecretStoreResult<T> = Result<T, SecretStoreError>;
+
+/// The reference stored in settings in place of the secret `key`.
+#[must_use]
+pub fn secret_ref(key: &str) -> String {
+    format!("{SECRET_REF_PREFIX}{key}")
+}
+
+/// The secret key referenced by `value`, or `None` for a plaintext value.
+#[must_use]
+pub fn parse_secret_ref(value: &str) -> Option<&str> {
+    value
+        .strip_prefix(SECRET_REF_PREFIX)
+        .filter(|key| !key.is_empty())
+}
+
+/// Port for storing credentials outside the settings database.
+///
+/// Operations are synchronous: keyring and file backends answer in
+/// microseconds, and callers resolve secrets once per request or session.
+pub trait SecretStorePort: Send + Sync {
+    /// Short backend name for logs and diagnostics (e.g. `keyring`).
+    fn backend(&self) -> &'static str;
+
+    /// Read the secret stored under `key`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the backend cannot be read.
+    fn get(&self, key: &str) -> SecretStoreResult<Option<String>>;
+
+    /// Store `value` under `key`, replacing any previous value.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the backend cannot be written.
+    fn set(&self, key: &str, value: &str) -> SecretStoreResult<()>;
+
+    /// Delete the secret under `key`, returning whether one was stored.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the backend cannot be written.
+    fn delete(&self, key: &str) -> SecretStoreResult<bool>;
+
+    /// The value behind a settings field.
+    ///
+    /// References are looked up; plaintext values are returned unchanged.
+    ///
+    /// # Errors
+    ///
+    /// [`SecretStoreError::NotFound`] when the referenced secret is missing,
+    /// or the backend's error.
+    fn resolve(&self, value: &str) -> SecretStoreResult<String> {
+        match parse_secret_ref(value) {
+            Some(key) => self
+                .get(key)?
+                .ok_or_else(|| SecretStoreError::NotFound(key.to_string())),
+            None => Ok(value.to_string()),
+        }
+    }
+
+    /// Move a plaintext settings value into the store under `key`.
+    ///
+    /// Returns the reference to persist instead. A value that already is a
+    /// reference is returned unchanged.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the backend cannot be written.
+    fn stash(&self, key: &str, value: &str) -> SecretStoreResult<String> {
+        if parse_secret_ref(value).is_some() {
+            return Ok(value.to_string());
+        }
+        self.set(key, value)?;
+        Ok(secret_ref(key))
+    }
+}
+
+/// In-process secret store, for tests and adapters without persistent
+/// storage.
+#[derive(Debug, Default)]
+pub struct MemorySecretStore {
+    secrets: Mutex<HashMap<String, String>>,
+}
+
+impl MemorySecretStore {
+    /// Create an empty store.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Keys of every stored secret, sorted.
+    #[must_use]
+    pub fn keys(&self) -> Vec<String> {
+        let mut keys: Vec<String> = self.lock().keys().cloned().collect();
+        keys.sort();
+        keys
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<String, String>> {
+        self.secrets
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+}
+
+impl SecretStorePort for MemorySecretStore {
+    fn backend(&self) -> &'static str {
+        "memory"
+    }
+
+    fn get(&self, key: &str) -> SecretStoreResult<Option<String>> {
+        Ok(self.lock().get(key).cloned())
+    }
+
+    fn set(&self, key: &str, value: &str) -> SecretStoreResult<()> {
+        self.lock().insert(key.to_string(), value.to_string());
+        Ok(())
+    }
+
+    fn delete(&self, key: &str) -> SecretStoreResult<bool> {
+        Ok(self.lock().remove(key).is_some())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn references_round_trip() {
+        assert_eq!(secret_ref("hf_token"), "secret:hf_token");
+        assert_eq!(parse_secret_ref("secret:hf_token"), Some("hf_token"));
+        assert_eq!(parse_secret_ref("hf_abc123"), None);
+        assert_eq!(parse_secret_ref("secret:"), None);
+    }
+
+    #[test]
+    fn stash_then_resolve() {
+        let store = MemorySecretStore::new();
+        let reference = store.stash("brave", "key-123").unwrap();
+        assert_eq!(reference, "secret:brave");
+        assert_eq!(store.resolve(&reference).unwrap(), "key-123");
+
+        // Stashing a reference again leaves the stored value alone.
+        assert_eq!(store.stash("brave", &reference).unwrap(), reference);
+        assert_eq!(store.get("brave").unwrap().as_deref(), Some("key-123"));
+
+        // Plaintext passes through; a dangling reference is an error.
+        assert_eq!(store.resolve("literal").unwrap(), "literal");
+        assert!(store.delete("brave").unwrap());
+        assert!(matches!(
+            store.resolve(&reference),
+            Err(SecretStoreError::NotFound(_))
+        ));
+    }
+}
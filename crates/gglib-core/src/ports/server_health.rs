@@ -4,11 +4,13 @@
 //! used for continuous monitoring after initial startup.
 
 use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+use utoipa::ToSchema;
 
 /// Health status of a running server process.
 ///
 /// Used by monitoring systems to track server state and emit lifecycle events.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, TS, ToSchema)]
 #[serde(tag = "status", rename_all = "lowercase")]
 pub enum ServerHealthStatus {
     /// Server is responding to health checks and process is alive.
@@ -0,0 +1,16 @@
+//! Outbound port for recording per-request latency and throughput.
+
+use crate::domain::RequestTiming;
+
+/// A sink for per-request timing of a running server.
+///
+/// The recording site — the proxy's forwarder — writes each completed
+/// request's [`RequestTiming`] here, keyed by the model whose server handled
+/// it, without knowing how the figures are aggregated. The runtime implements
+/// it with a per-session registry that resets whenever a server is spawned; a
+/// process with no consumer passes no sink at all, so recording becomes a
+/// no-op.
+pub trait ServerStatsSink: Send + Sync {
+    /// Record one completed request served by `model_id`'s server.
+    fn record(&self, model_id: u32, timing: RequestTiming);
+}
@@ -18,6 +18,13 @@ use crate::settings::Settings;
 /// - No `sqlx` types in signatures
 /// - Works with domain `Settings` type directly
 /// - Implementation handles JSON serialization internally
+///
+/// # Versioning
+///
+/// Every save bumps a monotonically increasing version, so a writer can
+/// detect that another process (a CLI next to an open GUI) saved in between
+/// its read and its write. Stores without versioning keep the defaults,
+/// which always report version `0`.
 #[async_trait]
 pub trait SettingsRepository: Send + Sync {
     /// Load application settings.
@@ -27,4 +34,30 @@ pub trait SettingsRepository: Send + Sync {
 
     /// Save application settings.
     async fn save(&self, settings: &Settings) -> Result<(), RepositoryError>;
+
+    /// Current settings version; `0` before the first save.
+    async fn version(&self) -> Result<u64, RepositoryError> {
+        Ok(0)
+    }
+
+    /// Save application settings only if the stored version is still
+    /// `expected`, returning the new version.
+    ///
+    /// Returns [`RepositoryError::Conflict`] when another writer saved
+    /// first. The default implementation checks and saves separately;
+    /// versioned stores must do both atomically.
+    async fn save_if_version(
+        &self,
+        settings: &Settings,
+        expected: u64,
+    ) -> Result<u64, RepositoryError> {
+        let current = self.version().await?;
+        if current != expected {
+            return Err(RepositoryError::Conflict(format!(
+                "settings are at version {current}, expected {expected}"
+            )));
+        }
+        self.save(settings).await?;
+        self.version().await
+    }
 }
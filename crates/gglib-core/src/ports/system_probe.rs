@@ -70,6 +70,16 @@ pub trait SystemProbePort: Send + Sync {
     /// Returns total RAM, GPU memory (if available), and platform info
     /// useful for determining which models can run on this system.
     fn get_system_memory_info(&self) -> SystemMemoryInfo;
+
+    /// Highest CUDA version the installed NVIDIA driver supports.
+    ///
+    /// Distinct from [`GpuInfo::cuda_version`], which is the toolkit
+    /// (`nvcc`) release; a toolkit newer than the driver builds binaries
+    /// that fail at launch. `None` when there is no NVIDIA driver or the
+    /// probe can't tell.
+    fn driver_cuda_version(&self) -> Option<String> {
+        None
+    }
 }
 
 #[cfg(test)]
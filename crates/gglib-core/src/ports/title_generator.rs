@@ -0,0 +1,36 @@
+//! Title generator port — names a conversation from its opening exchange.
+//!
+//! [`ChatHistoryService`](crate::services::ChatHistoryService) calls this
+//! once a new conversation has its first user/assistant pair. The adapter
+//! decides which model answers (usually whichever server is running) and
+//! how the request is bounded; the service cleans the reply, stores it and
+//! emits [`AppEvent::ConversationUpdated`](crate::events::AppEvent::ConversationUpdated).
+
+use anyhow::Result;
+use async_trait::async_trait;
+
+use crate::domain::chat::{Conversation, Message};
+
+/// Default instruction appended after the transcript when
+/// `Settings::title_generation_prompt` is unset.
+pub const DEFAULT_TITLE_GENERATION_PROMPT: &str = "Based on this conversation, generate a short \
+     descriptive title (max 6 words). Respond with ONLY the title text, no quotes, no \
+     explanation, no punctuation at the end.";
+
+/// Upper bound on the tokens a title completion may generate.
+pub const TITLE_MAX_TOKENS: u32 = 20;
+
+/// Asks a model for a short conversation title.
+#[async_trait]
+pub trait TitleGeneratorPort: Send + Sync {
+    /// Generate a title for `conversation` from its `messages`.
+    ///
+    /// `prompt` is the instruction to append after the transcript. The
+    /// returned text is the raw model reply; callers clean it up.
+    async fn generate_title(
+        &self,
+        conversation: &Conversation,
+        messages: &[Message],
+        prompt: &str,
+    ) -> Result<String>;
+}
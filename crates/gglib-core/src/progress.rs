@@ -0,0 +1,99 @@
+//! Plain progress mode for terminal renderers.
+//!
+//! Animated progress bars rewrite the same screen lines many times a second.
+//! In a CI log that becomes a wall of escape codes (or, with `indicatif`'s
+//! non-terminal fallback, nothing at all), and a screen reader either repeats
+//! every redraw or says nothing. In plain mode every CLI renderer — model
+//! downloads, llama.cpp builds and installs — instead prints a single status
+//! line at most every [`STATUS_INTERVAL`], plus a summary when it finishes.
+//!
+//! The mode is process-wide: the CLI sets it once at startup with
+//! [`set_plain`] (from `--plain`, or automatically when stdout is not a
+//! terminal), and renderers read it with [`is_plain`] when they start.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
+
+/// Minimum gap between two status lines for the same operation.
+pub const STATUS_INTERVAL: Duration = Duration::from_secs(5);
+
+static PLAIN: AtomicBool = AtomicBool::new(false);
+
+/// Turn plain progress on or off for this process.
+pub fn set_plain(enabled: bool) {
+    PLAIN.store(enabled, Ordering::Relaxed);
+}
+
+/// Whether renderers should print status lines instead of drawing bars.
+#[must_use]
+pub fn is_plain() -> bool {
+    PLAIN.load(Ordering::Relaxed)
+}
+
+/// Rate limiter for periodic status lines.
+///
+/// The first call to [`Self::ready`] is always let through, so an operation
+/// reports as soon as it has something to say; later calls only once
+/// `interval` has passed since the last line.
+#[derive(Debug, Clone)]
+pub struct StatusThrottle {
+    interval: Duration,
+    last: Option<Instant>,
+}
+
+impl StatusThrottle {
+    #[must_use]
+    pub const fn new(interval: Duration) -> Self {
+        Self {
+            interval,
+            last: None,
+        }
+    }
+
+    /// Whether a line may be printed at `now`; records it if so.
+    pub fn ready(&mut self, now: Instant) -> bool {
+        if self
+            .last
+            .is_some_and(|last| now.duration_since(last) < self.interval)
+        {
+            return false;
+        }
+        self.last = Some(now);
+        true
+    }
+}
+
+impl Default for StatusThrottle {
+    fn default() -> Self {
+        Self::new(STATUS_INTERVAL)
+    }
+}
+
+/// Percentage of `done` out of `total`, or `None` while the total is unknown.
+#[must_use]
+#[allow(clippy::cast_precision_loss)]
+pub fn percent(done: u64, total: u64) -> Option<f64> {
+    (total > 0).then(|| (done.min(total) as f64 / total as f64) * 100.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn throttle_lets_the_first_line_through_then_waits() {
+        let start = Instant::now();
+        let mut throttle = StatusThrottle::new(Duration::from_secs(5));
+        assert!(throttle.ready(start));
+        assert!(!throttle.ready(start + Duration::from_secs(4)));
+        assert!(throttle.ready(start + Duration::from_secs(5)));
+        assert!(!throttle.ready(start + Duration::from_secs(6)));
+    }
+
+    #[test]
+    fn percent_needs_a_total() {
+        assert_eq!(percent(5, 0), None);
+        assert_eq!(percent(25, 100), Some(25.0));
+        assert_eq!(percent(150, 100), Some(100.0));
+    }
+}
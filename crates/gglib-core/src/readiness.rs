@@ -0,0 +1,147 @@
+//! Readiness probes shared by the web server and the proxy.
+//!
+//! `/healthz` only says the process is up. `/readyz` runs the checks in this
+//! module — plus any adapter-specific ones — and reports each subsystem, so
+//! an orchestrator or the GUI can tell "listening" apart from "able to serve
+//! a model".
+//!
+//! Each check settles on a [`CheckStatus`]: `down` means the subsystem cannot
+//! do its job (the report is then not ready), `degraded` means it works with
+//! reduced capability, e.g. models can be served but not downloaded.
+
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::paths::{llama_server_path, resolve_models_dir, verify_writable};
+
+/// Outcome of one readiness check, ordered from best to worst.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum CheckStatus {
+    Ok,
+    Degraded,
+    Down,
+}
+
+/// Status of one subsystem.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct SubsystemCheck {
+    /// Subsystem name, e.g. `database` or `llama_binary`.
+    pub name: String,
+    pub status: CheckStatus,
+    /// What was found, or why the check did not pass.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub detail: Option<String>,
+}
+
+impl SubsystemCheck {
+    pub fn ok(name: &str, detail: impl Into<String>) -> Self {
+        Self::new(name, CheckStatus::Ok, Some(detail.into()))
+    }
+
+    pub fn degraded(name: &str, detail: impl Into<String>) -> Self {
+        Self::new(name, CheckStatus::Degraded, Some(detail.into()))
+    }
+
+    pub fn down(name: &str, detail: impl Into<String>) -> Self {
+        Self::new(name, CheckStatus::Down, Some(detail.into()))
+    }
+
+    fn new(name: &str, status: CheckStatus, detail: Option<String>) -> Self {
+        Self {
+            name: name.to_string(),
+            status,
+            detail,
+        }
+    }
+}
+
+/// Combined readiness of every checked subsystem.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct ReadinessReport {
+    /// Worst status among the checks.
+    pub status: CheckStatus,
+    /// `false` when any check is down.
+    pub ready: bool,
+    pub checks: Vec<SubsystemCheck>,
+}
+
+impl ReadinessReport {
+    #[must_use]
+    pub fn new(checks: Vec<SubsystemCheck>) -> Self {
+        let status = checks
+            .iter()
+            .map(|c| c.status)
+            .max()
+            .unwrap_or(CheckStatus::Ok);
+        Self {
+            status,
+            ready: status != CheckStatus::Down,
+            checks,
+        }
+    }
+}
+
+/// The `database` check from the result of a settings read, which needs
+/// the connection and the schema to both be usable.
+pub fn check_database<T, E: fmt::Display>(settings_read: Result<T, E>) -> SubsystemCheck {
+    match settings_read {
+        Ok(_) => SubsystemCheck::ok("database", "settings readable"),
+        Err(e) => SubsystemCheck::down("database", e.to_string()),
+    }
+}
+
+/// The managed `llama-server` binary is installed.
+#[must_use]
+pub fn check_llama_binary() -> SubsystemCheck {
+    const NAME: &str = "llama_binary";
+    match llama_server_path() {
+        Ok(path) if path.is_file() => SubsystemCheck::ok(NAME, path.display().to_string()),
+        Ok(path) => SubsystemCheck::down(NAME, format!("not installed at {}", path.display())),
+        Err(e) => SubsystemCheck::down(NAME, e.to_string()),
+    }
+}
+
+/// The models directory exists and accepts writes. A read-only directory
+/// can still serve the models in it, so that is only `degraded`.
+#[must_use]
+pub fn check_models_dir() -> SubsystemCheck {
+    const NAME: &str = "models_dir";
+    let path = match resolve_models_dir(None) {
+        Ok(resolved) => resolved.path,
+        Err(e) => return SubsystemCheck::down(NAME, e.to_string()),
+    };
+    if !path.is_dir() {
+        return SubsystemCheck::down(NAME, format!("{} does not exist", path.display()));
+    }
+    match verify_writable(&path) {
+        Ok(()) => SubsystemCheck::ok(NAME, path.display().to_string()),
+        Err(e) => SubsystemCheck::degraded(NAME, e.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn report_takes_the_worst_status() {
+        let report = ReadinessReport::new(vec![
+            SubsystemCheck::ok("a", "fine"),
+            SubsystemCheck::degraded("b", "slow"),
+        ]);
+        assert_eq!(report.status, CheckStatus::Degraded);
+        assert!(report.ready);
+
+        let report = ReadinessReport::new(vec![
+            SubsystemCheck::degraded("b", "slow"),
+            SubsystemCheck::down("c", "gone"),
+        ]);
+        assert_eq!(report.status, CheckStatus::Down);
+        assert!(!report.ready);
+
+        assert!(ReadinessReport::new(Vec::new()).ready);
+    }
+}
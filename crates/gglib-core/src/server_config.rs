@@ -7,7 +7,7 @@
 use anyhow::{Result, anyhow};
 use std::path::PathBuf;
 
-use crate::domain::InferenceConfig;
+use crate::domain::{InferenceConfig, RopeConfig};
 use crate::settings::DEFAULT_CONTEXT_SIZE;
 
 // =============================================================================
@@ -152,6 +152,50 @@ pub struct ServerConfigOptions {
     /// Inference parameter overrides (temperature, top-p, etc.) forwarded
     /// directly to llama-server.
     pub inference_params: Option<InferenceConfig>,
+
+    /// `RoPE` scaling overrides (`--rope-scaling`, `--rope-freq-base`,
+    /// `--rope-freq-scale`, `--yarn-orig-ctx`). `None` or an empty config
+    /// leaves the GGUF metadata in charge. Direct pass-through, no tag-based
+    /// auto-detection.
+    pub rope: Option<RopeConfig>,
+
+    /// Explicit generation thread count (`--threads`). `None` resolves from
+    /// the CPU topology (see `gglib_runtime::llama::args::resolve_cpu_args`).
+    pub threads: Option<u32>,
+
+    /// Explicit prompt-processing thread count (`--threads-batch`). `None`
+    /// leaves llama-server to reuse the generation thread count.
+    pub threads_batch: Option<u32>,
+
+    /// Explicit CPU pinning (`--cpu-mask`). `None` pins to the performance
+    /// cores on a hybrid CPU and leaves placement to the OS otherwise.
+    pub cpu_affinity: Option<crate::cpu_config::CpuSet>,
+
+    /// NUMA placement strategy (`--numa`). Direct pass-through, never
+    /// auto-detected.
+    pub numa: Option<crate::cpu_config::NumaStrategy>,
+
+    /// Multi-GPU split mode (`--split-mode`). Direct pass-through, never
+    /// auto-detected.
+    pub split_mode: Option<crate::gpu_config::SplitMode>,
+
+    /// Explicit per-GPU share of the model (`--tensor-split`). `None`
+    /// splits by free VRAM when there are two or more GPUs (see
+    /// `gglib_runtime::llama::args::resolve_gpu_args`).
+    pub tensor_split: Option<crate::gpu_config::TensorSplit>,
+
+    /// Explicit main GPU (`--main-gpu`). `None` picks the GPU with the most
+    /// free VRAM under `--split-mode none` and passes no flag otherwise.
+    pub main_gpu: Option<u32>,
+
+    /// Parallel request slots (`--parallel`). `None` runs one slot; callers
+    /// that know the memory budget resolve a default first (see
+    /// `gglib_runtime::llama::args::resolve_parallel`).
+    pub parallel: Option<u32>,
+
+    /// Continuous batching (`--cont-batching` / `--no-cont-batching`).
+    /// `None` leaves llama-server's default (on). Direct pass-through.
+    pub cont_batching: Option<bool>,
 }
 
 // =============================================================================
@@ -170,6 +214,43 @@ pub fn resolve_context_size(opts: &ServerConfigOptions) -> u64 {
         .unwrap_or(DEFAULT_CONTEXT_SIZE)
 }
 
+/// A requested context size larger than the model can be served with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+#[error("Context size {requested} exceeds the model's maximum of {max} tokens")]
+pub struct ContextTooLarge {
+    pub requested: u64,
+    pub max: u64,
+}
+
+/// Check a requested context size against the model's maximum (see
+/// [`crate::domain::Model::max_context`]).
+///
+/// `0` (llama-server's "use the model's context") and an unknown maximum
+/// always pass.
+pub const fn check_context_size(requested: u64, max: Option<u64>) -> Result<(), ContextTooLarge> {
+    match max {
+        Some(max) if requested > max => Err(ContextTooLarge { requested, max }),
+        _ => Ok(()),
+    }
+}
+
+/// [`resolve_context_size`], held to the model's maximum `model_max_ctx`.
+///
+/// An explicit request (`opts.context_size`) above the maximum is an error.
+/// A default above it — per-model, global or hardcoded — is lowered to the
+/// maximum instead, since nobody asked for that size for this model.
+pub fn resolve_context_size_within(
+    opts: &ServerConfigOptions,
+    model_max_ctx: Option<u64>,
+) -> Result<u64, ContextTooLarge> {
+    if let Some(requested) = opts.context_size {
+        check_context_size(requested, model_max_ctx)?;
+        return Ok(requested);
+    }
+    let resolved = resolve_context_size(opts);
+    Ok(model_max_ctx.map_or(resolved, |max| resolved.min(max)))
+}
+
 // =============================================================================
 // Host-RAM prompt cache budget (`--cache-ram`)
 // =============================================================================
@@ -258,6 +339,34 @@ mod tests {
         assert_eq!(resolve_context_size(&opts), 0);
     }
 
+    use crate::server_config::{ContextTooLarge, resolve_context_size_within};
+
+    #[test]
+    fn explicit_context_above_the_model_maximum_is_rejected() {
+        let opts = ServerConfigOptions {
+            context_size: Some(65_536),
+            ..Default::default()
+        };
+        assert_eq!(
+            resolve_context_size_within(&opts, Some(32_768)),
+            Err(ContextTooLarge {
+                requested: 65_536,
+                max: 32_768
+            })
+        );
+        assert_eq!(resolve_context_size_within(&opts, None), Ok(65_536));
+    }
+
+    #[test]
+    fn defaults_above_the_model_maximum_are_lowered_to_it() {
+        let opts = ServerConfigOptions {
+            global_default_ctx: Some(8192),
+            ..Default::default()
+        };
+        assert_eq!(resolve_context_size_within(&opts, Some(2048)), Ok(2048));
+        assert_eq!(resolve_context_size_within(&opts, Some(32_768)), Ok(8192));
+    }
+
     // -------------------------------------------------------------------
     // CtxSizeArg / parse_ctx_size_flag
     // -------------------------------------------------------------------
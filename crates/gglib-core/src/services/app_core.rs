@@ -3,11 +3,16 @@
 //! This is the composition root for core services. Adapters (CLI, GUI, Web)
 //! receive an `AppCore` instance and use it to access all functionality.
 
-use crate::ports::{ProcessRunner, Repos};
+use crate::ports::{
+    AppEventEmitter, ConversationShareRepository, ProcessRunner, Repos, SecretStorePort,
+    TitleGeneratorPort,
+};
 use std::sync::Arc;
 
 use super::{
-    ChatHistoryService, ModelService, ModelVerificationService, ServerService, SettingsService,
+    AttachmentService, AuditLogService, ChatHistoryService, ModelService, ModelTrashService,
+    ModelUsageService, ModelVerificationService, PipelineService, PresetService, ProjectService,
+    ServerService, SettingsService,
 };
 
 /// The core application facade.
@@ -31,7 +36,14 @@ pub struct AppCore {
     settings: SettingsService,
     servers: ServerService,
     chat_history: ChatHistoryService,
+    projects: ProjectService,
+    presets: PresetService,
+    pipelines: PipelineService,
     verification: Option<Arc<ModelVerificationService>>,
+    attachments: Option<Arc<AttachmentService>>,
+    trash: Option<Arc<ModelTrashService>>,
+    usage: Option<Arc<ModelUsageService>>,
+    audit: Option<Arc<AuditLogService>>,
 }
 
 impl AppCore {
@@ -42,7 +54,14 @@ impl AppCore {
             settings: SettingsService::new(repos.settings),
             servers: ServerService::new(runner),
             chat_history: ChatHistoryService::new(repos.chat_history),
+            projects: ProjectService::new(repos.projects),
+            presets: PresetService::new(repos.presets),
+            pipelines: PipelineService::new(repos.pipeline_runs),
             verification: None,
+            attachments: None,
+            trash: None,
+            usage: None,
+            audit: None,
         }
     }
 
@@ -55,6 +74,75 @@ impl AppCore {
         self
     }
 
+    /// Set the attachment service (optional).
+    #[must_use]
+    pub fn with_attachments(mut self, attachments: Arc<AttachmentService>) -> Self {
+        self.attachments = Some(attachments);
+        self
+    }
+
+    /// Set the model trash service (optional).
+    ///
+    /// Without it, removing a model deletes its row outright and leaves its
+    /// files where they are.
+    #[must_use]
+    pub fn with_trash(mut self, trash: Arc<ModelTrashService>) -> Self {
+        self.trash = Some(trash);
+        self
+    }
+
+    /// Set the model usage service (optional).
+    #[must_use]
+    pub fn with_usage(mut self, usage: Arc<ModelUsageService>) -> Self {
+        self.usage = Some(usage);
+        self
+    }
+
+    /// Set the audit log service (optional).
+    ///
+    /// Without it, administrative actions are not recorded.
+    #[must_use]
+    pub fn with_audit(mut self, audit: Arc<AuditLogService>) -> Self {
+        self.audit = Some(audit);
+        self
+    }
+
+    /// Keep conversation share links in `shares` (optional).
+    ///
+    /// Sharing also needs [`with_secrets`](Self::with_secrets) for the
+    /// link signing key.
+    #[must_use]
+    pub fn with_chat_shares(mut self, shares: Arc<dyn ConversationShareRepository>) -> Self {
+        self.chat_history = self.chat_history.with_shares(shares);
+        self
+    }
+
+    /// Keep credentials from settings in `secrets` (optional).
+    ///
+    /// Without a store, credentials stay in the settings row as given and
+    /// chat history cannot be encrypted.
+    #[must_use]
+    pub fn with_secrets(mut self, secrets: Arc<dyn SecretStorePort>) -> Self {
+        self.chat_history = self.chat_history.with_secrets(Arc::clone(&secrets));
+        self.settings = self.settings.with_secrets(secrets);
+        self
+    }
+
+    /// Enable background conversation titling (optional).
+    ///
+    /// Title events are sent through `emitter` so the UIs can refresh.
+    #[must_use]
+    pub fn with_auto_title(
+        mut self,
+        generator: Arc<dyn TitleGeneratorPort>,
+        emitter: Arc<dyn AppEventEmitter>,
+    ) -> Self {
+        self.chat_history =
+            self.chat_history
+                .with_auto_title(generator, self.settings.repo(), emitter);
+        self
+    }
+
     /// Access the model service.
     pub const fn models(&self) -> &ModelService {
         &self.models
@@ -75,6 +163,41 @@ impl AppCore {
         &self.chat_history
     }
 
+    /// Access the project service.
+    pub const fn projects(&self) -> &ProjectService {
+        &self.projects
+    }
+
+    /// Access the preset service.
+    pub const fn presets(&self) -> &PresetService {
+        &self.presets
+    }
+
+    /// Access the pipeline service.
+    pub const fn pipelines(&self) -> &PipelineService {
+        &self.pipelines
+    }
+
+    /// Access the attachment service (if available).
+    pub fn attachments(&self) -> Option<&AttachmentService> {
+        self.attachments.as_deref()
+    }
+
+    /// Access the model trash service (if available).
+    pub fn trash(&self) -> Option<&ModelTrashService> {
+        self.trash.as_deref()
+    }
+
+    /// Access the model usage service (if available).
+    pub fn usage(&self) -> Option<&ModelUsageService> {
+        self.usage.as_deref()
+    }
+
+    /// Access the audit log service (if available).
+    pub fn audit(&self) -> Option<&AuditLogService> {
+        self.audit.as_deref()
+    }
+
     /// Access the verification service (if available).
     pub fn verification(&self) -> Option<&ModelVerificationService> {
         self.verification.as_deref()
@@ -85,14 +208,18 @@ impl AppCore {
 mod tests {
     use super::*;
     use crate::domain::chat::{
-        Conversation, ConversationUpdate, Message, NewConversation, NewMessage,
+        Conversation, ConversationUpdate, Message, MessageRevision, NewConversation, NewMessage,
     };
     use crate::domain::mcp::{McpServer, NewMcpServer};
-    use crate::domain::{Model, NewModel};
+    use crate::domain::{
+        Model, NewModel, NewPipelineRun, NewPreset, NewProject, PipelineRun, PipelineRunStatus,
+        Preset, PresetUpdate, Project, ProjectUpdate,
+    };
     use crate::ports::{
         ChatHistoryError, ChatHistoryRepository, McpRepositoryError, McpServerRepository,
-        ModelRepository, ProcessError, ProcessHandle, ProcessRunner, RepositoryError, ServerConfig,
-        ServerHealth, SettingsRepository,
+        ModelRepository, PipelineError, PipelineRunRepository, PresetError, PresetRepository,
+        ProcessError, ProcessHandle, ProcessRunner, ProjectError, ProjectRepository,
+        RepositoryError, ServerConfig, ServerHealth, SettingsRepository,
     };
     use crate::settings::Settings;
     use async_trait::async_trait;
@@ -201,11 +328,110 @@ mod tests {
         async fn delete_message_and_subsequent(&self, _id: i64) -> Result<i64, ChatHistoryError> {
             Ok(0)
         }
+        async fn get_message(&self, _id: i64) -> Result<Option<Message>, ChatHistoryError> {
+            Ok(None)
+        }
+        async fn supersede_message(
+            &self,
+            id: i64,
+            _content: String,
+            _metadata: Option<serde_json::Value>,
+        ) -> Result<MessageRevision, ChatHistoryError> {
+            Err(ChatHistoryError::MessageNotFound(id))
+        }
+        async fn get_message_versions(&self, _id: i64) -> Result<Vec<Message>, ChatHistoryError> {
+            Ok(vec![])
+        }
         async fn get_message_count(&self, _conversation_id: i64) -> Result<i64, ChatHistoryError> {
             Ok(0)
         }
     }
 
+    struct MockProjectRepo;
+
+    #[async_trait]
+    impl ProjectRepository for MockProjectRepo {
+        async fn create(&self, _project: NewProject) -> Result<i64, ProjectError> {
+            unimplemented!()
+        }
+        async fn list(&self) -> Result<Vec<Project>, ProjectError> {
+            Ok(vec![])
+        }
+        async fn get(&self, _id: i64) -> Result<Option<Project>, ProjectError> {
+            Ok(None)
+        }
+        async fn update(&self, id: i64, _update: ProjectUpdate) -> Result<(), ProjectError> {
+            Err(ProjectError::NotFound(id))
+        }
+        async fn delete(&self, _id: i64) -> Result<(), ProjectError> {
+            Ok(())
+        }
+        async fn link_conversation(&self, _cid: i64, pid: i64) -> Result<(), ProjectError> {
+            Err(ProjectError::NotFound(pid))
+        }
+        async fn unlink_conversation(&self, _cid: i64) -> Result<(), ProjectError> {
+            Ok(())
+        }
+        async fn get_for_conversation(&self, _cid: i64) -> Result<Option<Project>, ProjectError> {
+            Ok(None)
+        }
+    }
+
+    struct MockPresetRepo;
+
+    #[async_trait]
+    impl PresetRepository for MockPresetRepo {
+        async fn create(&self, _preset: NewPreset) -> Result<i64, PresetError> {
+            unimplemented!()
+        }
+        async fn list(&self) -> Result<Vec<Preset>, PresetError> {
+            Ok(vec![])
+        }
+        async fn get(&self, _id: i64) -> Result<Option<Preset>, PresetError> {
+            Ok(None)
+        }
+        async fn get_by_name(&self, _name: &str) -> Result<Option<Preset>, PresetError> {
+            Ok(None)
+        }
+        async fn update(&self, id: i64, _update: PresetUpdate) -> Result<(), PresetError> {
+            Err(PresetError::NotFound(id))
+        }
+        async fn delete(&self, _id: i64) -> Result<(), PresetError> {
+            Ok(())
+        }
+    }
+
+    struct MockPipelineRunRepo;
+
+    #[async_trait]
+    impl PipelineRunRepository for MockPipelineRunRepo {
+        async fn create_run(&self, _run: NewPipelineRun) -> Result<i64, PipelineError> {
+            unimplemented!()
+        }
+        async fn record_step(
+            &self,
+            _id: i64,
+            _step: &str,
+            _out: &str,
+        ) -> Result<(), PipelineError> {
+            unimplemented!()
+        }
+        async fn finish_run(
+            &self,
+            _id: i64,
+            _status: PipelineRunStatus,
+            _error: Option<&str>,
+        ) -> Result<(), PipelineError> {
+            unimplemented!()
+        }
+        async fn get(&self, _id: i64) -> Result<Option<PipelineRun>, PipelineError> {
+            Ok(None)
+        }
+        async fn list(&self) -> Result<Vec<PipelineRun>, PipelineError> {
+            Ok(vec![])
+        }
+    }
+
     struct MockSettingsRepo {
         settings: Mutex<Settings>,
     }
@@ -263,6 +489,9 @@ mod tests {
             settings: Arc::new(MockSettingsRepo::new()),
             mcp_servers: Arc::new(MockMcpRepo),
             chat_history: Arc::new(MockChatHistoryRepo),
+            projects: Arc::new(MockProjectRepo),
+            presets: Arc::new(MockPresetRepo),
+            pipeline_runs: Arc::new(MockPipelineRunRepo),
         };
         let runner = Arc::new(MockRunner);
 
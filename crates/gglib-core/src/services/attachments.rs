@@ -0,0 +1,415 @@
+//! Attachment service - blob storage and metadata for chat attachments.
+//!
+//! Uploaded bytes are written once to a content-addressed store,
+//! `{root}/{sha[..2]}/{sha}`, so identical uploads share a blob; metadata
+//! goes through the [`AttachmentRepository`] port. An upload's
+//! check-and-insert and a delete's count-and-remove hold the blob's lock,
+//! so a delete never removes a blob an upload just decided to reuse. Size and type limits
+//! come from settings on every upload, so changes apply without a restart.
+//!
+//! [`resolve_content`](AttachmentService::resolve_content) turns
+//! `attachment` content parts into parts a model can read: images become
+//! `image_url` data URLs and text files are inlined.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+use base64::Engine;
+use sha2::{Digest, Sha256};
+
+use crate::domain::MessageContent;
+use crate::domain::attachment::{
+    Attachment, NewAttachment, attachment_part_id, is_text_mime_type, mime_type_allowed,
+};
+use crate::ports::{AttachmentError, AttachmentRepository, SettingsRepository};
+
+/// Service for chat attachment operations.
+pub struct AttachmentService {
+    repo: Arc<dyn AttachmentRepository>,
+    settings: Arc<dyn SettingsRepository>,
+    root: PathBuf,
+    /// Per-blob locks, keyed by hash; entries nobody holds are pruned.
+    blob_locks: Mutex<HashMap<String, Arc<tokio::sync::Mutex<()>>>>,
+}
+
+impl AttachmentService {
+    /// Create a service storing blobs under `root`.
+    pub fn new(
+        repo: Arc<dyn AttachmentRepository>,
+        settings: Arc<dyn SettingsRepository>,
+        root: PathBuf,
+    ) -> Self {
+        Self {
+            repo,
+            settings,
+            root,
+            blob_locks: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Store an upload and record its metadata.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AttachmentError::TooLarge`] or
+    /// [`AttachmentError::UnsupportedType`] when the upload breaks the
+    /// configured limits.
+    pub async fn upload(
+        &self,
+        file_name: &str,
+        mime_type: &str,
+        bytes: &[u8],
+    ) -> Result<Attachment, AttachmentError> {
+        let settings = self
+            .settings
+            .load()
+            .await
+            .map_err(|e| AttachmentError::Database(e.to_string()))?;
+
+        let max = settings.effective_max_attachment_bytes();
+        let size = bytes.len() as u64;
+        if size > max {
+            return Err(AttachmentError::TooLarge { size, max });
+        }
+        if !mime_type_allowed(mime_type, &settings.effective_attachment_types()) {
+            return Err(AttachmentError::UnsupportedType(mime_type.to_string()));
+        }
+
+        let sha256 = format!("{:x}", Sha256::digest(bytes));
+        let lock = self.blob_lock(&sha256);
+        let _guard = lock.lock().await;
+        self.write_blob(&sha256, bytes).await?;
+
+        #[allow(clippy::cast_possible_wrap)] // bounded by the size limit above
+        let id = self
+            .repo
+            .insert(NewAttachment {
+                sha256,
+                file_name: sanitize_file_name(file_name),
+                mime_type: mime_type.to_string(),
+                size_bytes: size as i64,
+            })
+            .await?;
+        self.get(id).await
+    }
+
+    /// Get attachment metadata.
+    pub async fn get(&self, id: i64) -> Result<Attachment, AttachmentError> {
+        self.repo
+            .get(id)
+            .await?
+            .ok_or(AttachmentError::NotFound(id))
+    }
+
+    /// Get attachment metadata together with its contents.
+    pub async fn read(&self, id: i64) -> Result<(Attachment, Vec<u8>), AttachmentError> {
+        let attachment = self.get(id).await?;
+        let bytes = tokio::fs::read(self.blob_path(&attachment.sha256))
+            .await
+            .map_err(|e| AttachmentError::Storage(e.to_string()))?;
+        Ok((attachment, bytes))
+    }
+
+    /// Attach uploaded attachments to a saved message.
+    pub async fn link_to_message(
+        &self,
+        message_id: i64,
+        ids: &[i64],
+    ) -> Result<(), AttachmentError> {
+        if ids.is_empty() {
+            return Ok(());
+        }
+        self.repo.link_to_message(ids, message_id).await
+    }
+
+    /// List the attachments of a message.
+    pub async fn list_for_message(
+        &self,
+        message_id: i64,
+    ) -> Result<Vec<Attachment>, AttachmentError> {
+        self.repo.list_for_message(message_id).await
+    }
+
+    /// Delete an attachment, removing its blob once nothing else shares it.
+    pub async fn delete(&self, id: i64) -> Result<(), AttachmentError> {
+        let attachment = self.get(id).await?;
+        let lock = self.blob_lock(&attachment.sha256);
+        let _guard = lock.lock().await;
+        self.repo.delete(id).await?;
+        if self.repo.count_by_sha256(&attachment.sha256).await? == 0 {
+            match tokio::fs::remove_file(self.blob_path(&attachment.sha256)).await {
+                Ok(()) => {}
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+                Err(e) => return Err(AttachmentError::Storage(e.to_string())),
+            }
+        }
+        Ok(())
+    }
+
+    /// Replace `attachment` parts in `content` with model-readable parts.
+    ///
+    /// Images become `image_url` parts carrying a data URL; text files
+    /// become `text` parts wrapped in a `<file>` tag; anything else is
+    /// described by name so the model at least knows it was attached.
+    pub async fn resolve_content(
+        &self,
+        content: MessageContent,
+    ) -> Result<MessageContent, AttachmentError> {
+        let MessageContent::Parts(parts) = content else {
+            return Ok(content);
+        };
+        let mut resolved = Vec::with_capacity(parts.len());
+        for part in parts {
+            match attachment_part_id(&part) {
+                Some(id) => resolved.push(self.inline_part(id).await?),
+                None => resolved.push(part),
+            }
+        }
+        Ok(MessageContent::Parts(resolved))
+    }
+
+    async fn inline_part(&self, id: i64) -> Result<serde_json::Value, AttachmentError> {
+        let (attachment, bytes) = self.read(id).await?;
+        let part = if attachment.mime_type.starts_with("image/") {
+            let data = base64::engine::general_purpose::STANDARD.encode(&bytes);
+            serde_json::json!({
+                "type": "image_url",
+                "image_url": { "url": format!("data:{};base64,{data}", attachment.mime_type) },
+            })
+        } else if is_text_mime_type(&attachment.mime_type) {
+            serde_json::json!({
+                "type": "text",
+                "text": format!(
+                    "<file name=\"{}\">\n{}\n</file>",
+                    attachment.file_name,
+                    String::from_utf8_lossy(&bytes)
+                ),
+            })
+        } else {
+            serde_json::json!({
+                "type": "text",
+                "text": format!(
+                    "[Attached file: {} ({}, {} bytes)]",
+                    attachment.file_name, attachment.mime_type, attachment.size_bytes
+                ),
+            })
+        };
+        Ok(part)
+    }
+
+    fn blob_path(&self, sha256: &str) -> PathBuf {
+        blob_path(&self.root, sha256)
+    }
+
+    /// The lock serialising uploads and deletes of the blob `sha256`.
+    fn blob_lock(&self, sha256: &str) -> Arc<tokio::sync::Mutex<()>> {
+        let mut locks = self
+            .blob_locks
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        locks.retain(|_, lock| Arc::strong_count(lock) > 1);
+        Arc::clone(
+            locks
+                .entry(sha256.to_string())
+                .or_insert_with(|| Arc::new(tokio::sync::Mutex::new(()))),
+        )
+    }
+
+    /// Write `bytes` under `sha256` unless that blob already exists.
+    ///
+    /// Writes go to a temporary file first so a crash never leaves a
+    /// truncated blob under its final name.
+    async fn write_blob(&self, sha256: &str, bytes: &[u8]) -> Result<(), AttachmentError> {
+        let path = self.blob_path(sha256);
+        if tokio::fs::try_exists(&path).await.unwrap_or(false) {
+            return Ok(());
+        }
+        let storage = |e: std::io::Error| AttachmentError::Storage(e.to_string());
+        if let Some(dir) = path.parent() {
+            tokio::fs::create_dir_all(dir).await.map_err(storage)?;
+        }
+        let tmp = path.with_extension(format!("{}.tmp", uuid::Uuid::new_v4()));
+        tokio::fs::write(&tmp, bytes).await.map_err(storage)?;
+        tokio::fs::rename(&tmp, &path).await.map_err(storage)
+    }
+}
+
+/// On-disk location of the blob `sha256` under `root`.
+fn blob_path(root: &Path, sha256: &str) -> PathBuf {
+    root.join(&sha256[..2.min(sha256.len())]).join(sha256)
+}
+
+/// The final path component of `name`, or `"attachment"` when empty.
+fn sanitize_file_name(name: &str) -> String {
+    let base = name.rsplit(['/', '\\']).next().unwrap_or_default().trim();
+    if base.is_empty() {
+        "attachment".to_string()
+    } else {
+        base.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use async_trait::async_trait;
+
+    use super::*;
+    use crate::ports::RepositoryError;
+    use crate::settings::Settings;
+
+    #[derive(Default)]
+    struct MemoryRepo {
+        rows: Mutex<Vec<Attachment>>,
+    }
+
+    #[async_trait]
+    impl AttachmentRepository for MemoryRepo {
+        async fn insert(&self, a: NewAttachment) -> Result<i64, AttachmentError> {
+            let mut rows = self.rows.lock().unwrap();
+            let id = i64::try_from(rows.len()).unwrap() + 1;
+            rows.push(Attachment {
+                id,
+                message_id: None,
+                sha256: a.sha256,
+                file_name: a.file_name,
+                mime_type: a.mime_type,
+                size_bytes: a.size_bytes,
+                created_at: String::new(),
+            });
+            drop(rows);
+            Ok(id)
+        }
+        async fn get(&self, id: i64) -> Result<Option<Attachment>, AttachmentError> {
+            Ok(self
+                .rows
+                .lock()
+                .unwrap()
+                .iter()
+                .find(|a| a.id == id)
+                .cloned())
+        }
+        async fn link_to_message(&self, _ids: &[i64], _mid: i64) -> Result<(), AttachmentError> {
+            Ok(())
+        }
+        async fn list_for_message(&self, _mid: i64) -> Result<Vec<Attachment>, AttachmentError> {
+            Ok(vec![])
+        }
+        async fn delete(&self, id: i64) -> Result<(), AttachmentError> {
+            self.rows.lock().unwrap().retain(|a| a.id != id);
+            Ok(())
+        }
+        async fn count_by_sha256(&self, sha256: &str) -> Result<i64, AttachmentError> {
+            let rows = self.rows.lock().unwrap();
+            let count = rows.iter().filter(|a| a.sha256 == sha256).count();
+            drop(rows);
+            Ok(i64::try_from(count).unwrap())
+        }
+    }
+
+    struct FixedSettings(Settings);
+
+    #[async_trait]
+    impl SettingsRepository for FixedSettings {
+        async fn load(&self) -> Result<Settings, RepositoryError> {
+            Ok(self.0.clone())
+        }
+        async fn save(&self, _settings: &Settings) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+    }
+
+    fn service(root: &Path, settings: Settings) -> AttachmentService {
+        AttachmentService::new(
+            Arc::new(MemoryRepo::default()),
+            Arc::new(FixedSettings(settings)),
+            root.to_path_buf(),
+        )
+    }
+
+    #[tokio::test]
+    async fn identical_uploads_share_a_blob_until_both_are_deleted() {
+        let dir = tempfile::tempdir().unwrap();
+        let svc = service(dir.path(), Settings::default());
+
+        let a = svc
+            .upload("notes.txt", "text/plain", b"hello")
+            .await
+            .unwrap();
+        let b = svc
+            .upload("../copy.txt", "text/plain", b"hello")
+            .await
+            .unwrap();
+        assert_eq!(a.sha256, b.sha256);
+        assert_eq!(b.file_name, "copy.txt");
+
+        let blob = blob_path(dir.path(), &a.sha256);
+        svc.delete(a.id).await.unwrap();
+        assert!(blob.exists());
+        svc.delete(b.id).await.unwrap();
+        assert!(!blob.exists());
+    }
+
+    #[tokio::test]
+    async fn uploads_outside_the_limits_are_rejected() {
+        let dir = tempfile::tempdir().unwrap();
+        let svc = service(
+            dir.path(),
+            Settings {
+                max_attachment_size_mb: Some(1),
+                ..Settings::default()
+            },
+        );
+
+        let big = vec![0u8; 1024 * 1024 + 1];
+        assert!(matches!(
+            svc.upload("big.png", "image/png", &big).await,
+            Err(AttachmentError::TooLarge { .. })
+        ));
+        assert!(matches!(
+            svc.upload("a.zip", "application/zip", b"PK").await,
+            Err(AttachmentError::UnsupportedType(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn attachment_parts_resolve_to_model_content() {
+        let dir = tempfile::tempdir().unwrap();
+        let svc = service(dir.path(), Settings::default());
+        let text = svc.upload("a.md", "text/markdown", b"# Hi").await.unwrap();
+        let image = svc.upload("a.png", "image/png", b"\x89PNG").await.unwrap();
+
+        let content = MessageContent::Parts(vec![
+            crate::domain::attachment::attachment_part(text.id),
+            crate::domain::attachment::attachment_part(image.id),
+        ]);
+        let MessageContent::Parts(parts) = svc.resolve_content(content).await.unwrap() else {
+            panic!("expected parts");
+        };
+        assert_eq!(parts[0]["text"], "<file name=\"a.md\">\n# Hi\n</file>");
+        assert_eq!(
+            parts[1]["image_url"]["url"],
+            "data:image/png;base64,iVBORw=="
+        );
+    }
+
+    #[tokio::test]
+    async fn an_upload_racing_a_delete_keeps_its_blob() {
+        let dir = tempfile::tempdir().unwrap();
+        let svc = service(dir.path(), Settings::default());
+
+        for _ in 0..50 {
+            let old = svc.upload("a.txt", "text/plain", b"shared").await.unwrap();
+            let (new, deleted) = tokio::join!(
+                svc.upload("b.txt", "text/plain", b"shared"),
+                svc.delete(old.id)
+            );
+            deleted.unwrap();
+            let new = new.unwrap();
+            assert!(blob_path(dir.path(), &new.sha256).exists());
+            svc.delete(new.id).await.unwrap();
+        }
+    }
+}
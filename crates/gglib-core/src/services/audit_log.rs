@@ -0,0 +1,45 @@
+//! Audit log service - records administrative actions and lists them back.
+//!
+//! Recording never fails the action being recorded: a write that does not
+//! make it to storage is logged and dropped.
+
+use std::sync::Arc;
+
+use crate::domain::audit::{AuditAction, AuditEntry, AuditFilter, NewAuditEntry};
+use crate::ports::{AuditLogRepository, CoreError};
+
+/// Service for the audit trail of administrative actions.
+pub struct AuditLogService {
+    repo: Arc<dyn AuditLogRepository>,
+}
+
+impl AuditLogService {
+    /// Create a service storing entries in `repo`.
+    pub fn new(repo: Arc<dyn AuditLogRepository>) -> Self {
+        Self { repo }
+    }
+
+    /// Record that `actor` took `action` on `target`.
+    pub async fn record(
+        &self,
+        actor: String,
+        action: AuditAction,
+        target: Option<String>,
+        summary: Option<String>,
+    ) {
+        let entry = NewAuditEntry {
+            actor,
+            action,
+            target,
+            summary,
+        };
+        if let Err(e) = self.repo.record(&entry).await {
+            tracing::warn!(action = %entry.action, actor = %entry.actor, "Could not record audit entry: {e}");
+        }
+    }
+
+    /// Entries matching `filter`, newest first.
+    pub async fn list(&self, filter: &AuditFilter) -> Result<Vec<AuditEntry>, CoreError> {
+        Ok(self.repo.list(filter).await?)
+    }
+}
@@ -2,11 +2,121 @@
 //!
 //! This service provides a clean interface for chat history operations,
 //! delegating all persistence to the `ChatHistoryRepository` port.
+//!
+//! When wired with a [`TitleGeneratorPort`], saving the first assistant
+//! reply of a conversation that still has a placeholder title kicks off a
+//! background task that asks the model for a short title, stores it and
+//! emits [`AppEvent::ConversationUpdated`].
+//!
+//! When wired with a [`SecretStorePort`], [`ChatHistoryService::apply_encryption`]
+//! keeps message content encrypted at rest under a key held in that store.
+//!
+//! Conversations created with `incognito` set live in an in-memory store
+//! instead of the repository (see [`incognito_history`](super::incognito_history)).
+//! Every call routes by ID sign, so callers use the same API for both and
+//! only the merged [`ChatHistoryService::list_conversations`] shows them side
+//! by side. [`ChatHistoryService::discard_incognito`] drops them all.
+//!
+//! When wired with a [`ConversationShareRepository`] and a secret store,
+//! [`ChatHistoryService::create_share`] hands out signed, expiring
+//! read-only links to a conversation (see
+//! [`chat_share`](crate::domain::chat_share)).
+//!
+//! [`ChatHistoryService::import_conversations`] stores conversations parsed
+//! from a `ChatGPT` or Claude data export (see
+//! [`chat_import`](crate::domain::chat_import)).
 
 use std::sync::Arc;
 
-use crate::domain::chat::{Conversation, ConversationUpdate, Message, NewConversation, NewMessage};
-use crate::ports::chat_history::{ChatHistoryError, ChatHistoryRepository};
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use chrono::{Duration, Utc};
+
+use crate::domain::Model;
+use crate::domain::chat::{
+    Conversation, ConversationSettings, ConversationUpdate, Message, MessageRevision, MessageRole,
+    NewConversation, NewMessage,
+};
+use crate::domain::chat_import::ImportedConversation;
+use crate::domain::chat_share::{
+    ConversationShare, DEFAULT_SHARE_DAYS, MAX_SHARE_DAYS, ShareRecord, ShareSigner,
+    SharedConversation,
+};
+use crate::events::AppEvent;
+use crate::normalize::history::strip_think_blocks;
+use crate::ports::chat_history::{ChatContentKey, ChatHistoryError, ChatHistoryRepository};
+use crate::ports::{
+    AppEventEmitter, CHAT_HISTORY_KEY_SECRET, CHAT_SHARE_KEY_SECRET, ConversationShareRepository,
+    DEFAULT_TITLE_GENERATION_PROMPT, SecretStorePort, SettingsRepository, TitleGeneratorPort,
+};
+
+use super::incognito_history::{IncognitoHistory, is_incognito_id};
+
+/// Titles the UIs give a conversation before it has one of its own.
+const PLACEHOLDER_TITLES: &[&str] = &["New Chat", "New Conversation"];
+
+/// Longest title stored, in characters.
+const MAX_TITLE_CHARS: usize = 100;
+
+/// Collaborators for background title generation.
+#[derive(Clone)]
+struct AutoTitle {
+    generator: Arc<dyn TitleGeneratorPort>,
+    settings: Arc<dyn SettingsRepository>,
+    emitter: Arc<dyn AppEventEmitter>,
+}
+
+impl AutoTitle {
+    /// Title `conversation_id` if it is enabled, still untitled and has
+    /// exactly one exchange so far.
+    async fn run(
+        &self,
+        repo: &dyn ChatHistoryRepository,
+        conversation_id: i64,
+    ) -> anyhow::Result<()> {
+        let settings = self.settings.load().await?;
+        if settings.auto_title_conversations == Some(false) {
+            return Ok(());
+        }
+        let Some(conversation) = repo.get_conversation(conversation_id).await? else {
+            return Ok(());
+        };
+        if !is_placeholder_title(&conversation.title) {
+            return Ok(());
+        }
+        let messages = repo.get_messages(conversation_id).await?;
+        if !is_first_exchange(&messages) {
+            return Ok(());
+        }
+
+        let prompt = settings
+            .title_generation_prompt
+            .as_deref()
+            .filter(|p| !p.trim().is_empty())
+            .unwrap_or(DEFAULT_TITLE_GENERATION_PROMPT);
+        let raw = self
+            .generator
+            .generate_title(&conversation, &messages, prompt)
+            .await?;
+        let Some(title) = clean_title(&raw) else {
+            return Ok(());
+        };
+
+        repo.update_conversation(
+            conversation_id,
+            ConversationUpdate {
+                title: Some(title.clone()),
+                system_prompt: None,
+                settings: None,
+                model_id: None,
+            },
+        )
+        .await?;
+        self.emitter
+            .emit(AppEvent::conversation_updated(conversation_id, title));
+        Ok(())
+    }
+}
 
 /// Service for managing chat history.
 ///
@@ -15,12 +125,138 @@ use crate::ports::chat_history::{ChatHistoryError, ChatHistoryRepository};
 /// belong in the repository layer.
 pub struct ChatHistoryService {
     repo: Arc<dyn ChatHistoryRepository>,
+    incognito: Arc<IncognitoHistory>,
+    auto_title: Option<AutoTitle>,
+    secrets: Option<Arc<dyn SecretStorePort>>,
+    shares: Option<Arc<dyn ConversationShareRepository>>,
 }
 
 impl ChatHistoryService {
     /// Create a new chat history service.
     pub fn new(repo: Arc<dyn ChatHistoryRepository>) -> Self {
-        Self { repo }
+        Self {
+            repo,
+            incognito: Arc::default(),
+            auto_title: None,
+            secrets: None,
+            shares: None,
+        }
+    }
+
+    /// The store holding conversation or message `id`.
+    fn repo_for(&self, id: i64) -> Arc<dyn ChatHistoryRepository> {
+        if is_incognito_id(id) {
+            Arc::clone(&self.incognito) as Arc<dyn ChatHistoryRepository>
+        } else {
+            Arc::clone(&self.repo)
+        }
+    }
+
+    /// Whether `id` names an incognito conversation or message.
+    #[must_use]
+    pub const fn is_incognito(id: i64) -> bool {
+        is_incognito_id(id)
+    }
+
+    /// Drop every incognito conversation and its messages.
+    ///
+    /// Called on app exit; returns how many conversations were discarded.
+    pub fn discard_incognito(&self) -> usize {
+        let discarded = self.incognito.clear();
+        if discarded > 0 {
+            tracing::debug!(discarded, "Discarded incognito conversations");
+        }
+        discarded
+    }
+
+    /// Keep the message encryption key in `secrets`.
+    ///
+    /// Required before [`apply_encryption`](Self::apply_encryption) can
+    /// turn encryption on.
+    #[must_use]
+    pub fn with_secrets(mut self, secrets: Arc<dyn SecretStorePort>) -> Self {
+        self.secrets = Some(secrets);
+        self
+    }
+
+    /// Keep share links in `shares`.
+    ///
+    /// Sharing also needs a secret store (see
+    /// [`with_secrets`](Self::with_secrets)) for the signing key.
+    #[must_use]
+    pub fn with_shares(mut self, shares: Arc<dyn ConversationShareRepository>) -> Self {
+        self.shares = Some(shares);
+        self
+    }
+
+    /// Bring stored message content in line with the
+    /// `encrypt_chat_history` setting.
+    ///
+    /// Enabling creates the key on first use, then encrypts every plaintext
+    /// message; disabling decrypts every sealed message. The key stays in
+    /// the secret store either way, so running this again (e.g. at startup)
+    /// finishes a pass that was interrupted. Returns the number of messages
+    /// rewritten.
+    ///
+    /// # Errors
+    ///
+    /// Fails when enabling without a secret store, when the stored key
+    /// cannot be read, or when a message cannot be rewritten.
+    pub async fn apply_encryption(&self, enabled: bool) -> Result<u64, ChatHistoryError> {
+        let Some(secrets) = &self.secrets else {
+            if enabled {
+                return Err(ChatHistoryError::Encryption(
+                    "no secret store is configured for the chat history key".to_string(),
+                ));
+            }
+            return Ok(0);
+        };
+        let secret_error = |e: crate::ports::SecretStoreError| {
+            ChatHistoryError::Encryption(format!("chat history key: {e}"))
+        };
+
+        let key = match secrets.get(CHAT_HISTORY_KEY_SECRET).map_err(secret_error)? {
+            Some(text) => ChatContentKey::from_base64(&text)?,
+            // Nothing was ever encrypted, so there is nothing to undo.
+            None if !enabled => return Ok(0),
+            None => {
+                let key = ChatContentKey::generate()?;
+                secrets
+                    .set(CHAT_HISTORY_KEY_SECRET, &key.to_base64())
+                    .map_err(secret_error)?;
+                key
+            }
+        };
+
+        self.repo.set_content_encryption(Some(key), enabled)?;
+        let rewritten = self.repo.reseal_messages().await?;
+        if rewritten > 0 {
+            tracing::info!(
+                rewritten,
+                encrypted = enabled,
+                "Rewrote chat messages for the encryption setting"
+            );
+        }
+        Ok(rewritten)
+    }
+
+    /// Title new conversations with `generator` after their first exchange.
+    ///
+    /// `settings` is read on every attempt so the
+    /// `auto_title_conversations` opt-out applies without a restart.
+    #[must_use]
+    pub fn with_auto_title(
+        mut self,
+        generator: Arc<dyn TitleGeneratorPort>,
+        settings: Arc<dyn SettingsRepository>,
+        emitter: Arc<dyn AppEventEmitter>,
+    ) -> Self {
+        self.auto_title = Some(AutoTitle {
+            generator,
+            settings,
+            emitter,
+        });
+        self
     }
 
     /// Create a new conversation.
@@ -36,21 +272,46 @@ impl ChatHistoryService {
                 model_id,
                 system_prompt,
                 settings: None,
+                incognito: false,
             })
             .await
     }
 
     /// Create a new conversation with session settings for resume.
+    ///
+    /// With `incognito` set the conversation is kept in memory only.
     pub async fn create_conversation_with_settings(
         &self,
         conv: NewConversation,
     ) -> Result<i64, ChatHistoryError> {
-        self.repo.create_conversation(conv).await
+        if conv.incognito {
+            self.incognito.create_conversation(conv).await
+        } else {
+            self.repo.create_conversation(conv).await
+        }
     }
 
-    /// List all conversations, ordered by most recently updated.
+    /// Store conversations read from another app's export, keeping their
+    /// original timestamps. Returns the new conversation IDs in order.
+    pub async fn import_conversations(
+        &self,
+        conversations: &[ImportedConversation],
+    ) -> Result<Vec<i64>, ChatHistoryError> {
+        let mut ids = Vec::with_capacity(conversations.len());
+        for conversation in conversations {
+            ids.push(self.repo.import_conversation(conversation).await?);
+        }
+        Ok(ids)
+    }
+
+    /// List all conversations, incognito ones included, ordered by most
+    /// recently updated.
     pub async fn list_conversations(&self) -> Result<Vec<Conversation>, ChatHistoryError> {
-        self.repo.list_conversations().await
+        let mut conversations = self.incognito.list_conversations().await?;
+        conversations.extend(self.repo.list_conversations().await?);
+        // Stable sort: on equal timestamps incognito conversations stay first.
+        conversations.sort_by(|a, b| b.updated_at.cmp(&a.updated_at));
+        Ok(conversations)
     }
 
     /// Get a specific conversation by ID.
@@ -58,7 +319,7 @@ impl ChatHistoryService {
         &self,
         id: i64,
     ) -> Result<Option<Conversation>, ChatHistoryError> {
-        self.repo.get_conversation(id).await
+        self.repo_for(id).get_conversation(id).await
     }
 
     /// Update conversation metadata.
@@ -68,26 +329,71 @@ impl ChatHistoryService {
         new_title: Option<String>,
         system_prompt: Option<Option<String>>,
     ) -> Result<(), ChatHistoryError> {
-        self.repo
+        self.repo_for(id)
             .update_conversation(
                 id,
                 ConversationUpdate {
                     title: new_title,
                     system_prompt,
                     settings: None,
+                    model_id: None,
+                },
+            )
+            .await
+    }
+
+    /// Replace the session settings saved with a conversation.
+    pub async fn update_conversation_settings(
+        &self,
+        id: i64,
+        settings: Option<ConversationSettings>,
+    ) -> Result<(), ChatHistoryError> {
+        self.repo_for(id)
+            .update_conversation(
+                id,
+                ConversationUpdate {
+                    title: None,
+                    system_prompt: None,
+                    settings: Some(settings),
+                    model_id: None,
                 },
             )
             .await
     }
 
+    /// Bind a conversation to `model`.
+    ///
+    /// Sets its `model_id` and records the model's name, repo and
+    /// quantization in its settings, keeping the saved sampling parameters.
+    pub async fn bind_model(&self, id: i64, model: &Model) -> Result<(), ChatHistoryError> {
+        let repo = self.repo_for(id);
+        let conversation = repo
+            .get_conversation(id)
+            .await?
+            .ok_or(ChatHistoryError::ConversationNotFound(id))?;
+        let mut settings = conversation.settings.unwrap_or_default();
+        settings.bind_model(model);
+        repo.update_conversation(
+            id,
+            ConversationUpdate {
+                title: None,
+                system_prompt: None,
+                settings: Some(Some(settings)),
+                model_id: Some(model.id),
+            },
+        )
+        .await
+    }
+
     /// Delete a conversation and all its messages.
     pub async fn delete_conversation(&self, id: i64) -> Result<(), ChatHistoryError> {
-        self.repo.delete_conversation(id).await
+        self.repo_for(id).delete_conversation(id).await
     }
 
-    /// Get conversation count.
+    /// Get conversation count, incognito ones included.
     pub async fn get_conversation_count(&self) -> Result<i64, ChatHistoryError> {
-        self.repo.get_conversation_count().await
+        Ok(self.repo.get_conversation_count().await?
+            + self.incognito.get_conversation_count().await?)
     }
 
     /// Get all messages for a conversation.
@@ -95,12 +401,32 @@ impl ChatHistoryService {
         &self,
         conversation_id: i64,
     ) -> Result<Vec<Message>, ChatHistoryError> {
-        self.repo.get_messages(conversation_id).await
+        self.repo_for(conversation_id)
+            .get_messages(conversation_id)
+            .await
     }
 
     /// Save a new message.
+    ///
+    /// Saving an assistant reply may start background title generation;
+    /// see [`with_auto_title`](Self::with_auto_title).
     pub async fn save_message(&self, msg: NewMessage) -> Result<i64, ChatHistoryError> {
-        self.repo.save_message(msg).await
+        let conversation_id = msg.conversation_id;
+        let is_reply = msg.role == MessageRole::Assistant;
+        let repo = self.repo_for(conversation_id);
+        let id = repo.save_message(msg).await?;
+
+        if is_reply && let Some(auto_title) = self.auto_title.clone() {
+            tokio::spawn(async move {
+                if let Err(e) = auto_title.run(repo.as_ref(), conversation_id).await {
+                    tracing::warn!(
+                        conversation_id,
+                        "Failed to generate conversation title: {e}"
+                    );
+                }
+            });
+        }
+        Ok(id)
     }
 
     /// Update a message's content and optionally its metadata.
@@ -110,16 +436,319 @@ impl ChatHistoryService {
         content: String,
         metadata: Option<serde_json::Value>,
     ) -> Result<(), ChatHistoryError> {
-        self.repo.update_message(id, content, metadata).await
+        self.repo_for(id)
+            .update_message(id, content, metadata)
+            .await
     }
 
     /// Delete a message and all subsequent messages.
     pub async fn delete_message_and_subsequent(&self, id: i64) -> Result<i64, ChatHistoryError> {
-        self.repo.delete_message_and_subsequent(id).await
+        self.repo_for(id).delete_message_and_subsequent(id).await
     }
 
     /// Get message count for a conversation.
     pub async fn get_message_count(&self, conversation_id: i64) -> Result<i64, ChatHistoryError> {
-        self.repo.get_message_count(conversation_id).await
+        self.repo_for(conversation_id)
+            .get_message_count(conversation_id)
+            .await
+    }
+
+    /// Replace an assistant reply with a regenerated one.
+    ///
+    /// The old reply and anything after it are superseded rather than
+    /// deleted, so they stay available from
+    /// [`get_message_versions`](Self::get_message_versions).
+    pub async fn regenerate_message(
+        &self,
+        id: i64,
+        content: String,
+        metadata: Option<serde_json::Value>,
+    ) -> Result<MessageRevision, ChatHistoryError> {
+        self.revise(id, MessageRole::Assistant, content, metadata)
+            .await
+    }
+
+    /// Replace a user message with an edited one.
+    ///
+    /// Every message after it is invalidated (superseded) so the caller can
+    /// generate a fresh reply to the edited prompt.
+    pub async fn edit_message(
+        &self,
+        id: i64,
+        content: String,
+        metadata: Option<serde_json::Value>,
+    ) -> Result<MessageRevision, ChatHistoryError> {
+        self.revise(id, MessageRole::User, content, metadata).await
+    }
+
+    /// Get every version of a message, oldest first.
+    pub async fn get_message_versions(&self, id: i64) -> Result<Vec<Message>, ChatHistoryError> {
+        self.repo_for(id).get_message_versions(id).await
+    }
+
+    /// Create a read-only share link to conversation `conversation_id`,
+    /// valid for `days` days ([`DEFAULT_SHARE_DAYS`] when `None`, at most
+    /// [`MAX_SHARE_DAYS`]).
+    ///
+    /// Expired shares of every conversation are deleted on the way.
+    ///
+    /// # Errors
+    ///
+    /// Fails when sharing is not configured, the conversation does not exist
+    /// or is incognito, or `days` is out of range.
+    pub async fn create_share(
+        &self,
+        conversation_id: i64,
+        days: Option<u32>,
+    ) -> Result<ConversationShare, ChatHistoryError> {
+        let days = days.unwrap_or(DEFAULT_SHARE_DAYS);
+        if !(1..=MAX_SHARE_DAYS).contains(&days) {
+            return Err(ChatHistoryError::NotShareable(format!(
+                "share links last 1 to {MAX_SHARE_DAYS} days, not {days}"
+            )));
+        }
+        if is_incognito_id(conversation_id) {
+            return Err(ChatHistoryError::NotShareable(
+                "incognito conversations cannot be shared".to_string(),
+            ));
+        }
+        let shares = self.shares()?;
+        let signer = self.share_signer()?;
+        if self.repo.get_conversation(conversation_id).await?.is_none() {
+            return Err(ChatHistoryError::ConversationNotFound(conversation_id));
+        }
+
+        let now = Utc::now();
+        let purged = shares.delete_expired(now).await?;
+        if purged > 0 {
+            tracing::debug!(purged, "Deleted expired conversation shares");
+        }
+        let record = shares
+            .create(conversation_id, now + Duration::days(i64::from(days)))
+            .await?;
+        Ok(with_token(&signer, record))
+    }
+
+    /// Every share link to conversation `conversation_id`, newest first.
+    /// Expired links are included until the next
+    /// [`create_share`](Self::create_share) deletes them.
+    pub async fn list_shares(
+        &self,
+        conversation_id: i64,
+    ) -> Result<Vec<ConversationShare>, ChatHistoryError> {
+        let shares = self.shares()?;
+        // Without a key no link was ever handed out.
+        let Some(signer) = self.stored_share_signer()? else {
+            return Ok(Vec::new());
+        };
+        Ok(shares
+            .list(conversation_id)
+            .await?
+            .into_iter()
+            .map(|record| with_token(&signer, record))
+            .collect())
+    }
+
+    /// Revoke share link `share_id`; the link stops opening at once.
+    pub async fn revoke_share(&self, share_id: i64) -> Result<(), ChatHistoryError> {
+        if self.shares()?.delete(share_id).await? {
+            Ok(())
+        } else {
+            Err(ChatHistoryError::ShareNotFound)
+        }
+    }
+
+    /// The conversation a share link opens.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ChatHistoryError::ShareNotFound`] when the token's
+    /// signature does not match, or its share was revoked or has expired.
+    pub async fn open_share(&self, token: &str) -> Result<SharedConversation, ChatHistoryError> {
+        let shares = self.shares()?;
+        let share_id = self
+            .stored_share_signer()?
+            .and_then(|signer| signer.verify(token))
+            .ok_or(ChatHistoryError::ShareNotFound)?;
+        let share = shares
+            .get(share_id)
+            .await?
+            .filter(|share| !share.is_expired(Utc::now()))
+            .ok_or(ChatHistoryError::ShareNotFound)?;
+        let conversation = self
+            .repo
+            .get_conversation(share.conversation_id)
+            .await?
+            .ok_or(ChatHistoryError::ShareNotFound)?;
+        let messages = self.repo.get_messages(share.conversation_id).await?;
+        Ok(SharedConversation::new(&conversation, &messages, &share))
+    }
+
+    fn shares(&self) -> Result<&Arc<dyn ConversationShareRepository>, ChatHistoryError> {
+        self.shares.as_ref().ok_or_else(|| {
+            ChatHistoryError::NotShareable("no share store is configured".to_string())
+        })
+    }
+
+    /// The share link signer, `None` until the first link is created.
+    fn stored_share_signer(&self) -> Result<Option<ShareSigner>, ChatHistoryError> {
+        let Some(text) = self
+            .share_secrets()?
+            .get(CHAT_SHARE_KEY_SECRET)
+            .map_err(|e| share_key_error(&e))?
+        else {
+            return Ok(None);
+        };
+        let key = STANDARD
+            .decode(text.trim())
+            .ok()
+            .and_then(|bytes| <[u8; 32]>::try_from(bytes).ok())
+            .ok_or_else(|| {
+                ChatHistoryError::Encryption("stored share link key is malformed".to_string())
+            })?;
+        Ok(Some(ShareSigner::new(key)))
+    }
+
+    /// The share link signer, its key created in the secret store on first
+    /// use.
+    fn share_signer(&self) -> Result<ShareSigner, ChatHistoryError> {
+        if let Some(signer) = self.stored_share_signer()? {
+            return Ok(signer);
+        }
+        let mut key = [0u8; 32];
+        getrandom::fill(&mut key)
+            .map_err(|e| ChatHistoryError::Encryption(format!("generating share link key: {e}")))?;
+        self.share_secrets()?
+            .set(CHAT_SHARE_KEY_SECRET, &STANDARD.encode(key))
+            .map_err(|e| share_key_error(&e))?;
+        Ok(ShareSigner::new(key))
+    }
+
+    fn share_secrets(&self) -> Result<&Arc<dyn SecretStorePort>, ChatHistoryError> {
+        self.secrets.as_ref().ok_or_else(|| {
+            ChatHistoryError::NotShareable(
+                "no secret store is configured for the share link key".to_string(),
+            )
+        })
+    }
+
+    /// Supersede message `id` after checking it has the `expected` role.
+    async fn revise(
+        &self,
+        id: i64,
+        expected: MessageRole,
+        content: String,
+        metadata: Option<serde_json::Value>,
+    ) -> Result<MessageRevision, ChatHistoryError> {
+        let repo = self.repo_for(id);
+        let message = repo
+            .get_message(id)
+            .await?
+            .ok_or(ChatHistoryError::MessageNotFound(id))?;
+        if message.role != expected {
+            return Err(ChatHistoryError::UnexpectedRole {
+                id,
+                expected,
+                actual: message.role,
+            });
+        }
+        repo.supersede_message(id, content, metadata).await
+    }
+}
+
+fn share_key_error(e: &crate::ports::SecretStoreError) -> ChatHistoryError {
+    ChatHistoryError::Encryption(format!("share link key: {e}"))
+}
+
+fn with_token(signer: &ShareSigner, record: ShareRecord) -> ConversationShare {
+    ConversationShare {
+        token: signer.sign(record.id),
+        record,
+    }
+}
+
+/// Whether `title` is one the UIs assign before the conversation is named.
+fn is_placeholder_title(title: &str) -> bool {
+    let title = title.trim();
+    title.is_empty() || PLACEHOLDER_TITLES.contains(&title)
+}
+
+/// Whether `messages` hold exactly one user turn and its reply.
+fn is_first_exchange(messages: &[Message]) -> bool {
+    let users = messages
+        .iter()
+        .filter(|m| m.role == MessageRole::User)
+        .count();
+    users == 1 && messages.iter().any(|m| m.role == MessageRole::Assistant)
+}
+
+/// Extract a usable title from a model reply.
+///
+/// Models decorate titles in many ways: reasoning blocks, a `Title:`
+/// prefix, quotes, markdown emphasis, a JSON object, a trailing period or
+/// an explanation on the following lines. Returns `None` when nothing is
+/// left.
+fn clean_title(raw: &str) -> Option<String> {
+    let text = strip_think_blocks(raw).unwrap_or_else(|| raw.trim().to_string());
+
+    let from_json = serde_json::from_str::<serde_json::Value>(&text)
+        .ok()
+        .and_then(|v| v.get("title")?.as_str().map(str::to_string));
+    let line = from_json.unwrap_or_else(|| {
+        text.lines()
+            .map(str::trim)
+            .find(|l| !l.is_empty())
+            .unwrap_or_default()
+            .to_string()
+    });
+
+    let mut title = line.as_str();
+    for prefix in ["title:", "here's a title:", "here is a title:"] {
+        if title
+            .get(..prefix.len())
+            .is_some_and(|p| p.eq_ignore_ascii_case(prefix))
+        {
+            title = title[prefix.len()..].trim_start();
+            break;
+        }
+    }
+    let title = title
+        .trim_matches(|c: char| matches!(c, '"' | '\'' | '`' | '“' | '”' | '‘' | '’'))
+        .trim_end_matches('.')
+        .trim_matches(|c: char| matches!(c, '*' | '_'))
+        .trim();
+
+    let title: String = title.chars().take(MAX_TITLE_CHARS).collect();
+    (!title.is_empty()).then_some(title)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn clean_title_strips_decoration() {
+        assert_eq!(
+            clean_title("Title: \"Rust Lifetimes Explained.\"").as_deref(),
+            Some("Rust Lifetimes Explained")
+        );
+        assert_eq!(
+            clean_title("<think>hmm</think>\n**Sorting in Go**\nThis title fits because")
+                .as_deref(),
+            Some("Sorting in Go")
+        );
+        assert_eq!(
+            clean_title(r#"{"title": "Docker Networking"}"#).as_deref(),
+            Some("Docker Networking")
+        );
+        assert_eq!(clean_title("  \"\"  "), None);
+    }
+
+    #[test]
+    fn only_placeholder_titles_are_replaced() {
+        assert!(is_placeholder_title("New Chat"));
+        assert!(is_placeholder_title("New Conversation"));
+        assert!(is_placeholder_title("  "));
+        assert!(!is_placeholder_title("My renamed chat"));
     }
 }
@@ -0,0 +1,422 @@
+//! In-memory chat history for incognito conversations.
+//!
+//! [`IncognitoHistory`] implements [`ChatHistoryRepository`] over plain
+//! vectors behind a mutex. Nothing here touches the database, so incognito
+//! conversations never reach backups, the CLI history listing or anything
+//! else that reads `chat_conversations` directly, and they vanish with the
+//! process.
+//!
+//! IDs count down from `-1`, keeping them disjoint from `SQLite` row IDs;
+//! [`is_incognito_id`] is how callers tell the two apart. Messages are kept
+//! in insertion order, which stands in for the ascending row IDs the
+//! `SQLite` repository uses to find "later" messages.
+
+use std::sync::{Mutex, MutexGuard, PoisonError};
+
+use async_trait::async_trait;
+
+use crate::domain::chat::{
+    Conversation, ConversationUpdate, Message, MessageRevision, NewConversation, NewMessage,
+};
+use crate::ports::chat_history::{ChatHistoryError, ChatHistoryRepository};
+
+/// Whether `id` names an incognito conversation or message.
+#[must_use]
+pub const fn is_incognito_id(id: i64) -> bool {
+    id < 0
+}
+
+/// Conversations and messages that live only as long as the process.
+#[derive(Default)]
+pub struct IncognitoHistory {
+    state: Mutex<State>,
+}
+
+impl IncognitoHistory {
+    fn lock(&self) -> MutexGuard<'_, State> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Drop every conversation and message, returning how many
+    /// conversations were held.
+    pub fn clear(&self) -> usize {
+        let mut state = self.lock();
+        state.messages.clear();
+        std::mem::take(&mut state.conversations).len()
+    }
+}
+
+#[derive(Default)]
+struct State {
+    last_id: i64,
+    conversations: Vec<Conversation>,
+    /// Every message of every conversation, oldest first.
+    messages: Vec<Message>,
+}
+
+impl State {
+    const fn next_id(&mut self) -> i64 {
+        self.last_id -= 1;
+        self.last_id
+    }
+
+    fn conversation_mut(&mut self, id: i64) -> Result<&mut Conversation, ChatHistoryError> {
+        self.conversations
+            .iter_mut()
+            .find(|c| c.id == id)
+            .ok_or(ChatHistoryError::ConversationNotFound(id))
+    }
+
+    fn message_index(&self, id: i64) -> Result<usize, ChatHistoryError> {
+        self.messages
+            .iter()
+            .position(|m| m.id == id)
+            .ok_or(ChatHistoryError::MessageNotFound(id))
+    }
+
+    fn touch(&mut self, conversation_id: i64) {
+        if let Ok(conversation) = self.conversation_mut(conversation_id) {
+            conversation.updated_at = now();
+        }
+    }
+
+    fn active(&self, conversation_id: i64) -> impl Iterator<Item = &Message> {
+        self.messages
+            .iter()
+            .filter(move |m| m.conversation_id == conversation_id && m.superseded_by.is_none())
+    }
+
+    fn create_conversation(&mut self, conv: NewConversation) -> i64 {
+        let id = self.next_id();
+        let created_at = now();
+        self.conversations.push(Conversation {
+            id,
+            title: conv.title,
+            model_id: conv.model_id,
+            system_prompt: conv.system_prompt,
+            settings: conv.settings,
+            updated_at: created_at.clone(),
+            created_at,
+            incognito: true,
+        });
+        id
+    }
+
+    fn update_conversation(
+        &mut self,
+        id: i64,
+        update: ConversationUpdate,
+    ) -> Result<(), ChatHistoryError> {
+        let conversation = self.conversation_mut(id)?;
+        if let Some(title) = update.title {
+            conversation.title = title;
+        }
+        if let Some(system_prompt) = update.system_prompt {
+            conversation.system_prompt = system_prompt;
+        }
+        if let Some(settings) = update.settings {
+            conversation.settings = settings;
+        }
+        if let Some(model_id) = update.model_id {
+            conversation.model_id = Some(model_id);
+        }
+        conversation.updated_at = now();
+        Ok(())
+    }
+
+    fn delete_conversation(&mut self, id: i64) {
+        self.conversations.retain(|c| c.id != id);
+        self.messages.retain(|m| m.conversation_id != id);
+    }
+
+    fn save_message(&mut self, msg: NewMessage) -> Result<i64, ChatHistoryError> {
+        self.conversation_mut(msg.conversation_id)?;
+        let id = self.next_id();
+        self.messages.push(Message {
+            id,
+            conversation_id: msg.conversation_id,
+            role: msg.role,
+            content: msg.content,
+            created_at: now(),
+            metadata: msg.metadata,
+            version_of: None,
+            superseded_by: None,
+        });
+        self.touch(msg.conversation_id);
+        Ok(id)
+    }
+
+    fn update_message(
+        &mut self,
+        id: i64,
+        content: String,
+        metadata: Option<serde_json::Value>,
+    ) -> Result<(), ChatHistoryError> {
+        let index = self.message_index(id)?;
+        let message = &mut self.messages[index];
+        message.content = content;
+        message.metadata = metadata;
+        let conversation_id = message.conversation_id;
+        self.touch(conversation_id);
+        Ok(())
+    }
+
+    fn delete_message_and_subsequent(&mut self, id: i64) -> Result<i64, ChatHistoryError> {
+        let index = self.message_index(id)?;
+        let conversation_id = self.messages[index].conversation_id;
+        let tail = self.messages.split_off(index);
+        let before = tail.len();
+        self.messages.extend(
+            tail.into_iter()
+                .filter(|m| m.conversation_id != conversation_id),
+        );
+        let deleted = before - (self.messages.len() - index);
+        self.touch(conversation_id);
+        Ok(count(deleted))
+    }
+
+    fn supersede_message(
+        &mut self,
+        id: i64,
+        content: String,
+        metadata: Option<serde_json::Value>,
+    ) -> Result<MessageRevision, ChatHistoryError> {
+        let index = self.message_index(id)?;
+        let original = self.messages[index].clone();
+        if original.superseded_by.is_some() {
+            return Err(ChatHistoryError::MessageSuperseded(id));
+        }
+
+        let message_id = self.next_id();
+        let mut superseded = 0;
+        for message in &mut self.messages[index..] {
+            if message.conversation_id == original.conversation_id
+                && message.superseded_by.is_none()
+            {
+                message.superseded_by = Some(message_id);
+                superseded += 1;
+            }
+        }
+        self.messages.push(Message {
+            id: message_id,
+            conversation_id: original.conversation_id,
+            role: original.role,
+            content,
+            created_at: now(),
+            metadata,
+            version_of: Some(original.version_of.unwrap_or(id)),
+            superseded_by: None,
+        });
+        self.touch(original.conversation_id);
+
+        Ok(MessageRevision {
+            message_id,
+            superseded,
+        })
+    }
+
+    fn message_versions(&self, id: i64) -> Result<Vec<Message>, ChatHistoryError> {
+        let root = self.messages[self.message_index(id)?]
+            .version_of
+            .unwrap_or(id);
+        Ok(self
+            .messages
+            .iter()
+            .filter(|m| m.id == root || m.version_of == Some(root))
+            .cloned()
+            .collect())
+    }
+}
+
+/// Timestamp in the format `SQLite`'s `datetime('now')` produces, so merged
+/// listings sort consistently.
+fn now() -> String {
+    chrono::Utc::now().format("%Y-%m-%d %H:%M:%S").to_string()
+}
+
+fn count(n: usize) -> i64 {
+    i64::try_from(n).unwrap_or(i64::MAX)
+}
+
+#[async_trait]
+impl ChatHistoryRepository for IncognitoHistory {
+    async fn create_conversation(&self, conv: NewConversation) -> Result<i64, ChatHistoryError> {
+        Ok(self.lock().create_conversation(conv))
+    }
+
+    async fn list_conversations(&self) -> Result<Vec<Conversation>, ChatHistoryError> {
+        let mut conversations = self.lock().conversations.clone();
+        // Stable sort, so ties keep the newest (last pushed) first.
+        conversations.reverse();
+        conversations.sort_by(|a, b| b.updated_at.cmp(&a.updated_at));
+        Ok(conversations)
+    }
+
+    async fn get_conversation(&self, id: i64) -> Result<Option<Conversation>, ChatHistoryError> {
+        Ok(self
+            .lock()
+            .conversations
+            .iter()
+            .find(|c| c.id == id)
+            .cloned())
+    }
+
+    async fn update_conversation(
+        &self,
+        id: i64,
+        update: ConversationUpdate,
+    ) -> Result<(), ChatHistoryError> {
+        self.lock().update_conversation(id, update)
+    }
+
+    async fn delete_conversation(&self, id: i64) -> Result<(), ChatHistoryError> {
+        self.lock().delete_conversation(id);
+        Ok(())
+    }
+
+    async fn get_conversation_count(&self) -> Result<i64, ChatHistoryError> {
+        Ok(count(self.lock().conversations.len()))
+    }
+
+    async fn get_messages(&self, conversation_id: i64) -> Result<Vec<Message>, ChatHistoryError> {
+        Ok(self.lock().active(conversation_id).cloned().collect())
+    }
+
+    async fn get_message(&self, id: i64) -> Result<Option<Message>, ChatHistoryError> {
+        Ok(self.lock().messages.iter().find(|m| m.id == id).cloned())
+    }
+
+    async fn save_message(&self, msg: NewMessage) -> Result<i64, ChatHistoryError> {
+        self.lock().save_message(msg)
+    }
+
+    async fn update_message(
+        &self,
+        id: i64,
+        content: String,
+        metadata: Option<serde_json::Value>,
+    ) -> Result<(), ChatHistoryError> {
+        self.lock().update_message(id, content, metadata)
+    }
+
+    async fn delete_message_and_subsequent(&self, id: i64) -> Result<i64, ChatHistoryError> {
+        self.lock().delete_message_and_subsequent(id)
+    }
+
+    async fn supersede_message(
+        &self,
+        id: i64,
+        content: String,
+        metadata: Option<serde_json::Value>,
+    ) -> Result<MessageRevision, ChatHistoryError> {
+        self.lock().supersede_message(id, content, metadata)
+    }
+
+    async fn get_message_versions(&self, id: i64) -> Result<Vec<Message>, ChatHistoryError> {
+        self.lock().message_versions(id)
+    }
+
+    async fn get_message_count(&self, conversation_id: i64) -> Result<i64, ChatHistoryError> {
+        Ok(count(self.lock().active(conversation_id).count()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::chat::MessageRole;
+
+    fn new_conversation() -> NewConversation {
+        NewConversation {
+            title: "New Chat".to_string(),
+            model_id: None,
+            system_prompt: None,
+            settings: None,
+            incognito: true,
+        }
+    }
+
+    async fn say(history: &IncognitoHistory, conversation_id: i64, content: &str) -> i64 {
+        history
+            .save_message(NewMessage {
+                conversation_id,
+                role: MessageRole::User,
+                content: content.to_string(),
+                metadata: None,
+            })
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn ids_are_negative_and_conversations_flagged() {
+        let history = IncognitoHistory::default();
+        let cid = history
+            .create_conversation(new_conversation())
+            .await
+            .unwrap();
+        let mid = say(&history, cid, "hello").await;
+        assert!(is_incognito_id(cid) && is_incognito_id(mid));
+        assert_ne!(cid, mid);
+
+        let conversation = history.get_conversation(cid).await.unwrap().unwrap();
+        assert!(conversation.incognito);
+        assert!(
+            history
+                .save_message(NewMessage {
+                    conversation_id: -999,
+                    role: MessageRole::User,
+                    content: String::new(),
+                    metadata: None,
+                })
+                .await
+                .is_err()
+        );
+    }
+
+    #[tokio::test]
+    async fn supersede_and_delete_follow_insertion_order() {
+        let history = IncognitoHistory::default();
+        let cid = history
+            .create_conversation(new_conversation())
+            .await
+            .unwrap();
+        let first = say(&history, cid, "one").await;
+        say(&history, cid, "two").await;
+
+        let revision = history
+            .supersede_message(first, "one, edited".to_string(), None)
+            .await
+            .unwrap();
+        assert_eq!(revision.superseded, 2);
+        let active = history.get_messages(cid).await.unwrap();
+        assert_eq!(active.len(), 1);
+        assert_eq!(active[0].content, "one, edited");
+        let versions = history.get_message_versions(first).await.unwrap();
+        assert_eq!(versions.len(), 2);
+
+        say(&history, cid, "three").await;
+        assert_eq!(
+            history
+                .delete_message_and_subsequent(revision.message_id)
+                .await
+                .unwrap(),
+            2
+        );
+        assert_eq!(history.get_message_count(cid).await.unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn clear_discards_everything() {
+        let history = IncognitoHistory::default();
+        let cid = history
+            .create_conversation(new_conversation())
+            .await
+            .unwrap();
+        say(&history, cid, "secret").await;
+
+        assert_eq!(history.clear(), 1);
+        assert!(history.list_conversations().await.unwrap().is_empty());
+        assert!(history.get_messages(cid).await.unwrap().is_empty());
+    }
+}
@@ -0,0 +1,532 @@
+//! Library scan service - reads GGUF metadata for the whole model library.
+//!
+//! [`scan`](LibraryScanService::scan) reads each file's metadata from the
+//! [`GgufMetadataCache`] when the file's size and modification time match
+//! what was cached, and parses the rest on a bounded pool of blocking
+//! workers. [`refresh_library`](LibraryScanService::refresh_library) scans
+//! every registered model and brings its stored metadata up to date with
+//! its file, so an unchanged library costs one cache lookup per model.
+
+use std::num::NonZeroUsize;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use tokio::sync::Semaphore;
+
+use crate::domain::Model;
+use crate::ports::{
+    CoreError, FileStamp, GgufMetadata, GgufMetadataCache, GgufParseError, GgufParserPort,
+    ModelRepository,
+};
+
+/// Most files [`LibraryScanService`] parses at once by default; fewer on
+/// machines with fewer cores.
+pub const MAX_SCAN_WORKERS: usize = 8;
+
+/// One file's result from [`LibraryScanService::scan`].
+#[derive(Debug)]
+pub struct ScannedFile {
+    /// The file scanned.
+    pub path: PathBuf,
+    /// Its metadata, or why it couldn't be read.
+    pub metadata: Result<GgufMetadata, GgufParseError>,
+    /// Whether the metadata came from the cache rather than the file.
+    pub cached: bool,
+}
+
+/// What [`LibraryScanService::refresh_library`] did.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct LibraryScanSummary {
+    /// Models scanned.
+    pub models: usize,
+    /// Models whose metadata came from the cache.
+    pub cached: usize,
+    /// Models whose file was parsed.
+    pub parsed: usize,
+    /// Models whose stored metadata changed.
+    pub updated: usize,
+    /// Models whose file was missing or unreadable.
+    pub failed: usize,
+}
+
+/// Service for reading the library's GGUF metadata through the cache.
+pub struct LibraryScanService {
+    models: Arc<dyn ModelRepository>,
+    parser: Arc<dyn GgufParserPort>,
+    cache: Arc<dyn GgufMetadataCache>,
+    workers: usize,
+}
+
+impl LibraryScanService {
+    /// Create a service parsing with `parser` and caching in `cache`.
+    pub fn new(
+        models: Arc<dyn ModelRepository>,
+        parser: Arc<dyn GgufParserPort>,
+        cache: Arc<dyn GgufMetadataCache>,
+    ) -> Self {
+        let workers = std::thread::available_parallelism()
+            .map_or(1, NonZeroUsize::get)
+            .min(MAX_SCAN_WORKERS);
+        Self {
+            models,
+            parser,
+            cache,
+            workers,
+        }
+    }
+
+    /// Parse at most `workers` files at once (at least one).
+    #[must_use]
+    pub fn with_workers(mut self, workers: usize) -> Self {
+        self.workers = workers.max(1);
+        self
+    }
+
+    /// Read the metadata of every file in `paths`, in the same order.
+    ///
+    /// A file is parsed only when the cache has nothing stored for its
+    /// current size and modification time, and what is parsed is cached.
+    /// A file that no longer exists has its cache entry dropped. Cache
+    /// failures are logged and fall back to parsing.
+    pub async fn scan(&self, paths: &[PathBuf]) -> Vec<ScannedFile> {
+        let mut results: Vec<Option<ScannedFile>> = paths.iter().map(|_| None).collect();
+        let mut misses = Vec::new();
+        for (i, path) in paths.iter().enumerate() {
+            let stamp = match FileStamp::of(path) {
+                Ok(stamp) => stamp,
+                Err(e) => {
+                    if e.kind() == std::io::ErrorKind::NotFound {
+                        if let Err(e) = self.cache.remove(path).await {
+                            tracing::warn!(path = %path.display(), "Could not drop cached metadata: {e}");
+                        }
+                    }
+                    results[i] = Some(ScannedFile {
+                        path: path.clone(),
+                        metadata: Err(GgufParseError::Io(e.to_string())),
+                        cached: false,
+                    });
+                    continue;
+                }
+            };
+            match self.cache.get(path, stamp).await {
+                Ok(Some(metadata)) => {
+                    results[i] = Some(ScannedFile {
+                        path: path.clone(),
+                        metadata: Ok(metadata),
+                        cached: true,
+                    });
+                }
+                Ok(None) => misses.push((i, stamp)),
+                Err(e) => {
+                    tracing::warn!(path = %path.display(), "Could not read cached metadata: {e}");
+                    misses.push((i, stamp));
+                }
+            }
+        }
+
+        // Each parse holds a permit until it finishes, so at most `workers`
+        // run at once. The stamp was taken first: a file that changes while
+        // it's parsed is cached under its old stamp and parsed again next time.
+        let pool = Arc::new(Semaphore::new(self.workers));
+        let mut running = Vec::with_capacity(misses.len());
+        for (i, stamp) in misses {
+            let permit = Arc::clone(&pool)
+                .acquire_owned()
+                .await
+                .expect("the scan's semaphore is never closed");
+            let parser = Arc::clone(&self.parser);
+            let path = paths[i].clone();
+            let parse = tokio::task::spawn_blocking(move || {
+                let _permit = permit;
+                parser.parse(&path)
+            });
+            running.push((i, stamp, parse));
+        }
+        for (i, stamp, parse) in running {
+            let path = &paths[i];
+            let metadata = parse
+                .await
+                .unwrap_or_else(|e| Err(GgufParseError::Io(format!("parser failed: {e}"))));
+            if let Ok(metadata) = &metadata
+                && let Err(e) = self.cache.put(path, stamp, metadata).await
+            {
+                tracing::warn!(path = %path.display(), "Could not cache metadata: {e}");
+            }
+            results[i] = Some(ScannedFile {
+                path: path.clone(),
+                metadata,
+                cached: false,
+            });
+        }
+
+        results.into_iter().flatten().collect()
+    }
+
+    /// Scan every registered model's file and store the metadata of those
+    /// whose file changed since they were registered.
+    ///
+    /// Models whose file is missing or unreadable keep what they have; a
+    /// model that can't be updated is logged and skipped.
+    pub async fn refresh_library(&self) -> Result<LibraryScanSummary, CoreError> {
+        let models = self.models.list().await?;
+        let paths: Vec<PathBuf> = models.iter().map(|m| m.file_path.clone()).collect();
+        let scanned = self.scan(&paths).await;
+
+        let mut summary = LibraryScanSummary {
+            models: models.len(),
+            ..LibraryScanSummary::default()
+        };
+        for (mut model, file) in models.into_iter().zip(scanned) {
+            let metadata = match file.metadata {
+                Ok(metadata) => metadata,
+                Err(e) => {
+                    tracing::debug!(model_id = model.id, "Can't read model metadata: {e}");
+                    summary.failed += 1;
+                    continue;
+                }
+            };
+            if file.cached {
+                summary.cached += 1;
+            } else {
+                summary.parsed += 1;
+            }
+            if !apply_metadata(&mut model, metadata) {
+                continue;
+            }
+            match self.models.update(&model).await {
+                Ok(()) => summary.updated += 1,
+                Err(e) => {
+                    tracing::warn!(model_id = model.id, "Could not update model metadata: {e}");
+                }
+            }
+        }
+        Ok(summary)
+    }
+}
+
+/// Copy what the file says onto `model`, returning whether anything changed.
+///
+/// Only what comes from the file is replaced: the name, quantization and
+/// parameter count may have been set by hand, and a field the file doesn't
+/// carry keeps its stored value.
+fn apply_metadata(model: &mut Model, metadata: GgufMetadata) -> bool {
+    fn replace<T: PartialEq>(field: &mut Option<T>, value: Option<T>) -> bool {
+        match value {
+            Some(value) if field.as_ref() != Some(&value) => {
+                *field = Some(value);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    let mut changed = replace(&mut model.architecture, metadata.architecture);
+    changed |= replace(&mut model.context_length, metadata.context_length);
+    changed |= replace(&mut model.expert_count, metadata.expert_count);
+    changed |= replace(&mut model.expert_used_count, metadata.expert_used_count);
+    changed |= replace(&mut model.expert_shared_count, metadata.expert_shared_count);
+    if model.metadata != metadata.metadata {
+        model.metadata = metadata.metadata;
+        changed = true;
+    }
+    changed
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::path::Path;
+    use std::sync::Mutex;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::Duration;
+
+    use async_trait::async_trait;
+
+    use super::*;
+    use crate::domain::NewModel;
+    use crate::ports::{GgufCapabilities, RepositoryError};
+
+    #[derive(Default)]
+    struct LibraryRepo(Mutex<Vec<Model>>);
+
+    impl LibraryRepo {
+        #[allow(clippy::significant_drop_tightening)]
+        fn add(&self, path: &Path) {
+            let mut models = self.0.lock().unwrap();
+            let id = i64::try_from(models.len()).unwrap() + 1;
+            models.push(Model {
+                id,
+                name: format!("model-{id}"),
+                model_key: String::new(),
+                file_path: path.to_path_buf(),
+                param_count_b: 7.0,
+                architecture: None,
+                quantization: Some("Q4_K_M".to_string()),
+                context_length: None,
+                expert_count: None,
+                expert_used_count: None,
+                expert_shared_count: None,
+                metadata: HashMap::new(),
+                added_at: chrono::Utc::now(),
+                hf_repo_id: None,
+                hf_commit_sha: None,
+                hf_filename: None,
+                download_date: None,
+                last_update_check: None,
+                license: None,
+                tags: vec![],
+                capabilities: crate::domain::ModelCapabilities::default(),
+                capabilities_version: None,
+                inference_defaults: None,
+                server_defaults: None,
+                card: None,
+                benchmark_summary: None,
+            });
+        }
+    }
+
+    #[async_trait]
+    impl ModelRepository for LibraryRepo {
+        async fn list(&self) -> Result<Vec<Model>, RepositoryError> {
+            Ok(self.0.lock().unwrap().clone())
+        }
+        async fn get_by_id(&self, id: i64) -> Result<Model, RepositoryError> {
+            Err(RepositoryError::NotFound(format!("id={id}")))
+        }
+        async fn get_by_name(&self, name: &str) -> Result<Model, RepositoryError> {
+            Err(RepositoryError::NotFound(format!("name={name}")))
+        }
+        async fn insert(&self, _model: &NewModel) -> Result<Model, RepositoryError> {
+            unimplemented!()
+        }
+        async fn update(&self, model: &Model) -> Result<(), RepositoryError> {
+            let mut models = self.0.lock().unwrap();
+            let stored = models.iter_mut().find(|m| m.id == model.id);
+            stored.unwrap().clone_from(model);
+            drop(models);
+            Ok(())
+        }
+        async fn delete(&self, _id: i64) -> Result<(), RepositoryError> {
+            unimplemented!()
+        }
+    }
+
+    #[derive(Default)]
+    struct MemoryCache(Mutex<HashMap<PathBuf, (FileStamp, GgufMetadata)>>);
+
+    #[async_trait]
+    impl GgufMetadataCache for MemoryCache {
+        async fn get(
+            &self,
+            path: &Path,
+            stamp: FileStamp,
+        ) -> Result<Option<GgufMetadata>, RepositoryError> {
+            let entries = self.0.lock().unwrap();
+            Ok(entries
+                .get(path)
+                .filter(|(stored, _)| *stored == stamp)
+                .map(|(_, metadata)| metadata.clone()))
+        }
+
+        async fn put(
+            &self,
+            path: &Path,
+            stamp: FileStamp,
+            metadata: &GgufMetadata,
+        ) -> Result<(), RepositoryError> {
+            let entry = (stamp, metadata.clone());
+            self.0.lock().unwrap().insert(path.to_path_buf(), entry);
+            Ok(())
+        }
+
+        async fn remove(&self, path: &Path) -> Result<(), RepositoryError> {
+            self.0.lock().unwrap().remove(path);
+            Ok(())
+        }
+    }
+
+    /// Reports each file's contents as its architecture, and counts parses.
+    #[derive(Default)]
+    struct CountingParser {
+        parses: AtomicUsize,
+        running: AtomicUsize,
+        most_running: AtomicUsize,
+    }
+
+    impl GgufParserPort for CountingParser {
+        fn parse(&self, file_path: &Path) -> Result<GgufMetadata, GgufParseError> {
+            self.parses.fetch_add(1, Ordering::SeqCst);
+            let running = self.running.fetch_add(1, Ordering::SeqCst) + 1;
+            self.most_running.fetch_max(running, Ordering::SeqCst);
+            std::thread::sleep(Duration::from_millis(10));
+            self.running.fetch_sub(1, Ordering::SeqCst);
+            let contents = std::fs::read_to_string(file_path)
+                .map_err(|e| GgufParseError::Io(e.to_string()))?;
+            Ok(GgufMetadata {
+                architecture: Some(contents),
+                ..GgufMetadata::default()
+            })
+        }
+
+        fn detect_capabilities(&self, _metadata: &GgufMetadata) -> GgufCapabilities {
+            GgufCapabilities::empty()
+        }
+    }
+
+    struct Fixture {
+        dir: tempfile::TempDir,
+        models: Arc<LibraryRepo>,
+        parser: Arc<CountingParser>,
+        cache: Arc<MemoryCache>,
+    }
+
+    impl Fixture {
+        fn new() -> Self {
+            Self {
+                dir: tempfile::tempdir().unwrap(),
+                models: Arc::default(),
+                parser: Arc::default(),
+                cache: Arc::default(),
+            }
+        }
+
+        fn files(&self, count: usize) -> Vec<PathBuf> {
+            (0..count)
+                .map(|i| {
+                    let path = self.dir.path().join(format!("model-{i}.gguf"));
+                    std::fs::write(&path, format!("arch-{i}")).unwrap();
+                    path
+                })
+                .collect()
+        }
+
+        fn service(&self, workers: usize) -> LibraryScanService {
+            LibraryScanService::new(self.models.clone(), self.parser.clone(), self.cache.clone())
+                .with_workers(workers)
+        }
+
+        fn parses(&self) -> usize {
+            self.parser.parses.load(Ordering::SeqCst)
+        }
+    }
+
+    fn architectures(scanned: &[ScannedFile]) -> Vec<String> {
+        scanned
+            .iter()
+            .map(|file| {
+                file.metadata
+                    .as_ref()
+                    .unwrap()
+                    .architecture
+                    .clone()
+                    .unwrap()
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn unchanged_files_are_read_from_the_cache() {
+        let fixture = Fixture::new();
+        let paths = fixture.files(5);
+        let service = fixture.service(2);
+
+        let first = service.scan(&paths).await;
+        assert!(first.iter().all(|file| !file.cached));
+        assert_eq!(fixture.parses(), 5);
+
+        let second = service.scan(&paths).await;
+        assert!(second.iter().all(|file| file.cached));
+        assert_eq!(fixture.parses(), 5);
+        assert_eq!(architectures(&first), architectures(&second));
+    }
+
+    #[tokio::test]
+    async fn a_changed_file_is_parsed_again() {
+        let fixture = Fixture::new();
+        let paths = fixture.files(3);
+        let service = fixture.service(2);
+        service.scan(&paths).await;
+
+        std::fs::write(&paths[1], "arch-1-requantized").unwrap();
+        let scanned = service.scan(&paths).await;
+
+        assert_eq!(fixture.parses(), 4);
+        assert!(scanned[0].cached && !scanned[1].cached && scanned[2].cached);
+        assert_eq!(
+            scanned[1]
+                .metadata
+                .as_ref()
+                .unwrap()
+                .architecture
+                .as_deref(),
+            Some("arch-1-requantized")
+        );
+    }
+
+    #[tokio::test]
+    async fn a_missing_file_is_an_error_and_loses_its_entry() {
+        let fixture = Fixture::new();
+        let paths = fixture.files(2);
+        let service = fixture.service(2);
+        service.scan(&paths).await;
+
+        std::fs::remove_file(&paths[0]).unwrap();
+        let scanned = service.scan(&paths).await;
+
+        assert!(scanned[0].metadata.is_err());
+        assert!(scanned[1].cached);
+        assert!(!fixture.cache.0.lock().unwrap().contains_key(&paths[0]));
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn parsing_never_exceeds_the_worker_limit() {
+        let fixture = Fixture::new();
+        let paths = fixture.files(16);
+        let scanned = fixture.service(3).scan(&paths).await;
+
+        let most = fixture.parser.most_running.load(Ordering::SeqCst);
+        assert!((1..=3).contains(&most), "{most} parses ran at once");
+        let expected: Vec<_> = (0..16).map(|i| format!("arch-{i}")).collect();
+        assert_eq!(architectures(&scanned), expected);
+    }
+
+    #[tokio::test]
+    async fn refresh_library_stores_only_what_changed() {
+        let fixture = Fixture::new();
+        let paths = fixture.files(3);
+        for path in &paths {
+            fixture.models.add(path);
+        }
+        fixture.models.add(&fixture.dir.path().join("deleted.gguf"));
+        let service = fixture.service(2);
+
+        let first = service.refresh_library().await.unwrap();
+        let expected = LibraryScanSummary {
+            models: 4,
+            cached: 0,
+            parsed: 3,
+            updated: 3,
+            failed: 1,
+        };
+        assert_eq!(first, expected);
+
+        std::fs::write(&paths[2], "arch-2-requantized").unwrap();
+        let second = service.refresh_library().await.unwrap();
+        let expected = LibraryScanSummary {
+            models: 4,
+            cached: 2,
+            parsed: 1,
+            updated: 1,
+            failed: 1,
+        };
+        assert_eq!(second, expected);
+
+        let models = fixture.models.0.lock().unwrap().clone();
+        assert_eq!(
+            models[2].architecture.as_deref(),
+            Some("arch-2-requantized")
+        );
+        assert_eq!(models[2].quantization.as_deref(), Some("Q4_K_M"));
+        assert_eq!(models[3].architecture, None);
+    }
+}
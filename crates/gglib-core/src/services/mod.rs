@@ -1,20 +1,39 @@
 #![doc = include_str!("README.md")]
 mod app_core;
+mod attachments;
+mod audit_log;
 mod chat_history;
+mod incognito_history;
+mod library_scan;
+mod model_directory_sync;
 mod model_registrar;
 mod model_service;
+mod model_trash;
+mod model_usage;
 mod model_verification;
+mod pipelines;
+mod presets;
+mod projects;
 mod server_service;
 mod settings_service;
 
 pub use app_core::AppCore;
+pub use attachments::AttachmentService;
+pub use audit_log::AuditLogService;
 pub use chat_history::ChatHistoryService;
+pub use library_scan::{LibraryScanService, LibraryScanSummary, MAX_SCAN_WORKERS, ScannedFile};
+pub use model_directory_sync::{DirectorySyncSummary, ModelDirectorySync};
 pub use model_registrar::{ModelFilesRepositoryPort, ModelRegistrar};
-pub use model_service::{ModelService, RetagDiff};
+pub use model_service::{CapabilityMigrationSummary, ModelService, RedetectOutcome, RetagDiff};
+pub use model_trash::{ModelTrashService, PURGE_INTERVAL};
+pub use model_usage::{ModelUsageService, USAGE_COLLECT_INTERVAL};
 pub use model_verification::{
     DownloadTriggerPort, ModelFilesReaderPort, ModelVerificationService, OverallHealth,
     ShardHealth, ShardHealthReport, ShardProgress, UpdateCheckResult, UpdateDetails,
     VerificationProgress, VerificationReport,
 };
+pub use pipelines::PipelineService;
+pub use presets::PresetService;
+pub use projects::ProjectService;
 pub use server_service::ServerService;
-pub use settings_service::SettingsService;
+pub use settings_service::{SettingsService, SettingsWrite};
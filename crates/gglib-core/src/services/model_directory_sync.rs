@@ -0,0 +1,176 @@
+//! Model directory sync - brings hand-made changes to the models directory
+//! into the library.
+//!
+//! A file copied into the models directory, or deleted from it, goes
+//! unnoticed until something looks. The runtime's watcher hands
+//! [`apply`](ModelDirectorySync::apply) the paths that changed once they
+//! have settled: a `.gguf` not yet in the library is imported the way
+//! `gglib model add` does, and a library model whose file is gone is moved
+//! to the trash — where it shows as removed and is purged as usual. Each
+//! change is announced as an [`AppEvent::ModelAdded`] or
+//! [`AppEvent::ModelRemoved`].
+//!
+//! Only the first shard of a sharded model is registered (llama-server
+//! finds the rest next to it), and hidden directories — the download cache
+//! — and the trash are ignored.
+
+use std::path::{Component, Path, PathBuf};
+use std::sync::Arc;
+
+use crate::domain::Model;
+use crate::domain::trash::TRASH_DIR;
+use crate::events::{AppEvent, ModelSummary};
+use crate::ports::{AppEventEmitter, CoreError, GgufParserPort};
+use crate::services::AppCore;
+use crate::utils::shard_filename::base_shard_filename;
+
+/// What [`ModelDirectorySync::apply`] did.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DirectorySyncSummary {
+    /// Files imported into the library.
+    pub added: usize,
+    /// Models moved to the trash because their file is gone.
+    pub removed: usize,
+    /// Files that could not be imported or models that could not be trashed
+    /// (including when no trash is configured).
+    pub failed: usize,
+}
+
+/// Service applying models-directory changes to the library.
+pub struct ModelDirectorySync {
+    core: Arc<AppCore>,
+    parser: Arc<dyn GgufParserPort>,
+    emitter: Arc<dyn AppEventEmitter>,
+    root: PathBuf,
+}
+
+impl ModelDirectorySync {
+    /// Create a service for the models directory `root`.
+    pub fn new(
+        core: Arc<AppCore>,
+        parser: Arc<dyn GgufParserPort>,
+        emitter: Arc<dyn AppEventEmitter>,
+        root: PathBuf,
+    ) -> Self {
+        Self {
+            core,
+            parser,
+            emitter,
+            root,
+        }
+    }
+
+    /// The models directory this service keeps in step with.
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Whether a change to `path` can matter to the library: a `.gguf`
+    /// under the root, outside hidden directories and the trash, that is
+    /// not a second or later shard.
+    pub fn is_candidate(&self, path: &Path) -> bool {
+        is_library_file(&self.root, path)
+    }
+
+    /// Import the new files and trash the models whose file is gone among
+    /// `paths`. Paths that are not candidates, files already in the library
+    /// and deleted files no model refers to are skipped.
+    pub async fn apply(&self, paths: &[PathBuf]) -> Result<DirectorySyncSummary, CoreError> {
+        // Listed once up front; a download registering the same file meanwhile
+        // is what the watcher's settle delay guards against.
+        let models = self.core.models();
+        let library = models.list().await?;
+        let mut summary = DirectorySyncSummary::default();
+        for path in paths.iter().filter(|p| self.is_candidate(p)) {
+            let owner = library.iter().find(|m| m.file_path == *path);
+            match (path.exists(), owner) {
+                (true, None) => match models
+                    .import_from_file(path, self.parser.as_ref(), None)
+                    .await
+                {
+                    Ok(model) => {
+                        tracing::info!(path = %path.display(), id = model.id, "Registered a model added to the models directory");
+                        self.emitter.emit(AppEvent::model_added(summarize(&model)));
+                        summary.added += 1;
+                    }
+                    Err(e) => {
+                        tracing::warn!(path = %path.display(), "Could not register a model added to the models directory: {e}");
+                        summary.failed += 1;
+                    }
+                },
+                (false, Some(model)) => match self.trash(model).await {
+                    Ok(()) => {
+                        tracing::info!(path = %path.display(), id = model.id, "Moved a model whose file was deleted to the trash");
+                        self.emitter.emit(AppEvent::model_removed(model.id));
+                        summary.removed += 1;
+                    }
+                    Err(e) => {
+                        tracing::warn!(path = %path.display(), "Could not trash a model whose file was deleted: {e}");
+                        summary.failed += 1;
+                    }
+                },
+                _ => {}
+            }
+        }
+        Ok(summary)
+    }
+
+    async fn trash(&self, model: &Model) -> Result<(), CoreError> {
+        let trash = self
+            .core
+            .trash()
+            .ok_or_else(|| CoreError::Configuration("no model trash is configured".into()))?;
+        trash.trash(model).await.map(drop)
+    }
+}
+
+fn is_library_file(root: &Path, path: &Path) -> bool {
+    let Ok(relative) = path.strip_prefix(root) else {
+        return false;
+    };
+    let hidden = relative
+        .parent()
+        .into_iter()
+        .flat_map(Path::components)
+        .any(|c| match c {
+            Component::Normal(dir) => dir.to_string_lossy().starts_with('.') || dir == TRASH_DIR,
+            _ => true,
+        });
+    let Some(name) = path.file_name().and_then(|n| n.to_str()) else {
+        return false;
+    };
+    let is_gguf = Path::new(name)
+        .extension()
+        .is_some_and(|e| e.eq_ignore_ascii_case("gguf"));
+    !hidden && is_gguf && (base_shard_filename(name) == name || name.contains("-00001-of-"))
+}
+
+fn summarize(model: &Model) -> ModelSummary {
+    ModelSummary::new(
+        model.id,
+        model.name.clone(),
+        model.file_path.to_string_lossy(),
+        model.architecture.clone(),
+        model.quantization.clone(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_first_shards_and_single_files_outside_hidden_dirs_count() {
+        let root = Path::new("/models");
+        let counts = |p: &str| is_library_file(root, Path::new(p));
+
+        assert!(counts("/models/llama-3-8b.Q4_K_M.gguf"));
+        assert!(counts("/models/org/repo/Qwen3.GGUF"));
+        assert!(counts("/models/big-00001-of-00003.gguf"));
+        assert!(!counts("/models/big-00002-of-00003.gguf"));
+        assert!(!counts("/models/notes.txt"));
+        assert!(!counts("/models/.cache/huggingface/download/model.gguf"));
+        assert!(!counts("/models/trash/1-123/model.gguf"));
+        assert!(!counts("/elsewhere/model.gguf"));
+    }
+}
@@ -10,7 +10,7 @@ use std::sync::Arc;
 use async_trait::async_trait;
 use chrono::Utc;
 
-use crate::domain::{Model, NewModel, NewModelFile};
+use crate::domain::{Model, NewModel, NewModelFile, ServerConfig};
 use crate::download::Quantization;
 use crate::ports::{
     CompletedDownload, GgufParserPort, ModelRegistrarPort, ModelRepository, RepositoryError,
@@ -131,6 +131,7 @@ impl ModelRegistrarPort for ModelRegistrar {
             .or_else(|| Some(download.quantization.to_string()));
         model.architecture = gguf_metadata.as_ref().and_then(|m| m.architecture.clone());
         model.context_length = gguf_metadata.as_ref().and_then(|m| m.context_length);
+        model.server_defaults = ServerConfig::initial(model.context_length);
         model.expert_count = gguf_metadata.as_ref().and_then(|m| m.expert_count);
         model.expert_used_count = gguf_metadata.as_ref().and_then(|m| m.expert_used_count);
         model.expert_shared_count = gguf_metadata.as_ref().and_then(|m| m.expert_shared_count);
@@ -141,15 +142,21 @@ impl ModelRegistrarPort for ModelRegistrar {
         model.hf_commit_sha = Some(download.commit_sha.clone());
         model.hf_filename = Some(file_path.file_name().unwrap().to_string_lossy().to_string());
         model.download_date = Some(Utc::now());
+        // The repository's license tag is authoritative; the GGUF header is a
+        // fallback for repos that don't declare one.
+        model.license = crate::domain::license_from_hf_tags(&download.hf_tags)
+            .or_else(|| crate::domain::license_from_metadata(&model.metadata));
 
         // Pass through file_paths for sharded models
         model.file_paths.clone_from(&download.file_paths);
 
         // Auto-detect capabilities from metadata and merge with HF tags
-        let gguf_tags = gguf_metadata.as_ref().map_or_else(Vec::new, |meta| {
-            let capabilities = self.gguf_parser.detect_capabilities(meta);
-            capabilities.to_tags()
-        });
+        let gguf_capabilities = gguf_metadata
+            .as_ref()
+            .map(|meta| self.gguf_parser.detect_capabilities(meta));
+        let gguf_tags = gguf_capabilities
+            .as_ref()
+            .map_or_else(Vec::new, crate::domain::gguf::GgufCapabilities::to_tags);
 
         // Merge GGUF-derived tags with filtered HF tags (deduplicated)
         model.tags = Self::merge_tags(gguf_tags, &download.hf_tags);
@@ -166,18 +173,14 @@ impl ModelRegistrarPort for ModelRegistrar {
             model.inference_defaults = Some(crate::domain::InferenceConfig::reasoning_profile());
         }
 
-        // Infer capabilities from chat template OR architecture — OR'd so
-        // either signal is sufficient.  Architecture is the backstop for models
-        // whose GGUF ships without a tokenizer section.
-        let template = model.metadata.get("tokenizer.chat_template");
-        let name = model.metadata.get("general.name");
-        let arch = model.metadata.get("general.architecture");
-        let from_template = crate::domain::infer_from_chat_template(
-            template.map(String::as_str),
-            name.map(String::as_str),
+        // Infer capabilities from chat template, architecture, and GGUF
+        // purpose flags (embedding/reranker) — OR'd so any signal is
+        // sufficient.  Architecture is the backstop for models whose GGUF
+        // ships without a tokenizer section.
+        model.capabilities = crate::domain::detect_capabilities(
+            &model.metadata,
+            &gguf_capabilities.unwrap_or_default(),
         );
-        let from_arch = crate::domain::capabilities_from_architecture(arch.map(String::as_str));
-        model.capabilities = from_template | from_arch;
 
         let registered = self.model_repo.insert(&model).await?;
 
@@ -303,12 +306,15 @@ mod tests {
                 hf_commit_sha: model.hf_commit_sha.clone(),
                 hf_filename: model.hf_filename.clone(),
                 capabilities: model.capabilities,
+                capabilities_version: Some(crate::domain::CAPABILITY_DETECTOR_VERSION),
                 download_date: model.download_date,
                 last_update_check: model.last_update_check,
+                license: model.license.clone(),
                 tags: model.tags.clone(),
                 inference_defaults: model.inference_defaults.clone(),
                 server_defaults: model.server_defaults.clone(),
                 benchmark_summary: None,
+                card: model.card.clone(),
             };
             *id += 1;
             drop(id);
@@ -340,7 +346,7 @@ mod tests {
             is_sharded: false,
             total_bytes: 1024,
             file_paths: None,
-            hf_tags: vec![],
+            hf_tags: vec!["license:apache-2.0".to_string()],
             hf_file_entries: vec![],
         };
 
@@ -349,6 +355,8 @@ mod tests {
 
         let model = result.unwrap();
         assert_eq!(model.name, "test/model");
+        assert_eq!(model.license.as_deref(), Some("apache-2.0"));
+        assert!(!model.tags.iter().any(|t| t.starts_with("license:")));
         assert_eq!(model.hf_repo_id, Some("test/model".to_string()));
         assert_eq!(model.hf_commit_sha, Some("abc123".to_string()));
         assert_eq!(model.quantization, Some("Q4_K_M".to_string()));
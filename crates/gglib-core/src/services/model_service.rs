@@ -1,27 +1,64 @@
 //! Model service - orchestrates model CRUD operations.
 
-use crate::domain::{Model, NewModel};
+use crate::domain::chat::{Conversation, ModelBindingWarning, ModelSuggestion};
+use crate::domain::{
+    Model, ModelCapabilities, ModelChanges, ModelListQuery, ModelPage, NewModel, PageRequest,
+    ServerConfig,
+};
+use crate::download::Quantization;
+use crate::jobs::{JobKind, JobManager, JobSpec};
 use crate::ports::{CoreError, GgufParserPort, ModelRepository, RepositoryError};
+use crate::server_config::check_context_size;
 use std::path::Path;
 use std::sync::Arc;
 
 /// The diff produced by [`ModelService::retag_model`] when at least one tag
-/// changed.
+/// or capability flag changed.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct RetagDiff {
     /// Tags that were newly added.
     pub added: Vec<String>,
     /// Tags that were removed (only non-empty on a `full = true` rebuild).
     pub removed: Vec<String>,
+    /// The new capability flags, when the retag changed them (only on a
+    /// `full = true` rebuild).
+    pub capabilities: Option<ModelCapabilities>,
 }
 
 impl RetagDiff {
-    /// Returns `true` if any tag was added or removed.
+    /// Returns `true` if any tag was added or removed, or capabilities changed.
     pub const fn is_changed(&self) -> bool {
-        !self.added.is_empty() || !self.removed.is_empty()
+        !self.added.is_empty() || !self.removed.is_empty() || self.capabilities.is_some()
     }
 }
 
+/// What [`ModelService::redetect_capabilities`] did to one model.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RedetectOutcome {
+    /// Detection ran and changed the flags.
+    Changed {
+        /// The flags before.
+        before: ModelCapabilities,
+        /// The flags now stored.
+        after: ModelCapabilities,
+    },
+    /// Detection ran and produced the flags already stored.
+    Unchanged,
+    /// The flags were set by hand and were left alone.
+    Manual,
+}
+
+/// Counts from [`ModelService::migrate_capabilities`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CapabilityMigrationSummary {
+    /// Models an older detector had seen.
+    pub outdated: usize,
+    /// Of those, models whose flags changed.
+    pub changed: usize,
+    /// Models that could not be re-detected.
+    pub failed: usize,
+}
+
 /// Service for model operations.
 ///
 /// This service provides high-level model management by delegating
@@ -42,6 +79,29 @@ impl ModelService {
         self.repo.list().await.map_err(CoreError::from)
     }
 
+    /// One page of the models matching `query`.
+    pub async fn list_page(
+        &self,
+        query: &ModelListQuery,
+        page: PageRequest,
+    ) -> Result<ModelPage, CoreError> {
+        self.repo
+            .list_page(query, page)
+            .await
+            .map_err(CoreError::from)
+    }
+
+    /// The models added, updated or removed since `since`.
+    pub async fn list_changed_since(
+        &self,
+        since: chrono::DateTime<chrono::Utc>,
+    ) -> Result<ModelChanges, CoreError> {
+        self.repo
+            .list_changed_since(since)
+            .await
+            .map_err(CoreError::from)
+    }
+
     /// Get a model by its identifier (numeric database id, then exact name).
     ///
     /// Thin wrapper over [`ModelRepository::get_by_identifier`], which owns the
@@ -72,6 +132,66 @@ impl ModelService {
         }
     }
 
+    /// Check that the model `conversation` was bound to still exists.
+    ///
+    /// The binding is the conversation's `model_id`, or failing that its
+    /// `settings.model_name` — the database clears `model_id` when the
+    /// model row is deleted, so the saved name is usually what reveals the
+    /// deletion. When that model is
+    /// gone, the warning suggests the library model from the same
+    /// `HuggingFace` repo whose quantization is closest to the original.
+    /// Conversations bound to no model never warn.
+    pub async fn binding_warning(
+        &self,
+        conversation: &Conversation,
+    ) -> Result<Option<ModelBindingWarning>, CoreError> {
+        let settings = conversation.settings.as_ref();
+        let saved_name = settings.and_then(|s| s.model_name.clone());
+        let exists = match (conversation.model_id, &saved_name) {
+            (Some(id), _) => self.get_by_id(id).await?.is_some(),
+            (None, Some(name)) => self.get(name).await?.is_some(),
+            (None, None) => return Ok(None),
+        };
+        if exists {
+            return Ok(None);
+        }
+
+        let suggestion = match settings.and_then(|s| s.hf_repo_id.as_deref()) {
+            Some(repo) => closest_quant(
+                self.list().await?,
+                repo,
+                settings.and_then(|s| s.quantization.as_deref()),
+            )
+            .map(|model| ModelSuggestion {
+                model_id: model.id,
+                model_name: model.name,
+                quantization: model.quantization,
+            }),
+            None => None,
+        };
+
+        let original = saved_name.as_deref().map_or_else(
+            || format!("#{}", conversation.model_id.unwrap_or_default()),
+            |name| format!("'{name}'"),
+        );
+        let message = suggestion.as_ref().map_or_else(
+            || format!("The model {original} this conversation used has been deleted."),
+            |s| {
+                format!(
+                    "The model {original} this conversation used has been deleted; '{}' ({}) from the same repo is available.",
+                    s.model_name,
+                    s.quantization.as_deref().unwrap_or("unknown quant")
+                )
+            },
+        );
+        Ok(Some(ModelBindingWarning {
+            model_id: conversation.model_id,
+            model_name: saved_name,
+            suggestion,
+            message,
+        }))
+    }
+
     /// Find a model by identifier (id, name, or HF ID).
     /// Returns error if not found.
     pub async fn find_by_identifier(&self, identifier: &str) -> Result<Model, CoreError> {
@@ -115,7 +235,8 @@ impl ModelService {
     /// 3. Capability detection (reasoning, tool-calling from metadata)
     /// 4. Chat template inference (additional capability signals)
     /// 5. Auto-tag generation from detected capabilities
-    /// 6. Model persistence with complete `NewModel` struct
+    /// 6. Model card generation from the above
+    /// 7. Model persistence with complete `NewModel` struct
     pub async fn import_from_file(
         &self,
         file_path: &Path,
@@ -144,18 +265,13 @@ impl ModelService {
         //    provides signal.  Architecture-based inference is the backstop for
         //    models whose GGUF ships without a tokenizer section (common in
         //    stripped quantisation builds, e.g. many Mistral/Devstral releases).
-        let template = gguf_metadata.metadata.get("tokenizer.chat_template");
+        let model_capabilities =
+            crate::domain::detect_capabilities(&gguf_metadata.metadata, &gguf_capabilities);
         let name = gguf_metadata.metadata.get("general.name");
-        let from_template = crate::domain::infer_from_chat_template(
-            template.map(String::as_str),
-            name.map(String::as_str),
-        );
-        let from_arch =
-            crate::domain::capabilities_from_architecture(gguf_metadata.architecture.as_deref());
-        let model_capabilities = from_template | from_arch;
+        let license = crate::domain::license_from_metadata(&gguf_metadata.metadata);
 
         // 5. Construct fully-populated NewModel
-        let new_model = NewModel {
+        let mut new_model = NewModel {
             name: name.cloned().unwrap_or_else(|| {
                 file_path
                     .file_stem()
@@ -178,19 +294,36 @@ impl ModelService {
             hf_filename: None,
             download_date: None,
             last_update_check: None,
+            license,
             tags: auto_tags,
             file_paths: None,
             capabilities: model_capabilities,
             inference_defaults: None,
-            server_defaults: None,
+            server_defaults: ServerConfig::initial(gguf_metadata.context_length),
+            card: None,
         };
 
-        // 6. Persist to repository
+        // 6. Local files have no upstream README, so describe the model
+        //    from what was just extracted.
+        new_model.card = Some(crate::domain::generate_model_card(&new_model));
+
+        // 7. Persist to repository
         self.repo.insert(&new_model).await.map_err(CoreError::from)
     }
 
     /// Update a model.
+    ///
+    /// Rejects a per-model default context larger than the model's maximum
+    /// (see [`Model::max_context`]).
     pub async fn update(&self, model: &Model) -> Result<(), CoreError> {
+        if let Some(ctx) = model
+            .server_defaults
+            .as_ref()
+            .and_then(|s| s.context_length)
+        {
+            check_context_size(ctx as u64, model.max_context(None))
+                .map_err(|e| CoreError::Validation(e.to_string()))?;
+        }
         self.repo.update(model).await.map_err(CoreError::from)
     }
 
@@ -298,7 +431,7 @@ impl ModelService {
 
     /// Get filter options aggregated from all models.
     ///
-    /// Returns distinct quantizations, parameter count range, and context length range
+    /// Returns distinct quantizations and licenses, parameter count range, and context length range
     /// for use in the GUI filter popover.
     ///
     /// Note: Uses in-memory aggregation for simplicity. This is acceptable for typical
@@ -319,6 +452,15 @@ impl ModelService {
             .collect();
         quantizations.sort();
 
+        // Collect distinct licenses
+        let mut licenses: Vec<String> = models
+            .iter()
+            .filter_map(|m| m.license.clone())
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect();
+        licenses.sort();
+
         // Compute param_count_b range
         let param_range = if models.is_empty() {
             None
@@ -371,6 +513,7 @@ impl ModelService {
             param_range,
             context_range,
             speed_range,
+            licenses,
         })
     }
 
@@ -425,10 +568,13 @@ impl ModelService {
     /// tag (the predefined capability tag namespace plus every existing
     /// `format:*` tag) is dropped and the freshly-detected set is added in
     /// its place. User-curated tags outside that namespace are preserved.
+    /// A full rebuild also re-derives the `EMBEDDING` / `RERANKER` purpose
+    /// flags on the stored capabilities; the additive path never touches
+    /// capabilities, so manual overrides survive a routine retag.
     ///
-    /// Returns `None` when the tag set is unchanged (no write occurred) and
-    /// `Some(diff)` when the model was updated, carrying the full added/removed
-    /// delta.
+    /// Returns `None` when neither the tag set nor the capabilities changed
+    /// (no write occurred) and `Some(diff)` when the model was updated,
+    /// carrying the added/removed tags and any new capability flags.
     pub async fn retag_model(
         &self,
         model_id: i64,
@@ -447,16 +593,33 @@ impl ModelService {
             metadata: model.metadata.clone(),
             ..Default::default()
         };
-        let new_tags = gguf_parser.detect_capabilities(&gguf_metadata).to_tags();
+        let detected = gguf_parser.detect_capabilities(&gguf_metadata);
+        let new_tags = detected.to_tags();
 
+        let caps_before = model.capabilities;
         let before: std::collections::BTreeSet<String> = model.tags.iter().cloned().collect();
 
         if full {
             // Drop every tag in the auto-generated namespace, then re-add.
-            const AUTO_TAG_NAMES: &[&str] = &["reasoning", "agent", "vision", "code", "moe"];
+            const AUTO_TAG_NAMES: &[&str] = &[
+                "reasoning",
+                "agent",
+                "vision",
+                "code",
+                "moe",
+                "embedding",
+                "reranker",
+            ];
             model.tags.retain(|t| {
                 !AUTO_TAG_NAMES.contains(&t.as_str()) && !crate::domain::is_system_tag(t)
             });
+
+            // Replace the purpose flags with the detected ones so libraries
+            // imported before embedding detection landed drop out of chat
+            // listings. Other capability bits are left as they are.
+            let purpose = ModelCapabilities::EMBEDDING | ModelCapabilities::RERANKER;
+            model.capabilities.remove(purpose);
+            model.capabilities |= crate::domain::capabilities_from_gguf(&detected);
         }
 
         for t in &new_tags {
@@ -467,7 +630,7 @@ impl ModelService {
         model.tags.sort();
 
         let after: std::collections::BTreeSet<String> = model.tags.iter().cloned().collect();
-        if after == before {
+        if after == before && model.capabilities == caps_before {
             return Ok(None);
         }
 
@@ -475,8 +638,137 @@ impl ModelService {
         Ok(Some(RetagDiff {
             added: after.difference(&before).cloned().collect(),
             removed: before.difference(&after).cloned().collect(),
+            capabilities: (model.capabilities != caps_before).then_some(model.capabilities),
         }))
     }
+    // ─────────────────────────────────────────────────────────────────────────
+    // Capability re-detection
+    // ─────────────────────────────────────────────────────────────────────────
+
+    /// Models whose capabilities were detected by an older detector than
+    /// [`CAPABILITY_DETECTOR_VERSION`]. Hand-set capabilities never are.
+    ///
+    /// [`CAPABILITY_DETECTOR_VERSION`]: crate::domain::CAPABILITY_DETECTOR_VERSION
+    pub async fn outdated_capabilities(&self) -> Result<Vec<Model>, CoreError> {
+        let models = self.repo.list().await.map_err(CoreError::from)?;
+        Ok(models
+            .into_iter()
+            .filter(|m| {
+                m.capabilities_version
+                    .is_some_and(|v| v < crate::domain::CAPABILITY_DETECTOR_VERSION)
+            })
+            .collect())
+    }
+
+    /// Re-run capability detection for one model from its persisted GGUF
+    /// metadata and stamp the current detector version.
+    ///
+    /// The detected flags replace the stored ones. Capabilities set by hand
+    /// are left alone unless `force` is set.
+    pub async fn redetect_capabilities(
+        &self,
+        model_id: i64,
+        gguf_parser: &dyn GgufParserPort,
+        force: bool,
+    ) -> Result<RedetectOutcome, CoreError> {
+        let mut model = self
+            .repo
+            .get_by_id(model_id)
+            .await
+            .map_err(CoreError::from)?;
+        if model.capabilities_version.is_none() && !force {
+            return Ok(RedetectOutcome::Manual);
+        }
+
+        let gguf_metadata = crate::domain::gguf::GgufMetadata {
+            metadata: model.metadata.clone(),
+            architecture: model.architecture.clone(),
+            ..Default::default()
+        };
+        let detected = gguf_parser.detect_capabilities(&gguf_metadata);
+        let before = model.capabilities;
+        model.capabilities = crate::domain::detect_capabilities(&model.metadata, &detected);
+        model.capabilities_version = Some(crate::domain::CAPABILITY_DETECTOR_VERSION);
+        self.repo.update(&model).await.map_err(CoreError::from)?;
+
+        Ok(if model.capabilities == before {
+            RedetectOutcome::Unchanged
+        } else {
+            RedetectOutcome::Changed {
+                before,
+                after: model.capabilities,
+            }
+        })
+    }
+
+    /// Re-detect every model in [`Self::outdated_capabilities`], tracked as
+    /// a cancellable job in `jobs`.
+    ///
+    /// Runs at startup after a detector upgrade. No job is started when
+    /// nothing is outdated. A model that fails is logged and skipped; it
+    /// stays outdated and is retried next time.
+    pub async fn migrate_capabilities(
+        &self,
+        gguf_parser: &dyn GgufParserPort,
+        jobs: &Arc<JobManager>,
+    ) -> Result<CapabilityMigrationSummary, CoreError> {
+        let outdated = self.outdated_capabilities().await?;
+        let mut summary = CapabilityMigrationSummary {
+            outdated: outdated.len(),
+            ..Default::default()
+        };
+        if outdated.is_empty() {
+            return Ok(summary);
+        }
+
+        let job = jobs.start(
+            JobSpec::new(JobKind::CapabilityDetection, "Re-detect model capabilities")
+                .cancellable(),
+        );
+        let total = outdated.len() as u64;
+        for (done, model) in (0u64..).zip(outdated) {
+            if job.is_cancel_requested() {
+                job.cancelled_by_request();
+                return Ok(summary);
+            }
+            job.progress(done, Some(total));
+            job.message(model.name.clone());
+            match self
+                .redetect_capabilities(model.id, gguf_parser, false)
+                .await
+            {
+                Ok(RedetectOutcome::Changed { .. }) => summary.changed += 1,
+                Ok(RedetectOutcome::Unchanged | RedetectOutcome::Manual) => {}
+                Err(e) => {
+                    summary.failed += 1;
+                    tracing::warn!(model_id = model.id, "Failed to re-detect capabilities: {e}");
+                }
+            }
+        }
+        job.complete();
+        Ok(summary)
+    }
+}
+
+/// The model from `repo` whose quantization is nearest to `quantization`.
+///
+/// Distance is measured along [`Quantization`]'s declaration order, which
+/// runs from the fewest bits per weight to the most; ties go to the larger
+/// quant. Models with an unrecognised quantization rank last.
+fn closest_quant(models: Vec<Model>, repo: &str, quantization: Option<&str>) -> Option<Model> {
+    let rank = |q: Option<&str>| {
+        let q = Quantization::from_filename(q.unwrap_or_default());
+        (!q.is_unknown()).then_some(q as i32)
+    };
+    let target = rank(quantization);
+    models
+        .into_iter()
+        .filter(|m| m.hf_repo_id.as_deref() == Some(repo))
+        .min_by_key(|m| match (rank(m.quantization.as_deref()), target) {
+            (Some(r), Some(t)) => (0, (r - t).abs(), -r),
+            (Some(r), None) => (0, 0, -r),
+            (None, _) => (1, 0, 0),
+        })
 }
 
 #[cfg(test)]
@@ -550,11 +842,14 @@ mod tests {
                 hf_filename: model.hf_filename.clone(),
                 download_date: model.download_date,
                 last_update_check: model.last_update_check,
+                license: model.license.clone(),
                 tags: model.tags.clone(),
                 capabilities: model.capabilities,
+                capabilities_version: Some(crate::domain::CAPABILITY_DETECTOR_VERSION),
                 inference_defaults: model.inference_defaults.clone(),
                 server_defaults: model.server_defaults.clone(),
                 benchmark_summary: None,
+                card: model.card.clone(),
             };
             models.push(created.clone());
             Ok(created)
@@ -611,6 +906,34 @@ mod tests {
         assert_eq!(found.unwrap().id, created.id);
     }
 
+    #[tokio::test]
+    async fn update_rejects_default_context_above_the_model_maximum() {
+        let repo = Arc::new(MockRepo::new());
+        let service = ModelService::new(repo);
+
+        let mut new_model = NewModel::new(
+            "short-context".to_string(),
+            PathBuf::from("/path/to/model.gguf"),
+            7.0,
+            Utc::now(),
+        );
+        new_model.context_length = Some(4096);
+        let mut model = service.add(new_model).await.unwrap();
+
+        model.server_defaults = Some(ServerConfig {
+            context_length: Some(8192),
+            ..Default::default()
+        });
+        let err = service.update(&model).await.unwrap_err();
+        assert!(matches!(err, CoreError::Validation(ref msg) if msg.contains("4096")));
+
+        model.server_defaults = Some(ServerConfig {
+            context_length: Some(4096),
+            ..Default::default()
+        });
+        service.update(&model).await.unwrap();
+    }
+
     #[tokio::test]
     async fn test_find_by_identifier_not_found() {
         let repo = Arc::new(MockRepo::new());
@@ -645,6 +968,7 @@ mod tests {
         );
         model1.quantization = Some("Q4_K_M".to_string());
         model1.context_length = Some(4096);
+        model1.license = Some("mit".to_string());
 
         let mut model2 = NewModel::new(
             "model-2".to_string(),
@@ -654,6 +978,7 @@ mod tests {
         );
         model2.quantization = Some("Q8_0".to_string());
         model2.context_length = Some(8192);
+        model2.license = Some("apache-2.0".to_string());
 
         let mut model3 = NewModel::new(
             "model-3".to_string(),
@@ -662,6 +987,7 @@ mod tests {
             Utc::now(),
         );
         model3.quantization = Some("Q4_K_M".to_string()); // Duplicate quant
+        model3.license = Some("mit".to_string()); // Duplicate license
         // No context_length set
 
         service.add(model1).await.unwrap();
@@ -672,6 +998,7 @@ mod tests {
 
         // Should have 2 distinct quantizations, sorted
         assert_eq!(options.quantizations, vec!["Q4_K_M", "Q8_0"]);
+        assert_eq!(options.licenses, vec!["apache-2.0", "mit"]);
 
         // Param range: 7.0 to 70.0
         let param_range = options.param_range.unwrap();
@@ -736,6 +1063,7 @@ mod tests {
     /// Stub parser that emits a fixed capability set for retag tests.
     struct StubCapsParser {
         tags: Vec<String>,
+        flags: crate::domain::gguf::CapabilityFlags,
     }
 
     impl crate::ports::GgufParserPort for StubCapsParser {
@@ -755,7 +1083,7 @@ mod tests {
                 extensions.insert(t.clone());
             }
             crate::ports::GgufCapabilities {
-                flags: crate::domain::gguf::CapabilityFlags::empty(),
+                flags: self.flags,
                 extensions,
             }
         }
@@ -773,6 +1101,7 @@ mod tests {
 
         let parser = StubCapsParser {
             tags: vec!["format:qwen-xml".to_string()],
+            flags: crate::domain::gguf::CapabilityFlags::empty(),
         };
         let diff = service
             .retag_model(created.id, &parser, false)
@@ -797,6 +1126,7 @@ mod tests {
 
         let parser = StubCapsParser {
             tags: vec!["format:qwen-xml".to_string()],
+            flags: crate::domain::gguf::CapabilityFlags::empty(),
         };
         let diff = service
             .retag_model(created.id, &parser, false)
@@ -821,6 +1151,7 @@ mod tests {
 
         let parser = StubCapsParser {
             tags: vec!["format:qwen-xml".to_string()],
+            flags: crate::domain::gguf::CapabilityFlags::empty(),
         };
         service
             .retag_model(created.id, &parser, true)
@@ -833,4 +1164,178 @@ mod tests {
         assert!(!tags.contains(&"format:hermes".to_string()));
         assert!(!tags.contains(&"reasoning".to_string()));
     }
+
+    #[tokio::test]
+    async fn test_retag_full_lifts_embedding_flag_onto_capabilities() {
+        let repo = Arc::new(MockRepo::new());
+        let service = ModelService::new(repo);
+
+        let mut new_model =
+            NewModel::new("bge".to_string(), PathBuf::from("/p.gguf"), 0.3, Utc::now());
+        new_model.tags = vec!["embedding".to_string()];
+        let created = service.add(new_model).await.unwrap();
+        assert!(created.capabilities.is_empty());
+
+        let parser = StubCapsParser {
+            tags: vec![],
+            flags: crate::domain::gguf::CapabilityFlags::EMBEDDING,
+        };
+
+        // Additive retag never writes capabilities.
+        let diff = service
+            .retag_model(created.id, &parser, false)
+            .await
+            .unwrap();
+        assert!(diff.is_none());
+
+        let diff = service
+            .retag_model(created.id, &parser, true)
+            .await
+            .unwrap()
+            .expect("capabilities changed");
+        assert!(diff.added.is_empty() && diff.removed.is_empty());
+        assert_eq!(diff.capabilities, Some(ModelCapabilities::EMBEDDING));
+
+        let stored = service.get_by_id(created.id).await.unwrap().unwrap();
+        assert_eq!(stored.capabilities, ModelCapabilities::EMBEDDING);
+
+        // A second full rebuild finds nothing to change.
+        let diff = service
+            .retag_model(created.id, &parser, true)
+            .await
+            .unwrap();
+        assert!(diff.is_none());
+    }
+
+    #[tokio::test]
+    async fn redetect_leaves_hand_set_capabilities_unless_forced() {
+        let repo = Arc::new(MockRepo::new());
+        let service = ModelService::new(repo);
+
+        let new_model = NewModel::new("bge".to_string(), PathBuf::from("/p.gguf"), 0.3, Utc::now());
+        let mut created = service.add(new_model).await.unwrap();
+        created.capabilities_version = None;
+        service.update(&created).await.unwrap();
+
+        let parser = StubCapsParser {
+            tags: vec![],
+            flags: crate::domain::gguf::CapabilityFlags::EMBEDDING,
+        };
+        let outcome = service
+            .redetect_capabilities(created.id, &parser, false)
+            .await
+            .unwrap();
+        assert_eq!(outcome, RedetectOutcome::Manual);
+
+        let outcome = service
+            .redetect_capabilities(created.id, &parser, true)
+            .await
+            .unwrap();
+        assert_eq!(
+            outcome,
+            RedetectOutcome::Changed {
+                before: ModelCapabilities::empty(),
+                after: ModelCapabilities::EMBEDDING,
+            }
+        );
+        let stored = service.get_by_id(created.id).await.unwrap().unwrap();
+        assert_eq!(
+            stored.capabilities_version,
+            Some(crate::domain::CAPABILITY_DETECTOR_VERSION)
+        );
+    }
+
+    #[tokio::test]
+    async fn migration_redetects_only_outdated_models() {
+        let repo = Arc::new(MockRepo::new());
+        let service = ModelService::new(repo);
+
+        // Detected by the unversioned detector, by the current one, by hand.
+        for (name, version) in [
+            ("old", Some(0)),
+            ("current", Some(crate::domain::CAPABILITY_DETECTOR_VERSION)),
+            ("manual", None),
+        ] {
+            let new_model = NewModel::new(name.to_string(), PathBuf::from(name), 0.3, Utc::now());
+            let mut created = service.add(new_model).await.unwrap();
+            created.capabilities_version = version;
+            service.update(&created).await.unwrap();
+        }
+
+        let parser = StubCapsParser {
+            tags: vec![],
+            flags: crate::domain::gguf::CapabilityFlags::EMBEDDING,
+        };
+        let jobs = Arc::new(JobManager::new(Arc::new(crate::ports::NoopEmitter)));
+        let summary = service.migrate_capabilities(&parser, &jobs).await.unwrap();
+        assert_eq!(
+            summary,
+            CapabilityMigrationSummary {
+                outdated: 1,
+                changed: 1,
+                failed: 0,
+            }
+        );
+
+        let caps: Vec<_> = service
+            .list()
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|m| m.capabilities)
+            .collect();
+        assert_eq!(
+            caps,
+            [
+                ModelCapabilities::EMBEDDING,
+                ModelCapabilities::empty(),
+                ModelCapabilities::empty(),
+            ]
+        );
+        assert!(service.outdated_capabilities().await.unwrap().is_empty());
+
+        let job = &jobs.list()[0];
+        assert_eq!(job.kind, JobKind::CapabilityDetection);
+        assert_eq!(job.state, crate::jobs::JobState::Completed);
+    }
+
+    #[tokio::test]
+    async fn binding_warning_suggests_the_closest_quant() {
+        let repo = Arc::new(MockRepo::new());
+        let service = ModelService::new(repo);
+        for (name, quant) in [("q2", "Q2_K"), ("q5", "Q5_K_M"), ("q8", "Q8_0")] {
+            let mut model = NewModel::new(name.to_string(), PathBuf::from(name), 7.0, Utc::now());
+            model.hf_repo_id = Some("org/model-GGUF".to_string());
+            model.quantization = Some(quant.to_string());
+            service.add(model).await.unwrap();
+        }
+
+        let mut conversation = Conversation {
+            id: 1,
+            title: "Chat".to_string(),
+            model_id: Some(1),
+            system_prompt: None,
+            settings: Some(crate::domain::chat::ConversationSettings {
+                model_name: Some("q4".to_string()),
+                hf_repo_id: Some("org/model-GGUF".to_string()),
+                quantization: Some("Q4_K_M".to_string()),
+                ..Default::default()
+            }),
+            created_at: String::new(),
+            updated_at: String::new(),
+            incognito: false,
+        };
+        // Bound to a model that still exists.
+        assert_eq!(service.binding_warning(&conversation).await.unwrap(), None);
+
+        conversation.model_id = Some(99);
+        let warning = service
+            .binding_warning(&conversation)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(warning.model_name.as_deref(), Some("q4"));
+        assert_eq!(warning.suggestion.unwrap().model_name, "q5");
+        assert!(warning.message.contains("'q4'"));
+    }
 }
@@ -0,0 +1,273 @@
+//! Model trash service - soft deletion, restore and scheduled purge.
+//!
+//! [`trash`](ModelTrashService::trash) moves a model's files (every shard
+//! of a sharded model) into a fresh entry directory under the trash root
+//! and marks its row deleted through the [`ModelTrashRepository`] port.
+//! [`restore`](ModelTrashService::restore) reverses both. Retention and the
+//! size cap come from settings on every purge, so changes apply without a
+//! restart; [`start_purger`](ModelTrashService::start_purger) runs the purge
+//! periodically.
+
+use std::collections::HashSet;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::Utc;
+
+use crate::domain::Model;
+use crate::domain::trash::{TrashEntry, TrashRecord, TrashedFile, select_for_purge};
+use crate::ports::{
+    CoreError, ModelRepository, ModelTrashRepository, RepositoryError, SettingsRepository,
+};
+use crate::utils::shard_filename::base_shard_filename;
+
+/// How often [`ModelTrashService::start_purger`] purges due entries.
+pub const PURGE_INTERVAL: Duration = Duration::from_hours(1);
+
+/// Service for trashing, restoring and purging models.
+pub struct ModelTrashService {
+    models: Arc<dyn ModelRepository>,
+    trash: Arc<dyn ModelTrashRepository>,
+    settings: Arc<dyn SettingsRepository>,
+    root: PathBuf,
+}
+
+impl ModelTrashService {
+    /// Create a service keeping trashed files under `root`.
+    pub fn new(
+        models: Arc<dyn ModelRepository>,
+        trash: Arc<dyn ModelTrashRepository>,
+        settings: Arc<dyn SettingsRepository>,
+        root: PathBuf,
+    ) -> Self {
+        Self {
+            models,
+            trash,
+            settings,
+            root,
+        }
+    }
+
+    /// Move `model`'s files to the trash and mark it deleted.
+    ///
+    /// Files that are already gone are skipped, so a model whose file was
+    /// deleted by hand can still be removed.
+    pub async fn trash(&self, model: &Model) -> Result<TrashEntry, CoreError> {
+        let deleted_at = Utc::now();
+        let dir = self.root.join(format!(
+            "{}-{}",
+            model.id,
+            deleted_at.format("%Y%m%dT%H%M%SZ")
+        ));
+        tokio::fs::create_dir_all(&dir)
+            .await
+            .map_err(|e| storage(&e))?;
+
+        let mut files = Vec::new();
+        let mut size_bytes = 0;
+        for original in model_files(&model.file_path).await {
+            let Some(name) = original.file_name() else {
+                continue;
+            };
+            let trashed = dir.join(name);
+            size_bytes += tokio::fs::metadata(&original).await.map_or(0, |m| m.len());
+            move_file(&original, &trashed)
+                .await
+                .map_err(|e| storage(&e))?;
+            files.push(TrashedFile {
+                original: original.to_string_lossy().into_owned(),
+                trashed: trashed.to_string_lossy().into_owned(),
+            });
+        }
+
+        let record = TrashRecord {
+            deleted_at,
+            dir: dir.to_string_lossy().into_owned(),
+            files,
+            size_bytes,
+        };
+        self.trash.mark_deleted(model.id, &record).await?;
+        let retention = self.retention_days().await;
+        Ok(TrashEntry::new(
+            model.id,
+            model.name.clone(),
+            record,
+            retention,
+        ))
+    }
+
+    /// List trashed models, oldest first.
+    pub async fn list(&self) -> Result<Vec<TrashEntry>, CoreError> {
+        let retention = self.retention_days().await;
+        Ok(self
+            .trash
+            .list_deleted()
+            .await?
+            .into_iter()
+            .map(|(model, record)| TrashEntry::new(model.id, model.name, record, retention))
+            .collect())
+    }
+
+    /// Move a trashed model's files back and clear its deleted mark.
+    ///
+    /// Fails without moving anything if a file now exists at one of the
+    /// original paths.
+    pub async fn restore(&self, id: i64) -> Result<Model, CoreError> {
+        let (_, record) = self.find(id).await?;
+        for file in &record.files {
+            if tokio::fs::try_exists(&file.original).await.unwrap_or(false) {
+                return Err(CoreError::Validation(format!(
+                    "cannot restore: '{}' already exists",
+                    file.original
+                )));
+            }
+        }
+        for file in &record.files {
+            move_file(Path::new(&file.trashed), Path::new(&file.original))
+                .await
+                .map_err(|e| storage(&e))?;
+        }
+        self.trash.restore(id).await?;
+        remove_dir(Path::new(&record.dir)).await;
+        Ok(self.models.get_by_id(id).await?)
+    }
+
+    /// Permanently delete every trashed model. Returns what was deleted.
+    pub async fn empty(&self) -> Result<Vec<TrashEntry>, CoreError> {
+        let entries = self.list().await?;
+        let ids: Vec<i64> = entries.iter().map(|e| e.model_id).collect();
+        self.purge(&ids).await?;
+        Ok(entries)
+    }
+
+    /// Permanently delete expired entries, then the oldest ones while the
+    /// trash is over its size cap. Returns what was deleted.
+    pub async fn purge_due(&self) -> Result<Vec<TrashEntry>, CoreError> {
+        let settings = self.settings.load().await?;
+        let entries = self.list().await?;
+        let due = select_for_purge(&entries, Utc::now(), settings.trash_max_size_bytes());
+        self.purge(&due).await?;
+        Ok(entries
+            .into_iter()
+            .filter(|e| due.contains(&e.model_id))
+            .collect())
+    }
+
+    /// Run [`purge_due`](Self::purge_due) now and then every
+    /// [`PURGE_INTERVAL`] in the background.
+    pub fn start_purger(self: &Arc<Self>) {
+        let service = Arc::clone(self);
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(PURGE_INTERVAL);
+            loop {
+                ticker.tick().await;
+                match service.purge_due().await {
+                    Ok(purged) if !purged.is_empty() => {
+                        tracing::info!(count = purged.len(), "Purged models from the trash");
+                    }
+                    Ok(_) => {}
+                    Err(e) => tracing::warn!("Trash purge failed: {e}"),
+                }
+            }
+        });
+    }
+
+    /// Delete the rows and entry directories of `ids`, then any entry
+    /// directory no trashed model refers to (left behind when a trashed
+    /// model was added again).
+    async fn purge(&self, ids: &[i64]) -> Result<(), CoreError> {
+        let deleted = self.trash.list_deleted().await?;
+        let mut kept = HashSet::new();
+        for (model, record) in deleted {
+            if ids.contains(&model.id) {
+                self.models.delete(model.id).await?;
+                remove_dir(Path::new(&record.dir)).await;
+            } else {
+                kept.insert(PathBuf::from(record.dir));
+            }
+        }
+
+        let Ok(mut dirs) = tokio::fs::read_dir(&self.root).await else {
+            return Ok(());
+        };
+        while let Ok(Some(item)) = dirs.next_entry().await {
+            let path = item.path();
+            if !kept.contains(&path) {
+                remove_dir(&path).await;
+            }
+        }
+        Ok(())
+    }
+
+    async fn find(&self, id: i64) -> Result<(Model, TrashRecord), CoreError> {
+        self.trash
+            .list_deleted()
+            .await?
+            .into_iter()
+            .find(|(model, _)| model.id == id)
+            .ok_or_else(|| RepositoryError::NotFound(format!("Trashed model with ID {id}")).into())
+    }
+
+    async fn retention_days(&self) -> u32 {
+        self.settings
+            .load()
+            .await
+            .map_or(crate::settings::DEFAULT_TRASH_RETENTION_DAYS, |s| {
+                s.effective_trash_retention_days()
+            })
+    }
+}
+
+/// The files making up the model at `path`: the file itself, or every
+/// shard beside it when it is one shard of a split GGUF.
+async fn model_files(path: &Path) -> Vec<PathBuf> {
+    let name = path
+        .file_name()
+        .map(|n| n.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let base = base_shard_filename(&name);
+    let is_shard = base != name;
+
+    let mut files = Vec::new();
+    if is_shard && let Some(parent) = path.parent() {
+        if let Ok(mut siblings) = tokio::fs::read_dir(parent).await {
+            while let Ok(Some(item)) = siblings.next_entry().await {
+                let sibling = item.file_name().to_string_lossy().into_owned();
+                if sibling != base && base_shard_filename(&sibling) == base {
+                    files.push(item.path());
+                }
+            }
+        }
+        files.sort();
+    } else if tokio::fs::try_exists(path).await.unwrap_or(false) {
+        files.push(path.to_path_buf());
+    }
+    files
+}
+
+/// Rename, falling back to copy-and-delete across filesystems.
+async fn move_file(from: &Path, to: &Path) -> io::Result<()> {
+    if let Some(parent) = to.parent() {
+        tokio::fs::create_dir_all(parent).await?;
+    }
+    if tokio::fs::rename(from, to).await.is_ok() {
+        return Ok(());
+    }
+    tokio::fs::copy(from, to).await?;
+    tokio::fs::remove_file(from).await
+}
+
+/// Remove a trash entry directory, logging rather than failing.
+async fn remove_dir(dir: &Path) {
+    match tokio::fs::remove_dir_all(dir).await {
+        Ok(()) => {}
+        Err(e) if e.kind() == io::ErrorKind::NotFound => {}
+        Err(e) => tracing::warn!(dir = %dir.display(), "Could not remove trash entry: {e}"),
+    }
+}
+
+fn storage(e: &io::Error) -> CoreError {
+    CoreError::Internal(format!("trash storage error: {e}"))
+}
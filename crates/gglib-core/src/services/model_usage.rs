@@ -0,0 +1,114 @@
+//! Model usage service - collects per-model usage and ranks the library.
+//!
+//! [`start_collector`](ModelUsageService::start_collector) periodically
+//! drains a [`ModelUsageSource`] and adds what it counted to today's
+//! aggregate for each model; [`ranking`](ModelUsageService::ranking) reads
+//! the aggregates back over a period and orders every model by use, and
+//! [`daily`](ModelUsageService::daily) sums them per day. Both estimate
+//! energy at the [`rate`](ModelUsageService::rate) from settings, falling
+//! back to a figure for the detected hardware.
+
+use std::sync::{Arc, OnceLock};
+use std::time::Duration;
+
+use chrono::{Days, Local, NaiveDate};
+
+use crate::domain::energy::{EnergyRate, detected_watts};
+use crate::domain::usage::{DailyUsage, ModelUsage, daily_summary, rank_models};
+use crate::ports::{
+    CoreError, ModelRepository, ModelUsageRepository, ModelUsageSource, SettingsRepository,
+    SystemProbePort,
+};
+
+/// How often [`ModelUsageService::start_collector`] stores counted usage.
+/// At most this much usage is lost when the process exits.
+pub const USAGE_COLLECT_INTERVAL: Duration = Duration::from_mins(1);
+
+/// Service for storing and ranking per-model usage.
+pub struct ModelUsageService {
+    models: Arc<dyn ModelRepository>,
+    usage: Arc<dyn ModelUsageRepository>,
+    settings: Arc<dyn SettingsRepository>,
+    system: Arc<dyn SystemProbePort>,
+    /// Hardware draw from `system`, probed on first use.
+    detected_watts: OnceLock<f64>,
+}
+
+impl ModelUsageService {
+    /// Create a service storing usage in `usage`. The `energy` setting is
+    /// read from `settings`; `system` is probed for a fallback draw.
+    pub fn new(
+        models: Arc<dyn ModelRepository>,
+        usage: Arc<dyn ModelUsageRepository>,
+        settings: Arc<dyn SettingsRepository>,
+        system: Arc<dyn SystemProbePort>,
+    ) -> Self {
+        Self {
+            models,
+            usage,
+            settings,
+            system,
+            detected_watts: OnceLock::new(),
+        }
+    }
+
+    /// Store what `source` has counted since the last collection under
+    /// today's date (local time).
+    ///
+    /// A model whose usage cannot be stored — e.g. one purged since its
+    /// server ran — is logged and skipped; the others are still stored.
+    pub async fn collect(&self, source: &dyn ModelUsageSource) {
+        let today = Local::now().date_naive();
+        for (model_id, delta) in source.drain_usage() {
+            if let Err(e) = self.usage.add(model_id, today, &delta).await {
+                tracing::warn!(model_id, "Could not store model usage: {e}");
+            }
+        }
+    }
+
+    /// Every model ranked by its usage over the last `days` days (today
+    /// included), or over all recorded usage when `days` is `None`.
+    pub async fn ranking(&self, days: Option<u32>) -> Result<Vec<ModelUsage>, CoreError> {
+        let models = self.models.list().await?;
+        let totals = self.usage.totals(days.map(since)).await?;
+        Ok(rank_models(&models, &totals, &self.rate().await?))
+    }
+
+    /// Usage across the library per day over the last `days` days (today
+    /// included), newest first. Days without usage are left out.
+    pub async fn daily(&self, days: u32) -> Result<Vec<DailyUsage>, CoreError> {
+        let totals = self.usage.daily(since(days)).await?;
+        Ok(daily_summary(&totals, &self.rate().await?))
+    }
+
+    /// The draw and price energy is estimated with: the `energy` setting,
+    /// with a figure for the detected hardware when it names no draw.
+    pub async fn rate(&self) -> Result<EnergyRate, CoreError> {
+        let settings = self.settings.load().await?;
+        let detected = *self
+            .detected_watts
+            .get_or_init(|| detected_watts(&self.system.get_system_memory_info()));
+        Ok(EnergyRate::resolve(settings.energy.as_ref(), detected))
+    }
+
+    /// Run [`collect`](Self::collect) every [`USAGE_COLLECT_INTERVAL`] in
+    /// the background.
+    pub fn start_collector(self: &Arc<Self>, source: Arc<dyn ModelUsageSource>) {
+        let service = Arc::clone(self);
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(USAGE_COLLECT_INTERVAL);
+            loop {
+                ticker.tick().await;
+                service.collect(source.as_ref()).await;
+            }
+        });
+    }
+}
+
+/// First day of a period of `days` days ending today.
+fn since(days: u32) -> NaiveDate {
+    let today = Local::now().date_naive();
+    today
+        .checked_sub_days(Days::new(u64::from(days.saturating_sub(1))))
+        .unwrap_or(NaiveDate::MIN)
+}
@@ -18,9 +18,11 @@ use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
 use tokio::sync::{RwLock, mpsc};
 use tokio::task::JoinHandle;
+use ts_rs::TS;
+use utoipa::ToSchema;
 
 use crate::domain::ModelFile;
-use crate::ports::{HfClientPort, ModelRepository, RepositoryError};
+use crate::ports::{HfClientPort, ModelHub, ModelRepository, RepositoryError};
 
 // ============================================================================
 // Domain Types
@@ -49,7 +51,7 @@ pub enum ShardProgress {
 }
 
 /// Health status of an individual shard after verification.
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, ToSchema)]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum ShardHealth {
     /// File is healthy - hash matches expected OID.
@@ -81,7 +83,7 @@ pub struct VerificationProgress {
 }
 
 /// Complete verification report for a model.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct VerificationReport {
     /// Model ID that was verified.
     pub model_id: i64,
@@ -94,7 +96,7 @@ pub struct VerificationReport {
 }
 
 /// Overall health status for a model.
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, TS, ToSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum OverallHealth {
     /// All shards are healthy.
@@ -106,7 +108,7 @@ pub enum OverallHealth {
 }
 
 /// Health report for a single shard.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct ShardHealthReport {
     /// Shard index.
     pub index: usize,
@@ -117,7 +119,7 @@ pub struct ShardHealthReport {
 }
 
 /// Result of checking for model updates.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct UpdateCheckResult {
     /// Model ID that was checked.
     pub model_id: i64,
@@ -128,7 +130,7 @@ pub struct UpdateCheckResult {
 }
 
 /// Details about available updates.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct UpdateDetails {
     /// Number of shards that have changed.
     pub changed_shards: usize,
@@ -137,7 +139,7 @@ pub struct UpdateDetails {
 }
 
 /// Update information for a single shard.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct ShardUpdate {
     /// Shard index.
     pub index: usize,
@@ -436,7 +438,7 @@ impl ModelVerificationService {
     }
 
     /// Verify a single shard by computing its SHA256 and comparing with OID.
-    #[allow(clippy::cognitive_complexity)]
+    #[allow(clippy::cognitive_complexity, clippy::too_many_lines)]
     async fn verify_shard(
         file: &ModelFile,
         resolved_path: &Path,
@@ -496,6 +498,11 @@ impl ModelVerificationService {
             });
 
             loop {
+                // Nobody is listening any more: the verification was
+                // cancelled, so stop reading rather than hash the rest.
+                if tx_clone.is_closed() {
+                    anyhow::bail!("verification cancelled");
+                }
                 let n = file.read(&mut buffer)?;
                 if n == 0 {
                     break;
@@ -564,6 +571,14 @@ impl ModelVerificationService {
         }
     }
 
+    /// The files recorded for a model, with their LFS OIDs where known.
+    pub async fn model_files(&self, model_id: i64) -> Result<Vec<ModelFile>, RepositoryError> {
+        self.model_files_repo
+            .get_by_model_id(model_id)
+            .await
+            .map_err(|e| RepositoryError::Storage(e.to_string()))
+    }
+
     /// Check if updates are available for a model.
     ///
     /// Compares local OIDs with remote OIDs from `HuggingFace`.
@@ -605,6 +620,16 @@ impl ModelVerificationService {
             });
         }
 
+        // Only the configured hub's models can be checked.
+        let (hub, repo_id) = ModelHub::split(repo_id);
+        if hub != self.hf_client.hub() {
+            return Ok(UpdateCheckResult {
+                model_id,
+                update_available: false,
+                details: None,
+            });
+        }
+
         // Get remote file metadata from HuggingFace
         let remote_files = self
             .hf_client
@@ -0,0 +1,36 @@
+//! Pipeline service - read access to persisted pipeline runs.
+//!
+//! Runs are written by the pipeline runner in `gglib-agent` as steps
+//! complete; this service is what surfaces them to the CLI and API.
+
+use std::sync::Arc;
+
+use crate::domain::pipeline::PipelineRun;
+use crate::ports::pipelines::{PipelineError, PipelineRunRepository};
+
+/// Service for pipeline run queries.
+pub struct PipelineService {
+    repo: Arc<dyn PipelineRunRepository>,
+}
+
+impl PipelineService {
+    /// Create a new pipeline service.
+    pub fn new(repo: Arc<dyn PipelineRunRepository>) -> Self {
+        Self { repo }
+    }
+
+    /// Return the underlying run repository, for the runner to write to.
+    pub fn repo(&self) -> Arc<dyn PipelineRunRepository> {
+        Arc::clone(&self.repo)
+    }
+
+    /// List runs, most recent first.
+    pub async fn list_runs(&self) -> Result<Vec<PipelineRun>, PipelineError> {
+        self.repo.list().await
+    }
+
+    /// Get a run by ID.
+    pub async fn get_run(&self, id: i64) -> Result<PipelineRun, PipelineError> {
+        self.repo.get(id).await?.ok_or(PipelineError::NotFound(id))
+    }
+}
@@ -0,0 +1,78 @@
+//! Preset service - thin orchestrator for preset persistence.
+//!
+//! Validates preset fields before they reach the [`PresetRepository`] port.
+
+use std::sync::Arc;
+
+use crate::domain::preset::{NewPreset, Preset, PresetUpdate, validate_preset};
+use crate::ports::presets::{PresetError, PresetRepository};
+
+/// Service for preset operations.
+pub struct PresetService {
+    repo: Arc<dyn PresetRepository>,
+}
+
+impl PresetService {
+    /// Create a new preset service.
+    pub fn new(repo: Arc<dyn PresetRepository>) -> Self {
+        Self { repo }
+    }
+
+    /// Return the underlying preset repository.
+    pub fn repo(&self) -> Arc<dyn PresetRepository> {
+        Arc::clone(&self.repo)
+    }
+
+    /// Create a preset and return it.
+    pub async fn create(&self, preset: NewPreset) -> Result<Preset, PresetError> {
+        validate_preset(&Preset {
+            id: 0,
+            name: preset.name.clone(),
+            description: preset.description.clone(),
+            system_prompt: preset.system_prompt.clone(),
+            sampling: preset.sampling.clone(),
+            tools: preset.tools.clone(),
+            mcp_server_ids: preset.mcp_server_ids.clone(),
+            default_model: preset.default_model.clone(),
+            created_at: String::new(),
+            updated_at: String::new(),
+        })
+        .map_err(PresetError::Invalid)?;
+
+        let id = self.repo.create(preset).await?;
+        self.get(id).await
+    }
+
+    /// List all presets.
+    pub async fn list(&self) -> Result<Vec<Preset>, PresetError> {
+        self.repo.list().await
+    }
+
+    /// Get a preset by ID.
+    pub async fn get(&self, id: i64) -> Result<Preset, PresetError> {
+        self.repo.get(id).await?.ok_or(PresetError::NotFound(id))
+    }
+
+    /// Get a preset by name.
+    pub async fn get_by_name(&self, name: &str) -> Result<Option<Preset>, PresetError> {
+        self.repo.get_by_name(name).await
+    }
+
+    /// Update a preset and return the result.
+    pub async fn update(&self, id: i64, update: PresetUpdate) -> Result<Preset, PresetError> {
+        let mut preset = self.get(id).await?;
+        if update.is_empty() {
+            return Ok(preset);
+        }
+        update.clone().apply(&mut preset);
+        validate_preset(&preset).map_err(PresetError::Invalid)?;
+
+        self.repo.update(id, update).await?;
+        self.get(id).await
+    }
+
+    /// Delete a preset.
+    pub async fn delete(&self, id: i64) -> Result<(), PresetError> {
+        self.repo.delete(id).await
+    }
+}
@@ -0,0 +1,93 @@
+//! Project service - thin orchestrator for project persistence.
+//!
+//! Validates project fields before they reach the [`ProjectRepository`]
+//! port. Filesystem concerns (file trees, reading included files) live in
+//! the adapters, which know the project root is on the local disk.
+
+use std::sync::Arc;
+
+use crate::domain::project::{NewProject, Project, ProjectUpdate, validate_project};
+use crate::ports::projects::{ProjectError, ProjectRepository};
+
+/// Service for project operations.
+pub struct ProjectService {
+    repo: Arc<dyn ProjectRepository>,
+}
+
+impl ProjectService {
+    /// Create a new project service.
+    pub fn new(repo: Arc<dyn ProjectRepository>) -> Self {
+        Self { repo }
+    }
+
+    /// Create a project and return it.
+    pub async fn create(&self, project: NewProject) -> Result<Project, ProjectError> {
+        validate_project(&Project {
+            id: 0,
+            name: project.name.clone(),
+            root_path: project.root_path.clone(),
+            include_globs: project.include_globs.clone(),
+            exclude_globs: project.exclude_globs.clone(),
+            auto_include: project.auto_include,
+            token_budget: project.token_budget,
+            created_at: String::new(),
+            updated_at: String::new(),
+        })
+        .map_err(ProjectError::Invalid)?;
+
+        let id = self.repo.create(project).await?;
+        self.get(id).await
+    }
+
+    /// List all projects.
+    pub async fn list(&self) -> Result<Vec<Project>, ProjectError> {
+        self.repo.list().await
+    }
+
+    /// Get a project by ID.
+    pub async fn get(&self, id: i64) -> Result<Project, ProjectError> {
+        self.repo.get(id).await?.ok_or(ProjectError::NotFound(id))
+    }
+
+    /// Update a project and return the result.
+    pub async fn update(&self, id: i64, update: ProjectUpdate) -> Result<Project, ProjectError> {
+        let mut project = self.get(id).await?;
+        if update.is_empty() {
+            return Ok(project);
+        }
+        update.clone().apply(&mut project);
+        validate_project(&project).map_err(ProjectError::Invalid)?;
+
+        self.repo.update(id, update).await?;
+        self.get(id).await
+    }
+
+    /// Delete a project.
+    pub async fn delete(&self, id: i64) -> Result<(), ProjectError> {
+        self.repo.delete(id).await
+    }
+
+    /// Link a conversation to a project.
+    pub async fn link_conversation(
+        &self,
+        conversation_id: i64,
+        project_id: i64,
+    ) -> Result<(), ProjectError> {
+        self.repo
+            .link_conversation(conversation_id, project_id)
+            .await
+    }
+
+    /// Remove a conversation's project link.
+    pub async fn unlink_conversation(&self, conversation_id: i64) -> Result<(), ProjectError> {
+        self.repo.unlink_conversation(conversation_id).await
+    }
+
+    /// The project a conversation is linked to, if any.
+    pub async fn get_for_conversation(
+        &self,
+        conversation_id: i64,
+    ) -> Result<Option<Project>, ProjectError> {
+        self.repo.get_for_conversation(conversation_id).await
+    }
+}
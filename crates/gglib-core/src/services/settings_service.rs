@@ -1,18 +1,52 @@
 //! Settings service - orchestrates settings operations.
 
-use crate::ports::{CoreError, SettingsRepository};
+use crate::domain::sync::peer_token_secret;
+use crate::ports::{
+    BACKUP_SECRET_ACCESS_KEY_SECRET, CoreError, RepositoryError, SecretStoreError, SecretStorePort,
+    SettingsRepository, WEB_SEARCH_API_KEY_SECRET, parse_secret_ref, secret_ref,
+};
 use crate::settings::{Settings, SettingsUpdate, validate_settings};
 use std::sync::Arc;
+use uuid::Uuid;
+
+/// How many times an unconditional [`SettingsService::update`] re-reads and
+/// re-applies its changes after losing a race with another writer.
+const UPDATE_ATTEMPTS: usize = 5;
+
+/// Secret store keys a stash wrote, so a rejected save can remove them.
+type StashedSecrets = Vec<String>;
+
+/// Result of a versioned settings update.
+#[derive(Debug, Clone)]
+pub struct SettingsWrite {
+    /// The settings as stored (credentials are `secret:` references).
+    pub settings: Settings,
+    /// Version of the stored settings after the write.
+    pub version: u64,
+    /// Stored names of the fields the update changed, sorted.
+    pub changed_keys: Vec<String>,
+}
 
 /// Service for settings operations.
 pub struct SettingsService {
     repo: Arc<dyn SettingsRepository>,
+    secrets: Option<Arc<dyn SecretStorePort>>,
 }
 
 impl SettingsService {
     /// Create a new settings service.
     pub fn new(repo: Arc<dyn SettingsRepository>) -> Self {
-        Self { repo }
+        Self {
+            repo,
+            secrets: None,
+        }
+    }
+
+    /// Keep credentials in `secrets` instead of the settings row.
+    #[must_use]
+    pub fn with_secrets(mut self, secrets: Arc<dyn SecretStorePort>) -> Self {
+        self.secrets = Some(secrets);
+        self
     }
 
     /// Return the underlying settings repository.
@@ -20,42 +54,294 @@ impl SettingsService {
         Arc::clone(&self.repo)
     }
 
+    /// Return the secret store, if one is configured.
+    pub fn secrets(&self) -> Option<Arc<dyn SecretStorePort>> {
+        self.secrets.clone()
+    }
+
     /// Get current settings.
+    ///
+    /// Credential fields hold `secret:` references; use
+    /// [`get_resolved`](Self::get_resolved) where the values are needed.
     pub async fn get(&self) -> Result<Settings, CoreError> {
         self.repo.load().await.map_err(CoreError::from)
     }
 
+    /// Current settings version; bumped by every save from any process.
+    pub async fn version(&self) -> Result<u64, CoreError> {
+        self.repo.version().await.map_err(CoreError::from)
+    }
+
+    /// Get current settings together with their version.
+    ///
+    /// The version is read first, so a write landing in between yields a
+    /// version that is already stale: a conditional update based on it is
+    /// rejected rather than silently overwriting the newer settings.
+    pub async fn get_versioned(&self) -> Result<(Settings, u64), CoreError> {
+        let version = self.version().await?;
+        Ok((self.get().await?, version))
+    }
+
+    /// Get current settings with secret references replaced by their values.
+    ///
+    /// For runtime use only — never hand the result back to a UI. A feature
+    /// whose credential cannot be resolved is switched off with a warning
+    /// rather than failing the whole read.
+    pub async fn get_resolved(&self) -> Result<Settings, CoreError> {
+        let mut settings = self.get().await?;
+        let Some(secrets) = &self.secrets else {
+            return Ok(settings);
+        };
+        if let Some(config) = &mut settings.web_search
+            && let Some(api_key) = config.provider.api_key_mut()
+        {
+            match secrets.resolve(api_key) {
+                Ok(value) => *api_key = value,
+                Err(e) => {
+                    tracing::warn!("web search disabled: {e}");
+                    settings.web_search = None;
+                }
+            }
+        }
+        if let Some(config) = &mut settings.backup {
+            match secrets.resolve(&config.secret_access_key) {
+                Ok(value) => config.secret_access_key = value,
+                Err(e) => {
+                    tracing::warn!("backup disabled: {e}");
+                    settings.backup = None;
+                }
+            }
+        }
+        Ok(settings)
+    }
+
     /// Update settings with partial changes.
+    ///
+    /// Credentials in the update are moved into the secret store and
+    /// persisted as references. If another writer saves in between, the
+    /// update is re-applied on top of its settings.
     pub async fn update(&self, update: SettingsUpdate) -> Result<Settings, CoreError> {
-        let mut current = self.repo.load().await.map_err(CoreError::from)?;
-        current.merge(&update);
-        validate_settings(&current)?;
-        self.repo.save(&current).await.map_err(CoreError::from)?;
-        Ok(current)
+        self.update_if_version(update, None)
+            .await
+            .map(|write| write.settings)
+    }
+
+    /// Update settings with partial changes, optionally only if they are
+    /// still at version `expected`.
+    ///
+    /// With `expected` set, a concurrent save makes this fail with
+    /// [`RepositoryError::Conflict`] so the caller can re-read and decide;
+    /// without it, the update retries like [`update`](Self::update).
+    pub async fn update_if_version(
+        &self,
+        update: SettingsUpdate,
+        expected: Option<u64>,
+    ) -> Result<SettingsWrite, CoreError> {
+        let mut attempt = 1;
+        loop {
+            let (before, version) = self.get_versioned().await?;
+            let mut current = before.clone();
+            current.merge(&update);
+            validate_settings(&current)?;
+            // Diffed before stashing: a new credential stored under the
+            // same reference still counts as a change.
+            let changed_keys = before.changed_keys(&current);
+            let stashed = self.stash_secrets(&mut current)?;
+
+            match self
+                .repo
+                .save_if_version(&current, expected.unwrap_or(version))
+                .await
+            {
+                Ok(version) => {
+                    self.drop_superseded_secrets(&before, &current);
+                    return Ok(SettingsWrite {
+                        settings: current,
+                        version,
+                        changed_keys,
+                    });
+                }
+                // The store is written before the row, so a rejected save
+                // must not leave the new credentials behind.
+                Err(e) => {
+                    self.discard_secrets(&stashed);
+                    match e {
+                        RepositoryError::Conflict(_)
+                            if expected.is_none() && attempt < UPDATE_ATTEMPTS =>
+                        {
+                            attempt += 1;
+                        }
+                        e => return Err(e.into()),
+                    }
+                }
+            }
+        }
     }
 
     /// Save complete settings (validates first).
     pub async fn save(&self, settings: &Settings) -> Result<(), CoreError> {
         validate_settings(settings)?;
-        self.repo.save(settings).await.map_err(CoreError::from)
+        let before = self.get().await?;
+        let mut settings = settings.clone();
+        let stashed = self.stash_secrets(&mut settings)?;
+        if let Err(e) = self.repo.save(&settings).await {
+            self.discard_secrets(&stashed);
+            return Err(e.into());
+        }
+        self.drop_superseded_secrets(&before, &settings);
+        Ok(())
+    }
+
+    /// Move plaintext credentials left in the settings row into the secret
+    /// store. Returns whether anything was migrated.
+    ///
+    /// Run once at startup; a no-op without a secret store.
+    pub async fn migrate_secrets(&self) -> Result<bool, CoreError> {
+        let current = self.get().await?;
+        let mut migrated = current.clone();
+        let stashed = self.stash_secrets(&mut migrated)?;
+        if migrated == current {
+            return Ok(false);
+        }
+        if let Err(e) = self.repo.save(&migrated).await {
+            self.discard_secrets(&stashed);
+            return Err(e.into());
+        }
+        tracing::info!("moved plaintext credentials from settings into the secret store");
+        Ok(true)
+    }
+
+    /// Move the credentials in `settings` into the secret store, returning
+    /// the keys written.
+    ///
+    /// Each credential goes under a fresh key, never over one a stored row
+    /// may reference, so saves racing each other cannot swap credentials
+    /// and a rejected save only has its own keys to remove. On failure the
+    /// keys already written are removed before returning.
+    fn stash_secrets(&self, settings: &mut Settings) -> Result<StashedSecrets, CoreError> {
+        let mut stashed = StashedSecrets::new();
+        let Some(secrets) = &self.secrets else {
+            return Ok(stashed);
+        };
+        if let Err(e) = stash_each(secrets.as_ref(), settings, &mut stashed) {
+            self.discard_secrets(&stashed);
+            return Err(CoreError::ExternalService(e.to_string()));
+        }
+        Ok(stashed)
+    }
+
+    /// Remove secrets a rejected save wrote. Best effort: a failure is
+    /// logged, since the caller is already reporting an error.
+    fn discard_secrets(&self, stashed: &[String]) {
+        let Some(secrets) = &self.secrets else {
+            return;
+        };
+        for key in stashed {
+            if let Err(e) = secrets.delete(key) {
+                tracing::warn!("could not remove secret {key} after a rejected save: {e}");
+            }
+        }
+    }
+
+    /// Remove secrets `before` referenced that the saved `after` no longer
+    /// does. Best effort: a leftover secret is unreachable, not wrong.
+    fn drop_superseded_secrets(&self, before: &Settings, after: &Settings) {
+        let Some(secrets) = &self.secrets else {
+            return;
+        };
+        let kept = secret_keys(after);
+        for key in secret_keys(before) {
+            if kept.contains(&key) {
+                continue;
+            }
+            if let Err(e) = secrets.delete(key) {
+                tracing::warn!("could not remove replaced secret {key}: {e}");
+            }
+        }
     }
 }
 
+/// Store each plaintext credential in `settings` under a fresh key and
+/// put a reference in its place, recording the keys in `stashed`.
+fn stash_each(
+    secrets: &dyn SecretStorePort,
+    settings: &mut Settings,
+    stashed: &mut StashedSecrets,
+) -> Result<(), SecretStoreError> {
+    let mut stash = |base: &str, value: &mut String| -> Result<(), SecretStoreError> {
+        if parse_secret_ref(value).is_some() {
+            return Ok(());
+        }
+        let key = format!("{base}.{}", Uuid::new_v4().simple());
+        // Recorded first: a write that fails halfway is removed too.
+        stashed.push(key.clone());
+        secrets.set(&key, value)?;
+        *value = secret_ref(&key);
+        Ok(())
+    };
+    if let Some(api_key) = settings
+        .web_search
+        .as_mut()
+        .and_then(|config| config.provider.api_key_mut())
+    {
+        stash(WEB_SEARCH_API_KEY_SECRET, api_key)?;
+    }
+    if let Some(config) = &mut settings.backup {
+        stash(
+            BACKUP_SECRET_ACCESS_KEY_SECRET,
+            &mut config.secret_access_key,
+        )?;
+    }
+    for peer in settings.sync_peers.iter_mut().flatten() {
+        stash(&peer_token_secret(&peer.name), &mut peer.token)?;
+    }
+    Ok(())
+}
+
+/// Secret store keys the credentials in `settings` reference.
+fn secret_keys(settings: &Settings) -> Vec<&str> {
+    let web_search = settings
+        .web_search
+        .as_ref()
+        .and_then(|config| config.provider.api_key());
+    let backup = settings
+        .backup
+        .as_ref()
+        .map(|config| config.secret_access_key.as_str());
+    let peers = settings
+        .sync_peers
+        .iter()
+        .flatten()
+        .map(|peer| peer.token.as_str());
+    web_search
+        .into_iter()
+        .chain(backup)
+        .chain(peers)
+        .filter_map(parse_secret_ref)
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::ports::RepositoryError;
     use async_trait::async_trait;
     use std::sync::Mutex;
+    use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 
     struct MockSettingsRepo {
         settings: Mutex<Settings>,
+        version: AtomicU64,
+        /// Simulate another writer saving just before the next conditional
+        /// save.
+        race_next_save: AtomicBool,
     }
 
     impl MockSettingsRepo {
         fn new() -> Self {
             Self {
                 settings: Mutex::new(Settings::with_defaults()),
+                version: AtomicU64::new(0),
+                race_next_save: AtomicBool::new(false),
             }
         }
     }
@@ -68,8 +354,29 @@ mod tests {
 
         async fn save(&self, settings: &Settings) -> Result<(), RepositoryError> {
             *self.settings.lock().unwrap() = settings.clone();
+            self.version.fetch_add(1, Ordering::SeqCst);
             Ok(())
         }
+
+        async fn version(&self) -> Result<u64, RepositoryError> {
+            Ok(self.version.load(Ordering::SeqCst))
+        }
+
+        async fn save_if_version(
+            &self,
+            settings: &Settings,
+            expected: u64,
+        ) -> Result<u64, RepositoryError> {
+            if self.race_next_save.swap(false, Ordering::SeqCst) {
+                self.settings.lock().unwrap().proxy_port = Some(9999);
+                self.version.fetch_add(1, Ordering::SeqCst);
+            }
+            if self.version.load(Ordering::SeqCst) != expected {
+                return Err(RepositoryError::Conflict("stale".to_string()));
+            }
+            self.save(settings).await?;
+            Ok(self.version.load(Ordering::SeqCst))
+        }
     }
 
     #[tokio::test]
@@ -98,4 +405,224 @@ mod tests {
         let fetched = service.get().await.unwrap();
         assert_eq!(fetched.default_context_size, Some(8192));
     }
+
+    #[tokio::test]
+    async fn update_reapplies_changes_after_a_concurrent_save() {
+        let repo = Arc::new(MockSettingsRepo::new());
+        let service = SettingsService::new(repo.clone());
+        repo.race_next_save.store(true, Ordering::SeqCst);
+
+        let update = SettingsUpdate {
+            default_context_size: Some(Some(8192)),
+            ..Default::default()
+        };
+        let updated = service.update(update).await.unwrap();
+
+        // Neither the racing writer's change nor ours is lost.
+        assert_eq!(updated.default_context_size, Some(8192));
+        assert_eq!(updated.proxy_port, Some(9999));
+        assert_eq!(service.version().await.unwrap(), 2);
+    }
+
+    #[tokio::test]
+    async fn conditional_update_rejects_a_stale_version() {
+        let repo = Arc::new(MockSettingsRepo::new());
+        let service = SettingsService::new(repo);
+
+        let update = || SettingsUpdate {
+            default_context_size: Some(Some(8192)),
+            ..Default::default()
+        };
+        let write = service.update_if_version(update(), Some(0)).await.unwrap();
+        assert_eq!(write.version, 1);
+        assert_eq!(write.changed_keys, vec!["default_context_size"]);
+
+        let err = service
+            .update_if_version(update(), Some(0))
+            .await
+            .unwrap_err();
+        assert!(
+            matches!(err, CoreError::Repository(RepositoryError::Conflict(_))),
+            "{err:?}"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_credentials_are_kept_in_the_secret_store() {
+        use crate::domain::{WebSearchConfig, WebSearchProvider};
+        use crate::ports::MemorySecretStore;
+
+        let brave = |api_key: &str| {
+            Some(WebSearchConfig::new(WebSearchProvider::Brave {
+                api_key: api_key.to_string(),
+            }))
+        };
+
+        // A key written before secrets moved out of the database.
+        let repo = Arc::new(MockSettingsRepo::new());
+        repo.settings.lock().unwrap().web_search = brave("legacy-key");
+        let secrets = Arc::new(MemorySecretStore::new());
+        let service = SettingsService::new(repo.clone()).with_secrets(secrets.clone());
+
+        assert!(service.migrate_secrets().await.unwrap());
+        assert!(!service.migrate_secrets().await.unwrap());
+        let migrated = repo.settings.lock().unwrap().web_search.clone().unwrap();
+        let migrated = migrated.provider.api_key().unwrap().to_string();
+        assert!(
+            migrated.starts_with("secret:web_search_api_key."),
+            "{migrated}"
+        );
+        assert_eq!(
+            service.get_resolved().await.unwrap().web_search,
+            brave("legacy-key")
+        );
+
+        // New keys never reach the repository in plaintext, and the key
+        // they replace leaves the store.
+        let update = SettingsUpdate {
+            web_search: Some(brave("new-key")),
+            ..Default::default()
+        };
+        let updated = service.update(update).await.unwrap();
+        let reference = updated
+            .web_search
+            .unwrap()
+            .provider
+            .api_key()
+            .unwrap()
+            .to_string();
+        assert_ne!(reference, migrated);
+        assert_eq!(secrets.resolve(&reference).unwrap(), "new-key");
+        assert_eq!(
+            secrets.keys(),
+            vec![parse_secret_ref(&reference).unwrap().to_string()]
+        );
+
+        // A dangling reference switches web search off instead of failing.
+        secrets
+            .delete(parse_secret_ref(&reference).unwrap())
+            .unwrap();
+        assert_eq!(service.get_resolved().await.unwrap().web_search, None);
+    }
+
+    #[tokio::test]
+    async fn rejected_update_leaves_the_secrets_unchanged() {
+        use crate::domain::{WebSearchConfig, WebSearchProvider};
+        use crate::ports::MemorySecretStore;
+
+        let repo = Arc::new(MockSettingsRepo::new());
+        let secrets = Arc::new(MemorySecretStore::new());
+        let service = SettingsService::new(repo).with_secrets(secrets.clone());
+        let set_key = |api_key: &str| SettingsUpdate {
+            web_search: Some(Some(WebSearchConfig::new(WebSearchProvider::Brave {
+                api_key: api_key.to_string(),
+            }))),
+            ..Default::default()
+        };
+
+        // Rejected before any key exists: none is left behind.
+        let err = service
+            .update_if_version(set_key("stray-key"), Some(7))
+            .await
+            .unwrap_err();
+        assert!(
+            matches!(err, CoreError::Repository(RepositoryError::Conflict(_))),
+            "{err:?}"
+        );
+        assert!(secrets.keys().is_empty());
+
+        // Losing to another writer: the winner's key is untouched, even
+        // though both saved a key for the same field.
+        let winner = service
+            .update_if_version(set_key("old-key"), Some(0))
+            .await
+            .unwrap();
+        let err = service
+            .update_if_version(set_key("new-key"), Some(0))
+            .await
+            .unwrap_err();
+        assert!(
+            matches!(err, CoreError::Repository(RepositoryError::Conflict(_))),
+            "{err:?}"
+        );
+        let reference = winner
+            .settings
+            .web_search
+            .unwrap()
+            .provider
+            .api_key()
+            .unwrap()
+            .to_string();
+        assert_eq!(secrets.resolve(&reference).unwrap(), "old-key");
+        assert_eq!(secrets.keys().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn failed_stash_removes_the_secrets_it_wrote() {
+        use crate::domain::SyncPeer;
+        use crate::ports::{MemorySecretStore, SecretStoreResult};
+
+        /// Refuses to store the value `fail`.
+        struct FlakyStore(MemorySecretStore);
+
+        impl SecretStorePort for FlakyStore {
+            fn backend(&self) -> &'static str {
+                "flaky"
+            }
+            fn get(&self, key: &str) -> SecretStoreResult<Option<String>> {
+                self.0.get(key)
+            }
+            fn set(&self, key: &str, value: &str) -> SecretStoreResult<()> {
+                if value == "fail" {
+                    return Err(SecretStoreError::Backend("disk full".to_string()));
+                }
+                self.0.set(key, value)
+            }
+            fn delete(&self, key: &str) -> SecretStoreResult<bool> {
+                self.0.delete(key)
+            }
+        }
+
+        let repo = Arc::new(MockSettingsRepo::new());
+        let secrets = Arc::new(FlakyStore(MemorySecretStore::new()));
+        let service = SettingsService::new(repo.clone()).with_secrets(secrets.clone());
+        let peer = |name: &str, token: &str| SyncPeer {
+            name: name.to_string(),
+            url: format!("http://{name}.local:9887"),
+            token: token.to_string(),
+        };
+
+        let mut settings = service.get().await.unwrap();
+        settings.sync_peers = Some(vec![peer("studio", "t0ken"), peer("laptop", "fail")]);
+        assert!(service.save(&settings).await.is_err());
+        assert!(secrets.0.keys().is_empty());
+        assert_eq!(repo.settings.lock().unwrap().sync_peers, None);
+    }
+
+    #[tokio::test]
+    async fn sync_peer_tokens_are_stashed() {
+        use crate::domain::SyncPeer;
+        use crate::ports::MemorySecretStore;
+
+        let repo = Arc::new(MockSettingsRepo::new());
+        let secrets = Arc::new(MemorySecretStore::new());
+        let service = SettingsService::new(repo.clone()).with_secrets(secrets.clone());
+
+        let mut settings = service.get().await.unwrap();
+        settings.sync_peers = Some(vec![SyncPeer {
+            name: "studio".to_string(),
+            url: "http://studio.local:9887".to_string(),
+            token: "t0ken".to_string(),
+        }]);
+        service.save(&settings).await.unwrap();
+
+        let stored = repo.settings.lock().unwrap().sync_peers.clone().unwrap();
+        assert!(stored[0].token.starts_with("secret:sync_peer_studio."));
+        assert_eq!(secrets.resolve(&stored[0].token).unwrap(), "t0ken");
+
+        // Unpairing drops the token along with the peer.
+        settings.sync_peers = None;
+        service.save(&settings).await.unwrap();
+        assert!(secrets.keys().is_empty());
+    }
 }
@@ -5,17 +5,37 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::domain::{InferenceConfig, InferenceProfile};
+use crate::domain::attachment::{
+    DEFAULT_ALLOWED_ATTACHMENT_TYPES, DEFAULT_MAX_ATTACHMENT_SIZE_MB, MAX_ATTACHMENT_SIZE_MB,
+};
+use crate::domain::{
+    BackupConfig, CachedPrompt, EnergyConfig, InferenceConfig, InferenceProfile, ModerationConfig,
+    OnboardingProgress, ProxyLimits, StartupModel, SyncPeer, UrlFetchConfig, WebSearchConfig,
+    validate_cached_prompts, validate_startup_models,
+};
+use crate::telemetry::OtlpConfig;
 
 /// Default port for the OpenAI-compatible proxy server.
 pub const DEFAULT_PROXY_PORT: u16 = 8080;
 
+/// Default address the OpenAI-compatible proxy binds to.
+pub const DEFAULT_PROXY_HOST: &str = "127.0.0.1";
+
 /// Default base port for llama-server instance allocation.
 pub const DEFAULT_LLAMA_BASE_PORT: u16 = 9000;
 
 /// Default context size for models when not specified by the user.
 pub const DEFAULT_CONTEXT_SIZE: u64 = 4096;
 
+/// Upper bound for idle auto-shutdown, in minutes (one week).
+pub const MAX_IDLE_SHUTDOWN_MINUTES: u32 = 10_080;
+
+/// Hours an orphaned partial download is kept before the janitor deletes it.
+pub const DEFAULT_PARTIAL_DOWNLOAD_GRACE_HOURS: u32 = 24;
+
+/// Days a removed model stays in the trash before it is purged.
+pub const DEFAULT_TRASH_RETENTION_DAYS: u32 = 30;
+
 /// Application settings structure.
 ///
 /// All fields are optional to support partial updates and graceful defaults.
@@ -31,6 +51,33 @@ pub struct Settings {
     /// Port for the OpenAI-compatible proxy server.
     pub proxy_port: Option<u16>,
 
+    /// Address the proxy binds to. `None` means [`DEFAULT_PROXY_HOST`].
+    /// Anything other than a loopback address also needs
+    /// `proxy_allow_public`.
+    #[serde(default)]
+    pub proxy_host: Option<String>,
+
+    /// Opt-in for binding the proxy to a non-loopback address such as
+    /// `0.0.0.0`, e.g. so containers can reach it. `None` means disabled.
+    #[serde(default)]
+    pub proxy_allow_public: Option<bool>,
+
+    /// Origins browsers may call the proxy from (`http://localhost:3000`).
+    /// `None` allows any origin.
+    #[serde(default)]
+    pub proxy_allowed_origins: Option<Vec<String>>,
+
+    /// Cut background-priority streams short when interactive requests are
+    /// waiting for a slot. `None` means disabled.
+    #[serde(default)]
+    pub proxy_preempt_background: Option<bool>,
+
+    /// Drop reasoning models' chain of thought from streamed responses
+    /// instead of passing it through as `reasoning_content`. `None` means
+    /// disabled.
+    #[serde(default)]
+    pub proxy_strip_reasoning: Option<bool>,
+
     /// Base port for llama-server instance allocation (first port in range).
     /// Note: The OpenAI-compatible proxy listens on `proxy_port`.
     pub llama_base_port: Option<u16>,
@@ -38,9 +85,32 @@ pub struct Settings {
     /// Maximum number of downloads that can be queued (1-50).
     pub max_download_queue_size: Option<u32>,
 
+    /// Hours an orphaned partial download (one no queued job refers to) is
+    /// kept before it is deleted. `None` means
+    /// [`DEFAULT_PARTIAL_DOWNLOAD_GRACE_HOURS`]. Read at startup.
+    pub partial_download_grace_hours: Option<u32>,
+
+    /// Days a removed model is kept in the trash. `None` means
+    /// [`DEFAULT_TRASH_RETENTION_DAYS`].
+    pub trash_retention_days: Option<u32>,
+
+    /// Size cap for the trash in GB; the oldest entries are purged first
+    /// when it is exceeded. `None` means no cap.
+    pub trash_max_size_gb: Option<u32>,
+
+    /// Whether `gglib web` watches the models directory and adds the `.gguf`
+    /// files copied into it, and trashes the models whose file is deleted,
+    /// without a rescan. `None` means off. Read at startup.
+    pub watch_models_dir: Option<bool>,
+
     /// Whether to show memory fit indicators in `HuggingFace` browser.
     pub show_memory_fit_indicators: Option<bool>,
 
+    /// Whether to warn before downloading a model whose license forbids
+    /// commercial use or limits it to research (see
+    /// [`crate::domain::license::is_restrictive_license`]). `None` means off.
+    pub warn_restrictive_licenses: Option<bool>,
+
     /// Maximum iterations for tool calling agentic loop.
     pub max_tool_iterations: Option<u32>,
 
@@ -50,6 +120,24 @@ pub struct Settings {
     /// Default model ID for commands that support a default model.
     pub default_model_id: Option<i64>,
 
+    /// Minutes without a request after which a running server is stopped.
+    ///
+    /// `None` or `0` keeps servers running until stopped by hand. A model's
+    /// `server_defaults.idle_shutdown_minutes` overrides this.
+    pub idle_shutdown_minutes: Option<u32>,
+
+    /// Models launched automatically when the app starts, in launch order.
+    ///
+    /// Relaunched after a llama.cpp upgrade so they run the new binary. See
+    /// [`crate::domain::startup`].
+    #[serde(default)]
+    pub startup_models: Option<Vec<StartupModel>>,
+
+    /// System prompts whose KV state is kept warm per model and restored
+    /// when the model starts. See [`crate::domain::prompt_cache`].
+    #[serde(default)]
+    pub cached_prompts: Option<Vec<CachedPrompt>>,
+
     /// Global inference parameter defaults.
     ///
     /// Applied when neither request nor per-model defaults are specified.
@@ -66,12 +154,93 @@ pub struct Settings {
     #[serde(default)]
     pub inference_profiles: Option<Vec<InferenceProfile>>,
 
+    /// OTLP trace export. `None` keeps spans local (console and log file).
+    #[serde(default)]
+    pub otlp: Option<OtlpConfig>,
+
     // ── Setup wizard ────────────────────────────────────────────────
     /// Whether the first-run setup wizard has been completed.
     pub setup_completed: Option<bool>,
 
+    /// Onboarding choices to resume on the next launch.
+    #[serde(default)]
+    pub onboarding: Option<OnboardingProgress>,
+
     /// Custom prompt template for generating chat titles.
     pub title_generation_prompt: Option<String>,
+
+    /// Whether new conversations are titled by the model after the first
+    /// exchange. `None` means enabled.
+    #[serde(default)]
+    pub auto_title_conversations: Option<bool>,
+
+    /// Whether message content is encrypted at rest, keyed from the secret
+    /// store. `None` means disabled. Titles and system prompts stay
+    /// readable so the conversation list works without the key.
+    #[serde(default)]
+    pub encrypt_chat_history: Option<bool>,
+
+    // ── Attachments ─────────────────────────────────────────────────
+    /// Largest chat attachment accepted, in MiB. `None` means
+    /// [`DEFAULT_MAX_ATTACHMENT_SIZE_MB`].
+    #[serde(default)]
+    pub max_attachment_size_mb: Option<u32>,
+
+    /// MIME types accepted for chat attachments (`type/*` wildcards
+    /// allowed). `None` means [`DEFAULT_ALLOWED_ATTACHMENT_TYPES`].
+    #[serde(default)]
+    pub allowed_attachment_types: Option<Vec<String>>,
+
+    // ── Display ─────────────────────────────────────────────────────
+    /// BCP 47 language tag for user-facing text and number formatting
+    /// (e.g. `de-DE`). `None` follows the `GGLIB_LOCALE`, `LC_ALL`,
+    /// `LC_MESSAGES` and `LANG` environment variables, then `en-US`.
+    #[serde(default)]
+    pub locale: Option<String>,
+
+    // ── Web access ──────────────────────────────────────────────────
+    /// Provider and filters for the built-in `web_search` tool. `None`
+    /// leaves the tool disabled.
+    #[serde(default)]
+    pub web_search: Option<WebSearchConfig>,
+
+    /// Limits for the built-in `fetch_url` tool. `None` leaves the tool
+    /// disabled.
+    #[serde(default)]
+    pub url_fetch: Option<UrlFetchConfig>,
+
+    // ── Moderation ──────────────────────────────────────────────────
+    /// Keyword, pattern and classifier checks the proxy applies to chat
+    /// completions. `None` disables moderation.
+    #[serde(default)]
+    pub moderation: Option<ModerationConfig>,
+
+    // ── Proxy limits ────────────────────────────────────────────────
+    /// Caps on the tokens, run time and open streams of proxied chat
+    /// completions. `None` leaves requests unbounded.
+    #[serde(default)]
+    pub proxy_limits: Option<ProxyLimits>,
+
+    // ── Energy ──────────────────────────────────────────────────────
+    /// Power draw and electricity price used to estimate the energy and cost
+    /// of inference. `None` estimates energy from the detected hardware and
+    /// leaves cost out.
+    #[serde(default)]
+    pub energy: Option<EnergyConfig>,
+
+    // ── Library sync ────────────────────────────────────────────────
+    /// Paired gglib instances models can be pulled from. Managed through
+    /// the sync operations rather than settings updates; tokens are kept in
+    /// the secret store when one is available.
+    #[serde(default)]
+    pub sync_peers: Option<Vec<SyncPeer>>,
+
+    // ── Backup ──────────────────────────────────────────────────────
+    /// S3-compatible bucket the database and model files are backed up to.
+    /// `None` disables backups. The secret access key is kept in the secret
+    /// store when one is available.
+    #[serde(default)]
+    pub backup: Option<BackupConfig>,
 }
 
 impl Settings {
@@ -82,18 +251,45 @@ impl Settings {
             default_download_path: None,
             default_context_size: Some(DEFAULT_CONTEXT_SIZE),
             proxy_port: Some(DEFAULT_PROXY_PORT),
+            proxy_host: None,
+            proxy_allow_public: None,
+            proxy_allowed_origins: None,
+            proxy_preempt_background: None,
+            proxy_strip_reasoning: None,
             llama_base_port: Some(DEFAULT_LLAMA_BASE_PORT),
             max_download_queue_size: Some(10),
+            partial_download_grace_hours: None,
+            trash_retention_days: None,
+            trash_max_size_gb: None,
+            watch_models_dir: None,
             show_memory_fit_indicators: Some(true),
+            warn_restrictive_licenses: None,
             #[allow(clippy::cast_possible_truncation)] // compile-time constants, always < u32::MAX
             max_tool_iterations: Some(crate::domain::agent::DEFAULT_MAX_ITERATIONS as u32),
             #[allow(clippy::cast_possible_truncation)]
             max_stagnation_steps: Some(crate::domain::agent::DEFAULT_MAX_STAGNATION_STEPS as u32),
             default_model_id: None,
+            idle_shutdown_minutes: None,
+            startup_models: None,
+            cached_prompts: None,
             inference_defaults: None,
             inference_profiles: None,
+            otlp: None,
             setup_completed: None,
+            onboarding: None,
             title_generation_prompt: None,
+            auto_title_conversations: Some(true),
+            encrypt_chat_history: None,
+            max_attachment_size_mb: None,
+            allowed_attachment_types: None,
+            locale: None,
+            web_search: None,
+            url_fetch: None,
+            moderation: None,
+            proxy_limits: None,
+            energy: None,
+            sync_peers: None,
+            backup: None,
         }
     }
 
@@ -106,6 +302,37 @@ impl Settings {
         }
     }
 
+    /// Get the effective proxy bind address (with default fallback).
+    #[must_use]
+    pub fn effective_proxy_host(&self) -> &str {
+        self.proxy_host.as_deref().unwrap_or(DEFAULT_PROXY_HOST)
+    }
+
+    /// Get the effective partial-download grace period in hours.
+    #[must_use]
+    pub const fn effective_partial_download_grace_hours(&self) -> u32 {
+        match self.partial_download_grace_hours {
+            Some(hours) => hours,
+            None => DEFAULT_PARTIAL_DOWNLOAD_GRACE_HOURS,
+        }
+    }
+
+    /// Get the effective trash retention period in days.
+    #[must_use]
+    pub const fn effective_trash_retention_days(&self) -> u32 {
+        match self.trash_retention_days {
+            Some(days) => days,
+            None => DEFAULT_TRASH_RETENTION_DAYS,
+        }
+    }
+
+    /// Get the trash size cap in bytes, if one is set.
+    #[must_use]
+    pub fn trash_max_size_bytes(&self) -> Option<u64> {
+        self.trash_max_size_gb
+            .map(|gb| u64::from(gb) * 1024 * 1024 * 1024)
+    }
+
     /// Get the effective llama-server base port (with default fallback).
     #[must_use]
     pub const fn effective_llama_base_port(&self) -> u16 {
@@ -115,7 +342,29 @@ impl Settings {
         }
     }
 
+    /// Get the effective attachment size limit in bytes (with default fallback).
+    #[must_use]
+    pub fn effective_max_attachment_bytes(&self) -> u64 {
+        u64::from(
+            self.max_attachment_size_mb
+                .unwrap_or(DEFAULT_MAX_ATTACHMENT_SIZE_MB),
+        ) * 1024
+            * 1024
+    }
+
+    /// Get the effective list of allowed attachment MIME types.
+    #[must_use]
+    pub fn effective_attachment_types(&self) -> Vec<String> {
+        self.allowed_attachment_types.clone().unwrap_or_else(|| {
+            DEFAULT_ALLOWED_ATTACHMENT_TYPES
+                .iter()
+                .map(|t| (*t).to_string())
+                .collect()
+        })
+    }
+
     /// Merge another settings into this one, only updating fields that are Some.
+    #[allow(clippy::too_many_lines)]
     pub fn merge(&mut self, other: &SettingsUpdate) {
         if let Some(ref path) = other.default_download_path {
             self.default_download_path.clone_from(path);
@@ -126,15 +375,45 @@ impl Settings {
         if let Some(ref port) = other.proxy_port {
             self.proxy_port = *port;
         }
+        if let Some(ref host) = other.proxy_host {
+            self.proxy_host.clone_from(host);
+        }
+        if let Some(ref v) = other.proxy_allow_public {
+            self.proxy_allow_public = *v;
+        }
+        if let Some(ref origins) = other.proxy_allowed_origins {
+            self.proxy_allowed_origins.clone_from(origins);
+        }
+        if let Some(ref v) = other.proxy_preempt_background {
+            self.proxy_preempt_background = *v;
+        }
+        if let Some(ref v) = other.proxy_strip_reasoning {
+            self.proxy_strip_reasoning = *v;
+        }
         if let Some(ref port) = other.llama_base_port {
             self.llama_base_port = *port;
         }
         if let Some(ref queue_size) = other.max_download_queue_size {
             self.max_download_queue_size = *queue_size;
         }
+        if let Some(ref hours) = other.partial_download_grace_hours {
+            self.partial_download_grace_hours = *hours;
+        }
+        if let Some(ref days) = other.trash_retention_days {
+            self.trash_retention_days = *days;
+        }
+        if let Some(ref gb) = other.trash_max_size_gb {
+            self.trash_max_size_gb = *gb;
+        }
+        if let Some(ref watch) = other.watch_models_dir {
+            self.watch_models_dir = *watch;
+        }
         if let Some(ref show_fit) = other.show_memory_fit_indicators {
             self.show_memory_fit_indicators = *show_fit;
         }
+        if let Some(ref warn) = other.warn_restrictive_licenses {
+            self.warn_restrictive_licenses = *warn;
+        }
         if let Some(ref iters) = other.max_tool_iterations {
             self.max_tool_iterations = *iters;
         }
@@ -144,18 +423,86 @@ impl Settings {
         if let Some(ref model_id) = other.default_model_id {
             self.default_model_id = *model_id;
         }
+        if let Some(ref minutes) = other.idle_shutdown_minutes {
+            self.idle_shutdown_minutes = *minutes;
+        }
+        if let Some(ref startup_models) = other.startup_models {
+            self.startup_models.clone_from(startup_models);
+        }
+        if let Some(ref cached_prompts) = other.cached_prompts {
+            self.cached_prompts.clone_from(cached_prompts);
+        }
         if let Some(ref inference_defaults) = other.inference_defaults {
             self.inference_defaults.clone_from(inference_defaults);
         }
         if let Some(ref inference_profiles) = other.inference_profiles {
             self.inference_profiles.clone_from(inference_profiles);
         }
+        if let Some(ref otlp) = other.otlp {
+            self.otlp.clone_from(otlp);
+        }
         if let Some(ref v) = other.setup_completed {
             self.setup_completed = *v;
         }
+        if let Some(ref v) = other.onboarding {
+            self.onboarding.clone_from(v);
+        }
         if let Some(ref v) = other.title_generation_prompt {
             self.title_generation_prompt.clone_from(v);
         }
+        if let Some(ref v) = other.auto_title_conversations {
+            self.auto_title_conversations = *v;
+        }
+        if let Some(ref v) = other.encrypt_chat_history {
+            self.encrypt_chat_history = *v;
+        }
+        if let Some(ref v) = other.max_attachment_size_mb {
+            self.max_attachment_size_mb = *v;
+        }
+        if let Some(ref v) = other.allowed_attachment_types {
+            self.allowed_attachment_types.clone_from(v);
+        }
+        if let Some(ref v) = other.locale {
+            self.locale.clone_from(v);
+        }
+        if let Some(ref v) = other.web_search {
+            self.web_search.clone_from(v);
+        }
+        if let Some(ref v) = other.url_fetch {
+            self.url_fetch = *v;
+        }
+        if let Some(ref v) = other.moderation {
+            self.moderation.clone_from(v);
+        }
+        if let Some(ref v) = other.proxy_limits {
+            self.proxy_limits.clone_from(v);
+        }
+        if let Some(ref v) = other.energy {
+            self.energy.clone_from(v);
+        }
+        if let Some(ref v) = other.backup {
+            self.backup.clone_from(v);
+        }
+    }
+
+    /// Names of the fields that differ between `self` and `other`, as
+    /// stored (the `serde` field names), in sorted order.
+    #[must_use]
+    pub fn changed_keys(&self, other: &Self) -> Vec<String> {
+        let as_map = |settings: &Self| match serde_json::to_value(settings) {
+            Ok(serde_json::Value::Object(map)) => map,
+            _ => serde_json::Map::new(),
+        };
+        let (before, after) = (as_map(self), as_map(other));
+        let mut keys: Vec<String> = before
+            .keys()
+            .chain(after.keys())
+            .filter(|key| before.get(*key) != after.get(*key))
+            .cloned()
+            .collect();
+        keys.sort();
+        keys.dedup();
+        keys
     }
 }
 
@@ -170,16 +517,42 @@ pub struct SettingsUpdate {
     pub default_download_path: Option<Option<String>>,
     pub default_context_size: Option<Option<u64>>,
     pub proxy_port: Option<Option<u16>>,
+    pub proxy_host: Option<Option<String>>,
+    pub proxy_allow_public: Option<Option<bool>>,
+    pub proxy_allowed_origins: Option<Option<Vec<String>>>,
+    pub proxy_preempt_background: Option<Option<bool>>,
+    pub proxy_strip_reasoning: Option<Option<bool>>,
     pub llama_base_port: Option<Option<u16>>,
     pub max_download_queue_size: Option<Option<u32>>,
+    pub partial_download_grace_hours: Option<Option<u32>>,
+    pub trash_retention_days: Option<Option<u32>>,
+    pub trash_max_size_gb: Option<Option<u32>>,
+    pub watch_models_dir: Option<Option<bool>>,
     pub show_memory_fit_indicators: Option<Option<bool>>,
+    pub warn_restrictive_licenses: Option<Option<bool>>,
     pub max_tool_iterations: Option<Option<u32>>,
     pub max_stagnation_steps: Option<Option<u32>>,
     pub default_model_id: Option<Option<i64>>,
+    pub idle_shutdown_minutes: Option<Option<u32>>,
+    pub startup_models: Option<Option<Vec<StartupModel>>>,
+    pub cached_prompts: Option<Option<Vec<CachedPrompt>>>,
     pub inference_defaults: Option<Option<InferenceConfig>>,
     pub inference_profiles: Option<Option<Vec<InferenceProfile>>>,
+    pub otlp: Option<Option<OtlpConfig>>,
     pub setup_completed: Option<Option<bool>>,
+    pub onboarding: Option<Option<OnboardingProgress>>,
     pub title_generation_prompt: Option<Option<String>>,
+    pub auto_title_conversations: Option<Option<bool>>,
+    pub encrypt_chat_history: Option<Option<bool>>,
+    pub max_attachment_size_mb: Option<Option<u32>>,
+    pub allowed_attachment_types: Option<Option<Vec<String>>>,
+    pub locale: Option<Option<String>>,
+    pub web_search: Option<Option<WebSearchConfig>>,
+    pub url_fetch: Option<Option<UrlFetchConfig>>,
+    pub moderation: Option<Option<ModerationConfig>>,
+    pub proxy_limits: Option<Option<ProxyLimits>>,
+    pub energy: Option<Option<EnergyConfig>>,
+    pub backup: Option<Option<BackupConfig>>,
 }
 
 /// Settings validation error.
@@ -191,9 +564,31 @@ pub enum SettingsError {
     #[error("Port should be >= 1024 (privileged ports require root), got {0}")]
     InvalidPort(u16),
 
+    #[error("Invalid proxy host {0:?}: expected an IP address or localhost")]
+    InvalidProxyHost(String),
+
+    #[error(
+        "Proxy host {0} is reachable from other machines; enable proxy_allow_public to bind it"
+    )]
+    PublicProxyHost(String),
+
+    #[error(
+        "Invalid proxy origin {0:?}: expected scheme://host[:port], such as http://localhost:3000"
+    )]
+    InvalidProxyOrigin(String),
+
     #[error("Max download queue size must be between 1 and 50, got {0}")]
     InvalidQueueSize(u32),
 
+    #[error("Idle shutdown must be at most {MAX_IDLE_SHUTDOWN_MINUTES} minutes, got {0}")]
+    InvalidIdleShutdown(u32),
+
+    #[error("Invalid startup models: {0}")]
+    InvalidStartupModels(String),
+
+    #[error("Invalid cached prompts: {0}")]
+    InvalidCachedPrompts(String),
+
     #[error("Download path cannot be empty")]
     EmptyDownloadPath,
 
@@ -202,6 +597,55 @@ pub enum SettingsError {
 
     #[error("Invalid inference profile: {0}")]
     InvalidInferenceProfile(String),
+
+    #[error("Invalid OTLP export settings: {0}")]
+    InvalidOtlp(String),
+
+    #[error("Max attachment size must be between 1 and {MAX_ATTACHMENT_SIZE_MB} MiB, got {0}")]
+    InvalidAttachmentSize(u32),
+
+    #[error("Invalid attachment type {0:?}: expected a MIME type such as image/png or image/*")]
+    InvalidAttachmentType(String),
+
+    #[error("Invalid locale {0:?}: expected a language tag such as en-US or de")]
+    InvalidLocale(String),
+
+    #[error("Invalid web search settings: {0}")]
+    InvalidWebSearch(String),
+
+    #[error("Invalid URL fetch settings: {0}")]
+    InvalidUrlFetch(String),
+
+    #[error("Invalid moderation settings: {0}")]
+    InvalidModeration(String),
+
+    #[error("Invalid proxy limits: {0}")]
+    InvalidProxyLimits(String),
+
+    #[error("Invalid energy settings: {0}")]
+    InvalidEnergy(String),
+
+    #[error("Invalid backup settings: {0}")]
+    InvalidBackup(String),
+}
+
+/// Validate the proxy bind address and CORS origins; public addresses must
+/// be opted into.
+fn validate_proxy_access(settings: &Settings) -> Result<(), SettingsError> {
+    if let Some(ref host) = settings.proxy_host {
+        if !is_bind_host(host) {
+            return Err(SettingsError::InvalidProxyHost(host.clone()));
+        }
+        if !is_loopback_host(host) && settings.proxy_allow_public != Some(true) {
+            return Err(SettingsError::PublicProxyHost(host.clone()));
+        }
+    }
+    if let Some(ref origins) = settings.proxy_allowed_origins
+        && let Some(bad) = origins.iter().find(|o| !is_origin(o))
+    {
+        return Err(SettingsError::InvalidProxyOrigin(bad.clone()));
+    }
+    Ok(())
 }
 
 /// Validate settings values.
@@ -220,6 +664,8 @@ pub fn validate_settings(settings: &Settings) -> Result<(), SettingsError> {
         return Err(SettingsError::InvalidPort(port));
     }
 
+    validate_proxy_access(settings)?;
+
     // Validate llama-server base port
     if let Some(port) = settings.llama_base_port
         && port < 1024
@@ -234,6 +680,23 @@ pub fn validate_settings(settings: &Settings) -> Result<(), SettingsError> {
         return Err(SettingsError::InvalidQueueSize(queue_size));
     }
 
+    // Validate idle shutdown window
+    if let Some(minutes) = settings.idle_shutdown_minutes
+        && minutes > MAX_IDLE_SHUTDOWN_MINUTES
+    {
+        return Err(SettingsError::InvalidIdleShutdown(minutes));
+    }
+
+    // Validate startup models if specified
+    if let Some(ref startup_models) = settings.startup_models {
+        validate_startup_models(startup_models).map_err(SettingsError::InvalidStartupModels)?;
+    }
+
+    // Validate cached prompts if specified
+    if let Some(ref cached_prompts) = settings.cached_prompts {
+        validate_cached_prompts(cached_prompts).map_err(SettingsError::InvalidCachedPrompts)?;
+    }
+
     // Validate download path if specified
     if settings
         .default_download_path
@@ -254,9 +717,119 @@ pub fn validate_settings(settings: &Settings) -> Result<(), SettingsError> {
         validate_inference_profiles(profiles).map_err(SettingsError::InvalidInferenceProfile)?;
     }
 
+    // Validate OTLP export settings if specified
+    if let Some(ref otlp) = settings.otlp {
+        otlp.validate().map_err(SettingsError::InvalidOtlp)?;
+    }
+
+    // Validate attachment limits if specified
+    if let Some(mb) = settings.max_attachment_size_mb
+        && !(1..=MAX_ATTACHMENT_SIZE_MB).contains(&mb)
+    {
+        return Err(SettingsError::InvalidAttachmentSize(mb));
+    }
+    if let Some(ref types) = settings.allowed_attachment_types
+        && let Some(bad) = types.iter().find(|t| !is_mime_pattern(t))
+    {
+        return Err(SettingsError::InvalidAttachmentType(bad.clone()));
+    }
+
+    // Validate the locale tag if specified
+    if let Some(ref locale) = settings.locale
+        && !is_language_tag(locale)
+    {
+        return Err(SettingsError::InvalidLocale(locale.clone()));
+    }
+
+    // Validate web search settings if specified
+    if let Some(ref web_search) = settings.web_search {
+        web_search
+            .validate()
+            .map_err(SettingsError::InvalidWebSearch)?;
+    }
+    if let Some(ref url_fetch) = settings.url_fetch {
+        url_fetch
+            .validate()
+            .map_err(SettingsError::InvalidUrlFetch)?;
+    }
+    if let Some(ref moderation) = settings.moderation {
+        moderation
+            .validate()
+            .map_err(SettingsError::InvalidModeration)?;
+    }
+    if let Some(ref limits) = settings.proxy_limits {
+        limits
+            .validate()
+            .map_err(SettingsError::InvalidProxyLimits)?;
+    }
+    if let Some(ref energy) = settings.energy {
+        energy.validate().map_err(SettingsError::InvalidEnergy)?;
+    }
+    if let Some(ref backup) = settings.backup {
+        backup.validate().map_err(SettingsError::InvalidBackup)?;
+    }
+
     Ok(())
 }
 
+/// Whether `host` is an address the proxy can bind: an IP address or
+/// `localhost`.
+fn is_bind_host(host: &str) -> bool {
+    host.eq_ignore_ascii_case("localhost") || host.parse::<std::net::IpAddr>().is_ok()
+}
+
+/// Whether binding `host` keeps a listener private to this machine.
+///
+/// True for `localhost` and loopback addresses (`127.0.0.0/8`, `::1`);
+/// unspecified (`0.0.0.0`) and interface addresses are public.
+#[must_use]
+pub fn is_loopback_host(host: &str) -> bool {
+    host.eq_ignore_ascii_case("localhost")
+        || host
+            .parse::<std::net::IpAddr>()
+            .is_ok_and(|ip| ip.is_loopback())
+}
+
+/// Whether `origin` is a browser origin: `scheme://host[:port]` with no
+/// path, as sent in the `Origin` header.
+fn is_origin(origin: &str) -> bool {
+    origin.split_once("://").is_some_and(|(scheme, rest)| {
+        !scheme.is_empty()
+            && scheme
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || "+-.".contains(c))
+            && !rest.is_empty()
+            && !rest.contains(['/', '?', '#'])
+            && !rest.contains(char::is_whitespace)
+    })
+}
+
+/// Whether `pattern` looks like `type/subtype` or `type/*`.
+fn is_mime_pattern(pattern: &str) -> bool {
+    pattern.split_once('/').is_some_and(|(top, sub)| {
+        !top.is_empty()
+            && !sub.is_empty()
+            && !top.contains('*')
+            && (sub == "*" || !sub.contains('*'))
+            && !pattern.contains(char::is_whitespace)
+    })
+}
+
+/// Whether `tag` is shaped like a BCP 47 language tag (`en`, `pt-BR`,
+/// `zh-Hant-TW`): a 2-3 letter language followed by alphanumeric subtags.
+///
+/// POSIX-style `de_DE` is accepted too, since that is what `LANG` holds.
+#[must_use]
+pub fn is_language_tag(tag: &str) -> bool {
+    let mut subtags = tag.split(['-', '_']);
+    let language_ok = subtags
+        .next()
+        .is_some_and(|l| (2..=3).contains(&l.len()) && l.chars().all(|c| c.is_ascii_alphabetic()));
+    language_ok
+        && subtags
+            .all(|s| (1..=8).contains(&s.len()) && s.chars().all(|c| c.is_ascii_alphanumeric()))
+}
+
 /// Validate a set of inference profiles.
 ///
 /// Checks each profile's name against [`crate::domain::validate_name`], rejects
@@ -524,6 +1097,186 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_validate_attachment_limits() {
+        let settings = Settings {
+            max_attachment_size_mb: Some(0),
+            ..Default::default()
+        };
+        assert!(matches!(
+            validate_settings(&settings),
+            Err(SettingsError::InvalidAttachmentSize(0))
+        ));
+
+        let settings = Settings {
+            allowed_attachment_types: Some(vec!["image/*".into(), "pdf".into()]),
+            ..Default::default()
+        };
+        assert!(matches!(
+            validate_settings(&settings),
+            Err(SettingsError::InvalidAttachmentType(t)) if t == "pdf"
+        ));
+
+        assert_eq!(
+            Settings::default().effective_max_attachment_bytes(),
+            20 * 1024 * 1024
+        );
+    }
+
+    #[test]
+    fn test_validate_proxy_access() {
+        let proxy = |host: &str, public: Option<bool>| Settings {
+            proxy_host: Some(host.into()),
+            proxy_allow_public: public,
+            ..Default::default()
+        };
+        assert!(validate_settings(&proxy("127.0.0.1", None)).is_ok());
+        assert!(validate_settings(&proxy("::1", None)).is_ok());
+        assert!(validate_settings(&proxy("localhost", None)).is_ok());
+        assert!(validate_settings(&proxy("0.0.0.0", Some(true))).is_ok());
+        assert!(matches!(
+            validate_settings(&proxy("0.0.0.0", None)),
+            Err(SettingsError::PublicProxyHost(_))
+        ));
+        assert!(matches!(
+            validate_settings(&proxy("my host", Some(true))),
+            Err(SettingsError::InvalidProxyHost(_))
+        ));
+        assert_eq!(
+            Settings::default().effective_proxy_host(),
+            DEFAULT_PROXY_HOST
+        );
+
+        let origins = |list: &[&str]| Settings {
+            proxy_allowed_origins: Some(list.iter().map(|o| (*o).to_string()).collect()),
+            ..Default::default()
+        };
+        assert!(
+            validate_settings(&origins(&["http://localhost:3000", "tauri://localhost"])).is_ok()
+        );
+        for bad in ["localhost:3000", "http://host/path", "http://"] {
+            assert!(
+                matches!(
+                    validate_settings(&origins(&[bad])),
+                    Err(SettingsError::InvalidProxyOrigin(_))
+                ),
+                "{bad}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_validate_locale() {
+        for tag in ["en", "de-DE", "pt_BR", "zh-Hant-TW"] {
+            let settings = Settings {
+                locale: Some(tag.into()),
+                ..Default::default()
+            };
+            assert!(validate_settings(&settings).is_ok(), "{tag}");
+        }
+        for tag in ["", "english", "de-", "en US"] {
+            let settings = Settings {
+                locale: Some(tag.into()),
+                ..Default::default()
+            };
+            assert!(
+                matches!(
+                    validate_settings(&settings),
+                    Err(SettingsError::InvalidLocale(_))
+                ),
+                "{tag}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_validate_web_search() {
+        use crate::domain::WebSearchProvider;
+
+        let mut config = WebSearchConfig::new(WebSearchProvider::DuckDuckGo);
+        config.max_results = Some(100);
+        let settings = Settings {
+            web_search: Some(config),
+            ..Default::default()
+        };
+        assert!(matches!(
+            validate_settings(&settings),
+            Err(SettingsError::InvalidWebSearch(_))
+        ));
+
+        let settings = Settings {
+            web_search: Some(WebSearchConfig::new(WebSearchProvider::DuckDuckGo)),
+            ..Default::default()
+        };
+        assert!(validate_settings(&settings).is_ok());
+    }
+
+    #[test]
+    fn test_validate_url_fetch() {
+        let settings = Settings {
+            url_fetch: Some(UrlFetchConfig {
+                max_tokens: Some(0),
+                max_fetches_per_conversation: None,
+            }),
+            ..Default::default()
+        };
+        assert!(matches!(
+            validate_settings(&settings),
+            Err(SettingsError::InvalidUrlFetch(_))
+        ));
+
+        let settings = Settings {
+            url_fetch: Some(UrlFetchConfig::default()),
+            ..Default::default()
+        };
+        assert!(validate_settings(&settings).is_ok());
+    }
+
+    #[test]
+    fn test_validate_moderation() {
+        use crate::domain::{ModerationAction, ModerationMatch, ModerationRule, ModerationScope};
+
+        let rule = ModerationRule {
+            name: "secrets".to_string(),
+            pattern: "api_key(".to_string(),
+            match_kind: ModerationMatch::Regex,
+            scope: ModerationScope::Both,
+            action: ModerationAction::Block,
+        };
+        let mut settings = Settings {
+            moderation: Some(ModerationConfig {
+                rules: vec![rule],
+                classifier: None,
+            }),
+            ..Default::default()
+        };
+        assert!(matches!(
+            validate_settings(&settings),
+            Err(SettingsError::InvalidModeration(_))
+        ));
+
+        settings.moderation.as_mut().unwrap().rules[0].match_kind = ModerationMatch::Keyword;
+        assert!(validate_settings(&settings).is_ok());
+    }
+
+    #[test]
+    fn test_validate_proxy_limits() {
+        let mut settings = Settings {
+            proxy_limits: Some(ProxyLimits {
+                max_streams_per_key: Some(0),
+                ..ProxyLimits::default()
+            }),
+            ..Default::default()
+        };
+        assert!(matches!(
+            validate_settings(&settings),
+            Err(SettingsError::InvalidProxyLimits(_))
+        ));
+
+        settings.proxy_limits.as_mut().unwrap().max_streams_per_key = Some(2);
+        assert!(validate_settings(&settings).is_ok());
+    }
+
     #[test]
     fn test_merge_settings() {
         let mut settings = Settings::with_defaults();
@@ -539,6 +1292,24 @@ mod tests {
         assert_eq!(settings.llama_base_port, Some(DEFAULT_LLAMA_BASE_PORT)); // Unchanged
     }
 
+    #[test]
+    fn test_changed_keys_names_only_differing_fields() {
+        let before = Settings::with_defaults();
+        let mut after = before.clone();
+        after.merge(&SettingsUpdate {
+            default_context_size: Some(Some(8192)),
+            proxy_port: Some(None),
+            llama_base_port: Some(Some(DEFAULT_LLAMA_BASE_PORT)), // same value
+            ..Default::default()
+        });
+
+        assert_eq!(
+            before.changed_keys(&after),
+            vec!["default_context_size", "proxy_port"]
+        );
+        assert!(after.changed_keys(&after).is_empty());
+    }
+
     #[test]
     fn test_effective_ports() {
         let settings = Settings::with_defaults();
@@ -39,6 +39,10 @@ use crate::LlmStreamEvent;
 /// be sent after `[DONE]` on the wire.
 pub const DONE_SENTINEL: &str = "data: [DONE]\n\n";
 
+/// Top-level chunk key carrying gglib's non-`OpenAI` additions, such as the
+/// proxy's timing figures on the terminal usage chunk.
+pub const EXTENSION_KEY: &str = "x_gglib";
+
 /// Stateful encoder that produces OpenAI-shape SSE frames for one response.
 ///
 /// The `id`, `model`, and `created` fields are stable across all frames the
@@ -156,6 +160,7 @@ impl SseEncoder {
                 *completion_tokens,
                 *total_tokens,
                 *cached_tokens,
+                None,
             )),
             LlmStreamEvent::NormalizationError { .. } => None,
             LlmStreamEvent::UpstreamError {
@@ -166,6 +171,35 @@ impl SseEncoder {
         }
     }
 
+    /// Encode a [`LlmStreamEvent::Usage`] event with gglib's own figures
+    /// under a top-level [`EXTENSION_KEY`] next to `usage`.
+    ///
+    /// Returns `None` for any other event. `OpenAI` clients ignore unknown
+    /// chunk keys, so the frame is still an ordinary usage chunk to them.
+    #[must_use]
+    pub fn encode_usage_with_extension(
+        &self,
+        event: &LlmStreamEvent,
+        extension: Value,
+    ) -> Option<String> {
+        let LlmStreamEvent::Usage {
+            prompt_tokens,
+            completion_tokens,
+            total_tokens,
+            cached_tokens,
+        } = event
+        else {
+            return None;
+        };
+        Some(self.usage_frame(
+            *prompt_tokens,
+            *completion_tokens,
+            *total_tokens,
+            *cached_tokens,
+            Some(extension),
+        ))
+    }
+
     /// Encode a [`LlmStreamEvent::Usage`] event.
     ///
     /// Per the `OpenAI` `stream_options.include_usage` convention, the
@@ -178,6 +212,7 @@ impl SseEncoder {
         completion_tokens: u32,
         total_tokens: u32,
         cached_tokens: Option<u32>,
+        extension: Option<Value>,
     ) -> String {
         let mut usage = json!({
             "prompt_tokens": prompt_tokens,
@@ -190,7 +225,7 @@ impl SseEncoder {
         if let Some(cached) = cached_tokens {
             usage["prompt_tokens_details"] = json!({ "cached_tokens": cached });
         }
-        let value = json!({
+        let mut value = json!({
             "id": self.id,
             "object": "chat.completion.chunk",
             "created": self.created,
@@ -198,6 +233,9 @@ impl SseEncoder {
             "choices": [],
             "usage": usage,
         });
+        if let Some(extension) = extension {
+            value[EXTENSION_KEY] = extension;
+        }
         format!("data: {value}\n\n")
     }
 
@@ -400,6 +438,35 @@ mod tests {
         assert_eq!(v["usage"]["prompt_tokens_details"]["cached_tokens"], 0);
     }
 
+    #[test]
+    fn usage_extension_sits_beside_an_unchanged_usage_object() {
+        let usage = LlmStreamEvent::Usage {
+            prompt_tokens: 123,
+            completion_tokens: 45,
+            total_tokens: 168,
+            cached_tokens: None,
+        };
+        let out = enc()
+            .encode_usage_with_extension(&usage, json!({ "ttft_ms": 80 }))
+            .expect("frame");
+        let v = parse_data_frame(&out);
+        assert_eq!(v[EXTENSION_KEY], json!({ "ttft_ms": 80 }));
+        assert_eq!(
+            v["usage"],
+            parse_data_frame(&enc().encode(&usage).unwrap())["usage"]
+        );
+        assert!(v["choices"].as_array().is_some_and(Vec::is_empty));
+
+        let done = LlmStreamEvent::Done {
+            finish_reason: "stop".to_owned(),
+        };
+        assert!(
+            enc()
+                .encode_usage_with_extension(&done, json!({}))
+                .is_none()
+        );
+    }
+
     #[test]
     fn upstream_error_event_encodes_to_bare_error_frame_no_sentinel() {
         let out = enc()
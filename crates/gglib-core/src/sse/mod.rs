@@ -4,5 +4,5 @@ pub mod encoder;
 pub mod parser;
 
 pub use decoder::SseStreamDecoder;
-pub use encoder::{DONE_SENTINEL, SseEncoder};
+pub use encoder::{DONE_SENTINEL, EXTENSION_KEY, SseEncoder};
 pub use parser::{SseParseResult, parse_sse_frame};
@@ -3,22 +3,156 @@
 //! Design:
 //! - A single layered subscriber (console + daily rotating file) is installed once via [`OnceLock`].
 //! - Calls to [`init_tracing`] are idempotent — subsequent calls return `Ok(())`.
-//! - Log directory: `./logs/` in debug builds, `data_root()/logs` in release.
+//! - Log directory ([`log_dir`]): `./logs/` in debug builds, `data_root()/logs`
+//!   in release. Files roll daily as `gglib.log.YYYY-MM-DD` (UTC) and are
+//!   written without ANSI colours so they can be read back by the log viewer.
 //! - Filter: `RUST_LOG` env var wins; otherwise `"debug"` if verbose, else `"warn"`.
 //! - Console output goes through [`console_println`], which defaults to stderr
 //!   but can be redirected via [`set_console_hook`] — see the "Console hook"
 //!   section below.
+//! - An empty export slot sits alongside the console and file layers. Once
+//!   settings are loaded, [`set_export_layer`] fills it (e.g. with the OTLP
+//!   exporter from `gglib-runtime`) without reinstalling the subscriber.
 
 use std::path::PathBuf;
 use std::sync::{Arc, OnceLock, RwLock};
 
-use tracing_subscriber::{EnvFilter, Registry, layer::SubscriberExt, util::SubscriberInitExt};
+use serde::{Deserialize, Serialize};
+use tracing_subscriber::{
+    EnvFilter, Layer, Registry, layer::SubscriberExt, reload, util::SubscriberInitExt,
+};
+use ts_rs::TS;
+use utoipa::ToSchema;
 
 #[allow(unused_imports)] // only used in release builds via cfg(not(debug_assertions))
 use crate::paths::data_root;
 
 static GUARD: OnceLock<tracing_appender::non_blocking::WorkerGuard> = OnceLock::new();
 
+// ─── Export slot ─────────────────────────────────────────────────────────────
+//
+// Tracing is initialised before the database is open, but exporter settings
+// live in the database. The subscriber therefore carries a reloadable, empty
+// layer with its own (initially "off") filter; `set_export_layer` swaps both
+// in once settings are known. The export filter is separate from the console
+// filter so spans can be exported at `info` while the console stays at `warn`.
+
+/// A layer that ships spans to an external collector.
+pub type ExportLayer = Box<dyn Layer<Registry> + Send + Sync>;
+
+struct ExportHandles {
+    layer: reload::Handle<Option<ExportLayer>, Registry>,
+    filter: reload::Handle<EnvFilter, Registry>,
+}
+
+static EXPORT: OnceLock<ExportHandles> = OnceLock::new();
+
+/// Spans exported when an export layer is installed: gglib's own crates at
+/// `info`, everything else only when it warns.
+const EXPORT_FILTER: &str = "warn,gglib=info";
+
+/// OTLP trace export settings.
+///
+/// Stored as the `otlp` setting; `None` there disables export.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, TS, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct OtlpConfig {
+    /// Collector base URL for OTLP/HTTP, e.g. `http://localhost:4318`.
+    /// `/v1/traces` is appended when the URL does not already end with it.
+    pub endpoint: String,
+    /// `service.name` resource attribute. Defaults to `gglib`.
+    #[serde(default)]
+    pub service_name: Option<String>,
+    /// Fraction of root traces to keep, from `0.0` to `1.0`. Defaults to
+    /// `1.0`; child spans follow their parent's decision.
+    #[serde(default)]
+    pub sample_ratio: Option<f64>,
+}
+
+impl OtlpConfig {
+    /// Default `service.name` when none is configured.
+    pub const DEFAULT_SERVICE_NAME: &'static str = "gglib";
+
+    /// Check the endpoint scheme and sampling ratio.
+    ///
+    /// # Errors
+    ///
+    /// Returns a human-readable description of the first problem found.
+    pub fn validate(&self) -> Result<(), String> {
+        let endpoint = self.endpoint.trim();
+        if !(endpoint.starts_with("http://") || endpoint.starts_with("https://")) {
+            return Err(format!(
+                "endpoint must be an http:// or https:// URL, got '{endpoint}'"
+            ));
+        }
+        if let Some(ratio) = self.sample_ratio
+            && !(0.0..=1.0).contains(&ratio)
+        {
+            return Err(format!("sample ratio must be between 0 and 1, got {ratio}"));
+        }
+        if self
+            .service_name
+            .as_ref()
+            .is_some_and(|name| name.trim().is_empty())
+        {
+            return Err("service name cannot be empty".to_string());
+        }
+        Ok(())
+    }
+
+    /// The traces endpoint, with `/v1/traces` appended when missing.
+    #[must_use]
+    pub fn traces_endpoint(&self) -> String {
+        let base = self.endpoint.trim().trim_end_matches('/');
+        if base.ends_with("/v1/traces") {
+            base.to_string()
+        } else {
+            format!("{base}/v1/traces")
+        }
+    }
+
+    /// The configured service name, or [`Self::DEFAULT_SERVICE_NAME`].
+    #[must_use]
+    pub fn service_name(&self) -> &str {
+        self.service_name
+            .as_deref()
+            .unwrap_or(Self::DEFAULT_SERVICE_NAME)
+    }
+
+    /// The configured sampling ratio, or `1.0`.
+    #[must_use]
+    pub fn sample_ratio(&self) -> f64 {
+        self.sample_ratio.unwrap_or(1.0)
+    }
+}
+
+/// Install (`Some`) or remove (`None`) the span export layer.
+///
+/// A no-op before [`init_tracing`] has run, since there is no subscriber to
+/// export from.
+///
+/// # Errors
+///
+/// Returns an error if the subscriber has been dropped.
+pub fn set_export_layer(layer: Option<ExportLayer>) -> anyhow::Result<()> {
+    let Some(handles) = EXPORT.get() else {
+        return Ok(());
+    };
+    let filter = if layer.is_some() {
+        EXPORT_FILTER
+    } else {
+        "off"
+    };
+    handles
+        .filter
+        .reload(EnvFilter::try_new(filter).unwrap_or_default())
+        .map_err(|e| anyhow::anyhow!("failed to update export filter: {e}"))?;
+    handles
+        .layer
+        .reload(layer)
+        .map_err(|e| anyhow::anyhow!("failed to install export layer: {e}"))
+}
+
 // ─── Console hook ────────────────────────────────────────────────────────────
 //
 // The stdout `fmt` layer below writes through this hook instead of directly
@@ -91,7 +225,11 @@ impl Drop for ConsoleWriter {
     }
 }
 
-fn resolve_log_dir() -> PathBuf {
+/// File name prefix of the daily log files; the appender adds `.YYYY-MM-DD`.
+pub const LOG_FILE_NAME: &str = "gglib.log";
+
+/// Directory holding the rotating log files, created if missing.
+pub fn log_dir() -> PathBuf {
     #[cfg(debug_assertions)]
     let dir = PathBuf::from("./logs");
 
@@ -123,31 +261,42 @@ pub fn init_tracing(verbose: bool) -> anyhow::Result<()> {
         return Ok(());
     }
 
-    let log_dir = resolve_log_dir();
-    let file_appender = tracing_appender::rolling::daily(&log_dir, "gglib.log");
+    let log_dir = log_dir();
+    let file_appender = tracing_appender::rolling::daily(&log_dir, LOG_FILE_NAME);
     let (non_blocking, guard) = tracing_appender::non_blocking(file_appender);
 
-    let env_filter = build_env_filter(verbose);
+    // Each layer carries its own filter so the export slot can see spans
+    // the console and file logs are configured to drop.
+    let (export_layer, layer_handle) = reload::Layer::new(None::<ExportLayer>);
+    let (export_filter, filter_handle) =
+        reload::Layer::new(EnvFilter::try_new("off").unwrap_or_default());
 
     let subscriber = Registry::default()
-        .with(env_filter)
+        .with(export_layer.with_filter(export_filter))
         .with(
             tracing_subscriber::fmt::layer()
                 .with_target(true)
-                .with_writer(ConsoleWriter::default),
+                .with_writer(ConsoleWriter::default)
+                .with_filter(build_env_filter(verbose)),
         )
         .with(
             tracing_subscriber::fmt::layer()
                 .with_writer(non_blocking)
-                .with_target(false),
+                .with_ansi(false)
+                .with_target(true)
+                .with_filter(build_env_filter(verbose)),
         );
 
     subscriber
         .try_init()
         .map_err(|e| anyhow::anyhow!("failed to set global tracer: {e}"))?;
 
-    // Ignore the Result since failure just means another thread set it concurrently
+    // Ignore the Results since failure just means another thread set them concurrently
     let _ = GUARD.set(guard);
+    let _ = EXPORT.set(ExportHandles {
+        layer: layer_handle,
+        filter: filter_handle,
+    });
 
     Ok(())
 }
@@ -181,4 +330,46 @@ mod tests {
         console_println("after clear");
         assert_eq!(captured.lock().unwrap().len(), 1);
     }
+
+    #[test]
+    fn otlp_endpoint_gets_traces_path_once() {
+        let config = OtlpConfig {
+            endpoint: "http://collector:4318/".to_string(),
+            service_name: None,
+            sample_ratio: None,
+        };
+        assert_eq!(config.traces_endpoint(), "http://collector:4318/v1/traces");
+
+        let explicit = OtlpConfig {
+            endpoint: "https://collector/v1/traces".to_string(),
+            ..config
+        };
+        assert_eq!(explicit.traces_endpoint(), "https://collector/v1/traces");
+        assert_eq!(explicit.service_name(), "gglib");
+    }
+
+    #[test]
+    fn otlp_validation_rejects_bad_scheme_and_ratio() {
+        let config = OtlpConfig {
+            endpoint: "localhost:4318".to_string(),
+            service_name: None,
+            sample_ratio: None,
+        };
+        assert!(config.validate().is_err());
+
+        let ratio = OtlpConfig {
+            endpoint: "http://localhost:4318".to_string(),
+            sample_ratio: Some(1.5),
+            ..config
+        };
+        assert!(ratio.validate().is_err());
+        assert!(
+            OtlpConfig {
+                sample_ratio: Some(0.25),
+                ..ratio
+            }
+            .validate()
+            .is_ok()
+        );
+    }
 }
@@ -0,0 +1,121 @@
+//! Injectable time source.
+//!
+//! Code that measures rates, stamps records or ages files asks a [`Clock`]
+//! instead of calling `Instant::now` / `SystemTime::now` directly, so tests
+//! can substitute a [`ManualClock`] and step time forward exactly rather than
+//! sleeping and asserting within a tolerance.
+
+use std::fmt;
+use std::sync::Mutex;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+/// Source of the current time.
+pub trait Clock: Send + Sync + fmt::Debug {
+    /// Monotonic time, for measuring intervals.
+    fn now(&self) -> Instant;
+
+    /// Wall-clock time, for timestamps that are stored or shown.
+    fn system_now(&self) -> SystemTime;
+
+    /// Wall-clock milliseconds since the Unix epoch; `0` before the epoch.
+    fn unix_millis(&self) -> u64 {
+        self.system_now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| u64::try_from(d.as_millis()).unwrap_or(u64::MAX))
+    }
+
+    /// Wall-clock seconds since the Unix epoch; `0` before the epoch.
+    fn unix_secs(&self) -> u64 {
+        self.system_now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_secs())
+    }
+}
+
+/// The real clock.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+
+    fn system_now(&self) -> SystemTime {
+        SystemTime::now()
+    }
+}
+
+/// A clock that only moves when told to.
+///
+/// Both readings start from fixed origins and advance together by
+/// [`advance`](Self::advance), so intervals measured against it are exact.
+#[derive(Debug)]
+pub struct ManualClock {
+    origin: Instant,
+    system_origin: SystemTime,
+    elapsed: Mutex<Duration>,
+}
+
+impl ManualClock {
+    /// A clock whose wall time starts at `start`.
+    #[must_use]
+    pub fn starting_at(start: SystemTime) -> Self {
+        Self {
+            origin: Instant::now(),
+            system_origin: start,
+            elapsed: Mutex::new(Duration::ZERO),
+        }
+    }
+
+    /// Move the clock forward by `by`.
+    pub fn advance(&self, by: Duration) {
+        *self.lock() += by;
+    }
+
+    /// Time advanced since the clock was created.
+    #[must_use]
+    pub fn elapsed(&self) -> Duration {
+        *self.lock()
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Duration> {
+        self.elapsed
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+}
+
+impl Default for ManualClock {
+    /// A clock whose wall time starts at 2024-01-01T00:00:00Z.
+    fn default() -> Self {
+        Self::starting_at(UNIX_EPOCH + Duration::from_hours(473_352))
+    }
+}
+
+impl Clock for ManualClock {
+    fn now(&self) -> Instant {
+        self.origin + self.elapsed()
+    }
+
+    fn system_now(&self) -> SystemTime {
+        self.system_origin + self.elapsed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn manual_clock_moves_only_when_advanced() {
+        let clock = ManualClock::default();
+        let (start, wall) = (clock.now(), clock.unix_millis());
+        assert_eq!(clock.now(), start);
+
+        clock.advance(Duration::from_millis(1_500));
+        assert_eq!(clock.now() - start, Duration::from_millis(1_500));
+        assert_eq!(clock.unix_millis() - wall, 1_500);
+        assert_eq!(clock.unix_secs(), 1_704_067_201);
+    }
+}
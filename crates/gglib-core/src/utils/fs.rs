@@ -0,0 +1,283 @@
+//! Injectable filesystem access.
+//!
+//! Covers the metadata and move/delete operations that bookkeeping code
+//! (download promotion, partial-file cleanup) performs, not reading or
+//! writing file contents. [`RealFs`] forwards to `std::fs`; [`MemoryFs`]
+//! keeps a tree of sizes and modification times so tests can lay out and
+//! age files without touching the disk.
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::SystemTime;
+
+use super::clock::Clock;
+
+/// What a path points at.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FileKind {
+    File,
+    Dir,
+    /// A symlink or special file; never followed.
+    Other,
+}
+
+/// Metadata of one path, read without following symlinks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FileInfo {
+    pub kind: FileKind,
+    /// Size in bytes; `0` for directories.
+    pub len: u64,
+    pub modified: SystemTime,
+}
+
+impl FileInfo {
+    pub const fn is_dir(&self) -> bool {
+        matches!(self.kind, FileKind::Dir)
+    }
+
+    pub const fn is_file(&self) -> bool {
+        matches!(self.kind, FileKind::File)
+    }
+}
+
+/// Filesystem operations used outside of content I/O.
+pub trait FileSystem: Send + Sync + fmt::Debug {
+    /// Metadata of `path`, without following a final symlink.
+    fn metadata(&self, path: &Path) -> io::Result<FileInfo>;
+
+    /// Paths of the entries directly inside `dir`.
+    fn read_dir(&self, dir: &Path) -> io::Result<Vec<PathBuf>>;
+
+    fn create_dir_all(&self, path: &Path) -> io::Result<()>;
+
+    fn rename(&self, from: &Path, to: &Path) -> io::Result<()>;
+
+    fn remove_file(&self, path: &Path) -> io::Result<()>;
+
+    fn remove_dir_all(&self, path: &Path) -> io::Result<()>;
+
+    /// Whether `path` exists; `false` when it cannot be read.
+    fn exists(&self, path: &Path) -> bool {
+        self.metadata(path).is_ok()
+    }
+}
+
+/// The real filesystem, through `std::fs`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RealFs;
+
+impl FileSystem for RealFs {
+    fn metadata(&self, path: &Path) -> io::Result<FileInfo> {
+        let meta = std::fs::symlink_metadata(path)?;
+        let kind = if meta.is_dir() {
+            FileKind::Dir
+        } else if meta.is_file() {
+            FileKind::File
+        } else {
+            FileKind::Other
+        };
+        Ok(FileInfo {
+            kind,
+            len: if meta.is_dir() { 0 } else { meta.len() },
+            modified: meta.modified().unwrap_or(SystemTime::UNIX_EPOCH),
+        })
+    }
+
+    fn read_dir(&self, dir: &Path) -> io::Result<Vec<PathBuf>> {
+        std::fs::read_dir(dir)?
+            .map(|entry| entry.map(|e| e.path()))
+            .collect()
+    }
+
+    fn create_dir_all(&self, path: &Path) -> io::Result<()> {
+        std::fs::create_dir_all(path)
+    }
+
+    fn rename(&self, from: &Path, to: &Path) -> io::Result<()> {
+        std::fs::rename(from, to)
+    }
+
+    fn remove_file(&self, path: &Path) -> io::Result<()> {
+        std::fs::remove_file(path)
+    }
+
+    fn remove_dir_all(&self, path: &Path) -> io::Result<()> {
+        std::fs::remove_dir_all(path)
+    }
+}
+
+/// An in-memory tree of files and directories, for tests.
+///
+/// Files have a size but no contents. New entries are stamped with the
+/// clock's current wall time, so advancing a
+/// [`ManualClock`](super::clock::ManualClock) ages them.
+#[derive(Debug)]
+pub struct MemoryFs {
+    clock: Arc<dyn Clock>,
+    entries: Mutex<BTreeMap<PathBuf, FileInfo>>,
+}
+
+impl MemoryFs {
+    /// An empty filesystem stamping entries from `clock`.
+    pub fn new(clock: Arc<dyn Clock>) -> Self {
+        Self {
+            clock,
+            entries: Mutex::new(BTreeMap::new()),
+        }
+    }
+
+    /// Create (or replace) a file of `len` bytes, along with its parents.
+    pub fn add_file(&self, path: impl AsRef<Path>, len: u64) {
+        let path = path.as_ref();
+        if let Some(parent) = path.parent() {
+            let _ = self.create_dir_all(parent);
+        }
+        let modified = self.clock.system_now();
+        self.lock().insert(
+            path.to_path_buf(),
+            FileInfo {
+                kind: FileKind::File,
+                len,
+                modified,
+            },
+        );
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, BTreeMap<PathBuf, FileInfo>> {
+        self.entries
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+}
+
+fn not_found(path: &Path) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::NotFound,
+        format!("{} does not exist", path.display()),
+    )
+}
+
+impl FileSystem for MemoryFs {
+    fn metadata(&self, path: &Path) -> io::Result<FileInfo> {
+        self.lock()
+            .get(path)
+            .copied()
+            .ok_or_else(|| not_found(path))
+    }
+
+    fn read_dir(&self, dir: &Path) -> io::Result<Vec<PathBuf>> {
+        let entries = self.lock();
+        if !entries.get(dir).is_some_and(FileInfo::is_dir) {
+            return Err(not_found(dir));
+        }
+        Ok(entries
+            .keys()
+            .filter(|path| path.parent() == Some(dir))
+            .cloned()
+            .collect())
+    }
+
+    fn create_dir_all(&self, path: &Path) -> io::Result<()> {
+        let modified = self.clock.system_now();
+        let mut entries = self.lock();
+        for dir in path.ancestors().filter(|p| !p.as_os_str().is_empty()) {
+            match entries.get(dir) {
+                Some(info) if info.is_dir() => {}
+                Some(_) => {
+                    return Err(io::Error::new(
+                        io::ErrorKind::AlreadyExists,
+                        format!("{} is not a directory", dir.display()),
+                    ));
+                }
+                None => {
+                    entries.insert(
+                        dir.to_path_buf(),
+                        FileInfo {
+                            kind: FileKind::Dir,
+                            len: 0,
+                            modified,
+                        },
+                    );
+                }
+            }
+        }
+        drop(entries);
+        Ok(())
+    }
+
+    fn rename(&self, from: &Path, to: &Path) -> io::Result<()> {
+        let mut entries = self.lock();
+        if !entries.contains_key(from) {
+            return Err(not_found(from));
+        }
+        let moved: Vec<PathBuf> = entries
+            .keys()
+            .filter(|path| path.starts_with(from))
+            .cloned()
+            .collect();
+        for path in moved {
+            if let Some(info) = entries.remove(&path) {
+                let dest = match path.strip_prefix(from) {
+                    Ok(rest) if !rest.as_os_str().is_empty() => to.join(rest),
+                    _ => to.to_path_buf(),
+                };
+                entries.insert(dest, info);
+            }
+        }
+        drop(entries);
+        Ok(())
+    }
+
+    fn remove_file(&self, path: &Path) -> io::Result<()> {
+        if self.metadata(path)?.is_dir() {
+            return Err(io::Error::other(format!(
+                "{} is a directory",
+                path.display()
+            )));
+        }
+        self.lock().remove(path);
+        Ok(())
+    }
+
+    fn remove_dir_all(&self, path: &Path) -> io::Result<()> {
+        self.metadata(path)?;
+        self.lock().retain(|p, _| !p.starts_with(path));
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::clock::ManualClock;
+    use std::time::Duration;
+
+    #[test]
+    fn memory_fs_tracks_sizes_times_and_moves() {
+        let clock = Arc::new(ManualClock::default());
+        let fs = MemoryFs::new(clock.clone());
+        fs.add_file("/models/.staging/a/m.gguf", 10);
+        clock.advance(Duration::from_mins(1));
+        fs.add_file("/models/.staging/a/n.gguf", 20);
+
+        let staged = fs.read_dir(Path::new("/models/.staging/a")).unwrap();
+        assert_eq!(staged.len(), 2);
+        let m = fs.metadata(Path::new("/models/.staging/a/m.gguf")).unwrap();
+        let n = fs.metadata(Path::new("/models/.staging/a/n.gguf")).unwrap();
+        assert_eq!(
+            n.modified.duration_since(m.modified).unwrap(),
+            Duration::from_mins(1)
+        );
+
+        fs.rename(Path::new("/models/.staging/a"), Path::new("/models/a"))
+            .unwrap();
+        assert!(fs.exists(Path::new("/models/a/n.gguf")));
+        assert!(!fs.exists(Path::new("/models/.staging/a/n.gguf")));
+
+        fs.remove_dir_all(Path::new("/models/a")).unwrap();
+        assert_eq!(fs.read_dir(Path::new("/models")).unwrap().len(), 1);
+    }
+}
@@ -1,4 +1,6 @@
 #![doc = include_str!("README.md")]
+pub mod clock;
+pub mod fs;
 pub mod process;
 pub mod shard_filename;
 pub mod system;
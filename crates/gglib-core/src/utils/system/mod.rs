@@ -2,4 +2,4 @@
 mod types;
 
 // Re-export pure domain types only - no active probing functions
-pub use types::{Dependency, DependencyStatus, GpuInfo, SystemMemoryInfo};
+pub use types::{Dependency, DependencyStatus, GpuDevice, GpuInfo, SystemMemoryInfo};
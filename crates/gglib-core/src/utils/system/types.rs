@@ -90,6 +90,20 @@ pub struct GpuInfo {
     pub vulkan_spirv_headers: bool,
 }
 
+/// One GPU and its memory, as enumerated at launch time.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GpuDevice {
+    /// Device index, in the order llama.cpp numbers devices for
+    /// `--tensor-split` and `--main-gpu`.
+    pub index: u32,
+    /// Marketing name reported by the driver.
+    pub name: String,
+    /// Total VRAM in bytes.
+    pub total_vram_bytes: u64,
+    /// VRAM not in use by any process, in bytes.
+    pub free_vram_bytes: u64,
+}
+
 /// System memory information for model fit calculations.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SystemMemoryInfo {
@@ -8,12 +8,14 @@ use sqlx::SqlitePool;
 use std::sync::Arc;
 
 use gglib_core::Repos;
-use gglib_core::ports::ProcessRunner;
+use gglib_core::ports::{ModelTrashRepository, ProcessRunner};
 use gglib_core::services::AppCore;
 
 use crate::repositories::{
-    SqliteChatHistoryRepository, SqliteDownloadStateRepository, SqliteMcpRepository,
-    SqliteModelRepository, SqliteSettingsRepository,
+    SqliteAttachmentRepository, SqliteAuditLogRepository, SqliteChatHistoryRepository,
+    SqliteConversationShareRepository, SqliteDownloadStateRepository, SqliteMcpRepository,
+    SqliteModelRepository, SqliteModelUsageRepository, SqlitePipelineRunRepository,
+    SqlitePresetRepository, SqliteProjectRepository, SqliteSettingsRepository,
 };
 
 /// Factory for creating repository instances with `SQLite` backends.
@@ -48,7 +50,10 @@ impl CoreFactory {
             Arc::new(SqliteModelRepository::new(pool.clone())),
             Arc::new(SqliteSettingsRepository::new(pool.clone())),
             Arc::new(SqliteMcpRepository::new(pool.clone())),
-            Arc::new(SqliteChatHistoryRepository::new(pool)),
+            Arc::new(SqliteChatHistoryRepository::new(pool.clone())),
+            Arc::new(SqliteProjectRepository::new(pool.clone())),
+            Arc::new(SqlitePresetRepository::new(pool.clone())),
+            Arc::new(SqlitePipelineRunRepository::new(pool)),
         )
     }
 
@@ -87,6 +92,31 @@ impl CoreFactory {
         Arc::new(SqliteModelRepository::new(pool))
     }
 
+    /// Create a model trash repository from a pool.
+    ///
+    /// The trash is a view of the model table, so this is the model
+    /// repository behind the [`ModelTrashRepository`] port.
+    pub fn model_trash_repository(pool: SqlitePool) -> Arc<dyn ModelTrashRepository> {
+        Arc::new(SqliteModelRepository::new(pool))
+    }
+
+    /// Create a conversation share repository from a pool.
+    pub fn conversation_share_repository(
+        pool: SqlitePool,
+    ) -> Arc<SqliteConversationShareRepository> {
+        Arc::new(SqliteConversationShareRepository::new(pool))
+    }
+
+    /// Create a model usage repository from a pool.
+    pub fn model_usage_repository(pool: SqlitePool) -> Arc<SqliteModelUsageRepository> {
+        Arc::new(SqliteModelUsageRepository::new(pool))
+    }
+
+    /// Create an audit log repository from a pool.
+    pub fn audit_log_repository(pool: SqlitePool) -> Arc<SqliteAuditLogRepository> {
+        Arc::new(SqliteAuditLogRepository::new(pool))
+    }
+
     /// Create a settings repository from a pool.
     pub fn settings_repository(pool: SqlitePool) -> Arc<SqliteSettingsRepository> {
         Arc::new(SqliteSettingsRepository::new(pool))
@@ -101,6 +131,11 @@ impl CoreFactory {
     pub fn download_state_repository(pool: SqlitePool) -> Arc<SqliteDownloadStateRepository> {
         Arc::new(SqliteDownloadStateRepository::new(pool))
     }
+
+    /// Create an attachment repository from a pool.
+    pub fn attachment_repository(pool: SqlitePool) -> Arc<SqliteAttachmentRepository> {
+        Arc::new(SqliteAttachmentRepository::new(pool))
+    }
 }
 
 /// Test database helper for integration tests.
@@ -144,7 +179,25 @@ impl TestDb {
                 file_paths_json TEXT,
                 capabilities INTEGER DEFAULT 0,
                 inference_defaults TEXT,
-                server_defaults TEXT
+                server_defaults TEXT,
+                card TEXT,
+                deleted_at TEXT,
+                trash_json TEXT,
+                updated_at_ms INTEGER NOT NULL DEFAULT 0,
+                capabilities_version INTEGER DEFAULT 0,
+                license TEXT
+            )
+            "#,
+        )
+        .execute(&pool)
+        .await?;
+
+        // Create model tombstones table
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS model_tombstones (
+                id INTEGER PRIMARY KEY NOT NULL,
+                deleted_at_ms INTEGER NOT NULL
             )
             "#,
         )
@@ -169,6 +222,18 @@ impl TestDb {
         .execute(&pool)
         .await?;
 
+        // Create settings version table (see SqliteSettingsRepository)
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS settings_version (
+                id INTEGER PRIMARY KEY CHECK (id = 1),
+                version INTEGER NOT NULL
+            )
+            "#,
+        )
+        .execute(&pool)
+        .await?;
+
         // Create chat_conversations table
         sqlx::query(
             r#"
@@ -195,6 +260,8 @@ impl TestDb {
                 role TEXT NOT NULL CHECK(role IN ('system', 'user', 'assistant')),
                 content TEXT NOT NULL,
                 metadata TEXT,
+                version_of INTEGER,
+                superseded_by INTEGER,
                 created_at TEXT NOT NULL DEFAULT (datetime('now')),
                 FOREIGN KEY (conversation_id) REFERENCES chat_conversations(id) ON DELETE CASCADE
             )
@@ -213,6 +280,109 @@ impl TestDb {
         .execute(&pool)
         .await?;
 
+        // Create attachments table
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS attachments (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                message_id INTEGER,
+                sha256 TEXT NOT NULL,
+                file_name TEXT NOT NULL,
+                mime_type TEXT NOT NULL,
+                size_bytes INTEGER NOT NULL,
+                created_at TEXT NOT NULL DEFAULT (datetime('now')),
+                FOREIGN KEY (message_id) REFERENCES chat_messages(id) ON DELETE CASCADE
+            )
+            "#,
+        )
+        .execute(&pool)
+        .await?;
+
+        // Create projects tables
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS projects (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                name TEXT NOT NULL,
+                root_path TEXT NOT NULL,
+                include_globs TEXT NOT NULL DEFAULT '[]',
+                exclude_globs TEXT NOT NULL DEFAULT '[]',
+                auto_include INTEGER NOT NULL DEFAULT 1,
+                token_budget INTEGER,
+                created_at TEXT NOT NULL DEFAULT (datetime('now')),
+                updated_at TEXT NOT NULL DEFAULT (datetime('now'))
+            )
+            "#,
+        )
+        .execute(&pool)
+        .await?;
+
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS project_conversations (
+                conversation_id INTEGER PRIMARY KEY,
+                project_id INTEGER NOT NULL,
+                FOREIGN KEY (conversation_id) REFERENCES chat_conversations(id) ON DELETE CASCADE,
+                FOREIGN KEY (project_id) REFERENCES projects(id) ON DELETE CASCADE
+            )
+            "#,
+        )
+        .execute(&pool)
+        .await?;
+
+        // Create presets table
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS presets (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                name TEXT NOT NULL UNIQUE,
+                description TEXT,
+                system_prompt TEXT,
+                sampling TEXT NOT NULL DEFAULT '{}',
+                tools TEXT NOT NULL DEFAULT '[]',
+                mcp_server_ids TEXT NOT NULL DEFAULT '[]',
+                default_model TEXT,
+                created_at TEXT NOT NULL DEFAULT (datetime('now')),
+                updated_at TEXT NOT NULL DEFAULT (datetime('now'))
+            )
+            "#,
+        )
+        .execute(&pool)
+        .await?;
+
+        // Create pipeline run tables
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS pipeline_runs (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                name TEXT NOT NULL,
+                definition TEXT NOT NULL,
+                inputs TEXT NOT NULL DEFAULT '{}',
+                status TEXT NOT NULL DEFAULT 'running',
+                error TEXT,
+                created_at TEXT NOT NULL DEFAULT (datetime('now')),
+                updated_at TEXT NOT NULL DEFAULT (datetime('now'))
+            )
+            "#,
+        )
+        .execute(&pool)
+        .await?;
+
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS pipeline_step_results (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                run_id INTEGER NOT NULL,
+                step_id TEXT NOT NULL,
+                output TEXT NOT NULL,
+                created_at TEXT NOT NULL DEFAULT (datetime('now')),
+                FOREIGN KEY (run_id) REFERENCES pipeline_runs(id) ON DELETE CASCADE
+            )
+            "#,
+        )
+        .execute(&pool)
+        .await?;
+
         // Create MCP servers table
         sqlx::query(
             r#"
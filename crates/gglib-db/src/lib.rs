@@ -4,6 +4,7 @@
 pub mod factory;
 pub mod repositories;
 pub mod setup;
+pub mod snapshot;
 
 // Re-export factory for convenient access
 pub use factory::CoreFactory;
@@ -14,12 +15,18 @@ pub use factory::TestDb;
 
 // Re-export repository implementations
 pub use repositories::{
-    ModelFilesRepository, SqliteBenchmarkRepository, SqliteChatHistoryRepository,
-    SqliteCouncilRepository, SqliteDownloadStateRepository, SqliteMcpRepository,
-    SqliteModelRepository, SqliteSettingsRepository,
+    ModelFilesRepository, SqliteAttachmentRepository, SqliteAuditLogRepository,
+    SqliteBenchmarkRepository, SqliteChatHistoryRepository, SqliteConversationShareRepository,
+    SqliteCouncilRepository, SqliteDownloadStateRepository, SqliteGgufMetadataCache,
+    SqliteMcpRepository, SqliteModelRepository, SqliteModelUsageRepository,
+    SqlitePipelineRunRepository, SqlitePresetRepository, SqliteProjectRepository,
+    SqliteSettingsRepository,
 };
 
+pub use snapshot::SqliteDatabaseSnapshot;
+
 // Re-export setup functions for convenient access
+pub use setup::check_integrity;
 pub use setup::cleanup_zombie_benchmark_runs;
 pub use setup::setup_database;
 #[cfg(any(test, feature = "test-utils"))]
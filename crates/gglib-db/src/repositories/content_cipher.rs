@@ -0,0 +1,90 @@
+//! AES-256-GCM sealing of chat message content.
+//!
+//! A sealed value is stored as `enc:v1:` followed by the base64 of a random
+//! 96-bit nonce and the ciphertext. Anything without that prefix is
+//! plaintext, which is how rows written before encryption was enabled keep
+//! reading back unchanged. A plaintext message that itself begins with the
+//! prefix cannot be told apart and will fail to open.
+
+use aes_gcm::aead::{Aead, AeadCore, KeyInit, OsRng};
+use aes_gcm::{Aes256Gcm, Key, Nonce};
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+
+use gglib_core::ports::{ChatContentKey, ChatHistoryError};
+
+/// Prefix of sealed content.
+pub(crate) const SEALED_PREFIX: &str = "enc:v1:";
+
+const NONCE_LEN: usize = 12;
+
+/// The repository's current encryption mode.
+#[derive(Clone, Default)]
+pub(crate) struct ContentCipher {
+    cipher: Option<Aes256Gcm>,
+    encrypt: bool,
+}
+
+impl ContentCipher {
+    /// A cipher that opens content sealed under `key` and, with `encrypt`
+    /// set, seals new content too.
+    pub(crate) fn new(
+        key: Option<&ChatContentKey>,
+        encrypt: bool,
+    ) -> Result<Self, ChatHistoryError> {
+        if encrypt && key.is_none() {
+            return Err(ChatHistoryError::Encryption(
+                "encryption needs a chat history key".to_string(),
+            ));
+        }
+        Ok(Self {
+            cipher: key.map(|key| Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(key.as_bytes()))),
+            encrypt,
+        })
+    }
+
+    /// Whether new content is sealed.
+    pub(crate) const fn encrypts(&self) -> bool {
+        self.encrypt
+    }
+
+    /// The value to store for `content`: sealed when encrypting, else as-is.
+    pub(crate) fn seal(&self, content: &str) -> Result<String, ChatHistoryError> {
+        let Some(cipher) = self.cipher.as_ref().filter(|_| self.encrypt) else {
+            return Ok(content.to_string());
+        };
+        let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
+        let ciphertext = cipher
+            .encrypt(&nonce, content.as_bytes())
+            .map_err(|e| ChatHistoryError::Encryption(format!("sealing message: {e}")))?;
+        let mut payload = nonce.to_vec();
+        payload.extend_from_slice(&ciphertext);
+        Ok(format!("{SEALED_PREFIX}{}", STANDARD.encode(payload)))
+    }
+
+    /// The content behind a stored value.
+    pub(crate) fn open(&self, stored: String) -> Result<String, ChatHistoryError> {
+        let Some(encoded) = stored.strip_prefix(SEALED_PREFIX) else {
+            return Ok(stored);
+        };
+        let Some(cipher) = &self.cipher else {
+            return Err(ChatHistoryError::Encryption(
+                "message is encrypted but no chat history key is loaded".to_string(),
+            ));
+        };
+        let failed = || {
+            ChatHistoryError::Encryption(
+                "message could not be decrypted (wrong or replaced key?)".to_string(),
+            )
+        };
+        let payload = STANDARD.decode(encoded).map_err(|_| failed())?;
+        if payload.len() < NONCE_LEN {
+            return Err(failed());
+        }
+        let (nonce, ciphertext) = payload.split_at(NONCE_LEN);
+        let plaintext = cipher
+            .decrypt(Nonce::from_slice(nonce), ciphertext)
+            .map_err(|_| failed())?;
+        String::from_utf8(plaintext).map_err(|_| failed())
+    }
+}
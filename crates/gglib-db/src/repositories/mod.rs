@@ -1,19 +1,36 @@
 #![doc = include_str!("README.md")]
+mod content_cipher;
 mod model_files_repository;
 mod row_mappers;
+mod sqlite_attachment_repository;
+mod sqlite_audit_log_repository;
 mod sqlite_benchmark_repository;
 mod sqlite_chat_history_repository;
+mod sqlite_conversation_share_repository;
 mod sqlite_council_repository;
 mod sqlite_download_state_repository;
+mod sqlite_gguf_metadata_cache;
 mod sqlite_mcp_repository;
 mod sqlite_model_repository;
+mod sqlite_model_usage_repository;
+mod sqlite_pipeline_run_repository;
+mod sqlite_preset_repository;
+mod sqlite_project_repository;
 mod sqlite_settings_repository;
 
 pub use model_files_repository::ModelFilesRepository;
+pub use sqlite_attachment_repository::SqliteAttachmentRepository;
+pub use sqlite_audit_log_repository::SqliteAuditLogRepository;
 pub use sqlite_benchmark_repository::SqliteBenchmarkRepository;
 pub use sqlite_chat_history_repository::SqliteChatHistoryRepository;
+pub use sqlite_conversation_share_repository::SqliteConversationShareRepository;
 pub use sqlite_council_repository::SqliteCouncilRepository;
 pub use sqlite_download_state_repository::SqliteDownloadStateRepository;
+pub use sqlite_gguf_metadata_cache::SqliteGgufMetadataCache;
 pub use sqlite_mcp_repository::SqliteMcpRepository;
 pub use sqlite_model_repository::SqliteModelRepository;
+pub use sqlite_model_usage_repository::SqliteModelUsageRepository;
+pub use sqlite_pipeline_run_repository::SqlitePipelineRunRepository;
+pub use sqlite_preset_repository::SqlitePresetRepository;
+pub use sqlite_project_repository::SqliteProjectRepository;
 pub use sqlite_settings_repository::SqliteSettingsRepository;
@@ -7,7 +7,7 @@ use sqlx::Row;
 use std::path::Path;
 
 /// Shared SELECT column list for model queries (no table alias required).
-pub const MODEL_SELECT_COLUMNS: &str = "id, name, file_path, param_count_b, architecture, quantization, context_length, expert_count, expert_used_count, expert_shared_count, metadata, added_at, hf_repo_id, hf_commit_sha, hf_filename, download_date, last_update_check, tags, capabilities, inference_defaults, server_defaults, model_key";
+pub const MODEL_SELECT_COLUMNS: &str = "id, name, file_path, param_count_b, architecture, quantization, context_length, expert_count, expert_used_count, expert_shared_count, metadata, added_at, hf_repo_id, hf_commit_sha, hf_filename, download_date, last_update_check, tags, capabilities, capabilities_version, inference_defaults, server_defaults, card, license, model_key";
 
 /// Additional columns to SELECT when the model query includes a LEFT JOIN
 /// with `model_benchmark_summaries s`. All columns are aliased with an `s_`
@@ -106,12 +106,17 @@ pub fn row_to_model(row: &sqlx::sqlite::SqliteRow) -> Result<Model, RepositoryEr
             .map_err(|e| RepositoryError::Storage(e.to_string()))?,
         download_date: parse_datetime(download_date_str),
         last_update_check: parse_datetime(last_update_check_str),
+        license: row.try_get::<Option<String>, _>("license").ok().flatten(),
         tags: serde_json::from_str(&tags_json).unwrap_or_default(),
         capabilities: row
             .try_get::<u32, _>("capabilities")
             .ok()
             .map(ModelCapabilities::from_bits_truncate)
             .unwrap_or_default(),
+        capabilities_version: row
+            .try_get::<Option<u32>, _>("capabilities_version")
+            .ok()
+            .flatten(),
         inference_defaults: row
             .try_get::<Option<String>, _>("inference_defaults")
             .ok()
@@ -122,6 +127,7 @@ pub fn row_to_model(row: &sqlx::sqlite::SqliteRow) -> Result<Model, RepositoryEr
             .ok()
             .flatten()
             .and_then(|json| serde_json::from_str(&json).ok()),
+        card: row.try_get::<Option<String>, _>("card").ok().flatten(),
         // Defensively attempt to read benchmark summary columns (only present
         // when the query includes a LEFT JOIN with model_benchmark_summaries).
         benchmark_summary: try_read_summary(row),
@@ -0,0 +1,203 @@
+//! `SQLite` implementation of the `AttachmentRepository` trait.
+
+use async_trait::async_trait;
+use sqlx::{Row, SqlitePool, sqlite::SqliteRow};
+
+use gglib_core::{
+    domain::attachment::{Attachment, NewAttachment},
+    ports::attachments::{AttachmentError, AttachmentRepository},
+};
+
+/// `SQLite` implementation of the `AttachmentRepository` trait.
+///
+/// Stores attachment metadata only; blobs are managed by the service.
+pub struct SqliteAttachmentRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteAttachmentRepository {
+    /// Create a new `SQLite` attachment repository.
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+}
+
+fn attachment_from_row(row: &SqliteRow) -> Attachment {
+    Attachment {
+        id: row.get("id"),
+        message_id: row.get("message_id"),
+        sha256: row.get("sha256"),
+        file_name: row.get("file_name"),
+        mime_type: row.get("mime_type"),
+        size_bytes: row.get("size_bytes"),
+        created_at: row.get("created_at"),
+    }
+}
+
+#[async_trait]
+impl AttachmentRepository for SqliteAttachmentRepository {
+    async fn insert(&self, attachment: NewAttachment) -> Result<i64, AttachmentError> {
+        let result = sqlx::query(
+            "INSERT INTO attachments (sha256, file_name, mime_type, size_bytes) VALUES (?, ?, ?, ?)",
+        )
+        .bind(&attachment.sha256)
+        .bind(&attachment.file_name)
+        .bind(&attachment.mime_type)
+        .bind(attachment.size_bytes)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| AttachmentError::Database(e.to_string()))?;
+
+        Ok(result.last_insert_rowid())
+    }
+
+    async fn get(&self, id: i64) -> Result<Option<Attachment>, AttachmentError> {
+        let row = sqlx::query(
+            "SELECT id, message_id, sha256, file_name, mime_type, size_bytes, created_at
+             FROM attachments
+             WHERE id = ?",
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| AttachmentError::Database(e.to_string()))?;
+
+        Ok(row.as_ref().map(attachment_from_row))
+    }
+
+    async fn link_to_message(&self, ids: &[i64], message_id: i64) -> Result<(), AttachmentError> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| AttachmentError::Database(e.to_string()))?;
+
+        for &id in ids {
+            let result = sqlx::query("UPDATE attachments SET message_id = ? WHERE id = ?")
+                .bind(message_id)
+                .bind(id)
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| AttachmentError::Database(e.to_string()))?;
+            if result.rows_affected() == 0 {
+                return Err(AttachmentError::NotFound(id));
+            }
+        }
+
+        tx.commit()
+            .await
+            .map_err(|e| AttachmentError::Database(e.to_string()))
+    }
+
+    async fn list_for_message(&self, message_id: i64) -> Result<Vec<Attachment>, AttachmentError> {
+        let rows = sqlx::query(
+            "SELECT id, message_id, sha256, file_name, mime_type, size_bytes, created_at
+             FROM attachments
+             WHERE message_id = ?
+             ORDER BY id ASC",
+        )
+        .bind(message_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| AttachmentError::Database(e.to_string()))?;
+
+        Ok(rows.iter().map(attachment_from_row).collect())
+    }
+
+    async fn delete(&self, id: i64) -> Result<(), AttachmentError> {
+        let result = sqlx::query("DELETE FROM attachments WHERE id = ?")
+            .bind(id)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| AttachmentError::Database(e.to_string()))?;
+
+        if result.rows_affected() == 0 {
+            return Err(AttachmentError::NotFound(id));
+        }
+        Ok(())
+    }
+
+    async fn count_by_sha256(&self, sha256: &str) -> Result<i64, AttachmentError> {
+        let row = sqlx::query("SELECT COUNT(*) as count FROM attachments WHERE sha256 = ?")
+            .bind(sha256)
+            .fetch_one(&self.pool)
+            .await
+            .map_err(|e| AttachmentError::Database(e.to_string()))?;
+
+        Ok(row.get("count"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use gglib_core::domain::chat::{MessageRole, NewConversation, NewMessage};
+    use gglib_core::ports::chat_history::ChatHistoryRepository;
+
+    use crate::repositories::SqliteChatHistoryRepository;
+    use crate::setup::setup_test_database;
+
+    use super::*;
+
+    fn make_attachment(sha256: &str) -> NewAttachment {
+        NewAttachment {
+            sha256: sha256.to_string(),
+            file_name: "photo.png".to_string(),
+            mime_type: "image/png".to_string(),
+            size_bytes: 4,
+        }
+    }
+
+    #[tokio::test]
+    async fn link_and_list_for_message() {
+        let pool = setup_test_database().await.expect("setup_test_database");
+        let chat = SqliteChatHistoryRepository::new(pool.clone());
+        let repo = SqliteAttachmentRepository::new(pool);
+
+        let cid = chat
+            .create_conversation(NewConversation {
+                title: "Pics".to_string(),
+                model_id: None,
+                system_prompt: None,
+                settings: None,
+                incognito: false,
+            })
+            .await
+            .unwrap();
+        let mid = chat
+            .save_message(NewMessage {
+                conversation_id: cid,
+                role: MessageRole::User,
+                content: "look".to_string(),
+                metadata: None,
+            })
+            .await
+            .unwrap();
+
+        let a = repo.insert(make_attachment("aa")).await.unwrap();
+        let b = repo.insert(make_attachment("aa")).await.unwrap();
+        repo.link_to_message(&[a, b], mid).await.unwrap();
+
+        let listed = repo.list_for_message(mid).await.unwrap();
+        assert_eq!(listed.len(), 2);
+        assert_eq!(listed[0].message_id, Some(mid));
+        assert_eq!(repo.count_by_sha256("aa").await.unwrap(), 2);
+
+        assert!(matches!(
+            repo.link_to_message(&[a, 999], mid).await,
+            Err(AttachmentError::NotFound(999))
+        ));
+    }
+
+    #[tokio::test]
+    async fn delete_missing_attachment_is_not_found() {
+        let pool = setup_test_database().await.expect("setup_test_database");
+        let repo = SqliteAttachmentRepository::new(pool);
+        let id = repo.insert(make_attachment("bb")).await.unwrap();
+        repo.delete(id).await.unwrap();
+        assert!(repo.get(id).await.unwrap().is_none());
+        assert!(matches!(
+            repo.delete(id).await,
+            Err(AttachmentError::NotFound(_))
+        ));
+    }
+}
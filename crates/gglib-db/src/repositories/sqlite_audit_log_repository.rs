@@ -0,0 +1,181 @@
+//! `SQLite` implementation of [`AuditLogRepository`].
+
+use async_trait::async_trait;
+use chrono::Utc;
+use sqlx::{QueryBuilder, Row, Sqlite, SqlitePool, sqlite::SqliteRow};
+
+use gglib_core::domain::audit::{
+    AuditAction, AuditEntry, AuditFilter, DEFAULT_AUDIT_LIMIT, NewAuditEntry,
+};
+use gglib_core::ports::{AuditLogRepository, RepositoryError};
+
+/// `SQLite` implementation of [`AuditLogRepository`].
+///
+/// Entries live in `audit_log` and are never updated; they outlive the
+/// models and servers they name.
+pub struct SqliteAuditLogRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteAuditLogRepository {
+    /// Create a new audit log repository from a shared connection pool.
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+}
+
+fn storage(e: &sqlx::Error) -> RepositoryError {
+    RepositoryError::Storage(e.to_string())
+}
+
+fn entry_from_row(row: &SqliteRow) -> Result<AuditEntry, RepositoryError> {
+    let action: String = row.get("action");
+    Ok(AuditEntry {
+        id: row.get("id"),
+        at: row.get("at"),
+        actor: row.get("actor"),
+        action: AuditAction::parse(&action).ok_or_else(|| {
+            RepositoryError::Serialization(format!("unknown audit action '{action}'"))
+        })?,
+        target: row.get("target"),
+        summary: row.get("summary"),
+    })
+}
+
+#[async_trait]
+impl AuditLogRepository for SqliteAuditLogRepository {
+    async fn record(&self, entry: &NewAuditEntry) -> Result<AuditEntry, RepositoryError> {
+        let at = Utc::now();
+        let result = sqlx::query(
+            "INSERT INTO audit_log (at, actor, action, target, summary) VALUES (?, ?, ?, ?, ?)",
+        )
+        .bind(at)
+        .bind(&entry.actor)
+        .bind(entry.action.as_str())
+        .bind(&entry.target)
+        .bind(&entry.summary)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| storage(&e))?;
+
+        Ok(AuditEntry {
+            id: result.last_insert_rowid(),
+            at,
+            actor: entry.actor.clone(),
+            action: entry.action,
+            target: entry.target.clone(),
+            summary: entry.summary.clone(),
+        })
+    }
+
+    async fn list(&self, filter: &AuditFilter) -> Result<Vec<AuditEntry>, RepositoryError> {
+        let mut query = QueryBuilder::<Sqlite>::new(
+            "SELECT id, at, actor, action, target, summary FROM audit_log WHERE 1 = 1",
+        );
+        if let Some(action) = filter.action {
+            query.push(" AND action = ").push_bind(action.as_str());
+        }
+        if let Some(actor) = &filter.actor {
+            query
+                .push(" AND instr(lower(actor), lower(")
+                .push_bind(actor.clone())
+                .push(")) > 0");
+        }
+        if let Some(since) = filter.since {
+            query.push(" AND at >= ").push_bind(since);
+        }
+        query
+            .push(" ORDER BY id DESC LIMIT ")
+            .push_bind(i64::from(filter.limit.unwrap_or(DEFAULT_AUDIT_LIMIT)));
+
+        let rows = query
+            .build()
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| storage(&e))?;
+        rows.iter().map(entry_from_row).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Duration;
+
+    use crate::setup::setup_test_database;
+
+    use super::*;
+
+    fn entry(actor: &str, action: AuditAction, target: &str) -> NewAuditEntry {
+        NewAuditEntry {
+            actor: actor.to_string(),
+            action,
+            target: Some(target.to_string()),
+            summary: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn entries_list_newest_first_and_filter() {
+        let pool = setup_test_database().await.expect("setup_test_database");
+        let repo = SqliteAuditLogRepository::new(pool);
+
+        repo.record(&entry(
+            "alice (key:0a1b2c3d)",
+            AuditAction::ModelRemoved,
+            "3",
+        ))
+        .await
+        .unwrap();
+        repo.record(&entry("ip:10.0.0.7", AuditAction::ServerStarted, "5"))
+            .await
+            .unwrap();
+        let last = repo
+            .record(&entry(
+                "Alice (key:0a1b2c3d)",
+                AuditAction::ServerStopped,
+                "5",
+            ))
+            .await
+            .unwrap();
+
+        let all = repo.list(&AuditFilter::default()).await.unwrap();
+        assert_eq!(all.len(), 3);
+        assert_eq!(all[0], last);
+
+        let alice = repo
+            .list(&AuditFilter {
+                actor: Some("ALICE".to_string()),
+                ..AuditFilter::default()
+            })
+            .await
+            .unwrap();
+        assert_eq!(alice.len(), 2);
+
+        let starts = repo
+            .list(&AuditFilter {
+                action: Some(AuditAction::ServerStarted),
+                ..AuditFilter::default()
+            })
+            .await
+            .unwrap();
+        assert_eq!(starts[0].actor, "ip:10.0.0.7");
+
+        let future = repo
+            .list(&AuditFilter {
+                since: Some(Utc::now() + Duration::minutes(1)),
+                ..AuditFilter::default()
+            })
+            .await
+            .unwrap();
+        assert!(future.is_empty());
+
+        let limited = repo
+            .list(&AuditFilter {
+                limit: Some(1),
+                ..AuditFilter::default()
+            })
+            .await
+            .unwrap();
+        assert_eq!(limited, vec![last]);
+    }
+}
@@ -1,30 +1,88 @@
 //! `SQLite` implementation of the `ChatHistoryRepository` trait.
 
+use std::sync::{PoisonError, RwLock, RwLockReadGuard};
+
 use async_trait::async_trait;
-use sqlx::{Row, SqlitePool};
+use chrono::{DateTime, Utc};
+use sqlx::{Row, SqlitePool, sqlite::SqliteRow};
 
 use gglib_core::{
     domain::chat::{
-        Conversation, ConversationUpdate, Message, MessageRole, NewConversation, NewMessage,
+        Conversation, ConversationUpdate, Message, MessageRevision, MessageRole, NewConversation,
+        NewMessage,
     },
-    ports::chat_history::{ChatHistoryError, ChatHistoryRepository},
+    domain::chat_import::ImportedConversation,
+    ports::chat_history::{ChatContentKey, ChatHistoryError, ChatHistoryRepository},
 };
 
+use super::content_cipher::{ContentCipher, SEALED_PREFIX};
+
 /// `SQLite` implementation of the `ChatHistoryRepository` trait.
 ///
 /// This struct holds a connection pool and implements all CRUD operations
-/// for chat conversations and messages using `SQLite`.
+/// for chat conversations and messages using `SQLite`. Message content can
+/// be sealed with AES-256-GCM; titles, system prompts and metadata are
+/// always stored as-is.
 pub struct SqliteChatHistoryRepository {
     pool: SqlitePool,
+    cipher: RwLock<ContentCipher>,
 }
 
 impl SqliteChatHistoryRepository {
     /// Create a new `SQLite` chat history repository.
+    ///
+    /// Content is stored in plaintext until
+    /// [`set_content_encryption`](ChatHistoryRepository::set_content_encryption)
+    /// says otherwise.
     pub fn new(pool: SqlitePool) -> Self {
-        Self { pool }
+        Self {
+            pool,
+            cipher: RwLock::new(ContentCipher::default()),
+        }
+    }
+
+    fn cipher(&self) -> RwLockReadGuard<'_, ContentCipher> {
+        self.cipher.read().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Map message rows, opening sealed content.
+    fn messages_from_rows(&self, rows: &[SqliteRow]) -> Result<Vec<Message>, ChatHistoryError> {
+        let cipher = self.cipher();
+        rows.iter()
+            .map(|row| message_from_row(row, &cipher))
+            .collect()
     }
 }
 
+/// Columns selected for every [`Message`] query.
+const MESSAGE_COLUMNS: &str =
+    "id, conversation_id, role, content, metadata, version_of, superseded_by, created_at";
+
+/// Map a `chat_messages` row selected with [`MESSAGE_COLUMNS`].
+fn message_from_row(row: &SqliteRow, cipher: &ContentCipher) -> Result<Message, ChatHistoryError> {
+    let role_str: String = row.get("role");
+    let role = MessageRole::parse(&role_str).unwrap_or(MessageRole::User);
+    let metadata_str: Option<String> = row.get("metadata");
+    let metadata = metadata_str.and_then(|s| serde_json::from_str(&s).ok());
+    let id: i64 = row.get("id");
+    let content = cipher.open(row.get("content")).map_err(|e| match e {
+        ChatHistoryError::Encryption(msg) => {
+            ChatHistoryError::Encryption(format!("message {id}: {msg}"))
+        }
+        other => other,
+    })?;
+    Ok(Message {
+        id,
+        conversation_id: row.get("conversation_id"),
+        role,
+        content,
+        created_at: row.get("created_at"),
+        metadata,
+        version_of: row.get("version_of"),
+        superseded_by: row.get("superseded_by"),
+    })
+}
+
 #[async_trait]
 impl ChatHistoryRepository for SqliteChatHistoryRepository {
     async fn create_conversation(&self, conv: NewConversation) -> Result<i64, ChatHistoryError> {
@@ -70,6 +128,7 @@ impl ChatHistoryRepository for SqliteChatHistoryRepository {
                     settings,
                     created_at: row.get("created_at"),
                     updated_at: row.get("updated_at"),
+                    incognito: false,
                 }
             })
             .collect();
@@ -99,6 +158,7 @@ impl ChatHistoryRepository for SqliteChatHistoryRepository {
                 settings,
                 created_at: r.get("created_at"),
                 updated_at: r.get("updated_at"),
+                incognito: false,
             }
         }))
     }
@@ -108,12 +168,16 @@ impl ChatHistoryRepository for SqliteChatHistoryRepository {
         id: i64,
         update: ConversationUpdate,
     ) -> Result<(), ChatHistoryError> {
-        if update.title.is_none() && update.system_prompt.is_none() && update.settings.is_none() {
+        if update.title.is_none()
+            && update.system_prompt.is_none()
+            && update.settings.is_none()
+            && update.model_id.is_none()
+        {
             return Ok(());
         }
 
         let row = sqlx::query(
-            "SELECT title, system_prompt, settings FROM chat_conversations WHERE id = ?",
+            "SELECT title, model_id, system_prompt, settings FROM chat_conversations WHERE id = ?",
         )
         .bind(id)
         .fetch_optional(&self.pool)
@@ -124,6 +188,7 @@ impl ChatHistoryRepository for SqliteChatHistoryRepository {
         let current_title: String = row.get("title");
         let current_prompt: Option<String> = row.get("system_prompt");
         let current_settings: Option<String> = row.get("settings");
+        let current_model_id: Option<i64> = row.get("model_id");
 
         let next_title = update.title.unwrap_or(current_title);
         let next_prompt = update.system_prompt.unwrap_or(current_prompt);
@@ -132,11 +197,13 @@ impl ChatHistoryRepository for SqliteChatHistoryRepository {
             Some(None) => None,
             None => current_settings,
         };
+        let next_model_id = update.model_id.or(current_model_id);
 
         sqlx::query(
-            "UPDATE chat_conversations SET title = ?, system_prompt = ?, settings = ?, updated_at = datetime('now') WHERE id = ?",
+            "UPDATE chat_conversations SET title = ?, model_id = ?, system_prompt = ?, settings = ?, updated_at = datetime('now') WHERE id = ?",
         )
         .bind(next_title)
+        .bind(next_model_id)
         .bind(next_prompt)
         .bind(next_settings)
         .bind(id)
@@ -167,36 +234,31 @@ impl ChatHistoryRepository for SqliteChatHistoryRepository {
     }
 
     async fn get_messages(&self, conversation_id: i64) -> Result<Vec<Message>, ChatHistoryError> {
-        let rows = sqlx::query(
-            "SELECT id, conversation_id, role, content, metadata, created_at 
+        let rows = sqlx::query(&format!(
+            "SELECT {MESSAGE_COLUMNS} 
              FROM chat_messages 
-             WHERE conversation_id = ? 
-             ORDER BY created_at ASC",
-        )
+             WHERE conversation_id = ? AND superseded_by IS NULL 
+             ORDER BY created_at ASC, id ASC"
+        ))
         .bind(conversation_id)
         .fetch_all(&self.pool)
         .await
         .map_err(|e| ChatHistoryError::Database(e.to_string()))?;
 
-        let messages = rows
-            .iter()
-            .map(|row| {
-                let role_str: String = row.get("role");
-                let role = MessageRole::parse(&role_str).unwrap_or(MessageRole::User);
-                let metadata_str: Option<String> = row.get("metadata");
-                let metadata = metadata_str.and_then(|s| serde_json::from_str(&s).ok());
-                Message {
-                    id: row.get("id"),
-                    conversation_id: row.get("conversation_id"),
-                    role,
-                    content: row.get("content"),
-                    created_at: row.get("created_at"),
-                    metadata,
-                }
-            })
-            .collect();
+        self.messages_from_rows(&rows)
+    }
+
+    async fn get_message(&self, id: i64) -> Result<Option<Message>, ChatHistoryError> {
+        let row = sqlx::query(&format!(
+            "SELECT {MESSAGE_COLUMNS} FROM chat_messages WHERE id = ?"
+        ))
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| ChatHistoryError::Database(e.to_string()))?;
 
-        Ok(messages)
+        row.map(|row| message_from_row(&row, &self.cipher()))
+            .transpose()
     }
 
     async fn save_message(&self, msg: NewMessage) -> Result<i64, ChatHistoryError> {
@@ -205,6 +267,7 @@ impl ChatHistoryRepository for SqliteChatHistoryRepository {
             .metadata
             .as_ref()
             .map(|m| serde_json::to_string(m).unwrap_or_default());
+        let content = self.cipher().seal(&msg.content)?;
 
         // Insert message
         let result = sqlx::query(
@@ -212,7 +275,7 @@ impl ChatHistoryRepository for SqliteChatHistoryRepository {
         )
         .bind(msg.conversation_id)
         .bind(msg.role.as_str())
-        .bind(&msg.content)
+        .bind(&content)
         .bind(&metadata_str)
         .execute(&self.pool)
         .await
@@ -240,6 +303,7 @@ impl ChatHistoryRepository for SqliteChatHistoryRepository {
         let metadata_str = metadata
             .as_ref()
             .map(|m| serde_json::to_string(m).unwrap_or_default());
+        let content = self.cipher().seal(&content)?;
 
         let result = sqlx::query("UPDATE chat_messages SET content = ?, metadata = ? WHERE id = ?")
             .bind(&content)
@@ -295,16 +359,230 @@ impl ChatHistoryRepository for SqliteChatHistoryRepository {
         Ok(result.rows_affected() as i64)
     }
 
-    async fn get_message_count(&self, conversation_id: i64) -> Result<i64, ChatHistoryError> {
+    async fn supersede_message(
+        &self,
+        id: i64,
+        content: String,
+        metadata: Option<serde_json::Value>,
+    ) -> Result<MessageRevision, ChatHistoryError> {
+        let metadata_str = metadata
+            .as_ref()
+            .map(|m| serde_json::to_string(m).unwrap_or_default());
+        let content = self.cipher().seal(&content)?;
+
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| ChatHistoryError::Database(e.to_string()))?;
+
+        let row = sqlx::query(
+            "SELECT conversation_id, role, version_of, superseded_by FROM chat_messages WHERE id = ?",
+        )
+        .bind(id)
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(|e| ChatHistoryError::Database(e.to_string()))?
+        .ok_or(ChatHistoryError::MessageNotFound(id))?;
+
+        let superseded_by: Option<i64> = row.get("superseded_by");
+        if superseded_by.is_some() {
+            return Err(ChatHistoryError::MessageSuperseded(id));
+        }
+        let conversation_id: i64 = row.get("conversation_id");
+        let role: String = row.get("role");
+        let version_of: Option<i64> = row.get("version_of");
+
+        // Insert the revision, pointing at the first message of the chain
+        let result = sqlx::query(
+            "INSERT INTO chat_messages (conversation_id, role, content, metadata, version_of) VALUES (?, ?, ?, ?, ?)",
+        )
+        .bind(conversation_id)
+        .bind(&role)
+        .bind(&content)
+        .bind(&metadata_str)
+        .bind(version_of.unwrap_or(id))
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| ChatHistoryError::Database(e.to_string()))?;
+
+        let message_id = result.last_insert_rowid();
+
+        // Supersede the original and every active message after it
+        let result = sqlx::query(
+            "UPDATE chat_messages SET superseded_by = ? 
+             WHERE conversation_id = ? AND id >= ? AND id < ? AND superseded_by IS NULL",
+        )
+        .bind(message_id)
+        .bind(conversation_id)
+        .bind(id)
+        .bind(message_id)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| ChatHistoryError::Database(e.to_string()))?;
+
+        let superseded = result.rows_affected() as i64;
+
+        sqlx::query("UPDATE chat_conversations SET updated_at = datetime('now') WHERE id = ?")
+            .bind(conversation_id)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| ChatHistoryError::Database(e.to_string()))?;
+
+        tx.commit()
+            .await
+            .map_err(|e| ChatHistoryError::Database(e.to_string()))?;
+
+        Ok(MessageRevision {
+            message_id,
+            superseded,
+        })
+    }
+
+    async fn get_message_versions(&self, id: i64) -> Result<Vec<Message>, ChatHistoryError> {
         let row =
-            sqlx::query("SELECT COUNT(*) as count FROM chat_messages WHERE conversation_id = ?")
-                .bind(conversation_id)
-                .fetch_one(&self.pool)
+            sqlx::query("SELECT COALESCE(version_of, id) AS root FROM chat_messages WHERE id = ?")
+                .bind(id)
+                .fetch_optional(&self.pool)
                 .await
-                .map_err(|e| ChatHistoryError::Database(e.to_string()))?;
+                .map_err(|e| ChatHistoryError::Database(e.to_string()))?
+                .ok_or(ChatHistoryError::MessageNotFound(id))?;
+
+        let root: i64 = row.get("root");
+
+        let rows = sqlx::query(&format!(
+            "SELECT {MESSAGE_COLUMNS} 
+             FROM chat_messages 
+             WHERE id = ? OR version_of = ? 
+             ORDER BY id ASC"
+        ))
+        .bind(root)
+        .bind(root)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| ChatHistoryError::Database(e.to_string()))?;
+
+        self.messages_from_rows(&rows)
+    }
+
+    async fn get_message_count(&self, conversation_id: i64) -> Result<i64, ChatHistoryError> {
+        let row = sqlx::query(
+            "SELECT COUNT(*) as count FROM chat_messages WHERE conversation_id = ? AND superseded_by IS NULL",
+        )
+        .bind(conversation_id)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| ChatHistoryError::Database(e.to_string()))?;
 
         Ok(row.get("count"))
     }
+
+    fn set_content_encryption(
+        &self,
+        key: Option<ChatContentKey>,
+        encrypt: bool,
+    ) -> Result<(), ChatHistoryError> {
+        let cipher = ContentCipher::new(key.as_ref(), encrypt)?;
+        *self.cipher.write().unwrap_or_else(PoisonError::into_inner) = cipher;
+        Ok(())
+    }
+
+    async fn reseal_messages(&self) -> Result<u64, ChatHistoryError> {
+        let cipher = self.cipher().clone();
+        // Only rows in the wrong form need rewriting
+        let op = if cipher.encrypts() { "<>" } else { "=" };
+        let len = SEALED_PREFIX.len();
+
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| ChatHistoryError::Database(e.to_string()))?;
+
+        let rows = sqlx::query(&format!(
+            "SELECT id, content FROM chat_messages WHERE substr(content, 1, {len}) {op} ?"
+        ))
+        .bind(SEALED_PREFIX)
+        .fetch_all(&mut *tx)
+        .await
+        .map_err(|e| ChatHistoryError::Database(e.to_string()))?;
+
+        let mut rewritten = 0;
+        for row in &rows {
+            let id: i64 = row.get("id");
+            let stored: String = row.get("content");
+            let content = cipher.seal(&cipher.open(stored)?)?;
+            sqlx::query("UPDATE chat_messages SET content = ? WHERE id = ?")
+                .bind(&content)
+                .bind(id)
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| ChatHistoryError::Database(e.to_string()))?;
+            rewritten += 1;
+        }
+
+        tx.commit()
+            .await
+            .map_err(|e| ChatHistoryError::Database(e.to_string()))?;
+
+        Ok(rewritten)
+    }
+
+    async fn import_conversation(
+        &self,
+        conversation: &ImportedConversation,
+    ) -> Result<i64, ChatHistoryError> {
+        let cipher = self.cipher().clone();
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| ChatHistoryError::Database(e.to_string()))?;
+
+        let id = sqlx::query(
+            "INSERT INTO chat_conversations (title, system_prompt, created_at, updated_at)
+             VALUES (?, ?, ?, ?)",
+        )
+        .bind(&conversation.title)
+        .bind(&conversation.system_prompt)
+        .bind(sqlite_time(conversation.created_at))
+        .bind(sqlite_time(conversation.updated_at))
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| ChatHistoryError::Database(e.to_string()))?
+        .last_insert_rowid();
+
+        for message in &conversation.messages {
+            let metadata_str = message
+                .metadata
+                .as_ref()
+                .map(|m| serde_json::to_string(m).unwrap_or_default());
+            sqlx::query(
+                "INSERT INTO chat_messages (conversation_id, role, content, metadata, created_at)
+                 VALUES (?, ?, ?, ?, ?)",
+            )
+            .bind(id)
+            .bind(message.role.as_str())
+            .bind(cipher.seal(&message.content)?)
+            .bind(&metadata_str)
+            .bind(sqlite_time(message.created_at))
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| ChatHistoryError::Database(e.to_string()))?;
+        }
+
+        tx.commit()
+            .await
+            .map_err(|e| ChatHistoryError::Database(e.to_string()))?;
+
+        Ok(id)
+    }
+}
+
+/// `time` in the form `datetime('now')` writes, so imported rows sort with
+/// the rest.
+fn sqlite_time(time: DateTime<Utc>) -> String {
+    time.format("%Y-%m-%d %H:%M:%S").to_string()
 }
 
 #[cfg(test)]
@@ -324,6 +602,7 @@ mod tests {
             model_id: None,
             system_prompt: None,
             settings: None,
+            incognito: false,
         }
     }
 
@@ -435,4 +714,195 @@ mod tests {
         assert_eq!(removed, 2);
         assert_eq!(repo.get_messages(cid).await.unwrap().len(), 1);
     }
+
+    #[tokio::test]
+    async fn supersede_message_keeps_earlier_versions() {
+        let repo = repo().await;
+        let cid = repo.create_conversation(make_conv("Regen")).await.unwrap();
+        repo.save_message(make_msg(cid, "Q")).await.unwrap();
+        let first = repo.save_message(make_msg(cid, "A1")).await.unwrap();
+
+        let second = repo
+            .supersede_message(first, "A2".to_string(), None)
+            .await
+            .unwrap();
+        let third = repo
+            .supersede_message(second.message_id, "A3".to_string(), None)
+            .await
+            .unwrap();
+        assert_eq!(third.superseded, 1);
+
+        let active = repo.get_messages(cid).await.unwrap();
+        assert_eq!(active.len(), 2);
+        assert_eq!(active[1].content, "A3");
+
+        let versions = repo.get_message_versions(first).await.unwrap();
+        let contents: Vec<_> = versions.iter().map(|m| m.content.as_str()).collect();
+        assert_eq!(contents, ["A1", "A2", "A3"]);
+        assert_eq!(versions[2].version_of, Some(first));
+        assert_eq!(versions[0].superseded_by, Some(second.message_id));
+
+        let again = repo.supersede_message(first, "A4".to_string(), None).await;
+        assert!(matches!(again, Err(ChatHistoryError::MessageSuperseded(_))));
+    }
+
+    #[tokio::test]
+    async fn supersede_message_invalidates_later_messages() {
+        let repo = repo().await;
+        let cid = repo.create_conversation(make_conv("Edit")).await.unwrap();
+        repo.save_message(make_msg(cid, "Q1")).await.unwrap();
+        repo.save_message(make_msg(cid, "A1")).await.unwrap();
+        let q2 = repo.save_message(make_msg(cid, "Q2")).await.unwrap();
+        repo.save_message(make_msg(cid, "A2")).await.unwrap();
+
+        let revision = repo
+            .supersede_message(q2, "Q2 edited".to_string(), None)
+            .await
+            .unwrap();
+        assert_eq!(revision.superseded, 2);
+
+        let contents: Vec<_> = repo
+            .get_messages(cid)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|m| m.content)
+            .collect();
+        assert_eq!(contents, ["Q1", "A1", "Q2 edited"]);
+        assert_eq!(repo.get_message_count(cid).await.unwrap(), 3);
+    }
+
+    async fn raw_content(repo: &SqliteChatHistoryRepository, id: i64) -> String {
+        sqlx::query("SELECT content FROM chat_messages WHERE id = ?")
+            .bind(id)
+            .fetch_one(&repo.pool)
+            .await
+            .unwrap()
+            .get("content")
+    }
+
+    #[tokio::test]
+    async fn encrypted_content_round_trips() {
+        let repo = repo().await;
+        let cid = repo.create_conversation(make_conv("Secret")).await.unwrap();
+        let key = ChatContentKey::generate().unwrap();
+        repo.set_content_encryption(Some(key), true).unwrap();
+
+        let mid = repo
+            .save_message(make_msg(cid, "my diagnosis"))
+            .await
+            .unwrap();
+        let raw = raw_content(&repo, mid).await;
+        assert!(raw.starts_with(SEALED_PREFIX));
+        assert!(!raw.contains("diagnosis"));
+        assert_eq!(
+            repo.get_messages(cid).await.unwrap()[0].content,
+            "my diagnosis"
+        );
+
+        let revision = repo
+            .supersede_message(mid, "edited".to_string(), None)
+            .await
+            .unwrap();
+        let raw = raw_content(&repo, revision.message_id).await;
+        assert!(raw.starts_with(SEALED_PREFIX));
+        let versions = repo.get_message_versions(mid).await.unwrap();
+        let contents: Vec<_> = versions.iter().map(|m| m.content.as_str()).collect();
+        assert_eq!(contents, ["my diagnosis", "edited"]);
+    }
+
+    #[tokio::test]
+    async fn reseal_migrates_both_ways() {
+        let repo = repo().await;
+        let cid = repo.create_conversation(make_conv("Old")).await.unwrap();
+        let plain = repo.save_message(make_msg(cid, "before")).await.unwrap();
+
+        // Enabling leaves old rows readable, then reseal encrypts them
+        let key = ChatContentKey::generate().unwrap();
+        repo.set_content_encryption(Some(key.clone()), true)
+            .unwrap();
+        assert_eq!(repo.get_messages(cid).await.unwrap()[0].content, "before");
+        repo.save_message(make_msg(cid, "after")).await.unwrap();
+        assert_eq!(repo.reseal_messages().await.unwrap(), 1);
+        assert_eq!(repo.reseal_messages().await.unwrap(), 0);
+        let raw = raw_content(&repo, plain).await;
+        assert!(raw.starts_with(SEALED_PREFIX));
+
+        // Disabling with the key still loaded decrypts everything
+        repo.set_content_encryption(Some(key), false).unwrap();
+        assert_eq!(repo.reseal_messages().await.unwrap(), 2);
+        assert_eq!(raw_content(&repo, plain).await, "before");
+        let contents: Vec<_> = repo
+            .get_messages(cid)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|m| m.content)
+            .collect();
+        assert_eq!(contents, ["before", "after"]);
+    }
+
+    #[tokio::test]
+    async fn sealed_content_needs_the_right_key() {
+        let repo = repo().await;
+        let cid = repo.create_conversation(make_conv("Keys")).await.unwrap();
+        repo.set_content_encryption(Some(ChatContentKey::generate().unwrap()), true)
+            .unwrap();
+        let mid = repo.save_message(make_msg(cid, "hidden")).await.unwrap();
+
+        repo.set_content_encryption(None, false).unwrap();
+        assert!(matches!(
+            repo.get_message(mid).await,
+            Err(ChatHistoryError::Encryption(_))
+        ));
+        repo.set_content_encryption(Some(ChatContentKey::generate().unwrap()), false)
+            .unwrap();
+        assert!(matches!(
+            repo.get_messages(cid).await,
+            Err(ChatHistoryError::Encryption(_))
+        ));
+        assert!(repo.set_content_encryption(None, true).is_err());
+    }
+
+    #[tokio::test]
+    async fn imported_conversations_keep_their_timestamps() {
+        use gglib_core::domain::chat_import::ImportedMessage;
+
+        let repo = repo().await;
+        repo.create_conversation(make_conv("Today")).await.unwrap();
+        let at = |s: &str| s.parse::<DateTime<Utc>>().unwrap();
+        let imported = ImportedConversation {
+            title: "From 2023".to_string(),
+            system_prompt: Some("Be brief".to_string()),
+            created_at: at("2023-03-01T10:00:00Z"),
+            updated_at: at("2023-03-01T10:05:00Z"),
+            messages: vec![
+                ImportedMessage {
+                    role: MessageRole::User,
+                    content: "Hi".to_string(),
+                    created_at: at("2023-03-01T10:00:01Z"),
+                    metadata: Some(serde_json::json!({"imported_attachments": []})),
+                },
+                ImportedMessage {
+                    role: MessageRole::Assistant,
+                    content: "Hello".to_string(),
+                    created_at: at("2023-03-01T10:00:02Z"),
+                    metadata: None,
+                },
+            ],
+        };
+        let id = repo.import_conversation(&imported).await.unwrap();
+
+        let conv = repo.get_conversation(id).await.unwrap().unwrap();
+        assert_eq!(conv.created_at, "2023-03-01 10:00:00");
+        assert_eq!(conv.updated_at, "2023-03-01 10:05:00");
+        assert_eq!(conv.system_prompt.as_deref(), Some("Be brief"));
+        let messages = repo.get_messages(id).await.unwrap();
+        assert_eq!(messages.len(), 2);
+        assert_eq!(messages[1].content, "Hello");
+        assert_eq!(messages[0].created_at, "2023-03-01 10:00:01");
+        assert!(messages[0].metadata.is_some());
+        // Older than anything created today, so it lists last.
+        assert_eq!(repo.list_conversations().await.unwrap()[1].id, id);
+    }
 }
@@ -0,0 +1,187 @@
+//! `SQLite` implementation of [`ConversationShareRepository`].
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{Row, SqlitePool, sqlite::SqliteRow};
+
+use gglib_core::domain::chat_share::ShareRecord;
+use gglib_core::ports::{ChatHistoryError, ConversationShareRepository};
+
+/// `SQLite` implementation of [`ConversationShareRepository`].
+///
+/// Shares live in `conversation_shares` and go away with their
+/// conversation.
+pub struct SqliteConversationShareRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteConversationShareRepository {
+    /// Create a new conversation share repository from a shared connection pool.
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+}
+
+fn db_err(e: &sqlx::Error) -> ChatHistoryError {
+    ChatHistoryError::Database(e.to_string())
+}
+
+fn share_from_row(row: &SqliteRow) -> ShareRecord {
+    ShareRecord {
+        id: row.get("id"),
+        conversation_id: row.get("conversation_id"),
+        created_at: row.get("created_at"),
+        expires_at: row.get("expires_at"),
+    }
+}
+
+#[async_trait]
+impl ConversationShareRepository for SqliteConversationShareRepository {
+    async fn create(
+        &self,
+        conversation_id: i64,
+        expires_at: DateTime<Utc>,
+    ) -> Result<ShareRecord, ChatHistoryError> {
+        let created_at = Utc::now();
+        let result = sqlx::query(
+            "INSERT INTO conversation_shares (conversation_id, created_at, expires_at)
+             VALUES (?, ?, ?)",
+        )
+        .bind(conversation_id)
+        .bind(created_at)
+        .bind(expires_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| db_err(&e))?;
+
+        Ok(ShareRecord {
+            id: result.last_insert_rowid(),
+            conversation_id,
+            created_at,
+            expires_at,
+        })
+    }
+
+    async fn get(&self, id: i64) -> Result<Option<ShareRecord>, ChatHistoryError> {
+        let row = sqlx::query(
+            "SELECT id, conversation_id, created_at, expires_at
+             FROM conversation_shares WHERE id = ?",
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| db_err(&e))?;
+        Ok(row.as_ref().map(share_from_row))
+    }
+
+    async fn list(&self, conversation_id: i64) -> Result<Vec<ShareRecord>, ChatHistoryError> {
+        let rows = sqlx::query(
+            "SELECT id, conversation_id, created_at, expires_at
+             FROM conversation_shares WHERE conversation_id = ? ORDER BY id DESC",
+        )
+        .bind(conversation_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| db_err(&e))?;
+        Ok(rows.iter().map(share_from_row).collect())
+    }
+
+    async fn delete(&self, id: i64) -> Result<bool, ChatHistoryError> {
+        let result = sqlx::query("DELETE FROM conversation_shares WHERE id = ?")
+            .bind(id)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| db_err(&e))?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn delete_expired(&self, now: DateTime<Utc>) -> Result<u64, ChatHistoryError> {
+        let result = sqlx::query("DELETE FROM conversation_shares WHERE expires_at <= ?")
+            .bind(now)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| db_err(&e))?;
+        Ok(result.rows_affected())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use chrono::Duration;
+    use gglib_core::domain::chat::{MessageRole, NewMessage};
+    use gglib_core::ports::MemorySecretStore;
+    use gglib_core::services::ChatHistoryService;
+
+    use crate::repositories::SqliteChatHistoryRepository;
+    use crate::setup::setup_test_database;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn share_links_open_until_revoked_or_expired() {
+        let pool = setup_test_database().await.expect("setup_test_database");
+        let shares = Arc::new(SqliteConversationShareRepository::new(pool.clone()));
+        let history =
+            ChatHistoryService::new(Arc::new(SqliteChatHistoryRepository::new(pool.clone())))
+                .with_secrets(Arc::new(MemorySecretStore::default()))
+                .with_shares(shares.clone());
+
+        let id = history
+            .create_conversation("Lunch plans".to_string(), None, Some("secret".to_string()))
+            .await
+            .unwrap();
+        for (role, content) in [
+            (MessageRole::User, "Where should we eat?"),
+            (MessageRole::Assistant, "Try the noodle bar."),
+        ] {
+            history
+                .save_message(NewMessage {
+                    conversation_id: id,
+                    role,
+                    content: content.to_string(),
+                    metadata: None,
+                })
+                .await
+                .unwrap();
+        }
+
+        let share = history.create_share(id, None).await.unwrap();
+        let shared = history.open_share(&share.token).await.unwrap();
+        assert_eq!(shared.title, "Lunch plans");
+        assert_eq!(shared.messages.len(), 2);
+        assert_eq!(shared.messages[1].content, "Try the noodle bar.");
+        assert_eq!(history.list_shares(id).await.unwrap(), vec![share.clone()]);
+
+        // A tampered token never reaches the database.
+        let forged = format!("{}.AAAA", share.record.id);
+        assert!(matches!(
+            history.open_share(&forged).await,
+            Err(ChatHistoryError::ShareNotFound)
+        ));
+
+        history.revoke_share(share.record.id).await.unwrap();
+        assert!(matches!(
+            history.open_share(&share.token).await,
+            Err(ChatHistoryError::ShareNotFound)
+        ));
+
+        let stale = shares
+            .create(id, Utc::now() - Duration::minutes(1))
+            .await
+            .unwrap();
+        let stale_token = history.list_shares(id).await.unwrap()[0].token.clone();
+        assert!(matches!(
+            history.open_share(&stale_token).await,
+            Err(ChatHistoryError::ShareNotFound)
+        ));
+        history.create_share(id, Some(1)).await.unwrap();
+        assert_eq!(shares.get(stale.id).await.unwrap(), None);
+
+        assert!(matches!(
+            history.create_share(id, Some(0)).await,
+            Err(ChatHistoryError::NotShareable(_))
+        ));
+    }
+}
@@ -4,8 +4,9 @@ use async_trait::async_trait;
 use sqlx::SqlitePool;
 
 use gglib_core::{
-    DownloadId, DownloadStateRepositoryPort, DownloadStatus, Quantization, QueuedDownload,
-    RepositoryError, ShardInfo,
+    AttemptCounts, CompletionDetail, CompletionKey, CompletionKind, DownloadHistoryFilter,
+    DownloadId, DownloadStateRepositoryPort, DownloadStatus, Quantization, QueueRunSummary,
+    QueuedDownload, RepositoryError, ShardInfo,
 };
 
 /// `SQLite` implementation of the `DownloadStateRepositoryPort` trait.
@@ -30,6 +31,7 @@ impl SqliteDownloadStateRepository {
 
 #[async_trait]
 impl DownloadStateRepositoryPort for SqliteDownloadStateRepository {
+    #[tracing::instrument(name = "db.downloads.enqueue", skip_all)]
     async fn enqueue(&self, download: &QueuedDownload) -> Result<(), RepositoryError> {
         let quantization = download.quantization.as_ref().map(|q| q.to_string());
         let shard_info_json = download
@@ -71,6 +73,7 @@ impl DownloadStateRepositoryPort for SqliteDownloadStateRepository {
         Ok(())
     }
 
+    #[tracing::instrument(name = "db.downloads.update_status", skip_all)]
     async fn update_status(
         &self,
         id: &DownloadId,
@@ -100,6 +103,7 @@ impl DownloadStateRepositoryPort for SqliteDownloadStateRepository {
         Ok(())
     }
 
+    #[tracing::instrument(name = "db.downloads.load_queue", skip_all)]
     async fn load_queue(&self) -> Result<Vec<QueuedDownload>, RepositoryError> {
         let rows = sqlx::query(
             r#"
@@ -118,6 +122,7 @@ impl DownloadStateRepositoryPort for SqliteDownloadStateRepository {
         rows.iter().map(row_to_queued_download).collect()
     }
 
+    #[tracing::instrument(name = "db.downloads.mark_failed", skip_all)]
     async fn mark_failed(
         &self,
         id: &DownloadId,
@@ -148,6 +153,7 @@ impl DownloadStateRepositoryPort for SqliteDownloadStateRepository {
         Ok(())
     }
 
+    #[tracing::instrument(name = "db.downloads.remove", skip_all)]
     async fn remove(&self, id: &DownloadId) -> Result<(), RepositoryError> {
         sqlx::query(
             r#"
@@ -162,6 +168,7 @@ impl DownloadStateRepositoryPort for SqliteDownloadStateRepository {
         Ok(())
     }
 
+    #[tracing::instrument(name = "db.downloads.prune_completed", skip_all)]
     async fn prune_completed(&self, older_than_days: u32) -> Result<u32, RepositoryError> {
         let result = sqlx::query(
             r#"
@@ -177,6 +184,205 @@ impl DownloadStateRepositoryPort for SqliteDownloadStateRepository {
 
         Ok(result.rows_affected() as u32)
     }
+
+    #[tracing::instrument(name = "db.downloads.record_run", skip_all)]
+    async fn record_run(&self, run: &QueueRunSummary) -> Result<(), RepositoryError> {
+        let storage = |e: sqlx::Error| RepositoryError::Storage(e.to_string());
+        let mut tx = self.pool.begin().await.map_err(storage)?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO download_runs (
+                run_id, started_at_ms, completed_at_ms,
+                total_attempts_downloaded, total_attempts_failed, total_attempts_cancelled,
+                unique_models_downloaded, unique_models_failed, unique_models_cancelled
+            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)
+            "#,
+        )
+        .bind(run.run_id.to_string())
+        .bind(run.started_at_ms as i64)
+        .bind(run.completed_at_ms as i64)
+        .bind(run.total_attempts_downloaded)
+        .bind(run.total_attempts_failed)
+        .bind(run.total_attempts_cancelled)
+        .bind(run.unique_models_downloaded)
+        .bind(run.unique_models_failed)
+        .bind(run.unique_models_cancelled)
+        .execute(&mut *tx)
+        .await
+        .map_err(storage)?;
+
+        for item in &run.items {
+            let key = serde_json::to_string(&item.key)
+                .map_err(|e| RepositoryError::Serialization(e.to_string()))?;
+            let download_ids = serde_json::to_string(&item.download_ids)
+                .map_err(|e| RepositoryError::Serialization(e.to_string()))?;
+
+            sqlx::query(
+                r#"
+                INSERT INTO download_history (
+                    run_id, completion_key, display_name, last_result, completed_at_ms,
+                    download_ids, attempts_downloaded, attempts_failed, attempts_cancelled
+                ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)
+                "#,
+            )
+            .bind(run.run_id.to_string())
+            .bind(&key)
+            .bind(&item.display_name)
+            .bind(item.last_result.as_str())
+            .bind(item.last_completed_at_ms as i64)
+            .bind(&download_ids)
+            .bind(item.attempt_counts.downloaded)
+            .bind(item.attempt_counts.failed)
+            .bind(item.attempt_counts.cancelled)
+            .execute(&mut *tx)
+            .await
+            .map_err(storage)?;
+        }
+
+        tx.commit().await.map_err(storage)
+    }
+
+    #[tracing::instrument(name = "db.downloads.load_history", skip_all)]
+    async fn load_history(
+        &self,
+        filter: &DownloadHistoryFilter,
+    ) -> Result<Vec<QueueRunSummary>, RepositoryError> {
+        let result = filter.result.map(|kind| kind.as_str());
+        let search = filter.search.as_deref();
+
+        // ?3 and ?4 are the record filters; a run qualifies when any of its
+        // records passes them, and only passing records are returned.
+        let runs = sqlx::query(
+            r#"
+            SELECT run_id, started_at_ms, completed_at_ms,
+                   total_attempts_downloaded, total_attempts_failed, total_attempts_cancelled,
+                   unique_models_downloaded, unique_models_failed, unique_models_cancelled
+            FROM download_runs r
+            WHERE (?1 IS NULL OR r.completed_at_ms >= ?1)
+              AND (?2 IS NULL OR r.completed_at_ms <= ?2)
+              AND ((?3 IS NULL AND ?4 IS NULL) OR EXISTS (
+                  SELECT 1 FROM download_history h
+                  WHERE h.run_id = r.run_id
+                    AND (?3 IS NULL OR h.last_result = ?3)
+                    AND (?4 IS NULL OR instr(lower(h.display_name), lower(?4)) > 0)
+              ))
+            ORDER BY r.completed_at_ms DESC
+            LIMIT ?5
+            "#,
+        )
+        .bind(filter.since_ms.map(|t| t as i64))
+        .bind(filter.until_ms.map(|t| t as i64))
+        .bind(result)
+        .bind(search)
+        .bind(i64::from(filter.limit()))
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| RepositoryError::Storage(e.to_string()))?;
+
+        let mut summaries = Vec::with_capacity(runs.len());
+        for run in &runs {
+            let mut summary = row_to_run_summary(run)?;
+            let records = sqlx::query(
+                r#"
+                SELECT completion_key, display_name, last_result, completed_at_ms,
+                       download_ids, attempts_downloaded, attempts_failed, attempts_cancelled
+                FROM download_history
+                WHERE run_id = ?1
+                  AND (?2 IS NULL OR last_result = ?2)
+                  AND (?3 IS NULL OR instr(lower(display_name), lower(?3)) > 0)
+                ORDER BY completed_at_ms DESC, id ASC
+                "#,
+            )
+            .bind(summary.run_id.to_string())
+            .bind(result)
+            .bind(search)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| RepositoryError::Storage(e.to_string()))?;
+
+            summary.items = records
+                .iter()
+                .map(row_to_completion_detail)
+                .collect::<Result<_, _>>()?;
+            summaries.push(summary);
+        }
+
+        Ok(summaries)
+    }
+}
+
+/// Convert a `download_runs` row to a `QueueRunSummary` without its records.
+fn row_to_run_summary(row: &sqlx::sqlite::SqliteRow) -> Result<QueueRunSummary, RepositoryError> {
+    use sqlx::Row;
+
+    let run_id: String = row.try_get("run_id").map_err(map_column_error)?;
+    let run_id = uuid::Uuid::parse_str(&run_id)
+        .map_err(|e| RepositoryError::Serialization(format!("Invalid run id: {}", e)))?;
+    let started_at_ms: i64 = row.try_get("started_at_ms").map_err(map_column_error)?;
+    let completed_at_ms: i64 = row.try_get("completed_at_ms").map_err(map_column_error)?;
+
+    Ok(QueueRunSummary {
+        run_id,
+        started_at_ms: started_at_ms as u64,
+        completed_at_ms: completed_at_ms as u64,
+        total_attempts_downloaded: row
+            .try_get("total_attempts_downloaded")
+            .map_err(map_column_error)?,
+        total_attempts_failed: row
+            .try_get("total_attempts_failed")
+            .map_err(map_column_error)?,
+        total_attempts_cancelled: row
+            .try_get("total_attempts_cancelled")
+            .map_err(map_column_error)?,
+        unique_models_downloaded: row
+            .try_get("unique_models_downloaded")
+            .map_err(map_column_error)?,
+        unique_models_failed: row
+            .try_get("unique_models_failed")
+            .map_err(map_column_error)?,
+        unique_models_cancelled: row
+            .try_get("unique_models_cancelled")
+            .map_err(map_column_error)?,
+        truncated: false,
+        items: Vec::new(),
+    })
+}
+
+/// Convert a `download_history` row to a `CompletionDetail`.
+fn row_to_completion_detail(
+    row: &sqlx::sqlite::SqliteRow,
+) -> Result<CompletionDetail, RepositoryError> {
+    use sqlx::Row;
+
+    let key_json: String = row.try_get("completion_key").map_err(map_column_error)?;
+    let key: CompletionKey = serde_json::from_str(&key_json)
+        .map_err(|e| RepositoryError::Serialization(e.to_string()))?;
+    let last_result: String = row.try_get("last_result").map_err(map_column_error)?;
+    let last_result = CompletionKind::parse(&last_result).ok_or_else(|| {
+        RepositoryError::Serialization(format!("Unknown completion result '{}'", last_result))
+    })?;
+    let completed_at_ms: i64 = row.try_get("completed_at_ms").map_err(map_column_error)?;
+    let download_ids: String = row.try_get("download_ids").map_err(map_column_error)?;
+    let download_ids: Vec<DownloadId> = serde_json::from_str(&download_ids)
+        .map_err(|e| RepositoryError::Serialization(e.to_string()))?;
+
+    Ok(CompletionDetail {
+        key,
+        display_name: row.try_get("display_name").map_err(map_column_error)?,
+        last_result,
+        last_completed_at_ms: completed_at_ms as u64,
+        download_ids,
+        attempt_counts: AttemptCounts {
+            downloaded: row
+                .try_get("attempts_downloaded")
+                .map_err(map_column_error)?,
+            failed: row.try_get("attempts_failed").map_err(map_column_error)?,
+            cancelled: row
+                .try_get("attempts_cancelled")
+                .map_err(map_column_error)?,
+        },
+    })
 }
 
 /// Convert a database row to a `QueuedDownload`.
@@ -220,11 +426,12 @@ fn row_to_queued_download(
         position: position as u32,
         downloaded_bytes: downloaded_bytes as u64,
         total_bytes: total_bytes as u64,
-        // Neither is persisted: they are live readings from the download
+        // None of these is persisted: they are live readings from the download
         // manager's rate estimator, and a restored row has no transfer in
         // flight to measure. None means unknown, which is exactly right here.
         speed_bps: None,
         eta_seconds: None,
+        elapsed_seconds: None,
         progress_percent,
         queued_at: queued_at as u64,
         started_at: started_at.map(|t| t as u64),
@@ -268,9 +475,80 @@ mod tests {
         .await
         .unwrap();
 
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS download_runs (
+                run_id TEXT PRIMARY KEY NOT NULL,
+                started_at_ms INTEGER NOT NULL,
+                completed_at_ms INTEGER NOT NULL,
+                total_attempts_downloaded INTEGER NOT NULL DEFAULT 0,
+                total_attempts_failed INTEGER NOT NULL DEFAULT 0,
+                total_attempts_cancelled INTEGER NOT NULL DEFAULT 0,
+                unique_models_downloaded INTEGER NOT NULL DEFAULT 0,
+                unique_models_failed INTEGER NOT NULL DEFAULT 0,
+                unique_models_cancelled INTEGER NOT NULL DEFAULT 0
+            )
+            "#,
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS download_history (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                run_id TEXT NOT NULL,
+                completion_key TEXT NOT NULL,
+                display_name TEXT NOT NULL,
+                last_result TEXT NOT NULL,
+                completed_at_ms INTEGER NOT NULL,
+                download_ids TEXT NOT NULL DEFAULT '[]',
+                attempts_downloaded INTEGER NOT NULL DEFAULT 0,
+                attempts_failed INTEGER NOT NULL DEFAULT 0,
+                attempts_cancelled INTEGER NOT NULL DEFAULT 0
+            )
+            "#,
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+
         pool
     }
 
+    fn completion(name: &str, last_result: CompletionKind, at_ms: u64) -> CompletionDetail {
+        CompletionDetail {
+            key: CompletionKey::HfFile {
+                repo_id: format!("org/{name}"),
+                revision: "main".to_string(),
+                filename_canon: format!("{name}.gguf"),
+                quantization: Some("Q4_K_M".to_string()),
+            },
+            display_name: format!("org/{name} (Q4_K_M)"),
+            last_result,
+            last_completed_at_ms: at_ms,
+            download_ids: vec![DownloadId::new(format!("org/{name}"), Some("Q4_K_M"))],
+            attempt_counts: AttemptCounts::from_kind(last_result),
+        }
+    }
+
+    fn run(completed_at_ms: u64, items: Vec<CompletionDetail>) -> QueueRunSummary {
+        QueueRunSummary {
+            run_id: uuid::Uuid::new_v4(),
+            started_at_ms: completed_at_ms - 1_000,
+            completed_at_ms,
+            total_attempts_downloaded: 0,
+            total_attempts_failed: 0,
+            total_attempts_cancelled: 0,
+            unique_models_downloaded: 0,
+            unique_models_failed: 0,
+            unique_models_cancelled: 0,
+            truncated: false,
+            items,
+        }
+    }
+
     #[tokio::test]
     async fn test_enqueue_and_load() {
         let pool = setup_test_db().await;
@@ -358,4 +636,89 @@ mod tests {
 
         assert_eq!(repo.prune_completed(0).await.unwrap(), 1);
     }
+
+    #[tokio::test]
+    async fn recorded_runs_load_back_newest_first() {
+        let repo = SqliteDownloadStateRepository::new(setup_test_db().await);
+
+        let mut retried = completion("llama", CompletionKind::Downloaded, 1_500);
+        retried.attempt_counts = AttemptCounts {
+            downloaded: 1,
+            failed: 2,
+            cancelled: 0,
+        };
+        let older = run(2_000, vec![retried.clone()]);
+        let newer = run(
+            5_000,
+            vec![
+                completion("qwen", CompletionKind::Failed, 4_900),
+                completion("phi", CompletionKind::Cancelled, 4_000),
+            ],
+        );
+        repo.record_run(&older).await.unwrap();
+        repo.record_run(&newer).await.unwrap();
+
+        let history = repo
+            .load_history(&DownloadHistoryFilter::default())
+            .await
+            .unwrap();
+        assert_eq!(history, vec![newer, older]);
+        assert_eq!(history[1].items[0].attempt_counts.failed, 2);
+    }
+
+    #[tokio::test]
+    async fn history_filters_runs_and_records() {
+        let repo = SqliteDownloadStateRepository::new(setup_test_db().await);
+        repo.record_run(&run(
+            2_000,
+            vec![completion("llama", CompletionKind::Downloaded, 1_500)],
+        ))
+        .await
+        .unwrap();
+        repo.record_run(&run(
+            5_000,
+            vec![
+                completion("Llama-Big", CompletionKind::Failed, 4_900),
+                completion("qwen", CompletionKind::Failed, 4_000),
+            ],
+        ))
+        .await
+        .unwrap();
+
+        let failed_llamas = repo
+            .load_history(&DownloadHistoryFilter {
+                result: Some(CompletionKind::Failed),
+                search: Some("LLAMA".to_string()),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        assert_eq!(failed_llamas.len(), 1);
+        assert_eq!(failed_llamas[0].items.len(), 1);
+        assert_eq!(
+            failed_llamas[0].items[0].display_name,
+            "org/Llama-Big (Q4_K_M)"
+        );
+
+        let early = repo
+            .load_history(&DownloadHistoryFilter {
+                until_ms: Some(3_000),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        assert_eq!(early.len(), 1);
+        assert_eq!(early[0].completed_at_ms, 2_000);
+
+        let latest = repo
+            .load_history(&DownloadHistoryFilter {
+                since_ms: Some(1_000),
+                limit: Some(1),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        assert_eq!(latest.len(), 1);
+        assert_eq!(latest[0].completed_at_ms, 5_000);
+    }
 }
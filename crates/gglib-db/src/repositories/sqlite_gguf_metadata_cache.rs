@@ -0,0 +1,202 @@
+//! `SQLite` implementation of [`GgufMetadataCache`].
+
+use std::collections::HashMap;
+use std::path::Path;
+
+use async_trait::async_trait;
+use sqlx::{Row, SqlitePool};
+
+use gglib_core::ports::{FileStamp, GgufMetadata, GgufMetadataCache, RepositoryError};
+
+/// `SQLite` implementation of [`GgufMetadataCache`].
+///
+/// Stores one `gguf_metadata_cache` row per file path. A row whose size or
+/// modification time no longer matches the file is never returned, and is
+/// overwritten the next time the file is parsed.
+pub struct SqliteGgufMetadataCache {
+    pool: SqlitePool,
+}
+
+impl SqliteGgufMetadataCache {
+    /// Create a new metadata cache from a shared connection pool.
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+}
+
+fn storage(e: &sqlx::Error) -> RepositoryError {
+    RepositoryError::Storage(e.to_string())
+}
+
+#[async_trait]
+impl GgufMetadataCache for SqliteGgufMetadataCache {
+    async fn get(
+        &self,
+        path: &Path,
+        stamp: FileStamp,
+    ) -> Result<Option<GgufMetadata>, RepositoryError> {
+        let row = sqlx::query(
+            r#"
+            SELECT name, architecture, quantization, param_count_b, context_length,
+                   expert_count, expert_used_count, expert_shared_count, metadata
+            FROM gguf_metadata_cache
+            WHERE path = ? AND size = ? AND modified_ms = ?
+            "#,
+        )
+        .bind(path.to_string_lossy())
+        .bind(stamp.size as i64)
+        .bind(stamp.modified_ms)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| storage(&e))?;
+
+        let Some(row) = row else {
+            return Ok(None);
+        };
+        let metadata: String = row.get("metadata");
+        let metadata: HashMap<String, String> = serde_json::from_str(&metadata)
+            .map_err(|e| RepositoryError::Serialization(e.to_string()))?;
+        Ok(Some(GgufMetadata {
+            name: row.get("name"),
+            architecture: row.get("architecture"),
+            quantization: row.get("quantization"),
+            param_count_b: row.get("param_count_b"),
+            context_length: row
+                .get::<Option<i64>, _>("context_length")
+                .map(|n| n as u64),
+            expert_count: row.get::<Option<i64>, _>("expert_count").map(|n| n as u32),
+            expert_used_count: row
+                .get::<Option<i64>, _>("expert_used_count")
+                .map(|n| n as u32),
+            expert_shared_count: row
+                .get::<Option<i64>, _>("expert_shared_count")
+                .map(|n| n as u32),
+            metadata,
+        }))
+    }
+
+    async fn put(
+        &self,
+        path: &Path,
+        stamp: FileStamp,
+        metadata: &GgufMetadata,
+    ) -> Result<(), RepositoryError> {
+        let blob = serde_json::to_string(&metadata.metadata)
+            .map_err(|e| RepositoryError::Serialization(e.to_string()))?;
+        sqlx::query(
+            r#"
+            INSERT INTO gguf_metadata_cache
+                (path, size, modified_ms, name, architecture, quantization, param_count_b,
+                 context_length, expert_count, expert_used_count, expert_shared_count, metadata)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+            ON CONFLICT (path) DO UPDATE SET
+                size = excluded.size,
+                modified_ms = excluded.modified_ms,
+                name = excluded.name,
+                architecture = excluded.architecture,
+                quantization = excluded.quantization,
+                param_count_b = excluded.param_count_b,
+                context_length = excluded.context_length,
+                expert_count = excluded.expert_count,
+                expert_used_count = excluded.expert_used_count,
+                expert_shared_count = excluded.expert_shared_count,
+                metadata = excluded.metadata
+            "#,
+        )
+        .bind(path.to_string_lossy())
+        .bind(stamp.size as i64)
+        .bind(stamp.modified_ms)
+        .bind(&metadata.name)
+        .bind(&metadata.architecture)
+        .bind(&metadata.quantization)
+        .bind(metadata.param_count_b)
+        .bind(metadata.context_length.map(|n| n as i64))
+        .bind(metadata.expert_count.map(i64::from))
+        .bind(metadata.expert_used_count.map(i64::from))
+        .bind(metadata.expert_shared_count.map(i64::from))
+        .bind(blob)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| storage(&e))?;
+        Ok(())
+    }
+
+    async fn remove(&self, path: &Path) -> Result<(), RepositoryError> {
+        sqlx::query("DELETE FROM gguf_metadata_cache WHERE path = ?")
+            .bind(path.to_string_lossy())
+            .execute(&self.pool)
+            .await
+            .map_err(|e| storage(&e))?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::setup::setup_test_database;
+
+    use super::*;
+
+    const STAMP: FileStamp = FileStamp {
+        size: 4_368_439_584,
+        modified_ms: 1_760_000_000_000,
+    };
+
+    fn metadata() -> GgufMetadata {
+        GgufMetadata {
+            name: Some("Qwen2.5 7B Instruct".to_string()),
+            architecture: Some("qwen2".to_string()),
+            quantization: Some("Q4_K_M".to_string()),
+            param_count_b: Some(7.6),
+            context_length: Some(32_768),
+            metadata: HashMap::from([("general.architecture".to_string(), "qwen2".to_string())]),
+            ..GgufMetadata::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn returns_an_entry_only_for_the_stamp_it_was_stored_under() {
+        let pool = setup_test_database().await.expect("setup_test_database");
+        let cache = SqliteGgufMetadataCache::new(pool);
+        let path = Path::new("/models/qwen2.5-7b-instruct-q4_k_m.gguf");
+
+        assert!(cache.get(path, STAMP).await.unwrap().is_none());
+        cache.put(path, STAMP, &metadata()).await.unwrap();
+
+        let cached = cache.get(path, STAMP).await.unwrap().unwrap();
+        assert_eq!(cached.name, metadata().name);
+        assert_eq!(cached.context_length, Some(32_768));
+        assert_eq!(cached.param_count_b, Some(7.6));
+        assert_eq!(cached.metadata, metadata().metadata);
+
+        let touched = FileStamp {
+            modified_ms: STAMP.modified_ms + 1,
+            ..STAMP
+        };
+        assert!(cache.get(path, touched).await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn storing_again_replaces_the_entry_and_remove_drops_it() {
+        let pool = setup_test_database().await.expect("setup_test_database");
+        let cache = SqliteGgufMetadataCache::new(pool);
+        let path = Path::new("/models/qwen2.5-7b-instruct-q4_k_m.gguf");
+        cache.put(path, STAMP, &metadata()).await.unwrap();
+
+        let grown = FileStamp {
+            size: STAMP.size + 1,
+            ..STAMP
+        };
+        let requantized = GgufMetadata {
+            quantization: Some("Q8_0".to_string()),
+            ..metadata()
+        };
+        cache.put(path, grown, &requantized).await.unwrap();
+        assert!(cache.get(path, STAMP).await.unwrap().is_none());
+        let cached = cache.get(path, grown).await.unwrap().unwrap();
+        assert_eq!(cached.quantization.as_deref(), Some("Q8_0"));
+
+        cache.remove(path).await.unwrap();
+        assert!(cache.get(path, grown).await.unwrap().is_none());
+    }
+}
@@ -1,10 +1,17 @@
 //! `SQLite` implementation of the `ModelRepository` trait.
 
 use async_trait::async_trait;
-use sqlx::SqlitePool;
+use chrono::{DateTime, Duration, Utc};
+use sqlx::{QueryBuilder, Row, Sqlite, SqlitePool};
 
+use gglib_core::domain::trash::TrashRecord;
+use gglib_core::domain::{
+    CAPABILITY_DETECTOR_VERSION, ModelChanges, ModelListQuery, ModelPage, ModelSortBy, PageRequest,
+    SortOrder,
+};
+use gglib_core::ports::ModelHub;
 use gglib_core::utils::shard_filename::base_shard_filename;
-use gglib_core::{Model, ModelRepository, NewModel, RepositoryError};
+use gglib_core::{Model, ModelRepository, ModelTrashRepository, NewModel, RepositoryError};
 
 use super::row_mappers::{
     BENCHMARK_SUMMARY_COLUMNS, MODEL_SELECT_COLUMNS, normalized_file_path_string, row_to_model,
@@ -13,6 +20,7 @@ use super::row_mappers::{
 /// Compute a canonical model key for deduplication.
 ///
 /// For HuggingFace models: `hf:<repo_id>@<commit_sha>#<base_filename>`
+/// For other hubs: the hub's prefix instead of `hf` (`modelscope:...`)
 /// For local models: `local:<file_path_hash>`
 ///
 /// The filename is normalized to remove shard suffixes, ensuring all shards
@@ -21,7 +29,8 @@ fn compute_model_key(model: &NewModel) -> String {
     match (&model.hf_repo_id, &model.hf_commit_sha, &model.hf_filename) {
         (Some(repo), Some(sha), Some(filename)) => {
             let base = base_shard_filename(filename);
-            format!("hf:{}@{}#{}", repo, sha, base)
+            let (hub, repo) = ModelHub::split(repo);
+            format!("{}:{}@{}#{}", hub.prefix(), repo, sha, base)
         }
         _ => {
             // For local models without HF metadata, use file path
@@ -34,10 +43,104 @@ fn compute_model_key(model: &NewModel) -> String {
     }
 }
 
+/// How far `as_of` in a change delta trails the time it was read, so a
+/// write stamped just before the read but committed just after is picked up
+/// by the next delta.
+const CHANGE_OVERLAP_MS: i64 = 1000;
+
+/// Milliseconds since the epoch, as stored in `updated_at_ms`.
+fn now_ms() -> i64 {
+    Utc::now().timestamp_millis()
+}
+
+/// Push the `WHERE` clause for `query`, matching
+/// [`gglib_core::domain::apply_query`]'s filter rules.
+fn push_filters(builder: &mut QueryBuilder<'_, Sqlite>, query: &ModelListQuery) {
+    builder.push(" WHERE models.deleted_at IS NULL");
+    if let Some(min) = query.min_params {
+        builder.push(" AND models.param_count_b >= ").push_bind(min);
+    }
+    if let Some(max) = query.max_params {
+        builder.push(" AND models.param_count_b <= ").push_bind(max);
+    }
+    // Models without a context length pass a context range.
+    if let Some(min) = query.min_context {
+        builder
+            .push(" AND (models.context_length IS NULL OR models.context_length >= ")
+            .push_bind(min)
+            .push(")");
+    }
+    if let Some(max) = query.max_context {
+        builder
+            .push(" AND (models.context_length IS NULL OR models.context_length <= ")
+            .push_bind(max)
+            .push(")");
+    }
+    if let Some(quants) = query.quantizations.as_ref().filter(|q| !q.is_empty()) {
+        builder.push(" AND models.quantization IN (");
+        let mut list = builder.separated(", ");
+        for quant in quants {
+            list.push_bind(quant.clone());
+        }
+        builder.push(")");
+    }
+    if let Some(licenses) = query.licenses.as_ref().filter(|l| !l.is_empty()) {
+        builder.push(" AND lower(models.license) IN (");
+        let mut list = builder.separated(", ");
+        for license in licenses {
+            list.push_bind(license.to_lowercase());
+        }
+        builder.push(")");
+    }
+    for tag in query.tags.iter().flatten() {
+        builder
+            .push(
+                " AND EXISTS (SELECT 1 FROM json_each(models.tags) \
+                 WHERE json_each.value = ",
+            )
+            .push_bind(tag.clone())
+            .push(")");
+    }
+    // Models without benchmark data fail a speed range.
+    if query.min_speed.is_some() || query.max_speed.is_some() {
+        builder.push(" AND s.latest_tg_tps IS NOT NULL");
+    }
+    if let Some(min) = query.min_speed {
+        builder.push(" AND s.latest_tg_tps >= ").push_bind(min);
+    }
+    if let Some(max) = query.max_speed {
+        builder.push(" AND s.latest_tg_tps <= ").push_bind(max);
+    }
+}
+
+/// The `ORDER BY` clause for `query`. Ties fall back to newest first, and
+/// models without benchmark data sort last by speed in either direction.
+fn order_by(query: &ModelListQuery) -> &'static str {
+    match (query.sort_by, query.order) {
+        (ModelSortBy::Name, SortOrder::Asc) => " ORDER BY models.name ASC",
+        (ModelSortBy::Name, SortOrder::Desc) => " ORDER BY models.name DESC",
+        (ModelSortBy::ParamCount, SortOrder::Asc) => " ORDER BY models.param_count_b ASC",
+        (ModelSortBy::ParamCount, SortOrder::Desc) => " ORDER BY models.param_count_b DESC",
+        (ModelSortBy::LatestTgTps, SortOrder::Asc) => {
+            " ORDER BY s.latest_tg_tps IS NULL, s.latest_tg_tps ASC"
+        }
+        (ModelSortBy::LatestTgTps, SortOrder::Desc) => {
+            " ORDER BY s.latest_tg_tps IS NULL, s.latest_tg_tps DESC"
+        }
+        (ModelSortBy::AddedAt, SortOrder::Asc) => " ORDER BY models.added_at ASC",
+        (ModelSortBy::AddedAt, SortOrder::Desc) => " ORDER BY models.added_at DESC",
+    }
+}
+
 /// `SQLite` implementation of the `ModelRepository` trait.
 ///
 /// This struct holds a connection pool and implements all CRUD operations
-/// for models using `SQLite`.
+/// for models using `SQLite`. Rows marked deleted by its
+/// `ModelTrashRepository` implementation are invisible to every lookup
+/// except [`ModelTrashRepository::list_deleted`]. Every write stamps the
+/// row's `updated_at_ms`, and hard deletes leave a row in
+/// `model_tombstones`, so [`ModelRepository::list_changed_since`] is two
+/// indexed reads.
 pub struct SqliteModelRepository {
     pool: SqlitePool,
 }
@@ -57,12 +160,14 @@ impl SqliteModelRepository {
 
 #[async_trait]
 impl ModelRepository for SqliteModelRepository {
+    #[tracing::instrument(name = "db.models.list", skip_all)]
     async fn list(&self) -> Result<Vec<Model>, RepositoryError> {
         // Include benchmark summary via LEFT JOIN so model cards can show
         // speed badges without a separate round-trip.
         let query = format!(
             "SELECT {}, {} FROM models \
              LEFT JOIN model_benchmark_summaries s ON s.model_id = models.id \
+             WHERE models.deleted_at IS NULL \
              ORDER BY models.added_at DESC",
             MODEL_SELECT_COLUMNS, BENCHMARK_SUMMARY_COLUMNS
         );
@@ -75,8 +180,12 @@ impl ModelRepository for SqliteModelRepository {
         rows.iter().map(row_to_model).collect()
     }
 
+    #[tracing::instrument(name = "db.models.get_by_id", skip_all)]
     async fn get_by_id(&self, id: i64) -> Result<Model, RepositoryError> {
-        let query = format!("SELECT {} FROM models WHERE id = ?", MODEL_SELECT_COLUMNS);
+        let query = format!(
+            "SELECT {} FROM models WHERE id = ? AND deleted_at IS NULL",
+            MODEL_SELECT_COLUMNS
+        );
 
         let row = sqlx::query(&query)
             .bind(id)
@@ -88,8 +197,12 @@ impl ModelRepository for SqliteModelRepository {
         row_to_model(&row)
     }
 
+    #[tracing::instrument(name = "db.models.get_by_name", skip_all)]
     async fn get_by_name(&self, name: &str) -> Result<Model, RepositoryError> {
-        let query = format!("SELECT {} FROM models WHERE name = ?", MODEL_SELECT_COLUMNS);
+        let query = format!(
+            "SELECT {} FROM models WHERE name = ? AND deleted_at IS NULL",
+            MODEL_SELECT_COLUMNS
+        );
 
         let row = sqlx::query(&query)
             .bind(name)
@@ -101,6 +214,7 @@ impl ModelRepository for SqliteModelRepository {
         row_to_model(&row)
     }
 
+    #[tracing::instrument(name = "db.models.insert", skip_all)]
     async fn insert(&self, model: &NewModel) -> Result<Model, RepositoryError> {
         let metadata_json = serde_json::to_string(&model.metadata)
             .map_err(|e| RepositoryError::Serialization(e.to_string()))?;
@@ -136,8 +250,8 @@ impl ModelRepository for SqliteModelRepository {
                 name, file_path, param_count_b, architecture, quantization, 
                 context_length, expert_count, expert_used_count, expert_shared_count,
                 metadata, added_at, hf_repo_id, hf_commit_sha, 
-                hf_filename, download_date, last_update_check, tags, model_key, file_paths_json, capabilities, inference_defaults, server_defaults
-            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+                hf_filename, download_date, last_update_check, tags, model_key, file_paths_json, capabilities, capabilities_version, inference_defaults, server_defaults, card, license, updated_at_ms
+            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
             ON CONFLICT(model_key) DO UPDATE SET
                 file_path = excluded.file_path,
                 file_paths_json = excluded.file_paths_json,
@@ -150,7 +264,13 @@ impl ModelRepository for SqliteModelRepository {
                 last_update_check = excluded.last_update_check,
                 tags = excluded.tags,
                 capabilities = excluded.capabilities,
-                inference_defaults = excluded.inference_defaults
+                capabilities_version = excluded.capabilities_version,
+                inference_defaults = excluded.inference_defaults,
+                card = COALESCE(models.card, excluded.card),
+                license = COALESCE(excluded.license, models.license),
+                deleted_at = NULL,
+                trash_json = NULL,
+                updated_at_ms = excluded.updated_at_ms
             "#,
         )
         .bind(&model.name)
@@ -173,8 +293,12 @@ impl ModelRepository for SqliteModelRepository {
         .bind(&model_key)
         .bind(&file_paths_json)
         .bind(model.capabilities.bits() as i64)
+        .bind(CAPABILITY_DETECTOR_VERSION)
         .bind(&inference_defaults_json)
         .bind(&server_defaults_json)
+        .bind(&model.card)
+        .bind(&model.license)
+        .bind(now_ms())
         .execute(&self.pool)
         .await
         .map_err(|e| RepositoryError::Storage(e.to_string()))?;
@@ -188,10 +312,19 @@ impl ModelRepository for SqliteModelRepository {
         .fetch_one(&self.pool)
         .await
         .map_err(|e| RepositoryError::Storage(e.to_string()))?;
+        let model = row_to_model(&row)?;
 
-        row_to_model(&row)
+        // The id may be a reused one; it is live again.
+        sqlx::query("DELETE FROM model_tombstones WHERE id = ?")
+            .bind(model.id)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| RepositoryError::Storage(e.to_string()))?;
+
+        Ok(model)
     }
 
+    #[tracing::instrument(name = "db.models.update", skip_all)]
     async fn update(&self, model: &Model) -> Result<(), RepositoryError> {
         let metadata_json = serde_json::to_string(&model.metadata)
             .map_err(|e| RepositoryError::Serialization(e.to_string()))?;
@@ -210,7 +343,7 @@ impl ModelRepository for SqliteModelRepository {
             .and_then(|cfg| serde_json::to_string(cfg).ok());
 
         let result = sqlx::query(
-            "UPDATE models SET name = ?, file_path = ?, param_count_b = ?, architecture = ?, quantization = ?, context_length = ?, metadata = ?, hf_repo_id = ?, hf_commit_sha = ?, hf_filename = ?, download_date = ?, last_update_check = ?, tags = ?, capabilities = ?, inference_defaults = ?, server_defaults = ? WHERE id = ?"
+            "UPDATE models SET name = ?, file_path = ?, param_count_b = ?, architecture = ?, quantization = ?, context_length = ?, metadata = ?, hf_repo_id = ?, hf_commit_sha = ?, hf_filename = ?, download_date = ?, last_update_check = ?, tags = ?, capabilities = ?, capabilities_version = ?, inference_defaults = ?, server_defaults = ?, card = ?, license = ?, updated_at_ms = ? WHERE id = ?"
         )
             .bind(&model.name)
             .bind(model.file_path.to_string_lossy().as_ref())
@@ -226,8 +359,12 @@ impl ModelRepository for SqliteModelRepository {
             .bind(model.last_update_check.as_ref().map(|dt| dt.to_string()))
             .bind(&tags_json)
             .bind(model.capabilities.bits() as i64)
+            .bind(model.capabilities_version)
             .bind(&inference_defaults_json)
             .bind(&server_defaults_json)
+            .bind(&model.card)
+            .bind(&model.license)
+            .bind(now_ms())
             .bind(model.id)
             .execute(&self.pool)
             .await
@@ -243,6 +380,7 @@ impl ModelRepository for SqliteModelRepository {
         Ok(())
     }
 
+    #[tracing::instrument(name = "db.models.delete", skip_all)]
     async fn delete(&self, id: i64) -> Result<(), RepositoryError> {
         let result = sqlx::query("DELETE FROM models WHERE id = ?")
             .bind(id)
@@ -254,6 +392,171 @@ impl ModelRepository for SqliteModelRepository {
             return Err(RepositoryError::NotFound(format!("Model with ID {id}")));
         }
 
+        sqlx::query("INSERT OR REPLACE INTO model_tombstones (id, deleted_at_ms) VALUES (?, ?)")
+            .bind(id)
+            .bind(now_ms())
+            .execute(&self.pool)
+            .await
+            .map_err(|e| RepositoryError::Storage(e.to_string()))?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(name = "db.models.list_page", skip_all)]
+    async fn list_page(
+        &self,
+        query: &ModelListQuery,
+        page: PageRequest,
+    ) -> Result<ModelPage, RepositoryError> {
+        let mut count = QueryBuilder::<Sqlite>::new(
+            "SELECT COUNT(*) FROM models \
+             LEFT JOIN model_benchmark_summaries s ON s.model_id = models.id",
+        );
+        push_filters(&mut count, query);
+        let total: i64 = count
+            .build_query_scalar()
+            .fetch_one(&self.pool)
+            .await
+            .map_err(|e| RepositoryError::Storage(e.to_string()))?;
+
+        let mut select = QueryBuilder::<Sqlite>::new(format!(
+            "SELECT {MODEL_SELECT_COLUMNS}, {BENCHMARK_SUMMARY_COLUMNS} FROM models \
+             LEFT JOIN model_benchmark_summaries s ON s.model_id = models.id"
+        ));
+        push_filters(&mut select, query);
+        select
+            .push(order_by(query))
+            .push(", models.added_at DESC, models.id DESC LIMIT ")
+            .push_bind(i64::try_from(page.limit).unwrap_or(i64::MAX))
+            .push(" OFFSET ")
+            .push_bind(i64::try_from(page.offset).unwrap_or(i64::MAX));
+        let rows = select
+            .build()
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| RepositoryError::Storage(e.to_string()))?;
+
+        Ok(ModelPage {
+            items: rows.iter().map(row_to_model).collect::<Result<_, _>>()?,
+            total: usize::try_from(total).unwrap_or_default(),
+        })
+    }
+
+    #[tracing::instrument(name = "db.models.list_changed_since", skip_all)]
+    async fn list_changed_since(
+        &self,
+        since: DateTime<Utc>,
+    ) -> Result<ModelChanges, RepositoryError> {
+        let as_of = Utc::now() - Duration::milliseconds(CHANGE_OVERLAP_MS);
+        let since_ms = since.timestamp_millis();
+
+        // Benchmark summaries are stamped to the second by SQLite, so a new
+        // speed badge also counts as a change.
+        let query = format!(
+            "SELECT {}, {} FROM models \
+             LEFT JOIN model_benchmark_summaries s ON s.model_id = models.id \
+             WHERE models.deleted_at IS NULL \
+             AND (models.updated_at_ms >= ? OR s.updated_at >= datetime(?, 'unixepoch')) \
+             ORDER BY models.added_at DESC",
+            MODEL_SELECT_COLUMNS, BENCHMARK_SUMMARY_COLUMNS
+        );
+        let rows = sqlx::query(&query)
+            .bind(since_ms)
+            .bind(since.timestamp())
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| RepositoryError::Storage(e.to_string()))?;
+        let changed = rows.iter().map(row_to_model).collect::<Result<_, _>>()?;
+
+        // Trashed models are gone from the list as much as deleted ones.
+        let removed = sqlx::query_scalar(
+            "SELECT id FROM models WHERE deleted_at IS NOT NULL AND updated_at_ms >= ? \
+             UNION SELECT id FROM model_tombstones WHERE deleted_at_ms >= ? \
+             ORDER BY id",
+        )
+        .bind(since_ms)
+        .bind(since_ms)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| RepositoryError::Storage(e.to_string()))?;
+
+        Ok(ModelChanges {
+            changed,
+            removed,
+            full: false,
+            as_of,
+        })
+    }
+}
+
+#[async_trait]
+impl ModelTrashRepository for SqliteModelRepository {
+    #[tracing::instrument(name = "db.models.mark_deleted", skip_all)]
+    async fn mark_deleted(&self, id: i64, record: &TrashRecord) -> Result<(), RepositoryError> {
+        let record_json = serde_json::to_string(record)
+            .map_err(|e| RepositoryError::Serialization(e.to_string()))?;
+
+        let result = sqlx::query(
+            "UPDATE models SET deleted_at = ?, trash_json = ?, updated_at_ms = ? \
+             WHERE id = ? AND deleted_at IS NULL",
+        )
+        .bind(record.deleted_at.to_string())
+        .bind(&record_json)
+        .bind(now_ms())
+        .bind(id)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| RepositoryError::Storage(e.to_string()))?;
+
+        if result.rows_affected() == 0 {
+            return Err(RepositoryError::NotFound(format!("Model with ID {id}")));
+        }
+
+        Ok(())
+    }
+
+    #[tracing::instrument(name = "db.models.list_deleted", skip_all)]
+    async fn list_deleted(&self) -> Result<Vec<(Model, TrashRecord)>, RepositoryError> {
+        let query = format!(
+            "SELECT {}, trash_json FROM models WHERE deleted_at IS NOT NULL ORDER BY deleted_at",
+            MODEL_SELECT_COLUMNS
+        );
+
+        let rows = sqlx::query(&query)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| RepositoryError::Storage(e.to_string()))?;
+
+        rows.iter()
+            .map(|row| {
+                let record_json: String = row
+                    .try_get("trash_json")
+                    .map_err(|e| RepositoryError::Storage(e.to_string()))?;
+                let record = serde_json::from_str(&record_json)
+                    .map_err(|e| RepositoryError::Serialization(e.to_string()))?;
+                Ok((row_to_model(row)?, record))
+            })
+            .collect()
+    }
+
+    #[tracing::instrument(name = "db.models.restore", skip_all)]
+    async fn restore(&self, id: i64) -> Result<(), RepositoryError> {
+        let result = sqlx::query(
+            "UPDATE models SET deleted_at = NULL, trash_json = NULL, updated_at_ms = ? \
+             WHERE id = ? AND deleted_at IS NOT NULL",
+        )
+        .bind(now_ms())
+        .bind(id)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| RepositoryError::Storage(e.to_string()))?;
+
+        if result.rows_affected() == 0 {
+            return Err(RepositoryError::NotFound(format!(
+                "Trashed model with ID {id}"
+            )));
+        }
+
         Ok(())
     }
 }
@@ -358,4 +661,165 @@ mod tests {
         repo.insert(&make_model("Zeta")).await.unwrap();
         assert_eq!(repo.list().await.unwrap().len(), 1);
     }
+
+    #[tokio::test]
+    async fn trashed_models_are_hidden_until_restored() {
+        let repo = repo().await;
+        let model = repo.insert(&make_model("Eta")).await.unwrap();
+        let record = TrashRecord {
+            deleted_at: Utc::now(),
+            dir: "/models/trash/1".into(),
+            files: Vec::new(),
+            size_bytes: 42,
+        };
+
+        repo.mark_deleted(model.id, &record).await.unwrap();
+        assert!(repo.list().await.unwrap().is_empty());
+        assert!(matches!(
+            repo.get_by_name("Eta").await.unwrap_err(),
+            RepositoryError::NotFound(_)
+        ));
+        let deleted = repo.list_deleted().await.unwrap();
+        assert_eq!(deleted.len(), 1);
+        assert_eq!(deleted[0].1.size_bytes, 42);
+        // A second removal of the same row finds nothing live to mark.
+        assert!(repo.mark_deleted(model.id, &record).await.is_err());
+
+        repo.restore(model.id).await.unwrap();
+        assert_eq!(repo.get_by_id(model.id).await.unwrap().name, "Eta");
+        assert!(repo.list_deleted().await.unwrap().is_empty());
+    }
+
+    fn names(models: &[Model]) -> Vec<&str> {
+        models.iter().map(|m| m.name.as_str()).collect()
+    }
+
+    #[tokio::test]
+    async fn list_page_matches_apply_query() {
+        let repo = repo().await;
+        let specs = [
+            ("a", 7.0, Some(4096), "Q4_K_M", &["chat"][..], Some(40.0)),
+            ("b", 13.0, None, "Q8_0", &["chat", "code"][..], None),
+            ("c", 70.0, Some(32768), "Q4_K_M", &["code"][..], Some(8.0)),
+            ("d", 3.0, Some(8192), "F16", &[][..], Some(90.0)),
+            ("e", 34.0, Some(16384), "Q8_0", &["chat"][..], None),
+        ];
+        for (i, (name, params, ctx, quant, tags, tps)) in specs.into_iter().enumerate() {
+            let mut new = make_model(name);
+            new.param_count_b = params;
+            new.context_length = ctx;
+            new.quantization = Some(quant.to_string());
+            new.tags = tags.iter().map(ToString::to_string).collect();
+            new.license = (i % 2 == 0).then(|| ["apache-2.0", "cc-by-nc-4.0"][i % 4 / 2].into());
+            new.added_at = Utc::now() - Duration::seconds(i64::try_from(i).unwrap());
+            let model = repo.insert(&new).await.unwrap();
+            if let Some(tps) = tps {
+                sqlx::query(
+                    "INSERT INTO model_benchmark_summaries \
+                     (model_id, latest_tg_tps, last_benchmarked_at, updated_at) \
+                     VALUES (?, ?, datetime('now'), datetime('now'))",
+                )
+                .bind(model.id)
+                .bind(tps)
+                .execute(repo.pool())
+                .await
+                .unwrap();
+            }
+        }
+
+        let queries = [
+            ModelListQuery::default(),
+            ModelListQuery {
+                sort_by: ModelSortBy::Name,
+                order: SortOrder::Asc,
+                ..Default::default()
+            },
+            ModelListQuery {
+                sort_by: ModelSortBy::ParamCount,
+                min_params: Some(5.0),
+                max_params: Some(50.0),
+                ..Default::default()
+            },
+            ModelListQuery {
+                sort_by: ModelSortBy::LatestTgTps,
+                order: SortOrder::Asc,
+                ..Default::default()
+            },
+            ModelListQuery {
+                sort_by: ModelSortBy::LatestTgTps,
+                min_speed: Some(10.0),
+                ..Default::default()
+            },
+            ModelListQuery {
+                min_context: Some(8192.0),
+                quantizations: Some(vec!["Q4_K_M".into(), "Q8_0".into()]),
+                ..Default::default()
+            },
+            ModelListQuery {
+                sort_by: ModelSortBy::AddedAt,
+                order: SortOrder::Asc,
+                tags: Some(vec!["chat".into(), "code".into()]),
+                ..Default::default()
+            },
+            ModelListQuery {
+                licenses: Some(vec!["Apache-2.0".into(), "mit".into()]),
+                ..Default::default()
+            },
+        ];
+        let all = repo.list().await.unwrap();
+        for query in &queries {
+            for page in [PageRequest::new(0, 50), PageRequest::new(1, 2)] {
+                let expected = gglib_core::domain::page_of(all.clone(), query, page);
+                let actual = repo.list_page(query, page).await.unwrap();
+                assert_eq!(actual.total, expected.total, "{query:?}");
+                assert_eq!(names(&actual.items), names(&expected.items), "{query:?}");
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn list_changed_since_reports_writes_and_removals() {
+        let repo = repo().await;
+        let kept = repo.insert(&make_model("kept")).await.unwrap();
+        let edited = repo.insert(&make_model("edited")).await.unwrap();
+        let trashed = repo.insert(&make_model("trashed")).await.unwrap();
+        let deleted = repo.insert(&make_model("deleted")).await.unwrap();
+
+        // Stamps are in milliseconds; step past the inserts.
+        tokio::time::sleep(std::time::Duration::from_millis(5)).await;
+        let since = Utc::now();
+        let mut renamed = edited.clone();
+        renamed.name = "edited-v2".to_string();
+        repo.update(&renamed).await.unwrap();
+        let record = TrashRecord {
+            deleted_at: Utc::now(),
+            dir: "/models/trash/1".into(),
+            files: Vec::new(),
+            size_bytes: 0,
+        };
+        repo.mark_deleted(trashed.id, &record).await.unwrap();
+        repo.delete(deleted.id).await.unwrap();
+
+        let changes = repo.list_changed_since(since).await.unwrap();
+        assert!(!changes.full);
+        assert_eq!(names(&changes.changed), ["edited-v2"]);
+        let mut removed = changes.removed.clone();
+        removed.sort_unstable();
+        assert_eq!(removed, [trashed.id, deleted.id]);
+        assert!(changes.as_of <= Utc::now());
+
+        // Everything is new to a client that has seen nothing.
+        let everything = repo
+            .list_changed_since(DateTime::<Utc>::UNIX_EPOCH)
+            .await
+            .unwrap();
+        assert_eq!(everything.changed.len(), 2);
+        assert!(everything.changed.iter().any(|m| m.id == kept.id));
+
+        // Restoring brings the model back as a change, not a removal.
+        repo.restore(trashed.id).await.unwrap();
+        let changes = repo.list_changed_since(since).await.unwrap();
+        assert!(changes.changed.iter().any(|m| m.id == trashed.id));
+        assert_eq!(changes.removed, [deleted.id]);
+    }
 }
@@ -0,0 +1,209 @@
+//! `SQLite` implementation of [`ModelUsageRepository`].
+
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use sqlx::{Row, SqlitePool};
+
+use gglib_core::domain::usage::{DailyTotals, UsageDelta, UsageTotals};
+use gglib_core::ports::{ModelUsageRepository, RepositoryError};
+
+/// `SQLite` implementation of [`ModelUsageRepository`].
+///
+/// Stores one `model_usage_daily` row per model per day; adding to a day
+/// that already has a row sums into it.
+pub struct SqliteModelUsageRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteModelUsageRepository {
+    /// Create a new model usage repository from a shared connection pool.
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+}
+
+fn storage(e: &sqlx::Error) -> RepositoryError {
+    RepositoryError::Storage(e.to_string())
+}
+
+#[async_trait]
+impl ModelUsageRepository for SqliteModelUsageRepository {
+    async fn add(
+        &self,
+        model_id: i64,
+        day: NaiveDate,
+        delta: &UsageDelta,
+    ) -> Result<(), RepositoryError> {
+        sqlx::query(
+            r#"
+            INSERT INTO model_usage_daily
+                (model_id, day, requests, prompt_tokens, completion_tokens,
+                 serve_seconds, busy_seconds)
+            VALUES (?, ?, ?, ?, ?, ?, ?)
+            ON CONFLICT (model_id, day) DO UPDATE SET
+                requests = requests + excluded.requests,
+                prompt_tokens = prompt_tokens + excluded.prompt_tokens,
+                completion_tokens = completion_tokens + excluded.completion_tokens,
+                serve_seconds = serve_seconds + excluded.serve_seconds,
+                busy_seconds = busy_seconds + excluded.busy_seconds
+            "#,
+        )
+        .bind(model_id)
+        .bind(day.to_string())
+        .bind(delta.requests as i64)
+        .bind(delta.prompt_tokens as i64)
+        .bind(delta.completion_tokens as i64)
+        .bind(delta.serve_seconds)
+        .bind(delta.busy_seconds)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| storage(&e))?;
+        Ok(())
+    }
+
+    async fn totals(&self, since: Option<NaiveDate>) -> Result<Vec<UsageTotals>, RepositoryError> {
+        let rows = sqlx::query(
+            r#"
+            SELECT model_id,
+                   SUM(requests) AS requests,
+                   SUM(prompt_tokens) AS prompt_tokens,
+                   SUM(completion_tokens) AS completion_tokens,
+                   SUM(serve_seconds) AS serve_seconds,
+                   SUM(busy_seconds) AS busy_seconds,
+                   COUNT(*) AS days_used,
+                   MAX(day) AS last_used
+            FROM model_usage_daily
+            WHERE ? IS NULL OR day >= ?
+            GROUP BY model_id
+            "#,
+        )
+        .bind(since.map(|d| d.to_string()))
+        .bind(since.map(|d| d.to_string()))
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| storage(&e))?;
+
+        rows.iter()
+            .map(|row| {
+                let last_used: String = row.get("last_used");
+                Ok(UsageTotals {
+                    model_id: row.get("model_id"),
+                    usage: summed_usage(row),
+                    days_used: row.get::<i64, _>("days_used") as u32,
+                    last_used: Some(
+                        last_used
+                            .parse()
+                            .map_err(|e| RepositoryError::Serialization(format!("{e}")))?,
+                    ),
+                })
+            })
+            .collect()
+    }
+
+    async fn daily(&self, since: NaiveDate) -> Result<Vec<DailyTotals>, RepositoryError> {
+        let rows = sqlx::query(
+            r#"
+            SELECT day,
+                   SUM(requests) AS requests,
+                   SUM(prompt_tokens) AS prompt_tokens,
+                   SUM(completion_tokens) AS completion_tokens,
+                   SUM(serve_seconds) AS serve_seconds,
+                   SUM(busy_seconds) AS busy_seconds
+            FROM model_usage_daily
+            WHERE day >= ?
+            GROUP BY day
+            "#,
+        )
+        .bind(since.to_string())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| storage(&e))?;
+
+        rows.iter()
+            .map(|row| {
+                let day: String = row.get("day");
+                Ok(DailyTotals {
+                    day: day
+                        .parse()
+                        .map_err(|e| RepositoryError::Serialization(format!("{e}")))?,
+                    usage: summed_usage(row),
+                })
+            })
+            .collect()
+    }
+}
+
+/// The summed usage columns of a `totals` or `daily` row.
+fn summed_usage(row: &sqlx::sqlite::SqliteRow) -> UsageDelta {
+    UsageDelta {
+        requests: row.get::<i64, _>("requests") as u64,
+        prompt_tokens: row.get::<i64, _>("prompt_tokens") as u64,
+        completion_tokens: row.get::<i64, _>("completion_tokens") as u64,
+        serve_seconds: row.get("serve_seconds"),
+        busy_seconds: row.get("busy_seconds"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use gglib_core::domain::NewModel;
+    use gglib_core::ports::ModelRepository;
+
+    use crate::repositories::SqliteModelRepository;
+    use crate::setup::setup_test_database;
+
+    use super::*;
+
+    fn day(d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 3, d).unwrap()
+    }
+
+    fn delta(requests: u64, serve_seconds: f64) -> UsageDelta {
+        UsageDelta {
+            requests,
+            prompt_tokens: requests * 100,
+            completion_tokens: requests * 10,
+            serve_seconds,
+            busy_seconds: serve_seconds / 2.0,
+        }
+    }
+
+    #[tokio::test]
+    async fn adds_into_daily_rows_and_sums_totals_since_a_day() {
+        let pool = setup_test_database().await.expect("setup_test_database");
+        let models = SqliteModelRepository::new(pool.clone());
+        let model = models
+            .insert(&NewModel::new(
+                "used".to_string(),
+                "/models/used.gguf".into(),
+                7.0,
+                chrono::Utc::now(),
+            ))
+            .await
+            .unwrap();
+        let repo = SqliteModelUsageRepository::new(pool);
+
+        repo.add(model.id, day(1), &delta(2, 60.0)).await.unwrap();
+        repo.add(model.id, day(3), &delta(1, 30.0)).await.unwrap();
+        repo.add(model.id, day(3), &delta(4, 30.0)).await.unwrap();
+
+        let all = repo.totals(None).await.unwrap();
+        assert_eq!(all.len(), 1);
+        assert_eq!(all[0].model_id, model.id);
+        assert_eq!(all[0].usage, delta(7, 120.0));
+        assert_eq!(all[0].days_used, 2);
+        assert_eq!(all[0].last_used, Some(day(3)));
+
+        let recent = repo.totals(Some(day(2))).await.unwrap();
+        assert_eq!(recent[0].usage.requests, 5);
+        assert_eq!(recent[0].days_used, 1);
+
+        assert!(repo.totals(Some(day(4))).await.unwrap().is_empty());
+
+        let mut days = repo.daily(day(1)).await.unwrap();
+        days.sort_by_key(|d| d.day);
+        assert_eq!(days.len(), 2);
+        assert_eq!(days[1].day, day(3));
+        assert_eq!(days[1].usage, delta(5, 60.0));
+    }
+}
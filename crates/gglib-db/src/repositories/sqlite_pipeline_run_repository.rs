@@ -0,0 +1,273 @@
+//! `SQLite` implementation of the `PipelineRunRepository` trait.
+
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use sqlx::{Row, SqlitePool, sqlite::SqliteRow};
+
+use gglib_core::{
+    domain::pipeline::{NewPipelineRun, PipelineRun, PipelineRunStatus, PipelineStepResult},
+    ports::pipelines::{PipelineError, PipelineRunRepository},
+};
+
+/// `SQLite` implementation of the `PipelineRunRepository` trait.
+///
+/// The definition and inputs are stored as JSON on the run row; step
+/// outputs live in `pipeline_step_results`, one row per completed step.
+pub struct SqlitePipelineRunRepository {
+    pool: SqlitePool,
+}
+
+impl SqlitePipelineRunRepository {
+    /// Create a new `SQLite` pipeline run repository.
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    async fn steps_for(&self, run_id: i64) -> Result<Vec<PipelineStepResult>, PipelineError> {
+        let rows = sqlx::query(
+            "SELECT step_id, output, created_at FROM pipeline_step_results
+             WHERE run_id = ? ORDER BY id ASC",
+        )
+        .bind(run_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(db_err)?;
+
+        Ok(rows.iter().map(step_from_row).collect())
+    }
+}
+
+/// Columns selected for every [`PipelineRun`] query.
+const RUN_COLUMNS: &str = "id, name, definition, inputs, status, error, created_at, updated_at";
+
+fn db_err(e: sqlx::Error) -> PipelineError {
+    PipelineError::Database(e.to_string())
+}
+
+fn step_from_row(row: &SqliteRow) -> PipelineStepResult {
+    PipelineStepResult {
+        step_id: row.get("step_id"),
+        output: row.get("output"),
+        created_at: row.get("created_at"),
+    }
+}
+
+/// Map a `pipeline_runs` row selected with [`RUN_COLUMNS`].
+fn run_from_row(
+    row: &SqliteRow,
+    steps: Vec<PipelineStepResult>,
+) -> Result<PipelineRun, PipelineError> {
+    let definition: String = row.get("definition");
+    let inputs: String = row.get("inputs");
+    let status: String = row.get("status");
+    Ok(PipelineRun {
+        id: row.get("id"),
+        name: row.get("name"),
+        definition: serde_json::from_str(&definition)
+            .map_err(|e| PipelineError::Database(format!("corrupt pipeline definition: {e}")))?,
+        inputs: serde_json::from_str(&inputs).unwrap_or_default(),
+        status: PipelineRunStatus::parse(&status).unwrap_or(PipelineRunStatus::Failed),
+        error: row.get("error"),
+        steps,
+        created_at: row.get("created_at"),
+        updated_at: row.get("updated_at"),
+    })
+}
+
+#[async_trait]
+impl PipelineRunRepository for SqlitePipelineRunRepository {
+    async fn create_run(&self, run: NewPipelineRun) -> Result<i64, PipelineError> {
+        let definition = serde_json::to_string(&run.definition)
+            .map_err(|e| PipelineError::Invalid(e.to_string()))?;
+        let inputs = serde_json::to_string(&run.inputs)
+            .map_err(|e| PipelineError::Invalid(e.to_string()))?;
+
+        let result = sqlx::query(
+            "INSERT INTO pipeline_runs (name, definition, inputs, status) VALUES (?, ?, ?, ?)",
+        )
+        .bind(&run.definition.name)
+        .bind(definition)
+        .bind(inputs)
+        .bind(PipelineRunStatus::Running.as_str())
+        .execute(&self.pool)
+        .await
+        .map_err(db_err)?;
+
+        Ok(result.last_insert_rowid())
+    }
+
+    async fn record_step(
+        &self,
+        run_id: i64,
+        step_id: &str,
+        output: &str,
+    ) -> Result<(), PipelineError> {
+        sqlx::query("INSERT INTO pipeline_step_results (run_id, step_id, output) VALUES (?, ?, ?)")
+            .bind(run_id)
+            .bind(step_id)
+            .bind(output)
+            .execute(&self.pool)
+            .await
+            .map_err(db_err)?;
+
+        sqlx::query("UPDATE pipeline_runs SET updated_at = datetime('now') WHERE id = ?")
+            .bind(run_id)
+            .execute(&self.pool)
+            .await
+            .map_err(db_err)?;
+        Ok(())
+    }
+
+    async fn finish_run(
+        &self,
+        run_id: i64,
+        status: PipelineRunStatus,
+        error: Option<&str>,
+    ) -> Result<(), PipelineError> {
+        let result = sqlx::query(
+            "UPDATE pipeline_runs SET status = ?, error = ?, updated_at = datetime('now')
+             WHERE id = ?",
+        )
+        .bind(status.as_str())
+        .bind(error)
+        .bind(run_id)
+        .execute(&self.pool)
+        .await
+        .map_err(db_err)?;
+
+        if result.rows_affected() == 0 {
+            return Err(PipelineError::NotFound(run_id));
+        }
+        Ok(())
+    }
+
+    async fn get(&self, id: i64) -> Result<Option<PipelineRun>, PipelineError> {
+        let row = sqlx::query(&format!(
+            "SELECT {RUN_COLUMNS} FROM pipeline_runs WHERE id = ?"
+        ))
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(db_err)?;
+
+        match row {
+            Some(row) => Ok(Some(run_from_row(&row, self.steps_for(id).await?)?)),
+            None => Ok(None),
+        }
+    }
+
+    async fn list(&self) -> Result<Vec<PipelineRun>, PipelineError> {
+        let rows = sqlx::query(&format!(
+            "SELECT {RUN_COLUMNS} FROM pipeline_runs ORDER BY id DESC"
+        ))
+        .fetch_all(&self.pool)
+        .await
+        .map_err(db_err)?;
+
+        let step_rows = sqlx::query(
+            "SELECT run_id, step_id, output, created_at FROM pipeline_step_results
+             ORDER BY id ASC",
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(db_err)?;
+        let mut steps: HashMap<i64, Vec<PipelineStepResult>> = HashMap::new();
+        for row in &step_rows {
+            steps
+                .entry(row.get("run_id"))
+                .or_default()
+                .push(step_from_row(row));
+        }
+
+        rows.iter()
+            .map(|row| {
+                run_from_row(
+                    row,
+                    steps.remove(&row.get::<i64, _>("id")).unwrap_or_default(),
+                )
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use gglib_core::domain::pipeline::{PipelineDefinition, PipelineStep};
+
+    use crate::setup::setup_test_database;
+
+    use super::*;
+
+    fn new_run(name: &str) -> NewPipelineRun {
+        NewPipelineRun {
+            definition: PipelineDefinition {
+                name: name.to_string(),
+                description: None,
+                model: Some("qwen3".to_string()),
+                inputs: BTreeMap::new(),
+                steps: vec![PipelineStep {
+                    id: "summary".to_string(),
+                    prompt: Some("Summarise {{input.text}}".to_string()),
+                    system: None,
+                    model: None,
+                    sampling: None,
+                    tool: None,
+                    args: None,
+                }],
+            },
+            inputs: BTreeMap::from([("text".to_string(), "hello".to_string())]),
+        }
+    }
+
+    #[tokio::test]
+    async fn run_lifecycle_keeps_step_outputs() {
+        let pool = setup_test_database().await.expect("setup_test_database");
+        let repo = SqlitePipelineRunRepository::new(pool);
+
+        let first = repo.create_run(new_run("first")).await.unwrap();
+        let second = repo.create_run(new_run("second")).await.unwrap();
+
+        repo.record_step(first, "summary", "short").await.unwrap();
+        repo.record_step(first, "facts", "- a").await.unwrap();
+        repo.finish_run(
+            first,
+            PipelineRunStatus::Failed,
+            Some("step 'table' failed"),
+        )
+        .await
+        .unwrap();
+
+        let run = repo.get(first).await.unwrap().unwrap();
+        assert_eq!(run.status, PipelineRunStatus::Failed);
+        assert_eq!(run.error.as_deref(), Some("step 'table' failed"));
+        assert_eq!(run.inputs["text"], "hello");
+        assert_eq!(run.definition.steps[0].id, "summary");
+        assert_eq!(
+            run.steps
+                .iter()
+                .map(|s| s.step_id.as_str())
+                .collect::<Vec<_>>(),
+            ["summary", "facts"]
+        );
+        assert_eq!(run.output(), Some("- a"));
+
+        let listed = repo.list().await.unwrap();
+        assert_eq!(
+            listed.iter().map(|r| r.id).collect::<Vec<_>>(),
+            [second, first]
+        );
+        assert_eq!(listed[0].status, PipelineRunStatus::Running);
+        assert!(listed[0].steps.is_empty());
+        assert_eq!(listed[1].steps.len(), 2);
+
+        assert!(repo.get(999).await.unwrap().is_none());
+        assert!(matches!(
+            repo.finish_run(999, PipelineRunStatus::Completed, None)
+                .await,
+            Err(PipelineError::NotFound(999))
+        ));
+    }
+}
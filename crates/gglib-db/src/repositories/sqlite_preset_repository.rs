@@ -0,0 +1,245 @@
+//! `SQLite` implementation of the `PresetRepository` trait.
+
+use async_trait::async_trait;
+use sqlx::{Row, SqlitePool, sqlite::SqliteRow};
+
+use gglib_core::{
+    domain::preset::{NewPreset, Preset, PresetUpdate},
+    ports::presets::{PresetError, PresetRepository},
+};
+
+/// `SQLite` implementation of the `PresetRepository` trait.
+///
+/// The sampling config, tool names and MCP server ids are stored as JSON.
+pub struct SqlitePresetRepository {
+    pool: SqlitePool,
+}
+
+impl SqlitePresetRepository {
+    /// Create a new `SQLite` preset repository.
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+}
+
+/// Columns selected for every [`Preset`] query.
+const PRESET_COLUMNS: &str = "id, name, description, system_prompt, sampling, tools, \
+     mcp_server_ids, default_model, created_at, updated_at";
+
+/// Map a write error, reporting a taken name as [`PresetError::AlreadyExists`].
+fn write_err(e: sqlx::Error, name: &str) -> PresetError {
+    if e.to_string().contains("UNIQUE constraint failed") {
+        return PresetError::AlreadyExists(name.to_string());
+    }
+    db_err(e)
+}
+
+fn db_err(e: sqlx::Error) -> PresetError {
+    PresetError::Database(e.to_string())
+}
+
+fn to_json<T: serde::Serialize>(value: &T) -> String {
+    serde_json::to_string(value).unwrap_or_default()
+}
+
+/// Map a `presets` row selected with [`PRESET_COLUMNS`].
+fn preset_from_row(row: &SqliteRow) -> Preset {
+    let sampling: String = row.get("sampling");
+    let tools: String = row.get("tools");
+    let mcp_server_ids: String = row.get("mcp_server_ids");
+    Preset {
+        id: row.get("id"),
+        name: row.get("name"),
+        description: row.get("description"),
+        system_prompt: row.get("system_prompt"),
+        sampling: serde_json::from_str(&sampling).unwrap_or_default(),
+        tools: serde_json::from_str(&tools).unwrap_or_default(),
+        mcp_server_ids: serde_json::from_str(&mcp_server_ids).unwrap_or_default(),
+        default_model: row.get("default_model"),
+        created_at: row.get("created_at"),
+        updated_at: row.get("updated_at"),
+    }
+}
+
+#[async_trait]
+impl PresetRepository for SqlitePresetRepository {
+    async fn create(&self, preset: NewPreset) -> Result<i64, PresetError> {
+        let result = sqlx::query(
+            "INSERT INTO presets
+                 (name, description, system_prompt, sampling, tools, mcp_server_ids, default_model)
+             VALUES (?, ?, ?, ?, ?, ?, ?)",
+        )
+        .bind(&preset.name)
+        .bind(&preset.description)
+        .bind(&preset.system_prompt)
+        .bind(to_json(&preset.sampling))
+        .bind(to_json(&preset.tools))
+        .bind(to_json(&preset.mcp_server_ids))
+        .bind(&preset.default_model)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| write_err(e, &preset.name))?;
+
+        Ok(result.last_insert_rowid())
+    }
+
+    async fn list(&self) -> Result<Vec<Preset>, PresetError> {
+        let rows = sqlx::query(&format!(
+            "SELECT {PRESET_COLUMNS} FROM presets ORDER BY name ASC"
+        ))
+        .fetch_all(&self.pool)
+        .await
+        .map_err(db_err)?;
+
+        Ok(rows.iter().map(preset_from_row).collect())
+    }
+
+    async fn get(&self, id: i64) -> Result<Option<Preset>, PresetError> {
+        let row = sqlx::query(&format!(
+            "SELECT {PRESET_COLUMNS} FROM presets WHERE id = ?"
+        ))
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(db_err)?;
+
+        Ok(row.as_ref().map(preset_from_row))
+    }
+
+    async fn get_by_name(&self, name: &str) -> Result<Option<Preset>, PresetError> {
+        let row = sqlx::query(&format!(
+            "SELECT {PRESET_COLUMNS} FROM presets WHERE name = ?"
+        ))
+        .bind(name)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(db_err)?;
+
+        Ok(row.as_ref().map(preset_from_row))
+    }
+
+    async fn update(&self, id: i64, update: PresetUpdate) -> Result<(), PresetError> {
+        let mut preset = self.get(id).await?.ok_or(PresetError::NotFound(id))?;
+        update.apply(&mut preset);
+
+        sqlx::query(
+            "UPDATE presets
+             SET name = ?, description = ?, system_prompt = ?, sampling = ?, tools = ?,
+                 mcp_server_ids = ?, default_model = ?, updated_at = datetime('now')
+             WHERE id = ?",
+        )
+        .bind(&preset.name)
+        .bind(&preset.description)
+        .bind(&preset.system_prompt)
+        .bind(to_json(&preset.sampling))
+        .bind(to_json(&preset.tools))
+        .bind(to_json(&preset.mcp_server_ids))
+        .bind(&preset.default_model)
+        .bind(id)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| write_err(e, &preset.name))?;
+
+        Ok(())
+    }
+
+    async fn delete(&self, id: i64) -> Result<(), PresetError> {
+        let result = sqlx::query("DELETE FROM presets WHERE id = ?")
+            .bind(id)
+            .execute(&self.pool)
+            .await
+            .map_err(db_err)?;
+
+        if result.rows_affected() == 0 {
+            return Err(PresetError::NotFound(id));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use gglib_core::domain::InferenceConfig;
+
+    use crate::setup::setup_test_database;
+
+    use super::*;
+
+    fn new_preset(name: &str) -> NewPreset {
+        NewPreset {
+            name: name.to_string(),
+            system_prompt: Some("You are terse.".to_string()),
+            sampling: InferenceConfig {
+                temperature: Some(0.3),
+                ..Default::default()
+            },
+            tools: vec!["builtin:time".to_string()],
+            mcp_server_ids: vec![2],
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn create_lookup_and_update() {
+        let pool = setup_test_database().await.expect("setup_test_database");
+        let repo = SqlitePresetRepository::new(pool);
+
+        let b = repo.create(new_preset("terse")).await.unwrap();
+        let a = repo.create(new_preset("coder")).await.unwrap();
+
+        let listed = repo.list().await.unwrap();
+        assert_eq!(listed.iter().map(|p| p.id).collect::<Vec<_>>(), [a, b]);
+
+        let by_name = repo.get_by_name("terse").await.unwrap().unwrap();
+        assert_eq!(by_name.id, b);
+        assert_eq!(by_name.sampling.temperature, Some(0.3));
+        assert_eq!(by_name.tools, ["builtin:time"]);
+        assert_eq!(by_name.mcp_server_ids, [2]);
+        assert!(repo.get_by_name("missing").await.unwrap().is_none());
+
+        repo.update(
+            b,
+            PresetUpdate {
+                system_prompt: Some(None),
+                default_model: Some(Some("qwen3".to_string())),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+        let updated = repo.get(b).await.unwrap().unwrap();
+        assert_eq!(updated.system_prompt, None);
+        assert_eq!(updated.default_model.as_deref(), Some("qwen3"));
+
+        repo.delete(a).await.unwrap();
+        assert!(matches!(
+            repo.delete(a).await,
+            Err(PresetError::NotFound(id)) if id == a
+        ));
+    }
+
+    #[tokio::test]
+    async fn names_are_unique() {
+        let pool = setup_test_database().await.expect("setup_test_database");
+        let repo = SqlitePresetRepository::new(pool);
+
+        let id = repo.create(new_preset("terse")).await.unwrap();
+        let other = repo.create(new_preset("other")).await.unwrap();
+        assert!(matches!(
+            repo.create(new_preset("terse")).await,
+            Err(PresetError::AlreadyExists(name)) if name == "terse"
+        ));
+        assert!(matches!(
+            repo.update(
+                other,
+                PresetUpdate {
+                    name: Some("terse".to_string()),
+                    ..Default::default()
+                }
+            )
+            .await,
+            Err(PresetError::AlreadyExists(_))
+        ));
+        assert_eq!(repo.get(id).await.unwrap().unwrap().name, "terse");
+    }
+}
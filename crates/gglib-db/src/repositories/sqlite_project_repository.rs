@@ -0,0 +1,295 @@
+//! `SQLite` implementation of the `ProjectRepository` trait.
+
+use async_trait::async_trait;
+use sqlx::{Row, SqlitePool, sqlite::SqliteRow};
+
+use gglib_core::{
+    domain::project::{NewProject, Project, ProjectUpdate},
+    ports::projects::{ProjectError, ProjectRepository},
+};
+
+/// `SQLite` implementation of the `ProjectRepository` trait.
+///
+/// Globs are stored as JSON string arrays; conversation links live in the
+/// `project_conversations` table.
+pub struct SqliteProjectRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteProjectRepository {
+    /// Create a new `SQLite` project repository.
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+}
+
+/// Columns selected for every [`Project`] query, qualified for joins.
+const PROJECT_COLUMNS: &str = "p.id, p.name, p.root_path, p.include_globs, p.exclude_globs, \
+     p.auto_include, p.token_budget, p.created_at, p.updated_at";
+
+fn db_err(e: sqlx::Error) -> ProjectError {
+    ProjectError::Database(e.to_string())
+}
+
+fn globs_to_json(globs: &[String]) -> String {
+    serde_json::to_string(globs).unwrap_or_else(|_| "[]".to_string())
+}
+
+fn globs_from_json(json: &str) -> Vec<String> {
+    serde_json::from_str(json).unwrap_or_default()
+}
+
+/// Map a `projects` row selected with [`PROJECT_COLUMNS`].
+fn project_from_row(row: &SqliteRow) -> Project {
+    let include: String = row.get("include_globs");
+    let exclude: String = row.get("exclude_globs");
+    let budget: Option<i64> = row.get("token_budget");
+    Project {
+        id: row.get("id"),
+        name: row.get("name"),
+        root_path: row.get("root_path"),
+        include_globs: globs_from_json(&include),
+        exclude_globs: globs_from_json(&exclude),
+        auto_include: row.get("auto_include"),
+        token_budget: budget.and_then(|b| u32::try_from(b).ok()),
+        created_at: row.get("created_at"),
+        updated_at: row.get("updated_at"),
+    }
+}
+
+#[async_trait]
+impl ProjectRepository for SqliteProjectRepository {
+    async fn create(&self, project: NewProject) -> Result<i64, ProjectError> {
+        let result = sqlx::query(
+            "INSERT INTO projects (name, root_path, include_globs, exclude_globs, auto_include, token_budget)
+             VALUES (?, ?, ?, ?, ?, ?)",
+        )
+        .bind(&project.name)
+        .bind(&project.root_path)
+        .bind(globs_to_json(&project.include_globs))
+        .bind(globs_to_json(&project.exclude_globs))
+        .bind(project.auto_include)
+        .bind(project.token_budget.map(i64::from))
+        .execute(&self.pool)
+        .await
+        .map_err(db_err)?;
+
+        Ok(result.last_insert_rowid())
+    }
+
+    async fn list(&self) -> Result<Vec<Project>, ProjectError> {
+        let rows = sqlx::query(&format!(
+            "SELECT {PROJECT_COLUMNS} FROM projects p ORDER BY p.name COLLATE NOCASE ASC, p.id ASC"
+        ))
+        .fetch_all(&self.pool)
+        .await
+        .map_err(db_err)?;
+
+        Ok(rows.iter().map(project_from_row).collect())
+    }
+
+    async fn get(&self, id: i64) -> Result<Option<Project>, ProjectError> {
+        let row = sqlx::query(&format!(
+            "SELECT {PROJECT_COLUMNS} FROM projects p WHERE p.id = ?"
+        ))
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(db_err)?;
+
+        Ok(row.as_ref().map(project_from_row))
+    }
+
+    async fn update(&self, id: i64, update: ProjectUpdate) -> Result<(), ProjectError> {
+        let mut project = self.get(id).await?.ok_or(ProjectError::NotFound(id))?;
+        update.apply(&mut project);
+
+        sqlx::query(
+            "UPDATE projects
+             SET name = ?, root_path = ?, include_globs = ?, exclude_globs = ?,
+                 auto_include = ?, token_budget = ?, updated_at = datetime('now')
+             WHERE id = ?",
+        )
+        .bind(&project.name)
+        .bind(&project.root_path)
+        .bind(globs_to_json(&project.include_globs))
+        .bind(globs_to_json(&project.exclude_globs))
+        .bind(project.auto_include)
+        .bind(project.token_budget.map(i64::from))
+        .bind(id)
+        .execute(&self.pool)
+        .await
+        .map_err(db_err)?;
+
+        Ok(())
+    }
+
+    async fn delete(&self, id: i64) -> Result<(), ProjectError> {
+        let result = sqlx::query("DELETE FROM projects WHERE id = ?")
+            .bind(id)
+            .execute(&self.pool)
+            .await
+            .map_err(db_err)?;
+
+        if result.rows_affected() == 0 {
+            return Err(ProjectError::NotFound(id));
+        }
+        Ok(())
+    }
+
+    async fn link_conversation(
+        &self,
+        conversation_id: i64,
+        project_id: i64,
+    ) -> Result<(), ProjectError> {
+        if self.get(project_id).await?.is_none() {
+            return Err(ProjectError::NotFound(project_id));
+        }
+        let conversation_exists = sqlx::query("SELECT 1 FROM chat_conversations WHERE id = ?")
+            .bind(conversation_id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(db_err)?
+            .is_some();
+        if !conversation_exists {
+            return Err(ProjectError::ConversationNotFound(conversation_id));
+        }
+
+        sqlx::query(
+            "INSERT INTO project_conversations (conversation_id, project_id) VALUES (?, ?)
+             ON CONFLICT(conversation_id) DO UPDATE SET project_id = excluded.project_id",
+        )
+        .bind(conversation_id)
+        .bind(project_id)
+        .execute(&self.pool)
+        .await
+        .map_err(db_err)?;
+
+        Ok(())
+    }
+
+    async fn unlink_conversation(&self, conversation_id: i64) -> Result<(), ProjectError> {
+        sqlx::query("DELETE FROM project_conversations WHERE conversation_id = ?")
+            .bind(conversation_id)
+            .execute(&self.pool)
+            .await
+            .map_err(db_err)?;
+
+        Ok(())
+    }
+
+    async fn get_for_conversation(
+        &self,
+        conversation_id: i64,
+    ) -> Result<Option<Project>, ProjectError> {
+        let row = sqlx::query(&format!(
+            "SELECT {PROJECT_COLUMNS}
+             FROM projects p
+             JOIN project_conversations pc ON pc.project_id = p.id
+             WHERE pc.conversation_id = ?"
+        ))
+        .bind(conversation_id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(db_err)?;
+
+        Ok(row.as_ref().map(project_from_row))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use gglib_core::domain::chat::NewConversation;
+    use gglib_core::ports::chat_history::ChatHistoryRepository;
+
+    use crate::repositories::SqliteChatHistoryRepository;
+    use crate::setup::setup_test_database;
+
+    use super::*;
+
+    fn new_project(name: &str) -> NewProject {
+        NewProject {
+            name: name.to_string(),
+            root_path: "/src/app".to_string(),
+            include_globs: vec!["src/**/*.rs".to_string()],
+            exclude_globs: vec!["src/generated/**".to_string()],
+            auto_include: true,
+            token_budget: Some(4096),
+        }
+    }
+
+    #[tokio::test]
+    async fn create_update_and_list() {
+        let pool = setup_test_database().await.expect("setup_test_database");
+        let repo = SqliteProjectRepository::new(pool);
+
+        let b = repo.create(new_project("beta")).await.unwrap();
+        let a = repo.create(new_project("Alpha")).await.unwrap();
+
+        let listed = repo.list().await.unwrap();
+        assert_eq!(listed.iter().map(|p| p.id).collect::<Vec<_>>(), [a, b]);
+        assert_eq!(listed[0].include_globs, ["src/**/*.rs"]);
+        assert_eq!(listed[0].token_budget, Some(4096));
+
+        repo.update(
+            a,
+            ProjectUpdate {
+                auto_include: Some(false),
+                token_budget: Some(None),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+        let updated = repo.get(a).await.unwrap().unwrap();
+        assert!(!updated.auto_include);
+        assert_eq!(updated.token_budget, None);
+        assert_eq!(updated.exclude_globs, ["src/generated/**"]);
+
+        assert!(matches!(
+            repo.update(999, ProjectUpdate::default()).await,
+            Err(ProjectError::NotFound(999))
+        ));
+    }
+
+    #[tokio::test]
+    async fn conversation_links_follow_deletes() {
+        let pool = setup_test_database().await.expect("setup_test_database");
+        let chat = SqliteChatHistoryRepository::new(pool.clone());
+        let repo = SqliteProjectRepository::new(pool);
+
+        let cid = chat
+            .create_conversation(NewConversation {
+                title: "Refactor".to_string(),
+                model_id: None,
+                system_prompt: None,
+                settings: None,
+                incognito: false,
+            })
+            .await
+            .unwrap();
+        let first = repo.create(new_project("one")).await.unwrap();
+        let second = repo.create(new_project("two")).await.unwrap();
+
+        assert!(matches!(
+            repo.link_conversation(999, first).await,
+            Err(ProjectError::ConversationNotFound(999))
+        ));
+        assert!(matches!(
+            repo.link_conversation(cid, 999).await,
+            Err(ProjectError::NotFound(999))
+        ));
+
+        repo.link_conversation(cid, first).await.unwrap();
+        repo.link_conversation(cid, second).await.unwrap();
+        let linked = repo.get_for_conversation(cid).await.unwrap().unwrap();
+        assert_eq!(linked.id, second);
+
+        repo.delete(second).await.unwrap();
+        assert!(repo.get_for_conversation(cid).await.unwrap().is_none());
+
+        repo.link_conversation(cid, first).await.unwrap();
+        repo.unlink_conversation(cid).await.unwrap();
+        assert!(repo.get_for_conversation(cid).await.unwrap().is_none());
+    }
+}
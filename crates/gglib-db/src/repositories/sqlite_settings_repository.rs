@@ -11,6 +11,9 @@ use gglib_core::{RepositoryError, Settings, SettingsRepository};
 /// Stores each setting as an individual row in the key-value table, using the
 /// `serde` field name as the key and a compact JSON encoding as the value.
 /// `None`-valued fields are not stored; an absent row means "use default".
+///
+/// The settings version lives in the single-row `settings_version` table and
+/// is bumped in the same transaction as the values it versions.
 pub struct SqliteSettingsRepository {
     pool: SqlitePool,
 }
@@ -38,32 +41,32 @@ impl SqliteSettingsRepository {
         .await
         .map_err(|e| RepositoryError::Storage(e.to_string()))?;
 
-        Ok(())
-    }
-}
-
-#[async_trait]
-impl SettingsRepository for SqliteSettingsRepository {
-    async fn load(&self) -> Result<Settings, RepositoryError> {
-        let rows = sqlx::query("SELECT key, value FROM settings_kv")
-            .fetch_all(&self.pool)
-            .await
-            .map_err(|e| RepositoryError::Storage(e.to_string()))?;
-
-        let mut map = Map::new();
-        for row in rows {
-            let key: String = row.get("key");
-            let raw: String = row.get("value");
-            let val: Value =
-                serde_json::from_str(&raw).map_err(|e| RepositoryError::Storage(e.to_string()))?;
-            map.insert(key, val);
-        }
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS settings_version (
+                id INTEGER PRIMARY KEY CHECK (id = 1),
+                version INTEGER NOT NULL
+            )
+            "#,
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| RepositoryError::Storage(e.to_string()))?;
 
-        serde_json::from_value(Value::Object(map))
-            .map_err(|e| RepositoryError::Storage(e.to_string()))
+        Ok(())
     }
 
-    async fn save(&self, settings: &Settings) -> Result<(), RepositoryError> {
+    /// Write every field and bump the version in one transaction.
+    ///
+    /// With `expected` set, the version is only bumped (and anything
+    /// written) if it still equals `expected`. The bump is the transaction's
+    /// first write, so a concurrent writer waits on the database lock and
+    /// then sees the new version.
+    async fn write(
+        &self,
+        settings: &Settings,
+        expected: Option<u64>,
+    ) -> Result<u64, RepositoryError> {
         let map = match serde_json::to_value(settings)
             .map_err(|e| RepositoryError::Storage(e.to_string()))?
         {
@@ -83,6 +86,34 @@ impl SettingsRepository for SqliteSettingsRepository {
             .await
             .map_err(|e| RepositoryError::Storage(e.to_string()))?;
 
+        sqlx::query("INSERT OR IGNORE INTO settings_version (id, version) VALUES (1, 0)")
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| RepositoryError::Storage(e.to_string()))?;
+
+        let bumped = match expected {
+            Some(expected) => sqlx::query(
+                "UPDATE settings_version SET version = version + 1 WHERE id = 1 AND version = ?",
+            )
+            .bind(to_db_version(expected)?),
+            None => sqlx::query("UPDATE settings_version SET version = version + 1 WHERE id = 1"),
+        }
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| RepositoryError::Storage(e.to_string()))?;
+
+        let version: i64 = sqlx::query_scalar("SELECT version FROM settings_version WHERE id = 1")
+            .fetch_one(&mut *tx)
+            .await
+            .map_err(|e| RepositoryError::Storage(e.to_string()))?;
+
+        if bumped.rows_affected() == 0 {
+            return Err(RepositoryError::Conflict(format!(
+                "settings are at version {version}, expected {}",
+                expected.unwrap_or_default()
+            )));
+        }
+
         for (key, value) in &map {
             if value.is_null() {
                 sqlx::query("DELETE FROM settings_kv WHERE key = ?")
@@ -107,7 +138,65 @@ impl SettingsRepository for SqliteSettingsRepository {
             .await
             .map_err(|e| RepositoryError::Storage(e.to_string()))?;
 
-        Ok(())
+        from_db_version(version)
+    }
+}
+
+fn to_db_version(version: u64) -> Result<i64, RepositoryError> {
+    i64::try_from(version).map_err(|_| {
+        RepositoryError::Constraint(format!("settings version {version} out of range"))
+    })
+}
+
+fn from_db_version(version: i64) -> Result<u64, RepositoryError> {
+    u64::try_from(version)
+        .map_err(|_| RepositoryError::Storage(format!("negative settings version {version}")))
+}
+
+#[async_trait]
+impl SettingsRepository for SqliteSettingsRepository {
+    #[tracing::instrument(name = "db.settings.load", skip_all)]
+    async fn load(&self) -> Result<Settings, RepositoryError> {
+        let rows = sqlx::query("SELECT key, value FROM settings_kv")
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| RepositoryError::Storage(e.to_string()))?;
+
+        let mut map = Map::new();
+        for row in rows {
+            let key: String = row.get("key");
+            let raw: String = row.get("value");
+            let val: Value =
+                serde_json::from_str(&raw).map_err(|e| RepositoryError::Storage(e.to_string()))?;
+            map.insert(key, val);
+        }
+
+        serde_json::from_value(Value::Object(map))
+            .map_err(|e| RepositoryError::Storage(e.to_string()))
+    }
+
+    #[tracing::instrument(name = "db.settings.save", skip_all)]
+    async fn save(&self, settings: &Settings) -> Result<(), RepositoryError> {
+        self.write(settings, None).await.map(drop)
+    }
+
+    #[tracing::instrument(name = "db.settings.version", skip_all)]
+    async fn version(&self) -> Result<u64, RepositoryError> {
+        let version: Option<i64> =
+            sqlx::query_scalar("SELECT version FROM settings_version WHERE id = 1")
+                .fetch_optional(&self.pool)
+                .await
+                .map_err(|e| RepositoryError::Storage(e.to_string()))?;
+        version.map_or(Ok(0), from_db_version)
+    }
+
+    #[tracing::instrument(name = "db.settings.save_if_version", skip_all)]
+    async fn save_if_version(
+        &self,
+        settings: &Settings,
+        expected: u64,
+    ) -> Result<u64, RepositoryError> {
+        self.write(settings, Some(expected)).await
     }
 }
 
@@ -182,4 +271,40 @@ mod tests {
             "proxy_port row should be deleted after saving None"
         );
     }
+
+    #[tokio::test]
+    async fn test_every_save_bumps_the_version() {
+        let pool = SqlitePool::connect("sqlite::memory:").await.unwrap();
+        let repo = SqliteSettingsRepository::new(pool);
+        repo.ensure_table().await.unwrap();
+
+        assert_eq!(repo.version().await.unwrap(), 0);
+        repo.save(&Settings::with_defaults()).await.unwrap();
+        assert_eq!(repo.version().await.unwrap(), 1);
+        repo.save(&Settings::with_defaults()).await.unwrap();
+        assert_eq!(repo.version().await.unwrap(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_save_if_version_rejects_a_stale_version() {
+        let pool = SqlitePool::connect("sqlite::memory:").await.unwrap();
+        let repo = SqliteSettingsRepository::new(pool);
+        repo.ensure_table().await.unwrap();
+
+        let first = Settings {
+            proxy_port: Some(9090),
+            ..Settings::default()
+        };
+        assert_eq!(repo.save_if_version(&first, 0).await.unwrap(), 1);
+
+        // A second writer that read version 0 must not overwrite the first.
+        let stale = Settings {
+            proxy_port: Some(7070),
+            ..Settings::default()
+        };
+        let err = repo.save_if_version(&stale, 0).await.unwrap_err();
+        assert!(matches!(err, RepositoryError::Conflict(_)), "{err:?}");
+        assert_eq!(repo.load().await.unwrap().proxy_port, Some(9090));
+        assert_eq!(repo.version().await.unwrap(), 1);
+    }
 }
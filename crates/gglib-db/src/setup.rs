@@ -15,10 +15,11 @@ use std::time::Duration;
 /// Sets up the `SQLite` database connection and ensures the schema exists.
 ///
 /// This function:
-/// 1. Establishes a connection to the `SQLite` database file
-/// 2. Creates the database file if it doesn't exist
-/// 3. Creates all tables and indexes
-/// 4. Runs any necessary schema migrations
+/// 1. Swaps in a database restored from backup, if one is staged
+/// 2. Establishes a connection to the `SQLite` database file
+/// 3. Creates the database file if it doesn't exist
+/// 4. Creates all tables and indexes
+/// 5. Runs any necessary schema migrations
 ///
 /// # Arguments
 ///
@@ -51,6 +52,7 @@ pub async fn setup_database(db_path: &Path) -> Result<SqlitePool> {
     if let Some(parent) = db_path.parent() {
         std::fs::create_dir_all(parent)?;
     }
+    apply_pending_restore(db_path)?;
 
     let pool = SqlitePoolOptions::new()
         .max_connections(5)
@@ -73,6 +75,31 @@ pub async fn setup_database(db_path: &Path) -> Result<SqlitePool> {
     Ok(pool)
 }
 
+/// Replace the database with a staged restore, if there is one.
+///
+/// The current database and its WAL files are kept as `*.pre-restore` so a
+/// bad restore can be undone by hand.
+fn apply_pending_restore(db_path: &Path) -> Result<()> {
+    let staged = gglib_core::paths::pending_restore_path(db_path);
+    if !staged.exists() {
+        return Ok(());
+    }
+
+    for suffix in ["", "-wal", "-shm"] {
+        let mut current = db_path.as_os_str().to_os_string();
+        current.push(suffix);
+        let current = std::path::PathBuf::from(current);
+        if current.exists() {
+            let mut aside = current.clone().into_os_string();
+            aside.push(".pre-restore");
+            std::fs::rename(&current, aside)?;
+        }
+    }
+    std::fs::rename(&staged, db_path)?;
+    tracing::info!(path = %db_path.display(), "Restored database from backup");
+    Ok(())
+}
+
 /// Sets up an in-memory `SQLite` database for testing.
 ///
 /// Creates a fresh in-memory database with the full production schema.
@@ -101,6 +128,38 @@ pub async fn cleanup_zombie_benchmark_runs(pool: &SqlitePool) -> Result<()> {
     Ok(())
 }
 
+/// Run `PRAGMA integrity_check` against an existing database.
+///
+/// Opens the file read-only without creating it or touching the schema, so
+/// it is safe on a database that fails to open normally. Returns the
+/// problems `SQLite` reported; an empty list means the database is intact.
+///
+/// # Errors
+///
+/// Returns an error if the file cannot be opened as a `SQLite` database.
+pub async fn check_integrity(db_path: &Path) -> Result<Vec<String>> {
+    let pool = SqlitePoolOptions::new()
+        .max_connections(1)
+        .connect_with(
+            SqliteConnectOptions::new()
+                .filename(db_path)
+                .read_only(true)
+                .busy_timeout(Duration::from_secs(5)),
+        )
+        .await?;
+
+    let rows: Vec<(String,)> = sqlx::query_as("PRAGMA integrity_check")
+        .fetch_all(&pool)
+        .await?;
+    pool.close().await;
+
+    Ok(rows
+        .into_iter()
+        .map(|(line,)| line)
+        .filter(|line| line != "ok")
+        .collect())
+}
+
 /// Creates the complete database schema.
 ///
 /// This function creates all tables and indexes required by the application.
@@ -132,7 +191,78 @@ async fn create_schema(pool: &SqlitePool) -> Result<()> {
             tags TEXT DEFAULT '[]',
             model_key TEXT NOT NULL,
             file_paths_json TEXT,
-            capabilities INTEGER DEFAULT 0
+            capabilities INTEGER DEFAULT 0,
+            card TEXT,
+            deleted_at TEXT,
+            trash_json TEXT,
+            updated_at_ms INTEGER NOT NULL DEFAULT 0,
+            capabilities_version INTEGER DEFAULT 0,
+            license TEXT
+        )
+        "#,
+    )
+    .execute(pool)
+    .await?;
+
+    // Migration: Add model card column (Markdown, generated for local imports).
+    let _ = sqlx::query(r#"ALTER TABLE models ADD COLUMN card TEXT"#)
+        .execute(pool)
+        .await;
+    // Ignore error if column already exists
+
+    // Migration: Add soft-delete columns for the model trash. `trash_json`
+    // records where the removed model's files were moved.
+    let _ = sqlx::query(r#"ALTER TABLE models ADD COLUMN deleted_at TEXT"#)
+        .execute(pool)
+        .await;
+    let _ = sqlx::query(r#"ALTER TABLE models ADD COLUMN trash_json TEXT"#)
+        .execute(pool)
+        .await;
+    // Ignore errors if columns already exist
+
+    // Migration: Add the last-write time (ms since the epoch) that change
+    // deltas are read from. Existing rows predate every delta.
+    let _ =
+        sqlx::query(r#"ALTER TABLE models ADD COLUMN updated_at_ms INTEGER NOT NULL DEFAULT 0"#)
+            .execute(pool)
+            .await;
+    // Ignore error if column already exists
+
+    // Migration: Add the capability detector version. Existing rows count as
+    // detected by the unversioned detector (0); NULL marks hand-set flags.
+    let _ = sqlx::query(r#"ALTER TABLE models ADD COLUMN capabilities_version INTEGER DEFAULT 0"#)
+        .execute(pool)
+        .await;
+    // Ignore error if column already exists
+
+    // Migration: Add the license column. When it is new, fill it in from
+    // the GGUF metadata already stored; HF license tags were never kept.
+    if sqlx::query(r#"ALTER TABLE models ADD COLUMN license TEXT"#)
+        .execute(pool)
+        .await
+        .is_ok()
+    {
+        sqlx::query(
+            r#"UPDATE models SET license = NULLIF(lower(trim(COALESCE(
+                   json_extract(metadata, '$."general.license"'),
+                   json_extract(metadata, '$."general.license.name"')
+               ))), '')
+               WHERE json_valid(metadata)"#,
+        )
+        .execute(pool)
+        .await?;
+    }
+
+    sqlx::query("CREATE INDEX IF NOT EXISTS idx_models_updated_at ON models(updated_at_ms)")
+        .execute(pool)
+        .await?;
+
+    // Ids of models deleted outright, so change deltas can report them.
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS model_tombstones (
+            id INTEGER PRIMARY KEY NOT NULL,
+            deleted_at_ms INTEGER NOT NULL
         )
         "#,
     )
@@ -178,6 +308,58 @@ async fn create_schema(pool: &SqlitePool) -> Result<()> {
         .execute(pool)
         .await?;
 
+    // Create daily per-model usage aggregates; one row per model per day
+    // (local date, YYYY-MM-DD) that saw any requests or serve time.
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS model_usage_daily (
+            model_id INTEGER NOT NULL,
+            day TEXT NOT NULL,
+            requests INTEGER NOT NULL DEFAULT 0,
+            prompt_tokens INTEGER NOT NULL DEFAULT 0,
+            completion_tokens INTEGER NOT NULL DEFAULT 0,
+            serve_seconds REAL NOT NULL DEFAULT 0,
+            busy_seconds REAL NOT NULL DEFAULT 0,
+            PRIMARY KEY (model_id, day),
+            FOREIGN KEY (model_id) REFERENCES models(id) ON DELETE CASCADE
+        )
+        "#,
+    )
+    .execute(pool)
+    .await?;
+
+    // Migration: Add the time servers spent on requests, used for energy
+    // estimates. Days recorded before it count as idle.
+    let _ = sqlx::query(
+        r#"ALTER TABLE model_usage_daily ADD COLUMN busy_seconds REAL NOT NULL DEFAULT 0"#,
+    )
+    .execute(pool)
+    .await;
+    // Ignore error if column already exists
+
+    // Create the GGUF metadata cache; one row per file, valid only while the
+    // file's size and modification time (ms since the epoch) still match.
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS gguf_metadata_cache (
+            path TEXT PRIMARY KEY NOT NULL,
+            size INTEGER NOT NULL,
+            modified_ms INTEGER NOT NULL,
+            name TEXT,
+            architecture TEXT,
+            quantization TEXT,
+            param_count_b REAL,
+            context_length INTEGER,
+            expert_count INTEGER,
+            expert_used_count INTEGER,
+            expert_shared_count INTEGER,
+            metadata TEXT NOT NULL
+        )
+        "#,
+    )
+    .execute(pool)
+    .await?;
+
     // Create settings table
     sqlx::query(
         r#"
@@ -191,6 +373,18 @@ async fn create_schema(pool: &SqlitePool) -> Result<()> {
     .execute(pool)
     .await?;
 
+    // Create settings version table (see SqliteSettingsRepository)
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS settings_version (
+            id INTEGER PRIMARY KEY CHECK (id = 1),
+            version INTEGER NOT NULL
+        )
+        "#,
+    )
+    .execute(pool)
+    .await?;
+
     // Guard: drop chat tables if the schema is out of date (missing 'tool' role).
     // No backwards-compat needed — tables are recreated below.
     let needs_recreate: bool = sqlx::query_scalar::<_, String>(
@@ -263,6 +457,153 @@ async fn create_schema(pool: &SqlitePool) -> Result<()> {
         .await;
     // Ignore error if column already exists
 
+    // Migration: Add message versioning columns for regenerate/edit.
+    // `version_of` points at the first message of a revision chain;
+    // `superseded_by` marks rows that a later revision replaced.
+    let _ = sqlx::query(r#"ALTER TABLE chat_messages ADD COLUMN version_of INTEGER"#)
+        .execute(pool)
+        .await;
+    let _ = sqlx::query(r#"ALTER TABLE chat_messages ADD COLUMN superseded_by INTEGER"#)
+        .execute(pool)
+        .await;
+    // Ignore errors if columns already exist
+
+    sqlx::query("CREATE INDEX IF NOT EXISTS idx_messages_version_of ON chat_messages(version_of)")
+        .execute(pool)
+        .await?;
+
+    // Read-only share links. The token is signed over the share ID, so
+    // deleting the row revokes the link.
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS conversation_shares (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            conversation_id INTEGER NOT NULL,
+            created_at TEXT NOT NULL,
+            expires_at TEXT NOT NULL,
+            FOREIGN KEY (conversation_id) REFERENCES chat_conversations(id) ON DELETE CASCADE
+        )
+        "#,
+    )
+    .execute(pool)
+    .await?;
+
+    // Create attachments table. Bytes live in the content-addressed blob
+    // store; rows sharing a sha256 share one blob.
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS attachments (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            message_id INTEGER,
+            sha256 TEXT NOT NULL,
+            file_name TEXT NOT NULL,
+            mime_type TEXT NOT NULL,
+            size_bytes INTEGER NOT NULL,
+            created_at TEXT NOT NULL DEFAULT (datetime('now')),
+            FOREIGN KEY (message_id) REFERENCES chat_messages(id) ON DELETE CASCADE
+        )
+        "#,
+    )
+    .execute(pool)
+    .await?;
+
+    sqlx::query("CREATE INDEX IF NOT EXISTS idx_attachments_message ON attachments(message_id)")
+        .execute(pool)
+        .await?;
+
+    sqlx::query("CREATE INDEX IF NOT EXISTS idx_attachments_sha256 ON attachments(sha256)")
+        .execute(pool)
+        .await?;
+
+    // Create projects table. Globs are JSON string arrays.
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS projects (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            name TEXT NOT NULL,
+            root_path TEXT NOT NULL,
+            include_globs TEXT NOT NULL DEFAULT '[]',
+            exclude_globs TEXT NOT NULL DEFAULT '[]',
+            auto_include INTEGER NOT NULL DEFAULT 1,
+            token_budget INTEGER,
+            created_at TEXT NOT NULL DEFAULT (datetime('now')),
+            updated_at TEXT NOT NULL DEFAULT (datetime('now'))
+        )
+        "#,
+    )
+    .execute(pool)
+    .await?;
+
+    // Conversation → project links; a conversation has at most one project.
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS project_conversations (
+            conversation_id INTEGER PRIMARY KEY,
+            project_id INTEGER NOT NULL,
+            FOREIGN KEY (conversation_id) REFERENCES chat_conversations(id) ON DELETE CASCADE,
+            FOREIGN KEY (project_id) REFERENCES projects(id) ON DELETE CASCADE
+        )
+        "#,
+    )
+    .execute(pool)
+    .await?;
+
+    // Create presets table. Sampling is an `InferenceConfig` JSON object,
+    // tools and MCP server ids are JSON arrays.
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS presets (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            name TEXT NOT NULL UNIQUE,
+            description TEXT,
+            system_prompt TEXT,
+            sampling TEXT NOT NULL DEFAULT '{}',
+            tools TEXT NOT NULL DEFAULT '[]',
+            mcp_server_ids TEXT NOT NULL DEFAULT '[]',
+            default_model TEXT,
+            created_at TEXT NOT NULL DEFAULT (datetime('now')),
+            updated_at TEXT NOT NULL DEFAULT (datetime('now'))
+        )
+        "#,
+    )
+    .execute(pool)
+    .await?;
+
+    // Create pipeline run tables. The definition and inputs are stored as
+    // JSON; each completed step's output is a row of its own so a failed run
+    // keeps everything that finished before it.
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS pipeline_runs (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            name TEXT NOT NULL,
+            definition TEXT NOT NULL,
+            inputs TEXT NOT NULL DEFAULT '{}',
+            status TEXT NOT NULL DEFAULT 'running',
+            error TEXT,
+            created_at TEXT NOT NULL DEFAULT (datetime('now')),
+            updated_at TEXT NOT NULL DEFAULT (datetime('now'))
+        )
+        "#,
+    )
+    .execute(pool)
+    .await?;
+
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS pipeline_step_results (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            run_id INTEGER NOT NULL,
+            step_id TEXT NOT NULL,
+            output TEXT NOT NULL,
+            created_at TEXT NOT NULL DEFAULT (datetime('now')),
+            FOREIGN KEY (run_id) REFERENCES pipeline_runs(id) ON DELETE CASCADE
+        )
+        "#,
+    )
+    .execute(pool)
+    .await?;
+
     // Create MCP servers table
     sqlx::query(
         r#"
@@ -331,6 +672,56 @@ async fn create_schema(pool: &SqlitePool) -> Result<()> {
     .execute(pool)
     .await?;
 
+    // Drained queue runs and the per-artifact completion records of each run,
+    // kept so past downloads (and their retries) can be audited.
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS download_runs (
+            run_id TEXT PRIMARY KEY NOT NULL,
+            started_at_ms INTEGER NOT NULL,
+            completed_at_ms INTEGER NOT NULL,
+            total_attempts_downloaded INTEGER NOT NULL DEFAULT 0,
+            total_attempts_failed INTEGER NOT NULL DEFAULT 0,
+            total_attempts_cancelled INTEGER NOT NULL DEFAULT 0,
+            unique_models_downloaded INTEGER NOT NULL DEFAULT 0,
+            unique_models_failed INTEGER NOT NULL DEFAULT 0,
+            unique_models_cancelled INTEGER NOT NULL DEFAULT 0
+        )
+        "#,
+    )
+    .execute(pool)
+    .await?;
+
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS download_history (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            run_id TEXT NOT NULL,
+            completion_key TEXT NOT NULL,
+            display_name TEXT NOT NULL,
+            last_result TEXT NOT NULL,
+            completed_at_ms INTEGER NOT NULL,
+            download_ids TEXT NOT NULL DEFAULT '[]',
+            attempts_downloaded INTEGER NOT NULL DEFAULT 0,
+            attempts_failed INTEGER NOT NULL DEFAULT 0,
+            attempts_cancelled INTEGER NOT NULL DEFAULT 0,
+            FOREIGN KEY (run_id) REFERENCES download_runs(run_id) ON DELETE CASCADE
+        )
+        "#,
+    )
+    .execute(pool)
+    .await?;
+
+    sqlx::query(
+        "CREATE INDEX IF NOT EXISTS idx_download_runs_completed ON download_runs(completed_at_ms)",
+    )
+    .execute(pool)
+    .await?;
+
+    sqlx::query("CREATE INDEX IF NOT EXISTS idx_download_history_run ON download_history(run_id)")
+        .execute(pool)
+        .await?;
+
     // Create orchestrator_runs table for persistent council run records.
     // Table name: orchestrator_runs — historical name, kept for schema compatibility.
     sqlx::query(
@@ -531,6 +922,27 @@ async fn create_schema(pool: &SqlitePool) -> Result<()> {
     .execute(pool)
     .await?;
 
+    // Create the audit log of administrative actions. Targets are plain
+    // text, not foreign keys: entries outlive what they name.
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS audit_log (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            at TEXT NOT NULL,
+            actor TEXT NOT NULL,
+            action TEXT NOT NULL,
+            target TEXT,
+            summary TEXT
+        )
+        "#,
+    )
+    .execute(pool)
+    .await?;
+
+    sqlx::query("CREATE INDEX IF NOT EXISTS idx_audit_log_action ON audit_log(action, id)")
+        .execute(pool)
+        .await?;
+
     Ok(())
 }
 
@@ -567,4 +979,39 @@ mod tests {
             .await
             .unwrap();
     }
+
+    #[tokio::test]
+    async fn test_check_integrity() {
+        let dir = tempfile::tempdir().unwrap();
+        let db = dir.path().join("gglib.db");
+        setup_database(&db).await.unwrap().close().await;
+        assert!(check_integrity(&db).await.unwrap().is_empty());
+
+        let garbage = dir.path().join("garbage.db");
+        std::fs::write(&garbage, vec![0x42; 4096]).unwrap();
+        assert!(check_integrity(&garbage).await.is_err());
+    }
+
+    #[test]
+    fn test_apply_pending_restore_swaps_and_keeps_the_old_database() {
+        let dir = tempfile::tempdir().unwrap();
+        let db = dir.path().join("gglib.db");
+        std::fs::write(&db, "old").unwrap();
+        std::fs::write(dir.path().join("gglib.db-wal"), "wal").unwrap();
+
+        apply_pending_restore(&db).unwrap();
+        assert_eq!(std::fs::read_to_string(&db).unwrap(), "old");
+
+        std::fs::write(gglib_core::paths::pending_restore_path(&db), "new").unwrap();
+        apply_pending_restore(&db).unwrap();
+
+        assert_eq!(std::fs::read_to_string(&db).unwrap(), "new");
+        assert!(!gglib_core::paths::pending_restore_path(&db).exists());
+        let aside = |name: &str| dir.path().join(format!("{name}.pre-restore"));
+        assert_eq!(std::fs::read_to_string(aside("gglib.db")).unwrap(), "old");
+        assert_eq!(
+            std::fs::read_to_string(aside("gglib.db-wal")).unwrap(),
+            "wal"
+        );
+    }
 }
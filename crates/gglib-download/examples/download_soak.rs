@@ -0,0 +1,361 @@
+//! Soak test for the download pipeline under injected faults.
+//!
+//! Queues single-file and sharded downloads against a manager whose
+//! transfers come from the fault injector, retries whatever fails (as a user
+//! would) and, once the queue drains, checks that:
+//!
+//! - every download was registered exactly once, with all of its shards;
+//! - every registered file is complete and in the models directory;
+//! - nothing is left behind in the staging directory;
+//! - every truncated transfer was quarantined instead of registered;
+//! - connection resets were resumed rather than restarted.
+//!
+//! ```text
+//! cargo run -p gglib-download --features fault-injection --example download_soak -- \
+//!     --rounds 5 --models 12 --seed 42
+//! ```
+//!
+//! Each round starts from an empty models directory and uses the seed plus
+//! the round number, so a failing round can be replayed on its own.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use async_trait::async_trait;
+use chrono::Utc;
+
+use gglib_core::ports::{
+    CompletedDownload, DownloadFaultConfig, DownloadManagerConfig, DownloadManagerPort,
+    DownloadRequest, ModelRegistrarPort, NoopDownloadEmitter, RepositoryError,
+};
+use gglib_core::utils::clock::SystemClock;
+use gglib_core::utils::fs::RealFs;
+use gglib_core::{Model, ModelRepository, NewModel};
+use gglib_download::quarantine::list_quarantine;
+use gglib_download::{DownloadManagerDeps, Quantization, build_download_manager};
+use gglib_test_support::{
+    MemoryDownloadStateRepository, MemoryModelRepository, ScriptedHfClient, ScriptedRepo,
+};
+
+/// Retries per download before the soak gives up on it.
+const MAX_ATTEMPTS: u32 = 25;
+/// How long one round may take before it is reported as stuck.
+const ROUND_TIMEOUT: Duration = Duration::from_mins(2);
+
+struct Options {
+    rounds: u64,
+    models: u32,
+    seed: u64,
+}
+
+impl Options {
+    fn parse() -> Result<Self, String> {
+        let mut options = Self {
+            rounds: 3,
+            models: 8,
+            seed: 1,
+        };
+        let mut args = std::env::args().skip(1);
+        while let Some(flag) = args.next() {
+            let value = args.next().ok_or_else(|| format!("{flag} needs a value"))?;
+            let number = value
+                .parse::<u64>()
+                .map_err(|e| format!("{flag} {value}: {e}"))?;
+            match flag.as_str() {
+                "--rounds" => options.rounds = number,
+                "--models" => {
+                    options.models = u32::try_from(number).map_err(|e| e.to_string())?;
+                }
+                "--seed" => options.seed = number,
+                _ => return Err(format!("unknown option {flag}")),
+            }
+        }
+        Ok(options)
+    }
+}
+
+/// Registers into an in-memory repository and remembers every call.
+#[derive(Default)]
+struct RecordingRegistrar {
+    models: MemoryModelRepository,
+    registered: Mutex<Vec<CompletedDownload>>,
+}
+
+#[async_trait]
+impl ModelRegistrarPort for RecordingRegistrar {
+    async fn register_model(&self, download: &CompletedDownload) -> Result<Model, RepositoryError> {
+        self.registered.lock().unwrap().push(download.clone());
+        let mut model = NewModel::new(
+            download.repo_id.clone(),
+            download.primary_path.clone(),
+            0.0,
+            Utc::now(),
+        );
+        model.hf_repo_id = Some(download.repo_id.clone());
+        self.models.insert(&model).await
+    }
+
+    async fn register_model_from_path(
+        &self,
+        repo_id: &str,
+        _commit_sha: &str,
+        file_path: &Path,
+        _quantization: &str,
+    ) -> Result<Model, RepositoryError> {
+        let model = NewModel::new(
+            repo_id.to_string(),
+            file_path.to_path_buf(),
+            0.0,
+            Utc::now(),
+        );
+        self.models.insert(&model).await
+    }
+}
+
+/// One repository to download: its shard file names and sizes.
+struct Planned {
+    repo_id: String,
+    files: Vec<(String, u64)>,
+}
+
+fn plan(models: u32) -> Vec<Planned> {
+    (0..models)
+        .map(|i| {
+            let shards = 1 + i % 3;
+            let files = (1..=shards)
+                .map(|k| {
+                    let name = if shards == 1 {
+                        format!("soak-{i}-Q4_K_M.gguf")
+                    } else {
+                        format!("soak-{i}-Q4_K_M-{k:05}-of-{shards:05}.gguf")
+                    };
+                    (name, 4096 + 1024 * u64::from((i * 7 + k * 3) % 60))
+                })
+                .collect();
+            Planned {
+                repo_id: format!("soak/model-{i}"),
+                files,
+            }
+        })
+        .collect()
+}
+
+const fn fault_config(seed: u64) -> DownloadFaultConfig {
+    DownloadFaultConfig {
+        seed,
+        connection_reset_rate: 0.2,
+        slow_stream_rate: 0.1,
+        truncate_rate: 0.1,
+        rate_limit_rate: 0.1,
+        chunk_size: 1024,
+        slow_chunk_delay: Duration::from_millis(2),
+    }
+}
+
+/// Every file under `dir`, recursively.
+fn files_under(dir: &Path) -> Vec<PathBuf> {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    entries
+        .flatten()
+        .flat_map(|entry| {
+            let path = entry.path();
+            if path.is_dir() {
+                files_under(&path)
+            } else {
+                vec![path]
+            }
+        })
+        .collect()
+}
+
+/// Run one round; returns the problems found.
+async fn round(seed: u64, models: u32) -> Result<Vec<String>, String> {
+    let dir = tempfile::tempdir().map_err(|e| e.to_string())?;
+    let planned = plan(models);
+    let hf = planned.iter().fold(ScriptedHfClient::new(), |hf, p| {
+        let repo = p
+            .files
+            .iter()
+            .fold(ScriptedRepo::new(&p.repo_id), |repo, (name, size)| {
+                repo.with_file(name, *size)
+            });
+        hf.with_repo(repo)
+    });
+    let registrar = Arc::new(RecordingRegistrar::default());
+    let config = DownloadManagerConfig::new(dir.path().to_path_buf())
+        .with_max_queue_size(10_000)
+        .with_fault_injection(fault_config(seed));
+    let manager = Arc::new(build_download_manager(DownloadManagerDeps {
+        model_registrar: Arc::clone(&registrar),
+        download_repo: Arc::new(MemoryDownloadStateRepository::new()),
+        hf_client: Arc::new(hf),
+        event_emitter: Arc::new(NoopDownloadEmitter::new()),
+        arch_support: None,
+        clock: Arc::new(SystemClock),
+        fs: Arc::new(RealFs),
+        config,
+    }));
+
+    let mut ids = HashMap::new();
+    for p in &planned {
+        let id = manager
+            .queue_download(DownloadRequest::new(&p.repo_id, Quantization::Q4KM))
+            .await
+            .map_err(|e| format!("queueing {}: {e}", p.repo_id))?;
+        ids.insert(id.to_string(), id);
+    }
+    manager.ensure_runner();
+
+    let mut problems = Vec::new();
+    let mut attempts: HashMap<String, u32> = HashMap::new();
+    let started = Instant::now();
+    loop {
+        tokio::time::sleep(Duration::from_millis(5)).await;
+        let snapshot = manager
+            .get_queue_snapshot()
+            .await
+            .map_err(|e| e.to_string())?;
+        for failure in &snapshot.recent_failures {
+            let tries = attempts.entry(failure.id.clone()).or_default();
+            *tries += 1;
+            let Some(id) = ids.get(&failure.id) else {
+                return Err(format!("unknown failed download {}", failure.id));
+            };
+            if *tries > MAX_ATTEMPTS {
+                problems.push(format!(
+                    "{}: gave up after {MAX_ATTEMPTS} retries",
+                    failure.id
+                ));
+                manager
+                    .remove_from_queue(id)
+                    .await
+                    .map_err(|e| e.to_string())?;
+            } else {
+                manager.retry(id).await.map_err(|e| e.to_string())?;
+            }
+        }
+        if snapshot.items.is_empty()
+            && snapshot.recent_failures.is_empty()
+            && snapshot.active_count == 0
+        {
+            break;
+        }
+        if started.elapsed() > ROUND_TIMEOUT {
+            return Err(format!("queue still busy after {ROUND_TIMEOUT:?}"));
+        }
+    }
+
+    check(dir.path(), &planned, &registrar, &mut problems);
+    let stats = manager.fault_stats().unwrap_or_default();
+    let quarantined = list_quarantine(dir.path()).map_or(0, |entries| entries.len() as u64);
+    if quarantined != stats.truncations {
+        problems.push(format!(
+            "{} truncated transfers but {quarantined} quarantined files",
+            stats.truncations
+        ));
+    }
+    if stats.connection_resets > 0 && stats.resumed_bytes == 0 {
+        problems.push("connection resets were never resumed".to_string());
+    }
+    println!(
+        "seed {seed}: {} attempts, {} resets, {} slow, {} truncated, {} rate-limited, \
+         {} bytes resumed, {} retries, {:.1}s",
+        stats.attempts,
+        stats.connection_resets,
+        stats.slow_streams,
+        stats.truncations,
+        stats.rate_limits,
+        stats.resumed_bytes,
+        attempts.values().sum::<u32>(),
+        started.elapsed().as_secs_f64(),
+    );
+    Ok(problems)
+}
+
+/// Check registrations and the files on disk against the plan.
+fn check(
+    models_dir: &Path,
+    planned: &[Planned],
+    registrar: &RecordingRegistrar,
+    problems: &mut Vec<String>,
+) {
+    let registered = registrar.registered.lock().unwrap();
+    for p in planned {
+        let registrations: Vec<_> = registered
+            .iter()
+            .filter(|d| d.repo_id == p.repo_id)
+            .collect();
+        let [download] = registrations.as_slice() else {
+            problems.push(format!(
+                "{} registered {} times",
+                p.repo_id,
+                registrations.len()
+            ));
+            continue;
+        };
+        if download.all_paths.len() != p.files.len() {
+            problems.push(format!(
+                "{} registered {} of {} shards",
+                p.repo_id,
+                download.all_paths.len(),
+                p.files.len()
+            ));
+        }
+        for (path, (name, size)) in download.all_paths.iter().zip(&p.files) {
+            let actual = std::fs::metadata(path).map_or(0, |m| m.len());
+            if !path.ends_with(name) || actual != *size {
+                problems.push(format!(
+                    "{}: expected {name} of {size} bytes, found {} of {actual}",
+                    p.repo_id,
+                    path.display()
+                ));
+            }
+        }
+    }
+    drop(registered);
+
+    let leftovers = files_under(&models_dir.join(".staging"));
+    if !leftovers.is_empty() {
+        problems.push(format!("files left in staging: {leftovers:?}"));
+    }
+}
+
+#[tokio::main]
+async fn main() -> ExitCode {
+    let options = match Options::parse() {
+        Ok(options) => options,
+        Err(e) => {
+            eprintln!("{e}\nusage: download_soak [--rounds N] [--models N] [--seed N]");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut failed = false;
+    for n in 0..options.rounds {
+        let seed = options.seed + n;
+        match round(seed, options.models).await {
+            Ok(problems) if problems.is_empty() => {}
+            Ok(problems) => {
+                failed = true;
+                for problem in problems {
+                    eprintln!("seed {seed}: {problem}");
+                }
+            }
+            Err(e) => {
+                failed = true;
+                eprintln!("seed {seed}: {e}");
+            }
+        }
+    }
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        println!("all {} rounds recovered", options.rounds);
+        ExitCode::SUCCESS
+    }
+}
@@ -0,0 +1,318 @@
+//! Fault injection for the download pipeline (`fault-injection` feature).
+//!
+//! With [`DownloadManagerConfig::fault_injection`] set, the worker replaces
+//! every transfer with a synthetic one: a GGUF header followed by zeros,
+//! written in chunks to the job's download directory and resumed from any
+//! partial file an earlier attempt left behind. Each attempt may draw one
+//! fault from a seeded generator, so a queue run with a given seed is
+//! repeatable:
+//!
+//! | Fault | Effect |
+//! |-------|--------|
+//! | Connection reset | The stream stops partway; the partial file stays for the retry to resume |
+//! | Slow stream | Every chunk is delayed by [`DownloadFaultConfig::slow_chunk_delay`] |
+//! | Truncated file | The transfer ends short but reports success, so staged validation quarantines it |
+//! | Rate limit | The attempt fails with HTTP 429 before a byte is written |
+//!
+//! `examples/download_soak.rs` runs a whole queue against the injector and
+//! checks that retries, resumes and shard groups recover.
+//!
+//! [`DownloadManagerConfig::fault_injection`]: gglib_core::ports::DownloadManagerConfig::fault_injection
+
+use std::path::Path;
+use std::sync::Mutex;
+
+use tokio::io::AsyncWriteExt;
+use tokio::sync::watch;
+use tokio_util::sync::CancellationToken;
+
+use gglib_core::download::DownloadError;
+use gglib_core::ports::DownloadFaultConfig;
+
+use crate::ProgressUpdate;
+
+/// Header of the synthetic files: GGUF magic, version 3, no tensors and no
+/// metadata. Enough for staged validation, which checks magic and size.
+const GGUF_HEADER: [u8; 24] = [
+    b'G', b'G', b'U', b'F', 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+];
+
+/// Size of a synthetic file whose real size is unknown.
+const UNKNOWN_SIZE: u64 = 1 << 20;
+
+/// The fault drawn for one transfer attempt.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Fault {
+    ConnectionReset { after: u64 },
+    SlowStream,
+    Truncated { at: u64 },
+    RateLimited,
+}
+
+/// Counts of what the injector has done so far.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct FaultStats {
+    /// Transfer attempts, faulty or not.
+    pub attempts: u64,
+    pub connection_resets: u64,
+    pub slow_streams: u64,
+    pub truncations: u64,
+    pub rate_limits: u64,
+    /// Bytes an attempt found already on disk and did not write again.
+    pub resumed_bytes: u64,
+}
+
+#[derive(Debug)]
+struct State {
+    rng: u64,
+    stats: FaultStats,
+}
+
+/// Synthetic transfers with seeded faults; shared by every job of a manager.
+#[derive(Debug)]
+pub struct FaultInjector {
+    config: DownloadFaultConfig,
+    state: Mutex<State>,
+}
+
+impl FaultInjector {
+    pub fn new(config: DownloadFaultConfig) -> Self {
+        let rng = config.seed;
+        Self {
+            config,
+            state: Mutex::new(State {
+                rng,
+                stats: FaultStats::default(),
+            }),
+        }
+    }
+
+    /// What the injector has done so far.
+    pub fn stats(&self) -> FaultStats {
+        self.lock().stats
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, State> {
+        self.state
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+
+    /// Draw this attempt's fault, if any, for a file of `size` bytes.
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)] // fraction of a u64
+    fn draw(&self, size: u64, resumed: u64) -> Option<Fault> {
+        let config = &self.config;
+        let mut state = self.lock();
+        state.stats.attempts += 1;
+        state.stats.resumed_bytes += resumed;
+        // A point strictly inside the file, past anything already on disk.
+        #[allow(clippy::cast_precision_loss)]
+        let cut = resumed
+            + 1
+            + (next_unit(&mut state.rng) * (size - resumed).saturating_sub(1) as f64) as u64;
+
+        let rates = [
+            config.connection_reset_rate,
+            config.slow_stream_rate,
+            config.truncate_rate,
+            config.rate_limit_rate,
+        ];
+        let mut roll = next_unit(&mut state.rng);
+        let picked = rates.iter().position(|&rate| {
+            roll -= rate;
+            roll < 0.0
+        });
+        let fault = match picked? {
+            0 => {
+                state.stats.connection_resets += 1;
+                Fault::ConnectionReset { after: cut }
+            }
+            1 => {
+                state.stats.slow_streams += 1;
+                Fault::SlowStream
+            }
+            2 => {
+                state.stats.truncations += 1;
+                Fault::Truncated { at: cut }
+            }
+            _ => {
+                state.stats.rate_limits += 1;
+                Fault::RateLimited
+            }
+        };
+        drop(state);
+        Some(fault)
+    }
+
+    /// Write a synthetic `size`-byte GGUF file to `dest`, subject to this
+    /// attempt's fault.
+    ///
+    /// Like a real transfer, a partial file is resumed rather than
+    /// restarted, and progress is reported on `progress_tx`.
+    pub(crate) async fn transfer(
+        &self,
+        dest: &Path,
+        size: Option<u64>,
+        progress_tx: &watch::Sender<ProgressUpdate>,
+        cancel: &CancellationToken,
+    ) -> Result<(), DownloadError> {
+        let size = size.unwrap_or(UNKNOWN_SIZE).max(GGUF_HEADER.len() as u64);
+        let existing = match tokio::fs::metadata(dest).await {
+            Ok(meta) if meta.len() < size => meta.len(),
+            Ok(meta) if meta.len() == size => {
+                report(progress_tx, size, size);
+                return Ok(());
+            }
+            _ => 0,
+        };
+
+        let fault = self.draw(size, existing);
+        let end = match fault {
+            Some(Fault::RateLimited) => {
+                return Err(DownloadError::network_with_status(
+                    "Too Many Requests (injected)",
+                    429,
+                ));
+            }
+            Some(Fault::ConnectionReset { after }) => after,
+            Some(Fault::Truncated { at }) => at,
+            Some(Fault::SlowStream) | None => size,
+        };
+
+        let mut file = tokio::fs::OpenOptions::new()
+            .create(true)
+            .append(existing > 0)
+            .write(true)
+            .truncate(existing == 0)
+            .open(dest)
+            .await
+            .map_err(|e| DownloadError::from_io_error(&e))?;
+        let mut written = existing;
+        report(progress_tx, written, size);
+
+        while written < end {
+            if fault == Some(Fault::SlowStream) {
+                tokio::select! {
+                    biased;
+                    () = cancel.cancelled() => return Err(DownloadError::Cancelled),
+                    () = tokio::time::sleep(self.config.slow_chunk_delay) => {}
+                }
+            } else if cancel.is_cancelled() {
+                return Err(DownloadError::Cancelled);
+            }
+            let chunk_end = end.min(written + self.config.chunk_size.max(1));
+            file.write_all(&synthetic_bytes(written, chunk_end))
+                .await
+                .map_err(|e| DownloadError::from_io_error(&e))?;
+            written = chunk_end;
+            report(progress_tx, written, size);
+        }
+        file.flush()
+            .await
+            .map_err(|e| DownloadError::from_io_error(&e))?;
+
+        match fault {
+            Some(Fault::ConnectionReset { .. }) => Err(DownloadError::network(format!(
+                "Connection reset by peer at {written} of {size} bytes (injected)"
+            ))),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Bytes `start..end` of a synthetic file.
+#[allow(clippy::cast_possible_truncation)] // offsets below the header length
+fn synthetic_bytes(start: u64, end: u64) -> Vec<u8> {
+    (start..end)
+        .map(|offset| GGUF_HEADER.get(offset as usize).copied().unwrap_or(0))
+        .collect()
+}
+
+/// A uniform draw from `[0, 1)` (`SplitMix64`).
+#[allow(clippy::cast_precision_loss)]
+fn next_unit(state: &mut u64) -> f64 {
+    *state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^= z >> 31;
+    (z >> 11) as f64 / (1u64 << 53) as f64
+}
+
+fn report(progress_tx: &watch::Sender<ProgressUpdate>, downloaded: u64, total: u64) {
+    progress_tx.send_modify(|state| {
+        state.downloaded = downloaded;
+        state.total = total;
+        state.seq += 1;
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(seed: u64) -> DownloadFaultConfig {
+        DownloadFaultConfig {
+            seed,
+            connection_reset_rate: 0.25,
+            slow_stream_rate: 0.25,
+            truncate_rate: 0.25,
+            rate_limit_rate: 0.25,
+            chunk_size: 7,
+            ..DownloadFaultConfig::default()
+        }
+    }
+
+    #[test]
+    fn the_same_seed_draws_the_same_faults() {
+        let draws = |seed| {
+            let injector = FaultInjector::new(config(seed));
+            (0..32).map(|_| injector.draw(1000, 0)).collect::<Vec<_>>()
+        };
+        assert_eq!(draws(7), draws(7));
+        assert_ne!(draws(7), draws(8));
+
+        let injector = FaultInjector::new(config(7));
+        for _ in 0..200 {
+            if let Some(Fault::ConnectionReset { after: cut } | Fault::Truncated { at: cut }) =
+                injector.draw(1000, 400)
+            {
+                assert!((401..1000).contains(&cut), "{cut}");
+            }
+        }
+        let stats = injector.stats();
+        assert_eq!(stats.attempts, 200);
+        assert_eq!(stats.resumed_bytes, 200 * 400);
+        assert!(stats.connection_resets > 0 && stats.rate_limits > 0);
+    }
+
+    #[tokio::test]
+    async fn a_reset_transfer_resumes_into_a_valid_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let dest = dir.path().join("model.gguf");
+        let (tx, rx) = watch::channel(ProgressUpdate::default());
+        let cancel = CancellationToken::new();
+        let injector = FaultInjector::new(DownloadFaultConfig {
+            connection_reset_rate: 1.0,
+            chunk_size: 16,
+            ..DownloadFaultConfig::default()
+        });
+
+        let err = injector
+            .transfer(&dest, Some(100), &tx, &cancel)
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("reset"), "{err}");
+        let partial = std::fs::metadata(&dest).unwrap().len();
+        assert!(partial > 0 && partial < 100);
+
+        let clean = FaultInjector::new(DownloadFaultConfig::default());
+        clean
+            .transfer(&dest, Some(100), &tx, &cancel)
+            .await
+            .unwrap();
+        assert_eq!(clean.stats().resumed_bytes, partial);
+        assert_eq!(rx.borrow().downloaded, 100);
+        gglib_gguf::validate_gguf_quick(&dest, Some(100)).unwrap();
+    }
+}
@@ -6,17 +6,27 @@
 //!
 //! A shared [`Arc<MultiProgress>`] handle is exposed so the interactive monitor
 //! can call [`MultiProgress::suspend`] while prompting for user input.
+//!
+//! For CI and scripts, [`CliDownloadEventEmitter::set_json_output`] swaps the
+//! bars for NDJSON: each event is written to stdout as one line of its
+//! `DownloadEvent` serialization. In plain mode (see [`gglib_core::progress`])
+//! the bars are replaced by a status line per download every few seconds and
+//! a one-line summary when it ends.
 
 use std::collections::HashMap;
+use std::io::Write;
 use std::sync::Arc;
 use std::sync::Mutex;
-use std::time::Duration;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
 
 use indicatif::{HumanBytes, MultiProgress, ProgressBar, ProgressState, ProgressStyle};
 
-use gglib_core::download::{DownloadEvent, format_duration, format_rate};
+use gglib_core::download::{DownloadEvent, DownloadPhase, format_duration, format_rate};
 use gglib_core::events::AppEvent;
 use gglib_core::ports::{AppEventEmitter, DownloadEventEmitterPort};
+use gglib_core::progress::{StatusThrottle, percent};
+use gglib_core::telemetry::console_println;
 
 // ─── Style constants ─────────────────────────────────────────────────────────
 
@@ -65,6 +75,23 @@ pub struct CliDownloadEventEmitter {
     /// pinned to the bottom without the remove-then-re-add churn that used
     /// to re-anchor it on every new item.
     footer: Mutex<Option<ProgressBar>>,
+    /// Write events to stdout as NDJSON instead of drawing bars.
+    json_output: AtomicBool,
+    /// Print status lines instead of drawing bars.
+    plain_output: AtomicBool,
+    /// Per-download state for plain output, keyed like `bars`.
+    plain: Mutex<HashMap<String, PlainStatus>>,
+}
+
+/// What plain output remembers about one download between events.
+struct PlainStatus {
+    started: Instant,
+    throttle: StatusThrottle,
+    /// Bytes transferred so far, for the completion summary.
+    transferred: u64,
+    /// The last phase or status note printed, so a phase that is reported
+    /// repeatedly is only announced once.
+    last_note: Option<String>,
 }
 
 impl CliDownloadEventEmitter {
@@ -111,10 +138,193 @@ impl CliDownloadEventEmitter {
             }
         }));
 
-        Self {
+        let emitter = Self {
             multi_progress,
             bars: Mutex::new(HashMap::new()),
             footer: Mutex::new(None),
+            json_output: AtomicBool::new(false),
+            plain_output: AtomicBool::new(false),
+            plain: Mutex::new(HashMap::new()),
+        };
+        emitter.set_plain_output(gglib_core::progress::is_plain());
+        emitter
+    }
+
+    /// Switch between progress bars and plain status lines on stderr.
+    ///
+    /// Defaults to the process-wide [`gglib_core::progress::is_plain`]. Like
+    /// JSON mode, plain mode hides the bars' draw target, so log lines go
+    /// straight to stderr between the status lines.
+    pub fn set_plain_output(&self, enabled: bool) {
+        self.plain_output.store(enabled, Ordering::Relaxed);
+        if enabled {
+            self.multi_progress
+                .set_draw_target(indicatif::ProgressDrawTarget::hidden());
+        }
+    }
+
+    /// Render `event` as plain status lines.
+    fn emit_plain(&self, event: DownloadEvent) {
+        match event {
+            DownloadEvent::DownloadStarted {
+                id,
+                shard_index,
+                total_shards,
+            } => {
+                let shards = match (shard_index, total_shards) {
+                    (Some(idx), Some(total)) => format!(" [shard {}/{total}]", idx + 1),
+                    _ => String::new(),
+                };
+                console_println(&format!("↓ {id}{shards}: started"));
+                if let Ok(mut plain) = self.plain.lock() {
+                    plain.entry(id).or_insert_with(|| PlainStatus {
+                        started: Instant::now(),
+                        throttle: StatusThrottle::default(),
+                        transferred: 0,
+                        last_note: None,
+                    });
+                }
+            }
+            DownloadEvent::DownloadProgress {
+                id,
+                downloaded,
+                total,
+                speed_bps,
+                eta_seconds,
+                ..
+            } => {
+                self.plain_progress(&id, downloaded, total, &rate_suffix(speed_bps, eta_seconds));
+            }
+            DownloadEvent::ShardProgress {
+                id,
+                shard_index,
+                total_shards,
+                aggregate_downloaded,
+                aggregate_total,
+                speed_bps,
+                eta_seconds,
+                ..
+            } => {
+                let detail = format!(
+                    "{} · shard {}/{total_shards}",
+                    rate_suffix(speed_bps, eta_seconds),
+                    shard_index + 1
+                );
+                self.plain_progress(&id, aggregate_downloaded, aggregate_total, &detail);
+            }
+            DownloadEvent::DownloadCompleted { id, .. } => {
+                let status = self.plain.lock().ok().and_then(|mut p| p.remove(&id));
+                let summary = status.map_or_else(String::new, |s| {
+                    format!(
+                        ": {} in {}",
+                        HumanBytes(s.transferred),
+                        format_duration(Some(s.started.elapsed().as_secs_f64()))
+                    )
+                });
+                console_println(&format!("✓ {id}{summary}"));
+            }
+            DownloadEvent::DownloadFailed { id, error } => {
+                self.forget_plain(&id);
+                console_println(&format!("✗ {id}: {error}"));
+            }
+            DownloadEvent::DownloadCancelled { id } => {
+                self.forget_plain(&id);
+                console_println(&format!("✗ {id}: cancelled"));
+            }
+            DownloadEvent::DownloadStatusChanged { id, status } => {
+                self.plain_note(&id, format!("{}…", status.label()));
+            }
+            DownloadEvent::DownloadNotice { id, message } => self.plain_note(&id, message),
+            DownloadEvent::PhaseProgress {
+                id,
+                phase,
+                processed,
+                total,
+                ..
+            } => match phase {
+                DownloadPhase::Transferring => {}
+                DownloadPhase::Verifying if total > 0 => {
+                    if self.plain_due(&id) {
+                        let pct = percent(processed, total).unwrap_or_default();
+                        console_println(&format!("{id}: verifying {pct:.1}%"));
+                    }
+                }
+                _ => self.plain_note(&id, format!("{}…", phase.label())),
+            },
+            DownloadEvent::QueueSnapshot { .. }
+            | DownloadEvent::QueueRunComplete { .. }
+            | DownloadEvent::PartialCleanup { .. } => {}
+        }
+    }
+
+    /// Record transfer progress for `id`, printing a status line when one
+    /// is due.
+    fn plain_progress(&self, id: &str, done: u64, total: u64, detail: &str) {
+        let due = self.plain.lock().ok().is_some_and(|mut plain| {
+            plain.get_mut(id).is_some_and(|status| {
+                status.transferred = done;
+                status.throttle.ready(Instant::now())
+            })
+        });
+        if due {
+            console_println(&plain_progress_line(id, done, total, detail));
+        }
+    }
+
+    /// Whether the throttle for `id` allows another status line now.
+    fn plain_due(&self, id: &str) -> bool {
+        self.plain.lock().ok().is_some_and(|mut plain| {
+            plain
+                .get_mut(id)
+                .is_some_and(|status| status.throttle.ready(Instant::now()))
+        })
+    }
+
+    /// Print `note` for `id` unless it is the note printed last.
+    fn plain_note(&self, id: &str, note: String) {
+        let line = format!("{id} — {note}");
+        let fresh = self.plain.lock().ok().is_some_and(|mut plain| {
+            plain.get_mut(id).is_some_and(|status| {
+                let fresh = status.last_note.as_ref() != Some(&note);
+                status.last_note = Some(note);
+                fresh
+            })
+        });
+        if fresh {
+            console_println(&line);
+        }
+    }
+
+    fn forget_plain(&self, id: &str) {
+        if let Ok(mut plain) = self.plain.lock() {
+            plain.remove(id);
+        }
+    }
+
+    /// Switch between progress bars (the default) and NDJSON on stdout.
+    ///
+    /// In JSON mode every event except the periodic `QueueSnapshot` is
+    /// written as one line of its `DownloadEvent` serialization, and the bars'
+    /// draw target is hidden so log lines fall back to plain stderr (see the
+    /// console hook installed by [`Self::new`]) — stdout carries nothing but
+    /// events.
+    pub fn set_json_output(&self, enabled: bool) {
+        self.json_output.store(enabled, Ordering::Relaxed);
+        if enabled {
+            self.multi_progress
+                .set_draw_target(indicatif::ProgressDrawTarget::hidden());
+        }
+    }
+
+    /// Write `event` to stdout as a single JSON line.
+    fn emit_json(event: &DownloadEvent) {
+        if matches!(event, DownloadEvent::QueueSnapshot { .. }) {
+            return;
+        }
+        if let Ok(line) = serde_json::to_string(event) {
+            let mut stdout = std::io::stdout().lock();
+            let _ = writeln!(stdout, "{line}");
+            let _ = stdout.flush();
         }
     }
 
@@ -224,6 +434,26 @@ impl CliDownloadEventEmitter {
         bar.set_position(position);
         bar.set_message(message.to_string());
     }
+
+    /// Reflect a [`DownloadEvent::PhaseProgress`] on the bar for `id`.
+    fn show_phase(&self, id: &str, phase: DownloadPhase, processed: u64, total: u64) {
+        match phase {
+            // Transfer progress keeps arriving on its own events.
+            DownloadPhase::Transferring => {}
+            // Hashing reuses the bar: it refills from empty as the file is
+            // read back, instead of sitting at 100%.
+            DownloadPhase::Verifying if total > 0 => {
+                self.update_bar(id, processed, total, &format!("{id} — Verifying…"));
+            }
+            _ => {
+                if let Ok(bars) = self.bars.lock() {
+                    if let Some(bar) = bars.get(id) {
+                        bar.set_message(format!("{id} — {}…", phase.label()));
+                    }
+                }
+            }
+        }
+    }
 }
 
 /// Custom `{total_bytes}` renderer: `—` while the length is unknown, the
@@ -241,6 +471,20 @@ fn total_bytes_key(state: &ProgressState, w: &mut dyn std::fmt::Write) {
     }
 }
 
+/// One plain status line: `org/model: 1.2 GiB / 4.0 GiB (30.0%) · <detail>`.
+fn plain_progress_line(id: &str, done: u64, total: u64, detail: &str) -> String {
+    percent(done, total).map_or_else(
+        || format!("{id}: {} · {detail}", HumanBytes(done)),
+        |pct| {
+            format!(
+                "{id}: {} / {} ({pct:.1}%) · {detail}",
+                HumanBytes(done),
+                HumanBytes(total)
+            )
+        },
+    )
+}
+
 /// Render the rate and ETA the manager computed, e.g. `118.4 MB/s · ETA 2m 40s`.
 ///
 /// Both are `None` until the estimator warms up, and render as a placeholder
@@ -261,6 +505,14 @@ impl Default for CliDownloadEventEmitter {
 
 impl DownloadEventEmitterPort for CliDownloadEventEmitter {
     fn emit(&self, event: DownloadEvent) {
+        if self.json_output.load(Ordering::Relaxed) {
+            Self::emit_json(&event);
+            return;
+        }
+        if self.plain_output.load(Ordering::Relaxed) {
+            self.emit_plain(event);
+            return;
+        }
         match event {
             DownloadEvent::DownloadStarted {
                 id,
@@ -359,8 +611,18 @@ impl DownloadEventEmitterPort for CliDownloadEventEmitter {
                 }
             }
 
+            DownloadEvent::PhaseProgress {
+                id,
+                phase,
+                processed,
+                total,
+                ..
+            } => self.show_phase(&id, phase, processed, total),
+
             // Queue-level events don't need bar updates in the CLI emitter.
-            DownloadEvent::QueueSnapshot { .. } | DownloadEvent::QueueRunComplete { .. } => {}
+            DownloadEvent::QueueSnapshot { .. }
+            | DownloadEvent::QueueRunComplete { .. }
+            | DownloadEvent::PartialCleanup { .. } => {}
         }
     }
 
@@ -503,6 +765,56 @@ mod tests {
         }
     }
 
+    /// JSON mode bypasses the bars entirely: no bar is registered for a
+    /// started download.
+    #[test]
+    fn json_output_does_not_create_bars() {
+        let emitter = CliDownloadEventEmitter::new();
+        emitter.set_json_output(true);
+        DownloadEventEmitterPort::emit(&emitter, DownloadEvent::started("test/model"));
+
+        assert!(emitter.bars.lock().unwrap().is_empty());
+        assert!(emitter.multi_progress().is_hidden());
+    }
+
+    /// Plain mode tracks downloads without drawing bars, and forgets them
+    /// once they end.
+    #[test]
+    fn plain_output_tracks_downloads_without_bars() {
+        let emitter = CliDownloadEventEmitter::new();
+        emitter.set_plain_output(true);
+        DownloadEventEmitterPort::emit(&emitter, DownloadEvent::started("test/model"));
+        DownloadEventEmitterPort::emit(
+            &emitter,
+            DownloadEvent::progress("test/model", 10, 100, None, None),
+        );
+
+        assert!(emitter.bars.lock().unwrap().is_empty());
+        assert!(emitter.multi_progress().is_hidden());
+        let transferred = emitter.plain.lock().unwrap()["test/model"].transferred;
+        assert_eq!(transferred, 10);
+
+        DownloadEventEmitterPort::emit(
+            &emitter,
+            DownloadEvent::DownloadCancelled {
+                id: "test/model".to_string(),
+            },
+        );
+        assert!(emitter.plain.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn plain_progress_line_shows_sizes_and_percent() {
+        assert_eq!(
+            plain_progress_line("org/model", 512 * 1024, 2048 * 1024, "— · ETA —"),
+            "org/model: 512.00 KiB / 2.00 MiB (25.0%) · — · ETA —"
+        );
+        assert_eq!(
+            plain_progress_line("org/model", 1024, 0, "— · ETA —"),
+            "org/model: 1.00 KiB · — · ETA —"
+        );
+    }
+
     /// Dropping an emitter must not panic, hang, or otherwise misbehave —
     /// the hook it installed captures a `Weak`, not a strong `Arc`, so it
     /// degrades to `eprintln!` once this emitter (and its `MultiProgress`)
@@ -4,8 +4,9 @@
 //! These operations don't require database access.
 
 use anyhow::{Result, anyhow};
-use gglib_core::ports::huggingface::HfClientPort;
-use gglib_hf::{DefaultHfClient, HfClientConfig};
+use gglib_core::ports::huggingface::HfQuantInfo;
+use gglib_core::ports::{ModelHub, ModelHubPort};
+use gglib_hf::{DefaultHfClient, HfClientConfig, hub_client};
 use hf_hub::api::sync::Api;
 use std::path::Path;
 
@@ -29,6 +30,9 @@ pub fn create_hf_api(token: Option<String>, models_dir: &Path) -> Result<Api> {
 }
 
 /// List available GGUF quantizations for a model.
+///
+/// `model_id` may name another hub (`modelscope:org/repo`); that hub's
+/// client lists the files directly.
 pub async fn list_quantizations(
     model_id: &str,
     models_dir: &Path,
@@ -36,6 +40,19 @@ pub async fn list_quantizations(
 ) -> Result<()> {
     println!("Finding available GGUF quantizations for {model_id}...");
 
+    let (hub, repo_id) = ModelHub::split(model_id);
+    if hub != ModelHub::HuggingFace {
+        println!(
+            "Searching for GGUF files using {} API...",
+            hub.display_name()
+        );
+        match hub_client(hub, None).list_quantizations(repo_id).await {
+            Ok(quantizations) => print_quantizations(model_id, &quantizations),
+            Err(e) => println!("Failed to fetch quantizations: {e}"),
+        }
+        return Ok(());
+    }
+
     let api = create_hf_api(token, models_dir)?;
     let hf_api_repo = api.repo(hf_hub::Repo::with_revision(
         model_id.to_string(),
@@ -52,28 +69,7 @@ pub async fn list_quantizations(
             let client = DefaultHfClient::new(&HfClientConfig::default());
 
             match client.list_quantizations(model_id).await {
-                Ok(quantizations) => {
-                    if quantizations.is_empty() {
-                        println!("✗ No GGUF files found in this repository.");
-                    } else {
-                        println!("✓ Found {} quantizations:", quantizations.len());
-                        for quant in &quantizations {
-                            let shard_info = if quant.shard_count > 1 {
-                                format!(" ({} shards)", quant.shard_count)
-                            } else {
-                                String::new()
-                            };
-                            #[allow(clippy::cast_precision_loss)]
-                            let size_mb = quant.total_size as f64 / 1_048_576.0;
-                            println!("  {} ({:.1} MB){}", quant.name, size_mb, shard_info);
-                        }
-
-                        println!("\nTo download a specific quantization, use:");
-                        for quant in &quantizations {
-                            println!("  gglib model download {} -q {}", model_id, quant.name);
-                        }
-                    }
-                }
+                Ok(quantizations) => print_quantizations(model_id, &quantizations),
                 Err(e) => {
                     println!("Failed to fetch quantizations: {e}");
                     fallback_file_search(&hf_api_repo, model_id);
@@ -89,6 +85,30 @@ pub async fn list_quantizations(
     Ok(())
 }
 
+/// Print the quantizations found for `model_id` and how to download each.
+fn print_quantizations(model_id: &str, quantizations: &[HfQuantInfo]) {
+    if quantizations.is_empty() {
+        println!("✗ No GGUF files found in this repository.");
+        return;
+    }
+    println!("✓ Found {} quantizations:", quantizations.len());
+    for quant in quantizations {
+        let shard_info = if quant.shard_count > 1 {
+            format!(" ({} shards)", quant.shard_count)
+        } else {
+            String::new()
+        };
+        #[allow(clippy::cast_precision_loss)]
+        let size_mb = quant.total_size as f64 / 1_048_576.0;
+        println!("  {} ({:.1} MB){}", quant.name, size_mb, shard_info);
+    }
+
+    println!("\nTo download a specific quantization, use:");
+    for quant in quantizations {
+        println!("  gglib model download {} -q {}", model_id, quant.name);
+    }
+}
+
 /// Fallback method for when API listing fails.
 fn fallback_file_search(repo: &hf_hub::api::sync::ApiRepo, model_id: &str) {
     println!("\nFalling back to pattern matching...");
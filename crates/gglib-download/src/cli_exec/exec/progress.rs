@@ -11,15 +11,13 @@
 //! math of its own — an earlier private exponentially-weighted average here
 //! was one of three competing implementations that disagreed with each other.
 
-use std::io::{self, IsTerminal, Write};
+use std::io::{self, IsTerminal};
 use std::time::{Duration, Instant};
 
 use gglib_core::download::{RateEstimator, format_duration, format_rate};
+use gglib_core::progress::{StatusThrottle, percent};
 use indicatif::{HumanBytes, ProgressBar, ProgressDrawTarget, ProgressState, ProgressStyle};
 
-/// Minimum gap between redraws on the non-terminal path.
-const PLAIN_MIN_INTERVAL: Duration = Duration::from_millis(250);
-
 // ============================================================================
 // CLI Progress Printer
 // ============================================================================
@@ -39,6 +37,8 @@ enum ProgressRender {
 
 impl CliProgressPrinter {
     /// Create a new progress printer, auto-detecting terminal capability.
+    /// Plain mode ([`gglib_core::progress::is_plain`]) always gets status
+    /// lines.
     ///
     /// Checks stderr, not stdout: the bar itself draws to stderr (see
     /// [`FancyProgress::new`]), matching the queued-download path's
@@ -47,7 +47,7 @@ impl CliProgressPrinter {
     /// silently downgrade the bar when stderr is still an attended terminal.
     #[must_use]
     pub fn new() -> Self {
-        let inner = if io::stderr().is_terminal() {
+        let inner = if io::stderr().is_terminal() && !gglib_core::progress::is_plain() {
             ProgressRender::Fancy(FancyProgress::new())
         } else {
             ProgressRender::Plain(PlainProgress::new())
@@ -208,69 +208,65 @@ impl FancyProgress {
 }
 
 // ============================================================================
-// Plain Progress (non-terminal)
+// Plain Progress (non-terminal or --plain)
 // ============================================================================
 
+/// One complete status line every [`gglib_core::progress::STATUS_INTERVAL`]
+/// and a summary at the end. Lines are never rewritten in place with `\r`,
+/// which CI logs keep as one ever-growing line and screen readers re-read.
 struct PlainProgress {
-    last_emit: Instant,
-    last_line_len: usize,
-    printed: bool,
+    started: Instant,
+    throttle: StatusThrottle,
+    /// `(label, downloaded)` from the latest update, for the summary.
+    last: Option<(Option<String>, u64)>,
 }
 
 impl PlainProgress {
     fn new() -> Self {
         Self {
-            last_emit: Instant::now(),
-            last_line_len: 0,
-            printed: false,
+            started: Instant::now(),
+            throttle: StatusThrottle::default(),
+            last: None,
         }
     }
 
     fn update(&mut self, label: Option<&str>, downloaded: u64, total: u64, rate: &Rate) {
-        use std::fmt::Write;
-
-        let now = Instant::now();
-        if downloaded < total && now.duration_since(self.last_emit) < PLAIN_MIN_INTERVAL {
+        let label = label.filter(|name| !name.is_empty());
+        self.last = Some((label.map(str::to_string), downloaded));
+        if !self.throttle.ready(Instant::now()) {
             return;
         }
-        self.last_emit = now;
-
-        let mut line = String::from("⚡ Fast download");
-        if let Some(name) = label.filter(|name| !name.is_empty()) {
-            let _ = write!(line, " [{name}]");
-        }
 
-        if total == 0 {
-            let _ = write!(line, ": {} downloaded", HumanBytes(downloaded));
-        } else if downloaded == 0 {
-            let _ = write!(line, ": Preparing... ({})", HumanBytes(total));
-        } else {
-            #[allow(clippy::cast_precision_loss)]
-            let percent = (downloaded as f64 / total as f64) * 100.0;
-            let _ = write!(
-                line,
-                ": {} / {} ({percent:5.1}%) @ {}",
+        let status = match percent(downloaded, total) {
+            None => format!("{} downloaded", HumanBytes(downloaded)),
+            Some(_) if downloaded == 0 => format!("Preparing... ({})", HumanBytes(total)),
+            Some(pct) => format!(
+                "{} / {} ({pct:.1}%) @ {}",
                 HumanBytes(downloaded),
                 HumanBytes(total),
                 rate.display(),
-            );
-        }
-
-        let pad = self.last_line_len.saturating_sub(line.len());
-        eprint!("\r{line}{:pad$}", "", pad = pad);
-        io::stderr().flush().ok();
-
-        self.last_line_len = line.len();
-        self.printed = true;
+            ),
+        };
+        eprintln!("{}: {status}", Self::prefix(label));
     }
 
     fn finish(&mut self) {
-        if self.printed {
-            eprintln!();
-            self.printed = false;
-            self.last_line_len = 0;
+        if let Some((label, downloaded)) = self.last.take() {
+            eprintln!(
+                "{}: {} in {}",
+                Self::prefix(label.as_deref()),
+                HumanBytes(downloaded),
+                format_duration(Some(self.started.elapsed().as_secs_f64()))
+            );
         }
     }
+
+    fn prefix(label: Option<&str>) -> String {
+        label.map_or_else(
+            || "⚡ Fast download".to_string(),
+            |name| format!("⚡ Fast download [{name}]"),
+        )
+    }
 }
 
 // ============================================================================
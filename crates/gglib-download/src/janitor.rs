@@ -0,0 +1,272 @@
+//! Cleanup of orphaned partial downloads.
+//!
+//! An interrupted job leaves bytes behind: `.incomplete` files under a model
+//! directory's `.cache/huggingface/download/`, `.part` files from resumable
+//! fetches, and whole directories under `<models_dir>/.staging/`. A partial
+//! is orphaned when no pending, active or failed download refers to the
+//! directory it sits in. [`sweep`] deletes orphans whose newest
+//! modification is older than the grace period and reports the rest as
+//! reclaimable; the download manager runs it at startup and every
+//! [`JANITOR_INTERVAL`].
+//!
+//! All disk access goes through a [`FileSystem`], and the current time is
+//! passed in, so sweeps can be tested against a `MemoryFs` and a manual clock.
+
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+use gglib_core::utils::fs::FileSystem;
+
+use crate::manager::STAGING_DIR;
+use crate::quarantine::QUARANTINE_DIR;
+
+/// How often the download manager sweeps after the startup sweep.
+pub const JANITOR_INTERVAL: Duration = Duration::from_hours(1);
+
+/// File suffixes that mark a partial download.
+const PARTIAL_SUFFIXES: [&str; 2] = [".incomplete", ".part"];
+
+/// One orphaned partial: a file, or a whole staging directory.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OrphanedPartial {
+    pub path: PathBuf,
+    /// Size in bytes (summed for a directory).
+    pub size_bytes: u64,
+    /// Newest modification time (across a directory's contents).
+    pub modified: SystemTime,
+}
+
+/// Outcome of a [`sweep`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SweepReport {
+    /// Orphans kept because they are within the grace period.
+    pub reclaimable_files: u32,
+    pub reclaimable_bytes: u64,
+    /// Orphans deleted.
+    pub removed_files: u32,
+    pub removed_bytes: u64,
+}
+
+impl SweepReport {
+    /// Whether the sweep found any orphan at all.
+    pub const fn found_any(&self) -> bool {
+        self.reclaimable_files > 0 || self.removed_files > 0
+    }
+}
+
+/// List the orphaned partials under `models_dir`.
+///
+/// `in_use` holds the directories queued, active and failed downloads
+/// write into; anything inside one of them is not an orphan. The
+/// quarantine directory is never scanned.
+pub fn find_orphans(
+    fs: &dyn FileSystem,
+    models_dir: &Path,
+    in_use: &[PathBuf],
+) -> Vec<OrphanedPartial> {
+    let mut orphans = Vec::new();
+    let referenced = |path: &Path| in_use.iter().any(|dir| path.starts_with(dir));
+
+    // Staging directories are orphaned as a whole.
+    let staging = models_dir.join(STAGING_DIR);
+    for path in read_dir(fs, &staging) {
+        if referenced(&path) {
+            continue;
+        }
+        if let Some((size_bytes, modified)) = measure(fs, &path) {
+            orphans.push(OrphanedPartial {
+                path,
+                size_bytes,
+                modified,
+            });
+        }
+    }
+
+    let skip = [staging, models_dir.join(QUARANTINE_DIR)];
+    let mut dirs = vec![models_dir.to_path_buf()];
+    while let Some(dir) = dirs.pop() {
+        for path in read_dir(fs, &dir) {
+            let Ok(meta) = fs.metadata(&path) else {
+                continue;
+            };
+            if meta.is_dir() {
+                if !skip.contains(&path) {
+                    dirs.push(path);
+                }
+            } else if meta.is_file() && is_partial(&path) && !referenced(&path) {
+                orphans.push(OrphanedPartial {
+                    path,
+                    size_bytes: meta.len,
+                    modified: meta.modified,
+                });
+            }
+        }
+    }
+
+    orphans
+}
+
+/// Delete orphans last modified at least `grace` before `now`; report the
+/// others as reclaimable.
+pub fn sweep(
+    fs: &dyn FileSystem,
+    models_dir: &Path,
+    in_use: &[PathBuf],
+    grace: Duration,
+    now: SystemTime,
+) -> SweepReport {
+    let mut report = SweepReport::default();
+    for orphan in find_orphans(fs, models_dir, in_use) {
+        let age = now.duration_since(orphan.modified).unwrap_or_default();
+        if age >= grace {
+            match remove(fs, &orphan.path) {
+                Ok(()) => {
+                    tracing::info!(
+                        target: "gglib.download",
+                        path = %orphan.path.display(),
+                        bytes = orphan.size_bytes,
+                        "Removed orphaned partial download"
+                    );
+                    report.removed_files += 1;
+                    report.removed_bytes += orphan.size_bytes;
+                }
+                Err(e) => tracing::warn!(
+                    target: "gglib.download",
+                    path = %orphan.path.display(),
+                    error = %e,
+                    "Could not remove orphaned partial download"
+                ),
+            }
+        } else {
+            report.reclaimable_files += 1;
+            report.reclaimable_bytes += orphan.size_bytes;
+        }
+    }
+    report
+}
+
+fn is_partial(path: &Path) -> bool {
+    path.file_name()
+        .and_then(|name| name.to_str())
+        .is_some_and(|name| PARTIAL_SUFFIXES.iter().any(|s| name.ends_with(s)))
+}
+
+fn read_dir(fs: &dyn FileSystem, dir: &Path) -> Vec<PathBuf> {
+    fs.read_dir(dir).unwrap_or_default()
+}
+
+/// Total size and newest modification time of a file or directory tree.
+fn measure(fs: &dyn FileSystem, path: &Path) -> Option<(u64, SystemTime)> {
+    let meta = fs.metadata(path).ok()?;
+    let mut size = 0;
+    let mut newest = meta.modified;
+    if meta.is_dir() {
+        for child in read_dir(fs, path) {
+            if let Some((child_size, child_newest)) = measure(fs, &child) {
+                size += child_size;
+                newest = newest.max(child_newest);
+            }
+        }
+    } else {
+        size = meta.len;
+    }
+    Some((size, newest))
+}
+
+fn remove(fs: &dyn FileSystem, path: &Path) -> io::Result<()> {
+    if fs.metadata(path)?.is_dir() {
+        fs.remove_dir_all(path)
+    } else {
+        fs.remove_file(path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use gglib_core::utils::clock::{Clock, ManualClock};
+    use gglib_core::utils::fs::{MemoryFs, RealFs};
+
+    use super::*;
+
+    const HOUR: Duration = Duration::from_hours(1);
+
+    #[test]
+    fn finds_partials_outside_directories_in_use() {
+        let dir = tempfile::tempdir().unwrap();
+        let models = dir.path();
+        let write = |path: &Path, bytes: usize| {
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, vec![0u8; bytes]).unwrap();
+        };
+        let busy = models.join("org_busy");
+        write(
+            &busy.join(".cache/huggingface/download/a.gguf.incomplete"),
+            10,
+        );
+        write(
+            &models.join("org_idle/.cache/huggingface/download/b.gguf.incomplete"),
+            20,
+        );
+        write(&models.join("org_idle/c.gguf.part"), 30);
+        write(&models.join("org_idle/model.gguf"), 40);
+        write(&models.join(".staging/org_idle/model.gguf"), 50);
+        write(&models.join(QUARANTINE_DIR).join("x/d.gguf.part"), 60);
+
+        let mut orphans = find_orphans(&RealFs, models, &[busy]);
+        orphans.sort_by_key(|o| o.size_bytes);
+        let sizes: Vec<u64> = orphans.iter().map(|o| o.size_bytes).collect();
+        assert_eq!(sizes, vec![20, 30, 50]);
+        assert_eq!(orphans[2].path, models.join(".staging/org_idle"));
+    }
+
+    #[test]
+    fn sweep_keeps_orphans_within_the_grace_period() {
+        let clock = Arc::new(ManualClock::default());
+        let fs = MemoryFs::new(clock.clone());
+        let models = Path::new("/models");
+        let partial = models.join("org_model/m.gguf.part");
+        fs.add_file(&partial, 100);
+
+        clock.advance(23 * HOUR);
+        let report = sweep(&fs, models, &[], 24 * HOUR, clock.system_now());
+        assert_eq!(
+            report,
+            SweepReport {
+                reclaimable_files: 1,
+                reclaimable_bytes: 100,
+                ..Default::default()
+            }
+        );
+        assert!(fs.exists(&partial));
+
+        clock.advance(HOUR);
+        let report = sweep(&fs, models, &[], 24 * HOUR, clock.system_now());
+        assert_eq!(report.removed_files, 1);
+        assert_eq!(report.removed_bytes, 100);
+        assert!(!fs.exists(&partial));
+        assert!(!sweep(&fs, models, &[], 24 * HOUR, clock.system_now()).found_any());
+    }
+
+    #[test]
+    fn staging_directories_age_by_their_newest_file() {
+        let clock = Arc::new(ManualClock::default());
+        let fs = MemoryFs::new(clock.clone());
+        let models = Path::new("/models");
+        let staged = models.join(STAGING_DIR).join("org_model");
+        fs.add_file(staged.join("a.gguf"), 10);
+        clock.advance(20 * HOUR);
+        fs.add_file(staged.join("b.gguf"), 20);
+
+        clock.advance(10 * HOUR);
+        let report = sweep(&fs, models, &[], 24 * HOUR, clock.system_now());
+        assert_eq!(report.reclaimable_bytes, 30);
+
+        clock.advance(14 * HOUR);
+        let report = sweep(&fs, models, &[], 24 * HOUR, clock.system_now());
+        assert_eq!(report.removed_bytes, 30);
+        assert!(!fs.exists(&staged));
+    }
+}
@@ -35,6 +35,16 @@ pub use quant_selector::{QuantizationSelection, QuantizationSelector, SelectionE
 // CLI execution module (list_quantizations + Python bridge helpers)
 pub mod cli_exec;
 
+// Quarantine for downloads that fail validation
+pub mod quarantine;
+
+// Cleanup of orphaned partial downloads
+pub mod janitor;
+
+// Synthetic transfers with injected faults, for chaos and soak testing
+#[cfg(feature = "fault-injection")]
+pub mod chaos;
+
 // CLI terminal progress emitter
 mod cli_emitter;
 pub use cli_emitter::CliDownloadEventEmitter;
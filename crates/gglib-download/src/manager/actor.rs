@@ -0,0 +1,673 @@
+//! The queue actor: one task that owns the queue and the active downloads.
+//!
+//! Everything that reads or changes which downloads are pending, active or
+//! failed goes through a [`QueueHandle`], which sends a [`Command`] to the
+//! actor and waits for its reply. The actor applies commands one at a time,
+//! so there are no locks to order and no window between checking the queue
+//! and acting on it. After each command it hands the next job to the
+//! runner if nothing is active, and publishes the pending and active counts
+//! on a [`watch`] channel.
+//!
+//! Commands are handled synchronously — the actor never awaits anything but
+//! its mailbox — so a slow registration or transfer can't hold the queue.
+
+use std::collections::HashMap;
+
+use tokio::sync::{mpsc, oneshot, watch};
+use tokio_util::sync::CancellationToken;
+
+use gglib_core::download::{
+    CompletionKey, DownloadError, DownloadId, QueueSnapshot, QueuedDownload, ShardInfo,
+};
+
+use super::worker::ProgressUpdate;
+use crate::queue::{DownloadQueue, QueuedItem, ShardGroupId};
+
+/// Lease ID for tracking active downloads.
+///
+/// Used to prevent stale finalize commits when a download is cancelled
+/// or replaced while running.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(super) struct LeaseId(u64);
+
+/// State for an active download.
+#[derive(Debug, Clone)]
+pub(super) struct ActiveJob {
+    /// Unique lease for this execution.
+    pub lease: LeaseId,
+    /// Cancellation token.
+    pub cancel: CancellationToken,
+    /// Progress sender (bridges subscribe to this).
+    pub progress_tx: watch::Sender<ProgressUpdate>,
+    /// Shard information if this is a sharded download.
+    pub shard_info: Option<ShardInfo>,
+    /// Group ID if this is part of a shard group.
+    pub group_id: Option<String>,
+}
+
+/// A job handed to the runner: the item, now active under `lease`.
+pub(super) struct Job {
+    pub lease: LeaseId,
+    pub item: QueuedItem,
+    pub cancel: CancellationToken,
+    pub progress_tx: watch::Sender<ProgressUpdate>,
+}
+
+/// A shard group and its shards, in order.
+pub(super) type GroupLayout = (ShardGroupId, Vec<ShardInfo>);
+
+/// How many downloads are pending and active, as of the last command.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(super) struct QueueStatus {
+    pub pending: usize,
+    pub active: usize,
+}
+
+/// What [`QueueHandle::cancel`] did.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum Cancelled {
+    /// The download was running; its token is cancelled and the runner
+    /// finalizes it.
+    Active,
+    /// The download was pending or failed and has been removed.
+    Removed,
+}
+
+type Reply<T> = oneshot::Sender<T>;
+
+/// A request to the actor.
+enum Command {
+    Queue {
+        id: DownloadId,
+        completion_key: CompletionKey,
+        shard_files: Vec<(String, Option<u64>)>,
+        reply: Reply<Result<(u32, Option<GroupLayout>), DownloadError>>,
+    },
+    FailedLayout {
+        id: DownloadId,
+        reply: Reply<Option<GroupLayout>>,
+    },
+    Retry {
+        id: DownloadId,
+        present: Vec<u32>,
+        reply: Reply<Result<(u32, Option<GroupLayout>), DownloadError>>,
+    },
+    Reorder {
+        id: DownloadId,
+        position: u32,
+        reply: Reply<Result<u32, DownloadError>>,
+    },
+    Remove {
+        id: DownloadId,
+        reply: Reply<Result<(), DownloadError>>,
+    },
+    RemoveGroup {
+        group_id: ShardGroupId,
+        reply: Reply<usize>,
+    },
+    Cancel {
+        id: DownloadId,
+        reply: Reply<Result<Cancelled, DownloadError>>,
+    },
+    CancelAll {
+        reply: Reply<()>,
+    },
+    MarkFailed {
+        item: QueuedItem,
+        error: String,
+        completed_shards: Vec<u32>,
+        reply: Reply<()>,
+    },
+    ClearFailed {
+        reply: Reply<()>,
+    },
+    SetMaxSize {
+        size: u32,
+        reply: Reply<()>,
+    },
+    MaxSize {
+        reply: Reply<u32>,
+    },
+    Status {
+        reply: Reply<QueueStatus>,
+    },
+    Active {
+        reply: Reply<Option<(DownloadId, ActiveJob)>>,
+    },
+    Snapshot {
+        current: Option<QueuedDownload>,
+        reply: Reply<QueueSnapshot>,
+    },
+    Contains {
+        id: DownloadId,
+        reply: Reply<bool>,
+    },
+    ReferencedIds {
+        reply: Reply<Vec<DownloadId>>,
+    },
+    HoldsLease {
+        id: DownloadId,
+        lease: LeaseId,
+        reply: Reply<bool>,
+    },
+    Finish {
+        id: DownloadId,
+        lease: LeaseId,
+        reply: Reply<()>,
+    },
+    StartDispatch {
+        jobs: mpsc::UnboundedSender<Job>,
+    },
+}
+
+/// The actor's state; lives on its own task.
+struct QueueActor {
+    queue: DownloadQueue,
+    active: HashMap<DownloadId, ActiveJob>,
+    next_lease: u64,
+    /// Where jobs go once the runner has started.
+    jobs: Option<mpsc::UnboundedSender<Job>>,
+    /// Parent of every job's cancellation token.
+    shutdown: CancellationToken,
+    status_tx: watch::Sender<QueueStatus>,
+}
+
+impl QueueActor {
+    async fn run(mut self, mut commands: mpsc::UnboundedReceiver<Command>) {
+        while let Some(command) = commands.recv().await {
+            self.handle(command);
+            self.dispatch();
+            self.publish_status();
+        }
+    }
+
+    fn has_active(&self) -> bool {
+        !self.active.is_empty()
+    }
+
+    #[allow(clippy::too_many_lines)] // One short arm per command.
+    fn handle(&mut self, command: Command) {
+        // A dropped reply means the caller stopped waiting; nothing to undo.
+        match command {
+            Command::Queue {
+                id,
+                completion_key,
+                shard_files,
+                reply,
+            } => {
+                let has_active = self.has_active();
+                let result = self
+                    .queue
+                    .queue_sharded(&id, &completion_key, shard_files, has_active)
+                    .map(|position| (position, self.queue.group_layout(&id)));
+                let _ = reply.send(result);
+            }
+            Command::FailedLayout { id, reply } => {
+                let _ = reply.send(self.queue.failed_group_layout(&id));
+            }
+            Command::Retry { id, present, reply } => {
+                let has_active = self.has_active();
+                // The whole group, so progress counts the shards kept.
+                let layout = self.queue.failed_group_layout(&id);
+                let result = self
+                    .queue
+                    .retry_failed(&id, has_active, &present)
+                    .map(|position| (position, layout));
+                let _ = reply.send(result);
+            }
+            Command::Reorder {
+                id,
+                position,
+                reply,
+            } => {
+                let has_active = self.has_active();
+                let _ = reply.send(self.queue.reorder(&id, position, has_active));
+            }
+            Command::Remove { id, reply } => {
+                let _ = reply.send(self.queue.remove(&id));
+            }
+            Command::RemoveGroup { group_id, reply } => {
+                let _ = reply.send(self.queue.remove_group(&group_id));
+            }
+            Command::Cancel { id, reply } => {
+                let result = match self.active.get(&id) {
+                    Some(job) => {
+                        job.cancel.cancel();
+                        Ok(Cancelled::Active)
+                    }
+                    None => self.queue.remove(&id).map(|()| Cancelled::Removed),
+                };
+                let _ = reply.send(result);
+            }
+            Command::CancelAll { reply } => {
+                for job in self.active.values() {
+                    job.cancel.cancel();
+                }
+                self.queue.clear();
+                let _ = reply.send(());
+            }
+            Command::MarkFailed {
+                item,
+                error,
+                completed_shards,
+                reply,
+            } => {
+                self.queue.mark_failed(item, error, completed_shards);
+                let _ = reply.send(());
+            }
+            Command::ClearFailed { reply } => {
+                self.queue.clear_failed();
+                let _ = reply.send(());
+            }
+            Command::SetMaxSize { size, reply } => {
+                self.queue.set_max_size(size);
+                let _ = reply.send(());
+            }
+            Command::MaxSize { reply } => {
+                let _ = reply.send(self.queue.max_size());
+            }
+            Command::Status { reply } => {
+                let _ = reply.send(self.status());
+            }
+            Command::Active { reply } => {
+                let active = self
+                    .active
+                    .iter()
+                    .next()
+                    .map(|(id, job)| (id.clone(), job.clone()));
+                let _ = reply.send(active);
+            }
+            Command::Snapshot { current, reply } => {
+                let _ = reply.send(self.queue.snapshot(current));
+            }
+            Command::Contains { id, reply } => {
+                let known = self.active.contains_key(&id)
+                    || self.queue.is_queued(&id)
+                    || self.queue.is_failed(&id);
+                let _ = reply.send(known);
+            }
+            Command::ReferencedIds { reply } => {
+                let ids = self
+                    .queue
+                    .referenced_ids()
+                    .chain(self.active.keys())
+                    .cloned()
+                    .collect();
+                let _ = reply.send(ids);
+            }
+            Command::HoldsLease { id, lease, reply } => {
+                let holds = self.active.get(&id).is_some_and(|job| job.lease == lease);
+                let _ = reply.send(holds);
+            }
+            Command::Finish { id, lease, reply } => {
+                if self.active.get(&id).is_some_and(|job| job.lease == lease) {
+                    self.active.remove(&id);
+                }
+                let _ = reply.send(());
+            }
+            Command::StartDispatch { jobs } => self.jobs = Some(jobs),
+        }
+    }
+
+    /// Hand the next pending item to the runner, one download at a time.
+    fn dispatch(&mut self) {
+        let Some(jobs) = &self.jobs else {
+            return;
+        };
+        if self.has_active() {
+            return;
+        }
+        let Some(item) = self.queue.dequeue() else {
+            return;
+        };
+
+        let lease = LeaseId(self.next_lease);
+        self.next_lease += 1;
+        let cancel = self.shutdown.child_token();
+        let (progress_tx, _) = watch::channel(ProgressUpdate::default());
+        self.active.insert(
+            item.id.clone(),
+            ActiveJob {
+                lease,
+                cancel: cancel.clone(),
+                progress_tx: progress_tx.clone(),
+                shard_info: item.shard_info.clone(),
+                group_id: item.group_id.as_ref().map(ToString::to_string),
+            },
+        );
+        let id = item.id.clone();
+        let job = Job {
+            lease,
+            item,
+            cancel,
+            progress_tx,
+        };
+        if jobs.send(job).is_err() {
+            // The runner is gone; nothing will finish this job.
+            self.active.remove(&id);
+            self.jobs = None;
+        }
+    }
+
+    fn status(&self) -> QueueStatus {
+        QueueStatus {
+            pending: self.queue.pending_len(),
+            active: self.active.len(),
+        }
+    }
+
+    fn publish_status(&self) {
+        let status = self.status();
+        self.status_tx.send_if_modified(|current| {
+            let changed = *current != status;
+            *current = status;
+            changed
+        });
+    }
+}
+
+/// The manager's side of the actor.
+///
+/// Cloning is cheap; the actor stops when the last handle is dropped.
+#[derive(Clone)]
+pub(super) struct QueueHandle {
+    commands: mpsc::UnboundedSender<Command>,
+    status: watch::Receiver<QueueStatus>,
+    shutdown: CancellationToken,
+}
+
+impl QueueHandle {
+    /// Spawn the actor with an empty queue. Must be called within a Tokio
+    /// runtime.
+    pub(super) fn spawn(max_size: u32) -> Self {
+        let (commands, mailbox) = mpsc::unbounded_channel();
+        let (status_tx, status) = watch::channel(QueueStatus::default());
+        let shutdown = CancellationToken::new();
+        let actor = QueueActor {
+            queue: DownloadQueue::new(max_size),
+            active: HashMap::new(),
+            next_lease: 0,
+            jobs: None,
+            shutdown: shutdown.clone(),
+            status_tx,
+        };
+        tokio::spawn(actor.run(mailbox));
+        Self {
+            commands,
+            status,
+            shutdown,
+        }
+    }
+
+    /// Send a command and wait for the reply.
+    async fn call<T>(&self, command: impl FnOnce(Reply<T>) -> Command) -> T {
+        let (reply, response) = oneshot::channel();
+        // The actor holds the receiver for as long as any handle exists, so
+        // these only fail if it panicked.
+        self.commands
+            .send(command(reply))
+            .expect("download queue actor stopped");
+        response.await.expect("download queue actor stopped")
+    }
+
+    /// Queue a download's files; returns its position and shard layout.
+    pub(super) async fn queue(
+        &self,
+        id: DownloadId,
+        completion_key: CompletionKey,
+        shard_files: Vec<(String, Option<u64>)>,
+    ) -> Result<(u32, Option<GroupLayout>), DownloadError> {
+        self.call(|reply| Command::Queue {
+            id,
+            completion_key,
+            shard_files,
+            reply,
+        })
+        .await
+    }
+
+    /// Every shard of a failed download's group, in order.
+    pub(super) async fn failed_layout(&self, id: DownloadId) -> Option<GroupLayout> {
+        self.call(|reply| Command::FailedLayout { id, reply }).await
+    }
+
+    /// Queue a failed download again, except the shards in `present`;
+    /// returns its position and the layout of its whole shard group.
+    pub(super) async fn retry(
+        &self,
+        id: DownloadId,
+        present: Vec<u32>,
+    ) -> Result<(u32, Option<GroupLayout>), DownloadError> {
+        self.call(|reply| Command::Retry { id, present, reply })
+            .await
+    }
+
+    pub(super) async fn reorder(
+        &self,
+        id: DownloadId,
+        position: u32,
+    ) -> Result<u32, DownloadError> {
+        self.call(|reply| Command::Reorder {
+            id,
+            position,
+            reply,
+        })
+        .await
+    }
+
+    pub(super) async fn remove(&self, id: DownloadId) -> Result<(), DownloadError> {
+        self.call(|reply| Command::Remove { id, reply }).await
+    }
+
+    /// Remove a shard group's pending shards; returns how many there were.
+    pub(super) async fn remove_group(&self, group_id: ShardGroupId) -> usize {
+        self.call(|reply| Command::RemoveGroup { group_id, reply })
+            .await
+    }
+
+    /// Cancel a running download or remove a pending one.
+    pub(super) async fn cancel(&self, id: DownloadId) -> Result<Cancelled, DownloadError> {
+        self.call(|reply| Command::Cancel { id, reply }).await
+    }
+
+    /// Cancel every running download and drop everything pending.
+    pub(super) async fn cancel_all(&self) {
+        self.call(|reply| Command::CancelAll { reply }).await;
+    }
+
+    /// Cancel every running download and any started later, without going
+    /// through the actor; for process shutdown, where it may never run again.
+    /// Returns how many were running.
+    pub(super) fn shutdown(&self) -> usize {
+        self.shutdown.cancel();
+        self.status.borrow().active
+    }
+
+    pub(super) async fn mark_failed(
+        &self,
+        item: QueuedItem,
+        error: String,
+        completed_shards: Vec<u32>,
+    ) {
+        self.call(|reply| Command::MarkFailed {
+            item,
+            error,
+            completed_shards,
+            reply,
+        })
+        .await;
+    }
+
+    pub(super) async fn clear_failed(&self) {
+        self.call(|reply| Command::ClearFailed { reply }).await;
+    }
+
+    pub(super) async fn set_max_size(&self, size: u32) {
+        self.call(|reply| Command::SetMaxSize { size, reply }).await;
+    }
+
+    pub(super) async fn max_size(&self) -> u32 {
+        self.call(|reply| Command::MaxSize { reply }).await
+    }
+
+    /// The running download, if any.
+    pub(super) async fn active(&self) -> Option<(DownloadId, ActiveJob)> {
+        self.call(|reply| Command::Active { reply }).await
+    }
+
+    /// The queue as an API snapshot, led by `current` if something is running.
+    pub(super) async fn snapshot(&self, current: Option<QueuedDownload>) -> QueueSnapshot {
+        self.call(|reply| Command::Snapshot { current, reply })
+            .await
+    }
+
+    /// Whether a download is running, pending or failed.
+    pub(super) async fn contains(&self, id: DownloadId) -> bool {
+        self.call(|reply| Command::Contains { id, reply }).await
+    }
+
+    /// IDs of every running, pending and failed download.
+    pub(super) async fn referenced_ids(&self) -> Vec<DownloadId> {
+        self.call(|reply| Command::ReferencedIds { reply }).await
+    }
+
+    /// Whether `lease` is still the download's current run.
+    pub(super) async fn holds_lease(&self, id: DownloadId, lease: LeaseId) -> bool {
+        self.call(|reply| Command::HoldsLease { id, lease, reply })
+            .await
+    }
+
+    /// End a run: the download is no longer active, and the next one can
+    /// start. Ignored if `lease` is stale.
+    pub(super) async fn finish(&self, id: DownloadId, lease: LeaseId) {
+        self.call(|reply| Command::Finish { id, lease, reply })
+            .await;
+    }
+
+    /// Start handing jobs to the runner; returns the channel they arrive on.
+    pub(super) fn start_dispatch(&self) -> mpsc::UnboundedReceiver<Job> {
+        let (jobs, receiver) = mpsc::unbounded_channel();
+        let _ = self.commands.send(Command::StartDispatch { jobs });
+        receiver
+    }
+
+    pub(super) async fn status(&self) -> QueueStatus {
+        self.call(|reply| Command::Status { reply }).await
+    }
+
+    /// Wait until nothing is active.
+    pub(super) async fn idle(&self) {
+        let mut status = self.status.clone();
+        let _ = status.wait_for(|status| status.active == 0).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn completion_key(id: &DownloadId) -> CompletionKey {
+        CompletionKey::HfFile {
+            repo_id: id.model_id().to_string(),
+            revision: "unspecified".to_string(),
+            filename_canon: "model.gguf".to_string(),
+            quantization: id.quantization().map(String::from),
+        }
+    }
+
+    async fn queue_one(handle: &QueueHandle, name: &str) -> DownloadId {
+        let id = DownloadId::new(name, Some("Q4_K_M"));
+        let files = vec![("model.gguf".to_string(), Some(100))];
+        handle
+            .queue(id.clone(), completion_key(&id), files)
+            .await
+            .unwrap();
+        id
+    }
+
+    #[test]
+    fn lease_id_equality() {
+        let l1 = LeaseId(1);
+        let l2 = LeaseId(1);
+        let l3 = LeaseId(2);
+
+        assert_eq!(l1, l2);
+        assert_ne!(l1, l3);
+    }
+
+    #[tokio::test]
+    async fn jobs_are_handed_out_one_at_a_time() {
+        let handle = QueueHandle::spawn(10);
+        let first = queue_one(&handle, "first").await;
+        let second = queue_one(&handle, "second").await;
+        assert_eq!(
+            handle.status().await,
+            QueueStatus {
+                pending: 2,
+                active: 0
+            }
+        );
+
+        let mut jobs = handle.start_dispatch();
+        let job = jobs.recv().await.unwrap();
+        assert_eq!(job.item.id, first);
+        assert_eq!(
+            handle.status().await,
+            QueueStatus {
+                pending: 1,
+                active: 1
+            }
+        );
+        assert!(jobs.try_recv().is_err(), "a second job started early");
+
+        handle.finish(first, job.lease).await;
+        let job = jobs.recv().await.unwrap();
+        assert_eq!(job.item.id, second);
+    }
+
+    #[tokio::test]
+    async fn a_stale_lease_finishes_nothing() {
+        let handle = QueueHandle::spawn(10);
+        let id = queue_one(&handle, "model").await;
+        let mut jobs = handle.start_dispatch();
+        let job = jobs.recv().await.unwrap();
+
+        let stale = LeaseId(job.lease.0 + 1);
+        assert!(!handle.holds_lease(id.clone(), stale).await);
+        handle.finish(id.clone(), stale).await;
+        assert_eq!(handle.status().await.active, 1);
+
+        assert!(handle.holds_lease(id.clone(), job.lease).await);
+        handle.finish(id, job.lease).await;
+        assert_eq!(handle.status().await.active, 0);
+    }
+
+    #[tokio::test]
+    async fn cancel_stops_an_active_job_and_removes_a_pending_one() {
+        let handle = QueueHandle::spawn(10);
+        let running = queue_one(&handle, "running").await;
+        let waiting = queue_one(&handle, "waiting").await;
+        let mut jobs = handle.start_dispatch();
+        let job = jobs.recv().await.unwrap();
+
+        assert_eq!(handle.cancel(running.clone()).await, Ok(Cancelled::Active));
+        assert!(job.cancel.is_cancelled());
+        assert!(handle.contains(running).await, "the runner finalizes it");
+
+        assert_eq!(handle.cancel(waiting.clone()).await, Ok(Cancelled::Removed));
+        assert!(!handle.contains(waiting).await);
+    }
+
+    #[tokio::test]
+    async fn shutdown_cancels_the_running_job_without_the_actor() {
+        let handle = QueueHandle::spawn(10);
+        queue_one(&handle, "model").await;
+        let mut jobs = handle.start_dispatch();
+        let job = jobs.recv().await.unwrap();
+        // A round trip, so the published status includes the dispatch.
+        handle.status().await;
+
+        assert_eq!(handle.shutdown(), 1);
+        assert!(job.cancel.is_cancelled());
+    }
+}
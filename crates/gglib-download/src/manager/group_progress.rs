@@ -0,0 +1,246 @@
+//! Aggregate byte progress for shard groups.
+//!
+//! [`ShardInfo`] carries exact group offsets only when `HuggingFace` reported
+//! a size for *every* shard; otherwise the progress bridge had to assume
+//! equal-sized shards, which overstates the total of real GGUF sets (their
+//! final shard is smaller) and makes the bar jump at each boundary.
+//!
+//! [`GroupProgressTracker`] instead sums the sizes it actually knows: those
+//! seeded from the resolved shard list, plus any learned from a shard's own
+//! transfer once it starts. Only shards whose size is still unknown are
+//! estimated, at the mean of the known ones. Like [`ShardGroupTracker`] this
+//! is a pure state machine; the manager owns the locking.
+//!
+//! [`ShardGroupTracker`]: super::shard_group_tracker::ShardGroupTracker
+
+use std::collections::HashMap;
+
+use gglib_core::download::{GroupProgress, ShardInfo};
+
+use crate::queue::ShardGroupId;
+
+/// Progress of one shard within its group.
+#[derive(Debug, Clone, Copy, Default)]
+struct ShardSlot {
+    /// Actual size in bytes, once known.
+    size: Option<u64>,
+    downloaded: u64,
+    done: bool,
+}
+
+/// Per-shard progress for one group.
+#[derive(Debug)]
+struct GroupState {
+    slots: Vec<ShardSlot>,
+}
+
+impl GroupState {
+    fn new(total_shards: u32) -> Self {
+        Self {
+            slots: vec![ShardSlot::default(); total_shards as usize],
+        }
+    }
+
+    /// Record a shard's size if it is not already known.
+    fn learn_size(&mut self, index: u32, size: Option<u64>) {
+        if let Some(slot) = self.slots.get_mut(index as usize)
+            && slot.size.is_none()
+        {
+            slot.size = size.filter(|&s| s > 0);
+        }
+    }
+
+    /// `(downloaded, total, exact)` across the group.
+    fn totals(&self) -> (u64, u64, bool) {
+        let known: Vec<u64> = self.slots.iter().filter_map(|s| s.size).collect();
+        let known_sum: u64 = known.iter().sum();
+        let estimate = if known.is_empty() {
+            0
+        } else {
+            known_sum / known.len() as u64
+        };
+
+        let mut downloaded = 0u64;
+        let mut total = 0u64;
+        for slot in &self.slots {
+            // An unknown shard already past the estimate is at least as
+            // large as what it has delivered.
+            let size = slot.size.unwrap_or_else(|| estimate.max(slot.downloaded));
+            total = total.saturating_add(size);
+            downloaded = downloaded.saturating_add(if slot.done { size } else { slot.downloaded });
+        }
+        (
+            downloaded.min(total),
+            total,
+            known.len() == self.slots.len(),
+        )
+    }
+}
+
+/// Aggregate progress for every shard group in flight.
+#[derive(Debug, Default)]
+pub struct GroupProgressTracker {
+    groups: HashMap<ShardGroupId, GroupState>,
+}
+
+impl GroupProgressTracker {
+    /// Create a new empty tracker.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Seed a group with the resolved shard list, before any shard starts.
+    pub fn seed<'a>(
+        &mut self,
+        group_id: &ShardGroupId,
+        shards: impl IntoIterator<Item = &'a ShardInfo>,
+    ) {
+        for shard in shards {
+            self.group_mut(group_id, shard.total_shards)
+                .learn_size(shard.shard_index, shard.file_size);
+        }
+    }
+
+    /// Record a shard's own progress and return the group's
+    /// `(downloaded, total)`.
+    ///
+    /// `shard_total` is the size reported by the transfer itself; it fills in
+    /// a size `HuggingFace` did not supply.
+    pub fn observe(
+        &mut self,
+        group_id: &ShardGroupId,
+        shard: &ShardInfo,
+        downloaded: u64,
+        shard_total: u64,
+    ) -> (u64, u64) {
+        let group = self.group_mut(group_id, shard.total_shards);
+        group.learn_size(shard.shard_index, shard.file_size.or(Some(shard_total)));
+        if let Some(slot) = group.slots.get_mut(shard.shard_index as usize) {
+            slot.downloaded = slot.size.map_or(downloaded, |size| downloaded.min(size));
+        }
+        let (downloaded, total, _) = group.totals();
+        (downloaded, total)
+    }
+
+    /// Mark a shard as fully downloaded.
+    pub fn complete_shard(&mut self, group_id: &ShardGroupId, shard: &ShardInfo) {
+        let group = self.group_mut(group_id, shard.total_shards);
+        if let Some(slot) = group.slots.get_mut(shard.shard_index as usize) {
+            slot.size = slot.size.or(Some(slot.downloaded));
+            slot.done = true;
+        }
+    }
+
+    /// Snapshot one group's progress, if it is tracked.
+    pub fn snapshot(&self, group_id: &ShardGroupId) -> Option<GroupProgress> {
+        let group = self.groups.get(group_id)?;
+        let (downloaded, total, exact) = group.totals();
+        let completed = group.slots.iter().filter(|s| s.done).count();
+        Some(GroupProgress::new(
+            group_id.as_str(),
+            u32::try_from(group.slots.len()).unwrap_or(u32::MAX),
+            u32::try_from(completed).unwrap_or(u32::MAX),
+            downloaded,
+            total,
+            exact,
+        ))
+    }
+
+    /// Stop tracking a group.
+    pub fn remove(&mut self, group_id: &ShardGroupId) {
+        self.groups.remove(group_id);
+    }
+
+    /// Stop tracking every group.
+    pub fn clear(&mut self) {
+        self.groups.clear();
+    }
+
+    fn group_mut(&mut self, group_id: &ShardGroupId, total_shards: u32) -> &mut GroupState {
+        self.groups
+            .entry(group_id.clone())
+            .or_insert_with(|| GroupState::new(total_shards))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn group() -> ShardGroupId {
+        ShardGroupId::new("owner/repo:Q4_K_M:0")
+    }
+
+    fn shard(index: u32, size: Option<u64>) -> ShardInfo {
+        let filename = format!("shard-{index}.gguf");
+        match size {
+            Some(size) => ShardInfo::with_size(index, 3, filename, size),
+            None => ShardInfo::new(index, 3, filename),
+        }
+    }
+
+    #[test]
+    fn sums_actual_sizes_including_a_smaller_final_shard() {
+        let mut tracker = GroupProgressTracker::new();
+        let shards = [
+            shard(0, Some(4_000)),
+            shard(1, Some(4_000)),
+            shard(2, Some(1_500)),
+        ];
+        tracker.seed(&group(), &shards);
+
+        tracker.complete_shard(&group(), &shards[0]);
+        tracker.complete_shard(&group(), &shards[1]);
+        assert_eq!(
+            tracker.observe(&group(), &shards[2], 1_500, 1_500),
+            (9_500, 9_500)
+        );
+        assert!(tracker.snapshot(&group()).unwrap().exact);
+    }
+
+    #[test]
+    fn learns_unknown_sizes_from_the_transfer() {
+        let mut tracker = GroupProgressTracker::new();
+        let shards = [shard(0, Some(4_000)), shard(1, Some(4_000)), shard(2, None)];
+        tracker.seed(&group(), &shards);
+
+        // Before the last shard starts its size is estimated at the mean.
+        let progress = tracker.snapshot(&group()).unwrap();
+        assert_eq!(progress.total_bytes, 12_000);
+        assert!(!progress.exact);
+
+        tracker.complete_shard(&group(), &shards[0]);
+        tracker.complete_shard(&group(), &shards[1]);
+        assert_eq!(
+            tracker.observe(&group(), &shards[2], 500, 1_500),
+            (8_500, 9_500)
+        );
+
+        let progress = tracker.snapshot(&group()).unwrap();
+        assert_eq!(progress.completed_shards, 2);
+        assert!(progress.exact);
+    }
+
+    #[test]
+    fn clamps_over_reported_shard_progress() {
+        let mut tracker = GroupProgressTracker::new();
+        let tail = shard(2, Some(1_500));
+        tracker.seed(
+            &group(),
+            &[shard(0, Some(4_000)), shard(1, Some(4_000)), tail.clone()],
+        );
+        assert_eq!(
+            tracker.observe(&group(), &tail, 5_000, 1_500),
+            (1_500, 9_500)
+        );
+    }
+
+    #[test]
+    fn unknown_groups_have_no_snapshot() {
+        let mut tracker = GroupProgressTracker::new();
+        assert!(tracker.snapshot(&group()).is_none());
+        tracker.seed(&group(), &[shard(0, None)]);
+        tracker.remove(&group());
+        assert!(tracker.snapshot(&group()).is_none());
+    }
+}
@@ -0,0 +1,218 @@
+//! Ranked locks for the manager's shared state.
+//!
+//! Each of the manager's locks has a [`LockRank`], and a task may only take
+//! a lock ranked above every lock it already holds. In debug builds every
+//! acquisition is checked against the locks the current task (or, outside a
+//! task, the current thread) holds, and an out-of-order one panics with both
+//! ranks — at the call site that would eventually deadlock, instead of in a
+//! hang nobody can reproduce. Release builds compile the check away.
+//!
+//! Only the manager's own locks are ranked. The queue and the active
+//! downloads aren't locks at all — the queue actor owns them. The group
+//! progress tracker and the per-group rate estimators are shared with the
+//! progress bridges and are leaves: nothing is locked while they are held.
+
+use std::ops::{Deref, DerefMut};
+
+use tokio::sync::{Mutex, MutexGuard};
+
+use tracking::Held;
+
+/// The manager's locks, in the order they must be acquired.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub(super) enum LockRank {
+    /// Drained/busy state; held while a queue run is started or finalized.
+    DrainState,
+    /// The current queue run.
+    CurrentRun,
+    /// The shard group tracker.
+    ShardTracker,
+    /// Resolved file entries.
+    FileEntries,
+    /// Peer download sources.
+    PeerSources,
+    /// Rendered destination overrides.
+    DestinationOverrides,
+    /// The map of rate estimators (not the estimators themselves).
+    RateEstimators,
+}
+
+/// A [`Mutex`] with a place in the lock order.
+#[derive(Debug)]
+pub(super) struct RankedMutex<T> {
+    rank: LockRank,
+    inner: Mutex<T>,
+}
+
+impl<T: Send> RankedMutex<T> {
+    pub(super) fn new(rank: LockRank, value: T) -> Self {
+        Self {
+            rank,
+            inner: Mutex::new(value),
+        }
+    }
+
+    pub(super) async fn lock(&self) -> Ranked<MutexGuard<'_, T>> {
+        let held = Held::acquire(self.rank);
+        Ranked {
+            guard: self.inner.lock().await,
+            _held: held,
+        }
+    }
+}
+
+/// A lock guard that releases its rank when dropped.
+pub(super) struct Ranked<G> {
+    // Declared first so the lock is released before the rank is.
+    guard: G,
+    _held: Held,
+}
+
+impl<G: Deref> Deref for Ranked<G> {
+    type Target = G::Target;
+
+    fn deref(&self) -> &Self::Target {
+        &self.guard
+    }
+}
+
+impl<G: DerefMut> DerefMut for Ranked<G> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.guard
+    }
+}
+
+#[cfg(debug_assertions)]
+mod tracking {
+    use std::collections::HashMap;
+    use std::sync::{Mutex, OnceLock, PoisonError};
+    use std::thread::ThreadId;
+
+    use super::LockRank;
+
+    /// Who is holding a lock. Guards are held across `.await`, and a task
+    /// can move between threads while it waits, so inside a task the task
+    /// is the holder.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    enum Holder {
+        Task(tokio::task::Id),
+        Thread(ThreadId),
+    }
+
+    impl Holder {
+        fn current() -> Self {
+            tokio::task::try_id()
+                .map_or_else(|| Self::Thread(std::thread::current().id()), Self::Task)
+        }
+    }
+
+    fn held() -> &'static Mutex<HashMap<Holder, Vec<LockRank>>> {
+        static HELD: OnceLock<Mutex<HashMap<Holder, Vec<LockRank>>>> = OnceLock::new();
+        HELD.get_or_init(Mutex::default)
+    }
+
+    /// A rank held by the current task, released on drop.
+    #[derive(Debug)]
+    pub struct Held {
+        holder: Holder,
+        rank: LockRank,
+    }
+
+    impl Held {
+        /// Record `rank` as held, panicking if a lock ranked at or above it
+        /// already is.
+        pub fn acquire(rank: LockRank) -> Self {
+            let holder = Holder::current();
+            let mut held = held().lock().unwrap_or_else(PoisonError::into_inner);
+            let ranks = held.entry(holder).or_default();
+            if let Some(&top) = ranks.iter().max()
+                && top >= rank
+            {
+                drop(held);
+                panic!("lock order violation: acquiring {rank:?} while holding {top:?}");
+            }
+            ranks.push(rank);
+            Self { holder, rank }
+        }
+    }
+
+    impl Drop for Held {
+        fn drop(&mut self) {
+            let mut held = held().lock().unwrap_or_else(PoisonError::into_inner);
+            if let Some(ranks) = held.get_mut(&self.holder) {
+                if let Some(at) = ranks.iter().rposition(|&rank| rank == self.rank) {
+                    ranks.swap_remove(at);
+                }
+                if ranks.is_empty() {
+                    held.remove(&self.holder);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(not(debug_assertions))]
+mod tracking {
+    use super::LockRank;
+
+    /// Release builds don't track ranks.
+    #[derive(Debug)]
+    pub struct Held;
+
+    impl Held {
+        #[inline]
+        pub const fn acquire(_rank: LockRank) -> Self {
+            Self
+        }
+    }
+}
+
+#[cfg(all(test, debug_assertions))]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn locks_taken_in_rank_order_are_allowed() {
+        let run = RankedMutex::new(LockRank::CurrentRun, ());
+        let tracker = RankedMutex::new(LockRank::ShardTracker, ());
+
+        let _run = run.lock().await;
+        let _tracker = tracker.lock().await;
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "acquiring CurrentRun while holding ShardTracker")]
+    async fn a_lock_taken_out_of_order_panics() {
+        let run = RankedMutex::new(LockRank::CurrentRun, ());
+        let tracker = RankedMutex::new(LockRank::ShardTracker, ());
+
+        let _tracker = tracker.lock().await;
+        let _run = run.lock().await;
+    }
+
+    #[tokio::test]
+    async fn released_locks_no_longer_count() {
+        let run = RankedMutex::new(LockRank::CurrentRun, ());
+        let tracker = RankedMutex::new(LockRank::ShardTracker, ());
+
+        drop(tracker.lock().await);
+        let _run = run.lock().await;
+        let _tracker = tracker.lock().await;
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn each_task_has_its_own_order() {
+        let tracker = std::sync::Arc::new(RankedMutex::new(LockRank::ShardTracker, ()));
+        let run = std::sync::Arc::new(RankedMutex::new(LockRank::CurrentRun, ()));
+
+        let guard = tracker.lock().await;
+        let other = tokio::spawn({
+            let run = std::sync::Arc::clone(&run);
+            async move {
+                let _run = run.lock().await;
+            }
+        });
+        other.await.unwrap();
+        drop(guard);
+    }
+}
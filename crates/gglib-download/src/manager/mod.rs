@@ -1,39 +1,52 @@
 #![doc = include_str!("README.md")]
+mod actor;
+mod group_progress;
+mod lock_order;
 mod paths;
+mod peer_fetch;
 mod shard_group_tracker;
 mod worker;
 
 use crate::queue::ShardGroupId;
 use std::collections::HashMap;
+use std::path::PathBuf;
 use std::sync::Arc;
-use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::time::Duration;
 
 use async_trait::async_trait;
 use indexmap::IndexMap;
 
+use gglib_core::utils::clock::Clock;
+use gglib_core::utils::fs::FileSystem;
 use gglib_core::utils::shard_filename::base_shard_filename;
-use tokio::sync::{Mutex, Notify, RwLock, watch};
+use tokio::sync::{Mutex, mpsc, watch};
 use tokio::time::interval;
 use tokio_util::sync::CancellationToken;
 
 use gglib_core::download::{
-    DownloadError, DownloadEvent, DownloadId, DownloadSummary, QueueSnapshot, RateEstimator,
+    CollectionQueueResult, CollectionQueuedRepo, CollectionSkippedRepo, DestinationOverride,
+    DownloadError, DownloadEvent, DownloadHistoryFilter, DownloadId, DownloadPhase,
+    DownloadSummary, GroupProgress, QueueRunSummary, QueueSnapshot, QueuedDownload, RateEstimator,
     ShardInfo,
 };
 use gglib_core::ports::{
-    DownloadEventEmitterPort, DownloadManagerConfig, DownloadManagerPort, DownloadRequest,
-    DownloadStateRepositoryPort, HfClientPort, ModelRegistrarPort, QuantizationResolver,
-    ResolvedFile,
+    ArchitectureSupportPort, DownloadEventEmitterPort, DownloadManagerConfig, DownloadManagerPort,
+    DownloadRequest, DownloadStateRepositoryPort, HfClientPort, ModelHub, ModelHubPort,
+    ModelRegistrarPort, PeerSource, QuantizationResolver, ResolvedFile,
 };
 
 use crate::quant_selector::QuantizationSelector;
-use crate::queue::{DownloadQueue, QueuedItem};
+use crate::queue::QueuedItem;
 use crate::resolver::HfQuantizationResolver;
 
+use actor::{Cancelled, Job, LeaseId, QueueHandle};
+use group_progress::GroupProgressTracker;
+use lock_order::{LockRank, RankedMutex};
 use shard_group_tracker::{GroupMetadata, ShardGroupTracker};
 
-pub use paths::DownloadDestination;
+pub use paths::STAGING_DIR;
+pub use paths::{DownloadDestination, RenderedOverride};
 pub use worker::{CompletedJob, DownloadJob, ProgressUpdate, WorkerDeps};
 
 /// How often the progress bridge samples the worker and emits an event.
@@ -42,27 +55,6 @@ pub use worker::{CompletedJob, DownloadJob, ProgressUpdate, WorkerDeps};
 /// the display cadence. The GUI does not re-throttle on top of it.
 const PROGRESS_TICK: Duration = Duration::from_millis(250);
 
-/// Lease ID for tracking active downloads.
-///
-/// Used to prevent stale finalize commits when a download is cancelled
-/// or replaced while running.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-struct LeaseId(u64);
-
-/// State for an active download.
-struct ActiveJob {
-    /// Unique lease for this execution.
-    lease: LeaseId,
-    /// Cancellation token.
-    cancel: CancellationToken,
-    /// Progress sender (bridges subscribe to this).
-    progress_tx: watch::Sender<ProgressUpdate>,
-    /// Shard information if this is a sharded download.
-    shard_info: Option<ShardInfo>,
-    /// Group ID if this is part of a shard group.
-    group_id: Option<String>,
-}
-
 // =============================================================================
 // Queue Run State (for completion tracking)
 // =============================================================================
@@ -150,17 +142,11 @@ struct QueueRunState {
 }
 
 impl QueueRunState {
-    /// Create a new queue run.
-    fn new() -> Self {
-        use std::time::SystemTime;
-
-        let now = SystemTime::now()
-            .duration_since(SystemTime::UNIX_EPOCH)
-            .unwrap_or_default();
-
+    /// Create a new queue run starting at `started_at_ms`.
+    fn new(started_at_ms: u64) -> Self {
         Self {
             run_id: uuid::Uuid::new_v4(),
-            started_at_ms: now.as_millis().try_into().unwrap_or(0),
+            started_at_ms,
             completions: IndexMap::new(),
         }
     }
@@ -188,6 +174,28 @@ impl QueueRunState {
     }
 }
 
+/// Everything needed to resolve and fetch downloads from one model hub.
+struct HubRoute {
+    /// Client for the hub's API.
+    client: Arc<dyn ModelHubPort>,
+    /// File resolver.
+    resolver: HfQuantizationResolver,
+    /// Quantization selector for choosing best quantization.
+    selector: QuantizationSelector,
+}
+
+impl HubRoute {
+    fn new(client: Arc<dyn ModelHubPort>) -> Self {
+        let resolver_arc: Arc<dyn QuantizationResolver> =
+            Arc::new(HfQuantizationResolver::new(Arc::clone(&client)));
+        Self {
+            resolver: HfQuantizationResolver::new(Arc::clone(&client)),
+            selector: QuantizationSelector::new(resolver_arc),
+            client,
+        }
+    }
+}
+
 /// Dependencies for creating a download manager.
 ///
 /// This struct bundles all the ports and configuration needed
@@ -207,6 +215,13 @@ where
     pub hf_client: Arc<H>,
     /// Port for emitting download events.
     pub event_emitter: Arc<E>,
+    /// Checks downloaded architectures against the installed llama.cpp;
+    /// `None` skips the check.
+    pub arch_support: Option<Arc<dyn ArchitectureSupportPort>>,
+    /// Time source for rates, ETAs, queue-run timestamps and partial ages.
+    pub clock: Arc<dyn Clock>,
+    /// Filesystem used to promote staged files and sweep partials.
+    pub fs: Arc<dyn FileSystem>,
     /// Configuration for the download manager.
     pub config: DownloadManagerConfig,
 }
@@ -215,6 +230,8 @@ where
 ///
 /// Returns an implementation of `DownloadManagerPort` that can be
 /// stored as `Arc<dyn DownloadManagerPort>` in adapters.
+///
+/// Must be called within a Tokio runtime: it spawns the queue actor.
 pub fn build_download_manager<R, D, H, E>(
     deps: DownloadManagerDeps<R, D, H, E>,
 ) -> DownloadManagerImpl
@@ -224,13 +241,7 @@ where
     H: HfClientPort + 'static,
     E: DownloadEventEmitterPort + 'static,
 {
-    DownloadManagerImpl::new(
-        deps.model_registrar,
-        deps.download_repo,
-        deps.hf_client,
-        deps.event_emitter,
-        deps.config,
-    )
+    DownloadManagerImpl::new(deps)
 }
 
 /// Concrete implementation of the download manager.
@@ -240,87 +251,163 @@ where
 pub struct DownloadManagerImpl {
     /// Model registrar for completed downloads.
     model_registrar: Arc<dyn ModelRegistrarPort>,
-    /// Repository for persisting queue state (reserved for future persistence).
-    _download_repo: Arc<dyn DownloadStateRepositoryPort>,
+    /// Repository recording drained queue runs for the download history.
+    download_repo: Arc<dyn DownloadStateRepositoryPort>,
     /// Event emitter for download events.
     event_emitter: Arc<dyn DownloadEventEmitterPort>,
-    /// `HuggingFace` client for fetching model metadata (e.g. tags at registration time).
+    /// `HuggingFace` client for collections, which only `HuggingFace` has.
     hf_client: Arc<dyn HfClientPort>,
-    /// File resolver.
-    resolver: HfQuantizationResolver,
-    /// Quantization selector for choosing best quantization.
-    selector: QuantizationSelector,
-    /// Queue state (protected by `RwLock` for async access).
-    queue: RwLock<DownloadQueue>,
+    /// Architecture check run on each registered model.
+    arch_support: Option<Arc<dyn ArchitectureSupportPort>>,
+    /// Time source; see [`DownloadManagerDeps::clock`].
+    clock: Arc<dyn Clock>,
+    /// Filesystem; see [`DownloadManagerDeps::fs`].
+    fs: Arc<dyn FileSystem>,
+    /// Resolvers for each model hub downloads can come from; `HuggingFace`
+    /// is always present, others are added with [`Self::with_hub`].
+    hubs: HashMap<ModelHub, HubRoute>,
+    /// The queue actor, which owns the pending, active and failed downloads.
+    queue: QueueHandle,
     /// Configuration.
     config: DownloadManagerConfig,
-    /// Active downloads (keyed by download ID).
-    /// Lock order: always acquire queue lock before active lock.
-    active: Mutex<HashMap<DownloadId, ActiveJob>>,
     /// Shard group tracker for coordinating multi-shard downloads.
-    shard_tracker: Mutex<ShardGroupTracker>,
-    /// Counter for generating lease IDs.
-    lease_counter: AtomicU64,
-    /// Notifier for waking the runner when work is available.
-    queue_notify: Notify,
+    shard_tracker: RankedMutex<ShardGroupTracker>,
     /// Whether the runner has been started (never reset for long-lived runner).
     runner_started: AtomicBool,
+    /// Whether the partial-download janitor has been started.
+    janitor_started: AtomicBool,
     /// Current queue run state (None when drained).
-    current_run: Mutex<Option<QueueRunState>>,
+    current_run: RankedMutex<Option<QueueRunState>>,
     /// Previous drain state for transition detection.
-    prev_is_drained: Mutex<bool>,
+    prev_is_drained: RankedMutex<bool>,
     /// File entries with OIDs for each download (keyed by download ID).
-    file_entries_map: Mutex<HashMap<String, Vec<ResolvedFile>>>,
+    file_entries_map: RankedMutex<HashMap<String, Vec<ResolvedFile>>>,
+    /// Peer downloads' sources (keyed by download ID); absent for
+    /// `HuggingFace` downloads.
+    peer_sources: RankedMutex<HashMap<String, PeerSource>>,
+    /// Destination overrides (keyed by download ID), rendered when queued;
+    /// absent for downloads stored in the default layout.
+    destination_overrides: RankedMutex<HashMap<String, RenderedOverride>>,
     /// Rate estimators, keyed by shard group (or by download ID when unsharded).
     ///
     /// Keyed by *group* rather than job so the estimate survives shard
     /// boundaries. A per-job estimator restarted from zero on every shard,
     /// which on a five-shard model meant five ramp-ups from a cold average.
-    rate_estimators: Mutex<HashMap<String, Arc<Mutex<RateEstimator>>>>,
+    rate_estimators: RankedMutex<HashMap<String, Arc<Mutex<RateEstimator>>>>,
+    /// Byte progress per shard group, shared with the progress bridges.
+    group_progress: Arc<Mutex<GroupProgressTracker>>,
+    /// Synthetic, faulty transfers; see [`DownloadManagerConfig::fault_injection`].
+    #[cfg(feature = "fault-injection")]
+    faults: Option<Arc<crate::chaos::FaultInjector>>,
 }
 
 impl DownloadManagerImpl {
     /// Create a new download manager.
-    fn new<R, D, H, E>(
-        model_registrar: Arc<R>,
-        download_repo: Arc<D>,
-        hf_client: Arc<H>,
-        event_emitter: Arc<E>,
-        config: DownloadManagerConfig,
-    ) -> Self
+    fn new<R, D, H, E>(deps: DownloadManagerDeps<R, D, H, E>) -> Self
     where
         R: ModelRegistrarPort + 'static,
         D: DownloadStateRepositoryPort + 'static,
         H: HfClientPort + 'static,
         E: DownloadEventEmitterPort + 'static,
     {
+        let DownloadManagerDeps {
+            model_registrar,
+            download_repo,
+            hf_client,
+            event_emitter,
+            arch_support,
+            clock,
+            fs,
+            config,
+        } = deps;
         let hf_client_dyn: Arc<dyn HfClientPort> = hf_client;
-        let resolver = HfQuantizationResolver::new(Arc::clone(&hf_client_dyn));
-        let resolver_arc: Arc<dyn QuantizationResolver> =
-            Arc::new(HfQuantizationResolver::new(Arc::clone(&hf_client_dyn)));
-        let selector = QuantizationSelector::new(resolver_arc);
+        let hf_route = HubRoute::new(Arc::clone(&hf_client_dyn) as Arc<dyn ModelHubPort>);
+
+        #[cfg(feature = "fault-injection")]
+        let faults = config
+            .fault_injection
+            .clone()
+            .map(|faults| Arc::new(crate::chaos::FaultInjector::new(faults)));
+        #[cfg(not(feature = "fault-injection"))]
+        if config.fault_injection.is_some() {
+            tracing::warn!(
+                "Download fault injection is configured but gglib-download was built \
+                 without the `fault-injection` feature; ignoring it"
+            );
+        }
 
         Self {
             model_registrar,
-            _download_repo: download_repo,
+            download_repo,
             event_emitter: event_emitter as Arc<dyn DownloadEventEmitterPort>,
             hf_client: hf_client_dyn,
-            resolver,
-            selector,
-            queue: RwLock::new(DownloadQueue::new(config.max_queue_size)),
+            arch_support,
+            clock,
+            fs,
+            hubs: HashMap::from([(ModelHub::HuggingFace, hf_route)]),
+            queue: QueueHandle::spawn(config.max_queue_size),
             config,
-            active: Mutex::new(HashMap::new()),
-            shard_tracker: Mutex::new(ShardGroupTracker::new()),
-            lease_counter: AtomicU64::new(0),
-            queue_notify: Notify::new(),
+            shard_tracker: RankedMutex::new(LockRank::ShardTracker, ShardGroupTracker::new()),
             runner_started: AtomicBool::new(false),
-            current_run: Mutex::new(None),
-            prev_is_drained: Mutex::new(true), // Start in drained state
-            file_entries_map: Mutex::new(HashMap::new()),
-            rate_estimators: Mutex::new(HashMap::new()),
+            janitor_started: AtomicBool::new(false),
+            current_run: RankedMutex::new(LockRank::CurrentRun, None),
+            prev_is_drained: RankedMutex::new(LockRank::DrainState, true), // Start in drained state
+            file_entries_map: RankedMutex::new(LockRank::FileEntries, HashMap::new()),
+            peer_sources: RankedMutex::new(LockRank::PeerSources, HashMap::new()),
+            destination_overrides: RankedMutex::new(LockRank::DestinationOverrides, HashMap::new()),
+            rate_estimators: RankedMutex::new(LockRank::RateEstimators, HashMap::new()),
+            group_progress: Arc::new(Mutex::new(GroupProgressTracker::new())),
+            #[cfg(feature = "fault-injection")]
+            faults,
         }
     }
 
+    /// Also resolve and download `modelscope:`-style references from the
+    /// hub `client` talks to.
+    #[must_use]
+    pub fn with_hub(mut self, client: Arc<dyn ModelHubPort>) -> Self {
+        self.hubs.insert(client.hub(), HubRoute::new(client));
+        self
+    }
+
+    /// The route for `reference`'s hub and its bare repository ID.
+    fn route<'a>(&self, reference: &'a str) -> Result<(&HubRoute, &'a str), DownloadError> {
+        let (hub, repo_id) = ModelHub::split(reference);
+        let route = self.hubs.get(&hub).ok_or_else(|| {
+            DownloadError::resolution_failed(format!(
+                "{} downloads are not configured",
+                hub.display_name()
+            ))
+        })?;
+        Ok((route, repo_id))
+    }
+
+    /// Direct download URL for `item` when it comes from a hub other than
+    /// `HuggingFace`, which the worker streams itself.
+    fn hub_file_url(&self, item: &QueuedItem) -> Option<String> {
+        let (route, repo_id) = self.route(item.id.model_id()).ok()?;
+        if route.client.hub() == ModelHub::HuggingFace {
+            return None;
+        }
+        let path = &item.shard_info.as_ref()?.filename;
+        Some(
+            route
+                .client
+                .file_url(repo_id, item.revision.as_deref(), path),
+        )
+    }
+
+    /// Tags of `reference`'s repository, from the hub it came from.
+    async fn fetch_model_tags(&self, reference: &str) -> Result<Vec<String>, String> {
+        let (route, repo_id) = self.route(reference).map_err(|e| e.to_string())?;
+        route
+            .client
+            .get_model_info(repo_id)
+            .await
+            .map(|info| info.tags)
+            .map_err(|e| e.to_string())
+    }
+
     /// Get (or create) the rate estimator covering this item's transfer.
     ///
     /// Sharded downloads share one estimator across the whole group so the
@@ -336,12 +423,61 @@ impl DownloadManagerImpl {
                 .lock()
                 .await
                 .entry(key)
-                .or_insert_with(|| {
-                    Arc::new(Mutex::new(RateEstimator::new(std::time::Instant::now())))
-                }),
+                .or_insert_with(|| Arc::new(Mutex::new(RateEstimator::new(self.clock.now())))),
         )
     }
 
+    /// Fill in the active item's bytes, rate, ETA and elapsed time.
+    ///
+    /// Reads the same sources as the progress bridge (the group tracker for
+    /// aggregate bytes, the shared estimator for rate and ETA) without feeding
+    /// the estimator, so taking a snapshot never disturbs the average.
+    async fn fill_live_progress(
+        &self,
+        dto: &mut QueuedDownload,
+        group_id: Option<&str>,
+        progress: &ProgressUpdate,
+    ) {
+        let transferring = progress.phase == DownloadPhase::Transferring;
+        let group = match group_id {
+            Some(group) => self
+                .group_progress
+                .lock()
+                .await
+                .snapshot(&ShardGroupId::new(group)),
+            None => None,
+        };
+        let (downloaded, total) = match group {
+            Some(group) => (group.downloaded_bytes, group.total_bytes),
+            None if transferring => (progress.downloaded, progress.total),
+            // Hashing a staged file: every byte is already on disk.
+            None => (progress.total, progress.total),
+        };
+
+        let key = group_id.map_or_else(|| dto.id.clone(), ToString::to_string);
+        let estimator = self.rate_estimators.lock().await.get(&key).cloned();
+        let (speed, eta, elapsed) = match estimator {
+            Some(estimator) => {
+                let estimator = estimator.lock().await;
+                // Nothing is transferring while a staged file is hashed.
+                let (speed, eta) = if transferring {
+                    (estimator.rate_bps(), estimator.eta_seconds())
+                } else {
+                    (None, None)
+                };
+                (
+                    speed,
+                    eta,
+                    Some(estimator.elapsed_seconds(self.clock.now())),
+                )
+            }
+            None => (None, None, None),
+        };
+
+        dto.update_progress(downloaded, total, speed, eta);
+        dto.elapsed_seconds = elapsed;
+    }
+
     /// Drop the estimator for a finished transfer.
     async fn release_rate_estimator(&self, item: &QueuedItem) {
         let key = item
@@ -351,16 +487,16 @@ impl DownloadManagerImpl {
         self.rate_estimators.lock().await.remove(&key);
     }
 
+    /// Start tracking byte progress for a newly queued shard group.
+    async fn seed_group_progress(&self, layout: Option<(ShardGroupId, Vec<ShardInfo>)>) {
+        if let Some((group_id, shards)) = layout {
+            self.group_progress.lock().await.seed(&group_id, &shards);
+        }
+    }
+
     /// Record a completion in the current queue run (if active).
     async fn record_completion_in_run(&self, item: &QueuedItem, kind: CompletionKind) {
-        use std::time::SystemTime;
-
-        let timestamp_ms = SystemTime::now()
-            .duration_since(SystemTime::UNIX_EPOCH)
-            .unwrap_or_default()
-            .as_millis()
-            .try_into()
-            .unwrap_or(0);
+        let timestamp_ms = self.clock.unix_millis();
 
         // Generate display name from completion key
         let display_name = item.completion_key.to_string();
@@ -388,6 +524,12 @@ impl DownloadManagerImpl {
         }
     }
 
+    /// What the fault injector has done so far; `None` without fault injection.
+    #[cfg(feature = "fault-injection")]
+    pub fn fault_stats(&self) -> Option<crate::chaos::FaultStats> {
+        self.faults.as_ref().map(|faults| faults.stats())
+    }
+
     /// Get access to the model registrar for direct registration.
     pub fn model_registrar(&self) -> &Arc<dyn ModelRegistrarPort> {
         &self.model_registrar
@@ -400,8 +542,8 @@ impl DownloadManagerImpl {
         &self,
         id: &DownloadId,
     ) -> Option<watch::Receiver<ProgressUpdate>> {
-        let active = self.active.lock().await;
-        active.get(id).map(|job| job.progress_tx.subscribe())
+        let (active_id, job) = self.queue.active().await?;
+        (&active_id == id).then(|| job.progress_tx.subscribe())
     }
 
     /// Ensure the runner is started.
@@ -415,106 +557,267 @@ impl DownloadManagerImpl {
             .is_ok()
         {
             let manager = Arc::clone(self);
+            let jobs = self.queue.start_dispatch();
             tokio::spawn(async move {
-                manager.run_loop().await;
+                manager.run_loop(jobs).await;
+            });
+        }
+    }
+
+    /// Start the partial-download janitor: one sweep now, then one every
+    /// [`JANITOR_INTERVAL`](crate::janitor::JANITOR_INTERVAL).
+    ///
+    /// Like [`Self::ensure_runner`] this is idempotent, and the task runs
+    /// for the lifetime of the manager.
+    pub fn start_janitor(self: &Arc<Self>) {
+        if self
+            .janitor_started
+            .compare_exchange(false, true, Ordering::SeqCst, Ordering::SeqCst)
+            .is_ok()
+        {
+            let manager = Arc::clone(self);
+            tokio::spawn(async move {
+                let mut ticker = interval(crate::janitor::JANITOR_INTERVAL);
+                loop {
+                    ticker.tick().await;
+                    manager.sweep_partials().await;
+                }
             });
         }
     }
 
+    /// Sweep orphaned partial downloads and report what was found.
+    async fn sweep_partials(&self) -> crate::janitor::SweepReport {
+        let in_use = self.partial_dirs_in_use().await;
+        let models_dir = self.config.models_directory.clone();
+        let grace = self.config.partial_grace_period;
+        let fs = Arc::clone(&self.fs);
+        let now = self.clock.system_now();
+        let report = tokio::task::spawn_blocking(move || {
+            crate::janitor::sweep(fs.as_ref(), &models_dir, &in_use, grace, now)
+        })
+        .await
+        .unwrap_or_default();
+
+        if report.found_any() {
+            self.event_emitter.emit(DownloadEvent::PartialCleanup {
+                reclaimable_files: report.reclaimable_files,
+                reclaimable_bytes: report.reclaimable_bytes,
+                removed_files: report.removed_files,
+                removed_bytes: report.removed_bytes,
+            });
+        }
+        report
+    }
+
+    /// Directories that pending, active and failed downloads write into.
+    async fn partial_dirs_in_use(&self) -> Vec<PathBuf> {
+        let ids = self.queue.referenced_ids().await;
+        let overrides = self.destination_overrides.lock().await;
+        let models_dir = &self.config.models_directory;
+        ids.iter()
+            .flat_map(|id| {
+                let mut dest = DownloadDestination::plan_staged(models_dir, id, Vec::new());
+                if let Some(rendered) = overrides.get(&id.to_string()) {
+                    dest = dest.with_override(models_dir, rendered);
+                }
+                std::iter::once(dest.model_dir).chain(dest.staging_dir)
+            })
+            .collect()
+    }
+
     /// The main runner loop.
     ///
-    /// This runs for the lifetime of the manager, waiting on `queue_notify`
-    /// when there's no work and draining the queue when there is.
-    async fn run_loop(&self) {
-        loop {
-            // Try to get the next job
-            if let Some((lease, item, cancel, progress_tx)) = self.next_job().await {
-                // Spawn progress bridge task, sharing the shard group's
-                // estimator so the reported speed does not restart per shard.
-                let estimator = self.rate_estimator_for(&item).await;
-                let bridge_finished = CancellationToken::new();
-                let bridge_handle = self.spawn_progress_bridge(
-                    &item.id,
-                    item.shard_info.as_ref(),
-                    progress_tx.subscribe(),
-                    cancel.clone(),
-                    estimator,
-                    bridge_finished.clone(),
-                );
+    /// This runs for the lifetime of the manager, running each job the
+    /// queue actor hands it; the actor sends the next one once this one is
+    /// finalized.
+    async fn run_loop(&self, mut jobs: mpsc::UnboundedReceiver<Job>) {
+        while let Some(Job {
+            lease,
+            item,
+            cancel,
+            progress_tx,
+        }) = jobs.recv().await
+        {
+            // The item is now active.
+            self.emit_queue_snapshot().await;
+
+            // Spawn progress bridge task, sharing the shard group's
+            // estimator so the reported speed does not restart per shard.
+            let estimator = self.rate_estimator_for(&item).await;
+            let bridge_finished = CancellationToken::new();
+            let bridge_handle = self.spawn_progress_bridge(
+                &item,
+                progress_tx.subscribe(),
+                cancel.clone(),
+                estimator,
+                bridge_finished.clone(),
+            );
 
-                // Create worker deps and job
-                let deps = WorkerDeps {
-                    config: self.config.clone(),
-                    event_emitter: Arc::clone(&self.event_emitter),
-                };
+            // Create worker deps and job
+            let deps = WorkerDeps {
+                config: self.config.clone(),
+                event_emitter: Arc::clone(&self.event_emitter),
+                fs: Arc::clone(&self.fs),
+                #[cfg(feature = "fault-injection")]
+                faults: self.faults.clone(),
+            };
+
+            let files = Self::extract_files(&item);
+            let expected_sha256 = self.expected_sha256(&item).await;
+            let peer = self
+                .peer_sources
+                .lock()
+                .await
+                .get(&item.id.to_string())
+                .cloned();
+            // Stage into `.staging/` and validate before anything lands in
+            // the models directory, unless the caller opted out.
+            let models_dir = &self.config.models_directory;
+            let mut destination = if self.config.verify_before_register {
+                DownloadDestination::plan_staged(models_dir, &item.id, files)
+            } else {
+                DownloadDestination::plan(models_dir, &item.id, files)
+            };
+            if let Some(rendered) = self
+                .destination_overrides
+                .lock()
+                .await
+                .get(&item.id.to_string())
+            {
+                destination = destination.with_override(models_dir, rendered);
+            }
 
-                let files = Self::extract_files(&item);
-                let destination =
-                    DownloadDestination::plan(&self.config.models_directory, &item.id, files);
-
-                // Save primary file path before destination is moved into the job.
-                let primary_file_path = destination.primary_path();
-
-                // Remove corrupt cached files before hf_hub_download sees them.
-                Self::remove_corrupt_cached_file(&item, primary_file_path.as_ref());
-
-                // Clone the progress sender so we can detect cache hits after
-                // run_job consumes the original.
-                let progress_tx_clone = progress_tx.clone();
-
-                let job = DownloadJob {
-                    id: item.id.clone(),
-                    destination,
-                    revision: item.revision.clone(),
-                    cancel: cancel.clone(),
-                    progress_tx,
-                    // Plumb the per-shard file size from HF metadata so the
-                    // stat-fallback poller (`xet_poller`) can emit synthetic
-                    // progress events with a real total. Without this the
-                    // hf-xet fast path leaves the CLI bar stuck at `0 B/0 B`.
-                    expected_total: item.shard_info.as_ref().and_then(|s| s.file_size),
-                };
+            // Save primary file path before destination is moved into the job.
+            let primary_file_path = destination.primary_path();
 
-                // Emit started event (include shard info if this is a sharded download)
-                self.emit_started_event(&item);
+            // Remove corrupt cached files before hf_hub_download sees them.
+            Self::remove_corrupt_cached_file(&item, primary_file_path.as_ref());
 
-                // Run the worker
-                let result = worker::run_job(job, &deps).await;
+            // Clone the progress sender so we can detect cache hits after
+            // run_job consumes the original.
+            let progress_tx_clone = progress_tx.clone();
 
-                // If the file was cached, emit synthetic progress so the UI
-                // shows at least one ShardProgress event.
-                Self::emit_synthetic_progress_if_cached(
-                    &item,
-                    &result,
-                    &progress_tx_clone,
-                    primary_file_path.as_ref(),
-                );
+            let job = DownloadJob {
+                id: item.id.clone(),
+                destination,
+                revision: item.revision.clone(),
+                cancel: cancel.clone(),
+                progress_tx,
+                // Plumb the per-shard file size from HF metadata so the
+                // stat-fallback poller (`xet_poller`) can emit synthetic
+                // progress events with a real total. Without this the
+                // hf-xet fast path leaves the CLI bar stuck at `0 B/0 B`.
+                expected_total: item.shard_info.as_ref().and_then(|s| s.file_size),
+                expected_sha256,
+                peer,
+                hub_url: self.hub_file_url(&item),
+            };
+
+            // Emit started event (include shard info if this is a sharded download)
+            self.emit_started_event(&item);
+
+            // Run the worker
+            let result = worker::run_job(job, &deps).await;
+
+            // If the file was cached, emit synthetic progress so the UI
+            // shows at least one ShardProgress event.
+            Self::emit_synthetic_progress_if_cached(
+                &item,
+                &result,
+                &progress_tx_clone,
+                primary_file_path.as_ref(),
+            );
 
-                drop(progress_tx_clone);
+            drop(progress_tx_clone);
 
-                // Tell the bridge the worker is done, then actually join it.
-                // Dropping the JoinHandle only detaches the task, which let its
-                // final progress event race the terminal event emitted below.
-                //
-                // The signal is explicit rather than "wait for the senders to
-                // drop": the active-jobs map holds a `progress_tx` clone until
-                // `finalize_job` removes it, and that runs after this join.
-                bridge_finished.cancel();
-                let _ = bridge_handle.await;
+            // Tell the bridge the worker is done, then actually join it.
+            // Dropping the JoinHandle only detaches the task, which let its
+            // final progress event race the terminal event emitted below.
+            //
+            // The signal is explicit rather than "wait for the senders to
+            // drop": the active-jobs map holds a `progress_tx` clone until
+            // `finalize_job` removes it, and that runs after this join.
+            bridge_finished.cancel();
+            let _ = bridge_handle.await;
 
-                // Finalize the job with item context for shard tracking
-                self.finalize_job(&item, lease, result).await;
+            // Finalize the job with item context for shard tracking
+            self.finalize_job(&item, lease, result).await;
+        }
+    }
 
-                // Notify to keep draining if more work
-                self.queue_notify.notify_one();
-            } else {
-                // No work, wait for notification
-                self.queue_notify.notified().await;
+    /// Queue a single file fetched from a paired instance.
+    ///
+    /// Skips `HuggingFace` resolution: the peer already said which file it is
+    /// and how large. Its SHA-256, when known, is recorded as the file's OID
+    /// so staged validation can check it and the registered model keeps it.
+    async fn queue_peer_download(
+        &self,
+        id: DownloadId,
+        peer: PeerSource,
+        destination: Option<DestinationOverride>,
+    ) -> Result<DownloadId, DownloadError> {
+        let rendered = destination
+            .map(|d| RenderedOverride::render(&d, &id, [peer.file_name.as_str()]))
+            .transpose()?;
+        let completion_key = gglib_core::download::CompletionKey::UrlFile {
+            url: peer.url.clone(),
+            filename: peer.file_name.clone(),
+        };
+        let shard_files = vec![(peer.file_name.clone(), Some(peer.size))];
+
+        // Record the file and its source first: the runner may be handed the
+        // download as soon as it is queued.
+        let key = id.to_string();
+        let previous_files = self.file_entries_map.lock().await.insert(
+            key.clone(),
+            vec![ResolvedFile {
+                path: peer.file_name.clone(),
+                size: Some(peer.size),
+                oid: peer.sha256.clone(),
+            }],
+        );
+        let previous_peer = self.peer_sources.lock().await.insert(key.clone(), peer);
+        let previous_override = self.record_override(&key, rendered).await;
+
+        let (position, layout) = match self
+            .queue
+            .queue(id.clone(), completion_key, shard_files)
+            .await
+        {
+            Ok(queued) => queued,
+            Err(e) => {
+                restore(&self.file_entries_map, key.clone(), previous_files).await;
+                restore(&self.peer_sources, key.clone(), previous_peer).await;
+                restore(&self.destination_overrides, key, previous_override).await;
+                return Err(e);
             }
+        };
+        self.seed_group_progress(layout).await;
+
+        tracing::info!(id = %id, position = position, "Peer download queued");
+
+        self.emit_queue_snapshot().await;
+
+        Ok(id)
+    }
+
+    /// Record (or, for `None`, clear) the destination override of the
+    /// download `key`, returning the one it replaces.
+    async fn record_override(
+        &self,
+        key: &str,
+        rendered: Option<RenderedOverride>,
+    ) -> Option<RenderedOverride> {
+        let mut overrides = self.destination_overrides.lock().await;
+        match rendered {
+            Some(rendered) => overrides.insert(key.to_string(), rendered),
+            None => overrides.remove(key),
         }
     }
 
-    /// Emit a `DownloadStarted` event, including shard info when available.
+    /// Emit a `DownloadStarted` event, including shard info when available,
+    /// followed by the `Resolving` phase the worker starts in.
     fn emit_started_event(&self, item: &QueuedItem) {
         if let Some(shard) = &item.shard_info {
             self.event_emitter.emit(DownloadEvent::started_shard(
@@ -529,6 +832,13 @@ impl DownloadManagerImpl {
                 total_shards: None,
             });
         }
+        self.event_emitter.emit(DownloadEvent::phase_progress(
+            item.id.to_string(),
+            DownloadPhase::Resolving,
+            item.shard_info.as_ref().map(shard_position),
+            0,
+            0,
+        ));
     }
 
     /// Validate a cached GGUF file and delete it if corrupt.
@@ -566,6 +876,18 @@ impl DownloadManagerImpl {
         }
     }
 
+    /// Shards of `id`'s group whose final file exists and passes
+    /// [`validate_cached_gguf`], with their paths.
+    async fn shards_on_disk(&self, id: &DownloadId, shards: &[ShardInfo]) -> Vec<(u32, PathBuf)> {
+        let models_dir = &self.config.models_directory;
+        let files = shards.iter().map(|s| s.filename.clone()).collect();
+        let mut destination = DownloadDestination::plan(models_dir, id, files);
+        if let Some(rendered) = self.destination_overrides.lock().await.get(&id.to_string()) {
+            destination = destination.with_override(models_dir, rendered);
+        }
+        intact_shards(&destination, shards)
+    }
+
     /// Emit synthetic 100% progress when a cache hit produced no callbacks.
     ///
     /// When `hf_hub_download(force_download=False)` finds a cached file it
@@ -601,52 +923,6 @@ impl DownloadManagerImpl {
         );
     }
 
-    /// Get the next job from the queue.
-    ///
-    /// Returns `None` if the queue is empty.
-    /// Lock order: queue → active.
-    async fn next_job(
-        &self,
-    ) -> Option<(
-        LeaseId,
-        QueuedItem,
-        CancellationToken,
-        watch::Sender<ProgressUpdate>,
-    )> {
-        // Acquire queue lock first, then active lock
-        let item = {
-            let mut queue = self.queue.write().await;
-            queue.dequeue()?
-        };
-
-        // Mint a new lease
-        let lease = LeaseId(self.lease_counter.fetch_add(1, Ordering::Relaxed));
-
-        // Create cancellation token and progress channel
-        let cancel = CancellationToken::new();
-        let (progress_tx, _) = watch::channel(ProgressUpdate::default());
-
-        // Insert into active map
-        {
-            let mut active = self.active.lock().await;
-            active.insert(
-                item.id.clone(),
-                ActiveJob {
-                    lease,
-                    cancel: cancel.clone(),
-                    progress_tx: progress_tx.clone(),
-                    shard_info: item.shard_info.clone(),
-                    group_id: item.group_id.as_ref().map(std::string::ToString::to_string),
-                },
-            );
-        }
-
-        // Emit queue snapshot (item now active)
-        self.emit_queue_snapshot().await;
-
-        Some((lease, item, cancel, progress_tx))
-    }
-
     /// Finalize a job after it completes or fails.
     ///
     /// Verifies the lease first (to prevent double-finalization), then runs
@@ -658,8 +934,6 @@ impl DownloadManagerImpl {
     /// `active_count == 0`. Removing the item before registration completes
     /// would allow the CLI to exit mid-insert, dropping the tokio runtime and
     /// silently losing the DB row.
-    ///
-    /// Lock order: active → queue.
     async fn finalize_job(
         &self,
         item: &QueuedItem,
@@ -668,7 +942,7 @@ impl DownloadManagerImpl {
     ) {
         // Step 1 — verify lease (guards against stale/duplicate finalization)
         // without yet removing the item from the active map.
-        if !self.verify_lease(&item.id, lease).await {
+        if !self.queue.holds_lease(item.id.clone(), lease).await {
             tracing::debug!(id = %item.id, "Ignoring stale finalize (lease mismatch)");
             return;
         }
@@ -678,7 +952,8 @@ impl DownloadManagerImpl {
         self.handle_job_result(item, result).await;
 
         // Step 3 — now safe to remove from active map and notify watchers.
-        self.remove_from_active(&item.id).await;
+        // This also lets the actor hand the runner its next job.
+        self.queue.finish(item.id.clone(), lease).await;
 
         // A sharded download keeps its estimator until the whole group is
         // done; the drain transition sweeps those. An unsharded one is finished
@@ -690,17 +965,6 @@ impl DownloadManagerImpl {
         self.emit_queue_snapshot().await;
     }
 
-    /// Check that the stored lease matches `lease` without removing the entry.
-    async fn verify_lease(&self, id: &DownloadId, lease: LeaseId) -> bool {
-        let active = self.active.lock().await;
-        active.get(id).is_some_and(|job| job.lease == lease)
-    }
-
-    /// Remove an entry from the active map unconditionally.
-    async fn remove_from_active(&self, id: &DownloadId) {
-        self.active.lock().await.remove(id);
-    }
-
     /// Handle the result of a completed job.
     async fn handle_job_result(
         &self,
@@ -757,6 +1021,11 @@ impl DownloadManagerImpl {
             file_entries,
         };
 
+        self.group_progress
+            .lock()
+            .await
+            .complete_shard(group_id, shard_info);
+
         let group_complete = {
             let mut tracker = self.shard_tracker.lock().await;
             tracker.on_shard_done(
@@ -775,6 +1044,7 @@ impl DownloadManagerImpl {
                 shard_count = complete.ordered_paths.len(),
                 "All shards downloaded, registering model"
             );
+            self.group_progress.lock().await.remove(group_id);
             // Record completion ONCE per group (not per shard)
             self.record_completion_in_run(item, CompletionKind::Downloaded)
                 .await;
@@ -826,7 +1096,7 @@ impl DownloadManagerImpl {
 
         // Clean up shard tracker if this was part of a group
         if let Some(group_id) = &item.group_id {
-            self.shard_tracker.lock().await.on_group_failed(group_id);
+            self.shard_tracker.lock().await.on_group_cancelled(group_id);
         }
 
         // Record cancellation
@@ -842,20 +1112,54 @@ impl DownloadManagerImpl {
     async fn handle_failure(&self, item: &QueuedItem, e: DownloadError) {
         tracing::warn!(id = %item.id, error = %e, "Download failed");
 
-        // Clean up shard tracker if this was part of a group
-        if let Some(group_id) = &item.group_id {
-            self.shard_tracker.lock().await.on_group_failed(group_id);
-        }
+        // The group stops being tracked, but keeps its completed shards for
+        // a retry.
+        let completed_shards = match &item.group_id {
+            Some(group_id) => {
+                self.group_progress.lock().await.remove(group_id);
+                self.shard_tracker.lock().await.on_group_failed(group_id)
+            }
+            None => Vec::new(),
+        };
 
         // Record failure
         self.record_completion_in_run(item, CompletionKind::Failed)
             .await;
 
-        let queued_item = QueuedItem::new(item.id.clone(), item.completion_key.clone());
+        // The queue drops the group's remaining shards with it.
         self.queue
-            .write()
-            .await
-            .mark_failed(queued_item, e.to_string());
+            .mark_failed(item.clone(), e.to_string(), completed_shards)
+            .await;
+    }
+
+    /// Message for the `DownloadCompleted` event of a registered model.
+    ///
+    /// A model the installed llama.cpp can't load only fails at launch, so
+    /// the architecture warning rides along while the user is looking at
+    /// the download.
+    fn completion_message(
+        &self,
+        model: &gglib_core::Model,
+        completed: &gglib_core::ports::CompletedDownload,
+    ) -> String {
+        let mut message = format!(
+            "Downloaded {} to {}",
+            if completed.is_sharded {
+                format!("{} shards", completed.all_paths.len())
+            } else {
+                "model".to_string()
+            },
+            completed.primary_path.display()
+        );
+        let warning = self.arch_support.as_ref().and_then(|arch_support| {
+            let architecture = model.architecture.as_deref()?;
+            arch_support.check(architecture).warning(architecture)
+        });
+        if let Some(warning) = warning {
+            tracing::warn!(model_id = model.id, "{warning}");
+            message = format!("{message}. Warning: {warning}");
+        }
+        message
     }
 
     /// Register a completed model (all shards downloaded).
@@ -884,6 +1188,13 @@ impl DownloadManagerImpl {
                 id: event_id.clone(),
                 status: gglib_core::download::DownloadStatus::Finalizing,
             });
+        self.event_emitter.emit(DownloadEvent::phase_progress(
+            event_id.clone(),
+            DownloadPhase::Registering,
+            None,
+            0,
+            0,
+        ));
 
         // Fetch HF model tags (nice-to-have metadata). Bounded by a strict
         // 5-second timeout: a stalled/slow connection must never delay the
@@ -891,11 +1202,11 @@ impl DownloadManagerImpl {
         // an empty tag list — a failed tag fetch is never a hard failure.
         let hf_tags = match tokio::time::timeout(
             std::time::Duration::from_secs(5),
-            self.hf_client.get_model_info(&complete.metadata.repo_id),
+            self.fetch_model_tags(&complete.metadata.repo_id),
         )
         .await
         {
-            Ok(Ok(info)) => info.tags,
+            Ok(Ok(tags)) => tags,
             Ok(Err(e)) => {
                 tracing::warn!(
                     error = %e,
@@ -950,15 +1261,7 @@ impl DownloadManagerImpl {
                 // Emit completion event
                 self.event_emitter.emit(DownloadEvent::DownloadCompleted {
                     id: event_id,
-                    message: Some(format!(
-                        "Downloaded {} to {}",
-                        if completed.is_sharded {
-                            format!("{} shards", complete.ordered_paths.len())
-                        } else {
-                            "model".to_string()
-                        },
-                        primary_path.display()
-                    )),
+                    message: Some(self.completion_message(&model, &completed)),
                 });
             }
             Err(e) => {
@@ -983,8 +1286,7 @@ impl DownloadManagerImpl {
     /// manager's event emitter.
     fn spawn_progress_bridge(
         &self,
-        id: &DownloadId,
-        shard_info: Option<&ShardInfo>,
+        item: &QueuedItem,
         rx: watch::Receiver<ProgressUpdate>,
         cancel: CancellationToken,
         estimator: Arc<Mutex<RateEstimator>>,
@@ -992,9 +1294,14 @@ impl DownloadManagerImpl {
     ) -> tokio::task::JoinHandle<()> {
         let bridge = ProgressBridge {
             event_emitter: Arc::clone(&self.event_emitter),
-            id: id.to_string(),
-            shard_info: shard_info.cloned(),
+            id: item.id.to_string(),
+            shard_info: item.shard_info.clone(),
+            group: item
+                .group_id
+                .clone()
+                .map(|group_id| (group_id, Arc::clone(&self.group_progress))),
             estimator,
+            clock: Arc::clone(&self.clock),
             cancel,
             finished,
         };
@@ -1002,6 +1309,16 @@ impl DownloadManagerImpl {
     }
 
     /// Extract files from a queued item.
+    /// LFS OID of the item's file, from the resolved file entries.
+    async fn expected_sha256(&self, item: &QueuedItem) -> Option<String> {
+        let filename = &item.shard_info.as_ref()?.filename;
+        let map = self.file_entries_map.lock().await;
+        map.get(&item.id.to_string())?
+            .iter()
+            .find(|file| &file.path == filename)
+            .and_then(|file| file.oid.clone())
+    }
+
     fn extract_files(item: &QueuedItem) -> Vec<String> {
         item.shard_info.as_ref().map_or_else(
             || {
@@ -1012,11 +1329,6 @@ impl DownloadManagerImpl {
         )
     }
 
-    /// Check if there's an active download.
-    async fn has_active(&self) -> bool {
-        !self.active.lock().await.is_empty()
-    }
-
     /// Emit a queue snapshot event.
     async fn emit_queue_snapshot(&self) {
         let Ok(snapshot) = self.get_queue_snapshot().await else {
@@ -1062,6 +1374,7 @@ impl DownloadManagerImpl {
             // Nothing is in flight, so no estimator is in use. This is the
             // sweep for shard groups, which outlive their individual jobs.
             self.rate_estimators.lock().await.clear();
+            self.group_progress.lock().await.clear();
         }
 
         *prev = is_drained;
@@ -1069,7 +1382,7 @@ impl DownloadManagerImpl {
 
     /// Start a new queue run when transitioning from drained to busy.
     async fn start_new_queue_run(&self) {
-        *self.current_run.lock().await = Some(QueueRunState::new());
+        *self.current_run.lock().await = Some(QueueRunState::new(self.clock.unix_millis()));
         tracing::info!(target: "gglib.download", "Queue run STARTED");
     }
 
@@ -1083,7 +1396,7 @@ impl DownloadManagerImpl {
                     unique_downloaded = run.completions.len(),
                     "Queue run COMPLETED - emitting summary"
                 );
-                self.emit_queue_run_complete(run);
+                self.emit_queue_run_complete(run).await;
             }
             None => {
                 tracing::warn!(target: "gglib.download", "Queue drained but no run state found");
@@ -1104,6 +1417,11 @@ impl DownloadManagerImpl {
                 error: None,
                 group_id: item.group_id.clone(),
                 shard_info: item.shard_info.clone(),
+                downloaded_bytes: item.downloaded_bytes,
+                total_bytes: item.total_bytes,
+                speed_bps: item.speed_bps,
+                eta_seconds: item.eta_seconds,
+                elapsed_seconds: item.elapsed_seconds,
             })
             .collect();
 
@@ -1120,11 +1438,10 @@ impl DownloadManagerImpl {
         });
     }
 
-    /// Emit queue run complete event with summary.
-    fn emit_queue_run_complete(&self, run: QueueRunState) {
-        use gglib_core::download::QueueRunSummary;
-
-        let completed_at_ms = Self::get_current_timestamp_ms();
+    /// Record the run in the download history, then emit the queue run
+    /// complete event with its summary.
+    async fn emit_queue_run_complete(&self, run: QueueRunState) {
+        let completed_at_ms = self.clock.unix_millis();
         let mut items = Self::build_completion_details(run.completions);
         items.sort_by_key(|b| std::cmp::Reverse(b.last_completed_at_ms));
 
@@ -1133,10 +1450,7 @@ impl DownloadManagerImpl {
         let (unique_downloaded, unique_failed, unique_cancelled) =
             Self::calculate_unique_counts(&items);
 
-        let truncated = items.len() > 20;
-        items.truncate(20);
-
-        let summary = QueueRunSummary {
+        let mut summary = QueueRunSummary {
             run_id: run.run_id,
             started_at_ms: run.started_at_ms,
             completed_at_ms,
@@ -1146,10 +1460,24 @@ impl DownloadManagerImpl {
             unique_models_downloaded: unique_downloaded,
             unique_models_failed: unique_failed,
             unique_models_cancelled: unique_cancelled,
-            truncated,
+            truncated: false,
             items,
         };
 
+        // History keeps every record; only the event payload is capped.
+        if !summary.items.is_empty()
+            && let Err(e) = self.download_repo.record_run(&summary).await
+        {
+            tracing::warn!(
+                target: "gglib.download",
+                run_id = %summary.run_id,
+                error = %e,
+                "Failed to record queue run in download history"
+            );
+        }
+        summary.truncated = summary.items.len() > 20;
+        summary.items.truncate(20);
+
         tracing::info!(
             run_id = %summary.run_id,
             item_count = summary.items.len(),
@@ -1163,17 +1491,6 @@ impl DownloadManagerImpl {
             .emit(DownloadEvent::QueueRunComplete { summary });
     }
 
-    fn get_current_timestamp_ms() -> u64 {
-        use std::time::SystemTime;
-
-        SystemTime::now()
-            .duration_since(SystemTime::UNIX_EPOCH)
-            .unwrap_or_default()
-            .as_millis()
-            .try_into()
-            .unwrap_or(0)
-    }
-
     fn build_completion_details(
         completions: indexmap::IndexMap<gglib_core::download::CompletionKey, CompletionAggregate>,
     ) -> Vec<gglib_core::download::CompletionDetail> {
@@ -1227,14 +1544,28 @@ impl DownloadManagerImpl {
     }
 }
 
+/// Put back what a map held under `key` before a download that failed to
+/// queue overwrote it.
+async fn restore<V: Send>(map: &RankedMutex<HashMap<String, V>>, key: String, previous: Option<V>) {
+    let mut map = map.lock().await;
+    match previous {
+        Some(value) => map.insert(key, value),
+        None => map.remove(&key),
+    };
+}
+
 /// Everything the progress bridge needs, independent of the manager.
 struct ProgressBridge {
     event_emitter: Arc<dyn DownloadEventEmitterPort>,
     /// Canonical download ID, as it appears on emitted events.
     id: String,
     shard_info: Option<ShardInfo>,
+    /// The shard group and the tracker summing its byte progress.
+    group: Option<(ShardGroupId, Arc<Mutex<GroupProgressTracker>>)>,
     /// Shared with the rest of the shard group.
     estimator: Arc<Mutex<RateEstimator>>,
+    /// Timestamps the samples fed to `estimator`.
+    clock: Arc<dyn Clock>,
     /// User cancellation: stop without emitting further progress.
     cancel: CancellationToken,
     /// Worker finished: emit a final progress event, then stop.
@@ -1253,6 +1584,12 @@ struct ProgressBridge {
 /// shard boundaries. Each shard's byte counter restarts at zero; the estimator
 /// re-baselines on that without disturbing its running average.
 ///
+/// Samples in any phase other than `Transferring` — hashing a staged file
+/// once its bytes are on disk — are reported as
+/// [`DownloadEvent::PhaseProgress`] instead and kept away from the estimator,
+/// which measures the transfer alone. A `PhaseProgress` also marks the moment
+/// the transfer begins.
+///
 /// Termination is driven by `finished`, never by the `watch` senders dropping.
 /// The active-jobs map holds a `progress_tx` clone that is released only during
 /// finalization, which the run loop performs *after* joining this task — so
@@ -1262,7 +1599,9 @@ async fn run_progress_bridge(bridge: ProgressBridge, rx: watch::Receiver<Progres
         event_emitter,
         id,
         shard_info,
+        group,
         estimator,
+        clock,
         cancel,
         finished,
     } = bridge;
@@ -1271,6 +1610,7 @@ async fn run_progress_bridge(bridge: ProgressBridge, rx: watch::Receiver<Progres
     tick.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
 
     let mut last_emitted = ProgressUpdate::default();
+    let mut last_phase = None;
 
     loop {
         tokio::select! {
@@ -1285,17 +1625,33 @@ async fn run_progress_bridge(bridge: ProgressBridge, rx: watch::Receiver<Progres
                 // Emit the last sample so the bar lands on its true final
                 // position, then exit.
                 let final_progress = rx.borrow().clone();
-                if final_progress.seq > last_emitted.seq {
-                    let (speed, eta) =
-                        sample(&estimator, shard_info.as_ref(), &final_progress).await;
+                if final_progress.seq <= last_emitted.seq {
+                    break;
+                }
+                if final_progress.phase == DownloadPhase::Transferring {
+                    let aggregate =
+                        aggregate_for(shard_info.as_ref(), group.as_ref(), &final_progress).await;
+                    let (speed, eta) = sample(&estimator, aggregate, clock.now()).await;
+                    if last_phase != Some(DownloadPhase::Transferring) {
+                        emit_phase(&event_emitter, &id, shard_info.as_ref(), DownloadPhase::Transferring, aggregate);
+                    }
                     emit_progress(
                         &event_emitter,
                         &id,
                         shard_info.as_ref(),
                         &final_progress,
+                        aggregate,
                         speed,
                         eta,
                     );
+                } else {
+                    emit_phase(
+                        &event_emitter,
+                        &id,
+                        shard_info.as_ref(),
+                        final_progress.phase,
+                        (final_progress.downloaded, final_progress.total),
+                    );
                 }
                 break;
             }
@@ -1303,17 +1659,38 @@ async fn run_progress_bridge(bridge: ProgressBridge, rx: watch::Receiver<Progres
             _ = tick.tick() => {
                 let current = rx.borrow().clone();
 
+                if current.phase != DownloadPhase::Transferring {
+                    if current.seq > last_emitted.seq {
+                        emit_phase(
+                            &event_emitter,
+                            &id,
+                            shard_info.as_ref(),
+                            current.phase,
+                            (current.downloaded, current.total),
+                        );
+                        last_phase = Some(current.phase);
+                        last_emitted = current;
+                    }
+                    continue;
+                }
+
                 // Sample unconditionally — a tick carrying no new bytes is a
                 // real observation of "nothing arrived".
-                let (speed, eta) = sample(&estimator, shard_info.as_ref(), &current).await;
+                let aggregate = aggregate_for(shard_info.as_ref(), group.as_ref(), &current).await;
+                let (speed, eta) = sample(&estimator, aggregate, clock.now()).await;
 
                 // Nothing has been reported yet: no bar to update.
                 if current.seq > 0 {
+                    if last_phase != Some(DownloadPhase::Transferring) {
+                        emit_phase(&event_emitter, &id, shard_info.as_ref(), DownloadPhase::Transferring, aggregate);
+                        last_phase = Some(DownloadPhase::Transferring);
+                    }
                     emit_progress(
                         &event_emitter,
                         &id,
                         shard_info.as_ref(),
                         &current,
+                        aggregate,
                         speed,
                         eta,
                     );
@@ -1331,19 +1708,36 @@ async fn run_progress_bridge(bridge: ProgressBridge, rx: watch::Receiver<Progres
 /// would otherwise look like the download going backwards.
 async fn sample(
     estimator: &Arc<Mutex<RateEstimator>>,
-    shard_info: Option<&ShardInfo>,
-    progress: &ProgressUpdate,
+    (downloaded, total): (u64, u64),
+    now: std::time::Instant,
 ) -> (Option<f64>, Option<f64>) {
-    let (downloaded, total) = shard_info.map_or((progress.downloaded, progress.total), |shard| {
-        aggregate_progress(shard, progress.downloaded, progress.total)
-    });
-
     let mut estimator = estimator.lock().await;
-    estimator.record(downloaded, total, std::time::Instant::now());
+    estimator.record(downloaded, total, now);
     (estimator.rate_bps(), estimator.eta_seconds())
 }
 
-/// Aggregate progress across a whole shard group.
+/// `(downloaded, total)` for the whole transfer a progress sample belongs to.
+///
+/// A sharded download reports through its group's [`GroupProgressTracker`],
+/// which sums the actual shard sizes; anything else reports its own bytes.
+async fn aggregate_for(
+    shard_info: Option<&ShardInfo>,
+    group: Option<&(ShardGroupId, Arc<Mutex<GroupProgressTracker>>)>,
+    progress: &ProgressUpdate,
+) -> (u64, u64) {
+    match (shard_info, group) {
+        (Some(shard), Some((group_id, tracker))) => {
+            tracker
+                .lock()
+                .await
+                .observe(group_id, shard, progress.downloaded, progress.total)
+        }
+        (Some(shard), None) => aggregate_progress(shard, progress.downloaded, progress.total),
+        (None, _) => (progress.downloaded, progress.total),
+    }
+}
+
+/// Aggregate progress for a shard without a group tracker.
 ///
 /// Prefers the exact byte layout recorded on [`ShardInfo`] when `HuggingFace`
 /// supplied a size for every shard. Falls back to assuming equal-sized shards,
@@ -1377,13 +1771,11 @@ fn emit_progress(
     id: &str,
     shard_info: Option<&ShardInfo>,
     progress: &ProgressUpdate,
+    (aggregate_downloaded, aggregate_total): (u64, u64),
     speed_bps: Option<f64>,
     eta_seconds: Option<f64>,
 ) {
     if let Some(shard) = shard_info {
-        let (aggregate_downloaded, aggregate_total) =
-            aggregate_progress(shard, progress.downloaded, progress.total);
-
         emitter.emit(DownloadEvent::shard_progress(
             id,
             shard.shard_index,
@@ -1408,6 +1800,28 @@ fn emit_progress(
     }
 }
 
+/// Emit a [`DownloadEvent::PhaseProgress`] for `phase`.
+fn emit_phase(
+    emitter: &Arc<dyn DownloadEventEmitterPort>,
+    id: &str,
+    shard_info: Option<&ShardInfo>,
+    phase: DownloadPhase,
+    (processed, total): (u64, u64),
+) {
+    emitter.emit(DownloadEvent::phase_progress(
+        id,
+        phase,
+        shard_info.map(shard_position),
+        processed,
+        total,
+    ));
+}
+
+/// `(shard_index, total_shards)` of a shard.
+const fn shard_position(shard: &ShardInfo) -> (u32, u32) {
+    (shard.shard_index, shard.total_shards)
+}
+
 /// GGUF magic number: "GGUF" in little-endian.
 const GGUF_MAGIC: [u8; 4] = [0x47, 0x47, 0x55, 0x46];
 
@@ -1449,6 +1863,31 @@ fn validate_cached_gguf(path: &std::path::Path, expected_size: Option<u64>) -> R
     Ok(())
 }
 
+/// The `shards` of `destination` whose final file is a valid GGUF of the
+/// expected size, with their paths. A file that fails the check is left for
+/// the download to replace.
+fn intact_shards(destination: &DownloadDestination, shards: &[ShardInfo]) -> Vec<(u32, PathBuf)> {
+    shards
+        .iter()
+        .zip(destination.all_paths())
+        .filter(|(shard, path)| {
+            if !path.exists() {
+                return false;
+            }
+            validate_cached_gguf(path, shard.file_size)
+                .inspect_err(|reason| {
+                    tracing::warn!(
+                        path = %path.display(),
+                        reason,
+                        "Shard on disk is corrupt, downloading it again"
+                    );
+                })
+                .is_ok()
+        })
+        .map(|(shard, path)| (shard.shard_index, path))
+        .collect()
+}
+
 // =============================================================================
 // DownloadManagerPort implementation
 // =============================================================================
@@ -1456,17 +1895,26 @@ fn validate_cached_gguf(path: &std::path::Path, expected_size: Option<u64>) -> R
 #[async_trait]
 impl DownloadManagerPort for DownloadManagerImpl {
     async fn queue_download(&self, request: DownloadRequest) -> Result<DownloadId, DownloadError> {
-        let id = DownloadId::new(&request.repo_id, Some(request.quantization.to_string()));
+        if let Some(peer) = request.peer {
+            let id = DownloadId::new(&request.repo_id, Some(request.quantization.to_string()));
+            return self
+                .queue_peer_download(id, peer, request.destination)
+                .await;
+        }
+
+        // Downloads are keyed by the canonical reference, so `hf:org/repo`
+        // and `org/repo` are the same download.
+        let (route, bare_repo) = self.route(&request.repo_id)?;
+        let repo_id = route.client.hub().qualify(bare_repo);
+        let id = DownloadId::new(&repo_id, Some(request.quantization.to_string()));
 
         // Resolve files (outside lock)
-        let resolution = self
+        let resolution = route
             .resolver
-            .resolve(&request.repo_id, request.quantization)
+            .resolve(bare_repo, request.quantization)
             .await?;
 
-        let has_active = self.has_active().await;
-
-        // Build shard files list (outside lock)
+        // Build shard files list
         let shard_files: Vec<_> = resolution
             .files
             .iter()
@@ -1486,7 +1934,7 @@ impl DownloadManagerPort for DownloadManagerImpl {
             .unwrap_or(first_path);
         let filename_canon = base_shard_filename(filename);
         let completion_key = gglib_core::download::CompletionKey::HfFile {
-            repo_id: request.repo_id.clone(),
+            repo_id,
             revision: request
                 .revision
                 .clone()
@@ -1495,11 +1943,30 @@ impl DownloadManagerPort for DownloadManagerImpl {
             quantization: Some(request.quantization.to_string()),
         };
 
-        // Minimal lock scope: mutate queue and get snapshot
-        let position = {
-            let mut queue = self.queue.write().await;
-            queue.queue_sharded(&id, &completion_key, shard_files, has_active)?
+        // Rendered now so a bad override is refused before anything is
+        // queued, and recorded first: the runner may be handed the download
+        // as soon as it is queued.
+        let rendered = request
+            .destination
+            .map(|d| {
+                RenderedOverride::render(&d, &id, resolution.files.iter().map(|f| f.path.as_str()))
+            })
+            .transpose()?;
+        let key = id.to_string();
+        let previous_override = self.record_override(&key, rendered).await;
+
+        let (position, layout) = match self
+            .queue
+            .queue(id.clone(), completion_key, shard_files)
+            .await
+        {
+            Ok(queued) => queued,
+            Err(e) => {
+                restore(&self.destination_overrides, key, previous_override).await;
+                return Err(e);
+            }
         };
+        self.seed_group_progress(layout).await;
 
         tracing::info!(
             id = %id,
@@ -1509,8 +1976,6 @@ impl DownloadManagerPort for DownloadManagerImpl {
             "Download queued"
         );
 
-        // Notify runner and emit snapshot (outside lock)
-        self.queue_notify.notify_one();
         self.emit_queue_snapshot().await;
 
         Ok(id)
@@ -1535,64 +2000,67 @@ impl DownloadManagerPort for DownloadManagerImpl {
         Ok((1, result.queued as usize))
     }
 
+    async fn queue_collection(
+        self: Arc<Self>,
+        slug: String,
+        quantization: Option<String>,
+    ) -> Result<CollectionQueueResult, DownloadError> {
+        let result = self
+            .queue_collection_repos(&slug, quantization.as_deref())
+            .await?;
+        if !result.queued.is_empty() {
+            self.ensure_runner();
+        }
+        Ok(result)
+    }
+
     async fn get_queue_snapshot(&self) -> Result<QueueSnapshot, DownloadError> {
-        // Build current item DTO if there's an active download (short lock scope)
-        let current_dto = {
-            let active = self.active.lock().await;
-            active.iter().next().map(|(id, job)| {
-                let mut dto = gglib_core::download::QueuedDownload::new(
-                    id.to_string(),
-                    id.model_id(),
-                    id.to_string(),
-                    1,
-                    0,
-                )
+        // Build current item DTO if there's an active download
+        let current = self.queue.active().await.map(|(id, job)| {
+            let mut dto = QueuedDownload::new(id.to_string(), id.model_id(), id.to_string(), 1, 0)
                 .with_status(gglib_core::download::DownloadStatus::Downloading);
 
-                // Preserve shard info if this is a sharded download
-                if let Some(shard) = &job.shard_info {
-                    if let Some(group) = &job.group_id {
-                        dto = dto.with_shard_info(group.clone(), shard.clone());
-                    }
+            // Preserve shard info if this is a sharded download
+            if let Some(shard) = &job.shard_info {
+                if let Some(group) = &job.group_id {
+                    dto = dto.with_shard_info(group.clone(), shard.clone());
                 }
+            }
 
-                dto
-            })
+            (dto, job.group_id.clone(), job.progress_tx.borrow().clone())
+        });
+
+        let current_dto = match current {
+            Some((mut dto, group_id, progress)) => {
+                self.fill_live_progress(&mut dto, group_id.as_deref(), &progress)
+                    .await;
+                Some(dto)
+            }
+            None => None,
         };
 
-        let queue = self.queue.read().await;
-        Ok(queue.snapshot(current_dto))
+        Ok(self.queue.snapshot(current_dto).await)
     }
 
     async fn cancel_download(&self, id: &DownloadId) -> Result<(), DownloadError> {
-        // Check if active, cancel via token
-        {
-            let active = self.active.lock().await;
-            if let Some(job) = active.get(id) {
-                job.cancel.cancel();
+        // An active download is cancelled via its token; the runner
+        // finalizes it. Anything else is removed from the queue.
+        match self.queue.cancel(id.clone()).await? {
+            Cancelled::Active => {
                 tracing::info!(id = %id, "Cancelled active download");
-                return Ok(());
+            }
+            Cancelled::Removed => {
+                tracing::info!(id = %id, "Removed download from queue");
+                self.emit_queue_snapshot().await;
             }
         }
-
-        // Otherwise remove from queue
-        self.queue.write().await.remove(id)?;
-        tracing::info!(id = %id, "Removed download from queue");
-        self.emit_queue_snapshot().await;
         Ok(())
     }
 
     async fn cancel_all(&self) -> Result<(), DownloadError> {
-        // Cancel all active downloads
-        {
-            let active = self.active.lock().await;
-            for job in active.values() {
-                job.cancel.cancel();
-            }
-        }
-
-        // Clear queue (drops everything still pending).
-        self.queue.write().await.clear();
+        // Cancel all active downloads and clear the queue (drops everything
+        // still pending).
+        self.queue.cancel_all().await;
         self.emit_queue_snapshot().await;
 
         // Bounded drain: wait up to 5s for active downloads to actually
@@ -1600,20 +2068,13 @@ impl DownloadManagerPort for DownloadManagerImpl {
         // are still cleaning up. Without this the CLI would exit with
         // partially-written files and no DB row — exactly the symptom
         // tracked in #466.
-        let drain_deadline = tokio::time::Instant::now() + std::time::Duration::from_secs(5);
-        let mut poll = tokio::time::interval(std::time::Duration::from_millis(50));
-        poll.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
-        loop {
-            poll.tick().await;
-            if self.active.lock().await.is_empty() {
-                break;
-            }
-            if tokio::time::Instant::now() >= drain_deadline {
-                tracing::warn!(
-                    "cancel_all drain deadline exceeded; returning while jobs still draining"
-                );
-                break;
-            }
+        if tokio::time::timeout(Duration::from_secs(5), self.queue.idle())
+            .await
+            .is_err()
+        {
+            tracing::warn!(
+                "cancel_all drain deadline exceeded; returning while jobs still draining"
+            );
         }
 
         tracing::info!("Cancelled all downloads");
@@ -1621,29 +2082,21 @@ impl DownloadManagerPort for DownloadManagerImpl {
     }
 
     async fn has_download(&self, id: &DownloadId) -> Result<bool, DownloadError> {
-        // Check active
-        if self.active.lock().await.contains_key(id) {
-            return Ok(true);
-        }
-
-        // Check queue
-        let queue = self.queue.read().await;
-        Ok(queue.is_queued(id) || queue.is_failed(id))
+        Ok(self.queue.contains(id.clone()).await)
     }
 
     async fn active_count(&self) -> Result<u32, DownloadError> {
         #[allow(clippy::cast_possible_truncation)]
-        Ok(self.active.lock().await.len() as u32)
+        Ok(self.queue.status().await.active as u32)
     }
 
     async fn pending_count(&self) -> Result<u32, DownloadError> {
-        let queue = self.queue.read().await;
         #[allow(clippy::cast_possible_truncation)]
-        Ok(queue.pending_len() as u32)
+        Ok(self.queue.status().await.pending as u32)
     }
 
     async fn remove_from_queue(&self, id: &DownloadId) -> Result<(), DownloadError> {
-        self.queue.write().await.remove(id)?;
+        self.queue.remove(id.clone()).await?;
         tracing::info!(id = %id, "Removed download from queue");
         Ok(())
     }
@@ -1653,12 +2106,7 @@ impl DownloadManagerPort for DownloadManagerImpl {
         id: &DownloadId,
         new_position: u32,
     ) -> Result<u32, DownloadError> {
-        let has_active = self.has_active().await;
-        let actual_position = self
-            .queue
-            .write()
-            .await
-            .reorder(id, new_position, has_active)?;
+        let actual_position = self.queue.reorder(id.clone(), new_position).await?;
         tracing::info!(id = %id, position = actual_position, "Reordered download");
         self.emit_queue_snapshot().await;
         Ok(actual_position)
@@ -1668,36 +2116,79 @@ impl DownloadManagerPort for DownloadManagerImpl {
         use crate::queue::ShardGroupId;
 
         let shard_group_id = ShardGroupId::new(group_id);
-        let removed = self.queue.write().await.remove_group(&shard_group_id);
+        let removed = self.queue.remove_group(shard_group_id.clone()).await;
+        self.shard_tracker
+            .lock()
+            .await
+            .on_group_cancelled(&shard_group_id);
+        self.group_progress.lock().await.remove(&shard_group_id);
         self.emit_queue_snapshot().await;
         tracing::info!(group_id = %group_id, removed = removed, "Cancelled shard group");
         Ok(())
     }
 
+    async fn get_group_progress(&self, group_id: &str) -> Result<GroupProgress, DownloadError> {
+        self.group_progress
+            .lock()
+            .await
+            .snapshot(&ShardGroupId::new(group_id))
+            .ok_or_else(|| DownloadError::not_in_queue(group_id))
+    }
+
     async fn retry(&self, id: &DownloadId) -> Result<u32, DownloadError> {
-        let has_active = self.has_active().await;
-        let position = self.queue.write().await.retry_failed(id, has_active)?;
+        // Shards whose files are already in place and intact are not
+        // downloaded again, whether this session completed them or not.
+        let on_disk = match self.queue.failed_layout(id.clone()).await {
+            Some((_, shards)) => self.shards_on_disk(id, &shards).await,
+            None => Vec::new(),
+        };
+        let present = on_disk.iter().map(|(index, _)| *index).collect();
+        let (position, layout) = self.queue.retry(id.clone(), present).await?;
+        if let Some((group_id, shards)) = layout {
+            let total = shards.first().map_or(0, |s| s.total_shards);
+            let completed = self
+                .shard_tracker
+                .lock()
+                .await
+                .resume_group(&group_id, total, on_disk);
+            let mut progress = self.group_progress.lock().await;
+            progress.seed(&group_id, &shards);
+            for shard in shards.iter().filter(|s| completed.contains(&s.shard_index)) {
+                progress.complete_shard(&group_id, shard);
+            }
+        }
         tracing::info!(id = %id, position = position, "Retried failed download");
         self.emit_queue_snapshot().await;
-        self.queue_notify.notify_one();
         Ok(position)
     }
 
     async fn clear_failed(&self) -> Result<(), DownloadError> {
-        self.queue.write().await.clear_failed();
+        self.queue.clear_failed().await;
+        self.shard_tracker.lock().await.clear_parked();
         tracing::info!("Cleared failed downloads");
+        self.emit_queue_snapshot().await;
         Ok(())
     }
 
+    async fn get_history(
+        &self,
+        filter: &DownloadHistoryFilter,
+    ) -> Result<Vec<QueueRunSummary>, DownloadError> {
+        self.download_repo
+            .load_history(filter)
+            .await
+            .map_err(|e| DownloadError::other(format!("Failed to load download history: {e}")))
+    }
+
     async fn set_max_queue_size(&self, size: u32) -> Result<(), DownloadError> {
-        self.queue.write().await.set_max_size(size);
+        self.queue.set_max_size(size).await;
         tracing::info!(size = size, "Set max queue size");
+        self.emit_queue_snapshot().await;
         Ok(())
     }
 
     async fn get_max_queue_size(&self) -> Result<u32, DownloadError> {
-        let queue = self.queue.read().await;
-        Ok(queue.max_size())
+        Ok(self.queue.max_size().await)
     }
 }
 
@@ -1723,11 +2214,13 @@ impl DownloadManagerImpl {
         repo_id: impl Into<String>,
         quantization: Option<String>,
     ) -> Result<QueueAutoResult, DownloadError> {
-        let repo_id = repo_id.into();
+        let reference = repo_id.into();
+        let (route, bare_repo) = self.route(&reference)?;
+        let repo_id = route.client.hub().qualify(bare_repo);
 
-        let selection = self
+        let selection = route
             .selector
-            .select(&repo_id, quantization.as_deref())
+            .select(bare_repo, quantization.as_deref())
             .await?;
 
         let quant_str = selection.quantization.to_string();
@@ -1742,15 +2235,14 @@ impl DownloadManagerImpl {
             );
         }
 
-        let resolution = self
+        let resolution = route
             .resolver
-            .resolve(&repo_id, selection.quantization)
+            .resolve(bare_repo, selection.quantization)
             .await?;
 
-        let has_active = self.has_active().await;
         let shard_count = resolution.files.len();
 
-        // Build shard files outside lock
+        // Build shard files
         let shard_files: Vec<_> = resolution
             .files
             .iter()
@@ -1776,17 +2268,32 @@ impl DownloadManagerImpl {
             quantization: Some(selection.quantization.to_string()),
         };
 
-        // Minimal lock scope
-        let position = {
-            let mut queue = self.queue.write().await;
-            queue.queue_sharded(&id, &completion_key, shard_files, has_active)?
-        };
+        // Store file entries with OIDs for later model registration. They
+        // go in first: the runner may be handed the download as soon as it
+        // is queued.
+        let key = id.to_string();
+        let previous_files = self
+            .file_entries_map
+            .lock()
+            .await
+            .insert(key.clone(), resolution.files.clone());
+        // Stored in the default layout, whatever an earlier request for the
+        // same download asked for.
+        let previous_override = self.record_override(&key, None).await;
 
-        // Store file entries with OIDs for later model registration
+        let (position, layout) = match self
+            .queue
+            .queue(id.clone(), completion_key, shard_files)
+            .await
         {
-            let mut file_entries = self.file_entries_map.lock().await;
-            file_entries.insert(id.to_string(), resolution.files.clone());
-        }
+            Ok(queued) => queued,
+            Err(e) => {
+                restore(&self.file_entries_map, key.clone(), previous_files).await;
+                restore(&self.destination_overrides, key, previous_override).await;
+                return Err(e);
+            }
+        };
+        self.seed_group_progress(layout).await;
 
         let group_id = Some(id.to_string());
 
@@ -1798,7 +2305,6 @@ impl DownloadManagerImpl {
             "Download queued via queue_download_smart"
         );
 
-        self.queue_notify.notify_one();
         self.emit_queue_snapshot().await;
 
         #[allow(clippy::cast_possible_truncation)]
@@ -1809,24 +2315,67 @@ impl DownloadManagerImpl {
         })
     }
 
+    /// Queue each model repository of a collection via
+    /// [`queue_download_smart`](Self::queue_download_smart).
+    ///
+    /// Per-repo failures are recorded as skips so one repo without GGUF files
+    /// (or without the requested quantization) does not abort the batch.
+    pub async fn queue_collection_repos(
+        &self,
+        slug: &str,
+        quantization: Option<&str>,
+    ) -> Result<CollectionQueueResult, DownloadError> {
+        let collection = self.hf_client.get_collection(slug).await.map_err(|e| {
+            DownloadError::resolution_failed(format!("Failed to get collection {slug}: {e}"))
+        })?;
+
+        let mut queued = Vec::new();
+        let mut skipped = Vec::new();
+        for repo_id in collection.model_ids {
+            match self
+                .queue_download_smart(&repo_id, quantization.map(String::from))
+                .await
+            {
+                Ok(result) => queued.push(CollectionQueuedRepo {
+                    quantization: result
+                        .root_id
+                        .quantization()
+                        .unwrap_or_default()
+                        .to_string(),
+                    shard_count: result.queued as usize,
+                    repo_id,
+                }),
+                Err(e) => {
+                    tracing::info!(repo_id = %repo_id, error = %e, "Skipping collection repo");
+                    skipped.push(CollectionSkippedRepo {
+                        reason: e.to_string(),
+                        repo_id,
+                    });
+                }
+            }
+        }
+
+        tracing::info!(
+            slug = %collection.slug,
+            queued = queued.len(),
+            skipped = skipped.len(),
+            "Collection queued"
+        );
+
+        Ok(CollectionQueueResult {
+            slug: collection.slug,
+            title: collection.title,
+            queued,
+            skipped,
+        })
+    }
+
     /// Shutdown cleanup for process termination.
     pub fn shutdown_cleanup(&self) -> usize {
-        // Cancel all tokens synchronously
-        // We can't block_read() on tokio::sync::Mutex, so use try_lock
-        self.active.try_lock().map_or_else(
-            |_| {
-                tracing::warn!("Shutdown cleanup: couldn't acquire lock");
-                0
-            },
-            |active| {
-                let count = active.len();
-                for job in active.values() {
-                    job.cancel.cancel();
-                }
-                tracing::info!(count = count, "Shutdown cleanup: cancelled download tokens");
-                count
-            },
-        )
+        // Cancel all tokens synchronously; the actor may never run again.
+        let count = self.queue.shutdown();
+        tracing::info!(count = count, "Shutdown cleanup: cancelled download tokens");
+        count
     }
 }
 
@@ -1834,16 +2383,6 @@ impl DownloadManagerImpl {
 mod tests {
     use super::*;
 
-    #[test]
-    fn lease_id_equality() {
-        let l1 = LeaseId(1);
-        let l2 = LeaseId(1);
-        let l3 = LeaseId(2);
-
-        assert_eq!(l1, l2);
-        assert_ne!(l1, l3);
-    }
-
     #[test]
     fn progress_update_seq_comparison() {
         let p1 = ProgressUpdate::new(100, 1000, 1);
@@ -1907,7 +2446,9 @@ mod tests {
             event_emitter: Arc::new(gglib_core::ports::NoopDownloadEmitter::new()),
             id: "owner/repo:Q4_K_M".to_string(),
             shard_info: None,
+            group: None,
             estimator: Arc::new(Mutex::new(RateEstimator::new(std::time::Instant::now()))),
+            clock: Arc::new(gglib_core::utils::clock::SystemClock),
             cancel,
             finished,
         }
@@ -1950,6 +2491,114 @@ mod tests {
         assert!(joined.is_ok(), "bridge must exit when the job is cancelled");
     }
 
+    #[derive(Clone, Default)]
+    struct RecordingEmitter(Arc<std::sync::Mutex<Vec<DownloadEvent>>>);
+
+    impl DownloadEventEmitterPort for RecordingEmitter {
+        fn emit(&self, event: DownloadEvent) {
+            self.0.lock().unwrap().push(event);
+        }
+
+        fn clone_box(&self) -> Box<dyn DownloadEventEmitterPort> {
+            Box::new(self.clone())
+        }
+    }
+
+    /// Hashing after the transfer must show up as its own phase, not as a
+    /// transfer frozen at 100%.
+    #[tokio::test]
+    async fn verification_is_reported_as_its_own_phase() {
+        let (progress_tx, _rx) = watch::channel(ProgressUpdate::default());
+        let finished = CancellationToken::new();
+        let recorder = RecordingEmitter::default();
+        let bridge = ProgressBridge {
+            event_emitter: Arc::new(recorder.clone()),
+            ..test_bridge(CancellationToken::new(), finished.clone())
+        };
+        let handle = tokio::spawn(run_progress_bridge(bridge, progress_tx.subscribe()));
+
+        progress_tx.send_replace(ProgressUpdate::new(100, 100, 1));
+        tokio::time::sleep(PROGRESS_TICK * 2).await;
+        progress_tx.send_replace(ProgressUpdate {
+            phase: DownloadPhase::Verifying,
+            downloaded: 40,
+            total: 100,
+            seq: 2,
+        });
+        finished.cancel();
+        handle.await.unwrap();
+
+        let events = std::mem::take(&mut *recorder.0.lock().unwrap());
+        let phases: Vec<_> = events
+            .iter()
+            .filter_map(|event| match event {
+                DownloadEvent::PhaseProgress {
+                    phase, processed, ..
+                } => Some((*phase, *processed)),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(
+            phases,
+            [
+                (DownloadPhase::Transferring, 100),
+                (DownloadPhase::Verifying, 40)
+            ]
+        );
+        assert!(
+            matches!(events.last(), Some(DownloadEvent::PhaseProgress { .. })),
+            "no transfer progress after verification started"
+        );
+    }
+
+    /// A three-shard group sampled on a simulated clock, exactly as the
+    /// progress bridge samples it: 50 MB/s throughout, shard counters
+    /// restarting at each boundary.
+    #[tokio::test]
+    async fn simulated_transfer_reports_steady_speed_and_eta() {
+        use gglib_core::utils::clock::ManualClock;
+
+        const RATE: u64 = 50_000_000;
+        const SHARD: u64 = 3_000_000_000;
+        let group_total = 3 * SHARD;
+        let clock = ManualClock::default();
+        let estimator = Arc::new(Mutex::new(RateEstimator::new(clock.now())));
+
+        let mut speeds = Vec::new();
+        for index in 0..3u32 {
+            let shard = shard_with_offsets(index, u64::from(index) * SHARD, group_total, SHARD);
+            let mut downloaded = 0;
+            while downloaded < SHARD {
+                let aggregate = aggregate_progress(&shard, downloaded, SHARD);
+                let (speed, eta) = sample(&estimator, aggregate, clock.now()).await;
+                if clock.elapsed() >= Duration::from_secs(30) {
+                    speeds.push(speed.expect("speed after warmup"));
+                    // The smoothed ETA trails a steady countdown by about
+                    // its time constant (5s), and by no more than that.
+                    #[allow(clippy::cast_precision_loss)]
+                    let expected = (group_total - aggregate.0) as f64 / RATE as f64 + 5.0;
+                    let eta = eta.expect("ETA after warmup");
+                    assert!(
+                        (eta - expected).abs() < 0.5,
+                        "ETA {eta} at {:?}, expected about {expected}",
+                        clock.elapsed()
+                    );
+                }
+                clock.advance(PROGRESS_TICK);
+                downloaded = (downloaded + RATE / 4).min(SHARD);
+            }
+        }
+
+        assert_eq!(clock.elapsed(), Duration::from_mins(3));
+        for speed in speeds {
+            #[allow(clippy::cast_precision_loss)]
+            let error = (speed - RATE as f64).abs() / RATE as f64;
+            assert!(error < 0.01, "speed {speed} strayed from 50 MB/s");
+        }
+        let elapsed = estimator.lock().await.elapsed_seconds(clock.now());
+        assert!((elapsed - 180.0).abs() < f64::EPSILON);
+    }
+
     #[test]
     fn aggregate_never_exceeds_the_group_total() {
         // The stat poller can transiently over-report (a file counted at both
@@ -1959,4 +2608,117 @@ mod tests {
         let (downloaded, total) = aggregate_progress(&shard, 5_000, 1_500);
         assert_eq!(downloaded, total, "must clamp to the group total");
     }
+
+    #[test]
+    fn only_intact_shard_files_count_as_present() {
+        let dir = tempfile::tempdir().unwrap();
+        let id = DownloadId::new("org/model", Some("Q4_K_M"));
+        let names = [
+            "m-00001-of-00003.gguf",
+            "m-00002-of-00003.gguf",
+            "m-00003-of-00003.gguf",
+        ];
+        let shards: Vec<_> = (0u32..)
+            .zip(names)
+            .map(|(i, name)| ShardInfo::with_size(i, 3, name.to_string(), 8))
+            .collect();
+        let destination = DownloadDestination::plan(
+            dir.path(),
+            &id,
+            names.iter().map(ToString::to_string).collect(),
+        );
+        std::fs::create_dir_all(&destination.model_dir).unwrap();
+        let paths = destination.all_paths();
+        std::fs::write(&paths[0], b"GGUF\x03\x00\x00\x00").unwrap();
+        // Truncated by the failed attempt; the third was never started.
+        std::fs::write(&paths[1], b"GGUF").unwrap();
+
+        assert_eq!(
+            intact_shards(&destination, &shards),
+            vec![(0, paths[0].clone())]
+        );
+    }
+
+    struct UnusedRegistrar;
+
+    #[async_trait]
+    impl ModelRegistrarPort for UnusedRegistrar {
+        async fn register_model(
+            &self,
+            _download: &gglib_core::ports::CompletedDownload,
+        ) -> Result<gglib_core::Model, gglib_core::ports::RepositoryError> {
+            unreachable!("nothing is downloaded")
+        }
+
+        async fn register_model_from_path(
+            &self,
+            _repo_id: &str,
+            _commit_sha: &str,
+            _file_path: &std::path::Path,
+            _quantization: &str,
+        ) -> Result<gglib_core::Model, gglib_core::ports::RepositoryError> {
+            unreachable!("nothing is downloaded")
+        }
+    }
+
+    fn scripted(hub: ModelHub) -> gglib_test_support::ScriptedHfClient {
+        gglib_test_support::ScriptedHfClient::new()
+            .with_hub(hub)
+            .with_repo(
+                gglib_test_support::ScriptedRepo::new("org/model-GGUF")
+                    .with_file("model-Q4_K_M.gguf", 1024),
+            )
+    }
+
+    fn manager(models_dir: &std::path::Path) -> DownloadManagerImpl {
+        build_download_manager(DownloadManagerDeps {
+            model_registrar: Arc::new(UnusedRegistrar),
+            download_repo: Arc::new(gglib_test_support::MemoryDownloadStateRepository::new()),
+            hf_client: Arc::new(scripted(ModelHub::HuggingFace)),
+            event_emitter: Arc::new(gglib_core::ports::NoopDownloadEmitter::new()),
+            arch_support: None,
+            clock: Arc::new(gglib_core::utils::clock::SystemClock),
+            fs: Arc::new(gglib_core::utils::fs::RealFs),
+            config: DownloadManagerConfig::new(models_dir.to_path_buf()),
+        })
+    }
+
+    #[tokio::test]
+    async fn prefixed_references_route_to_their_hub() {
+        let dir = tempfile::tempdir().unwrap();
+        let modelscope = Arc::new(scripted(ModelHub::ModelScope));
+        let manager = manager(dir.path()).with_hub(Arc::clone(&modelscope) as _);
+
+        let queued = manager
+            .queue_download_smart("modelscope:org/model-GGUF", None)
+            .await
+            .unwrap();
+        assert_eq!(queued.root_id.model_id(), "modelscope:org/model-GGUF");
+        assert!(
+            modelscope
+                .calls()
+                .iter()
+                .any(|call| call.ends_with(" org/model-GGUF"))
+        );
+
+        // `hf:` is the default hub, so it names the bare repository.
+        let queued = manager
+            .queue_download_smart("hf:org/model-GGUF", None)
+            .await
+            .unwrap();
+        assert_eq!(queued.root_id.model_id(), "org/model-GGUF");
+    }
+
+    #[tokio::test]
+    async fn unconfigured_hub_is_refused() {
+        let dir = tempfile::tempdir().unwrap();
+        let err = manager(dir.path())
+            .queue_download_smart("modelscope:org/model-GGUF", None)
+            .await
+            .unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("ModelScope downloads are not configured")
+        );
+    }
 }
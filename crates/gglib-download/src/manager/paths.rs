@@ -1,11 +1,17 @@
 //! Destination path planning for downloads.
 //!
 //! This module handles the planning and creation of download destinations,
-//! including model directories and temporary file management.
+//! including model directories and the staging directory downloads land in
+//! before validation, and applies per-download [`DestinationOverride`]s.
 
+use std::collections::{HashMap, HashSet};
 use std::path::{Path, PathBuf};
 
-use gglib_core::download::{DownloadError, DownloadId};
+use gglib_core::download::{DestinationOverride, DownloadError, DownloadId};
+
+/// Directory under the models directory where staged downloads are written
+/// until they pass validation.
+pub const STAGING_DIR: &str = ".staging";
 
 /// A planned download destination.
 #[derive(Debug, Clone)]
@@ -14,6 +20,50 @@ pub struct DownloadDestination {
     pub model_dir: PathBuf,
     /// The files to download (relative paths within the model dir).
     pub files: Vec<String>,
+    /// Where each of `files` ends up, relative to the model dir; the same
+    /// names unless a destination override renames them.
+    pub targets: Vec<String>,
+    /// Where files are downloaded before validation, when staging is on.
+    pub staging_dir: Option<PathBuf>,
+}
+
+/// A [`DestinationOverride`] rendered for one download's files.
+///
+/// Rendered when the download is queued, so an invalid override is refused
+/// then rather than when the download starts.
+#[derive(Debug, Clone)]
+pub struct RenderedOverride {
+    /// Directory under the models directory replacing the default one.
+    pub subdirectory: Option<PathBuf>,
+    /// Repository file name to stored file name.
+    pub names: HashMap<String, String>,
+}
+
+impl RenderedOverride {
+    /// Render `destination` for `id`'s `files`, failing if any path is
+    /// unsafe or two files would get the same name.
+    pub fn render<'a>(
+        destination: &DestinationOverride,
+        id: &DownloadId,
+        files: impl IntoIterator<Item = &'a str>,
+    ) -> Result<Self, DownloadError> {
+        let subdirectory = destination.target_dir(id)?;
+        let mut names = HashMap::new();
+        let mut taken = HashSet::new();
+        for file in files {
+            let target = destination.target_file(id, file)?;
+            if !taken.insert(target.clone()) {
+                return Err(DownloadError::invalid_destination(format!(
+                    "the file name template gives more than one file the name '{target}'"
+                )));
+            }
+            names.insert(file.to_string(), target);
+        }
+        Ok(Self {
+            subdirectory,
+            names,
+        })
+    }
 }
 
 impl DownloadDestination {
@@ -25,30 +75,107 @@ impl DownloadDestination {
     /// * `id` - Download ID used to derive the subdirectory name
     /// * `files` - List of files to download
     pub fn plan(models_directory: &Path, id: &DownloadId, files: Vec<String>) -> Self {
-        // Convert repo ID to a safe directory name (replace / with _)
-        let dir_name = id.model_id().replace('/', "_");
+        // Convert repo ID to a safe directory name (replace / and a hub
+        // prefix's : with _)
+        let dir_name = id.model_id().replace([':', '/'], "_");
         let model_dir = models_directory.join(dir_name);
 
-        Self { model_dir, files }
+        Self {
+            model_dir,
+            targets: files.clone(),
+            files,
+            staging_dir: None,
+        }
+    }
+
+    /// Apply a rendered override: a different model directory and/or
+    /// different file names. The staging directory is left as planned.
+    #[must_use]
+    pub fn with_override(self, models_directory: &Path, rendered: &RenderedOverride) -> Self {
+        let model_dir = rendered
+            .subdirectory
+            .as_ref()
+            .map_or(self.model_dir, |dir| models_directory.join(dir));
+        let targets = self
+            .files
+            .iter()
+            .map(|f| rendered.names.get(f).unwrap_or(f).clone())
+            .collect();
+        Self {
+            model_dir,
+            targets,
+            ..self
+        }
     }
 
-    /// Ensure the model directory exists, creating it if necessary.
+    /// Plan a destination whose files are downloaded into
+    /// `<models_directory>/.staging/` and moved into the model directory only
+    /// once validated.
+    pub fn plan_staged(models_directory: &Path, id: &DownloadId, files: Vec<String>) -> Self {
+        let plan = Self::plan(models_directory, id, files);
+        let staging_dir = plan
+            .model_dir
+            .file_name()
+            .map(|name| models_directory.join(STAGING_DIR).join(name));
+        Self {
+            staging_dir,
+            ..plan
+        }
+    }
+
+    /// Directory the downloader writes into.
+    pub fn download_dir(&self) -> &Path {
+        self.staging_dir.as_deref().unwrap_or(&self.model_dir)
+    }
+
+    /// Ensure the model directory (and staging directory, if any) exists.
     pub fn ensure_dir(&self) -> Result<(), DownloadError> {
-        if !self.model_dir.exists() {
-            std::fs::create_dir_all(&self.model_dir)
-                .map_err(|e| DownloadError::io("create_dir", e.to_string()))?;
+        for dir in std::iter::once(&self.model_dir).chain(&self.staging_dir) {
+            if !dir.exists() {
+                std::fs::create_dir_all(dir)
+                    .map_err(|e| DownloadError::io("create_dir", e.to_string()))?;
+            }
         }
         Ok(())
     }
 
+    /// `(staged, final)` path pairs; empty when staging is off.
+    pub fn staged_paths(&self) -> Vec<(PathBuf, PathBuf)> {
+        self.staging_dir.as_ref().map_or_else(Vec::new, |staging| {
+            self.files
+                .iter()
+                .zip(&self.targets)
+                .map(|(f, t)| (staging.join(f), self.model_dir.join(t)))
+                .collect()
+        })
+    }
+
+    /// `(downloaded, final)` path pairs of files an override renames when
+    /// staging is off; those are downloaded under their repository names
+    /// and renamed afterwards.
+    pub fn renamed_paths(&self) -> Vec<(PathBuf, PathBuf)> {
+        if self.staging_dir.is_some() {
+            return Vec::new();
+        }
+        self.files
+            .iter()
+            .zip(&self.targets)
+            .filter(|(f, t)| f != t)
+            .map(|(f, t)| (self.model_dir.join(f), self.model_dir.join(t)))
+            .collect()
+    }
+
     /// Get the primary file path (first file in the list).
     pub fn primary_path(&self) -> Option<PathBuf> {
-        self.files.first().map(|f| self.model_dir.join(f))
+        self.targets.first().map(|f| self.model_dir.join(f))
     }
 
     /// Get all file paths.
     pub fn all_paths(&self) -> Vec<PathBuf> {
-        self.files.iter().map(|f| self.model_dir.join(f)).collect()
+        self.targets
+            .iter()
+            .map(|f| self.model_dir.join(f))
+            .collect()
     }
 }
 
@@ -85,6 +212,71 @@ mod tests {
         );
     }
 
+    #[test]
+    fn staged_plan_downloads_under_the_staging_dir() {
+        let base = PathBuf::from("/models");
+        let id = DownloadId::new("test/model", Some("Q4"));
+        let dest = DownloadDestination::plan_staged(&base, &id, vec!["file1.gguf".to_string()]);
+
+        assert_eq!(
+            dest.download_dir(),
+            Path::new("/models/.staging/test_model")
+        );
+        assert_eq!(
+            dest.staged_paths(),
+            vec![(
+                PathBuf::from("/models/.staging/test_model/file1.gguf"),
+                PathBuf::from("/models/test_model/file1.gguf"),
+            )]
+        );
+        assert_eq!(
+            dest.primary_path(),
+            Some(PathBuf::from("/models/test_model/file1.gguf"))
+        );
+    }
+
+    #[test]
+    fn override_moves_and_renames_files_but_not_the_staging_dir() {
+        let base = PathBuf::from("/models");
+        let id = DownloadId::new("test/model", Some("Q4_K_M"));
+        let files = vec!["sub/model-Q4_K_M.gguf".to_string()];
+        let destination = DestinationOverride {
+            subdirectory: Some("llm/{repo}".into()),
+            filename: Some("{repo}-{quant}".into()),
+        };
+        let rendered =
+            RenderedOverride::render(&destination, &id, files.iter().map(String::as_str)).unwrap();
+
+        let staged = DownloadDestination::plan_staged(&base, &id, files.clone())
+            .with_override(&base, &rendered);
+        assert_eq!(
+            staged.staged_paths(),
+            vec![(
+                PathBuf::from("/models/.staging/test_model/sub/model-Q4_K_M.gguf"),
+                PathBuf::from("/models/llm/model/model-Q4_K_M.gguf"),
+            )]
+        );
+
+        let direct = DownloadDestination::plan(&base, &id, files).with_override(&base, &rendered);
+        assert_eq!(
+            direct.renamed_paths(),
+            vec![(
+                PathBuf::from("/models/llm/model/sub/model-Q4_K_M.gguf"),
+                PathBuf::from("/models/llm/model/model-Q4_K_M.gguf"),
+            )]
+        );
+        assert_eq!(
+            direct.primary_path(),
+            Some(PathBuf::from("/models/llm/model/model-Q4_K_M.gguf"))
+        );
+
+        let clash = DestinationOverride {
+            subdirectory: None,
+            filename: Some("{repo}".into()),
+        };
+        assert!(RenderedOverride::render(&clash, &id, ["a.gguf", "b.gguf"]).is_err());
+    }
+
     #[test]
     fn all_paths_returns_full_paths() {
         let base = PathBuf::from("/models");
@@ -0,0 +1,210 @@
+//! Transfer of a model file from a paired gglib instance or a model hub.
+//!
+//! Library sync queues peer downloads through the same manager as
+//! `HuggingFace` ones, so they share the queue, progress events, staged
+//! validation and registration. Only the transfer differs: instead of the
+//! Python fast downloader, the file is streamed from the peer's
+//! `/api/models/{id}/file` endpoint. Files from a hub other than
+//! `HuggingFace` (`ModelScope`) are streamed from their direct download URL
+//! the same way. A partial file left by an interrupted attempt is resumed
+//! with a `Range` request.
+
+use std::path::Path;
+
+use reqwest::StatusCode;
+use reqwest::header::{AUTHORIZATION, RANGE};
+use tokio::io::AsyncWriteExt;
+use tokio::sync::watch;
+use tokio_util::sync::CancellationToken;
+
+use gglib_core::download::DownloadError;
+use gglib_core::ports::PeerSource;
+
+use super::worker::ProgressUpdate;
+
+/// Where a file is streamed from, for error messages.
+#[derive(Debug, Clone, Copy)]
+enum Origin {
+    Peer,
+    Hub,
+}
+
+impl Origin {
+    const fn name(self) -> &'static str {
+        match self {
+            Self::Peer => "Peer",
+            Self::Hub => "Model hub",
+        }
+    }
+
+    const fn denied(self) -> &'static str {
+        match self {
+            Self::Peer => "Peer rejected the share token; pair with it again",
+            Self::Hub => "Model hub refused access to the file",
+        }
+    }
+}
+
+/// Stream `peer`'s file to `dest`, reporting progress on `progress_tx`.
+pub async fn fetch(
+    client: &reqwest::Client,
+    peer: &PeerSource,
+    dest: &Path,
+    progress_tx: &watch::Sender<ProgressUpdate>,
+    cancel: &CancellationToken,
+) -> Result<(), DownloadError> {
+    let request = client
+        .get(&peer.url)
+        .header(AUTHORIZATION, format!("Bearer {}", peer.token));
+    stream(request, Origin::Peer, peer.size, dest, progress_tx, cancel).await
+}
+
+/// Stream the `size`-byte file at a model hub's `url` to `dest`, reporting
+/// progress on `progress_tx`.
+pub async fn fetch_url(
+    client: &reqwest::Client,
+    url: &str,
+    size: u64,
+    dest: &Path,
+    progress_tx: &watch::Sender<ProgressUpdate>,
+    cancel: &CancellationToken,
+) -> Result<(), DownloadError> {
+    stream(
+        client.get(url),
+        Origin::Hub,
+        size,
+        dest,
+        progress_tx,
+        cancel,
+    )
+    .await
+}
+
+/// Send `request`, resuming a partial `dest`, and write the body to it.
+async fn stream(
+    mut request: reqwest::RequestBuilder,
+    origin: Origin,
+    size: u64,
+    dest: &Path,
+    progress_tx: &watch::Sender<ProgressUpdate>,
+    cancel: &CancellationToken,
+) -> Result<(), DownloadError> {
+    let mut downloaded = match tokio::fs::metadata(dest).await {
+        Ok(meta) if meta.len() < size => meta.len(),
+        Ok(meta) if meta.len() == size => {
+            report(progress_tx, size, size);
+            return Ok(());
+        }
+        _ => 0,
+    };
+
+    if downloaded > 0 {
+        request = request.header(RANGE, format!("bytes={downloaded}-"));
+    }
+    let mut response = tokio::select! {
+        biased;
+        () = cancel.cancelled() => return Err(DownloadError::Cancelled),
+        response = request.send() => response
+            .map_err(|e| DownloadError::network(format!("{} unreachable: {e}", origin.name())))?,
+    };
+
+    let append = match response.status() {
+        StatusCode::PARTIAL_CONTENT => true,
+        StatusCode::OK => {
+            downloaded = 0;
+            false
+        }
+        StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => {
+            return Err(DownloadError::network_with_status(
+                origin.denied(),
+                response.status().as_u16(),
+            ));
+        }
+        status => {
+            return Err(DownloadError::network_with_status(
+                format!("{} answered {status}", origin.name()),
+                status.as_u16(),
+            ));
+        }
+    };
+
+    let mut file = tokio::fs::OpenOptions::new()
+        .create(true)
+        .write(true)
+        .append(append)
+        .truncate(!append)
+        .open(dest)
+        .await
+        .map_err(|e| DownloadError::from_io_error(&e))?;
+    report(progress_tx, downloaded, size);
+
+    loop {
+        let chunk = tokio::select! {
+            biased;
+            () = cancel.cancelled() => return Err(DownloadError::Cancelled),
+            chunk = response.chunk() => chunk
+                .map_err(|e| DownloadError::network(format!(
+                    "Transfer from {} failed: {e}",
+                    origin.name().to_lowercase()
+                )))?,
+        };
+        let Some(chunk) = chunk else { break };
+        file.write_all(&chunk)
+            .await
+            .map_err(|e| DownloadError::from_io_error(&e))?;
+        downloaded += chunk.len() as u64;
+        report(progress_tx, downloaded, size);
+    }
+    file.flush()
+        .await
+        .map_err(|e| DownloadError::from_io_error(&e))?;
+
+    if downloaded == size {
+        Ok(())
+    } else {
+        Err(DownloadError::network(format!(
+            "{} closed the transfer at {downloaded} of {size} bytes",
+            origin.name()
+        )))
+    }
+}
+
+fn report(progress_tx: &watch::Sender<ProgressUpdate>, downloaded: u64, total: u64) {
+    progress_tx.send_modify(|state| {
+        state.downloaded = downloaded;
+        state.total = total;
+        state.seq += 1;
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn complete_file_is_not_fetched_again() {
+        let dir = tempfile::tempdir().unwrap();
+        let dest = dir.path().join("model.gguf");
+        std::fs::write(&dest, b"GGUF").unwrap();
+        let peer = PeerSource {
+            // Unroutable: any request would fail the test.
+            url: "http://127.0.0.1:9/api/models/1/file".to_string(),
+            token: "t".to_string(),
+            file_name: "model.gguf".to_string(),
+            size: 4,
+            sha256: None,
+        };
+        let (tx, rx) = watch::channel(ProgressUpdate::default());
+
+        fetch(
+            &reqwest::Client::new(),
+            &peer,
+            &dest,
+            &tx,
+            &CancellationToken::new(),
+        )
+        .await
+        .unwrap();
+        assert_eq!((rx.borrow().downloaded, rx.borrow().total), (4, 4));
+    }
+}
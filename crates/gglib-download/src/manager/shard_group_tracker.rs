@@ -2,6 +2,11 @@
 //!
 //! This module provides a pure state tracker that accumulates shard completion
 //! events and signals when all shards in a group have been downloaded.
+//!
+//! A group that fails keeps its completed shards, parked outside the
+//! in-progress set, so retrying it only has to fetch the shards it is
+//! missing. A retried group is rebuilt from the shard files found on disk,
+//! whether or not it was parked.
 
 use std::collections::HashMap;
 use std::path::PathBuf;
@@ -39,15 +44,16 @@ struct ShardGroupState {
     paths_by_index: Vec<Option<PathBuf>>,
     /// Total number of shards expected.
     expected_total: u32,
-    /// Metadata for model registration.
-    metadata: GroupMetadata,
+    /// Metadata for model registration; set by the first shard done in
+    /// this session, so a group rebuilt from disk has none yet.
+    metadata: Option<GroupMetadata>,
     /// Last time this group was updated.
     last_updated: Instant,
 }
 
 impl ShardGroupState {
     /// Create a new shard group state.
-    fn new(expected_total: u32, metadata: GroupMetadata) -> Self {
+    fn new(expected_total: u32, metadata: Option<GroupMetadata>) -> Self {
         Self {
             paths_by_index: vec![None; expected_total as usize],
             expected_total,
@@ -75,6 +81,15 @@ impl ShardGroupState {
             && self.paths_by_index.iter().all(Option::is_some)
     }
 
+    /// Indices of the shards downloaded so far.
+    fn completed_indices(&self) -> Vec<u32> {
+        self.paths_by_index
+            .iter()
+            .zip(0u32..)
+            .filter_map(|(path, index)| path.as_ref().map(|_| index))
+            .collect()
+    }
+
     /// Extract ordered paths (only call if `is_complete`).
     fn ordered_paths(&self) -> Vec<PathBuf> {
         self.paths_by_index
@@ -104,6 +119,9 @@ pub struct ShardGroupTracker {
     /// INVARIANT: `groups` contains ONLY in-progress groups.
     /// Terminal paths (completion, failure, cancel) MUST remove entries from `groups`.
     groups: HashMap<ShardGroupId, ShardGroupState>,
+    /// Failed groups with at least one completed shard, until they are
+    /// retried or the failures are cleared. Not counted as open.
+    parked: HashMap<ShardGroupId, ShardGroupState>,
 }
 
 impl ShardGroupTracker {
@@ -142,11 +160,12 @@ impl ShardGroupTracker {
         let state = self
             .groups
             .entry(group_id.clone())
-            .or_insert_with(|| ShardGroupState::new(expected_total, metadata.clone()));
+            .or_insert_with(|| ShardGroupState::new(expected_total, None));
 
         // Guard: in debug builds, assert metadata consistency
+        let recorded = state.metadata.get_or_insert_with(|| metadata.clone());
         debug_assert_eq!(
-            state.metadata, *metadata,
+            recorded, metadata,
             "Metadata mismatch for group {group_id:?}! All shards must compute identical identity."
         );
 
@@ -159,7 +178,8 @@ impl ShardGroupTracker {
             if let Some(state) = self.groups.remove(group_id) {
                 return Some(GroupComplete {
                     ordered_paths: state.ordered_paths(),
-                    metadata: state.metadata,
+                    // Recorded above, at the latest by this shard.
+                    metadata: state.metadata.unwrap_or_else(|| metadata.clone()),
                 });
             }
         }
@@ -167,11 +187,54 @@ impl ShardGroupTracker {
         None
     }
 
-    /// Remove a shard group that was cancelled or failed.
+    /// Stop tracking a shard group whose download failed.
     ///
-    /// This prevents memory leaks from incomplete downloads.
-    pub fn on_group_failed(&mut self, group_id: &ShardGroupId) {
+    /// Completed shards are kept aside for [`resume_group`](Self::resume_group);
+    /// returns their indices.
+    pub fn on_group_failed(&mut self, group_id: &ShardGroupId) -> Vec<u32> {
+        let Some(state) = self.groups.remove(group_id) else {
+            return Vec::new();
+        };
+        let completed = state.completed_indices();
+        self.parked.insert(group_id.clone(), state);
+        completed
+    }
+
+    /// Forget a shard group that was cancelled, parked or not.
+    pub fn on_group_cancelled(&mut self, group_id: &ShardGroupId) {
         self.groups.remove(group_id);
+        self.parked.remove(group_id);
+    }
+
+    /// Track a failed group again as it is retried.
+    ///
+    /// `on_disk` lists the shards whose files were found intact; they
+    /// replace whatever the parked group had recorded, so a shard whose file
+    /// went missing is downloaded again and one left by an earlier attempt
+    /// counts. Returns their indices.
+    pub fn resume_group(
+        &mut self,
+        group_id: &ShardGroupId,
+        expected_total: u32,
+        on_disk: Vec<(u32, PathBuf)>,
+    ) -> Vec<u32> {
+        let mut state = self
+            .parked
+            .remove(group_id)
+            .unwrap_or_else(|| ShardGroupState::new(expected_total, None));
+        state.paths_by_index = vec![None; state.expected_total as usize];
+        for (index, path) in on_disk {
+            state.record_shard(index, path);
+        }
+        state.last_updated = Instant::now();
+        let completed = state.completed_indices();
+        self.groups.insert(group_id.clone(), state);
+        completed
+    }
+
+    /// Forget every parked group (their failures were cleared).
+    pub fn clear_parked(&mut self) {
+        self.parked.clear();
     }
 
     /// Check if there are any in-progress shard groups.
@@ -333,6 +396,49 @@ mod tests {
         assert_eq!(tracker.active_count(), 0);
     }
 
+    #[test]
+    fn test_failed_group_resumes_with_the_shards_on_disk() {
+        let mut tracker = ShardGroupTracker::new();
+        let group_id = ShardGroupId::new("test-group");
+        let metadata = test_metadata();
+
+        tracker.on_shard_done(&group_id, 0, PathBuf::from("/s0"), 4, &metadata);
+        tracker.on_shard_done(&group_id, 2, PathBuf::from("/s2"), 4, &metadata);
+        assert_eq!(tracker.on_group_failed(&group_id), vec![0, 2]);
+        assert!(!tracker.has_open_groups(), "a parked group is not open");
+
+        // Shard 2's file is gone; shard 3 was left by an earlier attempt.
+        let on_disk = vec![(0, PathBuf::from("/s0")), (3, PathBuf::from("/s3"))];
+        assert_eq!(tracker.resume_group(&group_id, 4, on_disk), vec![0, 3]);
+        assert!(tracker.has_open_groups());
+        tracker.on_shard_done(&group_id, 1, PathBuf::from("/s1"), 4, &metadata);
+        let complete = tracker
+            .on_shard_done(&group_id, 2, PathBuf::from("/s2"), 4, &metadata)
+            .expect("the retried shards complete the group");
+        assert_eq!(complete.ordered_paths[3], PathBuf::from("/s3"));
+        assert_eq!(complete.metadata, metadata);
+
+        tracker.on_shard_done(&group_id, 0, PathBuf::from("/s0"), 4, &metadata);
+        tracker.on_group_failed(&group_id);
+        tracker.on_group_cancelled(&group_id);
+        assert!(tracker.parked.is_empty(), "cancelling forgets it");
+    }
+
+    #[test]
+    fn test_group_rebuilt_from_disk_takes_metadata_from_its_next_shard() {
+        let mut tracker = ShardGroupTracker::new();
+        let group_id = ShardGroupId::new("lost-group");
+        let metadata = test_metadata();
+
+        let on_disk = vec![(0, PathBuf::from("/s0"))];
+        assert_eq!(tracker.resume_group(&group_id, 2, on_disk), vec![0]);
+        let complete = tracker
+            .on_shard_done(&group_id, 1, PathBuf::from("/s1"), 2, &metadata)
+            .unwrap();
+        assert_eq!(complete.ordered_paths.len(), 2);
+        assert_eq!(complete.metadata, metadata);
+    }
+
     #[test]
     fn test_gc_expired() {
         let mut tracker = ShardGroupTracker::new();
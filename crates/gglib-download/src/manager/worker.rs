@@ -2,14 +2,17 @@
 //!
 //! This module contains the core download execution logic, isolated from
 //! the queue orchestration. The worker operates on value types and cloned
-//! Arc dependencies, with no access to the manager's queue locks.
+//! Arc dependencies, with no access to the manager's queue.
 //!
 //! # Design Principles
 //!
 //! - Worker receives a `DownloadJob` (value type) and `WorkerDeps` (cloned Arcs)
-//! - Worker only writes to `watch::Sender` for progress, never emits events directly
+//! - Worker only writes to `watch::Sender` for progress, never emits events directly;
+//!   that includes hashing progress while staged files are verified
 //! - Cancellation is handled via `tokio::select!` around IO operations
 //! - Registration is deferred to the manager after shard group completion
+//! - Staged downloads are validated before they reach the models directory;
+//!   failures are moved to quarantine instead
 
 use std::fmt::Write;
 use std::path::PathBuf;
@@ -18,8 +21,9 @@ use std::sync::Arc;
 use tokio::sync::watch;
 use tokio_util::sync::CancellationToken;
 
-use gglib_core::download::{DownloadError, DownloadEvent, DownloadId, Quantization};
-use gglib_core::ports::{DownloadEventEmitterPort, DownloadManagerConfig};
+use gglib_core::download::{DownloadError, DownloadEvent, DownloadId, DownloadPhase, Quantization};
+use gglib_core::ports::{DownloadEventEmitterPort, DownloadManagerConfig, PeerSource};
+use gglib_core::utils::fs::FileSystem;
 
 use crate::cli_exec::{FastDownloadRequest, PythonBridgeError, run_fast_download};
 
@@ -42,6 +46,11 @@ pub struct WorkerDeps {
     pub config: DownloadManagerConfig,
     /// Event sink for [`DownloadEvent::DownloadNotice`] only.
     pub event_emitter: Arc<dyn DownloadEventEmitterPort>,
+    /// Filesystem the validated files are moved into place through.
+    pub fs: Arc<dyn FileSystem>,
+    /// Stands in for every transfer when fault injection is configured.
+    #[cfg(feature = "fault-injection")]
+    pub faults: Option<Arc<crate::chaos::FaultInjector>>,
 }
 
 /// A download job to be executed by the worker.
@@ -66,23 +75,38 @@ pub struct DownloadJob {
     /// for the entire transfer because the hf-xet fast path never drives
     /// tqdm to publish a `(0, total)` initial event.
     pub expected_total: Option<u64>,
+    /// SHA-256 from the file's LFS OID, checked during staged validation
+    /// when [`DownloadManagerConfig::verify_sha256`] is on.
+    pub expected_sha256: Option<String>,
+    /// Paired instance to fetch the file from instead of `HuggingFace`.
+    pub peer: Option<PeerSource>,
+    /// Direct download URL on a hub other than `HuggingFace`, streamed
+    /// instead of going through the Python fast downloader.
+    pub hub_url: Option<String>,
 }
 
 /// Progress update sent through the watch channel.
-#[derive(Debug, Clone, Default, PartialEq, Eq)]
+///
+/// Transfer progress leaves `phase` at its default of
+/// [`DownloadPhase::Transferring`]; staged validation switches it to
+/// [`DownloadPhase::Verifying`] and reuses the byte counters for hashing.
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ProgressUpdate {
-    /// Bytes downloaded so far.
+    /// What the byte counts measure.
+    pub phase: DownloadPhase,
+    /// Bytes downloaded (or, while verifying, hashed) so far.
     pub downloaded: u64,
-    /// Total bytes to download.
+    /// Total bytes to download (or hash).
     pub total: u64,
     /// Monotonically increasing sequence number for change detection.
     pub seq: u64,
 }
 
 impl ProgressUpdate {
-    /// Create a new progress update with a sequence number.
+    /// Create a new transfer progress update with a sequence number.
     pub const fn new(downloaded: u64, total: u64, seq: u64) -> Self {
         Self {
+            phase: DownloadPhase::Transferring,
             downloaded,
             total,
             seq,
@@ -90,6 +114,12 @@ impl ProgressUpdate {
     }
 }
 
+impl Default for ProgressUpdate {
+    fn default() -> Self {
+        Self::new(0, 0, 0)
+    }
+}
+
 /// Result of a successful download.
 #[derive(Debug, Clone)]
 pub struct CompletedJob {
@@ -138,6 +168,9 @@ fn percent_encode_revision(revision: &str) -> String {
 /// This function executes the full download pipeline:
 /// 1. Ensures destination directory exists
 /// 2. Downloads files with progress reporting
+/// 3. For staged destinations, validates each file and moves it into the
+///    model directory, or into quarantine if validation fails; unstaged
+///    files a destination override renames are renamed in place
 ///
 /// Progress is reported through `job.progress_tx` only; no events are emitted.
 /// The bridge task (spawned by the manager) handles event emission.
@@ -147,15 +180,33 @@ fn percent_encode_revision(revision: &str) -> String {
 ///
 /// The job can be cancelled via `job.cancel`. When cancelled, this returns
 /// `Err(DownloadError::Cancelled)`.
+#[tracing::instrument(name = "download.job", skip_all, fields(id = %job.id))]
 pub async fn run_job(job: DownloadJob, deps: &WorkerDeps) -> Result<CompletedJob, DownloadError> {
     // Step 1: Ensure destination directory exists
     job.destination.ensure_dir()?;
 
-    // Step 2: Execute download with cancellation support
-    let download_result = execute_download(&job, deps).await;
+    // A staged download whose files are already in place (validated by the
+    // manager's cached-file check) has nothing to fetch.
+    let staged = job.destination.staged_paths();
+    let already_in_place = !staged.is_empty() && staged.iter().all(|(_, dest)| dest.is_file());
 
-    // Handle cancellation or errors
-    let () = download_result?;
+    if !already_in_place {
+        // Step 2: Execute download with cancellation support
+        let download_result = execute_download(&job, deps).await;
+
+        // Handle cancellation or errors
+        let () = download_result?;
+
+        // Step 2b: Validate staged files, then promote or quarantine them
+        promote_staged(&job, deps, staged).await?;
+
+        // Step 2c: Give unstaged files the names an override asked for
+        for (from, to) in job.destination.renamed_paths() {
+            deps.fs
+                .rename(&from, &to)
+                .map_err(|e| DownloadError::from_io_error(&e))?;
+        }
+    }
 
     // Step 3: Prepare result with metadata for manager
     let primary_path = job
@@ -191,10 +242,152 @@ pub async fn run_job(job: DownloadJob, deps: &WorkerDeps) -> Result<CompletedJob
     })
 }
 
+/// Validate each staged file and move it into the model directory.
+///
+/// Checks GGUF magic and size, plus SHA-256 when enabled. The first failure
+/// moves that file to quarantine and fails the job, so nothing invalid ever
+/// reaches the models directory or the registrar.
+///
+/// Reports each file as [`DownloadPhase::Verifying`] on the job's progress
+/// channel, with hashing progress when the SHA-256 is checked.
+async fn promote_staged(
+    job: &DownloadJob,
+    deps: &WorkerDeps,
+    staged: Vec<(PathBuf, PathBuf)>,
+) -> Result<(), DownloadError> {
+    // Expected size and hash describe the job's single file.
+    let single = staged.len() == 1;
+    let expected_size = job.expected_total.filter(|_| single);
+    let expected_sha256 = job
+        .expected_sha256
+        .clone()
+        .filter(|_| single && deps.config.verify_sha256);
+    let models_dir = deps.config.models_directory.clone();
+    let id = job.id.to_string();
+    let progress_tx = job.progress_tx.clone();
+    let fs = Arc::clone(&deps.fs);
+
+    tokio::task::spawn_blocking(move || {
+        for (from, to) in staged {
+            let report = |hashed: u64, total: u64| {
+                progress_tx.send_modify(|state| {
+                    state.phase = DownloadPhase::Verifying;
+                    state.downloaded = hashed;
+                    state.total = total;
+                    state.seq += 1;
+                });
+            };
+            report(0, fs.metadata(&from).map_or(0, |m| m.len));
+            if let Err(reason) =
+                validate_staged(&from, expected_size, expected_sha256.as_deref(), report)
+            {
+                tracing::warn!(id, path = %from.display(), reason, "Download failed validation");
+                return Err(
+                    match crate::quarantine::quarantine_file(&models_dir, &id, &from, &reason) {
+                        Ok(entry) => {
+                            DownloadError::quarantined(reason, entry.display().to_string())
+                        }
+                        Err(e) => {
+                            let _ = fs.remove_file(&from);
+                            DownloadError::other(format!(
+                                "Validation failed ({reason}) and quarantine failed: {e}"
+                            ))
+                        }
+                    },
+                );
+            }
+            if let Some(parent) = to.parent() {
+                fs.create_dir_all(parent)
+                    .map_err(|e| DownloadError::from_io_error(&e))?;
+            }
+            // Same filesystem as the models directory, so this is atomic.
+            fs.rename(&from, &to)
+                .map_err(|e| DownloadError::from_io_error(&e))?;
+        }
+        Ok(())
+    })
+    .await
+    .map_err(|e| DownloadError::other(format!("Validation task failed: {e}")))?
+}
+
+/// Check a staged file's GGUF magic, size and (optionally) SHA-256.
+///
+/// `progress` receives `(bytes hashed, file size)` while the hash runs.
+fn validate_staged(
+    path: &std::path::Path,
+    expected_size: Option<u64>,
+    expected_sha256: Option<&str>,
+    progress: impl FnMut(u64, u64),
+) -> Result<(), String> {
+    gglib_gguf::validate_gguf_quick(path, expected_size).map_err(|e| e.to_string())?;
+
+    // LFS OIDs are SHA-256; a 40-character git blob SHA cannot be compared.
+    if let Some(expected) = expected_sha256.filter(|oid| oid.len() == 64) {
+        let actual = gglib_gguf::compute_gguf_sha256(path, progress).map_err(|e| e.to_string())?;
+        if !actual.eq_ignore_ascii_case(expected) {
+            return Err(format!(
+                "SHA-256 mismatch: expected {expected}, got {actual}"
+            ));
+        }
+    }
+    Ok(())
+}
+
 /// Execute the actual file download with progress and cancellation.
 async fn execute_download(job: &DownloadJob, deps: &WorkerDeps) -> Result<(), DownloadError> {
     use std::sync::atomic::{AtomicU64, Ordering};
 
+    #[cfg(feature = "fault-injection")]
+    if let Some(faults) = &deps.faults {
+        let file = job
+            .destination
+            .files
+            .first()
+            .ok_or_else(|| DownloadError::other("No files in download"))?;
+        let dest = job.destination.download_dir().join(file);
+        return faults
+            .transfer(&dest, job.expected_total, &job.progress_tx, &job.cancel)
+            .await;
+    }
+
+    if let Some(peer) = &job.peer {
+        let file = job
+            .destination
+            .files
+            .first()
+            .ok_or_else(|| DownloadError::other("No files in download"))?;
+        let dest = job.destination.download_dir().join(file);
+        return super::peer_fetch::fetch(
+            &reqwest::Client::new(),
+            peer,
+            &dest,
+            &job.progress_tx,
+            &job.cancel,
+        )
+        .await;
+    }
+
+    if let Some(url) = &job.hub_url {
+        let file = job
+            .destination
+            .files
+            .first()
+            .ok_or_else(|| DownloadError::other("No files in download"))?;
+        let size = job.expected_total.ok_or_else(|| {
+            DownloadError::resolution_failed(format!("Model hub did not report the size of {file}"))
+        })?;
+        let dest = job.destination.download_dir().join(file);
+        return super::peer_fetch::fetch_url(
+            &reqwest::Client::new(),
+            url,
+            size,
+            &dest,
+            &job.progress_tx,
+            &job.cancel,
+        )
+        .await;
+    }
+
     // Sequence counter for progress updates
     let seq = Arc::new(AtomicU64::new(0));
 
@@ -229,7 +422,7 @@ async fn execute_download(job: &DownloadJob, deps: &WorkerDeps) -> Result<(), Do
         repo_id: job.id.model_id(),
         revision: "main",
         repo_type: "model",
-        destination: &job.destination.model_dir,
+        destination: job.destination.download_dir(),
         files: &job.destination.files,
         token: deps.config.hf_token.as_deref(),
         force: false,
@@ -271,11 +464,35 @@ mod tests {
     #[test]
     fn progress_update_default_is_zero() {
         let update = ProgressUpdate::default();
+        assert_eq!(update.phase, DownloadPhase::Transferring);
         assert_eq!(update.downloaded, 0);
         assert_eq!(update.total, 0);
         assert_eq!(update.seq, 0);
     }
 
+    #[test]
+    fn validate_staged_rejects_bad_magic_size_and_hash() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("model.gguf");
+        std::fs::write(&path, b"GGUF\x03\x00\x00\x00").unwrap();
+
+        let none = |_, _| {};
+        assert!(validate_staged(&path, Some(8), None, none).is_ok());
+        assert!(validate_staged(&path, Some(9), None, none).is_err());
+
+        let wrong_hash = "0".repeat(64);
+        let mut hashed = 0;
+        let err =
+            validate_staged(&path, None, Some(&wrong_hash), |done, _| hashed = done).unwrap_err();
+        assert!(err.contains("SHA-256 mismatch"), "{err}");
+        assert_eq!(hashed, 8, "hashing reports its progress");
+        // Git blob SHAs are not comparable and are skipped.
+        assert!(validate_staged(&path, None, Some(&"0".repeat(40)), none).is_ok());
+
+        std::fs::write(&path, b"not gguf").unwrap();
+        assert!(validate_staged(&path, None, None, none).is_err());
+    }
+
     #[test]
     fn test_percent_encode_revision() {
         // Normal alphanumeric revisions pass through
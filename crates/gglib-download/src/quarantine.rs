@@ -0,0 +1,203 @@
+//! Quarantine for downloads that fail post-download validation.
+//!
+//! A staged file that fails its magic, size or SHA-256 check is moved to
+//! `<models_dir>/quarantine/<timestamp>-<file>/` next to a `reason.json`
+//! describing the failure, rather than being left half-registered in the
+//! models directory. `gglib downloads quarantine list|clear` reads and
+//! empties this directory.
+
+use std::io;
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Directory under the models directory holding quarantined files.
+pub const QUARANTINE_DIR: &str = "quarantine";
+
+/// Name of the reason file written beside each quarantined file.
+pub const REASON_FILE: &str = "reason.json";
+
+/// Contents of a quarantine entry's [`REASON_FILE`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct QuarantineRecord {
+    /// Download that produced the file.
+    pub download_id: String,
+    /// File name as requested from the repository.
+    pub file: String,
+    /// Why validation failed.
+    pub reason: String,
+    pub quarantined_at: DateTime<Utc>,
+}
+
+/// One quarantined download, as listed.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct QuarantineEntry {
+    /// Entry directory name; pass to [`clear_quarantine`] to remove it.
+    pub name: String,
+    pub path: PathBuf,
+    /// Size of the quarantined file(s).
+    pub size_bytes: u64,
+    #[serde(flatten)]
+    pub record: QuarantineRecord,
+}
+
+/// `<models_dir>/quarantine`.
+pub fn quarantine_dir(models_dir: &Path) -> PathBuf {
+    models_dir.join(QUARANTINE_DIR)
+}
+
+/// Move `file` into a new quarantine entry and write its reason file.
+///
+/// Returns the entry directory.
+pub fn quarantine_file(
+    models_dir: &Path,
+    download_id: &str,
+    file: &Path,
+    reason: &str,
+) -> io::Result<PathBuf> {
+    let quarantined_at = Utc::now();
+    let file_name = file
+        .file_name()
+        .map_or_else(|| "download".into(), |n| n.to_string_lossy().into_owned());
+
+    let root = quarantine_dir(models_dir);
+    let stem = format!("{}-{file_name}", quarantined_at.format("%Y%m%dT%H%M%SZ"));
+    let mut entry = root.join(&stem);
+    let mut suffix = 1;
+    while entry.exists() {
+        entry = root.join(format!("{stem}-{suffix}"));
+        suffix += 1;
+    }
+    std::fs::create_dir_all(&entry)?;
+
+    move_file(file, &entry.join(&file_name))?;
+
+    let record = QuarantineRecord {
+        download_id: download_id.to_string(),
+        file: file_name,
+        reason: reason.to_string(),
+        quarantined_at,
+    };
+    let json = serde_json::to_vec_pretty(&record).map_err(io::Error::other)?;
+    std::fs::write(entry.join(REASON_FILE), json)?;
+
+    Ok(entry)
+}
+
+/// List quarantine entries, newest first. Directories without a readable
+/// reason file are skipped.
+pub fn list_quarantine(models_dir: &Path) -> io::Result<Vec<QuarantineEntry>> {
+    let root = quarantine_dir(models_dir);
+    let dir = match std::fs::read_dir(&root) {
+        Ok(dir) => dir,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(e),
+    };
+
+    let mut entries = Vec::new();
+    for item in dir {
+        let path = item?.path();
+        let Ok(json) = std::fs::read(path.join(REASON_FILE)) else {
+            continue;
+        };
+        let Ok(record) = serde_json::from_slice::<QuarantineRecord>(&json) else {
+            continue;
+        };
+        entries.push(QuarantineEntry {
+            name: path
+                .file_name()
+                .map(|n| n.to_string_lossy().into_owned())
+                .unwrap_or_default(),
+            size_bytes: payload_size(&path),
+            path,
+            record,
+        });
+    }
+    entries.sort_by_key(|e| std::cmp::Reverse(e.record.quarantined_at));
+    Ok(entries)
+}
+
+/// Delete one quarantine entry by name, or every entry when `name` is `None`.
+///
+/// Returns the removed entries.
+pub fn clear_quarantine(models_dir: &Path, name: Option<&str>) -> io::Result<Vec<QuarantineEntry>> {
+    let entries = list_quarantine(models_dir)?;
+    let selected: Vec<_> = match name {
+        Some(name) => {
+            let found: Vec<_> = entries.into_iter().filter(|e| e.name == name).collect();
+            if found.is_empty() {
+                return Err(io::Error::new(
+                    io::ErrorKind::NotFound,
+                    format!("no quarantine entry named '{name}'"),
+                ));
+            }
+            found
+        }
+        None => entries,
+    };
+    for entry in &selected {
+        std::fs::remove_dir_all(&entry.path)?;
+    }
+    Ok(selected)
+}
+
+/// Rename, falling back to copy-and-delete across filesystems.
+fn move_file(from: &Path, to: &Path) -> io::Result<()> {
+    if std::fs::rename(from, to).is_ok() {
+        return Ok(());
+    }
+    std::fs::copy(from, to)?;
+    std::fs::remove_file(from)
+}
+
+/// Total size of everything in an entry except its reason file.
+fn payload_size(entry: &Path) -> u64 {
+    std::fs::read_dir(entry)
+        .into_iter()
+        .flatten()
+        .filter_map(Result::ok)
+        .filter(|item| item.file_name() != REASON_FILE)
+        .filter_map(|item| item.metadata().ok())
+        .map(|meta| meta.len())
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn quarantined_files_can_be_listed_and_cleared() {
+        let models = tempfile::tempdir().unwrap();
+        let staged = models.path().join("model.gguf");
+        std::fs::write(&staged, b"not a gguf").unwrap();
+
+        let entry = quarantine_file(
+            models.path(),
+            "owner/repo:Q4_K_M",
+            &staged,
+            "invalid GGUF magic",
+        )
+        .unwrap();
+        assert!(!staged.exists());
+        assert!(entry.join("model.gguf").exists());
+
+        let listed = list_quarantine(models.path()).unwrap();
+        assert_eq!(listed.len(), 1);
+        assert_eq!(listed[0].record.reason, "invalid GGUF magic");
+        assert_eq!(listed[0].size_bytes, 10);
+
+        assert!(clear_quarantine(models.path(), Some("missing")).is_err());
+        let removed = clear_quarantine(models.path(), Some(&listed[0].name)).unwrap();
+        assert_eq!(removed.len(), 1);
+        assert!(list_quarantine(models.path()).unwrap().is_empty());
+    }
+
+    #[test]
+    fn missing_quarantine_directory_is_empty() {
+        let models = tempfile::tempdir().unwrap();
+        assert!(list_quarantine(models.path()).unwrap().is_empty());
+        assert!(clear_quarantine(models.path(), None).unwrap().is_empty());
+    }
+}
@@ -0,0 +1,69 @@
+//! One pending download in the queue.
+
+use std::collections::VecDeque;
+
+use gglib_core::download::DownloadId;
+
+use super::shard_group::ShardGroupId;
+use super::types::QueuedItem;
+
+/// A pending download: its single file, or the shards of its group that
+/// have not been dequeued yet, in order.
+///
+/// The queue orders entries rather than items, so a download's items are
+/// always adjacent and keep their order. An entry is never empty — the
+/// queue drops it once its last item is taken.
+#[derive(Debug)]
+pub(super) struct Entry {
+    id: DownloadId,
+    group_id: Option<ShardGroupId>,
+    items: VecDeque<QueuedItem>,
+    /// Some of the group's shards have been dequeued.
+    started: bool,
+}
+
+impl Entry {
+    /// Create an entry from a download's items (at least one, sharing an ID).
+    pub(super) fn new(items: Vec<QueuedItem>) -> Self {
+        let first = &items[0];
+        debug_assert!(items.iter().all(|item| item.id == first.id));
+        Self {
+            id: first.id.clone(),
+            group_id: first.group_id.clone(),
+            items: items.into(),
+            started: false,
+        }
+    }
+
+    pub(super) const fn id(&self) -> &DownloadId {
+        &self.id
+    }
+
+    pub(super) const fn group_id(&self) -> Option<&ShardGroupId> {
+        self.group_id.as_ref()
+    }
+
+    pub(super) fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    pub(super) fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Whether the download is already running: its first shards have been
+    /// dequeued and the rest must follow them.
+    pub(super) const fn is_started(&self) -> bool {
+        self.started
+    }
+
+    pub(super) fn items(&self) -> impl Iterator<Item = &QueuedItem> {
+        self.items.iter()
+    }
+
+    /// Take the next item to download.
+    pub(super) fn take_next(&mut self) -> Option<QueuedItem> {
+        self.started = true;
+        self.items.pop_front()
+    }
+}
@@ -1,15 +1,20 @@
 #![doc = include_str!("README.md")]
+mod entry;
+#[cfg(test)]
+mod proptests;
 mod shard_group;
 mod types;
 
 use std::collections::VecDeque;
+use std::sync::Arc;
 
 use gglib_core::download::{
     CompletionKey, DownloadError, DownloadId, DownloadStatus, QueueSnapshot, ShardInfo,
 };
 
+use entry::Entry;
 pub use shard_group::ShardGroupId;
-pub use types::{FailedItem, QueuedItem};
+pub use types::{FailedItem, GroupFiles, QueuedItem};
 
 /// Saturating conversion from usize to u32 for queue positions.
 /// Returns `u32::MAX` if the value exceeds `u32::MAX`.
@@ -17,12 +22,22 @@ fn usize_to_u32_saturating(n: usize) -> u32 {
     u32::try_from(n).unwrap_or(u32::MAX)
 }
 
+/// 1-based position of the pending item at `index`; position 1 belongs to
+/// the active download, if there is one.
+fn position_of(index: usize, has_active: bool) -> u32 {
+    let base = if has_active { 2 } else { 1 };
+    usize_to_u32_saturating(index).saturating_add(base)
+}
+
 /// Manages the download queue state.
 ///
 /// This is a sync type with no internal locking — the caller
 /// (`DownloadManager`) is responsible for synchronization.
+///
+/// Pending work is held one [`Entry`] per download, so every operation moves
+/// a download's shards as a unit and a shard group can't be split.
 pub struct DownloadQueue {
-    pending: VecDeque<QueuedItem>,
+    pending: VecDeque<Entry>,
     failed: Vec<FailedItem>,
     max_size: u32,
 }
@@ -49,7 +64,7 @@ impl DownloadQueue {
 
     /// Get the number of pending items.
     pub fn pending_len(&self) -> usize {
-        self.pending.len()
+        self.pending.iter().map(Entry::len).sum()
     }
 
     /// Get the number of failed items.
@@ -60,7 +75,7 @@ impl DownloadQueue {
 
     /// Check if a download ID is currently queued.
     pub fn is_queued(&self, id: &DownloadId) -> bool {
-        self.pending.iter().any(|item| &item.id == id)
+        self.index_of(id).is_some()
     }
 
     /// Check if a download ID is in the failed list.
@@ -68,6 +83,17 @@ impl DownloadQueue {
         self.failed.iter().any(|item| &item.item.id == id)
     }
 
+    /// IDs of every pending and failed download.
+    ///
+    /// Failed downloads count because a retry resumes from their partial
+    /// files.
+    pub fn referenced_ids(&self) -> impl Iterator<Item = &DownloadId> {
+        self.pending
+            .iter()
+            .map(Entry::id)
+            .chain(self.failed.iter().map(|failed| &failed.item.id))
+    }
+
     /// Queue a single (non-sharded) download.
     ///
     /// Returns the 1-based queue position on success.
@@ -85,15 +111,9 @@ impl DownloadQueue {
         self.check_capacity(1)?;
         self.remove_from_failed(&id);
 
-        let item = QueuedItem::new(id, completion_key);
-        self.pending.push_back(item);
-
-        // Position: if something is active, pending starts at 2
-        let position = if has_active {
-            usize_to_u32_saturating(self.pending.len()).saturating_add(1)
-        } else {
-            usize_to_u32_saturating(self.pending.len())
-        };
+        let position = position_of(self.pending_len(), has_active);
+        self.pending
+            .push_back(Entry::new(vec![QueuedItem::new(id, completion_key)]));
 
         Ok(position)
     }
@@ -116,21 +136,37 @@ impl DownloadQueue {
         self.check_capacity(shard_files.len())?;
         self.remove_from_failed(id);
 
-        let first_position = if has_active {
-            usize_to_u32_saturating(self.pending.len()).saturating_add(2)
-        } else {
-            usize_to_u32_saturating(self.pending.len()).saturating_add(1)
-        };
+        let first_position = position_of(self.pending_len(), has_active);
 
-        let items = self.create_shard_items(id, completion_key, shard_files);
-        self.pending.extend(items);
+        let group_id = ShardGroupId::generate(id);
+        let items = self.create_shard_items(id, completion_key, shard_files, &group_id);
+        self.pending.push_back(Entry::new(items));
 
         Ok(first_position)
     }
 
+    /// The shard group of a pending download and its shards, in order.
+    pub fn group_layout(&self, id: &DownloadId) -> Option<(ShardGroupId, Vec<ShardInfo>)> {
+        let entry = &self.pending[self.index_of(id)?];
+        let group_id = entry.group_id()?.clone();
+        let shards = entry
+            .items()
+            .filter_map(|item| item.shard_info.clone())
+            .collect();
+        Some((group_id, shards))
+    }
+
     /// Pop the next item from the front of the queue.
+    ///
+    /// Taking the first shard of a group starts it; the rest of the group
+    /// stays at the front until it has all been taken.
     pub fn dequeue(&mut self) -> Option<QueuedItem> {
-        self.pending.pop_front()
+        let front = self.pending.front_mut()?;
+        let item = front.take_next();
+        if front.is_empty() {
+            self.pending.pop_front();
+        }
+        item
     }
 
     /// Clear all items from the queue (pending and failed).
@@ -141,10 +177,8 @@ impl DownloadQueue {
 
     /// Remove an item from the pending queue or failed list.
     pub fn remove(&mut self, id: &DownloadId) -> Result<(), DownloadError> {
-        let initial_pending = self.pending.len();
-        self.pending.retain(|item| &item.id != id);
-
-        if self.pending.len() < initial_pending {
+        if let Some(index) = self.index_of(id) {
+            self.pending.remove(index);
             return Ok(());
         }
 
@@ -160,6 +194,10 @@ impl DownloadQueue {
 
     /// Reorder a queued item (or shard group) to a new position.
     ///
+    /// A download is never placed inside another one, so a position that
+    /// falls within a shard group moves to just after that group. Nor can it
+    /// go ahead of a group whose first shards have already been dequeued.
+    ///
     /// Returns the actual 1-based position where the item(s) were placed.
     pub fn reorder(
         &mut self,
@@ -167,69 +205,41 @@ impl DownloadQueue {
         new_position: u32,
         has_active: bool,
     ) -> Result<u32, DownloadError> {
-        // Find item(s) to move (handles shard groups)
-        let group_id = self
-            .pending
-            .iter()
-            .find(|item| &item.id == id)
-            .and_then(|item| item.group_id.clone());
-
-        let items_to_move: Vec<_> = if let Some(ref gid) = group_id {
-            self.pending
-                .iter()
-                .filter(|item| item.group_id.as_ref() == Some(gid))
-                .cloned()
-                .collect()
-        } else {
-            self.pending
-                .iter()
-                .filter(|item| &item.id == id)
-                .cloned()
-                .collect()
-        };
-
-        if items_to_move.is_empty() {
-            return Err(DownloadError::not_in_queue(id.to_string()));
+        let from = self
+            .index_of(id)
+            .ok_or_else(|| DownloadError::not_in_queue(id.to_string()))?;
+        if from == 0 && self.pending[0].is_started() {
+            return Ok(position_of(0, has_active));
         }
+        let entry = self
+            .pending
+            .remove(from)
+            .ok_or_else(|| DownloadError::not_in_queue(id.to_string()))?;
 
-        // Remove then reinsert at new position
-        if let Some(ref gid) = group_id {
-            self.pending
-                .retain(|item| item.group_id.as_ref() != Some(gid));
-        } else {
-            self.pending.retain(|item| &item.id != id);
-        }
+        // Convert 1-based position to a 0-based item index
+        let base = if has_active { 2 } else { 1 };
+        let target = new_position.saturating_sub(base) as usize;
 
-        // Convert 1-based position to 0-based index
-        let target_index = if has_active {
-            (new_position.saturating_sub(2)) as usize
-        } else {
-            (new_position.saturating_sub(1)) as usize
-        };
-        let insert_pos = target_index.min(self.pending.len());
-
-        // Insert items at new position preserving order
-        for (offset, item) in items_to_move.into_iter().enumerate() {
-            let pos = insert_pos + offset;
-            if pos >= self.pending.len() {
-                self.pending.push_back(item);
-            } else {
-                self.pending.push_back(item);
-                let len = self.pending.len();
-                for i in (pos + 1..len).rev() {
-                    self.pending.swap(i, i - 1);
-                }
-            }
+        // Entries that start before the target, i.e. the index of the first
+        // entry boundary at or after it
+        let mut start = 0;
+        let mut to = self
+            .pending
+            .iter()
+            .take_while(|other| {
+                let before = start < target;
+                start += other.len();
+                before
+            })
+            .count();
+        if to == 0 && self.pending.front().is_some_and(Entry::is_started) {
+            to = 1;
         }
 
-        // Return 1-based position
-        let result_position = if has_active {
-            usize_to_u32_saturating(insert_pos).saturating_add(2)
-        } else {
-            usize_to_u32_saturating(insert_pos).saturating_add(1)
-        };
+        let offset: usize = self.pending.iter().take(to).map(Entry::len).sum();
+        self.pending.insert(to, entry);
 
-        Ok(result_position)
+        Ok(position_of(offset, has_active))
     }
 
     /// Get a snapshot of the current queue state for API responses.
@@ -242,8 +252,7 @@ impl DownloadQueue {
         let base_position = if current_item.is_some() { 2 } else { 1 };
 
         let pending: Vec<_> = self
-            .pending
-            .iter()
+            .items()
             .enumerate()
             .map(|(idx, item)| {
                 item.to_dto(
@@ -274,8 +283,22 @@ impl DownloadQueue {
     }
 
     /// Mark a download as failed and add to the failed list.
-    pub fn mark_failed(&mut self, item: QueuedItem, error: impl Into<String>) {
-        self.failed.push(FailedItem::new(item, error));
+    ///
+    /// A failed shard fails its whole group: the group's pending shards are
+    /// dropped and one failed entry stands for the group, reporting how many
+    /// of its shards had completed.
+    pub fn mark_failed(
+        &mut self,
+        item: QueuedItem,
+        error: impl Into<String>,
+        completed_shards: Vec<u32>,
+    ) {
+        if let Some(group_id) = item.group_id.clone() {
+            self.remove_group(&group_id);
+        }
+        self.remove_from_failed(&item.id);
+        self.failed
+            .push(FailedItem::new(item, error).with_completed_shards(completed_shards));
     }
 
     /// Clear all failed downloads.
@@ -283,14 +306,34 @@ impl DownloadQueue {
         self.failed.clear();
     }
 
+    /// Every shard of a failed download's group, in order, including the
+    /// ones already downloaded.
+    pub fn failed_group_layout(&self, id: &DownloadId) -> Option<(ShardGroupId, Vec<ShardInfo>)> {
+        let failed = self.failed.iter().find(|f| &f.item.id == id)?;
+        let group_id = failed.item.group_id.clone()?;
+        let files = failed.item.group_files.as_ref()?;
+        let shards = self
+            .create_shard_items(id, &failed.item.completion_key, files.to_vec(), &group_id)
+            .into_iter()
+            .filter_map(|item| item.shard_info)
+            .collect();
+        Some((group_id, shards))
+    }
+
     /// Retry a failed download by moving it back to the pending queue.
     ///
+    /// A shard group is queued again under its own group ID, minus the
+    /// `present` shards (already on disk); the caller resumes tracking
+    /// those. When every shard is present the last one is queued anyway, so
+    /// the group still completes and registers.
+    ///
     /// Returns the 1-based position in the queue on success.
     #[allow(clippy::cast_possible_truncation)] // Queue positions won't exceed u32::MAX in practice
     pub fn retry_failed(
         &mut self,
         id: &DownloadId,
         has_active: bool,
+        present: &[u32],
     ) -> Result<u32, DownloadError> {
         // Find and remove from failed list
         let pos = self.failed.iter().position(|f| &f.item.id == id);
@@ -300,16 +343,39 @@ impl DownloadQueue {
         };
 
         // Add back to pending queue with fresh timestamp, reusing completion_key
-        let item = QueuedItem::new(failed.item.id, failed.item.completion_key);
-        self.pending.push_back(item);
-
-        // Return 1-based position
-        let position = if has_active {
-            usize_to_u32_saturating(self.pending.len()).saturating_add(1)
-        } else {
-            usize_to_u32_saturating(self.pending.len())
+        let items = match (&failed.item.group_files, &failed.item.group_id) {
+            (Some(files), Some(group_id)) => {
+                let mut items = self.create_shard_items(
+                    id,
+                    &failed.item.completion_key,
+                    files.to_vec(),
+                    group_id,
+                );
+                let last = items.pop();
+                items.retain(|item| {
+                    item.shard_info
+                        .as_ref()
+                        .is_none_or(|s| !present.contains(&s.shard_index))
+                });
+                let last_present = last
+                    .as_ref()
+                    .and_then(|item| item.shard_info.as_ref())
+                    .is_some_and(|s| present.contains(&s.shard_index));
+                if !last_present || items.is_empty() {
+                    items.extend(last);
+                }
+                if let Err(e) = self.check_capacity(items.len()) {
+                    self.failed.push(failed);
+                    return Err(e);
+                }
+                items
+            }
+            _ => vec![QueuedItem::new(failed.item.id, failed.item.completion_key)],
         };
+        let position = position_of(self.pending_len(), has_active);
+        self.pending.push_back(Entry::new(items));
 
+        // Return 1-based position of the first item
         Ok(position)
     }
 
@@ -317,33 +383,48 @@ impl DownloadQueue {
 
     /// Remove all pending items belonging to a shard group.
     pub fn remove_group(&mut self, group_id: &ShardGroupId) -> usize {
-        let initial = self.pending.len();
-        self.pending
-            .retain(|item| item.group_id.as_ref() != Some(group_id));
-        initial - self.pending.len()
+        let Some(index) = self
+            .pending
+            .iter()
+            .position(|entry| entry.group_id() == Some(group_id))
+        else {
+            return 0;
+        };
+        self.pending.remove(index).map_or(0, |entry| entry.len())
     }
 
     /// Move all pending items in a shard group to the failed list.
     #[cfg(test)]
     pub fn fail_group(&mut self, group_id: &ShardGroupId, error: &str) -> usize {
-        let mut removed = Vec::new();
-        self.pending.retain(|item| {
-            if item.group_id.as_ref() == Some(group_id) {
-                removed.push(item.clone());
-                false
-            } else {
-                true
+        let Some(index) = self
+            .pending
+            .iter()
+            .position(|entry| entry.group_id() == Some(group_id))
+        else {
+            return 0;
+        };
+        self.pending.remove(index).map_or(0, |mut entry| {
+            let mut count = 0;
+            while let Some(item) = entry.take_next() {
+                self.failed.push(FailedItem::new(item, error));
+                count += 1;
             }
-        });
-        let count = removed.len();
-        for item in removed {
-            self.failed.push(FailedItem::new(item, error));
-        }
-        count
+            count
+        })
     }
 
     // --- Private helpers ---
 
+    /// Every pending item, in queue order.
+    fn items(&self) -> impl Iterator<Item = &QueuedItem> {
+        self.pending.iter().flat_map(Entry::items)
+    }
+
+    /// Index of the pending entry for a download.
+    fn index_of(&self, id: &DownloadId) -> Option<usize> {
+        self.pending.iter().position(|entry| entry.id() == id)
+    }
+
     fn check_not_queued(&self, id: &DownloadId) -> Result<(), DownloadError> {
         if self.is_queued(id) {
             Err(DownloadError::already_queued(id.to_string()))
@@ -353,7 +434,7 @@ impl DownloadQueue {
     }
 
     fn check_capacity(&self, additional: usize) -> Result<(), DownloadError> {
-        if self.pending.len() + additional > self.max_size as usize {
+        if self.pending_len() + additional > self.max_size as usize {
             Err(DownloadError::queue_full(self.max_size))
         } else {
             Ok(())
@@ -370,9 +451,10 @@ impl DownloadQueue {
         id: &DownloadId,
         completion_key: &CompletionKey,
         shard_files: Vec<(String, Option<u64>)>,
+        group_id: &ShardGroupId,
     ) -> Vec<QueuedItem> {
-        let group_id = ShardGroupId::generate(id);
         let total_shards = usize_to_u32_saturating(shard_files.len());
+        let group_files: GroupFiles = shard_files.clone().into();
 
         // Exact byte offsets, but only when HuggingFace gave us a size for
         // every shard. A partial layout is worse than none: the consumer's
@@ -406,6 +488,7 @@ impl DownloadQueue {
                     group_id.clone(),
                     shard_info,
                     completion_key.clone(),
+                    Arc::clone(&group_files),
                 )
             })
             .collect()
@@ -576,6 +659,65 @@ mod tests {
         assert_eq!(snapshot.items[1].group_id.as_ref().unwrap(), group_id);
     }
 
+    #[test]
+    fn test_failed_shard_fails_its_group_and_retries_the_missing_shards() {
+        let mut queue = DownloadQueue::new(10);
+        let id = test_id("model/x", Some("Q4_K_M"));
+        let shards = vec![
+            ("s1.gguf".to_string(), Some(10u64)),
+            ("s2.gguf".to_string(), Some(20u64)),
+            ("s3.gguf".to_string(), Some(30u64)),
+        ];
+        let key = test_completion_key(&id);
+        queue.queue_sharded(&id, &key, shards, false).unwrap();
+
+        queue.dequeue().unwrap();
+        let second = queue.dequeue().unwrap();
+        queue.mark_failed(second.clone(), "connection reset", vec![0]);
+        assert_eq!(queue.pending_len(), 0, "the rest of the group is dropped");
+        assert_eq!(queue.failed_len(), 1);
+
+        let snapshot = queue.snapshot(None);
+        let failure = &snapshot.recent_failures[0];
+        assert_eq!(failure.error, "Part 2/3: connection reset");
+        assert_eq!(
+            (failure.total_shards, failure.completed_shards),
+            (Some(3), 1)
+        );
+
+        let (group_id, layout) = queue.failed_group_layout(&id).unwrap();
+        assert_eq!(Some(&group_id), second.group_id.as_ref());
+        assert_eq!(layout.len(), 3, "the layout covers the whole group");
+
+        assert_eq!(queue.retry_failed(&id, false, &[0]).unwrap(), 1);
+        let retried: Vec<_> = queue.items().collect();
+        let files: Vec<_> = retried
+            .iter()
+            .map(|item| item.shard_info.as_ref().unwrap().filename.as_str())
+            .collect();
+        assert_eq!(
+            files,
+            ["s2.gguf", "s3.gguf"],
+            "the completed shard is skipped"
+        );
+        let last = retried[1].shard_info.as_ref().unwrap();
+        assert_eq!((last.shard_index, last.total_shards), (2, 3));
+        assert_eq!(last.file_size, Some(30));
+        assert_eq!(retried[0].group_id, second.group_id, "the same group");
+        assert!(!queue.is_failed(&id));
+
+        // With every shard on disk, the last is queued to finish the group.
+        let third = queue.dequeue().unwrap();
+        queue.dequeue().unwrap();
+        queue.mark_failed(third, "registration lost", vec![0, 1, 2]);
+        queue.retry_failed(&id, false, &[0, 1, 2]).unwrap();
+        let files: Vec<_> = queue
+            .items()
+            .map(|item| item.shard_info.as_ref().unwrap().filename.as_str())
+            .collect();
+        assert_eq!(files, ["s3.gguf"]);
+    }
+
     #[test]
     fn test_remove_group() {
         let mut queue = DownloadQueue::new(10);
@@ -584,11 +726,11 @@ mod tests {
         let key = test_completion_key(&id);
         queue.queue_sharded(&id, &key, shards, false).unwrap();
 
-        let group_id = queue.pending.front().unwrap().group_id.clone().unwrap();
+        let group_id = queue.items().next().unwrap().group_id.clone().unwrap();
         let removed = queue.remove_group(&group_id);
 
         assert_eq!(removed, 2);
-        assert!(queue.pending.is_empty());
+        assert_eq!(queue.pending_len(), 0);
     }
 
     #[test]
@@ -599,11 +741,11 @@ mod tests {
         let key = test_completion_key(&id);
         queue.queue_sharded(&id, &key, shards, false).unwrap();
 
-        let group_id = queue.pending.front().unwrap().group_id.clone().unwrap();
+        let group_id = queue.items().next().unwrap().group_id.clone().unwrap();
         let failed_count = queue.fail_group(&group_id, "Network error");
 
         assert_eq!(failed_count, 2);
-        assert!(queue.pending.is_empty());
+        assert_eq!(queue.pending_len(), 0);
         assert_eq!(queue.failed.len(), 2);
         assert_eq!(queue.failed[0].error, "Network error");
     }
@@ -636,7 +778,7 @@ mod tests {
         let mut queue = DownloadQueue::new(10);
         let id = test_id("a", None);
         let item = QueuedItem::new(id.clone(), test_completion_key(&id));
-        queue.mark_failed(item, "error");
+        queue.mark_failed(item, "error", Vec::new());
 
         assert!(queue.is_failed(&id));
         queue.remove(&id).unwrap();
@@ -672,7 +814,7 @@ mod tests {
         let new_pos = queue.reorder(&test_id("c", None), 1, false).unwrap();
 
         assert_eq!(new_pos, 1);
-        let ids: Vec<_> = queue.pending.iter().map(|i| i.id.model_id()).collect();
+        let ids: Vec<_> = queue.items().map(|i| i.id.model_id()).collect();
         assert_eq!(ids, vec!["c", "a", "b"]);
     }
 
@@ -696,7 +838,7 @@ mod tests {
         let new_pos = queue.reorder(&test_id("c", None), 2, false).unwrap();
 
         assert_eq!(new_pos, 2);
-        let ids: Vec<_> = queue.pending.iter().map(|i| i.id.model_id()).collect();
+        let ids: Vec<_> = queue.items().map(|i| i.id.model_id()).collect();
         assert_eq!(ids, vec!["a", "c", "b"]);
     }
 
@@ -721,7 +863,7 @@ mod tests {
         let new_pos = queue.reorder(&test_id("c", None), 2, true).unwrap();
 
         assert_eq!(new_pos, 2);
-        let ids: Vec<_> = queue.pending.iter().map(|i| i.id.model_id()).collect();
+        let ids: Vec<_> = queue.items().map(|i| i.id.model_id()).collect();
         assert_eq!(ids, vec!["c", "a", "b"]);
     }
 
@@ -765,22 +907,78 @@ mod tests {
         let new_pos = queue.reorder(&id_sharded, 1, false).unwrap();
 
         assert_eq!(new_pos, 1);
-        let ids: Vec<_> = queue.pending.iter().map(|i| i.id.model_id()).collect();
+        let ids: Vec<_> = queue.items().map(|i| i.id.model_id()).collect();
         // Both shards at front, then a, then b
         assert_eq!(ids, vec!["sharded", "sharded", "a", "b"]);
     }
 
+    #[test]
+    fn test_reorder_into_shard_group_lands_after_it() {
+        let mut queue = DownloadQueue::new(10);
+        let id_sharded = test_id("sharded", Some("Q4"));
+        let shards = vec![
+            ("s1.gguf".to_string(), None),
+            ("s2.gguf".to_string(), None),
+            ("s3.gguf".to_string(), None),
+        ];
+        queue
+            .queue_sharded(
+                &id_sharded,
+                &test_completion_key(&id_sharded),
+                shards,
+                false,
+            )
+            .unwrap();
+        let id_a = test_id("a", None);
+        queue
+            .queue(id_a.clone(), test_completion_key(&id_a), false)
+            .unwrap();
+
+        // Position 2 is the group's second shard
+        let new_pos = queue.reorder(&id_a, 2, false).unwrap();
+
+        assert_eq!(new_pos, 4);
+        let ids: Vec<_> = queue.items().map(|i| i.id.model_id()).collect();
+        assert_eq!(ids, vec!["sharded", "sharded", "sharded", "a"]);
+    }
+
+    #[test]
+    fn test_reorder_cannot_jump_a_started_group() {
+        let mut queue = DownloadQueue::new(10);
+        let id_sharded = test_id("sharded", Some("Q4"));
+        let shards = vec![("s1.gguf".to_string(), None), ("s2.gguf".to_string(), None)];
+        queue
+            .queue_sharded(
+                &id_sharded,
+                &test_completion_key(&id_sharded),
+                shards,
+                false,
+            )
+            .unwrap();
+        let id_a = test_id("a", None);
+        queue
+            .queue(id_a.clone(), test_completion_key(&id_a), true)
+            .unwrap();
+        queue.dequeue().unwrap();
+
+        // The first shard is active; its sibling stays next in line
+        assert_eq!(queue.reorder(&id_a, 2, true).unwrap(), 3);
+        assert_eq!(queue.reorder(&id_sharded, 3, true).unwrap(), 2);
+        let ids: Vec<_> = queue.items().map(|i| i.id.model_id()).collect();
+        assert_eq!(ids, vec!["sharded", "a"]);
+    }
+
     #[test]
     fn test_retry_failed() {
         let mut queue = DownloadQueue::new(10);
         let id = test_id("failed-model", None);
         let item = QueuedItem::new(id.clone(), test_completion_key(&id));
-        queue.mark_failed(item, "temporary error");
+        queue.mark_failed(item, "temporary error", Vec::new());
 
         assert!(queue.is_failed(&id));
         assert!(!queue.is_queued(&id));
 
-        let position = queue.retry_failed(&id, false).unwrap();
+        let position = queue.retry_failed(&id, false, &[]).unwrap();
 
         assert_eq!(position, 1);
         assert!(!queue.is_failed(&id));
@@ -801,9 +999,9 @@ mod tests {
 
         let id = test_id("failed-model", None);
         let item = QueuedItem::new(id.clone(), test_completion_key(&id));
-        queue.mark_failed(item, "error");
+        queue.mark_failed(item, "error", Vec::new());
 
-        let position = queue.retry_failed(&id, false).unwrap();
+        let position = queue.retry_failed(&id, false, &[]).unwrap();
 
         // Should be at end of queue
         assert_eq!(position, 2);
@@ -814,7 +1012,7 @@ mod tests {
     fn test_retry_failed_not_found() {
         let mut queue = DownloadQueue::new(10);
 
-        let result = queue.retry_failed(&test_id("nonexistent", None), false);
+        let result = queue.retry_failed(&test_id("nonexistent", None), false, &[]);
         assert!(matches!(result, Err(DownloadError::NotInQueue { .. })));
     }
 
@@ -826,10 +1024,12 @@ mod tests {
         queue.mark_failed(
             QueuedItem::new(id_a.clone(), test_completion_key(&id_a)),
             "err1",
+            Vec::new(),
         );
         queue.mark_failed(
             QueuedItem::new(id_b.clone(), test_completion_key(&id_b)),
             "err2",
+            Vec::new(),
         );
 
         assert_eq!(queue.failed_len(), 2);
@@ -1008,7 +1208,7 @@ mod tests {
         assert_eq!(item.id.model_id(), "model-a");
 
         let error_msg = "connection timeout";
-        queue.mark_failed(item, error_msg);
+        queue.mark_failed(item, error_msg, Vec::new());
 
         // Snapshot: only "model-b" remains in pending, failed item in recent_failures
         let snapshot = queue.snapshot(None);
@@ -1042,8 +1242,8 @@ mod tests {
         let item_a = queue.dequeue().unwrap();
         let item_b = queue.dequeue().unwrap();
 
-        queue.mark_failed(item_a, "error for a");
-        queue.mark_failed(item_b, "error for b");
+        queue.mark_failed(item_a, "error for a", Vec::new());
+        queue.mark_failed(item_b, "error for b", Vec::new());
 
         // Verify 2 failed items
         assert_eq!(queue.failed_len(), 2);
@@ -0,0 +1,248 @@
+//! Property tests for the queue's ordering invariants.
+//!
+//! Random sequences of queue operations run against a [`DownloadQueue`], and
+//! after every step the pending items are checked for:
+//!
+//! - no item lost or duplicated;
+//! - each download's items contiguous, shards in ascending order;
+//! - downloads the operation did not touch keeping their relative order;
+//! - `reorder` placing the download at the position it reports, which is the
+//!   first download boundary at or after the requested one.
+
+use std::collections::HashSet;
+
+use gglib_core::download::{CompletionKey, DownloadId};
+use proptest::prelude::*;
+
+use super::{DownloadQueue, QueuedItem};
+
+/// A pending item's identity: its download and, for shards, its index.
+type Key = (DownloadId, Option<u32>);
+
+#[derive(Debug, Clone)]
+enum Op {
+    /// Queue a new download with this many files (1 = not sharded).
+    Queue(usize),
+    Dequeue,
+    /// Remove the n-th pending download (modulo the number pending).
+    Remove(usize),
+    Reorder {
+        pick: usize,
+        position: u32,
+        has_active: bool,
+    },
+    /// Dequeue the front item and mark it failed.
+    FailFront,
+    /// Retry the n-th failed download (modulo the number failed).
+    Retry(usize),
+}
+
+fn op() -> impl Strategy<Value = Op> {
+    prop_oneof![
+        3 => (1usize..=4).prop_map(Op::Queue),
+        2 => Just(Op::Dequeue),
+        1 => any::<usize>().prop_map(Op::Remove),
+        4 => (any::<usize>(), 0u32..16, any::<bool>()).prop_map(|(pick, position, has_active)| {
+            Op::Reorder {
+                pick,
+                position,
+                has_active,
+            }
+        }),
+        1 => Just(Op::FailFront),
+        1 => any::<usize>().prop_map(Op::Retry),
+    ]
+}
+
+fn key(item: &QueuedItem) -> Key {
+    (
+        item.id.clone(),
+        item.shard_info.as_ref().map(|shard| shard.shard_index),
+    )
+}
+
+fn keys(queue: &DownloadQueue) -> Vec<Key> {
+    queue.items().map(key).collect()
+}
+
+/// Pending downloads in queue order, one entry per download.
+fn downloads(keys: &[Key]) -> Vec<DownloadId> {
+    let mut ids: Vec<_> = keys.iter().map(|(id, _)| id.clone()).collect();
+    ids.dedup();
+    ids
+}
+
+fn without(keys: &[Key], id: &DownloadId) -> Vec<Key> {
+    keys.iter().filter(|(k, _)| k != id).cloned().collect()
+}
+
+fn completion_key(id: &DownloadId) -> CompletionKey {
+    CompletionKey::HfFile {
+        repo_id: id.model_id().to_string(),
+        revision: "unspecified".to_string(),
+        filename_canon: "test.gguf".to_string(),
+        quantization: id.quantization().map(String::from),
+    }
+}
+
+/// Invariants that hold after any operation.
+fn check_invariants(queue: &DownloadQueue) -> Result<(), TestCaseError> {
+    let keys = keys(queue);
+    prop_assert_eq!(queue.pending_len(), keys.len());
+
+    let unique: HashSet<_> = keys.iter().collect();
+    prop_assert_eq!(unique.len(), keys.len(), "duplicated items in {:?}", keys);
+
+    let ids = downloads(&keys);
+    let distinct: HashSet<_> = ids.iter().collect();
+    prop_assert_eq!(distinct.len(), ids.len(), "a download is split: {:?}", keys);
+
+    for pair in keys.windows(2) {
+        if pair[0].0 == pair[1].0 {
+            prop_assert!(pair[0].1 < pair[1].1, "shards out of order: {:?}", keys);
+        }
+    }
+
+    let snapshot = queue.snapshot(None);
+    let positions: Vec<_> = snapshot.items.iter().map(|item| item.position).collect();
+    let expected: Vec<_> = (1..=u32::try_from(keys.len()).unwrap()).collect();
+    prop_assert_eq!(positions, expected);
+    Ok(())
+}
+
+/// Check a reorder against the queue before it.
+fn check_reorder(
+    before: &[Key],
+    after: &[Key],
+    moved: &DownloadId,
+    requested: u32,
+    has_active: bool,
+    reported: u32,
+) -> Result<(), TestCaseError> {
+    let base: u32 = if has_active { 2 } else { 1 };
+    let rest = without(before, moved);
+    prop_assert_eq!(&without(after, moved), &rest, "untouched items reordered");
+
+    let moved_before: Vec<_> = before.iter().filter(|(id, _)| id == moved).collect();
+    let start = after.iter().position(|(id, _)| id == moved).unwrap();
+    let moved_after: Vec<_> = after[start..start + moved_before.len()].iter().collect();
+    prop_assert_eq!(moved_after, moved_before, "moved items split or reordered");
+
+    // A download that has started stays at the front, nothing can jump
+    // ahead of it, and no download lands inside another one.
+    if matches!(before.first(), Some((id, Some(index))) if id == moved && *index > 0) {
+        prop_assert_eq!(start, 0);
+        prop_assert_eq!(reported, base);
+        return Ok(());
+    }
+    let pinned = match rest.first() {
+        Some((id, Some(index))) if *index > 0 => rest.iter().take_while(|(k, _)| k == id).count(),
+        _ => 0,
+    };
+    let target = (requested.saturating_sub(base) as usize).min(rest.len());
+    let is_boundary = |i: usize| i == 0 || i == rest.len() || rest[i - 1].0 != rest[i].0;
+    let expected = (target.max(pinned)..=rest.len())
+        .find(|&i| is_boundary(i))
+        .unwrap();
+    prop_assert_eq!(start, expected);
+    prop_assert_eq!(reported, base + u32::try_from(start).unwrap());
+    Ok(())
+}
+
+fn apply(
+    queue: &mut DownloadQueue,
+    op: &Op,
+    next_id: &mut usize,
+    failed: &mut Vec<DownloadId>,
+) -> Result<(), TestCaseError> {
+    let before = keys(queue);
+    let pending = downloads(&before);
+    match *op {
+        Op::Queue(files) => {
+            let id = DownloadId::new(format!("model-{next_id}"), Some("Q4_K_M"));
+            *next_id += 1;
+            let shards = (0..files)
+                .map(|i| (format!("shard-{i}.gguf"), Some(100)))
+                .collect();
+            let result = if files == 1 {
+                queue.queue(id.clone(), completion_key(&id), false)
+            } else {
+                queue.queue_sharded(&id, &completion_key(&id), shards, false)
+            };
+            if result.is_ok() {
+                let after = keys(queue);
+                prop_assert_eq!(&after[..before.len()], &before[..]);
+                prop_assert_eq!(after.len(), before.len() + files);
+            } else {
+                prop_assert_eq!(keys(queue), before);
+            }
+        }
+        Op::Dequeue => {
+            let item = queue.dequeue();
+            prop_assert_eq!(item.as_ref().map(key), before.first().cloned());
+            prop_assert_eq!(keys(queue), before.get(1..).unwrap_or_default());
+        }
+        Op::Remove(pick) => {
+            let Some(id) = pending.get(pick % pending.len().max(1)) else {
+                return Ok(());
+            };
+            queue.remove(id).unwrap();
+            prop_assert_eq!(keys(queue), without(&before, id));
+        }
+        Op::Reorder {
+            pick,
+            position,
+            has_active,
+        } => {
+            let Some(id) = pending.get(pick % pending.len().max(1)) else {
+                let missing = DownloadId::new("missing", None::<&str>);
+                prop_assert!(queue.reorder(&missing, position, has_active).is_err());
+                prop_assert_eq!(keys(queue), before);
+                return Ok(());
+            };
+            let reported = queue.reorder(id, position, has_active).unwrap();
+            check_reorder(&before, &keys(queue), id, position, has_active, reported)?;
+        }
+        Op::FailFront => {
+            let Some(item) = queue.dequeue() else {
+                return Ok(());
+            };
+            let id = item.id.clone();
+            queue.mark_failed(item, "injected", Vec::new());
+            prop_assert_eq!(keys(queue), without(&before, &id));
+            failed.retain(|f| f != &id);
+            failed.push(id);
+        }
+        Op::Retry(pick) => {
+            if failed.is_empty() {
+                return Ok(());
+            }
+            let index = pick % failed.len();
+            let id = failed[index].clone();
+            let Ok(reported) = queue.retry_failed(&id, false, &[]) else {
+                prop_assert!(queue.is_failed(&id), "a refused retry stays failed");
+                prop_assert_eq!(keys(queue), before);
+                return Ok(());
+            };
+            failed.remove(index);
+            let after = keys(queue);
+            prop_assert_eq!(&after[..before.len()], &before[..]);
+            prop_assert!(after[before.len()..].iter().all(|(k, _)| k == &id));
+            prop_assert_eq!(reported, u32::try_from(before.len()).unwrap() + 1);
+        }
+    }
+    Ok(())
+}
+
+proptest! {
+    #[test]
+    fn queue_operations_preserve_ordering_invariants(ops in prop::collection::vec(op(), 1..60)) {
+        let mut queue = DownloadQueue::new(64);
+        let mut next_id = 0;
+        let mut failed = Vec::new();
+        for op in &ops {
+            apply(&mut queue, op, &mut next_id, &mut failed)?;
+            check_invariants(&queue)?;
+        }
+    }
+}
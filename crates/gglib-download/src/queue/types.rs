@@ -3,12 +3,16 @@
 //! These types are used internally by the queue state machine.
 //! For API responses, use the DTO types from `gglib_core::download::queue`.
 
+use std::sync::Arc;
 use std::time::Instant;
 
 use gglib_core::download::{CompletionKey, DownloadId, DownloadStatus, ShardInfo};
 
 use super::shard_group::ShardGroupId;
 
+/// A shard group's files, in order, with their sizes when known.
+pub type GroupFiles = Arc<[(String, Option<u64>)]>;
+
 /// A queued download item waiting to be processed.
 ///
 /// This is an internal type for the queue state machine.
@@ -28,6 +32,9 @@ pub struct QueuedItem {
     /// Stable artifact identity computed at enqueue time.
     /// Used for completion tracking and deduplication.
     pub completion_key: CompletionKey,
+    /// Every file of the shard group, with its size, so a failed group can
+    /// be queued again whole.
+    pub group_files: Option<GroupFiles>,
 }
 
 impl QueuedItem {
@@ -40,6 +47,7 @@ impl QueuedItem {
             revision: None,
             queued_at: Instant::now(),
             completion_key,
+            group_files: None,
         }
     }
 
@@ -49,6 +57,7 @@ impl QueuedItem {
         group_id: ShardGroupId,
         shard_info: ShardInfo,
         completion_key: CompletionKey,
+        group_files: GroupFiles,
     ) -> Self {
         Self {
             id,
@@ -57,6 +66,7 @@ impl QueuedItem {
             revision: None,
             queued_at: Instant::now(),
             completion_key,
+            group_files: Some(group_files),
         }
     }
 
@@ -100,6 +110,8 @@ pub struct FailedItem {
     pub error: String,
     /// When the failure occurred.
     pub failed_at: Instant,
+    /// Indices of the group's shards that had already been downloaded.
+    pub completed_shards: Vec<u32>,
 }
 
 impl FailedItem {
@@ -109,22 +121,47 @@ impl FailedItem {
             item,
             error: error.into(),
             failed_at: Instant::now(),
+            completed_shards: Vec::new(),
         }
     }
 
+    /// Record which of the group's shards had been downloaded.
+    #[must_use]
+    pub fn with_completed_shards(mut self, completed_shards: Vec<u32>) -> Self {
+        self.completed_shards = completed_shards;
+        self
+    }
+
     /// Convert to a core DTO for API responses.
+    ///
+    /// A shard group of several files is shown as one failed download; the
+    /// error names the shard that failed.
     pub fn to_dto(&self) -> gglib_core::download::FailedDownload {
-        let display_name = self.item.shard_info.as_ref().map_or_else(
-            || self.item.id.to_string(),
-            |shard| format!("{} ({})", self.item.id, shard.display()),
-        );
-
+        let shard = self.item.shard_info.as_ref().filter(|s| s.total_shards > 1);
+        let (Some(group_id), Some(shard)) = (&self.item.group_id, shard) else {
+            return gglib_core::download::FailedDownload::new(
+                self.item.canonical_id(),
+                self.item.id.to_string(),
+                &self.error,
+                self.failed_at.elapsed().as_secs(),
+            );
+        };
+
+        let downloaded_bytes = self.item.group_files.as_ref().map_or(0, |files| {
+            self.completed_shards
+                .iter()
+                .filter_map(|&index| files.get(index as usize)?.1)
+                .sum()
+        });
+        let completed = u32::try_from(self.completed_shards.len()).unwrap_or(u32::MAX);
         gglib_core::download::FailedDownload::new(
             self.item.canonical_id(),
-            display_name,
-            &self.error,
+            self.item.id.to_string(),
+            format!("{}: {}", shard.display(), self.error),
             self.failed_at.elapsed().as_secs(),
         )
+        .with_downloaded_bytes(downloaded_bytes)
+        .with_shard_group(group_id.to_string(), shard.total_shards, completed)
     }
 }
 
@@ -160,7 +197,12 @@ mod tests {
         let shard_info = ShardInfo::new(0, 2, "shard-00001.gguf".to_string());
         let key = test_completion_key(&id);
 
-        let item = QueuedItem::new_shard(id, group_id.clone(), shard_info, key);
+        let files: GroupFiles = Arc::from([
+            ("shard-00001.gguf".to_string(), None),
+            ("shard-00002.gguf".to_string(), None),
+        ]);
+
+        let item = QueuedItem::new_shard(id, group_id.clone(), shard_info, key, files);
 
         assert!(item.shard_info.is_some());
         assert_eq!(item.group_id.as_ref(), Some(&group_id));
@@ -197,6 +239,33 @@ mod tests {
         assert_eq!(dto.failed_at, 0);
     }
 
+    #[test]
+    fn test_failed_shard_reports_its_group() {
+        let id = DownloadId::new("model/test", Some("Q4_K_M"));
+        let files: GroupFiles = Arc::from([
+            ("s1.gguf".to_string(), Some(10)),
+            ("s2.gguf".to_string(), Some(20)),
+            ("s3.gguf".to_string(), Some(30)),
+        ]);
+        let item = QueuedItem::new_shard(
+            id.clone(),
+            ShardGroupId::new("group"),
+            ShardInfo::new(1, 3, "s2.gguf".to_string()),
+            test_completion_key(&id),
+            files,
+        );
+        let dto = FailedItem::new(item, "Connection reset")
+            .with_completed_shards(vec![0, 2])
+            .to_dto();
+
+        assert_eq!(dto.display_name, "model/test:Q4_K_M");
+        assert_eq!(dto.error, "Part 2/3: Connection reset");
+        assert_eq!(dto.group_id.as_deref(), Some("group"));
+        assert_eq!(dto.total_shards, Some(3));
+        assert_eq!(dto.completed_shards, 2);
+        assert_eq!(dto.downloaded_bytes, 40);
+    }
+
     #[test]
     fn test_queued_item_to_dto_with_all_statuses() {
         let id = DownloadId::new("model/test", Some("Q4_K_M"));
@@ -4,16 +4,19 @@ use std::sync::Arc;
 use async_trait::async_trait;
 
 use gglib_core::download::{DownloadError, Quantization};
-use gglib_core::ports::{HfClientPort, QuantizationResolver, Resolution, ResolvedFile};
+use gglib_core::ports::{ModelHubPort, QuantizationResolver, Resolution, ResolvedFile};
 
-/// Resolver that uses the `HuggingFace` client port.
+/// Resolver that uses a model hub port: the `HuggingFace` client, or the
+/// client of another hub such as `ModelScope`.
+///
+/// Repository IDs passed to it are bare (`org/repo`), without a hub prefix.
 pub struct HfQuantizationResolver {
-    hf_client: Arc<dyn HfClientPort>,
+    hf_client: Arc<dyn ModelHubPort>,
 }
 
 impl HfQuantizationResolver {
-    /// Create a new resolver with the given HF client.
-    pub fn new(hf_client: Arc<dyn HfClientPort>) -> Self {
+    /// Create a new resolver with the given hub client.
+    pub fn new(hf_client: Arc<dyn ModelHubPort>) -> Self {
         Self { hf_client }
     }
 }
@@ -80,5 +83,5 @@ impl QuantizationResolver for HfQuantizationResolver {
 
 #[cfg(test)]
 mod tests {
-    // TODO: Add tests with mock HfClientPort
+    // TODO: Add tests with mock ModelHubPort
 }
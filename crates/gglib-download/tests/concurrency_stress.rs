@@ -0,0 +1,276 @@
+//! Concurrency stress tests for the download manager.
+//!
+//! Several tasks on a multi-threaded runtime queue, cancel, reorder, retry
+//! and remove downloads at once while the worker runs transfers from the
+//! fault injector. Every call must return and the manager must drain
+//! afterwards: a deadlock shows up as a timeout, and in debug builds a lock
+//! taken out of order panics where it is taken.
+//!
+//! Needs the `fault-injection` feature:
+//! `cargo test -p gglib-download --features fault-injection --test concurrency_stress`
+
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use async_trait::async_trait;
+use chrono::Utc;
+
+use gglib_core::ports::{
+    CompletedDownload, DownloadFaultConfig, DownloadManagerConfig, DownloadManagerPort,
+    DownloadRequest, ModelRegistrarPort, NoopDownloadEmitter, RepositoryError,
+};
+use gglib_core::utils::clock::SystemClock;
+use gglib_core::utils::fs::RealFs;
+use gglib_core::{Model, ModelRepository, NewModel};
+use gglib_download::{
+    DownloadId, DownloadManagerDeps, DownloadManagerImpl, Quantization, build_download_manager,
+};
+use gglib_test_support::{
+    MemoryDownloadStateRepository, MemoryModelRepository, ScriptedHfClient, ScriptedRepo,
+};
+
+const REPOS: u32 = 12;
+const WORKERS: u64 = 6;
+const OPS_PER_WORKER: u32 = 150;
+/// Far longer than any run needs; reaching it means something is stuck.
+const DEADLINE: Duration = Duration::from_mins(1);
+
+#[derive(Default)]
+struct RecordingRegistrar {
+    models: MemoryModelRepository,
+    registered: Mutex<Vec<CompletedDownload>>,
+}
+
+#[async_trait]
+impl ModelRegistrarPort for RecordingRegistrar {
+    async fn register_model(&self, download: &CompletedDownload) -> Result<Model, RepositoryError> {
+        self.registered.lock().unwrap().push(download.clone());
+        let model = NewModel::new(
+            download.repo_id.clone(),
+            download.primary_path.clone(),
+            0.0,
+            Utc::now(),
+        );
+        self.models.insert(&model).await
+    }
+
+    async fn register_model_from_path(
+        &self,
+        repo_id: &str,
+        _commit_sha: &str,
+        file_path: &Path,
+        _quantization: &str,
+    ) -> Result<Model, RepositoryError> {
+        let model = NewModel::new(
+            repo_id.to_string(),
+            file_path.to_path_buf(),
+            0.0,
+            Utc::now(),
+        );
+        self.models.insert(&model).await
+    }
+}
+
+/// Repositories of one to three shards each.
+fn hf_client() -> ScriptedHfClient {
+    (0..REPOS).fold(ScriptedHfClient::new(), |hf, i| {
+        let shards = 1 + i % 3;
+        let repo = (1..=shards).fold(ScriptedRepo::new(&repo_id(i)), |repo, k| {
+            let name = if shards == 1 {
+                format!("stress-{i}-Q4_K_M.gguf")
+            } else {
+                format!("stress-{i}-Q4_K_M-{k:05}-of-{shards:05}.gguf")
+            };
+            repo.with_file(&name, 16 * 1024)
+        });
+        hf.with_repo(repo)
+    })
+}
+
+fn repo_id(i: u32) -> String {
+    format!("stress/model-{i}")
+}
+
+fn manager(
+    models_dir: &Path,
+    registrar: Arc<RecordingRegistrar>,
+    faults: DownloadFaultConfig,
+) -> Arc<DownloadManagerImpl> {
+    let config = DownloadManagerConfig::new(models_dir.to_path_buf())
+        .with_max_queue_size(1_000)
+        .with_fault_injection(faults);
+    Arc::new(build_download_manager(DownloadManagerDeps {
+        model_registrar: registrar,
+        download_repo: Arc::new(MemoryDownloadStateRepository::new()),
+        hf_client: Arc::new(hf_client()),
+        event_emitter: Arc::new(NoopDownloadEmitter::new()),
+        arch_support: None,
+        clock: Arc::new(SystemClock),
+        fs: Arc::new(RealFs),
+        config,
+    }))
+}
+
+/// `SplitMix64`, so each worker's operation sequence is reproducible.
+struct Rng(u64);
+
+impl Rng {
+    const fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    fn below(&mut self, n: u32) -> u32 {
+        u32::try_from(self.next() % u64::from(n)).unwrap()
+    }
+}
+
+/// Wait until nothing is pending or active.
+async fn drained(manager: &DownloadManagerImpl) {
+    loop {
+        let snapshot = manager.get_queue_snapshot().await.unwrap();
+        if snapshot.pending_count == 0 && snapshot.active_count == 0 {
+            return;
+        }
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+}
+
+/// One worker's share of the storm: random operations on random downloads.
+async fn hammer(manager: Arc<DownloadManagerImpl>, seed: u64) {
+    let mut rng = Rng(seed);
+    for _ in 0..OPS_PER_WORKER {
+        let repo = repo_id(rng.below(REPOS));
+        let id = DownloadId::new(&repo, Some(Quantization::Q4KM.to_string()));
+        // Most of these fail (already queued, not in queue, not failed);
+        // only returning matters.
+        match rng.below(8) {
+            0 | 1 => {
+                let request = DownloadRequest::new(&repo, Quantization::Q4KM);
+                let _ = manager.queue_download(request).await;
+                manager.ensure_runner();
+            }
+            2 => {
+                let _ = manager.cancel_download(&id).await;
+            }
+            3 | 4 => {
+                let _ = manager.reorder_queue(&id, 1 + rng.below(6)).await;
+            }
+            5 => {
+                let _ = manager.retry(&id).await;
+            }
+            6 => {
+                let _ = manager.remove_from_queue(&id).await;
+            }
+            _ => {
+                let _ = manager.get_queue_snapshot().await;
+                let _ = manager.has_download(&id).await;
+            }
+        }
+        if rng.below(4) == 0 {
+            tokio::task::yield_now().await;
+        }
+    }
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn concurrent_queue_cancel_and_reorder_never_deadlock() {
+    let dir = tempfile::tempdir().unwrap();
+    let faults = DownloadFaultConfig {
+        seed: 7,
+        connection_reset_rate: 0.2,
+        slow_stream_rate: 0.4,
+        truncate_rate: 0.1,
+        rate_limit_rate: 0.1,
+        chunk_size: 1024,
+        slow_chunk_delay: Duration::from_millis(1),
+    };
+    let manager = manager(dir.path(), Arc::default(), faults);
+
+    let storm = async {
+        let workers: Vec<_> = (0..WORKERS)
+            .map(|seed| tokio::spawn(hammer(Arc::clone(&manager), seed)))
+            .collect();
+        for worker in workers {
+            worker.await.expect("a worker panicked");
+        }
+        manager.cancel_all().await.unwrap();
+        manager.clear_failed().await.unwrap();
+        drained(&manager).await;
+    };
+    tokio::time::timeout(DEADLINE, storm)
+        .await
+        .expect("the manager deadlocked under concurrent operations");
+
+    let snapshot = manager.get_queue_snapshot().await.unwrap();
+    assert!(snapshot.items.is_empty(), "{:?}", snapshot.items);
+    assert!(snapshot.recent_failures.is_empty());
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn downloads_reordered_while_running_each_register_once() {
+    let dir = tempfile::tempdir().unwrap();
+    let faults = DownloadFaultConfig {
+        seed: 11,
+        slow_stream_rate: 0.5,
+        chunk_size: 1024,
+        slow_chunk_delay: Duration::from_millis(1),
+        ..DownloadFaultConfig::default()
+    };
+    let registrar = Arc::new(RecordingRegistrar::default());
+    let manager = manager(dir.path(), Arc::clone(&registrar), faults);
+
+    for i in 0..REPOS {
+        manager
+            .queue_download(DownloadRequest::new(repo_id(i), Quantization::Q4KM))
+            .await
+            .unwrap();
+    }
+    manager.ensure_runner();
+
+    let run = async {
+        let shufflers: Vec<_> = (0..WORKERS)
+            .map(|seed| {
+                let manager = Arc::clone(&manager);
+                tokio::spawn(async move {
+                    let mut rng = Rng(seed);
+                    for _ in 0..OPS_PER_WORKER {
+                        let repo = repo_id(rng.below(REPOS));
+                        let id = DownloadId::new(&repo, Some(Quantization::Q4KM.to_string()));
+                        let _ = manager.reorder_queue(&id, 1 + rng.below(8)).await;
+                        let _ = manager.get_queue_snapshot().await;
+                        tokio::task::yield_now().await;
+                    }
+                })
+            })
+            .collect();
+        for shuffler in shufflers {
+            shuffler.await.expect("a shuffler panicked");
+        }
+        drained(&manager).await;
+    };
+    tokio::time::timeout(DEADLINE, run)
+        .await
+        .expect("the queue never drained");
+
+    let registered = registrar.registered.lock().unwrap().clone();
+    for i in 0..REPOS {
+        let shards = (1 + i % 3) as usize;
+        let matches: Vec<_> = registered
+            .iter()
+            .filter(|download| download.repo_id == repo_id(i))
+            .collect();
+        assert_eq!(
+            matches.len(),
+            1,
+            "{} registered {} times",
+            repo_id(i),
+            matches.len()
+        );
+        assert_eq!(matches[0].all_paths.len(), shards);
+    }
+}
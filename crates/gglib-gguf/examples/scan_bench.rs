@@ -0,0 +1,228 @@
+//! Benchmark: parse the headers of a library of large sharded models.
+//!
+//! Writes sparse GGUF shards — real headers, with a full-size tokenizer in
+//! each model's first shard as `gguf-split` produces, then a hole up to the
+//! shard size — so a 100 GB library takes a few megabytes of disk. Each run
+//! parses every shard the way a library scan does and reports the time.
+//!
+//! ```text
+//! cargo run --release -p gglib-gguf --example scan_bench -- \
+//!     --models 4 --shards 5 --shard-gb 5 --runs 5
+//! ```
+//!
+//! Built with the default `mmap` feature it measures the memory-mapped
+//! reader; with `--no-default-features`, the buffered one.
+
+use std::fs::File;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::time::{Duration, Instant};
+
+use gglib_core::ports::GgufParserPort;
+use gglib_gguf::GgufParser;
+
+/// Tokenizer size of a current large model (Qwen-style vocabulary).
+const VOCAB: u32 = 152_064;
+
+struct Options {
+    models: u64,
+    shards: u64,
+    shard_gb: u64,
+    runs: u64,
+}
+
+impl Options {
+    fn parse() -> Result<Self, String> {
+        let mut options = Self {
+            models: 4,
+            shards: 5,
+            shard_gb: 5,
+            runs: 5,
+        };
+        let mut args = std::env::args().skip(1);
+        while let Some(flag) = args.next() {
+            let value = args.next().ok_or_else(|| format!("{flag} needs a value"))?;
+            let number = value
+                .parse::<u64>()
+                .map_err(|e| format!("{flag} {value}: {e}"))?;
+            match flag.as_str() {
+                "--models" => options.models = number,
+                "--shards" => options.shards = number,
+                "--shard-gb" => options.shard_gb = number,
+                "--runs" => options.runs = number.max(1),
+                _ => return Err(format!("unknown option {flag}")),
+            }
+        }
+        Ok(options)
+    }
+}
+
+/// GGUF metadata, serialized as it is written.
+#[derive(Default)]
+struct Metadata {
+    bytes: Vec<u8>,
+    count: u64,
+}
+
+impl Metadata {
+    fn key(&mut self, key: &str, value_type: u32) -> &mut Vec<u8> {
+        self.count += 1;
+        push_str(&mut self.bytes, key);
+        self.bytes.extend(value_type.to_le_bytes());
+        &mut self.bytes
+    }
+
+    fn string(&mut self, key: &str, value: &str) {
+        push_str(self.key(key, 8), value);
+    }
+
+    fn u32(&mut self, key: &str, value: u32) {
+        self.key(key, 4).extend(value.to_le_bytes());
+    }
+
+    fn array(&mut self, key: &str, element_type: u32, len: u32, element: impl Fn(u32) -> Vec<u8>) {
+        let bytes = self.key(key, 9);
+        bytes.extend(element_type.to_le_bytes());
+        bytes.extend(u64::from(len).to_le_bytes());
+        for i in 0..len {
+            bytes.extend(element(i));
+        }
+    }
+}
+
+fn push_str(bytes: &mut Vec<u8>, s: &str) {
+    bytes.extend((s.len() as u64).to_le_bytes());
+    bytes.extend(s.as_bytes());
+}
+
+fn string_bytes(s: &str) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    push_str(&mut bytes, s);
+    bytes
+}
+
+/// Metadata of one shard; the first carries the model's full metadata.
+fn shard_metadata(model: u64, shard: u64, shards: u64) -> Metadata {
+    let mut metadata = Metadata::default();
+    metadata.string("general.architecture", "qwen2");
+    metadata.string("general.name", &format!("Bench Model {model}"));
+    metadata.u32("split.no", u32::try_from(shard).unwrap());
+    metadata.u32("split.count", u32::try_from(shards).unwrap());
+    if shard > 0 {
+        return metadata;
+    }
+    metadata.u32("qwen2.context_length", 131_072);
+    metadata.u32("qwen2.block_count", 80);
+    metadata.string("general.size_label", "72B");
+    metadata.string("tokenizer.ggml.model", "gpt2");
+    metadata.array("tokenizer.ggml.tokens", 8, VOCAB, |i| {
+        string_bytes(&format!("token-{i}"))
+    });
+    metadata.array("tokenizer.ggml.token_type", 5, VOCAB, |i| {
+        (i32::from(i % 7 == 0) + 1).to_le_bytes().to_vec()
+    });
+    metadata.array("tokenizer.ggml.merges", 8, VOCAB - 677, |i| {
+        string_bytes(&format!("tok{} en{}", i % 997, i % 991))
+    });
+    metadata.string(
+        "tokenizer.chat_template",
+        &"{% for message in messages %}{{ message.content }}{% endfor %}".repeat(64),
+    );
+    metadata
+}
+
+fn write_shard(path: &Path, metadata: &Metadata, size: u64) -> std::io::Result<()> {
+    let mut file = File::create(path)?;
+    file.write_all(b"GGUF")?;
+    file.write_all(&3u32.to_le_bytes())?;
+    file.write_all(&963u64.to_le_bytes())?;
+    file.write_all(&metadata.count.to_le_bytes())?;
+    file.write_all(&metadata.bytes)?;
+    // The tensor data: a hole, so the library costs no disk.
+    file.set_len(size)
+}
+
+fn write_library(dir: &Path, options: &Options) -> std::io::Result<Vec<PathBuf>> {
+    let size = options.shard_gb << 30;
+    let mut paths = Vec::new();
+    for model in 0..options.models {
+        for shard in 0..options.shards {
+            let path = dir.join(format!(
+                "bench-{model}-Q4_K_M-{:05}-of-{:05}.gguf",
+                shard + 1,
+                options.shards
+            ));
+            write_shard(&path, &shard_metadata(model, shard, options.shards), size)?;
+            paths.push(path);
+        }
+    }
+    Ok(paths)
+}
+
+fn scan(paths: &[PathBuf]) -> Result<Duration, String> {
+    let parser = GgufParser::new();
+    let started = Instant::now();
+    for path in paths {
+        let metadata = parser
+            .parse(path)
+            .map_err(|e| format!("{}: {e}", path.display()))?;
+        std::hint::black_box(metadata);
+    }
+    Ok(started.elapsed())
+}
+
+fn main() -> ExitCode {
+    let options = match Options::parse() {
+        Ok(options) => options,
+        Err(e) => {
+            eprintln!("{e}\nusage: scan_bench [--models N] [--shards N] [--shard-gb N] [--runs N]");
+            return ExitCode::FAILURE;
+        }
+    };
+    let dir = match tempfile::tempdir() {
+        Ok(dir) => dir,
+        Err(e) => {
+            eprintln!("creating a scratch directory: {e}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let paths = match write_library(dir.path(), &options) {
+        Ok(paths) => paths,
+        Err(e) => {
+            eprintln!("writing the library: {e}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let reader = if cfg!(feature = "mmap") {
+        "mmap"
+    } else {
+        "buffered"
+    };
+    println!(
+        "{reader} reader: {} shards, {} GB",
+        paths.len(),
+        paths.len() as u64 * options.shard_gb
+    );
+    let mut times = Vec::new();
+    for run in 1..=options.runs {
+        match scan(&paths) {
+            Ok(time) => {
+                println!("run {run}: {time:.1?}");
+                times.push(time);
+            }
+            Err(e) => {
+                eprintln!("{e}");
+                return ExitCode::FAILURE;
+            }
+        }
+    }
+    times.sort();
+    println!(
+        "fastest {:.1?}, median {:.1?}",
+        times[0],
+        times[times.len() / 2]
+    );
+    ExitCode::SUCCESS
+}
@@ -0,0 +1,152 @@
+//! Embedding and reranker capability detection.
+//!
+//! Detects models that produce vectors or relevance scores rather than chat
+//! completions, so they can be kept out of chat model pickers and routed to
+//! embedding/rerank endpoints instead.
+//!
+//! # Detection Strategy
+//!
+//! Two metadata signals are consulted, strongest first:
+//!
+//! 1. **`{arch}.pooling_type`** — llama.cpp writes this key for every model
+//!    converted with a pooling head. `4` (`LLAMA_POOLING_TYPE_RANK`) marks a
+//!    reranker; any other non-zero value (mean, CLS, last-token) marks an
+//!    embedding model.
+//! 2. **`general.architecture`** — encoder-only families (`bert`,
+//!    `nomic-bert`, `jina-bert-v2`, …) cannot generate text at all, so they
+//!    are tagged as embedding models even when the pooling key is missing.
+//!
+//! As with MTP detection, no model-name heuristics are used: a decoder model
+//! whose name happens to contain "embed" is still a chat model.
+
+use std::collections::HashMap;
+
+/// `LLAMA_POOLING_TYPE_RANK` as written to `{arch}.pooling_type`.
+const POOLING_TYPE_RANK: u32 = 4;
+
+/// Encoder-only architectures that never produce chat completions.
+const EMBEDDING_ARCHITECTURES: &[&str] = &[
+    "bert",
+    "nomic-bert",
+    "nomic-bert-moe",
+    "jina-bert-v2",
+    "jina-bert-v3",
+    "modern-bert",
+    "neo-bert",
+    "t5encoder",
+];
+
+/// What a model is for, as far as embedding detection is concerned.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum EmbeddingKind {
+    /// Regular generative model (no pooling head detected).
+    #[default]
+    None,
+    /// Produces embedding vectors (`/v1/embeddings`).
+    Embedding,
+    /// Produces query/document relevance scores (`/v1/rerank`).
+    Reranker,
+}
+
+/// Detect whether a model is an embedding or reranker model.
+#[must_use]
+pub fn detect_embedding_support(metadata: &HashMap<String, String>) -> EmbeddingKind {
+    let arch = metadata.get("general.architecture").map(String::as_str);
+
+    match pooling_type(metadata, arch) {
+        Some(POOLING_TYPE_RANK) => return EmbeddingKind::Reranker,
+        Some(n) if n > 0 => return EmbeddingKind::Embedding,
+        _ => {}
+    }
+
+    if arch.is_some_and(|arch| EMBEDDING_ARCHITECTURES.contains(&arch)) {
+        EmbeddingKind::Embedding
+    } else {
+        EmbeddingKind::None
+    }
+}
+
+/// Read the pooling type for the model's own architecture.
+///
+/// Looks up `{arch}.pooling_type` when `general.architecture` is known, so a
+/// stray key for another architecture (e.g. a bundled encoder) is ignored.
+/// Falls back to the first `*.pooling_type` key only when the architecture
+/// itself is missing.
+fn pooling_type(metadata: &HashMap<String, String>, arch: Option<&str>) -> Option<u32> {
+    let value = arch.map_or_else(
+        || {
+            metadata
+                .iter()
+                .find(|(key, _)| key.ends_with(".pooling_type"))
+                .map(|(_, value)| value)
+        },
+        |arch| metadata.get(&format!("{arch}.pooling_type")),
+    );
+    value.and_then(|v| v.parse::<u32>().ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn meta(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+        pairs
+            .iter()
+            .map(|(k, v)| ((*k).to_string(), (*v).to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn mean_pooling_is_embedding() {
+        let m = meta(&[
+            ("general.architecture", "qwen3"),
+            ("qwen3.pooling_type", "1"),
+        ]);
+        assert_eq!(detect_embedding_support(&m), EmbeddingKind::Embedding);
+    }
+
+    #[test]
+    fn rank_pooling_is_reranker() {
+        let m = meta(&[("general.architecture", "bert"), ("bert.pooling_type", "4")]);
+        assert_eq!(detect_embedding_support(&m), EmbeddingKind::Reranker);
+    }
+
+    #[test]
+    fn encoder_architecture_without_pooling_is_embedding() {
+        let m = meta(&[("general.architecture", "nomic-bert")]);
+        assert_eq!(detect_embedding_support(&m), EmbeddingKind::Embedding);
+    }
+
+    #[test]
+    fn decoder_without_pooling_is_not_embedding() {
+        let m = meta(&[
+            ("general.architecture", "llama"),
+            ("general.name", "nomic-embed-lookalike"),
+        ]);
+        assert_eq!(detect_embedding_support(&m), EmbeddingKind::None);
+    }
+
+    #[test]
+    fn zero_pooling_is_not_embedding() {
+        let m = meta(&[
+            ("general.architecture", "llama"),
+            ("llama.pooling_type", "0"),
+        ]);
+        assert_eq!(detect_embedding_support(&m), EmbeddingKind::None);
+    }
+
+    #[test]
+    fn pooling_key_for_another_architecture_is_ignored() {
+        let m = meta(&[
+            ("general.architecture", "llama"),
+            ("bert.pooling_type", "4"),
+        ]);
+        assert_eq!(detect_embedding_support(&m), EmbeddingKind::None);
+    }
+
+    #[test]
+    fn pooling_key_without_architecture_falls_back_to_suffix_scan() {
+        let m = meta(&[("qwen3.pooling_type", "3")]);
+        assert_eq!(detect_embedding_support(&m), EmbeddingKind::Embedding);
+    }
+}
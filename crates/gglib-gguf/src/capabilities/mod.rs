@@ -1,4 +1,5 @@
 #![doc = include_str!("README.md")]
+mod embedding;
 mod mtp;
 mod patterns;
 mod reasoning;
@@ -9,6 +10,7 @@ use std::collections::HashMap;
 use gglib_core::GgufCapabilities;
 use gglib_core::domain::gguf::CapabilityFlags;
 
+use embedding::{EmbeddingKind, detect_embedding_support};
 use mtp::detect_mtp_support;
 use reasoning::detect_reasoning_support;
 use tool_calling::detect_tool_support;
@@ -39,6 +41,13 @@ pub fn detect_all(metadata: &HashMap<String, String>) -> GgufCapabilities {
         flags |= CapabilityFlags::MTP;
     }
 
+    // Detect embedding / reranker models (pooling head or encoder-only arch)
+    match detect_embedding_support(metadata) {
+        EmbeddingKind::Embedding => flags |= CapabilityFlags::EMBEDDING,
+        EmbeddingKind::Reranker => flags |= CapabilityFlags::RERANKER,
+        EmbeddingKind::None => {}
+    }
+
     // Surface the detected dialect as a `format:*` extension tag so the
     // normalization pipeline can pick a parser without re-deriving the
     // detection at runtime.  Only emit when tool-calling is actually
@@ -109,6 +118,21 @@ mod tests {
         assert!(tags.contains(&"agent".to_string()));
     }
 
+    #[test]
+    fn test_detect_all_embedding_and_reranker() {
+        let mut metadata = HashMap::new();
+        metadata.insert("general.architecture".to_string(), "bert".to_string());
+        let caps = detect_all(&metadata);
+        assert!(caps.has_embedding());
+        assert!(caps.to_tags().contains(&"embedding".to_string()));
+
+        metadata.insert("bert.pooling_type".to_string(), "4".to_string());
+        let caps = detect_all(&metadata);
+        assert!(caps.has_reranker());
+        assert!(!caps.has_embedding());
+        assert!(caps.to_tags().contains(&"reranker".to_string()));
+    }
+
     #[test]
     fn test_detect_all_emits_hermes_format_tag() {
         let mut metadata = HashMap::new();
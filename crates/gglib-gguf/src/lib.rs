@@ -1,14 +1,15 @@
 #![doc = include_str!(concat!(env!("OUT_DIR"), "/README_GENERATED.md"))]
 #![deny(unused_crate_dependencies)]
 
-// Silence unused dependency warnings for optional/future use
-#[cfg(feature = "mmap")]
-use memmap2 as _;
+// Only the mmap fallback logs.
+#[cfg(not(feature = "mmap"))]
 use tracing as _;
 
 mod capabilities;
 mod error;
 mod format;
+#[cfg(feature = "mmap")]
+mod mmap;
 mod parser;
 mod reader;
 mod validation;
@@ -20,6 +21,10 @@ mod validation;
 /// The GGUF parser implementation.
 pub use parser::GgufParser;
 
+/// Memory-mapped access to a file's metadata, with arrays decoded on demand.
+#[cfg(feature = "mmap")]
+pub use mmap::{GgufFile, LazyArray, MetadataValue};
+
 // Re-export domain types and port from core for convenience
 pub use gglib_core::domain::gguf::GgufValue;
 pub use gglib_core::{GgufCapabilities, GgufMetadata, GgufParseError, GgufParserPort};
@@ -0,0 +1,483 @@
+//! Memory-mapped GGUF reading.
+//!
+//! [`GgufFile`] maps a file and indexes its metadata in place: opening it
+//! walks the header once, recording where each key and value lives, and
+//! copies nothing. Values are decoded when asked for. Arrays — tokenizer
+//! vocabularies run to hundreds of thousands of entries — stay as
+//! [`LazyArray`]s, whose length is known up front and whose elements are
+//! only decoded by [`LazyArray::materialize`].
+//!
+//! Only the pages the header occupies are ever touched, so opening a shard
+//! costs the same whether it holds 1 GB of tensors or 50.
+
+use std::fs::File;
+use std::io;
+use std::ops::Range;
+use std::path::Path;
+
+use gglib_core::GgufParseError;
+use gglib_core::domain::gguf::GgufValue;
+use memmap2::Mmap;
+
+use crate::error::{GgufInternalError, GgufResult};
+use crate::format::GGUF_MAGIC;
+
+/// Value type code of a string.
+const STRING: u32 = 8;
+/// Value type code of an array.
+const ARRAY: u32 = 9;
+
+/// A GGUF file mapped into memory, with its metadata indexed.
+pub struct GgufFile {
+    map: Mmap,
+    version: u32,
+    tensor_count: u64,
+    entries: Vec<Entry>,
+}
+
+/// Where one metadata key-value pair lives in the map.
+struct Entry {
+    key: Range<usize>,
+    value_type: u32,
+    /// The value's bytes, after its type code.
+    value: Range<usize>,
+}
+
+/// A metadata value: decoded if it is a scalar or string, lazy if it is an
+/// array.
+#[derive(Debug, Clone)]
+pub enum MetadataValue<'a> {
+    /// A scalar or string.
+    Value(GgufValue),
+    /// An array, not yet decoded.
+    Array(LazyArray<'a>),
+}
+
+/// An array in the mapped file whose elements have not been decoded.
+#[derive(Debug, Clone, Copy)]
+pub struct LazyArray<'a> {
+    element_type: u32,
+    len: usize,
+    /// The elements' bytes.
+    data: &'a [u8],
+}
+
+impl GgufFile {
+    /// Map a GGUF file and index its metadata.
+    pub fn open(path: &Path) -> Result<Self, GgufParseError> {
+        Self::open_internal(path).map_err(Into::into)
+    }
+
+    pub(crate) fn open_internal(path: &Path) -> GgufResult<Self> {
+        let file = File::open(path).map_err(|e| {
+            if e.kind() == io::ErrorKind::NotFound {
+                GgufInternalError::FileNotFound(path.display().to_string())
+            } else {
+                GgufInternalError::Io(e)
+            }
+        })?;
+        // SAFETY: the map is only read, and every read is bounds-checked
+        // against its length. A file truncated by another process while
+        // mapped can still fault on access; model files are written once
+        // and then only read, and the buffered reader has the same exposure
+        // to files changing under it, just without the fault.
+        #[allow(unsafe_code)]
+        let map =
+            unsafe { Mmap::map(&file) }.map_err(|e| GgufInternalError::MmapError(e.to_string()))?;
+        Self::index(map)
+    }
+
+    fn index(map: Mmap) -> GgufResult<Self> {
+        let mut cursor = Cursor::new(&map);
+        if cursor.take(4)? != GGUF_MAGIC {
+            return Err(GgufInternalError::InvalidMagic);
+        }
+        let version = cursor.u32()?;
+        if !(1..=3).contains(&version) {
+            return Err(GgufInternalError::UnsupportedVersion(version));
+        }
+        let tensor_count = cursor.count(version)?;
+        let metadata_count = cursor.count(version)?;
+
+        // Each entry takes at least 12 bytes, which bounds a corrupt count.
+        let capacity = usize::try_from(metadata_count)
+            .unwrap_or(usize::MAX)
+            .min(cursor.remaining() / 12);
+        let mut entries = Vec::with_capacity(capacity);
+        for _ in 0..metadata_count {
+            let key = cursor.string_range()?;
+            let value_type = cursor.u32()?;
+            let start = cursor.pos;
+            cursor.skip_value(value_type)?;
+            entries.push(Entry {
+                key,
+                value_type,
+                value: start..cursor.pos,
+            });
+        }
+
+        Ok(Self {
+            map,
+            version,
+            tensor_count,
+            entries,
+        })
+    }
+
+    /// The GGUF format version (1 to 3).
+    pub const fn version(&self) -> u32 {
+        self.version
+    }
+
+    /// Number of tensors the file declares.
+    pub const fn tensor_count(&self) -> u64 {
+        self.tensor_count
+    }
+
+    /// Number of metadata entries.
+    pub const fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Whether the file has no metadata.
+    pub const fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// The metadata, in file order.
+    pub fn entries(&self) -> impl Iterator<Item = (&str, MetadataValue<'_>)> {
+        self.entries
+            .iter()
+            .map(|entry| (self.key(entry), self.value(entry)))
+    }
+
+    /// The value stored under `key`, if any.
+    pub fn get(&self, key: &str) -> Option<MetadataValue<'_>> {
+        self.entries
+            .iter()
+            .find(|entry| self.key(entry) == key)
+            .map(|entry| self.value(entry))
+    }
+
+    fn key(&self, entry: &Entry) -> &str {
+        std::str::from_utf8(&self.map[entry.key.clone()]).expect("keys are validated on open")
+    }
+
+    fn value(&self, entry: &Entry) -> MetadataValue<'_> {
+        let bytes = &self.map[entry.value.clone()];
+        let mut cursor = Cursor::new(bytes);
+        if entry.value_type == ARRAY {
+            // The entry's range already ends where the elements do.
+            let (element_type, len) = cursor.array_header().expect("validated on open");
+            MetadataValue::Array(LazyArray {
+                element_type,
+                len,
+                data: &bytes[cursor.pos..],
+            })
+        } else {
+            let value = cursor.value(entry.value_type).expect("validated on open");
+            MetadataValue::Value(value)
+        }
+    }
+}
+
+impl LazyArray<'_> {
+    /// Number of elements.
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether the array has no elements.
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Decode every element into a [`GgufValue::Array`].
+    pub fn materialize(&self) -> Result<GgufValue, GgufParseError> {
+        self.decode().map_err(Into::into)
+    }
+
+    pub(crate) fn decode(&self) -> GgufResult<GgufValue> {
+        let mut cursor = Cursor::new(self.data);
+        let elements = (0..self.len)
+            .map(|_| cursor.value(self.element_type))
+            .collect::<GgufResult<_>>()?;
+        Ok(GgufValue::Array(elements))
+    }
+}
+
+/// Bounds-checked little-endian reads over a byte slice.
+struct Cursor<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Cursor<'a> {
+    const fn new(data: &'a [u8]) -> Self {
+        Self { data, pos: 0 }
+    }
+
+    const fn remaining(&self) -> usize {
+        self.data.len() - self.pos
+    }
+
+    fn take(&mut self, n: usize) -> GgufResult<&'a [u8]> {
+        if n > self.remaining() {
+            return Err(truncated());
+        }
+        let bytes = &self.data[self.pos..self.pos + n];
+        self.pos += n;
+        Ok(bytes)
+    }
+
+    fn array<const N: usize>(&mut self) -> GgufResult<[u8; N]> {
+        let mut buf = [0u8; N];
+        buf.copy_from_slice(self.take(N)?);
+        Ok(buf)
+    }
+
+    fn u32(&mut self) -> GgufResult<u32> {
+        Ok(u32::from_le_bytes(self.array()?))
+    }
+
+    fn u64(&mut self) -> GgufResult<u64> {
+        Ok(u64::from_le_bytes(self.array()?))
+    }
+
+    /// A length prefix that no slice of this data could satisfy is
+    /// truncated, whatever it claims.
+    fn length(&mut self) -> GgufResult<usize> {
+        usize::try_from(self.u64()?).map_err(|_| truncated())
+    }
+
+    /// A tensor or metadata count: 32-bit in version 1, 64-bit after.
+    fn count(&mut self, version: u32) -> GgufResult<u64> {
+        if version >= 2 {
+            self.u64()
+        } else {
+            self.u32().map(u64::from)
+        }
+    }
+
+    fn str(&mut self) -> GgufResult<&'a str> {
+        let len = self.length()?;
+        std::str::from_utf8(self.take(len)?).map_err(|_| GgufInternalError::Utf8Error)
+    }
+
+    /// A validated string's position, without copying it.
+    fn string_range(&mut self) -> GgufResult<Range<usize>> {
+        let len = self.length()?;
+        let start = self.pos;
+        std::str::from_utf8(self.take(len)?).map_err(|_| GgufInternalError::Utf8Error)?;
+        Ok(start..self.pos)
+    }
+
+    /// An array's element type and length.
+    fn array_header(&mut self) -> GgufResult<(u32, usize)> {
+        Ok((self.u32()?, self.length()?))
+    }
+
+    /// Step over an array's elements, validating them. Fixed-width elements
+    /// are skipped in one step.
+    fn lazy_array(&mut self) -> GgufResult<LazyArray<'a>> {
+        let (element_type, len) = self.array_header()?;
+        let start = self.pos;
+        match fixed_width(element_type) {
+            Some(width) => {
+                let size = len.checked_mul(width).ok_or_else(truncated)?;
+                self.take(size)?;
+            }
+            None => {
+                for _ in 0..len {
+                    self.skip_value(element_type)?;
+                }
+            }
+        }
+        Ok(LazyArray {
+            element_type,
+            len,
+            data: &self.data[start..self.pos],
+        })
+    }
+
+    /// Step over a value, validating it without decoding it.
+    fn skip_value(&mut self, value_type: u32) -> GgufResult<()> {
+        match value_type {
+            STRING => self.str().map(drop),
+            ARRAY => self.lazy_array().map(drop),
+            _ => {
+                let width = fixed_width(value_type)
+                    .ok_or(GgufInternalError::InvalidValueType(value_type))?;
+                self.take(width).map(drop)
+            }
+        }
+    }
+
+    #[allow(clippy::cast_possible_wrap)]
+    fn value(&mut self, value_type: u32) -> GgufResult<GgufValue> {
+        Ok(match value_type {
+            0 => GgufValue::U8(self.array::<1>()?[0]),
+            1 => GgufValue::I8(self.array::<1>()?[0] as i8),
+            2 => GgufValue::U16(u16::from_le_bytes(self.array()?)),
+            3 => GgufValue::I16(i16::from_le_bytes(self.array()?)),
+            4 => GgufValue::U32(self.u32()?),
+            5 => GgufValue::I32(i32::from_le_bytes(self.array()?)),
+            6 => GgufValue::F32(f32::from_le_bytes(self.array()?)),
+            7 => GgufValue::Bool(self.array::<1>()?[0] != 0),
+            STRING => GgufValue::String(self.str()?.to_owned()),
+            ARRAY => return self.lazy_array()?.decode(),
+            10 => GgufValue::U64(self.u64()?),
+            11 => GgufValue::I64(i64::from_le_bytes(self.array()?)),
+            12 => GgufValue::F64(f64::from_le_bytes(self.array()?)),
+            _ => return Err(GgufInternalError::InvalidValueType(value_type)),
+        })
+    }
+}
+
+/// The data ends before a value it declares does.
+fn truncated() -> GgufInternalError {
+    io::Error::from(io::ErrorKind::UnexpectedEof).into()
+}
+
+/// Size in bytes of a fixed-width value type.
+const fn fixed_width(value_type: u32) -> Option<usize> {
+    match value_type {
+        0 | 1 | 7 => Some(1),
+        2 | 3 => Some(2),
+        4..=6 => Some(4),
+        10..=12 => Some(8),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+
+    fn push_str(bytes: &mut Vec<u8>, s: &str) {
+        bytes.extend((s.len() as u64).to_le_bytes());
+        bytes.extend(s.as_bytes());
+    }
+
+    /// A version 3 file with a name, a context length, a 200-token
+    /// vocabulary and a three-element array.
+    pub fn sample_gguf() -> Vec<u8> {
+        let mut bytes = GGUF_MAGIC.to_vec();
+        bytes.extend(3u32.to_le_bytes());
+        bytes.extend(7u64.to_le_bytes()); // tensors
+        bytes.extend(4u64.to_le_bytes()); // metadata entries
+
+        push_str(&mut bytes, "general.name");
+        bytes.extend(STRING.to_le_bytes());
+        push_str(&mut bytes, "Sample");
+
+        push_str(&mut bytes, "llama.context_length");
+        bytes.extend(4u32.to_le_bytes());
+        bytes.extend(4096u32.to_le_bytes());
+
+        push_str(&mut bytes, "tokenizer.ggml.tokens");
+        bytes.extend(ARRAY.to_le_bytes());
+        bytes.extend(STRING.to_le_bytes());
+        bytes.extend(200u64.to_le_bytes());
+        for i in 0..200 {
+            push_str(&mut bytes, &format!("tok{i}"));
+        }
+
+        push_str(&mut bytes, "llama.rope.dims");
+        bytes.extend(ARRAY.to_le_bytes());
+        bytes.extend(4u32.to_le_bytes());
+        bytes.extend(3u64.to_le_bytes());
+        for dim in [64u32, 32, 32] {
+            bytes.extend(dim.to_le_bytes());
+        }
+        bytes
+    }
+
+    fn open(bytes: &[u8]) -> GgufResult<GgufFile> {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("model.gguf");
+        std::fs::write(&path, bytes).unwrap();
+        GgufFile::open_internal(&path)
+    }
+
+    #[test]
+    fn indexes_metadata_in_file_order() {
+        let file = open(&sample_gguf()).unwrap();
+        assert_eq!(file.version(), 3);
+        assert_eq!(file.tensor_count(), 7);
+        let keys: Vec<_> = file.entries().map(|(key, _)| key.to_owned()).collect();
+        assert_eq!(
+            keys,
+            [
+                "general.name",
+                "llama.context_length",
+                "tokenizer.ggml.tokens",
+                "llama.rope.dims"
+            ]
+        );
+        assert!(matches!(
+            file.get("general.name"),
+            Some(MetadataValue::Value(GgufValue::String(name))) if name == "Sample"
+        ));
+        assert!(matches!(
+            file.get("llama.context_length"),
+            Some(MetadataValue::Value(GgufValue::U32(4096)))
+        ));
+        assert!(file.get("missing").is_none());
+    }
+
+    #[test]
+    fn arrays_are_decoded_only_when_materialized() {
+        let file = open(&sample_gguf()).unwrap();
+        let Some(MetadataValue::Array(tokens)) = file.get("tokenizer.ggml.tokens") else {
+            panic!("expected an array");
+        };
+        assert_eq!(tokens.len(), 200);
+
+        let GgufValue::Array(elements) = tokens.materialize().unwrap() else {
+            panic!("expected an array");
+        };
+        assert_eq!(elements.len(), 200);
+        assert!(matches!(&elements[199], GgufValue::String(s) if s == "tok199"));
+
+        let Some(MetadataValue::Array(dims)) = file.get("llama.rope.dims") else {
+            panic!("expected an array");
+        };
+        assert_eq!(dims.materialize().unwrap().to_string(), "[64, 32, 32]");
+    }
+
+    #[test]
+    fn a_truncated_file_is_an_error_not_a_panic() {
+        let bytes = sample_gguf();
+        for len in [3, 10, 30, bytes.len() / 2, bytes.len() - 1] {
+            assert!(open(&bytes[..len]).is_err(), "accepted {len} bytes");
+        }
+    }
+
+    #[test]
+    fn a_huge_declared_length_is_truncation() {
+        let mut bytes = GGUF_MAGIC.to_vec();
+        bytes.extend(3u32.to_le_bytes());
+        bytes.extend(0u64.to_le_bytes());
+        bytes.extend(1u64.to_le_bytes());
+        push_str(&mut bytes, "tokenizer.ggml.scores");
+        bytes.extend(ARRAY.to_le_bytes());
+        bytes.extend(6u32.to_le_bytes());
+        bytes.extend(u64::MAX.to_le_bytes());
+        assert!(matches!(open(&bytes), Err(GgufInternalError::Io(_))));
+    }
+
+    #[test]
+    fn rejects_bad_magic_and_version() {
+        let mut bytes = sample_gguf();
+        bytes[0] = b'X';
+        assert!(matches!(open(&bytes), Err(GgufInternalError::InvalidMagic)));
+
+        let mut bytes = sample_gguf();
+        bytes[4] = 9;
+        assert!(matches!(
+            open(&bytes),
+            Err(GgufInternalError::UnsupportedVersion(9))
+        ));
+    }
+}
@@ -13,6 +13,15 @@ use crate::capabilities;
 use crate::error::GgufResult;
 use crate::format::{CONTEXT_LENGTH_KEYS, quantization};
 use crate::reader::GgufReader;
+#[cfg(feature = "mmap")]
+use crate::{
+    error::GgufInternalError,
+    mmap::{GgufFile, MetadataValue},
+};
+
+/// Arrays longer than this are summarized by length in the processed
+/// metadata; the mapped reader never decodes them.
+const SUMMARIZED_ARRAY_LEN: usize = 100;
 
 /// GGUF file parser.
 ///
@@ -29,40 +38,87 @@ impl GgufParser {
     }
 
     /// Internal parse implementation that returns rich internal errors.
+    ///
+    /// Maps the file when the `mmap` feature is on, falling back to
+    /// buffered reads where the file can't be mapped (some network and FUSE
+    /// filesystems).
     #[allow(clippy::unused_self)]
     fn parse_internal(&self, file_path: &Path) -> GgufResult<GgufMetadata> {
-        let mut reader = GgufReader::open(file_path)?;
-
-        // Read and validate header
-        reader.read_magic()?;
-        let version = reader.read_version()?;
-
-        // Read tensor count (not used but must be read)
-        let _tensor_count = if version >= 2 {
-            reader.read_u64()?
-        } else {
-            u64::from(reader.read_u32()?)
-        };
-
-        // Read metadata count
-        let metadata_count = if version >= 2 {
-            reader.read_u64()?
-        } else {
-            u64::from(reader.read_u32()?)
-        };
-
-        // Parse metadata key-value pairs
-        let mut raw_metadata = HashMap::new();
-        for _ in 0..metadata_count {
-            let key = reader.read_string()?;
-            let value_type = reader.read_u32()?;
-            let value = reader.read_value(value_type)?;
-            raw_metadata.insert(key, value);
+        #[cfg(feature = "mmap")]
+        match GgufFile::open_internal(file_path) {
+            Ok(file) => {
+                let (raw, unread) = read_mapped(&file)?;
+                return Ok(extract_metadata(&raw, &unread, file_path));
+            }
+            Err(GgufInternalError::MmapError(e)) => {
+                tracing::debug!(
+                    path = %file_path.display(),
+                    error = %e,
+                    "Can't map GGUF file; reading it buffered"
+                );
+            }
+            Err(e) => return Err(e),
         }
 
-        // Extract structured metadata
-        Ok(extract_metadata(&raw_metadata, file_path))
+        let raw = read_buffered(file_path)?;
+        Ok(extract_metadata(&raw, &HashMap::new(), file_path))
+    }
+}
+
+/// Read every metadata value through a buffered reader.
+fn read_buffered(file_path: &Path) -> GgufResult<RawMetadata> {
+    let mut reader = GgufReader::open(file_path)?;
+
+    // Read and validate header
+    reader.read_magic()?;
+    let version = reader.read_version()?;
+
+    // Read tensor count (not used but must be read)
+    let _tensor_count = if version >= 2 {
+        reader.read_u64()?
+    } else {
+        u64::from(reader.read_u32()?)
+    };
+
+    // Read metadata count
+    let metadata_count = if version >= 2 {
+        reader.read_u64()?
+    } else {
+        u64::from(reader.read_u32()?)
+    };
+
+    // Parse metadata key-value pairs
+    let mut raw_metadata = HashMap::new();
+    for _ in 0..metadata_count {
+        let key = reader.read_string()?;
+        let value_type = reader.read_u32()?;
+        let value = reader.read_value(value_type)?;
+        raw_metadata.insert(key, value);
+    }
+
+    Ok(raw_metadata)
+}
+
+/// Read a mapped file's metadata, leaving arrays that would only be
+/// summarized undecoded; their lengths are returned alongside.
+#[cfg(feature = "mmap")]
+fn read_mapped(file: &GgufFile) -> GgufResult<(RawMetadata, HashMap<String, usize>)> {
+    let mut raw = HashMap::new();
+    let mut unread = HashMap::new();
+    for (key, value) in file.entries() {
+        match value {
+            MetadataValue::Value(value) => {
+                raw.insert(key.to_owned(), value);
+            }
+            MetadataValue::Array(array) if array.len() > SUMMARIZED_ARRAY_LEN => {
+                unread.insert(key.to_owned(), array.len());
+            }
+            MetadataValue::Array(array) => {
+                raw.insert(key.to_owned(), array.decode()?);
+            }
+        }
     }
+    Ok((raw, unread))
 }
 
 impl GgufParserPort for GgufParser {
@@ -80,22 +136,30 @@ impl GgufParserPort for GgufParser {
 // =============================================================================
 
 /// Extract structured metadata from raw GGUF key-value pairs.
-fn extract_metadata(raw: &RawMetadata, file_path: &Path) -> GgufMetadata {
+///
+/// `unread` holds the lengths of arrays that were never decoded; they are
+/// summarized exactly as they would have been if they had been.
+fn extract_metadata(
+    raw: &RawMetadata,
+    unread: &HashMap<String, usize>,
+    file_path: &Path,
+) -> GgufMetadata {
     let mut processed = HashMap::new();
 
     // Convert metadata to string representation, skipping large arrays
     for (key, value) in raw {
-        if key.starts_with("tokenizer.")
-            && matches!(value, GgufValue::Array(arr) if arr.len() > 100)
-        {
-            // Store just a summary for large tokenizer arrays
-            if let GgufValue::Array(arr) = value {
-                processed.insert(key.clone(), format!("Array with {} elements", arr.len()));
+        match value {
+            GgufValue::Array(arr) if arr.len() > SUMMARIZED_ARRAY_LEN => {
+                processed.insert(key.clone(), array_summary(key, arr.len()));
+            }
+            _ => {
+                processed.insert(key.clone(), value.to_string());
             }
-        } else {
-            processed.insert(key.clone(), value.to_string());
         }
     }
+    for (key, &len) in unread {
+        processed.insert(key.clone(), array_summary(key, len));
+    }
 
     // Extract fields
     let name = extract_name(raw, file_path);
@@ -121,6 +185,17 @@ fn extract_metadata(raw: &RawMetadata, file_path: &Path) -> GgufMetadata {
     }
 }
 
+/// Summary of an array longer than [`SUMMARIZED_ARRAY_LEN`].
+fn array_summary(key: &str, len: usize) -> String {
+    // Tokenizer vocabularies have always been summarized without brackets;
+    // anything else matches `GgufValue`'s display of a long array.
+    if key.starts_with("tokenizer.") {
+        format!("Array with {len} elements")
+    } else {
+        format!("[Array with {len} elements]")
+    }
+}
+
 /// Extract model name from metadata or filename.
 fn extract_name(raw: &RawMetadata, file_path: &Path) -> Option<String> {
     raw.get("general.name")
@@ -418,6 +493,30 @@ mod tests {
         assert_eq!(shared, None);
     }
 
+    #[cfg(feature = "mmap")]
+    #[test]
+    fn mapped_and_buffered_reads_agree() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("sample-7B-Q4_K_M.gguf");
+        std::fs::write(&path, crate::mmap::tests::sample_gguf()).unwrap();
+
+        let file = GgufFile::open_internal(&path).unwrap();
+        let (raw, unread) = read_mapped(&file).unwrap();
+        assert_eq!(unread.get("tokenizer.ggml.tokens"), Some(&200));
+        let mapped = extract_metadata(&raw, &unread, &path);
+        let buffered = extract_metadata(&read_buffered(&path).unwrap(), &HashMap::new(), &path);
+
+        assert_eq!(mapped.metadata, buffered.metadata);
+        assert_eq!(
+            mapped.metadata["tokenizer.ggml.tokens"],
+            "Array with 200 elements"
+        );
+        assert_eq!(mapped.metadata["llama.rope.dims"], "[64, 32, 32]");
+        assert_eq!(mapped.name, buffered.name);
+        assert_eq!(mapped.context_length, Some(4096));
+        assert_eq!(mapped.quantization, buffered.quantization);
+    }
+
     #[test]
     fn test_extract_quantization_from_filename() {
         assert_eq!(
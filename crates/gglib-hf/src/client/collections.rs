@@ -0,0 +1,49 @@
+//! Collection lookup for the `HuggingFace` client.
+
+use crate::error::HfResult;
+use crate::http::HttpBackend;
+use crate::models::HfCollectionSummary;
+use crate::parsing::parse_collection;
+use crate::url::build_collection_url;
+
+use super::HfClient;
+
+impl<B: HttpBackend> HfClient<B> {
+    /// Fetch a collection and the model repositories it lists.
+    pub(crate) async fn get_collection(&self, slug: &str) -> HfResult<HfCollectionSummary> {
+        let url = build_collection_url(&self.config, slug);
+        let json: serde_json::Value = self.backend.get_json(&url).await?;
+        parse_collection(&json)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::tests::test_config;
+    use crate::http::testing::{CannedResponse, FakeBackend};
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn test_get_collection() {
+        let backend = FakeBackend::new().with_response(
+            "/api/collections/org/family",
+            CannedResponse {
+                json: json!({
+                    "slug": "org/family",
+                    "title": "Family",
+                    "items": [
+                        {"id": "org/Family-1B-GGUF", "type": "model"},
+                        {"id": "org/Family-8B-GGUF", "type": "model"}
+                    ]
+                }),
+                has_more: false,
+            },
+        );
+        let client = HfClient::with_backend(test_config(), backend);
+
+        let collection = client.get_collection("org/family").await.unwrap();
+        assert_eq!(collection.model_ids.len(), 2);
+        assert_eq!(collection.title, "Family");
+    }
+}
@@ -2,6 +2,7 @@
 // Constructor is used via port.rs which compiler doesn't detect
 #![allow(dead_code)]
 
+mod collections;
 mod repo_files;
 mod search;
 
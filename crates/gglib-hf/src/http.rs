@@ -34,6 +34,15 @@ pub trait HttpBackend: Send + Sync {
         &self,
         url: &Url,
     ) -> HfResult<(T, bool)>;
+
+    /// `PUT` a JSON body to a URL and deserialize the JSON response.
+    ///
+    /// `ModelScope`'s model search is a `PUT` rather than a query string.
+    async fn put_json<T: DeserializeOwned + Send>(
+        &self,
+        url: &Url,
+        body: &serde_json::Value,
+    ) -> HfResult<T>;
 }
 
 // ============================================================================
@@ -70,9 +79,17 @@ impl ReqwestBackend {
         }
     }
 
-    /// Build a request with optional authentication.
-    fn build_request(&self, url: &Url) -> reqwest::RequestBuilder {
-        let mut request = self.client.get(url.as_str());
+    /// Build a request with optional authentication: a `GET`, or a `PUT`
+    /// when there is a JSON body.
+    fn build_request(
+        &self,
+        url: &Url,
+        body: Option<&serde_json::Value>,
+    ) -> reqwest::RequestBuilder {
+        let mut request = body.map_or_else(
+            || self.client.get(url.as_str()),
+            |body| self.client.put(url.as_str()).json(body),
+        );
         if let Some(ref token) = self.auth_token {
             request = request.header("Authorization", format!("Bearer {token}"));
         }
@@ -80,7 +97,11 @@ impl ReqwestBackend {
     }
 
     /// Fetch a URL with automatic retry for transient errors.
-    async fn fetch_with_retry(&self, url: &Url) -> HfResult<reqwest::Response> {
+    async fn fetch_with_retry(
+        &self,
+        url: &Url,
+        body: Option<&serde_json::Value>,
+    ) -> HfResult<reqwest::Response> {
         let mut last_error: Option<HfError> = None;
 
         for attempt in 0..=self.max_retries {
@@ -91,7 +112,7 @@ impl ReqwestBackend {
                 tokio::time::sleep(delay).await;
             }
 
-            match self.build_request(url).send().await {
+            match self.build_request(url, body).send().await {
                 Ok(response) => {
                     let status = response.status();
                     if status.is_success() {
@@ -137,10 +158,14 @@ impl ReqwestBackend {
     }
 }
 
-/// Try to extract a model ID from an API path.
+/// Try to extract a model ID from an API path (`HuggingFace`'s
+/// `api/models/` or `ModelScope`'s `api/v1/models/`).
 fn extract_model_id_from_path(path: &str) -> Option<String> {
     let path = path.trim_start_matches('/');
-    if let Some(rest) = path.strip_prefix("api/models/") {
+    if let Some(rest) = path
+        .strip_prefix("api/models/")
+        .or_else(|| path.strip_prefix("api/v1/models/"))
+    {
         let parts: Vec<&str> = rest.splitn(3, '/').collect();
         if parts.len() >= 2 {
             return Some(format!("{}/{}", parts[0], parts[1]));
@@ -152,7 +177,7 @@ fn extract_model_id_from_path(path: &str) -> Option<String> {
 #[async_trait]
 impl HttpBackend for ReqwestBackend {
     async fn get_json<T: DeserializeOwned + Send>(&self, url: &Url) -> HfResult<T> {
-        let response = self.fetch_with_retry(url).await?;
+        let response = self.fetch_with_retry(url, None).await?;
         let data: T = response.json().await?;
         Ok(data)
     }
@@ -161,7 +186,7 @@ impl HttpBackend for ReqwestBackend {
         &self,
         url: &Url,
     ) -> HfResult<(T, bool)> {
-        let response = self.fetch_with_retry(url).await?;
+        let response = self.fetch_with_retry(url, None).await?;
 
         // Check for pagination via Link header
         let has_more = response
@@ -173,6 +198,16 @@ impl HttpBackend for ReqwestBackend {
         let data: T = response.json().await?;
         Ok((data, has_more))
     }
+
+    async fn put_json<T: DeserializeOwned + Send>(
+        &self,
+        url: &Url,
+        body: &serde_json::Value,
+    ) -> HfResult<T> {
+        let response = self.fetch_with_retry(url, Some(body)).await?;
+        let data: T = response.json().await?;
+        Ok(data)
+    }
 }
 
 // ============================================================================
@@ -268,6 +303,14 @@ pub mod testing {
             let data: T = serde_json::from_value(response.json)?;
             Ok((data, response.has_more))
         }
+
+        async fn put_json<T: DeserializeOwned + Send>(
+            &self,
+            url: &Url,
+            _body: &serde_json::Value,
+        ) -> HfResult<T> {
+            self.get_json(url).await
+        }
     }
 }
 
@@ -292,6 +335,11 @@ mod tests {
             Some("Org/Model".to_string())
         );
 
+        assert_eq!(
+            extract_model_id_from_path("/api/v1/models/Qwen/Qwen3-8B-GGUF/repo/files"),
+            Some("Qwen/Qwen3-8B-GGUF".to_string())
+        );
+
         assert_eq!(extract_model_id_from_path("/api/models/"), None);
         assert_eq!(extract_model_id_from_path("/other/path"), None);
     }
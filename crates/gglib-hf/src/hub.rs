@@ -0,0 +1,35 @@
+//! Client selection by model hub.
+
+use std::sync::Arc;
+
+use gglib_core::ports::{ModelHub, ModelHubPort};
+
+use crate::client::DefaultHfClient;
+use crate::config::HfClientConfig;
+use crate::modelscope::DefaultModelScopeClient;
+
+/// A client for `hub` with default settings.
+///
+/// `token` authenticates `HuggingFace` requests only; it is never sent to
+/// another hub.
+#[must_use]
+pub fn hub_client(hub: ModelHub, token: Option<String>) -> Arc<dyn ModelHubPort> {
+    match hub {
+        ModelHub::HuggingFace => Arc::new(DefaultHfClient::new(
+            &HfClientConfig::default().with_optional_token(token),
+        )),
+        ModelHub::ModelScope => Arc::new(DefaultModelScopeClient::default_client()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn each_hub_gets_its_own_client() {
+        for hub in ModelHub::ALL {
+            assert_eq!(hub_client(hub, Some("hf_token".to_string())).hub(), hub);
+        }
+    }
+}
@@ -9,7 +9,9 @@ mod client;
 mod config;
 mod error;
 mod http;
+mod hub;
 mod models;
+mod modelscope;
 mod parsing;
 mod port;
 mod url;
@@ -21,6 +23,12 @@ mod url;
 // Client
 pub use client::DefaultHfClient;
 
+// ModelScope client
+pub use modelscope::{DefaultModelScopeClient, MODELSCOPE_API_URL};
+
+// Client for a hub chosen at runtime
+pub use hub::hub_client;
+
 // Configuration
 pub use config::HfClientConfig;
 
@@ -309,6 +309,23 @@ pub struct HfSearchResponse {
     pub page: u32,
 }
 
+// ============================================================================
+// Collections
+// ============================================================================
+
+/// A `HuggingFace` collection with its model items.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HfCollectionSummary {
+    /// Collection slug (e.g., `unsloth/qwen3-680edabfb790c8c34a242f95`)
+    pub slug: String,
+    /// Display title
+    pub title: String,
+    /// Optional description
+    pub description: Option<String>,
+    /// IDs of the collection's model items, in collection order
+    pub model_ids: Vec<String>,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
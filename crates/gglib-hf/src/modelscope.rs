@@ -0,0 +1,514 @@
+//! `ModelScope` client.
+//!
+//! `ModelScope` (`modelscope.cn`) hosts many of the same GGUF repositories as
+//! `HuggingFace` and is reachable where `HuggingFace` is not. This client
+//! implements the source-agnostic `ModelHubPort` over `ModelScope`'s REST API,
+//! reusing the crate's HTTP backend, quantization grouping and DTO
+//! conversions; only the endpoints and response envelopes differ:
+//!
+//! - files: `GET /api/v1/models/{id}/repo/files?Recursive=true`
+//! - model info: `GET /api/v1/models/{id}`
+//! - search: `PUT /api/v1/dolphin/models` with a JSON body
+//! - downloads: `/models/{id}/resolve/{revision}/{path}`
+//!
+//! Responses wrap their payload as `{"Code", "Success", "Message", "Data"}`,
+//! and a missing repository answers `200` with `Success: false`.
+
+use async_trait::async_trait;
+use gglib_core::ports::huggingface::{
+    HfFileInfo, HfPortError, HfPortResult, HfQuantInfo, HfRepoInfo, HfSearchOptions, HfSearchResult,
+};
+use gglib_core::ports::{ModelHub, ModelHubPort};
+use serde_json::{Value, json};
+use url::Url;
+
+use crate::config::HfClientConfig;
+use crate::error::{HfError, HfResult};
+use crate::http::{HttpBackend, ReqwestBackend};
+use crate::models::{HfConfig, HfEntryType, HfFileEntry, HfModelSummary, HfRepoRef};
+use crate::parsing::{aggregate_quantizations, filter_files_by_quantization};
+use crate::port::{map_error, to_quant_info, to_repo_info};
+
+/// `ModelScope` model API, the default base URL of [`DefaultModelScopeClient`].
+pub const MODELSCOPE_API_URL: &str = "https://modelscope.cn/api/v1/models";
+
+/// Branch files are listed and downloaded from when no revision is given.
+const DEFAULT_REVISION: &str = "master";
+
+/// Default `ModelScope` client using the reqwest HTTP backend.
+pub type DefaultModelScopeClient = ModelScopeClient<ReqwestBackend>;
+
+/// Client for the `ModelScope` model hub.
+///
+/// Generic over the HTTP backend like [`HfClient`](crate::client::HfClient);
+/// use [`DefaultModelScopeClient`] in production code.
+pub struct ModelScopeClient<B: HttpBackend> {
+    backend: B,
+    config: HfConfig,
+}
+
+impl DefaultModelScopeClient {
+    /// Create a client with the given configuration.
+    ///
+    /// Only the base URL and retry settings are used: `ModelScope` does not
+    /// accept `HuggingFace` tokens.
+    #[must_use]
+    pub fn new(config: &HfClientConfig) -> Self {
+        let config = HfConfig {
+            base_url: Url::parse(&config.base_url).unwrap_or_else(|_| {
+                Url::parse(MODELSCOPE_API_URL).expect("default URL is valid")
+            }),
+            token: None,
+            max_retries: config.max_retries,
+            #[allow(clippy::cast_possible_truncation)] // Duration milliseconds won't exceed u64 in practice
+            retry_base_delay_ms: config.retry_base_delay.as_millis() as u64,
+        };
+        Self {
+            backend: ReqwestBackend::new(&config),
+            config,
+        }
+    }
+
+    /// Create a client against `modelscope.cn`.
+    #[must_use]
+    pub fn default_client() -> Self {
+        Self::new(&HfClientConfig::new().with_base_url(MODELSCOPE_API_URL))
+    }
+}
+
+impl<B: HttpBackend> ModelScopeClient<B> {
+    /// Create a client with a custom backend.
+    #[cfg(test)]
+    const fn with_backend(config: HfConfig, backend: B) -> Self {
+        Self { backend, config }
+    }
+
+    /// `{base}/{owner}/{name}` followed by `suffix`.
+    fn repo_url(&self, repo: &HfRepoRef, suffix: &str) -> Url {
+        let mut url = self.config.base_url.clone();
+        let base_path = url.path().trim_end_matches('/').to_string();
+        url.set_path(&format!("{base_path}/{}{suffix}", repo.id()));
+        url
+    }
+
+    /// Every file in the repository on the default branch.
+    async fn list_files(&self, repo: &HfRepoRef) -> HfResult<Vec<ModelScopeFile>> {
+        let mut url = self.repo_url(repo, "/repo/files");
+        url.set_query(Some(&format!("Recursive=true&Revision={DEFAULT_REVISION}")));
+        let json: Value = self.backend.get_json(&url).await?;
+        parse_files(&json, repo)
+    }
+
+    /// The repository's GGUF files.
+    async fn list_gguf_entries(&self, repo: &HfRepoRef) -> HfResult<Vec<HfFileEntry>> {
+        Ok(self
+            .list_files(repo)
+            .await?
+            .into_iter()
+            .map(|f| f.entry)
+            .filter(HfFileEntry::is_gguf)
+            .collect())
+    }
+
+    /// The repository's model card data.
+    async fn model_info(&self, repo: &HfRepoRef) -> HfResult<HfModelSummary> {
+        let url = self.repo_url(repo, "");
+        let json: Value = self.backend.get_json(&url).await?;
+        let data = unwrap_envelope(&json, repo)?;
+        parse_model(data).ok_or_else(|| HfError::InvalidResponse {
+            message: format!("ModelScope returned no model data for {repo}"),
+        })
+    }
+
+    /// The site root (`https://modelscope.cn`), which downloads and search
+    /// hang off.
+    fn site(&self) -> String {
+        self.config.base_url.origin().ascii_serialization()
+    }
+}
+
+// ============================================================================
+// Response parsing
+// ============================================================================
+
+/// A file from the repository listing, with the commit it was listed at.
+struct ModelScopeFile {
+    entry: HfFileEntry,
+    revision: Option<String>,
+}
+
+/// The `Data` of a response, or the error its envelope reports.
+fn unwrap_envelope<'a>(json: &'a Value, repo: &HfRepoRef) -> HfResult<&'a Value> {
+    if json.get("Success").and_then(Value::as_bool) == Some(false) {
+        let message = json
+            .get("Message")
+            .and_then(Value::as_str)
+            .unwrap_or_default();
+        let code = json.get("Code").and_then(Value::as_u64).unwrap_or_default();
+        // ModelScope reports a missing repository in the body, with a code
+        // whose trailing digits are the HTTP status it stands for.
+        if code % 1000 == 404 || message.to_ascii_lowercase().contains("not exist") {
+            return Err(HfError::ModelNotFound {
+                model_id: repo.id(),
+            });
+        }
+        return Err(HfError::InvalidResponse {
+            message: format!("ModelScope rejected the request for {repo}: {message}"),
+        });
+    }
+    json.get("Data").ok_or_else(|| HfError::InvalidResponse {
+        message: "ModelScope response has no Data".to_string(),
+    })
+}
+
+/// Parse a `repo/files` listing.
+fn parse_files(json: &Value, repo: &HfRepoRef) -> HfResult<Vec<ModelScopeFile>> {
+    let files = unwrap_envelope(json, repo)?
+        .get("Files")
+        .and_then(Value::as_array)
+        .ok_or_else(|| HfError::InvalidResponse {
+            message: "Expected Data.Files in ModelScope file listing".to_string(),
+        })?;
+
+    Ok(files
+        .iter()
+        .filter_map(|file| {
+            let path = file.get("Path").and_then(Value::as_str)?.to_string();
+            let entry_type = match file.get("Type").and_then(Value::as_str) {
+                Some("tree") => HfEntryType::Directory,
+                _ => HfEntryType::File,
+            };
+            Some(ModelScopeFile {
+                entry: HfFileEntry {
+                    path,
+                    entry_type,
+                    size: file.get("Size").and_then(Value::as_u64).unwrap_or(0),
+                    oid: file
+                        .get("Sha256")
+                        .and_then(Value::as_str)
+                        .filter(|sha| !sha.is_empty())
+                        .map(str::to_ascii_lowercase),
+                },
+                revision: file
+                    .get("Revision")
+                    .and_then(Value::as_str)
+                    .map(String::from),
+            })
+        })
+        .collect())
+}
+
+/// Parse one model object, from search results or the model info endpoint.
+///
+/// `ModelScope` splits the ID into `Path` (the owner) and `Name`. Its
+/// license is folded into the tags as `license:<id>`, the form
+/// `HuggingFace` uses, so registration records it the same way.
+fn parse_model(json: &Value) -> Option<HfModelSummary> {
+    let owner = json.get("Path").and_then(Value::as_str)?;
+    let name = json.get("Name").and_then(Value::as_str)?;
+    if owner.is_empty() || name.is_empty() {
+        return None;
+    }
+
+    let mut tags: Vec<String> = json
+        .get("Tags")
+        .and_then(Value::as_array)
+        .map(|arr| {
+            arr.iter()
+                .filter_map(|t| t.as_str().map(String::from))
+                .collect()
+        })
+        .unwrap_or_default();
+    if let Some(license) = json
+        .get("License")
+        .and_then(Value::as_str)
+        .filter(|l| !l.is_empty())
+    {
+        tags.push(format!("license:{}", license.to_ascii_lowercase()));
+    }
+
+    Some(HfModelSummary {
+        id: format!("{owner}/{name}"),
+        name: name.to_string(),
+        author: Some(owner.to_string()),
+        downloads: json.get("Downloads").and_then(Value::as_u64).unwrap_or(0),
+        likes: json.get("Stars").and_then(Value::as_u64).unwrap_or(0),
+        last_modified: json
+            .get("LastUpdatedTime")
+            .and_then(Value::as_str)
+            .map(String::from),
+        parameters_b: None,
+        description: json
+            .get("Description")
+            .and_then(Value::as_str)
+            .filter(|d| !d.is_empty())
+            .map(String::from),
+        tags,
+    })
+}
+
+/// Parse a search response into the page's models and whether more follow.
+fn parse_search(json: &Value, options: &HfSearchOptions) -> HfResult<(Vec<HfModelSummary>, bool)> {
+    let data = json
+        .get("Data")
+        .and_then(|d| d.get("Model"))
+        .ok_or_else(|| HfError::InvalidResponse {
+            message: "Expected Data.Model in ModelScope search response".to_string(),
+        })?;
+    let items: Vec<HfModelSummary> = data
+        .get("Models")
+        .and_then(Value::as_array)
+        .map(|models| models.iter().filter_map(parse_model).collect())
+        .unwrap_or_default();
+    let total = data.get("TotalCount").and_then(Value::as_u64).unwrap_or(0);
+    let seen = u64::from(options.page + 1) * u64::from(options.limit.max(1));
+    Ok((items, seen < total))
+}
+
+/// The search request body: GGUF repositories matching the query.
+fn search_body(options: &HfSearchOptions) -> Value {
+    let sort_by = match options.sort_by.as_str() {
+        "likes" => "StarsCount",
+        "modified" | "lastModified" | "created" | "createdAt" => "GmtModified",
+        _ => "DownloadsCount",
+    };
+    json!({
+        "Name": options.query.as_deref().unwrap_or_default().trim(),
+        "PageNumber": options.page + 1,
+        "PageSize": options.limit.clamp(1, 100),
+        "SortBy": sort_by,
+        "Target": "",
+        "SingleCriterion": [],
+        "Criterion": [{
+            "category": "libraries",
+            "predicate": "contains",
+            "values": ["gguf"],
+        }],
+    })
+}
+
+/// Parse a bare `owner/name` model ID.
+fn repo_ref(model_id: &str) -> HfPortResult<HfRepoRef> {
+    HfRepoRef::parse(model_id).ok_or_else(|| HfPortError::InvalidResponse {
+        message: format!("Invalid model ID format: {model_id}"),
+    })
+}
+
+// ============================================================================
+// Port Implementation
+// ============================================================================
+
+#[async_trait]
+impl<B: HttpBackend + Send + Sync> ModelHubPort for ModelScopeClient<B> {
+    fn hub(&self) -> ModelHub {
+        ModelHub::ModelScope
+    }
+
+    async fn search(&self, options: &HfSearchOptions) -> HfPortResult<HfSearchResult> {
+        let mut url = self.config.base_url.clone();
+        url.set_path("/api/v1/dolphin/models");
+        url.set_query(None);
+        let json: Value = self
+            .backend
+            .put_json(&url, &search_body(options))
+            .await
+            .map_err(map_error)?;
+        let (items, has_more) = parse_search(&json, options).map_err(map_error)?;
+
+        Ok(HfSearchResult {
+            items: items.iter().map(to_repo_info).collect(),
+            has_more,
+            page: options.page,
+        })
+    }
+
+    async fn list_quantizations(&self, model_id: &str) -> HfPortResult<Vec<HfQuantInfo>> {
+        let repo = repo_ref(model_id)?;
+        let files = self.list_gguf_entries(&repo).await.map_err(map_error)?;
+        Ok(aggregate_quantizations(&files)
+            .iter()
+            .map(to_quant_info)
+            .collect())
+    }
+
+    async fn list_gguf_files(&self, model_id: &str) -> HfPortResult<Vec<HfFileInfo>> {
+        let repo = repo_ref(model_id)?;
+        let files = self.list_gguf_entries(&repo).await.map_err(map_error)?;
+        Ok(files
+            .into_iter()
+            .map(|f| HfFileInfo {
+                path: f.path,
+                size: f.size,
+                is_gguf: true,
+                oid: f.oid,
+            })
+            .collect())
+    }
+
+    async fn get_quantization_files(
+        &self,
+        model_id: &str,
+        quantization: &str,
+    ) -> HfPortResult<Vec<HfFileInfo>> {
+        let repo = repo_ref(model_id)?;
+        let files = self.list_gguf_entries(&repo).await.map_err(map_error)?;
+        let matching = filter_files_by_quantization(&files, quantization);
+        if matching.is_empty() {
+            return Err(HfPortError::QuantizationNotFound {
+                model_id: model_id.to_string(),
+                quantization: quantization.to_string(),
+            });
+        }
+        Ok(matching
+            .into_iter()
+            .map(|f| HfFileInfo {
+                path: f.path,
+                size: f.size,
+                is_gguf: true,
+                oid: f.oid,
+            })
+            .collect())
+    }
+
+    async fn get_commit_sha(&self, model_id: &str) -> HfPortResult<String> {
+        // Each listed file carries the commit it was listed at; the model
+        // info endpoint has no commit.
+        let repo = repo_ref(model_id)?;
+        let files = self.list_files(&repo).await.map_err(map_error)?;
+        Ok(files
+            .into_iter()
+            .find_map(|f| f.revision)
+            .unwrap_or_else(|| DEFAULT_REVISION.to_string()))
+    }
+
+    async fn get_model_info(&self, model_id: &str) -> HfPortResult<HfRepoInfo> {
+        let repo = repo_ref(model_id)?;
+        let model = self.model_info(&repo).await.map_err(map_error)?;
+        Ok(to_repo_info(&model))
+    }
+
+    fn file_url(&self, model_id: &str, revision: Option<&str>, path: &str) -> String {
+        format!(
+            "{}/models/{model_id}/resolve/{}/{path}",
+            self.site(),
+            revision.unwrap_or(DEFAULT_REVISION)
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::http::testing::{CannedResponse, FakeBackend};
+
+    fn client(backend: FakeBackend) -> ModelScopeClient<FakeBackend> {
+        let config = HfConfig {
+            base_url: Url::parse(MODELSCOPE_API_URL).unwrap(),
+            ..HfConfig::default()
+        };
+        ModelScopeClient::with_backend(config, backend)
+    }
+
+    fn canned(json: Value) -> CannedResponse {
+        CannedResponse {
+            json,
+            has_more: false,
+        }
+    }
+
+    fn files_json() -> Value {
+        json!({
+            "Code": 200,
+            "Success": true,
+            "Data": {"Files": [
+                {"Path": "README.md", "Type": "blob", "Size": 10, "Revision": "abc123"},
+                {"Path": "Q8_0", "Type": "tree", "Size": 0},
+                {"Path": "Q8_0/Model-Q8_0-00002-of-00002.gguf", "Type": "blob",
+                 "Size": 200, "Sha256": "BEEF", "Revision": "abc123"},
+                {"Path": "Q8_0/Model-Q8_0-00001-of-00002.gguf", "Type": "blob",
+                 "Size": 300, "Sha256": "CAFE", "Revision": "abc123"},
+                {"Path": "Model-Q4_K_M.gguf", "Type": "blob", "Size": 100,
+                 "Sha256": "f00d", "Revision": "abc123"}
+            ]}
+        })
+    }
+
+    #[tokio::test]
+    async fn quantizations_group_shards_from_the_file_listing() {
+        let client = client(FakeBackend::new().with_response("repo/files", canned(files_json())));
+
+        let quants = client.list_quantizations("Org/Model-GGUF").await.unwrap();
+        let names: Vec<_> = quants.iter().map(|q| q.name.as_str()).collect();
+        assert_eq!(names, ["Q4_K_M", "Q8_0"]);
+        assert_eq!(quants[1].shard_count, 2);
+        assert_eq!(quants[1].total_size, 500);
+
+        let files = client
+            .get_quantization_files("Org/Model-GGUF", "Q8_0")
+            .await
+            .unwrap();
+        assert_eq!(files[0].path, "Q8_0/Model-Q8_0-00001-of-00002.gguf");
+        assert_eq!(files[0].oid.as_deref(), Some("cafe"));
+        assert_eq!(
+            client.get_commit_sha("Org/Model-GGUF").await.unwrap(),
+            "abc123"
+        );
+    }
+
+    #[tokio::test]
+    async fn missing_repository_is_model_not_found() {
+        let client = client(FakeBackend::new().with_default(canned(json!({
+            "Code": 10_010_205_404_u64,
+            "Success": false,
+            "Message": "model does not exist",
+        }))));
+
+        let err = client.list_gguf_files("Org/Missing").await.unwrap_err();
+        assert!(
+            matches!(err, HfPortError::ModelNotFound { model_id } if model_id == "Org/Missing")
+        );
+    }
+
+    #[tokio::test]
+    async fn model_info_maps_stars_and_license() {
+        let client = client(FakeBackend::new().with_default(canned(json!({
+            "Success": true,
+            "Data": {
+                "Path": "Qwen", "Name": "Qwen3-8B-GGUF", "Downloads": 42, "Stars": 7,
+                "License": "Apache-2.0", "Tags": ["gguf"], "Description": ""
+            }
+        }))));
+
+        let info = client.get_model_info("Qwen/Qwen3-8B-GGUF").await.unwrap();
+        assert_eq!(info.model_id, "Qwen/Qwen3-8B-GGUF");
+        assert_eq!((info.downloads, info.likes), (42, 7));
+        assert_eq!(info.tags, ["gguf", "license:apache-2.0"]);
+        assert_eq!(info.description, None);
+    }
+
+    #[tokio::test]
+    async fn search_reads_models_and_total_count() {
+        let client = client(FakeBackend::new().with_response(
+            "dolphin/models",
+            canned(json!({"Data": {"Model": {
+                "Models": [{"Path": "Qwen", "Name": "Qwen3-8B-GGUF", "Downloads": 5}],
+                "TotalCount": 3
+            }}})),
+        ));
+
+        let options = HfSearchOptions::new().with_query("qwen").with_limit(1);
+        let result = client.search(&options).await.unwrap();
+        assert_eq!(result.items[0].model_id, "Qwen/Qwen3-8B-GGUF");
+        assert!(result.has_more);
+        assert_eq!(search_body(&options)["PageNumber"], 1);
+    }
+
+    #[test]
+    fn file_url_points_at_resolve_endpoint() {
+        let client = client(FakeBackend::new());
+        assert_eq!(
+            client.file_url("Qwen/Qwen3-8B-GGUF", None, "Qwen3-8B-Q4_K_M.gguf"),
+            "https://modelscope.cn/models/Qwen/Qwen3-8B-GGUF/resolve/master/Qwen3-8B-Q4_K_M.gguf"
+        );
+    }
+}
@@ -4,7 +4,9 @@
 //! responses into typed domain objects.
 
 use crate::error::{HfError, HfResult};
-use crate::models::{HfEntryType, HfFileEntry, HfModelSummary, HfQuantization, HfSearchResponse};
+use crate::models::{
+    HfCollectionSummary, HfEntryType, HfFileEntry, HfModelSummary, HfQuantization, HfSearchResponse,
+};
 use gglib_core::Quantization;
 use serde_json::Value;
 use std::collections::HashMap;
@@ -240,6 +242,47 @@ pub fn filter_files_by_quantization(files: &[HfFileEntry], quantization: &str) -
     matching
 }
 
+// ============================================================================
+// Collection Parsing
+// ============================================================================
+
+/// Parse a collection response, keeping only its model items.
+pub fn parse_collection(json: &Value) -> HfResult<HfCollectionSummary> {
+    let slug = json
+        .get("slug")
+        .and_then(Value::as_str)
+        .ok_or_else(|| HfError::InvalidResponse {
+            message: "Collection response is missing 'slug'".to_string(),
+        })?
+        .to_string();
+
+    let model_ids = json
+        .get("items")
+        .and_then(Value::as_array)
+        .map(|items| {
+            items
+                .iter()
+                .filter(|item| item.get("type").and_then(Value::as_str) == Some("model"))
+                .filter_map(|item| item.get("id").and_then(Value::as_str).map(String::from))
+                .collect()
+        })
+        .unwrap_or_default();
+
+    Ok(HfCollectionSummary {
+        title: json
+            .get("title")
+            .and_then(Value::as_str)
+            .map_or_else(|| slug.clone(), String::from),
+        description: json
+            .get("description")
+            .and_then(Value::as_str)
+            .filter(|d| !d.is_empty())
+            .map(String::from),
+        slug,
+        model_ids,
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -513,4 +556,28 @@ mod tests {
         assert!(response.has_more);
         assert_eq!(response.page, 0);
     }
+
+    #[test]
+    fn test_parse_collection_keeps_only_models() {
+        let json = json!({
+            "slug": "unsloth/qwen3-680edabfb790c8c34a242f95",
+            "title": "Qwen3",
+            "description": "",
+            "items": [
+                {"id": "unsloth/Qwen3-8B-GGUF", "type": "model"},
+                {"id": "unsloth/some-dataset", "type": "dataset"},
+                {"id": "unsloth/Qwen3-32B-GGUF", "type": "model"}
+            ]
+        });
+
+        let collection = parse_collection(&json).unwrap();
+        assert_eq!(collection.title, "Qwen3");
+        assert!(collection.description.is_none());
+        assert_eq!(
+            collection.model_ids,
+            vec!["unsloth/Qwen3-8B-GGUF", "unsloth/Qwen3-32B-GGUF"]
+        );
+
+        assert!(parse_collection(&json!({"title": "no slug"})).is_err());
+    }
 }
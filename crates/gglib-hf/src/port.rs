@@ -1,13 +1,16 @@
-//! Port trait implementation for `HfClient`.
+//! Port trait implementations for `HfClient`.
 //!
-//! This module implements the core-owned `HfClientPort` trait for `HfClient`,
-//! handling the conversion between internal `HuggingFace` types and core DTOs.
+//! This module implements the core-owned `ModelHubPort` and `HfClientPort`
+//! traits for `HfClient`, handling the conversion between internal
+//! `HuggingFace` types and core DTOs. The conversions and error mapping are
+//! shared with the `ModelScope` client.
 
 use async_trait::async_trait;
 use gglib_core::ports::huggingface::{
-    HfClientPort, HfFileInfo, HfPortError, HfPortResult, HfQuantInfo, HfRepoInfo, HfSearchOptions,
-    HfSearchResult,
+    HfClientPort, HfCollection, HfFileInfo, HfPortError, HfPortResult, HfQuantInfo, HfRepoInfo,
+    HfSearchOptions, HfSearchResult,
 };
+use gglib_core::ports::{ModelHub, ModelHubPort};
 
 use crate::client::HfClient;
 use crate::error::HfError;
@@ -19,7 +22,7 @@ use crate::models::{HfModelSummary, HfQuantization, HfRepoRef, HfSearchQuery, Hf
 // ============================================================================
 
 /// Convert internal `HfError` to core `HfPortError`.
-fn map_error(err: HfError) -> HfPortError {
+pub fn map_error(err: HfError) -> HfPortError {
     match err {
         HfError::ApiRequestFailed { status, url } => {
             if status == 404 {
@@ -77,7 +80,7 @@ fn extract_model_id_from_url(url: &str) -> String {
 // ============================================================================
 
 /// Convert internal `HfModelSummary` to core `HfRepoInfo`.
-fn to_repo_info(model: &HfModelSummary) -> HfRepoInfo {
+pub fn to_repo_info(model: &HfModelSummary) -> HfRepoInfo {
     HfRepoInfo {
         model_id: model.id.clone(),
         name: model.name.clone(),
@@ -93,7 +96,7 @@ fn to_repo_info(model: &HfModelSummary) -> HfRepoInfo {
 }
 
 /// Convert internal `HfQuantization` to core `HfQuantInfo`.
-fn to_quant_info(quant: &HfQuantization) -> HfQuantInfo {
+pub fn to_quant_info(quant: &HfQuantization) -> HfQuantInfo {
     HfQuantInfo {
         name: quant.name.clone(),
         shard_count: quant.shard_count,
@@ -128,7 +131,11 @@ fn to_search_query(options: &HfSearchOptions) -> HfSearchQuery {
 // ============================================================================
 
 #[async_trait]
-impl<B: HttpBackend + Send + Sync> HfClientPort for HfClient<B> {
+impl<B: HttpBackend + Send + Sync> ModelHubPort for HfClient<B> {
+    fn hub(&self) -> ModelHub {
+        ModelHub::HuggingFace
+    }
+
     async fn search(&self, options: &HfSearchOptions) -> HfPortResult<HfSearchResult> {
         let query = to_search_query(options);
         let response = self.search_models_page(&query).await.map_err(map_error)?;
@@ -295,6 +302,28 @@ impl<B: HttpBackend + Send + Sync> HfClientPort for HfClient<B> {
             tags,
         })
     }
+
+    fn file_url(&self, model_id: &str, revision: Option<&str>, path: &str) -> String {
+        format!(
+            "{}/{model_id}/resolve/{}/{path}",
+            self.config.base_url.origin().ascii_serialization(),
+            revision.unwrap_or("main")
+        )
+    }
+}
+
+#[async_trait]
+impl<B: HttpBackend + Send + Sync> HfClientPort for HfClient<B> {
+    async fn get_collection(&self, slug: &str) -> HfPortResult<HfCollection> {
+        let collection = self.get_collection(slug).await.map_err(map_error)?;
+
+        Ok(HfCollection {
+            slug: collection.slug,
+            title: collection.title,
+            description: collection.description,
+            model_ids: collection.model_ids,
+        })
+    }
 }
 
 #[cfg(test)]
@@ -350,6 +379,15 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_file_url_points_at_resolve_endpoint() {
+        let client = crate::DefaultHfClient::default_client();
+        assert_eq!(
+            client.file_url("org/model", None, "model-Q4_K_M.gguf"),
+            "https://huggingface.co/org/model/resolve/main/model-Q4_K_M.gguf"
+        );
+    }
+
     #[test]
     fn test_to_search_query() {
         let options = HfSearchOptions {
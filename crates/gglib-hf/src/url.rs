@@ -84,6 +84,24 @@ pub fn build_model_info_url(config: &HfConfig, repo: &HfRepoRef) -> Url {
     url
 }
 
+/// Build a URL for the collection endpoint.
+///
+/// Collections live beside models (`/api/collections/{slug}`), so the
+/// trailing `models` segment of the configured base URL is swapped out.
+pub fn build_collection_url(config: &HfConfig, slug: &str) -> Url {
+    let mut url = config.base_url.clone();
+
+    let base_path = url.path().trim_end_matches('/');
+    let api_path = base_path.strip_suffix("/models").unwrap_or(base_path);
+    url.set_path(&format!(
+        "{api_path}/collections/{}",
+        slug.trim_matches('/')
+    ));
+    url.set_query(None);
+
+    url
+}
+
 /// Build a URL for downloading a file from a repository.
 pub fn build_download_url(repo: &HfRepoRef, file_path: &str, revision: Option<&str>) -> Url {
     let rev = revision.unwrap_or("main");
@@ -244,4 +262,16 @@ mod tests {
             "https://huggingface.co/TheBloke/Llama-2-7B-GGUF/resolve/abc123/model.gguf"
         );
     }
+
+    #[test]
+    fn test_build_collection_url() {
+        let config = default_config();
+
+        let url = build_collection_url(&config, "unsloth/qwen3-680edabfb790c8c34a242f95");
+
+        assert_eq!(
+            url.as_str(),
+            "https://huggingface.co/api/collections/unsloth/qwen3-680edabfb790c8c34a242f95"
+        );
+    }
 }
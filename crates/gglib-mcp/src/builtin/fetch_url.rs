@@ -0,0 +1,511 @@
+//! `builtin:fetch_url` tool implementation.
+//!
+//! Downloads one page and returns it as markdown the model can read:
+//!
+//! - **SSRF guard** — every hop (including redirects, which are followed by
+//!   hand) resolves the host first and refuses loopback, private,
+//!   link-local and other non-public addresses. The connection is then
+//!   pinned to the checked addresses, so a second DNS answer cannot point
+//!   the request somewhere else, and proxies are bypassed.
+//! - **Readability pass** — scripts, navigation, headers, footers and
+//!   similar boilerplate are dropped, and the largest `article`/`main`
+//!   element (or the body) is converted to markdown.
+//! - **Budget** — the text is cut to the configured token budget, bodies
+//!   over [`MAX_BODY_BYTES`] are refused, and each conversation may fetch
+//!   at most the configured number of pages.
+//! - **Cache** — converted pages are kept for [`CACHE_TTL`] so re-reading a
+//!   page in the same session does not hit the network again.
+
+use std::collections::HashMap;
+use std::fmt::Write as _;
+use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::{Arc, LazyLock, Mutex};
+use std::time::{Duration, Instant};
+
+use dom_query::Document;
+use gglib_core::domain::UrlFetchConfig;
+use gglib_core::domain::agent::AgentMessage;
+use gglib_core::request_pipeline::CHARS_PER_TOKEN_APPROX;
+use serde_json::Value;
+use url::{Host, Url};
+
+use super::BUILTIN_PREFIX;
+
+/// How long one request may take, redirects included per hop.
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(20);
+
+/// Redirects followed before giving up.
+const MAX_REDIRECTS: usize = 5;
+
+/// Largest response body read; bigger pages are refused rather than cut
+/// mid-document.
+const MAX_BODY_BYTES: usize = 5 * 1024 * 1024;
+
+/// How long a fetched page is served from the cache.
+const CACHE_TTL: Duration = Duration::from_mins(15);
+
+/// Pages kept in the cache; the oldest is evicted past this.
+const CACHE_CAPACITY: usize = 64;
+
+const USER_AGENT: &str = "Mozilla/5.0 (compatible; gglib fetch_url)";
+
+/// Elements that never carry the main text of a page.
+const BOILERPLATE: &str = "script, style, noscript, template, iframe, object, embed, svg, \
+    canvas, form, button, nav, header, footer, aside, [hidden], [aria-hidden=true], \
+    [role=navigation], [role=banner], [role=contentinfo], [role=complementary], \
+    [class*=cookie], [id*=cookie], [class*=sidebar], [id*=sidebar], [class*=advert], \
+    [class*=newsletter]";
+
+/// A fetched page, converted but not yet cut to a budget.
+#[derive(Debug, Clone)]
+struct Page {
+    url: String,
+    title: Option<String>,
+    text: String,
+}
+
+/// Process-wide page cache shared by every [`UrlFetcher`].
+static CACHE: LazyLock<Mutex<HashMap<String, (Instant, Page)>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// Fetches pages for one conversation, enforcing its fetch limit.
+///
+/// Clones share the fetch counter, so every executor built from one
+/// fetcher draws from the same per-conversation allowance.
+#[derive(Debug, Clone)]
+pub struct UrlFetcher {
+    config: UrlFetchConfig,
+    used: Arc<AtomicU32>,
+}
+
+impl UrlFetcher {
+    /// A fetcher with the full per-conversation allowance.
+    #[must_use]
+    pub fn new(config: UrlFetchConfig) -> Self {
+        Self {
+            config,
+            used: Arc::new(AtomicU32::new(0)),
+        }
+    }
+
+    /// Count the `fetch_url` calls already made in `messages` against the
+    /// allowance, for conversations resumed from their history.
+    #[must_use]
+    pub fn with_history(self, messages: &[AgentMessage]) -> Self {
+        let name = format!("{BUILTIN_PREFIX}fetch_url");
+        let prior = messages
+            .iter()
+            .filter_map(|m| match m {
+                AgentMessage::Assistant { content } => Some(&content.tool_calls),
+                _ => None,
+            })
+            .flatten()
+            .filter(|call| call.name == name)
+            .count();
+        self.used
+            .store(u32::try_from(prior).unwrap_or(u32::MAX), Ordering::Relaxed);
+        self
+    }
+
+    /// Fetch the `url` argument and render it as markdown.
+    pub(super) async fn fetch(&self, args: &HashMap<String, Value>) -> Result<String, String> {
+        let raw = args
+            .get("url")
+            .and_then(Value::as_str)
+            .map(str::trim)
+            .filter(|u| !u.is_empty())
+            .ok_or("missing required argument 'url'")?;
+        let url = Url::parse(raw).map_err(|e| format!("invalid URL '{raw}': {e}"))?;
+        if !matches!(url.scheme(), "http" | "https") {
+            return Err(format!(
+                "only http and https URLs can be fetched, got '{raw}'"
+            ));
+        }
+
+        let limit = self.config.effective_max_fetches();
+        if self.used.fetch_add(1, Ordering::Relaxed) >= limit {
+            return Err(format!(
+                "fetch limit reached: at most {limit} pages can be fetched per conversation"
+            ));
+        }
+
+        let page = if let Some(page) = cached(url.as_str()) {
+            page
+        } else {
+            let page = download(url.clone()).await?;
+            store(url.as_str(), &page);
+            page
+        };
+        Ok(render(&page, self.config.effective_max_tokens() as usize))
+    }
+}
+
+// =============================================================================
+// Cache
+// =============================================================================
+
+fn cached(url: &str) -> Option<Page> {
+    let cache = CACHE.lock().ok()?;
+    cache
+        .get(url)
+        .filter(|(at, _)| at.elapsed() < CACHE_TTL)
+        .map(|(_, page)| page.clone())
+}
+
+fn store(url: &str, page: &Page) {
+    let Ok(mut cache) = CACHE.lock() else {
+        return;
+    };
+    cache.retain(|_, (at, _)| at.elapsed() < CACHE_TTL);
+    if cache.len() >= CACHE_CAPACITY
+        && let Some(oldest) = cache
+            .iter()
+            .min_by_key(|(_, (at, _))| *at)
+            .map(|(k, _)| k.clone())
+    {
+        cache.remove(&oldest);
+    }
+    cache.insert(url.to_string(), (Instant::now(), page.clone()));
+}
+
+// =============================================================================
+// Download
+// =============================================================================
+
+/// GET `url`, following redirects through the same public-address check.
+async fn download(mut url: Url) -> Result<Page, String> {
+    for _ in 0..=MAX_REDIRECTS {
+        let addrs = resolve_public(&url).await?;
+        let mut builder = reqwest::Client::builder()
+            .no_proxy()
+            .redirect(reqwest::redirect::Policy::none())
+            .timeout(REQUEST_TIMEOUT)
+            .user_agent(USER_AGENT);
+        if let Some(Host::Domain(domain)) = url.host() {
+            builder = builder.resolve_to_addrs(domain, &addrs);
+        }
+        let client = builder
+            .build()
+            .map_err(|e| format!("failed to build HTTP client: {e}"))?;
+
+        let response = client
+            .get(url.clone())
+            .header("Accept", "text/html, text/plain;q=0.9, */*;q=0.5")
+            .send()
+            .await
+            .map_err(|e| format!("failed to fetch {url}: {e}"))?;
+
+        if response.status().is_redirection() {
+            let location = response
+                .headers()
+                .get(reqwest::header::LOCATION)
+                .and_then(|v| v.to_str().ok())
+                .ok_or_else(|| format!("{url} redirected without a location"))?;
+            url = url
+                .join(location)
+                .map_err(|e| format!("{url} redirected to an invalid URL: {e}"))?;
+            if !matches!(url.scheme(), "http" | "https") {
+                return Err(format!("refusing to follow redirect to {url}"));
+            }
+            continue;
+        }
+        if !response.status().is_success() {
+            return Err(format!("{url} returned HTTP {}", response.status()));
+        }
+
+        let content_type = response
+            .headers()
+            .get(reqwest::header::CONTENT_TYPE)
+            .and_then(|v| v.to_str().ok())
+            .unwrap_or("text/html")
+            .to_ascii_lowercase();
+        let body = read_body(response).await?;
+        return to_page(url.as_str(), &content_type, &body);
+    }
+    Err(format!("too many redirects (more than {MAX_REDIRECTS})"))
+}
+
+async fn read_body(mut response: reqwest::Response) -> Result<String, String> {
+    let mut body = Vec::new();
+    while let Some(chunk) = response
+        .chunk()
+        .await
+        .map_err(|e| format!("failed to read page: {e}"))?
+    {
+        if body.len() + chunk.len() > MAX_BODY_BYTES {
+            return Err(format!(
+                "page is larger than {} MiB",
+                MAX_BODY_BYTES / (1024 * 1024)
+            ));
+        }
+        body.extend_from_slice(&chunk);
+    }
+    Ok(String::from_utf8_lossy(&body).into_owned())
+}
+
+fn to_page(url: &str, content_type: &str, body: &str) -> Result<Page, String> {
+    let (title, text) = if content_type.contains("html") {
+        extract_readable(body)
+    } else if content_type.starts_with("text/")
+        || content_type.contains("json")
+        || content_type.contains("xml")
+    {
+        (None, body.trim().to_string())
+    } else {
+        return Err(format!(
+            "cannot read {url}: unsupported content type {content_type}"
+        ));
+    };
+    Ok(Page {
+        url: url.to_string(),
+        title,
+        text,
+    })
+}
+
+// =============================================================================
+// SSRF guard
+// =============================================================================
+
+/// Resolve the host of `url`, failing unless every address is public.
+async fn resolve_public(url: &Url) -> Result<Vec<SocketAddr>, String> {
+    let port = url
+        .port_or_known_default()
+        .ok_or_else(|| format!("{url} has no port"))?;
+    let addrs: Vec<SocketAddr> = match url.host() {
+        Some(Host::Ipv4(ip)) => vec![SocketAddr::new(IpAddr::V4(ip), port)],
+        Some(Host::Ipv6(ip)) => vec![SocketAddr::new(IpAddr::V6(ip), port)],
+        Some(Host::Domain(domain)) => tokio::net::lookup_host((domain, port))
+            .await
+            .map_err(|e| format!("cannot resolve {domain}: {e}"))?
+            .collect(),
+        None => return Err(format!("{url} has no host")),
+    };
+    if addrs.is_empty() {
+        return Err(format!("{url} did not resolve to any address"));
+    }
+    if let Some(blocked) = addrs.iter().find(|a| !is_public(a.ip())) {
+        return Err(format!(
+            "refusing to fetch {url}: it resolves to the non-public address {}",
+            blocked.ip()
+        ));
+    }
+    Ok(addrs)
+}
+
+/// Whether `ip` is a globally routable address.
+fn is_public(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(v4) => is_public_v4(v4),
+        IpAddr::V6(v6) => {
+            if let Some(v4) = v6.to_ipv4_mapped() {
+                return is_public_v4(v4);
+            }
+            let segments = v6.segments();
+            // NAT64 (64:ff9b::/96) embeds an IPv4 address in the last 32 bits.
+            if segments[..6] == [0x64, 0xff9b, 0, 0, 0, 0] {
+                let [.., hi, lo] = segments;
+                let [a, b] = hi.to_be_bytes();
+                let [c, d] = lo.to_be_bytes();
+                return is_public_v4(Ipv4Addr::new(a, b, c, d));
+            }
+            !(v6.is_loopback()
+                || v6.is_unspecified()
+                || v6.is_multicast()
+                || (segments[0] & 0xfe00) == 0xfc00 // unique local fc00::/7
+                || (segments[0] & 0xffc0) == 0xfe80 // link-local fe80::/10
+                || (segments[0] == 0x2001 && segments[1] == 0x0db8)) // documentation
+        }
+    }
+}
+
+const fn is_public_v4(ip: Ipv4Addr) -> bool {
+    let [a, b, c, _] = ip.octets();
+    !(ip.is_private()
+        || ip.is_loopback()
+        || ip.is_link_local()
+        || ip.is_broadcast()
+        || ip.is_documentation()
+        || ip.is_unspecified()
+        || ip.is_multicast()
+        || a == 0 // "this network"
+        || (a == 100 && (b & 0xc0) == 64) // shared address space 100.64.0.0/10
+        || (a == 192 && b == 0 && c == 0) // IETF protocol assignments
+        || (a == 198 && (b & 0xfe) == 18) // benchmarking 198.18.0.0/15
+        || a >= 240) // reserved
+}
+
+// =============================================================================
+// Readability
+// =============================================================================
+
+/// Strip boilerplate and convert the main content of `html` to markdown.
+fn extract_readable(html: &str) -> (Option<String>, String) {
+    let doc = Document::from(html);
+    let title = Some(collapse_whitespace(&doc.select("title").text()))
+        .filter(|t| !t.is_empty())
+        .or_else(|| Some(collapse_whitespace(&doc.select("h1").text())).filter(|t| !t.is_empty()));
+
+    doc.select(BOILERPLATE).remove();
+
+    let main = doc
+        .select("article, main, [role=main]")
+        .nodes()
+        .iter()
+        .max_by_key(|node| node.text().len())
+        .copied()
+        .or_else(|| doc.select("body").nodes().first().copied())
+        .map_or_else(|| doc.md(None), |node| node.md(None));
+    (title, tidy_markdown(&main))
+}
+
+/// Trim trailing spaces and collapse runs of blank lines.
+fn tidy_markdown(markdown: &str) -> String {
+    let mut out = String::with_capacity(markdown.len());
+    let mut blank = 0;
+    for line in markdown.lines().map(str::trim_end) {
+        if line.is_empty() {
+            blank += 1;
+            if blank > 1 {
+                continue;
+            }
+        } else {
+            blank = 0;
+        }
+        out.push_str(line);
+        out.push('\n');
+    }
+    out.trim().to_string()
+}
+
+fn collapse_whitespace(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Render `page` with a source line, cut to `max_tokens` estimated tokens.
+fn render(page: &Page, max_tokens: usize) -> String {
+    let max_chars = max_tokens * CHARS_PER_TOKEN_APPROX;
+    let mut out = String::new();
+    if let Some(title) = &page.title {
+        out.push_str("# ");
+        out.push_str(title);
+        out.push('\n');
+    }
+    out.push_str("Source: ");
+    out.push_str(&page.url);
+    out.push_str("\n\n");
+    if page.text.chars().count() > max_chars {
+        out.extend(page.text.chars().take(max_chars));
+        let _ = write!(out, "\n\n[page truncated to about {max_tokens} tokens]");
+    } else {
+        out.push_str(&page.text);
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use gglib_core::ToolCall;
+    use gglib_core::domain::agent::AssistantContent;
+    use serde_json::json;
+
+    #[test]
+    fn rejects_non_public_addresses() {
+        for ip in [
+            "127.0.0.1",
+            "10.1.2.3",
+            "172.16.0.1",
+            "192.168.1.1",
+            "169.254.169.254",
+            "100.64.0.1",
+            "0.0.0.0",
+            "::1",
+            "fd00::1",
+            "fe80::1",
+            "::ffff:127.0.0.1",
+            "64:ff9b::a9fe:a9fe",
+        ] {
+            assert!(!is_public(ip.parse().unwrap()), "{ip} should be blocked");
+        }
+        for ip in ["93.184.216.34", "2606:4700::1111"] {
+            assert!(is_public(ip.parse().unwrap()), "{ip} should be allowed");
+        }
+    }
+
+    #[tokio::test]
+    async fn private_hosts_are_refused_before_connecting() {
+        let fetcher = UrlFetcher::new(UrlFetchConfig::default());
+        let args = |url: &str| HashMap::from([("url".to_string(), json!(url))]);
+
+        let err = fetcher
+            .fetch(&args("http://127.0.0.1:9/admin"))
+            .await
+            .unwrap_err();
+        assert!(err.contains("non-public"), "{err}");
+        let err = fetcher
+            .fetch(&args("file:///etc/passwd"))
+            .await
+            .unwrap_err();
+        assert!(err.contains("only http and https"), "{err}");
+    }
+
+    #[tokio::test]
+    async fn fetch_limit_counts_history() {
+        let config = UrlFetchConfig {
+            max_tokens: None,
+            max_fetches_per_conversation: Some(1),
+        };
+        let history = vec![AgentMessage::Assistant {
+            content: AssistantContent {
+                text: None,
+                tool_calls: vec![ToolCall {
+                    id: "1".to_string(),
+                    name: "builtin:fetch_url".to_string(),
+                    arguments: json!({ "url": "https://example.com" }),
+                }],
+            },
+        }];
+        let fetcher = UrlFetcher::new(config).with_history(&history);
+        let args = HashMap::from([("url".to_string(), json!("http://127.0.0.1/"))]);
+        let err = fetcher.fetch(&args).await.unwrap_err();
+        assert!(err.contains("fetch limit reached"), "{err}");
+    }
+
+    #[test]
+    fn readability_keeps_main_content() {
+        let html = r#"<html><head><title> Example   Post </title>
+            <script>var tracking = 1;</script></head>
+            <body>
+              <nav><a href="/">Home</a> <a href="/about">About</a></nav>
+              <div class="cookie-banner">We use cookies</div>
+              <article>
+                <h1>Hello</h1>
+                <p>The <strong>main</strong> text.</p>
+              </article>
+              <footer>Copyright</footer>
+            </body></html>"#;
+        let (title, text) = extract_readable(html);
+        assert_eq!(title.as_deref(), Some("Example Post"));
+        assert!(text.contains("Hello"));
+        assert!(text.contains("**main**"));
+        for noise in ["tracking", "Home", "cookies", "Copyright"] {
+            assert!(!text.contains(noise), "{noise} should be stripped: {text}");
+        }
+    }
+
+    #[test]
+    fn render_truncates_to_token_budget() {
+        let page = Page {
+            url: "https://example.com/".to_string(),
+            title: None,
+            text: "x".repeat(100),
+        };
+        let out = render(&page, 5);
+        assert!(out.starts_with("Source: https://example.com/\n\n"));
+        assert!(out.contains(&"x".repeat(5 * CHARS_PER_TOKEN_APPROX)));
+        assert!(!out.contains(&"x".repeat(5 * CHARS_PER_TOKEN_APPROX + 1)));
+        assert!(out.ends_with("[page truncated to about 5 tokens]"));
+    }
+}
@@ -0,0 +1,308 @@
+//! `builtin:git_diff`, `builtin:git_log`, `builtin:git_show` and
+//! `builtin:git_blame` tool implementations.
+//!
+//! Each tool runs one read-only `git` subcommand in the sandbox root. The
+//! model never supplies raw arguments: revisions are checked so they cannot
+//! be read as options, paths go through [`resolve_sandboxed_path`] and are
+//! passed after `--`, and repository config that could run external
+//! programs (diff drivers, textconv filters, fsmonitor hooks) is disabled.
+
+use std::collections::HashMap;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+use serde_json::Value;
+
+use super::sandboxing::resolve_sandboxed_path;
+
+/// Bytes of git output returned to the model before truncating.
+const MAX_OUTPUT_BYTES: usize = 50_000;
+
+/// Default and maximum number of commits listed by `git_log`.
+const DEFAULT_LOG_COUNT: u64 = 20;
+const MAX_LOG_COUNT: u64 = 100;
+
+/// Show the working-tree or staged changes, optionally against a revision.
+pub fn git_diff(args: &HashMap<String, Value>, sandbox_root: &Path) -> Result<String, String> {
+    let mut cmd = vec!["diff", "--no-ext-diff", "--no-textconv", "--no-color"];
+    if args.get("staged").and_then(Value::as_bool).unwrap_or(false) {
+        cmd.push("--cached");
+    }
+    let revision = optional_revision(args, "revision")?;
+    if let Some(rev) = &revision {
+        cmd.push(rev);
+    }
+    let path = optional_path(args, sandbox_root)?;
+
+    let output = run_git(sandbox_root, &cmd, path.as_deref())?;
+    Ok(or_placeholder(output, "no changes"))
+}
+
+/// List recent commits, newest first.
+pub fn git_log(args: &HashMap<String, Value>, sandbox_root: &Path) -> Result<String, String> {
+    let count = args
+        .get("max_count")
+        .and_then(Value::as_u64)
+        .unwrap_or(DEFAULT_LOG_COUNT)
+        .clamp(1, MAX_LOG_COUNT)
+        .to_string();
+    let revision = optional_revision(args, "revision")?.unwrap_or_else(|| "HEAD".to_string());
+    let path = optional_path(args, sandbox_root)?;
+
+    let cmd = [
+        "log",
+        "--no-color",
+        "--date=short",
+        "--format=%h %ad %an%n    %s",
+        "-n",
+        &count,
+        &revision,
+    ];
+    let output = run_git(sandbox_root, &cmd, path.as_deref())?;
+    Ok(or_placeholder(output, "no commits"))
+}
+
+/// Show one commit: its message and patch.
+pub fn git_show(args: &HashMap<String, Value>, sandbox_root: &Path) -> Result<String, String> {
+    let revision =
+        optional_revision(args, "revision")?.ok_or("missing required argument 'revision'")?;
+    let path = optional_path(args, sandbox_root)?;
+
+    let cmd = [
+        "show",
+        "--no-ext-diff",
+        "--no-textconv",
+        "--no-color",
+        "--format=fuller",
+        &revision,
+    ];
+    run_git(sandbox_root, &cmd, path.as_deref())
+}
+
+/// Show who last changed each line of a file.
+pub fn git_blame(args: &HashMap<String, Value>, sandbox_root: &Path) -> Result<String, String> {
+    let path = optional_path(args, sandbox_root)?.ok_or("missing required argument 'path'")?;
+    let revision = optional_revision(args, "revision")?;
+
+    let start = args.get("start_line").and_then(Value::as_u64);
+    let end = args.get("end_line").and_then(Value::as_u64);
+    let range = match (start, end) {
+        (None, None) => None,
+        (Some(0), _) | (_, Some(0)) => return Err("line numbers start at 1".to_string()),
+        (Some(s), Some(e)) if e < s => {
+            return Err("'end_line' must not be before 'start_line'".to_string());
+        }
+        (Some(s), Some(e)) => Some(format!("{s},{e}")),
+        (Some(s), None) => Some(format!("{s},")),
+        (None, Some(e)) => Some(format!("1,{e}")),
+    };
+
+    let mut cmd = vec!["blame", "--no-textconv", "--date=short"];
+    if let Some(range) = &range {
+        cmd.extend(["-L", range]);
+    }
+    if let Some(rev) = &revision {
+        cmd.push(rev);
+    }
+    run_git(sandbox_root, &cmd, Some(&path))
+}
+
+// =============================================================================
+// Helpers
+// =============================================================================
+
+/// Read an optional revision argument, rejecting anything git could parse
+/// as an option or that is not a plain revision expression.
+fn optional_revision(args: &HashMap<String, Value>, key: &str) -> Result<Option<String>, String> {
+    let Some(rev) = args.get(key).and_then(Value::as_str).map(str::trim) else {
+        return Ok(None);
+    };
+    if rev.is_empty() {
+        return Ok(None);
+    }
+    let allowed = |c: char| c.is_ascii_alphanumeric() || "._-/~^@{}".contains(c);
+    if rev.starts_with('-') || !rev.chars().all(allowed) {
+        return Err(format!("'{key}' is not a valid revision: {rev}"));
+    }
+    Ok(Some(rev.to_string()))
+}
+
+/// Resolve the optional `path` argument to a path relative to the sandbox.
+fn optional_path(
+    args: &HashMap<String, Value>,
+    sandbox_root: &Path,
+) -> Result<Option<PathBuf>, String> {
+    let Some(path) = args.get("path").and_then(Value::as_str) else {
+        return Ok(None);
+    };
+    let resolved = resolve_sandboxed_path(sandbox_root, path)?;
+    let root = sandbox_root
+        .canonicalize()
+        .map_err(|e| format!("sandbox root does not exist: {e}"))?;
+    let relative = resolved
+        .strip_prefix(&root)
+        .map_err(|_| format!("path '{path}' is outside the sandbox"))?;
+    Ok(Some(if relative.as_os_str().is_empty() {
+        PathBuf::from(".")
+    } else {
+        relative.to_path_buf()
+    }))
+}
+
+fn or_placeholder(output: String, placeholder: &str) -> String {
+    if output.trim().is_empty() {
+        placeholder.to_string()
+    } else {
+        output
+    }
+}
+
+/// Run `git <args> [-- <path>]` in `sandbox_root`, returning at most
+/// [`MAX_OUTPUT_BYTES`] of stdout.
+fn run_git(sandbox_root: &Path, args: &[&str], path: Option<&Path>) -> Result<String, String> {
+    let mut command = Command::new("git");
+    command
+        .current_dir(sandbox_root)
+        .args(["-c", "core.fsmonitor=false", "-c", "core.pager=cat"])
+        .args(args)
+        .env("GIT_OPTIONAL_LOCKS", "0")
+        .env("GIT_TERMINAL_PROMPT", "0")
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+    if let Some(path) = path {
+        command.arg("--").arg(path);
+    }
+
+    let mut child = command
+        .spawn()
+        .map_err(|e| format!("failed to run git: {e}"))?;
+
+    let mut stdout = Vec::new();
+    if let Some(out) = child.stdout.take() {
+        out.take(MAX_OUTPUT_BYTES as u64 + 1)
+            .read_to_end(&mut stdout)
+            .map_err(|e| format!("failed to read git output: {e}"))?;
+    }
+    let truncated = stdout.len() > MAX_OUTPUT_BYTES;
+    if truncated {
+        // The rest of the output is not needed; stop git writing it.
+        let _ = child.kill();
+        stdout.truncate(MAX_OUTPUT_BYTES);
+    }
+
+    let output = child
+        .wait_with_output()
+        .map_err(|e| format!("failed to run git: {e}"))?;
+    if !truncated && !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(format!("git {} failed: {}", args[0], stderr.trim()));
+    }
+
+    let mut text = String::from_utf8_lossy(&stdout).into_owned();
+    if truncated {
+        use std::fmt::Write;
+        let _ = write!(text, "\n\n[output truncated at {MAX_OUTPUT_BYTES} bytes]");
+    }
+    Ok(text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use std::fs;
+
+    fn git(dir: &Path, args: &[&str]) {
+        let status = Command::new("git")
+            .current_dir(dir)
+            .args(["-c", "user.name=Test", "-c", "user.email=test@example.com"])
+            .args(args)
+            .stdout(Stdio::null())
+            .status()
+            .unwrap();
+        assert!(status.success(), "git {args:?} failed");
+    }
+
+    fn args(value: Value) -> HashMap<String, Value> {
+        match value {
+            Value::Object(map) => map.into_iter().collect(),
+            _ => panic!("tool arguments must be an object"),
+        }
+    }
+
+    fn repo() -> tempfile::TempDir {
+        let dir = tempfile::tempdir().unwrap();
+        git(dir.path(), &["init", "-q"]);
+        fs::write(dir.path().join("a.txt"), "one\n").unwrap();
+        git(dir.path(), &["add", "a.txt"]);
+        git(dir.path(), &["commit", "-q", "-m", "first commit"]);
+        fs::write(dir.path().join("a.txt"), "one\ntwo\n").unwrap();
+        git(dir.path(), &["commit", "-q", "-am", "second commit"]);
+        dir
+    }
+
+    #[test]
+    fn log_and_show_read_history() {
+        let dir = repo();
+        let log = git_log(&args(json!({ "max_count": 1 })), dir.path()).unwrap();
+        assert!(log.contains("second commit"));
+        assert!(!log.contains("first commit"));
+
+        let show = git_show(&args(json!({ "revision": "HEAD~1" })), dir.path()).unwrap();
+        assert!(show.contains("first commit"));
+        assert!(show.contains("+one"));
+    }
+
+    #[test]
+    fn diff_and_blame_cover_working_tree() {
+        let dir = repo();
+        assert_eq!(
+            git_diff(&args(json!({})), dir.path()).unwrap(),
+            "no changes"
+        );
+
+        fs::write(dir.path().join("a.txt"), "one\ntwo\nthree\n").unwrap();
+        let diff = git_diff(&args(json!({ "path": "a.txt" })), dir.path()).unwrap();
+        assert!(diff.contains("+three"));
+
+        let blame = git_blame(
+            &args(json!({ "path": "a.txt", "start_line": 2, "end_line": 2 })),
+            dir.path(),
+        )
+        .unwrap();
+        assert_eq!(blame.lines().count(), 1);
+        assert!(blame.contains("two"));
+    }
+
+    #[test]
+    fn blame_never_runs_textconv_drivers() {
+        let dir = repo();
+        let marker = dir.path().join("textconv-ran");
+        let driver = format!("touch '{}'; cat", marker.display());
+        git(dir.path(), &["config", "diff.marker.textconv", &driver]);
+        fs::write(dir.path().join(".gitattributes"), "a.txt diff=marker\n").unwrap();
+
+        let blame = git_blame(&args(json!({ "path": "a.txt" })), dir.path()).unwrap();
+        assert!(blame.contains("two"));
+        assert!(
+            !marker.exists(),
+            "blame ran the repository's textconv driver"
+        );
+    }
+
+    #[test]
+    fn rejects_option_like_revisions() {
+        let dir = repo();
+        let err = git_show(&args(json!({ "revision": "--output=/tmp/x" })), dir.path());
+        assert!(err.unwrap_err().contains("not a valid revision"));
+        assert!(git_log(&args(json!({ "revision": "HEAD; rm" })), dir.path()).is_err());
+    }
+
+    #[test]
+    fn outside_a_repository_is_an_error() {
+        let dir = tempfile::tempdir().unwrap();
+        assert!(git_log(&args(json!({})), dir.path()).is_err());
+    }
+}
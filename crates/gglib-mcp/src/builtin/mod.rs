@@ -1,15 +1,21 @@
 #![doc = include_str!("README.md")]
+mod fetch_url;
 mod fs_grep;
 mod fs_list;
 mod fs_read;
+mod git;
 pub(crate) mod sandboxing;
 mod time;
+mod web_search;
 
 use std::collections::HashMap;
 use std::path::PathBuf;
 
+pub use fetch_url::UrlFetcher;
+
 use anyhow::anyhow;
 use async_trait::async_trait;
+use gglib_core::domain::WebSearchConfig;
 use gglib_core::ports::ToolExecutorPort;
 use gglib_core::{McpTool, ToolCall, ToolDefinition, ToolResult};
 use serde_json::{Value, json};
@@ -24,11 +30,18 @@ pub const BUILTIN_PREFIX: &str = "builtin:";
 /// Executor for built-in tools.
 ///
 /// When `sandbox_root` is set, filesystem tools (`read_file`, `list_directory`,
-/// `grep_search`) are available and confined to that directory. When `None`,
+/// `grep_search`) and read-only git tools (`git_diff`, `git_log`, `git_show`,
+/// `git_blame`) are available and confined to that directory. When `None`,
 /// only non-filesystem tools (`get_current_time`) are exposed.
+///
+/// `web_search` and `fetch_url` are exposed only after
+/// [`Self::with_web_search`] and [`Self::with_url_fetch`], i.e. when the
+/// user has turned them on in settings.
 #[derive(Debug, Default, Clone)]
 pub struct BuiltinToolExecutorAdapter {
     sandbox_root: Option<PathBuf>,
+    web_search: Option<web_search::WebSearcher>,
+    url_fetch: Option<UrlFetcher>,
 }
 
 impl BuiltinToolExecutorAdapter {
@@ -36,9 +49,26 @@ impl BuiltinToolExecutorAdapter {
     pub const fn with_sandbox(root: PathBuf) -> Self {
         Self {
             sandbox_root: Some(root),
+            web_search: None,
+            url_fetch: None,
         }
     }
 
+    /// Enable the `web_search` tool, sending queries through `client` to the
+    /// provider in `config`.
+    #[must_use]
+    pub fn with_web_search(mut self, client: reqwest::Client, config: WebSearchConfig) -> Self {
+        self.web_search = Some(web_search::WebSearcher::new(client, config));
+        self
+    }
+
+    /// Enable the `fetch_url` tool, drawing on `fetcher`'s allowance.
+    #[must_use]
+    pub fn with_url_fetch(mut self, fetcher: UrlFetcher) -> Self {
+        self.url_fetch = Some(fetcher);
+        self
+    }
+
     /// Bare (unprefixed) tool definitions for the HTTP discovery endpoint.
     ///
     /// These use the exact same schema as [`ToolExecutorPort::list_tools`] but
@@ -48,40 +78,44 @@ impl BuiltinToolExecutorAdapter {
         Self::all_definitions()
     }
 
-    /// All tool definitions including filesystem tools.
+    /// All tool definitions including filesystem and web tools.
     fn all_definitions() -> Vec<McpTool> {
-        let mut defs = vec![
-            McpTool::new("get_current_time")
-                .with_description(
-                    "Get the current date and time. Can return time in different \
-                     timezones and formats. Useful for time-sensitive queries or scheduling.",
-                )
-                .with_input_schema(json!({
-                    "type": "object",
-                    "properties": {
-                        "timezone": {
-                            "type": "string",
-                            "description": "IANA timezone name (e.g. \"America/New_York\", \
-                                            \"Europe/London\"). Defaults to UTC."
-                        },
-                        "format": {
-                            "type": "string",
-                            "description": "Output format: \"iso\" for ISO 8601, \
-                                            \"human\" for human-readable, \
-                                            \"unix\" for Unix timestamp.",
-                            "enum": ["iso", "human", "unix"],
-                            "default": "human"
-                        }
-                    },
-                    "required": []
-                })),
-        ];
-
+        let mut defs = vec![Self::time_definition()];
         defs.extend(Self::fs_definitions());
-
+        defs.extend(Self::git_definitions());
+        defs.push(Self::web_search_definition());
+        defs.push(Self::fetch_url_definition());
         defs
     }
 
+    /// The `get_current_time` definition, the only tool always exposed.
+    fn time_definition() -> McpTool {
+        McpTool::new("get_current_time")
+            .with_description(
+                "Get the current date and time. Can return time in different \
+                     timezones and formats. Useful for time-sensitive queries or scheduling.",
+            )
+            .with_input_schema(json!({
+                "type": "object",
+                "properties": {
+                    "timezone": {
+                        "type": "string",
+                        "description": "IANA timezone name (e.g. \"America/New_York\", \
+                                        \"Europe/London\"). Defaults to UTC."
+                    },
+                    "format": {
+                        "type": "string",
+                        "description": "Output format: \"iso\" for ISO 8601, \
+                                        \"human\" for human-readable, \
+                                        \"unix\" for Unix timestamp.",
+                        "enum": ["iso", "human", "unix"],
+                        "default": "human"
+                    }
+                },
+                "required": []
+            }))
+    }
+
     /// Tool definitions available only when a sandbox root is set.
     fn fs_definitions() -> Vec<McpTool> {
         vec![
@@ -149,6 +183,141 @@ impl BuiltinToolExecutorAdapter {
     }
 }
 
+impl BuiltinToolExecutorAdapter {
+    /// Read-only git tool definitions, also gated on a sandbox root.
+    fn git_definitions() -> Vec<McpTool> {
+        let revision = json!({
+            "type": "string",
+            "description": "Commit, branch or tag (e.g. \"HEAD~3\", \"main\")"
+        });
+        let path = json!({
+            "type": "string",
+            "description": "Limit to this file or directory (relative to working directory)"
+        });
+        vec![
+            McpTool::new("git_diff")
+                .with_description(
+                    "Show uncommitted changes in the git repository as a unified diff. \
+                     Compares the working tree with the index, or with a revision when \
+                     one is given. Large diffs are truncated.",
+                )
+                .with_input_schema(json!({
+                    "type": "object",
+                    "properties": {
+                        "staged": {
+                            "type": "boolean",
+                            "description": "Show staged changes instead of unstaged ones",
+                            "default": false
+                        },
+                        "revision": revision,
+                        "path": path
+                    },
+                    "required": []
+                })),
+            McpTool::new("git_log")
+                .with_description(
+                    "List recent commits (abbreviated hash, date, author, subject), \
+                     newest first. Useful for answering what changed recently.",
+                )
+                .with_input_schema(json!({
+                    "type": "object",
+                    "properties": {
+                        "max_count": {
+                            "type": "integer",
+                            "description": "Number of commits to list (1-100)",
+                            "default": 20
+                        },
+                        "revision": revision,
+                        "path": path
+                    },
+                    "required": []
+                })),
+            McpTool::new("git_show")
+                .with_description(
+                    "Show a commit's message, author and patch. Large patches are truncated.",
+                )
+                .with_input_schema(json!({
+                    "type": "object",
+                    "properties": {
+                        "revision": revision,
+                        "path": path
+                    },
+                    "required": ["revision"]
+                })),
+            McpTool::new("git_blame")
+                .with_description(
+                    "Show the commit, author and date that last changed each line of a \
+                     file, optionally for a range of lines.",
+                )
+                .with_input_schema(json!({
+                    "type": "object",
+                    "properties": {
+                        "path": {
+                            "type": "string",
+                            "description": "File to blame (relative to working directory)"
+                        },
+                        "start_line": {
+                            "type": "integer",
+                            "description": "First line to show (1-based)"
+                        },
+                        "end_line": {
+                            "type": "integer",
+                            "description": "Last line to show (inclusive)"
+                        },
+                        "revision": revision
+                    },
+                    "required": ["path"]
+                })),
+        ]
+    }
+}
+
+impl BuiltinToolExecutorAdapter {
+    /// Web search definition, exposed only when a provider is configured.
+    fn web_search_definition() -> McpTool {
+        McpTool::new("web_search")
+            .with_description(
+                "Search the web and return the top results as JSON: a numbered list \
+                 of title, URL and snippet. Cite results by their number and URL. \
+                 Snippets are short; they summarise a page, not replace it.",
+            )
+            .with_input_schema(json!({
+                "type": "object",
+                "properties": {
+                    "query": {
+                        "type": "string",
+                        "description": "Search query"
+                    },
+                    "max_results": {
+                        "type": "integer",
+                        "description": "Number of results to return (capped by the configured limit)"
+                    }
+                },
+                "required": ["query"]
+            }))
+    }
+
+    /// Page fetch definition, exposed only when fetching is enabled.
+    fn fetch_url_definition() -> McpTool {
+        McpTool::new("fetch_url")
+            .with_description(
+                "Download a public web page and return its main content as markdown, \
+                 without navigation and other boilerplate. Long pages are truncated. \
+                 Fetches per conversation are limited, so prefer the most relevant URL.",
+            )
+            .with_input_schema(json!({
+                "type": "object",
+                "properties": {
+                    "url": {
+                        "type": "string",
+                        "description": "Absolute http:// or https:// URL"
+                    }
+                },
+                "required": ["url"]
+            }))
+    }
+}
+
 // =============================================================================
 // ToolExecutorPort
 // =============================================================================
@@ -156,12 +325,17 @@ impl BuiltinToolExecutorAdapter {
 #[async_trait]
 impl ToolExecutorPort for BuiltinToolExecutorAdapter {
     async fn list_tools(&self) -> Vec<ToolDefinition> {
-        let defs = if self.sandbox_root.is_some() {
-            Self::all_definitions()
-        } else {
-            // Without sandbox, only expose non-filesystem tools
-            vec![Self::all_definitions().into_iter().next().unwrap()]
-        };
+        let mut defs = vec![Self::time_definition()];
+        if self.sandbox_root.is_some() {
+            defs.extend(Self::fs_definitions());
+            defs.extend(Self::git_definitions());
+        }
+        if self.web_search.is_some() {
+            defs.push(Self::web_search_definition());
+        }
+        if self.url_fetch.is_some() {
+            defs.push(Self::fetch_url_definition());
+        }
 
         defs.into_iter()
             .map(|t| ToolDefinition {
@@ -192,7 +366,35 @@ impl ToolExecutorPort for BuiltinToolExecutorAdapter {
                     success: true,
                 })
             }
-            "read_file" | "list_directory" | "grep_search" => {
+            "web_search" | "fetch_url" => {
+                let result = if bare == "web_search" {
+                    let searcher = self
+                        .web_search
+                        .as_ref()
+                        .ok_or_else(|| anyhow!("web search is not configured"))?;
+                    searcher.search(&args).await
+                } else {
+                    let fetcher = self
+                        .url_fetch
+                        .as_ref()
+                        .ok_or_else(|| anyhow!("URL fetching is not enabled"))?;
+                    fetcher.fetch(&args).await
+                };
+                Ok(match result {
+                    Ok(content) => ToolResult {
+                        tool_call_id: call.id.clone(),
+                        content,
+                        success: true,
+                    },
+                    Err(msg) => ToolResult {
+                        tool_call_id: call.id.clone(),
+                        content: msg,
+                        success: false,
+                    },
+                })
+            }
+            "read_file" | "list_directory" | "grep_search" | "git_diff" | "git_log"
+            | "git_show" | "git_blame" => {
                 let root = self
                     .sandbox_root
                     .as_ref()
@@ -201,6 +403,10 @@ impl ToolExecutorPort for BuiltinToolExecutorAdapter {
                     "read_file" => fs_read::read_file(&args, root),
                     "list_directory" => fs_list::list_directory(&args, root),
                     "grep_search" => fs_grep::grep_search(&args, root),
+                    "git_diff" => git::git_diff(&args, root),
+                    "git_log" => git::git_log(&args, root),
+                    "git_show" => git::git_show(&args, root),
+                    "git_blame" => git::git_blame(&args, root),
                     _ => unreachable!(),
                 };
                 match result {
@@ -266,6 +472,36 @@ mod tests {
         assert!(tools.iter().all(|t| t.name.starts_with(BUILTIN_PREFIX)));
     }
 
+    #[tokio::test]
+    async fn web_search_listed_only_when_configured() {
+        use gglib_core::domain::WebSearchProvider;
+
+        let adapter = BuiltinToolExecutorAdapter::default();
+        let tools = adapter.list_tools().await;
+        assert!(tools.iter().all(|t| t.name != "builtin:web_search"));
+        assert!(
+            adapter
+                .execute(&make_call("builtin:web_search", json!({ "query": "rust" })))
+                .await
+                .is_err()
+        );
+
+        let adapter = adapter.with_web_search(
+            reqwest::Client::new(),
+            WebSearchConfig::new(WebSearchProvider::DuckDuckGo),
+        );
+        let names: Vec<_> = adapter
+            .list_tools()
+            .await
+            .into_iter()
+            .map(|t| t.name)
+            .collect();
+        assert_eq!(
+            names,
+            vec!["builtin:get_current_time", "builtin:web_search"]
+        );
+    }
+
     #[tokio::test]
     async fn bare_definitions_have_no_prefix() {
         for t in BuiltinToolExecutorAdapter::bare_definitions() {
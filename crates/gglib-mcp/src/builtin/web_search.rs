@@ -0,0 +1,344 @@
+//! `builtin:web_search` tool implementation.
+//!
+//! Queries go to the provider in the `web_search` setting: a `SearxNG`
+//! instance (JSON API), the Brave Search API, or `DuckDuckGo`'s HTML page.
+//! Whatever the provider, hits are filtered through the configured domain
+//! allow/deny lists, capped at the configured count, and returned as one
+//! JSON document with numbered results so the model can cite `[1]`, `[2]`…
+//! by index and URL.
+//!
+//! Response parsing is kept in plain functions over the raw body so it can
+//! be tested without the network.
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use dom_query::Document;
+use gglib_core::domain::{WebSearchConfig, WebSearchProvider, WebSearchResult};
+use serde_json::{Value, json};
+use url::Url;
+
+/// How long a provider may take to answer before the search fails.
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(15);
+
+const BRAVE_ENDPOINT: &str = "https://api.search.brave.com/res/v1/web/search";
+const DUCKDUCKGO_ENDPOINT: &str = "https://html.duckduckgo.com/html/";
+
+/// `DuckDuckGo` serves an empty page to clients without a browser-like agent.
+const DUCKDUCKGO_USER_AGENT: &str = "Mozilla/5.0 (compatible; gglib web_search)";
+
+/// A configured search provider plus the HTTP client used to reach it.
+#[derive(Debug, Clone)]
+pub(super) struct WebSearcher {
+    client: reqwest::Client,
+    config: WebSearchConfig,
+}
+
+impl WebSearcher {
+    pub(super) const fn new(client: reqwest::Client, config: WebSearchConfig) -> Self {
+        Self { client, config }
+    }
+
+    /// Run the `query` argument against the provider and render the results.
+    pub(super) async fn search(&self, args: &HashMap<String, Value>) -> Result<String, String> {
+        let query = args
+            .get("query")
+            .and_then(Value::as_str)
+            .map(str::trim)
+            .filter(|q| !q.is_empty())
+            .ok_or("missing required argument 'query'")?;
+        let limit = self.config.effective_max_results();
+        let wanted = args
+            .get("max_results")
+            .and_then(Value::as_u64)
+            .map_or(limit, |n| u32::try_from(n).unwrap_or(limit).clamp(1, limit));
+
+        let hits = match &self.config.provider {
+            WebSearchProvider::Searxng { url } => self.searxng(url, query).await?,
+            WebSearchProvider::Brave { api_key } => self.brave(api_key, query, limit).await?,
+            WebSearchProvider::DuckDuckGo => self.duckduckgo(query).await?,
+        };
+
+        let results = select_results(&self.config, hits, wanted as usize);
+        Ok(render(query, self.config.provider.name(), &results))
+    }
+
+    async fn searxng(&self, base: &str, query: &str) -> Result<Vec<WebSearchResult>, String> {
+        let endpoint = format!("{}/search", base.trim().trim_end_matches('/'));
+        let url = Url::parse_with_params(&endpoint, [("q", query), ("format", "json")])
+            .map_err(|e| format!("invalid SearxNG URL: {e}"))?;
+        let body = self.get_json(self.client.get(url)).await?;
+        Ok(parse_searxng(&body))
+    }
+
+    async fn brave(
+        &self,
+        api_key: &str,
+        query: &str,
+        count: u32,
+    ) -> Result<Vec<WebSearchResult>, String> {
+        let count = count.to_string();
+        let url = Url::parse_with_params(BRAVE_ENDPOINT, [("q", query), ("count", &count)])
+            .map_err(|e| format!("invalid search URL: {e}"))?;
+        let request = self
+            .client
+            .get(url)
+            .header("Accept", "application/json")
+            .header("X-Subscription-Token", api_key);
+        let body = self.get_json(request).await?;
+        Ok(parse_brave(&body))
+    }
+
+    async fn duckduckgo(&self, query: &str) -> Result<Vec<WebSearchResult>, String> {
+        let url = Url::parse_with_params(DUCKDUCKGO_ENDPOINT, [("q", query)])
+            .map_err(|e| format!("invalid search URL: {e}"))?;
+        let response = self
+            .client
+            .get(url)
+            .header("User-Agent", DUCKDUCKGO_USER_AGENT)
+            .timeout(REQUEST_TIMEOUT)
+            .send()
+            .await
+            .and_then(reqwest::Response::error_for_status)
+            .map_err(|e| format!("web search request failed: {e}"))?;
+        let html = response
+            .text()
+            .await
+            .map_err(|e| format!("failed to read search results: {e}"))?;
+        Ok(parse_duckduckgo(&html))
+    }
+
+    async fn get_json(&self, request: reqwest::RequestBuilder) -> Result<Value, String> {
+        request
+            .timeout(REQUEST_TIMEOUT)
+            .send()
+            .await
+            .and_then(reqwest::Response::error_for_status)
+            .map_err(|e| format!("web search request failed: {e}"))?
+            .json()
+            .await
+            .map_err(|e| format!("search provider returned invalid JSON: {e}"))
+    }
+}
+
+// =============================================================================
+// Provider responses
+// =============================================================================
+
+/// Parse a `SearxNG` `format=json` response.
+fn parse_searxng(body: &Value) -> Vec<WebSearchResult> {
+    json_results(body.get("results"), "content")
+}
+
+/// Parse a Brave Search API response. Descriptions carry `<strong>` markup
+/// around matched terms, which is stripped.
+fn parse_brave(body: &Value) -> Vec<WebSearchResult> {
+    let mut results = json_results(body.pointer("/web/results"), "description");
+    for result in &mut results {
+        result.title = html_to_text(&result.title);
+        result.snippet = html_to_text(&result.snippet);
+    }
+    results
+}
+
+fn json_results(list: Option<&Value>, snippet_key: &str) -> Vec<WebSearchResult> {
+    let field = |item: &Value, key: &str| {
+        item.get(key)
+            .and_then(Value::as_str)
+            .unwrap_or_default()
+            .trim()
+            .to_string()
+    };
+    list.and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .map(|item| WebSearchResult {
+            title: field(item, "title"),
+            url: field(item, "url"),
+            snippet: field(item, snippet_key),
+        })
+        .filter(|r| !r.url.is_empty())
+        .collect()
+}
+
+/// Parse `DuckDuckGo`'s HTML results page, skipping ads.
+///
+/// Result links point at a `/l/?uddg=<target>` redirect; the target is
+/// decoded so the model sees (and cites) the real URL.
+fn parse_duckduckgo(html: &str) -> Vec<WebSearchResult> {
+    let doc = Document::from(html);
+    doc.select("div.result")
+        .iter()
+        .filter(|result| !result.has_class("result--ad"))
+        .filter_map(|result| {
+            let link = result.select("a.result__a");
+            let href = link.attr("href")?;
+            Some(WebSearchResult {
+                title: collapse_whitespace(&link.text()),
+                url: duckduckgo_target(&href)?,
+                snippet: collapse_whitespace(&result.select(".result__snippet").text()),
+            })
+        })
+        .collect()
+}
+
+fn duckduckgo_target(href: &str) -> Option<String> {
+    let absolute = if href.starts_with("//") {
+        format!("https:{href}")
+    } else {
+        href.to_string()
+    };
+    let url = Url::parse(&absolute).ok()?;
+    if url.path() == "/l/" {
+        return url
+            .query_pairs()
+            .find(|(key, _)| key == "uddg")
+            .map(|(_, target)| target.into_owned());
+    }
+    Some(url.into())
+}
+
+fn html_to_text(html: &str) -> String {
+    collapse_whitespace(&Document::fragment(html).text())
+}
+
+fn collapse_whitespace(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+// =============================================================================
+// Result shaping
+// =============================================================================
+
+/// Apply the domain lists, drop repeated URLs, keep the first `limit` hits
+/// and shorten snippets to the configured length.
+fn select_results(
+    config: &WebSearchConfig,
+    hits: Vec<WebSearchResult>,
+    limit: usize,
+) -> Vec<WebSearchResult> {
+    let max_chars = config.effective_snippet_chars() as usize;
+    let mut seen = std::collections::HashSet::new();
+    hits.into_iter()
+        .filter(|hit| {
+            Url::parse(&hit.url)
+                .ok()
+                .and_then(|url| url.host_str().map(|host| config.allows_host(host)))
+                .unwrap_or(false)
+        })
+        .filter(|hit| seen.insert(hit.url.clone()))
+        .take(limit)
+        .map(|mut hit| {
+            if hit.snippet.chars().count() > max_chars {
+                hit.snippet = hit.snippet.chars().take(max_chars).collect::<String>() + "…";
+            }
+            hit
+        })
+        .collect()
+}
+
+fn render(query: &str, provider: &str, results: &[WebSearchResult]) -> String {
+    let results: Vec<Value> = results
+        .iter()
+        .enumerate()
+        .map(|(i, r)| {
+            json!({
+                "index": i + 1,
+                "title": r.title,
+                "url": r.url,
+                "snippet": r.snippet,
+            })
+        })
+        .collect();
+    json!({
+        "query": query,
+        "provider": provider,
+        "results": results,
+    })
+    .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hit(title: &str, url: &str, snippet: &str) -> WebSearchResult {
+        WebSearchResult {
+            title: title.to_string(),
+            url: url.to_string(),
+            snippet: snippet.to_string(),
+        }
+    }
+
+    #[test]
+    fn parses_duckduckgo_html_and_skips_ads() {
+        let html = r#"
+            <div class="result result--ad">
+              <h2><a class="result__a" href="https://ads.example.com/">Ad</a></h2>
+            </div>
+            <div class="result results_links web-result">
+              <h2 class="result__title">
+                <a class="result__a" href="//duckduckgo.com/l/?uddg=https%3A%2F%2Fwww.rust-lang.org%2Flearn&amp;rut=abc">
+                  Learn <b>Rust</b>
+                </a>
+              </h2>
+              <a class="result__snippet" href="/l/">The official   Rust book.</a>
+            </div>"#;
+        let results = parse_duckduckgo(html);
+        assert_eq!(
+            results,
+            vec![hit(
+                "Learn Rust",
+                "https://www.rust-lang.org/learn",
+                "The official Rust book."
+            )]
+        );
+    }
+
+    #[test]
+    fn parses_json_providers() {
+        let searxng = json!({
+            "results": [
+                { "title": "A", "url": "https://a.example/", "content": "first" },
+                { "title": "No URL" }
+            ]
+        });
+        let results = parse_searxng(&searxng);
+        assert_eq!(results, vec![hit("A", "https://a.example/", "first")]);
+
+        let brave = json!({
+            "web": { "results": [
+                { "title": "B", "url": "https://b.example/", "description": "a <strong>bold</strong> &amp; claim" }
+            ]}
+        });
+        assert_eq!(
+            parse_brave(&brave),
+            vec![hit("B", "https://b.example/", "a bold & claim")]
+        );
+    }
+
+    #[test]
+    fn select_results_filters_dedupes_and_truncates() {
+        let mut config = WebSearchConfig::new(WebSearchProvider::DuckDuckGo);
+        config.deny_domains = vec!["spam.example".to_string()];
+        config.max_snippet_chars = Some(5);
+
+        let hits = vec![
+            hit("t", "https://spam.example/x", "nope"),
+            hit("t", "https://docs.example/a", "abcdefgh"),
+            hit("t", "https://docs.example/a", "again"),
+            hit("t", "not a url", "bad"),
+            hit("t", "https://docs.example/b", "ok"),
+            hit("t", "https://docs.example/c", "over the limit"),
+        ];
+        let results = select_results(&config, hits, 2);
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].url, "https://docs.example/a");
+        assert_eq!(results[0].snippet, "abcde…");
+        assert_eq!(results[1].url, "https://docs.example/b");
+
+        let rendered: Value = serde_json::from_str(&render("q", "duckduckgo", &results)).unwrap();
+        assert_eq!(rendered["results"][1]["index"], 2);
+        assert_eq!(rendered["provider"], "duckduckgo");
+    }
+}
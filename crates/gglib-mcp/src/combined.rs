@@ -4,15 +4,17 @@
 //! | Prefix        | Executor                   |
 //! |---------------|----------------------------|
 //! | `"builtin:"`  | [`BuiltinToolExecutorAdapter`] |
+//! | `"plugin:"`   | WASM plugin executor (optional) |
 //! | `"{int}:"`    | [`McpToolExecutorAdapter`]     |
 //!
-//! The `:` separator is unambiguous in both cases: MCP tool names are
+//! The `:` separator is unambiguous in every case: MCP tool names are
 //! `[a-zA-Z0-9_-]+` and cannot contain `:`.
 
 use std::sync::Arc;
 
 use anyhow::anyhow;
 use async_trait::async_trait;
+use gglib_core::domain::agent::PLUGIN_PREFIX;
 use gglib_core::ports::ToolExecutorPort;
 use gglib_core::{ToolCall, ToolDefinition, ToolResult};
 
@@ -24,14 +26,16 @@ use crate::tool_executor::McpToolExecutorAdapter;
 // Executor
 // =============================================================================
 
-/// Combines the built-in and MCP executors into a single [`ToolExecutorPort`].
+/// Combines the built-in, MCP and plugin executors into a single
+/// [`ToolExecutorPort`].
 ///
-/// `list_tools()` merges both tool sets.  `execute()` dispatches to the
-/// appropriate executor by inspecting the `"builtin:"` prefix — no scan of
-/// the tool list is required.
+/// `list_tools()` merges the tool sets.  `execute()` dispatches to the
+/// appropriate executor by inspecting the name prefix — no scan of the tool
+/// list is required.
 pub struct CombinedToolExecutor {
     builtin: BuiltinToolExecutorAdapter,
     mcp: McpToolExecutorAdapter,
+    plugins: Option<Arc<dyn ToolExecutorPort>>,
 }
 
 impl CombinedToolExecutor {
@@ -40,6 +44,7 @@ impl CombinedToolExecutor {
         Self {
             builtin: BuiltinToolExecutorAdapter::default(),
             mcp: McpToolExecutorAdapter::new(mcp),
+            plugins: None,
         }
     }
 
@@ -48,26 +53,66 @@ impl CombinedToolExecutor {
         Self {
             builtin: BuiltinToolExecutorAdapter::with_sandbox(root),
             mcp: McpToolExecutorAdapter::new(mcp),
+            plugins: None,
         }
     }
+
+    /// Also expose the built-in `web_search` tool using `config`.
+    #[must_use]
+    pub fn with_web_search(
+        mut self,
+        client: reqwest::Client,
+        config: gglib_core::domain::WebSearchConfig,
+    ) -> Self {
+        self.builtin = self.builtin.with_web_search(client, config);
+        self
+    }
+
+    /// Also expose the built-in `fetch_url` tool using `fetcher`.
+    #[must_use]
+    pub fn with_url_fetch(mut self, fetcher: crate::builtin::UrlFetcher) -> Self {
+        self.builtin = self.builtin.with_url_fetch(fetcher);
+        self
+    }
+
+    /// Also expose WASM plugin tools, which `plugins` lists and executes
+    /// under the `"plugin:"` prefix.
+    #[must_use]
+    pub fn with_plugins(mut self, plugins: Arc<dyn ToolExecutorPort>) -> Self {
+        self.plugins = Some(plugins);
+        self
+    }
 }
 
 #[async_trait]
 impl ToolExecutorPort for CombinedToolExecutor {
     async fn list_tools(&self) -> Vec<ToolDefinition> {
-        let (builtin, mcp) = tokio::join!(self.builtin.list_tools(), self.mcp.list_tools());
-        builtin.into_iter().chain(mcp).collect()
+        let plugins = async {
+            match &self.plugins {
+                Some(plugins) => plugins.list_tools().await,
+                None => Vec::new(),
+            }
+        };
+        let (builtin, mcp, plugins) =
+            tokio::join!(self.builtin.list_tools(), self.mcp.list_tools(), plugins);
+        builtin.into_iter().chain(mcp).chain(plugins).collect()
     }
 
     async fn execute(&self, call: &ToolCall) -> anyhow::Result<ToolResult> {
         if call.name.starts_with(BUILTIN_PREFIX) {
             self.builtin.execute(call).await
+        } else if call.name.starts_with(PLUGIN_PREFIX) {
+            let plugins = self
+                .plugins
+                .as_ref()
+                .ok_or_else(|| anyhow!("no plugins are loaded for '{}'", call.name))?;
+            plugins.execute(call).await
         } else if call.name.contains(':') {
             self.mcp.execute(call).await
         } else {
             Err(anyhow!(
                 "tool name '{}' has no recognised prefix; \
-                 expected 'builtin:<name>' or '<server_id>:<name>'",
+                 expected 'builtin:<name>', 'plugin:<plugin>.<name>' or '<server_id>:<name>'",
                 call.name
             ))
         }
@@ -94,4 +139,12 @@ mod tests {
         assert!(!name.starts_with(BUILTIN_PREFIX));
         assert!(name.contains(':'));
     }
+
+    #[test]
+    fn plugin_name_detected_before_the_mcp_colon_check() {
+        let name = "plugin:jq.query";
+        assert!(name.starts_with(PLUGIN_PREFIX));
+        assert!(!name.starts_with(BUILTIN_PREFIX));
+        assert!(!"builtin:get_current_time".starts_with(PLUGIN_PREFIX));
+    }
 }
@@ -20,7 +20,7 @@ pub use gglib_core::{
 pub use gglib_core::ports::{ResolutionAttempt, ResolutionStatus};
 
 // Re-export this crate's public types
-pub use builtin::BuiltinToolExecutorAdapter;
+pub use builtin::{BuiltinToolExecutorAdapter, UrlFetcher};
 pub use combined::CombinedToolExecutor;
 pub use service::{McpServerInfo, McpService};
 pub use tool_executor::McpToolExecutorAdapter;
@@ -0,0 +1,67 @@
+//! Error type for plugin installation, loading and execution.
+
+use std::path::PathBuf;
+
+use thiserror::Error;
+
+/// Errors from the plugin store and the WASM host.
+#[derive(Debug, Error)]
+pub enum PluginError {
+    /// The plugin name is not usable as a directory and tool-name segment.
+    #[error(
+        "invalid plugin name '{0}': use 1-64 lowercase letters, digits, '-' or '_', \
+         starting with a letter"
+    )]
+    InvalidName(String),
+
+    /// No plugin with this name is installed.
+    #[error("plugin '{0}' is not installed")]
+    NotFound(String),
+
+    /// A plugin with this name is already installed.
+    #[error("plugin '{0}' is already installed (remove it first or pass --force)")]
+    AlreadyInstalled(String),
+
+    /// This build has no WASM runtime.
+    #[error("this build of gglib has no WASM plugin support (rebuild with the `wasm` feature)")]
+    Unsupported,
+
+    /// The module is not a valid plugin: it failed to compile, imports host
+    /// functions, or lacks a required export.
+    #[error("invalid plugin module: {0}")]
+    InvalidModule(String),
+
+    /// The plugin misbehaved while running: a trap, an exhausted limit, or
+    /// output that does not follow the plugin ABI.
+    #[error("plugin failed: {0}")]
+    Execution(String),
+
+    /// The tool ran and reported a failure of its own.
+    #[error("{0}")]
+    ToolFailed(String),
+
+    /// Reading or writing the plugin directory failed.
+    #[error("I/O error at {path}: {source}")]
+    Io {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+
+    /// A `plugin.json` manifest could not be read or written.
+    #[error("invalid manifest at {path}: {source}")]
+    Manifest {
+        path: PathBuf,
+        #[source]
+        source: serde_json::Error,
+    },
+}
+
+impl PluginError {
+    pub(crate) fn io(path: impl Into<PathBuf>, source: std::io::Error) -> Self {
+        Self::Io {
+            path: path.into(),
+            source,
+        }
+    }
+}
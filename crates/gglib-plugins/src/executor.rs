@@ -0,0 +1,170 @@
+//! [`PluginToolExecutor`] — exposes loaded plugins as a [`ToolExecutorPort`].
+//!
+//! Tools are listed as `plugin:<plugin>.<tool>`. Calls run on the blocking
+//! thread pool because a WASM call is synchronous CPU work. Anything that
+//! goes wrong inside a plugin — a reported error, a trap, an exhausted
+//! limit — becomes a failed [`ToolResult`] the model can react to.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use anyhow::anyhow;
+use async_trait::async_trait;
+
+use gglib_core::ports::ToolExecutorPort;
+use gglib_core::{ToolCall, ToolDefinition, ToolResult};
+
+use crate::manifest::split_tool_name;
+use crate::store::PluginStore;
+use crate::wasm::WasmPlugin;
+
+/// Serves the tools of every loaded plugin.
+#[derive(Debug, Clone, Default)]
+pub struct PluginToolExecutor {
+    plugins: BTreeMap<String, Arc<WasmPlugin>>,
+}
+
+impl PluginToolExecutor {
+    /// Load every plugin installed in `store`.
+    ///
+    /// Plugins that fail to load are skipped with a warning so one broken
+    /// module does not take the others down.
+    #[must_use]
+    pub fn load(store: &PluginStore) -> Self {
+        let manifests = match store.list() {
+            Ok(manifests) => manifests,
+            Err(e) => {
+                tracing::warn!("failed to list plugins: {e}");
+                return Self::default();
+            }
+        };
+
+        let mut executor = Self::default();
+        for manifest in manifests {
+            match store.load(&manifest.name) {
+                Ok(plugin) => executor.insert(manifest.name, plugin),
+                Err(e) => tracing::warn!(plugin = %manifest.name, "plugin not loaded: {e}"),
+            }
+        }
+        executor
+    }
+
+    /// Add `plugin` under `name`, replacing any plugin of that name.
+    pub fn insert(&mut self, name: impl Into<String>, plugin: WasmPlugin) {
+        self.plugins.insert(name.into(), Arc::new(plugin));
+    }
+
+    /// Names of the loaded plugins.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.plugins.keys().map(String::as_str)
+    }
+
+    /// Number of loaded plugins.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.plugins.len()
+    }
+
+    /// Whether no plugin is loaded.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.plugins.is_empty()
+    }
+}
+
+#[async_trait]
+impl ToolExecutorPort for PluginToolExecutor {
+    async fn list_tools(&self) -> Vec<ToolDefinition> {
+        self.plugins
+            .iter()
+            .flat_map(|(name, plugin)| plugin.tools().iter().map(|t| t.to_definition(name)))
+            .collect()
+    }
+
+    async fn execute(&self, call: &ToolCall) -> anyhow::Result<ToolResult> {
+        let (plugin_name, tool) = split_tool_name(&call.name)
+            .ok_or_else(|| anyhow!("'{}' is not a plugin tool name", call.name))?;
+        let plugin = self
+            .plugins
+            .get(plugin_name)
+            .ok_or_else(|| anyhow!("plugin '{plugin_name}' is not loaded"))?;
+        if !plugin.tools().iter().any(|t| t.name == tool) {
+            return Err(anyhow!("plugin '{plugin_name}' has no tool '{tool}'"));
+        }
+
+        let plugin = Arc::clone(plugin);
+        let tool = tool.to_owned();
+        let arguments = call.arguments.clone();
+        let outcome = tokio::task::spawn_blocking(move || plugin.call(&tool, &arguments)).await?;
+
+        Ok(match outcome {
+            Ok(content) => ToolResult {
+                tool_call_id: call.id.clone(),
+                content,
+                success: true,
+            },
+            Err(e) => ToolResult {
+                tool_call_id: call.id.clone(),
+                content: e.to_string(),
+                success: false,
+            },
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn call(name: &str) -> ToolCall {
+        ToolCall {
+            id: "c1".into(),
+            name: name.into(),
+            arguments: serde_json::json!({}),
+        }
+    }
+
+    #[tokio::test]
+    async fn an_empty_executor_rejects_calls() {
+        let executor = PluginToolExecutor::default();
+        assert!(executor.list_tools().await.is_empty());
+        assert!(executor.execute(&call("plugin:jq.query")).await.is_err());
+        assert!(executor.execute(&call("builtin:query")).await.is_err());
+    }
+
+    #[cfg(feature = "wasm")]
+    #[tokio::test]
+    async fn plugin_tools_are_listed_and_called_by_qualified_name() {
+        use crate::manifest::PluginLimits;
+
+        let wasm = wat::parse_str(
+            r#"(module
+                 (memory (export "memory") 1)
+                 (data (i32.const 0) "[{\"name\":\"hello\",\"description\":\"Greets\"}]")
+                 (data (i32.const 100) "{\"ok\":\"hi there\"}")
+                 (func (export "gglib_alloc") (param i32) (result i32) (i32.const 1024))
+                 (func (export "gglib_tools") (result i64) (i64.const 41))
+                 (func (export "gglib_call") (param i32 i32 i32 i32) (result i64)
+                   (i64.or (i64.shl (i64.const 100) (i64.const 32)) (i64.const 17))))"#,
+        )
+        .unwrap();
+        let mut executor = PluginToolExecutor::default();
+        executor.insert(
+            "greeter",
+            WasmPlugin::load(&wasm, PluginLimits::default()).unwrap(),
+        );
+
+        let tools = executor.list_tools().await;
+        assert_eq!(tools.len(), 1);
+        assert_eq!(tools[0].name, "plugin:greeter.hello");
+        assert_eq!(tools[0].description.as_deref(), Some("Greets"));
+
+        let result = executor
+            .execute(&call("plugin:greeter.hello"))
+            .await
+            .unwrap();
+        assert!(result.success);
+        assert_eq!(result.content, "hi there");
+        assert!(executor.execute(&call("plugin:greeter.bye")).await.is_err());
+    }
+}
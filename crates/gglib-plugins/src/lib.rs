@@ -0,0 +1,34 @@
+#![doc = include_str!(concat!(env!("OUT_DIR"), "/README_GENERATED.md"))]
+#![deny(unsafe_code)]
+
+//! Sandboxed WASM plugins that contribute tools to gglib agents.
+//!
+//! [`PluginStore`] installs, lists and removes plugins on disk;
+//! [`PluginToolExecutor`] loads them and serves their tools through
+//! [`ToolExecutorPort`](gglib_core::ports::ToolExecutorPort). The wasmtime
+//! host is behind the `wasm` feature — without it the store still lists and
+//! removes plugins, but installing or loading one reports
+//! [`PluginError::Unsupported`].
+
+mod error;
+mod executor;
+mod manifest;
+mod store;
+
+#[cfg(feature = "wasm")]
+mod wasm;
+#[cfg(not(feature = "wasm"))]
+#[path = "wasm_disabled.rs"]
+mod wasm;
+
+pub use error::PluginError;
+pub use executor::PluginToolExecutor;
+pub use manifest::{
+    PluginLimits, PluginManifest, PluginTool, qualified_tool_name, split_tool_name,
+    validate_plugin_name,
+};
+pub use store::{InstallOptions, PluginStore};
+pub use wasm::WasmPlugin;
+
+/// Whether this build can install and run plugins.
+pub const WASM_SUPPORTED: bool = cfg!(feature = "wasm");
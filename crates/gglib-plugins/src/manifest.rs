@@ -0,0 +1,167 @@
+//! Plugin manifest and per-plugin resource limits.
+//!
+//! Every installed plugin has a `plugin.json` next to its module. The
+//! manifest records the limits chosen at install time and the tools the
+//! module exported when it was validated, so `gglib plugins list` can show
+//! them without compiling anything.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use gglib_core::domain::agent::{PLUGIN_PREFIX, ToolDefinition};
+
+use crate::error::PluginError;
+
+/// Longest accepted plugin name.
+const MAX_NAME_LEN: usize = 64;
+
+/// Resource limits applied to every call into a plugin.
+///
+/// Each call runs in a fresh instance, so the limits bound one call rather
+/// than the plugin's lifetime.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PluginLimits {
+    /// Maximum linear memory, in MiB.
+    pub memory_mb: u32,
+    /// Fuel budget — roughly the number of WASM instructions a call may run.
+    pub fuel: u64,
+    /// Wall-clock limit for one call, in milliseconds.
+    pub timeout_ms: u64,
+}
+
+impl PluginLimits {
+    /// Default memory ceiling.
+    pub const DEFAULT_MEMORY_MB: u32 = 64;
+    /// Default fuel budget.
+    pub const DEFAULT_FUEL: u64 = 1_000_000_000;
+    /// Default wall-clock limit.
+    pub const DEFAULT_TIMEOUT_MS: u64 = 10_000;
+
+    /// Memory ceiling in bytes.
+    #[must_use]
+    pub fn memory_bytes(&self) -> usize {
+        usize::try_from(u64::from(self.memory_mb) * 1024 * 1024).unwrap_or(usize::MAX)
+    }
+}
+
+impl Default for PluginLimits {
+    fn default() -> Self {
+        Self {
+            memory_mb: Self::DEFAULT_MEMORY_MB,
+            fuel: Self::DEFAULT_FUEL,
+            timeout_ms: Self::DEFAULT_TIMEOUT_MS,
+        }
+    }
+}
+
+/// A tool exported by a plugin, as returned by its `gglib_tools` export.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PluginTool {
+    /// Tool name, unique within the plugin.
+    pub name: String,
+    /// Description sent to the model.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// JSON Schema of the arguments.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub input_schema: Option<serde_json::Value>,
+}
+
+impl PluginTool {
+    /// The tool as the agent sees it, named `plugin:<plugin>.<tool>`.
+    #[must_use]
+    pub fn to_definition(&self, plugin: &str) -> ToolDefinition {
+        ToolDefinition {
+            name: qualified_tool_name(plugin, &self.name),
+            description: self.description.clone(),
+            input_schema: self.input_schema.clone(),
+            title: None,
+        }
+    }
+}
+
+/// Contents of an installed plugin's `plugin.json`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PluginManifest {
+    /// Plugin name — the directory name and the tool-name namespace.
+    pub name: String,
+    /// Path the module was installed from.
+    pub source: String,
+    /// When the plugin was installed.
+    pub installed_at: DateTime<Utc>,
+    /// Limits applied to each call.
+    #[serde(default)]
+    pub limits: PluginLimits,
+    /// Tools the module exported at install time.
+    #[serde(default)]
+    pub tools: Vec<PluginTool>,
+}
+
+/// Check that `name` can be used as a plugin directory and tool namespace.
+///
+/// # Errors
+///
+/// [`PluginError::InvalidName`] unless `name` is 1–64 characters of
+/// lowercase ASCII letters, digits, `-` and `_`, starting with a letter.
+pub fn validate_plugin_name(name: &str) -> Result<(), PluginError> {
+    let valid = name.len() <= MAX_NAME_LEN
+        && name.starts_with(|c: char| c.is_ascii_lowercase())
+        && name
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-' || c == '_');
+    if valid {
+        Ok(())
+    } else {
+        Err(PluginError::InvalidName(name.to_string()))
+    }
+}
+
+/// `plugin:<plugin>.<tool>`.
+#[must_use]
+pub fn qualified_tool_name(plugin: &str, tool: &str) -> String {
+    format!("{PLUGIN_PREFIX}{plugin}.{tool}")
+}
+
+/// Split `plugin:<plugin>.<tool>` into its plugin and tool parts.
+#[must_use]
+pub fn split_tool_name(name: &str) -> Option<(&str, &str)> {
+    name.strip_prefix(PLUGIN_PREFIX)?.split_once('.')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn plugin_names_are_restricted() {
+        assert!(validate_plugin_name("jq").is_ok());
+        assert!(validate_plugin_name("word-count_2").is_ok());
+        for bad in [
+            "",
+            "Upper",
+            "2fast",
+            "dot.ted",
+            "../escape",
+            &"a".repeat(65),
+        ] {
+            assert!(validate_plugin_name(bad).is_err(), "{bad:?}");
+        }
+    }
+
+    #[test]
+    fn tool_names_round_trip() {
+        let name = qualified_tool_name("jq", "query");
+        assert_eq!(name, "plugin:jq.query");
+        assert_eq!(split_tool_name(&name), Some(("jq", "query")));
+        assert_eq!(split_tool_name("builtin:query"), None);
+    }
+
+    #[test]
+    fn missing_limits_fall_back_to_defaults() {
+        let limits: PluginLimits = serde_json::from_str(r#"{"memory_mb": 8}"#).unwrap();
+        assert_eq!(limits.memory_mb, 8);
+        assert_eq!(limits.fuel, PluginLimits::DEFAULT_FUEL);
+        assert_eq!(limits.memory_bytes(), 8 * 1024 * 1024);
+    }
+}
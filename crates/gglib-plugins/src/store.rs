@@ -0,0 +1,283 @@
+//! On-disk store of installed plugins.
+//!
+//! ```text
+//! <plugins_dir>/
+//!   <name>/
+//!     plugin.wasm   — the module, copied at install time
+//!     plugin.json   — PluginManifest (limits + exported tools)
+//! ```
+//!
+//! Installing compiles the module once to check the ABI and record its
+//! tools, so a module that would fail at load time is rejected up front.
+
+use std::path::{Path, PathBuf};
+
+use chrono::Utc;
+
+use crate::error::PluginError;
+use crate::manifest::{PluginLimits, PluginManifest, validate_plugin_name};
+use crate::wasm::WasmPlugin;
+
+const MODULE_FILE: &str = "plugin.wasm";
+const MANIFEST_FILE: &str = "plugin.json";
+
+/// Options for [`PluginStore::install`].
+#[derive(Debug, Clone, Default)]
+pub struct InstallOptions {
+    /// Plugin name; defaults to the module's file stem.
+    pub name: Option<String>,
+    /// Limits applied to each call.
+    pub limits: PluginLimits,
+    /// Replace an installed plugin of the same name.
+    pub force: bool,
+}
+
+/// Installed plugins under one directory.
+#[derive(Debug, Clone)]
+pub struct PluginStore {
+    root: PathBuf,
+}
+
+impl PluginStore {
+    /// A store rooted at `root` (usually `gglib_core::paths::plugins_dir()`).
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    /// Directory holding the installed plugins.
+    #[must_use]
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Manifests of every installed plugin, sorted by name.
+    ///
+    /// A missing store directory means nothing is installed. Entries with an
+    /// unreadable manifest are skipped with a warning.
+    ///
+    /// # Errors
+    ///
+    /// [`PluginError::Io`] when the store directory cannot be read.
+    pub fn list(&self) -> Result<Vec<PluginManifest>, PluginError> {
+        let entries = match std::fs::read_dir(&self.root) {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(PluginError::io(&self.root, e)),
+        };
+
+        let mut manifests = Vec::new();
+        for entry in entries.flatten() {
+            let Some(name) = entry.file_name().to_str().map(str::to_owned) else {
+                continue;
+            };
+            if !entry.path().is_dir() {
+                continue;
+            }
+            match self.get(&name) {
+                Ok(manifest) => manifests.push(manifest),
+                Err(e) => tracing::warn!(plugin = %name, "skipping plugin: {e}"),
+            }
+        }
+        manifests.sort_by(|a, b| a.name.cmp(&b.name));
+        Ok(manifests)
+    }
+
+    /// Manifest of the plugin called `name`.
+    ///
+    /// # Errors
+    ///
+    /// [`PluginError::NotFound`] when it is not installed, and
+    /// [`PluginError::Manifest`] when its manifest is unreadable.
+    pub fn get(&self, name: &str) -> Result<PluginManifest, PluginError> {
+        validate_plugin_name(name)?;
+        let path = self.dir(name).join(MANIFEST_FILE);
+        let text = match std::fs::read_to_string(&path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                return Err(PluginError::NotFound(name.to_string()));
+            }
+            Err(e) => return Err(PluginError::io(path, e)),
+        };
+        serde_json::from_str(&text).map_err(|source| PluginError::Manifest { path, source })
+    }
+
+    /// Compile the installed plugin called `name` with its recorded limits.
+    ///
+    /// # Errors
+    ///
+    /// As [`get`](Self::get), plus [`PluginError::Io`] for an unreadable
+    /// module and the errors of [`WasmPlugin::load`].
+    pub fn load(&self, name: &str) -> Result<WasmPlugin, PluginError> {
+        let manifest = self.get(name)?;
+        let path = self.dir(name).join(MODULE_FILE);
+        let bytes = std::fs::read(&path).map_err(|e| PluginError::io(path, e))?;
+        WasmPlugin::load(&bytes, manifest.limits)
+    }
+
+    /// Validate the module at `source` and install it.
+    ///
+    /// # Errors
+    ///
+    /// [`PluginError::InvalidName`] for an unusable name,
+    /// [`PluginError::AlreadyInstalled`] when the name is taken and `force`
+    /// is not set, the errors of [`WasmPlugin::load`] for an invalid module,
+    /// and [`PluginError::Io`] when the files cannot be written.
+    pub fn install(
+        &self,
+        source: &Path,
+        options: InstallOptions,
+    ) -> Result<PluginManifest, PluginError> {
+        let name = options.name.unwrap_or_else(|| {
+            source
+                .file_stem()
+                .and_then(|stem| stem.to_str())
+                .unwrap_or_default()
+                .to_string()
+        });
+        validate_plugin_name(&name)?;
+
+        let dir = self.dir(&name);
+        if dir.exists() && !options.force {
+            return Err(PluginError::AlreadyInstalled(name));
+        }
+
+        let bytes = std::fs::read(source).map_err(|e| PluginError::io(source, e))?;
+        let plugin = WasmPlugin::load(&bytes, options.limits)?;
+        let manifest = PluginManifest {
+            name,
+            source: source.display().to_string(),
+            installed_at: Utc::now(),
+            limits: options.limits,
+            tools: plugin.tools().to_vec(),
+        };
+
+        if dir.exists() {
+            std::fs::remove_dir_all(&dir).map_err(|e| PluginError::io(&dir, e))?;
+        }
+        std::fs::create_dir_all(&dir).map_err(|e| PluginError::io(&dir, e))?;
+        let module_path = dir.join(MODULE_FILE);
+        std::fs::write(&module_path, &bytes).map_err(|e| PluginError::io(module_path, e))?;
+        let manifest_path = dir.join(MANIFEST_FILE);
+        let json =
+            serde_json::to_string_pretty(&manifest).map_err(|source| PluginError::Manifest {
+                path: manifest_path.clone(),
+                source,
+            })?;
+        std::fs::write(&manifest_path, json).map_err(|e| PluginError::io(manifest_path, e))?;
+
+        tracing::info!(plugin = %manifest.name, tools = manifest.tools.len(), "plugin installed");
+        Ok(manifest)
+    }
+
+    /// Remove the plugin called `name`, returning its manifest.
+    ///
+    /// # Errors
+    ///
+    /// As [`get`](Self::get), plus [`PluginError::Io`] when the files cannot
+    /// be deleted.
+    pub fn remove(&self, name: &str) -> Result<PluginManifest, PluginError> {
+        let manifest = self.get(name)?;
+        let dir = self.dir(name);
+        std::fs::remove_dir_all(&dir).map_err(|e| PluginError::io(dir, e))?;
+        Ok(manifest)
+    }
+
+    fn dir(&self, name: &str) -> PathBuf {
+        self.root.join(name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn a_missing_directory_lists_nothing() {
+        let tmp = tempfile::tempdir().unwrap();
+        let store = PluginStore::new(tmp.path().join("plugins"));
+        assert!(store.list().unwrap().is_empty());
+    }
+
+    #[test]
+    fn removing_an_unknown_plugin_is_not_found() {
+        let tmp = tempfile::tempdir().unwrap();
+        let store = PluginStore::new(tmp.path());
+        assert!(matches!(
+            store.remove("nope"),
+            Err(PluginError::NotFound(_))
+        ));
+        assert!(matches!(
+            store.remove("../etc"),
+            Err(PluginError::InvalidName(_))
+        ));
+    }
+
+    #[cfg(not(feature = "wasm"))]
+    #[test]
+    fn install_needs_the_wasm_feature() {
+        let tmp = tempfile::tempdir().unwrap();
+        let module = tmp.path().join("echo.wasm");
+        std::fs::write(&module, b"\0asm\x01\0\0\0").unwrap();
+        let store = PluginStore::new(tmp.path().join("plugins"));
+        let err = store
+            .install(&module, InstallOptions::default())
+            .unwrap_err();
+        assert!(matches!(err, PluginError::Unsupported));
+        assert!(store.list().unwrap().is_empty());
+    }
+
+    #[cfg(feature = "wasm")]
+    #[test]
+    fn install_list_and_remove_round_trip() {
+        let tmp = tempfile::tempdir().unwrap();
+        let module = tmp.path().join("Echo.wasm");
+        let wasm = wat::parse_str(
+            r#"(module
+                 (memory (export "memory") 1)
+                 (data (i32.const 0) "[{\"name\":\"echo\"}]")
+                 (func (export "gglib_alloc") (param i32) (result i32) (i32.const 1024))
+                 (func (export "gglib_tools") (result i64) (i64.const 17))
+                 (func (export "gglib_call") (param i32 i32 i32 i32) (result i64) (i64.const 0)))"#,
+        )
+        .unwrap();
+        std::fs::write(&module, wasm).unwrap();
+        let store = PluginStore::new(tmp.path().join("plugins"));
+
+        // The file stem is not a valid name, so one must be given.
+        assert!(matches!(
+            store.install(&module, InstallOptions::default()),
+            Err(PluginError::InvalidName(_))
+        ));
+        let options = InstallOptions {
+            name: Some("echo".into()),
+            limits: PluginLimits {
+                memory_mb: 4,
+                ..PluginLimits::default()
+            },
+            force: false,
+        };
+        let manifest = store.install(&module, options.clone()).unwrap();
+        assert_eq!(manifest.tools[0].name, "echo");
+        assert!(matches!(
+            store.install(&module, options.clone()),
+            Err(PluginError::AlreadyInstalled(_))
+        ));
+        store
+            .install(
+                &module,
+                InstallOptions {
+                    force: true,
+                    ..options
+                },
+            )
+            .unwrap();
+
+        let listed = store.list().unwrap();
+        assert_eq!(listed.len(), 1);
+        assert_eq!(listed[0].limits.memory_mb, 4);
+        assert_eq!(store.load("echo").unwrap().tools().len(), 1);
+
+        store.remove("echo").unwrap();
+        assert!(store.list().unwrap().is_empty());
+    }
+}
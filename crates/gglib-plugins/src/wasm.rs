@@ -0,0 +1,384 @@
+//! wasmtime-backed plugin runtime.
+//!
+//! # Plugin ABI
+//!
+//! A plugin is a core WASM module with **no imports** and these exports:
+//!
+//! | Export | Signature | Purpose |
+//! |--------|-----------|---------|
+//! | `memory` | memory | Linear memory the host reads and writes |
+//! | `gglib_alloc` | `(len: i32) -> i32` | Reserve `len` bytes for host input |
+//! | `gglib_tools` | `() -> i64` | JSON array of tool definitions |
+//! | `gglib_call` | `(name_ptr, name_len, args_ptr, args_len: i32) -> i64` | Run one tool |
+//!
+//! `i64` results pack a pointer into the high 32 bits and a length into the
+//! low 32 bits. `gglib_tools` returns `[{"name", "description",
+//! "input_schema"}, …]`; `gglib_call` receives the tool name and its JSON
+//! arguments and returns `{"ok": <output>}` or `{"error": "<message>"}`. A
+//! string output is passed to the model verbatim, anything else as JSON.
+//!
+//! # Sandboxing
+//!
+//! With no imports a plugin cannot reach the filesystem, network, clock or
+//! environment — it can only compute on the bytes it is given. Every call
+//! runs in a fresh instance bounded by the plugin's [`PluginLimits`]: memory
+//! growth past the ceiling fails, fuel bounds the instructions executed, and
+//! an epoch deadline bounds wall-clock time.
+
+use std::sync::LazyLock;
+use std::time::Duration;
+
+use serde::Deserialize;
+use wasmtime::{
+    Config, Engine, Instance, Memory, Module, Store, StoreLimits, StoreLimitsBuilder, Trap,
+};
+
+use crate::error::PluginError;
+use crate::manifest::{PluginLimits, PluginTool};
+
+/// Interval between epoch ticks, in milliseconds; the granularity of call
+/// timeouts.
+const EPOCH_TICK_MS: u64 = 10;
+
+/// Fuel for reading the tool list at load time.
+const LOAD_FUEL: u64 = 100_000_000;
+
+/// One engine for every plugin, with a background thread advancing its
+/// epoch so per-call deadlines can interrupt runaway code.
+static ENGINE: LazyLock<Result<Engine, String>> = LazyLock::new(|| {
+    let mut config = Config::new();
+    config.consume_fuel(true).epoch_interruption(true);
+    let engine = Engine::new(&config).map_err(|e| format!("{e:#}"))?;
+    let ticker = engine.clone();
+    std::thread::Builder::new()
+        .name("gglib-plugin-epoch".into())
+        .spawn(move || {
+            loop {
+                std::thread::sleep(Duration::from_millis(EPOCH_TICK_MS));
+                ticker.increment_epoch();
+            }
+        })
+        .map_err(|e| format!("failed to start the plugin epoch thread: {e}"))?;
+    Ok(engine)
+});
+
+fn engine() -> Result<&'static Engine, PluginError> {
+    ENGINE
+        .as_ref()
+        .map_err(|e| PluginError::InvalidModule(format!("WASM engine unavailable: {e}")))
+}
+
+/// A compiled plugin module and the tools it exports.
+#[derive(Clone)]
+pub struct WasmPlugin {
+    module: Module,
+    limits: PluginLimits,
+    tools: Vec<PluginTool>,
+}
+
+impl std::fmt::Debug for WasmPlugin {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("WasmPlugin")
+            .field("limits", &self.limits)
+            .field("tools", &self.tools)
+            .finish_non_exhaustive()
+    }
+}
+
+/// What `gglib_call` returns.
+#[derive(Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum CallEnvelope {
+    Ok(serde_json::Value),
+    Error(String),
+}
+
+impl WasmPlugin {
+    /// Compile `bytes`, check the ABI, and read the tool list.
+    ///
+    /// # Errors
+    ///
+    /// [`PluginError::InvalidModule`] when the module does not compile,
+    /// imports anything, or does not export valid tool definitions.
+    pub fn load(bytes: &[u8], limits: PluginLimits) -> Result<Self, PluginError> {
+        let module = Module::new(engine()?, bytes)
+            .map_err(|e| PluginError::InvalidModule(format!("{e:#}")))?;
+        if let Some(import) = module.imports().next() {
+            return Err(PluginError::InvalidModule(format!(
+                "plugins may not import host functions, found '{}::{}'",
+                import.module(),
+                import.name()
+            )));
+        }
+
+        let mut plugin = Self {
+            module,
+            limits,
+            tools: Vec::new(),
+        };
+        let mut call = plugin.instantiate(LOAD_FUEL)?;
+        let tools = call.tools().map_err(|e| match e {
+            PluginError::Execution(msg) => PluginError::InvalidModule(msg),
+            other => other,
+        })?;
+        if tools.is_empty() {
+            return Err(PluginError::InvalidModule(
+                "gglib_tools returned no tools".into(),
+            ));
+        }
+        plugin.tools = tools;
+        Ok(plugin)
+    }
+
+    /// Tools exported by the module.
+    #[must_use]
+    pub fn tools(&self) -> &[PluginTool] {
+        &self.tools
+    }
+
+    /// Run `tool` with `args` in a fresh instance; blocks until it returns.
+    ///
+    /// # Errors
+    ///
+    /// [`PluginError::ToolFailed`] when the tool reports an error, and
+    /// [`PluginError::Execution`] when it traps, exceeds a limit, or breaks
+    /// the ABI.
+    pub fn call(&self, tool: &str, args: &serde_json::Value) -> Result<String, PluginError> {
+        let args = serde_json::to_vec(args).map_err(|e| PluginError::Execution(e.to_string()))?;
+        let mut call = self.instantiate(self.limits.fuel)?;
+        call.call(tool.as_bytes(), &args)
+    }
+
+    fn instantiate(&self, fuel: u64) -> Result<PluginCall, PluginError> {
+        let limits = StoreLimitsBuilder::new()
+            .memory_size(self.limits.memory_bytes())
+            .instances(1)
+            .build();
+        let mut store = Store::new(self.module.engine(), limits);
+        store.limiter(|limits| limits);
+        store.set_fuel(fuel).map_err(execution_error)?;
+        store.set_epoch_deadline(self.limits.timeout_ms.div_ceil(EPOCH_TICK_MS).max(1));
+        store.epoch_deadline_trap();
+
+        let instance = Instance::new(&mut store, &self.module, &[]).map_err(execution_error)?;
+        let memory = instance
+            .get_memory(&mut store, "memory")
+            .ok_or_else(|| PluginError::InvalidModule("missing `memory` export".into()))?;
+        Ok(PluginCall {
+            store,
+            instance,
+            memory,
+        })
+    }
+}
+
+/// One live instance of a plugin.
+struct PluginCall {
+    store: Store<StoreLimits>,
+    instance: Instance,
+    memory: Memory,
+}
+
+impl PluginCall {
+    fn tools(&mut self) -> Result<Vec<PluginTool>, PluginError> {
+        let tools = self
+            .instance
+            .get_typed_func::<(), i64>(&mut self.store, "gglib_tools")
+            .map_err(|e| PluginError::InvalidModule(format!("gglib_tools: {e:#}")))?;
+        let packed = tools.call(&mut self.store, ()).map_err(execution_error)?;
+        let bytes = self.read(packed)?;
+        serde_json::from_slice(&bytes)
+            .map_err(|e| PluginError::Execution(format!("gglib_tools returned invalid JSON: {e}")))
+    }
+
+    fn call(&mut self, name: &[u8], args: &[u8]) -> Result<String, PluginError> {
+        let (name_ptr, name_len) = self.write(name)?;
+        let (args_ptr, args_len) = self.write(args)?;
+        let call = self
+            .instance
+            .get_typed_func::<(i32, i32, i32, i32), i64>(&mut self.store, "gglib_call")
+            .map_err(|e| PluginError::InvalidModule(format!("gglib_call: {e:#}")))?;
+        let packed = call
+            .call(&mut self.store, (name_ptr, name_len, args_ptr, args_len))
+            .map_err(execution_error)?;
+
+        let bytes = self.read(packed)?;
+        match serde_json::from_slice(&bytes) {
+            Ok(CallEnvelope::Ok(serde_json::Value::String(text))) => Ok(text),
+            Ok(CallEnvelope::Ok(value)) => Ok(value.to_string()),
+            Ok(CallEnvelope::Error(message)) => Err(PluginError::ToolFailed(message)),
+            Err(e) => Err(PluginError::Execution(format!(
+                "gglib_call must return {{\"ok\": …}} or {{\"error\": …}}: {e}"
+            ))),
+        }
+    }
+
+    /// Copy `bytes` into guest memory obtained from `gglib_alloc`.
+    fn write(&mut self, bytes: &[u8]) -> Result<(i32, i32), PluginError> {
+        let len = i32::try_from(bytes.len())
+            .map_err(|_| PluginError::Execution("input too large for a plugin".into()))?;
+        let alloc = self
+            .instance
+            .get_typed_func::<i32, i32>(&mut self.store, "gglib_alloc")
+            .map_err(|e| PluginError::InvalidModule(format!("gglib_alloc: {e:#}")))?;
+        let ptr = alloc.call(&mut self.store, len).map_err(execution_error)?;
+        let offset = usize::try_from(ptr)
+            .map_err(|_| PluginError::Execution(format!("gglib_alloc returned {ptr}")))?;
+        self.memory
+            .write(&mut self.store, offset, bytes)
+            .map_err(|_| {
+                PluginError::Execution("gglib_alloc returned memory out of bounds".into())
+            })?;
+        Ok((ptr, len))
+    }
+
+    /// Copy the packed `(ptr << 32) | len` region out of guest memory.
+    fn read(&self, packed: i64) -> Result<Vec<u8>, PluginError> {
+        let packed = packed.cast_unsigned();
+        let ptr = usize::try_from(packed >> 32).unwrap_or(usize::MAX);
+        let len = usize::try_from(packed & 0xffff_ffff).unwrap_or(usize::MAX);
+        self.memory
+            .data(&self.store)
+            .get(ptr..ptr.saturating_add(len))
+            .map(<[u8]>::to_vec)
+            .ok_or_else(|| PluginError::Execution("plugin returned memory out of bounds".into()))
+    }
+}
+
+/// Describe a wasmtime failure, naming the limit when one was hit.
+#[allow(clippy::needless_pass_by_value)] // shaped for `map_err`
+fn execution_error(e: wasmtime::Error) -> PluginError {
+    let message = match e.downcast_ref::<Trap>() {
+        Some(Trap::OutOfFuel) => "fuel limit exceeded".to_string(),
+        Some(Trap::Interrupt) => "time limit exceeded".to_string(),
+        _ => format!("{e:#}"),
+    };
+    PluginError::Execution(message)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Exports `echo` (returns its arguments), `fail`, `spin` (never
+    /// returns) and `grow` (asks for 4 MiB more memory).
+    const TEST_PLUGIN: &str = r#"
+        (module
+          (memory (export "memory") 2)
+          (global $heap (mut i32) (i32.const 4096))
+          (data (i32.const 0) "[{\"name\":\"echo\",\"description\":\"Echo the arguments\"},{\"name\":\"fail\"},{\"name\":\"spin\"},{\"name\":\"grow\"}]")
+          (data (i32.const 1024) "{\"ok\":")
+          (data (i32.const 1100) "{\"error\":\"refused\"}")
+          (func (export "gglib_alloc") (param $len i32) (result i32)
+            (local $ptr i32)
+            (local.set $ptr (global.get $heap))
+            (global.set $heap (i32.add (global.get $heap) (local.get $len)))
+            (local.get $ptr))
+          (func (export "gglib_tools") (result i64)
+            (i64.const 100))
+          (func (export "gglib_call")
+            (param $name i32) (param $name_len i32) (param $args i32) (param $args_len i32)
+            (result i64)
+            (local $out i32)
+            (local $c i32)
+            (local.set $c (i32.load8_u (local.get $name)))
+            ;; 'f' → fail
+            (if (i32.eq (local.get $c) (i32.const 102))
+              (then (return (i64.or (i64.shl (i64.const 1100) (i64.const 32)) (i64.const 19)))))
+            ;; 's' → spin forever
+            (if (i32.eq (local.get $c) (i32.const 115))
+              (then (loop $forever (br $forever))))
+            ;; 'g' → grow by 64 pages, trap if refused, then echo
+            (if (i32.eq (local.get $c) (i32.const 103))
+              (then
+                (if (i32.eq (memory.grow (i32.const 64)) (i32.const -1))
+                  (then unreachable))))
+            ;; echo: {"ok": <args>}
+            (local.set $out (global.get $heap))
+            (memory.copy (local.get $out) (i32.const 1024) (i32.const 6))
+            (memory.copy (i32.add (local.get $out) (i32.const 6)) (local.get $args) (local.get $args_len))
+            (i32.store8 (i32.add (i32.add (local.get $out) (i32.const 6)) (local.get $args_len)) (i32.const 125))
+            (i64.or
+              (i64.shl (i64.extend_i32_u (local.get $out)) (i64.const 32))
+              (i64.extend_i32_u (i32.add (local.get $args_len) (i32.const 7))))))
+    "#;
+
+    fn load(limits: PluginLimits) -> WasmPlugin {
+        let bytes = wat::parse_str(TEST_PLUGIN).unwrap();
+        WasmPlugin::load(&bytes, limits).unwrap()
+    }
+
+    #[test]
+    fn load_reads_the_exported_tools() {
+        let plugin = load(PluginLimits::default());
+        let names: Vec<_> = plugin.tools().iter().map(|t| t.name.as_str()).collect();
+        assert_eq!(names, ["echo", "fail", "spin", "grow"]);
+        assert_eq!(
+            plugin.tools()[0].description.as_deref(),
+            Some("Echo the arguments")
+        );
+    }
+
+    #[test]
+    fn call_returns_the_ok_payload() {
+        let plugin = load(PluginLimits::default());
+        let out = plugin
+            .call("echo", &serde_json::json!({"text": "hi"}))
+            .unwrap();
+        assert_eq!(out, r#"{"text":"hi"}"#);
+    }
+
+    #[test]
+    fn reported_errors_are_tool_failures() {
+        let plugin = load(PluginLimits::default());
+        let err = plugin.call("fail", &serde_json::json!({})).unwrap_err();
+        assert!(
+            matches!(err, PluginError::ToolFailed(ref m) if m == "refused"),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn fuel_limit_stops_runaway_code() {
+        let plugin = load(PluginLimits {
+            fuel: 1_000_000,
+            ..PluginLimits::default()
+        });
+        let err = plugin.call("spin", &serde_json::json!({})).unwrap_err();
+        assert_eq!(err.to_string(), "plugin failed: fuel limit exceeded");
+    }
+
+    #[test]
+    fn timeout_stops_runaway_code() {
+        let plugin = load(PluginLimits {
+            fuel: u64::MAX,
+            timeout_ms: 50,
+            ..PluginLimits::default()
+        });
+        let err = plugin.call("spin", &serde_json::json!({})).unwrap_err();
+        assert_eq!(err.to_string(), "plugin failed: time limit exceeded");
+    }
+
+    #[test]
+    fn memory_limit_refuses_growth() {
+        let roomy = load(PluginLimits::default());
+        assert!(roomy.call("grow", &serde_json::json!({})).is_ok());
+
+        let tight = load(PluginLimits {
+            memory_mb: 1,
+            ..PluginLimits::default()
+        });
+        let err = tight.call("grow", &serde_json::json!({})).unwrap_err();
+        assert!(matches!(err, PluginError::Execution(_)), "{err}");
+    }
+
+    #[test]
+    fn modules_with_imports_are_rejected() {
+        let bytes = wat::parse_str(
+            r#"(module (import "env" "read_file" (func)) (memory (export "memory") 1))"#,
+        )
+        .unwrap();
+        let err = WasmPlugin::load(&bytes, PluginLimits::default()).unwrap_err();
+        assert!(err.to_string().contains("env::read_file"), "{err}");
+    }
+}
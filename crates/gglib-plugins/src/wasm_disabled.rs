@@ -0,0 +1,45 @@
+//! Stand-in for the WASM runtime when the `wasm` feature is off.
+//!
+//! Loading always fails with [`PluginError::Unsupported`], so no
+//! [`WasmPlugin`] can exist and the call paths are unreachable.
+
+use std::convert::Infallible;
+
+use crate::error::PluginError;
+use crate::manifest::{PluginLimits, PluginTool};
+
+/// Uninhabited: this build cannot run plugins.
+#[derive(Debug, Clone)]
+pub struct WasmPlugin {
+    never: Infallible,
+}
+
+impl WasmPlugin {
+    /// Always fails: this build has no WASM runtime.
+    ///
+    /// # Errors
+    ///
+    /// Always [`PluginError::Unsupported`].
+    pub const fn load(_bytes: &[u8], _limits: PluginLimits) -> Result<Self, PluginError> {
+        Err(PluginError::Unsupported)
+    }
+
+    /// Tools exported by the module.
+    #[must_use]
+    pub const fn tools(&self) -> &[PluginTool] {
+        match self.never {}
+    }
+
+    /// Run `tool` with `args`.
+    ///
+    /// # Errors
+    ///
+    /// Never returns; no instance can exist.
+    pub const fn call(
+        &self,
+        _tool: &str,
+        _args: &serde_json::Value,
+    ) -> Result<String, PluginError> {
+        match self.never {}
+    }
+}
@@ -0,0 +1,225 @@
+//! Who may call the proxy: browser origins (CORS) and an optional API key.
+//!
+//! On the default loopback bind any local process can reach the proxy
+//! anyway, so both checks are permissive by default. They matter once the
+//! proxy binds a public address (`0.0.0.0`) so that containers or other
+//! machines can reach it — see [`AccessPolicy::exposure_warning`].
+//!
+//! The key is checked before routing, as `Authorization: Bearer <key>` (what
+//! OpenAI SDKs send). Health probes stay open so orchestrators can use them
+//! without credentials. The header never reaches llama-server: `forward.rs`
+//! strips it.
+//!
+//! Background keys are accepted too, and mark every request made with them
+//! as background priority (see [`crate::priority`]), so batch jobs can be
+//! handed a key of their own.
+
+use std::fmt;
+use std::sync::Arc;
+
+use axum::Json;
+use axum::extract::{Request, State};
+use axum::http::{HeaderMap, HeaderValue, StatusCode, header};
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
+use subtle::{Choice, ConstantTimeEq};
+use tower_http::cors::{AllowOrigin, Any, CorsLayer};
+use tracing::warn;
+
+use gglib_core::Settings;
+use gglib_core::settings::is_loopback_host;
+
+use crate::models::ErrorResponse;
+
+/// Environment variable holding the proxy API key.
+pub const API_KEY_ENV: &str = "GGLIB_PROXY_API_KEY";
+
+/// Environment variable holding comma-separated background-priority keys.
+pub const BACKGROUND_API_KEYS_ENV: &str = "GGLIB_PROXY_BACKGROUND_API_KEYS";
+
+/// Paths answered without an API key.
+const OPEN_PATHS: &[&str] = &["/health", "/healthz", "/readyz"];
+
+/// Access rules for one proxy run. Read at startup; changing them needs a
+/// restart.
+#[derive(Clone, Default)]
+pub struct AccessPolicy {
+    /// Origins browsers may call the proxy from. `None` allows any.
+    pub allowed_origins: Option<Vec<String>>,
+    /// Key clients must present as a bearer token. `None` disables the check.
+    pub api_key: Option<String>,
+    /// Further accepted keys whose requests always run at background
+    /// priority.
+    pub background_api_keys: Vec<String>,
+}
+
+impl fmt::Debug for AccessPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AccessPolicy")
+            .field("allowed_origins", &self.allowed_origins)
+            .field("api_key", &self.api_key.as_ref().map(|_| "<redacted>"))
+            .field("background_api_keys", &self.background_api_keys.len())
+            .finish()
+    }
+}
+
+impl AccessPolicy {
+    /// The origins from `settings`, guarded by `api_key` (empty means none).
+    #[must_use]
+    pub fn from_settings(settings: &Settings, api_key: Option<String>) -> Self {
+        Self {
+            allowed_origins: settings.proxy_allowed_origins.clone(),
+            api_key: api_key.filter(|key| !key.is_empty()),
+            background_api_keys: Vec::new(),
+        }
+    }
+
+    /// Also accept `keys`, at background priority (empty entries dropped).
+    #[must_use]
+    pub fn with_background_keys(mut self, keys: Vec<String>) -> Self {
+        self.background_api_keys = keys.into_iter().filter(|k| !k.is_empty()).collect();
+        self
+    }
+
+    /// The API key from [`API_KEY_ENV`], if set and non-empty.
+    #[must_use]
+    pub fn api_key_from_env() -> Option<String> {
+        std::env::var(API_KEY_ENV)
+            .ok()
+            .filter(|key| !key.is_empty())
+    }
+
+    /// Keys from [`BACKGROUND_API_KEYS_ENV`], empty when unset.
+    #[must_use]
+    pub fn background_api_keys_from_env() -> Vec<String> {
+        std::env::var(BACKGROUND_API_KEYS_ENV)
+            .map(|keys| keys.split(',').map(|k| k.trim().to_owned()).collect())
+            .unwrap_or_default()
+    }
+
+    /// Whether `key` is one of the background keys. Compared in constant
+    /// time against every one, like [`require_api_key`].
+    #[must_use]
+    pub fn is_background_key(&self, key: &str) -> bool {
+        let found = self
+            .background_api_keys
+            .iter()
+            .fold(Choice::from(0), |found, k| found | ct_eq(k, key));
+        found.into()
+    }
+
+    /// A warning to show when binding `host` would let other machines use
+    /// the proxy without a key, `None` when it would not.
+    #[must_use]
+    pub fn exposure_warning(&self, host: &str) -> Option<String> {
+        (!is_loopback_host(host) && self.api_key.is_none()).then(|| {
+            format!(
+                "Proxy is bound to {host} without an API key: anyone who can reach this \
+                 address can run models on this machine. Set {API_KEY_ENV} to require one."
+            )
+        })
+    }
+
+    /// CORS for the configured origins; unparseable entries are skipped.
+    pub(crate) fn cors_layer(&self) -> CorsLayer {
+        let origins = self
+            .allowed_origins
+            .as_ref()
+            .map_or_else(AllowOrigin::any, |list| {
+                AllowOrigin::list(list.iter().filter_map(|o| HeaderValue::from_str(o).ok()))
+            });
+        CorsLayer::new()
+            .allow_origin(origins)
+            .allow_methods(Any)
+            .allow_headers(Any)
+    }
+}
+
+/// Whether two keys are equal, compared in constant time.
+fn ct_eq(expected: &str, presented: &str) -> Choice {
+    expected.as_bytes().ct_eq(presented.as_bytes())
+}
+
+/// The bearer token a request carries, if any.
+pub(crate) fn bearer_token(headers: &HeaderMap) -> Option<&str> {
+    headers
+        .get(header::AUTHORIZATION)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.strip_prefix("Bearer "))
+}
+
+/// Reject requests that do not carry `Bearer <key>` (the API key or a
+/// background key), except [`OPEN_PATHS`].
+///
+/// Keys are compared in constant time, so response timing reveals nothing
+/// about how much of a guessed key was right.
+///
+/// Replies like OpenAI does: `401` with an `invalid_api_key` error envelope.
+pub(crate) async fn require_api_key(
+    State(policy): State<Arc<AccessPolicy>>,
+    req: Request,
+    next: Next,
+) -> Response {
+    let accepted = bearer_token(req.headers()).is_some_and(|presented| {
+        let api_key = policy
+            .api_key
+            .as_deref()
+            .map_or(Choice::from(0), |key| ct_eq(key, presented));
+        bool::from(api_key) | policy.is_background_key(presented)
+    });
+    if accepted || OPEN_PATHS.contains(&req.uri().path()) {
+        return next.run(req).await;
+    }
+
+    warn!(path = %req.uri().path(), "Rejected proxy request without a valid API key");
+    (
+        StatusCode::UNAUTHORIZED,
+        [(header::WWW_AUTHENTICATE, "Bearer")],
+        Json(ErrorResponse::with_code(
+            "Invalid or missing API key",
+            "invalid_request_error",
+            "invalid_api_key",
+        )),
+    )
+        .into_response()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn warns_only_for_public_binds_without_a_key() {
+        let open = AccessPolicy::default();
+        assert!(open.exposure_warning("127.0.0.1").is_none());
+        assert!(open.exposure_warning("localhost").is_none());
+        assert!(open.exposure_warning("0.0.0.0").is_some());
+
+        let keyed = AccessPolicy::from_settings(&Settings::default(), Some("k".into()));
+        assert!(keyed.exposure_warning("0.0.0.0").is_none());
+    }
+
+    #[test]
+    fn empty_key_means_no_key_and_debug_hides_it() {
+        let policy = AccessPolicy::from_settings(&Settings::default(), Some(String::new()));
+        assert!(policy.api_key.is_none());
+
+        let policy = AccessPolicy::from_settings(&Settings::default(), Some("sk-1".into()))
+            .with_background_keys(vec!["bg-1".into(), String::new()]);
+        assert_eq!(policy.background_api_keys, ["bg-1"]);
+        let debug = format!("{policy:?}");
+        assert!(
+            !debug.contains("sk-1") && !debug.contains("bg-1"),
+            "{debug}"
+        );
+    }
+
+    #[test]
+    fn keys_match_only_in_full() {
+        let policy = AccessPolicy::default().with_background_keys(vec!["bg-1".into()]);
+        assert!(policy.is_background_key("bg-1"));
+        assert!(!policy.is_background_key("bg-"));
+        assert!(!policy.is_background_key("bg-12"));
+        assert!(!AccessPolicy::default().is_background_key(""));
+    }
+}
@@ -0,0 +1,289 @@
+//! Capability routing for `auto:*` model aliases.
+//!
+//! A client that cares what a model can do rather than which model it is asks
+//! for `auto:tools`, `auto:vision` or `auto:fast`. [`select`] picks a catalog
+//! model for that capability and the request continues as if the client had
+//! named it: [`with_model`] writes the chosen name into the forwarded body, so
+//! the response's `model` field reports it, and [`AUTO_ROUTE_HEADER`] on the
+//! response says why it was chosen.
+//!
+//! # Ranking
+//!
+//! Only chat models are candidates. `auto:tools` further requires tool-call
+//! support and `auto:vision` a `vision` tag; `auto:fast` takes any of them.
+//!
+//! For tools and vision, the model already loaded in llama-server wins when it
+//! qualifies: a swap costs a full model load, more than the quality gap
+//! between candidates is worth to a client that let the proxy choose. Failing
+//! that, the largest parameter count wins, ties going to the smaller file.
+//!
+//! `auto:fast` ranks on file size alone, smallest first, since decode speed
+//! tracks the bytes read per token. The loaded model only breaks ties there —
+//! a large model that happens to be loaded is exactly what the client asked
+//! to avoid.
+
+use axum::body::Bytes;
+use gglib_core::ports::ModelSummary;
+use serde_json::Value;
+use tracing::warn;
+
+/// Prefix that marks a requested model id as a capability alias.
+pub const AUTO_PREFIX: &str = "auto:";
+
+/// Response header naming the model an `auto:` alias resolved to, and why.
+pub const AUTO_ROUTE_HEADER: &str = "x-gglib-auto-route";
+
+/// What an `auto:` alias asks the chosen model to do.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Capability {
+    /// `auto:tools` — native tool/function calling.
+    Tools,
+    /// `auto:vision` — image input.
+    Vision,
+    /// `auto:fast` — the quickest model to answer.
+    Fast,
+}
+
+impl Capability {
+    /// Every capability, in the order they are listed to clients.
+    pub const ALL: [Self; 3] = [Self::Tools, Self::Vision, Self::Fast];
+
+    /// Parse the part of an alias after [`AUTO_PREFIX`].
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|c| c.name().eq_ignore_ascii_case(name))
+    }
+
+    /// Name as written after [`AUTO_PREFIX`].
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Tools => "tools",
+            Self::Vision => "vision",
+            Self::Fast => "fast",
+        }
+    }
+
+    /// Whether `model` can serve this capability.
+    pub fn matches(self, model: &ModelSummary) -> bool {
+        model.capabilities.is_chat_model()
+            && match self {
+                Self::Tools => model.capabilities.supports_tool_calls(),
+                Self::Vision => model.tags.iter().any(|t| t.eq_ignore_ascii_case("vision")),
+                Self::Fast => true,
+            }
+    }
+}
+
+/// The capability part of `model` when it is an `auto:` alias.
+///
+/// Returned unparsed so an unknown capability can be reported by name rather
+/// than falling through to model-not-found.
+pub fn alias_name(model: &str) -> Option<&str> {
+    model
+        .get(..AUTO_PREFIX.len())
+        .filter(|prefix| prefix.eq_ignore_ascii_case(AUTO_PREFIX))
+        .map(|_| &model[AUTO_PREFIX.len()..])
+}
+
+/// The model an alias resolved to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AutoRoute {
+    /// Capability the client asked for.
+    pub capability: Capability,
+    /// Catalog name of the chosen model.
+    pub model: String,
+    /// Why this model beat the other candidates.
+    pub reason: &'static str,
+    /// How many catalog models had the capability.
+    pub candidates: usize,
+}
+
+impl AutoRoute {
+    /// Value for [`AUTO_ROUTE_HEADER`], e.g.
+    /// `auto:tools -> qwen3-8b (already loaded; 3 candidates)`.
+    pub fn explanation(&self) -> String {
+        format!(
+            "{AUTO_PREFIX}{} -> {} ({}; {} candidate{})",
+            self.capability.name(),
+            self.model,
+            self.reason,
+            self.candidates,
+            if self.candidates == 1 { "" } else { "s" },
+        )
+    }
+}
+
+/// Pick the model to serve `capability`, given the name of the model
+/// currently loaded, if any. `None` when no catalog model qualifies.
+pub fn select(
+    models: &[ModelSummary],
+    capability: Capability,
+    loaded: Option<&str>,
+) -> Option<AutoRoute> {
+    let is_loaded = |m: &ModelSummary| loaded == Some(m.name.as_str());
+    let candidates: Vec<&ModelSummary> = models.iter().filter(|m| capability.matches(m)).collect();
+
+    let best = candidates.iter().copied().min_by(|a, b| match capability {
+        Capability::Fast => a
+            .file_size
+            .cmp(&b.file_size)
+            .then_with(|| is_loaded(b).cmp(&is_loaded(a))),
+        Capability::Tools | Capability::Vision => is_loaded(b)
+            .cmp(&is_loaded(a))
+            .then_with(|| parameters(&b.param_count).total_cmp(&parameters(&a.param_count)))
+            .then_with(|| a.file_size.cmp(&b.file_size)),
+    })?;
+
+    let reason = match capability {
+        Capability::Fast => "smallest",
+        _ if is_loaded(best) => "already loaded",
+        _ => "largest",
+    };
+    Some(AutoRoute {
+        capability,
+        model: best.name.clone(),
+        reason,
+        candidates: candidates.len(),
+    })
+}
+
+/// Parameter count in billions from a catalog label such as `7B`, `1.5B`,
+/// `270M` or `8x7B`. Unreadable labels count as zero, ranking last.
+fn parameters(label: &str) -> f64 {
+    let label = label.trim().to_ascii_uppercase();
+    let (experts, size) = label
+        .split_once('X')
+        .map_or((1.0, label.as_str()), |(n, size)| {
+            (n.trim().parse().unwrap_or(1.0), size)
+        });
+    let (number, scale) = if let Some(n) = size.strip_suffix('B') {
+        (n, 1.0)
+    } else if let Some(n) = size.strip_suffix('M') {
+        (n, 1e-3)
+    } else {
+        (size, 1.0)
+    };
+    number
+        .trim()
+        .parse::<f64>()
+        .map_or(0.0, |n| n * scale * experts)
+}
+
+/// Rewrite the body's `model` to the routed model.
+///
+/// A body that is not a JSON object is returned unchanged, as
+/// [`crate::presets::apply_preset`] does.
+pub(crate) fn with_model(body: Bytes, model: &str) -> Bytes {
+    let Ok(Value::Object(mut request)) = serde_json::from_slice::<Value>(&body) else {
+        return body;
+    };
+    request.insert("model".to_owned(), Value::String(model.to_owned()));
+    match serde_json::to_vec(&Value::Object(request)) {
+        Ok(v) => Bytes::from(v),
+        Err(e) => {
+            warn!(error = %e, "failed to re-serialize request body after auto routing");
+            body
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use gglib_core::domain::ModelCapabilities;
+
+    fn model(name: &str, params: &str, file_size: u64, tools: bool, tags: &[&str]) -> ModelSummary {
+        let mut capabilities = ModelCapabilities::SUPPORTS_SYSTEM_ROLE;
+        capabilities.set(ModelCapabilities::SUPPORTS_TOOL_CALLS, tools);
+        ModelSummary {
+            id: 0,
+            name: name.into(),
+            tags: tags.iter().map(|t| (*t).to_owned()).collect(),
+            capabilities,
+            param_count: params.into(),
+            quantization: None,
+            architecture: None,
+            created_at: 0,
+            file_size,
+            context_length: None,
+            inference_defaults: None,
+            server_defaults: None,
+        }
+    }
+
+    fn library() -> Vec<ModelSummary> {
+        let mut embedder = model("embedder", "0.1B", 100, false, &[]);
+        embedder.capabilities = ModelCapabilities::EMBEDDING;
+        vec![
+            embedder,
+            model("big-tools", "32B", 20_000, true, &[]),
+            model("small-tools", "4B", 3_000, true, &[]),
+            model("moe-tools", "8x7B", 30_000, true, &[]),
+            model("tiny-chat", "1.5B", 1_000, false, &[]),
+            model("eyes", "12B", 8_000, false, &["Vision"]),
+        ]
+    }
+
+    #[test]
+    fn parses_aliases_case_insensitively() {
+        assert_eq!(alias_name("auto:tools"), Some("tools"));
+        assert_eq!(alias_name("AUTO:Fast"), Some("Fast"));
+        assert_eq!(alias_name("qwen3:auto"), None);
+        assert_eq!(Capability::from_name("Fast"), Some(Capability::Fast));
+        assert_eq!(Capability::from_name("audio"), None);
+    }
+
+    #[test]
+    fn parameters_reads_catalog_labels() {
+        assert!((parameters("8x7B") - 56.0).abs() < f64::EPSILON);
+        assert!((parameters("270M") - 0.27).abs() < 1e-9);
+        assert!((parameters("1.5B") - 1.5).abs() < f64::EPSILON);
+        assert!(parameters("unknown").abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn tools_prefers_loaded_then_largest() {
+        let models = library();
+        let route = select(&models, Capability::Tools, None).unwrap();
+        assert_eq!(
+            (route.model.as_str(), route.reason),
+            ("moe-tools", "largest")
+        );
+        assert_eq!(route.candidates, 3);
+
+        let route = select(&models, Capability::Tools, Some("small-tools")).unwrap();
+        assert_eq!(route.model, "small-tools");
+        assert_eq!(
+            route.explanation(),
+            "auto:tools -> small-tools (already loaded; 3 candidates)"
+        );
+
+        // A loaded model without the capability does not count.
+        let route = select(&models, Capability::Tools, Some("tiny-chat")).unwrap();
+        assert_eq!(route.model, "moe-tools");
+    }
+
+    #[test]
+    fn vision_uses_tags_and_fast_ignores_what_is_loaded() {
+        let models = library();
+        assert_eq!(
+            select(&models, Capability::Vision, None).unwrap().model,
+            "eyes"
+        );
+        let route = select(&models, Capability::Fast, Some("big-tools")).unwrap();
+        assert_eq!(
+            (route.model.as_str(), route.reason),
+            ("tiny-chat", "smallest")
+        );
+        assert!(select(&models[..1], Capability::Fast, None).is_none());
+    }
+
+    #[test]
+    fn with_model_rewrites_only_the_model() {
+        let body = Bytes::from(r#"{"model":"auto:fast","stream":true}"#);
+        let value: Value = serde_json::from_slice(&with_model(body, "tiny-chat")).unwrap();
+        assert_eq!(value["model"], "tiny-chat");
+        assert_eq!(value["stream"], true);
+    }
+}
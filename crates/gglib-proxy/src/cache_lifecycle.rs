@@ -8,6 +8,7 @@ use std::path::PathBuf;
 use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 
+use axum::http::HeaderMap;
 use dashmap::DashSet;
 use reqwest::Client;
 use tokio::sync::{OwnedSemaphorePermit, Semaphore};
@@ -24,6 +25,25 @@ pub struct LastLoadedSession {
     pub session_id: String,
 }
 
+/// Header marking a request as part of an incognito conversation.
+///
+/// Slot files would keep the conversation's KV state on disk after the
+/// session ends, so these requests skip the disk layer entirely. The
+/// in-RAM prompt cache still applies; it dies with the llama-server process.
+pub const INCOGNITO_HEADER: &str = "x-gglib-incognito";
+
+/// Whether `headers` mark the request incognito.
+///
+/// Any value other than `0` or `false` counts, so a bare `x-gglib-incognito: 1`
+/// or `: true` both work.
+pub(crate) fn is_incognito(headers: &HeaderMap) -> bool {
+    headers
+        .get(INCOGNITO_HEADER)
+        .and_then(|v| v.to_str().ok())
+        .map(str::trim)
+        .is_some_and(|v| !(v == "0" || v.eq_ignore_ascii_case("false")))
+}
+
 // Retry budget for pre-generation restore failures — shared with
 // `slots::attempt_save`'s retry loop; see `slots::MAX_RETRIES` for why.
 // Total attempts = 1 (initial) + MAX_RETRIES = 3.
@@ -328,6 +348,16 @@ mod tests {
     use super::*;
     use tokio::time::Duration;
 
+    #[test]
+    fn incognito_header_values() {
+        let mut headers = HeaderMap::new();
+        assert!(!is_incognito(&headers));
+        for (value, expected) in [("1", true), ("true", true), ("0", false), ("False", false)] {
+            headers.insert(INCOGNITO_HEADER, value.parse().unwrap());
+            assert_eq!(is_incognito(&headers), expected, "{value}");
+        }
+    }
+
     #[test]
     fn test_stream_config_is_clone() {
         let config = StreamConfig {
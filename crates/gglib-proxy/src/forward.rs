@@ -7,10 +7,24 @@
 //! which owns their order and its rationale, and the proxy runs the whole of
 //! it with a single [`apply`](gglib_core::request_pipeline::apply) call — the
 //! same call the in-process agent path makes, so the two cannot drift.  What
-//! is proxy-specific, and therefore still here, is exactly two things:
+//! is proxy-specific, and therefore still here, is exactly three things:
 //! the `Bytes` ⇄ `Value` conversion at the HTTP boundary
-//! ([`shape_request_body`]), and mapping the pipeline's one failure mode onto
-//! this surface's wire contract — HTTP 400 / `context_length_exceeded`.
+//! ([`shape_request_body`]), hoisting llama-server-only sampling parameters
+//! out of an `extensions` object ([`hoist_extensions`]), and mapping the
+//! pipeline's one failure mode onto this surface's wire contract — HTTP 400 /
+//! `context_length_exceeded`.
+//!
+//! ## Sampling parameters
+//!
+//! Every top-level field the pipeline does not resolve is forwarded as the
+//! client sent it, so the OpenAI parameters llama-server also understands —
+//! `seed`, `stop`, `frequency_penalty` — need no mapping here. The ones
+//! gglib resolves (`presence_penalty`, `repeat_penalty`, `min_p`, …) keep the
+//! client's value, which is the top layer of the hierarchy.
+//!
+//! Parameters with no OpenAI spelling, mirostat above all, are awkward to
+//! send from SDKs that validate top-level keys, so they may be nested under
+//! `extensions` instead; see [`EXTENSION_KEYS`].
 //!
 //! The proxy differs from the agent path in one input: its truncation budget
 //! comes from the **live** serving context of the running llama-server, scaled
@@ -35,9 +49,15 @@
 //!  SseStreamDecoder          (→ typed LlmStreamEvent)
 //!        │
 //!        ▼
+//!  ThinkSplitter             (inline <think> → ReasoningDelta; reasoning models only)
+//!        │
+//!        ▼
 //!  NormalizingStream         (Qwen XML → ToolCallDelta, <think> → ReasoningDelta)
 //!        │
 //!        ▼
+//!  ToolCallAssembler         (dense indexes, stable ids, schema check at Done)
+//!        │
+//!        ▼
 //!  SseEncoder                (→ pristine OpenAI `data:` frames)
 //!        │
 //!        ▼
@@ -45,7 +65,11 @@
 //! ```
 //!
 //! `NormalizationError` events surfaced by the parsers are logged via
-//! `tracing::warn` and never forwarded to the wire.
+//! `tracing::warn` and never forwarded to the wire. Reasoning reaches the
+//! client as `reasoning_content` unless the settings strip it; see
+//! [`crate::reasoning`]. Tool calls whose arguments don't match the
+//! request's declared schema are reported in-stream; see
+//! [`crate::tool_calls`].
 //!
 //! Non-streaming responses are forwarded verbatim for now — the dialects
 //! we currently rewrite (Qwen XML tool calls, bare `<think>` tags) only
@@ -65,6 +89,7 @@ use reqwest::Client;
 use tracing::{debug, error, info, warn};
 
 use gglib_core::LlmStreamEvent;
+use gglib_core::domain::{ProxyLimits, TIME_LIMIT_FINISH_REASON};
 use gglib_core::normalize::{NormalizingStream, get_parser};
 use gglib_core::ports::ModelCatalogPort;
 use gglib_core::request_pipeline::{
@@ -73,9 +98,14 @@ use gglib_core::request_pipeline::{
 use gglib_core::sse::{DONE_SENTINEL, SseEncoder, SseStreamDecoder};
 
 use crate::connections::ConnectionGuard;
+use crate::limits::clamp_max_tokens;
 use crate::metrics::{ContextMetricsStore, ContextSnapshot};
+use crate::middleware::{ResponseHooks, ResponseSummary};
 use crate::models::ErrorResponse;
+use crate::reasoning::{ReasoningOutput, ReasoningPolicy, ThinkSplitter};
+use crate::request_timing::{RequestTimer, usage_extension};
 use crate::token_calibration::TokenCalibration;
+use crate::tool_calls::{DeclaredTools, ToolCallAssembler, ToolCallError};
 use crate::upstream_health::UpstreamHealth;
 use gglib_core::cache_metrics::CacheMetricsStore;
 
@@ -122,6 +152,12 @@ pub(crate) struct StreamOutcome {
     /// see [`gglib_core::LlmStreamEvent::Usage`] on why absent and zero must
     /// stay distinct. Feeds [`gglib_core::cache_metrics::CacheMetricsStore`].
     pub cached_tokens: Option<u32>,
+    /// `usage.completion_tokens` reported by the upstream, if a Usage frame
+    /// arrived. Feeds the per-session throughput figure.
+    pub completion_tokens: Option<u32>,
+    /// When the first generated token (content, reasoning, or tool call)
+    /// came through. Feeds the per-session time-to-first-token figure.
+    pub first_token_at: Option<std::time::Instant>,
 }
 
 /// Headers that should NOT be forwarded (hop-by-hop headers).
@@ -253,6 +289,48 @@ fn context_length_exceeded_response() -> Response {
         .into_response()
 }
 
+/// llama-server sampling parameters accepted inside a request's `extensions`
+/// object and hoisted to the top level, where llama-server reads them.
+const EXTENSION_KEYS: &[&str] = &[
+    "mirostat",
+    "mirostat_tau",
+    "mirostat_eta",
+    "typical_p",
+    "min_p",
+    "top_k",
+    "repeat_penalty",
+    "repeat_last_n",
+    "seed",
+];
+
+/// Move the [`EXTENSION_KEYS`] found in `body.extensions` to the top level.
+///
+/// A key the client also sent at the top level keeps that value — the nested
+/// copy is the fallback spelling, not an override. Keys that are not sampling
+/// parameters stay in `extensions`, and an `extensions` object left empty is
+/// removed, so llama-server never sees a field the proxy emptied itself.
+///
+/// Runs before the pipeline so hoisted `min_p`, `top_k` and `repeat_penalty`
+/// take part in sampling resolution as the client's own values.
+fn hoist_extensions(body: &mut serde_json::Value) {
+    let Some(obj) = body.as_object_mut() else {
+        return;
+    };
+    let Some(serde_json::Value::Object(extensions)) = obj.get_mut("extensions") else {
+        return;
+    };
+    let hoisted: Vec<_> = EXTENSION_KEYS
+        .iter()
+        .filter_map(|key| extensions.remove(*key).map(|value| (*key, value)))
+        .collect();
+    if extensions.is_empty() {
+        obj.remove("extensions");
+    }
+    for (key, value) in hoisted {
+        obj.entry(key).or_insert(value);
+    }
+}
+
 /// Run the shared request-shaping pipeline over a body held as `Bytes`.
 ///
 /// The pipeline operates on a `&mut serde_json::Value` — the seam that
@@ -260,6 +338,9 @@ fn context_length_exceeded_response() -> Response {
 /// drop.  This is the whole of what the proxy adds on top: the conversion at
 /// the HTTP boundary, with zero blast radius.
 ///
+/// The proxy's token ceiling is applied to the resolved body, so it caps
+/// whichever layer supplied `max_tokens` (see [`crate::limits`]).
+///
 /// A body that is not JSON is forwarded byte-for-byte and reported as
 /// unmeasured — the upstream can produce its own diagnostic for it.  A
 /// re-serialization failure likewise forwards the original and logs; `Value`
@@ -273,13 +354,16 @@ fn shape_request_body(
     body: Bytes,
     ctx: &ModelContext,
     layers: &SamplingLayers,
+    limits: &ProxyLimits,
     budget_chars: Option<usize>,
 ) -> Result<(Bytes, TruncationReport), TruncationError> {
     let Ok(mut value) = serde_json::from_slice::<serde_json::Value>(&body) else {
         return Ok((body, TruncationReport::default()));
     };
 
+    hoist_extensions(&mut value);
     let report = request_pipeline::apply(&mut value, ctx, layers, budget_chars)?;
+    clamp_max_tokens(&mut value, limits);
 
     match serde_json::to_vec(&value) {
         Ok(v) => Ok((Bytes::from(v), report)),
@@ -308,6 +392,8 @@ fn shape_request_body(
 /// * `metrics` - Metrics store for recording per-request context snapshots
 /// * `sampling` - The profile and global sampling layers to resolve beneath
 ///   the client's own request parameters
+/// * `limits` - Proxy generation limits; the token ceiling caps the resolved
+///   `max_tokens`
 /// * `connection` - RAII dashboard-registry guard for this request. Moved
 ///   into the spawned streaming task for the streaming path (so it lives
 ///   exactly as long as that task); held for the duration of this function
@@ -334,12 +420,24 @@ fn shape_request_body(
 ///   hybrid/sliding-window-attention models, where disk caching is disabled
 ///   but the host-RAM prompt cache — the thing this fix protects — still
 ///   applies).
+/// * `timer` - Started when the proxy began handling this request; finished
+///   with the request's queue wait, time-to-first-token, and throughput once
+///   the upstream response has been drained. Its time limit, if any, ends
+///   the request early (see [`crate::limits`]).
+/// * `hooks` - Registered middleware bound to this request: stream hooks
+///   rewrite each normalized event, and response hooks inspect the delivered
+///   response.
 ///
 /// # Returns
 ///
 /// The response from llama-server, with the streaming SSE body re-emitted
 /// through the universal normalization pipeline when `is_streaming` is true.
 #[allow(clippy::too_many_arguments)]
+#[tracing::instrument(
+    name = "proxy.forward",
+    skip_all,
+    fields(model = model_name, streaming = is_streaming, ctx = effective_ctx)
+)]
 pub(crate) async fn forward_chat_completion(
     client: &Client,
     upstream_url: &str,
@@ -351,6 +449,8 @@ pub(crate) async fn forward_chat_completion(
     catalog: Arc<dyn ModelCatalogPort>,
     metrics: Arc<ContextMetricsStore>,
     sampling: SamplingLayers,
+    limits: &ProxyLimits,
+    reasoning: ReasoningOutput,
     connection: ConnectionGuard,
     upstream_health: Arc<UpstreamHealth>,
     calibration: Arc<TokenCalibration>,
@@ -359,6 +459,8 @@ pub(crate) async fn forward_chat_completion(
     permit: Option<tokio::sync::OwnedSemaphorePermit>,
     config: Option<crate::cache_lifecycle::StreamConfig>,
     session_id: Option<String>,
+    timer: RequestTimer,
+    hooks: ResponseHooks,
 ) -> Result<Response, ForwardError> {
     debug!("Forwarding to {upstream_url}, streaming={is_streaming}");
 
@@ -393,7 +495,7 @@ pub(crate) async fn forward_chat_completion(
     );
     let budget_chars = Some((effective_ctx as f64 * chars_per_token) as usize);
 
-    let (body, report) = match shape_request_body(body, &context, &sampling, budget_chars) {
+    let (body, report) = match shape_request_body(body, &context, &sampling, limits, budget_chars) {
         Ok(shaped) => shaped,
         Err(e) => {
             // Hard abort: the conversation cannot be trimmed to fit. Record a
@@ -513,6 +615,8 @@ pub(crate) async fn forward_chat_completion(
         // relocated to `sse_stream::spawn_and_return` (Step 4).
         let (tx, rx) = tokio::sync::mpsc::channel::<Result<Bytes, std::io::Error>>(32);
         let model_name_owned = model_name.to_owned();
+        let reasoning = ReasoningPolicy::new(context.capabilities, reasoning);
+        let tools = DeclaredTools::from_body(&body);
         let tags = context.tags;
 
         return Ok(crate::sse_stream::spawn_and_return(
@@ -523,6 +627,8 @@ pub(crate) async fn forward_chat_completion(
             connection,
             model_name_owned,
             tags,
+            reasoning,
+            tools,
             upstream_health,
             calibration,
             cache_metrics,
@@ -530,10 +636,44 @@ pub(crate) async fn forward_chat_completion(
             permit,
             config,
             session_id,
+            timer,
+            hooks,
         ));
     }
 
-    // ── Non-streaming path (unchanged) ────────────────────────────────────
+    // ── Non-streaming path ───────────────────────────────────────────────
+    //
+    // A buffered reply cannot be cut short, so one still pending at the
+    // `proxy_limits` deadline is abandoned with a 504.
+    let time_limit = timer.time_limit();
+    let deadline = timer.deadline().map(tokio::time::Instant::from_std);
+    let buffered = forward_buffered(req_builder, body, &cache_metrics, timer, hooks);
+    match (deadline, time_limit) {
+        (Some(deadline), Some(limit)) => tokio::time::timeout_at(deadline, buffered)
+            .await
+            .unwrap_or_else(|_| {
+                warn!(
+                    limit_secs = limit.as_secs(),
+                    "proxy: request ran past the proxy time limit; abandoning it"
+                );
+                Ok((
+                    StatusCode::GATEWAY_TIMEOUT,
+                    axum::Json(ErrorResponse::time_limit_exceeded(limit.as_secs())),
+                )
+                    .into_response())
+            }),
+        _ => buffered.await,
+    }
+}
+
+/// Send a non-streaming request and relay the buffered reply.
+async fn forward_buffered(
+    req_builder: reqwest::RequestBuilder,
+    body: Bytes,
+    cache_metrics: &CacheMetricsStore,
+    timer: RequestTimer,
+    hooks: ResponseHooks,
+) -> Result<Response, ForwardError> {
     let response = match req_builder.body(body).send().await {
         Ok(resp) => resp,
         Err(e) if e.is_connect() || e.is_timeout() => {
@@ -580,7 +720,7 @@ pub(crate) async fn forward_chat_completion(
     // non-streaming responses is intentionally deferred — the wire
     // formats we currently rewrite (Qwen XML tool calls, bare <think>
     // tags) only manifest in streaming clients today.
-    Ok(forward_non_streaming_response(response, &cache_metrics).await)
+    Ok(forward_non_streaming_response(response, cache_metrics, timer, hooks).await)
 }
 
 /// Extract the `host:port` authority from an HTTP/HTTPS URL string.
@@ -644,12 +784,37 @@ pub(crate) fn visible_content_frame(model: &str, content: &str) -> String {
 /// records them on `connection` (the dashboard registry entry for this
 /// request) as a side effect — the frame is still encoded and forwarded to
 /// the client unchanged; this never alters what the client receives.
+///
+/// The upstream's usage frame is held back and sent as the last chunk before
+/// `[DONE]`, where `stream_options.include_usage` puts it, with this
+/// request's timing from `timer` and `headers_at` under
+/// [`EXTENSION_KEY`](gglib_core::sse::EXTENSION_KEY). It is sent whether or
+/// not the client asked: the proxy always requests it upstream, and clients
+/// such as the LLM Gateway extension rely on it without asking.
+///
+/// Every event passes through the stream hooks in `hooks` before any of the
+/// above, so the bookkeeping sees what the client sees; the response hooks
+/// run once `[DONE]` has been sent.
+///
+/// `reasoning` decides whether inline `<think>` blocks are split out of the
+/// content before normalization, and whether reasoning is sent at all.
+///
+/// Tool-call deltas are reassembled into `OpenAI`'s shape on their way out,
+/// and the finished calls are checked against `tools`; a call whose arguments
+/// don't fit gets an error frame just before the finish chunk (see
+/// [`crate::tool_calls`]).
+#[allow(clippy::too_many_arguments)]
 pub(crate) async fn stream_response_to_channel(
     response: reqwest::Response,
     model_name: String,
     tags: Vec<String>,
+    reasoning: ReasoningPolicy,
+    tools: DeclaredTools,
     tx: tokio::sync::mpsc::Sender<Result<Bytes, std::io::Error>>,
     connection: &ConnectionGuard,
+    timer: &RequestTimer,
+    headers_at: std::time::Instant,
+    hooks: &ResponseHooks,
 ) -> StreamOutcome {
     let id = format!("chatcmpl-{}", uuid::Uuid::new_v4().simple());
     let created = SystemTime::now()
@@ -661,6 +826,7 @@ pub(crate) async fn stream_response_to_channel(
     let byte_stream = response.bytes_stream();
     let event_stream = async_stream::stream! {
         let mut decoder = SseStreamDecoder::default();
+        let mut splitter = reasoning.splitter();
         let mut byte_stream = std::pin::pin!(byte_stream);
 
         'outer: while let Some(chunk_result) = byte_stream.next().await {
@@ -674,7 +840,14 @@ pub(crate) async fn stream_response_to_channel(
             };
             let (events, stop) = decoder.feed_bytes(&chunk);
             for event in events {
-                yield event;
+                match (&mut splitter, event) {
+                    (Some(splitter), Ok(event)) => {
+                        for event in splitter.process(event) {
+                            yield Ok(event);
+                        }
+                    }
+                    (_, event) => yield event,
+                }
             }
             if stop {
                 break 'outer;
@@ -682,20 +855,65 @@ pub(crate) async fn stream_response_to_channel(
         }
 
         if let Some(fallback) = decoder.finish() {
-            yield Ok(fallback);
+            match &mut splitter {
+                Some(splitter) => {
+                    for event in splitter.process(fallback) {
+                        yield Ok(event);
+                    }
+                }
+                None => yield Ok(fallback),
+            }
+        }
+        if let Some(held) = splitter.as_mut().and_then(ThinkSplitter::finish) {
+            yield Ok(held);
         }
     };
 
     let parser = get_parser(&tags);
     let normalized = NormalizingStream::new(Box::pin(event_stream), parser);
     let mut normalized = Box::pin(normalized);
+    let mut tool_calls = ToolCallAssembler::new(tools);
 
     let mut outcome = StreamOutcome::default();
     let mut client_connected = true;
     // Accumulates reasoning text for the promotion path below. Bounded in
     // practice by the request's `max_tokens`.
     let mut reasoning_buf = String::new();
-    while let Some(event) = normalized.next().await {
+    // The latest usage frame, sent after everything else.
+    let mut usage = None;
+    // Content the client received, for the response hooks. Left empty when
+    // none are registered.
+    let mut delivered = String::new();
+    // Set when the `proxy_limits` run-time limit cut the stream off.
+    let deadline = timer.deadline().map(tokio::time::Instant::from_std);
+    let mut time_limited = false;
+    loop {
+        let next = match deadline {
+            Some(deadline) => {
+                if let Ok(next) = tokio::time::timeout_at(deadline, normalized.next()).await {
+                    next
+                } else {
+                    time_limited = true;
+                    break;
+                }
+            }
+            None => normalized.next().await,
+        };
+        let Some(event) = next else {
+            break;
+        };
+        let event = match event {
+            Ok(ev) => match tool_calls.process(ev).and_then(|ev| hooks.transform(ev)) {
+                Some(ev) => Ok(ev),
+                None => continue,
+            },
+            Err(e) => Err(e),
+        };
+        if let Ok(LlmStreamEvent::TextDelta { content }) = &event
+            && !hooks.is_empty()
+        {
+            delivered.push_str(content);
+        }
         let frame: Option<Bytes> = match event {
             Ok(ev) => match &ev {
                 LlmStreamEvent::PromptProgress {
@@ -719,18 +937,31 @@ pub(crate) async fn stream_response_to_channel(
                     encoder.encode(&recovered).map(Bytes::from)
                 }
                 LlmStreamEvent::ReasoningDelta { content } => {
-                    // Forwarded unchanged, but NOT counted as visible output —
-                    // clients that collapse reasoning render this as empty.
-                    // Buffered so it can be promoted if the turn ends without
-                    // ever producing content of its own.
+                    // Forwarded unchanged (or dropped when the settings strip
+                    // reasoning), but NOT counted as visible output — clients
+                    // that collapse reasoning render this as empty. Buffered
+                    // so it can be promoted if the turn ends without ever
+                    // producing content of its own.
                     connection.mark_generating();
+                    outcome
+                        .first_token_at
+                        .get_or_insert_with(std::time::Instant::now);
                     outcome.saw_reasoning = true;
                     reasoning_buf.push_str(content);
+                    match reasoning.output {
+                        ReasoningOutput::PassThrough => encoder.encode(&ev).map(Bytes::from),
+                        ReasoningOutput::Strip => None,
+                    }
+                }
+                LlmStreamEvent::TextDelta { .. } | LlmStreamEvent::ToolCallDelta { .. } => {
+                    connection.mark_generating();
+                    outcome
+                        .first_token_at
+                        .get_or_insert_with(std::time::Instant::now);
+                    outcome.saw_visible_output = true;
                     encoder.encode(&ev).map(Bytes::from)
                 }
-                LlmStreamEvent::TextDelta { .. }
-                | LlmStreamEvent::ToolCallDelta { .. }
-                | LlmStreamEvent::UpstreamError { .. } => {
+                LlmStreamEvent::UpstreamError { .. } => {
                     connection.mark_generating();
                     outcome.saw_visible_output = true;
                     encoder.encode(&ev).map(Bytes::from)
@@ -738,10 +969,23 @@ pub(crate) async fn stream_response_to_channel(
                 LlmStreamEvent::Done { finish_reason } => {
                     connection.mark_generating();
                     outcome.finish_reason = Some(finish_reason.clone());
-                    encoder.encode(&ev).map(Bytes::from)
+                    // Invalid tool calls are reported before the finish chunk,
+                    // while the client is still reading this choice.
+                    let mut frames: String = tool_calls
+                        .finish()
+                        .iter()
+                        .inspect(|e| warn!(index = e.index, name = ?e.name, "proxy: tool call arguments do not match the declared schema"))
+                        .map(ToolCallError::frame)
+                        .collect();
+                    if !frames.is_empty() {
+                        outcome.saw_visible_output = true;
+                    }
+                    frames.extend(encoder.encode(&ev));
+                    (!frames.is_empty()).then(|| Bytes::from(frames))
                 }
                 LlmStreamEvent::Usage {
                     prompt_tokens,
+                    completion_tokens,
                     cached_tokens,
                     ..
                 } => {
@@ -751,7 +995,9 @@ pub(crate) async fn stream_response_to_channel(
                     // visible output (it carries an empty `choices` array).
                     outcome.prompt_tokens = Some(*prompt_tokens);
                     outcome.cached_tokens = *cached_tokens;
-                    encoder.encode(&ev).map(Bytes::from)
+                    outcome.completion_tokens = Some(*completion_tokens);
+                    usage = Some(ev.clone());
+                    None
                 }
             },
             Err(e) => {
@@ -779,6 +1025,29 @@ pub(crate) async fn stream_response_to_channel(
         }
     }
 
+    // Out of time: drop the upstream so llama-server abandons the
+    // generation, and finish the choice the way a length cap would — unless
+    // the upstream already finished it and only the usage frame was left.
+    if time_limited {
+        drop(normalized);
+        warn!(
+            limit_secs = timer.time_limit().map(|l| l.as_secs()),
+            "proxy: request ran past the proxy time limit; ending the stream"
+        );
+        if outcome.finish_reason.is_none() {
+            let done = LlmStreamEvent::Done {
+                finish_reason: TIME_LIMIT_FINISH_REASON.to_owned(),
+            };
+            outcome.finish_reason = Some(TIME_LIMIT_FINISH_REASON.to_owned());
+            if client_connected
+                && let Some(s) = encoder.encode(&done)
+                && tx.send(Ok(Bytes::from(s))).await.is_err()
+            {
+                client_connected = false;
+            }
+        }
+    }
+
     // No visible output: either rescue the turn or explain it. Skipped when the
     // client already disconnected (nothing to send).
     if client_connected && !outcome.saw_visible_output {
@@ -805,6 +1074,18 @@ pub(crate) async fn stream_response_to_channel(
             let _ = tx.send(Ok(Bytes::from(s))).await;
         }
     }
+    if client_connected && let Some(usage) = &usage {
+        let timing = timer.streaming_timing(
+            headers_at,
+            outcome.first_token_at,
+            outcome.completion_tokens,
+        );
+        if let Some(s) =
+            encoder.encode_usage_with_extension(usage, usage_extension(&timing, timer.energy()))
+        {
+            let _ = tx.send(Ok(Bytes::from(s))).await;
+        }
+    }
     // Exactly one [DONE] sentinel, sent once the wire stream is truly
     // exhausted -- never bundled into an individual event's encoding, since
     // a trailing Usage event can legitimately follow Done (see
@@ -816,6 +1097,17 @@ pub(crate) async fn stream_response_to_channel(
             .await;
     }
 
+    if !hooks.is_empty() {
+        hooks
+            .inspect(&ResponseSummary {
+                content: delivered,
+                finish_reason: outcome.finish_reason.clone(),
+                prompt_tokens: outcome.prompt_tokens,
+                completion_tokens: outcome.completion_tokens,
+            })
+            .await;
+    }
+
     outcome
 }
 
@@ -841,10 +1133,25 @@ fn usage_from_response_body(body: &[u8]) -> Option<(u32, Option<u32>)> {
     Some((prompt_tokens, cached_tokens))
 }
 
+/// Extract `usage.completion_tokens` from a non-streaming response body.
+///
+/// Kept apart from [`usage_from_response_body`] so a body that reports
+/// completion tokens without a prompt count still feeds the throughput figure.
+fn completion_tokens_from_response_body(body: &[u8]) -> Option<u32> {
+    let parsed: serde_json::Value = serde_json::from_slice(body).ok()?;
+    let tokens = parsed.get("usage")?.get("completion_tokens")?.as_u64()?;
+    u32::try_from(tokens).ok()
+}
+
 /// Forward a non-streaming JSON response from llama-server.
+///
+/// Response hooks in `hooks` run on a detached task, so inspection never
+/// delays the reply.
 async fn forward_non_streaming_response(
     response: reqwest::Response,
     cache_metrics: &CacheMetricsStore,
+    timer: RequestTimer,
+    hooks: ResponseHooks,
 ) -> Response {
     // Collect upstream headers we want to preserve
     let content_type = response
@@ -860,9 +1167,18 @@ async fn forward_non_streaming_response(
             // hold rather than extra I/O. Failure is silent by design: an
             // unparseable body still forwards verbatim, since telemetry must
             // never change what the client receives.
-            if let Some((prompt_tokens, cached_tokens)) = usage_from_response_body(&body_bytes) {
+            let usage = usage_from_response_body(&body_bytes);
+            if let Some((prompt_tokens, cached_tokens)) = usage {
                 cache_metrics.record(prompt_tokens, cached_tokens);
             }
+            timer.finish_buffered(
+                usage.map(|(prompt_tokens, _)| prompt_tokens),
+                completion_tokens_from_response_body(&body_bytes),
+            );
+            if !hooks.is_empty() {
+                let summary = ResponseSummary::from_response_body(&body_bytes);
+                tokio::spawn(async move { hooks.inspect(&summary).await });
+            }
             Response::builder()
                 .status(StatusCode::OK)
                 .header("content-type", content_type)
@@ -883,6 +1199,7 @@ async fn forward_non_streaming_response(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use gglib_core::domain::InferenceConfig;
 
     #[test]
     fn session_aware_budget_falls_back_to_live_ratio_without_a_session_id() {
@@ -1004,6 +1321,7 @@ mod tests {
             body,
             &ModelContext::passthrough(),
             &SamplingLayers::default(),
+            &ProxyLimits::default(),
             None,
         )
         .expect("no budget, so nothing to reject");
@@ -1022,6 +1340,7 @@ mod tests {
             body.clone(),
             &ModelContext::passthrough(),
             &SamplingLayers::default(),
+            &ProxyLimits::default(),
             Some(10),
         )
         .expect("a body we cannot read is forwarded, not rejected");
@@ -1036,6 +1355,7 @@ mod tests {
             oversized_body(),
             &ModelContext::passthrough(),
             &SamplingLayers::default(),
+            &ProxyLimits::default(),
             Some(20_000),
         )
         .expect("trimming the one oversized tool result is enough");
@@ -1051,6 +1371,7 @@ mod tests {
             oversized_body(),
             &ModelContext::passthrough(),
             &SamplingLayers::default(),
+            &ProxyLimits::default(),
             Some(200),
         )
         .expect_err("nothing left to trim, still over");
@@ -1061,6 +1382,158 @@ mod tests {
         ));
     }
 
+    fn shape_with_ceiling(ctx: &ModelContext, body: serde_json::Value) -> serde_json::Value {
+        let limits = ProxyLimits {
+            max_tokens: Some(2048),
+            ..ProxyLimits::default()
+        };
+        let (out, _) = shape_request_body(
+            Bytes::from(serde_json::to_vec(&body).unwrap()),
+            ctx,
+            &SamplingLayers::default(),
+            &limits,
+            None,
+        )
+        .expect("no budget, so nothing to reject");
+        serde_json::from_slice(&out).expect("valid json")
+    }
+
+    #[test]
+    fn token_ceiling_keeps_a_lower_model_default() {
+        let ctx = ModelContext {
+            inference_defaults: Some(InferenceConfig {
+                max_tokens: Some(256),
+                ..InferenceConfig::default()
+            }),
+            ..ModelContext::passthrough()
+        };
+        let body = serde_json::json!({"model": "m", "messages": []});
+
+        assert_eq!(shape_with_ceiling(&ctx, body.clone())["max_tokens"], 256);
+        assert_eq!(
+            shape_with_ceiling(&ModelContext::passthrough(), body)["max_tokens"],
+            2048,
+            "the ceiling fills in only when no layer sets a limit"
+        );
+    }
+
+    #[test]
+    fn token_ceiling_caps_a_higher_model_default() {
+        let ctx = ModelContext {
+            inference_defaults: Some(InferenceConfig {
+                max_tokens: Some(100_000),
+                ..InferenceConfig::default()
+            }),
+            ..ModelContext::passthrough()
+        };
+        let body = serde_json::json!({"model": "m", "messages": []});
+
+        assert_eq!(shape_with_ceiling(&ctx, body)["max_tokens"], 2048);
+    }
+
+    // ── Sampling parameters ──────────────────────────────────────────────────
+    //
+    // Each asserts the exact value llama-server receives. Values are chosen to
+    // be exact in `f32`, because the parameters the pipeline resolves take a
+    // round trip through `InferenceConfig`.
+
+    fn shape(body: serde_json::Value) -> serde_json::Value {
+        let (out, _) = shape_request_body(
+            Bytes::from(serde_json::to_vec(&body).unwrap()),
+            &ModelContext::passthrough(),
+            &SamplingLayers::default(),
+            &ProxyLimits::default(),
+            None,
+        )
+        .expect("no budget, so nothing to reject");
+        serde_json::from_slice(&out).expect("valid json")
+    }
+
+    fn with(fields: serde_json::Value) -> serde_json::Value {
+        let mut body = serde_json::json!({
+            "model": "m",
+            "messages": [{"role": "user", "content": "hi"}],
+        });
+        for (key, value) in fields.as_object().unwrap() {
+            body[key] = value.clone();
+        }
+        body
+    }
+
+    #[test]
+    fn each_openai_sampling_field_reaches_llama_server_unchanged() {
+        let cases = [
+            ("seed", serde_json::json!(42)),
+            ("stop", serde_json::json!(["\n\n", "</answer>"])),
+            ("stop", serde_json::json!("END")),
+            ("presence_penalty", serde_json::json!(0.5)),
+            ("frequency_penalty", serde_json::json!(0.25)),
+            ("min_p", serde_json::json!(0.125)),
+            ("typical_p", serde_json::json!(0.75)),
+            ("repeat_penalty", serde_json::json!(1.125)),
+            ("mirostat", serde_json::json!(2)),
+            ("mirostat_tau", serde_json::json!(5.0)),
+            ("mirostat_eta", serde_json::json!(0.125)),
+        ];
+
+        for (key, value) in cases {
+            let out = shape(with(serde_json::json!({ key: value.clone() })));
+            assert_eq!(out[key], value, "{key}");
+        }
+    }
+
+    #[test]
+    fn extensions_are_hoisted_to_the_top_level() {
+        let out = shape(with(serde_json::json!({
+            "extensions": {
+                "mirostat": 2,
+                "mirostat_tau": 5.0,
+                "mirostat_eta": 0.125,
+                "typical_p": 0.75,
+                "repeat_penalty": 1.125,
+                "seed": 7,
+            }
+        })));
+
+        assert_eq!(out["mirostat"], 2);
+        assert_eq!(out["mirostat_tau"], 5.0);
+        assert_eq!(out["mirostat_eta"], 0.125);
+        assert_eq!(out["typical_p"], 0.75);
+        assert_eq!(
+            out["repeat_penalty"], 1.125,
+            "resolved as the client's value"
+        );
+        assert_eq!(out["seed"], 7);
+        assert!(out.get("extensions").is_none(), "emptied, so removed");
+    }
+
+    #[test]
+    fn a_top_level_field_beats_its_extensions_copy() {
+        let out = shape(with(serde_json::json!({
+            "seed": 1,
+            "min_p": 0.25,
+            "extensions": { "seed": 2, "min_p": 0.5 },
+        })));
+
+        assert_eq!(out["seed"], 1);
+        assert_eq!(out["min_p"], 0.25);
+    }
+
+    #[test]
+    fn unrecognised_extensions_are_forwarded() {
+        let out = shape(with(serde_json::json!({
+            "extensions": { "mirostat": 1, "vendor_flag": true },
+        })));
+        assert_eq!(out["mirostat"], 1);
+        assert_eq!(
+            out["extensions"],
+            serde_json::json!({ "vendor_flag": true })
+        );
+
+        let out = shape(with(serde_json::json!({ "extensions": "opaque" })));
+        assert_eq!(out["extensions"], "opaque", "a non-object is left alone");
+    }
+
     /// The wire contract clients branch on. Asserted field by field because
     /// this is a public interface of the proxy, not an internal detail: the
     /// status, both codes and the message are all load-bearing.
@@ -1,16 +1,26 @@
 #![doc = include_str!(concat!(env!("OUT_DIR"), "/README_GENERATED.md"))]
 #![deny(unsafe_code)]
 
+pub mod access;
+pub mod auto_route;
 pub mod cache_lifecycle;
 pub mod canonicalization;
 pub mod connections;
 pub mod council_proxy;
 pub mod dashboard;
 pub mod forward;
+pub mod limits;
+pub mod live_config;
 pub mod mcp;
 pub mod metrics;
+pub mod middleware;
 pub mod models;
+pub mod moderation;
+pub mod presets;
+pub mod priority;
 pub mod profiles;
+pub mod reasoning;
+pub mod request_timing;
 pub mod server;
 pub mod settings_cache;
 pub mod slot_eviction;
@@ -18,8 +28,14 @@ pub mod slots;
 pub mod slots_poller;
 pub mod sse_stream;
 pub mod token_calibration;
+pub mod tool_calls;
 
 pub mod upstream_health;
 
+pub use access::AccessPolicy;
 pub use council_proxy::{CouncilDeps, CouncilRunParams, CouncilRunnerPort};
+pub use live_config::{AppliedConfig, ConfigWatch};
+pub use middleware::{
+    MiddlewareChain, MiddlewareRejection, ProxyMiddleware, ResponseContext, ResponseSummary,
+};
 pub use server::serve;
@@ -0,0 +1,259 @@
+//! Server-side generation limits from the `proxy_limits` setting.
+//!
+//! Each chat completion reads the limits from its settings snapshot (see
+//! [`gglib_core::domain::proxy_limits`]):
+//!
+//! - **Token ceiling** — [`clamp_max_tokens`] lowers `max_tokens`,
+//!   `max_completion_tokens` and `n_predict` to the ceiling once the sampling
+//!   layers are resolved, and sets `max_tokens` when none of them has one, so
+//!   llama-server itself stops at it with `finish_reason: "length"`.
+//! - **Wall-clock limit** — the request's [`RequestTimer`] carries a
+//!   deadline. A stream still running at it is cut off with a finish chunk
+//!   whose `finish_reason` is [`TIME_LIMIT_FINISH_REASON`], and dropping the
+//!   upstream connection makes llama-server abandon the generation. A
+//!   non-streaming request gets a 504 instead, since a buffered reply cannot
+//!   be truncated.
+//! - **Streams per key** — [`StreamLimiter`] counts open streams per
+//!   client (see [`stream_key`]); one over the limit is turned away with a
+//!   429 before a model is loaded for it.
+//!
+//! [`RequestTimer`]: crate::request_timing::RequestTimer
+//! [`TIME_LIMIT_FINISH_REASON`]: gglib_core::domain::TIME_LIMIT_FINISH_REASON
+
+use std::collections::HashMap;
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+
+use axum::body::Body;
+use axum::http::HeaderMap;
+use axum::response::Response;
+use futures_util::StreamExt;
+use gglib_core::domain::ProxyLimits;
+use serde_json::Value;
+
+use crate::access::{AccessPolicy, bearer_token};
+use tracing::debug;
+
+/// Request fields llama-server reads a generation limit from.
+const TOKEN_LIMIT_FIELDS: &[&str] = &["max_tokens", "max_completion_tokens", "n_predict"];
+
+/// Lower the token limits in a request `body` whose sampling layers are
+/// already resolved to the ceiling in `limits`.
+///
+/// Runs after resolution so `max_tokens` from a profile, preset, model or the
+/// global defaults is capped like the client's own, and the ceiling is only
+/// added when no layer set a limit at all. Bodies that are not JSON objects,
+/// and requests already within the ceiling, are left unchanged.
+pub(crate) fn clamp_max_tokens(body: &mut Value, limits: &ProxyLimits) {
+    if limits.max_tokens.is_none() {
+        return;
+    }
+    let Some(request) = body.as_object_mut() else {
+        return;
+    };
+    let mut clamped = false;
+    let mut present = false;
+    for field in TOKEN_LIMIT_FIELDS {
+        let Some(value) = request.get_mut(*field) else {
+            continue;
+        };
+        present = true;
+        if let Some(ceiling) = limits.clamp_max_tokens(value.as_i64()) {
+            *value = Value::from(ceiling);
+            clamped = true;
+        }
+    }
+    if !present && let Some(ceiling) = limits.clamp_max_tokens(None) {
+        request.insert("max_tokens".to_owned(), Value::from(ceiling));
+        clamped = true;
+    }
+    if clamped {
+        debug!(ceiling = ?limits.max_tokens, "lowered the request's token limit to the proxy ceiling");
+    }
+}
+
+/// Who a request's streams are counted against: its bearer token when
+/// `policy` has an API key (the key check has then accepted the token),
+/// otherwise its peer address. A token nothing checked is ignored, or a
+/// client could send a fresh one per stream to dodge the limit.
+pub(crate) fn stream_key(
+    headers: &HeaderMap,
+    peer: Option<SocketAddr>,
+    policy: &AccessPolicy,
+) -> String {
+    match (policy.api_key.is_some(), bearer_token(headers), peer) {
+        (true, Some(token), _) => format!("key:{token}"),
+        (_, _, Some(addr)) => format!("ip:{}", addr.ip()),
+        _ => String::new(),
+    }
+}
+
+/// Open streams per client.
+#[derive(Debug, Default)]
+pub(crate) struct StreamLimiter {
+    open: Mutex<HashMap<String, u32>>,
+}
+
+impl StreamLimiter {
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    /// Count a new stream for `key` (a [`stream_key`]), or `None` when
+    /// `key` already has `max` open.
+    pub(crate) fn try_open(self: &Arc<Self>, key: &str, max: u32) -> Option<StreamSlot> {
+        let mut open = self.lock();
+        let count = open.entry(key.to_owned()).or_default();
+        if *count >= max {
+            return None;
+        }
+        *count += 1;
+        Some(StreamSlot {
+            limiter: Arc::clone(self),
+            key: key.to_owned(),
+        })
+    }
+
+    fn close(&self, key: &str) {
+        let mut open = self.lock();
+        if let Some(count) = open.get_mut(key) {
+            *count = count.saturating_sub(1);
+            if *count == 0 {
+                open.remove(key);
+            }
+        }
+    }
+
+    #[cfg(test)]
+    fn open_for(&self, key: &str) -> u32 {
+        self.lock().get(key).copied().unwrap_or(0)
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<String, u32>> {
+        self.open
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+}
+
+/// One open stream counted by a [`StreamLimiter`]; uncounted on drop.
+#[derive(Debug)]
+pub(crate) struct StreamSlot {
+    limiter: Arc<StreamLimiter>,
+    key: String,
+}
+
+impl Drop for StreamSlot {
+    fn drop(&mut self) {
+        self.limiter.close(&self.key);
+    }
+}
+
+/// Keep `slot` until `response`'s body is finished.
+pub(crate) fn hold_until_done(response: Response, slot: StreamSlot) -> Response {
+    let (parts, body) = response.into_parts();
+    let body = body.into_data_stream().map(move |chunk| {
+        let _held = &slot;
+        chunk
+    });
+    Response::from_parts(parts, Body::from_stream(body))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ceiling(max_tokens: u32) -> ProxyLimits {
+        ProxyLimits {
+            max_tokens: Some(max_tokens),
+            ..ProxyLimits::default()
+        }
+    }
+
+    fn clamped(body: &str, limits: &ProxyLimits) -> Value {
+        let mut body = serde_json::from_str(body).unwrap();
+        clamp_max_tokens(&mut body, limits);
+        body
+    }
+
+    #[test]
+    fn clamp_lowers_every_token_limit_over_the_ceiling() {
+        let body = clamped(
+            r#"{"model":"m","max_tokens":100000,"n_predict":-1,"max_completion_tokens":100}"#,
+            &ceiling(2048),
+        );
+        assert_eq!(body["max_tokens"], 2048);
+        assert_eq!(body["n_predict"], 2048);
+        assert_eq!(body["max_completion_tokens"], 100);
+    }
+
+    #[test]
+    fn clamp_sets_the_ceiling_when_the_request_has_none() {
+        assert_eq!(
+            clamped(r#"{"model":"m"}"#, &ceiling(512))["max_tokens"],
+            512
+        );
+
+        assert_eq!(
+            clamped(r#"{"model":"m"}"#, &ProxyLimits::default()),
+            serde_json::json!({"model": "m"})
+        );
+    }
+
+    #[tokio::test]
+    async fn streams_are_counted_per_key_until_the_body_finishes() {
+        let limiter = Arc::new(StreamLimiter::new());
+        let first = limiter.try_open("key-a", 2).expect("first stream");
+        let _second = limiter.try_open("key-a", 2).expect("second stream");
+        assert!(limiter.try_open("key-a", 2).is_none());
+        // Other keys, and requests without one, are counted separately.
+        assert!(limiter.try_open("key-b", 2).is_some());
+        assert!(limiter.try_open("", 2).is_some());
+
+        let response = hold_until_done(Response::new(Body::from("data: x\n\n")), first);
+        assert_eq!(limiter.open_for("key-a"), 2);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        assert_eq!(body, "data: x\n\n");
+        assert_eq!(limiter.open_for("key-a"), 1);
+    }
+
+    #[test]
+    fn unchecked_tokens_do_not_get_their_own_streams() {
+        use axum::http::header;
+        use gglib_core::Settings;
+
+        let peer: SocketAddr = "10.0.0.7:5000".parse().unwrap();
+        let bearer = |token: &str| {
+            let mut headers = HeaderMap::new();
+            headers.insert(
+                header::AUTHORIZATION,
+                format!("Bearer {token}").parse().unwrap(),
+            );
+            headers
+        };
+
+        // Without an API key nothing checks tokens: two made-up ones from
+        // the same address share its count.
+        let open = AccessPolicy::default();
+        let limiter = Arc::new(StreamLimiter::new());
+        let _first = limiter
+            .try_open(&stream_key(&bearer("fake-1"), Some(peer), &open), 1)
+            .expect("first stream");
+        assert!(
+            limiter
+                .try_open(&stream_key(&bearer("fake-2"), Some(peer), &open), 1)
+                .is_none()
+        );
+
+        // With one, the key check has accepted the token, so it is counted
+        // on its own.
+        let keyed = AccessPolicy::from_settings(&Settings::default(), Some("sk-1".into()))
+            .with_background_keys(vec!["bg-1".into()]);
+        assert_ne!(
+            stream_key(&bearer("sk-1"), Some(peer), &keyed),
+            stream_key(&bearer("bg-1"), Some(peer), &keyed)
+        );
+    }
+}
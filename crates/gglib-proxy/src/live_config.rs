@@ -0,0 +1,188 @@
+//! Live reload of settings into a running proxy.
+//!
+//! Changing the default context or the inference profiles used to need a
+//! proxy restart, which severs every in-flight stream. Instead, whoever owns
+//! the settings (the GUI backend's `SettingsOps`, via the supervisor)
+//! publishes each saved [`Settings`] on a watch channel, and
+//! [`spawn_config_watcher`] applies it to the running proxy:
+//!
+//! - the settings snapshot is replaced outright, so inference defaults and
+//!   `{model}:{profile}` routes change on the next request instead of after
+//!   the [`SettingsCache`] TTL
+//! - a configured `default_context_size` becomes the context new models are
+//!   launched with. Models already running keep theirs until they are next
+//!   swapped in; requests in flight are untouched.
+//!
+//! What was applied is reported back as an [`AppliedConfig`] so the
+//! publisher can confirm it to clients. Out-of-process writes (the CLI) are
+//! never published and still arrive through the TTL.
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use gglib_core::Settings;
+use tokio::sync::watch;
+use tokio::task::JoinHandle;
+use tokio_util::sync::CancellationToken;
+use tracing::{debug, info};
+
+use crate::settings_cache::SettingsCache;
+
+/// Channels connecting a running proxy to its settings publisher.
+#[derive(Debug)]
+pub struct ConfigWatch {
+    /// Settings published after each save. `None` until the first one.
+    pub updates: watch::Receiver<Option<Arc<Settings>>>,
+    /// Receives what the proxy applied from each update.
+    pub applied: watch::Sender<Option<AppliedConfig>>,
+}
+
+/// The values a running proxy applied from a settings update.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AppliedConfig {
+    /// Context size models are now launched with by default.
+    pub default_ctx: u64,
+    /// Inference profiles now selectable as `{model}:{profile}`.
+    pub profiles: Vec<String>,
+}
+
+/// Apply `settings` to the live values and describe the result.
+///
+/// An unset or zero `default_context_size` keeps the context the proxy is
+/// running with, which may have come from the command line.
+fn apply(settings: &Settings, default_ctx: &AtomicU64) -> AppliedConfig {
+    if let Some(ctx) = settings.default_context_size.filter(|&c| c > 0) {
+        default_ctx.store(ctx, Ordering::Relaxed);
+    }
+    AppliedConfig {
+        default_ctx: default_ctx.load(Ordering::Relaxed),
+        profiles: settings
+            .inference_profiles
+            .iter()
+            .flatten()
+            .map(|p| p.name.clone())
+            .collect(),
+    }
+}
+
+/// Apply every published settings update until `cancel` fires.
+///
+/// `serve()` joins the returned handle on shutdown, like its other
+/// background tasks.
+pub(crate) fn spawn_config_watcher(
+    watch: ConfigWatch,
+    default_ctx: Arc<AtomicU64>,
+    settings: Arc<SettingsCache>,
+    cancel: CancellationToken,
+) -> JoinHandle<()> {
+    let ConfigWatch {
+        mut updates,
+        applied,
+    } = watch;
+
+    tokio::spawn(async move {
+        loop {
+            tokio::select! {
+                changed = updates.changed() => {
+                    if changed.is_err() {
+                        debug!("proxy config: publisher dropped; live reload stopped");
+                        break;
+                    }
+                }
+                () = cancel.cancelled() => break,
+            }
+
+            let Some(update) = updates.borrow_and_update().clone() else {
+                continue;
+            };
+            settings.replace(Arc::clone(&update)).await;
+            let config = apply(&update, &default_ctx);
+            info!(
+                default_ctx = config.default_ctx,
+                profiles = ?config.profiles,
+                "proxy config reloaded"
+            );
+            applied.send_replace(Some(config));
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use async_trait::async_trait;
+    use gglib_core::RepositoryError;
+    use gglib_core::domain::{InferenceConfig, InferenceProfile};
+    use gglib_core::ports::SettingsRepository;
+    use std::time::Duration;
+
+    struct DefaultRepo;
+
+    #[async_trait]
+    impl SettingsRepository for DefaultRepo {
+        async fn load(&self) -> Result<Settings, RepositoryError> {
+            Ok(Settings::default())
+        }
+
+        async fn save(&self, _settings: &Settings) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn unset_context_keeps_the_running_value() {
+        let ctx = AtomicU64::new(8192);
+        let applied = apply(&Settings::default(), &ctx);
+        assert_eq!(applied.default_ctx, 8192);
+        assert!(applied.profiles.is_empty());
+    }
+
+    #[tokio::test]
+    async fn published_settings_apply_without_waiting_for_the_ttl() {
+        let cache = Arc::new(SettingsCache::with_ttl(
+            Arc::new(DefaultRepo),
+            Duration::from_secs(3600),
+        ));
+        assert_eq!(cache.get().await.default_context_size, None);
+
+        let (tx, updates) = watch::channel(None);
+        let (applied, mut applied_rx) = watch::channel(None);
+        let ctx = Arc::new(AtomicU64::new(4096));
+        let cancel = CancellationToken::new();
+        let task = spawn_config_watcher(
+            ConfigWatch { updates, applied },
+            Arc::clone(&ctx),
+            Arc::clone(&cache),
+            cancel.clone(),
+        );
+
+        tx.send_replace(Some(Arc::new(Settings {
+            default_context_size: Some(32768),
+            inference_profiles: Some(vec![InferenceProfile {
+                name: "coding".to_owned(),
+                description: None,
+                config: InferenceConfig::default(),
+                list_in_models: false,
+            }]),
+            ..Settings::default()
+        })));
+
+        tokio::time::timeout(Duration::from_secs(5), applied_rx.changed())
+            .await
+            .expect("reload is confirmed")
+            .unwrap();
+        assert_eq!(
+            *applied_rx.borrow(),
+            Some(AppliedConfig {
+                default_ctx: 32768,
+                profiles: vec!["coding".to_owned()],
+            })
+        );
+        assert_eq!(ctx.load(Ordering::Relaxed), 32768);
+        assert_eq!(cache.get().await.default_context_size, Some(32768));
+
+        cancel.cancel();
+        task.await.unwrap();
+    }
+}
@@ -0,0 +1,433 @@
+//! Request/response middleware hooks.
+//!
+//! A [`MiddlewareChain`] is an ordered list of [`ProxyMiddleware`]
+//! implementations registered when the proxy starts (see
+//! [`crate::serve`]). It lets an embedder add request filtering, prompt
+//! inspection or custom upstream headers without touching the forwarder.
+//!
+//! Each middleware may implement any of three hooks, all defaulting to
+//! no-ops:
+//!
+//! | Hook | When | Can |
+//! |------|------|-----|
+//! | [`on_request`](ProxyMiddleware::on_request) | Before routing, for every `/v1/chat/completions` request | Rewrite the JSON body and headers, or reject the request |
+//! | [`on_stream_event`](ProxyMiddleware::on_stream_event) | For each normalized streaming event, before encoding | Rewrite or drop the event |
+//! | [`on_response`](ProxyMiddleware::on_response) | Once a forwarded response has been handed to the client | Inspect a [`ResponseSummary`] |
+//!
+//! Hooks run in registration order. A request hook runs before routing, so a
+//! rewritten `model` or `preset` is honoured; the first rejection stops the
+//! chain and is returned to the client as an OpenAI error envelope. Council
+//! models are routed after the request hook, but their responses are produced
+//! by the orchestrator rather than forwarded, so the stream and response hooks
+//! never see them.
+//!
+//! An empty chain costs nothing: the body is only parsed, and text only
+//! accumulated for the summary, when at least one middleware is registered.
+//! While moderation is enabled the built-in [`crate::moderation`] stage runs
+//! ahead of the registered middleware.
+//!
+//! The request hook sees the request id as the [`REQUEST_ID_HEADER`] header,
+//! equal to [`ResponseContext::request_id`], so state it keeps for the
+//! response hooks can be keyed on it.
+
+use std::fmt;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use axum::http::{HeaderMap, StatusCode};
+use bytes::Bytes;
+use serde_json::Value;
+use tracing::{debug, warn};
+
+use gglib_core::LlmStreamEvent;
+
+/// Request header carrying [`ResponseContext::request_id`] to the request
+/// hooks. Set by the proxy; a client's own value is replaced.
+pub const REQUEST_ID_HEADER: &str = "x-gglib-request-id";
+
+/// A request/response hook registered on the proxy.
+#[async_trait]
+pub trait ProxyMiddleware: Send + Sync {
+    /// Short name used in logs and in rejection messages.
+    fn name(&self) -> &str;
+
+    /// Inspect or rewrite an incoming chat-completion request.
+    ///
+    /// `body` is the parsed request JSON; changes are forwarded. Headers
+    /// added to `headers` reach llama-server unless they are hop-by-hop or
+    /// `authorization`, which the forwarder always strips.
+    ///
+    /// # Errors
+    ///
+    /// A [`MiddlewareRejection`] ends the request with its status and
+    /// message; later middleware does not run.
+    async fn on_request(
+        &self,
+        _body: &mut Value,
+        _headers: &mut HeaderMap,
+    ) -> Result<(), MiddlewareRejection> {
+        Ok(())
+    }
+
+    /// Transform one normalized streaming event before it is encoded.
+    ///
+    /// Returning `None` drops the event. Synchronous because it runs on the
+    /// streaming hot path; a middleware that needs state across events can
+    /// key it on [`ResponseContext::request_id`].
+    fn on_stream_event(
+        &self,
+        _ctx: &ResponseContext,
+        event: LlmStreamEvent,
+    ) -> Option<LlmStreamEvent> {
+        Some(event)
+    }
+
+    /// Inspect a forwarded response once it has been delivered.
+    ///
+    /// Called for successful upstream responses only, once the response has
+    /// been handed to the client; it cannot change what was sent.
+    async fn on_response(&self, _ctx: &ResponseContext, _response: &ResponseSummary) {}
+}
+
+/// Why a middleware refused a request.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MiddlewareRejection {
+    /// HTTP status returned to the client.
+    pub status: StatusCode,
+    /// Message placed in the error envelope.
+    pub message: String,
+}
+
+impl MiddlewareRejection {
+    /// A `400 Bad Request` rejection.
+    pub fn bad_request(message: impl Into<String>) -> Self {
+        Self {
+            status: StatusCode::BAD_REQUEST,
+            message: message.into(),
+        }
+    }
+
+    /// A `403 Forbidden` rejection, for requests refused on policy grounds.
+    pub fn forbidden(message: impl Into<String>) -> Self {
+        Self {
+            status: StatusCode::FORBIDDEN,
+            message: message.into(),
+        }
+    }
+}
+
+/// What the stream and response hooks know about the request.
+#[derive(Debug, Clone)]
+pub struct ResponseContext {
+    /// Unique per request, shared by every hook call for it.
+    pub request_id: String,
+    /// Base model name the request was routed to (profile suffix removed).
+    pub model: String,
+    /// Whether the response is streamed.
+    pub streaming: bool,
+    /// Cache session id, from `x-gglib-session-id` or derived from the
+    /// conversation.
+    pub session_id: Option<String>,
+}
+
+/// A delivered response, as seen by [`ProxyMiddleware::on_response`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ResponseSummary {
+    /// Assistant text the client received. For a streamed response this is
+    /// the concatenated content deltas after stream hooks ran.
+    pub content: String,
+    /// The choice's `finish_reason`, when the upstream reported one.
+    pub finish_reason: Option<String>,
+    /// `usage.prompt_tokens`, when reported.
+    pub prompt_tokens: Option<u32>,
+    /// `usage.completion_tokens`, when reported.
+    pub completion_tokens: Option<u32>,
+}
+
+impl ResponseSummary {
+    /// Summarize a non-streaming chat-completion body. Fields the body does
+    /// not carry stay empty.
+    pub(crate) fn from_response_body(body: &[u8]) -> Self {
+        let Ok(parsed) = serde_json::from_slice::<Value>(body) else {
+            return Self::default();
+        };
+        let choice = parsed.pointer("/choices/0");
+        let tokens = |pointer: &str| {
+            parsed
+                .pointer(pointer)
+                .and_then(Value::as_u64)
+                .and_then(|n| u32::try_from(n).ok())
+        };
+        Self {
+            content: choice
+                .and_then(|c| c.pointer("/message/content"))
+                .and_then(Value::as_str)
+                .unwrap_or_default()
+                .to_owned(),
+            finish_reason: choice
+                .and_then(|c| c.get("finish_reason"))
+                .and_then(Value::as_str)
+                .map(str::to_owned),
+            prompt_tokens: tokens("/usage/prompt_tokens"),
+            completion_tokens: tokens("/usage/completion_tokens"),
+        }
+    }
+}
+
+/// Ordered middleware registered on the proxy. Cheap to clone.
+#[derive(Clone, Default)]
+pub struct MiddlewareChain {
+    hooks: Arc<[Arc<dyn ProxyMiddleware>]>,
+}
+
+impl MiddlewareChain {
+    /// A chain running `hooks` in the given order.
+    pub fn new(hooks: Vec<Arc<dyn ProxyMiddleware>>) -> Self {
+        Self {
+            hooks: hooks.into(),
+        }
+    }
+
+    /// Whether no middleware is registered.
+    pub fn is_empty(&self) -> bool {
+        self.hooks.is_empty()
+    }
+
+    /// This chain with `hook` running ahead of the rest.
+    pub(crate) fn with_first(&self, hook: Arc<dyn ProxyMiddleware>) -> Self {
+        Self::new(
+            std::iter::once(hook)
+                .chain(self.hooks.iter().cloned())
+                .collect(),
+        )
+    }
+
+    /// Registered middleware names, in order.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.hooks.iter().map(|hook| hook.name())
+    }
+
+    /// Run every request hook over `body`, returning the rewritten bytes.
+    ///
+    /// A body that is not JSON is returned untouched without running any
+    /// hook — the handler rejects it as malformed straight after.
+    ///
+    /// # Errors
+    ///
+    /// The first rejection, with the name of the middleware that made it.
+    pub(crate) async fn run_request(
+        &self,
+        body: Bytes,
+        headers: &mut HeaderMap,
+    ) -> Result<Bytes, (String, MiddlewareRejection)> {
+        if self.is_empty() {
+            return Ok(body);
+        }
+        let Ok(mut value) = serde_json::from_slice::<Value>(&body) else {
+            return Ok(body);
+        };
+        for hook in self.hooks.iter() {
+            if let Err(rejection) = hook.on_request(&mut value, headers).await {
+                debug!(middleware = hook.name(), status = %rejection.status, "request rejected by middleware");
+                return Err((hook.name().to_owned(), rejection));
+            }
+        }
+        match serde_json::to_vec(&value) {
+            Ok(bytes) => Ok(Bytes::from(bytes)),
+            Err(e) => {
+                warn!(error = %e, "failed to re-serialize request body after middleware; forwarding original");
+                Ok(body)
+            }
+        }
+    }
+
+    /// Bind the chain to one request for the stream and response hooks.
+    pub fn for_response(&self, ctx: ResponseContext) -> ResponseHooks {
+        ResponseHooks {
+            chain: self.clone(),
+            ctx,
+        }
+    }
+}
+
+impl fmt::Debug for MiddlewareChain {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.names()).finish()
+    }
+}
+
+/// A [`MiddlewareChain`] bound to one request's [`ResponseContext`].
+#[derive(Clone)]
+pub struct ResponseHooks {
+    chain: MiddlewareChain,
+    ctx: ResponseContext,
+}
+
+impl ResponseHooks {
+    /// Whether no middleware is registered.
+    pub(crate) fn is_empty(&self) -> bool {
+        self.chain.is_empty()
+    }
+
+    /// Run every stream hook over `event`; `None` once one drops it.
+    pub(crate) fn transform(&self, event: LlmStreamEvent) -> Option<LlmStreamEvent> {
+        self.chain
+            .hooks
+            .iter()
+            .try_fold(event, |event, hook| hook.on_stream_event(&self.ctx, event))
+    }
+
+    /// Run every response hook over `summary`.
+    pub(crate) async fn inspect(&self, summary: &ResponseSummary) {
+        for hook in self.chain.hooks.iter() {
+            hook.on_response(&self.ctx, summary).await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    struct SetField(&'static str);
+
+    #[async_trait]
+    impl ProxyMiddleware for SetField {
+        fn name(&self) -> &str {
+            self.0
+        }
+
+        async fn on_request(
+            &self,
+            body: &mut Value,
+            headers: &mut HeaderMap,
+        ) -> Result<(), MiddlewareRejection> {
+            let order = body["order"].as_str().unwrap_or_default().to_owned();
+            body["order"] = json!(format!("{order}{}", self.0));
+            headers.insert("x-seen-by", self.0.parse().unwrap());
+            Ok(())
+        }
+
+        fn on_stream_event(
+            &self,
+            _ctx: &ResponseContext,
+            event: LlmStreamEvent,
+        ) -> Option<LlmStreamEvent> {
+            match event {
+                LlmStreamEvent::TextDelta { content } if content == "drop" => None,
+                LlmStreamEvent::TextDelta { content } => Some(LlmStreamEvent::TextDelta {
+                    content: format!("{content}{}", self.0),
+                }),
+                other => Some(other),
+            }
+        }
+    }
+
+    struct Reject;
+
+    #[async_trait]
+    impl ProxyMiddleware for Reject {
+        fn name(&self) -> &str {
+            "reject"
+        }
+
+        async fn on_request(
+            &self,
+            _body: &mut Value,
+            _headers: &mut HeaderMap,
+        ) -> Result<(), MiddlewareRejection> {
+            Err(MiddlewareRejection::forbidden("no"))
+        }
+    }
+
+    fn chain(hooks: Vec<Arc<dyn ProxyMiddleware>>) -> MiddlewareChain {
+        MiddlewareChain::new(hooks)
+    }
+
+    fn ctx() -> ResponseContext {
+        ResponseContext {
+            request_id: "r".into(),
+            model: "m".into(),
+            streaming: true,
+            session_id: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn request_hooks_run_in_registration_order() {
+        let chain = chain(vec![Arc::new(SetField("a")), Arc::new(SetField("b"))]);
+        let mut headers = HeaderMap::new();
+        let body = chain
+            .run_request(Bytes::from(r#"{"model":"m"}"#), &mut headers)
+            .await
+            .unwrap();
+
+        let body: Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(body, json!({ "model": "m", "order": "ab" }));
+        assert_eq!(headers["x-seen-by"], "b");
+    }
+
+    #[tokio::test]
+    async fn the_first_rejection_stops_the_chain() {
+        let chain = chain(vec![Arc::new(Reject), Arc::new(SetField("late"))]);
+        let mut headers = HeaderMap::new();
+        let (name, rejection) = chain
+            .run_request(Bytes::from("{}"), &mut headers)
+            .await
+            .unwrap_err();
+
+        assert_eq!(name, "reject");
+        assert_eq!(rejection.status, StatusCode::FORBIDDEN);
+        assert!(headers.is_empty(), "later middleware must not run");
+    }
+
+    #[tokio::test]
+    async fn non_json_bodies_skip_the_request_hooks() {
+        let chain = chain(vec![Arc::new(Reject)]);
+        let body = chain
+            .run_request(Bytes::from("not json"), &mut HeaderMap::new())
+            .await
+            .unwrap();
+        assert_eq!(body, "not json");
+    }
+
+    #[test]
+    fn stream_hooks_chain_and_can_drop_events() {
+        let hooks =
+            chain(vec![Arc::new(SetField("a")), Arc::new(SetField("b"))]).for_response(ctx());
+
+        assert_eq!(
+            hooks.transform(LlmStreamEvent::TextDelta {
+                content: "x".into()
+            }),
+            Some(LlmStreamEvent::TextDelta {
+                content: "xab".into()
+            })
+        );
+        assert_eq!(
+            hooks.transform(LlmStreamEvent::TextDelta {
+                content: "drop".into()
+            }),
+            None
+        );
+    }
+
+    #[test]
+    fn summary_reads_a_non_streaming_body() {
+        let body = json!({
+            "choices": [{ "message": { "content": "hi" }, "finish_reason": "stop" }],
+            "usage": { "prompt_tokens": 5, "completion_tokens": 2 },
+        });
+        let summary = ResponseSummary::from_response_body(body.to_string().as_bytes());
+        assert_eq!(
+            summary,
+            ResponseSummary {
+                content: "hi".into(),
+                finish_reason: Some("stop".into()),
+                prompt_tokens: Some(5),
+                completion_tokens: Some(2),
+            }
+        );
+    }
+}
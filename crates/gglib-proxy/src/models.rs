@@ -3,6 +3,7 @@
 //! This module contains types that match the OpenAI API specification.
 //! Domain types live in `gglib-core`; this module handles the API layer mapping.
 
+use gglib_core::domain::ModelCapabilities;
 use gglib_core::ports::{ModelRuntimeError, ModelSummary};
 use gglib_core::server_config::{ServerConfigOptions, resolve_context_size};
 use serde::{Deserialize, Serialize};
@@ -108,6 +109,9 @@ pub(crate) struct ChatRoutingEnvelope {
     pub stream: bool,
     /// Optional context window override (Ollama-compatible).
     pub num_ctx: Option<u64>,
+    /// Preset selected in the body; the `x-gglib-preset` header overrides it.
+    #[serde(default)]
+    pub preset: Option<String>,
 }
 
 /// Full OpenAI-compatible chat completion request.
@@ -239,6 +243,42 @@ pub struct Usage {
 // Models Endpoint Types
 // =============================================================================
 
+/// Which family of models a `/v1/models` listing should contain.
+///
+/// OpenAI clients never send a filter, so the default is [`Self::Chat`]:
+/// embedding and reranker models stay out of chat pickers. Embedding-aware
+/// clients opt in with `GET /v1/models?type=embedding` (or `reranker`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ModelListKind {
+    /// Generative chat models (everything not flagged embedding/reranker).
+    #[default]
+    Chat,
+    /// Embedding models.
+    Embedding,
+    /// Reranker models.
+    Reranker,
+}
+
+impl ModelListKind {
+    /// Whether a model with the given capabilities belongs in this listing.
+    pub fn matches(self, capabilities: ModelCapabilities) -> bool {
+        match self {
+            Self::Chat => capabilities.is_chat_model(),
+            Self::Embedding => capabilities.is_embedding(),
+            Self::Reranker => capabilities.is_reranker(),
+        }
+    }
+}
+
+/// Query parameters accepted by `GET /v1/models`.
+#[derive(Debug, Default, Deserialize)]
+pub struct ModelsQuery {
+    /// Model family to list; defaults to chat models.
+    #[serde(default, rename = "type")]
+    pub kind: ModelListKind,
+}
+
 /// Response from /v1/models endpoint.
 #[derive(Debug, Clone, Serialize)]
 pub struct ModelsResponse {
@@ -403,6 +443,35 @@ impl ErrorResponse {
         )
     }
 
+    /// Create an error response for a request naming an unknown preset.
+    pub fn preset_not_found(name: &str) -> Self {
+        Self::with_code(
+            format!("Preset '{name}' not found"),
+            "invalid_request_error",
+            "preset_not_found",
+        )
+    }
+
+    /// Create an error response for an `auto:` alias no model can serve:
+    /// either the capability is unknown or no catalog model has it.
+    pub fn no_auto_route(alias: &str, reason: &str) -> Self {
+        Self::with_code(
+            format!("Cannot route '{alias}': {reason}"),
+            "invalid_request_error",
+            "no_auto_route",
+        )
+    }
+
+    /// Create an error response for a request refused by a registered
+    /// middleware (see [`crate::middleware`]).
+    pub fn rejected_by_middleware(middleware: &str, msg: &str) -> Self {
+        Self::with_code(
+            format!("Request rejected by {middleware}: {msg}"),
+            "invalid_request_error",
+            "request_rejected",
+        )
+    }
+
     /// Create an error response for upstream connection failure.
     pub fn upstream_error(reason: &str) -> Self {
         Self::with_code(
@@ -412,6 +481,26 @@ impl ErrorResponse {
         )
     }
 
+    /// Create an error response for a stream turned away because its API
+    /// key already has `max` streams open (HTTP 429).
+    pub fn too_many_streams(max: u32) -> Self {
+        Self::with_code(
+            format!("This API key already has {max} stream(s) open; retry when one finishes"),
+            "rate_limit_error",
+            "too_many_streams",
+        )
+    }
+
+    /// Create an error response for a non-streaming request that ran past
+    /// the proxy's time limit (HTTP 504).
+    pub fn time_limit_exceeded(seconds: u64) -> Self {
+        Self::with_code(
+            format!("Request ran longer than the proxy's {seconds}s limit"),
+            "server_error",
+            "time_limit",
+        )
+    }
+
     /// Create an error response for context length exceeded.
     ///
     /// Returned as HTTP 400 when the proxy cannot reduce the history payload
@@ -449,6 +538,9 @@ impl From<ModelRuntimeError> for ErrorResponse {
                 "invalid_request_error",
                 "model_file_not_found",
             ),
+            ModelRuntimeError::ContextTooLarge(msg) => {
+                Self::with_code(msg, "invalid_request_error", "context_length_exceeded")
+            }
             ModelRuntimeError::Internal(msg) => Self::new(msg, "server_error"),
         }
     }
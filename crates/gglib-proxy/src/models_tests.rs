@@ -1,5 +1,4 @@
 use super::*;
-use gglib_core::domain::ModelCapabilities;
 use gglib_core::settings::DEFAULT_CONTEXT_SIZE;
 
 // =========================================================================
@@ -143,6 +142,18 @@ fn from_model_file_not_found_error() {
     assert_eq!(err.error.code.as_deref(), Some("model_file_not_found"));
 }
 
+#[test]
+fn from_context_too_large_error() {
+    let err = ModelRuntimeError::ContextTooLarge(
+        "Context size 65536 exceeds the model's maximum of 32768 tokens".into(),
+    );
+    assert_eq!(err.suggested_status_code(), 400);
+    let err: ErrorResponse = err.into();
+    assert!(err.error.message.contains("32768"));
+    assert_eq!(err.error.r#type, "invalid_request_error");
+    assert_eq!(err.error.code.as_deref(), Some("context_length_exceeded"));
+}
+
 #[test]
 fn from_internal_error() {
     let err: ErrorResponse = ModelRuntimeError::Internal("db locked".into()).into();
@@ -180,6 +191,26 @@ fn models_response_from_empty_summaries() {
     assert!(resp.data.is_empty());
 }
 
+#[test]
+fn model_list_kind_defaults_to_chat_and_filters_by_purpose() {
+    let query: ModelsQuery = serde_json::from_str("{}").unwrap();
+    assert_eq!(query.kind, ModelListKind::Chat);
+
+    let chat = ModelCapabilities::SUPPORTS_TOOL_CALLS;
+    let embed = ModelCapabilities::EMBEDDING;
+    let rerank = ModelCapabilities::RERANKER;
+
+    assert!(ModelListKind::Chat.matches(chat));
+    assert!(!ModelListKind::Chat.matches(embed));
+    assert!(!ModelListKind::Chat.matches(rerank));
+    assert!(ModelListKind::Embedding.matches(embed));
+    assert!(!ModelListKind::Embedding.matches(chat));
+    assert!(ModelListKind::Reranker.matches(rerank));
+
+    let query: ModelsQuery = serde_json::from_str(r#"{"type":"embedding"}"#).unwrap();
+    assert_eq!(query.kind, ModelListKind::Embedding);
+}
+
 #[test]
 fn models_response_from_summaries_maps_fields() {
     let summaries = vec![
@@ -362,6 +393,7 @@ fn models_response_respects_server_defaults_context_length() {
         inference_defaults: None,
         server_defaults: Some(ServerConfig {
             context_length: Some(8192),
+            ..Default::default()
         }),
     };
     // Global default is 4096, but server_defaults (8192) wins.
@@ -388,6 +420,7 @@ fn models_response_falls_through_when_server_defaults_context_length_none() {
         inference_defaults: None,
         server_defaults: Some(ServerConfig {
             context_length: None, // exists but context_length is None
+            ..Default::default()
         }),
     };
     // Falls through to global default (4096).
@@ -0,0 +1,850 @@
+//! Built-in moderation stage.
+//!
+//! While the `moderation` setting holds a [`ModerationConfig`], the proxy
+//! puts [`ModerationMiddleware`] at the front of the middleware chain (see
+//! [`crate::middleware`]). It checks the latest user message before the
+//! request is routed and the assistant's reply as it streams, using the
+//! configured keyword/regex rules and, optionally, a classifier model.
+//!
+//! | Check | Prompt | Streamed response | Non-streamed response |
+//! |-------|--------|-------------------|-----------------------|
+//! | Rule | Before routing | As text arrives | After delivery |
+//! | Classifier | Before routing | After delivery | After delivery |
+//!
+//! A `block` on the prompt refuses the request with `403`. A `block` on a
+//! streamed reply replaces the rest of the text with a notice and ends the
+//! stream with `finish_reason: "content_filter"`; an `annotate` inserts a
+//! notice where the match was found, or at the start of the reply for a
+//! prompt match. Checks that can only run after delivery still record their
+//! decision, marked as not applied.
+//!
+//! Every decision goes to a [`ModerationLog`], served at
+//! `GET /v1/proxy/moderation` for review. A classifier that cannot be
+//! reached is logged and skipped; moderation never fails a request on its
+//! own account.
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use async_trait::async_trait;
+use axum::http::HeaderMap;
+use regex::{Regex, RegexBuilder};
+use reqwest::Client;
+use serde::Serialize;
+use serde_json::{Value, json};
+use tracing::{debug, warn};
+
+use gglib_core::LlmStreamEvent;
+use gglib_core::domain::{
+    ModerationAction, ModerationClassifier, ModerationConfig, ModerationMatch, ModerationRule,
+    ModerationScope,
+};
+
+use crate::middleware::{
+    MiddlewareRejection, ProxyMiddleware, REQUEST_ID_HEADER, ResponseContext, ResponseSummary,
+};
+use crate::settings_cache::SettingsCache;
+
+/// Decisions kept by [`ModerationLog`]; the oldest is dropped beyond this.
+const MAX_DECISIONS: usize = 200;
+
+/// How long a classifier call may take before the check is skipped.
+const CLASSIFIER_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// How long per-request state is kept for a response that never arrives
+/// (an upstream failure or a council model skips the response hooks).
+const PENDING_TTL: Duration = Duration::from_secs(600);
+
+/// Characters of context kept either side of a match in a decision excerpt.
+const EXCERPT_CONTEXT: usize = 40;
+
+/// `finish_reason` of a streamed reply cut short by a `block`, as `OpenAI`
+/// reports filtered completions.
+pub const CONTENT_FILTER_FINISH_REASON: &str = "content_filter";
+
+/// Source name recorded for classifier decisions.
+const CLASSIFIER_SOURCE: &str = "classifier";
+
+/// Which side of the exchange a decision is about.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ModerationStage {
+    Prompt,
+    Response,
+}
+
+impl ModerationStage {
+    const fn as_str(self) -> &'static str {
+        match self {
+            Self::Prompt => "prompt",
+            Self::Response => "response",
+        }
+    }
+}
+
+/// One check that fired on one request.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ModerationDecision {
+    /// Id shared by every decision on the same request.
+    pub request_id: String,
+    /// Model named in the request.
+    pub model: String,
+    pub stage: ModerationStage,
+    /// Name of the rule that fired, or `classifier`.
+    pub source: String,
+    pub action: ModerationAction,
+    /// Whether the action took effect. `false` for a `block` or `annotate`
+    /// decided after the response had already been delivered.
+    pub applied: bool,
+    /// The matched text with some surrounding context; the start of the
+    /// text for classifier decisions.
+    pub excerpt: String,
+    /// Unix timestamp (seconds since epoch) the decision was made.
+    pub recorded_at_secs: u64,
+}
+
+/// Fixed-capacity log of recent [`ModerationDecision`]s.
+///
+/// Like [`crate::metrics::ContextMetricsStore`], it uses a synchronous
+/// mutex so the lock can never be held across an `.await`.
+#[derive(Debug, Default)]
+pub struct ModerationLog {
+    decisions: Mutex<VecDeque<ModerationDecision>>,
+}
+
+impl ModerationLog {
+    /// An empty log.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record a decision, dropping the oldest beyond capacity.
+    pub fn record(&self, decision: ModerationDecision) {
+        debug!(
+            request_id = %decision.request_id,
+            stage = decision.stage.as_str(),
+            source = %decision.source,
+            action = ?decision.action,
+            applied = decision.applied,
+            "moderation check fired"
+        );
+        let mut guard = self.decisions.lock().unwrap_or_else(|e| e.into_inner());
+        guard.push_back(decision);
+        if guard.len() > MAX_DECISIONS {
+            guard.pop_front();
+        }
+    }
+
+    /// Recorded decisions, newest first.
+    pub fn recent(&self) -> Vec<ModerationDecision> {
+        let guard = self.decisions.lock().unwrap_or_else(|e| e.into_inner());
+        guard.iter().rev().cloned().collect()
+    }
+}
+
+/// A [`ModerationRule`] with its pattern compiled.
+struct CompiledRule {
+    name: String,
+    regex: Regex,
+    /// Bytes before the previous end of the text a new match can start at,
+    /// so a streamed keyword split across deltas is still found without
+    /// rescanning the whole reply. `None` (regexes) rescans from the start.
+    lookback: Option<usize>,
+    scope: ModerationScope,
+    action: ModerationAction,
+}
+
+impl CompiledRule {
+    /// Compile `rule`, or `None` (with a warning) when its regex is invalid.
+    /// Validation on save normally rules that out.
+    fn compile(rule: &ModerationRule) -> Option<Self> {
+        let (pattern, lookback) = match rule.match_kind {
+            ModerationMatch::Keyword => (
+                regex::escape(&rule.pattern),
+                // Case folding can change a character's encoded length.
+                Some(rule.pattern.chars().count() * 4),
+            ),
+            ModerationMatch::Regex => (rule.pattern.clone(), None),
+        };
+        let regex = RegexBuilder::new(&pattern)
+            .case_insensitive(rule.match_kind == ModerationMatch::Keyword)
+            .build();
+        match regex {
+            Ok(regex) => Some(Self {
+                name: rule.name.clone(),
+                regex,
+                lookback,
+                scope: rule.scope,
+                action: rule.action,
+            }),
+            Err(e) => {
+                warn!(rule = %rule.name, error = %e, "skipping moderation rule with an invalid pattern");
+                None
+            }
+        }
+    }
+
+    /// The excerpt of the first match in `text` that ends after byte
+    /// `seen`, the length of the text already checked.
+    fn find(&self, text: &str, seen: usize) -> Option<String> {
+        match self.lookback {
+            Some(lookback) => {
+                let start = floor_char_boundary(text, seen.saturating_sub(lookback));
+                self.regex
+                    .find_iter(&text[start..])
+                    .find(|m| start + m.end() > seen)
+                    .map(|m| excerpt(text, start + m.start(), start + m.end()))
+            }
+            None => self
+                .regex
+                .find(text)
+                .map(|m| excerpt(text, m.start(), m.end())),
+        }
+    }
+}
+
+/// The largest char boundary in `text` at or below `index`.
+fn floor_char_boundary(text: &str, index: usize) -> usize {
+    let mut index = index.min(text.len());
+    while !text.is_char_boundary(index) {
+        index -= 1;
+    }
+    index
+}
+
+/// `text[start..end]` with up to [`EXCERPT_CONTEXT`] characters either side.
+fn excerpt(text: &str, start: usize, end: usize) -> String {
+    let before: Vec<char> = text[..start].chars().rev().take(EXCERPT_CONTEXT).collect();
+    let after: String = text[end..].chars().take(EXCERPT_CONTEXT).collect();
+    let mut out = String::new();
+    if before.len() == EXCERPT_CONTEXT && before.len() < text[..start].chars().count() {
+        out.push('…');
+    }
+    out.extend(before.iter().rev());
+    out.push_str(&text[start..end]);
+    out.push_str(&after);
+    if after.len() < text[end..].len() {
+        out.push('…');
+    }
+    out
+}
+
+/// The notice shown to the client for an applied `annotate` or `block`.
+fn notice(stage: ModerationStage, source: &str, action: ModerationAction) -> String {
+    let verb = if action == ModerationAction::Block {
+        "blocked"
+    } else {
+        "flagged"
+    };
+    format!("[moderation: {} {verb} by {source}]", stage.as_str())
+}
+
+/// Text of the latest user message: a string `content`, or the `text`
+/// parts of a multi-part one.
+fn latest_user_text(body: &Value) -> Option<String> {
+    let message = body
+        .get("messages")?
+        .as_array()?
+        .iter()
+        .rev()
+        .find(|m| m.get("role").and_then(Value::as_str) == Some("user"))?;
+    match message.get("content")? {
+        Value::String(text) => Some(text.clone()),
+        Value::Array(parts) => Some(
+            parts
+                .iter()
+                .filter_map(|p| p.get("text").and_then(Value::as_str))
+                .collect::<Vec<_>>()
+                .join("\n"),
+        ),
+        _ => None,
+    }
+}
+
+fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+}
+
+/// What the response hooks need to know about one moderated request.
+struct Pending {
+    created: Instant,
+    model: String,
+    rules: Arc<[CompiledRule]>,
+    classifier: Option<ModerationClassifier>,
+    /// Notices for prompt annotations, placed before the first text delta.
+    prefix: Option<String>,
+    /// Reply text seen so far.
+    text: String,
+    /// Indexes into `rules` that already fired on the reply.
+    fired: Vec<usize>,
+    /// Set once a `block` cut the reply short.
+    blocked: bool,
+}
+
+/// The moderation stage, installed by [`crate::serve`] while moderation is
+/// enabled. See the module docs.
+pub struct ModerationMiddleware {
+    settings: Arc<SettingsCache>,
+    client: Client,
+    log: Arc<ModerationLog>,
+    pending: Mutex<HashMap<String, Pending>>,
+}
+
+impl ModerationMiddleware {
+    /// Read the configuration from `settings` on each request, call the
+    /// classifier through `client` and record decisions in `log`.
+    pub fn new(settings: Arc<SettingsCache>, client: Client, log: Arc<ModerationLog>) -> Self {
+        Self {
+            settings,
+            client,
+            log,
+            pending: Mutex::new(HashMap::new()),
+        }
+    }
+
+    fn pending(&self) -> std::sync::MutexGuard<'_, HashMap<String, Pending>> {
+        self.pending.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Ask the classifier about `text`. `None` when it could not answer.
+    async fn classify(&self, classifier: &ModerationClassifier, text: &str) -> Option<bool> {
+        let url = format!(
+            "{}/v1/chat/completions",
+            classifier.url.trim().trim_end_matches('/')
+        );
+        let mut body = json!({
+            "messages": [
+                { "role": "system", "content": classifier.effective_instruction() },
+                { "role": "user", "content": text },
+            ],
+            "temperature": 0,
+            "max_tokens": 16,
+            "stream": false,
+        });
+        if let Some(model) = &classifier.model {
+            body["model"] = json!(model);
+        }
+        let response = self
+            .client
+            .post(&url)
+            .timeout(CLASSIFIER_TIMEOUT)
+            .json(&body)
+            .send()
+            .await
+            .and_then(reqwest::Response::error_for_status);
+        let reply = match response {
+            Ok(response) => response.json::<Value>().await,
+            Err(e) => Err(e),
+        };
+        match reply {
+            Ok(reply) => reply
+                .pointer("/choices/0/message/content")
+                .and_then(Value::as_str)
+                .map(|content| classifier.is_hit(content)),
+            Err(e) => {
+                warn!(url = %url, error = %e, "moderation classifier unavailable; skipping check");
+                None
+            }
+        }
+    }
+
+    /// Run the classifier over `text` and record a hit.
+    async fn classify_and_record(
+        &self,
+        classifier: &ModerationClassifier,
+        text: &str,
+        request: (&str, &str),
+        stage: ModerationStage,
+        applied: bool,
+    ) -> bool {
+        if text.trim().is_empty() || self.classify(classifier, text).await != Some(true) {
+            return false;
+        }
+        let end = text
+            .char_indices()
+            .nth(EXCERPT_CONTEXT * 2)
+            .map_or(text.len(), |(i, _)| i);
+        self.record(
+            request,
+            stage,
+            CLASSIFIER_SOURCE,
+            classifier.action,
+            applied,
+            excerpt(text, 0, end),
+        );
+        true
+    }
+
+    fn record(
+        &self,
+        (request_id, model): (&str, &str),
+        stage: ModerationStage,
+        source: &str,
+        action: ModerationAction,
+        applied: bool,
+        excerpt: String,
+    ) {
+        self.log.record(ModerationDecision {
+            request_id: request_id.to_owned(),
+            model: model.to_owned(),
+            stage,
+            source: source.to_owned(),
+            action,
+            applied,
+            excerpt,
+            recorded_at_secs: now_secs(),
+        });
+    }
+
+    /// Check a streamed text delta against the response rules, returning
+    /// the text to send instead.
+    fn moderate_delta(
+        &self,
+        ctx: &ResponseContext,
+        pending: &mut Pending,
+        content: String,
+    ) -> String {
+        let mut out = pending
+            .prefix
+            .take()
+            .map_or(String::new(), |prefix| format!("{prefix}\n\n"));
+        let seen = pending.text.len();
+        pending.text.push_str(&content);
+        out.push_str(&content);
+
+        for (i, rule) in pending.rules.iter().enumerate() {
+            if !rule.scope.responses() || pending.fired.contains(&i) {
+                continue;
+            }
+            let Some(excerpt) = rule.find(&pending.text, seen) else {
+                continue;
+            };
+            pending.fired.push(i);
+            let request = (ctx.request_id.as_str(), pending.model.as_str());
+            self.record(
+                request,
+                ModerationStage::Response,
+                &rule.name,
+                rule.action,
+                true,
+                excerpt,
+            );
+            match rule.action {
+                ModerationAction::Block => {
+                    pending.blocked = true;
+                    return format!(
+                        "\n\n{}",
+                        notice(ModerationStage::Response, &rule.name, rule.action)
+                    );
+                }
+                ModerationAction::Annotate => {
+                    out.push_str(&format!(
+                        "\n\n{}\n\n",
+                        notice(ModerationStage::Response, &rule.name, rule.action)
+                    ));
+                }
+                ModerationAction::Flag => {}
+            }
+        }
+        out
+    }
+}
+
+#[async_trait]
+impl ProxyMiddleware for ModerationMiddleware {
+    fn name(&self) -> &str {
+        "moderation"
+    }
+
+    async fn on_request(
+        &self,
+        body: &mut Value,
+        headers: &mut HeaderMap,
+    ) -> Result<(), MiddlewareRejection> {
+        let Some(config) = self.settings.get().await.moderation.clone() else {
+            return Ok(());
+        };
+        let ModerationConfig { rules, classifier } = config;
+        let request_id = headers
+            .get(REQUEST_ID_HEADER)
+            .and_then(|v| v.to_str().ok())
+            .unwrap_or_default()
+            .to_owned();
+        let model = body
+            .get("model")
+            .and_then(Value::as_str)
+            .unwrap_or_default()
+            .to_owned();
+        let streaming = body.get("stream").and_then(Value::as_bool) == Some(true);
+        let request = (request_id.as_str(), model.as_str());
+        let rules: Arc<[CompiledRule]> = rules.iter().filter_map(CompiledRule::compile).collect();
+
+        let mut notices = Vec::new();
+        if let Some(prompt) = latest_user_text(body) {
+            let mut hits: Vec<(&str, ModerationAction)> = Vec::new();
+            for rule in rules.iter().filter(|r| r.scope.prompts()) {
+                if let Some(excerpt) = rule.find(&prompt, 0) {
+                    let applied = rule.action != ModerationAction::Annotate || streaming;
+                    self.record(
+                        request,
+                        ModerationStage::Prompt,
+                        &rule.name,
+                        rule.action,
+                        applied,
+                        excerpt,
+                    );
+                    hits.push((&rule.name, rule.action));
+                }
+            }
+            if let Some(classifier) = classifier.as_ref().filter(|c| c.scope.prompts()) {
+                let applied = classifier.action != ModerationAction::Annotate || streaming;
+                if self
+                    .classify_and_record(
+                        classifier,
+                        &prompt,
+                        request,
+                        ModerationStage::Prompt,
+                        applied,
+                    )
+                    .await
+                {
+                    hits.push((CLASSIFIER_SOURCE, classifier.action));
+                }
+            }
+            if let Some((source, _)) = hits.iter().find(|(_, a)| *a == ModerationAction::Block) {
+                return Err(MiddlewareRejection::forbidden(format!(
+                    "prompt blocked by {source}"
+                )));
+            }
+            notices.extend(
+                hits.iter()
+                    .filter(|(_, a)| *a == ModerationAction::Annotate)
+                    .map(|(source, action)| notice(ModerationStage::Prompt, source, *action)),
+            );
+        }
+
+        let checks_responses = rules.iter().any(|r| r.scope.responses())
+            || classifier.as_ref().is_some_and(|c| c.scope.responses());
+        if request_id.is_empty() || (!checks_responses && notices.is_empty()) {
+            return Ok(());
+        }
+        let mut pending = self.pending();
+        pending.retain(|_, p| p.created.elapsed() < PENDING_TTL);
+        pending.insert(
+            request_id,
+            Pending {
+                created: Instant::now(),
+                model,
+                rules,
+                classifier: classifier.filter(|c| c.scope.responses()),
+                prefix: (!notices.is_empty()).then(|| notices.join("\n")),
+                text: String::new(),
+                fired: Vec::new(),
+                blocked: false,
+            },
+        );
+        Ok(())
+    }
+
+    fn on_stream_event(
+        &self,
+        ctx: &ResponseContext,
+        event: LlmStreamEvent,
+    ) -> Option<LlmStreamEvent> {
+        let mut guard = self.pending();
+        let Some(pending) = guard.get_mut(&ctx.request_id) else {
+            return Some(event);
+        };
+        match event {
+            LlmStreamEvent::TextDelta { .. }
+            | LlmStreamEvent::ReasoningDelta { .. }
+            | LlmStreamEvent::ToolCallDelta { .. }
+                if pending.blocked =>
+            {
+                None
+            }
+            LlmStreamEvent::TextDelta { content } => Some(LlmStreamEvent::TextDelta {
+                content: self.moderate_delta(ctx, pending, content),
+            }),
+            LlmStreamEvent::Done { .. } if pending.blocked => Some(LlmStreamEvent::Done {
+                finish_reason: CONTENT_FILTER_FINISH_REASON.to_owned(),
+            }),
+            other => Some(other),
+        }
+    }
+
+    async fn on_response(&self, ctx: &ResponseContext, response: &ResponseSummary) {
+        let Some(pending) = self.pending().remove(&ctx.request_id) else {
+            return;
+        };
+        let request = (ctx.request_id.as_str(), pending.model.as_str());
+        if !ctx.streaming {
+            for rule in pending.rules.iter().filter(|r| r.scope.responses()) {
+                if let Some(excerpt) = rule.find(&response.content, 0) {
+                    let applied = rule.action == ModerationAction::Flag;
+                    self.record(
+                        request,
+                        ModerationStage::Response,
+                        &rule.name,
+                        rule.action,
+                        applied,
+                        excerpt,
+                    );
+                }
+            }
+        }
+        if let Some(classifier) = &pending.classifier
+            && !pending.blocked
+        {
+            let applied = classifier.action == ModerationAction::Flag;
+            self.classify_and_record(
+                classifier,
+                &response.content,
+                request,
+                ModerationStage::Response,
+                applied,
+            )
+            .await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use gglib_core::ports::SettingsRepository;
+    use gglib_core::{RepositoryError, Settings};
+
+    /// Repository serving fixed moderation rules.
+    struct RulesRepo(Vec<ModerationRule>);
+
+    #[async_trait]
+    impl SettingsRepository for RulesRepo {
+        async fn load(&self) -> Result<Settings, RepositoryError> {
+            Ok(Settings {
+                moderation: Some(ModerationConfig {
+                    rules: self.0.clone(),
+                    classifier: None,
+                }),
+                ..Settings::default()
+            })
+        }
+
+        async fn save(&self, _settings: &Settings) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+    }
+
+    fn middleware(rules: Vec<ModerationRule>) -> (ModerationMiddleware, Arc<ModerationLog>) {
+        let log = Arc::new(ModerationLog::new());
+        let settings = Arc::new(SettingsCache::new(Arc::new(RulesRepo(rules))));
+        (
+            ModerationMiddleware::new(settings, Client::new(), Arc::clone(&log)),
+            log,
+        )
+    }
+
+    fn named(
+        name: &str,
+        pattern: &str,
+        scope: ModerationScope,
+        action: ModerationAction,
+    ) -> ModerationRule {
+        ModerationRule {
+            name: name.into(),
+            pattern: pattern.into(),
+            match_kind: ModerationMatch::Keyword,
+            scope,
+            action,
+        }
+    }
+
+    fn request(id: &str, prompt: &str) -> (Value, HeaderMap) {
+        let mut headers = HeaderMap::new();
+        headers.insert(REQUEST_ID_HEADER, id.parse().unwrap());
+        let body = json!({
+            "model": "m",
+            "stream": true,
+            "messages": [{ "role": "user", "content": prompt }],
+        });
+        (body, headers)
+    }
+
+    fn ctx(id: &str) -> ResponseContext {
+        ResponseContext {
+            request_id: id.into(),
+            model: "m".into(),
+            streaming: true,
+            session_id: None,
+        }
+    }
+
+    fn text(content: &str) -> LlmStreamEvent {
+        LlmStreamEvent::TextDelta {
+            content: content.into(),
+        }
+    }
+
+    #[tokio::test]
+    async fn a_blocked_prompt_is_rejected_and_recorded() {
+        let (moderation, log) = middleware(vec![named(
+            "no-passwords",
+            "password",
+            ModerationScope::Prompt,
+            ModerationAction::Block,
+        )]);
+        let (mut body, mut headers) = request("r1", "what is the admin PASSWORD?");
+
+        let rejection = moderation
+            .on_request(&mut body, &mut headers)
+            .await
+            .unwrap_err();
+        assert_eq!(rejection.status, axum::http::StatusCode::FORBIDDEN);
+        assert!(rejection.message.contains("no-passwords"));
+
+        let decisions = log.recent();
+        assert_eq!(decisions.len(), 1);
+        assert_eq!(decisions[0].request_id, "r1");
+        assert_eq!(decisions[0].stage, ModerationStage::Prompt);
+        assert!(decisions[0].applied);
+    }
+
+    #[tokio::test]
+    async fn a_blocked_reply_is_cut_short_with_content_filter() {
+        let (moderation, log) = middleware(vec![
+            named(
+                "tone",
+                "hello",
+                ModerationScope::Prompt,
+                ModerationAction::Annotate,
+            ),
+            named(
+                "leak",
+                "secret",
+                ModerationScope::Response,
+                ModerationAction::Block,
+            ),
+        ]);
+        let (mut body, mut headers) = request("r2", "hello there");
+        moderation
+            .on_request(&mut body, &mut headers)
+            .await
+            .unwrap();
+        let ctx = ctx("r2");
+
+        assert_eq!(
+            moderation.on_stream_event(&ctx, text("The sec")),
+            Some(text("[moderation: prompt flagged by tone]\n\nThe sec"))
+        );
+        assert_eq!(
+            moderation.on_stream_event(&ctx, text("ret is")),
+            Some(text("\n\n[moderation: response blocked by leak]"))
+        );
+        assert_eq!(moderation.on_stream_event(&ctx, text(" 42")), None);
+        assert_eq!(
+            moderation.on_stream_event(
+                &ctx,
+                LlmStreamEvent::Done {
+                    finish_reason: "stop".into()
+                }
+            ),
+            Some(LlmStreamEvent::Done {
+                finish_reason: CONTENT_FILTER_FINISH_REASON.into()
+            })
+        );
+
+        moderation
+            .on_response(&ctx, &ResponseSummary::default())
+            .await;
+        let sources: Vec<_> = log.recent().into_iter().map(|d| d.source).collect();
+        assert_eq!(sources, ["leak", "tone"]);
+        assert!(
+            moderation.pending().is_empty(),
+            "state is dropped on response"
+        );
+    }
+
+    fn rule(pattern: &str, match_kind: ModerationMatch) -> CompiledRule {
+        CompiledRule::compile(&ModerationRule {
+            name: "r".into(),
+            pattern: pattern.into(),
+            match_kind,
+            scope: ModerationScope::Both,
+            action: ModerationAction::Flag,
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn keywords_match_case_insensitively_across_deltas() {
+        let keyword = rule("Secret", ModerationMatch::Keyword);
+        let text = "the SEC";
+        assert_eq!(keyword.find(text, 0), None);
+        let text = "the SECRET plan";
+        assert_eq!(keyword.find(text, 7).as_deref(), Some("the SECRET plan"));
+        // A match wholly inside text already checked does not fire again.
+        assert_eq!(keyword.find(text, text.len()), None);
+    }
+
+    #[test]
+    fn regexes_rescan_the_whole_text() {
+        let regex = rule(r"api[_-]key\s*=\s*\w+", ModerationMatch::Regex);
+        let text = "set api_key = abc123 now";
+        assert!(regex.find(text, 18).is_some());
+        assert!(
+            rule("[A-Z]{3}", ModerationMatch::Regex)
+                .find("abc", 0)
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn excerpts_are_clipped_around_the_match() {
+        let text = format!("{}needle{}", "a".repeat(60), "b".repeat(60));
+        let clipped = excerpt(&text, 60, 66);
+        assert_eq!(
+            clipped,
+            format!("…{}needle{}…", "a".repeat(40), "b".repeat(40))
+        );
+    }
+
+    #[test]
+    fn latest_user_text_reads_string_and_part_content() {
+        let body = json!({ "messages": [
+            { "role": "user", "content": "first" },
+            { "role": "assistant", "content": "reply" },
+            { "role": "user", "content": [
+                { "type": "text", "text": "second" },
+                { "type": "image_url", "image_url": { "url": "data:" } },
+            ]},
+        ]});
+        assert_eq!(latest_user_text(&body).as_deref(), Some("second"));
+        assert_eq!(latest_user_text(&json!({ "messages": [] })), None);
+    }
+
+    #[test]
+    fn the_log_keeps_the_newest_decisions() {
+        let log = ModerationLog::new();
+        for i in 0..=MAX_DECISIONS {
+            log.record(ModerationDecision {
+                request_id: i.to_string(),
+                model: "m".into(),
+                stage: ModerationStage::Prompt,
+                source: "r".into(),
+                action: ModerationAction::Flag,
+                applied: true,
+                excerpt: String::new(),
+                recorded_at_secs: 0,
+            });
+        }
+        let recent = log.recent();
+        assert_eq!(recent.len(), MAX_DECISIONS);
+        assert_eq!(recent[0].request_id, MAX_DECISIONS.to_string());
+        assert_eq!(recent.last().unwrap().request_id, "1");
+    }
+}
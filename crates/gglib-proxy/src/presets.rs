@@ -0,0 +1,236 @@
+//! Per-request generation presets.
+//!
+//! A client selects a stored [`Preset`] with the `x-gglib-preset` header or a
+//! `preset` field in the request body; the header wins when both are present.
+//! The preset then shapes the request before it reaches the routing and
+//! sampling pipeline:
+//!
+//! - its `default_model` is used when the request's `model` is empty;
+//! - its system prompt is placed ahead of the client's own system message;
+//! - its tool list, when non-empty, filters the request's `tools`;
+//! - its sampling config joins the profile layer, below any `{model}:{profile}`
+//!   the client also named (see [`stack_sampling`]).
+//!
+//! An unknown preset name fails the request with 404 rather than forwarding
+//! it unshaped, for the same reason an unknown profile does: a client that
+//! asked for curated behaviour should not silently get the raw model.
+
+use axum::body::Bytes;
+use axum::http::HeaderMap;
+use gglib_core::domain::InferenceConfig;
+use gglib_core::domain::preset::Preset;
+use serde_json::{Map, Value};
+use tracing::warn;
+
+/// Header a client names a preset with.
+pub const PRESET_HEADER: &str = "x-gglib-preset";
+
+/// Body field a client names a preset with. Removed before forwarding.
+const PRESET_FIELD: &str = "preset";
+
+/// The preset a request asked for, from the header or the body field.
+pub(crate) fn requested_preset(headers: &HeaderMap, body_field: Option<&str>) -> Option<String> {
+    headers
+        .get(PRESET_HEADER)
+        .and_then(|v| v.to_str().ok())
+        .or(body_field)
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .map(str::to_owned)
+}
+
+/// Layer a preset's sampling under the request's profile.
+///
+/// A profile named in the model id is the more specific choice, so it sits
+/// above the preset; the preset fills whatever the profile leaves unset.
+pub(crate) fn stack_sampling(
+    profile: Option<InferenceConfig>,
+    preset: Option<&Preset>,
+) -> Option<InferenceConfig> {
+    let Some(preset) = preset.filter(|p| p.sampling != InferenceConfig::default()) else {
+        return profile;
+    };
+    Some(profile.map_or_else(
+        || preset.sampling.clone(),
+        |profile| profile.stacked_over(&preset.sampling),
+    ))
+}
+
+/// Rewrite a request body for `preset`.
+///
+/// A body that is not a JSON object is returned unchanged; the upstream will
+/// report it as it would without a preset.
+pub(crate) fn apply_preset(body: Bytes, preset: &Preset) -> Bytes {
+    let Ok(Value::Object(mut request)) = serde_json::from_slice::<Value>(&body) else {
+        return body;
+    };
+    request.remove(PRESET_FIELD);
+    if let Some(prompt) = &preset.system_prompt {
+        prepend_system_prompt(&mut request, prompt);
+    }
+    if !preset.tools.is_empty() {
+        filter_tools(&mut request, preset);
+    }
+    match serde_json::to_vec(&Value::Object(request)) {
+        Ok(v) => Bytes::from(v),
+        Err(e) => {
+            warn!(error = %e, "failed to re-serialize request body after applying preset");
+            body
+        }
+    }
+}
+
+/// Put `prompt` at the top of the system message, creating one if needed.
+///
+/// Joined into an existing string system message rather than added as a
+/// second one: several chat templates reject a system message anywhere but
+/// first.
+fn prepend_system_prompt(request: &mut Map<String, Value>, prompt: &str) {
+    let Some(messages) = request.get_mut("messages").and_then(Value::as_array_mut) else {
+        return;
+    };
+    let existing = messages
+        .first_mut()
+        .filter(|m| m.get("role").and_then(Value::as_str) == Some("system"))
+        .and_then(|m| m.get_mut("content"));
+    if let Some(Value::String(content)) = existing {
+        *content = format!("{prompt}\n\n{content}");
+    } else {
+        messages.insert(
+            0,
+            serde_json::json!({ "role": "system", "content": prompt }),
+        );
+    }
+}
+
+/// Drop the request's tools the preset does not allow.
+///
+/// When none survive, `tools` and `tool_choice` are removed together, since a
+/// `tool_choice` naming a dropped function would be rejected upstream.
+fn filter_tools(request: &mut Map<String, Value>, preset: &Preset) {
+    let Some(tools) = request.get_mut("tools").and_then(Value::as_array_mut) else {
+        return;
+    };
+    tools.retain(|tool| {
+        tool.pointer("/function/name")
+            .and_then(Value::as_str)
+            .is_some_and(|name| preset.allows_tool(name))
+    });
+    if tools.is_empty() {
+        request.remove("tools");
+        request.remove("tool_choice");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn preset() -> Preset {
+        Preset {
+            id: 1,
+            name: "pirate".to_owned(),
+            description: None,
+            system_prompt: Some("Talk like a pirate.".to_owned()),
+            sampling: InferenceConfig::default(),
+            tools: vec![],
+            mcp_server_ids: vec![],
+            default_model: None,
+            created_at: String::new(),
+            updated_at: String::new(),
+        }
+    }
+
+    fn apply(body: &Value, preset: &Preset) -> Value {
+        let out = apply_preset(Bytes::from(body.to_string()), preset);
+        serde_json::from_slice(&out).unwrap()
+    }
+
+    fn tool(name: &str) -> Value {
+        json!({ "type": "function", "function": { "name": name, "parameters": {} } })
+    }
+
+    #[test]
+    fn header_wins_over_body_field() {
+        let mut headers = HeaderMap::new();
+        assert_eq!(
+            requested_preset(&headers, Some("body")).as_deref(),
+            Some("body")
+        );
+        assert_eq!(requested_preset(&headers, Some("  ")), None);
+        headers.insert(PRESET_HEADER, "header".parse().unwrap());
+        assert_eq!(
+            requested_preset(&headers, Some("body")).as_deref(),
+            Some("header")
+        );
+    }
+
+    #[test]
+    fn system_prompt_joins_or_creates_the_system_message() {
+        let out = apply(
+            &json!({
+                "model": "m",
+                "preset": "pirate",
+                "messages": [{ "role": "system", "content": "Be brief." }, { "role": "user", "content": "hi" }]
+            }),
+            &preset(),
+        );
+        assert!(
+            out.get("preset").is_none(),
+            "selector must not reach llama-server"
+        );
+        assert_eq!(
+            out["messages"][0]["content"],
+            "Talk like a pirate.\n\nBe brief."
+        );
+        assert_eq!(out["messages"].as_array().unwrap().len(), 2);
+
+        let out = apply(
+            &json!({ "model": "m", "messages": [{ "role": "user", "content": "hi" }] }),
+            &preset(),
+        );
+        assert_eq!(
+            out["messages"][0],
+            json!({ "role": "system", "content": "Talk like a pirate." })
+        );
+        assert_eq!(out["messages"][1]["role"], "user");
+    }
+
+    #[test]
+    fn tools_are_filtered_to_the_preset_list() {
+        let mut p = preset();
+        p.tools = vec!["search".to_owned()];
+        let out = apply(
+            &json!({ "model": "m", "messages": [], "tools": [tool("search"), tool("shell")] }),
+            &p,
+        );
+        assert_eq!(out["tools"], json!([tool("search")]));
+
+        let out = apply(
+            &json!({ "model": "m", "messages": [], "tools": [tool("shell")], "tool_choice": "required" }),
+            &p,
+        );
+        assert!(out.get("tools").is_none());
+        assert!(out.get("tool_choice").is_none());
+    }
+
+    #[test]
+    fn profile_sampling_sits_above_the_preset() {
+        let mut p = preset();
+        assert_eq!(stack_sampling(None, Some(&p)), None);
+
+        p.sampling = InferenceConfig {
+            temperature: Some(0.9),
+            top_k: Some(20),
+            ..Default::default()
+        };
+        let profile = InferenceConfig {
+            top_k: Some(5),
+            ..Default::default()
+        };
+        let stacked = stack_sampling(Some(profile), Some(&p)).unwrap();
+        assert_eq!(stacked.top_k, Some(5));
+        assert_eq!(stacked.temperature, Some(0.9));
+    }
+}
@@ -0,0 +1,357 @@
+//! Priority classes for chat completions: interactive before background.
+//!
+//! A request is [`Priority::Background`] when it carries one of the
+//! background API keys (see [`crate::access`]) or asks for it with
+//! `x-gglib-priority: background`; everything else is interactive. A
+//! background key cannot be talked up to interactive by the header.
+//!
+//! [`GenerationQueue`] admits requests per model against the number of
+//! llama-server slots:
+//!
+//! - **Interactive** requests are admitted at once, so they reach
+//!   llama-server's own queue (and the streaming keepalive path) exactly as
+//!   before.
+//! - **Background** requests wait here until a slot is free, so whenever
+//!   both kinds are waiting the interactive one goes first.
+//!
+//! When the `proxy_preempt_background` setting is on and an interactive
+//! request arrives with every slot taken, the most recently started
+//! background stream is cut short: it ends with an error event (`code:
+//! "preempted"`) and `[DONE]`, and dropping it makes llama-server abandon
+//! the generation. Non-streaming requests are never preempted.
+
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+
+use axum::body::{Body, Bytes};
+use axum::http::HeaderMap;
+use axum::response::Response;
+use futures_util::StreamExt;
+use gglib_core::sse::DONE_SENTINEL;
+use tokio::sync::Notify;
+use tokio_util::sync::CancellationToken;
+use tracing::info;
+
+use crate::access::{AccessPolicy, bearer_token};
+use crate::slots::SlotsPollResult;
+
+/// Header a client sets its priority class with.
+pub const PRIORITY_HEADER: &str = "x-gglib-priority";
+
+/// How urgently a request should be served.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Priority {
+    /// A person is waiting on the answer.
+    #[default]
+    Interactive,
+    /// Batch work that can wait for free capacity.
+    Background,
+}
+
+impl FromStr for Priority {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "interactive" => Ok(Self::Interactive),
+            "background" => Ok(Self::Background),
+            other => Err(format!(
+                "unknown priority '{other}' (expected interactive or background)"
+            )),
+        }
+    }
+}
+
+/// The priority class of a request: background keys first, then the
+/// [`PRIORITY_HEADER`], then interactive.
+///
+/// # Errors
+///
+/// Returns the parse error for an unrecognised header value.
+pub(crate) fn request_priority(
+    headers: &HeaderMap,
+    access: &AccessPolicy,
+) -> Result<Priority, String> {
+    if bearer_token(headers).is_some_and(|key| access.is_background_key(key)) {
+        return Ok(Priority::Background);
+    }
+    headers
+        .get(PRIORITY_HEADER)
+        .and_then(|v| v.to_str().ok())
+        .map_or(Ok(Priority::Interactive), str::parse)
+}
+
+/// Generations llama-server can run at once: its slot count, as last seen
+/// by the `/slots` poller, or 1 until the poller has seen one.
+pub(crate) fn slot_capacity(slots: &SlotsPollResult) -> usize {
+    match slots {
+        SlotsPollResult::Available(slots) if !slots.is_empty() => slots.len(),
+        _ => 1,
+    }
+}
+
+#[derive(Debug)]
+struct Running {
+    id: u64,
+    /// Cancelled to preempt; `None` for requests that cannot be preempted.
+    preempt: Option<CancellationToken>,
+}
+
+/// Per-model admission of generations, in priority order.
+///
+/// Uses `std::sync::Mutex` like [`crate::connections`]: the critical
+/// sections never await.
+#[derive(Debug, Default)]
+pub(crate) struct GenerationQueue {
+    running: Mutex<HashMap<u32, Vec<Running>>>,
+    freed: Notify,
+    next_id: AtomicU64,
+}
+
+impl GenerationQueue {
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    /// Wait for this request's turn on `model_id`, which has `capacity`
+    /// slots. Background requests wait for a free slot; interactive ones
+    /// never wait, and with `preempt` set they cut the newest background
+    /// stream short when every slot is taken.
+    ///
+    /// `streaming` requests can be preempted; others run to completion.
+    pub(crate) async fn admit(
+        self: &Arc<Self>,
+        model_id: u32,
+        priority: Priority,
+        capacity: usize,
+        streaming: bool,
+        preempt: bool,
+    ) -> Ticket {
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed);
+        let token = (streaming && priority == Priority::Background).then(CancellationToken::new);
+        let entry = || Running {
+            id,
+            preempt: token.clone(),
+        };
+
+        loop {
+            // Registered before checking, so a slot freed in between still
+            // wakes this waiter.
+            let freed = self.freed.notified();
+            {
+                let mut running = self.running.lock().unwrap_or_else(|e| e.into_inner());
+                let lane = running.entry(model_id).or_default();
+                if priority == Priority::Interactive {
+                    lane.push(entry());
+                    if preempt && lane.len() > capacity {
+                        preempt_newest_background(lane);
+                    }
+                    break;
+                }
+                if lane.len() < capacity {
+                    lane.push(entry());
+                    break;
+                }
+            }
+            freed.await;
+        }
+
+        Ticket {
+            queue: Arc::clone(self),
+            model_id,
+            id,
+            preempted: token,
+        }
+    }
+
+    /// Requests admitted for `model_id` and not yet finished.
+    #[cfg(test)]
+    fn admitted(&self, model_id: u32) -> usize {
+        let running = self.running.lock().unwrap_or_else(|e| e.into_inner());
+        running.get(&model_id).map_or(0, Vec::len)
+    }
+
+    fn release(&self, model_id: u32, id: u64) {
+        let mut running = self.running.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(lane) = running.get_mut(&model_id) {
+            lane.retain(|r| r.id != id);
+            if lane.is_empty() {
+                running.remove(&model_id);
+            }
+        }
+        drop(running);
+        self.freed.notify_waiters();
+    }
+}
+
+fn preempt_newest_background(lane: &[Running]) {
+    let victim = lane
+        .iter()
+        .rev()
+        .filter_map(|r| r.preempt.as_ref())
+        .find(|token| !token.is_cancelled());
+    if let Some(token) = victim {
+        info!("Preempting a background stream for an interactive request");
+        token.cancel();
+    }
+}
+
+/// A request's place in the [`GenerationQueue`]; frees it on drop.
+#[derive(Debug)]
+pub(crate) struct Ticket {
+    queue: Arc<GenerationQueue>,
+    model_id: u32,
+    id: u64,
+    preempted: Option<CancellationToken>,
+}
+
+impl Drop for Ticket {
+    fn drop(&mut self) {
+        self.queue.release(self.model_id, self.id);
+    }
+}
+
+/// Keep `ticket` until `response`'s body is finished, ending the body early
+/// with a `preempted` error event if the ticket is preempted.
+pub(crate) fn hold_until_done(response: Response, ticket: Ticket) -> Response {
+    let Some(preempted) = ticket.preempted.clone() else {
+        let (parts, body) = response.into_parts();
+        let body = body.into_data_stream().map(move |chunk| {
+            let _held = &ticket;
+            chunk
+        });
+        return Response::from_parts(parts, Body::from_stream(body));
+    };
+
+    let (parts, body) = response.into_parts();
+    let stream = async_stream::stream! {
+        let _ticket = ticket;
+        let mut body = body.into_data_stream();
+        loop {
+            tokio::select! {
+                biased;
+                () = preempted.cancelled() => {
+                    yield Ok(Bytes::from(preempted_event()));
+                    yield Ok(Bytes::from_static(DONE_SENTINEL.as_bytes()));
+                    break;
+                }
+                chunk = body.next() => match chunk {
+                    Some(chunk) => yield chunk,
+                    None => break,
+                },
+            }
+        }
+    };
+    Response::from_parts(parts, Body::from_stream(stream))
+}
+
+fn preempted_event() -> String {
+    let payload = serde_json::json!({
+        "error": {
+            "message": "Generation preempted by an interactive request; retry later",
+            "type": "server_error",
+            "code": "preempted",
+        }
+    });
+    format!("data: {payload}\n\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use axum::http::HeaderValue;
+    use http_body_util::BodyExt;
+
+    use super::*;
+
+    #[test]
+    fn background_keys_outrank_the_header() {
+        let access = AccessPolicy::default().with_background_keys(vec!["batch".into()]);
+        let mut headers = HeaderMap::new();
+        assert_eq!(
+            request_priority(&headers, &access),
+            Ok(Priority::Interactive)
+        );
+
+        headers.insert(PRIORITY_HEADER, HeaderValue::from_static("Background"));
+        assert_eq!(
+            request_priority(&headers, &access),
+            Ok(Priority::Background)
+        );
+
+        headers.insert(PRIORITY_HEADER, HeaderValue::from_static("interactive"));
+        headers.insert("authorization", HeaderValue::from_static("Bearer batch"));
+        assert_eq!(
+            request_priority(&headers, &access),
+            Ok(Priority::Background)
+        );
+
+        headers.remove("authorization");
+        headers.insert(PRIORITY_HEADER, HeaderValue::from_static("urgent"));
+        assert!(request_priority(&headers, &access).is_err());
+    }
+
+    #[tokio::test]
+    async fn background_waits_for_a_free_slot() {
+        let queue = Arc::new(GenerationQueue::new());
+        let interactive = queue.admit(1, Priority::Interactive, 1, true, false).await;
+        // Interactive requests never wait, even past capacity.
+        let second = queue.admit(1, Priority::Interactive, 1, true, false).await;
+        assert_eq!(queue.admitted(1), 2);
+
+        let waiting = tokio::spawn({
+            let queue = Arc::clone(&queue);
+            async move { queue.admit(1, Priority::Background, 1, true, false).await }
+        });
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        assert!(!waiting.is_finished());
+
+        // Another model's slots are separate.
+        let _other = queue.admit(2, Priority::Background, 1, true, false).await;
+
+        drop(interactive);
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        assert!(!waiting.is_finished());
+        drop(second);
+        let background = tokio::time::timeout(Duration::from_secs(1), waiting)
+            .await
+            .expect("admitted once the slot is free")
+            .unwrap();
+        assert_eq!(queue.admitted(1), 1);
+        drop(background);
+        assert_eq!(queue.admitted(1), 0);
+    }
+
+    #[tokio::test]
+    async fn interactive_preempts_background_stream_when_enabled() {
+        let queue = Arc::new(GenerationQueue::new());
+        let background = queue.admit(1, Priority::Background, 1, true, true).await;
+        let token = background.preempted.clone().unwrap();
+
+        let _no_preempt = queue.admit(1, Priority::Interactive, 1, true, false).await;
+        assert!(!token.is_cancelled());
+        drop(_no_preempt);
+
+        let _interactive = queue.admit(1, Priority::Interactive, 1, true, true).await;
+        assert!(token.is_cancelled());
+
+        let upstream = Response::new(Body::from_stream(futures_util::stream::pending::<
+            Result<Bytes, std::io::Error>,
+        >()));
+        let body = hold_until_done(upstream, background)
+            .into_body()
+            .collect()
+            .await
+            .unwrap()
+            .to_bytes();
+        let body = String::from_utf8(body.to_vec()).unwrap();
+        assert!(body.contains("\"code\":\"preempted\""), "{body}");
+        assert!(body.ends_with(DONE_SENTINEL), "{body}");
+        // Only the interactive request is left.
+        assert_eq!(queue.admitted(1), 1);
+    }
+}
@@ -0,0 +1,263 @@
+//! Reasoning-content separation for streaming responses.
+//!
+//! llama-server moves a reasoning model's chain of thought into
+//! `reasoning_content` only when it recognises the model's chat template;
+//! otherwise the `<think>…</think>` block arrives inline in `content`, and
+//! the client renders the model's scratchpad as part of its answer. For
+//! models with [`ModelCapabilities::SUPPORTS_REASONING`] the proxy splits
+//! those blocks out itself, so every reasoning model streams the
+//! DeepSeek-style shape UIs render as a collapsible thought section:
+//!
+//! ```text
+//!  content: "<think>Let me"   →  reasoning_content: "Let me"
+//!  content: " see.</think>42" →  reasoning_content: " see."
+//!                                content: "42"
+//! ```
+//!
+//! Tags split across chunks are held back until they are complete, so a
+//! stray `<thi` never reaches the client. A block the chat template opens in
+//! the prompt (only `</think>` appears in the output) is left to
+//! llama-server's own reasoning parser.
+//!
+//! The `proxy_strip_reasoning` setting decides what happens to reasoning
+//! afterwards, whichever side split it: passed through as `reasoning_content`
+//! (the default) or dropped. A turn that produced *only* reasoning is still
+//! promoted into `content` either way, see `forward::stream_response_to_channel`.
+
+use gglib_core::domain::ModelCapabilities;
+use gglib_core::{LlmStreamEvent, Settings};
+
+const OPEN: &str = "<think>";
+const CLOSE: &str = "</think>";
+
+/// What the proxy does with a response's reasoning.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ReasoningOutput {
+    /// Forward it to the client as `reasoning_content` deltas.
+    #[default]
+    PassThrough,
+    /// Drop it; the client only sees the answer.
+    Strip,
+}
+
+impl ReasoningOutput {
+    /// The output mode configured in `settings`.
+    #[must_use]
+    pub fn from_settings(settings: &Settings) -> Self {
+        if settings.proxy_strip_reasoning == Some(true) {
+            Self::Strip
+        } else {
+            Self::PassThrough
+        }
+    }
+}
+
+/// How one streaming response's reasoning is handled.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ReasoningPolicy {
+    /// Split inline `<think>` blocks out of `content`.
+    pub split_inline: bool,
+    /// Where the reasoning goes once it is separated.
+    pub output: ReasoningOutput,
+}
+
+impl ReasoningPolicy {
+    /// The policy for a model with `capabilities`: inline blocks are only
+    /// split for reasoning models, so `<think>` in another model's answer is
+    /// left alone.
+    #[must_use]
+    pub const fn new(capabilities: ModelCapabilities, output: ReasoningOutput) -> Self {
+        Self {
+            split_inline: capabilities.supports_reasoning(),
+            output,
+        }
+    }
+
+    /// A splitter for one response, if this policy splits at all.
+    pub(crate) fn splitter(self) -> Option<ThinkSplitter> {
+        self.split_inline.then(ThinkSplitter::default)
+    }
+}
+
+/// Moves inline `<think>` blocks from text deltas into reasoning deltas.
+///
+/// Runs on the decoded upstream events, before normalization (which would
+/// otherwise strip the tags and leave the reasoning in `content`).
+#[derive(Debug, Default)]
+pub(crate) struct ThinkSplitter {
+    /// Inside a `<think>` block.
+    thinking: bool,
+    /// A block just closed and no answer text has followed yet; the
+    /// whitespace separating the two is dropped.
+    after_block: bool,
+    /// Trailing bytes that may be the start of the next tag.
+    held: String,
+}
+
+impl ThinkSplitter {
+    /// Split one upstream event. Text deltas may become any mix of text and
+    /// reasoning deltas; `Done` first flushes held-back text. Everything else
+    /// passes through.
+    pub(crate) fn process(&mut self, event: LlmStreamEvent) -> Vec<LlmStreamEvent> {
+        match event {
+            LlmStreamEvent::TextDelta { content } => self.split(&content),
+            LlmStreamEvent::Done { .. } => {
+                let mut out: Vec<_> = self.finish().into_iter().collect();
+                out.push(event);
+                out
+            }
+            other => vec![other],
+        }
+    }
+
+    /// Flush text held back as a possible partial tag, for a stream that
+    /// ends without `Done`.
+    pub(crate) fn finish(&mut self) -> Option<LlmStreamEvent> {
+        let held = std::mem::take(&mut self.held);
+        let mut out = Vec::with_capacity(1);
+        self.emit(&held, &mut out);
+        out.pop()
+    }
+
+    fn split(&mut self, text: &str) -> Vec<LlmStreamEvent> {
+        let mut buf = std::mem::take(&mut self.held);
+        buf.push_str(text);
+        let mut out = Vec::new();
+        let mut rest = buf.as_str();
+        loop {
+            let tag = if self.thinking { CLOSE } else { OPEN };
+            if let Some(at) = rest.find(tag) {
+                self.emit(&rest[..at], &mut out);
+                rest = &rest[at + tag.len()..];
+                self.after_block = self.thinking;
+                self.thinking = !self.thinking;
+            } else {
+                let keep = partial_tag_len(rest, tag);
+                self.emit(&rest[..rest.len() - keep], &mut out);
+                self.held = rest[rest.len() - keep..].to_owned();
+                return out;
+            }
+        }
+    }
+
+    fn emit(&mut self, text: &str, out: &mut Vec<LlmStreamEvent>) {
+        if self.thinking {
+            if !text.is_empty() {
+                out.push(LlmStreamEvent::ReasoningDelta {
+                    content: text.to_owned(),
+                });
+            }
+            return;
+        }
+        let text = if self.after_block {
+            text.trim_start()
+        } else {
+            text
+        };
+        if !text.is_empty() {
+            self.after_block = false;
+            out.push(LlmStreamEvent::TextDelta {
+                content: text.to_owned(),
+            });
+        }
+    }
+}
+
+/// Length of the longest suffix of `text` that is a proper prefix of `tag`.
+fn partial_tag_len(text: &str, tag: &str) -> usize {
+    (1..tag.len())
+        .rev()
+        .find(|&n| text.ends_with(&tag[..n]))
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn text(s: &str) -> LlmStreamEvent {
+        LlmStreamEvent::TextDelta {
+            content: s.to_owned(),
+        }
+    }
+
+    fn reasoning(s: &str) -> LlmStreamEvent {
+        LlmStreamEvent::ReasoningDelta {
+            content: s.to_owned(),
+        }
+    }
+
+    /// Run `chunks` through a splitter and collect what comes out.
+    fn run(chunks: &[&str]) -> Vec<LlmStreamEvent> {
+        let mut splitter = ThinkSplitter::default();
+        let mut out: Vec<_> = chunks
+            .iter()
+            .flat_map(|c| splitter.process(text(c)))
+            .collect();
+        out.extend(splitter.finish());
+        out
+    }
+
+    #[test]
+    fn splits_a_think_block_out_of_content() {
+        assert_eq!(
+            run(&["<think>Let me see.</think>\n\n42"]),
+            [reasoning("Let me see."), text("42")]
+        );
+    }
+
+    #[test]
+    fn holds_back_tags_split_across_chunks() {
+        assert_eq!(
+            run(&["<thi", "nk>Let me", " see.</th", "ink>", "42"]),
+            [reasoning("Let me"), reasoning(" see."), text("42")]
+        );
+    }
+
+    #[test]
+    fn text_without_tags_passes_through() {
+        assert_eq!(
+            run(&["a < b", " and c <t"]),
+            [text("a < b"), text(" and c "), text("<t")]
+        );
+    }
+
+    #[test]
+    fn unclosed_block_stays_reasoning() {
+        assert_eq!(
+            run(&["<think>still going</thi"]),
+            [reasoning("still going"), reasoning("</thi")]
+        );
+    }
+
+    #[test]
+    fn done_flushes_held_text_first() {
+        let mut splitter = ThinkSplitter::default();
+        assert_eq!(splitter.process(text("x <")), [text("x ")]);
+        let done = LlmStreamEvent::Done {
+            finish_reason: "stop".to_owned(),
+        };
+        assert_eq!(splitter.process(done.clone()), [text("<"), done]);
+    }
+
+    #[test]
+    fn only_reasoning_models_split() {
+        let strip = Settings {
+            proxy_strip_reasoning: Some(true),
+            ..Settings::default()
+        };
+        let policy = ReasoningPolicy::new(
+            ModelCapabilities::SUPPORTS_REASONING,
+            ReasoningOutput::from_settings(&strip),
+        );
+        assert!(policy.splitter().is_some());
+        assert_eq!(policy.output, ReasoningOutput::Strip);
+
+        let policy = ReasoningPolicy::new(
+            ModelCapabilities::SUPPORTS_TOOL_CALLS,
+            ReasoningOutput::from_settings(&Settings::default()),
+        );
+        assert!(policy.splitter().is_none());
+        assert_eq!(policy.output, ReasoningOutput::PassThrough);
+    }
+}
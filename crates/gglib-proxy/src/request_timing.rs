@@ -0,0 +1,290 @@
+//! Per-request latency and throughput measurement.
+//!
+//! A [`RequestTimer`] is started when the proxy begins handling a chat
+//! completion and finished once the upstream response has been fully
+//! drained. The resulting [`RequestTiming`] goes to the runtime's
+//! [`ServerStatsSink`], which aggregates it into the serving session's
+//! [`gglib_core::domain::ServerStats`].
+//!
+//! Measurement never changes what the client receives: the timer only reads
+//! instants the forwarding path already passes through, and a proxy started
+//! without a sink skips recording entirely.
+//!
+//! When the `energy` setting names a draw, the timer also carries that
+//! [`EnergyRate`] so the stream's usage chunk can report the request's
+//! estimated energy and cost. Likewise it carries the request's deadline
+//! when the `proxy_limits` setting bounds run time (see [`crate::limits`]).
+
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use gglib_core::domain::{EnergyRate, RequestTiming};
+use gglib_core::ports::ServerStatsSink;
+
+/// Times one forwarded request against the server that handled it.
+pub struct RequestTimer {
+    sink: Option<Arc<dyn ServerStatsSink>>,
+    model_id: u32,
+    started: Instant,
+    energy: Option<EnergyRate>,
+    time_limit: Option<Duration>,
+}
+
+impl RequestTimer {
+    /// Start timing a request that will be served by `model_id`'s server.
+    pub fn start(sink: Option<Arc<dyn ServerStatsSink>>, model_id: u32) -> Self {
+        Self {
+            sink,
+            model_id,
+            started: Instant::now(),
+            energy: None,
+            time_limit: None,
+        }
+    }
+
+    /// Estimate the request's energy at `rate` in the usage extension.
+    #[must_use]
+    pub fn with_energy(mut self, rate: Option<EnergyRate>) -> Self {
+        self.energy = rate;
+        self
+    }
+
+    /// The rate set by [`Self::with_energy`], if any.
+    pub(crate) const fn energy(&self) -> Option<&EnergyRate> {
+        self.energy.as_ref()
+    }
+
+    /// Let the request run for at most `limit` from when timing started.
+    #[must_use]
+    pub fn with_time_limit(mut self, limit: Option<Duration>) -> Self {
+        self.time_limit = limit;
+        self
+    }
+
+    /// The time limit set by [`Self::with_time_limit`], if any.
+    pub(crate) const fn time_limit(&self) -> Option<Duration> {
+        self.time_limit
+    }
+
+    /// When the time limit runs out, if one is set.
+    pub(crate) fn deadline(&self) -> Option<Instant> {
+        self.time_limit.map(|limit| self.started + limit)
+    }
+
+    /// Record a streamed request.
+    ///
+    /// `headers_at` is when the upstream sent response headers (i.e. a slot
+    /// was assigned) and `first_token_at` when the first generated token
+    /// passed through; the generation window runs from that token to now.
+    pub(crate) fn finish_streaming(
+        self,
+        headers_at: Instant,
+        first_token_at: Option<Instant>,
+        prompt_tokens: Option<u32>,
+        completion_tokens: Option<u32>,
+    ) {
+        let timing = RequestTiming {
+            prompt_tokens,
+            ..self.streaming_timing(headers_at, first_token_at, completion_tokens)
+        };
+        self.record(timing);
+    }
+
+    /// The figures [`Self::finish_streaming`] would record if the stream
+    /// ended now, without recording them.
+    pub(crate) fn streaming_timing(
+        &self,
+        headers_at: Instant,
+        first_token_at: Option<Instant>,
+        completion_tokens: Option<u32>,
+    ) -> RequestTiming {
+        let now = Instant::now();
+        let started = self.started;
+        RequestTiming {
+            queue_wait: Some(headers_at.saturating_duration_since(started)),
+            ttft: first_token_at.map(|t| t.saturating_duration_since(started)),
+            generation: first_token_at.map(|t| now.saturating_duration_since(t)),
+            prompt_tokens: None,
+            completion_tokens,
+        }
+    }
+
+    /// Record a non-streaming request. Only the round trip is observable, so
+    /// queue wait and time-to-first-token stay unmeasured.
+    pub(crate) fn finish_buffered(
+        self,
+        prompt_tokens: Option<u32>,
+        completion_tokens: Option<u32>,
+    ) {
+        let generation = self.started.elapsed();
+        self.record(RequestTiming {
+            generation: Some(generation),
+            prompt_tokens,
+            completion_tokens,
+            ..RequestTiming::default()
+        });
+    }
+
+    fn record(self, timing: RequestTiming) {
+        if let Some(sink) = self.sink {
+            sink.record(self.model_id, timing);
+        }
+    }
+}
+
+/// The figures sent to the client under
+/// [`EXTENSION_KEY`](gglib_core::sse::EXTENSION_KEY) on the terminal usage
+/// chunk of a stream: `queue_ms`, `ttft_ms`, `generation_ms` and
+/// `tokens_per_second`, plus `energy_wh` and `cost` when an energy `rate`
+/// is configured.
+///
+/// A figure that was not measured is omitted rather than sent as zero, for
+/// the same reason [`RequestTiming`] keeps it `None`.
+pub(crate) fn usage_extension(
+    timing: &RequestTiming,
+    rate: Option<&EnergyRate>,
+) -> serde_json::Value {
+    let millis = |d: Option<std::time::Duration>| {
+        d.map(|d| serde_json::Value::from(u64::try_from(d.as_millis()).unwrap_or(u64::MAX)))
+    };
+    let figures = [
+        ("queue_ms", millis(timing.queue_wait)),
+        ("ttft_ms", millis(timing.ttft)),
+        ("generation_ms", millis(timing.generation)),
+        (
+            "tokens_per_second",
+            timing
+                .tokens_per_sec()
+                .map(|tps| serde_json::Value::from((tps * 100.0).round() / 100.0)),
+        ),
+    ];
+    let energy = rate
+        .zip(timing.busy_time())
+        .map(|(rate, busy)| rate.estimate(busy.as_secs_f64()));
+    let energy = [
+        (
+            "energy_wh",
+            energy.map(|e| serde_json::Value::from(round_to(e.watt_hours, 6))),
+        ),
+        (
+            "cost",
+            energy
+                .and_then(|e| e.cost)
+                .map(|cost| serde_json::Value::from(round_to(cost, 8))),
+        ),
+    ];
+    serde_json::Value::Object(
+        figures
+            .into_iter()
+            .chain(energy)
+            .filter_map(|(key, value)| Some((key.to_owned(), value?)))
+            .collect(),
+    )
+}
+
+/// `value` rounded to `digits` decimal places.
+fn round_to(value: f64, digits: i32) -> f64 {
+    let scale = 10f64.powi(digits);
+    (value * scale).round() / scale
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+    use std::time::Duration;
+
+    #[derive(Default)]
+    struct Capture(Mutex<Vec<(u32, RequestTiming)>>);
+
+    impl ServerStatsSink for Capture {
+        fn record(&self, model_id: u32, timing: RequestTiming) {
+            self.0.lock().unwrap().push((model_id, timing));
+        }
+    }
+
+    #[test]
+    fn streaming_records_queue_wait_ttft_and_generation() {
+        let sink = Arc::new(Capture::default());
+        let timer = RequestTimer::start(Some(sink.clone()), 4);
+        let headers_at = timer.started + Duration::from_millis(5);
+        let first_token_at = timer.started + Duration::from_millis(20);
+        timer.finish_streaming(headers_at, Some(first_token_at), Some(30), Some(8));
+
+        let recorded = sink.0.lock().unwrap();
+        let (model_id, timing) = recorded[0];
+        assert_eq!(model_id, 4);
+        assert_eq!(timing.queue_wait, Some(Duration::from_millis(5)));
+        assert_eq!(timing.ttft, Some(Duration::from_millis(20)));
+        assert!(timing.generation.is_some());
+        assert_eq!(timing.prompt_tokens, Some(30));
+        assert_eq!(timing.completion_tokens, Some(8));
+    }
+
+    #[test]
+    fn usage_extension_reports_measured_figures_only() {
+        let timing = RequestTiming {
+            queue_wait: Some(Duration::from_millis(5)),
+            ttft: Some(Duration::from_millis(120)),
+            generation: Some(Duration::from_millis(500)),
+            prompt_tokens: None,
+            completion_tokens: Some(21),
+        };
+        assert_eq!(
+            usage_extension(&timing, None),
+            serde_json::json!({
+                "queue_ms": 5,
+                "ttft_ms": 120,
+                "generation_ms": 500,
+                "tokens_per_second": 40.0,
+            })
+        );
+
+        let unmeasured = RequestTiming {
+            queue_wait: Some(Duration::from_millis(5)),
+            ..RequestTiming::default()
+        };
+        assert_eq!(
+            usage_extension(&unmeasured, None),
+            serde_json::json!({ "queue_ms": 5 })
+        );
+    }
+
+    #[test]
+    fn usage_extension_estimates_energy_at_a_configured_rate() {
+        let config = gglib_core::domain::EnergyConfig {
+            watts: Some(360.0),
+            price_per_kwh: Some(0.25),
+            currency: None,
+        };
+        let rate = EnergyRate::configured(Some(&config));
+        // 100 ms of prompt processing after the queue and 900 ms generating:
+        // one second at 360 W is 0.1 Wh.
+        let timing = RequestTiming {
+            queue_wait: Some(Duration::from_millis(20)),
+            ttft: Some(Duration::from_millis(120)),
+            generation: Some(Duration::from_millis(900)),
+            ..RequestTiming::default()
+        };
+
+        let extension = usage_extension(&timing, rate.as_ref());
+        assert_eq!(extension["energy_wh"], 0.1);
+        assert_eq!(extension["cost"], 0.000_025);
+        assert!(
+            usage_extension(&RequestTiming::default(), rate.as_ref())
+                .get("energy_wh")
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn buffered_leaves_streaming_figures_unmeasured() {
+        let sink = Arc::new(Capture::default());
+        RequestTimer::start(Some(sink.clone()), 1).finish_buffered(Some(12), Some(3));
+
+        let recorded = sink.0.lock().unwrap();
+        assert_eq!(recorded[0].1.queue_wait, None);
+        assert_eq!(recorded[0].1.ttft, None);
+        assert!(recorded[0].1.generation.is_some());
+    }
+}
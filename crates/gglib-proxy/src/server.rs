@@ -3,14 +3,15 @@
 //! This module provides the `serve()` function that runs the proxy server
 //! using a pre-bound TcpListener (from the supervisor).
 
+use std::net::SocketAddr;
 use std::path::PathBuf;
 use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, AtomicU64, Ordering as AtomicOrdering};
 
 use axum::{
-    Json, Router,
-    extract::State,
-    http::{HeaderMap, StatusCode},
+    Extension, Json, Router,
+    extract::{ConnectInfo, Query, State, rejection::QueryRejection},
+    http::{HeaderMap, HeaderValue, StatusCode},
     response::{IntoResponse, Response},
     routing::{get, post},
 };
@@ -19,26 +20,42 @@ use reqwest::Client;
 use tokio::net::TcpListener;
 use tokio::sync::Semaphore;
 use tokio_util::sync::CancellationToken;
-use tower_http::cors::{Any, CorsLayer};
 use tracing::{debug, error, info, warn};
 
 use gglib_core::cache_metrics::CacheMetricsStore;
+use gglib_core::domain::EnergyRate;
 use gglib_core::ports::{
-    ModelCatalogPort, ModelRuntimeError, ModelRuntimePort, SettingsRepository,
+    ModelCatalogPort, ModelRuntimeError, ModelRuntimePort, PresetRepository, ServerStatsSink,
+    SettingsRepository,
+};
+use gglib_core::readiness::{
+    ReadinessReport, SubsystemCheck, check_database, check_llama_binary, check_models_dir,
 };
 use gglib_core::request_pipeline::SamplingLayers;
 use gglib_mcp::McpService;
 
-use crate::cache_lifecycle::{StreamConfig, clear_cache, run_with_cache};
+use crate::access::{AccessPolicy, require_api_key};
+use crate::auto_route::{AUTO_ROUTE_HEADER, AutoRoute, Capability, alias_name, select, with_model};
+use crate::cache_lifecycle::{StreamConfig, clear_cache, is_incognito, run_with_cache};
 use crate::connections::ActiveConnectionsRegistry;
 use crate::council_proxy::{CouncilDeps, VIRTUAL_MODELS, handle_virtual_model, virtual_model_info};
 use crate::dashboard::{CacheStatus, CacheStatusCache, DashboardState, spawn_dashboard_publisher};
 use crate::forward::{ForwardError, forward_chat_completion};
+use crate::limits::{self, StreamLimiter};
+use crate::live_config::{ConfigWatch, spawn_config_watcher};
 use crate::mcp::handlers::{delete_mcp, get_mcp, post_mcp};
 use crate::mcp::session::SessionManager;
 use crate::metrics::ContextMetricsStore;
-use crate::models::{ChatRoutingEnvelope, ErrorResponse, ModelInfo, ModelsResponse};
+use crate::middleware::{MiddlewareChain, REQUEST_ID_HEADER, ResponseContext};
+use crate::models::{
+    ChatRoutingEnvelope, ErrorResponse, ModelInfo, ModelListKind, ModelsQuery, ModelsResponse,
+};
+use crate::moderation::{ModerationLog, ModerationMiddleware};
+use crate::presets::{apply_preset, requested_preset, stack_sampling};
+use crate::priority::{GenerationQueue, hold_until_done, request_priority, slot_capacity};
 use crate::profiles::{ModelRoute, configured_names, resolve_route, variant_entries};
+use crate::reasoning::ReasoningOutput;
+use crate::request_timing::RequestTimer;
 use crate::settings_cache::SettingsCache;
 use crate::slots_poller::{SlotsCache, spawn_slots_poller};
 use crate::token_calibration::TokenCalibration;
@@ -59,8 +76,9 @@ pub(crate) struct AppState {
     pub(crate) mcp: Arc<McpService>,
     /// Session manager for MCP Streamable HTTP sessions.
     pub(crate) sessions: SessionManager,
-    /// Default context size when not specified in request.
-    default_ctx: u64,
+    /// Default context size when not specified in request. Atomic so a
+    /// settings change can update it live (see `live_config`).
+    default_ctx: Arc<AtomicU64>,
     /// Orchestrator services for virtual model routing.
     council: CouncilDeps,
     /// Unified proxy dashboard state: active-connections registry, llama.cpp
@@ -72,6 +90,10 @@ pub(crate) struct AppState {
     /// Application settings, snapshotted so the per-request read does not hit
     /// the database every time. See `settings_cache` module docs.
     pub(crate) settings: Arc<SettingsCache>,
+    /// Generation presets, looked up by name when a request selects one.
+    /// Read directly rather than cached: only requests that name a preset
+    /// pay for the query.
+    presets: Arc<dyn PresetRepository>,
     /// Consecutive-failure watchdog: trips a proactive model recycle when the
     /// upstream degrades to empty responses / first-byte timeouts while still
     /// passing its `/health` check.
@@ -100,6 +122,25 @@ pub(crate) struct AppState {
     /// when the same model+session is already hot.
     last_loaded_session:
         Arc<tokio::sync::RwLock<Option<crate::cache_lifecycle::LastLoadedSession>>>,
+    /// Per-session latency/throughput sink (`None` when nothing consumes the
+    /// figures). See `request_timing` module docs.
+    server_stats: Option<Arc<dyn ServerStatsSink>>,
+    /// Request/response hooks registered at startup. See `middleware`
+    /// module docs.
+    middleware: MiddlewareChain,
+    /// `middleware` behind the moderation stage, used while the
+    /// `moderation` setting is on. See `moderation` module docs.
+    moderated_middleware: MiddlewareChain,
+    /// Recent moderation decisions, served at `GET /v1/proxy/moderation`.
+    moderation_log: Arc<ModerationLog>,
+    /// Origins and keys, also consulted for each request's priority class.
+    access: Arc<AccessPolicy>,
+    /// Per-model admission of generations by priority. See `priority`
+    /// module docs.
+    generation_queue: Arc<GenerationQueue>,
+    /// Open streams per API key, for the `proxy_limits` setting. See
+    /// `limits` module docs.
+    stream_limiter: Arc<StreamLimiter>,
 }
 
 /// Start the proxy server with a pre-bound listener.
@@ -117,10 +158,21 @@ pub(crate) struct AppState {
 /// * `cancel` - Cancellation token for graceful shutdown
 /// * `settings_repo` - Settings repository, wrapped in a `SettingsCache` so the
 ///   per-request read is served from a short-lived snapshot rather than a query
+/// * `preset_repo` - Preset repository for requests selecting a preset via
+///   `x-gglib-preset` or the `preset` body field
 /// * `disk_budget` - Byte budget for the on-disk slot cache eviction sweep.
 ///   Only consulted when `slot_dir` is `Some`.
 /// * `agent_metrics` - Agent-path prompt-cache reuse store (council + GUI chat),
 ///   surfaced on the dashboard as `agent_usage` alongside the proxied figure.
+/// * `server_stats` - Sink for per-request queue wait, time-to-first-token,
+///   and throughput, aggregated per server session by the runtime. `None`
+///   disables recording.
+/// * `middleware` - Request/response hooks, run in order on every chat
+///   completion (see [`crate::middleware`]). Empty for none.
+/// * `access` - Allowed browser origins and the API key clients must present
+///   (see [`crate::access`]).
+/// * `config_watch` - Settings published while the proxy runs, applied live
+///   (see [`crate::live_config`]). `None` relies on the settings TTL alone.
 ///
 /// # Returns
 ///
@@ -135,6 +187,7 @@ pub async fn serve(
     council: CouncilDeps,
     cancel: CancellationToken,
     settings_repo: Arc<dyn SettingsRepository>,
+    preset_repo: Arc<dyn PresetRepository>,
     // Operator overrides from this process's command line, applied above the
     // client's own request parameters. See `SamplingLayers::cli_override`.
     inference_override: Option<gglib_core::domain::InferenceConfig>,
@@ -146,6 +199,10 @@ pub async fn serve(
     // single proxy run. Exposed on the dashboard as `agent_usage`, alongside
     // the proxied figure.
     agent_metrics: Arc<CacheMetricsStore>,
+    server_stats: Option<Arc<dyn ServerStatsSink>>,
+    middleware: MiddlewareChain,
+    access: AccessPolicy,
+    config_watch: Option<ConfigWatch>,
 ) -> anyhow::Result<()> {
     let addr = listener.local_addr()?;
     info!("Proxy server starting on {addr}");
@@ -227,6 +284,26 @@ pub async fn serve(
     // slots poller above.
     let dashboard_publisher = spawn_dashboard_publisher(Arc::clone(&dashboard), cancel.clone());
 
+    let default_ctx = Arc::new(AtomicU64::new(default_ctx));
+    let settings = Arc::new(SettingsCache::new(settings_repo));
+    let access = Arc::new(access);
+    // Live settings reload; joined on shutdown like the tasks above.
+    let config_watcher = config_watch.map(|watch| {
+        spawn_config_watcher(
+            watch,
+            Arc::clone(&default_ctx),
+            Arc::clone(&settings),
+            cancel.clone(),
+        )
+    });
+
+    let moderation_log = Arc::new(ModerationLog::new());
+    let moderated_middleware = middleware.with_first(Arc::new(ModerationMiddleware::new(
+        Arc::clone(&settings),
+        client.clone(),
+        Arc::clone(&moderation_log),
+    )));
+
     let state = AppState {
         client,
         runtime_port,
@@ -236,7 +313,8 @@ pub async fn serve(
         default_ctx,
         council,
         dashboard,
-        settings: Arc::new(SettingsCache::new(settings_repo)),
+        settings,
+        presets: preset_repo,
         upstream_health,
         calibration: Arc::new(TokenCalibration::new()),
         inference_override,
@@ -247,40 +325,49 @@ pub async fn serve(
         per_session_cleared,
         server_start_time,
         last_loaded_session,
+        server_stats,
+        middleware,
+        moderated_middleware,
+        moderation_log,
+        access: Arc::clone(&access),
+        generation_queue: Arc::new(GenerationQueue::new()),
+        stream_limiter: Arc::new(StreamLimiter::new()),
     };
 
-    let app = Router::new()
+    let mut app = Router::new()
         .route("/health", get(health_check))
+        .route("/healthz", get(health_check))
+        .route("/readyz", get(readiness_check))
         .route("/v1/models", get(list_models))
         .route("/v1/chat/completions", post(chat_completions))
         .route("/v1/proxy/status", get(handle_proxy_status))
         .route("/v1/proxy/status/stream", get(handle_proxy_status_stream))
         .route("/v1/proxy/cache/clear", post(handle_proxy_cache_clear))
-        .route("/mcp", post(post_mcp).get(get_mcp).delete(delete_mcp))
-        // Permissive CORS: this proxy only ever binds to 127.0.0.1 for local
-        // developer use (CLI or the Tauri GUI) and strips `Authorization`
-        // before forwarding upstream (see `forward.rs`), so there's no
-        // credentialed session to protect and no benefit to restricting
-        // origins — same rationale as `CorsConfig::AllowAll` in gglib-axum.
-        // Without this, browser-based clients (notably the Tauri webview,
-        // which runs on the `tauri://localhost` / `http://tauri.localhost`
-        // origin) are blocked from calling this proxy's endpoints, including
-        // the `EventSource` connection to `/v1/proxy/status/stream`.
-        .layer(
-            CorsLayer::new()
-                .allow_origin(Any)
-                .allow_methods(Any)
-                .allow_headers(Any),
-        )
-        .with_state(state);
+        .route("/v1/proxy/moderation", get(handle_proxy_moderation))
+        .route("/mcp", post(post_mcp).get(get_mcp).delete(delete_mcp));
+    if access.api_key.is_some() {
+        app = app.layer(axum::middleware::from_fn_with_state(
+            Arc::clone(&access),
+            require_api_key,
+        ));
+    }
+    // CORS goes outermost so preflights are answered before the key check
+    // (browsers send them without credentials). With no allowlist every
+    // origin is accepted: the Tauri webview runs on `tauri://localhost` /
+    // `http://tauri.localhost`, and without CORS it could not call this
+    // proxy, including the `EventSource` on `/v1/proxy/status/stream`.
+    let app = app.layer(access.cors_layer()).with_state(state);
 
     info!("Proxy listening on {addr}");
     info!("Configure OpenWebUI to use: http://{addr}/v1");
     info!("MCP Streamable HTTP endpoint: http://{addr}/mcp");
 
-    axum::serve(listener, app)
-        .with_graceful_shutdown(cancel.cancelled_owned())
-        .await?;
+    axum::serve(
+        listener,
+        app.into_make_service_with_connect_info::<SocketAddr>(),
+    )
+    .with_graceful_shutdown(cancel.cancelled_owned())
+    .await?;
 
     // Ensure both background tasks are fully joined (not just cancelled-
     // and-detached) before `serve()` returns, so callers can rely on a
@@ -296,6 +383,11 @@ pub async fn serve(
     {
         warn!("proxy cache: LRU eviction task panicked during shutdown: {e}");
     }
+    if let Some(handle) = config_watcher
+        && let Err(e) = handle.await
+    {
+        warn!("proxy config: reload task panicked during shutdown: {e}");
+    }
 
     info!("Proxy server shut down");
     Ok(())
@@ -308,6 +400,43 @@ async fn health_check() -> impl IntoResponse {
     }))
 }
 
+/// Readiness endpoint.
+///
+/// Checks the database (bypassing the settings snapshot), the llama.cpp
+/// binary, the models directory and the upstream llama-server. No loaded
+/// model is still ready — the proxy starts one on demand — but a watchdog
+/// streak of degraded responses marks the upstream `degraded`. Responds
+/// `503` when any subsystem is down.
+async fn readiness_check(State(state): State<AppState>) -> impl IntoResponse {
+    const UPSTREAM: &str = "upstream";
+    let upstream = match state.runtime_port.current_model().await {
+        None => SubsystemCheck::ok(UPSTREAM, "no model loaded; started on demand"),
+        Some(target) => match state.upstream_health.strikes() {
+            0 => SubsystemCheck::ok(UPSTREAM, target.model_name),
+            strikes => SubsystemCheck::degraded(
+                UPSTREAM,
+                format!(
+                    "{}: {strikes} consecutive degraded responses",
+                    target.model_name
+                ),
+            ),
+        },
+    };
+
+    let report = ReadinessReport::new(vec![
+        check_database(state.settings.probe().await),
+        check_llama_binary(),
+        check_models_dir(),
+        upstream,
+    ]);
+    let status = if report.ready {
+        StatusCode::OK
+    } else {
+        StatusCode::SERVICE_UNAVAILABLE
+    };
+    (status, Json(report))
+}
+
 /// Percentage shaved off a model's raw context window when advertised via
 /// `/v1/models`.
 ///
@@ -348,12 +477,40 @@ fn advertised_context_window(raw_ctx: u64) -> u64 {
 /// Both are shaved by [`CONTEXT_WINDOW_SAFETY_MARGIN_PCT`] before being
 /// advertised, reserving headroom for tool-schema JSON and chat-template
 /// tokens that a client's own char→token budget does not account for.
-async fn list_models(State(state): State<AppState>) -> impl IntoResponse {
-    debug!("GET /v1/models");
+///
+/// The `type` query parameter selects the model family (see
+/// [`ModelListKind`]). Embedding and reranker models are excluded from the
+/// default chat listing; profile variants and council virtual models are
+/// only appended to the chat listing.
+async fn list_models(
+    State(state): State<AppState>,
+    query: Result<Query<ModelsQuery>, QueryRejection>,
+) -> impl IntoResponse {
+    let query = match query {
+        Ok(Query(query)) => query,
+        Err(e) => {
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(ErrorResponse::invalid_request(&format!(
+                    "Invalid model list query: {}",
+                    e.body_text()
+                ))),
+            )
+                .into_response();
+        }
+    };
+    debug!(kind = ?query.kind, "GET /v1/models");
 
     match state.catalog_port.list_models().await {
         Ok(models) => {
-            let mut response = ModelsResponse::from_summaries(models, state.default_ctx);
+            let models = models
+                .into_iter()
+                .filter(|m| query.kind.matches(m.capabilities))
+                .collect();
+            let mut response = ModelsResponse::from_summaries(
+                models,
+                state.default_ctx.load(AtomicOrdering::Relaxed),
+            );
 
             // Apply safety margin to every model's context_window.
             for model in &mut response.data {
@@ -366,6 +523,10 @@ async fn list_models(State(state): State<AppState>) -> impl IntoResponse {
                 model.context_window = Some(advertised_context_window(target.effective_ctx));
             }
 
+            if query.kind != ModelListKind::Chat {
+                return Json(response).into_response();
+            }
+
             // Append `{model}:{profile}` variants for profiles the user opted
             // into listing. Built from the base entries above, so they inherit
             // the context window each model would actually be served with.
@@ -435,6 +596,11 @@ async fn handle_proxy_status_stream(State(state): State<AppState>) -> impl IntoR
         .subscribe_with_hydration(current, SseOptions::default())
 }
 
+/// Return recent moderation decisions, newest first, for review.
+async fn handle_proxy_moderation(State(state): State<AppState>) -> impl IntoResponse {
+    Json(serde_json::json!({ "decisions": state.moderation_log.recent() }))
+}
+
 /// Handle cache clear requests via `POST /v1/proxy/cache/clear`.
 ///
 /// Two independent caches sit behind this endpoint:
@@ -561,7 +727,8 @@ async fn handle_proxy_cache_clear(
 /// Handle chat completions - ensure model is running and proxy to llama-server.
 async fn chat_completions(
     State(state): State<AppState>,
-    headers: HeaderMap,
+    peer: Option<Extension<ConnectInfo<SocketAddr>>>,
+    mut headers: HeaderMap,
     body: Bytes,
 ) -> Response {
     debug!("POST /v1/chat/completions");
@@ -574,6 +741,44 @@ async fn chat_completions(
     let body = crate::canonicalization::canonicalize_system_prompt(body);
     let body = crate::canonicalization::canonicalize_tool_order(body);
 
+    // Registered middleware runs before anything reads the body, so a
+    // rewritten model, preset or session header routes like a client's own.
+    // The request id is fixed here so the request hooks can key state on it.
+    let request_id = uuid::Uuid::new_v4().simple().to_string();
+    if let Ok(value) = HeaderValue::from_str(&request_id) {
+        headers.insert(REQUEST_ID_HEADER, value);
+    }
+    let middleware = if state.settings.get().await.moderation.is_some() {
+        &state.moderated_middleware
+    } else {
+        &state.middleware
+    };
+    let body = match middleware.run_request(body, &mut headers).await {
+        Ok(body) => body,
+        Err((name, rejection)) => {
+            return (
+                rejection.status,
+                Json(ErrorResponse::rejected_by_middleware(
+                    &name,
+                    &rejection.message,
+                )),
+            )
+                .into_response();
+        }
+    };
+
+    // After middleware, so a hook can set the class for clients that can't.
+    let priority = match request_priority(&headers, &state.access) {
+        Ok(priority) => priority,
+        Err(message) => {
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(ErrorResponse::invalid_request(&message)),
+            )
+                .into_response();
+        }
+    };
+
     // Extract and sanitize session ID from header (safety-critical: prevents path traversal)
     let session_id_from_header = headers
         .get("x-gglib-session-id")
@@ -641,7 +846,49 @@ async fn chat_completions(
         }
     };
 
-    let model_name = envelope.model.clone();
+    // Apply a selected preset before anything reads the model name: its
+    // default model may be the one to route to, including a council model.
+    let preset = match requested_preset(&headers, envelope.preset.as_deref()) {
+        None => None,
+        Some(name) => match state.presets.get_by_name(&name).await {
+            Ok(Some(preset)) => Some(preset),
+            Ok(None) => {
+                return (
+                    StatusCode::NOT_FOUND,
+                    Json(ErrorResponse::preset_not_found(&name)),
+                )
+                    .into_response();
+            }
+            Err(e) => {
+                error!("Failed to load preset '{name}': {e}");
+                return (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(ErrorResponse::internal_error(&format!(
+                        "Failed to load preset '{name}'"
+                    ))),
+                )
+                    .into_response();
+            }
+        },
+    };
+    let body = match &preset {
+        Some(preset) => apply_preset(body, preset),
+        None => body,
+    };
+
+    let model_name = match (&preset, envelope.model.trim().is_empty()) {
+        (Some(preset), true) => preset.default_model.clone().unwrap_or_default(),
+        _ => envelope.model.clone(),
+    };
+    if model_name.trim().is_empty() {
+        return (
+            StatusCode::BAD_REQUEST,
+            Json(ErrorResponse::invalid_request(
+                "Request has no model and no preset with a default model",
+            )),
+        )
+            .into_response();
+    }
     let is_streaming = envelope.stream;
     let num_ctx = envelope.num_ctx;
 
@@ -649,6 +896,7 @@ async fn chat_completions(
         model = %model_name,
         streaming = %is_streaming,
         num_ctx = ?num_ctx,
+        ?priority,
         "Processing chat completion request"
     );
 
@@ -670,12 +918,51 @@ async fn chat_completions(
         .await;
     }
 
+    // Resolve an `auto:{capability}` alias to a concrete model. Runs after
+    // the council interception for the same reason profile routing does:
+    // `auto:tools` would otherwise be split into a base and a profile suffix.
+    let auto_route = match alias_name(&model_name) {
+        None => None,
+        Some(capability) => match route_alias(&state, &model_name, capability).await {
+            Ok(route) => Some(route),
+            Err(response) => return response,
+        },
+    };
+    let (model_name, body) = match &auto_route {
+        Some(route) => (route.model.clone(), with_model(body, &route.model)),
+        None => (model_name, body),
+    };
+
     // One settings view for the whole request: the profile list read here and
     // the global defaults read further down come from the same snapshot, so a
     // concurrent settings edit cannot apply to half a request.
     let settings = state.settings.get().await;
     let configured_profiles = settings.inference_profiles.as_deref().unwrap_or_default();
 
+    // Generation limits from the same snapshot. A stream over its key's
+    // limit is turned away before any model is loaded for it; the counted
+    // slot is held until the response body finishes.
+    let proxy_limits = settings.proxy_limits.clone().unwrap_or_default();
+    let stream_slot = match proxy_limits.max_streams_per_key.filter(|_| is_streaming) {
+        None => None,
+        Some(max) => {
+            let peer = peer.map(|Extension(ConnectInfo(addr))| addr);
+            let key = limits::stream_key(&headers, peer, &state.access);
+            let Some(slot) = state.stream_limiter.try_open(&key, max) else {
+                warn!(max, "Turning away a stream: its client has too many open");
+                return (
+                    StatusCode::TOO_MANY_REQUESTS,
+                    Json(ErrorResponse::too_many_streams(max)),
+                )
+                    .into_response();
+            };
+            Some(slot)
+        }
+    };
+    let time_limit = proxy_limits
+        .max_request_seconds
+        .map(|secs| std::time::Duration::from_secs(u64::from(secs)));
+
     // Resolve any `{model}:{profile}` suffix. Everything downstream — the
     // model launch, dashboard registration, metrics, cache keys — uses the
     // base name, so a profile never causes a second model to be launched.
@@ -686,8 +973,11 @@ async fn chat_completions(
     )
     .await
     {
-        ModelRoute::Bare(model) => (model.to_owned(), None),
-        ModelRoute::Profiled { model, profile } => (model.to_owned(), Some(profile.config.clone())),
+        ModelRoute::Bare(model) => (model.to_owned(), stack_sampling(None, preset.as_ref())),
+        ModelRoute::Profiled { model, profile } => (
+            model.to_owned(),
+            stack_sampling(Some(profile.config.clone()), preset.as_ref()),
+        ),
         ModelRoute::ProfileNotFound { requested, suffix } => {
             return (
                 StatusCode::NOT_FOUND,
@@ -720,7 +1010,11 @@ async fn chat_completions(
     // Ensure the model is running with specified context or default
     let target = match state
         .runtime_port
-        .ensure_model_running(&model_name, num_ctx, state.default_ctx)
+        .ensure_model_running(
+            &model_name,
+            num_ctx,
+            state.default_ctx.load(AtomicOrdering::Relaxed),
+        )
         .await
     {
         Ok(target) => target,
@@ -787,12 +1081,27 @@ async fn chat_completions(
         Some(target.effective_ctx),
     );
 
+    // Background requests wait here, in the dashboard's queued phase, until
+    // a slot is free; interactive ones pass straight through. The ticket is
+    // released when the response body finishes.
+    let ticket = state
+        .generation_queue
+        .admit(
+            target.model_id,
+            priority,
+            slot_capacity(&state.dashboard.slots.get()),
+            is_streaming,
+            settings.proxy_preempt_background == Some(true),
+        )
+        .await;
+
     // Global defaults come from the same snapshot the profile list did.
     let sampling = SamplingLayers {
         cli_override: state.inference_override.clone(),
         profile: request_profile.clone(),
         global: settings.inference_defaults.clone(),
     };
+    let reasoning = ReasoningOutput::from_settings(&settings);
 
     // Clone body before forwarding — Bytes is reference-counted so this is
     // O(1).  Needed to retry with the original payload if the upstream dies.
@@ -805,8 +1114,10 @@ async fn chat_completions(
     // suppresses the in-RAM prompt cache that would have (see
     // `gglib_runtime::llama::args::slot_restore`). Leaving the config `None`
     // takes every disk save/restore call out of the request path; the
-    // host-RAM cache handles conversation switching by itself.
-    let stream_config = if state.cache_enabled && target.slot_restore_supported {
+    // host-RAM cache handles conversation switching by itself. Incognito
+    // requests take the same path so their KV state never reaches disk.
+    let incognito = is_incognito(&headers);
+    let stream_config = if state.cache_enabled && target.slot_restore_supported && !incognito {
         state.slot_dir.as_ref().map(|dir| StreamConfig {
             client: state.client.clone(),
             base_url: target.base_url.clone(),
@@ -821,6 +1132,19 @@ async fn chat_completions(
         None
     };
 
+    // Timed from here rather than from the top of the handler so a model
+    // swap's load time never lands in the new server's session stats.
+    let timer = RequestTimer::start(state.server_stats.clone(), target.model_id)
+        .with_energy(EnergyRate::configured(settings.energy.as_ref()))
+        .with_time_limit(time_limit);
+
+    let hooks = middleware.for_response(ResponseContext {
+        request_id,
+        model: model_name.clone(),
+        streaming: is_streaming,
+        session_id: sanitized_session_id.clone(),
+    });
+
     // Forward the request, optionally wrapped in cache lifecycle
     let response = if state.cache_enabled {
         if let (Some(sid), Some(cfg)) = (&sanitized_session_id, &stream_config) {
@@ -838,6 +1162,8 @@ async fn chat_completions(
                         state.catalog_port.clone(),
                         state.dashboard.metrics.clone(),
                         sampling,
+                        &proxy_limits,
+                        reasoning,
                         connection,
                         state.upstream_health.clone(),
                         state.calibration.clone(),
@@ -846,6 +1172,8 @@ async fn chat_completions(
                         None,
                         None,
                         None,
+                        timer,
+                        hooks.clone(),
                     )
                     .await
                 })
@@ -877,6 +1205,8 @@ async fn chat_completions(
                             state.catalog_port.clone(),
                             state.dashboard.metrics.clone(),
                             sampling,
+                            &proxy_limits,
+                            reasoning,
                             connection,
                             state.upstream_health.clone(),
                             state.calibration.clone(),
@@ -885,6 +1215,8 @@ async fn chat_completions(
                             Some(permit),
                             Some(cfg),
                             Some(sid),
+                            timer,
+                            hooks.clone(),
                         )
                         .await
                     }
@@ -901,6 +1233,8 @@ async fn chat_completions(
                             state.catalog_port.clone(),
                             state.dashboard.metrics.clone(),
                             sampling,
+                            &proxy_limits,
+                            reasoning,
                             connection,
                             state.upstream_health.clone(),
                             state.calibration.clone(),
@@ -909,6 +1243,8 @@ async fn chat_completions(
                             None,
                             None,
                             None,
+                            timer,
+                            hooks.clone(),
                         )
                         .await
                     }
@@ -927,6 +1263,8 @@ async fn chat_completions(
                 state.catalog_port.clone(),
                 state.dashboard.metrics.clone(),
                 sampling,
+                &proxy_limits,
+                reasoning,
                 connection,
                 state.upstream_health.clone(),
                 state.calibration.clone(),
@@ -935,6 +1273,8 @@ async fn chat_completions(
                 None,
                 None,
                 None,
+                timer,
+                hooks.clone(),
             )
             .await
         }
@@ -951,6 +1291,8 @@ async fn chat_completions(
             state.catalog_port.clone(),
             state.dashboard.metrics.clone(),
             sampling,
+            &proxy_limits,
+            reasoning,
             connection,
             state.upstream_health.clone(),
             state.calibration.clone(),
@@ -959,13 +1301,15 @@ async fn chat_completions(
             None,
             None,
             None,
+            timer,
+            hooks.clone(),
         )
         .await
     };
 
     // Handle UpstreamDead from the primary forward (only possible when cache is disabled
     // or no session ID — cache-wrapped paths return Ok(Response) internally)
-    match response {
+    let mut response = match response {
         Ok(resp) => resp,
         Err(ForwardError::UpstreamDead) => {
             // llama-server was dead after ensure_model_running() returned a
@@ -989,7 +1333,11 @@ async fn chat_completions(
             let new_target = loop {
                 match state
                     .runtime_port
-                    .ensure_model_running(&model_name, num_ctx, state.default_ctx)
+                    .ensure_model_running(
+                        &model_name,
+                        num_ctx,
+                        state.default_ctx.load(AtomicOrdering::Relaxed),
+                    )
                     .await
                 {
                     Ok(t) => break t,
@@ -1035,7 +1383,7 @@ async fn chat_completions(
             // the RAM-cache-only path (see the initial attempt above).
             let (retry_permit, retry_cfg, retry_session) =
                 if let (true, Some(sid), Some(slot_dir)) = (
-                    state.cache_enabled && new_target.slot_restore_supported,
+                    state.cache_enabled && new_target.slot_restore_supported && !incognito,
                     sanitized_session_id.as_ref(),
                     state.slot_dir.as_ref(),
                 ) {
@@ -1063,6 +1411,9 @@ async fn chat_completions(
                 } else {
                     (None, None, None)
                 };
+            let retry_timer = RequestTimer::start(state.server_stats.clone(), new_target.model_id)
+                .with_energy(EnergyRate::configured(settings.energy.as_ref()))
+                .with_time_limit(time_limit);
 
             match forward_chat_completion(
                 &state.client,
@@ -1075,6 +1426,8 @@ async fn chat_completions(
                 state.catalog_port.clone(),
                 state.dashboard.metrics.clone(),
                 retry_sampling,
+                &proxy_limits,
+                reasoning,
                 retry_connection,
                 state.upstream_health.clone(),
                 state.calibration.clone(),
@@ -1083,6 +1436,8 @@ async fn chat_completions(
                 retry_permit,
                 retry_cfg,
                 retry_session,
+                retry_timer,
+                hooks,
             )
             .await
             {
@@ -1102,9 +1457,59 @@ async fn chat_completions(
                 }
             }
         }
+    };
+    if let Some(value) = auto_route.and_then(|r| HeaderValue::from_str(&r.explanation()).ok()) {
+        response.headers_mut().insert(AUTO_ROUTE_HEADER, value);
+    }
+    let response = hold_until_done(response, ticket);
+    match stream_slot {
+        Some(slot) => limits::hold_until_done(response, slot),
+        None => response,
     }
 }
 
+/// Pick the model for an `auto:` alias, or the error response explaining why
+/// none can serve it.
+async fn route_alias(
+    state: &AppState,
+    alias: &str,
+    capability: &str,
+) -> Result<AutoRoute, Response> {
+    let not_routable = |reason: &str| {
+        (
+            StatusCode::NOT_FOUND,
+            Json(ErrorResponse::no_auto_route(alias, reason)),
+        )
+            .into_response()
+    };
+    let Some(capability) = Capability::from_name(capability) else {
+        let known: Vec<_> = Capability::ALL.iter().map(|c| c.name()).collect();
+        return Err(not_routable(&format!(
+            "unknown capability; expected one of {}",
+            known.join(", ")
+        )));
+    };
+    let models = state.catalog_port.list_models().await.map_err(|e| {
+        error!("Failed to list models for '{alias}': {e}");
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorResponse::internal_error(&format!(
+                "Failed to list models for '{alias}'"
+            ))),
+        )
+            .into_response()
+    })?;
+    let loaded = state.runtime_port.current_model().await;
+    let route = select(
+        &models,
+        capability,
+        loaded.as_ref().map(|t| t.model_name.as_str()),
+    )
+    .ok_or_else(|| not_routable("no model in the library has that capability"))?;
+    info!(alias, route = %route.explanation(), "auto-routed request");
+    Ok(route)
+}
+
 /// Convert ModelRuntimeError to HTTP response with appropriate status code.
 fn handle_runtime_error(err: ModelRuntimeError) -> Response {
     let status = StatusCode::from_u16(err.suggested_status_code())
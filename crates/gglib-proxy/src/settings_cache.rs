@@ -20,6 +20,10 @@
 //! staleness uniformly no matter which process did the writing, at the cost of
 //! settings changes taking up to [`DEFAULT_TTL`] to take effect.
 //!
+//! In-process changes do not wait for the TTL: the GUI backend publishes each
+//! save to the running proxy, which installs it with
+//! [`replace`](SettingsCache::replace) (see `live_config`).
+//!
 //! # Failure behaviour
 //!
 //! A failed load never fails the request. The last good snapshot is served if
@@ -121,6 +125,24 @@ impl SettingsCache {
             }
         }
     }
+
+    /// Install `settings` as the current snapshot, starting a fresh window.
+    ///
+    /// Used for settings pushed to a running proxy, which are newer than
+    /// anything a reload could return.
+    pub async fn replace(&self, settings: Arc<Settings>) {
+        *self.snapshot.write().await = Some((settings, Instant::now() + self.ttl));
+    }
+
+    /// Load straight from the repository, bypassing the snapshot, and report
+    /// the outcome.
+    ///
+    /// Used by `/readyz`: [`get`](Self::get) hides database failures by
+    /// design, which is the opposite of what a readiness probe wants. A
+    /// successful probe does not refresh the snapshot.
+    pub async fn probe(&self) -> Result<Settings, gglib_core::RepositoryError> {
+        self.repo.load().await
+    }
 }
 
 #[cfg(test)]
@@ -227,6 +249,18 @@ mod tests {
         assert_eq!(*cache.get().await, Settings::default());
     }
 
+    /// The probe surfaces the failure that `get` deliberately swallows.
+    #[tokio::test]
+    async fn probe_reports_repository_failures() {
+        let repo = Arc::new(CountingRepo::default());
+        let cache = SettingsCache::new(Arc::clone(&repo) as _);
+        assert!(cache.probe().await.is_ok());
+
+        repo.fail.store(true, Ordering::SeqCst);
+        assert!(cache.probe().await.is_err());
+        assert_eq!(*cache.get().await, Settings::default());
+    }
+
     /// A burst arriving on an expired snapshot must collapse into one query,
     /// not one per caller.
     #[tokio::test]
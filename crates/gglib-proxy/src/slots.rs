@@ -30,6 +30,7 @@ use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::time::{Duration, Instant};
 
 use dashmap::DashSet;
+use gglib_core::domain::SlotInfo;
 use reqwest::{Client, StatusCode};
 use serde::{Deserialize, Serialize};
 use tokio::time as tokio_time;
@@ -246,6 +247,17 @@ impl SlotSnapshot {
         let used = self.tokens_in_use()?;
         Some(n_ctx.saturating_sub(used))
     }
+
+    /// This slot as the domain [`SlotInfo`] served by the web API.
+    #[must_use]
+    pub fn to_slot_info(&self) -> SlotInfo {
+        SlotInfo {
+            id: self.id,
+            processing: self.is_processing,
+            context_size: self.n_ctx,
+            context_used: self.tokens_in_use(),
+        }
+    }
 }
 
 // =============================================================================
@@ -412,6 +424,33 @@ mod tests {
         assert_eq!(slots[0].context_remaining(), Some(4096 - 512));
     }
 
+    #[test]
+    fn slot_info_carries_usage_from_either_schema() {
+        let SlotsPollResult::Available(legacy) =
+            parse_slots_response(StatusCode::OK, LEGACY_SCHEMA_FIXTURE)
+        else {
+            panic!("expected Available");
+        };
+        assert_eq!(
+            legacy[0].to_slot_info(),
+            SlotInfo {
+                id: 0,
+                processing: false,
+                context_size: Some(4096),
+                context_used: Some(512),
+            }
+        );
+
+        let SlotsPollResult::Available(current) =
+            parse_slots_response(StatusCode::OK, CURRENT_SCHEMA_FIXTURE)
+        else {
+            panic!("expected Available");
+        };
+        let info = current[1].to_slot_info();
+        assert!(info.processing);
+        assert_eq!(info.context_used, Some(136));
+    }
+
     #[test]
     fn n_past_takes_priority_over_next_token_when_both_present() {
         let body = r#"[{
@@ -13,7 +13,11 @@ use tracing::{debug, error, warn};
 use crate::cache_lifecycle::{StreamConfig, save_after_generation};
 use crate::connections::ConnectionGuard;
 use crate::forward::{FIRST_BYTE_DEADLINE_SECS, stream_response_to_channel, visible_content_frame};
+use crate::middleware::ResponseHooks;
+use crate::reasoning::ReasoningPolicy;
+use crate::request_timing::RequestTimer;
 use crate::token_calibration::TokenCalibration;
+use crate::tool_calls::DeclaredTools;
 use crate::upstream_health::UpstreamHealth;
 use gglib_core::cache_metrics::CacheMetricsStore;
 
@@ -40,6 +44,10 @@ const RETRY_BACKOFF: std::time::Duration = std::time::Duration::from_millis(100)
 /// cache is saved via [`save_after_generation`] immediately after
 /// [`stream_response_to_channel`] returns — before the semaphore `permit`
 /// drops at the end of this task.
+///
+/// `timer` is finished once the stream has been drained, with the instant the
+/// upstream's headers arrived marking the end of the slot-queue wait.
+/// `hooks` is handed to [`stream_response_to_channel`].
 #[allow(clippy::too_many_arguments)]
 pub fn spawn_and_return(
     req_builder: reqwest::RequestBuilder,
@@ -49,6 +57,8 @@ pub fn spawn_and_return(
     connection: ConnectionGuard,
     model_name_owned: String,
     tags: Vec<String>,
+    reasoning: ReasoningPolicy,
+    tools: DeclaredTools,
     upstream_health: Arc<UpstreamHealth>,
     calibration: Arc<TokenCalibration>,
     cache_metrics: Arc<CacheMetricsStore>,
@@ -56,6 +66,8 @@ pub fn spawn_and_return(
     permit: Option<tokio::sync::OwnedSemaphorePermit>,
     config: Option<StreamConfig>,
     session_id: Option<String>,
+    timer: RequestTimer,
+    hooks: ResponseHooks,
 ) -> Response {
     // `connection` is moved into this task so it lives exactly as long
     // as the streaming task does — dropped (unregistering from the
@@ -167,6 +179,9 @@ pub fn spawn_and_return(
             }
         };
 
+        // Response headers only arrive once llama.cpp has assigned a slot, so
+        // this instant closes the slot-queue wait.
+        let headers_at = std::time::Instant::now();
         match upstream_response {
             Ok(resp) if resp.status().is_success() => {
                 debug!(
@@ -177,8 +192,13 @@ pub fn spawn_and_return(
                     resp,
                     model_name_owned.clone(),
                     tags,
+                    reasoning,
+                    tools,
                     tx,
                     &connection,
+                    &timer,
+                    headers_at,
+                    &hooks,
                 )
                 .await;
                 // Feed the terminal outcome to the watchdog: an empty
@@ -199,6 +219,12 @@ pub fn spawn_and_return(
                     // counted as zero reuse.
                     cache_metrics.record(prompt_tokens, outcome.cached_tokens);
                 }
+                timer.finish_streaming(
+                    headers_at,
+                    outcome.first_token_at,
+                    outcome.prompt_tokens,
+                    outcome.completion_tokens,
+                );
                 // KV cache save (opt-in): awaited, never detached, happens
                 // after stream exhaustion and before the permit drops.
                 if let (Some(cfg), Some(sid)) = (config.as_ref(), session_id.as_ref()) {
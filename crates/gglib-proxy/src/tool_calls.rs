@@ -0,0 +1,550 @@
+//! Tool-call assembly for streaming responses.
+//!
+//! llama-server streams a tool call as a run of fragments: the first names
+//! the function, the rest carry slices of the arguments JSON. Depending on
+//! the server version and the model's dialect, a response can also repeat
+//! the id and name on every fragment, leave the id out entirely, use sparse
+//! indexes (normalization numbers synthesised calls past every upstream
+//! index), or send argument bytes before the name. Clients that follow the
+//! `OpenAI` contract to the letter choke on all of these.
+//!
+//! [`ToolCallAssembler`] sits after normalization and rewrites the fragments
+//! into the shape the `OpenAI` SDKs expect:
+//!
+//! ```text
+//!  upstream                                  client
+//!  index 3, name "get_weather", args "{\"ci" → index 0, id "call_…", name, args
+//!  index 3, id "x", name "get_weather",      → index 0, args "ty\":\"Oslo\"}"
+//!           args "ty\":\"Oslo\"}"
+//! ```
+//!
+//! - indexes are dense and in order of first appearance;
+//! - the first fragment of every call carries its id (generated when the
+//!   upstream sent none) and name, later fragments only arguments;
+//! - fragments that arrive before the name are held back until it does.
+//!
+//! When the stream finishes, each call's assembled arguments are checked
+//! against the `parameters` schema the request declared for that function
+//! ([`DeclaredTools`]). A mismatch does not stop the call from reaching the
+//! client — its fragments are already on the wire — but is followed by a
+//! structured error frame ([`ToolCallError::frame`]) so the client can reject
+//! the call instead of executing it with bad input. The check covers the
+//! schema keywords tool definitions use in practice (`type`, `enum`,
+//! `required`, `properties`, `additionalProperties`, `items`); anything else
+//! is accepted.
+//!
+//! Non-streaming responses arrive as one finished JSON body and are returned
+//! as llama-server sent them.
+
+use std::collections::HashMap;
+
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+use gglib_core::LlmStreamEvent;
+
+/// The function schemas a request declared in `tools[]`, by name.
+#[derive(Debug, Clone, Default)]
+pub struct DeclaredTools {
+    schemas: HashMap<String, Value>,
+}
+
+impl DeclaredTools {
+    /// Read the declared functions from a chat-completion request body.
+    ///
+    /// Returns an empty set when the body is not JSON or declares no tools,
+    /// in which case arguments are not validated. A function declared
+    /// without `parameters` accepts any arguments.
+    #[must_use]
+    pub fn from_body(body: &[u8]) -> Self {
+        let Ok(request) = serde_json::from_slice::<Value>(body) else {
+            return Self::default();
+        };
+        let schemas = request["tools"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter_map(|tool| {
+                let function = &tool["function"];
+                let name = function["name"].as_str()?;
+                let schema = function
+                    .get("parameters")
+                    .cloned()
+                    .unwrap_or_else(|| Value::Object(Map::new()));
+                Some((name.to_owned(), schema))
+            })
+            .collect();
+        Self { schemas }
+    }
+
+    /// `true` when the request declared no functions.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.schemas.is_empty()
+    }
+}
+
+/// One schema mismatch in a tool call's arguments.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Violation {
+    /// JSON Pointer to the offending value; empty for the arguments object
+    /// itself.
+    pub path: String,
+    /// What is wrong with it.
+    pub message: String,
+}
+
+/// A finished tool call whose arguments do not fit the declared schema.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ToolCallError {
+    /// The call's index, as sent to the client.
+    pub index: usize,
+    /// The call's id, as sent to the client.
+    pub id: String,
+    /// The function name, `None` if the model never produced one.
+    pub name: Option<String>,
+    /// Every mismatch found.
+    pub violations: Vec<Violation>,
+}
+
+impl ToolCallError {
+    /// The SSE error frame reporting this call, in the shape of the proxy's
+    /// other in-stream errors plus the call and its violations:
+    ///
+    /// ```json
+    /// {"error": {"message": "…", "type": "invalid_tool_call",
+    ///            "code": "tool_arguments_invalid",
+    ///            "param": "tool_calls[0].function.arguments",
+    ///            "tool_call": {"index": 0, "id": "call_…", "name": "get_weather"},
+    ///            "violations": [{"path": "/days", "message": "expected integer, got string"}]}}
+    /// ```
+    #[must_use]
+    pub fn frame(&self) -> String {
+        let details: Vec<String> = self
+            .violations
+            .iter()
+            .map(|v| {
+                if v.path.is_empty() {
+                    v.message.clone()
+                } else {
+                    format!("{}: {}", v.path, v.message)
+                }
+            })
+            .collect();
+        let payload = serde_json::json!({
+            "error": {
+                "message": format!(
+                    "tool call {} ({}) does not match its declared schema: {}",
+                    self.index,
+                    self.name.as_deref().unwrap_or("unnamed"),
+                    details.join("; "),
+                ),
+                "type": "invalid_tool_call",
+                "code": "tool_arguments_invalid",
+                "param": format!("tool_calls[{}].function.arguments", self.index),
+                "tool_call": {
+                    "index": self.index,
+                    "id": self.id,
+                    "name": self.name,
+                },
+                "violations": self.violations,
+            }
+        });
+        format!("data: {payload}\n\n")
+    }
+}
+
+/// A tool call being assembled.
+#[derive(Debug)]
+struct PendingCall {
+    id: String,
+    name: Option<String>,
+    arguments: String,
+    /// The first fragment (id and name) has been sent.
+    announced: bool,
+}
+
+/// Rewrites one response's tool-call fragments into `OpenAI`-conformant
+/// deltas and validates the finished calls. See the module docs.
+#[derive(Debug, Default)]
+pub(crate) struct ToolCallAssembler {
+    tools: DeclaredTools,
+    /// Calls in order of first appearance; the position is the client index.
+    calls: Vec<PendingCall>,
+    /// Upstream index → position in `calls`.
+    positions: HashMap<usize, usize>,
+}
+
+impl ToolCallAssembler {
+    pub(crate) fn new(tools: DeclaredTools) -> Self {
+        Self {
+            tools,
+            ..Self::default()
+        }
+    }
+
+    /// Rewrite one event. Tool-call fragments come back renumbered and
+    /// trimmed, or `None` while they are held back (or carry nothing new);
+    /// every other event passes through.
+    pub(crate) fn process(&mut self, event: LlmStreamEvent) -> Option<LlmStreamEvent> {
+        let LlmStreamEvent::ToolCallDelta {
+            index,
+            id,
+            name,
+            arguments,
+        } = event
+        else {
+            return Some(event);
+        };
+
+        let position = *self.positions.entry(index).or_insert_with(|| {
+            self.calls.push(PendingCall {
+                id: id.unwrap_or_else(|| format!("call_{}", uuid::Uuid::new_v4().simple())),
+                name: None,
+                arguments: String::new(),
+                announced: false,
+            });
+            self.calls.len() - 1
+        });
+        let call = &mut self.calls[position];
+        if call.name.is_none() {
+            call.name = name.filter(|n| !n.is_empty());
+        }
+
+        if call.announced {
+            let arguments = arguments.filter(|a| !a.is_empty())?;
+            call.arguments.push_str(&arguments);
+            return Some(LlmStreamEvent::ToolCallDelta {
+                index: position,
+                id: None,
+                name: None,
+                arguments: Some(arguments),
+            });
+        }
+
+        if let Some(arguments) = arguments {
+            call.arguments.push_str(&arguments);
+        }
+        let name = call.name.clone()?;
+        call.announced = true;
+        Some(LlmStreamEvent::ToolCallDelta {
+            index: position,
+            id: Some(call.id.clone()),
+            name: Some(name),
+            arguments: Some(call.arguments.clone()).filter(|a| !a.is_empty()),
+        })
+    }
+
+    /// Check every call against its declared schema once the response is
+    /// complete. Calls the model never named are always reported; the
+    /// arguments are only checked when the request declared tools.
+    pub(crate) fn finish(&self) -> Vec<ToolCallError> {
+        self.calls
+            .iter()
+            .enumerate()
+            .filter_map(|(index, call)| {
+                let violations = self.violations(call);
+                (!violations.is_empty()).then(|| ToolCallError {
+                    index,
+                    id: call.id.clone(),
+                    name: call.name.clone(),
+                    violations,
+                })
+            })
+            .collect()
+    }
+
+    fn violations(&self, call: &PendingCall) -> Vec<Violation> {
+        let Some(name) = &call.name else {
+            return vec![violation("", "the model never named the function")];
+        };
+        if self.tools.is_empty() {
+            return Vec::new();
+        }
+        let Some(schema) = self.tools.schemas.get(name) else {
+            return vec![violation("", format!("`{name}` is not a declared tool"))];
+        };
+        let arguments = if call.arguments.trim().is_empty() {
+            Value::Object(Map::new())
+        } else {
+            match serde_json::from_str(&call.arguments) {
+                Ok(value) => value,
+                Err(e) => return vec![violation("", format!("arguments are not valid JSON: {e}"))],
+            }
+        };
+        let mut out = Vec::new();
+        check(schema, &arguments, "", &mut out);
+        out
+    }
+}
+
+fn violation(path: &str, message: impl Into<String>) -> Violation {
+    Violation {
+        path: path.to_owned(),
+        message: message.into(),
+    }
+}
+
+/// Check `value` against `schema`, appending every mismatch to `out`.
+fn check(schema: &Value, value: &Value, path: &str, out: &mut Vec<Violation>) {
+    let types: Vec<&str> = match schema.get("type") {
+        Some(Value::String(t)) => vec![t.as_str()],
+        Some(Value::Array(ts)) => ts.iter().filter_map(Value::as_str).collect(),
+        _ => Vec::new(),
+    };
+    if !types.is_empty() && !types.iter().any(|t| has_type(value, t)) {
+        out.push(violation(
+            path,
+            format!("expected {}, got {}", types.join(" or "), type_name(value)),
+        ));
+        return;
+    }
+    if let Some(options) = schema.get("enum").and_then(Value::as_array)
+        && !options.contains(value)
+    {
+        out.push(violation(
+            path,
+            format!("must be one of {}", Value::Array(options.clone())),
+        ));
+    }
+
+    match value {
+        Value::Object(fields) => {
+            for key in schema["required"].as_array().into_iter().flatten() {
+                if let Some(key) = key.as_str()
+                    && !fields.contains_key(key)
+                {
+                    out.push(violation(&child(path, key), "is required"));
+                }
+            }
+            let properties = schema.get("properties").and_then(Value::as_object);
+            for (key, field) in fields {
+                let field_path = child(path, key);
+                match (
+                    properties.and_then(|p| p.get(key)),
+                    schema.get("additionalProperties"),
+                ) {
+                    (Some(field_schema), _) => check(field_schema, field, &field_path, out),
+                    (None, Some(Value::Bool(false))) => {
+                        out.push(violation(&field_path, "is not a declared property"));
+                    }
+                    (None, Some(extra @ Value::Object(_))) => {
+                        check(extra, field, &field_path, out);
+                    }
+                    (None, _) => {}
+                }
+            }
+        }
+        Value::Array(items) => {
+            if let Some(item_schema) = schema.get("items").filter(|s| s.is_object()) {
+                for (i, item) in items.iter().enumerate() {
+                    check(item_schema, item, &child(path, &i.to_string()), out);
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+/// `path` extended by one JSON Pointer segment.
+fn child(path: &str, key: &str) -> String {
+    format!("{path}/{}", key.replace('~', "~0").replace('/', "~1"))
+}
+
+fn has_type(value: &Value, ty: &str) -> bool {
+    match ty {
+        "object" => value.is_object(),
+        "array" => value.is_array(),
+        "string" => value.is_string(),
+        "boolean" => value.is_boolean(),
+        "null" => value.is_null(),
+        "number" => value.is_number(),
+        "integer" => {
+            value.is_i64() || value.is_u64() || value.as_f64().is_some_and(|n| n.fract() == 0.0)
+        }
+        // Unknown type names are not ours to reject.
+        _ => true,
+    }
+}
+
+const fn type_name(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(_) => "number",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn delta(index: usize, id: Option<&str>, name: Option<&str>, args: &str) -> LlmStreamEvent {
+        LlmStreamEvent::ToolCallDelta {
+            index,
+            id: id.map(str::to_owned),
+            name: name.map(str::to_owned),
+            arguments: Some(args.to_owned()).filter(|a| !a.is_empty()),
+        }
+    }
+
+    fn weather_tools() -> DeclaredTools {
+        let body = json!({
+            "messages": [],
+            "tools": [{
+                "type": "function",
+                "function": {
+                    "name": "get_weather",
+                    "parameters": {
+                        "type": "object",
+                        "properties": {
+                            "city": { "type": "string" },
+                            "days": { "type": "integer" },
+                            "unit": { "type": "string", "enum": ["c", "f"] }
+                        },
+                        "required": ["city"],
+                        "additionalProperties": false
+                    }
+                }
+            }]
+        });
+        DeclaredTools::from_body(body.to_string().as_bytes())
+    }
+
+    /// Feed one call's fragments through an assembler and return it.
+    fn assemble(tools: DeclaredTools, name: &str, args: &str) -> ToolCallAssembler {
+        let mut assembler = ToolCallAssembler::new(tools);
+        assembler.process(delta(0, Some("c1"), Some(name), args));
+        assembler
+    }
+
+    #[test]
+    fn fragments_are_renumbered_and_trimmed() {
+        let mut assembler = ToolCallAssembler::default();
+        let out: Vec<_> = [
+            delta(3, Some("a"), Some("get_weather"), "{\"ci"),
+            delta(3, Some("a"), Some("get_weather"), "ty\":\"Oslo\"}"),
+            delta(7, None, Some("get_time"), ""),
+        ]
+        .into_iter()
+        .filter_map(|e| assembler.process(e))
+        .collect();
+
+        assert_eq!(
+            out[..2],
+            [
+                delta(0, Some("a"), Some("get_weather"), "{\"ci"),
+                delta(0, None, None, "ty\":\"Oslo\"}"),
+            ]
+        );
+        let LlmStreamEvent::ToolCallDelta {
+            index: 1,
+            id: Some(id),
+            ..
+        } = &out[2]
+        else {
+            panic!("second call must be announced at index 1: {:?}", out[2]);
+        };
+        assert!(id.starts_with("call_"), "missing ids are generated: {id}");
+    }
+
+    #[test]
+    fn arguments_before_the_name_are_held_back() {
+        let mut assembler = ToolCallAssembler::default();
+        assert_eq!(
+            assembler.process(delta(0, Some("a"), None, "{\"city\":")),
+            None
+        );
+        assert_eq!(
+            assembler.process(delta(0, None, Some("get_weather"), "\"Oslo\"}")),
+            Some(delta(
+                0,
+                Some("a"),
+                Some("get_weather"),
+                "{\"city\":\"Oslo\"}"
+            ))
+        );
+    }
+
+    #[test]
+    fn other_events_pass_through() {
+        let mut assembler = ToolCallAssembler::default();
+        let text = LlmStreamEvent::TextDelta {
+            content: "hi".to_owned(),
+        };
+        assert_eq!(assembler.process(text.clone()), Some(text));
+    }
+
+    #[test]
+    fn matching_arguments_pass() {
+        let assembler = assemble(
+            weather_tools(),
+            "get_weather",
+            r#"{"city":"Oslo","days":3}"#,
+        );
+        assert!(assembler.finish().is_empty());
+    }
+
+    #[test]
+    fn schema_mismatches_are_reported() {
+        let assembler = assemble(
+            weather_tools(),
+            "get_weather",
+            r#"{"days":"3","unit":"k","x":1}"#,
+        );
+        let errors = assembler.finish();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(
+            errors[0].violations,
+            [
+                violation("/city", "is required"),
+                violation("/days", "expected integer, got string"),
+                violation("/unit", r#"must be one of ["c","f"]"#),
+                violation("/x", "is not a declared property"),
+            ]
+        );
+    }
+
+    #[test]
+    fn unknown_tools_and_broken_json_are_reported() {
+        let unknown = assemble(weather_tools(), "launch", "{}").finish();
+        assert_eq!(
+            unknown[0].violations,
+            [violation("", "`launch` is not a declared tool")]
+        );
+
+        let broken = assemble(weather_tools(), "get_weather", "{\"city\":").finish();
+        assert!(
+            broken[0].violations[0]
+                .message
+                .starts_with("arguments are not valid JSON")
+        );
+    }
+
+    #[test]
+    fn arguments_are_not_checked_without_declared_tools() {
+        let assembler = assemble(DeclaredTools::default(), "anything", "not json");
+        assert!(assembler.finish().is_empty());
+    }
+
+    #[test]
+    fn error_frame_carries_the_call_and_its_violations() {
+        let frame = assemble(weather_tools(), "get_weather", "{}").finish()[0].frame();
+        let v: Value =
+            serde_json::from_str(frame.strip_prefix("data: ").unwrap().trim_end()).unwrap();
+        assert_eq!(v["error"]["code"], "tool_arguments_invalid");
+        assert_eq!(v["error"]["param"], "tool_calls[0].function.arguments");
+        assert_eq!(
+            v["error"]["tool_call"],
+            json!({"index": 0, "id": "c1", "name": "get_weather"})
+        );
+        assert_eq!(
+            v["error"]["violations"],
+            json!([{"path": "/city", "message": "is required"}])
+        );
+    }
+}
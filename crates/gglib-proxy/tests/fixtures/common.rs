@@ -8,10 +8,11 @@ use tokio_util::sync::CancellationToken;
 
 use gglib_core::Settings;
 use gglib_core::domain::council::{CouncilEvent, CouncilRun, CouncilRunEvent, CouncilRunStatus};
+use gglib_core::domain::preset::{NewPreset, Preset, PresetUpdate};
 use gglib_core::ports::{
     ApprovalDecision, CatalogError, CouncilApprovalRegistryPort, CouncilRepositoryPort,
     ModelCatalogPort, ModelLaunchSpec, ModelRuntimeError, ModelRuntimePort, ModelSummary,
-    RepositoryError, RunningTarget, SettingsRepository,
+    PresetError, PresetRepository, RepositoryError, RunningTarget, SettingsRepository,
 };
 use gglib_core::{McpRepositoryError, McpServer, McpServerRepository, NewMcpServer, NoopEmitter};
 use gglib_mcp::McpService;
@@ -83,6 +84,36 @@ impl SettingsRepository for MockSettingsRepo {
     }
 }
 
+// ─── PresetRepository mock ────────────────────────────────────────────────
+
+/// Serves a fixed preset list; writes are rejected.
+#[derive(Default)]
+pub struct MockPresetRepo {
+    pub presets: Vec<Preset>,
+}
+
+#[async_trait]
+impl PresetRepository for MockPresetRepo {
+    async fn create(&self, _: NewPreset) -> Result<i64, PresetError> {
+        Err(PresetError::Database("read-only mock".into()))
+    }
+    async fn list(&self) -> Result<Vec<Preset>, PresetError> {
+        Ok(self.presets.clone())
+    }
+    async fn get(&self, id: i64) -> Result<Option<Preset>, PresetError> {
+        Ok(self.presets.iter().find(|p| p.id == id).cloned())
+    }
+    async fn get_by_name(&self, name: &str) -> Result<Option<Preset>, PresetError> {
+        Ok(self.presets.iter().find(|p| p.name == name).cloned())
+    }
+    async fn update(&self, id: i64, _: PresetUpdate) -> Result<(), PresetError> {
+        Err(PresetError::NotFound(id))
+    }
+    async fn delete(&self, id: i64) -> Result<(), PresetError> {
+        Err(PresetError::NotFound(id))
+    }
+}
+
 // ─── Council mocks (verified against trait definitions) ───────────────────
 
 /// No-op council runner — `run` immediately returns Ok.
@@ -56,6 +56,15 @@ data: {\"id\":\"u-4\",\"object\":\"chat.completion.chunk\",\"created\":172900000
 data: {\"id\":\"u-4\",\"object\":\"chat.completion.chunk\",\"created\":1729000000,\"model\":\"upstream\",\"choices\":[{\"index\":0,\"delta\":{},\"finish_reason\":\"tool_calls\"}]}\n\n\
 data: [DONE]\n\n";
 
+/// llama-server's own streaming tail with `stream_options.include_usage`:
+/// the finish chunk, then a usage chunk with an empty `choices` array and
+/// llama.cpp's `timings` object alongside.
+pub const TEXT_WITH_USAGE: &[u8] = b"\
+data: {\"id\":\"u-7\",\"object\":\"chat.completion.chunk\",\"created\":1729000000,\"model\":\"upstream\",\"choices\":[{\"index\":0,\"delta\":{\"content\":\"Hi there\"},\"finish_reason\":null}]}\n\n\
+data: {\"id\":\"u-7\",\"object\":\"chat.completion.chunk\",\"created\":1729000000,\"model\":\"upstream\",\"choices\":[{\"index\":0,\"delta\":{},\"finish_reason\":\"stop\"}]}\n\n\
+data: {\"id\":\"u-7\",\"object\":\"chat.completion.chunk\",\"created\":1729000000,\"model\":\"upstream\",\"choices\":[],\"usage\":{\"completion_tokens\":3,\"prompt_tokens\":12,\"total_tokens\":15,\"prompt_tokens_details\":{\"cached_tokens\":8}},\"timings\":{\"prompt_n\":4,\"prompt_ms\":9.5,\"predicted_n\":3,\"predicted_ms\":30.1}}\n\n\
+data: [DONE]\n\n";
+
 /// A malformed `data:` payload (broken JSON) sandwiched between two valid
 /// frames.  The pipeline cannot recover frame boundaries after a JSON parse
 /// failure, so it must surface the pre-error content, emit a structured
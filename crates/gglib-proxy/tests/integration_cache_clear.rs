@@ -75,11 +75,16 @@ async fn spawn_proxy(
             fixtures::common::make_orchestrator_deps(),
             cancel_clone,
             Arc::new(fixtures::common::MockSettingsRepo),
+            Arc::new(fixtures::common::MockPresetRepo::default()),
             None, // inference_override
             cache_enabled,
             slot_dir,
             gglib_proxy::slot_eviction::DiskBudget::Auto,
             std::sync::Arc::new(gglib_core::cache_metrics::CacheMetricsStore::new()),
+            None,
+            gglib_proxy::MiddlewareChain::default(),
+            gglib_proxy::AccessPolicy::default(),
+            None,
         )
         .await
         .ok();
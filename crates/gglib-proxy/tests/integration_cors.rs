@@ -1,8 +1,9 @@
-//! Verifies the proxy's permissive CORS layer, added specifically so the
+//! Verifies the proxy's CORS layer, permissive by default specifically so the
 //! Tauri GUI's webview (origin `tauri://localhost` / `http://tauri.localhost`
 //! on Windows) can call this proxy's endpoints — including opening an
 //! `EventSource` connection to `GET /v1/proxy/status/stream` — without the
-//! browser blocking the request as cross-origin.
+//! browser blocking the request as cross-origin. Also covers the configured
+//! origin allowlist and API key (`gglib_proxy::AccessPolicy`).
 //!
 //! Uses the real `gglib_proxy::serve` (not a hand-rolled router), following
 //! the same self-contained-harness pattern as the other integration tests in
@@ -25,9 +26,11 @@ use gglib_core::ports::{
 };
 use gglib_core::{McpRepositoryError, McpServer, McpServerRepository, NewMcpServer, NoopEmitter};
 use gglib_mcp::McpService;
-use gglib_proxy::{CouncilDeps, CouncilRunParams, CouncilRunnerPort};
+use gglib_proxy::{AccessPolicy, CouncilDeps, CouncilRunParams, CouncilRunnerPort};
 use tokio::sync::{mpsc, oneshot};
 
+mod fixtures;
+
 // ─── Mock ports (trimmed to the bare minimum needed to boot `serve`) ──────
 
 #[derive(Debug)]
@@ -181,6 +184,10 @@ impl McpServerRepository for EmptyMcpRepo {
 /// needed — these tests only exercise `/v1/proxy/status`, which doesn't
 /// touch the runtime/catalog ports). Returns `(proxy_base_url, cancel)`.
 async fn spawn_proxy() -> (String, CancellationToken) {
+    spawn_proxy_with(AccessPolicy::default()).await
+}
+
+async fn spawn_proxy_with(access: AccessPolicy) -> (String, CancellationToken) {
     let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
     let addr = listener.local_addr().unwrap();
 
@@ -203,11 +210,16 @@ async fn spawn_proxy() -> (String, CancellationToken) {
             make_orchestrator_deps(),
             cancel_clone,
             Arc::new(MockSettingsRepo),
+            Arc::new(fixtures::common::MockPresetRepo::default()),
             None, // inference_override
             false,
             None,
             gglib_proxy::slot_eviction::DiskBudget::Auto,
             std::sync::Arc::new(gglib_core::cache_metrics::CacheMetricsStore::new()),
+            None,
+            gglib_proxy::MiddlewareChain::default(),
+            access,
+            None,
         )
         .await
         .ok();
@@ -304,3 +316,92 @@ async fn get_request_from_vite_dev_origin_receives_cors_header() {
 
     cancel.cancel();
 }
+
+/// With an allowlist, listed origins are echoed back and others get no
+/// `access-control-allow-origin`, so browsers block them.
+#[tokio::test]
+async fn allowlist_admits_only_listed_origins() {
+    let (base_url, cancel) = spawn_proxy_with(AccessPolicy {
+        allowed_origins: Some(vec!["http://localhost:3000".into()]),
+        ..AccessPolicy::default()
+    })
+    .await;
+
+    let get_from = |origin: &'static str| {
+        Client::new()
+            .get(format!("{base_url}/v1/proxy/status"))
+            .header("Origin", origin)
+            .send()
+    };
+
+    let listed = get_from("http://localhost:3000").await.unwrap();
+    assert_eq!(
+        listed.headers()["access-control-allow-origin"],
+        "http://localhost:3000"
+    );
+    let other = get_from("http://evil.example").await.unwrap();
+    assert!(!other.headers().contains_key("access-control-allow-origin"));
+
+    cancel.cancel();
+}
+
+/// With an API key, requests need `Authorization: Bearer <key>` (or a
+/// background key); health probes and CORS preflights do not.
+#[tokio::test]
+async fn api_key_is_required_except_for_health_and_preflight() {
+    let (base_url, cancel) = spawn_proxy_with(AccessPolicy {
+        allowed_origins: None,
+        api_key: Some("sk-test".into()),
+        background_api_keys: vec!["sk-batch".into()],
+    })
+    .await;
+    let client = Client::new();
+    let status_url = format!("{base_url}/v1/proxy/status");
+
+    let missing = client.get(&status_url).send().await.unwrap();
+    assert_eq!(missing.status(), reqwest::StatusCode::UNAUTHORIZED);
+    let body: serde_json::Value = missing.json().await.unwrap();
+    assert_eq!(body["error"]["code"], "invalid_api_key");
+
+    let wrong = client
+        .get(&status_url)
+        .bearer_auth("sk-other")
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(wrong.status(), reqwest::StatusCode::UNAUTHORIZED);
+
+    let right = client
+        .get(&status_url)
+        .bearer_auth("sk-test")
+        .send()
+        .await
+        .unwrap();
+    assert!(right.status().is_success());
+
+    let background = client
+        .get(&status_url)
+        .bearer_auth("sk-batch")
+        .send()
+        .await
+        .unwrap();
+    assert!(background.status().is_success());
+
+    let health = client
+        .get(format!("{base_url}/health"))
+        .send()
+        .await
+        .unwrap();
+    assert!(health.status().is_success());
+
+    let preflight = client
+        .request(reqwest::Method::OPTIONS, &status_url)
+        .header("Origin", "http://localhost:3000")
+        .header("Access-Control-Request-Method", "GET")
+        .send()
+        .await
+        .unwrap();
+    assert!(preflight.status().is_success());
+
+    cancel.cancel();
+}
@@ -19,7 +19,7 @@ use tokio::net::TcpListener;
 use tokio_util::sync::CancellationToken;
 
 use gglib_core::Settings;
-use gglib_core::domain::{InferenceConfig, InferenceProfile};
+use gglib_core::domain::{InferenceConfig, InferenceProfile, Preset};
 use gglib_core::ports::{
     CatalogError, ModelCatalogPort, ModelLaunchSpec, ModelRuntimeError, ModelRuntimePort,
     ModelSummary, RepositoryError, RunningTarget, SettingsRepository,
@@ -28,7 +28,7 @@ use gglib_core::{McpRepositoryError, McpServer, McpServerRepository, NewMcpServe
 use gglib_mcp::McpService;
 
 mod fixtures;
-use fixtures::common::make_orchestrator_deps;
+use fixtures::common::{MockPresetRepo, make_orchestrator_deps};
 
 const MODEL: &str = "qwen";
 
@@ -210,6 +210,15 @@ async fn spawn(
     profiles: Vec<InferenceProfile>,
     catalog_names: &[&str],
     model_defaults: Option<InferenceConfig>,
+) -> Harness {
+    spawn_with_presets(profiles, vec![], catalog_names, model_defaults).await
+}
+
+async fn spawn_with_presets(
+    profiles: Vec<InferenceProfile>,
+    presets: Vec<Preset>,
+    catalog_names: &[&str],
+    model_defaults: Option<InferenceConfig>,
 ) -> Harness {
     let forwarded: Arc<Mutex<Vec<Value>>> = Arc::new(Mutex::new(Vec::new()));
     let cancel = CancellationToken::new();
@@ -274,11 +283,16 @@ async fn spawn(
             make_orchestrator_deps(),
             proxy_cancel,
             Arc::new(ProfileSettings { profiles }),
+            Arc::new(MockPresetRepo { presets }),
             None, // inference_override
             false,
             None,
             gglib_proxy::slot_eviction::DiskBudget::Auto,
             std::sync::Arc::new(gglib_core::cache_metrics::CacheMetricsStore::new()),
+            None,
+            gglib_proxy::MiddlewareChain::default(),
+            gglib_proxy::AccessPolicy::default(),
+            None,
         )
         .await
         .ok();
@@ -558,3 +572,77 @@ async fn unlisted_profiles_do_not_appear_in_the_model_list() {
         "unlisted profile leaked into {ids:?}"
     );
 }
+
+// ─── Presets ───────────────────────────────────────────────────────────────
+
+fn persona_preset() -> Preset {
+    Preset {
+        id: 1,
+        name: "persona".to_owned(),
+        description: None,
+        system_prompt: Some("You are a pirate.".to_owned()),
+        sampling: InferenceConfig {
+            temperature: Some(0.9),
+            top_k: Some(7),
+            ..Default::default()
+        },
+        tools: vec![],
+        mcp_server_ids: vec![],
+        default_model: Some(MODEL.to_owned()),
+        created_at: String::new(),
+        updated_at: String::new(),
+    }
+}
+
+#[tokio::test]
+async fn preset_header_applies_prompt_sampling_and_default_model() {
+    let h = spawn_with_presets(vec![], vec![persona_preset()], &[MODEL], None).await;
+
+    let resp = Client::new()
+        .post(format!("{}/v1/chat/completions", h.proxy_url))
+        .header("x-gglib-preset", "persona")
+        .json(&chat_request(""))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), 200);
+
+    let body = h.only_forwarded();
+    assert_eq!(body["messages"][0]["role"], "system");
+    assert_eq!(body["messages"][0]["content"], "You are a pirate.");
+    assert_param(&body, "temperature", 0.9);
+    assert_param(&body, "top_k", 7.0);
+    assert_eq!(*h.launched.lock().unwrap(), [MODEL]);
+}
+
+#[tokio::test]
+async fn profile_outranks_preset_sampling() {
+    let h = spawn_with_presets(
+        vec![coding_profile()],
+        vec![persona_preset()],
+        &[MODEL],
+        None,
+    )
+    .await;
+
+    let mut request = chat_request(&format!("{MODEL}:coding"));
+    request["preset"] = json!("persona");
+    assert_eq!(h.post(request).await.status(), 200);
+
+    let body = h.only_forwarded();
+    assert!(body.get("preset").is_none(), "selector must be stripped");
+    assert_param(&body, "temperature", 0.2);
+}
+
+#[tokio::test]
+async fn unknown_preset_is_404_and_never_forwarded() {
+    let h = spawn_with_presets(vec![], vec![persona_preset()], &[MODEL], None).await;
+
+    let mut request = chat_request(MODEL);
+    request["preset"] = json!("missing");
+    let resp = h.post(request).await;
+    assert_eq!(resp.status(), 404);
+    let err: Value = resp.json().await.unwrap();
+    assert_eq!(err["error"]["code"], "preset_not_found");
+    assert!(h.forwarded.lock().unwrap().is_empty());
+}
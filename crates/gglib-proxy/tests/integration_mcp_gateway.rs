@@ -26,6 +26,8 @@ use gglib_mcp::McpService;
 use gglib_proxy::{CouncilDeps, CouncilRunParams, CouncilRunnerPort};
 use tokio::sync::{mpsc, oneshot};
 
+mod fixtures;
+
 #[derive(Debug)]
 struct NoopRunner;
 #[async_trait::async_trait]
@@ -206,11 +208,16 @@ async fn start_proxy() -> (String, CancellationToken) {
             make_orchestrator_deps(),
             cancel_clone,
             Arc::new(MockSettingsRepo),
+            Arc::new(fixtures::common::MockPresetRepo::default()),
             None, // inference_override
             false,
             None,
             gglib_proxy::slot_eviction::DiskBudget::Auto,
             std::sync::Arc::new(gglib_core::cache_metrics::CacheMetricsStore::new()),
+            None,
+            gglib_proxy::MiddlewareChain::default(),
+            gglib_proxy::AccessPolicy::default(),
+            None,
         )
         .await
         .ok();
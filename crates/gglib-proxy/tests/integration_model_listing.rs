@@ -0,0 +1,203 @@
+//! End-to-end tests for `GET /v1/models` purpose filtering.
+//!
+//! Embedding and reranker models must stay out of the default (chat) listing,
+//! and the `?type=` listings must contain only the requested family — no
+//! profile variants, no council virtual models. The proxy is the real
+//! `gglib_proxy::serve` with mock ports.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use reqwest::Client;
+use serde_json::Value;
+use tokio::net::TcpListener;
+use tokio_util::sync::CancellationToken;
+
+use gglib_core::Settings;
+use gglib_core::domain::{InferenceConfig, InferenceProfile, ModelCapabilities};
+use gglib_core::ports::{
+    CatalogError, ModelCatalogPort, ModelLaunchSpec, ModelSummary, RepositoryError,
+    SettingsRepository,
+};
+
+mod fixtures;
+use fixtures::common::{NoopRuntime, make_mcp_service, make_orchestrator_deps};
+
+const CHAT: &str = "qwen";
+const EMBED: &str = "bge-small";
+const RERANK: &str = "bge-reranker";
+
+// ─── Mock ports ────────────────────────────────────────────────────────────
+
+/// Catalog holding one chat, one embedding and one reranker model.
+#[derive(Debug)]
+struct MixedCatalog;
+
+fn summary(id: u32, name: &str, capabilities: ModelCapabilities) -> ModelSummary {
+    ModelSummary {
+        id,
+        name: name.to_owned(),
+        tags: Vec::new(),
+        capabilities,
+        param_count: "1B".into(),
+        quantization: None,
+        architecture: None,
+        created_at: 0,
+        file_size: 0,
+        context_length: None,
+        inference_defaults: None,
+        server_defaults: None,
+    }
+}
+
+#[async_trait]
+impl ModelCatalogPort for MixedCatalog {
+    async fn list_models(&self) -> Result<Vec<ModelSummary>, CatalogError> {
+        Ok(vec![
+            summary(1, CHAT, ModelCapabilities::SUPPORTS_TOOL_CALLS),
+            summary(2, EMBED, ModelCapabilities::EMBEDDING),
+            summary(3, RERANK, ModelCapabilities::RERANKER),
+        ])
+    }
+    async fn resolve_model(&self, _name: &str) -> Result<Option<ModelSummary>, CatalogError> {
+        Ok(None)
+    }
+    async fn resolve_for_launch(
+        &self,
+        _name: &str,
+    ) -> Result<Option<ModelLaunchSpec>, CatalogError> {
+        Ok(None)
+    }
+}
+
+/// Settings with one profile opted into the model list.
+struct ListedProfileSettings;
+
+#[async_trait]
+impl SettingsRepository for ListedProfileSettings {
+    async fn load(&self) -> Result<Settings, RepositoryError> {
+        Ok(Settings {
+            inference_profiles: Some(vec![InferenceProfile {
+                name: "coding".to_owned(),
+                description: None,
+                config: InferenceConfig {
+                    temperature: Some(0.2),
+                    ..Default::default()
+                },
+                list_in_models: true,
+            }]),
+            ..Settings::with_defaults()
+        })
+    }
+    async fn save(&self, _: &Settings) -> Result<(), RepositoryError> {
+        Ok(())
+    }
+}
+
+// ─── Harness ───────────────────────────────────────────────────────────────
+
+async fn spawn_proxy() -> (String, CancellationToken) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let cancel = CancellationToken::new();
+    let proxy_cancel = cancel.clone();
+    tokio::spawn(async move {
+        gglib_proxy::serve(
+            listener,
+            4096,
+            Arc::new(NoopRuntime),
+            Arc::new(MixedCatalog),
+            make_mcp_service(),
+            make_orchestrator_deps(),
+            proxy_cancel,
+            Arc::new(ListedProfileSettings),
+            Arc::new(fixtures::common::MockPresetRepo::default()),
+            None,
+            false,
+            None,
+            gglib_proxy::slot_eviction::DiskBudget::Auto,
+            Arc::new(gglib_core::cache_metrics::CacheMetricsStore::new()),
+            None,
+            gglib_proxy::MiddlewareChain::default(),
+            gglib_proxy::AccessPolicy::default(),
+            None,
+        )
+        .await
+        .ok();
+    });
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    (format!("http://{addr}"), cancel)
+}
+
+async fn list_ids(base: &str, query: &str) -> Vec<String> {
+    let body: Value = Client::new()
+        .get(format!("{base}/v1/models{query}"))
+        .send()
+        .await
+        .expect("request reaches the proxy")
+        .json()
+        .await
+        .expect("model list is JSON");
+    body["data"]
+        .as_array()
+        .expect("data array")
+        .iter()
+        .filter_map(|m| m["id"].as_str().map(str::to_owned))
+        .collect()
+}
+
+// ─── Tests ─────────────────────────────────────────────────────────────────
+
+/// The default listing is what chat clients see: no embedders or rerankers,
+/// but profile variants and council entries are still appended.
+#[tokio::test]
+async fn default_listing_excludes_embedding_and_reranker_models() {
+    let (base, _cancel) = spawn_proxy().await;
+    let ids = list_ids(&base, "").await;
+
+    assert!(
+        ids.contains(&CHAT.to_owned()),
+        "chat model missing: {ids:?}"
+    );
+    assert!(
+        ids.contains(&"qwen:coding".to_owned()),
+        "variant missing: {ids:?}"
+    );
+    assert!(ids.iter().any(|id| id.starts_with("gglib-council")));
+    assert!(!ids.contains(&EMBED.to_owned()), "embedder leaked: {ids:?}");
+    assert!(
+        !ids.contains(&RERANK.to_owned()),
+        "reranker leaked: {ids:?}"
+    );
+}
+
+/// `?type=embedding` lists only embedding models — no variants, no council.
+#[tokio::test]
+async fn embedding_listing_contains_only_embedding_models() {
+    let (base, _cancel) = spawn_proxy().await;
+    assert_eq!(list_ids(&base, "?type=embedding").await, vec![EMBED]);
+}
+
+/// `?type=reranker` lists only reranker models.
+#[tokio::test]
+async fn reranker_listing_contains_only_reranker_models() {
+    let (base, _cancel) = spawn_proxy().await;
+    assert_eq!(list_ids(&base, "?type=reranker").await, vec![RERANK]);
+}
+
+/// An unknown `type` is rejected with the proxy's OpenAI-style error body.
+#[tokio::test]
+async fn unknown_listing_type_returns_openai_error() {
+    let (base, _cancel) = spawn_proxy().await;
+    let resp = Client::new()
+        .get(format!("{base}/v1/models?type=vision"))
+        .send()
+        .await
+        .expect("request reaches the proxy");
+
+    assert_eq!(resp.status(), 400);
+    let body: Value = resp.json().await.expect("error body is JSON");
+    assert_eq!(body["error"]["type"], "invalid_request_error");
+    assert_eq!(body["error"]["code"], "invalid_request");
+}
@@ -124,7 +124,7 @@ impl SettingsRepository for MockSettingsRepo {
 mod fixtures;
 use fixtures::sse::{
     BASIC_TEXT, MALFORMED_JSON_RECOVERY, QWEN_XML_TOOL_CALL, REASONING_DEEPSEEK, REASONING_ONLY,
-    STANDARD_OPENAI_TOOL_CALL, basic_text_split_chunks,
+    STANDARD_OPENAI_TOOL_CALL, TEXT_WITH_USAGE, basic_text_split_chunks,
 };
 
 // ─── Mock ports ────────────────────────────────────────────────────────────
@@ -323,11 +323,16 @@ async fn spawn_proxy(
             make_orchestrator_deps(),
             cancel_clone,
             Arc::new(MockSettingsRepo),
+            Arc::new(fixtures::common::MockPresetRepo::default()),
             None, // inference_override
             false,
             None,
             gglib_proxy::slot_eviction::DiskBudget::Auto,
             std::sync::Arc::new(gglib_core::cache_metrics::CacheMetricsStore::new()),
+            None,
+            gglib_proxy::MiddlewareChain::default(),
+            gglib_proxy::AccessPolicy::default(),
+            None,
         )
         .await
         .ok();
@@ -345,6 +350,21 @@ async fn round_trip(
     upstream_chunks: Vec<&'static [u8]>,
     model_name: &str,
     tags: Vec<String>,
+) -> String {
+    let request = json!({
+        "model": model_name,
+        "stream": true,
+        "messages": [{"role": "user", "content": "hi"}],
+    });
+    round_trip_request(upstream_chunks, model_name, tags, &request).await
+}
+
+/// [`round_trip`] with a caller-built request body.
+async fn round_trip_request(
+    upstream_chunks: Vec<&'static [u8]>,
+    model_name: &str,
+    tags: Vec<String>,
+    request: &Value,
 ) -> String {
     let upstream_cancel = CancellationToken::new();
     let upstream_port = spawn_mock_upstream(upstream_chunks, upstream_cancel.clone()).await;
@@ -353,11 +373,7 @@ async fn round_trip(
     let client = Client::new();
     let resp = client
         .post(format!("{proxy_url}/v1/chat/completions"))
-        .json(&json!({
-            "model": model_name,
-            "stream": true,
-            "messages": [{"role": "user", "content": "hi"}],
-        }))
+        .json(request)
         .send()
         .await
         .expect("proxy request");
@@ -636,6 +652,65 @@ async fn standard_openai_tool_call_passthrough() {
     assert_eq!(parsed_args, json!({"city": "Paris"}));
 }
 
+/// A tool call whose arguments miss a property the request's schema requires
+/// still reaches the client, followed by a structured error frame ahead of the
+/// finish chunk.
+#[tokio::test]
+async fn tool_call_violating_the_declared_schema_is_reported() {
+    let request = json!({
+        "model": "strict-openai",
+        "stream": true,
+        "messages": [{"role": "user", "content": "hi"}],
+        "tools": [{
+            "type": "function",
+            "function": {
+                "name": "get_weather",
+                "parameters": {
+                    "type": "object",
+                    "properties": {
+                        "city": { "type": "string" },
+                        "days": { "type": "integer" }
+                    },
+                    "required": ["city", "days"]
+                }
+            }
+        }],
+    });
+    let body = round_trip_request(
+        vec![STANDARD_OPENAI_TOOL_CALL],
+        "strict-openai",
+        vec![],
+        &request,
+    )
+    .await;
+    let (frames, saw_done) = parse_frames(&body);
+    assert!(saw_done);
+
+    let error_at = frames
+        .iter()
+        .position(|f| f["error"].is_object())
+        .expect("missing tool-call error frame");
+    let error = &frames[error_at]["error"];
+    assert_eq!(error["code"], "tool_arguments_invalid");
+    assert_eq!(error["tool_call"]["id"], "call_abc");
+    assert_eq!(
+        error["violations"],
+        json!([{"path": "/days", "message": "is required"}])
+    );
+
+    let finish_at = frames
+        .iter()
+        .position(|f| f["choices"][0]["finish_reason"] == "tool_calls")
+        .expect("missing tool_calls finish chunk");
+    assert!(error_at < finish_at, "error must precede the finish chunk");
+    assert!(
+        frames[..error_at]
+            .iter()
+            .any(|f| f["choices"][0]["delta"]["tool_calls"].is_array()),
+        "the call itself is still forwarded"
+    );
+}
+
 /// SSE frames split across arbitrary byte boundaries must reassemble inside
 /// `SseStreamDecoder` and produce the same content as if they had arrived
 /// whole.
@@ -690,3 +765,43 @@ async fn malformed_json_terminates_cleanly() {
         "error frame missing message"
     );
 }
+
+/// The upstream's usage totals arrive as the last chunk before `[DONE]`, in
+/// the `stream_options.include_usage` shape, with the proxy's own timing
+/// figures under `x_gglib`.
+#[tokio::test]
+async fn usage_chunk_is_last_and_carries_timings() {
+    let body = round_trip(vec![TEXT_WITH_USAGE], "usage-model", vec![]).await;
+    let (frames, saw_done) = parse_frames(&body);
+    assert!(saw_done);
+    assert_canonical_envelope(&frames, "usage-model");
+
+    let usage = frames.last().expect("frames");
+    assert!(
+        usage["choices"].as_array().is_some_and(Vec::is_empty),
+        "usage chunk must be last, got {usage}"
+    );
+    assert_eq!(
+        usage["usage"],
+        json!({
+            "prompt_tokens": 12,
+            "completion_tokens": 3,
+            "total_tokens": 15,
+            "prompt_tokens_details": { "cached_tokens": 8 },
+        })
+    );
+    assert_eq!(
+        frames.iter().filter(|f| f.get("usage").is_some()).count(),
+        1,
+        "exactly one usage chunk"
+    );
+    assert!(
+        usage.get("timings").is_none(),
+        "llama.cpp's own timings are not forwarded"
+    );
+
+    let timings = &usage["x_gglib"];
+    assert!(timings["queue_ms"].is_u64(), "{timings}");
+    assert!(timings["ttft_ms"].is_u64(), "{timings}");
+    assert!(timings["generation_ms"].is_u64(), "{timings}");
+}
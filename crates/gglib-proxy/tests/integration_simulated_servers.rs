@@ -0,0 +1,157 @@
+//! End-to-end proxy tests against simulated llama-servers.
+//!
+//! The runtime is `gglib-test-support`'s single-swap `SimulatedRuntime`:
+//! models come from an in-memory repository and each one is served by a
+//! simulated llama-server on loopback, so these tests cover the proxy's
+//! model resolution, launch and swap path without a llama binary.
+
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use chrono::Utc;
+use reqwest::Client;
+use serde_json::{Value, json};
+use tokio::net::TcpListener;
+use tokio_util::sync::CancellationToken;
+
+use gglib_core::domain::ModelCapabilities;
+use gglib_core::ports::{
+    CatalogError, ModelCatalogPort, ModelLaunchSpec, ModelRepository, ModelSummary, ProcessRunner,
+};
+use gglib_core::{Model, NewModel};
+use gglib_test_support::{Fakes, MemoryModelRepository};
+
+mod fixtures;
+use fixtures::common::{MockSettingsRepo, make_mcp_service, make_orchestrator_deps};
+
+const REPLY: &str = "Simulated answer.";
+
+/// Catalog reading the same in-memory models the runtime launches.
+#[derive(Debug)]
+struct RepoCatalog(Arc<MemoryModelRepository>);
+
+fn summary(model: &Model) -> ModelSummary {
+    ModelSummary {
+        id: u32::try_from(model.id).unwrap(),
+        name: model.name.clone(),
+        tags: model.tags.clone(),
+        capabilities: ModelCapabilities::empty(),
+        param_count: "8B".into(),
+        quantization: None,
+        architecture: None,
+        created_at: 0,
+        file_size: 0,
+        context_length: None,
+        inference_defaults: None,
+        server_defaults: None,
+    }
+}
+
+#[async_trait]
+impl ModelCatalogPort for RepoCatalog {
+    async fn list_models(&self) -> Result<Vec<ModelSummary>, CatalogError> {
+        let models = self.0.list().await.unwrap();
+        Ok(models.iter().map(summary).collect())
+    }
+    async fn resolve_model(&self, name: &str) -> Result<Option<ModelSummary>, CatalogError> {
+        Ok(self
+            .0
+            .get_by_identifier(name)
+            .await
+            .unwrap()
+            .as_ref()
+            .map(summary))
+    }
+    async fn resolve_for_launch(
+        &self,
+        _name: &str,
+    ) -> Result<Option<ModelLaunchSpec>, CatalogError> {
+        Ok(None)
+    }
+}
+
+async fn spawn_proxy(fakes: &Fakes) -> (String, CancellationToken) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let cancel = CancellationToken::new();
+    let proxy_cancel = cancel.clone();
+    let runtime = Arc::new(fakes.runtime());
+    let catalog = Arc::new(RepoCatalog(fakes.models.clone()));
+    tokio::spawn(async move {
+        gglib_proxy::serve(
+            listener,
+            4096,
+            runtime,
+            catalog,
+            make_mcp_service(),
+            make_orchestrator_deps(),
+            proxy_cancel,
+            Arc::new(MockSettingsRepo),
+            Arc::new(fixtures::common::MockPresetRepo::default()),
+            None,
+            false,
+            None,
+            gglib_proxy::slot_eviction::DiskBudget::Auto,
+            Arc::new(gglib_core::cache_metrics::CacheMetricsStore::new()),
+            None,
+            gglib_proxy::MiddlewareChain::default(),
+            gglib_proxy::AccessPolicy::default(),
+            None,
+        )
+        .await
+        .ok();
+    });
+    tokio::time::sleep(Duration::from_millis(30)).await;
+    (format!("http://{addr}"), cancel)
+}
+
+async fn chat(proxy: &str, model: &str) -> Value {
+    let response = Client::new()
+        .post(format!("{proxy}/v1/chat/completions"))
+        .json(&json!({
+            "model": model,
+            "messages": [{"role": "user", "content": "hi"}],
+        }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), 200);
+    response.json().await.unwrap()
+}
+
+#[tokio::test]
+async fn requests_launch_and_swap_simulated_servers() {
+    let fakes = Fakes {
+        runner: Arc::new(gglib_test_support::SimulatedProcessRunner::with_reply(
+            REPLY,
+        )),
+        ..Fakes::new()
+    };
+    let mut ids = Vec::new();
+    for name in ["qwen3", "gemma3"] {
+        let model = NewModel::new(
+            name.to_string(),
+            PathBuf::from(format!("/models/{name}.gguf")),
+            8.0,
+            Utc::now(),
+        );
+        ids.push(fakes.models.insert(&model).await.unwrap().id);
+    }
+    let (proxy, cancel) = spawn_proxy(&fakes).await;
+
+    let answer = chat(&proxy, "qwen3").await;
+    assert_eq!(answer["choices"][0]["message"]["content"], REPLY);
+    chat(&proxy, "qwen3").await;
+    assert_eq!(fakes.runner.completions(ids[0]), 2);
+    assert_eq!(fakes.runner.starts().len(), 1);
+
+    chat(&proxy, "gemma3").await;
+    let running = fakes.runner.list_running().await.unwrap();
+    assert_eq!(running.len(), 1);
+    assert_eq!(running[0].model_id, ids[1]);
+    assert_eq!(fakes.runner.starts().len(), 2);
+
+    cancel.cancel();
+}
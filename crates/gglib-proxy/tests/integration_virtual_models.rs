@@ -39,6 +39,8 @@ use gglib_core::{McpRepositoryError, McpServer, McpServerRepository, NewMcpServe
 use gglib_mcp::McpService;
 use gglib_proxy::{CouncilDeps, CouncilRunParams, CouncilRunnerPort};
 
+mod fixtures;
+
 // =============================================================================
 // Minimal mock ports (runtime / catalog / MCP)
 // =============================================================================
@@ -236,11 +238,16 @@ async fn spawn_proxy_with(runner: Arc<dyn CouncilRunnerPort>) -> (String, Cancel
             orchestrator,
             cancel_clone,
             Arc::new(MockSettingsRepo),
+            Arc::new(fixtures::common::MockPresetRepo::default()),
             None, // inference_override
             false,
             None,
             gglib_proxy::slot_eviction::DiskBudget::Auto,
             std::sync::Arc::new(gglib_core::cache_metrics::CacheMetricsStore::new()),
+            None,
+            gglib_proxy::MiddlewareChain::default(),
+            gglib_proxy::AccessPolicy::default(),
+            None,
         )
         .await
         .ok();
@@ -4,6 +4,7 @@
 //! capturing stdout/stderr output.
 
 use crate::llama::{LlamaServerError, resolve_llama_server};
+use crate::process::shutdown::bind_to_parent_lifetime;
 use crate::process::spawn_stream_reader;
 use crate::system::is_truthy_flag;
 use gglib_core::ports::{ServerConfig, ServerLogSinkPort};
@@ -154,6 +155,7 @@ pub fn build_and_spawn(
     let child = cmd
         .spawn()
         .map_err(|e| anyhow::anyhow!("Failed to spawn llama-server: {}", e))?;
+    bind_to_parent_lifetime(&child);
 
     Ok(child)
 }
@@ -174,7 +176,8 @@ fn build_command(validated_path: &Path, config: &ServerConfig, port: u16) -> std
         .arg(port.to_string())
         .arg("--metrics");
 
-    // Serialize concurrent requests onto a single slot.
+    // Serialize concurrent requests onto a single slot unless more were
+    // asked for.
     //
     // Recent llama.cpp builds default to `--parallel 4` with a *unified* KV
     // cache: the `-c` context tokens become a single pool shared across all 4
@@ -184,15 +187,31 @@ fn build_command(validated_path: &Path, config: &ServerConfig, port: u16) -> std
     // each individually fits — llama-server then aborts BOTH with
     // "Context size has been exceeded", surfacing as an empty response.
     //
-    // Forcing a single slot gives every request the full `-c` context
-    // exclusively; a second concurrent request queues inside llama-server
-    // until the slot frees, which the proxy's streaming keepalive path is
-    // built to wait out.
-    cmd.arg("--parallel").arg("1");
+    // A single slot gives every request the full `-c` context exclusively; a
+    // second concurrent request queues inside llama-server until the slot
+    // frees, which the proxy's streaming keepalive path is built to wait out.
+    // With `parallel = n` the pool is sized `n` times the context instead, so
+    // `n` requests that each fit alone also fit together.
+    let slots = config.parallel.unwrap_or(1).max(1);
+    cmd.arg("--parallel").arg(slots.to_string());
+    if slots > 1 {
+        cmd.arg("--kv-unified");
+    }
 
     // Add context size if specified
     if let Some(ctx) = config.context_size {
-        cmd.arg("-c").arg(ctx.to_string());
+        cmd.arg("-c")
+            .arg(ctx.saturating_mul(u64::from(slots)).to_string());
+    }
+
+    match config.cont_batching {
+        Some(true) => {
+            cmd.arg("--cont-batching");
+        }
+        Some(false) => {
+            cmd.arg("--no-cont-batching");
+        }
+        None => {}
     }
 
     // Add GPU layers if specified
@@ -210,6 +229,13 @@ fn build_command(validated_path: &Path, config: &ServerConfig, port: u16) -> std
         cmd.arg("--reasoning-format").arg(format);
     }
 
+    // Add RoPE scaling overrides if specified
+    if let Some(ref rope) = config.rope {
+        for arg in rope.to_cli_args() {
+            cmd.arg(arg);
+        }
+    }
+
     // Add the KV cache disk slot-persistence flag if a slot-save directory is set.
     if let Some(ref slot_path) = config.slot_save_path {
         cmd.arg("--slot-save-path").arg(slot_path);
@@ -245,6 +271,39 @@ fn build_command(validated_path: &Path, config: &ServerConfig, port: u16) -> std
         cmd.arg("--cache-type-v").arg(t.as_llama_arg());
     }
 
+    // Threads and CPU placement. `--cpu-strict 1` keeps each thread on its
+    // own CPU of the mask instead of letting them float across it.
+    if let Some(n) = config.threads {
+        cmd.arg("--threads").arg(n.to_string());
+    }
+    if let Some(n) = config.threads_batch {
+        cmd.arg("--threads-batch").arg(n.to_string());
+    }
+    if let Some(ref cpus) = config.cpu_affinity {
+        cmd.arg("--cpu-mask").arg(cpus.to_hex_mask());
+        cmd.arg("--cpu-strict").arg("1");
+    }
+    if let Some(numa) = config.numa {
+        cmd.arg("--numa").arg(numa.as_llama_arg());
+    }
+
+    // Multi-GPU placement. Device numbers follow nvidia-smi's PCI bus order,
+    // which CUDA only uses when asked; an order the user set is kept.
+    if let Some(mode) = config.split_mode {
+        cmd.arg("--split-mode").arg(mode.as_llama_arg());
+    }
+    if let Some(ref split) = config.tensor_split {
+        cmd.arg("--tensor-split").arg(split.to_string());
+    }
+    if let Some(gpu) = config.main_gpu {
+        cmd.arg("--main-gpu").arg(gpu.to_string());
+    }
+    if (config.tensor_split.is_some() || config.main_gpu.is_some())
+        && std::env::var_os("CUDA_DEVICE_ORDER").is_none()
+    {
+        cmd.env("CUDA_DEVICE_ORDER", "PCI_BUS_ID");
+    }
+
     // Add MTP speculative decoding flags if enabled
     //
     // A global kill switch — the `GGLIB_DISABLE_MTP` environment variable set
@@ -347,12 +406,22 @@ mod tests {
             spec_draft_n_max: None,
             spec_draft_p_min: None,
             inference_config: None,
+            rope: None,
             extra_args: vec![],
             slot_save_path: None,
             cache_ram_mb: None,
             cache_reuse: None,
             cache_type_k: None,
             cache_type_v: None,
+            threads: None,
+            threads_batch: None,
+            cpu_affinity: None,
+            numa: None,
+            split_mode: None,
+            tensor_split: None,
+            main_gpu: None,
+            parallel: None,
+            cont_batching: None,
         }
     }
 
@@ -374,6 +443,42 @@ mod tests {
         assert!(!args.contains(&"--slot-save-path".to_string()));
     }
 
+    #[test]
+    fn parallel_slots_scale_the_context_pool() {
+        let flag_value = |args: &[String], flag: &str| {
+            let idx = args.iter().position(|a| a == flag).unwrap();
+            args[idx + 1].clone()
+        };
+
+        let serial = args_of(&build_command(
+            Path::new("/fake/llama-server"),
+            &ServerConfig {
+                context_size: Some(8192),
+                ..minimal_config()
+            },
+            5500,
+        ));
+        assert_eq!(flag_value(&serial, "--parallel"), "1");
+        assert_eq!(flag_value(&serial, "-c"), "8192");
+        assert!(!serial.contains(&"--kv-unified".to_string()));
+        assert!(!serial.iter().any(|a| a.contains("cont-batching")));
+
+        let parallel = args_of(&build_command(
+            Path::new("/fake/llama-server"),
+            &ServerConfig {
+                context_size: Some(8192),
+                parallel: Some(3),
+                cont_batching: Some(false),
+                ..minimal_config()
+            },
+            5500,
+        ));
+        assert_eq!(flag_value(&parallel, "--parallel"), "3");
+        assert_eq!(flag_value(&parallel, "-c"), "24576");
+        assert!(parallel.contains(&"--kv-unified".to_string()));
+        assert!(parallel.contains(&"--no-cont-batching".to_string()));
+    }
+
     #[test]
     fn cache_ram_mb_emits_flag_without_slot_save_path() {
         let config = ServerConfig {
@@ -420,6 +525,31 @@ mod tests {
         assert_eq!(args[idx + 1], "8000");
     }
 
+    #[test]
+    fn rope_overrides_emit_flags_when_set() {
+        let config = ServerConfig {
+            rope: Some(gglib_core::domain::RopeConfig {
+                scaling: Some(gglib_core::domain::RopeScalingType::Yarn),
+                yarn_orig_ctx: Some(32_768),
+                ..Default::default()
+            }),
+            ..minimal_config()
+        };
+        let cmd = build_command(Path::new("/fake/llama-server"), &config, 5500);
+        let args = args_of(&cmd);
+        let idx = args
+            .iter()
+            .position(|a| a == "--rope-scaling")
+            .expect("--rope-scaling should be present");
+        assert_eq!(args[idx + 1], "yarn");
+        let idx = args
+            .iter()
+            .position(|a| a == "--yarn-orig-ctx")
+            .expect("--yarn-orig-ctx should be present");
+        assert_eq!(args[idx + 1], "32768");
+        assert!(!args.iter().any(|a| a == "--rope-freq-base"));
+    }
+
     #[test]
     fn cache_reuse_emits_flag_when_set() {
         let config = ServerConfig {
@@ -465,6 +595,75 @@ mod tests {
         assert_eq!(args[v_idx + 1], "f16");
     }
 
+    #[test]
+    fn cpu_flags_omitted_by_default() {
+        let config = minimal_config();
+        let cmd = build_command(Path::new("/fake/llama-server"), &config, 5500);
+        let args = args_of(&cmd);
+        for flag in ["--threads", "--threads-batch", "--cpu-mask", "--numa"] {
+            assert!(!args.contains(&flag.to_string()), "{flag} should be absent");
+        }
+    }
+
+    #[test]
+    fn cpu_flags_emit_threads_mask_and_numa() {
+        let config = ServerConfig {
+            threads: Some(6),
+            threads_batch: Some(12),
+            cpu_affinity: Some("0-11".parse().unwrap()),
+            numa: Some(gglib_core::cpu_config::NumaStrategy::Isolate),
+            ..minimal_config()
+        };
+        let cmd = build_command(Path::new("/fake/llama-server"), &config, 5500);
+        let args = args_of(&cmd);
+        let value_of = |flag: &str| {
+            let idx = args
+                .iter()
+                .position(|a| a == flag)
+                .unwrap_or_else(|| panic!("{flag} should be present"));
+            args[idx + 1].clone()
+        };
+        assert_eq!(value_of("--threads"), "6");
+        assert_eq!(value_of("--threads-batch"), "12");
+        assert_eq!(value_of("--cpu-mask"), "0xfff");
+        assert_eq!(value_of("--cpu-strict"), "1");
+        assert_eq!(value_of("--numa"), "isolate");
+    }
+
+    #[test]
+    fn gpu_flags_emit_split_mode_tensor_split_and_main_gpu() {
+        let config = ServerConfig {
+            split_mode: Some(gglib_core::gpu_config::SplitMode::Row),
+            tensor_split: Some("3,1".parse().unwrap()),
+            main_gpu: Some(1),
+            ..minimal_config()
+        };
+        let cmd = build_command(Path::new("/fake/llama-server"), &config, 5500);
+        let args = args_of(&cmd);
+        let value_of = |flag: &str| {
+            let idx = args
+                .iter()
+                .position(|a| a == flag)
+                .unwrap_or_else(|| panic!("{flag} should be present"));
+            args[idx + 1].clone()
+        };
+        assert_eq!(value_of("--split-mode"), "row");
+        assert_eq!(value_of("--tensor-split"), "3,1");
+        assert_eq!(value_of("--main-gpu"), "1");
+
+        let default_args = args_of(&build_command(
+            Path::new("/fake/llama-server"),
+            &minimal_config(),
+            5500,
+        ));
+        for flag in ["--split-mode", "--tensor-split", "--main-gpu"] {
+            assert!(
+                !default_args.contains(&flag.to_string()),
+                "{flag} should be absent"
+            );
+        }
+    }
+
     /// Test that a valid bootstrap path is used directly.
     #[test]
     #[cfg(unix)]
@@ -523,12 +722,22 @@ mod tests {
             spec_draft_n_max: None,
             spec_draft_p_min: None,
             inference_config: None,
+            rope: None,
             extra_args: vec![],
             slot_save_path: None,
             cache_ram_mb: None,
             cache_reuse: None,
             cache_type_k: None,
             cache_type_v: None,
+            threads: None,
+            threads_batch: None,
+            cpu_affinity: None,
+            numa: None,
+            split_mode: None,
+            tensor_split: None,
+            main_gpu: None,
+            parallel: None,
+            cont_batching: None,
         };
 
         // Should use the bootstrap path (will spawn then immediately exit)
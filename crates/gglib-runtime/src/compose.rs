@@ -25,11 +25,16 @@ use std::collections::HashSet;
 use std::path::PathBuf;
 use std::sync::Arc;
 
+use async_trait::async_trait;
 use gglib_agent::AgentLoop;
-use gglib_core::domain::InferenceConfig;
-use gglib_core::ports::{AgentLoopPort, CacheMetricsSink, LlmCompletionPort, ToolExecutorPort};
-use gglib_core::request_pipeline::ModelContext;
-use gglib_mcp::{CombinedToolExecutor, McpService};
+use gglib_agent::pipeline::{PipelineModels, PipelineRunner};
+use gglib_core::domain::{InferenceConfig, WebSearchConfig};
+use gglib_core::ports::{
+    AgentLoopPort, CacheMetricsSink, LlmCompletionPort, ModelCatalogPort, PipelineRunRepository,
+    ToolExecutorPort,
+};
+use gglib_core::request_pipeline::{self, ModelContext};
+use gglib_mcp::{CombinedToolExecutor, McpService, UrlFetcher};
 use reqwest::Client;
 
 use crate::LlmCompletionAdapter;
@@ -52,6 +57,11 @@ use crate::LlmCompletionAdapter;
 /// * `cache_metrics` — `Some(sink)` reports this loop's prompt-cache reuse
 ///   (e.g. the proxy process's agent-path store, for GUI chat); `None` when
 ///   there is no dashboard to report to.
+/// * `web_search` — the `web_search` setting; `Some` exposes the built-in
+///   `web_search` tool, sending queries through `http_client`.
+/// * `url_fetch` — `Some` exposes the built-in `fetch_url` tool, drawing on
+///   the fetcher's per-conversation allowance.
+#[allow(clippy::too_many_arguments)]
 pub fn compose_agent_loop(
     base_url: String,
     http_client: Client,
@@ -60,6 +70,8 @@ pub fn compose_agent_loop(
     mcp: Arc<McpService>,
     tool_filter: Option<HashSet<String>>,
     cache_metrics: Option<Arc<dyn CacheMetricsSink>>,
+    web_search: Option<WebSearchConfig>,
+    url_fetch: Option<UrlFetcher>,
 ) -> Arc<dyn AgentLoopPort> {
     compose_agent_loop_inner(
         base_url,
@@ -71,6 +83,8 @@ pub fn compose_agent_loop(
         None,
         None,
         cache_metrics,
+        web_search,
+        url_fetch,
     )
 }
 
@@ -86,6 +100,8 @@ pub fn compose_agent_loop_with_sampling(
     sandbox_root: Option<PathBuf>,
     sampling: Option<InferenceConfig>,
     cache_metrics: Option<Arc<dyn CacheMetricsSink>>,
+    web_search: Option<WebSearchConfig>,
+    url_fetch: Option<UrlFetcher>,
 ) -> Arc<dyn AgentLoopPort> {
     compose_agent_loop_inner(
         base_url,
@@ -97,6 +113,8 @@ pub fn compose_agent_loop_with_sampling(
         sandbox_root,
         sampling,
         cache_metrics,
+        web_search,
+        url_fetch,
     )
 }
 
@@ -121,7 +139,8 @@ pub struct CouncilPorts {
 /// defaults.
 ///
 /// When `sandbox_root` is `Some`, filesystem tools (`read_file`,
-/// `list_directory`, `grep_search`) are enabled and scoped to that path.
+/// `list_directory`, `grep_search`) and read-only git tools (`git_diff`,
+/// `git_log`, `git_show`, `git_blame`) are enabled and scoped to that path.
 ///
 /// `cache_metrics` reports every LLM call's prompt-cache reuse to a sink when
 /// the council runs in the proxy process; `None` when there is no dashboard.
@@ -142,13 +161,88 @@ pub fn compose_council_ports(
             .with_model_context(model_context)
             .with_cache_metrics_sink(cache_metrics),
     );
-    let tool_executor: Arc<dyn ToolExecutorPort> = match sandbox_root {
-        Some(root) => Arc::new(CombinedToolExecutor::with_sandbox(mcp, root)),
-        None => Arc::new(CombinedToolExecutor::new(mcp)),
-    };
+    let tool_executor: Arc<dyn ToolExecutorPort> = Arc::new(combined_tools(mcp, sandbox_root));
     CouncilPorts { llm, tool_executor }
 }
 
+/// [`PipelineModels`] for an OpenAI-compatible endpoint that routes by model.
+///
+/// Every step goes to the same `base_url` with its own `model` field, so
+/// pointed at the gglib proxy each step runs on the model it names (the proxy
+/// starts or swaps llama-server as needed). Pointed at a single llama-server,
+/// the model names are only used to resolve per-model request shaping.
+pub struct EndpointPipelineModels {
+    base_url: String,
+    http_client: Client,
+    catalog: Arc<dyn ModelCatalogPort>,
+    cache_metrics: Option<Arc<dyn CacheMetricsSink>>,
+}
+
+impl EndpointPipelineModels {
+    /// Create a model source for `base_url` (`http://host:port`).
+    pub fn new(
+        base_url: String,
+        http_client: Client,
+        catalog: Arc<dyn ModelCatalogPort>,
+        cache_metrics: Option<Arc<dyn CacheMetricsSink>>,
+    ) -> Self {
+        Self {
+            base_url,
+            http_client,
+            catalog,
+            cache_metrics,
+        }
+    }
+}
+
+#[async_trait]
+impl PipelineModels for EndpointPipelineModels {
+    async fn llm(
+        &self,
+        model: Option<&str>,
+        sampling: Option<InferenceConfig>,
+    ) -> anyhow::Result<Arc<dyn LlmCompletionPort>> {
+        let model_context = request_pipeline::resolve(self.catalog.as_ref(), model).await;
+        Ok(Arc::new(
+            LlmCompletionAdapter::with_client(
+                self.base_url.clone(),
+                self.http_client.clone(),
+                model.map(str::to_owned),
+            )
+            .with_sampling(sampling)
+            .with_model_context(model_context)
+            .with_cache_metrics_sink(self.cache_metrics.clone()),
+        ))
+    }
+}
+
+/// Compose a [`PipelineRunner`] whose tool steps use the built-in and MCP
+/// tools.
+///
+/// When `sandbox_root` is `Some`, the filesystem and read-only git tools are
+/// available to tool steps, scoped to that path.
+pub fn compose_pipeline_runner(
+    models: Arc<dyn PipelineModels>,
+    mcp: Arc<McpService>,
+    sandbox_root: Option<PathBuf>,
+    repo: Arc<dyn PipelineRunRepository>,
+) -> PipelineRunner {
+    let tool_executor: Arc<dyn ToolExecutorPort> = Arc::new(combined_tools(mcp, sandbox_root));
+    PipelineRunner::new(models, tool_executor, repo)
+}
+
+/// Built-in and MCP tools, plus any loaded WASM plugin tools.
+fn combined_tools(mcp: Arc<McpService>, sandbox_root: Option<PathBuf>) -> CombinedToolExecutor {
+    let executor = match sandbox_root {
+        Some(root) => CombinedToolExecutor::with_sandbox(mcp, root),
+        None => CombinedToolExecutor::new(mcp),
+    };
+    match crate::plugins::plugin_tools() {
+        Some(plugins) => executor.with_plugins(plugins),
+        None => executor,
+    }
+}
+
 #[allow(clippy::too_many_arguments)]
 fn compose_agent_loop_inner(
     base_url: String,
@@ -160,16 +254,22 @@ fn compose_agent_loop_inner(
     sandbox_root: Option<PathBuf>,
     sampling: Option<InferenceConfig>,
     cache_metrics: Option<Arc<dyn CacheMetricsSink>>,
+    web_search: Option<WebSearchConfig>,
+    url_fetch: Option<UrlFetcher>,
 ) -> Arc<dyn AgentLoopPort> {
+    let mut executor = combined_tools(mcp, sandbox_root);
+    if let Some(config) = web_search {
+        executor = executor.with_web_search(http_client.clone(), config);
+    }
+    if let Some(fetcher) = url_fetch {
+        executor = executor.with_url_fetch(fetcher);
+    }
+    let tool_executor: Arc<dyn ToolExecutorPort> = Arc::new(executor);
     let llm: Arc<dyn LlmCompletionPort> = Arc::new(
         LlmCompletionAdapter::with_client(base_url, http_client, model)
             .with_sampling(sampling)
             .with_model_context(model_context)
             .with_cache_metrics_sink(cache_metrics),
     );
-    let tool_executor: Arc<dyn ToolExecutorPort> = match sandbox_root {
-        Some(root) => Arc::new(CombinedToolExecutor::with_sandbox(mcp, root)),
-        None => Arc::new(CombinedToolExecutor::new(mcp)),
-    };
     AgentLoop::build(llm, tool_executor, tool_filter)
 }
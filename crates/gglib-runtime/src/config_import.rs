@@ -0,0 +1,466 @@
+//! Import model launch configurations from other local-LLM managers.
+//!
+//! [`parse_config`] reads a [llama-swap] `config.yaml` or an Ollama
+//! `Modelfile` and turns every model it describes into an
+//! [`ImportedLaunch`]: the GGUF file to add to the library plus the launch
+//! settings gglib keeps per model — [`ServerConfig`] server defaults and
+//! [`InferenceConfig`] sampling defaults.
+//!
+//! Only what gglib has a per-model setting for comes across. llama-server
+//! flags gglib derives itself (`--port`, GPU layers, cache types, …) and
+//! llama-swap features without an equivalent (aliases, groups, hooks) are
+//! listed in [`ImportedLaunch::ignored`] so the user can see what was left
+//! behind. Entries that name no local GGUF file — a `-hf` download or an
+//! Ollama registry model — are reported in [`ConfigImport::skipped`].
+//!
+//! [llama-swap]: https://github.com/mostlygeek/llama-swap
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use gglib_core::domain::{InferenceConfig, ServerConfig};
+use serde::Deserialize;
+use thiserror::Error;
+
+/// A configuration format [`parse_config`] reads.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfigFormat {
+    /// llama-swap `config.yaml`.
+    LlamaSwap,
+    /// Ollama `Modelfile`.
+    Modelfile,
+}
+
+impl ConfigFormat {
+    /// The name used on the command line.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::LlamaSwap => "llama-swap",
+            Self::Modelfile => "modelfile",
+        }
+    }
+
+    /// Guess the format from a file name: `.yaml`/`.yml` files are llama-swap
+    /// configs, anything else is read as a `Modelfile`.
+    #[must_use]
+    pub fn detect(path: &Path) -> Self {
+        match path.extension().and_then(|e| e.to_str()) {
+            Some(ext) if ext.eq_ignore_ascii_case("yaml") || ext.eq_ignore_ascii_case("yml") => {
+                Self::LlamaSwap
+            }
+            _ => Self::Modelfile,
+        }
+    }
+}
+
+impl fmt::Display for ConfigFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for ConfigFormat {
+    type Err = ConfigImportError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "llama-swap" | "llamaswap" => Ok(Self::LlamaSwap),
+            "modelfile" | "ollama" => Ok(Self::Modelfile),
+            other => Err(ConfigImportError::UnknownFormat(other.to_string())),
+        }
+    }
+}
+
+/// Errors reading a configuration file as a whole.
+#[derive(Debug, Error)]
+pub enum ConfigImportError {
+    #[error("Unknown config format '{0}' (expected llama-swap or modelfile)")]
+    UnknownFormat(String),
+
+    #[error("Invalid llama-swap config: {0}")]
+    InvalidYaml(String),
+
+    #[error("Invalid Modelfile line {line}: {message}")]
+    InvalidModelfile { line: usize, message: String },
+}
+
+/// One model to add to the library, with its launch settings.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImportedLaunch {
+    /// Name the other manager served the model under; `None` keeps the name
+    /// from the GGUF metadata.
+    pub name: Option<String>,
+    /// The GGUF file, resolved against the config file's directory.
+    pub file_path: PathBuf,
+    /// Server defaults taken from the launch flags.
+    pub server: ServerConfig,
+    /// Sampling defaults taken from the launch flags or parameters.
+    pub sampling: InferenceConfig,
+    /// Flags and settings gglib has no per-model equivalent for.
+    pub ignored: Vec<String>,
+}
+
+/// An entry [`parse_config`] could not turn into an [`ImportedLaunch`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SkippedEntry {
+    pub name: String,
+    pub reason: String,
+}
+
+/// Everything read from one configuration file.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ConfigImport {
+    pub launches: Vec<ImportedLaunch>,
+    pub skipped: Vec<SkippedEntry>,
+}
+
+/// Parse `text`, read from a file in `base_dir`, as a `format` config.
+///
+/// Relative model paths are resolved against `base_dir`.
+///
+/// # Errors
+///
+/// Returns an error when the file as a whole cannot be read in `format`;
+/// problems with single entries are reported in [`ConfigImport::skipped`].
+pub fn parse_config(
+    format: ConfigFormat,
+    text: &str,
+    base_dir: &Path,
+) -> Result<ConfigImport, ConfigImportError> {
+    match format {
+        ConfigFormat::LlamaSwap => parse_llama_swap(text, base_dir),
+        ConfigFormat::Modelfile => parse_modelfile(text, base_dir),
+    }
+}
+
+// ── llama-swap ──────────────────────────────────────────────────────────────
+
+#[derive(Debug, Deserialize)]
+struct LlamaSwapConfig {
+    #[serde(default)]
+    macros: BTreeMap<String, String>,
+    #[serde(default)]
+    models: BTreeMap<String, LlamaSwapModel>,
+}
+
+#[derive(Debug, Deserialize)]
+struct LlamaSwapModel {
+    cmd: String,
+    #[serde(default)]
+    aliases: Vec<String>,
+    /// Seconds without a request before llama-swap unloads the model.
+    #[serde(default)]
+    ttl: Option<u64>,
+    #[serde(default)]
+    unlisted: bool,
+}
+
+fn parse_llama_swap(text: &str, base_dir: &Path) -> Result<ConfigImport, ConfigImportError> {
+    let config: LlamaSwapConfig =
+        serde_yaml_ng::from_str(text).map_err(|e| ConfigImportError::InvalidYaml(e.to_string()))?;
+
+    let mut import = ConfigImport::default();
+    for (name, model) in config.models {
+        let cmd = expand_macros(&model.cmd, &config.macros);
+        let mut launch = match parse_command(&cmd, base_dir) {
+            Ok(launch) => launch,
+            Err(reason) => {
+                import.skipped.push(SkippedEntry { name, reason });
+                continue;
+            }
+        };
+        if let Some(ttl) = model.ttl {
+            // llama-swap's `ttl: 0` never unloads, which is what a per-model
+            // `0` means to gglib too.
+            launch.server.idle_shutdown_minutes =
+                Some(u32::try_from(ttl.div_ceil(60)).unwrap_or(u32::MAX));
+        }
+        if !model.aliases.is_empty() {
+            launch
+                .ignored
+                .push(format!("aliases: {}", model.aliases.join(", ")));
+        }
+        if model.unlisted {
+            launch.ignored.push("unlisted".to_string());
+        }
+        launch.name = Some(name);
+        import.launches.push(launch);
+    }
+    Ok(import)
+}
+
+/// Replace `${name}` references to llama-swap macros; unknown ones such as
+/// `${PORT}` are left as they are.
+fn expand_macros(cmd: &str, macros: &BTreeMap<String, String>) -> String {
+    let mut cmd = cmd.to_string();
+    // Macros may refer to each other, so expand until nothing changes.
+    for _ in 0..=macros.len() {
+        let expanded = macros.iter().fold(cmd.clone(), |acc, (name, value)| {
+            acc.replace(&format!("${{{name}}}"), value)
+        });
+        if expanded == cmd {
+            break;
+        }
+        cmd = expanded;
+    }
+    cmd
+}
+
+/// Flags whose value the launcher sets itself; dropped without a mention.
+const LAUNCHER_FLAGS: &[&str] = &["--port", "--host"];
+
+/// Read a llama-server command line into a launch.
+fn parse_command(cmd: &str, base_dir: &Path) -> Result<ImportedLaunch, String> {
+    let joined = cmd.replace("\\\n", " ");
+    let args = shlex::split(&joined).ok_or_else(|| "command has unbalanced quotes".to_string())?;
+    // The first word is the server binary.
+    let mut args = args.into_iter().skip(1).peekable();
+
+    let mut file_path = None;
+    let mut server = ServerConfig::default();
+    let mut sampling = InferenceConfig::default();
+    let mut ignored = Vec::new();
+    while let Some(arg) = args.next() {
+        let (flag, inline) = match arg.split_once('=') {
+            Some((flag, value)) if flag.starts_with('-') => {
+                (flag.to_string(), Some(value.to_string()))
+            }
+            _ => (arg, None),
+        };
+        let takes_value = !matches!(
+            flag.as_str(),
+            "-cb" | "--cont-batching" | "-nocb" | "--no-cont-batching"
+        ) && inline.is_none()
+            && args.peek().is_some_and(|next| !is_flag(next));
+        let value = if takes_value { args.next() } else { inline };
+        match flag.as_str() {
+            "-m" | "--model" => {
+                file_path = value.map(|v| base_dir.join(v));
+            }
+            "-hf" | "--hf-repo" | "-mu" | "--model-url" => {
+                return Err(format!(
+                    "{flag} downloads the model; add it with `gglib model download` instead"
+                ));
+            }
+            flag if LAUNCHER_FLAGS.contains(&flag) => {}
+            _ => {
+                if !apply_setting(&flag, value.as_deref(), &mut server, &mut sampling)? {
+                    ignored.push(match value {
+                        Some(value) => format!("{flag} {value}"),
+                        None => flag,
+                    });
+                }
+            }
+        }
+    }
+
+    let file_path = file_path.ok_or_else(|| "command names no model file (-m)".to_string())?;
+    Ok(ImportedLaunch {
+        name: None,
+        file_path,
+        server,
+        sampling,
+        ignored,
+    })
+}
+
+/// Apply a llama-server `flag` gglib keeps per model to `server` or
+/// `sampling`; `false` when gglib has no setting for it.
+fn apply_setting(
+    flag: &str,
+    value: Option<&str>,
+    server: &mut ServerConfig,
+    sampling: &mut InferenceConfig,
+) -> Result<bool, String> {
+    match flag {
+        "-c" | "--ctx-size" => server.context_length = Some(number(flag, value)?),
+        "-np" | "--parallel" => server.parallel = Some(number(flag, value)?),
+        "-cb" | "--cont-batching" => server.cont_batching = Some(true),
+        "-nocb" | "--no-cont-batching" => server.cont_batching = Some(false),
+        "--temp" | "--temperature" => sampling.temperature = Some(number(flag, value)?),
+        "--top-p" => sampling.top_p = Some(number(flag, value)?),
+        "--top-k" => sampling.top_k = Some(number(flag, value)?),
+        "--min-p" => sampling.min_p = Some(number(flag, value)?),
+        "--repeat-penalty" => sampling.repeat_penalty = Some(number(flag, value)?),
+        "--presence-penalty" => sampling.presence_penalty = Some(number(flag, value)?),
+        "-n" | "--n-predict" => {
+            // llama-server's -1 means unbounded, which is gglib's unset.
+            let n: i64 = number(flag, value)?;
+            sampling.max_tokens = u32::try_from(n).ok().filter(|n| *n > 0);
+        }
+        _ => return Ok(false),
+    }
+    Ok(true)
+}
+
+/// Parse the value given to `flag`.
+fn number<T: FromStr>(flag: &str, value: Option<&str>) -> Result<T, String> {
+    value
+        .and_then(|v| v.parse().ok())
+        .ok_or_else(|| format!("{flag} needs a number, got {value:?}"))
+}
+
+/// Whether `arg` is a flag rather than a value; negative numbers are values.
+fn is_flag(arg: &str) -> bool {
+    arg.starts_with('-') && arg.parse::<f64>().is_err()
+}
+
+// ── Modelfile ───────────────────────────────────────────────────────────────
+
+/// Modelfile `PARAMETER`s and the llama-server flags they correspond to.
+const OLLAMA_PARAMETERS: &[(&str, &str)] = &[
+    ("num_ctx", "--ctx-size"),
+    ("num_predict", "--n-predict"),
+    ("temperature", "--temp"),
+    ("top_p", "--top-p"),
+    ("top_k", "--top-k"),
+    ("min_p", "--min-p"),
+    ("repeat_penalty", "--repeat-penalty"),
+    ("presence_penalty", "--presence-penalty"),
+];
+
+fn parse_modelfile(text: &str, base_dir: &Path) -> Result<ConfigImport, ConfigImportError> {
+    let mut from = None;
+    let mut server = ServerConfig::default();
+    let mut sampling = InferenceConfig::default();
+    let mut ignored = Vec::new();
+
+    // Triple-quoted values (`SYSTEM """…"""`) span lines; skip their bodies.
+    let mut in_block = false;
+    for (index, line) in text.lines().enumerate() {
+        let line = line.trim();
+        if in_block {
+            in_block = !line.contains("\"\"\"");
+            continue;
+        }
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let (instruction, rest) = line.split_once(char::is_whitespace).unwrap_or((line, ""));
+        let rest = rest.trim();
+        let invalid = |message: String| ConfigImportError::InvalidModelfile {
+            line: index + 1,
+            message,
+        };
+        match instruction.to_ascii_uppercase().as_str() {
+            "FROM" => from = Some(rest.to_string()),
+            "PARAMETER" => {
+                let (name, value) = rest
+                    .split_once(char::is_whitespace)
+                    .ok_or_else(|| invalid(format!("PARAMETER needs a value: {rest}")))?;
+                let applied = match OLLAMA_PARAMETERS.iter().find(|(p, _)| *p == name) {
+                    Some((_, flag)) => {
+                        apply_setting(flag, Some(value.trim()), &mut server, &mut sampling)
+                            .map_err(invalid)?
+                    }
+                    None => false,
+                };
+                if !applied {
+                    ignored.push(format!("PARAMETER {rest}"));
+                }
+            }
+            other => {
+                in_block = rest.starts_with("\"\"\"") && rest.matches("\"\"\"").count() == 1;
+                ignored.push(other.to_string());
+            }
+        }
+    }
+
+    let mut import = ConfigImport::default();
+    let Some(from) = from else {
+        import.skipped.push(SkippedEntry {
+            name: "Modelfile".to_string(),
+            reason: "no FROM line".to_string(),
+        });
+        return Ok(import);
+    };
+    if !Path::new(&from)
+        .extension()
+        .is_some_and(|e| e.eq_ignore_ascii_case("gguf"))
+    {
+        import.skipped.push(SkippedEntry {
+            name: from,
+            reason: "FROM names an Ollama model, not a GGUF file".to_string(),
+        });
+        return Ok(import);
+    }
+    import.launches.push(ImportedLaunch {
+        name: None,
+        file_path: base_dir.join(from),
+        server,
+        sampling,
+        ignored,
+    });
+    Ok(import)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LLAMA_SWAP: &str = r#"
+macros:
+  server: /opt/llama.cpp/llama-server --port ${PORT}
+
+models:
+  qwen-coder:
+    cmd: |
+      ${server}
+      -m models/qwen2.5-coder-7b-q4_k_m.gguf
+      --ctx-size 32768 -ngl 99
+      --temp 0.2 --top-k=20 -n -1
+    aliases: [gpt-4o-mini]
+    ttl: 300
+  remote:
+    cmd: ${server} -hf bartowski/Llama-3.2-3B-Instruct-GGUF
+"#;
+
+    #[test]
+    fn llama_swap_models_become_launches() {
+        let import = parse_config(ConfigFormat::LlamaSwap, LLAMA_SWAP, Path::new("/srv")).unwrap();
+
+        let [launch] = import.launches.as_slice() else {
+            panic!("expected one launch: {import:?}");
+        };
+        assert_eq!(launch.name.as_deref(), Some("qwen-coder"));
+        assert_eq!(
+            launch.file_path,
+            Path::new("/srv/models/qwen2.5-coder-7b-q4_k_m.gguf")
+        );
+        assert_eq!(launch.server.context_length, Some(32768));
+        assert_eq!(launch.server.idle_shutdown_minutes, Some(5));
+        assert_eq!(launch.sampling.temperature, Some(0.2));
+        assert_eq!(launch.sampling.top_k, Some(20));
+        assert_eq!(launch.sampling.max_tokens, None);
+        assert_eq!(launch.ignored, ["-ngl 99", "aliases: gpt-4o-mini"]);
+
+        assert_eq!(import.skipped.len(), 1);
+        assert_eq!(import.skipped[0].name, "remote");
+    }
+
+    #[test]
+    fn modelfile_parameters_become_defaults() {
+        let text = r#"
+FROM ./llama-3.2-3b.Q4_K_M.gguf
+PARAMETER num_ctx 8192
+PARAMETER temperature 0.7
+PARAMETER stop "<|eot_id|>"
+SYSTEM """
+You are terse.
+"""
+"#;
+        let import = parse_config(ConfigFormat::Modelfile, text, Path::new("/m")).unwrap();
+        let launch = &import.launches[0];
+        assert_eq!(launch.name, None);
+        assert_eq!(launch.file_path, Path::new("/m/./llama-3.2-3b.Q4_K_M.gguf"));
+        assert_eq!(launch.server.context_length, Some(8192));
+        assert_eq!(launch.sampling.temperature, Some(0.7));
+        assert_eq!(launch.ignored, ["PARAMETER stop \"<|eot_id|>\"", "SYSTEM"]);
+
+        let registry = parse_config(ConfigFormat::Modelfile, "FROM llama3.2", Path::new("/m"));
+        assert_eq!(registry.unwrap().skipped[0].name, "llama3.2");
+    }
+}
@@ -1,11 +1,19 @@
 //! Server health monitoring primitives.
 //!
 //! Provides reusable building blocks for continuous health monitoring
-//! of server processes. The monitor is policy-free - it only checks
-//! health and emits status changes without any business logic.
+//! of server processes. The health monitor applies a [`HealthPolicy`]: a
+//! server whose health checks stay slow for several probes in a row is
+//! reported as [`ServerHealthStatus::Degraded`], and a server that keeps
+//! failing climbs an [`Escalation`] ladder (log, then event, then restart).
+//! Acting on each step is left to the caller.
+//!
+//! The idle monitor applies an [`IdlePolicy`]: it watches how long a server
+//! has gone without a request and reports when a warning is due and when
+//! the server should be stopped. Stopping it is left to the caller, which
+//! owns the process and the lifecycle events.
 
 use std::sync::Arc;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 use async_stream::stream;
 use futures_util::Stream;
@@ -15,6 +23,13 @@ use tokio_util::sync::CancellationToken;
 use tracing::debug;
 
 use crate::health::check_http_health;
+use crate::process::ServerStatsRegistry;
+
+/// Longest lead time between an idle warning and the shutdown it announces.
+const MAX_IDLE_WARNING: Duration = Duration::from_secs(60);
+
+/// Default time between health probes.
+pub const DEFAULT_HEALTH_CHECK_INTERVAL: Duration = Duration::from_secs(10);
 
 /// Pure functions for checking server health.
 ///
@@ -98,18 +113,214 @@ impl ServerHealthChecker {
     }
 }
 
+/// When a responsive server counts as degraded.
+///
+/// A single slow probe is noise (a long prompt being processed, a page
+/// fault); only `slow_probes` consecutive probes slower than
+/// `slow_threshold` mark the server degraded. The threshold must stay below
+/// the 2 s health-check timeout, beyond which a probe is a failure.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DegradationPolicy {
+    /// Probe latency at or above which a probe counts as slow.
+    pub slow_threshold: Duration,
+    /// Consecutive slow probes before the server is reported degraded.
+    pub slow_probes: u32,
+}
+
+impl Default for DegradationPolicy {
+    fn default() -> Self {
+        Self {
+            slow_threshold: Duration::from_millis(1000),
+            slow_probes: 3,
+        }
+    }
+}
+
+/// How strongly the caller should react to an unhealthy server.
+///
+/// Ordered: each step implies the ones before it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Escalation {
+    /// Log the problem; it may be transient.
+    Log,
+    /// Tell clients: emit a health-changed event.
+    Event,
+    /// Give up on the process and restart it.
+    Restart,
+}
+
+/// After how many consecutive unhealthy probes each [`Escalation`] applies.
+///
+/// Degraded and unreachable probes count towards the streak. A dead process
+/// cannot recover on its own, so it escalates straight to a restart (or to
+/// an event when restarts are disabled).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EscalationPolicy {
+    /// Unhealthy probes before an event is emitted.
+    pub event_after: u32,
+    /// Unhealthy probes before a restart. `None` never restarts.
+    pub restart_after: Option<u32>,
+}
+
+impl Default for EscalationPolicy {
+    fn default() -> Self {
+        Self {
+            event_after: 2,
+            restart_after: Some(6),
+        }
+    }
+}
+
+impl EscalationPolicy {
+    /// The step reached after `streak` consecutive unhealthy probes.
+    pub fn step(&self, streak: u32, status: &ServerHealthStatus) -> Escalation {
+        let restart = self.restart_after.is_some_and(|n| streak >= n);
+        match status {
+            _ if restart => Escalation::Restart,
+            ServerHealthStatus::ProcessDied if self.restart_after.is_some() => Escalation::Restart,
+            ServerHealthStatus::ProcessDied => Escalation::Event,
+            _ if streak >= self.event_after => Escalation::Event,
+            _ => Escalation::Log,
+        }
+    }
+}
+
+/// Probe interval, degradation and escalation settings for one server.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HealthPolicy {
+    /// Time between probes.
+    pub interval: Duration,
+    pub degradation: DegradationPolicy,
+    pub escalation: EscalationPolicy,
+}
+
+impl Default for HealthPolicy {
+    fn default() -> Self {
+        Self {
+            interval: DEFAULT_HEALTH_CHECK_INTERVAL,
+            degradation: DegradationPolicy::default(),
+            escalation: EscalationPolicy::default(),
+        }
+    }
+}
+
+impl HealthPolicy {
+    /// The default policy with a per-server probe interval override.
+    /// `None` or `0` keeps the default interval.
+    pub fn with_interval_secs(secs: Option<u64>) -> Self {
+        let interval = secs
+            .filter(|&s| s > 0)
+            .map_or(DEFAULT_HEALTH_CHECK_INTERVAL, Duration::from_secs);
+        Self {
+            interval,
+            ..Self::default()
+        }
+    }
+}
+
+/// Item yielded by [`ServerHealthMonitor`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HealthUpdate {
+    /// Current health status, with latency degradation applied.
+    pub status: ServerHealthStatus,
+    /// How to react. `None` when the server is healthy.
+    pub escalation: Option<Escalation>,
+}
+
+/// Turns raw probe results into [`HealthUpdate`]s under a [`HealthPolicy`].
+///
+/// Pure state machine, separated from the polling loop so the policy can be
+/// tested without a server.
+#[derive(Debug, Clone)]
+pub struct HealthTracker {
+    policy: HealthPolicy,
+    slow_streak: u32,
+    unhealthy_streak: u32,
+    reported: Option<ServerHealthStatus>,
+    escalation: Option<Escalation>,
+}
+
+impl HealthTracker {
+    pub fn new(policy: HealthPolicy) -> Self {
+        Self {
+            policy,
+            slow_streak: 0,
+            unhealthy_streak: 0,
+            reported: None,
+            escalation: None,
+        }
+    }
+
+    /// Record one probe that returned `status` after `latency`.
+    ///
+    /// Returns an update when the status changes or the escalation climbs
+    /// a step; repeated identical probes yield nothing.
+    pub fn observe(
+        &mut self,
+        status: ServerHealthStatus,
+        latency: Duration,
+    ) -> Option<HealthUpdate> {
+        let degradation = self.policy.degradation;
+        let status = if status.is_healthy() {
+            if latency >= degradation.slow_threshold {
+                self.slow_streak += 1;
+            } else {
+                self.slow_streak = 0;
+            }
+            if self.slow_streak >= degradation.slow_probes {
+                ServerHealthStatus::Degraded {
+                    reason: format!(
+                        "Health checks slower than {} ms for {} consecutive probes",
+                        degradation.slow_threshold.as_millis(),
+                        degradation.slow_probes
+                    ),
+                }
+            } else {
+                ServerHealthStatus::Healthy
+            }
+        } else {
+            self.slow_streak = 0;
+            status
+        };
+
+        let changed = self.reported.as_ref() != Some(&status);
+        self.reported = Some(status.clone());
+
+        if status.is_healthy() {
+            self.unhealthy_streak = 0;
+            self.escalation = None;
+            return changed.then_some(HealthUpdate {
+                status,
+                escalation: None,
+            });
+        }
+
+        self.unhealthy_streak += 1;
+        let step = self.policy.escalation.step(self.unhealthy_streak, &status);
+        let climbed = self.escalation < Some(step);
+        if climbed {
+            self.escalation = Some(step);
+        }
+        (changed || climbed).then_some(HealthUpdate {
+            status,
+            escalation: self.escalation,
+        })
+    }
+}
+
 /// Continuous health monitor that emits status changes.
 ///
-/// Polls server health at regular intervals and yields only when
-/// status changes, reducing event noise.
+/// Polls server health at the policy's interval and yields only when the
+/// status changes or the escalation climbs, reducing event noise.
 pub struct ServerHealthMonitor {
     handle: ProcessHandle,
-    interval: Duration,
+    policy: HealthPolicy,
     cancel_token: CancellationToken,
 }
 
 impl ServerHealthMonitor {
-    /// Create a new health monitor.
+    /// Create a new health monitor with the default degradation and
+    /// escalation settings.
     ///
     /// # Arguments
     ///
@@ -120,28 +331,45 @@ impl ServerHealthMonitor {
         handle: ProcessHandle,
         check_interval: Duration,
         cancel_token: CancellationToken,
+    ) -> Self {
+        Self::with_policy(
+            handle,
+            HealthPolicy {
+                interval: check_interval,
+                ..HealthPolicy::default()
+            },
+            cancel_token,
+        )
+    }
+
+    /// Create a new health monitor with an explicit policy.
+    pub fn with_policy(
+        handle: ProcessHandle,
+        policy: HealthPolicy,
+        cancel_token: CancellationToken,
     ) -> Self {
         Self {
             handle,
-            interval: check_interval,
+            policy,
             cancel_token,
         }
     }
 
-    /// Start monitoring and return a stream of health status changes.
+    /// Start monitoring and return a stream of health updates.
     ///
-    /// The stream yields only when status changes, not on every check.
-    /// Completes when cancellation token is triggered.
-    pub fn monitor(self) -> impl Stream<Item = ServerHealthStatus> {
+    /// The stream yields only when the status changes or the escalation
+    /// climbs, not on every check. Completes when cancellation token is
+    /// triggered.
+    pub fn monitor(self) -> impl Stream<Item = HealthUpdate> {
         let handle = Arc::new(self.handle);
         let cancel_token = self.cancel_token;
-        let check_interval = self.interval;
+        let policy = self.policy;
 
         stream! {
-            let mut ticker = interval(check_interval);
+            let mut ticker = interval(policy.interval);
             ticker.set_missed_tick_behavior(MissedTickBehavior::Skip);
 
-            let mut last_status: Option<ServerHealthStatus> = None;
+            let mut tracker = HealthTracker::new(policy);
 
             debug!(
                 port = handle.port,
@@ -152,20 +380,19 @@ impl ServerHealthMonitor {
             loop {
                 tokio::select! {
                     _ = ticker.tick() => {
-                        let current_status = ServerHealthChecker::check_combined(&handle).await;
+                        let started = Instant::now();
+                        let status = ServerHealthChecker::check_combined(&handle).await;
+                        let latency = started.elapsed();
 
-                        // Emit only on state change
-                        if last_status.as_ref() != Some(&current_status) {
+                        if let Some(update) = tracker.observe(status, latency) {
                             debug!(
                                 port = handle.port,
                                 model_id = handle.model_id,
-                                ?current_status,
-                                ?last_status,
+                                ?update,
+                                ?latency,
                                 "Health status changed"
                             );
-
-                            yield current_status.clone();
-                            last_status = Some(current_status);
+                            yield update;
                         }
                     }
                     _ = cancel_token.cancelled() => {
@@ -182,6 +409,163 @@ impl ServerHealthMonitor {
     }
 }
 
+/// When an unused server should be stopped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IdlePolicy {
+    /// Time without a request after which the server is stopped.
+    pub timeout: Duration,
+    /// How long before the shutdown the warning is raised.
+    pub warn_before: Duration,
+}
+
+/// What an [`IdlePolicy`] says about a server that has been idle for a while.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IdleVerdict {
+    /// Recently used; nothing to do.
+    Active,
+    /// Close to the timeout; the server stops in `shutdown_in` unless used.
+    Warn {
+        /// Time left before the shutdown.
+        shutdown_in: Duration,
+    },
+    /// The timeout has passed.
+    Expired,
+}
+
+impl IdlePolicy {
+    /// Build a policy for `timeout`, warning a minute ahead (or halfway
+    /// through, for timeouts shorter than two minutes).
+    pub fn new(timeout: Duration) -> Self {
+        Self {
+            timeout,
+            warn_before: MAX_IDLE_WARNING.min(timeout / 2),
+        }
+    }
+
+    /// Resolve the policy for one model: its own `idle_shutdown_minutes`
+    /// override first, then the global setting. `None` (or `0` at whichever
+    /// level wins) disables idle shutdown.
+    pub fn resolve(model_minutes: Option<u32>, global_minutes: Option<u32>) -> Option<Self> {
+        let minutes = model_minutes.or(global_minutes)?;
+        (minutes > 0).then(|| Self::new(Duration::from_secs(u64::from(minutes) * 60)))
+    }
+
+    /// Judge a server that has gone `idle_for` without a request.
+    pub fn evaluate(&self, idle_for: Duration) -> IdleVerdict {
+        if idle_for >= self.timeout {
+            IdleVerdict::Expired
+        } else if idle_for >= self.timeout.saturating_sub(self.warn_before) {
+            IdleVerdict::Warn {
+                shutdown_in: self.timeout - idle_for,
+            }
+        } else {
+            IdleVerdict::Active
+        }
+    }
+}
+
+/// Event yielded by [`ServerIdleMonitor`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IdleEvent {
+    /// The server will be stopped in `shutdown_in` unless a request arrives.
+    Warning {
+        /// Time left before the shutdown.
+        shutdown_in: Duration,
+    },
+    /// The idle timeout has passed; the server should be stopped now.
+    Expired,
+}
+
+/// Continuous idle monitor for one server.
+///
+/// Reads the server's last activity from the [`ServerStatsRegistry`], which
+/// the proxy updates on every forwarded request, and applies an
+/// [`IdlePolicy`] to it.
+pub struct ServerIdleMonitor {
+    registry: Arc<ServerStatsRegistry>,
+    model_id: u32,
+    policy: IdlePolicy,
+    interval: Duration,
+    cancel_token: CancellationToken,
+}
+
+impl ServerIdleMonitor {
+    /// Create a new idle monitor.
+    ///
+    /// # Arguments
+    ///
+    /// * `registry` - Session registry holding the server's last activity
+    /// * `model_id` - Model whose server is watched
+    /// * `policy` - When to warn and when to stop
+    /// * `check_interval` - How often to check (e.g., 10 seconds)
+    /// * `cancel_token` - Token to signal monitor shutdown
+    pub fn new(
+        registry: Arc<ServerStatsRegistry>,
+        model_id: u32,
+        policy: IdlePolicy,
+        check_interval: Duration,
+        cancel_token: CancellationToken,
+    ) -> Self {
+        Self {
+            registry,
+            model_id,
+            policy,
+            interval: check_interval,
+            cancel_token,
+        }
+    }
+
+    /// Start monitoring and return a stream of idle events.
+    ///
+    /// Yields one `Warning` each time the server drifts into the warning
+    /// window (a request in between re-arms it), then `Expired` once and
+    /// completes. Also completes when the cancellation token is triggered.
+    pub fn monitor(self) -> impl Stream<Item = IdleEvent> {
+        let Self {
+            registry,
+            model_id,
+            policy,
+            interval: check_interval,
+            cancel_token,
+        } = self;
+
+        stream! {
+            let mut ticker = interval(check_interval);
+            ticker.set_missed_tick_behavior(MissedTickBehavior::Skip);
+            let mut warned = false;
+
+            loop {
+                tokio::select! {
+                    _ = ticker.tick() => {
+                        // No session means the server is already gone.
+                        let Some(idle_for) = registry.idle_for(model_id) else {
+                            continue;
+                        };
+                        match policy.evaluate(idle_for) {
+                            IdleVerdict::Active => warned = false,
+                            IdleVerdict::Warn { shutdown_in } => {
+                                if !warned {
+                                    warned = true;
+                                    yield IdleEvent::Warning { shutdown_in };
+                                }
+                            }
+                            IdleVerdict::Expired => {
+                                debug!(model_id, ?idle_for, "Idle timeout reached");
+                                yield IdleEvent::Expired;
+                                break;
+                            }
+                        }
+                    }
+                    _ = cancel_token.cancelled() => {
+                        debug!(model_id, "Idle monitor cancelled");
+                        break;
+                    }
+                }
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -245,6 +629,62 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_idle_policy_resolution() {
+        assert_eq!(IdlePolicy::resolve(None, None), None);
+        assert_eq!(IdlePolicy::resolve(None, Some(0)), None);
+        // A per-model 0 opts out of a global timeout.
+        assert_eq!(IdlePolicy::resolve(Some(0), Some(30)), None);
+
+        let policy = IdlePolicy::resolve(Some(5), Some(30)).unwrap();
+        assert_eq!(policy.timeout, Duration::from_secs(300));
+        assert_eq!(policy.warn_before, Duration::from_secs(60));
+
+        let short = IdlePolicy::resolve(None, Some(1)).unwrap();
+        assert_eq!(short.warn_before, Duration::from_secs(30));
+    }
+
+    #[test]
+    fn test_idle_policy_evaluate() {
+        let policy = IdlePolicy::new(Duration::from_secs(300));
+        assert_eq!(
+            policy.evaluate(Duration::from_secs(100)),
+            IdleVerdict::Active
+        );
+        assert_eq!(
+            policy.evaluate(Duration::from_secs(250)),
+            IdleVerdict::Warn {
+                shutdown_in: Duration::from_secs(50)
+            }
+        );
+        assert_eq!(
+            policy.evaluate(Duration::from_secs(300)),
+            IdleVerdict::Expired
+        );
+    }
+
+    #[tokio::test]
+    async fn test_idle_monitor_warns_then_expires() {
+        let registry = Arc::new(ServerStatsRegistry::new());
+        registry.begin_session(1);
+        let cancel_token = CancellationToken::new();
+
+        let monitor = ServerIdleMonitor::new(
+            Arc::clone(&registry),
+            1,
+            IdlePolicy::new(Duration::from_millis(200)),
+            Duration::from_millis(10),
+            cancel_token.clone(),
+        );
+        let events: Vec<_> =
+            tokio::time::timeout(Duration::from_secs(10), monitor.monitor().collect())
+                .await
+                .expect("monitor completes after expiry");
+
+        assert!(matches!(events[0], IdleEvent::Warning { .. }));
+        assert_eq!(events.last(), Some(&IdleEvent::Expired));
+    }
+
     #[tokio::test]
     async fn test_monitor_emits_initial_status() {
         // Create a monitor for an unused port
@@ -273,8 +713,96 @@ mod tests {
         assert!(status.is_some());
         // Should be unreachable since nothing is listening on that port
         assert!(matches!(
-            status.unwrap(),
+            status.unwrap().status,
             ServerHealthStatus::Unreachable { .. }
         ));
     }
+
+    const FAST: Duration = Duration::from_millis(5);
+    const SLOW: Duration = Duration::from_millis(1500);
+
+    fn unreachable() -> ServerHealthStatus {
+        ServerHealthStatus::Unreachable {
+            last_error: "Connection refused".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_tracker_degrades_after_consecutive_slow_probes() {
+        let mut tracker = HealthTracker::new(HealthPolicy::default());
+        let first = tracker.observe(ServerHealthStatus::Healthy, FAST).unwrap();
+        assert_eq!(first.status, ServerHealthStatus::Healthy);
+
+        // Two slow probes, then a fast one, resets the streak.
+        assert_eq!(tracker.observe(ServerHealthStatus::Healthy, SLOW), None);
+        assert_eq!(tracker.observe(ServerHealthStatus::Healthy, SLOW), None);
+        assert_eq!(tracker.observe(ServerHealthStatus::Healthy, FAST), None);
+
+        assert_eq!(tracker.observe(ServerHealthStatus::Healthy, SLOW), None);
+        assert_eq!(tracker.observe(ServerHealthStatus::Healthy, SLOW), None);
+        let degraded = tracker.observe(ServerHealthStatus::Healthy, SLOW).unwrap();
+        assert!(matches!(
+            degraded.status,
+            ServerHealthStatus::Degraded { .. }
+        ));
+        assert_eq!(degraded.escalation, Some(Escalation::Log));
+
+        let recovered = tracker.observe(ServerHealthStatus::Healthy, FAST).unwrap();
+        assert_eq!(recovered.status, ServerHealthStatus::Healthy);
+        assert_eq!(recovered.escalation, None);
+    }
+
+    #[test]
+    fn test_tracker_escalates_log_event_restart() {
+        let mut tracker = HealthTracker::new(HealthPolicy::default());
+        let steps: Vec<_> = (0..8)
+            .filter_map(|_| tracker.observe(unreachable(), FAST))
+            .map(|u| u.escalation)
+            .collect();
+        assert_eq!(
+            steps,
+            [
+                Some(Escalation::Log),
+                Some(Escalation::Event),
+                Some(Escalation::Restart)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_dead_process_escalates_immediately() {
+        let mut tracker = HealthTracker::new(HealthPolicy::default());
+        let update = tracker
+            .observe(ServerHealthStatus::ProcessDied, FAST)
+            .unwrap();
+        assert_eq!(update.escalation, Some(Escalation::Restart));
+
+        let mut no_restart = HealthTracker::new(HealthPolicy {
+            escalation: EscalationPolicy {
+                event_after: 2,
+                restart_after: None,
+            },
+            ..HealthPolicy::default()
+        });
+        let update = no_restart
+            .observe(ServerHealthStatus::ProcessDied, FAST)
+            .unwrap();
+        assert_eq!(update.escalation, Some(Escalation::Event));
+    }
+
+    #[test]
+    fn test_health_policy_interval_override() {
+        assert_eq!(
+            HealthPolicy::with_interval_secs(None).interval,
+            DEFAULT_HEALTH_CHECK_INTERVAL
+        );
+        assert_eq!(
+            HealthPolicy::with_interval_secs(Some(0)).interval,
+            DEFAULT_HEALTH_CHECK_INTERVAL
+        );
+        assert_eq!(
+            HealthPolicy::with_interval_secs(Some(3)).interval,
+            Duration::from_secs(3)
+        );
+    }
 }
@@ -4,17 +4,26 @@
 pub mod assistant_ui;
 mod command;
 pub mod compose;
+pub mod config_import;
 pub mod council_runner;
 mod health;
 pub mod health_monitor;
 pub mod llama;
+pub mod log_query;
+pub mod models_watcher;
+pub mod otlp;
 pub mod pidfile;
+pub mod plugins;
 pub mod ports_impl;
 pub mod process;
 mod process_core;
+pub mod project_files;
+pub mod prompt_cache;
+pub mod prompt_preview;
 pub mod proxy;
 mod runner;
 pub mod server_config;
+pub mod service;
 pub mod system;
 
 // Re-export the main ProcessRunner implementation
@@ -24,7 +33,11 @@ pub use runner::LlamaServerRunner;
 pub use health::{check_http_health, wait_for_http_health};
 
 // Re-export health monitoring primitives
-pub use health_monitor::{ServerHealthChecker, ServerHealthMonitor};
+pub use health_monitor::{
+    DEFAULT_HEALTH_CHECK_INTERVAL, DegradationPolicy, Escalation, EscalationPolicy, HealthPolicy,
+    HealthTracker, HealthUpdate, IdleEvent, IdlePolicy, IdleVerdict, ServerHealthChecker,
+    ServerHealthMonitor, ServerIdleMonitor,
+};
 
 // Re-export log sink utilities
 pub use command::NoopLogSink;
@@ -32,16 +45,20 @@ pub use command::NoopLogSink;
 // Re-export GUI process management types
 pub use process::{
     CurrentModelState, GuiProcessCore, ProcessManager, ProcessStrategy, ServerEvent,
-    ServerEventBroadcaster, ServerLogEntry, ServerLogManager, ServerStateInfo, ServerStatus,
-    get_event_broadcaster, get_log_manager,
+    ServerEventBroadcaster, ServerLogEntry, ServerLogManager, ServerStateInfo, ServerStatsRegistry,
+    ServerStatus, SlotRegistry, fetch_slots, get_event_broadcaster, get_log_manager,
+    get_slot_registry, get_stats_registry,
 };
 
 // Re-export port implementations for runtime adapters
-pub use ports_impl::{CatalogPortImpl, LlmCompletionAdapter, RuntimePortImpl};
+pub use ports_impl::{
+    CatalogPortImpl, LlmCompletionAdapter, RunningServerTitleGenerator, RuntimePortImpl,
+};
 
 // Re-export composition root factory
 pub use compose::{
-    CouncilPorts, compose_agent_loop, compose_agent_loop_with_sampling, compose_council_ports,
+    CouncilPorts, EndpointPipelineModels, compose_agent_loop, compose_agent_loop_with_sampling,
+    compose_council_ports, compose_pipeline_runner,
 };
 
 // Re-export system probe implementation
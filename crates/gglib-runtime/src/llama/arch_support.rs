@@ -0,0 +1,243 @@
+//! Which GGUF architectures the installed llama.cpp can load.
+//!
+//! Implements [`ArchitectureSupportPort`] in one of two ways:
+//!
+//! - **Source builds** carry their checkout, whose `src/llama-arch.cpp`
+//!   lists every architecture name the build recognises — an exact answer.
+//! - **Pre-built binaries** only report a release number through
+//!   `llama-server --version`, which is compared against
+//!   [`ARCH_FIRST_BUILD`], the release that first supported each recent
+//!   architecture. Architectures missing from the table are `Unknown`.
+
+use std::collections::HashSet;
+use std::fs;
+use std::path::Path;
+
+use gglib_core::paths::{llama_config_path, llama_cpp_dir, llama_server_path};
+use gglib_core::ports::{ArchitectureSupport, ArchitectureSupportPort};
+use gglib_core::utils::process::cmd;
+
+use super::config::BuildConfig;
+
+/// First llama.cpp release (`b<N>`) known to load each architecture.
+///
+/// Only architectures new enough that an older pre-built install might
+/// lack them are listed; extend it as llama.cpp adds model families.
+const ARCH_FIRST_BUILD: &[(&str, u32)] = &[
+    ("gemma3", 4875),
+    ("llama4", 5074),
+    ("qwen3", 5092),
+    ("qwen3moe", 5092),
+    ("gpt-oss", 6096),
+];
+
+const SOURCE_UPGRADE: &str = "gglib config llama update";
+const PREBUILT_UPGRADE: &str = "gglib config llama install --force";
+
+/// What is known about the installed llama.cpp.
+enum Installed {
+    /// A source build, with the architecture names of its checkout.
+    Source {
+        version: String,
+        architectures: HashSet<String>,
+    },
+    /// A pre-built release.
+    Release { build: u32 },
+    /// Not installed, or nothing could be learned from it.
+    Unknown,
+}
+
+/// [`ArchitectureSupportPort`] backed by gglib's own llama.cpp install.
+///
+/// Inspects the install on every check, so an upgrade made while the
+/// application is running is picked up by the next download.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct InstalledArchitectureSupport;
+
+impl InstalledArchitectureSupport {
+    /// Create the checker.
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+impl ArchitectureSupportPort for InstalledArchitectureSupport {
+    fn check(&self, architecture: &str) -> ArchitectureSupport {
+        check_against(&detect_installed(), architecture)
+    }
+}
+
+fn check_against(installed: &Installed, architecture: &str) -> ArchitectureSupport {
+    let architecture = architecture.trim().to_ascii_lowercase();
+    match installed {
+        Installed::Source {
+            version,
+            architectures,
+        } => {
+            if architectures.contains(&architecture) {
+                ArchitectureSupport::Supported
+            } else {
+                ArchitectureSupport::Unsupported {
+                    installed: version.clone(),
+                    required: first_build(&architecture).map(|b| format!("b{b}")),
+                    upgrade_command: SOURCE_UPGRADE.to_string(),
+                }
+            }
+        }
+        Installed::Release { build } => match first_build(&architecture) {
+            Some(required) if *build < required => ArchitectureSupport::Unsupported {
+                installed: format!("b{build}"),
+                required: Some(format!("b{required}")),
+                upgrade_command: PREBUILT_UPGRADE.to_string(),
+            },
+            Some(_) => ArchitectureSupport::Supported,
+            None => ArchitectureSupport::Unknown,
+        },
+        Installed::Unknown => ArchitectureSupport::Unknown,
+    }
+}
+
+fn first_build(architecture: &str) -> Option<u32> {
+    ARCH_FIRST_BUILD
+        .iter()
+        .find(|(name, _)| *name == architecture)
+        .map(|(_, build)| *build)
+}
+
+fn detect_installed() -> Installed {
+    let Ok(server_path) = llama_server_path() else {
+        return Installed::Unknown;
+    };
+    if !server_path.exists() {
+        return Installed::Unknown;
+    }
+
+    // Only source builds write a `BuildConfig`; pre-built installs record a
+    // different shape in the same file.
+    let source_build = llama_config_path()
+        .ok()
+        .and_then(|path| BuildConfig::load(&path).ok());
+    if let Some(config) = source_build
+        && let Ok(llama_dir) = llama_cpp_dir()
+        && let Some(architectures) = checkout_architectures(&llama_dir)
+    {
+        return Installed::Source {
+            version: config.version,
+            architectures,
+        };
+    }
+
+    cmd(&server_path)
+        .arg("--version")
+        .output()
+        .ok()
+        .and_then(|output| {
+            // llama-server prints its version banner on stderr.
+            let mut text = String::from_utf8_lossy(&output.stderr).into_owned();
+            text.push_str(&String::from_utf8_lossy(&output.stdout));
+            parse_build_number(&text)
+        })
+        .map_or(Installed::Unknown, |build| Installed::Release { build })
+}
+
+/// Architecture names listed in a llama.cpp checkout's `LLM_ARCH_NAMES`.
+fn checkout_architectures(llama_dir: &Path) -> Option<HashSet<String>> {
+    let source = fs::read_to_string(llama_dir.join("src").join("llama-arch.cpp")).ok()?;
+    let names = parse_arch_names(&source);
+    (!names.is_empty()).then_some(names)
+}
+
+/// Parse the `{ LLM_ARCH_FOO, "foo" },` entries of `llama-arch.cpp`.
+fn parse_arch_names(source: &str) -> HashSet<String> {
+    source
+        .lines()
+        .map(str::trim)
+        .filter_map(|line| {
+            line.strip_prefix('{')?
+                .trim_start()
+                .strip_prefix("LLM_ARCH_")
+        })
+        .filter(|entry| !entry.starts_with("UNKNOWN"))
+        .filter_map(|entry| entry.split('"').nth(1))
+        .map(str::to_string)
+        .collect()
+}
+
+/// The release number from `llama-server --version` (`version: 5074 (…)`).
+fn parse_build_number(output: &str) -> Option<u32> {
+    output.lines().find_map(|line| {
+        line.trim()
+            .strip_prefix("version:")?
+            .split_whitespace()
+            .next()?
+            .parse()
+            .ok()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arch_names_are_parsed_from_the_checkout() {
+        let source = r#"
+static const std::map<llm_arch, const char *> LLM_ARCH_NAMES = {
+    { LLM_ARCH_LLAMA,            "llama"            },
+    { LLM_ARCH_QWEN3MOE,         "qwen3moe"         },
+    { LLM_ARCH_UNKNOWN,          "(unknown)"        },
+};
+    { LLM_KV_GENERAL_ARCHITECTURE, "general.architecture" },
+"#;
+        let names = parse_arch_names(source);
+        assert_eq!(names.len(), 2);
+        assert!(names.contains("llama"));
+        assert!(names.contains("qwen3moe"));
+    }
+
+    #[test]
+    fn build_number_is_parsed_from_version_output() {
+        let output = "load_backend: loaded CPU backend\nversion: 5074 (1466621e)\n\
+                      built with cc (GCC) 14.2.1 for x86_64-pc-linux-gnu\n";
+        assert_eq!(parse_build_number(output), Some(5074));
+        assert_eq!(parse_build_number("no version here"), None);
+    }
+
+    #[test]
+    fn source_builds_check_the_checkout() {
+        let installed = Installed::Source {
+            version: "abc1234".to_string(),
+            architectures: ["llama".to_string()].into_iter().collect(),
+        };
+        assert_eq!(
+            check_against(&installed, "Llama"),
+            ArchitectureSupport::Supported
+        );
+        assert_eq!(
+            check_against(&installed, "llama4"),
+            ArchitectureSupport::Unsupported {
+                installed: "abc1234".to_string(),
+                required: Some("b5074".to_string()),
+                upgrade_command: SOURCE_UPGRADE.to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn releases_use_the_version_table() {
+        let old = Installed::Release { build: 5000 };
+        assert!(matches!(
+            check_against(&old, "llama4"),
+            ArchitectureSupport::Unsupported { .. }
+        ));
+        assert_eq!(
+            check_against(&old, "gemma3"),
+            ArchitectureSupport::Supported
+        );
+        assert_eq!(check_against(&old, "llama"), ArchitectureSupport::Unknown);
+        assert_eq!(
+            check_against(&Installed::Unknown, "llama4"),
+            ArchitectureSupport::Unknown
+        );
+    }
+}
@@ -0,0 +1,220 @@
+//! Thread count, CPU affinity and NUMA (`--threads`, `--threads-batch`,
+//! `--cpu-mask`, `--numa`) argument resolution.
+//!
+//! Defaults come from the host [`CpuTopology`]:
+//!
+//! - `--threads` is the number of physical performance cores. Generation is
+//!   memory-bandwidth bound, so hyperthreads add contention rather than
+//!   throughput, and on a hybrid CPU a thread on an efficiency core holds
+//!   back every other thread at each sync point.
+//! - `--threads-batch` is left unset, so llama-server reuses `--threads`.
+//! - On a hybrid CPU whose performance CPUs are known, threads are pinned to
+//!   them with `--cpu-mask`. `GGLIB_DISABLE_CPU_PINNING=1` turns this off.
+//! - `--numa` is never chosen automatically: llama.cpp recommends dropping
+//!   the page cache before first use with a NUMA strategy, which is not
+//!   something a launch should do behind the user's back.
+
+use crate::system::{CpuTopology, is_truthy_flag};
+use gglib_core::cpu_config::{CpuSet, NumaStrategy};
+
+/// Indicates how the CPU pinning was resolved.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CpuAffinitySource {
+    /// User explicitly supplied a CPU set.
+    Explicit,
+    /// Pinned to the performance cores of a hybrid CPU.
+    PerformanceCores,
+    /// Not pinned: the CPU is not hybrid, its performance CPUs are unknown,
+    /// or the requested thread count does not fit on them.
+    Unpinned,
+    /// Automatic pinning suppressed via `GGLIB_DISABLE_CPU_PINNING`.
+    DisabledByEnv,
+}
+
+/// Resolved CPU arguments for a llama-server launch.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CpuArgsResolution {
+    pub threads: u32,
+    pub threads_batch: Option<u32>,
+    pub cpu_affinity: Option<CpuSet>,
+    pub numa: Option<NumaStrategy>,
+    /// Whether `threads` came from the caller rather than the topology.
+    pub threads_explicit: bool,
+    pub affinity_source: CpuAffinitySource,
+}
+
+impl CpuArgsResolution {
+    /// A one-line, human-readable explanation of the resolved values.
+    #[must_use]
+    pub fn explain(&self) -> String {
+        let threads = if self.threads_explicit {
+            format!("threads={} (explicit)", self.threads)
+        } else {
+            format!("threads={} (performance cores)", self.threads)
+        };
+        let affinity = match (&self.cpu_affinity, self.affinity_source) {
+            (Some(cpus), CpuAffinitySource::Explicit) => format!(", cpus={cpus} (explicit)"),
+            (Some(cpus), _) => format!(", cpus={cpus} (performance cores)"),
+            (None, CpuAffinitySource::DisabledByEnv) => {
+                ", pinning disabled via GGLIB_DISABLE_CPU_PINNING".to_string()
+            }
+            (None, _) => String::new(),
+        };
+        let batch = self
+            .threads_batch
+            .map(|n| format!(", threads-batch={n}"))
+            .unwrap_or_default();
+        let numa = self
+            .numa
+            .map(|n| format!(", numa={}", n.as_llama_arg()))
+            .unwrap_or_default();
+        format!("CPU: {threads}{batch}{affinity}{numa}")
+    }
+}
+
+/// Whether `GGLIB_DISABLE_CPU_PINNING` requests that automatic pinning to
+/// performance cores be skipped.
+///
+/// Same `GGLIB_DISABLE_<FEATURE>` convention as `GGLIB_DISABLE_KV_QUANT`.
+/// An explicit CPU set is still honoured.
+fn cpu_pinning_disabled_via_env() -> bool {
+    std::env::var("GGLIB_DISABLE_CPU_PINNING")
+        .ok()
+        .is_some_and(|v| is_truthy_flag(&v))
+}
+
+/// Resolve the CPU arguments for a llama-server launch.
+///
+/// Explicit values always win. `threads` falls back to the topology's
+/// performance-core count; `cpu_affinity` falls back to the performance
+/// CPUs of a hybrid CPU, unless pinning is disabled or the explicit thread
+/// count is larger than that set. `threads_batch` and `numa` are direct
+/// pass-throughs.
+#[must_use]
+pub fn resolve_cpu_args(
+    threads: Option<u32>,
+    threads_batch: Option<u32>,
+    cpu_affinity: Option<CpuSet>,
+    numa: Option<NumaStrategy>,
+    topology: &CpuTopology,
+) -> CpuArgsResolution {
+    resolve_cpu_args_inner(
+        threads,
+        threads_batch,
+        cpu_affinity,
+        numa,
+        topology,
+        cpu_pinning_disabled_via_env(),
+    )
+}
+
+/// Pure core of [`resolve_cpu_args`], with the env lookup lifted into a
+/// parameter so the kill switch is testable without touching process state.
+fn resolve_cpu_args_inner(
+    threads: Option<u32>,
+    threads_batch: Option<u32>,
+    cpu_affinity: Option<CpuSet>,
+    numa: Option<NumaStrategy>,
+    topology: &CpuTopology,
+    pinning_disabled: bool,
+) -> CpuArgsResolution {
+    let threads_explicit = threads.is_some();
+    let threads = threads.unwrap_or(topology.performance_cores).max(1);
+
+    let (cpu_affinity, affinity_source) = match cpu_affinity {
+        Some(cpus) => (Some(cpus), CpuAffinitySource::Explicit),
+        None if pinning_disabled => (None, CpuAffinitySource::DisabledByEnv),
+        None => match &topology.performance_cpus {
+            Some(p_cpus) if topology.is_hybrid() && threads as usize <= p_cpus.len() => {
+                (Some(p_cpus.clone()), CpuAffinitySource::PerformanceCores)
+            }
+            _ => (None, CpuAffinitySource::Unpinned),
+        },
+    };
+
+    CpuArgsResolution {
+        threads,
+        threads_batch,
+        cpu_affinity,
+        numa,
+        threads_explicit,
+        affinity_source,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hybrid() -> CpuTopology {
+        CpuTopology {
+            logical_cpus: 20,
+            physical_cores: 14,
+            performance_cores: 6,
+            performance_cpus: Some("0-11".parse().unwrap()),
+            numa_nodes: 1,
+        }
+    }
+
+    fn uniform() -> CpuTopology {
+        CpuTopology {
+            logical_cpus: 16,
+            physical_cores: 8,
+            performance_cores: 8,
+            performance_cpus: None,
+            numa_nodes: 1,
+        }
+    }
+
+    #[test]
+    fn hybrid_defaults_to_performance_cores_pinned() {
+        let got = resolve_cpu_args_inner(None, None, None, None, &hybrid(), false);
+        assert_eq!(got.threads, 6);
+        assert_eq!(got.threads_batch, None);
+        assert_eq!(got.cpu_affinity.unwrap().to_string(), "0-11");
+        assert_eq!(got.affinity_source, CpuAffinitySource::PerformanceCores);
+    }
+
+    #[test]
+    fn uniform_cpu_is_not_pinned() {
+        let got = resolve_cpu_args_inner(None, None, None, None, &uniform(), false);
+        assert_eq!(got.threads, 8);
+        assert_eq!(got.cpu_affinity, None);
+        assert_eq!(got.affinity_source, CpuAffinitySource::Unpinned);
+    }
+
+    #[test]
+    fn explicit_values_pass_through() {
+        let cpus: CpuSet = "2-5".parse().unwrap();
+        let got = resolve_cpu_args_inner(
+            Some(4),
+            Some(12),
+            Some(cpus.clone()),
+            Some(NumaStrategy::Distribute),
+            &hybrid(),
+            true,
+        );
+        assert_eq!(got.threads, 4);
+        assert!(got.threads_explicit);
+        assert_eq!(got.threads_batch, Some(12));
+        assert_eq!(got.cpu_affinity, Some(cpus));
+        assert_eq!(got.numa, Some(NumaStrategy::Distribute));
+        assert_eq!(got.affinity_source, CpuAffinitySource::Explicit);
+    }
+
+    #[test]
+    fn too_many_threads_for_performance_cpus_stays_unpinned() {
+        let got = resolve_cpu_args_inner(Some(16), None, None, None, &hybrid(), false);
+        assert_eq!(got.cpu_affinity, None);
+        assert_eq!(got.affinity_source, CpuAffinitySource::Unpinned);
+    }
+
+    #[test]
+    fn env_kill_switch_skips_automatic_pinning() {
+        let got = resolve_cpu_args_inner(None, None, None, None, &hybrid(), true);
+        assert_eq!(got.threads, 6);
+        assert_eq!(got.cpu_affinity, None);
+        assert_eq!(got.affinity_source, CpuAffinitySource::DisabledByEnv);
+        assert!(got.explain().contains("GGLIB_DISABLE_CPU_PINNING"));
+    }
+}
@@ -0,0 +1,229 @@
+//! Multi-GPU placement (`--split-mode`, `--tensor-split`, `--main-gpu`)
+//! argument resolution.
+//!
+//! Defaults come from the GPUs enumerated at launch time:
+//!
+//! - `--tensor-split` on a machine with two or more GPUs gives each device a
+//!   share proportional to its free VRAM, so a card already holding another
+//!   model takes fewer layers instead of running out of memory. The shares
+//!   are written in GiB so the launch log reads as the memory each GPU had.
+//!   `GGLIB_DISABLE_TENSOR_SPLIT=1` turns this off.
+//! - With `--split-mode none` the model runs on one GPU; unless `--main-gpu`
+//!   names it, that is the GPU with the most free VRAM.
+//! - `--split-mode` itself is never chosen automatically: llama.cpp's
+//!   default (`layer`) is the right one unless the user knows better.
+
+use crate::system::is_truthy_flag;
+use gglib_core::gpu_config::{SplitMode, TensorSplit};
+use gglib_core::utils::system::GpuDevice;
+
+/// Bytes per GiB, the unit automatic tensor-split shares are written in.
+const GIB: f64 = 1024.0 * 1024.0 * 1024.0;
+
+/// Indicates how the tensor split was resolved.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TensorSplitSource {
+    /// User explicitly supplied a tensor split.
+    Explicit,
+    /// Proportional to each GPU's free VRAM.
+    FreeVram,
+    /// Not split: fewer than two GPUs, `--split-mode none`, or no free VRAM
+    /// reported.
+    NotSplit,
+    /// Automatic split suppressed via `GGLIB_DISABLE_TENSOR_SPLIT`.
+    DisabledByEnv,
+}
+
+/// Resolved GPU placement arguments for a llama-server launch.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GpuArgsResolution {
+    pub split_mode: Option<SplitMode>,
+    pub tensor_split: Option<TensorSplit>,
+    pub main_gpu: Option<u32>,
+    /// Whether `main_gpu` came from the caller rather than free VRAM.
+    pub main_gpu_explicit: bool,
+    pub split_source: TensorSplitSource,
+}
+
+impl GpuArgsResolution {
+    /// A one-line, human-readable explanation of the resolved values, or
+    /// `None` when no GPU placement flag is passed.
+    #[must_use]
+    pub fn explain(&self) -> Option<String> {
+        let mut parts = Vec::new();
+        if let Some(mode) = self.split_mode {
+            parts.push(format!("split-mode={}", mode.as_llama_arg()));
+        }
+        match (&self.tensor_split, self.split_source) {
+            (Some(split), TensorSplitSource::FreeVram) => parts.push(format!(
+                "tensor-split={split} (free VRAM in GiB on {} GPUs)",
+                split.len()
+            )),
+            (Some(split), _) => parts.push(format!("tensor-split={split} (explicit)")),
+            (None, TensorSplitSource::DisabledByEnv) => parts
+                .push("automatic tensor split disabled via GGLIB_DISABLE_TENSOR_SPLIT".to_string()),
+            (None, _) => {}
+        }
+        if let Some(gpu) = self.main_gpu {
+            let why = if self.main_gpu_explicit {
+                "explicit"
+            } else {
+                "most free VRAM"
+            };
+            parts.push(format!("main-gpu={gpu} ({why})"));
+        }
+        (!parts.is_empty()).then(|| format!("GPU: {}", parts.join(", ")))
+    }
+}
+
+/// Whether `GGLIB_DISABLE_TENSOR_SPLIT` requests that the automatic
+/// free-VRAM split be skipped.
+///
+/// Same `GGLIB_DISABLE_<FEATURE>` convention as `GGLIB_DISABLE_CPU_PINNING`.
+/// An explicit tensor split is still honoured.
+fn tensor_split_disabled_via_env() -> bool {
+    std::env::var("GGLIB_DISABLE_TENSOR_SPLIT")
+        .ok()
+        .is_some_and(|v| is_truthy_flag(&v))
+}
+
+/// Resolve the GPU placement arguments for a llama-server launch.
+///
+/// Explicit values always win. `tensor_split` falls back to shares
+/// proportional to free VRAM when `devices` holds two or more GPUs and the
+/// split mode spreads the model over them; `main_gpu` falls back to the GPU
+/// with the most free VRAM under `--split-mode none`. `split_mode` is a
+/// direct pass-through.
+#[must_use]
+pub fn resolve_gpu_args(
+    split_mode: Option<SplitMode>,
+    tensor_split: Option<TensorSplit>,
+    main_gpu: Option<u32>,
+    devices: &[GpuDevice],
+) -> GpuArgsResolution {
+    resolve_gpu_args_inner(
+        split_mode,
+        tensor_split,
+        main_gpu,
+        devices,
+        tensor_split_disabled_via_env(),
+    )
+}
+
+/// Pure core of [`resolve_gpu_args`], with the env lookup lifted into a
+/// parameter so the kill switch is testable without touching process state.
+fn resolve_gpu_args_inner(
+    split_mode: Option<SplitMode>,
+    tensor_split: Option<TensorSplit>,
+    main_gpu: Option<u32>,
+    devices: &[GpuDevice],
+    split_disabled: bool,
+) -> GpuArgsResolution {
+    let single_gpu = split_mode == Some(SplitMode::None);
+    let multi_gpu = devices.len() >= 2;
+
+    let (tensor_split, split_source) = match tensor_split {
+        Some(split) => (Some(split), TensorSplitSource::Explicit),
+        None if single_gpu || !multi_gpu => (None, TensorSplitSource::NotSplit),
+        None if split_disabled => (None, TensorSplitSource::DisabledByEnv),
+        None => free_vram_split(devices).map_or((None, TensorSplitSource::NotSplit), |split| {
+            (Some(split), TensorSplitSource::FreeVram)
+        }),
+    };
+
+    let main_gpu_explicit = main_gpu.is_some();
+    let main_gpu = main_gpu.or_else(|| {
+        (single_gpu && multi_gpu)
+            .then(|| devices.iter().max_by_key(|d| d.free_vram_bytes))
+            .flatten()
+            .map(|d| d.index)
+    });
+
+    GpuArgsResolution {
+        split_mode,
+        tensor_split,
+        main_gpu,
+        main_gpu_explicit,
+        split_source,
+    }
+}
+
+/// Shares proportional to each device's free VRAM, in GiB rounded to one
+/// decimal. `None` when no device reports any free VRAM.
+#[allow(clippy::cast_precision_loss, clippy::cast_possible_truncation)]
+fn free_vram_split(devices: &[GpuDevice]) -> Option<TensorSplit> {
+    let mut devices: Vec<&GpuDevice> = devices.iter().collect();
+    devices.sort_by_key(|d| d.index);
+    let shares = devices
+        .iter()
+        .map(|d| ((d.free_vram_bytes as f64 / GIB) * 10.0).round() as f32 / 10.0);
+    TensorSplit::new(shares).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn gpu(index: u32, free_gib: u64) -> GpuDevice {
+        GpuDevice {
+            index,
+            name: format!("GPU {index}"),
+            total_vram_bytes: 24 << 30,
+            free_vram_bytes: free_gib << 30,
+        }
+    }
+
+    #[test]
+    fn two_gpus_split_by_free_vram() {
+        let got = resolve_gpu_args_inner(None, None, None, &[gpu(1, 8), gpu(0, 20)], false);
+        assert_eq!(got.tensor_split.unwrap().to_string(), "20,8");
+        assert_eq!(got.split_source, TensorSplitSource::FreeVram);
+        assert_eq!(got.main_gpu, None);
+    }
+
+    #[test]
+    fn single_gpu_passes_no_flags() {
+        let got = resolve_gpu_args_inner(None, None, None, &[gpu(0, 20)], false);
+        assert_eq!(got.tensor_split, None);
+        assert_eq!(got.split_source, TensorSplitSource::NotSplit);
+        assert_eq!(got.explain(), None);
+    }
+
+    #[test]
+    fn split_mode_none_runs_on_the_gpu_with_most_free_vram() {
+        let devices = [gpu(0, 4), gpu(1, 20)];
+        let got = resolve_gpu_args_inner(Some(SplitMode::None), None, None, &devices, false);
+        assert_eq!(got.tensor_split, None);
+        assert_eq!(got.main_gpu, Some(1));
+        assert!(!got.main_gpu_explicit);
+    }
+
+    #[test]
+    fn explicit_values_pass_through() {
+        let split: TensorSplit = "3,1".parse().unwrap();
+        let got = resolve_gpu_args_inner(
+            Some(SplitMode::Row),
+            Some(split.clone()),
+            Some(1),
+            &[gpu(0, 20), gpu(1, 20)],
+            true,
+        );
+        assert_eq!(got.split_mode, Some(SplitMode::Row));
+        assert_eq!(got.tensor_split, Some(split));
+        assert_eq!(got.main_gpu, Some(1));
+        assert_eq!(got.split_source, TensorSplitSource::Explicit);
+        assert!(got.main_gpu_explicit);
+    }
+
+    #[test]
+    fn env_kill_switch_skips_automatic_split() {
+        let got = resolve_gpu_args_inner(None, None, None, &[gpu(0, 20), gpu(1, 8)], true);
+        assert_eq!(got.tensor_split, None);
+        assert_eq!(got.split_source, TensorSplitSource::DisabledByEnv);
+        assert!(
+            got.explain()
+                .unwrap()
+                .contains("GGLIB_DISABLE_TENSOR_SPLIT")
+        );
+    }
+}
@@ -0,0 +1,170 @@
+//! Parallel request slots (`--parallel`) argument resolution.
+//!
+//! By default every launch runs a single slot, so concurrent requests queue
+//! inside llama-server and each one gets the whole context (see the
+//! `--parallel` comment in `command.rs` for why sharing one context pool
+//! went wrong). More slots let several chat streams decode at once against
+//! one loaded model, at the cost of a full extra context's worth of KV cache
+//! per slot: the launch context is multiplied by the slot count so no
+//! request ever gets less room than it would have had alone.
+//!
+//! [`resolve_parallel`] picks the slot count. An explicit value always
+//! wins; otherwise it only goes above one when the KV footprint is known,
+//! the context is modest, disk slot persistence is off (it saves and
+//! restores slot 0 only), and the extra slots fit in half of the RAM left
+//! after the weights, the first slot and [`CACHE_RAM_HEADROOM_BYTES`] — the
+//! other half stays for the host-RAM prompt cache sized after it.
+
+use gglib_core::server_config::CACHE_RAM_HEADROOM_BYTES;
+
+/// Most slots the automatic default ever picks.
+pub const MAX_AUTO_PARALLEL: u32 = 4;
+
+/// Largest launch context the automatic default runs more than one slot
+/// for. Longer contexts are asked for by single long-document sessions,
+/// which parallel slots would only make more expensive.
+pub const MAX_AUTO_PARALLEL_CONTEXT: u64 = 32_768;
+
+/// Indicates how the slot count was chosen.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParallelSource {
+    /// Supplied by the request or the model's launch profile.
+    Explicit,
+    /// Derived from the context size and memory budget.
+    Auto,
+    /// Left at one slot: the KV footprint is unknown, the context is long,
+    /// slot persistence is on, or the memory is not there.
+    Serial,
+}
+
+/// Outcome of resolving the slot count.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParallelResolution {
+    /// Value for `--parallel`, at least 1.
+    pub slots: u32,
+    /// Why this value was chosen.
+    pub source: ParallelSource,
+}
+
+impl ParallelResolution {
+    /// A one-line explanation for the launch log, or `None` for a single
+    /// slot nobody asked for.
+    #[must_use]
+    pub fn explain(&self) -> Option<String> {
+        match self.source {
+            ParallelSource::Explicit => Some(format!("parallel slots: {} (explicit)", self.slots)),
+            ParallelSource::Auto => Some(format!(
+                "parallel slots: {} (auto from context size and free RAM) — override with \
+                 --parallel or the model's launch defaults",
+                self.slots
+            )),
+            ParallelSource::Serial => None,
+        }
+    }
+}
+
+/// Resolve the `--parallel` slot count for a launch.
+///
+/// # Arguments
+///
+/// * `explicit` — slot count from the request or the model's
+///   `server_defaults`; `Some(0)` is treated as 1.
+/// * `total_ram_bytes` — total physical RAM.
+/// * `model_bytes` — model weights on disk, all shards.
+/// * `kv_bytes_per_token` — per-token KV estimate; `None` keeps one slot.
+/// * `context_size` — context each slot launches with.
+/// * `persists_slots` — whether the launch has a `--slot-save-path`.
+#[must_use]
+pub fn resolve_parallel(
+    explicit: Option<u32>,
+    total_ram_bytes: u64,
+    model_bytes: u64,
+    kv_bytes_per_token: Option<u64>,
+    context_size: u64,
+    persists_slots: bool,
+) -> ParallelResolution {
+    if let Some(slots) = explicit {
+        return ParallelResolution {
+            slots: slots.max(1),
+            source: ParallelSource::Explicit,
+        };
+    }
+    let serial = ParallelResolution {
+        slots: 1,
+        source: ParallelSource::Serial,
+    };
+    let Some(per_token) = kv_bytes_per_token else {
+        return serial;
+    };
+    if persists_slots || context_size > MAX_AUTO_PARALLEL_CONTEXT {
+        return serial;
+    }
+
+    let kv_per_slot = gglib_core::domain::estimate_kv_bytes_for_context(per_token, context_size);
+    if kv_per_slot == 0 {
+        return serial;
+    }
+    let spare = total_ram_bytes
+        .saturating_sub(model_bytes)
+        .saturating_sub(kv_per_slot)
+        .saturating_sub(CACHE_RAM_HEADROOM_BYTES);
+    let extra = (spare / 2) / kv_per_slot;
+    let slots = u32::try_from(extra)
+        .unwrap_or(u32::MAX)
+        .saturating_add(1)
+        .min(MAX_AUTO_PARALLEL);
+    if slots == 1 {
+        return serial;
+    }
+    ParallelResolution {
+        slots,
+        source: ParallelSource::Auto,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const GIB: u64 = 1024 * 1024 * 1024;
+    /// 128 KiB per token: 1 GiB of KV per 8k-token slot.
+    const PER_TOKEN: u64 = 128 * 1024;
+
+    #[test]
+    fn explicit_wins_and_zero_means_one() {
+        let r = resolve_parallel(Some(3), 0, 0, None, 131_072, true);
+        assert_eq!((r.slots, r.source), (3, ParallelSource::Explicit));
+        assert_eq!(resolve_parallel(Some(0), 0, 0, None, 0, false).slots, 1);
+    }
+
+    #[test]
+    fn auto_scales_with_free_memory() {
+        // 64 GiB, 8 GiB model, 1 GiB per slot: plenty of room, capped.
+        let roomy = resolve_parallel(None, 64 * GIB, 8 * GIB, Some(PER_TOKEN), 8192, false);
+        assert_eq!(
+            (roomy.slots, roomy.source),
+            (MAX_AUTO_PARALLEL, ParallelSource::Auto)
+        );
+
+        // 28 GiB: 28 - 8 - 1 - 16 = 3 GiB spare, half of it buys one more.
+        let tighter = resolve_parallel(None, 28 * GIB, 8 * GIB, Some(PER_TOKEN), 8192, false);
+        assert_eq!(tighter.slots, 2);
+
+        // 24 GiB: nothing left after the headroom.
+        let tight = resolve_parallel(None, 24 * GIB, 8 * GIB, Some(PER_TOKEN), 8192, false);
+        assert_eq!((tight.slots, tight.source), (1, ParallelSource::Serial));
+    }
+
+    #[test]
+    fn auto_stays_serial_when_it_cannot_tell_or_should_not() {
+        let unknown_kv = resolve_parallel(None, 256 * GIB, GIB, None, 8192, false);
+        assert_eq!(unknown_kv.slots, 1);
+
+        let long_ctx = resolve_parallel(None, 256 * GIB, GIB, Some(PER_TOKEN), 65_536, false);
+        assert_eq!(long_ctx.slots, 1);
+
+        let persisted = resolve_parallel(None, 256 * GIB, GIB, Some(PER_TOKEN), 8192, true);
+        assert_eq!(persisted.slots, 1);
+        assert!(persisted.explain().is_none());
+    }
+}
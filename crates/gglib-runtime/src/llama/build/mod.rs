@@ -7,39 +7,95 @@ use super::detect::select_cuda_compiler_for_build;
 use anyhow::{Context, Result, bail};
 
 use super::build_events::{BuildEvent, BuildPhase};
-use std::io::{BufRead, BufReader};
 use std::path::Path;
-use std::process::{Command, Stdio};
-use std::sync::mpsc as std_mpsc;
-use std::thread;
+use std::process::Command;
 use tokio::sync::mpsc;
+use tokio_util::sync::CancellationToken;
+
+mod process;
+
+pub(super) use process::run_cancellable;
+
+/// Expected number of `-- ` status lines from a llama.cpp `cmake` configure.
+///
+/// cmake reports no progress while configuring, so the configure stage's
+/// [`BuildEvent::Progress`] counts status lines against this figure. The
+/// count is held just short of it until cmake exits.
+const CONFIGURE_STATUS_LINES: u64 = 60;
+
+/// Ctrl-C handling for a build run from a terminal.
+///
+/// Build subprocesses run in their own process group, so the terminal's
+/// SIGINT no longer reaches them; without this Ctrl-C would stop gglib and
+/// leave cmake and its compilers running. While the guard lives, Ctrl-C
+/// cancels [`token`](Self::token) instead. Installing a Ctrl-C handler
+/// replaces the default behaviour for the rest of the process, so once the
+/// guard is dropped (or on a second Ctrl-C) Ctrl-C exits the process the way
+/// it would have without the handler.
+pub struct CtrlCCancel {
+    token: CancellationToken,
+}
+
+impl CtrlCCancel {
+    /// Start routing Ctrl-C to a fresh token.
+    pub fn install() -> Self {
+        let token = CancellationToken::new();
+        let watched = token.clone();
+        tokio::spawn(async move {
+            while tokio::signal::ctrl_c().await.is_ok() {
+                if watched.is_cancelled() {
+                    std::process::exit(130);
+                }
+                watched.cancel();
+            }
+        });
+        Self { token }
+    }
+
+    /// The token to hand to the build.
+    pub fn token(&self) -> CancellationToken {
+        self.token.clone()
+    }
+}
+
+impl Drop for CtrlCCancel {
+    fn drop(&mut self) {
+        // The build is over; cancelling the token now only marks Ctrl-C as
+        // "exit" from here on.
+        self.token.cancel();
+    }
+}
 
 /// Build llama.cpp from source, emitting [`BuildEvent`] values over `tx`.
 ///
 /// Callers supply a sender so that progress can be rendered by any surface
 /// (CLI progress bar, Axum SSE, Tauri event) without this function knowing
-/// which interface is consuming the stream.
+/// which interface is consuming the stream. Cancelling `cancel` kills the
+/// running cmake process tree and returns [`LlamaError::Cancelled`](super::LlamaError::Cancelled).
 pub fn build_llama_cpp(
     llama_dir: &Path,
     acceleration: Acceleration,
     tx: &mpsc::Sender<BuildEvent>,
+    cancel: &CancellationToken,
 ) -> Result<()> {
     let build_dir = llama_dir.join("build");
     std::fs::create_dir_all(&build_dir).context("Failed to create build directory")?;
 
-    configure_cmake(llama_dir, &build_dir, acceleration, tx)?;
-    build_project(&build_dir, acceleration, tx)?;
+    configure_cmake(llama_dir, &build_dir, acceleration, tx, cancel)?;
+    build_project(&build_dir, acceleration, tx, cancel)?;
 
     Ok(())
 }
 
-/// Run `CMake` configuration, emitting a [`BuildEvent::PhaseStarted`] at the start
-/// and [`BuildEvent::Log`] for each non-empty subprocess output line.
+/// Run `CMake` configuration, emitting a [`BuildEvent::PhaseStarted`] at the start,
+/// [`BuildEvent::Log`] for each non-empty subprocess output line and an estimated
+/// [`BuildEvent::Progress`] for each status line.
 fn configure_cmake(
     llama_dir: &Path,
     build_dir: &Path,
     acceleration: Acceleration,
     tx: &mpsc::Sender<BuildEvent>,
+    cancel: &CancellationToken,
 ) -> Result<()> {
     let _ = tx.blocking_send(BuildEvent::PhaseStarted {
         phase: BuildPhase::Configure,
@@ -173,42 +229,32 @@ fn configure_cmake(
     args.extend(cuda_args.iter().map(|s| s.as_str()));
     cmd.args(&args);
 
-    let mut child = cmd
-        .stdout(Stdio::piped())
-        .stderr(Stdio::piped())
-        .spawn()
-        .context("Failed to run CMake")?;
-
-    let stdout = child.stdout.take().unwrap();
-    let stderr = child.stderr.take().unwrap();
-
-    let (line_tx, line_rx) = std_mpsc::channel();
-    let line_tx2 = line_tx.clone();
-
-    // Read stdout
-    thread::spawn(move || {
-        let reader = BufReader::new(stdout);
-        for line in reader.lines().map_while(Result::ok) {
-            let _ = line_tx.send(line);
+    let mut status_lines = 0;
+    let status = run_cancellable(cmd, cancel, |line| {
+        if line.trim().is_empty() {
+            return;
         }
-    });
-
-    // Read stderr
-    thread::spawn(move || {
-        let reader = BufReader::new(stderr);
-        for line in reader.lines().map_while(Result::ok) {
-            let _ = line_tx2.send(line);
-        }
-    });
-
-    while let Ok(line) = line_rx.recv() {
-        if !line.trim().is_empty() {
-            let _ = tx.blocking_send(BuildEvent::Log { message: line });
+        if line.starts_with("-- ") {
+            status_lines += 1;
+            let _ = tx.blocking_send(BuildEvent::Progress {
+                phase: BuildPhase::Configure,
+                current: status_lines.min(CONFIGURE_STATUS_LINES - 1),
+                total: CONFIGURE_STATUS_LINES,
+                target: None,
+            });
         }
+        let _ = tx.blocking_send(BuildEvent::Log { message: line });
+    })?;
+
+    if status.success() {
+        let _ = tx.blocking_send(BuildEvent::Progress {
+            phase: BuildPhase::Configure,
+            current: CONFIGURE_STATUS_LINES,
+            total: CONFIGURE_STATUS_LINES,
+            target: None,
+        });
     }
 
-    let status = child.wait().context("Failed to wait for CMake")?;
-
     let _ = tx.blocking_send(BuildEvent::PhaseCompleted {
         phase: BuildPhase::Configure,
     });
@@ -220,12 +266,13 @@ fn configure_cmake(
     Ok(())
 }
 
-/// Run `cmake --build`, emitting [`BuildEvent::Progress`] and [`BuildEvent::Log`]
-/// events as compilation proceeds.
+/// Run `cmake --build`, emitting [`BuildEvent::Progress`] (with the target being
+/// built) and [`BuildEvent::Log`] events as compilation proceeds.
 fn build_project(
     build_dir: &Path,
     acceleration: Acceleration,
     tx: &mpsc::Sender<BuildEvent>,
+    cancel: &CancellationToken,
 ) -> Result<()> {
     let _ = tx.blocking_send(BuildEvent::PhaseStarted {
         phase: BuildPhase::Compile,
@@ -238,59 +285,44 @@ fn build_project(
     let cxxflags = merge_flags("CXXFLAGS", "-O1");
     let cflags = merge_flags("CFLAGS", "-O1");
 
-    let mut child = Command::new("cmake")
-        .env("CXXFLAGS", cxxflags)
-        .env("CFLAGS", cflags)
-        .args([
-            "--build",
-            build_dir.to_str().unwrap(),
-            "--config",
-            "Release",
-            "-j",
-            &num_cores.to_string(),
-        ])
-        .stdout(Stdio::piped())
-        .stderr(Stdio::piped())
-        .spawn()
-        .context("Failed to run build")?;
-
-    let stdout = child.stdout.take().unwrap();
-    let stderr = child.stderr.take().unwrap();
-
-    let (line_tx, line_rx) = std_mpsc::channel();
-    let line_tx2 = line_tx.clone();
-
-    // Read stdout
-    thread::spawn(move || {
-        let reader = BufReader::new(stdout);
-        for line in reader.lines().map_while(Result::ok) {
-            let _ = line_tx.send(line);
-        }
-    });
-
-    // Read stderr
-    thread::spawn(move || {
-        let reader = BufReader::new(stderr);
-        for line in reader.lines().map_while(Result::ok) {
-            let _ = line_tx2.send(line);
-        }
-    });
+    let mut cmd = Command::new("cmake");
+    cmd.env("CXXFLAGS", cxxflags).env("CFLAGS", cflags).args([
+        "--build",
+        build_dir.to_str().unwrap(),
+        "--config",
+        "Release",
+        "-j",
+        &num_cores.to_string(),
+    ]);
 
     let mut last_progress = 0;
     let mut total_files = 100; // Default estimate
+    let mut current_target: Option<String> = None;
 
     // Process output and update progress
-    while let Ok(line) = line_rx.recv() {
+    let status = run_cancellable(cmd, cancel, |line| {
         // Parse build progress from output
         // Look for patterns like "[ 50%]" or "[150/200]"
+        let mut changed = false;
         if let Some(progress) = parse_build_progress(&line, &mut total_files)
             && progress > last_progress
         {
+            last_progress = progress;
+            changed = true;
+        }
+        if let Some(target) = parse_build_target(&line)
+            && current_target.as_deref() != Some(target)
+        {
+            current_target = Some(target.to_owned());
+            changed = true;
+        }
+        if changed {
             let _ = tx.blocking_send(BuildEvent::Progress {
-                current: progress as u64,
+                phase: BuildPhase::Compile,
+                current: last_progress as u64,
                 total: total_files as u64,
+                target: current_target.clone(),
             });
-            last_progress = progress;
         }
 
         // Show important lines: build progress, errors, and warnings
@@ -305,9 +337,7 @@ fn build_project(
         {
             let _ = tx.blocking_send(BuildEvent::Log { message: line });
         }
-    }
-
-    let status = child.wait().context("Failed to wait for build")?;
+    })?;
 
     let _ = tx.blocking_send(BuildEvent::PhaseCompleted {
         phase: BuildPhase::Compile,
@@ -355,6 +385,33 @@ fn merge_flags(var: &str, extra: &str) -> String {
     format!("{existing} {extra}").trim().to_owned()
 }
 
+/// Extract the cmake target a build output line refers to.
+///
+/// Understands the Makefile and Ninja generators' lines:
+/// `Building CXX object src/CMakeFiles/llama.dir/llama.cpp.o` → `llama`,
+/// `Linking CXX executable ../../bin/llama-server` → `llama-server`, and
+/// `Built target llama` → `llama`.
+fn parse_build_target(line: &str) -> Option<&str> {
+    if let Some(start) = line
+        .find("CMakeFiles/")
+        .or_else(|| line.find("CMakeFiles\\"))
+    {
+        let rest = &line[start + "CMakeFiles/".len()..];
+        return rest.find(".dir").map(|end| &rest[..end]);
+    }
+    if let Some(start) = line.find("Built target ") {
+        return line[start + "Built target ".len()..]
+            .split_whitespace()
+            .next();
+    }
+    if line.contains("Linking ") {
+        let artifact = line.split_whitespace().last()?;
+        let name = artifact.rsplit(['/', '\\']).next()?;
+        return Some(name.split('.').next().unwrap_or(name));
+    }
+    None
+}
+
 fn parse_build_progress(line: &str, total_files: &mut usize) -> Option<usize> {
     // Match "[ 50%]" pattern
     if let Some(start) = line.find('[')
@@ -409,6 +466,29 @@ mod tests {
         assert_eq!(total, 200);
     }
 
+    #[test]
+    fn test_parse_build_target() {
+        assert_eq!(
+            parse_build_target(
+                "[ 12%] Building C object ggml/src/CMakeFiles/ggml-base.dir/ggml.c.o"
+            ),
+            Some("ggml-base")
+        );
+        assert_eq!(
+            parse_build_target("[ 98%] Linking CXX executable ../../bin/llama-server"),
+            Some("llama-server")
+        );
+        assert_eq!(
+            parse_build_target("[150/200] Linking CXX shared library bin/libllama.so"),
+            Some("libllama")
+        );
+        assert_eq!(
+            parse_build_target("[ 40%] Built target llama"),
+            Some("llama")
+        );
+        assert_eq!(parse_build_target("-- Configuring done"), None);
+    }
+
     #[test]
     fn test_parse_build_progress_no_match() {
         let mut total = 100;
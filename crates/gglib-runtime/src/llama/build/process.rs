@@ -0,0 +1,179 @@
+//! Running the build's subprocesses so that a cancel can stop them.
+//!
+//! cmake fans out into a compiler per translation unit, so killing the
+//! cmake process alone would leave the compilers running. On Unix each
+//! subprocess is started in its own process group and the whole group is
+//! signalled; on Windows `taskkill /T` walks the tree.
+
+use std::io::{BufRead, BufReader, Read};
+use std::process::{Child, Command, ExitStatus, Stdio};
+use std::sync::mpsc as std_mpsc;
+use std::thread;
+use std::time::Duration;
+
+use anyhow::{Context, Result};
+use tokio_util::sync::CancellationToken;
+
+use crate::llama::error::LlamaError;
+
+/// How often the output loop looks at the cancellation token while the
+/// subprocess is quiet.
+const CANCEL_POLL: Duration = Duration::from_millis(200);
+
+/// How long a process group gets to exit after SIGTERM before SIGKILL.
+#[cfg(unix)]
+const TERM_GRACE: Duration = Duration::from_secs(2);
+
+/// Run `cmd`, handing each stdout/stderr line to `on_line`, until it exits
+/// or `cancel` fires.
+///
+/// On cancel the process tree is killed and [`LlamaError::Cancelled`] is
+/// returned; otherwise the exit status is returned for the caller to judge.
+pub(in crate::llama) fn run_cancellable(
+    mut cmd: Command,
+    cancel: &CancellationToken,
+    mut on_line: impl FnMut(String),
+) -> Result<ExitStatus> {
+    if cancel.is_cancelled() {
+        return Err(LlamaError::Cancelled.into());
+    }
+
+    let program = cmd.get_program().to_string_lossy().into_owned();
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::process::CommandExt;
+        cmd.process_group(0);
+    }
+
+    let mut child = cmd
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .with_context(|| format!("Failed to run {program}"))?;
+
+    let (line_tx, line_rx) = std_mpsc::channel();
+    forward_lines(child.stdout.take(), line_tx.clone());
+    forward_lines(child.stderr.take(), line_tx);
+
+    loop {
+        match line_rx.recv_timeout(CANCEL_POLL) {
+            Ok(line) => on_line(line),
+            Err(std_mpsc::RecvTimeoutError::Timeout) => {}
+            Err(std_mpsc::RecvTimeoutError::Disconnected) => break,
+        }
+        if cancel.is_cancelled() {
+            kill_tree(&mut child);
+            return Err(LlamaError::Cancelled.into());
+        }
+    }
+
+    // Both pipes are closed; the process is exiting (or has closed its
+    // output and kept running, which a cancel must still be able to stop).
+    loop {
+        if let Some(status) = child
+            .try_wait()
+            .with_context(|| format!("Failed to wait for {program}"))?
+        {
+            return Ok(status);
+        }
+        if cancel.is_cancelled() {
+            kill_tree(&mut child);
+            return Err(LlamaError::Cancelled.into());
+        }
+        thread::sleep(CANCEL_POLL);
+    }
+}
+
+/// Read `pipe` line by line on an OS thread and send each line to `tx`.
+fn forward_lines(pipe: Option<impl Read + Send + 'static>, tx: std_mpsc::Sender<String>) {
+    let Some(pipe) = pipe else {
+        return;
+    };
+    thread::spawn(move || {
+        for line in BufReader::new(pipe).lines().map_while(Result::ok) {
+            if tx.send(line).is_err() {
+                break;
+            }
+        }
+    });
+}
+
+/// Kill `child` and everything it started, then reap it.
+#[cfg(unix)]
+fn kill_tree(child: &mut Child) {
+    use nix::sys::signal::{Signal, killpg};
+    use nix::unistd::Pid;
+
+    let Ok(raw) = i32::try_from(child.id()) else {
+        let _ = child.kill();
+        let _ = child.wait();
+        return;
+    };
+    let group = Pid::from_raw(raw);
+
+    let _ = killpg(group, Signal::SIGTERM);
+    let polls = TERM_GRACE.as_millis() / CANCEL_POLL.as_millis();
+    for _ in 0..polls {
+        if matches!(child.try_wait(), Ok(Some(_))) {
+            break;
+        }
+        thread::sleep(CANCEL_POLL);
+    }
+    // The leader may be gone while compilers in its group are not.
+    let _ = killpg(group, Signal::SIGKILL);
+    let _ = child.wait();
+}
+
+/// Kill `child` and everything it started, then reap it.
+#[cfg(not(unix))]
+fn kill_tree(child: &mut Child) {
+    let killed = gglib_core::utils::process::cmd("taskkill")
+        .args(["/PID", &child.id().to_string(), "/T", "/F"])
+        .output()
+        .is_ok_and(|output| output.status.success());
+    if !killed {
+        let _ = child.kill();
+    }
+    let _ = child.wait();
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+
+    use std::time::Instant;
+
+    #[test]
+    fn lines_are_forwarded_and_status_returned() {
+        let mut cmd = Command::new("sh");
+        cmd.args(["-c", "echo one; echo two >&2; exit 3"]);
+        let mut lines = Vec::new();
+        let status = run_cancellable(cmd, &CancellationToken::new(), |l| lines.push(l)).unwrap();
+        lines.sort();
+        assert_eq!(lines, ["one", "two"]);
+        assert_eq!(status.code(), Some(3));
+    }
+
+    #[test]
+    fn cancel_kills_the_process_group() {
+        let cancel = CancellationToken::new();
+        let trigger = cancel.clone();
+        // The grandchild `sleep` is what a compiler under cmake looks like.
+        let mut cmd = Command::new("sh");
+        cmd.args(["-c", "echo started; sleep 30 & wait"]);
+
+        let started = Instant::now();
+        let err = run_cancellable(cmd, &cancel, |_| trigger.cancel()).unwrap_err();
+        assert!(LlamaError::is_cancellation(&err));
+        assert!(started.elapsed() < Duration::from_secs(10));
+    }
+
+    #[test]
+    fn cancelled_token_never_spawns() {
+        let cancel = CancellationToken::new();
+        cancel.cancel();
+        let err = run_cancellable(Command::new("true"), &cancel, |_| {}).unwrap_err();
+        assert!(LlamaError::is_cancellation(&err));
+    }
+}
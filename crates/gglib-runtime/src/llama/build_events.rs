@@ -12,6 +12,10 @@
 //! The sender end is a `tokio::sync::mpsc::Sender<BuildEvent>` with capacity 64.
 //! When the sender is dropped the consumer loop terminates naturally.
 //!
+//! The pipeline also takes a `CancellationToken`. Cancelling it kills the
+//! running subprocess tree and ends the stream with [`BuildEvent::Cancelled`]
+//! instead of [`BuildEvent::Failed`].
+//!
 //! The event type is **not** feature-gated: all three surfaces import [`BuildEvent`]
 //! and [`BuildPhase`] unconditionally. Only the pipeline that *produces* the events
 //! (in `build/` and `install/`) is gated behind `feature = "cli"`.
@@ -46,6 +50,19 @@ pub enum BuildPhase {
     InstallBinaries,
 }
 
+impl BuildPhase {
+    /// Short human-readable name of the stage.
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::DependencyCheck => "Checking dependencies",
+            Self::CloneOrUpdateRepo => "Fetching llama.cpp source",
+            Self::Configure => "Configuring",
+            Self::Compile => "Compiling",
+            Self::InstallBinaries => "Installing binaries",
+        }
+    }
+}
+
 // =============================================================================
 // BuildEvent
 // =============================================================================
@@ -76,15 +93,24 @@ pub enum BuildEvent {
         message: String,
     },
 
-    /// Compilation progress reported by cmake.
+    /// Progress within the configure or compile stage.
     ///
-    /// Both `current` and `total` are file counts derived from cmake output
-    /// patterns such as `[ 50%]` (mapped to `50/100`) or `[150/300]`.
+    /// During [`BuildPhase::Compile`], `current` and `total` are file counts
+    /// derived from cmake output patterns such as `[ 50%]` (mapped to
+    /// `50/100`) or `[150/300]`. During [`BuildPhase::Configure`] cmake
+    /// reports no progress of its own, so they count its `-- ` status lines
+    /// against an estimate; the estimate is only settled when the phase
+    /// completes.
     Progress {
-        /// Files compiled so far.
+        /// The stage the progress belongs to.
+        phase: BuildPhase,
+        /// Units done so far.
         current: u64,
-        /// Total files to compile.
+        /// Units in total.
         total: u64,
+        /// The cmake target being built (e.g. `"llama-server"`), when the
+        /// output names one.
+        target: Option<String>,
     },
 
     /// A pipeline stage has finished successfully.
@@ -107,4 +133,9 @@ pub enum BuildEvent {
         /// Human-readable description of the failure.
         message: String,
     },
+
+    /// The pipeline was stopped through its cancellation token. Any running
+    /// cmake or git process tree has been killed; the partial build
+    /// directory is left for the next build to reuse.
+    Cancelled,
 }
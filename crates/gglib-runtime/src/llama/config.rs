@@ -2,6 +2,7 @@
 
 #[cfg(feature = "cli")]
 use super::detect::Acceleration;
+use super::source::LlamaSource;
 use anyhow::{Context, Result};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
@@ -21,6 +22,9 @@ pub struct BuildConfig {
     pub acceleration: String,
     /// `CMake` flags used
     pub cmake_flags: Vec<String>,
+    /// Repository and ref the build came from (upstream for older configs)
+    #[serde(default)]
+    pub source: LlamaSource,
 }
 
 impl BuildConfig {
@@ -37,9 +41,17 @@ impl BuildConfig {
                 .iter()
                 .map(|s| s.to_string())
                 .collect(),
+            source: LlamaSource::default(),
         }
     }
 
+    /// Record the repository and ref the build came from
+    #[cfg(feature = "cli")]
+    pub fn with_source(mut self, source: LlamaSource) -> Self {
+        self.source = source;
+        self
+    }
+
     /// Save configuration to file
     #[cfg(feature = "cli")]
     pub fn save(&self, path: &Path) -> Result<()> {
@@ -58,7 +70,6 @@ impl BuildConfig {
 
 #[cfg(test)]
 mod tests {
-    #[cfg(feature = "cli")]
     use super::*;
     #[cfg(feature = "cli")]
     use tempfile::tempdir;
@@ -73,7 +84,11 @@ mod tests {
             "b1234".to_string(),
             "abc123def456".to_string(),
             Acceleration::Metal,
-        );
+        )
+        .with_source(LlamaSource::new(
+            Some("https://github.com/me/llama.cpp".to_string()),
+            Some("my-patches".to_string()),
+        ));
 
         original.save(&config_path).unwrap();
         let loaded = BuildConfig::load(&config_path).unwrap();
@@ -82,5 +97,19 @@ mod tests {
         assert_eq!(original.commit_sha, loaded.commit_sha);
         assert_eq!(original.acceleration, loaded.acceleration);
         assert_eq!(original.cmake_flags, loaded.cmake_flags);
+        assert_eq!(original.source, loaded.source);
+    }
+
+    #[test]
+    fn test_build_config_without_source_is_upstream() {
+        let json = r#"{
+            "version": "abc123",
+            "commit_sha": "abc123def456",
+            "build_date": "2025-01-01T00:00:00Z",
+            "acceleration": "CPU",
+            "cmake_flags": []
+        }"#;
+        let config: BuildConfig = serde_json::from_str(json).unwrap();
+        assert!(config.source.is_upstream());
     }
 }
@@ -1,8 +1,8 @@
 #![doc = include_str!("README.md")]
+#[cfg(feature = "cli")]
+use crate::llama::{CliProgress, ProgressReporter};
 #[cfg(feature = "prebuilt")]
 use anyhow::{Context, Result, bail};
-#[cfg(feature = "cli")]
-use indicatif::{ProgressBar, ProgressStyle};
 #[cfg(feature = "prebuilt")]
 use reqwest::Client;
 #[cfg(feature = "prebuilt")]
@@ -259,13 +259,12 @@ async fn download_with_callback_internal(
     // Use progress bar only when no callback provided AND cli feature enabled (CLI mode)
     #[cfg(feature = "cli")]
     let pb = if callback.is_none() {
-        let pb = ProgressBar::new(total_size);
-        pb.set_style(
-            ProgressStyle::default_bar()
-                .template("{spinner:.green} [{elapsed_precise}] [{bar:40.cyan/blue}] {bytes}/{total_bytes} ({bytes_per_sec})")
-                .unwrap()
-                .progress_chars("█▓░"),
+        let pb = CliProgress::new();
+        let name = dest.file_name().map_or_else(
+            || url.to_string(),
+            |name| name.to_string_lossy().into_owned(),
         );
+        pb.start(&format!("Downloading {name}"), Some(total_size));
         Some(pb)
     } else {
         None
@@ -293,7 +292,7 @@ async fn download_with_callback_internal(
 
         #[cfg(feature = "cli")]
         if let Some(ref pb) = pb {
-            pb.set_position(downloaded);
+            pb.update(downloaded, Some(total_size));
         }
         if let Some(ref cb) = callback {
             cb(downloaded, total_size);
@@ -302,7 +301,7 @@ async fn download_with_callback_internal(
 
     #[cfg(feature = "cli")]
     if let Some(pb) = pb {
-        pb.finish_with_message("Download complete");
+        pb.finish("Download complete");
     }
 
     Ok(())
@@ -3,12 +3,14 @@ use gglib_core::paths::{is_prebuilt_binary, llama_cpp_dir, llama_server_path};
 use std::io::{self, Write};
 use tokio::sync::mpsc;
 
-use super::build_events::BuildEvent;
+use super::build::CtrlCCancel;
+use super::build_events::{BuildEvent, BuildPhase};
 use super::detect::detect_optimal_acceleration;
 use super::download::{
     PrebuiltAvailability, check_prebuilt_availability, download_prebuilt_binaries,
 };
 use super::install::run_llama_source_build;
+use super::source::LlamaSource;
 
 // Helper to convert PathError to anyhow::Error
 fn path_err<T>(r: Result<T, gglib_core::paths::PathError>) -> Result<T> {
@@ -151,20 +153,30 @@ async fn install_from_source() -> Result<()> {
     let server_path = path_err(llama_server_path())?;
 
     let (tx, mut rx) = mpsc::channel::<BuildEvent>(64);
+    let ctrl_c = CtrlCCancel::install();
     let build = tokio::spawn(run_llama_source_build(
         acceleration,
+        LlamaSource::recorded(),
         llama_dir,
         server_path,
         tx,
+        ctrl_c.token(),
     ));
 
     while let Some(event) = rx.recv().await {
         match event {
             BuildEvent::PhaseStarted { phase } => println!("→ {:?}", phase),
             BuildEvent::Log { message } => println!("  {}", message),
-            BuildEvent::Progress { current, total } => {
-                println!("  [{}/{}] Compiling...", current, total);
+            BuildEvent::Progress {
+                phase: BuildPhase::Compile,
+                current,
+                total,
+                target,
+            } => {
+                let target = target.as_deref().unwrap_or("llama.cpp");
+                println!("  [{}/{}] Compiling {}...", current, total, target);
             }
+            BuildEvent::Progress { .. } => {}
             BuildEvent::PhaseCompleted { .. } => {}
             BuildEvent::Completed { version, .. } => {
                 println!("✓ Build complete ({})", version);
@@ -172,6 +184,7 @@ async fn install_from_source() -> Result<()> {
             BuildEvent::Failed { message } => {
                 eprintln!("✗ Build failed: {}", message);
             }
+            BuildEvent::Cancelled => eprintln!("✗ Build cancelled"),
         }
     }
 
@@ -88,6 +88,15 @@ impl LlamaError {
     pub fn other(err: impl std::fmt::Display) -> Self {
         Self::Other(err.to_string())
     }
+
+    /// Whether `err` is, or was caused by, [`LlamaError::Cancelled`].
+    ///
+    /// Build pipeline functions return `anyhow::Error`; this tells a
+    /// cancelled build apart from a failed one.
+    pub fn is_cancellation(err: &anyhow::Error) -> bool {
+        err.chain()
+            .any(|cause| matches!(cause.downcast_ref::<Self>(), Some(Self::Cancelled)))
+    }
 }
 
 /// Result type alias for llama operations
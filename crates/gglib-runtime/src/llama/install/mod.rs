@@ -1,17 +1,17 @@
 #![doc = include_str!("README.md")]
-use super::build::build_llama_cpp;
+use super::build::{build_llama_cpp, run_cancellable};
 use super::build_events::{BuildEvent, BuildPhase};
 use super::config::BuildConfig;
 use super::detect::Acceleration;
+use super::error::LlamaError;
+use super::source::{LlamaSource, server_compat_warnings};
 use anyhow::{Context, Result, bail};
 use gglib_core::paths::{llama_bench_path, llama_config_path};
 use gglib_core::utils::process::cmd;
 use std::fs;
-use std::io::{BufRead, BufReader};
 use std::path::{Path, PathBuf};
-use std::process::Stdio;
-use std::thread;
 use tokio::sync::mpsc;
+use tokio_util::sync::CancellationToken;
 
 // Helper to convert PathError to anyhow::Error
 fn path_err<T>(r: Result<T, gglib_core::paths::PathError>) -> Result<T> {
@@ -20,46 +20,78 @@ fn path_err<T>(r: Result<T, gglib_core::paths::PathError>) -> Result<T> {
 
 /// Core streaming build pipeline for llama.cpp from source.
 ///
-/// Clones or reuses the repository, configures, compiles, installs binaries, and saves
-/// the build configuration. All progress is emitted as [`BuildEvent`] values on `tx`.
+/// Clones, reuses or re-points the repository at `source`, configures, compiles,
+/// installs binaries, and saves the build configuration. All progress is emitted
+/// as [`BuildEvent`] values on `tx`. Builds from anything other than upstream's
+/// default branch also log a warning for each llama-server flag gglib relies on
+/// that the built binary doesn't accept.
 ///
 /// This function has no CLI concerns (no user prompts, no dependency checks). Callers
 /// must perform pre-flight validation before calling this.
 ///
+/// # Cancellation
+///
+/// Cancelling `cancel` kills the running git or cmake process tree, emits
+/// [`BuildEvent::Cancelled`] and returns [`LlamaError::Cancelled`]; use
+/// [`LlamaError::is_cancellation`] to tell it apart from a failure. Once the
+/// binaries are being installed the build runs to completion.
+///
 /// # Threading
 ///
-/// Blocking subprocess work ([`clone_llama_cpp`], [`build_llama_cpp`]) runs inside
+/// Blocking subprocess work ([`checkout_llama_cpp`], [`build_llama_cpp`]) runs inside
 /// [`tokio::task::spawn_blocking`] so the Tokio executor is never blocked and
 /// `blocking_send` calls are always on OS threads.
 pub async fn run_llama_source_build(
     acceleration: Acceleration,
+    source: LlamaSource,
     llama_dir: PathBuf,
     server_path: PathBuf,
     tx: mpsc::Sender<BuildEvent>,
+    cancel: CancellationToken,
 ) -> Result<()> {
-    // Step 1: Clone or reuse repository.
-    let (version, commit_sha) = if llama_dir.exists() {
-        let _ = tx
-            .send(BuildEvent::Log {
-                message: "Using existing llama.cpp repository.".to_string(),
-            })
-            .await;
-        get_repo_info(&llama_dir)?
-    } else {
+    let result =
+        source_build_steps(acceleration, source, llama_dir, server_path, &tx, cancel).await;
+    if let Err(e) = &result
+        && LlamaError::is_cancellation(e)
+    {
+        let _ = tx.send(BuildEvent::Cancelled).await;
+    }
+    result
+}
+
+async fn source_build_steps(
+    acceleration: Acceleration,
+    source: LlamaSource,
+    llama_dir: PathBuf,
+    server_path: PathBuf,
+    tx: &mpsc::Sender<BuildEvent>,
+    cancel: CancellationToken,
+) -> Result<()> {
+    // Step 1: Clone, reuse or re-point the repository.
+    let (version, commit_sha) = {
         let tx_clone = tx.clone();
         let dir = llama_dir.clone();
-        tokio::task::spawn_blocking(move || clone_llama_cpp(&dir, &tx_clone)).await??
+        let source = source.clone();
+        let cancel = cancel.clone();
+        tokio::task::spawn_blocking(move || checkout_llama_cpp(&dir, &source, &tx_clone, &cancel))
+            .await??
     };
 
     // Step 2: Configure and compile.
     {
         let tx_clone = tx.clone();
         let dir = llama_dir.clone();
-        tokio::task::spawn_blocking(move || build_llama_cpp(&dir, acceleration, &tx_clone))
-            .await??;
+        let cancel = cancel.clone();
+        tokio::task::spawn_blocking(move || {
+            build_llama_cpp(&dir, acceleration, &tx_clone, &cancel)
+        })
+        .await??;
     }
 
     // Step 3: Install binaries.
+    if cancel.is_cancelled() {
+        return Err(LlamaError::Cancelled.into());
+    }
     {
         let tx_clone = tx.clone();
         let dir = llama_dir.clone();
@@ -79,12 +111,25 @@ pub async fn run_llama_source_build(
         .await??;
     }
 
-    // Step 4: Persist build configuration.
-    let config = BuildConfig::new(version.clone(), commit_sha, acceleration);
+    // Step 4: Warn when a fork's llama-server lacks flags gglib passes.
+    if !source.is_upstream() {
+        let sp = server_path.clone();
+        let warnings = tokio::task::spawn_blocking(move || server_compat_warnings(&sp)).await?;
+        for warning in warnings {
+            let _ = tx
+                .send(BuildEvent::Log {
+                    message: format!("⚠ {warning}"),
+                })
+                .await;
+        }
+    }
+
+    // Step 5: Persist build configuration.
+    let config = BuildConfig::new(version.clone(), commit_sha, acceleration).with_source(source);
     let config_path = path_err(llama_config_path())?;
     config.save(&config_path)?;
 
-    // Step 5: Signal successful completion.
+    // Step 6: Signal successful completion.
     let _ = tx
         .send(BuildEvent::Completed {
             version,
@@ -95,57 +140,89 @@ pub async fn run_llama_source_build(
     Ok(())
 }
 
-/// Clone the llama.cpp repository, routing subprocess output through `tx`.
+/// Make `llama_dir` a checkout of `source`, routing git output through `tx`.
+///
+/// An existing checkout of the same repository is reused as-is unless a ref
+/// was asked for; otherwise its `origin` is re-pointed and the ref fetched
+/// and checked out (detached), so switching between upstream and a fork
+/// needs no reinstall.
 ///
 /// Git progress lines containing `\r` (animated carriage-return output) are filtered
 /// out to avoid corrupting SSE streams. Only clean, newline-terminated informational
 /// lines are emitted as [`BuildEvent::Log`].
-fn clone_llama_cpp(llama_dir: &Path, tx: &mpsc::Sender<BuildEvent>) -> Result<(String, String)> {
+fn checkout_llama_cpp(
+    llama_dir: &Path,
+    source: &LlamaSource,
+    tx: &mpsc::Sender<BuildEvent>,
+    cancel: &CancellationToken,
+) -> Result<(String, String)> {
+    let existing = llama_dir.exists();
+    if existing
+        && source.git_ref.is_none()
+        && origin_url(llama_dir).is_some_and(|url| source.matches_remote(&url))
+    {
+        let _ = tx.blocking_send(BuildEvent::Log {
+            message: "Using existing llama.cpp repository.".to_string(),
+        });
+        return get_repo_info(llama_dir);
+    }
+
     let _ = tx.blocking_send(BuildEvent::PhaseStarted {
         phase: BuildPhase::CloneOrUpdateRepo,
     });
+    let _ = tx.blocking_send(BuildEvent::Log {
+        message: format!("Source: {source}"),
+    });
 
-    if let Some(parent) = llama_dir.parent() {
-        fs::create_dir_all(parent).context("Failed to create parent directory")?;
-    }
-
-    let mut child = cmd("git")
-        .args([
-            "clone",
-            "--depth=1",
-            "https://github.com/ggerganov/llama.cpp",
-            llama_dir.to_str().unwrap(),
-        ])
-        .stdout(Stdio::piped())
-        .stderr(Stdio::piped())
-        .spawn()
-        .context("Failed to run git clone")?;
-
-    let stderr = child.stderr.take().unwrap();
-
-    // Git writes all progress to stderr. Read on an OS thread (blocking I/O).
-    // Carriage-return progress lines (e.g. "Receiving objects: 45%\r") are
-    // filtered: BufRead::lines() keeps \r as trailing content; any line
-    // containing \r is dropped to avoid corrupting SSE streams.
-    let tx_reader = tx.clone();
-    thread::spawn(move || {
-        let reader = BufReader::new(stderr);
-        for line in reader.lines().map_while(Result::ok) {
-            if line.trim().is_empty() || line.contains('\r') {
-                continue;
-            }
-            if tx_reader
-                .blocking_send(BuildEvent::Log { message: line })
-                .is_err()
-            {
-                break;
-            }
+    let dir = llama_dir.to_str().unwrap();
+    if existing {
+        run_git(
+            &["-C", dir, "remote", "set-url", "origin", &source.repo],
+            "point llama.cpp repository at new remote",
+            tx,
+            cancel,
+        )?;
+    } else {
+        if let Some(parent) = llama_dir.parent() {
+            fs::create_dir_all(parent).context("Failed to create parent directory")?;
         }
-    });
+        let mut args = vec!["clone", "--depth=1"];
+        // `--branch` takes branches and tags; a SHA is fetched below.
+        if let Some(git_ref) = source.git_ref.as_deref()
+            && !source.is_pinned()
+        {
+            args.extend(["--branch", git_ref]);
+        }
+        args.extend([source.repo.as_str(), dir]);
+        run_git(&args, "clone llama.cpp repository", tx, cancel).inspect_err(|e| {
+            // A half-cloned repository would be mistaken for a complete one by
+            // the next build.
+            if LlamaError::is_cancellation(e) {
+                let _ = fs::remove_dir_all(llama_dir);
+            }
+        })?;
+    }
 
-    let status = child.wait().context("Failed to wait for git clone")?;
-    if !status.success() {
-        bail!("Failed to clone llama.cpp repository");
+    if existing || source.is_pinned() {
+        run_git(
+            &[
+                "-C",
+                dir,
+                "fetch",
+                "--depth=1",
+                "origin",
+                source.fetch_ref(),
+            ],
+            &format!("fetch {}", source.fetch_ref()),
+            tx,
+            cancel,
+        )?;
+        run_git(
+            &["-C", dir, "checkout", "--detach", "FETCH_HEAD"],
+            &format!("check out {}", source.fetch_ref()),
+            tx,
+            cancel,
+        )?;
     }
 
     let _ = tx.blocking_send(BuildEvent::PhaseCompleted {
@@ -155,6 +232,51 @@ fn clone_llama_cpp(llama_dir: &Path, tx: &mpsc::Sender<BuildEvent>) -> Result<(S
     get_repo_info(llama_dir)
 }
 
+/// Run one git command for [`checkout_llama_cpp`]; a non-zero exit fails
+/// with "Failed to {what}".
+fn run_git(
+    args: &[&str],
+    what: &str,
+    tx: &mpsc::Sender<BuildEvent>,
+    cancel: &CancellationToken,
+) -> Result<()> {
+    let mut git = cmd("git");
+    git.args(args);
+
+    // Git writes all progress to stderr. Carriage-return progress lines
+    // (e.g. "Receiving objects: 45%\r") are filtered: BufRead::lines() keeps
+    // \r as trailing content; any line containing \r is dropped to avoid
+    // corrupting SSE streams.
+    let status = run_cancellable(git, cancel, |line| {
+        if line.trim().is_empty() || line.contains('\r') {
+            return;
+        }
+        let _ = tx.blocking_send(BuildEvent::Log { message: line });
+    })?;
+    if !status.success() {
+        bail!("Failed to {what}");
+    }
+    Ok(())
+}
+
+/// The `origin` URL of the checkout in `llama_dir`, if it has one.
+pub(super) fn origin_url(llama_dir: &Path) -> Option<String> {
+    let output = cmd("git")
+        .args([
+            "-C",
+            llama_dir.to_str()?,
+            "config",
+            "--get",
+            "remote.origin.url",
+        ])
+        .output()
+        .ok()?;
+    output
+        .status
+        .success()
+        .then(|| String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
 /// Get version and commit info from repository
 fn get_repo_info(llama_dir: &std::path::Path) -> Result<(String, String)> {
     // Get commit SHA
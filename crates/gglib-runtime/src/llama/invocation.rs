@@ -3,7 +3,7 @@
 //! This module provides a DRY abstraction for building llama-server
 //! command invocations, eliminating duplication between serve and other commands.
 
-use gglib_core::domain::InferenceConfig;
+use gglib_core::domain::{InferenceConfig, RopeConfig};
 use std::path::{Path, PathBuf};
 use std::process::Command;
 
@@ -27,6 +27,7 @@ pub struct LlamaCommandBuilder {
     context_size: Option<u64>,
     mlock: bool,
     inference_config: Option<InferenceConfig>,
+    rope: Option<RopeConfig>,
     additional_args: Vec<(String, Option<String>)>,
 }
 
@@ -39,6 +40,7 @@ impl LlamaCommandBuilder {
             context_size: None,
             mlock: false,
             inference_config: None,
+            rope: None,
             additional_args: Vec::new(),
         }
     }
@@ -61,6 +63,12 @@ impl LlamaCommandBuilder {
         self
     }
 
+    /// Set `RoPE` scaling overrides (`--rope-scaling`, `--rope-freq-base`, …).
+    pub fn rope(mut self, rope: RopeConfig) -> Self {
+        self.rope = Some(rope);
+        self
+    }
+
     /// Add an additional flag with an optional value.
     ///
     /// # Arguments
@@ -88,7 +96,8 @@ impl LlamaCommandBuilder {
     /// 1. Model path (`-m`)
     /// 2. Context size (`-c`) if resolved
     /// 3. Memory lock (`--mlock`) if enabled
-    /// 4. Additional flags in order they were added
+    /// 4. Inference and `RoPE` overrides if set
+    /// 5. Additional flags in order they were added
     pub fn build(self) -> Command {
         let mut cmd = Command::new(&self.binary_path);
 
@@ -113,6 +122,13 @@ impl LlamaCommandBuilder {
             }
         }
 
+        // RoPE scaling overrides
+        if let Some(rope) = &self.rope {
+            for arg in rope.to_cli_args() {
+                cmd.arg(arg);
+            }
+        }
+
         // Additional flags
         for (key, value) in self.additional_args {
             cmd.arg(key);
@@ -194,6 +210,23 @@ mod tests {
         assert!(args.contains(&"--mlock".into()));
     }
 
+    #[test]
+    fn builder_adds_rope_overrides() {
+        let cmd = LlamaCommandBuilder::new("/usr/bin/llama-server", "/models/test.gguf")
+            .rope(RopeConfig {
+                freq_scale: Some(0.5),
+                ..Default::default()
+            })
+            .build();
+
+        let args: Vec<_> = cmd.get_args().map(|a| a.to_string_lossy()).collect();
+        let idx = args
+            .iter()
+            .position(|a| a == "--rope-freq-scale")
+            .expect("--rope-freq-scale should be present");
+        assert_eq!(args[idx + 1], "0.5");
+    }
+
     #[test]
     fn builder_adds_custom_flags() {
         let cmd = LlamaCommandBuilder::new("/usr/bin/llama-server", "/models/test.gguf")
@@ -1,6 +1,7 @@
 #![doc = include_str!("README.md")]
 // === Submodules ===
 
+mod arch_support;
 pub mod args;
 #[cfg(feature = "cli")]
 mod build;
@@ -18,6 +19,7 @@ pub mod invocation;
 pub mod progress;
 pub mod prompt;
 mod server_availability;
+mod source;
 #[cfg(feature = "cli")]
 mod uninstall;
 #[cfg(feature = "cli")]
@@ -47,16 +49,21 @@ pub use progress::CliProgress;
 pub use prompt::CliPrompt;
 
 // Core functionality
+pub use arch_support::InstalledArchitectureSupport;
+pub use config::BuildConfig;
 pub use detect::{
     Acceleration, MissingPackage, VulkanStatus, detect_optimal_acceleration, vulkan_status,
 };
 pub use download::check_llama_installed;
 #[cfg(feature = "cli")]
 pub use ensure::ensure_llama_initialized;
+pub use source::{LlamaSource, UPSTREAM_REPO};
 pub use validate::{handle_status, validate_llama_binary};
 
 // Installation (CLI only)
 #[cfg(feature = "cli")]
+pub use build::CtrlCCancel;
+#[cfg(feature = "cli")]
 pub use install::run_llama_source_build;
 #[cfg(feature = "cli")]
 pub use uninstall::handle_uninstall;
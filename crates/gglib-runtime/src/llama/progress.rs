@@ -6,7 +6,8 @@
 //!
 //! # Feature Flags
 //!
-//! - `cli`: Enables `CliProgress` which uses `indicatif` for terminal progress bars.
+//! - `cli`: Enables `CliProgress` which uses `indicatif` for terminal progress bars
+//!   (or status lines in plain mode).
 //!   Without this feature, only `NoopProgress` is available.
 
 /// Trait for receiving progress updates during long-running operations.
@@ -59,12 +60,24 @@ impl ProgressReporter for NoopProgress {
 #[cfg(feature = "cli")]
 pub mod cli_progress {
     use super::ProgressReporter;
-    use indicatif::{ProgressBar, ProgressStyle};
+    use gglib_core::progress::{StatusThrottle, percent};
+    use indicatif::{HumanBytes, ProgressBar, ProgressStyle};
     use std::sync::Mutex;
+    use std::time::Instant;
 
-    /// CLI progress reporter with terminal progress bars.
+    /// CLI progress reporter with terminal progress bars, or status lines in
+    /// plain mode (see [`gglib_core::progress`]).
     pub struct CliProgress {
         bar: Mutex<Option<ProgressBar>>,
+        /// Set instead of `bar` in plain mode.
+        plain: Mutex<Option<PlainStatus>>,
+    }
+
+    /// The operation in progress, as plain mode reports it.
+    struct PlainStatus {
+        message: String,
+        started: Instant,
+        throttle: StatusThrottle,
     }
 
     impl CliProgress {
@@ -72,6 +85,7 @@ pub mod cli_progress {
         pub fn new() -> Self {
             Self {
                 bar: Mutex::new(None),
+                plain: Mutex::new(None),
             }
         }
 
@@ -97,6 +111,19 @@ pub mod cli_progress {
             );
             pb
         }
+
+        /// End the plain-mode operation with a summary line.
+        fn finish_plain(&self, mark: &str, message: &str) -> bool {
+            let Some(status) = self.plain.lock().unwrap().take() else {
+                return false;
+            };
+            let elapsed = status.started.elapsed().as_secs_f64();
+            println!(
+                "{mark} {message} ({})",
+                gglib_core::download::format_duration(Some(elapsed))
+            );
+            true
+        }
     }
 
     impl Default for CliProgress {
@@ -107,6 +134,18 @@ pub mod cli_progress {
 
     impl ProgressReporter for CliProgress {
         fn start(&self, message: &str, total: Option<u64>) {
+            if gglib_core::progress::is_plain() {
+                match total.filter(|t| *t > 0) {
+                    Some(t) => println!("{message} ({})", HumanBytes(t)),
+                    None => println!("{message}"),
+                }
+                *self.plain.lock().unwrap() = Some(PlainStatus {
+                    message: message.to_string(),
+                    started: Instant::now(),
+                    throttle: StatusThrottle::default(),
+                });
+                return;
+            }
             let pb = match total {
                 Some(t) if t > 0 => Self::create_download_bar(t),
                 _ => Self::create_spinner(),
@@ -118,6 +157,24 @@ pub mod cli_progress {
         }
 
         fn update(&self, current: u64, total: Option<u64>) {
+            if let Some(status) = self.plain.lock().unwrap().as_mut() {
+                // `start` has just printed a line; the first status follows
+                // one interval later.
+                if status.started.elapsed() >= gglib_core::progress::STATUS_INTERVAL
+                    && status.throttle.ready(Instant::now())
+                {
+                    match total.and_then(|t| percent(current, t).map(|pct| (t, pct))) {
+                        Some((t, pct)) => println!(
+                            "{}: {} / {} ({pct:.1}%)",
+                            status.message,
+                            HumanBytes(current),
+                            HumanBytes(t)
+                        ),
+                        None => println!("{}: {}", status.message, HumanBytes(current)),
+                    }
+                }
+                return;
+            }
             let guard = self.bar.lock().unwrap();
             if let Some(ref pb) = *guard {
                 if let Some(t) = total {
@@ -137,6 +194,9 @@ pub mod cli_progress {
         }
 
         fn finish(&self, message: &str) {
+            if self.finish_plain("✓", message) {
+                return;
+            }
             let mut guard = self.bar.lock().unwrap();
             if let Some(pb) = guard.take() {
                 pb.finish_with_message(message.to_string());
@@ -144,6 +204,9 @@ pub mod cli_progress {
         }
 
         fn finish_with_error(&self, message: &str) {
+            if self.finish_plain("✗", message) {
+                return;
+            }
             let mut guard = self.bar.lock().unwrap();
             if let Some(pb) = guard.take() {
                 pb.abandon_with_message(message.to_string());
@@ -0,0 +1,197 @@
+//! Where llama.cpp source builds come from.
+//!
+//! Source builds clone upstream llama.cpp by default. `gglib config llama
+//! install --repo <url> --ref <branch|sha>` points them at a fork instead;
+//! the choice is recorded in [`BuildConfig`](super::config::BuildConfig) so
+//! `update` and later rebuilds keep tracking it.
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// The upstream llama.cpp repository.
+pub const UPSTREAM_REPO: &str = "https://github.com/ggerganov/llama.cpp";
+
+/// llama-server flags gglib passes when launching models.
+///
+/// A fork that has dropped or renamed one of these will start, but the
+/// features behind it (chat templates, reasoning, slot restore, …) fail at
+/// model launch instead of at install time.
+#[cfg(any(feature = "cli", test))]
+const EXPECTED_SERVER_FLAGS: &[&str] = &[
+    "--jinja",
+    "--reasoning-format",
+    "--slot-save-path",
+    "--cache-reuse",
+    "--cache-type-k",
+    "--cache-type-v",
+    "--parallel",
+    "--metrics",
+];
+
+/// Git repository and ref a source build is made from.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LlamaSource {
+    /// Clone URL.
+    pub repo: String,
+    /// Branch, tag or commit SHA; `None` tracks the remote's default branch.
+    #[serde(rename = "ref", default)]
+    pub git_ref: Option<String>,
+}
+
+impl Default for LlamaSource {
+    fn default() -> Self {
+        Self {
+            repo: UPSTREAM_REPO.to_string(),
+            git_ref: None,
+        }
+    }
+}
+
+impl LlamaSource {
+    /// Build from `repo` (upstream when `None`) at `git_ref`.
+    pub fn new(repo: Option<String>, git_ref: Option<String>) -> Self {
+        Self {
+            repo: repo.unwrap_or_else(|| UPSTREAM_REPO.to_string()),
+            git_ref,
+        }
+    }
+
+    /// The source recorded by the last install, or upstream when there is
+    /// none.
+    ///
+    /// Rebuilds that aren't given a source explicitly use this so they
+    /// don't silently switch a fork install back to upstream.
+    pub fn recorded() -> Self {
+        gglib_core::paths::llama_config_path()
+            .ok()
+            .filter(|path| path.exists())
+            .and_then(|path| super::config::BuildConfig::load(&path).ok())
+            .map(|config| config.source)
+            .unwrap_or_default()
+    }
+
+    /// Whether this is upstream llama.cpp's default branch.
+    pub fn is_upstream(&self) -> bool {
+        same_repo(&self.repo, UPSTREAM_REPO) && self.git_ref.is_none()
+    }
+
+    /// Whether the ref is a commit SHA, which `update` cannot move.
+    pub fn is_pinned(&self) -> bool {
+        self.git_ref.as_deref().is_some_and(looks_like_sha)
+    }
+
+    /// The refspec to fetch: the configured ref, or the remote's `HEAD`.
+    pub fn fetch_ref(&self) -> &str {
+        self.git_ref.as_deref().unwrap_or("HEAD")
+    }
+
+    /// Whether `url` (typically `remote.origin.url`) names this repository.
+    pub fn matches_remote(&self, url: &str) -> bool {
+        same_repo(&self.repo, url)
+    }
+}
+
+impl fmt::Display for LlamaSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.git_ref {
+            Some(git_ref) => write!(f, "{} @ {}", self.repo, git_ref),
+            None => f.write_str(&self.repo),
+        }
+    }
+}
+
+/// Compare clone URLs, ignoring a trailing `/` or `.git`.
+fn same_repo(a: &str, b: &str) -> bool {
+    fn normalize(url: &str) -> &str {
+        let url = url.trim().trim_end_matches('/');
+        url.strip_suffix(".git").unwrap_or(url)
+    }
+    normalize(a).eq_ignore_ascii_case(normalize(b))
+}
+
+fn looks_like_sha(git_ref: &str) -> bool {
+    (7..=40).contains(&git_ref.len()) && git_ref.bytes().all(|b| b.is_ascii_hexdigit())
+}
+
+/// Expected llama-server flags missing from its `--help` output.
+#[cfg(any(feature = "cli", test))]
+pub(super) fn missing_server_flags(help: &str) -> Vec<&'static str> {
+    EXPECTED_SERVER_FLAGS
+        .iter()
+        .copied()
+        .filter(|flag| {
+            !help
+                .split(|c: char| c.is_whitespace() || c == ',')
+                .any(|word| {
+                    word == *flag || word.strip_prefix(*flag).is_some_and(|r| r.starts_with('='))
+                })
+        })
+        .collect()
+}
+
+/// Compatibility warnings for a freshly built llama-server.
+///
+/// Empty when the binary accepts every flag gglib relies on. A binary whose
+/// `--help` can't be read yields a single warning rather than an error: the
+/// install itself succeeded.
+#[cfg(feature = "cli")]
+pub(super) fn server_compat_warnings(server_path: &std::path::Path) -> Vec<String> {
+    let help = match gglib_core::utils::process::cmd(server_path)
+        .arg("--help")
+        .output()
+    {
+        Ok(output) => {
+            let mut help = String::from_utf8_lossy(&output.stdout).into_owned();
+            help.push_str(&String::from_utf8_lossy(&output.stderr));
+            help
+        }
+        Err(e) => return vec![format!("Could not run llama-server --help: {e}")],
+    };
+
+    missing_server_flags(&help)
+        .into_iter()
+        .map(|flag| {
+            format!("llama-server does not support {flag}; gglib features that use it will fail")
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_is_upstream() {
+        let source = LlamaSource::default();
+        assert!(source.is_upstream());
+        assert_eq!(source.fetch_ref(), "HEAD");
+        assert!(LlamaSource::new(Some(format!("{UPSTREAM_REPO}.git")), None).is_upstream());
+        assert!(!LlamaSource::new(None, Some("b5000".into())).is_upstream());
+    }
+
+    #[test]
+    fn sha_refs_are_pinned() {
+        let sha = |r: &str| LlamaSource::new(None, Some(r.to_string())).is_pinned();
+        assert!(sha("a1b2c3d"));
+        assert!(sha("0123456789abcdef0123456789abcdef01234567"));
+        assert!(!sha("master"));
+        assert!(!sha("beef"));
+        assert!(!LlamaSource::default().is_pinned());
+    }
+
+    #[test]
+    fn remote_matching_ignores_git_suffix() {
+        let source = LlamaSource::new(Some("https://github.com/me/llama.cpp".into()), None);
+        assert!(source.matches_remote("https://github.com/me/llama.cpp.git\n"));
+        assert!(!source.matches_remote(UPSTREAM_REPO));
+    }
+
+    #[test]
+    fn missing_flags_are_reported() {
+        let help = "--jinja  use jinja\n--reasoning-format FORMAT\n-np, --parallel N\n\
+                    --slot-save-path PATH\n--cache-reuse N\n-ctk, --cache-type-k TYPE\n\
+                    -ctv, --cache-type-v TYPE\n";
+        assert_eq!(missing_server_flags(help), ["--metrics"]);
+        assert!(missing_server_flags(&format!("{help} --metrics")).is_empty());
+    }
+}
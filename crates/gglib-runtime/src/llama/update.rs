@@ -1,14 +1,16 @@
 //! Update command for llama.cpp.
 
-use super::build::build_llama_cpp;
+use super::build::{CtrlCCancel, build_llama_cpp};
 use super::build_events::BuildEvent;
 use super::config::BuildConfig;
 use super::detect::{Acceleration, detect_optimal_acceleration};
-use super::install::install_binary;
+use super::install::{install_binary, origin_url};
+use super::source::{LlamaSource, server_compat_warnings};
 use anyhow::{Context, Result, bail};
 use gglib_core::paths::{llama_config_path, llama_cpp_dir, llama_server_path};
 use gglib_core::utils::process::cmd;
 use std::io::{self, Write};
+use std::path::Path;
 use tokio::sync::mpsc;
 
 // Helper to convert PathError to anyhow::Error
@@ -48,19 +50,25 @@ pub async fn handle_check_updates() -> Result<()> {
         config.build_date.format("%Y-%m-%d")
     );
     println!("Acceleration: {}", config.acceleration);
+    if !config.source.is_upstream() {
+        println!("Source: {}", config.source);
+    }
     println!();
-    println!("Checking for updates...");
-
-    // Fetch latest from remote
-    let status = cmd("git")
-        .args(["-C", llama_dir.to_str().unwrap(), "fetch", "origin"])
-        .status()
-        .context("Failed to fetch updates")?;
 
-    if !status.success() {
-        bail!("Failed to fetch updates from remote");
+    if config.source.is_pinned() {
+        println!(
+            "✓ llama.cpp is pinned to commit {}",
+            config.source.fetch_ref()
+        );
+        println!("Reinstall with 'gglib config llama install --force --ref <ref>' to move it.");
+        return Ok(());
     }
 
+    println!("Checking for updates...");
+
+    // Fetch latest from the tracked repository and ref
+    fetch_source(&llama_dir, &config.source)?;
+
     // Check if we're behind
     let output = cmd("git")
         .args([
@@ -68,7 +76,7 @@ pub async fn handle_check_updates() -> Result<()> {
             llama_dir.to_str().unwrap(),
             "rev-list",
             "--count",
-            "HEAD..origin/master",
+            "HEAD..FETCH_HEAD",
         ])
         .output()
         .context("Failed to check for updates")?;
@@ -92,7 +100,7 @@ pub async fn handle_check_updates() -> Result<()> {
             "--oneline",
             "-n",
             "5",
-            "HEAD..origin/master",
+            "HEAD..FETCH_HEAD",
         ])
         .output()
         .context("Failed to get commit log")?;
@@ -147,6 +155,16 @@ pub async fn handle_update() -> Result<()> {
         detect_optimal_acceleration()?
     };
 
+    let source = old_config
+        .as_ref()
+        .map(|config| config.source.clone())
+        .unwrap_or_default();
+    if source.is_pinned() {
+        println!("llama.cpp is pinned to commit {}.", source.fetch_ref());
+        println!("Reinstall with 'gglib config llama install --force --ref <ref>' to move it.");
+        return Ok(());
+    }
+
     println!("Updating llama.cpp...");
     println!();
 
@@ -154,6 +172,9 @@ pub async fn handle_update() -> Result<()> {
         println!("Current version: {}", config.version);
         println!("Build config: {}", config.acceleration);
     }
+    if !source.is_upstream() {
+        println!("Source: {}", source);
+    }
 
     println!();
     println!("This will:");
@@ -176,13 +197,14 @@ pub async fn handle_update() -> Result<()> {
     // Pull latest changes
     println!();
     println!("Pulling latest changes...");
+    fetch_source(&llama_dir, &source)?;
     let status = cmd("git")
         .args([
             "-C",
             llama_dir.to_str().unwrap(),
-            "pull",
-            "origin",
-            "master",
+            "checkout",
+            "--detach",
+            "FETCH_HEAD",
         ])
         .status()
         .context("Failed to pull updates")?;
@@ -214,13 +236,22 @@ pub async fn handle_update() -> Result<()> {
 
     // Rebuild
     let (build_tx, _build_rx) = mpsc::channel::<BuildEvent>(64);
-    build_llama_cpp(&llama_dir, acceleration, &build_tx)?;
+    let ctrl_c = CtrlCCancel::install();
+    build_llama_cpp(&llama_dir, acceleration, &build_tx, &ctrl_c.token())?;
+    drop(ctrl_c);
 
     // Install binaries
     install_binary(&llama_dir, "llama-server", &binary_path)?;
 
+    // A fork may have drifted from the llama-server flags gglib passes.
+    if !source.is_upstream() {
+        for warning in server_compat_warnings(&binary_path) {
+            println!("⚠ {}", warning);
+        }
+    }
+
     // Save new configuration
-    let config = BuildConfig::new(version.clone(), commit_sha, acceleration);
+    let config = BuildConfig::new(version.clone(), commit_sha, acceleration).with_source(source);
     config.save(&config_path)?;
 
     println!();
@@ -230,3 +261,28 @@ pub async fn handle_update() -> Result<()> {
 
     Ok(())
 }
+
+/// Fetch the tracked ref of `source` into `FETCH_HEAD`, re-pointing `origin`
+/// first if the checkout was cloned from elsewhere.
+fn fetch_source(llama_dir: &Path, source: &LlamaSource) -> Result<()> {
+    let dir = llama_dir.to_str().unwrap();
+    if !origin_url(llama_dir).is_some_and(|url| source.matches_remote(&url)) {
+        let status = cmd("git")
+            .args(["-C", dir, "remote", "set-url", "origin", &source.repo])
+            .status()
+            .context("Failed to set repository remote")?;
+        if !status.success() {
+            bail!("Failed to point llama.cpp repository at {}", source.repo);
+        }
+    }
+
+    let status = cmd("git")
+        .args(["-C", dir, "fetch", "origin", source.fetch_ref()])
+        .status()
+        .context("Failed to fetch updates")?;
+
+    if !status.success() {
+        bail!("Failed to fetch updates from {}", source);
+    }
+    Ok(())
+}
@@ -82,6 +82,7 @@ pub async fn handle_status() -> Result<()> {
                 println!("Build Information:");
                 println!("  Version: {}", config.version);
                 println!("  Commit: {}", config.commit_sha);
+                println!("  Source: {}", config.source);
                 println!(
                     "  Built: {}",
                     config.build_date.format("%Y-%m-%d %H:%M:%S UTC")
@@ -0,0 +1,625 @@
+//! Query the rotating application log files.
+//!
+//! [`gglib_core::telemetry::init_tracing`] writes one plain-text file per UTC
+//! day (`gglib.log.YYYY-MM-DD`). This module lists those files and reads them
+//! back as structured [`LogRecord`]s, filtered by level, target and time
+//! range, one page at a time.
+//!
+//! Every record is addressed by a [`LogCursor`] (file name plus line number).
+//! A query without a cursor returns the newest matches; passing
+//! [`LogPage::older`] as `before` pages back through history, and passing
+//! [`LogPage::latest`] as `after` returns only what was written since — which
+//! is how the log viewer follows the tail.
+
+use std::cmp::Ordering;
+use std::collections::VecDeque;
+use std::fmt;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader};
+use std::ops::ControlFlow;
+use std::path::Path;
+use std::str::FromStr;
+
+use chrono::{DateTime, NaiveDate, Utc};
+use gglib_core::telemetry::LOG_FILE_NAME;
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+use utoipa::{IntoParams, ToSchema};
+
+/// Records returned when a query does not set a limit.
+pub const DEFAULT_LOG_PAGE_SIZE: usize = 200;
+
+/// Upper bound on records per page.
+pub const MAX_LOG_PAGE_SIZE: usize = 1000;
+
+/// Severity of a log record, ordered from least to most severe.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize, TS, ToSchema,
+)]
+#[serde(rename_all = "lowercase")]
+pub enum LogLevel {
+    Trace,
+    Debug,
+    Info,
+    Warn,
+    Error,
+}
+
+impl LogLevel {
+    /// Parse the level column as `tracing-subscriber` writes it.
+    fn from_column(s: &str) -> Option<Self> {
+        match s {
+            "TRACE" => Some(Self::Trace),
+            "DEBUG" => Some(Self::Debug),
+            "INFO" => Some(Self::Info),
+            "WARN" => Some(Self::Warn),
+            "ERROR" => Some(Self::Error),
+            _ => None,
+        }
+    }
+}
+
+/// A log file in the log directory.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, TS, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct LogFile {
+    /// File name, e.g. `gglib.log.2026-03-14`.
+    pub name: String,
+    /// Size on disk in bytes.
+    pub size_bytes: u64,
+    /// Last modification time, when the platform reports it.
+    pub modified: Option<DateTime<Utc>>,
+}
+
+/// Position of a record: the file it is in and the line it starts on
+/// (1-based). Serialized as `"<file>:<line>"`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, TS)]
+#[serde(try_from = "String", into = "String")]
+#[ts(type = "string")]
+pub struct LogCursor {
+    pub file: String,
+    pub line: usize,
+}
+
+impl Ord for LogCursor {
+    /// File names carry the date, so name order is chronological order.
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.file.cmp(&other.file).then(self.line.cmp(&other.line))
+    }
+}
+
+impl PartialOrd for LogCursor {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl fmt::Display for LogCursor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.file, self.line)
+    }
+}
+
+impl FromStr for LogCursor {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (file, line) = s
+            .rsplit_once(':')
+            .ok_or_else(|| format!("invalid log cursor '{s}': expected <file>:<line>"))?;
+        let line = line
+            .parse()
+            .map_err(|_| format!("invalid log cursor '{s}': line is not a number"))?;
+        Ok(Self {
+            file: file.to_string(),
+            line,
+        })
+    }
+}
+
+impl TryFrom<String> for LogCursor {
+    type Error = String;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl From<LogCursor> for String {
+    fn from(cursor: LogCursor) -> Self {
+        cursor.to_string()
+    }
+}
+
+/// One event read back from a log file.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, TS, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct LogRecord {
+    #[schema(value_type = String)]
+    pub cursor: LogCursor,
+    pub timestamp: DateTime<Utc>,
+    pub level: LogLevel,
+    /// Module path that emitted the event; absent in files written before
+    /// targets were logged.
+    pub target: Option<String>,
+    /// Enclosing spans as written, e.g. `download.job{id=3}:`.
+    pub spans: Option<String>,
+    /// Message and fields; continuation lines are joined with `\n`.
+    pub message: String,
+}
+
+/// Filters and paging for [`query_logs`]. Every field is optional.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, TS, ToSchema, IntoParams)]
+#[into_params(parameter_in = Query)]
+#[serde(rename_all = "camelCase")]
+pub struct LogQuery {
+    /// Only read this file (see [`list_log_files`]).
+    #[serde(default)]
+    pub file: Option<String>,
+    /// Minimum severity.
+    #[serde(default)]
+    pub level: Option<LogLevel>,
+    /// Target prefix, e.g. `gglib_download`.
+    #[serde(default)]
+    pub target: Option<String>,
+    /// Case-insensitive substring of the message.
+    #[serde(default)]
+    pub contains: Option<String>,
+    /// Only records at or after this time.
+    #[serde(default)]
+    pub since: Option<DateTime<Utc>>,
+    /// Only records before this time.
+    #[serde(default)]
+    pub until: Option<DateTime<Utc>>,
+    /// Return the newest matches older than this record.
+    #[serde(default)]
+    #[schema(value_type = Option<String>)]
+    #[param(value_type = Option<String>)]
+    pub before: Option<LogCursor>,
+    /// Return the oldest matches newer than this record.
+    #[serde(default)]
+    #[schema(value_type = Option<String>)]
+    #[param(value_type = Option<String>)]
+    pub after: Option<LogCursor>,
+    /// Records per page (default [`DEFAULT_LOG_PAGE_SIZE`], at most
+    /// [`MAX_LOG_PAGE_SIZE`]).
+    #[serde(default)]
+    pub limit: Option<usize>,
+}
+
+impl LogQuery {
+    fn page_size(&self) -> usize {
+        self.limit
+            .unwrap_or(DEFAULT_LOG_PAGE_SIZE)
+            .clamp(1, MAX_LOG_PAGE_SIZE)
+    }
+
+    fn matches(&self, record: &LogRecord) -> bool {
+        if self.level.is_some_and(|min| record.level < min) {
+            return false;
+        }
+        if let Some(prefix) = &self.target
+            && !record
+                .target
+                .as_deref()
+                .is_some_and(|t| t.starts_with(prefix.as_str()))
+        {
+            return false;
+        }
+        if self.since.is_some_and(|since| record.timestamp < since)
+            || self.until.is_some_and(|until| record.timestamp >= until)
+        {
+            return false;
+        }
+        if let Some(needle) = &self.contains
+            && !record
+                .message
+                .to_lowercase()
+                .contains(&needle.to_lowercase())
+        {
+            return false;
+        }
+        true
+    }
+
+    /// Whether a file can hold records in the requested time range.
+    fn covers_file(&self, name: &str) -> bool {
+        let Some(date) = file_date(name) else {
+            return true;
+        };
+        !(self.since.is_some_and(|since| date < since.date_naive())
+            || self.until.is_some_and(|until| date > until.date_naive()))
+    }
+}
+
+/// One page of matching records, oldest first.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, TS, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct LogPage {
+    pub records: Vec<LogRecord>,
+    /// Pass as `before` for the previous page; `None` when there are no
+    /// older matches.
+    #[schema(value_type = Option<String>)]
+    pub older: Option<LogCursor>,
+    /// Pass as `after` to fetch only records written since this page.
+    #[schema(value_type = Option<String>)]
+    pub latest: Option<LogCursor>,
+}
+
+/// List the log files in `dir`, oldest first.
+///
+/// # Errors
+///
+/// Returns an error if the directory cannot be read. A missing directory
+/// yields an empty list.
+pub fn list_log_files(dir: &Path) -> io::Result<Vec<LogFile>> {
+    let entries = match std::fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(e),
+    };
+
+    let mut files = Vec::new();
+    for entry in entries {
+        let entry = entry?;
+        let name = entry.file_name().to_string_lossy().into_owned();
+        if !name.starts_with(LOG_FILE_NAME) {
+            continue;
+        }
+        let metadata = entry.metadata()?;
+        if !metadata.is_file() {
+            continue;
+        }
+        files.push(LogFile {
+            name,
+            size_bytes: metadata.len(),
+            modified: metadata.modified().ok().map(DateTime::<Utc>::from),
+        });
+    }
+    files.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(files)
+}
+
+/// Read one page of records from the log files in `dir`.
+///
+/// Without `after`, the page holds the newest matches (before `before`, if
+/// set). With `after`, it holds the oldest matches following that cursor.
+/// Files are read in full on every call; daily rotation keeps them small
+/// enough for that to be cheap.
+///
+/// # Errors
+///
+/// Returns an error if the directory or a log file cannot be read.
+pub fn query_logs(dir: &Path, query: &LogQuery) -> io::Result<LogPage> {
+    let limit = query.page_size();
+    let files: Vec<LogFile> = list_log_files(dir)?
+        .into_iter()
+        .filter(|f| query.file.as_ref().is_none_or(|name| &f.name == name))
+        .filter(|f| query.after.as_ref().is_none_or(|c| f.name >= c.file))
+        .filter(|f| query.before.as_ref().is_none_or(|c| f.name <= c.file))
+        .filter(|f| query.covers_file(&f.name))
+        .collect();
+
+    let mut window: VecDeque<LogRecord> = VecDeque::with_capacity(limit);
+    let mut dropped_older = false;
+    let mut latest = query.after.clone();
+
+    'files: for file in &files {
+        let flow = for_each_record(dir, &file.name, |record| {
+            if query.after.as_ref().is_some_and(|c| record.cursor <= *c) {
+                return ControlFlow::Continue(());
+            }
+            if query.before.as_ref().is_some_and(|c| record.cursor >= *c) {
+                return ControlFlow::Break(());
+            }
+            latest = Some(record.cursor.clone());
+            if !query.matches(&record) {
+                return ControlFlow::Continue(());
+            }
+
+            if query.after.is_some() {
+                window.push_back(record);
+                if window.len() == limit {
+                    return ControlFlow::Break(());
+                }
+            } else {
+                if window.len() == limit {
+                    window.pop_front();
+                    dropped_older = true;
+                }
+                window.push_back(record);
+            }
+            ControlFlow::Continue(())
+        })?;
+        if flow.is_break() {
+            break 'files;
+        }
+    }
+
+    let records: Vec<LogRecord> = window.into();
+    Ok(LogPage {
+        older: if dropped_older {
+            records.first().map(|r| r.cursor.clone())
+        } else {
+            None
+        },
+        latest,
+        records,
+    })
+}
+
+/// Call `f` with each record in `dir/name`, stopping early on `Break`.
+fn for_each_record(
+    dir: &Path,
+    name: &str,
+    mut f: impl FnMut(LogRecord) -> ControlFlow<()>,
+) -> io::Result<ControlFlow<()>> {
+    let file = match File::open(dir.join(name)) {
+        Ok(file) => file,
+        // Rotated away between listing and reading.
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(ControlFlow::Continue(())),
+        Err(e) => return Err(e),
+    };
+
+    let mut pending: Option<LogRecord> = None;
+    for (index, line) in BufReader::new(file).split(b'\n').enumerate() {
+        let line = line?;
+        let line = strip_ansi(String::from_utf8_lossy(&line).trim_end_matches('\r'));
+        let cursor = LogCursor {
+            file: name.to_string(),
+            line: index + 1,
+        };
+        match parse_line(cursor, &line) {
+            Some(record) => {
+                if let Some(done) = pending.replace(record)
+                    && f(done).is_break()
+                {
+                    return Ok(ControlFlow::Break(()));
+                }
+            }
+            // Multi-line messages (panics, pretty-printed values) continue
+            // the previous record.
+            None => {
+                if let Some(record) = pending.as_mut() {
+                    record.message.push('\n');
+                    record.message.push_str(&line);
+                }
+            }
+        }
+    }
+    Ok(match pending {
+        Some(record) => f(record),
+        None => ControlFlow::Continue(()),
+    })
+}
+
+/// Parse a line in `tracing-subscriber`'s default format:
+/// `<timestamp> <LEVEL> [<spans>: ]<target>: <message>`.
+/// Returns `None` for lines that do not start a record.
+fn parse_line(cursor: LogCursor, line: &str) -> Option<LogRecord> {
+    let (timestamp, rest) = line.split_once(' ')?;
+    let timestamp = DateTime::parse_from_rfc3339(timestamp)
+        .ok()?
+        .with_timezone(&Utc);
+    let rest = rest.trim_start();
+    let (level, rest) = rest.split_once(' ').unwrap_or((rest, ""));
+    let level = LogLevel::from_column(level)?;
+    let (spans, target, message) = split_context(rest.trim_start());
+
+    Some(LogRecord {
+        cursor,
+        timestamp,
+        level,
+        target: target.map(str::to_string),
+        spans: spans.map(str::to_string),
+        message: message.to_string(),
+    })
+}
+
+/// Split `[<spans> ]<target>: <message>` into its parts.
+///
+/// Spans are written back to back (`a{x=1}:b:`) with no space outside their
+/// field braces, so the first space-delimited token is either the span list
+/// or the target.
+fn split_context(rest: &str) -> (Option<&str>, Option<&str>, &str) {
+    let (first, after_first) = next_token(rest);
+    let Some(first_bare) = first.strip_suffix(':') else {
+        return (None, None, rest);
+    };
+    let (second, after_second) = next_token(after_first);
+    let second_target = second.strip_suffix(':').filter(|t| is_target(t));
+
+    if is_target(first_bare) && second_target.is_none() {
+        (None, Some(first_bare), after_first)
+    } else if let Some(target) = second_target {
+        (Some(first), Some(target), after_second)
+    } else {
+        (Some(first), None, after_first)
+    }
+}
+
+/// The next token up to a space outside `{}`, and the text after it.
+fn next_token(s: &str) -> (&str, &str) {
+    let mut depth = 0usize;
+    for (i, c) in s.char_indices() {
+        match c {
+            '{' => depth += 1,
+            '}' => depth = depth.saturating_sub(1),
+            ' ' if depth == 0 => return (&s[..i], s[i + 1..].trim_start()),
+            _ => {}
+        }
+    }
+    (s, "")
+}
+
+fn is_target(s: &str) -> bool {
+    !s.is_empty()
+        && !s.starts_with(':')
+        && s.chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == ':')
+}
+
+/// Remove ANSI escape sequences left by older, coloured log files.
+fn strip_ansi(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut chars = s.chars();
+    while let Some(c) = chars.next() {
+        if c == '\u{1b}' {
+            if chars.next() == Some('[') {
+                for c in chars.by_ref() {
+                    if ('@'..='~').contains(&c) {
+                        break;
+                    }
+                }
+            }
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
+
+/// Date suffix of a rotated file name, e.g. `gglib.log.2026-03-14`.
+fn file_date(name: &str) -> Option<NaiveDate> {
+    let suffix = name.strip_prefix(LOG_FILE_NAME)?.strip_prefix('.')?;
+    NaiveDate::parse_from_str(suffix, "%Y-%m-%d").ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DAY1: &str = "\
+2026-03-14T09:00:00.000001Z  INFO gglib_core::settings: settings loaded
+2026-03-14T09:00:01.000000Z  WARN download.job{id=3}: gglib_download::manager::worker: retrying shard
+2026-03-14T09:00:02.000000Z ERROR gglib_proxy::forward: upstream failed
+  caused by: connection refused
+2026-03-14T09:00:03.000000Z DEBUG gglib_core::settings: cache miss
+";
+
+    const DAY2: &str = "\
+2026-03-15T10:00:00.000000Z  INFO gglib_runtime::runner: server started
+2026-03-15T10:00:05.000000Z  WARN gglib_runtime::runner: slow health check
+";
+
+    fn log_dir() -> tempfile::TempDir {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("gglib.log.2026-03-14"), DAY1).unwrap();
+        std::fs::write(dir.path().join("gglib.log.2026-03-15"), DAY2).unwrap();
+        std::fs::write(dir.path().join("unrelated.txt"), "x").unwrap();
+        dir
+    }
+
+    #[test]
+    fn lists_only_log_files_oldest_first() {
+        let dir = log_dir();
+        let names: Vec<_> = list_log_files(dir.path())
+            .unwrap()
+            .into_iter()
+            .map(|f| f.name)
+            .collect();
+        assert_eq!(names, ["gglib.log.2026-03-14", "gglib.log.2026-03-15"]);
+    }
+
+    #[test]
+    fn parses_spans_targets_and_continuations() {
+        let dir = log_dir();
+        let page = query_logs(dir.path(), &LogQuery::default()).unwrap();
+        assert_eq!(page.records.len(), 6);
+
+        let warn = &page.records[1];
+        assert_eq!(warn.level, LogLevel::Warn);
+        assert_eq!(warn.spans.as_deref(), Some("download.job{id=3}:"));
+        assert_eq!(
+            warn.target.as_deref(),
+            Some("gglib_download::manager::worker")
+        );
+        assert_eq!(warn.message, "retrying shard");
+
+        let error = &page.records[2];
+        assert_eq!(error.target.as_deref(), Some("gglib_proxy::forward"));
+        assert_eq!(
+            error.message,
+            "upstream failed\n  caused by: connection refused"
+        );
+        assert_eq!(page.records[3].cursor.line, 5);
+    }
+
+    #[test]
+    fn filters_by_level_target_and_time() {
+        let dir = log_dir();
+        let query = LogQuery {
+            level: Some(LogLevel::Warn),
+            target: Some("gglib_runtime".to_string()),
+            ..Default::default()
+        };
+        let page = query_logs(dir.path(), &query).unwrap();
+        assert_eq!(page.records.len(), 1);
+        assert_eq!(page.records[0].message, "slow health check");
+
+        let query = LogQuery {
+            since: Some("2026-03-14T09:00:01Z".parse().unwrap()),
+            until: Some("2026-03-14T09:00:03Z".parse().unwrap()),
+            ..Default::default()
+        };
+        let page = query_logs(dir.path(), &query).unwrap();
+        assert_eq!(page.records.len(), 2);
+    }
+
+    #[test]
+    fn pages_back_from_the_tail_and_follows_forward() {
+        let dir = log_dir();
+        let tail = query_logs(
+            dir.path(),
+            &LogQuery {
+                limit: Some(2),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        assert_eq!(tail.records.len(), 2);
+        assert_eq!(tail.records[0].message, "server started");
+
+        let older = query_logs(
+            dir.path(),
+            &LogQuery {
+                limit: Some(2),
+                before: tail.older.clone(),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        assert_eq!(older.records[1].message, "cache miss");
+        assert!(older.older.is_some());
+
+        // Nothing new yet, then one line appended.
+        let follow = LogQuery {
+            after: tail.latest.clone(),
+            ..Default::default()
+        };
+        assert!(query_logs(dir.path(), &follow).unwrap().records.is_empty());
+
+        let path = dir.path().join("gglib.log.2026-03-15");
+        let mut contents = std::fs::read_to_string(&path).unwrap();
+        contents.push_str("2026-03-15T10:00:09.000000Z  INFO gglib_runtime::runner: stopped\n");
+        std::fs::write(&path, contents).unwrap();
+
+        let page = query_logs(dir.path(), &follow).unwrap();
+        assert_eq!(page.records.len(), 1);
+        assert_eq!(page.records[0].message, "stopped");
+    }
+
+    #[test]
+    fn cursor_round_trips_through_string() {
+        let cursor: LogCursor = "gglib.log.2026-03-14:42".parse().unwrap();
+        assert_eq!(cursor.file, "gglib.log.2026-03-14");
+        assert_eq!(cursor.line, 42);
+        assert_eq!(cursor.to_string(), "gglib.log.2026-03-14:42");
+        assert!("no-line".parse::<LogCursor>().is_err());
+    }
+
+    #[test]
+    fn strips_ansi_colours() {
+        assert_eq!(strip_ansi("\u{1b}[32m INFO\u{1b}[0m x"), " INFO x");
+    }
+}
@@ -0,0 +1,124 @@
+//! Models directory watcher.
+//!
+//! Watches the models directory with the OS file notification API and hands
+//! the `.gguf` paths that changed to [`ModelDirectorySync::apply`], which adds
+//! new files to the library and trashes models whose file is gone. Changes
+//! are batched until the directory has been quiet for [`SETTLE_DELAY`]: a
+//! file being copied keeps producing events, so it is not parsed
+//! half-written, and a finished download has been registered by the download
+//! manager before the watcher looks at it.
+
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+
+use gglib_core::services::ModelDirectorySync;
+use notify::{EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+use tracing::{debug, info, warn};
+
+/// How long the models directory must go without changes before the
+/// collected paths are applied.
+pub const SETTLE_DELAY: Duration = Duration::from_secs(10);
+
+/// Start watching `sync`'s models directory.
+///
+/// The returned task owns the watcher and runs until it is aborted. Fails
+/// when the directory cannot be watched (it does not exist, or the OS watch
+/// limit is reached).
+pub fn spawn(sync: Arc<ModelDirectorySync>) -> notify::Result<JoinHandle<()>> {
+    let filter = Arc::clone(&sync);
+    let (watcher, mut batches) =
+        watch_settled(sync.root(), SETTLE_DELAY, move |p| filter.is_candidate(p))?;
+    info!(root = %sync.root().display(), "Watching the models directory");
+
+    Ok(tokio::spawn(async move {
+        let _watcher = watcher;
+        while let Some(paths) = batches.recv().await {
+            debug!(count = paths.len(), "Models directory changed");
+            match sync.apply(&paths).await {
+                Ok(summary) if summary != Default::default() => info!(
+                    added = summary.added,
+                    removed = summary.removed,
+                    failed = summary.failed,
+                    "Synced models directory changes"
+                ),
+                Ok(_) => {}
+                Err(e) => warn!("Failed to sync models directory changes: {e}"),
+            }
+        }
+    }))
+}
+
+/// Watch `root` recursively and yield the paths accepted by `keep` in
+/// batches, each sent once no event has arrived for `settle`.
+///
+/// Batching stops when the watcher is dropped.
+fn watch_settled(
+    root: &Path,
+    settle: Duration,
+    keep: impl Fn(&Path) -> bool + Send + 'static,
+) -> notify::Result<(RecommendedWatcher, mpsc::UnboundedReceiver<Vec<PathBuf>>)> {
+    let (event_tx, mut event_rx) = mpsc::unbounded_channel::<PathBuf>();
+    let mut watcher =
+        notify::recommended_watcher(move |res: notify::Result<notify::Event>| match res {
+            Ok(event) if !matches!(event.kind, EventKind::Access(_)) => {
+                for path in event.paths.into_iter().filter(|p| keep(p)) {
+                    let _ = event_tx.send(path);
+                }
+            }
+            Ok(_) => {}
+            Err(e) => warn!("Models directory watch error: {e}"),
+        })?;
+    watcher.watch(root, RecursiveMode::Recursive)?;
+
+    let (batch_tx, batch_rx) = mpsc::unbounded_channel();
+    tokio::spawn(async move {
+        let mut pending = BTreeSet::new();
+        loop {
+            let next = if pending.is_empty() {
+                event_rx.recv().await
+            } else if let Ok(next) = tokio::time::timeout(settle, event_rx.recv()).await {
+                next
+            } else {
+                let batch = std::mem::take(&mut pending).into_iter().collect();
+                if batch_tx.send(batch).is_err() {
+                    return;
+                }
+                continue;
+            };
+            let Some(path) = next else { return };
+            pending.insert(path);
+        }
+    });
+
+    Ok((watcher, batch_rx))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn batches_kept_paths_once_quiet() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path().canonicalize().unwrap();
+        let (_watcher, mut batches) = watch_settled(&root, Duration::from_millis(300), |p| {
+            p.extension().is_some_and(|e| e == "gguf")
+        })
+        .unwrap();
+
+        let model = root.join("model.gguf");
+        std::fs::write(&model, b"GGUF").unwrap();
+        std::fs::write(root.join("notes.txt"), b"ignored").unwrap();
+        std::fs::write(&model, b"GGUF more").unwrap();
+
+        let batch = tokio::time::timeout(Duration::from_secs(5), batches.recv())
+            .await
+            .expect("a batch once the directory is quiet")
+            .unwrap();
+        assert_eq!(batch, vec![model]);
+    }
+}
@@ -0,0 +1,92 @@
+//! OTLP trace export.
+//!
+//! Turns the `otlp` setting into a `tracing-opentelemetry` layer and hands it
+//! to [`gglib_core::telemetry::set_export_layer`], so the spans gglib already
+//! emits (downloads, proxy forwarding, server launches, database calls) reach
+//! an existing collector. Export is off unless the setting is present, and it
+//! can be switched on, reconfigured, or off again while the app is running.
+//!
+//! Spans go out over OTLP/HTTP (protobuf) from the SDK's batch processor
+//! thread, so an unreachable collector costs dropped batches rather than
+//! blocked requests.
+
+use std::sync::Mutex;
+
+use gglib_core::telemetry::{ExportLayer, OtlpConfig, set_export_layer};
+use opentelemetry::trace::TracerProvider as _;
+use opentelemetry_otlp::{SpanExporter, WithExportConfig};
+use opentelemetry_sdk::Resource;
+use opentelemetry_sdk::trace::{Sampler, SdkTracerProvider};
+use tracing::{info, warn};
+
+/// Provider behind the installed layer, kept so it can be flushed and shut
+/// down when export is reconfigured or the process exits.
+static PROVIDER: Mutex<Option<SdkTracerProvider>> = Mutex::new(None);
+
+/// Apply the `otlp` setting: install an exporter for `Some`, remove it for
+/// `None`.
+///
+/// Failures are logged rather than returned — a misconfigured collector must
+/// not stop gglib from starting.
+pub fn apply(config: Option<&OtlpConfig>) {
+    let (layer, provider) = match config.map(build) {
+        Some(Ok((layer, provider))) => (Some(layer), Some(provider)),
+        Some(Err(e)) => {
+            warn!(error = %e, "Failed to build OTLP exporter; trace export disabled");
+            (None, None)
+        }
+        None => (None, None),
+    };
+
+    if let Err(e) = set_export_layer(layer) {
+        warn!(error = %e, "Failed to install OTLP export layer");
+        return;
+    }
+
+    let previous = std::mem::replace(&mut *lock(), provider);
+    if let Some(previous) = previous {
+        let _ = previous.shutdown();
+    }
+    if let Some(config) = config {
+        info!(
+            endpoint = %config.traces_endpoint(),
+            service = config.service_name(),
+            "OTLP trace export enabled"
+        );
+    }
+}
+
+/// Flush buffered spans and stop exporting. Call before the process exits.
+pub fn shutdown() {
+    if let Some(provider) = lock().take() {
+        let _ = provider.shutdown();
+    }
+}
+
+fn build(config: &OtlpConfig) -> anyhow::Result<(ExportLayer, SdkTracerProvider)> {
+    let exporter = SpanExporter::builder()
+        .with_http()
+        .with_endpoint(config.traces_endpoint())
+        .build()?;
+
+    let provider = SdkTracerProvider::builder()
+        .with_batch_exporter(exporter)
+        .with_sampler(Sampler::ParentBased(Box::new(Sampler::TraceIdRatioBased(
+            config.sample_ratio(),
+        ))))
+        .with_resource(
+            Resource::builder()
+                .with_service_name(config.service_name().to_string())
+                .build(),
+        )
+        .build();
+
+    let layer = tracing_opentelemetry::layer().with_tracer(provider.tracer("gglib"));
+    Ok((Box::new(layer), provider))
+}
+
+fn lock() -> std::sync::MutexGuard<'static, Option<SdkTracerProvider>> {
+    PROVIDER
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+}
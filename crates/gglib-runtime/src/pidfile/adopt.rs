@@ -0,0 +1,169 @@
+//! Startup adoption of llama-servers left running by a previous session.
+//!
+//! After an app crash the servers it started keep running. Killing and
+//! relaunching them costs a full model load, which takes minutes for large
+//! models, so a server that is verifiably ours, still answers `/health` on
+//! its recorded port, and is serving an unchanged model file is handed back
+//! to the process runner instead.
+
+use std::io;
+use std::path::Path;
+use std::time::UNIX_EPOCH;
+
+use gglib_core::ports::{ProcessHandle, ProcessRunner};
+use tracing::{debug, info, warn};
+
+use super::io::{PidFileData, PidFileModel, list_pidfiles};
+use super::sweep::kill_orphan;
+use super::verify::is_recorded_llama_server;
+use crate::process::check_http_health;
+
+/// Identify a model file by its canonical path, size and modification time.
+///
+/// Hashing the contents of a multi-gigabyte GGUF at startup would cost more
+/// than the reload adoption avoids; a file replaced in place (re-download,
+/// re-quantization) changes size or modification time. Returns 16 hex
+/// digits of FNV-1a, or `None` if the file cannot be read.
+pub fn model_file_hash(path: &Path) -> Option<String> {
+    let canonical = path.canonicalize().ok()?;
+    let meta = std::fs::metadata(&canonical).ok()?;
+    let modified = meta.modified().ok()?.duration_since(UNIX_EPOCH).ok()?;
+
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    let mut feed = |bytes: &[u8]| {
+        for &b in bytes {
+            hash ^= u64::from(b);
+            hash = hash.wrapping_mul(0x0100_0000_01b3);
+        }
+    };
+    feed(canonical.to_string_lossy().as_bytes());
+    feed(&meta.len().to_le_bytes());
+    feed(&modified.as_secs().to_le_bytes());
+    feed(&modified.subsec_nanos().to_le_bytes());
+    Some(format!("{hash:016x}"))
+}
+
+/// Why a recorded server cannot be adopted, or the model it serves.
+async fn adoptable_model(data: &PidFileData) -> Result<&PidFileModel, &'static str> {
+    let model = data
+        .model
+        .as_ref()
+        .ok_or("PID file does not record the model")?;
+    if !is_recorded_llama_server(data) {
+        return Err("process is not the recorded llama-server");
+    }
+    if model_file_hash(&model.path).as_deref() != Some(model.hash.as_str()) {
+        return Err("model file changed since launch");
+    }
+    if !check_http_health(data.port).await {
+        return Err("server is not healthy on its recorded port");
+    }
+    Ok(model)
+}
+
+/// Adopt healthy orphaned servers into `runner` and clean up the rest.
+///
+/// # Strategy
+/// For each PID file from a previous session:
+/// - If the server can be adopted (see the module docs) and `runner`
+///   accepts it, keep it running and keep its PID file
+/// - Otherwise fall back to [`cleanup_orphaned_servers`]'s handling: kill
+///   a verified orphan, or just delete a stale PID file
+///
+/// Call before anything starts servers, so startup-model launches see the
+/// adopted servers as already running.
+///
+/// [`cleanup_orphaned_servers`]: super::cleanup_orphaned_servers
+pub async fn adopt_or_cleanup_orphaned_servers(runner: &dyn ProcessRunner) -> io::Result<()> {
+    let pidfiles = list_pidfiles()?;
+
+    if pidfiles.is_empty() {
+        debug!("No orphaned PID files found");
+        return Ok(());
+    }
+
+    info!(
+        "Found {} PID files, checking for servers to adopt",
+        pidfiles.len()
+    );
+
+    let (mut adopted, mut killed, mut cleaned) = (0, 0, 0);
+
+    for (model_id, data) in pidfiles {
+        match adoptable_model(&data).await {
+            Ok(model) => {
+                let handle = ProcessHandle::new(
+                    model_id,
+                    model.name.clone(),
+                    Some(data.pid),
+                    data.port,
+                    data.started_at.unwrap_or_default(),
+                );
+                match runner.adopt(handle, model.context_size).await {
+                    Ok(()) => {
+                        info!(
+                            "Adopted running llama-server for {} (model {}, PID {}, port {})",
+                            model.name, model_id, data.pid, data.port
+                        );
+                        adopted += 1;
+                        continue;
+                    }
+                    Err(e) => warn!("Could not adopt server for model {}: {}", model_id, e),
+                }
+            }
+            Err(reason) => debug!(
+                "Not adopting PID {} (model {}): {}",
+                data.pid, model_id, reason
+            ),
+        }
+
+        if kill_orphan(model_id, &data).await? {
+            killed += 1;
+        } else {
+            cleaned += 1;
+        }
+    }
+
+    info!(
+        "Orphan sweep complete: {} servers adopted, {} killed, {} stale files removed",
+        adopted, killed, cleaned
+    );
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn model_file_hash_tracks_file_changes() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("model.gguf");
+        std::fs::write(&path, b"GGUF v1").unwrap();
+
+        let first = model_file_hash(&path).unwrap();
+        assert_eq!(first.len(), 16);
+        assert_eq!(model_file_hash(&path).as_deref(), Some(first.as_str()));
+
+        std::fs::write(&path, b"GGUF v1, re-quantized").unwrap();
+        assert_ne!(model_file_hash(&path).unwrap(), first);
+    }
+
+    #[test]
+    fn model_file_hash_none_for_missing_file() {
+        assert_eq!(model_file_hash(Path::new("/nonexistent/model.gguf")), None);
+    }
+
+    #[tokio::test]
+    async fn unrecorded_model_is_not_adoptable() {
+        let data = PidFileData {
+            pid: std::process::id(),
+            port: 1,
+            started_at: None,
+            model: None,
+        };
+        assert!(adoptable_model(&data).await.is_err());
+    }
+}
@@ -1,22 +1,65 @@
 //! Atomic PID file I/O operations.
 //!
-//! Format: Two-line text file
+//! Format: line-based text file; only the first two lines are required
 //! ```text
 //! <pid>
 //! <port>
+//! <start time, seconds since the Unix epoch>
+//! <model file hash>
+//! <context size>
+//! <model file path>
+//! <model name>
 //! ```
+//!
+//! The start time guards against PID reuse (see
+//! [`is_recorded_llama_server`](super::is_recorded_llama_server)); the model
+//! lines let a later session adopt a still-healthy server (see
+//! [`adopt_or_cleanup_orphaned_servers`](super::adopt_or_cleanup_orphaned_servers)).
+//! Unknown values are written as empty lines. Files written before these
+//! lines were added still parse.
 
 use std::fs;
 use std::io;
 use std::path::PathBuf;
 
 use gglib_core::paths::pids_dir;
+use gglib_core::ports::ServerConfig;
+
+use super::adopt::model_file_hash;
+use super::verify::process_start_time;
 
 /// PID file content parsed from disk.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct PidFileData {
     pub pid: u32,
     pub port: u16,
+    /// Process start time when the file was written, if it was known.
+    pub started_at: Option<u64>,
+    /// The model the server was launched with, if recorded.
+    pub model: Option<PidFileModel>,
+}
+
+/// The model a recorded llama-server was launched with.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PidFileModel {
+    /// [`model_file_hash`] of the model file at launch.
+    pub hash: String,
+    pub context_size: Option<u64>,
+    pub path: PathBuf,
+    pub name: String,
+}
+
+impl PidFileModel {
+    /// Describe the model of a launch config, or `None` if its file cannot
+    /// be hashed.
+    pub fn from_config(config: &ServerConfig) -> Option<Self> {
+        Some(Self {
+            hash: model_file_hash(&config.model_path)?,
+            context_size: config.context_size,
+            path: config.model_path.clone(),
+            name: config.model_name.clone(),
+        })
+    }
 }
 
 /// Write PID file atomically using temp file + rename.
@@ -27,7 +70,14 @@ pub struct PidFileData {
 /// # Atomicity
 /// 1. Write to `<model_id>.pid.tmp`
 /// 2. Rename to `<model_id>.pid` (atomic on Unix/macOS)
-pub fn write_pidfile(model_id: i64, pid: u32, port: u16) -> io::Result<PathBuf> {
+///
+/// The process start time is looked up and recorded alongside the PID.
+pub fn write_pidfile(
+    model_id: i64,
+    pid: u32,
+    port: u16,
+    model: Option<&PidFileModel>,
+) -> io::Result<PathBuf> {
     let dir = pids_dir().map_err(io::Error::other)?;
     fs::create_dir_all(&dir)?;
 
@@ -36,7 +86,12 @@ pub fn write_pidfile(model_id: i64, pid: u32, port: u16) -> io::Result<PathBuf>
     let temp_path = dir.join(format!("{}.tmp", filename));
 
     // Write to temp file
-    let content = format!("{}\n{}\n", pid, port);
+    let content = format_pidfile_content(&PidFileData {
+        pid,
+        port,
+        started_at: process_start_time(pid),
+        model: model.cloned(),
+    });
     fs::write(&temp_path, content)?;
 
     // Atomic rename
@@ -105,6 +160,21 @@ pub fn list_pidfiles() -> io::Result<Vec<(i64, PidFileData)>> {
     Ok(results)
 }
 
+fn format_pidfile_content(data: &PidFileData) -> String {
+    let opt = |v: Option<u64>| v.map(|v| v.to_string()).unwrap_or_default();
+    let mut content = format!("{}\n{}\n{}\n", data.pid, data.port, opt(data.started_at));
+    if let Some(model) = &data.model {
+        content.push_str(&format!(
+            "{}\n{}\n{}\n{}\n",
+            model.hash,
+            opt(model.context_size),
+            model.path.display(),
+            model.name
+        ));
+    }
+    content
+}
+
 fn parse_pidfile_content(content: &str) -> io::Result<PidFileData> {
     let mut lines = content.lines();
 
@@ -118,7 +188,26 @@ fn parse_pidfile_content(content: &str) -> io::Result<PidFileData> {
         .and_then(|s| s.trim().parse::<u16>().ok())
         .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "missing or invalid port"))?;
 
-    Ok(PidFileData { pid, port })
+    let started_at = lines.next().and_then(|s| s.trim().parse::<u64>().ok());
+
+    let model = match (lines.next(), lines.next(), lines.next(), lines.next()) {
+        (Some(hash), Some(context_size), Some(path), Some(name)) if !hash.trim().is_empty() => {
+            Some(PidFileModel {
+                hash: hash.trim().to_string(),
+                context_size: context_size.trim().parse().ok(),
+                path: PathBuf::from(path),
+                name: name.to_string(),
+            })
+        }
+        _ => None,
+    };
+
+    Ok(PidFileData {
+        pid,
+        port,
+        started_at,
+        model,
+    })
 }
 
 #[cfg(test)]
@@ -132,7 +221,7 @@ mod tests {
         let pid = 98765;
         let port = 8080;
 
-        let path = write_pidfile(model_id, pid, port).expect("write failed");
+        let path = write_pidfile(model_id, pid, port, None).expect("write failed");
         assert!(path.exists());
 
         let data = read_pidfile(model_id).expect("read failed");
@@ -146,6 +235,50 @@ mod tests {
         delete_pidfile(model_id).expect("second delete failed");
     }
 
+    #[test]
+    fn roundtrip_records_start_time_of_live_process() {
+        let model_id = 12346;
+        let pid = std::process::id();
+
+        write_pidfile(model_id, pid, 8081, None).expect("write failed");
+        let data = read_pidfile(model_id).expect("read failed");
+        assert_eq!(data.started_at, process_start_time(pid));
+        assert!(data.started_at.is_some());
+
+        delete_pidfile(model_id).expect("delete failed");
+    }
+
+    #[test]
+    fn parses_legacy_two_line_files() {
+        let data = parse_pidfile_content("123\n8080\n").expect("parse failed");
+        assert_eq!(
+            data,
+            PidFileData {
+                pid: 123,
+                port: 8080,
+                started_at: None,
+                model: None,
+            }
+        );
+    }
+
+    #[test]
+    fn model_lines_round_trip() {
+        let data = PidFileData {
+            pid: 123,
+            port: 8080,
+            started_at: None,
+            model: Some(PidFileModel {
+                hash: "00ff00ff00ff00ff".to_string(),
+                context_size: Some(32768),
+                path: PathBuf::from("/models/My Model Q4_K_M.gguf"),
+                name: "My Model".to_string(),
+            }),
+        };
+        let content = format_pidfile_content(&data);
+        assert_eq!(parse_pidfile_content(&content).unwrap(), data);
+    }
+
     #[test]
     #[ignore] // Run with --ignored or --include-ignored; prevents parallel test interference
     fn list_pidfiles_filters_non_pid_files() {
@@ -163,7 +296,7 @@ mod tests {
 
         // Create valid PID file with unique ID
         let test_id = 99999;
-        write_pidfile(test_id, 100, 8080).expect("write failed");
+        write_pidfile(test_id, 100, 8080, None).expect("write failed");
 
         // Create non-PID file (should be filtered out)
         fs::write(dir.join("not_a_pid.txt"), "garbage").expect("write failed");
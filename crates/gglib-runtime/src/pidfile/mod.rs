@@ -1,8 +1,12 @@
 #![doc = include_str!("README.md")]
+mod adopt;
 mod io;
 mod sweep;
 mod verify;
 
-pub use io::{PidFileData, delete_pidfile, list_pidfiles, read_pidfile, write_pidfile};
+pub use adopt::{adopt_or_cleanup_orphaned_servers, model_file_hash};
+pub use io::{
+    PidFileData, PidFileModel, delete_pidfile, list_pidfiles, read_pidfile, write_pidfile,
+};
 pub use sweep::cleanup_orphaned_servers;
-pub use verify::{is_our_llama_server, pid_exists};
+pub use verify::{is_our_llama_server, is_recorded_llama_server, pid_exists, process_start_time};
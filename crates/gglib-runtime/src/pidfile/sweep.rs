@@ -4,8 +4,8 @@ use std::io;
 
 use tracing::{debug, info, warn};
 
-use super::io::{delete_pidfile, list_pidfiles};
-use super::verify::is_our_llama_server;
+use super::io::{PidFileData, delete_pidfile, list_pidfiles};
+use super::verify::is_recorded_llama_server;
 use crate::process::shutdown::kill_pid;
 
 /// Clean up orphaned llama-server processes at startup.
@@ -13,13 +13,14 @@ use crate::process::shutdown::kill_pid;
 /// # Strategy
 /// 1. Read all PID files from `~/.gglib/pids/`
 /// 2. For each PID:
-///    - Verify it's actually our llama-server binary (not a reused PID)
+///    - Verify it's actually our llama-server binary, started at the time
+///      the PID file recorded (not a reused PID)
 ///    - If verified, kill it with SIGTERM → SIGKILL
 ///    - If not verified or already gone, just delete the PID file
 /// 3. Log results
 ///
 /// # Safety
-/// Uses `is_our_llama_server()` to avoid killing unrelated processes.
+/// Uses `is_recorded_llama_server()` to avoid killing unrelated processes.
 /// If verification fails, only the PID file is removed (conservative).
 pub async fn cleanup_orphaned_servers() -> io::Result<()> {
     let pidfiles = list_pidfiles()?;
@@ -38,34 +39,9 @@ pub async fn cleanup_orphaned_servers() -> io::Result<()> {
     let mut cleaned = 0;
 
     for (model_id, data) in pidfiles {
-        if is_our_llama_server(data.pid) {
-            // Verified orphaned server - kill it
-            debug!(
-                "Killing orphaned llama-server (model {}, PID {}, port {})",
-                model_id, data.pid, data.port
-            );
-
-            match kill_pid(data.pid).await {
-                Ok(_) => {
-                    killed += 1;
-                    delete_pidfile(model_id)?;
-                }
-                Err(e) => {
-                    warn!(
-                        "Failed to kill orphaned server PID {}: {}. Removing stale PID file.",
-                        data.pid, e
-                    );
-                    delete_pidfile(model_id)?;
-                    cleaned += 1;
-                }
-            }
+        if kill_orphan(model_id, &data).await? {
+            killed += 1;
         } else {
-            // PID doesn't match our binary (reused or gone) - just clean up file
-            debug!(
-                "PID {} (model {}) is not our llama-server, removing stale PID file",
-                data.pid, model_id
-            );
-            delete_pidfile(model_id)?;
             cleaned += 1;
         }
     }
@@ -80,6 +56,40 @@ pub async fn cleanup_orphaned_servers() -> io::Result<()> {
     Ok(())
 }
 
+/// Kill one orphaned server if it is still ours, then remove its PID file.
+///
+/// Returns `true` if a live server was killed, `false` if only a stale PID
+/// file was removed.
+pub(super) async fn kill_orphan(model_id: i64, data: &PidFileData) -> io::Result<bool> {
+    if !is_recorded_llama_server(data) {
+        // PID doesn't match our binary (reused or gone) - just clean up file
+        debug!(
+            "PID {} (model {}) is not our llama-server, removing stale PID file",
+            data.pid, model_id
+        );
+        delete_pidfile(model_id)?;
+        return Ok(false);
+    }
+
+    // Verified orphaned server - kill it
+    debug!(
+        "Killing orphaned llama-server (model {}, PID {}, port {})",
+        model_id, data.pid, data.port
+    );
+    let killed = match kill_pid(data.pid).await {
+        Ok(_) => true,
+        Err(e) => {
+            warn!(
+                "Failed to kill orphaned server PID {}: {}. Removing stale PID file.",
+                data.pid, e
+            );
+            false
+        }
+    };
+    delete_pidfile(model_id)?;
+    Ok(killed)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -88,7 +98,7 @@ mod tests {
     #[tokio::test]
     async fn cleanup_removes_stale_pidfiles() {
         // Create PID file for impossible PID
-        write_pidfile(99999, 999999, 9999).expect("write failed");
+        write_pidfile(99999, 999999, 9999, None).expect("write failed");
 
         cleanup_orphaned_servers().await.expect("cleanup failed");
 
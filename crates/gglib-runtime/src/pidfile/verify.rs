@@ -1,10 +1,13 @@
 //! Process verification to ensure PIDs belong to llama-server.
 
-#[cfg(any(target_os = "macos", target_os = "linux"))]
+#[cfg(any(target_os = "macos", target_os = "linux", windows))]
 use gglib_core::paths::llama_server_path;
 
-#[cfg(target_os = "macos")]
-use sysinfo::System;
+#[cfg(any(target_os = "macos", windows))]
+use sysinfo::UpdateKind;
+use sysinfo::{ProcessRefreshKind, ProcessesToUpdate, System};
+
+use super::io::PidFileData;
 
 #[cfg(target_os = "linux")]
 use std::fs;
@@ -12,7 +15,7 @@ use std::fs;
 /// Check if a PID belongs to our llama-server binary.
 ///
 /// # Platform behavior
-/// - **macOS**: Uses `sysinfo` to check executable path
+/// - **macOS / Windows**: Uses `sysinfo` to check executable path
 /// - **Linux**: Reads `/proc/<pid>/exe` symlink
 /// - **Other**: Always returns `false` (conservative)
 ///
@@ -20,9 +23,9 @@ use std::fs;
 /// Returns `false` if verification fails or PID doesn't match our binary.
 /// This prevents accidentally killing unrelated processes with reused PIDs.
 pub fn is_our_llama_server(pid: u32) -> bool {
-    #[cfg(target_os = "macos")]
+    #[cfg(any(target_os = "macos", windows))]
     {
-        is_our_llama_server_macos(pid)
+        is_our_llama_server_sysinfo(pid)
     }
 
     #[cfg(target_os = "linux")]
@@ -30,23 +33,28 @@ pub fn is_our_llama_server(pid: u32) -> bool {
         is_our_llama_server_linux(pid)
     }
 
-    #[cfg(not(any(target_os = "macos", target_os = "linux")))]
+    #[cfg(not(any(target_os = "macos", target_os = "linux", windows)))]
     {
         let _ = pid;
         false
     }
 }
 
-#[cfg(target_os = "macos")]
-fn is_our_llama_server_macos(pid: u32) -> bool {
+#[cfg(any(target_os = "macos", windows))]
+fn is_our_llama_server_sysinfo(pid: u32) -> bool {
     let Ok(expected_path) = llama_server_path() else {
         return false;
     };
 
-    // Use new_all() to ensure processes are loaded
-    let sys = System::new_all();
+    let pid = sysinfo::Pid::from_u32(pid);
+    let mut sys = System::new();
+    sys.refresh_processes_specifics(
+        ProcessesToUpdate::Some(&[pid]),
+        false,
+        ProcessRefreshKind::nothing().with_exe(UpdateKind::OnlyIfNotSet),
+    );
 
-    let Some(process) = sys.process(sysinfo::Pid::from_u32(pid)) else {
+    let Some(process) = sys.process(pid) else {
         return false;
     };
 
@@ -79,6 +87,42 @@ fn is_our_llama_server_linux(pid: u32) -> bool {
     }
 }
 
+/// Start time of a process, in seconds since the Unix epoch.
+///
+/// Recorded in the PID file at spawn so a later check can tell the original
+/// process apart from an unrelated one that was handed the same PID.
+pub fn process_start_time(pid: u32) -> Option<u64> {
+    let pid = sysinfo::Pid::from_u32(pid);
+    let mut sys = System::new();
+    sys.refresh_processes_specifics(
+        ProcessesToUpdate::Some(&[pid]),
+        false,
+        ProcessRefreshKind::nothing(),
+    );
+    sys.process(pid).map(sysinfo::Process::start_time)
+}
+
+/// Check that a PID file still describes the process that wrote it.
+///
+/// Requires [`is_our_llama_server`] and, when the file recorded a start
+/// time, that the live process started at that same time. A llama-server
+/// relaunched by someone else after a crash can land on a recycled PID, and
+/// the binary check alone would accept it.
+///
+/// Start times are compared with one second of slack, since some platforms
+/// derive them from boot time and clock ticks.
+pub fn is_recorded_llama_server(data: &PidFileData) -> bool {
+    if !is_our_llama_server(data.pid) {
+        return false;
+    }
+    match data.started_at {
+        None => true,
+        Some(recorded) => {
+            process_start_time(data.pid).is_some_and(|actual| actual.abs_diff(recorded) <= 1)
+        }
+    }
+}
+
 /// Check if a PID exists (without verifying it's our process).
 ///
 /// Uses `kill` with null signal which doesn't send a signal but checks existence.
@@ -96,8 +140,8 @@ pub fn pid_exists(pid: u32) -> bool {
 }
 
 #[cfg(not(unix))]
-pub fn pid_exists(_pid: u32) -> bool {
-    false // Not implemented on non-Unix
+pub fn pid_exists(pid: u32) -> bool {
+    process_start_time(pid).is_some()
 }
 
 #[cfg(test)]
@@ -117,6 +161,28 @@ mod tests {
         assert!(!pid_exists(999999));
     }
 
+    #[test]
+    fn process_start_time_for_self_is_in_the_past() {
+        let started = process_start_time(std::process::id()).expect("own start time");
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+        assert!(started > 0 && started <= now);
+    }
+
+    #[test]
+    fn is_recorded_llama_server_false_for_self() {
+        let pid = std::process::id();
+        let data = PidFileData {
+            pid,
+            port: 8080,
+            started_at: process_start_time(pid),
+            model: None,
+        };
+        assert!(!is_recorded_llama_server(&data));
+    }
+
     #[test]
     fn is_our_llama_server_false_for_self() {
         // Current process is not llama-server
@@ -0,0 +1,51 @@
+//! Process-wide registry of WASM plugin tools.
+//!
+//! Installed plugins are loaded from [`paths::plugins_dir`] the first time a
+//! tool executor is composed, and the same executor is then attached to every
+//! [`CombinedToolExecutor`](gglib_mcp::CombinedToolExecutor) built by
+//! [`compose`](crate::compose) — agent chat, council, pipelines and the HTTP
+//! API all see the same plugin tools. [`reload_plugins`] swaps in a fresh set
+//! after plugins are installed or removed in a long-running process.
+
+use std::sync::{Arc, LazyLock, RwLock};
+
+use gglib_core::paths;
+use gglib_core::ports::ToolExecutorPort;
+use gglib_plugins::{PluginStore, PluginToolExecutor};
+
+static PLUGIN_TOOLS: LazyLock<RwLock<Arc<PluginToolExecutor>>> =
+    LazyLock::new(|| RwLock::new(Arc::new(load_installed())));
+
+/// The loaded plugin tools, or `None` when no plugin is loaded.
+pub fn plugin_tools() -> Option<Arc<dyn ToolExecutorPort>> {
+    let tools = Arc::clone(&PLUGIN_TOOLS.read().unwrap_or_else(|e| e.into_inner()));
+    (!tools.is_empty()).then_some(tools as Arc<dyn ToolExecutorPort>)
+}
+
+/// Reload every installed plugin, returning how many loaded.
+///
+/// Executors composed earlier keep the plugins they were built with.
+pub fn reload_plugins() -> usize {
+    let fresh = load_installed();
+    let count = fresh.len();
+    *PLUGIN_TOOLS.write().unwrap_or_else(|e| e.into_inner()) = Arc::new(fresh);
+    count
+}
+
+fn load_installed() -> PluginToolExecutor {
+    let dir = match paths::plugins_dir() {
+        Ok(dir) => dir,
+        Err(e) => {
+            tracing::warn!("plugins directory unavailable: {e}");
+            return PluginToolExecutor::default();
+        }
+    };
+    let executor = PluginToolExecutor::load(&PluginStore::new(dir));
+    if !executor.is_empty() {
+        tracing::info!(
+            plugins = ?executor.names().collect::<Vec<_>>(),
+            "loaded WASM plugins"
+        );
+    }
+    executor
+}
@@ -3,8 +3,10 @@ pub mod llm_completion;
 pub mod model_catalog;
 pub mod model_runtime;
 pub mod model_shards;
+pub mod title_generator;
 
 pub use llm_completion::LlmCompletionAdapter;
 pub use model_catalog::CatalogPortImpl;
 pub use model_runtime::RuntimePortImpl;
 pub use model_shards::total_model_bytes;
+pub use title_generator::RunningServerTitleGenerator;
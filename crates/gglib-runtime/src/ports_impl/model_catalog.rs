@@ -165,11 +165,14 @@ mod tests {
                 hf_filename: None,
                 download_date: None,
                 last_update_check: None,
+                license: None,
                 tags: vec!["format:qwen".to_string()],
                 capabilities: ModelCapabilities::default(),
+                capabilities_version: None,
                 inference_defaults: None,
                 server_defaults: None,
                 benchmark_summary: None,
+                card: None,
             }
         }
     }
@@ -0,0 +1,116 @@
+//! Title generator backed by whichever llama-server is running.
+//!
+//! Implements [`TitleGeneratorPort`] by sending the conversation transcript
+//! plus the title instruction to the server serving the conversation's
+//! model, or to the first running server when that model is not loaded.
+//! Generation is capped at [`TITLE_MAX_TOKENS`] so a chatty model cannot
+//! hold the server for long.
+
+use std::sync::Arc;
+
+use anyhow::{Result, anyhow, bail};
+use async_trait::async_trait;
+use futures_util::StreamExt;
+use reqwest::Client;
+
+use gglib_core::domain::InferenceConfig;
+use gglib_core::domain::agent::{AgentMessage, AssistantContent, LlmStreamEvent};
+use gglib_core::domain::chat::{Conversation, Message, MessageRole};
+use gglib_core::ports::{LlmCompletionPort, ProcessRunner, TITLE_MAX_TOKENS, TitleGeneratorPort};
+
+use super::LlmCompletionAdapter;
+
+/// Send-phase timeout for a title request, in seconds.
+const TITLE_SEND_TIMEOUT_SECS: u64 = 30;
+
+/// [`TitleGeneratorPort`] that asks a running llama-server for the title.
+pub struct RunningServerTitleGenerator {
+    runner: Arc<dyn ProcessRunner>,
+    client: Client,
+}
+
+impl RunningServerTitleGenerator {
+    /// Create a generator that picks its server from `runner`.
+    ///
+    /// Allocates a fresh [`reqwest::Client`]; prefer
+    /// [`with_client`](Self::with_client) when a shared one is available.
+    #[must_use]
+    pub fn new(runner: Arc<dyn ProcessRunner>) -> Self {
+        Self::with_client(runner, Client::new())
+    }
+
+    /// Create a generator that reuses an existing [`reqwest::Client`].
+    #[must_use]
+    pub const fn with_client(runner: Arc<dyn ProcessRunner>, client: Client) -> Self {
+        Self { runner, client }
+    }
+}
+
+#[async_trait]
+impl TitleGeneratorPort for RunningServerTitleGenerator {
+    async fn generate_title(
+        &self,
+        conversation: &Conversation,
+        messages: &[Message],
+        prompt: &str,
+    ) -> Result<String> {
+        let running = self.runner.list_running().await?;
+        let handle = conversation
+            .model_id
+            .and_then(|id| running.iter().find(|h| h.model_id == id))
+            .or_else(|| running.first())
+            .ok_or_else(|| anyhow!("no model server is running"))?;
+
+        let llm = LlmCompletionAdapter::with_client(
+            format!("http://127.0.0.1:{}", handle.port),
+            self.client.clone(),
+            Some(handle.model_name.clone()),
+        )
+        .with_sampling(Some(InferenceConfig {
+            max_tokens: Some(TITLE_MAX_TOKENS),
+            ..InferenceConfig::default()
+        }))
+        .with_send_timeout(TITLE_SEND_TIMEOUT_SECS);
+
+        let mut request = transcript(messages);
+        request.push(AgentMessage::User {
+            content: prompt.to_string(),
+        });
+
+        let mut title = String::new();
+        let mut stream = llm.chat_stream(&request, &[], None).await?;
+        while let Some(event) = stream.next().await {
+            match event? {
+                LlmStreamEvent::TextDelta { content } => title.push_str(&content),
+                LlmStreamEvent::Done { .. } => break,
+                LlmStreamEvent::UpstreamError { message, .. } => bail!("model error: {message}"),
+                _ => {}
+            }
+        }
+        Ok(title)
+    }
+}
+
+/// The user and assistant text of `messages`, without tool traffic.
+///
+/// Tool calls would oblige the request to carry tool definitions; the
+/// title only needs what was said.
+fn transcript(messages: &[Message]) -> Vec<AgentMessage> {
+    messages
+        .iter()
+        .filter_map(|m| match m.role {
+            MessageRole::User => Some(AgentMessage::User {
+                content: m.content.clone(),
+            }),
+            MessageRole::Assistant if !m.content.trim().is_empty() => {
+                Some(AgentMessage::Assistant {
+                    content: AssistantContent {
+                        text: Some(m.content.clone()),
+                        tool_calls: Vec::new(),
+                    },
+                })
+            }
+            _ => None,
+        })
+        .collect()
+}
@@ -10,7 +10,7 @@ use super::ports::{allocate_port, is_port_available};
 use super::shutdown::shutdown_child;
 use super::types::{RunningProcess, ServerInfo};
 use crate::command::{build_and_spawn, spawn_log_readers};
-use crate::pidfile::{delete_pidfile, write_pidfile};
+use crate::pidfile::{PidFileModel, delete_pidfile, write_pidfile};
 use anyhow::{Result, anyhow};
 use gglib_core::ports::ServerConfig;
 use std::collections::HashMap;
@@ -48,6 +48,11 @@ impl GuiProcessCore {
     /// Spawn a new llama-server process
     ///
     /// Returns the port number for the spawned process.
+    #[tracing::instrument(
+        name = "server.launch",
+        skip_all,
+        fields(model_id = config.model_id, model = %config.model_name, ctx = ?config.context_size)
+    )]
     pub async fn spawn(&mut self, config: ServerConfig) -> Result<u16> {
         let model_id = config.model_id as u32;
 
@@ -83,7 +88,8 @@ impl GuiProcessCore {
             .ok_or_else(|| anyhow!("Failed to get child PID"))?;
 
         // Write PID file
-        if let Err(e) = write_pidfile(config.model_id, pid, port) {
+        let model = PidFileModel::from_config(&config);
+        if let Err(e) = write_pidfile(config.model_id, pid, port, model.as_ref()) {
             debug!("Failed to write PID file: {}", e);
         }
 
@@ -104,6 +110,8 @@ impl GuiProcessCore {
         );
         let running = RunningProcess::new(info, child);
         self.processes.insert(model_id, running);
+        super::get_stats_registry().begin_session(model_id);
+        super::get_slot_registry().watch(model_id, port);
 
         Ok(port)
     }
@@ -140,6 +148,8 @@ impl GuiProcessCore {
 
         let pid = running.info.pid;
         debug!(model_id = %model_id, pid = %pid, port = %running.info.port, "Stopping process");
+        super::get_stats_registry().end_session(model_id);
+        super::get_slot_registry().forget(model_id);
 
         // Use graceful shutdown with SIGTERM → SIGKILL
         let _ = shutdown_child(running.child).await;
@@ -233,6 +243,8 @@ impl GuiProcessCore {
         for id in &dead {
             debug!(id = %id, "Removing dead process from map");
             self.processes.remove(id);
+            super::get_stats_registry().end_session(*id);
+            super::get_slot_registry().forget(*id);
             // Remove PID file for naturally exited process
             if let Err(e) = delete_pidfile(*id as i64) {
                 debug!("Failed to delete PID file for {}: {}", id, e);
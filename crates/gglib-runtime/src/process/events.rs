@@ -4,6 +4,7 @@
 //! to maintain a synchronized view of server state. The frontend should
 //! treat these events as the sole source of truth for server lifecycle.
 
+use gglib_core::domain::{ServerStats, SlotOccupancy};
 use serde::{Deserialize, Serialize};
 
 /// Server lifecycle status.
@@ -35,6 +36,12 @@ pub struct ServerStateInfo {
     pub port: Option<u16>,
     /// Unix timestamp in milliseconds when this state was recorded
     pub updated_at: u64,
+    /// Latency and throughput for the current session (running servers only)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stats: Option<ServerStats>,
+    /// Slot occupancy from the latest poll (running servers only)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slots: Option<SlotOccupancy>,
 }
 
 impl ServerStateInfo {
@@ -45,9 +52,25 @@ impl ServerStateInfo {
             status,
             port,
             updated_at: Self::now_ms(),
+            stats: None,
+            slots: None,
         }
     }
 
+    /// Attach the server's current session stats.
+    #[must_use]
+    pub fn with_stats(mut self, stats: Option<ServerStats>) -> Self {
+        self.stats = stats;
+        self
+    }
+
+    /// Attach the server's latest slot occupancy.
+    #[must_use]
+    pub fn with_slots(mut self, slots: Option<SlotOccupancy>) -> Self {
+        self.slots = slots;
+        self
+    }
+
     /// Get current time as Unix milliseconds.
     fn now_ms() -> u64 {
         std::time::SystemTime::now()
@@ -7,6 +7,8 @@ use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, VecDeque};
 use std::sync::{Arc, LazyLock, RwLock};
 use tokio::sync::broadcast;
+use ts_rs::TS;
+use utoipa::ToSchema;
 
 /// Maximum number of log lines to keep in the ring buffer per server
 const MAX_LOG_LINES: usize = 5000;
@@ -21,7 +23,7 @@ pub fn get_log_manager() -> Arc<ServerLogManager> {
 }
 
 /// A single log entry from the server
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, TS, ToSchema)]
 pub struct ServerLogEntry {
     /// Unix timestamp in milliseconds
     pub timestamp: u64,
@@ -13,8 +13,9 @@ use gglib_core::domain::{CacheRamHealth, classify_cache_ram};
 use gglib_core::paths::slot_model_prefix;
 use gglib_core::ports::{
     CatalogError, ModelCatalogPort, ModelRuntimeError, RunningTarget, ServerConfig,
+    SettingsRepository,
 };
-use gglib_core::server_config::{CacheRamSetting, resolve_context_size};
+use gglib_core::server_config::{CacheRamSetting, check_context_size, resolve_context_size_within};
 use std::path::PathBuf;
 use std::sync::Arc;
 use std::time::Duration;
@@ -97,6 +98,9 @@ pub enum ProcessStrategy {
 pub struct ProcessManager {
     core: Arc<RwLock<GuiProcessCore>>,
     strategy: ProcessStrategy,
+    /// Source of the `cached_prompts` warmed after each SingleSwap start;
+    /// `None` warms nothing. See [`Self::with_cached_prompts`].
+    prompt_settings: Option<Arc<dyn SettingsRepository>>,
 }
 
 impl ProcessManager {
@@ -110,6 +114,7 @@ impl ProcessManager {
         Self {
             core: Arc::new(RwLock::new(core)),
             strategy: ProcessStrategy::Concurrent { max_concurrent },
+            prompt_settings: None,
         }
     }
 
@@ -177,9 +182,23 @@ impl ProcessManager {
                 cache_type_k,
                 cache_type_v,
             },
+            prompt_settings: None,
         }
     }
 
+    /// Warm the `cached_prompts` configured in `settings` on every model this
+    /// manager starts (SingleSwap only), restoring their saved KV state when
+    /// the launch has a slot directory. See [`crate::prompt_cache`].
+    ///
+    /// Settings are read at each start, so edits apply from the next swap.
+    /// Launches with the prompt cache turned off (`--cache-ram 0`, as
+    /// benchmarks use) are left cold.
+    #[must_use]
+    pub fn with_cached_prompts(mut self, settings: Arc<dyn SettingsRepository>) -> Self {
+        self.prompt_settings = Some(settings);
+        self
+    }
+
     /// Start a llama-server instance for a model (Concurrent strategy only)
     pub async fn start_server(&self, config: ServerConfig) -> Result<u16> {
         let max_concurrent = match &self.strategy {
@@ -347,6 +366,7 @@ impl ProcessManager {
                     let cache_reuse_owned = cache_reuse;
                     let cache_type_k_owned = cache_type_k;
                     let cache_type_v_owned = cache_type_v;
+                    let prompt_settings_owned = self.prompt_settings.clone();
 
                     // 4. Spawn the driver task (detached from this request's future)
                     drive(guard, STARTUP_WAIT_TIMEOUT, async move {
@@ -362,6 +382,12 @@ impl ProcessManager {
                                 ModelRuntimeError::ModelNotFound(model_name_owned.clone())
                             })?;
 
+                        // An explicit request is held to the model's
+                        // maximum; the defaults are lowered to it below.
+                        if let Some(requested) = num_ctx {
+                            check_context_size(requested, launch_spec.context_length)
+                                .map_err(|e| ModelRuntimeError::ContextTooLarge(e.to_string()))?;
+                        }
                         let effective_ctx = num_ctx.unwrap_or(default_ctx);
                         let model_path = &launch_spec.file_path;
 
@@ -481,21 +507,49 @@ impl ProcessManager {
                         opts.cache_type_v = Some(kv_types.v);
 
                         // Size the host-RAM prompt cache. Deliberately uses
-                        // `resolve_context_size` (the same 4-tier chain
-                        // `build_server_config` applies, including the
-                        // per-model tier) rather than `effective_ctx`, so the
-                        // KV estimate matches the context the server actually
-                        // launches with.
-                        let launch_ctx = resolve_context_size(&opts);
+                        // the 4-tier chain (including the per-model tier)
+                        // rather than `effective_ctx`, so the KV estimate
+                        // matches the context the server actually launches
+                        // with. The result, lowered to the model's maximum,
+                        // is pinned in `opts` for `build_server_config`.
+                        let launch_ctx =
+                            resolve_context_size_within(&opts, launch_spec.context_length)
+                                .map_err(|e| ModelRuntimeError::ContextTooLarge(e.to_string()))?;
+                        opts.context_size = Some(launch_ctx);
                         let kv_bytes_per_token = launch_spec.kv_elems_per_token.map(|elems| {
                             gglib_core::domain::kv_bytes_per_token(elems, kv_types.k, kv_types.v)
                         });
+                        let total_ram = crate::system::total_system_ram_bytes();
+
+                        // Pick the slot count before the cache budget: every
+                        // slot holds a full context of KV, which the budget
+                        // has to leave room for.
+                        let parallel = crate::llama::args::resolve_parallel(
+                            launch_spec
+                                .server_defaults
+                                .as_ref()
+                                .and_then(|sc| sc.parallel),
+                            total_ram,
+                            launch_spec.file_size_bytes,
+                            kv_bytes_per_token,
+                            launch_ctx,
+                            slot_save_path_owned.is_some(),
+                        );
+                        if let Some(explanation) = parallel.explain() {
+                            info!("{explanation}");
+                        }
+                        opts.parallel = Some(parallel.slots);
+                        opts.cont_batching = launch_spec
+                            .server_defaults
+                            .as_ref()
+                            .and_then(|sc| sc.cont_batching);
+
                         let cache_ram = crate::llama::args::resolve_cache_ram(
                             cache_ram_owned,
-                            crate::system::total_system_ram_bytes(),
+                            total_ram,
                             launch_spec.file_size_bytes,
                             kv_bytes_per_token,
-                            launch_ctx,
+                            launch_ctx.saturating_mul(u64::from(parallel.slots)),
                         );
                         if let Some(explanation) = cache_ram.explain() {
                             info!("{explanation}");
@@ -576,6 +630,30 @@ impl ProcessManager {
                             "Model started successfully"
                         );
 
+                        // Warm cached prompts in the background: requests
+                        // arriving meanwhile queue behind the warm in
+                        // llama-server, which is where a request sharing the
+                        // prefix wants to be anyway.
+                        if let Some(settings) = prompt_settings_owned
+                            && cache_ram.cache_ram_mb != Some(0)
+                        {
+                            let slot_dir = slot_save_path_owned.filter(|_| slot_restore.enabled);
+                            let model_id = launch_spec.id;
+                            tokio::spawn(async move {
+                                match settings.load().await {
+                                    Ok(settings) => {
+                                        let prompts = settings.cached_prompts.unwrap_or_default();
+                                        crate::prompt_cache::PromptWarmer::new(
+                                            port, model_id, slot_dir,
+                                        )
+                                        .warm_configured(&prompts)
+                                        .await;
+                                    }
+                                    Err(e) => warn!("Failed to load cached prompts: {e}"),
+                                }
+                            });
+                        }
+
                         Ok(RunningTarget::local(
                             port,
                             launch_spec.id,
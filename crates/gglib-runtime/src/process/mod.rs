@@ -7,7 +7,9 @@ mod logs;
 mod manager;
 mod ports;
 pub mod shutdown;
+mod slots;
 mod startup_guard;
+mod stats;
 mod stream;
 mod types;
 
@@ -21,5 +23,7 @@ pub use health::{
 pub use logs::{LogManagerSink, ServerLogEntry, ServerLogManager, get_log_manager};
 pub use manager::{CurrentModelState, ProcessManager, ProcessStrategy};
 pub use shutdown::{kill_pid, shutdown_child};
+pub use slots::{SLOT_POLL_INTERVAL, SlotRegistry, fetch_slots, get_slot_registry};
+pub use stats::{ServerStatsRegistry, get_stats_registry};
 pub(crate) use stream::spawn_stream_reader;
 pub use types::{RunningProcess, ServerInfo};
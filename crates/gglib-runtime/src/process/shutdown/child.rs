@@ -58,7 +58,8 @@ where
 ///
 /// # Platform behavior
 /// - Unix: Uses nix crate for SIGTERM, then SIGKILL via `.kill()`
-/// - Windows: Immediately calls `.kill()` (no graceful shutdown available)
+/// - Windows: Immediately kills the process tree with `taskkill /T /F`
+///   (no graceful shutdown available), falling back to `.kill()`
 ///
 /// # Residual risk
 /// If the process enters D-state (uninterruptible sleep) after SIGKILL — e.g., due to
@@ -122,9 +123,16 @@ async fn shutdown_unix(child: &mut Child) -> io::Result<ExitStatus> {
 
 #[cfg(not(unix))]
 async fn shutdown_windows(child: &mut Child) -> io::Result<ExitStatus> {
-    // Windows has no SIGTERM equivalent - terminate immediately
+    // Windows has no SIGTERM equivalent - terminate the whole tree
+    // immediately, falling back to the single process if taskkill fails.
     let pid = child.id();
-    child.kill().await?;
+    let tree_killed = match pid {
+        Some(pid) => super::pid::kill_tree_windows(pid).await.is_ok(),
+        None => false,
+    };
+    if !tree_killed {
+        child.kill().await?;
+    }
     bounded_wait(child.wait(), SIGKILL_REAP_TIMEOUT_SECS, pid).await
 }
 
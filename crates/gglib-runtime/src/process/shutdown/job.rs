@@ -0,0 +1,111 @@
+//! Tie spawned children to gglib's own lifetime (Windows job objects).
+//!
+//! Unix children are shut down explicitly, and orphans from a crash are
+//! swept from their PID files on the next start. Windows has no SIGTERM and
+//! no parent-death signal, so a gglib process that is killed from Task
+//! Manager (or crashes) used to leave `llama-server.exe` holding its port
+//! and GPU memory.
+//!
+//! On Windows every llama-server is assigned to one process-wide job object
+//! created with `JOB_OBJECT_LIMIT_KILL_ON_JOB_CLOSE`. The job handle is
+//! never closed explicitly: when gglib exits for any reason the OS closes
+//! it, which terminates every process in the job — including any children
+//! llama-server itself spawned, since job membership is inherited.
+//!
+//! On other platforms [`bind_to_parent_lifetime`] is a no-op.
+
+use tokio::process::Child;
+
+/// Make `child` (and anything it spawns) exit when this process exits.
+///
+/// Best effort: failures are logged and the child keeps running unbound,
+/// which is the pre-job-object behaviour.
+pub fn bind_to_parent_lifetime(child: &Child) {
+    #[cfg(windows)]
+    windows::bind(child);
+
+    #[cfg(not(windows))]
+    let _ = child;
+}
+
+#[cfg(windows)]
+#[allow(unsafe_code)]
+mod windows {
+    use std::sync::OnceLock;
+
+    use tokio::process::Child;
+    use tracing::{debug, warn};
+    use windows_sys::Win32::Foundation::HANDLE;
+    use windows_sys::Win32::System::JobObjects::{
+        AssignProcessToJobObject, CreateJobObjectW, JOB_OBJECT_LIMIT_KILL_ON_JOB_CLOSE,
+        JOBOBJECT_EXTENDED_LIMIT_INFORMATION, JobObjectExtendedLimitInformation,
+        SetInformationJobObject,
+    };
+
+    /// Process-wide job handle, stored as an integer so the static is
+    /// `Send + Sync`. `None` if creating the job failed.
+    static JOB: OnceLock<Option<isize>> = OnceLock::new();
+
+    fn job() -> Option<HANDLE> {
+        let handle = JOB.get_or_init(|| {
+            // SAFETY: null attributes and name create an anonymous job with
+            // default security; the returned handle is checked for null.
+            let job = unsafe { CreateJobObjectW(std::ptr::null(), std::ptr::null()) };
+            if job.is_null() {
+                warn!(
+                    "CreateJobObjectW failed: {}",
+                    std::io::Error::last_os_error()
+                );
+                return None;
+            }
+
+            // SAFETY: an all-zero JOBOBJECT_EXTENDED_LIMIT_INFORMATION is a
+            // valid "no limits" value; we then set a single flag.
+            let mut info: JOBOBJECT_EXTENDED_LIMIT_INFORMATION = unsafe { std::mem::zeroed() };
+            info.BasicLimitInformation.LimitFlags = JOB_OBJECT_LIMIT_KILL_ON_JOB_CLOSE;
+
+            // SAFETY: `job` is a live job handle and `info` is a properly
+            // sized JOBOBJECT_EXTENDED_LIMIT_INFORMATION for this class.
+            let ok = unsafe {
+                SetInformationJobObject(
+                    job,
+                    JobObjectExtendedLimitInformation,
+                    std::ptr::from_ref(&info).cast(),
+                    size_of::<JOBOBJECT_EXTENDED_LIMIT_INFORMATION>() as u32,
+                )
+            };
+            if ok == 0 {
+                warn!(
+                    "SetInformationJobObject failed: {}",
+                    std::io::Error::last_os_error()
+                );
+                return None;
+            }
+            Some(job as isize)
+        });
+        handle.map(|h| h as HANDLE)
+    }
+
+    pub(super) fn bind(child: &Child) {
+        let Some(job) = job() else {
+            return;
+        };
+        let Some(process) = child.raw_handle() else {
+            // Already reaped; nothing to bind.
+            return;
+        };
+
+        // SAFETY: both handles are live for the duration of the call: the
+        // job handle is never closed, and `child` owns the process handle.
+        let ok = unsafe { AssignProcessToJobObject(job, process as HANDLE) };
+        if ok == 0 {
+            warn!(
+                pid = ?child.id(),
+                "AssignProcessToJobObject failed: {}",
+                std::io::Error::last_os_error()
+            );
+        } else {
+            debug!(pid = ?child.id(), "llama-server bound to gglib's job object");
+        }
+    }
+}
@@ -1,6 +1,8 @@
 #![doc = include_str!("README.md")]
 mod child;
+mod job;
 mod pid;
 
 pub use child::shutdown_child;
+pub use job::bind_to_parent_lifetime;
 pub use pid::kill_pid;
@@ -2,6 +2,9 @@
 
 use std::io;
 
+#[cfg(not(unix))]
+use gglib_core::utils::process::async_cmd;
+
 #[cfg(unix)]
 use std::time::Duration;
 #[cfg(unix)]
@@ -16,6 +19,8 @@ use nix::unistd::Pid;
 
 /// Kill an orphaned process by PID with SIGTERM → SIGKILL escalation.
 ///
+/// On Windows the process tree is force-killed with `taskkill /T /F`.
+///
 /// # Strategy
 /// 1. Send SIGTERM
 /// 2. Poll for up to 2 seconds to verify process exit
@@ -107,13 +112,42 @@ async fn kill_pid_unix(pid: u32) -> io::Result<()> {
     ))
 }
 
+/// Terminate `pid` and its descendants with `taskkill /T /F`.
+///
+/// Windows has no SIGTERM to escalate from, so this is a forced kill. The
+/// `/T` matters: `TerminateProcess` on llama-server alone would leave any
+/// helper processes it started running.
+#[cfg(not(unix))]
+pub(super) async fn kill_tree_windows(pid: u32) -> io::Result<()> {
+    let output = async_cmd("taskkill")
+        .args(["/PID", &pid.to_string(), "/T", "/F"])
+        .output()
+        .await?;
+    // Exit code 128: no such process — already gone.
+    if output.status.success() || output.status.code() == Some(128) {
+        Ok(())
+    } else {
+        Err(io::Error::other(format!(
+            "taskkill failed for PID {pid}: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        )))
+    }
+}
+
 #[cfg(not(unix))]
-async fn kill_pid_windows(_pid: u32) -> io::Result<()> {
-    // Windows orphan cleanup would require different approach
-    // For now, not implemented - primarily a macOS/Linux concern
+async fn kill_pid_windows(pid: u32) -> io::Result<()> {
+    kill_tree_windows(pid).await?;
+
+    // Poll for exit (up to 2 seconds)
+    for _ in 0..20 {
+        if !crate::pidfile::pid_exists(pid) {
+            return Ok(());
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+    }
     Err(io::Error::new(
-        io::ErrorKind::Unsupported,
-        "orphan cleanup not implemented on Windows",
+        io::ErrorKind::TimedOut,
+        format!("process {} did not exit after taskkill", pid),
     ))
 }
 
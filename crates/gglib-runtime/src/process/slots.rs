@@ -0,0 +1,198 @@
+//! Slot occupancy polling.
+//!
+//! Keeps the latest [`SlotOccupancy`] of every running server, read from
+//! llama-server's `/slots` endpoint with the proxy's fetcher
+//! ([`gglib_proxy::slots::fetch_slots`], which copes with the endpoint's
+//! shifting schema). Every spawn path starts a poller when it starts a
+//! server and drops the entry when it stops it, the same way the
+//! [`super::ServerStatsRegistry`] sessions are kept.
+//!
+//! A server started with `--no-slots` is polled once; its occupancy then
+//! stays unknown.
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, LazyLock, Mutex};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use anyhow::{Result, anyhow};
+use gglib_core::domain::{SlotInfo, SlotOccupancy};
+use gglib_proxy::slots::SlotsPollResult;
+use reqwest::Client;
+use tracing::debug;
+
+/// Time between two reads of a server's slots.
+pub const SLOT_POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Global slot registry instance
+static SLOT_REGISTRY: LazyLock<Arc<SlotRegistry>> = LazyLock::new(|| Arc::new(SlotRegistry::new()));
+
+/// Get the global slot registry
+pub fn get_slot_registry() -> Arc<SlotRegistry> {
+    SLOT_REGISTRY.clone()
+}
+
+/// Read the slots of the llama-server listening on `port`.
+///
+/// llama-server answers `/slots` from the thread that evaluates prompts, so
+/// this can take as long as the prefill in progress.
+///
+/// # Errors
+///
+/// Returns an error if the server cannot be reached, was started with
+/// `--no-slots`, or answers with something that is not a slot list.
+pub async fn fetch_slots(port: u16) -> Result<Vec<SlotInfo>> {
+    let base_url = format!("http://127.0.0.1:{port}");
+    slot_infos(gglib_proxy::slots::fetch_slots(&Client::new(), &base_url).await)
+}
+
+fn slot_infos(result: SlotsPollResult) -> Result<Vec<SlotInfo>> {
+    match result {
+        SlotsPollResult::Available(slots) => Ok(slots.iter().map(|s| s.to_slot_info()).collect()),
+        SlotsPollResult::Disabled => Err(anyhow!(
+            "the server was started with --no-slots and does not report its slots"
+        )),
+        SlotsPollResult::Unreachable(reason) => Err(anyhow!("could not read slots: {reason}")),
+    }
+}
+
+#[derive(Debug)]
+struct Watched {
+    /// Identifies the poller that owns this entry, so a poller left over
+    /// from an earlier process of the same model stops instead of
+    /// overwriting the new one's figures.
+    generation: u64,
+    occupancy: Option<SlotOccupancy>,
+}
+
+/// Latest slot occupancy per running server, keyed by model ID.
+///
+/// Shared by every spawn path through [`get_slot_registry`].
+#[derive(Debug, Default)]
+pub struct SlotRegistry {
+    servers: Mutex<HashMap<u32, Watched>>,
+    next_generation: AtomicU64,
+}
+
+impl SlotRegistry {
+    /// Create an empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Start polling the slots of `model_id`'s server on `port`, replacing
+    /// any earlier poller for it.
+    ///
+    /// Outside a Tokio runtime nothing is polled and the occupancy stays
+    /// unknown.
+    pub fn watch(self: &Arc<Self>, model_id: u32, port: u16) {
+        let Ok(runtime) = tokio::runtime::Handle::try_current() else {
+            return;
+        };
+        let generation = self.next_generation.fetch_add(1, Ordering::Relaxed);
+        self.lock().insert(
+            model_id,
+            Watched {
+                generation,
+                occupancy: None,
+            },
+        );
+        let registry = Arc::clone(self);
+        runtime.spawn(async move {
+            let client = Client::new();
+            let base_url = format!("http://127.0.0.1:{port}");
+            let mut ticker = tokio::time::interval(SLOT_POLL_INTERVAL);
+            ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+            loop {
+                ticker.tick().await;
+                let result = gglib_proxy::slots::fetch_slots(&client, &base_url).await;
+                let disabled = result == SlotsPollResult::Disabled;
+                let occupancy = match slot_infos(result) {
+                    Ok(slots) => Some(SlotOccupancy::from_slots(&slots, now_ms())),
+                    Err(e) => {
+                        debug!(model_id, port, error = %e, "Failed to read server slots");
+                        None
+                    }
+                };
+                if !registry.update(model_id, generation, occupancy) || disabled {
+                    break;
+                }
+            }
+        });
+    }
+
+    /// Stop tracking `model_id` once its server has stopped; its poller ends
+    /// on its next tick.
+    pub fn forget(&self, model_id: u32) {
+        self.lock().remove(&model_id);
+    }
+
+    /// Latest occupancy of `model_id`'s server. `None` when it is not
+    /// running, has not been read yet, or the last read failed.
+    pub fn get(&self, model_id: u32) -> Option<SlotOccupancy> {
+        self.lock().get(&model_id)?.occupancy.clone()
+    }
+
+    /// Store a poller's reading. Returns `false` when the poller has been
+    /// replaced or its server forgotten, telling it to stop.
+    fn update(&self, model_id: u32, generation: u64, occupancy: Option<SlotOccupancy>) -> bool {
+        match self.lock().get_mut(&model_id) {
+            Some(watched) if watched.generation == generation => {
+                watched.occupancy = occupancy;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<u32, Watched>> {
+        // The figures are a display and routing aid; keep serving them after
+        // a poisoned update.
+        self.servers
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+}
+
+fn now_ms() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| u64::try_from(d.as_millis()).unwrap_or(u64::MAX))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn poll_results_map_onto_slot_infos() {
+        assert!(
+            slot_infos(SlotsPollResult::Available(Vec::new()))
+                .unwrap()
+                .is_empty()
+        );
+        let err = slot_infos(SlotsPollResult::Disabled).unwrap_err();
+        assert!(err.to_string().contains("--no-slots"), "{err}");
+        assert!(slot_infos(SlotsPollResult::Unreachable("refused".into())).is_err());
+    }
+
+    #[test]
+    fn a_replaced_poller_is_told_to_stop() {
+        let registry = SlotRegistry::new();
+        registry.lock().insert(
+            3,
+            Watched {
+                generation: 1,
+                occupancy: None,
+            },
+        );
+        let reading = SlotOccupancy::from_slots(&[], 5);
+        assert!(!registry.update(3, 0, Some(reading.clone())));
+        assert!(registry.update(3, 1, Some(reading.clone())));
+        assert_eq!(registry.get(3), Some(reading));
+
+        registry.forget(3);
+        assert!(!registry.update(3, 1, None));
+        assert_eq!(registry.get(3), None);
+    }
+}
@@ -0,0 +1,405 @@
+//! Per-session latency and throughput aggregation.
+//!
+//! Folds the proxy's per-request [`RequestTiming`]s into one [`ServerStats`]
+//! per running server. A session begins when the server is spawned and ends
+//! when it is killed, so a restart always starts from a clean slate and the
+//! figures describe only the process that is serving right now.
+//!
+//! The same sessions also track when each server was last used, which is what
+//! the idle auto-shutdown in [`crate::health_monitor`] measures against.
+//!
+//! Alongside the per-session figures, the registry counts each model's usage
+//! (requests, tokens and serve time) until the usage collector drains it
+//! through [`ModelUsageSource`]. That count outlives the session, so usage
+//! from a server stopped between two collections is still stored.
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, LazyLock, Mutex};
+use std::time::Duration;
+
+use gglib_core::domain::usage::UsageDelta;
+use gglib_core::domain::{RequestTiming, ServerStats};
+use gglib_core::ports::{ModelUsageSource, ServerStatsSink};
+use gglib_core::utils::clock::{Clock, SystemClock};
+
+/// Number of most recent requests averaged into the `recent_*` figures.
+const RECENT_WINDOW: usize = 20;
+
+/// Global stats registry instance
+static STATS_REGISTRY: LazyLock<Arc<ServerStatsRegistry>> =
+    LazyLock::new(|| Arc::new(ServerStatsRegistry::new()));
+
+/// Get the global server stats registry
+pub fn get_stats_registry() -> Arc<ServerStatsRegistry> {
+    STATS_REGISTRY.clone()
+}
+
+/// Running totals for one mean. Counts only the requests that measured it.
+#[derive(Debug, Default)]
+struct Mean {
+    sum: f64,
+    count: u64,
+}
+
+impl Mean {
+    fn add(&mut self, value: f64) {
+        self.sum += value;
+        self.count += 1;
+    }
+
+    fn get(&self) -> Option<f64> {
+        #[allow(clippy::cast_precision_loss)]
+        (self.count > 0).then(|| self.sum / self.count as f64)
+    }
+}
+
+fn recent_mean(window: &VecDeque<f64>) -> Option<f64> {
+    #[allow(clippy::cast_precision_loss)]
+    (!window.is_empty()).then(|| window.iter().sum::<f64>() / window.len() as f64)
+}
+
+fn push_recent(window: &mut VecDeque<f64>, value: f64) {
+    if window.len() == RECENT_WINDOW {
+        window.pop_front();
+    }
+    window.push_back(value);
+}
+
+fn millis(d: std::time::Duration) -> f64 {
+    d.as_secs_f64() * 1000.0
+}
+
+/// Accumulated figures for one server session.
+#[derive(Debug)]
+struct Session {
+    started_at: u64,
+    last_request_at: Option<u64>,
+    /// Latest request of any kind, including ones [`ServerStatsRegistry::touch`]
+    /// reported without timing.
+    last_active_at: u64,
+    /// Up to when serve time has been credited to the model's usage.
+    credited_until: u64,
+    requests: u64,
+    completion_tokens: u64,
+    queue_wait: Mean,
+    ttft: Mean,
+    tokens_per_sec: Mean,
+    recent_ttft: VecDeque<f64>,
+    recent_tokens_per_sec: VecDeque<f64>,
+}
+
+impl Session {
+    fn new(started_at: u64) -> Self {
+        Self {
+            started_at,
+            last_request_at: None,
+            last_active_at: started_at,
+            credited_until: started_at,
+            requests: 0,
+            completion_tokens: 0,
+            queue_wait: Mean::default(),
+            ttft: Mean::default(),
+            tokens_per_sec: Mean::default(),
+            recent_ttft: VecDeque::with_capacity(RECENT_WINDOW),
+            recent_tokens_per_sec: VecDeque::with_capacity(RECENT_WINDOW),
+        }
+    }
+
+    fn record(&mut self, timing: &RequestTiming, at: u64) {
+        self.requests += 1;
+        self.last_request_at = Some(at);
+        self.last_active_at = at;
+        if let Some(tokens) = timing.completion_tokens {
+            self.completion_tokens += u64::from(tokens);
+        }
+        if let Some(wait) = timing.queue_wait {
+            self.queue_wait.add(millis(wait));
+        }
+        if let Some(ttft) = timing.ttft {
+            let ms = millis(ttft);
+            self.ttft.add(ms);
+            push_recent(&mut self.recent_ttft, ms);
+        }
+        if let Some(tps) = timing.tokens_per_sec() {
+            self.tokens_per_sec.add(tps);
+            push_recent(&mut self.recent_tokens_per_sec, tps);
+        }
+    }
+
+    /// Serve time since it was last credited, moving the mark to `now`.
+    #[allow(clippy::cast_precision_loss)]
+    fn take_serve_seconds(&mut self, now: u64) -> f64 {
+        let seconds = now.saturating_sub(self.credited_until) as f64 / 1000.0;
+        self.credited_until = now;
+        seconds
+    }
+
+    fn snapshot(&self) -> ServerStats {
+        ServerStats {
+            session_started_at: self.started_at,
+            last_request_at: self.last_request_at,
+            requests: self.requests,
+            completion_tokens: self.completion_tokens,
+            avg_queue_wait_ms: self.queue_wait.get(),
+            avg_ttft_ms: self.ttft.get(),
+            avg_tokens_per_sec: self.tokens_per_sec.get(),
+            recent_ttft_ms: recent_mean(&self.recent_ttft),
+            recent_tokens_per_sec: recent_mean(&self.recent_tokens_per_sec),
+        }
+    }
+}
+
+/// Per-server session stats, keyed by model ID.
+///
+/// Shared by every spawn path (the GUI runner and the proxy's process
+/// manager) through [`get_stats_registry`], so the figures are the same
+/// whichever surface started the server.
+#[derive(Debug)]
+pub struct ServerStatsRegistry {
+    /// Stamps requests and measures idle and serve time.
+    clock: Arc<dyn Clock>,
+    sessions: Mutex<HashMap<u32, Session>>,
+    /// Usage counted per model since the last [`ModelUsageSource::drain_usage`].
+    /// Always locked after `sessions` when both are held.
+    usage: Mutex<HashMap<u32, UsageDelta>>,
+}
+
+impl ServerStatsRegistry {
+    /// Create an empty registry on the system clock.
+    pub fn new() -> Self {
+        Self::with_clock(Arc::new(SystemClock))
+    }
+
+    /// Create an empty registry reading time from `clock`.
+    pub fn with_clock(clock: Arc<dyn Clock>) -> Self {
+        Self {
+            clock,
+            sessions: Mutex::default(),
+            usage: Mutex::default(),
+        }
+    }
+
+    fn now_ms(&self) -> u64 {
+        self.clock.unix_millis()
+    }
+
+    /// Start a fresh session for `model_id`, discarding any previous one.
+    pub fn begin_session(&self, model_id: u32) {
+        let now = self.now_ms();
+        let previous = self.lock().insert(model_id, Session::new(now));
+        if let Some(mut previous) = previous {
+            self.credit_serve_time(model_id, previous.take_serve_seconds(now));
+        }
+    }
+
+    /// Drop `model_id`'s session once its server has stopped.
+    pub fn end_session(&self, model_id: u32) {
+        let ended = self.lock().remove(&model_id);
+        if let Some(mut ended) = ended {
+            self.credit_serve_time(model_id, ended.take_serve_seconds(self.now_ms()));
+        }
+    }
+
+    /// Snapshot `model_id`'s current session, if its server is running.
+    pub fn get(&self, model_id: u32) -> Option<ServerStats> {
+        self.lock().get(&model_id).map(Session::snapshot)
+    }
+
+    /// Note that `model_id`'s server handled a request this registry could not
+    /// time (e.g. one forwarded straight to the server rather than through the
+    /// proxy), so it counts as activity without skewing the averages.
+    pub fn touch(&self, model_id: u32) {
+        let now = self.now_ms();
+        self.lock()
+            .entry(model_id)
+            .or_insert_with(|| Session::new(now))
+            .last_active_at = now;
+    }
+
+    /// How long `model_id`'s server has gone without a request, measured from
+    /// session start when it has not had one yet. `None` when it has no session.
+    pub fn idle_for(&self, model_id: u32) -> Option<Duration> {
+        let last_active_at = self.lock().get(&model_id)?.last_active_at;
+        Some(Duration::from_millis(
+            self.now_ms().saturating_sub(last_active_at),
+        ))
+    }
+
+    fn credit_serve_time(&self, model_id: u32, seconds: f64) {
+        self.lock_usage().entry(model_id).or_default().serve_seconds += seconds;
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<u32, Session>> {
+        // A poisoned lock only means another recorder panicked mid-update;
+        // the figures are a display aid, so keep serving them.
+        self.sessions
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+
+    fn lock_usage(&self) -> std::sync::MutexGuard<'_, HashMap<u32, UsageDelta>> {
+        self.usage
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+}
+
+impl ServerStatsSink for ServerStatsRegistry {
+    fn record(&self, model_id: u32, timing: RequestTiming) {
+        let now = self.now_ms();
+        // A request can land on a server this registry never saw start (e.g.
+        // one adopted from a previous run), so the first record opens the
+        // session rather than being dropped.
+        self.lock()
+            .entry(model_id)
+            .or_insert_with(|| Session::new(now))
+            .record(&timing, now);
+
+        let mut usage = self.lock_usage();
+        let delta = usage.entry(model_id).or_default();
+        delta.requests += 1;
+        delta.prompt_tokens += u64::from(timing.prompt_tokens.unwrap_or(0));
+        delta.completion_tokens += u64::from(timing.completion_tokens.unwrap_or(0));
+        delta.busy_seconds += timing.busy_time().unwrap_or_default().as_secs_f64();
+    }
+}
+
+impl Default for ServerStatsRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ModelUsageSource for ServerStatsRegistry {
+    fn drain_usage(&self) -> Vec<(i64, UsageDelta)> {
+        let now = self.now_ms();
+        let mut sessions = self.lock();
+        let mut usage = self.lock_usage();
+        for (model_id, session) in sessions.iter_mut() {
+            usage.entry(*model_id).or_default().serve_seconds += session.take_serve_seconds(now);
+        }
+        usage
+            .drain()
+            .filter(|(_, delta)| !delta.is_empty())
+            .map(|(model_id, delta)| (i64::from(model_id), delta))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use gglib_core::utils::clock::ManualClock;
+    use std::time::Duration;
+
+    fn streamed(ttft_ms: u64, generation_ms: u64, tokens: u32) -> RequestTiming {
+        RequestTiming {
+            queue_wait: Some(Duration::from_millis(10)),
+            ttft: Some(Duration::from_millis(ttft_ms)),
+            generation: Some(Duration::from_millis(generation_ms)),
+            prompt_tokens: Some(50),
+            completion_tokens: Some(tokens),
+        }
+    }
+
+    #[test]
+    fn records_aggregate_into_session_means() {
+        let registry = ServerStatsRegistry::new();
+        registry.begin_session(1);
+        registry.record(1, streamed(100, 1000, 11));
+        registry.record(1, streamed(300, 1000, 21));
+
+        let stats = registry.get(1).expect("session exists");
+        assert_eq!(stats.requests, 2);
+        assert_eq!(stats.completion_tokens, 32);
+        assert_eq!(stats.avg_queue_wait_ms, Some(10.0));
+        assert_eq!(stats.avg_ttft_ms, Some(200.0));
+        assert_eq!(stats.avg_tokens_per_sec, Some(15.0));
+        assert!(stats.last_request_at.is_some());
+    }
+
+    #[test]
+    fn recent_window_tracks_only_latest_requests() {
+        let registry = ServerStatsRegistry::new();
+        for _ in 0..RECENT_WINDOW {
+            registry.record(1, streamed(100, 1000, 41));
+        }
+        for _ in 0..RECENT_WINDOW {
+            registry.record(1, streamed(500, 1000, 11));
+        }
+
+        let stats = registry.get(1).expect("session exists");
+        assert_eq!(stats.avg_ttft_ms, Some(300.0));
+        assert_eq!(stats.recent_ttft_ms, Some(500.0));
+        assert_eq!(stats.recent_tokens_per_sec, Some(10.0));
+    }
+
+    #[test]
+    fn begin_session_resets_and_end_session_removes() {
+        let registry = ServerStatsRegistry::new();
+        registry.record(7, streamed(100, 1000, 11));
+        registry.begin_session(7);
+        assert_eq!(registry.get(7).map(|s| s.requests), Some(0));
+
+        registry.end_session(7);
+        assert!(registry.get(7).is_none());
+    }
+
+    #[test]
+    fn touch_counts_as_activity_but_not_as_a_request() {
+        let clock = Arc::new(ManualClock::default());
+        let registry = ServerStatsRegistry::with_clock(clock.clone());
+        registry.begin_session(5);
+        clock.advance(Duration::from_secs(90));
+        assert_eq!(registry.idle_for(5), Some(Duration::from_secs(90)));
+
+        registry.touch(5);
+        clock.advance(Duration::from_secs(2));
+        assert_eq!(registry.idle_for(5), Some(Duration::from_secs(2)));
+        assert_eq!(registry.get(5).map(|s| s.requests), Some(0));
+        assert!(registry.idle_for(6).is_none());
+    }
+
+    #[test]
+    fn unmeasured_fields_stay_none() {
+        let registry = ServerStatsRegistry::new();
+        registry.record(
+            3,
+            RequestTiming {
+                generation: Some(Duration::from_secs(2)),
+                completion_tokens: Some(20),
+                ..Default::default()
+            },
+        );
+
+        let stats = registry.get(3).expect("session exists");
+        assert_eq!(stats.avg_ttft_ms, None);
+        assert_eq!(stats.avg_queue_wait_ms, None);
+        assert_eq!(stats.avg_tokens_per_sec, Some(10.0));
+    }
+
+    #[test]
+    fn drain_usage_counts_requests_tokens_and_serve_and_busy_time_once() {
+        let clock = Arc::new(ManualClock::default());
+        let registry = ServerStatsRegistry::with_clock(clock.clone());
+        registry.begin_session(2);
+        clock.advance(Duration::from_mins(1));
+        registry.record(2, streamed(100, 1000, 11));
+        clock.advance(Duration::from_secs(30));
+        registry.record(2, streamed(100, 1000, 21));
+        registry.end_session(2);
+
+        let drained = registry.drain_usage();
+        assert_eq!(drained.len(), 1);
+        let (model_id, delta) = drained[0];
+        assert_eq!(model_id, 2);
+        assert_eq!(delta.requests, 2);
+        assert_eq!(delta.prompt_tokens, 100);
+        assert_eq!(delta.completion_tokens, 32);
+        assert!((delta.serve_seconds - 90.0).abs() < f64::EPSILON);
+        // 90 ms of prompt processing after the queue wait, then 1 s generating.
+        assert!((delta.busy_seconds - 2.18).abs() < 1e-9);
+
+        assert!(registry.drain_usage().is_empty());
+    }
+}
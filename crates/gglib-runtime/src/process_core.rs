@@ -13,13 +13,17 @@ use tokio::process::Child;
 use tracing::debug;
 
 use crate::command;
-use crate::pidfile::{delete_pidfile, write_pidfile};
-use crate::process::shutdown::shutdown_child;
+use crate::pidfile::{
+    PidFileModel, delete_pidfile, is_our_llama_server, pid_exists, write_pidfile,
+};
+use crate::process::shutdown::{kill_pid, shutdown_child};
 
 /// Running process with handle to the child process.
 struct RunningProcess {
     handle: ProcessHandle,
-    child: Child,
+    /// `None` for a server adopted from a previous session, which this
+    /// process did not spawn and cannot reap.
+    child: Option<Child>,
     context_size: Option<u64>,
 }
 
@@ -63,7 +67,8 @@ impl ProcessCore {
             .ok_or_else(|| anyhow!("Failed to get child PID"))?;
 
         // Write PID file
-        if let Err(e) = write_pidfile(config.model_id, pid, port) {
+        let model = PidFileModel::from_config(config);
+        if let Err(e) = write_pidfile(config.model_id, pid, port, model.as_ref()) {
             debug!("Failed to write PID file: {}", e);
             // Non-fatal - continue anyway
         }
@@ -91,14 +96,47 @@ impl ProcessCore {
             config.model_id,
             RunningProcess {
                 handle: handle.clone(),
-                child,
+                child: Some(child),
                 context_size: config.context_size,
             },
         );
+        if let Ok(id) = u32::try_from(config.model_id) {
+            crate::process::get_stats_registry().begin_session(id);
+            crate::process::get_slot_registry().watch(id, handle.port);
+        }
 
         Ok(handle)
     }
 
+    /// Track a server left running by a previous session.
+    ///
+    /// Its PID file is kept, so a crash of this session leaves it adoptable
+    /// again.
+    pub fn adopt(&mut self, handle: ProcessHandle, context_size: Option<u64>) -> Result<()> {
+        if self.processes.contains_key(&handle.model_id) {
+            return Err(anyhow!("Model {} is already running", handle.model_id));
+        }
+        if handle.pid.is_none() {
+            return Err(anyhow!(
+                "Cannot adopt model {} without a PID",
+                handle.model_id
+            ));
+        }
+        if let Ok(id) = u32::try_from(handle.model_id) {
+            crate::process::get_stats_registry().begin_session(id);
+            crate::process::get_slot_registry().watch(id, handle.port);
+        }
+        self.processes.insert(
+            handle.model_id,
+            RunningProcess {
+                handle,
+                child: None,
+                context_size,
+            },
+        );
+        Ok(())
+    }
+
     /// Kill a running process with graceful shutdown.
     pub async fn kill(&mut self, model_id: i64) -> Result<()> {
         let running = self
@@ -108,9 +146,23 @@ impl ProcessCore {
 
         let pid = running.handle.pid.unwrap_or(0);
         debug!(model_id = %model_id, pid = %pid, "Stopping process");
+        if let Ok(id) = u32::try_from(model_id) {
+            crate::process::get_stats_registry().end_session(id);
+            crate::process::get_slot_registry().forget(id);
+        }
 
         // Use graceful shutdown with SIGTERM → SIGKILL
-        let _ = shutdown_child(running.child).await;
+        match running.child {
+            Some(child) => {
+                let _ = shutdown_child(child).await;
+            }
+            // Adopted: no child to reap, so only signal it while it is
+            // still the llama-server we adopted.
+            None if is_our_llama_server(pid) => {
+                let _ = kill_pid(pid).await;
+            }
+            None => {}
+        }
 
         // Remove PID file
         if let Err(e) = delete_pidfile(model_id) {
@@ -187,16 +239,21 @@ impl ProcessCore {
         let mut dead = Vec::new();
 
         for (id, r) in self.processes.iter_mut() {
-            match r.child.try_wait() {
-                Ok(Some(_)) | Err(_) => {
-                    dead.push(*id);
-                }
-                Ok(None) => {}
+            let exited = match r.child.as_mut() {
+                Some(child) => !matches!(child.try_wait(), Ok(None)),
+                None => !r.handle.pid.is_some_and(pid_exists),
+            };
+            if exited {
+                dead.push(*id);
             }
         }
 
         for id in &dead {
             self.processes.remove(id);
+            if let Ok(id) = u32::try_from(*id) {
+                crate::process::get_stats_registry().end_session(id);
+                crate::process::get_slot_registry().forget(id);
+            }
             // Remove PID file for naturally exited process
             if let Err(e) = delete_pidfile(*id) {
                 debug!("Failed to delete PID file for {}: {}", id, e);
@@ -220,3 +277,39 @@ impl Drop for ProcessCore {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn adopted_handle(model_id: i64, pid: u32) -> ProcessHandle {
+        ProcessHandle::new(model_id, "adopted".to_string(), Some(pid), 9123, 0)
+    }
+
+    #[test]
+    fn adopt_tracks_server_once() {
+        let mut core = ProcessCore::new("llama-server");
+        core.adopt(adopted_handle(99997, std::process::id()), Some(8192))
+            .unwrap();
+
+        assert!(core.is_running(99997));
+        assert_eq!(core.get_context_size(99997), Some(8192));
+        assert!(
+            core.adopt(adopted_handle(99997, std::process::id()), None)
+                .is_err()
+        );
+
+        // Forget it without running Drop's kill against our own PID.
+        core.processes.clear();
+    }
+
+    #[tokio::test]
+    #[cfg(unix)]
+    async fn cleanup_dead_drops_adopted_server_that_exited() {
+        let mut core = ProcessCore::new("llama-server");
+        core.adopt(adopted_handle(99998, 999_999), None).unwrap();
+
+        assert_eq!(core.cleanup_dead().await, vec![99998]);
+        assert!(!core.is_running(99998));
+    }
+}
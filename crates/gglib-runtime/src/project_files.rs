@@ -0,0 +1,373 @@
+//! File trees and prompt context for chat projects.
+//!
+//! A [`gglib_core::domain::Project`] names a local directory plus include
+//! and exclude globs. This module walks that directory and
+//!
+//! - lists it as a [`FileTree`], flagging the files the globs select, and
+//! - reads the selected files into a [`ProjectContext`], in path order,
+//!   until the token budget is spent.
+//!
+//! Paths are always relative to the project root with `/` separators.
+//! Symlinks are never followed, so nothing outside the root is read, and
+//! VCS metadata and dependency/build directories are skipped entirely.
+
+use std::fs;
+use std::io::{self, Read};
+use std::path::Path;
+
+use gglib_core::request_pipeline::CHARS_PER_TOKEN_APPROX;
+use glob::{MatchOptions, Pattern, PatternError};
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+use utoipa::ToSchema;
+use walkdir::{DirEntry, WalkDir};
+
+/// Upper bound on entries returned by [`list_file_tree`].
+pub const MAX_TREE_ENTRIES: usize = 5_000;
+
+/// Files larger than this are never injected, whatever the budget.
+pub const MAX_CONTEXT_FILE_BYTES: u64 = 512 * 1024;
+
+/// Directory names that are never descended into.
+const SKIPPED_DIRS: &[&str] = &[
+    ".git",
+    ".hg",
+    ".svn",
+    "node_modules",
+    "target",
+    "dist",
+    "build",
+    "__pycache__",
+    ".venv",
+    "venv",
+];
+
+/// Glob matching options: `*` stays within one path segment, `**` crosses them.
+const MATCH_OPTIONS: MatchOptions = MatchOptions {
+    case_sensitive: true,
+    require_literal_separator: true,
+    require_literal_leading_dot: false,
+};
+
+/// Compiled include/exclude globs of a project.
+#[derive(Debug, Clone)]
+pub struct IncludeRules {
+    include: Vec<Pattern>,
+    exclude: Vec<Pattern>,
+}
+
+impl IncludeRules {
+    /// Compile `include` and `exclude` globs.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first glob that fails to parse.
+    pub fn new(include: &[String], exclude: &[String]) -> Result<Self, PatternError> {
+        let compile = |globs: &[String]| -> Result<Vec<Pattern>, PatternError> {
+            globs.iter().map(|g| Pattern::new(g.trim())).collect()
+        };
+        Ok(Self {
+            include: compile(include)?,
+            exclude: compile(exclude)?,
+        })
+    }
+
+    /// Whether the file at `rel_path` is selected.
+    #[must_use]
+    pub fn matches(&self, rel_path: &str) -> bool {
+        self.include
+            .iter()
+            .any(|p| p.matches_with(rel_path, MATCH_OPTIONS))
+            && !self
+                .exclude
+                .iter()
+                .any(|p| p.matches_with(rel_path, MATCH_OPTIONS))
+    }
+}
+
+/// One file or directory in a project tree.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, TS, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct FileTreeEntry {
+    /// Path relative to the project root.
+    pub path: String,
+    pub is_dir: bool,
+    /// Size in bytes (0 for directories).
+    pub size_bytes: u64,
+    /// Whether the include rules select this file.
+    pub included: bool,
+}
+
+/// A project directory listing.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, TS, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct FileTree {
+    /// Entries in depth-first path order.
+    pub entries: Vec<FileTreeEntry>,
+    /// True when the listing stopped at [`MAX_TREE_ENTRIES`].
+    pub truncated: bool,
+}
+
+/// A file injected into the prompt.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, TS, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ContextFile {
+    pub path: String,
+    /// Estimated tokens, at [`CHARS_PER_TOKEN_APPROX`] characters per token.
+    pub tokens: usize,
+    pub content: String,
+}
+
+/// Why a selected file was left out of the prompt.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, TS, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum SkipReason {
+    /// Larger than [`MAX_CONTEXT_FILE_BYTES`].
+    TooLarge,
+    /// Not valid UTF-8 text.
+    Binary,
+    /// Would have pushed the total past the token budget.
+    OverBudget,
+    /// Could not be read.
+    Unreadable,
+}
+
+/// A selected file that was not injected.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, TS, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SkippedFile {
+    pub path: String,
+    pub reason: SkipReason,
+}
+
+/// The files of a project that fit in its token budget.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, TS, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectContext {
+    pub files: Vec<ContextFile>,
+    pub skipped: Vec<SkippedFile>,
+    /// Sum of [`ContextFile::tokens`].
+    pub total_tokens: usize,
+    pub token_budget: usize,
+}
+
+impl ProjectContext {
+    /// Render the injected files as `<file path="…">` blocks.
+    #[must_use]
+    pub fn render(&self) -> String {
+        self.files
+            .iter()
+            .map(|f| format!("<file path=\"{}\">\n{}\n</file>", f.path, f.content))
+            .collect::<Vec<_>>()
+            .join("\n\n")
+    }
+}
+
+/// Whether the walker should descend into / report `entry`.
+fn is_visible(entry: &DirEntry) -> bool {
+    entry.depth() == 0
+        || !(entry.file_type().is_dir()
+            && entry
+                .file_name()
+                .to_str()
+                .is_some_and(|name| SKIPPED_DIRS.contains(&name)))
+}
+
+/// Walk `root` in sorted order, yielding `(relative path, entry)` pairs.
+fn walk(root: &Path) -> impl Iterator<Item = (String, DirEntry)> + '_ {
+    WalkDir::new(root)
+        .min_depth(1)
+        .sort_by_file_name()
+        .into_iter()
+        .filter_entry(is_visible)
+        .filter_map(Result::ok)
+        .filter_map(move |entry| {
+            let rel = entry.path().strip_prefix(root).ok()?;
+            let rel = rel
+                .components()
+                .map(|c| c.as_os_str().to_str())
+                .collect::<Option<Vec<_>>>()?
+                .join("/");
+            Some((rel, entry))
+        })
+}
+
+fn ensure_dir(root: &Path) -> io::Result<()> {
+    if fs::metadata(root)?.is_dir() {
+        Ok(())
+    } else {
+        Err(io::Error::new(
+            io::ErrorKind::NotADirectory,
+            format!("{} is not a directory", root.display()),
+        ))
+    }
+}
+
+/// List the files and directories under `root`.
+///
+/// # Errors
+///
+/// Fails when `root` does not exist or is not a directory; unreadable
+/// entries below it are skipped.
+pub fn list_file_tree(root: &Path, rules: &IncludeRules) -> io::Result<FileTree> {
+    ensure_dir(root)?;
+
+    let mut entries = Vec::new();
+    let mut truncated = false;
+    for (path, entry) in walk(root) {
+        let file_type = entry.file_type();
+        if !file_type.is_dir() && !file_type.is_file() {
+            continue;
+        }
+        if entries.len() == MAX_TREE_ENTRIES {
+            truncated = true;
+            break;
+        }
+        let is_dir = file_type.is_dir();
+        entries.push(FileTreeEntry {
+            included: !is_dir && rules.matches(&path),
+            size_bytes: if is_dir {
+                0
+            } else {
+                entry.metadata().map_or(0, |m| m.len())
+            },
+            is_dir,
+            path,
+        });
+    }
+    Ok(FileTree { entries, truncated })
+}
+
+/// Read `path` as UTF-8 text, classifying why it cannot be injected.
+fn read_text(path: &Path) -> Result<String, SkipReason> {
+    let file = fs::File::open(path).map_err(|_| SkipReason::Unreadable)?;
+    let mut bytes = Vec::new();
+    file.take(MAX_CONTEXT_FILE_BYTES + 1)
+        .read_to_end(&mut bytes)
+        .map_err(|_| SkipReason::Unreadable)?;
+    if bytes.len() as u64 > MAX_CONTEXT_FILE_BYTES {
+        return Err(SkipReason::TooLarge);
+    }
+    if bytes.contains(&0) {
+        return Err(SkipReason::Binary);
+    }
+    String::from_utf8(bytes).map_err(|_| SkipReason::Binary)
+}
+
+/// Read the files `rules` select under `root`, in path order, until
+/// `token_budget` is spent.
+///
+/// A file that does not fit is skipped and later, smaller files may still
+/// be included.
+///
+/// # Errors
+///
+/// Fails when `root` does not exist or is not a directory.
+pub fn build_context(
+    root: &Path,
+    rules: &IncludeRules,
+    token_budget: usize,
+) -> io::Result<ProjectContext> {
+    ensure_dir(root)?;
+
+    let mut context = ProjectContext {
+        files: Vec::new(),
+        skipped: Vec::new(),
+        total_tokens: 0,
+        token_budget,
+    };
+    for (path, entry) in walk(root) {
+        if !entry.file_type().is_file() || !rules.matches(&path) {
+            continue;
+        }
+        let reason = match read_text(entry.path()) {
+            Ok(content) => {
+                let tokens = content.chars().count().div_ceil(CHARS_PER_TOKEN_APPROX);
+                if context.total_tokens + tokens <= token_budget {
+                    context.total_tokens += tokens;
+                    context.files.push(ContextFile {
+                        path,
+                        tokens,
+                        content,
+                    });
+                    continue;
+                }
+                SkipReason::OverBudget
+            }
+            Err(reason) => reason,
+        };
+        context.skipped.push(SkippedFile { path, reason });
+    }
+    Ok(context)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rules(include: &[&str], exclude: &[&str]) -> IncludeRules {
+        let owned = |globs: &[&str]| globs.iter().map(ToString::to_string).collect::<Vec<_>>();
+        IncludeRules::new(&owned(include), &owned(exclude)).unwrap()
+    }
+
+    fn write(root: &Path, rel: &str, content: &[u8]) {
+        let path = root.join(rel);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, content).unwrap();
+    }
+
+    #[test]
+    fn globs_match_relative_paths() {
+        let r = rules(&["src/**/*.rs", "*.md"], &["src/gen/**"]);
+        assert!(r.matches("src/lib.rs"));
+        assert!(r.matches("src/a/b.rs"));
+        assert!(r.matches("README.md"));
+        assert!(!r.matches("docs/guide.md"));
+        assert!(!r.matches("src/gen/out.rs"));
+        assert!(IncludeRules::new(&["[".to_string()], &[]).is_err());
+    }
+
+    #[test]
+    fn tree_skips_vcs_and_build_dirs() {
+        let dir = tempfile::tempdir().unwrap();
+        write(dir.path(), "src/main.rs", b"fn main() {}");
+        write(dir.path(), ".git/HEAD", b"ref");
+        write(dir.path(), "target/debug/app", b"bin");
+
+        let tree = list_file_tree(dir.path(), &rules(&["**/*.rs"], &[])).unwrap();
+        let paths: Vec<_> = tree.entries.iter().map(|e| e.path.as_str()).collect();
+        assert_eq!(paths, ["src", "src/main.rs"]);
+        assert!(tree.entries[1].included);
+        assert!(!tree.truncated);
+
+        assert!(list_file_tree(&dir.path().join("missing"), &rules(&[], &[])).is_err());
+    }
+
+    #[test]
+    fn context_respects_budget_and_skips_binaries() {
+        let dir = tempfile::tempdir().unwrap();
+        write(dir.path(), "a.txt", &[b'a'; 40]);
+        write(dir.path(), "b.txt", &[b'b'; 80]);
+        write(dir.path(), "c.txt", &[b'c'; 8]);
+        write(dir.path(), "d.txt", b"\0\x01\x02");
+
+        let context = build_context(dir.path(), &rules(&["*.txt"], &[]), 15).unwrap();
+        let included: Vec<_> = context.files.iter().map(|f| f.path.as_str()).collect();
+        assert_eq!(included, ["a.txt", "c.txt"]);
+        assert_eq!(context.total_tokens, 12);
+        assert_eq!(
+            context.skipped,
+            [
+                SkippedFile {
+                    path: "b.txt".to_string(),
+                    reason: SkipReason::OverBudget
+                },
+                SkippedFile {
+                    path: "d.txt".to_string(),
+                    reason: SkipReason::Binary
+                },
+            ]
+        );
+        assert!(context.render().starts_with("<file path=\"a.txt\">\naaaa"));
+    }
+}
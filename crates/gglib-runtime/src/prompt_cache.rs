@@ -0,0 +1,299 @@
+//! Warming cached prompts on a running llama-server.
+//!
+//! A cached prompt (see [`gglib_core::domain::prompt_cache`]) is made warm by
+//! leaving its KV state in slot 0, where llama-server's prefix matching
+//! picks it up for the next request that starts with the same system
+//! prompt. [`PromptWarmer::warm`] gets it there the cheap way when it can:
+//!
+//! 1. **Restore** the state saved on an earlier run
+//!    (`prompt__{model_id}__{name}.bin` in the slot directory, see
+//!    [`gglib_core::paths::prompt_cache_file_name`]).
+//! 2. Otherwise **evaluate** the prompt with a one-token chat completion,
+//!    then **save** the slot so the next start can restore it.
+//!
+//! A saved file the server rejects (another llama.cpp build, different
+//! context or cache types) just falls through to evaluation, which
+//! overwrites it. Persistence needs a slot directory and a model whose KV
+//! memory keeps the whole history — see [`crate::llama::args::slot_restore`]
+//! for why sliding-window and hybrid models only ever evaluate.
+//!
+//! With several prompts for one model, the last one warmed holds the slot;
+//! the others stay in llama-server's host-RAM prompt cache when
+//! `--cache-ram` gives it room.
+
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+use anyhow::{Context, Result, bail};
+use reqwest::Client;
+use tracing::{debug, info, warn};
+
+use gglib_core::domain::{CachedPrompt, PromptWarmResult, PromptWarmSource};
+use gglib_core::paths::prompt_cache_file_name;
+
+/// Upper bound for evaluating one prompt. Generous on purpose: a prompt
+/// worth caching is a long one, and prefill on a CPU-only machine can take
+/// minutes.
+const EVALUATE_TIMEOUT: Duration = Duration::from_secs(15 * 60);
+
+/// Upper bound for one slot save or restore request.
+const SLOT_IO_TIMEOUT: Duration = Duration::from_secs(120);
+
+/// Warms cached prompts on one llama-server instance.
+#[derive(Debug, Clone)]
+pub struct PromptWarmer {
+    client: Client,
+    base_url: String,
+    model_id: u32,
+    slot_dir: Option<PathBuf>,
+}
+
+impl PromptWarmer {
+    /// A warmer for the server of `model_id` on `port`.
+    ///
+    /// `slot_dir` is the server's `--slot-save-path`; pass `None` when it has
+    /// none or the model cannot restore slots, and prompts are evaluated
+    /// every time without being saved.
+    #[must_use]
+    pub fn new(port: u16, model_id: u32, slot_dir: Option<PathBuf>) -> Self {
+        Self {
+            client: Client::new(),
+            base_url: format!("http://127.0.0.1:{port}"),
+            model_id,
+            slot_dir,
+        }
+    }
+
+    /// Warm `prompt`, restoring saved state when `reuse_saved` is set and
+    /// there is some.
+    ///
+    /// Pass `reuse_saved = false` after editing a prompt's text: the saved
+    /// state still holds the old text and would be restored as-is.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the server fails to evaluate the prompt.
+    pub async fn warm(
+        &self,
+        name: &str,
+        system_prompt: &str,
+        reuse_saved: bool,
+    ) -> Result<PromptWarmResult> {
+        let started = Instant::now();
+        let file_name = prompt_cache_file_name(self.model_id, name);
+
+        let saved = self
+            .slot_dir
+            .as_ref()
+            .is_some_and(|dir| dir.join(&file_name).is_file());
+        if reuse_saved && saved {
+            match self.slot_action("restore", &file_name).await {
+                Ok(()) => {
+                    return Ok(warm_result(name, PromptWarmSource::Restored, true, started));
+                }
+                Err(e) => debug!(prompt = name, error = %e, "Saved state not restorable"),
+            }
+        }
+
+        self.evaluate(system_prompt)
+            .await
+            .with_context(|| format!("evaluating cached prompt '{name}'"))?;
+
+        let persisted = if self.slot_dir.is_some() {
+            match self.slot_action("save", &file_name).await {
+                Ok(()) => true,
+                Err(e) => {
+                    warn!(prompt = name, error = %e, "Failed to save cached prompt state");
+                    false
+                }
+            }
+        } else {
+            false
+        };
+        Ok(warm_result(
+            name,
+            PromptWarmSource::Evaluated,
+            persisted,
+            started,
+        ))
+    }
+
+    /// Warm every prompt in `prompts` configured for this warmer's model, in
+    /// list order, reusing saved state. Failures are logged and skipped.
+    pub async fn warm_configured(&self, prompts: &[CachedPrompt]) -> Vec<PromptWarmResult> {
+        let mut results = Vec::new();
+        for prompt in prompts
+            .iter()
+            .filter(|p| p.model_id == i64::from(self.model_id))
+        {
+            match self.warm(&prompt.name, &prompt.system_prompt, true).await {
+                Ok(result) => {
+                    info!(
+                        model_id = self.model_id,
+                        prompt = %result.name,
+                        source = ?result.source,
+                        persisted = result.persisted,
+                        elapsed_ms = result.elapsed_ms,
+                        "Cached prompt warmed"
+                    );
+                    results.push(result);
+                }
+                Err(e) => warn!(
+                    model_id = self.model_id,
+                    error = %format!("{e:#}"),
+                    "Failed to warm cached prompt"
+                ),
+            }
+        }
+        results
+    }
+
+    /// Prefill `system_prompt` into slot 0 with a one-token completion.
+    ///
+    /// The empty user turn keeps templates that refuse a system-only
+    /// conversation happy; the rendered system block, which is what later
+    /// requests share, comes out the same either way.
+    async fn evaluate(&self, system_prompt: &str) -> Result<()> {
+        let body = serde_json::json!({
+            "messages": [
+                {"role": "system", "content": system_prompt},
+                {"role": "user", "content": ""},
+            ],
+            "max_tokens": 1,
+            "cache_prompt": true,
+            "id_slot": 0,
+        });
+        let resp = self
+            .client
+            .post(format!("{}/v1/chat/completions", self.base_url))
+            .timeout(EVALUATE_TIMEOUT)
+            .json(&body)
+            .send()
+            .await?;
+        if !resp.status().is_success() {
+            let status = resp.status();
+            let text = resp.text().await.unwrap_or_default();
+            bail!("llama-server returned {status}: {text}");
+        }
+        Ok(())
+    }
+
+    /// `POST /slots/0?action={action}` for `file_name`.
+    async fn slot_action(&self, action: &str, file_name: &str) -> Result<()> {
+        let resp = self
+            .client
+            .post(format!("{}/slots/0?action={action}", self.base_url))
+            .timeout(SLOT_IO_TIMEOUT)
+            .json(&serde_json::json!({ "filename": file_name }))
+            .send()
+            .await?;
+        if !resp.status().is_success() {
+            let status = resp.status();
+            let text = resp.text().await.unwrap_or_default();
+            bail!("slot {action} returned {status}: {text}");
+        }
+        Ok(())
+    }
+}
+
+fn warm_result(
+    name: &str,
+    source: PromptWarmSource,
+    persisted: bool,
+    started: Instant,
+) -> PromptWarmResult {
+    PromptWarmResult {
+        name: name.to_string(),
+        source,
+        persisted,
+        elapsed_ms: u64::try_from(started.elapsed().as_millis()).unwrap_or(u64::MAX),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::Router;
+    use axum::extract::Query;
+    use axum::routing::post;
+    use std::collections::HashMap;
+    use std::sync::{Arc, Mutex};
+
+    use super::*;
+
+    /// A fake llama-server recording which endpoints were hit.
+    async fn fake_server(calls: Arc<Mutex<Vec<String>>>) -> u16 {
+        let chat_calls = Arc::clone(&calls);
+        let app = Router::new()
+            .route(
+                "/v1/chat/completions",
+                post(move || {
+                    chat_calls.lock().unwrap().push("evaluate".to_string());
+                    async { "{}" }
+                }),
+            )
+            .route(
+                "/slots/0",
+                post(move |Query(q): Query<HashMap<String, String>>| {
+                    calls.lock().unwrap().push(q["action"].clone());
+                    async { "{}" }
+                }),
+            );
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        tokio::spawn(async move { axum::serve(listener, app).await });
+        port
+    }
+
+    #[tokio::test]
+    async fn restores_saved_state_and_evaluates_otherwise() {
+        let calls = Arc::new(Mutex::new(Vec::new()));
+        let port = fake_server(Arc::clone(&calls)).await;
+        let dir = tempfile::tempdir().unwrap();
+        let warmer = PromptWarmer::new(port, 7, Some(dir.path().to_path_buf()));
+
+        let first = warmer.warm("agent", "You are terse.", true).await.unwrap();
+        assert_eq!(first.source, PromptWarmSource::Evaluated);
+        assert!(first.persisted);
+        assert_eq!(*calls.lock().unwrap(), ["evaluate", "save"]);
+
+        // The fake server does not write the file; stand in for it.
+        std::fs::write(dir.path().join(prompt_cache_file_name(7, "agent")), b"kv").unwrap();
+        calls.lock().unwrap().clear();
+        let second = warmer.warm("agent", "You are terse.", true).await.unwrap();
+        assert_eq!(second.source, PromptWarmSource::Restored);
+        assert_eq!(*calls.lock().unwrap(), ["restore"]);
+
+        // An edited prompt must not reuse the stale state.
+        calls.lock().unwrap().clear();
+        let edited = warmer
+            .warm("agent", "You are verbose.", false)
+            .await
+            .unwrap();
+        assert_eq!(edited.source, PromptWarmSource::Evaluated);
+        assert_eq!(*calls.lock().unwrap(), ["evaluate", "save"]);
+    }
+
+    #[tokio::test]
+    async fn without_a_slot_dir_nothing_is_saved() {
+        let calls = Arc::new(Mutex::new(Vec::new()));
+        let port = fake_server(Arc::clone(&calls)).await;
+        let warmer = PromptWarmer::new(port, 7, None);
+
+        let prompts = [
+            CachedPrompt {
+                model_id: 7,
+                name: "agent".to_string(),
+                system_prompt: "You are terse.".to_string(),
+            },
+            CachedPrompt {
+                model_id: 8,
+                name: "other".to_string(),
+                system_prompt: "Not this model.".to_string(),
+            },
+        ];
+        let results = warmer.warm_configured(&prompts).await;
+        assert_eq!(results.len(), 1);
+        assert!(!results[0].persisted);
+        assert_eq!(*calls.lock().unwrap(), ["evaluate"]);
+    }
+}
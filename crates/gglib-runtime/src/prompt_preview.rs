@@ -0,0 +1,240 @@
+//! Rendering conversations through a running llama-server's chat template.
+//!
+//! gglib has no template engine of its own: llama-server applies the
+//! model's Jinja template (or its built-in fallback) when it turns a chat
+//! request into a prompt, and that is the rendering worth showing. The
+//! [`PromptPreviewer`] asks the server for it the same way a chat request
+//! would be handled:
+//!
+//! 1. `POST /apply-template` renders the messages and tools, with the
+//!    generation prompt for the assistant's reply.
+//! 2. `POST /tokenize` counts the result with special tokens parsed and BOS
+//!    added, as a completion would.
+//! 3. `/slots` supplies the context size to measure it against.
+//!
+//! [`PromptPreviewer::check_template`] runs the golden conversations of
+//! [`gglib_core::domain::template_check`] through the same two steps and
+//! reports what looks wrong with each rendering.
+
+use std::time::Duration;
+
+use anyhow::{Context, Result, bail};
+use reqwest::Client;
+use serde::Deserialize;
+use serde_json::Value;
+
+use gglib_core::domain::PromptPreview;
+use gglib_core::domain::template_check::{
+    BosPolicy, TemplateCheck, check_rendered, golden_conversations,
+};
+use gglib_proxy::slots::SlotsPollResult;
+
+/// Upper bound for one render or tokenize request. Neither evaluates the
+/// model, but both queue behind the prompt the server is processing.
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(60);
+
+#[derive(Deserialize)]
+struct ApplyTemplateResponse {
+    prompt: String,
+}
+
+#[derive(Deserialize)]
+struct TokenizeResponse {
+    tokens: Vec<i64>,
+}
+
+/// Renders and measures prompts on one llama-server instance.
+#[derive(Debug, Clone)]
+pub struct PromptPreviewer {
+    client: Client,
+    base_url: String,
+}
+
+impl PromptPreviewer {
+    /// A previewer for the server listening on `port`.
+    #[must_use]
+    pub fn new(port: u16) -> Self {
+        Self {
+            client: Client::new(),
+            base_url: format!("http://127.0.0.1:{port}"),
+        }
+    }
+
+    /// Render `messages` (OpenAI chat format) and `tools`, count the
+    /// prompt's tokens, and measure them against the server's context.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the server cannot render or tokenize the
+    /// conversation — a template that rejects it included.
+    pub async fn preview(&self, messages: &[Value], tools: &[Value]) -> Result<PromptPreview> {
+        let prompt = self.render(messages, tools).await?;
+        let token_count = self.count_tokens(&prompt).await?;
+        let context_size = self.context_size().await;
+        Ok(PromptPreview::new(prompt, token_count, context_size))
+    }
+
+    /// Render `messages` and `tools` through the server's chat template.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the server is unreachable or the template
+    /// rejects the conversation.
+    pub async fn render(&self, messages: &[Value], tools: &[Value]) -> Result<String> {
+        let mut body = serde_json::json!({ "messages": messages });
+        if !tools.is_empty() {
+            body["tools"] = Value::from(tools.to_vec());
+        }
+        let rendered: ApplyTemplateResponse = self
+            .post("/apply-template", &body)
+            .await
+            .context("rendering the chat template")?;
+        Ok(rendered.prompt)
+    }
+
+    /// Render every golden conversation and check the result, in suite
+    /// order. A conversation the template rejects is reported as such
+    /// rather than ending the run.
+    pub async fn check_template(&self, bos: BosPolicy) -> Vec<TemplateCheck> {
+        let mut checks = Vec::new();
+        for conversation in golden_conversations() {
+            let rendered = match self
+                .render(&conversation.messages, &conversation.tools)
+                .await
+            {
+                Ok(prompt) => self
+                    .tokenize(&prompt)
+                    .await
+                    .map(|tokens| check_rendered(&conversation, prompt, &tokens, bos)),
+                Err(e) => Err(e),
+            };
+            checks.push(
+                rendered.unwrap_or_else(|e| {
+                    TemplateCheck::render_failed(&conversation, format!("{e:#}"))
+                }),
+            );
+        }
+        checks
+    }
+
+    /// Count the tokens of `prompt` the way a completion tokenizes it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the server is unreachable or cannot tokenize.
+    pub async fn count_tokens(&self, prompt: &str) -> Result<u64> {
+        let tokens = self.tokenize(prompt).await?;
+        Ok(u64::try_from(tokens.len()).unwrap_or(u64::MAX))
+    }
+
+    /// Tokenize `prompt` the way a completion does: special tokens parsed,
+    /// BOS added when the tokenizer asks for it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the server is unreachable or cannot tokenize.
+    pub async fn tokenize(&self, prompt: &str) -> Result<Vec<i64>> {
+        let body = serde_json::json!({
+            "content": prompt,
+            "add_special": true,
+            "parse_special": true,
+        });
+        let tokenized: TokenizeResponse = self
+            .post("/tokenize", &body)
+            .await
+            .context("tokenizing the prompt")?;
+        Ok(tokenized.tokens)
+    }
+
+    /// Context size of the server's first slot, when it reports one.
+    async fn context_size(&self) -> Option<u64> {
+        match gglib_proxy::slots::fetch_slots(&self.client, &self.base_url).await {
+            SlotsPollResult::Available(slots) => slots.first().and_then(|s| s.n_ctx),
+            SlotsPollResult::Disabled | SlotsPollResult::Unreachable(_) => None,
+        }
+    }
+
+    async fn post<T: for<'de> Deserialize<'de>>(&self, path: &str, body: &Value) -> Result<T> {
+        let resp = self
+            .client
+            .post(format!("{}{path}", self.base_url))
+            .timeout(REQUEST_TIMEOUT)
+            .json(body)
+            .send()
+            .await?;
+        if !resp.status().is_success() {
+            let status = resp.status();
+            let text = resp.text().await.unwrap_or_default();
+            bail!("llama-server returned {status}: {text}");
+        }
+        Ok(resp.json().await?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::routing::{get, post};
+    use axum::{Json, Router};
+
+    use super::*;
+
+    /// A fake llama-server with a two-token tokenizer and a 16-token slot.
+    async fn fake_server() -> u16 {
+        let app = Router::new()
+            .route(
+                "/apply-template",
+                post(|Json(body): Json<Value>| async move {
+                    let user = body["messages"][0]["content"].as_str().unwrap_or_default();
+                    let tools = body.get("tools").map_or(0, |t| t.as_array().unwrap().len());
+                    Json(serde_json::json!({
+                        "prompt": format!("<user>{user}</user>[tools:{tools}]<assistant>")
+                    }))
+                }),
+            )
+            .route(
+                "/tokenize",
+                post(|| async { Json(serde_json::json!({ "tokens": [1, 2] })) }),
+            )
+            .route(
+                "/slots",
+                get(|| async { Json(serde_json::json!([{ "id": 0, "n_ctx": 16 }])) }),
+            );
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        tokio::spawn(async move { axum::serve(listener, app).await });
+        port
+    }
+
+    #[tokio::test]
+    async fn previews_the_rendered_prompt_against_the_slot_context() {
+        let previewer = PromptPreviewer::new(fake_server().await);
+        let messages = [serde_json::json!({"role": "user", "content": "hi"})];
+        let tools = [serde_json::json!({"type": "function"})];
+
+        let preview = previewer.preview(&messages, &tools).await.unwrap();
+        assert_eq!(preview.prompt, "<user>hi</user>[tools:1]<assistant>");
+        assert_eq!(preview.token_count, 2);
+        assert_eq!(preview.context_size, Some(16));
+        assert!(preview.warning.is_none());
+
+        // Tools are left out of the request entirely when there are none.
+        let bare = previewer.render(&messages, &[]).await.unwrap();
+        assert!(bare.contains("[tools:0]"), "{bare}");
+    }
+
+    #[tokio::test]
+    async fn template_check_covers_the_suite_and_reports_what_it_finds() {
+        let previewer = PromptPreviewer::new(fake_server().await);
+        let checks = previewer
+            .check_template(BosPolicy {
+                bos_id: Some(1),
+                expects_bos: true,
+            })
+            .await;
+        assert_eq!(checks.len(), golden_conversations().len());
+        // The fake template keeps only the first message, so the user's
+        // turn in particular is lost.
+        assert!(checks.iter().all(|c| !c.passed()));
+        assert!(checks[0].prompt.as_deref().unwrap().starts_with("<user>"));
+    }
+}
@@ -3,6 +3,7 @@ pub mod models;
 pub mod supervisor;
 
 // Re-export supervisor types
+pub use gglib_proxy::AccessPolicy;
 pub use supervisor::{ProxyConfig, ProxyStatus, ProxySupervisor, SupervisorError};
 
 use anyhow::{Result, anyhow};
@@ -21,7 +22,7 @@ use gglib_core::domain::council::run::{CouncilRun, CouncilRunEvent, CouncilRunSt
 use gglib_core::domain::inference::InferenceConfig;
 use gglib_core::ports::{
     ApprovalDecision, CouncilApprovalRegistryPort, CouncilRepositoryPort, ModelCatalogPort,
-    ModelRepository, RepositoryError, SettingsRepository,
+    ModelRepository, PresetRepository, RepositoryError, SettingsRepository,
 };
 use gglib_core::server_config::CacheRamSetting;
 use gglib_mcp::McpService;
@@ -200,6 +201,9 @@ impl CouncilRepositoryPort for InMemoryCouncilRepository {
 /// # Arguments
 ///
 /// * `host` - Host to bind to (e.g., "127.0.0.1")
+/// * `allow_public` - Opt-in for a `host` other machines can reach
+///   (e.g., "0.0.0.0"); refused without it
+/// * `access` - Allowed browser origins and the API key clients must present
 /// * `port` - Port to bind to
 /// * `llama_base_port` - Base port for llama-server instances
 /// * `llama_server_path` - Path to llama-server binary
@@ -207,6 +211,7 @@ impl CouncilRepositoryPort for InMemoryCouncilRepository {
 /// * `default_context` - Default context size for models
 /// * `mcp` - MCP service for tool gateway
 /// * `settings_repo` - Settings repository for global inference defaults
+/// * `preset_repo` - Preset repository for requests that select a preset
 /// * `inference_override` - Optional once-off inference parameter overrides
 ///   (applied on top of persisted global defaults; not saved to disk)
 /// * `cache_enabled` - Whether to enable KV cache session persistence.
@@ -235,6 +240,8 @@ impl CouncilRepositoryPort for InMemoryCouncilRepository {
 #[allow(clippy::too_many_arguments)]
 pub async fn start_proxy_standalone(
     host: String,
+    allow_public: bool,
+    access: AccessPolicy,
     port: u16,
     llama_base_port: u16,
     llama_server_path: PathBuf,
@@ -242,6 +249,7 @@ pub async fn start_proxy_standalone(
     default_context: u64,
     mcp: Arc<McpService>,
     settings_repo: Arc<dyn SettingsRepository>,
+    preset_repo: Arc<dyn PresetRepository>,
     inference_override: Option<InferenceConfig>,
     cache_enabled: bool,
     slot_dir: Option<PathBuf>,
@@ -256,9 +264,7 @@ pub async fn start_proxy_standalone(
     // guarantees `--cache` off means zero cache-related flags downstream.
     let slot_save_path: Option<PathBuf> = if cache_enabled {
         Some(slot_dir.unwrap_or_else(|| {
-            gglib_core::paths::data_root()
-                .map(|d| d.join("slots"))
-                .unwrap_or_else(|_| PathBuf::from("slots"))
+            gglib_core::paths::slots_dir().unwrap_or_else(|_| PathBuf::from("slots"))
         }))
     } else {
         None
@@ -270,19 +276,22 @@ pub async fn start_proxy_standalone(
 
     // Create ProcessManager with SingleSwap strategy for proxy use
     // Now uses resolve_for_launch internally - no path resolver needed
-    let process_manager = Arc::new(ProcessManager::new_single_swap(
-        llama_base_port,
-        llama_server_path.to_string_lossy(),
-        Arc::clone(&catalog_port),
-        slot_save_path.clone(),
-        // No explicit value from the caller means auto-size, not "leave the
-        // llama-server default" — the proxy is the one launch surface where a
-        // right-sized prompt cache is the whole point.
-        cache_ram_mb.map_or(CacheRamSetting::Auto, CacheRamSetting::ExplicitMb),
-        cache_reuse,
-        cache_type_k,
-        cache_type_v,
-    ));
+    let process_manager = Arc::new(
+        ProcessManager::new_single_swap(
+            llama_base_port,
+            llama_server_path.to_string_lossy(),
+            Arc::clone(&catalog_port),
+            slot_save_path.clone(),
+            // No explicit value from the caller means auto-size, not "leave the
+            // llama-server default" — the proxy is the one launch surface where a
+            // right-sized prompt cache is the whole point.
+            cache_ram_mb.map_or(CacheRamSetting::Auto, CacheRamSetting::ExplicitMb),
+            cache_reuse,
+            cache_type_k,
+            cache_type_v,
+        )
+        .with_cached_prompts(Arc::clone(&settings_repo)),
+    );
 
     // Create runtime port
     let runtime_port: Arc<dyn gglib_core::ports::ModelRuntimePort> =
@@ -311,8 +320,10 @@ pub async fn start_proxy_standalone(
     let supervisor = ProxySupervisor::new();
 
     // Start proxy
+    let exposure_warning = access.exposure_warning(&host);
     let config = ProxyConfig {
         host: host.clone(),
+        allow_public,
         port,
         default_context,
         cache_enabled,
@@ -321,6 +332,9 @@ pub async fn start_proxy_standalone(
         // Passed as its own top-priority sampling layer rather than folded into
         // the persisted global defaults, which sit below the per-model layer.
         inference_override: inference_override.clone(),
+        // The standalone proxy registers no middleware of its own.
+        middleware: gglib_proxy::MiddlewareChain::default(),
+        access,
     };
 
     // Initialize MCP service (validates servers and auto-starts enabled ones)
@@ -387,6 +401,10 @@ pub async fn start_proxy_standalone(
     );
     println!("  MCP tools:       {} (eager-started)", tool_count);
     println!();
+    if let Some(warning) = exposure_warning {
+        println!("  ⚠ {warning}");
+        println!();
+    }
 
     let addr = supervisor
         .start(
@@ -396,6 +414,7 @@ pub async fn start_proxy_standalone(
             mcp,
             orchestrator_deps,
             settings_repo,
+            preset_repo,
         )
         .await
         .map_err(|e| anyhow!("{e}"))?;
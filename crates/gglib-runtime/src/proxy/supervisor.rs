@@ -23,13 +23,18 @@ use tokio::task::JoinHandle;
 use tokio_util::sync::CancellationToken;
 use tracing::{debug, error, info, warn};
 
+use gglib_core::Settings;
 use gglib_core::cache_metrics::CacheMetricsStore;
 use gglib_core::domain::InferenceConfig;
-use gglib_core::ports::{ModelCatalogPort, ModelRuntimePort, SettingsRepository};
-use gglib_core::settings::{DEFAULT_CONTEXT_SIZE, DEFAULT_PROXY_PORT};
+use gglib_core::ports::{
+    ModelCatalogPort, ModelRuntimePort, PresetRepository, ServerStatsSink, SettingsRepository,
+};
+use gglib_core::settings::{
+    DEFAULT_CONTEXT_SIZE, DEFAULT_PROXY_HOST, DEFAULT_PROXY_PORT, is_loopback_host,
+};
 use gglib_mcp::McpService;
-use gglib_proxy::CouncilDeps;
 use gglib_proxy::slot_eviction::DiskBudget;
+use gglib_proxy::{AccessPolicy, AppliedConfig, ConfigWatch, CouncilDeps, MiddlewareChain};
 
 /// Handle to a running proxy server.
 struct ProxyHandle {
@@ -76,6 +81,13 @@ pub enum SupervisorError {
     #[error("Failed to bind to {address}: {reason}")]
     BindFailed { address: String, reason: String },
 
+    /// Host is reachable from other machines and `allow_public` is off.
+    #[error(
+        "Refusing to bind the proxy to {0}, which other machines can reach; \
+         allow public binding to use it"
+    )]
+    PublicBindNotAllowed(String),
+
     /// Proxy is not running.
     #[error("Proxy is not running")]
     NotRunning,
@@ -90,6 +102,9 @@ pub enum SupervisorError {
 pub struct ProxyConfig {
     /// Host to bind to (e.g., "127.0.0.1" or "0.0.0.0").
     pub host: String,
+    /// Explicit opt-in for a `host` other machines can reach (`0.0.0.0`, a
+    /// LAN address). Without it such hosts are refused.
+    pub allow_public: bool,
     /// Port to bind to (0 for auto-assign).
     pub port: u16,
     /// Default context size for models.
@@ -108,18 +123,27 @@ pub struct ProxyConfig {
     /// (`gglib proxy --temperature …`), applied above the client's own request
     /// parameters. `None` means the client and the stored layers decide.
     pub inference_override: Option<InferenceConfig>,
+    /// Request/response hooks run on every chat completion, in order. Empty
+    /// by default.
+    pub middleware: MiddlewareChain,
+    /// Allowed browser origins and the API key clients must present. Open
+    /// by default.
+    pub access: AccessPolicy,
 }
 
 impl Default for ProxyConfig {
     fn default() -> Self {
         Self {
-            host: "127.0.0.1".to_string(),
+            host: DEFAULT_PROXY_HOST.to_string(),
+            allow_public: false,
             port: DEFAULT_PROXY_PORT,
             default_context: DEFAULT_CONTEXT_SIZE,
             cache_enabled: false,
             slot_dir: None,
             disk_budget: DiskBudget::Auto,
             inference_override: None,
+            middleware: MiddlewareChain::default(),
+            access: AccessPolicy::default(),
         }
     }
 }
@@ -148,6 +172,10 @@ pub struct ProxySupervisor {
     /// the embedded axum server (GUI chat, via [`Self::agent_metrics`]) reach —
     /// so a single population survives proxy restarts within one process.
     agent_metrics: Arc<CacheMetricsStore>,
+    /// Settings published for live reload into the running proxy.
+    settings_tx: watch::Sender<Option<Arc<Settings>>>,
+    /// What the running proxy applied from the latest published settings.
+    reload_tx: watch::Sender<Option<AppliedConfig>>,
 }
 
 impl Default for ProxySupervisor {
@@ -161,10 +189,14 @@ impl ProxySupervisor {
     #[must_use]
     pub fn new() -> Self {
         let (exit_tx, _) = watch::channel(ProxyStatus::Stopped);
+        let (settings_tx, _) = watch::channel(None);
+        let (reload_tx, _) = watch::channel(None);
         Self {
             handle: Mutex::new(None),
             exit_tx,
             agent_metrics: Arc::new(CacheMetricsStore::new()),
+            settings_tx,
+            reload_tx,
         }
     }
 
@@ -187,6 +219,24 @@ impl ProxySupervisor {
         self.exit_tx.subscribe()
     }
 
+    /// Publish saved settings to the running proxy, which applies them
+    /// without restarting (see [`gglib_proxy::live_config`]).
+    ///
+    /// A no-op while the proxy is stopped: a later start loads the settings
+    /// from the repository anyway.
+    pub fn publish_settings(&self, settings: Settings) {
+        self.settings_tx.send_replace(Some(Arc::new(settings)));
+    }
+
+    /// Get a watch receiver for live config reloads.
+    ///
+    /// Yields what the running proxy applied each time it picks up settings
+    /// from [`publish_settings`](Self::publish_settings). Callers should skip
+    /// the initial value and only react to `changed()` notifications.
+    pub fn reload_receiver(&self) -> watch::Receiver<Option<AppliedConfig>> {
+        self.reload_tx.subscribe()
+    }
+
     /// Start the proxy server.
     ///
     /// Binds to the specified address FIRST (bind-then-report pattern),
@@ -200,10 +250,14 @@ impl ProxySupervisor {
     /// * `mcp` - MCP service for tool gateway
     /// * `orchestrator` - Orchestrator services for virtual model routing
     /// * `settings_repo` - Settings repository for global inference defaults
+    /// * `preset_repo` - Preset repository for requests that select a preset
     ///
     /// # Errors
     ///
-    /// Returns error if already running or if bind fails.
+    /// Returns error if already running, if `config.host` is public without
+    /// `config.allow_public`, or if bind fails. A public bind without an API
+    /// key is allowed but logged as a warning.
+    #[allow(clippy::too_many_arguments)]
     pub async fn start(
         &self,
         config: ProxyConfig,
@@ -212,6 +266,7 @@ impl ProxySupervisor {
         mcp: Arc<McpService>,
         orchestrator: CouncilDeps,
         settings_repo: Arc<dyn SettingsRepository>,
+        preset_repo: Arc<dyn PresetRepository>,
     ) -> Result<SocketAddr, SupervisorError> {
         let mut guard = self.handle.lock().await;
 
@@ -231,6 +286,13 @@ impl ProxySupervisor {
             }
         }
 
+        if !is_loopback_host(&config.host) && !config.allow_public {
+            return Err(SupervisorError::PublicBindNotAllowed(config.host));
+        }
+        if let Some(warning) = config.access.exposure_warning(&config.host) {
+            warn!("{warning}");
+        }
+
         // Bind FIRST - get real address before spawning
         let bind_addr = format!("{}:{}", config.host, config.port);
         let listener =
@@ -256,6 +318,12 @@ impl ProxySupervisor {
         let slot_dir = config.slot_dir;
         let disk_budget = config.disk_budget;
         let inference_override = config.inference_override;
+        let middleware = config.middleware;
+        let access = config.access;
+        let config_watch = ConfigWatch {
+            updates: self.settings_tx.subscribe(),
+            applied: self.reload_tx.clone(),
+        };
         let agent_metrics = Arc::clone(&self.agent_metrics);
         let exit_tx = self.exit_tx.clone();
 
@@ -277,11 +345,16 @@ impl ProxySupervisor {
                 orchestrator,
                 cancel_clone,
                 settings_repo,
+                preset_repo,
                 inference_override,
                 cache_enabled,
                 slot_dir,
                 disk_budget,
                 agent_metrics,
+                Some(crate::process::get_stats_registry() as Arc<dyn ServerStatsSink>),
+                middleware,
+                access,
+                Some(config_watch),
             )
             .await;
 
@@ -419,9 +492,10 @@ mod tests {
         CouncilEvent, CouncilRun, CouncilRunEvent, CouncilRunStatus,
     };
     use gglib_core::domain::mcp::{McpServer, NewMcpServer};
+    use gglib_core::domain::preset::{NewPreset, Preset, PresetUpdate};
     use gglib_core::ports::{
-        ApprovalDecision, CouncilApprovalRegistryPort, CouncilRepositoryPort, RepositoryError,
-        SettingsRepository,
+        ApprovalDecision, CouncilApprovalRegistryPort, CouncilRepositoryPort, PresetError,
+        RepositoryError, SettingsRepository,
     };
     use gglib_core::ports::{
         CatalogError, ModelLaunchSpec, ModelRuntimeError, ModelSummary, RunningTarget,
@@ -614,6 +688,30 @@ mod tests {
         Arc::new(MockSettingsRepo)
     }
 
+    struct NoPresets;
+
+    #[async_trait]
+    impl PresetRepository for NoPresets {
+        async fn create(&self, _preset: NewPreset) -> Result<i64, PresetError> {
+            Err(PresetError::Database("not implemented".into()))
+        }
+        async fn list(&self) -> Result<Vec<Preset>, PresetError> {
+            Ok(vec![])
+        }
+        async fn get(&self, _id: i64) -> Result<Option<Preset>, PresetError> {
+            Ok(None)
+        }
+        async fn get_by_name(&self, _name: &str) -> Result<Option<Preset>, PresetError> {
+            Ok(None)
+        }
+        async fn update(&self, id: i64, _update: PresetUpdate) -> Result<(), PresetError> {
+            Err(PresetError::NotFound(id))
+        }
+        async fn delete(&self, id: i64) -> Result<(), PresetError> {
+            Err(PresetError::NotFound(id))
+        }
+    }
+
     #[tokio::test]
     async fn test_supervisor_lifecycle() {
         let supervisor = ProxySupervisor::new();
@@ -640,6 +738,7 @@ mod tests {
                 mcp,
                 make_orchestrator(),
                 make_settings_repo(),
+                Arc::new(NoPresets),
             )
             .await
             .unwrap();
@@ -661,7 +760,8 @@ mod tests {
                     catalog2,
                     make_mcp(),
                     make_orchestrator(),
-                    make_settings_repo()
+                    make_settings_repo(),
+                    Arc::new(NoPresets),
                 )
                 .await,
             Err(SupervisorError::AlreadyRunning(_))
@@ -680,6 +780,43 @@ mod tests {
         ));
     }
 
+    #[tokio::test]
+    async fn test_public_bind_requires_opt_in() {
+        let supervisor = ProxySupervisor::new();
+        let start = |config: ProxyConfig| {
+            let (runtime, catalog) = make_ports();
+            supervisor.start(
+                config,
+                runtime,
+                catalog,
+                make_mcp(),
+                make_orchestrator(),
+                make_settings_repo(),
+                Arc::new(NoPresets),
+            )
+        };
+
+        let public = ProxyConfig {
+            host: "0.0.0.0".to_string(),
+            port: 0,
+            ..ProxyConfig::default()
+        };
+        assert!(matches!(
+            start(public.clone()).await,
+            Err(SupervisorError::PublicBindNotAllowed(host)) if host == "0.0.0.0"
+        ));
+        assert_eq!(supervisor.status().await, ProxyStatus::Stopped);
+
+        let addr = start(ProxyConfig {
+            allow_public: true,
+            ..public
+        })
+        .await
+        .unwrap();
+        assert!(addr.ip().is_unspecified());
+        supervisor.stop().await.unwrap();
+    }
+
     #[tokio::test]
     async fn test_restart_after_stop() {
         let supervisor = ProxySupervisor::new();
@@ -703,6 +840,7 @@ mod tests {
                 make_mcp(),
                 make_orchestrator(),
                 make_settings_repo(),
+                Arc::new(NoPresets),
             )
             .await
             .unwrap();
@@ -720,6 +858,7 @@ mod tests {
                 make_mcp(),
                 make_orchestrator(),
                 make_settings_repo(),
+                Arc::new(NoPresets),
             )
             .await
             .unwrap();
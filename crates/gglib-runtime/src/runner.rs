@@ -69,6 +69,11 @@ impl LlamaServerRunner {
 
 #[async_trait]
 impl ProcessRunner for LlamaServerRunner {
+    #[tracing::instrument(
+        name = "server.launch",
+        skip_all,
+        fields(model_id = config.model_id, model = %config.model_name, ctx = ?config.context_size)
+    )]
     async fn start(&self, config: ServerConfig) -> Result<ProcessHandle, ProcessError> {
         debug!(
             model_id = %config.model_id,
@@ -182,4 +187,14 @@ impl ProcessRunner for LlamaServerRunner {
 
         Ok(core.list_all())
     }
+
+    async fn adopt(
+        &self,
+        handle: ProcessHandle,
+        context_size: Option<u64>,
+    ) -> Result<(), ProcessError> {
+        let mut core = self.core.write().await;
+        core.adopt(handle, context_size)
+            .map_err(|e| ProcessError::Configuration(e.to_string()))
+    }
 }
@@ -26,6 +26,12 @@
 //! | Jinja templates | `opts.jinja = Some(…)` | `"agent"` tag → enabled |
 //! | Reasoning format | `opts.reasoning_format = Some(…)` | model tags |
 //! | MTP speculative decoding | `opts.mtp_draft_n_max = Some(0)` (off) or `Some(n)` (on) | `"mtp"` tag → enabled |
+//!
+//! Thread count and CPU pinning are not tag-based: when `opts.threads` /
+//! `opts.cpu_affinity` are `None` they are derived from the host CPU
+//! topology (performance cores only on a hybrid CPU).
+//! Likewise `opts.tensor_split` falls back to the free VRAM of each GPU
+//! when the machine has more than one.
 
 use std::path::PathBuf;
 
@@ -34,8 +40,10 @@ pub use gglib_core::server_config::{ServerConfigOptions, resolve_context_size};
 use tracing::debug;
 
 use crate::llama::args::{
-    resolve_jinja_flag, resolve_kv_cache_types, resolve_mtp_args, resolve_reasoning_format,
+    resolve_cpu_args, resolve_gpu_args, resolve_jinja_flag, resolve_kv_cache_types,
+    resolve_mtp_args, resolve_reasoning_format,
 };
+use crate::system::{cpu_topology, gpu_devices};
 
 // =============================================================================
 // Builder
@@ -116,6 +124,13 @@ pub fn build_server_config(
         config = config.with_inference_config(params);
     }
 
+    // --- RoPE scaling overrides -------------------------------------------------
+    // Direct pass-through: an empty config is dropped so launches without
+    // overrides stay byte-for-byte unchanged.
+    if let Some(rope) = opts.rope.filter(|r| !r.is_empty()) {
+        config = config.with_rope(rope);
+    }
+
     // --- KV cache slot persistence ----------------------------------------------
     // Direct pass-through, no tag-based auto-detection: `None` here means the
     // feature is disabled and `build_and_spawn` emits zero cache-related flags,
@@ -144,6 +159,60 @@ pub fn build_server_config(
         .with_cache_type_k(kv_types.k)
         .with_cache_type_v(kv_types.v);
 
+    // --- Threads, CPU pinning and NUMA -----------------------------------------
+    // Resolved against the host CPU topology so a hybrid CPU runs on its
+    // performance cores on every launch surface — see `resolve_cpu_args`.
+    let cpu = resolve_cpu_args(
+        opts.threads,
+        opts.threads_batch,
+        opts.cpu_affinity,
+        opts.numa,
+        cpu_topology(),
+    );
+    debug!("{}", cpu.explain());
+    config = config.with_threads(cpu.threads);
+    if let Some(n) = cpu.threads_batch {
+        config = config.with_threads_batch(n);
+    }
+    if let Some(cpus) = cpu.cpu_affinity {
+        config = config.with_cpu_affinity(cpus);
+    }
+    if let Some(numa) = cpu.numa {
+        config = config.with_numa(numa);
+    }
+
+    // --- Multi-GPU placement ----------------------------------------------------
+    // Resolved against the GPUs present at launch so a multi-GPU machine
+    // splits by free VRAM on every launch surface — see `resolve_gpu_args`.
+    let gpu = resolve_gpu_args(
+        opts.split_mode,
+        opts.tensor_split,
+        opts.main_gpu,
+        &gpu_devices(),
+    );
+    if let Some(explanation) = gpu.explain() {
+        debug!("{explanation}");
+    }
+    if let Some(mode) = gpu.split_mode {
+        config = config.with_split_mode(mode);
+    }
+    if let Some(split) = gpu.tensor_split {
+        config = config.with_tensor_split(split);
+    }
+    if let Some(index) = gpu.main_gpu {
+        config = config.with_main_gpu(index);
+    }
+
+    // --- Parallel slots and continuous batching ---------------------------------
+    // Direct pass-through: the slot count default depends on the memory budget,
+    // which callers resolve alongside `--cache-ram` (see `resolve_parallel`).
+    if let Some(slots) = opts.parallel {
+        config = config.with_parallel(slots);
+    }
+    if let Some(enabled) = opts.cont_batching {
+        config = config.with_cont_batching(enabled);
+    }
+
     // --- MTP speculative decoding ----------------------------------------------
     let mtp = resolve_mtp_args(opts.mtp_draft_n_max, opts.mtp_draft_p_min, tags);
     if mtp.enabled {
@@ -0,0 +1,361 @@
+//! Register the gglib daemon (`gglib web`) to start with the user session.
+//!
+//! Each platform uses its per-user service manager, so the daemon runs as
+//! the logged-in user with their `~/.gglib` data, models and GPU access:
+//!
+//! - **Linux**: a systemd user unit, `~/.config/systemd/user/gglib.service`
+//! - **macOS**: a launchd agent, `~/Library/LaunchAgents/io.gglib.daemon.plist`
+//! - **Windows**: a Task Scheduler task named `gglib` that runs at logon
+//!
+//! On Windows a scheduled task is used rather than an SCM service: services
+//! run in session 0 under a system account, which has neither the user's
+//! data directory nor reliable GPU access, and would need the binary to
+//! implement the service control protocol.
+
+use std::io;
+use std::path::{Path, PathBuf};
+
+use gglib_core::utils::process::cmd;
+
+/// Name of the systemd unit and the Windows scheduled task.
+pub const SERVICE_NAME: &str = "gglib";
+
+/// launchd label of the macOS agent.
+pub const LAUNCHD_LABEL: &str = "io.gglib.daemon";
+
+/// The command a registered service runs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ServiceSpec {
+    /// Absolute path of the `gglib` executable.
+    pub program: PathBuf,
+    /// Arguments, e.g. `["web", "--port", "9887"]`.
+    pub args: Vec<String>,
+}
+
+/// The per-user service manager a service is registered with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ServiceManager {
+    SystemdUser,
+    Launchd,
+    TaskScheduler,
+}
+
+impl ServiceManager {
+    /// The service manager for the current platform, if supported.
+    #[must_use]
+    pub const fn current() -> Option<Self> {
+        if cfg!(target_os = "linux") {
+            Some(Self::SystemdUser)
+        } else if cfg!(target_os = "macos") {
+            Some(Self::Launchd)
+        } else if cfg!(windows) {
+            Some(Self::TaskScheduler)
+        } else {
+            None
+        }
+    }
+
+    /// Human-readable name for CLI output.
+    #[must_use]
+    pub const fn display_name(self) -> &'static str {
+        match self {
+            Self::SystemdUser => "systemd (user)",
+            Self::Launchd => "launchd",
+            Self::TaskScheduler => "Task Scheduler",
+        }
+    }
+}
+
+/// Where a service was registered (or removed from).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ServiceLocation {
+    pub manager: ServiceManager,
+    /// Unit or plist path, or the scheduled task name.
+    pub location: String,
+}
+
+/// Register `spec` to start at login and start it now.
+///
+/// Re-installing replaces an existing registration.
+///
+/// # Errors
+///
+/// Fails on unsupported platforms, when the service definition cannot be
+/// written, or when the service manager rejects it.
+pub fn install_service(spec: &ServiceSpec) -> io::Result<ServiceLocation> {
+    let manager = ServiceManager::current().ok_or_else(unsupported)?;
+    let location = match manager {
+        ServiceManager::SystemdUser => {
+            let path = systemd_unit_path()?;
+            write_file(&path, &systemd_unit(spec))?;
+            run("systemctl", &["--user", "daemon-reload"])?;
+            run(
+                "systemctl",
+                &[
+                    "--user",
+                    "enable",
+                    "--now",
+                    &format!("{SERVICE_NAME}.service"),
+                ],
+            )?;
+            path.display().to_string()
+        }
+        ServiceManager::Launchd => {
+            let path = launchd_plist_path()?;
+            let path_str = path.display().to_string();
+            // Unload first so a re-install picks up the new definition.
+            let _ = run("launchctl", &["unload", &path_str]);
+            write_file(&path, &launchd_plist(spec))?;
+            run("launchctl", &["load", "-w", &path_str])?;
+            path_str
+        }
+        ServiceManager::TaskScheduler => {
+            let create = schtasks_create_args(spec);
+            run(
+                "schtasks",
+                &create.iter().map(String::as_str).collect::<Vec<_>>(),
+            )?;
+            run("schtasks", &["/Run", "/TN", SERVICE_NAME])?;
+            SERVICE_NAME.to_string()
+        }
+    };
+    Ok(ServiceLocation { manager, location })
+}
+
+/// Stop the service and remove its registration.
+///
+/// # Errors
+///
+/// Fails on unsupported platforms or when the registration exists but
+/// cannot be removed.
+pub fn uninstall_service() -> io::Result<ServiceLocation> {
+    let manager = ServiceManager::current().ok_or_else(unsupported)?;
+    let location = match manager {
+        ServiceManager::SystemdUser => {
+            let path = systemd_unit_path()?;
+            let _ = run(
+                "systemctl",
+                &[
+                    "--user",
+                    "disable",
+                    "--now",
+                    &format!("{SERVICE_NAME}.service"),
+                ],
+            );
+            remove_file(&path)?;
+            let _ = run("systemctl", &["--user", "daemon-reload"]);
+            path.display().to_string()
+        }
+        ServiceManager::Launchd => {
+            let path = launchd_plist_path()?;
+            let path_str = path.display().to_string();
+            let _ = run("launchctl", &["unload", "-w", &path_str]);
+            remove_file(&path)?;
+            path_str
+        }
+        ServiceManager::TaskScheduler => {
+            let _ = run("schtasks", &["/End", "/TN", SERVICE_NAME]);
+            run("schtasks", &["/Delete", "/TN", SERVICE_NAME, "/F"])?;
+            SERVICE_NAME.to_string()
+        }
+    };
+    Ok(ServiceLocation { manager, location })
+}
+
+fn unsupported() -> io::Error {
+    io::Error::new(
+        io::ErrorKind::Unsupported,
+        "service installation is not supported on this platform",
+    )
+}
+
+fn home_dir() -> io::Result<PathBuf> {
+    dirs::home_dir().ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "no home directory"))
+}
+
+fn systemd_unit_path() -> io::Result<PathBuf> {
+    Ok(home_dir()?
+        .join(".config/systemd/user")
+        .join(format!("{SERVICE_NAME}.service")))
+}
+
+fn launchd_plist_path() -> io::Result<PathBuf> {
+    Ok(home_dir()?
+        .join("Library/LaunchAgents")
+        .join(format!("{LAUNCHD_LABEL}.plist")))
+}
+
+fn write_file(path: &Path, contents: &str) -> io::Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(path, contents)
+}
+
+fn remove_file(path: &Path) -> io::Result<()> {
+    match std::fs::remove_file(path) {
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(()),
+        other => other,
+    }
+}
+
+fn run(program: &str, args: &[&str]) -> io::Result<()> {
+    let output = cmd(program).args(args).output()?;
+    if output.status.success() {
+        return Ok(());
+    }
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let detail = if stderr.trim().is_empty() {
+        stdout
+    } else {
+        stderr
+    };
+    Err(io::Error::other(format!(
+        "`{program} {}` failed: {}",
+        args.join(" "),
+        detail.trim()
+    )))
+}
+
+/// Quote one word for a systemd `ExecStart=` line.
+fn systemd_quote(word: &str) -> String {
+    if !word.is_empty() && !word.contains(|c: char| c.is_whitespace() || c == '"' || c == '\\') {
+        return word.to_string();
+    }
+    format!("\"{}\"", word.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+fn systemd_unit(spec: &ServiceSpec) -> String {
+    let exec = std::iter::once(spec.program.display().to_string())
+        .chain(spec.args.iter().cloned())
+        .map(|w| systemd_quote(&w))
+        .collect::<Vec<_>>()
+        .join(" ");
+    format!(
+        "[Unit]\n\
+         Description=gglib daemon\n\
+         After=network-online.target\n\
+         \n\
+         [Service]\n\
+         ExecStart={exec}\n\
+         Restart=on-failure\n\
+         RestartSec=5\n\
+         \n\
+         [Install]\n\
+         WantedBy=default.target\n"
+    )
+}
+
+fn xml_escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+fn launchd_plist(spec: &ServiceSpec) -> String {
+    let args: String = std::iter::once(spec.program.display().to_string())
+        .chain(spec.args.iter().cloned())
+        .map(|a| format!("        <string>{}</string>\n", xml_escape(&a)))
+        .collect();
+    format!(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+         <!DOCTYPE plist PUBLIC \"-//Apple//DTD PLIST 1.0//EN\" \"http://www.apple.com/DTDs/PropertyList-1.0.dtd\">\n\
+         <plist version=\"1.0\">\n\
+         <dict>\n\
+         \x20   <key>Label</key>\n\
+         \x20   <string>{LAUNCHD_LABEL}</string>\n\
+         \x20   <key>ProgramArguments</key>\n\
+         \x20   <array>\n\
+         {args}\
+         \x20   </array>\n\
+         \x20   <key>RunAtLoad</key>\n\
+         \x20   <true/>\n\
+         \x20   <key>KeepAlive</key>\n\
+         \x20   <dict>\n\
+         \x20       <key>SuccessfulExit</key>\n\
+         \x20       <false/>\n\
+         \x20   </dict>\n\
+         </dict>\n\
+         </plist>\n"
+    )
+}
+
+/// Quote one word for a Windows command line (`CommandLineToArgvW` rules,
+/// simplified: gglib arguments never end in a backslash before a quote).
+fn windows_quote(word: &str) -> String {
+    if !word.is_empty() && !word.contains(|c: char| c.is_whitespace() || c == '"') {
+        return word.to_string();
+    }
+    format!("\"{}\"", word.replace('"', "\\\""))
+}
+
+fn schtasks_create_args(spec: &ServiceSpec) -> Vec<String> {
+    let command_line = std::iter::once(spec.program.display().to_string())
+        .chain(spec.args.iter().cloned())
+        .map(|w| windows_quote(&w))
+        .collect::<Vec<_>>()
+        .join(" ");
+    [
+        "/Create",
+        "/TN",
+        SERVICE_NAME,
+        "/SC",
+        "ONLOGON",
+        "/RL",
+        "LIMITED",
+        "/F",
+        "/TR",
+    ]
+    .into_iter()
+    .map(str::to_string)
+    .chain(std::iter::once(command_line))
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn spec() -> ServiceSpec {
+        ServiceSpec {
+            program: PathBuf::from("/opt/my tools/gglib"),
+            args: vec!["web".into(), "--port".into(), "9887".into()],
+        }
+    }
+
+    #[test]
+    fn systemd_unit_quotes_paths_with_spaces() {
+        let unit = systemd_unit(&spec());
+        assert!(unit.contains("ExecStart=\"/opt/my tools/gglib\" web --port 9887\n"));
+        assert!(unit.contains("WantedBy=default.target"));
+    }
+
+    #[test]
+    fn launchd_plist_lists_each_argument() {
+        let plist = launchd_plist(&spec());
+        assert!(plist.contains("<string>io.gglib.daemon</string>"));
+        assert!(plist.contains("        <string>/opt/my tools/gglib</string>\n"));
+        assert!(plist.contains("        <string>9887</string>\n"));
+        assert!(plist.contains("<key>RunAtLoad</key>"));
+    }
+
+    #[test]
+    fn schtasks_runs_quoted_command_at_logon() {
+        let args = schtasks_create_args(&ServiceSpec {
+            program: PathBuf::from(r"C:\Program Files\gglib\gglib.exe"),
+            args: vec!["web".into()],
+        });
+        assert_eq!(args[..5], ["/Create", "/TN", "gglib", "/SC", "ONLOGON"]);
+        assert_eq!(
+            args.last().unwrap(),
+            r#""C:\Program Files\gglib\gglib.exe" web"#
+        );
+    }
+
+    #[test]
+    fn xml_escape_handles_markup() {
+        assert_eq!(xml_escape("a<b>&\"c\""), "a&lt;b&gt;&amp;&quot;c&quot;");
+    }
+}
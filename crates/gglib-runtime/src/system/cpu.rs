@@ -0,0 +1,301 @@
+//! CPU topology detection for thread-count and affinity defaults.
+//!
+//! llama.cpp generation is memory-bandwidth bound and runs at the pace of
+//! its slowest thread, so on a hybrid CPU (performance + efficiency cores)
+//! threads scheduled onto E-cores drag the whole pool down. The probe here
+//! reports how many physical cores there are, which logical CPUs sit on
+//! performance cores, and how many NUMA nodes the machine has; the launch
+//! path turns that into `--threads` and `--cpu-mask` defaults.
+//!
+//! On Linux everything comes from sysfs: Intel hybrid parts list their
+//! P-core CPUs under `/sys/devices/cpu_core/cpus`, and ARM big.LITTLE parts
+//! report a per-CPU `cpu_capacity`. macOS reports the P-core count through
+//! `sysctl` but offers no thread pinning, so no CPU list is returned there.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::Path;
+use std::sync::OnceLock;
+
+use gglib_core::cpu_config::CpuSet;
+#[cfg(target_os = "macos")]
+use gglib_core::utils::process::cmd;
+use sysinfo::System;
+
+/// What the launch path needs to know about the host CPU.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CpuTopology {
+    /// Online logical CPUs (hardware threads).
+    pub logical_cpus: u32,
+    /// Physical cores across all packages.
+    pub physical_cores: u32,
+    /// Physical performance cores. Equal to `physical_cores` on a CPU that
+    /// is not hybrid.
+    pub performance_cores: u32,
+    /// Logical CPUs on performance cores, when the CPU is hybrid and the
+    /// platform reports which CPUs those are.
+    pub performance_cpus: Option<CpuSet>,
+    /// NUMA nodes (`1` on a single-node machine or when unknown).
+    pub numa_nodes: u32,
+}
+
+impl CpuTopology {
+    /// Whether the CPU mixes performance and efficiency cores.
+    #[must_use]
+    pub const fn is_hybrid(&self) -> bool {
+        self.performance_cores < self.physical_cores
+    }
+
+    /// Topology used when nothing can be probed: every CPU a performance
+    /// core, one NUMA node.
+    fn uniform(logical_cpus: u32, physical_cores: u32) -> Self {
+        let logical_cpus = logical_cpus.max(1);
+        let physical_cores = physical_cores.clamp(1, logical_cpus);
+        Self {
+            logical_cpus,
+            physical_cores,
+            performance_cores: physical_cores,
+            performance_cpus: None,
+            numa_nodes: 1,
+        }
+    }
+}
+
+/// The host CPU topology, probed once per process.
+pub fn cpu_topology() -> &'static CpuTopology {
+    static TOPOLOGY: OnceLock<CpuTopology> = OnceLock::new();
+    TOPOLOGY.get_or_init(detect_cpu_topology)
+}
+
+/// Probe the host CPU topology.
+fn detect_cpu_topology() -> CpuTopology {
+    let logical = std::thread::available_parallelism().map_or(1, |n| n.get() as u32);
+    let physical = System::physical_core_count().map_or(logical, |n| n as u32);
+
+    #[cfg(target_os = "linux")]
+    if let Some(topology) = read_sysfs_topology(Path::new("/sys")) {
+        return topology;
+    }
+
+    #[cfg(target_os = "macos")]
+    if let Some(performance_cores) = sysctl_u32("hw.perflevel0.physicalcpu")
+        && sysctl_u32("hw.nperflevels").unwrap_or(1) > 1
+    {
+        return CpuTopology {
+            performance_cores: performance_cores.clamp(1, physical),
+            ..CpuTopology::uniform(logical, physical)
+        };
+    }
+
+    CpuTopology::uniform(logical, physical)
+}
+
+#[cfg(target_os = "macos")]
+fn sysctl_u32(name: &str) -> Option<u32> {
+    let output = cmd("sysctl").arg("-n").arg(name).output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    String::from_utf8_lossy(&output.stdout).trim().parse().ok()
+}
+
+/// Read the topology from a sysfs tree rooted at `sys` (normally `/sys`).
+///
+/// Returns `None` when the CPU list itself is unreadable.
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+fn read_sysfs_topology(sys: &Path) -> Option<CpuTopology> {
+    let cpu_dir = sys.join("devices/system/cpu");
+    let online: CpuSet = read_trimmed(&cpu_dir.join("online"))?.parse().ok()?;
+
+    // Physical core of each CPU, keyed by (package, core id).
+    let core_of = |cpu: u32| {
+        let topo = cpu_dir.join(format!("cpu{cpu}/topology"));
+        let package = read_trimmed(&topo.join("physical_package_id"))?;
+        let core = read_trimmed(&topo.join("core_id"))?;
+        Some((package, core))
+    };
+    let cores: BTreeMap<u32, (String, String)> = online
+        .cpus()
+        .iter()
+        .filter_map(|&cpu| core_of(cpu).map(|core| (cpu, core)))
+        .collect();
+    let count_cores = |cpus: &[u32]| -> Option<u32> {
+        let unique: BTreeSet<_> = cpus.iter().filter_map(|cpu| cores.get(cpu)).collect();
+        (!unique.is_empty()).then_some(unique.len() as u32)
+    };
+
+    let logical_cpus = online.len() as u32;
+    let physical_cores = count_cores(online.cpus()).unwrap_or(logical_cpus);
+    let mut topology = CpuTopology::uniform(logical_cpus, physical_cores);
+    topology.numa_nodes = count_numa_nodes(&sys.join("devices/system/node"));
+
+    if let Some(p_cpus) = performance_cpus(sys, &online)
+        && p_cpus.len() < online.len()
+    {
+        topology.performance_cores = count_cores(p_cpus.cpus())
+            .unwrap_or(p_cpus.len() as u32)
+            .min(physical_cores);
+        topology.performance_cpus = Some(p_cpus);
+    }
+    Some(topology)
+}
+
+/// The logical CPUs on performance cores, if the platform singles them out.
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+fn performance_cpus(sys: &Path, online: &CpuSet) -> Option<CpuSet> {
+    // Intel hybrid: the core PMU lists exactly the P-core CPUs.
+    if let Some(list) = read_trimmed(&sys.join("devices/cpu_core/cpus")) {
+        return list.parse().ok();
+    }
+
+    // ARM big.LITTLE: the highest-capacity CPUs are the big cores.
+    let cpu_dir = sys.join("devices/system/cpu");
+    let capacities: Vec<(u32, u32)> = online
+        .cpus()
+        .iter()
+        .filter_map(|&cpu| {
+            read_trimmed(&cpu_dir.join(format!("cpu{cpu}/cpu_capacity")))?
+                .parse()
+                .ok()
+                .map(|capacity| (cpu, capacity))
+        })
+        .collect();
+    let max = capacities.iter().map(|&(_, c)| c).max()?;
+    if capacities.iter().all(|&(_, c)| c == max) {
+        return None;
+    }
+    CpuSet::new(
+        capacities
+            .into_iter()
+            .filter(|&(_, c)| c == max)
+            .map(|(cpu, _)| cpu),
+    )
+    .ok()
+}
+
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+fn count_numa_nodes(node_dir: &Path) -> u32 {
+    let nodes = std::fs::read_dir(node_dir)
+        .into_iter()
+        .flatten()
+        .flatten()
+        .filter(|entry| {
+            entry
+                .file_name()
+                .to_str()
+                .and_then(|name| name.strip_prefix("node"))
+                .is_some_and(|id| !id.is_empty() && id.bytes().all(|b| b.is_ascii_digit()))
+        })
+        .count() as u32;
+    nodes.max(1)
+}
+
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+fn read_trimmed(path: &Path) -> Option<String> {
+    std::fs::read_to_string(path)
+        .ok()
+        .map(|s| s.trim().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+    use tempfile::TempDir;
+
+    fn write(root: &Path, rel: &str, contents: &str) {
+        let path = root.join(rel);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, contents).unwrap();
+    }
+
+    /// A sysfs tree with `cores` physical cores of `threads` each; CPU `n`
+    /// sits on core `n / threads`.
+    fn sysfs(cores: u32, threads: u32) -> (TempDir, PathBuf) {
+        let dir = TempDir::new().unwrap();
+        let root = dir.path().to_path_buf();
+        let cpus = cores * threads;
+        write(
+            &root,
+            "devices/system/cpu/online",
+            &format!("0-{}\n", cpus - 1),
+        );
+        for cpu in 0..cpus {
+            let topo = format!("devices/system/cpu/cpu{cpu}/topology");
+            write(&root, &format!("{topo}/physical_package_id"), "0\n");
+            write(
+                &root,
+                &format!("{topo}/core_id"),
+                &format!("{}\n", cpu / threads),
+            );
+        }
+        write(&root, "devices/system/node/node0/cpulist", "0\n");
+        (dir, root)
+    }
+
+    #[test]
+    fn uniform_cpu_has_no_performance_list() {
+        let (_dir, root) = sysfs(8, 2);
+        let topo = read_sysfs_topology(&root).unwrap();
+        assert_eq!(topo.logical_cpus, 16);
+        assert_eq!(topo.physical_cores, 8);
+        assert_eq!(topo.performance_cores, 8);
+        assert_eq!(topo.performance_cpus, None);
+        assert_eq!(topo.numa_nodes, 1);
+        assert!(!topo.is_hybrid());
+    }
+
+    #[test]
+    fn intel_hybrid_reads_core_pmu_cpu_list() {
+        // 6 hyperthreaded P-cores (CPUs 0-11) and 8 E-cores (CPUs 12-19).
+        let (_dir, root) = sysfs(6, 2);
+        write(&root, "devices/system/cpu/online", "0-19\n");
+        for cpu in 12..20 {
+            let topo = format!("devices/system/cpu/cpu{cpu}/topology");
+            write(&root, &format!("{topo}/physical_package_id"), "0\n");
+            write(
+                &root,
+                &format!("{topo}/core_id"),
+                &format!("{}\n", cpu + 100),
+            );
+        }
+        write(&root, "devices/cpu_core/cpus", "0-11\n");
+        write(&root, "devices/system/node/node1/cpulist", "0\n");
+
+        let topo = read_sysfs_topology(&root).unwrap();
+        assert_eq!(topo.physical_cores, 14);
+        assert_eq!(topo.performance_cores, 6);
+        assert_eq!(topo.performance_cpus.as_ref().unwrap().to_string(), "0-11");
+        assert_eq!(topo.numa_nodes, 2);
+        assert!(topo.is_hybrid());
+    }
+
+    #[test]
+    fn arm_big_little_uses_highest_capacity() {
+        let (_dir, root) = sysfs(8, 1);
+        for cpu in 0..8 {
+            let capacity = if cpu >= 4 { "1024" } else { "446" };
+            write(
+                &root,
+                &format!("devices/system/cpu/cpu{cpu}/cpu_capacity"),
+                capacity,
+            );
+        }
+        let topo = read_sysfs_topology(&root).unwrap();
+        assert_eq!(topo.performance_cores, 4);
+        assert_eq!(topo.performance_cpus.unwrap().to_string(), "4-7");
+    }
+
+    #[test]
+    fn missing_cpu_list_yields_none() {
+        let dir = TempDir::new().unwrap();
+        assert_eq!(read_sysfs_topology(dir.path()), None);
+    }
+
+    #[test]
+    fn host_topology_is_consistent() {
+        let topo = cpu_topology();
+        assert!(topo.logical_cpus >= topo.physical_cores);
+        assert!(topo.physical_cores >= topo.performance_cores);
+        assert!(topo.performance_cores >= 1);
+    }
+}
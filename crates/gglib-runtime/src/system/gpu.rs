@@ -5,7 +5,7 @@
 //! `SystemProbePort` contract.
 
 use gglib_core::utils::process::cmd;
-use gglib_core::utils::system::{GpuInfo, SystemMemoryInfo};
+use gglib_core::utils::system::{GpuDevice, GpuInfo, SystemMemoryInfo};
 use sysinfo::System;
 
 use crate::llama::vulkan_status;
@@ -97,6 +97,22 @@ pub fn check_cuda() -> Option<String> {
     None
 }
 
+/// CUDA version supported by the NVIDIA driver, from nvidia-smi's banner.
+pub fn driver_cuda_version() -> Option<String> {
+    let output = cmd("nvidia-smi").output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    parse_driver_cuda_version(&String::from_utf8_lossy(&output.stdout))
+}
+
+/// Extract `12.4` from a banner line like `| NVIDIA-SMI 550.54 ... CUDA Version: 12.4 |`.
+fn parse_driver_cuda_version(banner: &str) -> Option<String> {
+    let rest = banner.split("CUDA Version:").nth(1)?;
+    let version = rest.split_whitespace().next()?.trim_end_matches('|');
+    (!version.is_empty()).then(|| version.to_string())
+}
+
 /// Get NVIDIA GPU VRAM in bytes using nvidia-smi.
 pub fn get_nvidia_vram_bytes() -> Option<u64> {
     let output = cmd("nvidia-smi")
@@ -115,6 +131,51 @@ pub fn get_nvidia_vram_bytes() -> Option<u64> {
     Some(mib * 1024 * 1024)
 }
 
+/// Enumerate the NVIDIA GPUs with their total and free VRAM.
+///
+/// Probed on every call, since free VRAM moves as servers start and stop.
+/// Empty when nvidia-smi is missing or reports no devices. Devices come
+/// back in nvidia-smi's PCI bus order, which llama.cpp's CUDA backend
+/// follows when `CUDA_DEVICE_ORDER=PCI_BUS_ID` is set.
+pub fn gpu_devices() -> Vec<GpuDevice> {
+    let Ok(output) = cmd("nvidia-smi")
+        .args([
+            "--query-gpu=index,name,memory.total,memory.free",
+            "--format=csv,noheader,nounits",
+        ])
+        .output()
+    else {
+        return Vec::new();
+    };
+    if !output.status.success() {
+        return Vec::new();
+    }
+    parse_nvidia_gpu_devices(&String::from_utf8_lossy(&output.stdout))
+}
+
+/// Parse `index, name, total MiB, free MiB` rows from nvidia-smi.
+///
+/// The name sits between the index and the two memory columns, so a name
+/// that itself contains a comma still parses. Malformed rows are skipped.
+fn parse_nvidia_gpu_devices(csv: &str) -> Vec<GpuDevice> {
+    const MIB: u64 = 1024 * 1024;
+
+    csv.lines()
+        .filter_map(|line| {
+            let fields: Vec<&str> = line.split(',').map(str::trim).collect();
+            let [index, name @ .., total, free] = fields.as_slice() else {
+                return None;
+            };
+            Some(GpuDevice {
+                index: index.parse().ok()?,
+                name: name.join(", "),
+                total_vram_bytes: total.parse::<u64>().ok()? * MIB,
+                free_vram_bytes: free.parse::<u64>().ok()? * MIB,
+            })
+        })
+        .collect()
+}
+
 /// Get system memory information for model fit calculations.
 pub fn get_system_memory_info() -> SystemMemoryInfo {
     let sys = System::new_all();
@@ -147,6 +208,28 @@ pub fn get_system_memory_info() -> SystemMemoryInfo {
 mod tests {
     use super::*;
 
+    #[test]
+    fn driver_cuda_version_is_read_from_the_banner() {
+        let banner =
+            "| NVIDIA-SMI 550.54.14    Driver Version: 550.54.14    CUDA Version: 12.4     |\n";
+        assert_eq!(parse_driver_cuda_version(banner).as_deref(), Some("12.4"));
+        assert_eq!(parse_driver_cuda_version("No devices were found"), None);
+    }
+
+    #[test]
+    fn gpu_devices_are_read_from_nvidia_smi_rows() {
+        let csv = "0, NVIDIA GeForce RTX 4090, 24564, 23000\n\
+                   1, NVIDIA RTX A4000, Rev 2, 16376, 15000\n\
+                   not a row\n";
+        let devices = parse_nvidia_gpu_devices(csv);
+        assert_eq!(devices.len(), 2);
+        assert_eq!(devices[0].index, 0);
+        assert_eq!(devices[0].name, "NVIDIA GeForce RTX 4090");
+        assert_eq!(devices[0].total_vram_bytes, 24_564 * 1024 * 1024);
+        assert_eq!(devices[0].free_vram_bytes, 23_000 * 1024 * 1024);
+        assert_eq!(devices[1].name, "NVIDIA RTX A4000, Rev 2");
+    }
+
     #[test]
     fn test_get_system_memory_info() {
         let info = get_system_memory_info();
@@ -1,10 +1,13 @@
 #![doc = include_str!("README.md")]
 mod commands;
+mod cpu;
 mod deps;
 pub(crate) mod gpu;
 
+pub use cpu::{CpuTopology, cpu_topology};
 use gglib_core::ports::SystemProbePort;
 use gglib_core::utils::system::{Dependency, DependencyStatus, GpuInfo, SystemMemoryInfo};
+pub use gpu::gpu_devices;
 
 #[cfg(target_os = "linux")]
 use commands::get_patchelf_version;
@@ -341,6 +344,10 @@ impl SystemProbePort for DefaultSystemProbe {
     fn get_system_memory_info(&self) -> SystemMemoryInfo {
         get_system_memory_info()
     }
+
+    fn driver_cuda_version(&self) -> Option<String> {
+        gpu::driver_cuda_version()
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,258 @@
+//! [`EncryptedFileStore`] — secrets in a ChaCha20-Poly1305 encrypted file.
+//!
+//! ```text
+//! <data_dir>/
+//!   secrets.key   — 32-byte random key, owner-only permissions
+//!   secrets.enc   — {"nonce", "ciphertext"} of a JSON map key → value
+//! ```
+//!
+//! The fallback for machines without a usable keyring (headless Linux,
+//! containers). The key sits next to the data, so this keeps credentials out
+//! of the database, backups of it and casual `grep`s — it does not protect
+//! them from someone who can read the user's files.
+
+use std::collections::BTreeMap;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use chacha20poly1305::aead::{Aead, AeadCore, KeyInit, OsRng};
+use chacha20poly1305::{ChaCha20Poly1305, Key, Nonce};
+use serde::{Deserialize, Serialize};
+
+use gglib_core::ports::{SecretStoreError, SecretStorePort, SecretStoreResult};
+
+const KEY_FILE: &str = "secrets.key";
+const DATA_FILE: &str = "secrets.enc";
+const KEY_LEN: usize = 32;
+
+/// On-disk envelope of `secrets.enc`.
+#[derive(Serialize, Deserialize)]
+struct Envelope {
+    nonce: String,
+    ciphertext: String,
+}
+
+/// Secrets encrypted into a file under the data directory.
+///
+/// Nothing is written until the first secret is stored, so opening a store
+/// on a fresh install leaves no files behind.
+#[derive(Debug)]
+pub struct EncryptedFileStore {
+    dir: PathBuf,
+    /// Serialises read-modify-write cycles within the process.
+    lock: Mutex<()>,
+}
+
+impl EncryptedFileStore {
+    /// A store keeping its files in `dir`.
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self {
+            dir: dir.into(),
+            lock: Mutex::new(()),
+        }
+    }
+
+    /// Path of the encrypted secrets file.
+    #[must_use]
+    pub fn path(&self) -> PathBuf {
+        self.dir.join(DATA_FILE)
+    }
+
+    fn key_path(&self) -> PathBuf {
+        self.dir.join(KEY_FILE)
+    }
+
+    fn cipher(&self, create: bool) -> SecretStoreResult<Option<ChaCha20Poly1305>> {
+        let path = self.key_path();
+        match std::fs::read(&path) {
+            Ok(bytes) if bytes.len() == KEY_LEN => {
+                Ok(Some(ChaCha20Poly1305::new(Key::from_slice(&bytes))))
+            }
+            Ok(_) => Err(SecretStoreError::Corrupt(format!(
+                "{} is not a {KEY_LEN}-byte key",
+                path.display()
+            ))),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound && create => {
+                let key = ChaCha20Poly1305::generate_key(&mut OsRng);
+                write_private(&path, key.as_slice())?;
+                Ok(Some(ChaCha20Poly1305::new(&key)))
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(io_error(&path, &e)),
+        }
+    }
+
+    fn load(&self) -> SecretStoreResult<BTreeMap<String, String>> {
+        let path = self.path();
+        let text = match std::fs::read_to_string(&path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(BTreeMap::new()),
+            Err(e) => return Err(io_error(&path, &e)),
+        };
+        let Some(cipher) = self.cipher(false)? else {
+            return Err(SecretStoreError::Corrupt(format!(
+                "{} exists but {} is missing",
+                path.display(),
+                self.key_path().display()
+            )));
+        };
+
+        let corrupt = |what: &str| SecretStoreError::Corrupt(format!("{}: {what}", path.display()));
+        let envelope: Envelope = serde_json::from_str(&text).map_err(|_| corrupt("not JSON"))?;
+        let nonce = STANDARD
+            .decode(envelope.nonce)
+            .map_err(|_| corrupt("bad nonce"))?;
+        if nonce.len() != 12 {
+            return Err(corrupt("bad nonce"));
+        }
+        let ciphertext = STANDARD
+            .decode(envelope.ciphertext)
+            .map_err(|_| corrupt("bad ciphertext"))?;
+        let plaintext = cipher
+            .decrypt(Nonce::from_slice(&nonce), ciphertext.as_slice())
+            .map_err(|_| corrupt("decryption failed (wrong key?)"))?;
+        serde_json::from_slice(&plaintext).map_err(|_| corrupt("not a secrets map"))
+    }
+
+    fn save(&self, secrets: &BTreeMap<String, String>) -> SecretStoreResult<()> {
+        std::fs::create_dir_all(&self.dir).map_err(|e| io_error(&self.dir, &e))?;
+        let Some(cipher) = self.cipher(true)? else {
+            unreachable!("cipher(true) creates a missing key");
+        };
+        let plaintext = serde_json::to_vec(secrets)
+            .map_err(|e| SecretStoreError::Backend(format!("serialising secrets: {e}")))?;
+        let nonce = ChaCha20Poly1305::generate_nonce(&mut OsRng);
+        let ciphertext = cipher
+            .encrypt(&nonce, plaintext.as_slice())
+            .map_err(|e| SecretStoreError::Backend(format!("encrypting secrets: {e}")))?;
+        let envelope = Envelope {
+            nonce: STANDARD.encode(nonce),
+            ciphertext: STANDARD.encode(ciphertext),
+        };
+        let json = serde_json::to_vec(&envelope)
+            .map_err(|e| SecretStoreError::Backend(format!("serialising secrets: {e}")))?;
+
+        // Write beside the target and rename, so a crash never leaves a
+        // half-written file behind.
+        let path = self.path();
+        let tmp = path.with_extension("enc.tmp");
+        write_private(&tmp, &json)?;
+        std::fs::rename(&tmp, &path).map_err(|e| io_error(&path, &e))
+    }
+
+    fn guard(&self) -> std::sync::MutexGuard<'_, ()> {
+        self.lock
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+}
+
+impl SecretStorePort for EncryptedFileStore {
+    fn backend(&self) -> &'static str {
+        "file"
+    }
+
+    fn get(&self, key: &str) -> SecretStoreResult<Option<String>> {
+        let _guard = self.guard();
+        Ok(self.load()?.remove(key))
+    }
+
+    fn set(&self, key: &str, value: &str) -> SecretStoreResult<()> {
+        let _guard = self.guard();
+        let mut secrets = self.load()?;
+        secrets.insert(key.to_string(), value.to_string());
+        self.save(&secrets)
+    }
+
+    fn delete(&self, key: &str) -> SecretStoreResult<bool> {
+        let _guard = self.guard();
+        let mut secrets = self.load()?;
+        if secrets.remove(key).is_none() {
+            return Ok(false);
+        }
+        self.save(&secrets)?;
+        Ok(true)
+    }
+}
+
+/// Create or truncate `path` readable by the owner only.
+fn write_private(path: &Path, bytes: &[u8]) -> SecretStoreResult<()> {
+    let mut options = std::fs::OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+    let mut file = options.open(path).map_err(|e| io_error(path, &e))?;
+    file.write_all(bytes).map_err(|e| io_error(path, &e))?;
+    file.sync_all().map_err(|e| io_error(path, &e))
+}
+
+fn io_error(path: &Path, e: &std::io::Error) -> SecretStoreError {
+    SecretStoreError::Backend(format!("{}: {e}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn secrets_round_trip_encrypted() {
+        let tmp = tempfile::tempdir().unwrap();
+        let store = EncryptedFileStore::new(tmp.path());
+        assert_eq!(store.get("hf_token").unwrap(), None);
+        assert!(!store.path().exists(), "reads must not create files");
+
+        store.set("hf_token", "hf_secret_value").unwrap();
+        store.set("web_search_api_key", "brave").unwrap();
+        let on_disk = std::fs::read_to_string(store.path()).unwrap();
+        assert!(!on_disk.contains("hf_secret_value"));
+
+        // A second handle on the same directory sees the same secrets.
+        let reopened = EncryptedFileStore::new(tmp.path());
+        assert_eq!(
+            reopened.get("hf_token").unwrap().as_deref(),
+            Some("hf_secret_value")
+        );
+        assert!(reopened.delete("hf_token").unwrap());
+        assert!(!reopened.delete("hf_token").unwrap());
+        assert_eq!(store.get("hf_token").unwrap(), None);
+        assert_eq!(
+            store.get("web_search_api_key").unwrap().as_deref(),
+            Some("brave")
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn files_are_owner_only() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let tmp = tempfile::tempdir().unwrap();
+        let store = EncryptedFileStore::new(tmp.path());
+        store.set("k", "v").unwrap();
+        for file in [KEY_FILE, DATA_FILE] {
+            let mode = std::fs::metadata(tmp.path().join(file))
+                .unwrap()
+                .permissions()
+                .mode();
+            assert_eq!(mode & 0o777, 0o600, "{file}");
+        }
+    }
+
+    #[test]
+    fn a_replaced_key_is_reported_as_corrupt() {
+        let tmp = tempfile::tempdir().unwrap();
+        let store = EncryptedFileStore::new(tmp.path());
+        store.set("k", "v").unwrap();
+        std::fs::write(tmp.path().join(KEY_FILE), [7u8; KEY_LEN]).unwrap();
+        assert!(matches!(store.get("k"), Err(SecretStoreError::Corrupt(_))));
+
+        std::fs::remove_file(tmp.path().join(KEY_FILE)).unwrap();
+        assert!(matches!(store.get("k"), Err(SecretStoreError::Corrupt(_))));
+    }
+}
@@ -0,0 +1,72 @@
+//! [`KeyringStore`] — secrets in the OS keyring.
+//!
+//! Each secret is one keyring entry under the `gglib` service, so it shows
+//! up by name in Keychain Access, Credential Manager or Seahorse.
+
+use gglib_core::ports::{SecretStoreError, SecretStorePort, SecretStoreResult};
+use keyring::{Entry, Error};
+
+/// Account name used to check that the keyring answers at all.
+const PROBE_ACCOUNT: &str = "gglib-probe";
+
+/// Secrets held by the platform keyring.
+#[derive(Debug, Clone)]
+pub struct KeyringStore {
+    service: String,
+}
+
+impl KeyringStore {
+    /// A store using keyring entries of `service`.
+    pub fn new(service: impl Into<String>) -> Self {
+        Self {
+            service: service.into(),
+        }
+    }
+
+    /// Whether the platform keyring can be read right now.
+    ///
+    /// False on machines without a Secret Service (headless Linux,
+    /// containers) or with a locked keyring that refuses access.
+    #[must_use]
+    pub fn is_available(&self) -> bool {
+        match Entry::new(&self.service, PROBE_ACCOUNT).and_then(|entry| entry.get_password()) {
+            Ok(_) | Err(Error::NoEntry) => true,
+            Err(e) => {
+                tracing::debug!("OS keyring unavailable: {e}");
+                false
+            }
+        }
+    }
+
+    fn entry(&self, key: &str) -> SecretStoreResult<Entry> {
+        Entry::new(&self.service, key).map_err(|e| SecretStoreError::Backend(e.to_string()))
+    }
+}
+
+impl SecretStorePort for KeyringStore {
+    fn backend(&self) -> &'static str {
+        "keyring"
+    }
+
+    fn get(&self, key: &str) -> SecretStoreResult<Option<String>> {
+        match self.entry(key)?.get_password() {
+            Ok(value) => Ok(Some(value)),
+            Err(Error::NoEntry) => Ok(None),
+            Err(e) => Err(SecretStoreError::Backend(e.to_string())),
+        }
+    }
+
+    fn set(&self, key: &str, value: &str) -> SecretStoreResult<()> {
+        self.entry(key)?
+            .set_password(value)
+            .map_err(|e| SecretStoreError::Backend(e.to_string()))
+    }
+
+    fn delete(&self, key: &str) -> SecretStoreResult<bool> {
+        match self.entry(key)?.delete_credential() {
+            Ok(()) => Ok(true),
+            Err(Error::NoEntry) => Ok(false),
+            Err(e) => Err(SecretStoreError::Backend(e.to_string())),
+        }
+    }
+}
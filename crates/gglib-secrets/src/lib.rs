@@ -0,0 +1,55 @@
+#![doc = include_str!(concat!(env!("OUT_DIR"), "/README_GENERATED.md"))]
+#![deny(unsafe_code)]
+
+//! Secret storage for gglib credentials.
+//!
+//! [`open`] picks the backend once at startup: the OS keyring when it
+//! answers, otherwise an [`EncryptedFileStore`] in the data directory. Both
+//! implement [`SecretStorePort`] from `gglib-core`.
+
+mod file;
+#[cfg(feature = "keyring")]
+mod keyring;
+
+use std::path::Path;
+use std::sync::Arc;
+
+use gglib_core::ports::SecretStorePort;
+
+pub use file::EncryptedFileStore;
+#[cfg(feature = "keyring")]
+pub use keyring::KeyringStore;
+
+/// Keyring service name all gglib secrets are filed under.
+pub const KEYRING_SERVICE: &str = "gglib";
+
+/// Environment variable that forces a backend: `keyring` or `file`.
+pub const BACKEND_ENV: &str = "GGLIB_SECRET_STORE";
+
+/// Open the secret store for the data directory `data_dir`.
+///
+/// Uses the OS keyring when it is available and not overridden by
+/// [`BACKEND_ENV`], and the encrypted file under `data_dir` otherwise.
+#[must_use]
+pub fn open(data_dir: &Path) -> Arc<dyn SecretStorePort> {
+    let forced = std::env::var(BACKEND_ENV).ok();
+    let store: Arc<dyn SecretStorePort> = match forced.as_deref() {
+        Some("file") => Arc::new(EncryptedFileStore::new(data_dir)),
+        _ => open_keyring().unwrap_or_else(|| Arc::new(EncryptedFileStore::new(data_dir))),
+    };
+    tracing::debug!(backend = store.backend(), "secret store opened");
+    store
+}
+
+#[cfg(feature = "keyring")]
+fn open_keyring() -> Option<Arc<dyn SecretStorePort>> {
+    let store = KeyringStore::new(KEYRING_SERVICE);
+    store
+        .is_available()
+        .then(|| Arc::new(store) as Arc<dyn SecretStorePort>)
+}
+
+#[cfg(not(feature = "keyring"))]
+fn open_keyring() -> Option<Arc<dyn SecretStorePort>> {
+    None
+}
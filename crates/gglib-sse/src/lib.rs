@@ -135,21 +135,36 @@ where
         events: impl Stream<Item = T> + Send + 'static,
         opts: SseOptions,
     ) -> Sse<impl Stream<Item = Result<Event, Infallible>> + Send + 'static> {
-        let encoded = events.filter_map(|event| Self::encode(&event));
-        Sse::new(encoded).keep_alive(
-            KeepAlive::new()
-                .interval(opts.keepalive_interval)
-                .text(opts.keepalive_text),
-        )
+        into_sse(events, opts)
     }
+}
 
-    fn encode(event: &T) -> Option<Result<Event, Infallible>> {
-        match serde_json::to_string(event) {
-            Ok(json) => Some(Ok(Event::default().data(json))),
-            Err(e) => {
-                tracing::warn!("Failed to serialize SSE event: {e}");
-                None
-            }
+/// Encode any event stream as an Axum SSE response with keep-alives.
+///
+/// For callers that assemble their own stream, e.g. by merging
+/// [`Broadcaster::subscribe_events`] streams from several broadcasters.
+/// Events are serialized only as they are sent.
+pub fn into_sse<T>(
+    events: impl Stream<Item = T> + Send + 'static,
+    opts: SseOptions,
+) -> Sse<impl Stream<Item = Result<Event, Infallible>> + Send + 'static>
+where
+    T: Serialize + Send + 'static,
+{
+    let encoded = events.filter_map(|event| encode(&event));
+    Sse::new(encoded).keep_alive(
+        KeepAlive::new()
+            .interval(opts.keepalive_interval)
+            .text(opts.keepalive_text),
+    )
+}
+
+fn encode<T: Serialize>(event: &T) -> Option<Result<Event, Infallible>> {
+    match serde_json::to_string(event) {
+        Ok(json) => Some(Ok(Event::default().data(json))),
+        Err(e) => {
+            tracing::warn!("Failed to serialize SSE event: {e}");
+            None
         }
     }
 }
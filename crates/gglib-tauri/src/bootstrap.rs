@@ -14,7 +14,7 @@ use anyhow::Result;
 use gglib_app_services::{
     BenchmarkDeps, BenchmarkOps, CouncilApprovalRegistry, DownloadDeps, DownloadOps, McpDeps,
     McpOps, ModelDeps, ModelOps, ProxyDeps, ProxyOps, ServerDeps, ServerOps, SettingsDeps,
-    SettingsOps, SetupDeps, SetupOps,
+    SettingsOps, SetupDeps, SetupOps, WarmStartDeps, WarmStartOps,
 };
 use gglib_bootstrap::{BootstrapConfig, BuiltCore, CoreBootstrap};
 use gglib_core::DEFAULT_LLAMA_BASE_PORT;
@@ -24,8 +24,8 @@ use gglib_core::ports::{
 };
 use gglib_core::server_config::CacheRamSetting;
 use gglib_core::services::AppCore;
-use gglib_db::SqliteBenchmarkRepository;
 use gglib_db::repositories::SqliteCouncilRepository;
+use gglib_db::{SqliteBenchmarkRepository, SqliteDatabaseSnapshot};
 use gglib_gguf::{GgufParser, ToolSupportDetector};
 use gglib_mcp::McpService;
 use gglib_runtime::ports_impl::{CatalogPortImpl, RuntimePortImpl};
@@ -101,12 +101,16 @@ pub struct TauriContext {
     pub mcp_ops: Arc<McpOps>,
     pub proxy: Arc<ProxyOps>,
     pub setup: Arc<SetupOps>,
+    /// Startup models: launched at boot, relaunched after llama.cpp upgrades.
+    pub warm_start: Arc<WarmStartOps>,
     /// Orchestrator approval registry (for HITL gates via the embedded Axum server).
     pub approval_registry: Arc<CouncilApprovalRegistry>,
     /// Orchestrator run repository (for HITL persistence via the embedded Axum server).
     pub council_repo: Arc<SqliteCouncilRepository>,
     /// Benchmark run repository for compare and perf results.
     pub bench_repo: Arc<SqliteBenchmarkRepository>,
+    /// Consistent copies of the live database, for backups.
+    pub db_snapshot: Arc<SqliteDatabaseSnapshot>,
     /// Benchmark operations: run_compare and run_perf.
     pub benchmark: Arc<BenchmarkOps>,
     /// Shared `ModelRuntimePort` wrapping the `SingleSwap` `ProcessManager`.
@@ -180,6 +184,7 @@ pub async fn bootstrap(config: TauriConfig, app_handle: AppHandle) -> Result<Tau
         repos,
         model_registrar: _,
         pool,
+        jobs: _,
     } = CoreBootstrap::build(bootstrap_config, Arc::clone(&tauri_emitter)).await?;
 
     // Orchestrator persistence (Phase D).
@@ -197,22 +202,25 @@ pub async fn bootstrap(config: TauriConfig, app_handle: AppHandle) -> Result<Tau
     let model_repo: Arc<dyn ModelRepository> = repos.models.clone();
     let catalog: Arc<dyn ModelCatalogPort> = Arc::new(CatalogPortImpl::new(model_repo.clone()));
     let catalog_for_runtime = Arc::clone(&catalog);
-    let process_manager = Arc::new(ProcessManager::new_single_swap(
-        DEFAULT_LLAMA_BASE_PORT,
-        config.llama_server_path.to_string_lossy().into_owned(),
-        catalog_for_runtime,
-        None,
-        // `Auto` is the manager's default (used by ProxyOps and the public
-        // `runtime` field, parity with the CLI proxy). BenchmarkOps below
-        // overrides it to `ExplicitMb(0)` via `RuntimePortImpl::with_cache_ram`
-        // — it must never gain a prompt cache, which would perturb prefill
-        // timings and RAM footprint — while still sharing this same
-        // SingleSwap manager, so only one llama-server ever runs.
-        CacheRamSetting::Auto,
-        None,
-        None,
-        None,
-    ));
+    let process_manager = Arc::new(
+        ProcessManager::new_single_swap(
+            DEFAULT_LLAMA_BASE_PORT,
+            config.llama_server_path.to_string_lossy().into_owned(),
+            catalog_for_runtime,
+            None,
+            // `Auto` is the manager's default (used by ProxyOps and the public
+            // `runtime` field, parity with the CLI proxy). BenchmarkOps below
+            // overrides it to `ExplicitMb(0)` via `RuntimePortImpl::with_cache_ram`
+            // — it must never gain a prompt cache, which would perturb prefill
+            // timings and RAM footprint — while still sharing this same
+            // SingleSwap manager, so only one llama-server ever runs.
+            CacheRamSetting::Auto,
+            None,
+            None,
+            None,
+        )
+        .with_cached_prompts(repos.settings.clone()),
+    );
     let runtime: Arc<dyn ModelRuntimePort> =
         Arc::new(RuntimePortImpl::new(Arc::clone(&process_manager)));
     let benchmark_runtime: Arc<dyn ModelRuntimePort> = Arc::new(RuntimePortImpl::with_cache_ram(
@@ -221,6 +229,7 @@ pub async fn bootstrap(config: TauriConfig, app_handle: AppHandle) -> Result<Tau
     ));
 
     // Benchmark ops — constructed after runtime to share SingleSwap semantics.
+    let db_snapshot = Arc::new(SqliteDatabaseSnapshot::new(pool.clone()));
     let bench_repo = Arc::new(SqliteBenchmarkRepository::new(pool));
     let benchmark_http = BenchmarkDeps::build_http_client()?;
     let benchmark = Arc::new(BenchmarkOps::new(BenchmarkDeps {
@@ -260,6 +269,8 @@ pub async fn bootstrap(config: TauriConfig, app_handle: AppHandle) -> Result<Tau
         core: Arc::clone(&app),
         system_probe: system_probe.clone(),
         downloads: downloads.clone(),
+        proxy: Arc::clone(&proxy_supervisor),
+        emitter: Arc::clone(&tauri_emitter),
     }));
     let mcp_ops = Arc::new(McpOps::new(McpDeps { mcp: mcp.clone() }));
     let approval_registry = Arc::new(CouncilApprovalRegistry::new());
@@ -278,6 +289,37 @@ pub async fn bootstrap(config: TauriConfig, app_handle: AppHandle) -> Result<Tau
         core: Arc::clone(&app),
         system_probe,
     }));
+    let warm_start = Arc::new(WarmStartOps::new(WarmStartDeps {
+        core: Arc::clone(&app),
+        servers: Arc::clone(&servers),
+    }));
+
+    // Announce settings saved by other processes (e.g. the CLI).
+    tokio::spawn({
+        let settings = Arc::clone(&settings);
+        async move {
+            settings
+                .watch_external_changes(gglib_app_services::EXTERNAL_CHANGE_POLL_INTERVAL)
+                .await;
+        }
+    });
+
+    // Confirm each live proxy settings reload to the frontend.
+    tokio::spawn({
+        let mut rx = proxy.reload_receiver();
+        let emitter = Arc::clone(&tauri_emitter);
+        async move {
+            while rx.changed().await.is_ok() {
+                let applied = rx.borrow().clone();
+                if let Some(applied) = applied {
+                    emitter.emit(gglib_core::events::AppEvent::proxy_config_reloaded(
+                        applied.default_ctx,
+                        applied.profiles,
+                    ));
+                }
+            }
+        }
+    });
 
     Ok(TauriContext {
         app,
@@ -296,9 +338,11 @@ pub async fn bootstrap(config: TauriConfig, app_handle: AppHandle) -> Result<Tau
         mcp_ops,
         proxy,
         setup,
+        warm_start,
         approval_registry,
         council_repo,
         bench_repo,
+        db_snapshot,
         benchmark,
         runtime,
     })
@@ -374,6 +418,8 @@ pub fn bootstrap_with(
         core: Arc::clone(&app),
         system_probe: system_probe.clone(),
         downloads: downloads.clone(),
+        proxy: Arc::clone(&proxy_supervisor),
+        emitter: Arc::new(NoopEmitter),
     }));
     let mcp_ops = Arc::new(McpOps::new(McpDeps { mcp: mcp.clone() }));
     let approval_registry_w = Arc::new(CouncilApprovalRegistry::new());
@@ -400,6 +446,10 @@ pub fn bootstrap_with(
         core: Arc::clone(&app),
         system_probe,
     }));
+    let warm_start_ops = Arc::new(WarmStartOps::new(WarmStartDeps {
+        core: Arc::clone(&app),
+        servers: Arc::clone(&servers_ops),
+    }));
 
     TauriContext {
         app,
@@ -418,9 +468,11 @@ pub fn bootstrap_with(
         mcp_ops,
         proxy: proxy_ops,
         setup: setup_ops,
+        warm_start: warm_start_ops,
         approval_registry: approval_registry_w,
         council_repo: orch_repo_w,
         bench_repo: bench_repo_w,
+        db_snapshot: Arc::new(SqliteDatabaseSnapshot::new_in_memory()),
         benchmark: benchmark_w,
         runtime,
     }
@@ -468,6 +520,7 @@ pub async fn bootstrap_early(config: TauriConfig) -> Result<TauriContext> {
         repos,
         model_registrar: _,
         pool,
+        jobs: _,
     } = CoreBootstrap::build(bootstrap_config, emitter).await?;
     let council_repo = Arc::new(SqliteCouncilRepository::new(pool.clone()));
     let mcp = Arc::new(McpService::new(
@@ -483,22 +536,25 @@ pub async fn bootstrap_early(config: TauriConfig) -> Result<TauriContext> {
     let model_repo: Arc<dyn ModelRepository> = repos.models.clone();
     let catalog: Arc<dyn ModelCatalogPort> = Arc::new(CatalogPortImpl::new(model_repo.clone()));
     let catalog_for_runtime = Arc::clone(&catalog);
-    let process_manager = Arc::new(ProcessManager::new_single_swap(
-        DEFAULT_LLAMA_BASE_PORT,
-        config.llama_server_path.to_string_lossy().into_owned(),
-        catalog_for_runtime,
-        None,
-        // `Auto` is the manager's default (used by ProxyOps and the public
-        // `runtime` field, parity with the CLI proxy). BenchmarkOps below
-        // overrides it to `ExplicitMb(0)` via `RuntimePortImpl::with_cache_ram`
-        // — it must never gain a prompt cache, which would perturb prefill
-        // timings and RAM footprint — while still sharing this same
-        // SingleSwap manager, so only one llama-server ever runs.
-        CacheRamSetting::Auto,
-        None,
-        None,
-        None,
-    ));
+    let process_manager = Arc::new(
+        ProcessManager::new_single_swap(
+            DEFAULT_LLAMA_BASE_PORT,
+            config.llama_server_path.to_string_lossy().into_owned(),
+            catalog_for_runtime,
+            None,
+            // `Auto` is the manager's default (used by ProxyOps and the public
+            // `runtime` field, parity with the CLI proxy). BenchmarkOps below
+            // overrides it to `ExplicitMb(0)` via `RuntimePortImpl::with_cache_ram`
+            // — it must never gain a prompt cache, which would perturb prefill
+            // timings and RAM footprint — while still sharing this same
+            // SingleSwap manager, so only one llama-server ever runs.
+            CacheRamSetting::Auto,
+            None,
+            None,
+            None,
+        )
+        .with_cached_prompts(repos.settings.clone()),
+    );
     let runtime: Arc<dyn ModelRuntimePort> =
         Arc::new(RuntimePortImpl::new(Arc::clone(&process_manager)));
     let benchmark_runtime: Arc<dyn ModelRuntimePort> = Arc::new(RuntimePortImpl::with_cache_ram(
@@ -507,6 +563,7 @@ pub async fn bootstrap_early(config: TauriConfig) -> Result<TauriContext> {
     ));
 
     // Benchmark ops — constructed after runtime to share SingleSwap semantics.
+    let db_snapshot = Arc::new(SqliteDatabaseSnapshot::new(pool.clone()));
     let bench_repo_e = Arc::new(SqliteBenchmarkRepository::new(pool));
     let benchmark_e_http = BenchmarkDeps::build_http_client()?;
     let benchmark_e = Arc::new(BenchmarkOps::new(BenchmarkDeps {
@@ -546,6 +603,8 @@ pub async fn bootstrap_early(config: TauriConfig) -> Result<TauriContext> {
         core: Arc::clone(&app),
         system_probe: system_probe.clone(),
         downloads: downloads.clone(),
+        proxy: Arc::clone(&proxy_supervisor),
+        emitter: Arc::new(NoopEmitter),
     }));
     let mcp_ops = Arc::new(McpOps::new(McpDeps { mcp: mcp.clone() }));
     let approval_registry_e = Arc::new(CouncilApprovalRegistry::new());
@@ -585,6 +644,7 @@ pub async fn bootstrap_early(config: TauriConfig) -> Result<TauriContext> {
         approval_registry: approval_registry_e,
         council_repo,
         bench_repo: bench_repo_e,
+        db_snapshot,
         benchmark: benchmark_e,
         runtime,
     })
@@ -66,6 +66,9 @@ impl From<RepositoryError> for TauriError {
                 TauriError::Internal(format!("Serialization: {}", msg))
             }
             RepositoryError::Constraint(msg) => TauriError::InvalidInput(msg),
+            RepositoryError::Conflict(msg) => {
+                TauriError::InvalidInput(format!("Conflict: {}", msg))
+            }
         }
     }
 }
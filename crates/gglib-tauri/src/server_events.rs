@@ -3,7 +3,7 @@
 //! This module implements the `ServerEvents` port by converting `ServerSummary`
 //! to Tauri's `ServerEvent` types and emitting via the Tauri event system.
 
-use gglib_core::events::{AppEvent, ServerEvents, ServerSummary};
+use gglib_core::events::{AppEvent, ServerEvents, ServerStopReason, ServerSummary};
 use tauri::AppHandle;
 
 use crate::events::emit_or_log;
@@ -40,8 +40,8 @@ impl ServerEvents for TauriServerEvents {
         );
     }
 
-    fn stopped(&self, server: &ServerSummary) {
-        let event = AppEvent::from_server_stopped(server);
+    fn stopped(&self, server: &ServerSummary, reason: ServerStopReason) {
+        let event = AppEvent::from_server_stopped(server, reason);
         emit_or_log(&self.app, event.event_name(), &event);
     }
 
@@ -102,7 +102,7 @@ mod tests {
     fn test_app_event_server_stopped_serialization() {
         let s = summary("42", 8080);
         let model_id = s.model_id.parse::<i64>().unwrap();
-        let event = AppEvent::server_stopped(model_id, &s.model_name);
+        let event = AppEvent::server_stopped(model_id, &s.model_name, ServerStopReason::Idle);
 
         assert_eq!(event.event_name(), "server:stopped");
 
@@ -110,5 +110,6 @@ mod tests {
         assert_eq!(json["type"], "server_stopped");
         assert_eq!(json["modelId"], 42);
         assert_eq!(json["modelName"], "TestModel");
+        assert_eq!(json["reason"], "idle");
     }
 }
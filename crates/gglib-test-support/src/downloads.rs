@@ -0,0 +1,268 @@
+//! In-memory [`DownloadStateRepositoryPort`].
+
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use async_trait::async_trait;
+use gglib_core::download::{
+    DownloadHistoryFilter, DownloadId, DownloadStatus, QueueRunSummary, QueuedDownload,
+};
+use gglib_core::ports::{DownloadStateRepositoryPort, RepositoryError};
+use gglib_core::utils::clock::{Clock, SystemClock};
+
+/// The persisted download queue and run history, kept in memory.
+///
+/// Follows the `SQLite` repository: `load_queue` returns queued and
+/// in-flight rows by position, re-enqueueing an id updates its status,
+/// position and byte counts, and `prune_completed` ages finished rows
+/// against the clock, from the moment they were marked failed.
+#[derive(Debug)]
+pub struct MemoryDownloadStateRepository {
+    clock: Arc<dyn Clock>,
+    state: Mutex<State>,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    rows: Vec<Row>,
+    runs: Vec<QueueRunSummary>,
+}
+
+#[derive(Debug)]
+struct Row {
+    download: QueuedDownload,
+    error: Option<String>,
+    /// Wall-clock milliseconds when the row was marked failed.
+    completed_at_ms: Option<u64>,
+}
+
+impl MemoryDownloadStateRepository {
+    pub fn new() -> Self {
+        Self::with_clock(Arc::new(SystemClock))
+    }
+
+    /// A repository that dates finished rows by `clock`.
+    pub fn with_clock(clock: Arc<dyn Clock>) -> Self {
+        Self {
+            clock,
+            state: Mutex::default(),
+        }
+    }
+
+    /// Every stored row, in any status, in enqueue order.
+    pub fn rows(&self) -> Vec<QueuedDownload> {
+        self.lock()
+            .rows
+            .iter()
+            .map(|row| row.download.clone())
+            .collect()
+    }
+
+    /// The error a failed download was marked with.
+    pub fn failure(&self, id: &DownloadId) -> Option<String> {
+        let id = id.to_string();
+        self.lock()
+            .rows
+            .iter()
+            .find(|row| row.download.id == id)
+            .and_then(|row| row.error.clone())
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, State> {
+        self.state
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+}
+
+impl Default for MemoryDownloadStateRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn not_found(id: &DownloadId) -> RepositoryError {
+    RepositoryError::NotFound(format!("Download with ID '{id}'"))
+}
+
+impl State {
+    fn row_mut(&mut self, id: &DownloadId) -> Result<&mut Row, RepositoryError> {
+        let key = id.to_string();
+        self.rows
+            .iter_mut()
+            .find(|row| row.download.id == key)
+            .ok_or_else(|| not_found(id))
+    }
+}
+
+#[async_trait]
+impl DownloadStateRepositoryPort for MemoryDownloadStateRepository {
+    async fn enqueue(&self, download: &QueuedDownload) -> Result<(), RepositoryError> {
+        let mut state = self.lock();
+        match state
+            .rows
+            .iter_mut()
+            .find(|row| row.download.id == download.id)
+        {
+            Some(row) => {
+                row.download.status = download.status;
+                row.download.position = download.position;
+                row.download.downloaded_bytes = download.downloaded_bytes;
+                row.download.total_bytes = download.total_bytes;
+            }
+            None => state.rows.push(Row {
+                download: download.clone(),
+                error: None,
+                completed_at_ms: None,
+            }),
+        }
+        drop(state);
+        Ok(())
+    }
+
+    async fn update_status(
+        &self,
+        id: &DownloadId,
+        status: DownloadStatus,
+    ) -> Result<(), RepositoryError> {
+        self.lock().row_mut(id)?.download.status = status;
+        Ok(())
+    }
+
+    async fn load_queue(&self) -> Result<Vec<QueuedDownload>, RepositoryError> {
+        let mut queue: Vec<QueuedDownload> = self
+            .lock()
+            .rows
+            .iter()
+            .filter(|row| {
+                matches!(
+                    row.download.status,
+                    DownloadStatus::Queued | DownloadStatus::Downloading
+                )
+            })
+            .map(|row| row.download.clone())
+            .collect();
+        queue.sort_by_key(|download| download.position);
+        Ok(queue)
+    }
+
+    async fn mark_failed(
+        &self,
+        id: &DownloadId,
+        error_message: &str,
+    ) -> Result<(), RepositoryError> {
+        let now = self.clock.unix_millis();
+        let mut state = self.lock();
+        let row = state.row_mut(id)?;
+        row.download.status = DownloadStatus::Failed;
+        row.error = Some(error_message.to_string());
+        row.completed_at_ms = Some(now);
+        drop(state);
+        Ok(())
+    }
+
+    async fn remove(&self, id: &DownloadId) -> Result<(), RepositoryError> {
+        let id = id.to_string();
+        self.lock().rows.retain(|row| row.download.id != id);
+        Ok(())
+    }
+
+    async fn prune_completed(&self, older_than_days: u32) -> Result<u32, RepositoryError> {
+        let max_age = Duration::from_hours(24 * u64::from(older_than_days));
+        let cutoff = self
+            .clock
+            .unix_millis()
+            .saturating_sub(u64::try_from(max_age.as_millis()).unwrap_or(u64::MAX));
+        let mut state = self.lock();
+        let before = state.rows.len();
+        state.rows.retain(|row| {
+            let finished = matches!(
+                row.download.status,
+                DownloadStatus::Completed | DownloadStatus::Failed | DownloadStatus::Cancelled
+            );
+            !(finished && row.completed_at_ms.is_some_and(|at| at < cutoff))
+        });
+        let pruned = before - state.rows.len();
+        drop(state);
+        Ok(u32::try_from(pruned).unwrap_or(u32::MAX))
+    }
+
+    async fn record_run(&self, run: &QueueRunSummary) -> Result<(), RepositoryError> {
+        self.lock().runs.push(QueueRunSummary {
+            truncated: false,
+            ..run.clone()
+        });
+        Ok(())
+    }
+
+    async fn load_history(
+        &self,
+        filter: &DownloadHistoryFilter,
+    ) -> Result<Vec<QueueRunSummary>, RepositoryError> {
+        let search = filter.search.as_deref().map(str::to_lowercase);
+        let filters_records = filter.result.is_some() || search.is_some();
+
+        let mut runs: Vec<QueueRunSummary> = self
+            .lock()
+            .runs
+            .iter()
+            .filter(|run| {
+                filter
+                    .since_ms
+                    .is_none_or(|since| run.completed_at_ms >= since)
+            })
+            .filter(|run| {
+                filter
+                    .until_ms
+                    .is_none_or(|until| run.completed_at_ms <= until)
+            })
+            .map(|run| {
+                let mut run = run.clone();
+                run.items.retain(|item| {
+                    filter.result.is_none_or(|kind| item.last_result == kind)
+                        && search
+                            .as_deref()
+                            .is_none_or(|s| item.display_name.to_lowercase().contains(s))
+                });
+                run.items
+                    .sort_by_key(|item| std::cmp::Reverse(item.last_completed_at_ms));
+                run
+            })
+            .filter(|run| !filters_records || !run.items.is_empty())
+            .collect();
+        runs.sort_by_key(|run| std::cmp::Reverse(run.completed_at_ms));
+        runs.truncate(usize::try_from(filter.limit()).unwrap_or(usize::MAX));
+        Ok(runs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use gglib_core::utils::clock::ManualClock;
+
+    #[tokio::test]
+    async fn failed_rows_leave_the_queue_and_age_out() {
+        let clock = Arc::new(ManualClock::default());
+        let repo = MemoryDownloadStateRepository::with_clock(clock.clone());
+        let id = DownloadId::from_model("org/a");
+        repo.enqueue(&QueuedDownload::new(id.to_string(), "org/a", "A", 2, 0))
+            .await
+            .unwrap();
+        repo.enqueue(&QueuedDownload::new("org/b", "org/b", "B", 1, 0))
+            .await
+            .unwrap();
+
+        let queue = repo.load_queue().await.unwrap();
+        assert_eq!(queue[0].model_id, "org/b");
+
+        repo.mark_failed(&id, "disk full").await.unwrap();
+        assert_eq!(repo.load_queue().await.unwrap().len(), 1);
+        assert_eq!(repo.failure(&id).as_deref(), Some("disk full"));
+
+        assert_eq!(repo.prune_completed(7).await.unwrap(), 0);
+        clock.advance(Duration::from_hours(24 * 8));
+        assert_eq!(repo.prune_completed(7).await.unwrap(), 1);
+        assert_eq!(repo.rows().len(), 1);
+    }
+}
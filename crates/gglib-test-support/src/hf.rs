@@ -0,0 +1,372 @@
+//! Scripted [`HfClientPort`], which also stands in for other model hubs.
+
+use std::collections::{BTreeMap, VecDeque};
+use std::sync::Mutex;
+
+use async_trait::async_trait;
+use gglib_core::download::Quantization;
+use gglib_core::ports::{
+    HfClientPort, HfCollection, HfFileInfo, HfPortError, HfQuantInfo, HfRepoInfo, HfSearchOptions,
+    HfSearchResult, ModelHub, ModelHubPort,
+};
+
+type HfPortResult<T> = Result<T, HfPortError>;
+
+/// A repository as the scripted Hub serves it.
+#[derive(Debug, Clone)]
+pub struct ScriptedRepo {
+    pub info: HfRepoInfo,
+    pub commit_sha: String,
+    pub files: Vec<HfFileInfo>,
+}
+
+impl ScriptedRepo {
+    /// An empty repository `owner/name` at commit `scripted`.
+    pub fn new(model_id: &str) -> Self {
+        let (author, name) = model_id.split_once('/').unwrap_or(("", model_id));
+        Self {
+            info: HfRepoInfo {
+                model_id: model_id.to_string(),
+                name: name.to_string(),
+                author: (!author.is_empty()).then(|| author.to_string()),
+                downloads: 0,
+                likes: 0,
+                parameters_b: None,
+                description: None,
+                last_modified: None,
+                chat_template: None,
+                tags: vec![],
+            },
+            commit_sha: "scripted".to_string(),
+            files: vec![],
+        }
+    }
+
+    /// Add a file of `size` bytes; `.gguf` paths count as GGUF files.
+    #[must_use]
+    pub fn with_file(mut self, path: &str, size: u64) -> Self {
+        self.files.push(HfFileInfo {
+            path: path.to_string(),
+            size,
+            is_gguf: std::path::Path::new(path)
+                .extension()
+                .is_some_and(|ext| ext.eq_ignore_ascii_case("gguf")),
+            oid: None,
+        });
+        self
+    }
+
+    #[must_use]
+    pub const fn with_params(mut self, parameters_b: f64) -> Self {
+        self.info.parameters_b = Some(parameters_b);
+        self
+    }
+
+    #[must_use]
+    pub const fn with_downloads(mut self, downloads: u64) -> Self {
+        self.info.downloads = downloads;
+        self
+    }
+
+    #[must_use]
+    pub fn with_commit_sha(mut self, sha: impl Into<String>) -> Self {
+        self.commit_sha = sha.into();
+        self
+    }
+
+    fn gguf_files(&self) -> impl Iterator<Item = &HfFileInfo> {
+        self.files.iter().filter(|file| file.is_gguf)
+    }
+}
+
+/// A Hub with a fixed set of repositories and collections.
+///
+/// Quantizations are derived from file names the way `gglib-hf` derives
+/// them. Errors queued with [`fail_next`](Self::fail_next) are returned by
+/// the following calls, one each, before any lookup; every call is logged
+/// for [`calls`](Self::calls). [`with_hub`](Self::with_hub) makes it
+/// answer as another hub, such as `ModelScope`.
+#[derive(Debug, Default)]
+pub struct ScriptedHfClient {
+    state: Mutex<State>,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    hub: ModelHub,
+    repos: BTreeMap<String, ScriptedRepo>,
+    collections: BTreeMap<String, HfCollection>,
+    failures: VecDeque<HfPortError>,
+    calls: Vec<String>,
+}
+
+impl ScriptedHfClient {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Report `hub` from [`ModelHubPort::hub`] (`HuggingFace` by default).
+    #[must_use]
+    pub fn with_hub(self, hub: ModelHub) -> Self {
+        self.lock().hub = hub;
+        self
+    }
+
+    #[must_use]
+    pub fn with_repo(self, repo: ScriptedRepo) -> Self {
+        self.lock().repos.insert(repo.info.model_id.clone(), repo);
+        self
+    }
+
+    #[must_use]
+    pub fn with_collection(self, collection: HfCollection) -> Self {
+        self.lock()
+            .collections
+            .insert(collection.slug.clone(), collection);
+        self
+    }
+
+    /// Make the next call that has not yet failed return `error`.
+    pub fn fail_next(&self, error: HfPortError) {
+        self.lock().failures.push_back(error);
+    }
+
+    /// Calls made so far, as `method argument` strings.
+    pub fn calls(&self) -> Vec<String> {
+        self.lock().calls.clone()
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, State> {
+        self.state
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+
+    /// Log the call, then either fail it or answer from `model_id`'s repo.
+    fn with_repo_of<T>(
+        &self,
+        call: &str,
+        model_id: &str,
+        answer: impl FnOnce(&ScriptedRepo) -> HfPortResult<T>,
+    ) -> HfPortResult<T> {
+        let mut state = self.lock();
+        state.calls.push(format!("{call} {model_id}"));
+        if let Some(error) = state.failures.pop_front() {
+            return Err(error);
+        }
+        let result = state.repos.get(model_id).map_or_else(
+            || {
+                Err(HfPortError::ModelNotFound {
+                    model_id: model_id.to_string(),
+                })
+            },
+            answer,
+        );
+        drop(state);
+        result
+    }
+}
+
+fn quantization_of(file: &HfFileInfo) -> Option<String> {
+    let quant = Quantization::from_filename(&file.path);
+    (!quant.is_unknown()).then(|| quant.to_string())
+}
+
+#[async_trait]
+impl ModelHubPort for ScriptedHfClient {
+    fn hub(&self) -> ModelHub {
+        self.lock().hub
+    }
+
+    async fn search(&self, options: &HfSearchOptions) -> HfPortResult<HfSearchResult> {
+        let mut state = self.lock();
+        state.calls.push(format!(
+            "search {}",
+            options.query.as_deref().unwrap_or_default()
+        ));
+        if let Some(error) = state.failures.pop_front() {
+            return Err(error);
+        }
+        let query = options.query.as_deref().map(str::to_lowercase);
+        let mut items: Vec<HfRepoInfo> = state
+            .repos
+            .values()
+            .map(|repo| repo.info.clone())
+            .filter(|info| {
+                query
+                    .as_deref()
+                    .is_none_or(|q| info.model_id.to_lowercase().contains(q))
+                    && options
+                        .min_params_b
+                        .is_none_or(|min| info.parameters_b.is_some_and(|p| p >= min))
+                    && options
+                        .max_params_b
+                        .is_none_or(|max| info.parameters_b.is_some_and(|p| p <= max))
+            })
+            .collect();
+        drop(state);
+
+        match options.sort_by.as_str() {
+            "likes" => items.sort_by_key(|info| info.likes),
+            "id" | "alphabetical" => items.sort_by(|a, b| a.model_id.cmp(&b.model_id)),
+            _ => items.sort_by_key(|info| info.downloads),
+        }
+        if !options.sort_ascending {
+            items.reverse();
+        }
+
+        let limit = options.limit.max(1) as usize;
+        let start = options.page as usize * limit;
+        let has_more = items.len() > start + limit;
+        Ok(HfSearchResult {
+            items: items.into_iter().skip(start).take(limit).collect(),
+            has_more,
+            page: options.page,
+        })
+    }
+
+    async fn list_quantizations(&self, model_id: &str) -> HfPortResult<Vec<HfQuantInfo>> {
+        self.with_repo_of("list_quantizations", model_id, |repo| {
+            let mut quants: BTreeMap<String, HfQuantInfo> = BTreeMap::new();
+            for file in repo.gguf_files() {
+                let Some(name) = quantization_of(file) else {
+                    continue;
+                };
+                let quant = quants.entry(name.clone()).or_insert(HfQuantInfo {
+                    name,
+                    shard_count: 0,
+                    total_size: 0,
+                    file_paths: vec![],
+                });
+                quant.shard_count += 1;
+                quant.total_size += file.size;
+                quant.file_paths.push(file.path.clone());
+            }
+            Ok(quants
+                .into_values()
+                .map(|mut quant| {
+                    quant.file_paths.sort();
+                    quant
+                })
+                .collect())
+        })
+    }
+
+    async fn list_gguf_files(&self, model_id: &str) -> HfPortResult<Vec<HfFileInfo>> {
+        self.with_repo_of("list_gguf_files", model_id, |repo| {
+            Ok(repo.gguf_files().cloned().collect())
+        })
+    }
+
+    async fn get_quantization_files(
+        &self,
+        model_id: &str,
+        quantization: &str,
+    ) -> HfPortResult<Vec<HfFileInfo>> {
+        self.with_repo_of("get_quantization_files", model_id, |repo| {
+            let mut files: Vec<HfFileInfo> = repo
+                .gguf_files()
+                .filter(|file| {
+                    quantization_of(file).is_some_and(|q| q.eq_ignore_ascii_case(quantization))
+                })
+                .cloned()
+                .collect();
+            if files.is_empty() {
+                return Err(HfPortError::QuantizationNotFound {
+                    model_id: model_id.to_string(),
+                    quantization: quantization.to_string(),
+                });
+            }
+            files.sort_by(|a, b| a.path.cmp(&b.path));
+            Ok(files)
+        })
+    }
+
+    async fn get_commit_sha(&self, model_id: &str) -> HfPortResult<String> {
+        self.with_repo_of("get_commit_sha", model_id, |repo| {
+            Ok(repo.commit_sha.clone())
+        })
+    }
+
+    async fn get_model_info(&self, model_id: &str) -> HfPortResult<HfRepoInfo> {
+        self.with_repo_of("get_model_info", model_id, |repo| Ok(repo.info.clone()))
+    }
+
+    fn file_url(&self, model_id: &str, revision: Option<&str>, path: &str) -> String {
+        format!(
+            "https://{}.invalid/{model_id}/resolve/{}/{path}",
+            self.hub(),
+            revision.unwrap_or("main")
+        )
+    }
+}
+
+#[async_trait]
+impl HfClientPort for ScriptedHfClient {
+    async fn get_collection(&self, slug: &str) -> HfPortResult<HfCollection> {
+        let mut state = self.lock();
+        state.calls.push(format!("get_collection {slug}"));
+        if let Some(error) = state.failures.pop_front() {
+            return Err(error);
+        }
+        state
+            .collections
+            .get(slug)
+            .cloned()
+            .ok_or_else(|| HfPortError::ModelNotFound {
+                model_id: slug.to_string(),
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hub() -> ScriptedHfClient {
+        ScriptedHfClient::new().with_repo(
+            ScriptedRepo::new("unsloth/Qwen3-8B-GGUF")
+                .with_file("Qwen3-8B-Q4_K_M.gguf", 5)
+                .with_file("Q8_0/Qwen3-8B-Q8_0-00002-of-00002.gguf", 4)
+                .with_file("Q8_0/Qwen3-8B-Q8_0-00001-of-00002.gguf", 6)
+                .with_file("README.md", 1),
+        )
+    }
+
+    #[tokio::test]
+    async fn quantizations_group_shards_like_the_hub_client() {
+        let hub = hub();
+        let quants = hub
+            .list_quantizations("unsloth/Qwen3-8B-GGUF")
+            .await
+            .unwrap();
+        let q8 = quants.iter().find(|q| q.name == "Q8_0").unwrap();
+        assert_eq!((q8.shard_count, q8.total_size), (2, 10));
+
+        let files = hub
+            .get_quantization_files("unsloth/Qwen3-8B-GGUF", "q8_0")
+            .await
+            .unwrap();
+        assert!(files[0].path.ends_with("00001-of-00002.gguf"));
+    }
+
+    #[tokio::test]
+    async fn scripted_failures_come_first_and_calls_are_logged() {
+        let hub = hub();
+        hub.fail_next(HfPortError::RateLimited);
+
+        assert!(matches!(
+            hub.get_commit_sha("unsloth/Qwen3-8B-GGUF").await,
+            Err(HfPortError::RateLimited)
+        ));
+        assert_eq!(
+            hub.get_commit_sha("unsloth/Qwen3-8B-GGUF").await.unwrap(),
+            "scripted"
+        );
+        assert!(matches!(
+            hub.get_model_info("nobody/none").await,
+            Err(HfPortError::ModelNotFound { .. })
+        ));
+        assert_eq!(hub.calls().len(), 3);
+    }
+}
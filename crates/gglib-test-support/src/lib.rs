@@ -0,0 +1,70 @@
+#![doc = include_str!(concat!(env!("OUT_DIR"), "/README_GENERATED.md"))]
+#![deny(unsafe_code)]
+
+//! In-memory implementations of gglib's ports, for tests.
+//!
+//! [`Fakes`] bundles one of each and builds the [`Repos`] and [`AppCore`]
+//! that application services take, so `gglib-app-services` and
+//! `gglib-proxy` behaviour can be exercised end to end without `SQLite`,
+//! the network or a llama-server binary.
+
+mod downloads;
+mod hf;
+mod models;
+mod runner;
+mod runtime;
+mod settings;
+mod unsupported;
+
+use std::sync::Arc;
+
+use gglib_core::ports::Repos;
+use gglib_core::services::AppCore;
+
+pub use downloads::MemoryDownloadStateRepository;
+pub use hf::{ScriptedHfClient, ScriptedRepo};
+pub use models::MemoryModelRepository;
+pub use runner::SimulatedProcessRunner;
+pub use runtime::SimulatedRuntime;
+pub use settings::MemorySettingsRepository;
+pub use unsupported::Unsupported;
+
+/// One fake per port, shared so tests can arrange state through the
+/// concrete types and inspect it after the code under test has run.
+#[derive(Debug, Clone, Default)]
+pub struct Fakes {
+    pub models: Arc<MemoryModelRepository>,
+    pub settings: Arc<MemorySettingsRepository>,
+    pub downloads: Arc<MemoryDownloadStateRepository>,
+    pub hf: Arc<ScriptedHfClient>,
+    pub runner: Arc<SimulatedProcessRunner>,
+}
+
+impl Fakes {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Repositories backed by these fakes, with [`Unsupported`] for the rest.
+    pub fn repos(&self) -> Repos {
+        Repos::new(
+            self.models.clone(),
+            self.settings.clone(),
+            Arc::new(Unsupported),
+            Arc::new(Unsupported),
+            Arc::new(Unsupported),
+            Arc::new(Unsupported),
+            Arc::new(Unsupported),
+        )
+    }
+
+    /// An `AppCore` over [`repos`](Self::repos) and the simulated runner.
+    pub fn core(&self) -> Arc<AppCore> {
+        Arc::new(AppCore::new(self.repos(), self.runner.clone()))
+    }
+
+    /// A single-model runtime over the model fake and the simulated runner.
+    pub fn runtime(&self) -> SimulatedRuntime {
+        SimulatedRuntime::new(self.models.clone(), self.runner.clone())
+    }
+}
@@ -0,0 +1,227 @@
+//! In-memory [`ModelRepository`].
+
+use std::collections::BTreeMap;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::sync::Mutex;
+
+use async_trait::async_trait;
+use gglib_core::utils::shard_filename::base_shard_filename;
+use gglib_core::{Model, ModelRepository, NewModel, RepositoryError};
+
+/// Models kept in a map, with the `SQLite` repository's semantics.
+///
+/// Ids are assigned in insertion order, `insert` upserts on the model key
+/// (`hf:<repo>@<sha>#<base file>`, or a hash of the local path), and
+/// `list` returns the newest models first.
+#[derive(Debug, Default)]
+pub struct MemoryModelRepository {
+    state: Mutex<State>,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    next_id: i64,
+    models: BTreeMap<i64, Model>,
+}
+
+impl MemoryModelRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A repository already holding `models`, inserted in order.
+    pub fn with_models(models: impl IntoIterator<Item = NewModel>) -> Self {
+        let repo = Self::new();
+        {
+            let mut state = repo.lock();
+            for model in models {
+                state.upsert(&model);
+            }
+        }
+        repo
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, State> {
+        self.state
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+}
+
+/// The deduplication key the `SQLite` repository derives for `model`.
+fn model_key(model: &NewModel) -> String {
+    if let (Some(repo), Some(sha), Some(filename)) =
+        (&model.hf_repo_id, &model.hf_commit_sha, &model.hf_filename)
+    {
+        format!("hf:{repo}@{sha}#{}", base_shard_filename(filename))
+    } else {
+        let mut hasher = DefaultHasher::new();
+        model.file_path.hash(&mut hasher);
+        format!("local:{:x}", hasher.finish())
+    }
+}
+
+impl State {
+    fn upsert(&mut self, new: &NewModel) -> Model {
+        let key = model_key(new);
+        if let Some(existing) = self.models.values_mut().find(|m| m.model_key == key) {
+            existing.file_path.clone_from(&new.file_path);
+            existing.quantization = new
+                .quantization
+                .clone()
+                .or_else(|| existing.quantization.take());
+            existing.context_length = new.context_length.or(existing.context_length);
+            existing.expert_count = new.expert_count.or(existing.expert_count);
+            existing.expert_used_count = new.expert_used_count.or(existing.expert_used_count);
+            existing.expert_shared_count = new.expert_shared_count.or(existing.expert_shared_count);
+            existing.download_date = new.download_date;
+            existing.last_update_check = new.last_update_check;
+            existing.tags.clone_from(&new.tags);
+            existing.capabilities = new.capabilities;
+            existing
+                .inference_defaults
+                .clone_from(&new.inference_defaults);
+            if existing.card.is_none() {
+                existing.card.clone_from(&new.card);
+            }
+            if new.license.is_some() {
+                existing.license.clone_from(&new.license);
+            }
+            return existing.clone();
+        }
+
+        self.next_id += 1;
+        let model = Model {
+            id: self.next_id,
+            name: new.name.clone(),
+            model_key: key,
+            file_path: new.file_path.clone(),
+            param_count_b: new.param_count_b,
+            architecture: new.architecture.clone(),
+            quantization: new.quantization.clone(),
+            context_length: new.context_length,
+            expert_count: new.expert_count,
+            expert_used_count: new.expert_used_count,
+            expert_shared_count: new.expert_shared_count,
+            metadata: new.metadata.clone(),
+            added_at: new.added_at,
+            hf_repo_id: new.hf_repo_id.clone(),
+            hf_commit_sha: new.hf_commit_sha.clone(),
+            hf_filename: new.hf_filename.clone(),
+            download_date: new.download_date,
+            last_update_check: new.last_update_check,
+            license: new.license.clone(),
+            tags: new.tags.clone(),
+            capabilities: new.capabilities,
+            capabilities_version: Some(gglib_core::domain::CAPABILITY_DETECTOR_VERSION),
+            inference_defaults: new.inference_defaults.clone(),
+            server_defaults: new.server_defaults.clone(),
+            benchmark_summary: None,
+            card: new.card.clone(),
+        };
+        self.models.insert(model.id, model.clone());
+        model
+    }
+}
+
+#[async_trait]
+impl ModelRepository for MemoryModelRepository {
+    async fn list(&self) -> Result<Vec<Model>, RepositoryError> {
+        let mut models: Vec<Model> = self.lock().models.values().cloned().collect();
+        models.sort_by_key(|model| std::cmp::Reverse(model.added_at));
+        Ok(models)
+    }
+
+    async fn get_by_id(&self, id: i64) -> Result<Model, RepositoryError> {
+        self.lock()
+            .models
+            .get(&id)
+            .cloned()
+            .ok_or_else(|| RepositoryError::NotFound(format!("Model with ID {id}")))
+    }
+
+    async fn get_by_name(&self, name: &str) -> Result<Model, RepositoryError> {
+        self.lock()
+            .models
+            .values()
+            .find(|m| m.name == name)
+            .cloned()
+            .ok_or_else(|| RepositoryError::NotFound(format!("Model with name '{name}'")))
+    }
+
+    async fn insert(&self, model: &NewModel) -> Result<Model, RepositoryError> {
+        Ok(self.lock().upsert(model))
+    }
+
+    async fn update(&self, model: &Model) -> Result<(), RepositoryError> {
+        let mut state = self.lock();
+        let stored = state
+            .models
+            .get_mut(&model.id)
+            .ok_or_else(|| RepositoryError::NotFound(format!("Model with ID {}", model.id)))?;
+        let (model_key, benchmark_summary) =
+            (stored.model_key.clone(), stored.benchmark_summary.clone());
+        *stored = Model {
+            model_key,
+            benchmark_summary,
+            ..model.clone()
+        };
+        drop(state);
+        Ok(())
+    }
+
+    async fn delete(&self, id: i64) -> Result<(), RepositoryError> {
+        self.lock()
+            .models
+            .remove(&id)
+            .map(|_| ())
+            .ok_or_else(|| RepositoryError::NotFound(format!("Model with ID {id}")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use std::path::PathBuf;
+
+    fn downloaded(file: &str) -> NewModel {
+        let mut model = NewModel::new(
+            "Qwen3".to_string(),
+            PathBuf::from(format!("/models/{file}")),
+            8.0,
+            Utc::now(),
+        );
+        model.hf_repo_id = Some("Qwen/Qwen3-8B-GGUF".to_string());
+        model.hf_commit_sha = Some("abc".to_string());
+        model.hf_filename = Some(file.to_string());
+        model
+    }
+
+    #[tokio::test]
+    async fn shards_of_one_download_upsert_into_one_model() {
+        let repo = MemoryModelRepository::new();
+        let first = repo
+            .insert(&downloaded("q-00001-of-00002.gguf"))
+            .await
+            .unwrap();
+        let again = repo
+            .insert(&downloaded("q-00002-of-00002.gguf"))
+            .await
+            .unwrap();
+
+        assert_eq!(first.id, again.id);
+        assert_eq!(repo.list().await.unwrap().len(), 1);
+        assert_eq!(
+            repo.get_by_identifier("Qwen3").await.unwrap().unwrap().id,
+            first.id
+        );
+
+        repo.delete(first.id).await.unwrap();
+        assert!(matches!(
+            repo.get_by_id(first.id).await,
+            Err(RepositoryError::NotFound(_))
+        ));
+    }
+}
@@ -0,0 +1,361 @@
+//! Simulated [`ProcessRunner`].
+//!
+//! Each "server" is an HTTP listener on `127.0.0.1` inside the test process
+//! that answers the llama-server endpoints gglib calls — `/health`,
+//! `/props`, `/slots`, `/v1/models` and `/v1/chat/completions` — so
+//! health monitors and the proxy see a live upstream without a binary.
+
+use std::collections::{BTreeMap, HashMap};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+use axum::extract::State as Shared;
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use axum::routing::{get, post};
+use axum::{Json, Router};
+use serde_json::{Value, json};
+use tokio::net::TcpListener;
+use tokio_util::sync::CancellationToken;
+
+use gglib_core::ports::{ProcessError, ProcessHandle, ProcessRunner, ServerConfig, ServerHealth};
+use gglib_core::utils::clock::{Clock, SystemClock};
+
+/// Context size reported by servers started without one.
+const DEFAULT_CONTEXT_SIZE: u64 = 4096;
+
+/// Starts simulated llama-servers, one per model.
+///
+/// A server listens on `config.port` when one is set, otherwise on a free
+/// port (not `base_port`, so tests running in parallel never collide). Its
+/// handle carries the test process's own pid, so liveness checks pass until
+/// it is stopped or [`crash`](Self::crash)ed.
+#[derive(Debug)]
+pub struct SimulatedProcessRunner {
+    reply: String,
+    state: Mutex<State>,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    servers: BTreeMap<i64, Server>,
+    start_failures: HashMap<i64, String>,
+    starts: Vec<ServerConfig>,
+}
+
+#[derive(Debug)]
+struct Server {
+    handle: ProcessHandle,
+    shutdown: CancellationToken,
+    shared: Arc<Upstream>,
+}
+
+/// What a running server's handlers share.
+#[derive(Debug)]
+struct Upstream {
+    model_name: String,
+    context_size: u64,
+    reply: String,
+    healthy: AtomicBool,
+    completions: AtomicUsize,
+}
+
+impl SimulatedProcessRunner {
+    pub fn new() -> Self {
+        Self::with_reply("Hello from a simulated server.")
+    }
+
+    /// A runner whose servers answer every chat completion with `reply`.
+    pub fn with_reply(reply: impl Into<String>) -> Self {
+        Self {
+            reply: reply.into(),
+            state: Mutex::default(),
+        }
+    }
+
+    /// Make the next start of `model_id` fail with `message`.
+    pub fn fail_start(&self, model_id: i64, message: impl Into<String>) {
+        self.lock().start_failures.insert(model_id, message.into());
+    }
+
+    /// Make `model_id`'s server report itself healthy or not, on `/health`
+    /// and through [`ProcessRunner::health`].
+    pub fn set_healthy(&self, model_id: i64, healthy: bool) {
+        if let Some(server) = self.lock().servers.get(&model_id) {
+            server.shared.healthy.store(healthy, Ordering::SeqCst);
+        }
+    }
+
+    /// End `model_id`'s server as if the process had died: its port closes
+    /// and it is no longer running, without a call to `stop`.
+    pub fn crash(&self, model_id: i64) {
+        let server = self.lock().servers.remove(&model_id);
+        if let Some(server) = server {
+            server.shutdown.cancel();
+        }
+    }
+
+    /// Configurations passed to `start`, including failed starts.
+    pub fn starts(&self) -> Vec<ServerConfig> {
+        self.lock().starts.clone()
+    }
+
+    /// Chat completions `model_id`'s current server has answered.
+    pub fn completions(&self, model_id: i64) -> usize {
+        self.lock()
+            .servers
+            .get(&model_id)
+            .map_or(0, |server| server.shared.completions.load(Ordering::SeqCst))
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, State> {
+        self.state
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+
+    fn running(&self, handle: &ProcessHandle) -> Option<Arc<Upstream>> {
+        self.lock()
+            .servers
+            .get(&handle.model_id)
+            .filter(|server| server.handle.port == handle.port)
+            .map(|server| Arc::clone(&server.shared))
+    }
+}
+
+impl Default for SimulatedProcessRunner {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl ProcessRunner for SimulatedProcessRunner {
+    async fn start(&self, config: ServerConfig) -> Result<ProcessHandle, ProcessError> {
+        {
+            let mut state = self.lock();
+            state.starts.push(config.clone());
+            if let Some(message) = state.start_failures.remove(&config.model_id) {
+                return Err(ProcessError::StartFailed(message));
+            }
+            if state.servers.contains_key(&config.model_id) {
+                return Err(ProcessError::StartFailed(format!(
+                    "Model {} is already running",
+                    config.model_id
+                )));
+            }
+        }
+
+        let listener = TcpListener::bind(("127.0.0.1", config.port.unwrap_or(0)))
+            .await
+            .map_err(|e| ProcessError::StartFailed(e.to_string()))?;
+        let port = listener
+            .local_addr()
+            .map_err(|e| ProcessError::StartFailed(e.to_string()))?
+            .port();
+
+        let shared = Arc::new(Upstream {
+            model_name: config.model_name.clone(),
+            context_size: config.context_size.unwrap_or(DEFAULT_CONTEXT_SIZE),
+            reply: self.reply.clone(),
+            healthy: AtomicBool::new(true),
+            completions: AtomicUsize::new(0),
+        });
+        let shutdown = CancellationToken::new();
+        let app = router(Arc::clone(&shared));
+        let stopped = shutdown.clone().cancelled_owned();
+        tokio::spawn(async move {
+            let _ = axum::serve(listener, app)
+                .with_graceful_shutdown(stopped)
+                .await;
+        });
+
+        let handle = ProcessHandle::new(
+            config.model_id,
+            config.model_name,
+            Some(std::process::id()),
+            port,
+            SystemClock.unix_secs(),
+        );
+        self.lock().servers.insert(
+            handle.model_id,
+            Server {
+                handle: handle.clone(),
+                shutdown,
+                shared,
+            },
+        );
+        Ok(handle)
+    }
+
+    async fn stop(&self, handle: &ProcessHandle) -> Result<(), ProcessError> {
+        let server = self
+            .lock()
+            .servers
+            .remove(&handle.model_id)
+            .ok_or_else(|| {
+                ProcessError::NotRunning(format!("Model {} is not running", handle.model_id))
+            })?;
+        server.shutdown.cancel();
+        Ok(())
+    }
+
+    async fn is_running(&self, handle: &ProcessHandle) -> bool {
+        self.running(handle).is_some()
+    }
+
+    async fn health(&self, handle: &ProcessHandle) -> Result<ServerHealth, ProcessError> {
+        let shared = self.running(handle).ok_or_else(|| {
+            ProcessError::NotRunning(format!("Model {} is not running", handle.model_id))
+        })?;
+        Ok(if shared.healthy.load(Ordering::SeqCst) {
+            ServerHealth::healthy().with_context_size(shared.context_size)
+        } else {
+            ServerHealth::unhealthy("simulated failure")
+        })
+    }
+
+    async fn list_running(&self) -> Result<Vec<ProcessHandle>, ProcessError> {
+        Ok(self
+            .lock()
+            .servers
+            .values()
+            .map(|server| server.handle.clone())
+            .collect())
+    }
+}
+
+fn router(shared: Arc<Upstream>) -> Router {
+    Router::new()
+        .route("/health", get(health))
+        .route("/props", get(props))
+        .route("/slots", get(slots))
+        .route("/v1/models", get(models))
+        .route("/v1/chat/completions", post(chat_completions))
+        .with_state(shared)
+}
+
+async fn health(Shared(upstream): Shared<Arc<Upstream>>) -> Response {
+    if upstream.healthy.load(Ordering::SeqCst) {
+        Json(json!({ "status": "ok" })).into_response()
+    } else {
+        (
+            StatusCode::SERVICE_UNAVAILABLE,
+            Json(json!({ "error": { "message": "simulated failure" } })),
+        )
+            .into_response()
+    }
+}
+
+async fn props(Shared(upstream): Shared<Arc<Upstream>>) -> Json<Value> {
+    Json(json!({
+        "default_generation_settings": { "n_ctx": upstream.context_size },
+        "total_slots": 1,
+        "model_path": upstream.model_name,
+    }))
+}
+
+async fn slots(Shared(upstream): Shared<Arc<Upstream>>) -> Json<Value> {
+    Json(json!([{ "id": 0, "n_ctx": upstream.context_size, "is_processing": false }]))
+}
+
+async fn models(Shared(upstream): Shared<Arc<Upstream>>) -> Json<Value> {
+    Json(json!({
+        "object": "list",
+        "data": [{ "id": upstream.model_name, "object": "model", "owned_by": "gglib" }],
+    }))
+}
+
+/// Answer with the scripted reply, streamed as SSE when the request asks.
+async fn chat_completions(
+    Shared(upstream): Shared<Arc<Upstream>>,
+    Json(request): Json<Value>,
+) -> Response {
+    upstream.completions.fetch_add(1, Ordering::SeqCst);
+    let model = upstream.model_name.as_str();
+
+    if request.get("stream").and_then(Value::as_bool) == Some(true) {
+        let chunk = |delta: Value, finish: Value| {
+            json!({
+                "id": "chatcmpl-simulated",
+                "object": "chat.completion.chunk",
+                "model": model,
+                "choices": [{ "index": 0, "delta": delta, "finish_reason": finish }],
+            })
+        };
+        let body = format!(
+            "data: {}\n\ndata: {}\n\ndata: [DONE]\n\n",
+            chunk(
+                json!({ "role": "assistant", "content": upstream.reply }),
+                Value::Null
+            ),
+            chunk(json!({}), json!("stop")),
+        );
+        return ([("content-type", "text/event-stream")], body).into_response();
+    }
+
+    Json(json!({
+        "id": "chatcmpl-simulated",
+        "object": "chat.completion",
+        "model": model,
+        "choices": [{
+            "index": 0,
+            "message": { "role": "assistant", "content": upstream.reply },
+            "finish_reason": "stop",
+        }],
+        "usage": { "prompt_tokens": 1, "completion_tokens": 1, "total_tokens": 2 },
+    }))
+    .into_response()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn config(model_id: i64) -> ServerConfig {
+        ServerConfig::new(
+            model_id,
+            format!("model-{model_id}"),
+            PathBuf::from("/models/m.gguf"),
+            9000,
+        )
+    }
+
+    async fn get_status(port: u16, path: &str) -> u16 {
+        reqwest::get(format!("http://127.0.0.1:{port}{path}"))
+            .await
+            .map_or(0, |r| r.status().as_u16())
+    }
+
+    #[tokio::test]
+    async fn servers_answer_over_http_until_stopped() {
+        let runner = SimulatedProcessRunner::new();
+        let handle = runner.start(config(1)).await.unwrap();
+        assert_eq!(get_status(handle.port, "/health").await, 200);
+        assert!(runner.health(&handle).await.unwrap().healthy);
+
+        runner.set_healthy(1, false);
+        assert_eq!(get_status(handle.port, "/health").await, 503);
+
+        runner.stop(&handle).await.unwrap();
+        assert!(!runner.is_running(&handle).await);
+        assert!(runner.list_running().await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn scripted_start_failures_happen_once() {
+        let runner = SimulatedProcessRunner::new();
+        runner.fail_start(2, "llama-server binary not found");
+
+        assert!(matches!(
+            runner.start(config(2)).await,
+            Err(ProcessError::StartFailed(_))
+        ));
+        let handle = runner.start(config(2)).await.unwrap();
+        runner.crash(2);
+        assert!(!runner.is_running(&handle).await);
+        assert_eq!(runner.starts().len(), 2);
+    }
+}
@@ -0,0 +1,107 @@
+//! [`ModelRuntimePort`] over any [`ProcessRunner`].
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use tokio::sync::Mutex;
+
+use gglib_core::ports::{
+    ModelRepository, ModelRuntimeError, ModelRuntimePort, ProcessHandle, ProcessRunner,
+    RunningTarget, ServerConfig,
+};
+
+/// Serves one model at a time, the way the proxy's single-swap runtime does.
+///
+/// Models are resolved from a repository and started through a runner, so
+/// the proxy can be driven against a
+/// [`SimulatedProcessRunner`](crate::SimulatedProcessRunner).
+///
+/// A request for the running model at the same context reuses it; any
+/// other request stops it and starts the new one.
+pub struct SimulatedRuntime {
+    models: Arc<dyn ModelRepository>,
+    runner: Arc<dyn ProcessRunner>,
+    current: Mutex<Option<(ProcessHandle, RunningTarget)>>,
+}
+
+impl SimulatedRuntime {
+    pub fn new(models: Arc<dyn ModelRepository>, runner: Arc<dyn ProcessRunner>) -> Self {
+        Self {
+            models,
+            runner,
+            current: Mutex::new(None),
+        }
+    }
+}
+
+impl std::fmt::Debug for SimulatedRuntime {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SimulatedRuntime").finish_non_exhaustive()
+    }
+}
+
+#[async_trait]
+impl ModelRuntimePort for SimulatedRuntime {
+    async fn ensure_model_running(
+        &self,
+        model_name: &str,
+        num_ctx: Option<u64>,
+        default_ctx: u64,
+    ) -> Result<RunningTarget, ModelRuntimeError> {
+        let model = self
+            .models
+            .get_by_identifier(model_name)
+            .await
+            .map_err(|e| ModelRuntimeError::Internal(e.to_string()))?
+            .ok_or_else(|| ModelRuntimeError::ModelNotFound(model_name.to_string()))?;
+        let ctx = num_ctx.unwrap_or(default_ctx);
+
+        let mut current = self.current.lock().await;
+        if let Some((handle, target)) = current.as_ref()
+            && handle.model_id == model.id
+            && target.effective_ctx == ctx
+            && self.runner.is_running(handle).await
+        {
+            return Ok(RunningTarget {
+                just_started: false,
+                ..target.clone()
+            });
+        }
+        if let Some((handle, _)) = current.take() {
+            let _ = self.runner.stop(&handle).await;
+        }
+
+        let config = ServerConfig::new(model.id, model.name.clone(), model.file_path, 0)
+            .with_context_size(ctx);
+        let handle = self
+            .runner
+            .start(config)
+            .await
+            .map_err(|e| ModelRuntimeError::SpawnFailed(e.to_string()))?;
+        let model_id = u32::try_from(model.id).map_err(|_| {
+            ModelRuntimeError::Internal(format!("model id {} out of range", model.id))
+        })?;
+        let target = RunningTarget::local(handle.port, model_id, model.name, ctx, true);
+        *current = Some((handle, target.clone()));
+        drop(current);
+        Ok(target)
+    }
+
+    async fn current_model(&self) -> Option<RunningTarget> {
+        self.current
+            .lock()
+            .await
+            .as_ref()
+            .map(|(_, target)| target.clone())
+    }
+
+    async fn stop_current(&self) -> Result<(), ModelRuntimeError> {
+        let Some((handle, _)) = self.current.lock().await.take() else {
+            return Ok(());
+        };
+        self.runner
+            .stop(&handle)
+            .await
+            .map_err(|e| ModelRuntimeError::Internal(e.to_string()))
+    }
+}
@@ -0,0 +1,87 @@
+//! In-memory [`SettingsRepository`].
+
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use async_trait::async_trait;
+use gglib_core::{RepositoryError, Settings, SettingsRepository};
+
+/// Settings held in memory. Loads return the defaults until something is
+/// saved, as with an empty database. The version is the save count.
+#[derive(Debug)]
+pub struct MemorySettingsRepository {
+    settings: Mutex<Settings>,
+    saves: AtomicUsize,
+}
+
+impl MemorySettingsRepository {
+    pub const fn new() -> Self {
+        Self::with_settings(Settings::with_defaults())
+    }
+
+    /// A repository whose stored settings start as `settings`.
+    pub const fn with_settings(settings: Settings) -> Self {
+        Self {
+            settings: Mutex::new(settings),
+            saves: AtomicUsize::new(0),
+        }
+    }
+
+    /// How many times the settings have been saved.
+    pub fn save_count(&self) -> usize {
+        self.saves.load(Ordering::SeqCst)
+    }
+}
+
+impl Default for MemorySettingsRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl SettingsRepository for MemorySettingsRepository {
+    async fn load(&self) -> Result<Settings, RepositoryError> {
+        Ok(self
+            .settings
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .clone())
+    }
+
+    async fn save(&self, settings: &Settings) -> Result<(), RepositoryError> {
+        let mut stored = self
+            .settings
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        *stored = settings.clone();
+        self.saves.fetch_add(1, Ordering::SeqCst);
+        drop(stored);
+        Ok(())
+    }
+
+    async fn version(&self) -> Result<u64, RepositoryError> {
+        Ok(self.save_count() as u64)
+    }
+
+    async fn save_if_version(
+        &self,
+        settings: &Settings,
+        expected: u64,
+    ) -> Result<u64, RepositoryError> {
+        let mut stored = self
+            .settings
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        let current = self.saves.load(Ordering::SeqCst) as u64;
+        if current != expected {
+            return Err(RepositoryError::Conflict(format!(
+                "settings are at version {current}, expected {expected}"
+            )));
+        }
+        *stored = settings.clone();
+        let version = self.saves.fetch_add(1, Ordering::SeqCst) as u64 + 1;
+        drop(stored);
+        Ok(version)
+    }
+}
@@ -0,0 +1,234 @@
+//! Stand-ins for the repositories `AppCore` requires but model, server and
+//! download tests never touch: MCP servers, chat history, projects,
+//! presets and pipeline runs.
+//!
+//! [`Unsupported`] behaves like an empty store that refuses writes: lists
+//! are empty, lookups find nothing, and anything that would create or
+//! change a record fails, so a test that strays into these areas fails
+//! loudly instead of passing against state it never set up.
+
+use async_trait::async_trait;
+use gglib_core::domain::chat::{
+    Conversation, ConversationUpdate, Message, MessageRevision, NewConversation, NewMessage,
+};
+use gglib_core::domain::mcp::{McpServer, NewMcpServer};
+use gglib_core::domain::pipeline::{NewPipelineRun, PipelineRun, PipelineRunStatus};
+use gglib_core::domain::preset::{NewPreset, Preset, PresetUpdate};
+use gglib_core::domain::project::{NewProject, Project, ProjectUpdate};
+use gglib_core::ports::{
+    ChatHistoryError, ChatHistoryRepository, McpRepositoryError, McpServerRepository,
+    PipelineError, PipelineRunRepository, PresetError, PresetRepository, ProjectError,
+    ProjectRepository,
+};
+
+const REFUSED: &str = "not supported by the in-memory test fakes";
+
+/// An empty, read-only store for every repository without an in-memory fake.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Unsupported;
+
+#[async_trait]
+impl McpServerRepository for Unsupported {
+    async fn insert(&self, _server: NewMcpServer) -> Result<McpServer, McpRepositoryError> {
+        Err(McpRepositoryError::Internal(REFUSED.to_string()))
+    }
+
+    async fn get_by_id(&self, id: i64) -> Result<McpServer, McpRepositoryError> {
+        Err(McpRepositoryError::NotFound(id.to_string()))
+    }
+
+    async fn get_by_name(&self, name: &str) -> Result<McpServer, McpRepositoryError> {
+        Err(McpRepositoryError::NotFound(name.to_string()))
+    }
+
+    async fn list(&self) -> Result<Vec<McpServer>, McpRepositoryError> {
+        Ok(vec![])
+    }
+
+    async fn update(&self, server: &McpServer) -> Result<(), McpRepositoryError> {
+        Err(McpRepositoryError::NotFound(server.id.to_string()))
+    }
+
+    async fn delete(&self, id: i64) -> Result<(), McpRepositoryError> {
+        Err(McpRepositoryError::NotFound(id.to_string()))
+    }
+
+    async fn update_last_connected(&self, id: i64) -> Result<(), McpRepositoryError> {
+        Err(McpRepositoryError::NotFound(id.to_string()))
+    }
+}
+
+#[async_trait]
+impl ChatHistoryRepository for Unsupported {
+    async fn create_conversation(&self, _conv: NewConversation) -> Result<i64, ChatHistoryError> {
+        Err(ChatHistoryError::Database(REFUSED.to_string()))
+    }
+
+    async fn list_conversations(&self) -> Result<Vec<Conversation>, ChatHistoryError> {
+        Ok(vec![])
+    }
+
+    async fn get_conversation(&self, _id: i64) -> Result<Option<Conversation>, ChatHistoryError> {
+        Ok(None)
+    }
+
+    async fn update_conversation(
+        &self,
+        id: i64,
+        _update: ConversationUpdate,
+    ) -> Result<(), ChatHistoryError> {
+        Err(ChatHistoryError::ConversationNotFound(id))
+    }
+
+    async fn delete_conversation(&self, id: i64) -> Result<(), ChatHistoryError> {
+        Err(ChatHistoryError::ConversationNotFound(id))
+    }
+
+    async fn get_conversation_count(&self) -> Result<i64, ChatHistoryError> {
+        Ok(0)
+    }
+
+    async fn get_messages(&self, _conversation_id: i64) -> Result<Vec<Message>, ChatHistoryError> {
+        Ok(vec![])
+    }
+
+    async fn get_message(&self, _id: i64) -> Result<Option<Message>, ChatHistoryError> {
+        Ok(None)
+    }
+
+    async fn save_message(&self, msg: NewMessage) -> Result<i64, ChatHistoryError> {
+        Err(ChatHistoryError::ConversationNotFound(msg.conversation_id))
+    }
+
+    async fn update_message(
+        &self,
+        id: i64,
+        _content: String,
+        _metadata: Option<serde_json::Value>,
+    ) -> Result<(), ChatHistoryError> {
+        Err(ChatHistoryError::MessageNotFound(id))
+    }
+
+    async fn delete_message_and_subsequent(&self, id: i64) -> Result<i64, ChatHistoryError> {
+        Err(ChatHistoryError::MessageNotFound(id))
+    }
+
+    async fn supersede_message(
+        &self,
+        id: i64,
+        _content: String,
+        _metadata: Option<serde_json::Value>,
+    ) -> Result<MessageRevision, ChatHistoryError> {
+        Err(ChatHistoryError::MessageNotFound(id))
+    }
+
+    async fn get_message_versions(&self, id: i64) -> Result<Vec<Message>, ChatHistoryError> {
+        Err(ChatHistoryError::MessageNotFound(id))
+    }
+
+    async fn get_message_count(&self, _conversation_id: i64) -> Result<i64, ChatHistoryError> {
+        Ok(0)
+    }
+}
+
+#[async_trait]
+impl ProjectRepository for Unsupported {
+    async fn create(&self, _project: NewProject) -> Result<i64, ProjectError> {
+        Err(ProjectError::Database(REFUSED.to_string()))
+    }
+
+    async fn list(&self) -> Result<Vec<Project>, ProjectError> {
+        Ok(vec![])
+    }
+
+    async fn get(&self, _id: i64) -> Result<Option<Project>, ProjectError> {
+        Ok(None)
+    }
+
+    async fn update(&self, id: i64, _update: ProjectUpdate) -> Result<(), ProjectError> {
+        Err(ProjectError::NotFound(id))
+    }
+
+    async fn delete(&self, id: i64) -> Result<(), ProjectError> {
+        Err(ProjectError::NotFound(id))
+    }
+
+    async fn link_conversation(
+        &self,
+        conversation_id: i64,
+        _project_id: i64,
+    ) -> Result<(), ProjectError> {
+        Err(ProjectError::ConversationNotFound(conversation_id))
+    }
+
+    async fn unlink_conversation(&self, conversation_id: i64) -> Result<(), ProjectError> {
+        Err(ProjectError::ConversationNotFound(conversation_id))
+    }
+
+    async fn get_for_conversation(
+        &self,
+        _conversation_id: i64,
+    ) -> Result<Option<Project>, ProjectError> {
+        Ok(None)
+    }
+}
+
+#[async_trait]
+impl PresetRepository for Unsupported {
+    async fn create(&self, _preset: NewPreset) -> Result<i64, PresetError> {
+        Err(PresetError::Database(REFUSED.to_string()))
+    }
+
+    async fn list(&self) -> Result<Vec<Preset>, PresetError> {
+        Ok(vec![])
+    }
+
+    async fn get(&self, _id: i64) -> Result<Option<Preset>, PresetError> {
+        Ok(None)
+    }
+
+    async fn get_by_name(&self, _name: &str) -> Result<Option<Preset>, PresetError> {
+        Ok(None)
+    }
+
+    async fn update(&self, id: i64, _update: PresetUpdate) -> Result<(), PresetError> {
+        Err(PresetError::NotFound(id))
+    }
+
+    async fn delete(&self, id: i64) -> Result<(), PresetError> {
+        Err(PresetError::NotFound(id))
+    }
+}
+
+#[async_trait]
+impl PipelineRunRepository for Unsupported {
+    async fn create_run(&self, _run: NewPipelineRun) -> Result<i64, PipelineError> {
+        Err(PipelineError::Database(REFUSED.to_string()))
+    }
+
+    async fn record_step(
+        &self,
+        run_id: i64,
+        _step_id: &str,
+        _output: &str,
+    ) -> Result<(), PipelineError> {
+        Err(PipelineError::NotFound(run_id))
+    }
+
+    async fn finish_run(
+        &self,
+        run_id: i64,
+        _status: PipelineRunStatus,
+        _error: Option<&str>,
+    ) -> Result<(), PipelineError> {
+        Err(PipelineError::NotFound(run_id))
+    }
+
+    async fn get(&self, _id: i64) -> Result<Option<PipelineRun>, PipelineError> {
+        Ok(None)
+    }
+
+    async fn list(&self) -> Result<Vec<PipelineRun>, PipelineError> {
+        Ok(vec![])
+    }
+}
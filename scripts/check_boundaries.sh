@@ -150,6 +150,18 @@ main() {
     fi
     log ""
     
+    log "📦 gglib-plugins (WASM tool host - no adapters)"
+    if ! check_crate_deps "gglib-plugins" "${DOMAIN_FORBIDDEN[@]}"; then
+        FAILED=1
+    fi
+    log ""
+    
+    log "📦 gglib-secrets (keyring / encrypted-file secret store - no adapters)"
+    if ! check_crate_deps "gglib-secrets" "${DOMAIN_FORBIDDEN[@]}"; then
+        FAILED=1
+    fi
+    log ""
+    
     log "📦 gglib-agent (pure domain agentic loop - no adapters, no infra crates)"
     if ! check_crate_deps "gglib-agent" "${DOMAIN_FORBIDDEN[@]}"; then
         FAILED=1
@@ -173,6 +185,16 @@ main() {
     fi
     log ""
 
+    # gglib-test-support: in-memory port fakes. Like gglib-sse it serves HTTP
+    # through axum (simulated llama-servers), but it must stay on gglib-core
+    # alone so tests built on it never pull in SQLite or a real adapter.
+    TEST_SUPPORT_FORBIDDEN=(clap tauri sqlx tower-http gglib-db gglib-runtime gglib-hf)
+    log "📦 gglib-test-support (in-memory fakes - gglib-core only)"
+    if ! check_crate_deps "gglib-test-support" "${TEST_SUPPORT_FORBIDDEN[@]}"; then
+        FAILED=1
+    fi
+    log ""
+
     # Source-level guards: shared composition root (gglib-bootstrap) is the
     # ONLY place where the following infrastructure entry points may be
     # called from adapters. This prevents Phase 1/2/3 of #458 from regressing.
@@ -2,10 +2,12 @@
 
 use std::sync::Arc;
 
-use gglib_app_services::{DownloadOps, ServerOps};
+use gglib_app_services::{DownloadOps, LogOps, ServerOps, WarmStartOps};
 use gglib_axum::EmbeddedApiInfo;
+use gglib_core::services::AppCore;
 use tauri::async_runtime::JoinHandle;
 use tokio::sync::RwLock;
+use tokio_util::sync::CancellationToken;
 
 use crate::menu::AppMenu;
 
@@ -14,10 +16,16 @@ use crate::menu::AppMenu;
 /// This struct is managed by Tauri and accessible to all commands
 /// via `tauri::State<'_, AppState>`.
 pub struct AppState {
+    /// Core services, for the shutdown scrub of incognito chats.
+    pub core: Arc<AppCore>,
     /// Server lifecycle operations.
     pub servers: Arc<ServerOps>,
     /// Download queue operations.
     pub downloads: Arc<DownloadOps>,
+    /// Startup model launch and post-upgrade reconciliation.
+    pub warm_start: Arc<WarmStartOps>,
+    /// Application log file queries.
+    pub logs: Arc<LogOps>,
     /// Embedded API server info (port and auth token)
     pub embedded_api: EmbeddedApiInfo,
     /// Menu state for dynamic updates
@@ -30,6 +38,8 @@ pub struct AppState {
     pub proxy_port: Arc<RwLock<Option<u16>>>,
     /// Background task handles for proper cleanup
     pub background_tasks: Arc<RwLock<BackgroundTasks>>,
+    /// Cancel token of the llama.cpp source build in progress, if any
+    pub llama_build: Arc<RwLock<Option<CancellationToken>>>,
 }
 
 /// Background task handles that need to be aborted on shutdown.
@@ -43,13 +53,19 @@ pub struct BackgroundTasks {
 impl AppState {
     /// Create a new application state.
     pub fn new(
+        core: Arc<AppCore>,
         servers: Arc<ServerOps>,
         downloads: Arc<DownloadOps>,
+        warm_start: Arc<WarmStartOps>,
+        logs: Arc<LogOps>,
         embedded_api: EmbeddedApiInfo,
     ) -> Self {
         Self {
+            core,
             servers,
             downloads,
+            warm_start,
+            logs,
             embedded_api,
             menu: Arc::new(RwLock::new(None)),
             selected_model_id: Arc::new(RwLock::new(None)),
@@ -59,6 +75,7 @@ impl AppState {
                 embedded_server: None,
                 log_emitter: None,
             })),
+            llama_build: Arc::new(RwLock::new(None)),
         }
     }
 }
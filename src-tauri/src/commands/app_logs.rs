@@ -1,7 +1,10 @@
 //! Application log commands.
 //!
-//! Commands for bridging frontend logs to Rust tracing infrastructure.
+//! Commands for bridging frontend logs to Rust tracing infrastructure, and
+//! for reading the rotating log files back in the desktop log viewer.
 //! Frontend logs are mapped to tracing events with the target "gglib.frontend.*".
+use crate::app::AppState;
+use gglib_app_services::types::{LogFile, LogPage, LogQuery};
 use serde::Deserialize;
 
 /// Frontend log entry structure.
@@ -80,3 +83,24 @@ pub fn log_from_frontend(entry: FrontendLogEntry) -> Result<(), String> {
 
     Ok(())
 }
+
+/// Query one page of application log records.
+///
+/// TRANSPORT_EXCEPTION: The desktop log viewer reads the log files directly.
+/// Web mode uses the HTTP API (`GET /api/logs`). Follow the tail by polling
+/// with `after` set to the previous page's `latest` cursor.
+#[tauri::command]
+pub async fn query_app_logs(
+    query: LogQuery,
+    state: tauri::State<'_, AppState>,
+) -> Result<LogPage, String> {
+    state.logs.query(query).await.map_err(|e| e.to_string())
+}
+
+/// List the application log files, oldest first.
+///
+/// TRANSPORT_EXCEPTION: Web mode uses `GET /api/logs/files`.
+#[tauri::command]
+pub async fn list_app_log_files(state: tauri::State<'_, AppState>) -> Result<Vec<LogFile>, String> {
+    state.logs.list_files().await.map_err(|e| e.to_string())
+}
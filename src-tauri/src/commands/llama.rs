@@ -1,17 +1,19 @@
 //! llama.cpp installation and status commands.
 
+use crate::app::AppState;
 use crate::app::events::{emit_or_log, names};
 use gglib_core::download::{RateEstimator, format_duration, format_rate};
 use gglib_core::paths::{llama_cpp_dir, llama_server_path};
 use gglib_download::ProgressThrottle;
 use gglib_runtime::llama::{
-    BuildEvent, PrebuiltAvailability, check_llama_installed, check_prebuilt_availability,
-    detect_optimal_acceleration, download_prebuilt_binaries_with_boxed_callback,
-    run_llama_source_build,
+    BuildEvent, LlamaError, LlamaSource, PrebuiltAvailability, check_llama_installed,
+    check_prebuilt_availability, detect_optimal_acceleration,
+    download_prebuilt_binaries_with_boxed_callback, run_llama_source_build,
 };
 use std::sync::{Arc, Mutex};
 use std::time::Instant;
-use tauri::AppHandle;
+use tauri::{AppHandle, State};
+use tokio_util::sync::CancellationToken;
 
 /// Response for check_llama_status command.
 #[derive(serde::Serialize)]
@@ -39,33 +41,88 @@ pub struct LlamaInstallEvent {
 /// |------------------|---------------------------------------------------------|
 /// | `PhaseStarted`   | `{ "type": "phase_started", "phase": "..." }`          |
 /// | `Log`            | `{ "type": "log", "message": "..." }`                  |
-/// | `Progress`       | `{ "type": "progress", "current": N, "total": N }`     |
+/// | `Progress`       | `{ "type": "progress", "phase": "...", "current": N, "total": N, "target": "..." }` |
 /// | `PhaseCompleted` | `{ "type": "phase_completed", "phase": "..." }`        |
 /// | `Completed`      | `{ "type": "completed", "version": "...", "acceleration": "..." }` |
 /// | `Failed`         | `{ "type": "failed", "message": "..." }`               |
+/// | `Cancelled`      | `{ "type": "cancelled" }`                              |
 ///
-/// Returns an error string if path resolution, acceleration detection, or the
-/// build itself fails.
+/// Only one build runs at a time; [`cancel_llama_build`] stops it. A
+/// cancelled build resolves successfully — the `cancelled` event is the
+/// frontend's signal.
+///
+/// Returns an error string if a build is already running, or if path
+/// resolution, acceleration detection, or the build itself fails.
+#[tauri::command]
+pub async fn build_llama_from_source(
+    app: AppHandle,
+    state: State<'_, AppState>,
+) -> Result<(), String> {
+    let cancel = {
+        let mut running = state.llama_build.write().await;
+        if running.is_some() {
+            return Err("A llama.cpp build is already running".to_string());
+        }
+        let cancel = CancellationToken::new();
+        *running = Some(cancel.clone());
+        cancel
+    };
+
+    let result = run_build(&app, cancel).await;
+    *state.llama_build.write().await = None;
+
+    match result {
+        Ok(()) => {
+            reconcile_startup_models(&state).await;
+            Ok(())
+        }
+        Err(e) if LlamaError::is_cancellation(&e) => Ok(()),
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+/// Stop the source build started by [`build_llama_from_source`].
+///
+/// Kills the running git or cmake process tree. Returns `false` when no
+/// build is running.
 #[tauri::command]
-pub async fn build_llama_from_source(app: AppHandle) -> Result<(), String> {
+pub async fn cancel_llama_build(state: State<'_, AppState>) -> Result<bool, String> {
+    Ok(state
+        .llama_build
+        .read()
+        .await
+        .as_ref()
+        .map(CancellationToken::cancel)
+        .is_some())
+}
+
+/// Run the source build, forwarding its events to the WebView.
+async fn run_build(app: &AppHandle, cancel: CancellationToken) -> anyhow::Result<()> {
     let (tx, mut rx) = tokio::sync::mpsc::channel::<BuildEvent>(64);
 
-    let llama_dir = llama_cpp_dir().map_err(|e| e.to_string())?;
-    let server_path = llama_server_path().map_err(|e| e.to_string())?;
-    let acceleration = detect_optimal_acceleration().map_err(|e| e.to_string())?;
+    let llama_dir = llama_cpp_dir()?;
+    let server_path = llama_server_path()?;
+    let acceleration = detect_optimal_acceleration()?;
 
     let build_handle = tokio::spawn(async move {
-        run_llama_source_build(acceleration, llama_dir, server_path, tx).await
+        let source = LlamaSource::recorded();
+        run_llama_source_build(acceleration, source, llama_dir, server_path, tx, cancel).await
     });
 
     while let Some(event) = rx.recv().await {
-        emit_or_log(&app, names::LLAMA_BUILD_PROGRESS, event);
+        emit_or_log(app, names::LLAMA_BUILD_PROGRESS, event);
     }
 
     build_handle
         .await
-        .map_err(|e| format!("Build task panicked: {e}"))?
-        .map_err(|e| e.to_string())
+        .map_err(|e| anyhow::anyhow!("Build task panicked: {e}"))?
+}
+
+/// Relaunch running startup models on the freshly installed llama-server.
+async fn reconcile_startup_models(state: &AppState) {
+    if let Err(e) = state.warm_start.reconcile().await {
+        tracing::warn!(error = %e, "Failed to reconcile startup models after llama.cpp upgrade");
+    }
 }
 
 /// Check if llama.cpp is installed.
@@ -85,7 +142,7 @@ pub fn check_llama_status() -> Result<LlamaStatus, String> {
 
 /// Install llama.cpp by downloading pre-built binaries.
 #[tauri::command]
-pub async fn install_llama(app: AppHandle) -> Result<String, String> {
+pub async fn install_llama(app: AppHandle, state: State<'_, AppState>) -> Result<String, String> {
     // Check if pre-built binaries are available
     match check_prebuilt_availability() {
         PrebuiltAvailability::Available { description, .. } => {
@@ -172,6 +229,7 @@ pub async fn install_llama(app: AppHandle) -> Result<String, String> {
                             message: "llama.cpp installed successfully!".to_string(),
                         },
                     );
+                    reconcile_startup_models(&state).await;
                     Ok("llama.cpp installed successfully".to_string())
                 }
                 Err(e) => {
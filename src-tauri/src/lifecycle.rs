@@ -1,7 +1,8 @@
 //! Application lifecycle and shutdown orchestration.
 
 use crate::app::AppState;
-use gglib_runtime::pidfile::cleanup_orphaned_servers;
+use gglib_core::ports::ProcessRunner;
+use gglib_runtime::pidfile::{adopt_or_cleanup_orphaned_servers, cleanup_orphaned_servers};
 use std::time::Duration;
 use tracing::{error, info, warn};
 
@@ -74,6 +75,13 @@ async fn parallel_cleanup(state: &AppState) -> Result<(), String> {
         }
     }
 
+    // Incognito chats live only in memory; drop them before anything else
+    // so a slow server stop cannot leave them around.
+    let discarded = state.core.chat_history().discard_incognito();
+    if discarded > 0 {
+        info!("Discarded {} incognito conversations", discarded);
+    }
+
     // Run server stop and download cancel in parallel
     let (servers_result, _) = tokio::join!(state.servers.stop_all(), state.downloads.cancel_all());
 
@@ -83,10 +91,13 @@ async fn parallel_cleanup(state: &AppState) -> Result<(), String> {
 
 /// Perform startup cleanup of orphaned processes.
 ///
-/// Should be called early in the setup phase, before any servers are started.
-pub async fn startup_cleanup() {
+/// Servers a crashed session left running are adopted into `runner` when
+/// they are still healthy and serving an unchanged model file; the rest are
+/// killed. Should be called early in the setup phase, before any servers
+/// are started.
+pub async fn startup_cleanup(runner: &dyn ProcessRunner) {
     info!("Performing startup orphan cleanup");
-    if let Err(e) = cleanup_orphaned_servers().await {
+    if let Err(e) = adopt_or_cleanup_orphaned_servers(runner).await {
         tracing::warn!("Error during startup orphan cleanup: {}", e);
     }
 }
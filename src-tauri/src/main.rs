@@ -9,6 +9,10 @@ mod menu;
 use app::AppState;
 use app::events::{emit_or_log, names};
 use dotenvy::dotenv;
+use gglib_app_services::{
+    BackupDeps, BackupOps, LogDeps, LogOps, OnboardingDeps, OnboardingOps, PipelineDeps,
+    PipelineOps, PresetDeps, PresetOps, ProjectDeps, ProjectOps, SyncDeps, SyncOps,
+};
 use gglib_axum::embedded::{EmbeddedServerConfig, start_embedded_server};
 use gglib_download::cli_exec::preflight_fast_helper;
 use gglib_runtime::process::get_log_manager;
@@ -43,6 +47,37 @@ fn main() {
                 bootstrap(config, app_handle).await
             }).expect("Failed to bootstrap application");
 
+            let logs = Arc::new(LogOps::new(LogDeps {
+                log_dir: gglib_core::telemetry::log_dir(),
+            }));
+            let projects = Arc::new(ProjectOps::new(ProjectDeps {
+                core: ctx.app.clone(),
+            }));
+            let presets = Arc::new(PresetOps::new(PresetDeps {
+                core: ctx.app.clone(),
+            }));
+            let pipelines = Arc::new(PipelineOps::new(PipelineDeps {
+                core: ctx.app.clone(),
+            }));
+            let sync = Arc::new(SyncOps::new(SyncDeps {
+                core: ctx.app.clone(),
+                downloads: ctx.download_manager.clone(),
+                http: reqwest::Client::new(),
+            }));
+            let sse = Arc::new(gglib_axum::sse::SseBroadcaster::with_defaults());
+            let backup = Arc::new(BackupOps::new(BackupDeps {
+                core: ctx.app.clone(),
+                snapshot: ctx.db_snapshot.clone(),
+                emitter: sse.clone(),
+            }));
+            let onboarding = Arc::new(OnboardingOps::new(OnboardingDeps {
+                core: ctx.app.clone(),
+                setup: ctx.setup.clone(),
+                proxy: ctx.proxy.clone(),
+                downloads: ctx.download_manager.clone(),
+                emitter: sse.clone(),
+            }));
+
             // Build AxumContext for the embedded server using the 7 domain ops from ctx
             let axum_ctx = gglib_axum::AxumContext {
                 models: ctx.models.clone(),
@@ -52,11 +87,19 @@ fn main() {
                 mcp_ops: ctx.mcp_ops.clone(),
                 proxy: ctx.proxy.clone(),
                 setup: ctx.setup.clone(),
+                onboarding,
+                warm_start: ctx.warm_start.clone(),
+                logs: logs.clone(),
+                projects,
+                presets,
+                pipelines,
+                sync,
+                backup,
                 core: ctx.app.clone(),
                 mcp: ctx.mcp.clone(),
                 hf_client: ctx.hf_client.clone(),
                 runner: ctx.runner.clone(),
-                sse: Arc::new(gglib_axum::sse::SseBroadcaster::with_defaults()),
+                sse,
                 http_client: reqwest::Client::new(),
                 agent_semaphore: Arc::new(tokio::sync::Semaphore::new(4)),
                 approval_registry: ctx.approval_registry.clone(),
@@ -66,6 +109,7 @@ fn main() {
                 steering_note_queues: Arc::new(tokio::sync::Mutex::new(std::collections::HashMap::new())),
                 runtime: ctx.runtime.clone(),
                 catalog: ctx.catalog.clone(),
+                model_share_token: None,
             };
 
             // Start embedded API server with auth and ephemeral port
@@ -80,7 +124,28 @@ fn main() {
             });
 
             // Create and manage app state
-            let app_state = AppState::new(ctx.servers.clone(), ctx.downloads.clone(), embedded_api);
+            let app_state = AppState::new(
+                ctx.app.clone(),
+                ctx.servers.clone(),
+                ctx.downloads.clone(),
+                ctx.warm_start.clone(),
+                logs,
+                embedded_api,
+            );
+
+            // Adopt or clean up servers left by a previous session before
+            // startup models launch, so adopted ones count as running.
+            tauri::async_runtime::block_on(lifecycle::startup_cleanup(ctx.runner.as_ref()));
+
+            // Launch the configured startup models without blocking the window.
+            {
+                let warm_start = ctx.warm_start.clone();
+                tauri::async_runtime::spawn(async move {
+                    if let Err(e) = warm_start.warm_start().await {
+                        error!(error = %e, "Failed to launch startup models");
+                    }
+                });
+            }
 
             // Store the embedded server handle for cleanup
             {
@@ -119,9 +184,6 @@ fn main() {
                 });
             }
 
-            // Perform startup orphan cleanup
-            tauri::async_runtime::block_on(lifecycle::startup_cleanup());
-
             // Continue with rest of setup
             setup_app(app)?;
 
@@ -165,8 +227,12 @@ fn main() {
             commands::llama::check_llama_status,
             commands::llama::install_llama,
             commands::llama::build_llama_from_source,
+            commands::llama::cancel_llama_build,
             // Frontend logging: bridge to Rust tracing
             commands::app_logs::log_from_frontend,
+            // TRANSPORT_EXCEPTION: Desktop log viewer (web uses /api/logs)
+            commands::app_logs::query_app_logs,
+            commands::app_logs::list_app_log_files,
         ])
         .build(tauri::generate_context!())
         .expect("error while building tauri application")
@@ -34,6 +34,9 @@ function AppContent() {
     installing: llamaInstalling,
     installProgress,
     installLlama,
+    build: llamaBuild,
+    buildFromSource: buildLlamaFromSource,
+    cancelBuild: cancelLlamaBuild,
     checkStatus: checkLlamaStatus,
   } = useLlamaStatus();
 
@@ -73,14 +76,14 @@ function AppContent() {
 
   // Close modal when installation completes
   useEffect(() => {
-    if (installProgress?.status === 'completed') {
+    if (installProgress?.status === 'completed' || llamaBuild?.outcome === 'completed') {
       setTimeout(() => {
         setShowLlamaModal(false);
         // Sync menu state after llama installation
         syncMenuStateSilent();
       }, 2000);
     }
-  }, [installProgress?.status]);
+  }, [installProgress?.status, llamaBuild?.outcome]);
 
   // Menu event listeners (desktop only - via platform helper)
   useEffect(() => {
@@ -182,6 +185,9 @@ function AppContent() {
           error={llamaError}
           onInstall={installLlama}
           onSkip={() => setShowLlamaModal(false)}
+          build={llamaBuild}
+          onBuild={buildLlamaFromSource}
+          onCancelBuild={cancelLlamaBuild}
         />
         <ToastContainer toasts={toasts} onDismiss={dismissToast} />
       </div>
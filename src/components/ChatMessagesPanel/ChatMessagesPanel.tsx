@@ -162,7 +162,6 @@ const ChatMessagesPanel: React.FC<ChatMessagesPanelProps> = ({
     isGeneratingTitle,
     generateTitle,
   } = useTitleGeneration({
-    threadRuntime,
     activeConversation,
     activeConversationId,
     serverPort,
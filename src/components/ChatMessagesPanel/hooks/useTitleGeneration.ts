@@ -1,6 +1,5 @@
-import { useState, useEffect, useRef, useCallback } from 'react';
+import { useState, useEffect, useCallback } from 'react';
 import { useConfirmContext } from '../../../contexts/ConfirmContext';
-import type { ThreadRuntime } from '@assistant-ui/react';
 import { appLogger } from '../../../services/platform';
 import { getMessages, generateChatTitle } from '../../../services/clients/chat';
 import type { ConversationSummary } from '../../../services/clients/chat';
@@ -10,8 +9,6 @@ import type { ToastType } from '../../Toast';
  * Options for the useTitleGeneration hook.
  */
 export interface UseTitleGenerationOptions {
-  /** The thread runtime from @assistant-ui/react */
-  threadRuntime: ThreadRuntime | null;
   /** Currently active conversation */
   activeConversation: ConversationSummary | null;
   /** Currently active conversation ID */
@@ -54,11 +51,12 @@ export interface UseTitleGenerationResult {
  * Responsibilities:
  * - Manages title draft state for inline editing
  * - Handles rename commit/cancel
- * - Auto-generates title on first assistant response
  * - Manual AI title generation with confirmation
+ *
+ * New chats are titled automatically by the backend after their first
+ * exchange; this hook only covers renames and explicit regeneration.
  */
 export function useTitleGeneration({
-  threadRuntime,
   activeConversation,
   activeConversationId,
   serverPort,
@@ -69,7 +67,6 @@ export function useTitleGeneration({
   const [titleDraft, setTitleDraft] = useState('');
   const [isRenaming, setIsRenaming] = useState(false);
   const [isGeneratingTitle, setIsGeneratingTitle] = useState(false);
-  const hasAutoGeneratedTitleRef = useRef(false);
   const { confirm } = useConfirmContext();
 
   // Sync title draft with active conversation
@@ -82,7 +79,6 @@ export function useTitleGeneration({
   // Reset state when conversation changes
   useEffect(() => {
     setIsGeneratingTitle(false);
-    hasAutoGeneratedTitleRef.current = false;
   }, [activeConversationId]);
 
   const startRenaming = useCallback(() => {
@@ -152,62 +148,6 @@ export function useTitleGeneration({
     }
   }, [activeConversation, activeConversationId, serverPort, titleGenerationPrompt, onRenameConversation, showToast, confirm]);
 
-  // Auto-generate title on first assistant response.
-  // IMPORTANT: Only triggers for NEW in-session messages (not messages hydrated from DB).
-  // Hydrated messages have IDs prefixed with "db-" — we skip those to prevent
-  // spurious title generation on app reopen / conversation switch.
-  useEffect(() => {
-    if (!threadRuntime || !activeConversation || !activeConversationId || !serverPort) return;
-    if (hasAutoGeneratedTitleRef.current) return;
-
-    // Only auto-generate if title is still the default
-    const isDefaultTitle = activeConversation.title === 'New Chat' || !activeConversation.title;
-    if (!isDefaultTitle) {
-      hasAutoGeneratedTitleRef.current = true; // Don't try again for this conversation
-      return;
-    }
-
-    let timeoutId: ReturnType<typeof setTimeout> | null = null;
-    let cancelled = false;
-
-    const unsubscribe = threadRuntime.subscribe(() => {
-      if (cancelled || hasAutoGeneratedTitleRef.current) return;
-
-      const state = threadRuntime.getState();
-      
-      // Check if we have at least one completed assistant message that was
-      // created in THIS session (not hydrated from DB). DB-hydrated messages
-      // have IDs like "db-123" while new runtime messages have UUIDs.
-      const hasNewCompletedAssistantMessage = state.messages.some(
-        (msg) => msg.role === 'assistant' &&
-                 msg.status?.type === 'complete' &&
-                 !msg.id.startsWith('db-')
-      );
-
-      // Also need at least one user message for context
-      const hasUserMessage = state.messages.some((msg) => msg.role === 'user');
-
-      if (hasNewCompletedAssistantMessage && hasUserMessage) {
-        hasAutoGeneratedTitleRef.current = true;
-        
-        // Delay slightly to ensure message is persisted first
-        timeoutId = setTimeout(() => {
-          if (!cancelled) {
-            generateTitle(true); // Skip confirmation for auto-generate
-          }
-        }, 500);
-      }
-    });
-
-    return () => {
-      cancelled = true;
-      unsubscribe();
-      if (timeoutId !== null) {
-        clearTimeout(timeoutId);
-      }
-    };
-  }, [threadRuntime, activeConversation, activeConversationId, serverPort, generateTitle]);
-
   return {
     titleDraft,
     setTitleDraft,
@@ -136,6 +136,14 @@ const ConversationListPanel: FC<ConversationListPanelProps> = ({
                   <span className="font-medium text-text overflow-hidden text-ellipsis whitespace-nowrap">{conversation.title}</span>
                   <span className="text-sm text-text-muted">
                     {formatRelativeTime(conversation.updated_at)}
+                    {conversation.incognito && (
+                      <span
+                        className="ml-sm text-xs text-text-secondary bg-surface px-2 py-[2px] rounded-sm"
+                        title="Not saved — discarded when the app exits"
+                      >
+                        Incognito
+                      </span>
+                    )}
                   </span>
                 </Stack>
                 <button
@@ -15,6 +15,7 @@ export interface FilterState {
   contextRange: [number, number] | null;
   speedRange: [number, number] | null;
   selectedQuantizations: string[];
+  selectedLicenses: string[];
   selectedTags: string[];
 }
 
@@ -68,7 +69,7 @@ const formatSpeed = (value: number): string => `${value.toFixed(1)} t/s`;
 /**
  * Filter + sort popover for the model library.
  * Contains a sort section (field + direction) at the top, followed by range
- * sliders for params/context/speed and checkboxes for quantizations/tags.
+ * sliders for params/context/speed and checkboxes for quantizations/licenses/tags.
  * Clearing filters resets all filter controls but preserves the sort preference.
  */
 const FilterPopover: FC<FilterPopoverProps> = ({
@@ -89,6 +90,7 @@ const FilterPopover: FC<FilterPopoverProps> = ({
   const hasContextRange = filterOptions?.context_range != null;
   const hasSpeedRange = filterOptions?.speed_range != null;
   const hasQuantizations = filterOptions?.quantizations && filterOptions.quantizations.length > 0;
+  const hasLicenses = (filterOptions?.licenses?.length ?? 0) > 0;
   const hasTags = tags.length > 0;
 
   const paramRangeHasVariety = hasParamRange &&
@@ -110,6 +112,7 @@ const FilterPopover: FC<FilterPopoverProps> = ({
     filters.contextRange !== null ||
     filters.speedRange !== null ||
     filters.selectedQuantizations.length > 0 ||
+    filters.selectedLicenses.length > 0 ||
     filters.selectedTags.length > 0;
 
   const handleParamRangeChange = (min: number, max: number) => {
@@ -154,6 +157,16 @@ const FilterPopover: FC<FilterPopoverProps> = ({
     }
   };
 
+  const handleLicenseToggle = (license: string) => {
+    const selected = filters.selectedLicenses;
+    onFiltersChange({
+      ...filters,
+      selectedLicenses: selected.includes(license)
+        ? selected.filter(l => l !== license)
+        : [...selected, license],
+    });
+  };
+
   const handleTagToggle = (tag: string) => {
     const selected = filters.selectedTags;
     if (selected.includes(tag)) {
@@ -316,6 +329,26 @@ const FilterPopover: FC<FilterPopoverProps> = ({
           </div>
         )}
 
+        {/* Licenses */}
+        {hasLicenses && (
+          <div className="py-sm border-b border-border last:border-b-0">
+            <span className="block text-sm font-medium text-text mb-xs">License</span>
+            <Stack gap="xs" className="mt-xs">
+              {filterOptions!.licenses!.map(license => (
+                <label key={license} className="flex items-center gap-sm cursor-pointer py-[4px] hover:bg-surface-elevated hover:rounded-sm hover:mx-[-4px] hover:px-[4px]">
+                  <input
+                    type="checkbox"
+                    checked={filters.selectedLicenses.includes(license)}
+                    onChange={() => handleLicenseToggle(license)}
+                    className="w-[16px] h-[16px] accent-primary cursor-pointer"
+                  />
+                  <span className="text-sm text-text">{license}</span>
+                </label>
+              ))}
+            </Stack>
+          </div>
+        )}
+
         {/* Tags */}
         {hasTags && (
           <div className="py-sm border-b border-border last:border-b-0">
@@ -338,7 +371,7 @@ const FilterPopover: FC<FilterPopoverProps> = ({
         )}
 
         {/* Empty state — sort section is always visible so this only fires when truly nothing else is available */}
-        {!hasParamRange && !hasContextRange && !hasSpeedRange && !hasQuantizations && !hasTags && (
+        {!hasParamRange && !hasContextRange && !hasSpeedRange && !hasQuantizations && !hasLicenses && !hasTags && (
           <div className="flex flex-col items-center justify-center p-lg text-center">
             <span className="text-sm text-text-secondary">No filter options available</span>
             <span className="text-xs text-text-muted mt-xs">Add models to enable filtering</span>
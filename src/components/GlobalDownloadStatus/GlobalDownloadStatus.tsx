@@ -139,6 +139,10 @@ const GlobalDownloadStatus: FC<GlobalDownloadStatusProps> = ({
   // progress — shown verbatim since the message itself is the label.
   const phaseLabel = (() => {
     if (progress?.status === 'notice' && progress.message) return progress.message;
+    if (progress?.status === 'resolving') return 'Resolving';
+    if (progress?.status === 'verifying') {
+      return isSharded && shard ? `Verifying shard ${shard.index + 1}/${shard.total}` : 'Verifying';
+    }
     if (progress?.status === 'finalizing') return 'Finalizing';
     if (progress?.status === 'registering') return 'Registering';
     if (isSharded && shard) return `Downloading shard ${shard.index + 1}/${shard.total}`;
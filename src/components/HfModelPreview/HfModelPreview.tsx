@@ -18,6 +18,7 @@ import { openUrl } from '../../services/platform';
 import { formatBytes, formatNumber, getHuggingFaceModelUrl } from '../../utils/format';
 import { useSystemMemory } from '../../hooks/useSystemMemory';
 import { useSettings } from '../../hooks/useSettings';
+import { useConfirmContext } from '../../contexts/ConfirmContext';
 import { Icon } from '../ui/Icon';
 import { cn } from '../../utils/cn';
 
@@ -86,6 +87,8 @@ const HfModelPreview: FC<HfModelPreviewProps> = ({
   const { checkFit, getTooltip, loading: memoryLoading } = useSystemMemory();
   const { settings } = useSettings();
   const showFitIndicators = settings?.showMemoryFitIndicators ?? true;
+  const warnRestrictiveLicenses = settings?.warnRestrictiveLicenses ?? false;
+  const { confirm } = useConfirmContext();
 
   // Format last modified date
   const formatLastModified = (dateStr?: string | null): string => {
@@ -175,9 +178,17 @@ const HfModelPreview: FC<HfModelPreviewProps> = ({
     openUrl(url);
   }, [model.id]);
 
-  const handleDownload = useCallback((quant: HfQuantization) => {
+  const handleDownload = useCallback(async (quant: HfQuantization) => {
+    if (warnRestrictiveLicenses && model.restrictive_license) {
+      const confirmed = await confirm({
+        title: `Download ${model.name}?`,
+        description: `This model is licensed under ${model.license}, which restricts commercial use.`,
+        confirmLabel: 'Download',
+      });
+      if (!confirmed) return;
+    }
     onDownload(model.id, quant.name);
-  }, [model.id, onDownload]);
+  }, [model.id, model.name, model.license, model.restrictive_license, warnRestrictiveLicenses, confirm, onDownload]);
 
   // Build tooltip for tool support badge
   const getToolSupportTooltip = (): string => {
@@ -247,6 +258,17 @@ const HfModelPreview: FC<HfModelPreviewProps> = ({
               {formatLastModified(model.last_modified)}
             </span>
           )}
+          {model.license && (
+            <span
+              className={cn(
+                "px-sm py-xs rounded-base text-xs font-medium",
+                model.restrictive_license ? "bg-danger-subtle text-danger" : "bg-surface-elevated text-text-secondary"
+              )}
+              title={model.restrictive_license ? 'Restricts commercial use' : 'License'}
+            >
+              {model.license}
+            </span>
+          )}
         </div>
       </div>
 
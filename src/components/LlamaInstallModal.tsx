@@ -1,7 +1,7 @@
-import { FC, useState } from 'react';
-import { AlertCircle, AlertTriangle, CheckCircle2, Download, Loader2, XCircle } from 'lucide-react';
+import { FC, useEffect, useRef, useState } from 'react';
+import { AlertCircle, AlertTriangle, CheckCircle2, Download, Hammer, Loader2, XCircle } from 'lucide-react';
 import { appLogger } from '../services/platform';
-import { LlamaInstallProgress } from '../hooks/useLlamaStatus';
+import { LlamaBuildPhase, LlamaBuildState, LlamaInstallProgress } from '../hooks/useLlamaStatus';
 import { formatBytes } from '../utils/format';
 import { installLlama } from '../services/platform/llamaInstall';
 import { Button } from './ui/Button';
@@ -17,6 +17,11 @@ interface LlamaInstallModalProps {
   error?: string | null;
   onInstall?: () => void;
   onSkip?: () => void;
+  /** Source build state, when a build has been started. */
+  build?: LlamaBuildState | null;
+  /** Offer a source build when no prebuilt binary is available. */
+  onBuild?: () => void;
+  onCancelBuild?: () => void;
   // New props for error-triggered mode
   metadata?: {
     expectedPath: string;
@@ -36,6 +41,9 @@ export const LlamaInstallModal: FC<LlamaInstallModalProps> = ({
   error: propError = null,
   onInstall,
   onSkip,
+  build = null,
+  onBuild,
+  onCancelBuild,
   metadata,
   onClose,
   onInstalled,
@@ -48,8 +56,16 @@ export const LlamaInstallModal: FC<LlamaInstallModalProps> = ({
   const progress = propProgress;
   const error = metadata ? localError : propError;
 
-  const isCompleted = progress?.status === 'completed';
-  const isError = progress?.status === 'error';
+  const building = build?.outcome === 'running';
+  const isCompleted = progress?.status === 'completed' || build?.outcome === 'completed';
+  const isError = progress?.status === 'error' || build?.outcome === 'failed';
+  const canBuild = !canDownload && !!onBuild;
+
+  // Keep the newest build output in view.
+  const logRef = useRef<HTMLPreElement>(null);
+  useEffect(() => {
+    logRef.current?.scrollTo({ top: logRef.current.scrollHeight });
+  }, [build?.log.length]);
 
   // Error-triggered mode: handle installation
   const handleErrorModeInstall = async () => {
@@ -98,6 +114,46 @@ export const LlamaInstallModal: FC<LlamaInstallModalProps> = ({
       </div>
     );
   };
+  const renderBuild = () => {
+    if (!build) return null;
+
+    const percent = build.total > 0 ? Math.min(100, (build.current / build.total) * 100) : null;
+    const phase = build.phase ? BUILD_PHASE_LABELS[build.phase] : 'Starting build';
+
+    return (
+      <div className="flex flex-col gap-[0.35rem]">
+        {building ? (
+          <>
+            <div className="h-2 bg-background-tertiary rounded overflow-hidden">
+              <div
+                className={cn('h-full bg-gradient-to-r from-primary to-[#74c7ec] rounded transition-[width] duration-300', percent === null && 'w-[30%] animate-indeterminate')}
+                style={percent !== null ? { width: `${percent}%` } : undefined}
+              />
+            </div>
+            <div className="flex justify-between text-text-secondary text-[0.9rem]">
+              <span>{phase}{build.target ? ` — ${build.target}` : ''}</span>
+              {percent !== null && <span>{percent.toFixed(0)}%</span>}
+            </div>
+          </>
+        ) : null}
+        {build.outcome === 'cancelled' ? (
+          <div className="text-text-secondary text-[0.95rem]">Build cancelled.</div>
+        ) : null}
+        {build.outcome === 'failed' && build.message ? (
+          <div className="bg-danger-subtle border border-danger-border rounded-lg py-3 px-4 text-danger text-[0.95rem]">{build.message}</div>
+        ) : null}
+        {build.log.length > 0 ? (
+          <pre
+            ref={logRef}
+            className="m-0 max-h-48 overflow-auto bg-background-tertiary border border-border rounded-md py-2 px-3 font-mono text-[0.8rem] text-text-secondary whitespace-pre-wrap break-all"
+          >
+            {build.log.join('\n')}
+          </pre>
+        ) : null}
+      </div>
+    );
+  };
+
   const renderFooterContent = () => {
     if (metadata) {
       return (
@@ -125,6 +181,27 @@ export const LlamaInstallModal: FC<LlamaInstallModalProps> = ({
         </>
       );
     }
+    if (building) {
+      return (
+        <Button variant="ghost" onClick={onCancelBuild} leftIcon={<Icon icon={XCircle} size={16} />}>
+          Cancel build
+        </Button>
+      );
+    }
+    if (!installing && !isCompleted && canBuild) {
+      return (
+        <>
+          <Button onClick={onBuild} leftIcon={<Icon icon={Hammer} size={16} />}>
+            {build ? 'Build again' : 'Build from source'}
+          </Button>
+          {onSkip ? (
+            <Button variant="ghost" onClick={onSkip}>
+              Skip for now
+            </Button>
+          ) : null}
+        </>
+      );
+    }
     if (!installing && !isCompleted && !canDownload && onSkip) {
       return (
         <Button variant="ghost" onClick={onSkip}>
@@ -171,7 +248,7 @@ export const LlamaInstallModal: FC<LlamaInstallModalProps> = ({
           <Icon
             icon={isCompleted ? CheckCircle2 : isError ? XCircle : AlertCircle}
             size={28}
-            className={installing ? 'animate-pulse' : ''}
+            className={installing || building ? 'animate-pulse' : ''}
           />
         </div>
         <div>
@@ -180,7 +257,9 @@ export const LlamaInstallModal: FC<LlamaInstallModalProps> = ({
             <p className="mt-1 mb-0 text-text-secondary text-[0.95rem]">
               {canDownload
                 ? 'We will download a prebuilt binary for your platform (~15 MB).'
-                : 'Please build llama.cpp via the CLI: gglib config llama install'}
+                : canBuild
+                  ? 'No prebuilt binary is available for your platform. Build llama.cpp from source (needs git, CMake and a C++ compiler; takes a few minutes).'
+                  : 'Please build llama.cpp via the CLI: gglib config llama install'}
             </p>
           )}
         </div>
@@ -190,6 +269,8 @@ export const LlamaInstallModal: FC<LlamaInstallModalProps> = ({
 
       {renderProgress()}
 
+      {renderBuild()}
+
       {isCompleted ? (
         <p className="text-success font-semibold">llama.cpp is ready! You can now serve models.</p>
       ) : null}
@@ -204,7 +285,7 @@ export const LlamaInstallModal: FC<LlamaInstallModalProps> = ({
       onClose={onClose ?? (() => {})}
       title="Llama installation"
       size="md"
-      preventClose={installing}
+      preventClose={installing || building}
       footer={renderFooterContent() ?? undefined}
     >
       <div className="flex flex-col gap-4">{metadata ? renderMetadataContent() : renderStandardContent()}</div>
@@ -212,4 +293,12 @@ export const LlamaInstallModal: FC<LlamaInstallModalProps> = ({
   );
 };
 
+const BUILD_PHASE_LABELS: Record<LlamaBuildPhase, string> = {
+  dependency_check: 'Checking dependencies',
+  clone_or_update_repo: 'Fetching llama.cpp source',
+  configure: 'Configuring',
+  compile: 'Compiling',
+  install_binaries: 'Installing binaries',
+};
+
 export default LlamaInstallModal;
@@ -120,6 +120,7 @@ const ModelInspectorPanel: FC<ModelInspectorPanelProps> = ({
     mtpNMaxOverride: serveModal.mtpNMaxOverride,
     mtpPMinOverride: serveModal.mtpPMinOverride,
     inferenceParams: serveModal.inferenceParams,
+    ropeConfig: serveModal.ropeConfig,
     onStopServer,
     onRemoveModel,
     onUpdateModel,
@@ -251,6 +252,8 @@ const ModelInspectorPanel: FC<ModelInspectorPanelProps> = ({
           mtpNMaxOverride={serveModal.mtpNMaxOverride}
           mtpPMinOverride={serveModal.mtpPMinOverride}
           inferenceParams={serveModal.inferenceParams}
+          ropeConfig={serveModal.ropeConfig}
+          metadata={detail.modelDetail?.metadata}
           onContextChange={serveModal.setCustomContext}
           onPortChange={serveModal.setCustomPort}
           onJinjaChange={serveModal.setJinjaOverride}
@@ -258,6 +261,7 @@ const ModelInspectorPanel: FC<ModelInspectorPanelProps> = ({
           onMtpNMaxChange={serveModal.setMtpNMaxOverride}
           onMtpPMinChange={serveModal.setMtpPMinOverride}
           onInferenceParamsChange={serveModal.setInferenceParams}
+          onRopeConfigChange={serveModal.setRopeConfig}
           onClose={serveModal.closeServeModal}
           onStart={serverActions.handleStartServer}
         />
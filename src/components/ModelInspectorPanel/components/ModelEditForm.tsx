@@ -19,6 +19,10 @@ interface ModelEditFormProps {
   onServerDefaultsChange: (config: ServerConfig | null) => void;
 }
 
+/** Whether any per-model server override is still set. */
+const hasServerDefaults = (config: ServerConfig): boolean =>
+  config.contextLength !== undefined || config.idleShutdownMinutes !== undefined;
+
 /**
  * Edit mode form for model metadata.
  * Shows editable inputs for quantization and file path,
@@ -72,7 +76,8 @@ export const ModelEditForm: FC<ModelEditFormProps> = ({
               value={editedServerDefaults?.contextLength ?? ''}
               onChange={(e) => {
                 const val = e.target.value ? parseInt(e.target.value, 10) : undefined;
-                onServerDefaultsChange(val !== undefined ? { contextLength: val } : null);
+                const next = { ...editedServerDefaults, contextLength: val };
+                onServerDefaultsChange(hasServerDefaults(next) ? next : null);
               }}
               placeholder="Use default"
               className="w-32"
@@ -96,6 +101,24 @@ export const ModelEditForm: FC<ModelEditFormProps> = ({
             )}
           </div>
         </div>
+        {/* Idle shutdown — editable override */}
+        <div className="flex justify-between items-start gap-base">
+          <span className="text-text-muted text-sm shrink-0">Idle Shutdown (min):</span>
+          <Input
+            type="number"
+            min={0}
+            step={1}
+            value={editedServerDefaults?.idleShutdownMinutes ?? ''}
+            onChange={(e) => {
+              const val = e.target.value ? parseInt(e.target.value, 10) : undefined;
+              const next = { ...editedServerDefaults, idleShutdownMinutes: val };
+              onServerDefaultsChange(hasServerDefaults(next) ? next : null);
+            }}
+            placeholder="Use default"
+            title="0 keeps this model's server running regardless of the global setting"
+            className="w-32"
+          />
+        </div>
         <div className="flex justify-between items-start gap-base">
           <span className="text-text-muted text-sm shrink-0">Path:</span>
           <Input
@@ -5,8 +5,9 @@ import { Icon } from '../../ui/Icon';
 import { Input } from '../../ui/Input';
 import { Modal } from '../../ui/Modal';
 import { InferenceParametersForm } from '../../InferenceParametersForm';
-import type { GgufModel, AppSettings, InferenceConfig } from '../../../types';
+import type { GgufModel, AppSettings, InferenceConfig, RopeConfig, RopeScalingType } from '../../../types';
 import { formatParamCount } from '../../../utils/format';
+import { suggestMaxContext } from '../../../utils/rope';
 
 interface ServeModalProps {
   model: GgufModel;
@@ -23,6 +24,10 @@ interface ServeModalProps {
   /** null = use default 0.75 */
   mtpPMinOverride: number | null;
   inferenceParams: InferenceConfig | undefined;
+  /** undefined = use GGUF metadata */
+  ropeConfig: RopeConfig | undefined;
+  /** Raw GGUF metadata, used for the safe max-context suggestion */
+  metadata: Record<string, string> | undefined;
   // Handlers
   onContextChange: (value: string) => void;
   onPortChange: (value: string) => void;
@@ -31,6 +36,7 @@ interface ServeModalProps {
   onMtpNMaxChange: (value: number | null) => void;
   onMtpPMinChange: (value: number | null) => void;
   onInferenceParamsChange: (params: InferenceConfig) => void;
+  onRopeConfigChange: (config: RopeConfig | undefined) => void;
   onClose: () => void;
   onStart: () => void;
 }
@@ -50,6 +56,8 @@ export const ServeModal: FC<ServeModalProps> = ({
   mtpNMaxOverride,
   mtpPMinOverride,
   inferenceParams,
+  ropeConfig,
+  metadata,
   onContextChange,
   onPortChange,
   onJinjaChange,
@@ -57,6 +65,7 @@ export const ServeModal: FC<ServeModalProps> = ({
   onMtpNMaxChange,
   onMtpPMinChange,
   onInferenceParamsChange,
+  onRopeConfigChange,
   onClose,
   onStart,
 }) => {
@@ -67,6 +76,31 @@ export const ServeModal: FC<ServeModalProps> = ({
   const effectiveMtpEnabled = mtpNMaxOverride !== null ? mtpNMaxOverride > 0 : hasMtpTag;
   const isAutoMtp = mtpNMaxOverride === null && hasMtpTag;
   const [showAdvanced, setShowAdvanced] = useState(false);
+  const [showRope, setShowRope] = useState(false);
+
+  const hasRopeOverrides = ropeConfig !== undefined && Object.values(ropeConfig).some(v => v != null);
+  // The server refuses a context above this, so the dialog does too.
+  const maxContext = metadata
+    ? suggestMaxContext(metadata, model.architecture, ropeConfig) ?? model.contextLength
+    : model.contextLength;
+  const modelDefaultContext = model.serverDefaults?.contextLength ?? undefined;
+  const requestedContext = parseInt(customContext.trim(), 10);
+  const exceedsMax = maxContext !== undefined
+    && Number.isFinite(requestedContext)
+    && requestedContext > maxContext;
+
+  // Merge one field into the RoPE overrides; drop the object once it's empty
+  // so the request carries no `rope` at all.
+  const updateRope = (patch: Partial<RopeConfig>) => {
+    const next = { ...ropeConfig, ...patch };
+    onRopeConfigChange(Object.values(next).some(v => v != null) ? next : undefined);
+  };
+  const parseOptional = (raw: string, parse: (v: string) => number): number | undefined => {
+    const v = raw.trim();
+    if (v === '') return undefined;
+    const parsed = parse(v);
+    return Number.isFinite(parsed) && parsed > 0 ? parsed : undefined;
+  };
 
   // Check if any inference params are set (for visual indicator)
   const hasInferenceOverrides = inferenceParams && Object.values(inferenceParams).some(v => v != null);
@@ -86,7 +120,7 @@ export const ServeModal: FC<ServeModalProps> = ({
           <Button
             variant="primary"
             onClick={onStart}
-            disabled={isServing}
+            disabled={isServing || exceedsMax}
             leftIcon={!isServing ? <Icon icon={Play} size={14} /> : undefined}
           >
             {isServing ? (
@@ -116,11 +150,13 @@ export const ServeModal: FC<ServeModalProps> = ({
             type="number"
             className="w-full p-md bg-background-input border border-border rounded-base text-text text-base transition duration-200 focus:outline-none focus:border-border-focus focus:shadow-[0_0_0_3px_rgba(59,130,246,0.1)]"
             placeholder={
-              settings?.defaultContextSize
-                ? `Default: ${settings.defaultContextSize.toLocaleString()}`
-                : model.contextLength
-                  ? `Model max: ${model.contextLength.toLocaleString()}`
-                  : 'Enter context length'
+              modelDefaultContext
+                ? `Model default: ${modelDefaultContext.toLocaleString()}`
+                : settings?.defaultContextSize
+                  ? `Default: ${settings.defaultContextSize.toLocaleString()}`
+                  : model.contextLength
+                    ? `Model max: ${model.contextLength.toLocaleString()}`
+                    : 'Enter context length'
             }
             value={customContext}
             onChange={(e) => onContextChange(e.target.value)}
@@ -131,7 +167,15 @@ export const ServeModal: FC<ServeModalProps> = ({
             {model.contextLength
               ? `Model's maximum: ${model.contextLength.toLocaleString()} tokens`
               : 'No model context metadata available'}
+            {hasRopeOverrides && maxContext !== undefined
+              && ` · With RoPE scaling: ${maxContext.toLocaleString()} tokens`}
           </p>
+          {exceedsMax && (
+            <p className="mt-sm text-sm text-danger" role="alert">
+              Exceeds the model's maximum of {maxContext!.toLocaleString()} tokens — the server
+              won't start. Set a RoPE frequency scale below to stretch the context.
+            </p>
+          )}
         </div>
 
         <div className="mb-lg">
@@ -319,6 +363,97 @@ export const ServeModal: FC<ServeModalProps> = ({
           )}
         </div>
 
+        {/* Advanced: RoPE Scaling */}
+        <div className="mb-lg">
+          <button
+            type="button"
+            className="advanced-toggle"
+            onClick={() => setShowRope(!showRope)}
+            disabled={isServing}
+          >
+            <Icon icon={showRope ? ChevronDown : ChevronRight} size={16} />
+            <span>RoPE Scaling</span>
+            {hasRopeOverrides && <span className="override-indicator">•</span>}
+          </button>
+          {showRope && (
+            <div className="advanced-section">
+              <p className="mt-sm text-sm text-text-secondary" style={{ marginBottom: '12px' }}>
+                Run past the model's trained context. Leave empty to use the GGUF metadata.
+              </p>
+              <div className="flex gap-md mb-md">
+                <div className="flex-1">
+                  <label htmlFor="rope-scaling" className="block mb-sm text-sm font-medium text-text">
+                    Scaling
+                  </label>
+                  <select
+                    id="rope-scaling"
+                    className="w-full p-md bg-background-input border border-border rounded-base text-text text-base"
+                    value={ropeConfig?.scaling ?? ''}
+                    onChange={(e) => updateRope({
+                      scaling: e.target.value === '' ? undefined : e.target.value as RopeScalingType,
+                    })}
+                    disabled={isServing}
+                  >
+                    <option value="">Auto (from model)</option>
+                    <option value="linear">Linear</option>
+                    <option value="yarn">YaRN</option>
+                    <option value="none">None</option>
+                  </select>
+                </div>
+                <div className="flex-1">
+                  <label htmlFor="rope-freq-scale" className="block mb-sm text-sm font-medium text-text">
+                    Frequency scale
+                  </label>
+                  <Input
+                    id="rope-freq-scale"
+                    type="number"
+                    className="w-full p-md bg-background-input border border-border rounded-base text-text text-base"
+                    placeholder="e.g. 0.25 for 4×"
+                    value={ropeConfig?.freqScale !== undefined ? String(ropeConfig.freqScale) : ''}
+                    onChange={(e) => updateRope({ freqScale: parseOptional(e.target.value, parseFloat) })}
+                    disabled={isServing}
+                    min="0"
+                    max="1"
+                    step="0.05"
+                  />
+                </div>
+              </div>
+              <div className="flex gap-md">
+                <div className="flex-1">
+                  <label htmlFor="rope-freq-base" className="block mb-sm text-sm font-medium text-text">
+                    Frequency base
+                  </label>
+                  <Input
+                    id="rope-freq-base"
+                    type="number"
+                    className="w-full p-md bg-background-input border border-border rounded-base text-text text-base"
+                    placeholder="From model"
+                    value={ropeConfig?.freqBase !== undefined ? String(ropeConfig.freqBase) : ''}
+                    onChange={(e) => updateRope({ freqBase: parseOptional(e.target.value, parseFloat) })}
+                    disabled={isServing}
+                    min="0"
+                  />
+                </div>
+                <div className="flex-1">
+                  <label htmlFor="yarn-orig-ctx" className="block mb-sm text-sm font-medium text-text">
+                    YaRN original context
+                  </label>
+                  <Input
+                    id="yarn-orig-ctx"
+                    type="number"
+                    className="w-full p-md bg-background-input border border-border rounded-base text-text text-base"
+                    placeholder="From model"
+                    value={ropeConfig?.yarnOrigCtx !== undefined ? String(ropeConfig.yarnOrigCtx) : ''}
+                    onChange={(e) => updateRope({ yarnOrigCtx: parseOptional(e.target.value, (v) => parseInt(v, 10)) })}
+                    disabled={isServing}
+                    min="1"
+                  />
+                </div>
+              </div>
+            </div>
+          )}
+        </div>
+
         {/* Advanced: Inference Parameters */}
         <div className="mb-lg">
           <button 
@@ -1,5 +1,5 @@
 import { useState, useEffect, useCallback } from 'react';
-import type { InferenceConfig } from '../../../types';
+import type { InferenceConfig, RopeConfig } from '../../../types';
 
 export interface ServeModalState {
   showServeModal: boolean;
@@ -12,6 +12,8 @@ export interface ServeModalState {
   mtpPMinOverride: number | null;
   isServing: boolean;
   inferenceParams: InferenceConfig | undefined;
+  /** undefined = use GGUF metadata */
+  ropeConfig: RopeConfig | undefined;
   setShowServeModal: (show: boolean) => void;
   setCustomContext: (context: string) => void;
   setCustomPort: (port: string) => void;
@@ -20,6 +22,7 @@ export interface ServeModalState {
   setMtpPMinOverride: (override: number | null) => void;
   setIsServing: (serving: boolean) => void;
   setInferenceParams: (params: InferenceConfig | undefined) => void;
+  setRopeConfig: (config: RopeConfig | undefined) => void;
   openServeModal: () => void;
   closeServeModal: () => void;
   resetServeModal: () => void;
@@ -37,6 +40,7 @@ export function useServeModal(modelId: number | undefined): ServeModalState {
   const [mtpPMinOverride, setMtpPMinOverride] = useState<number | null>(null);
   const [isServing, setIsServing] = useState(false);
   const [inferenceParams, setInferenceParams] = useState<InferenceConfig | undefined>(undefined);
+  const [ropeConfig, setRopeConfig] = useState<RopeConfig | undefined>(undefined);
 
   // Reset overrides and inference params when model changes
   useEffect(() => {
@@ -44,6 +48,7 @@ export function useServeModal(modelId: number | undefined): ServeModalState {
     setMtpNMaxOverride(null);
     setMtpPMinOverride(null);
     setInferenceParams(undefined);
+    setRopeConfig(undefined);
   }, [modelId]);
 
   const openServeModal = useCallback(() => {
@@ -51,6 +56,7 @@ export function useServeModal(modelId: number | undefined): ServeModalState {
     setMtpNMaxOverride(null);
     setMtpPMinOverride(null);
     setInferenceParams(undefined);
+    setRopeConfig(undefined);
     setShowServeModal(true);
   }, []);
 
@@ -67,6 +73,7 @@ export function useServeModal(modelId: number | undefined): ServeModalState {
     setMtpPMinOverride(null);
     setIsServing(false);
     setInferenceParams(undefined);
+    setRopeConfig(undefined);
   }, []);
 
   return {
@@ -78,6 +85,7 @@ export function useServeModal(modelId: number | undefined): ServeModalState {
     mtpPMinOverride,
     isServing,
     inferenceParams,
+    ropeConfig,
     setShowServeModal,
     setCustomContext,
     setCustomPort,
@@ -86,6 +94,7 @@ export function useServeModal(modelId: number | undefined): ServeModalState {
     setMtpPMinOverride,
     setIsServing,
     setInferenceParams,
+    setRopeConfig,
     openServeModal,
     closeServeModal,
     resetServeModal,
@@ -1,6 +1,6 @@
 import { useCallback } from 'react';
 import { appLogger } from '../../../services/platform';
-import type { GgufModel, ServeConfig, ServerInfo, AppSettings, InferenceConfig } from '../../../types';
+import type { GgufModel, ServeConfig, ServerInfo, AppSettings, InferenceConfig, RopeConfig } from '../../../types';
 import { serveModel } from '../../../services/clients/servers';
 import { useToastContext } from '../../../contexts/ToastContext';
 import { TransportError, LlamaServerNotInstalledMetadata } from '../../../services/transport/errors';
@@ -23,6 +23,7 @@ export interface ServerActionsConfig {
   mtpNMaxOverride: number | null;
   mtpPMinOverride: number | null;
   inferenceParams: InferenceConfig | undefined;
+  ropeConfig: RopeConfig | undefined;
   editedServerDefaults: import('../../../types').ServerConfig | null | undefined;
   // Callbacks
   onStopServer: (modelId: number) => Promise<void>;
@@ -71,6 +72,7 @@ export function useServerActions(config: ServerActionsConfig): ServerActionsResu
     mtpNMaxOverride,
     mtpPMinOverride,
     inferenceParams,
+    ropeConfig,
     onStopServer,
     onRemoveModel,
     onUpdateModel,
@@ -127,6 +129,7 @@ export function useServerActions(config: ServerActionsConfig): ServerActionsResu
         // MTP: null = auto-detect from tag; 0 = disable; >0 = explicit token count
         specDraftNMax: mtpNMaxOverride !== null ? mtpNMaxOverride : (hasMtpTag ? undefined : undefined),
         specDraftPMin: mtpPMinOverride !== null ? mtpPMinOverride : undefined,
+        rope: ropeConfig,
         // Inference parameters for this session
         temperature: inferenceParams?.temperature,
         topP: inferenceParams?.topP,
@@ -169,7 +172,7 @@ export function useServerActions(config: ServerActionsConfig): ServerActionsResu
     } finally {
       setIsServing(false);
     }
-  }, [model, settings, customContext, customPort, jinjaOverride, hasAgentTag, hasMtpTag, mtpNMaxOverride, mtpPMinOverride, inferenceParams, onStartServer, onServerStarted, closeServeModal, setIsServing, showToast, onLlamaServerNotInstalled]);
+  }, [model, settings, customContext, customPort, jinjaOverride, hasAgentTag, hasMtpTag, mtpNMaxOverride, mtpPMinOverride, inferenceParams, ropeConfig, onStartServer, onServerStarted, closeServeModal, setIsServing, showToast, onLlamaServerNotInstalled]);
 
   const handleToggleServer = useCallback(async () => {
     if (!model?.id) return;
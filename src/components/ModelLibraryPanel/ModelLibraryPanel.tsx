@@ -93,6 +93,7 @@ const ModelLibraryPanel: FC<ModelLibraryPanelProps> = ({
     filters.paramRange !== null ||
     filters.contextRange !== null ||
     filters.selectedQuantizations.length > 0 ||
+    filters.selectedLicenses.length > 0 ||
     filters.selectedTags.length > 0;
 
   const handleSwitchToAddTab = () => {
@@ -4,7 +4,7 @@ import { useModelsDirectory } from "../hooks/useModelsDirectory";
 import { useSettings } from "../hooks/useSettings";
 import { useMcpServers } from "../hooks/useMcpServers";
 import { useModels } from "../hooks/useModels";
-import { UpdateSettingsRequest, InferenceConfig } from "../types";
+import { UpdateSettingsRequest, InferenceConfig, StartupModel } from "../types";
 import type { McpServerInfo } from "../services/clients/mcp";
 import { McpServersPanel } from "./McpServersPanel";
 import { AddMcpServerModal } from "./AddMcpServerModal";
@@ -33,14 +33,19 @@ export const SettingsModal: FC<SettingsModalProps> = ({ isOpen, onClose }) => {
   
   const [pathInput, setPathInput] = useState("");
   const [contextSizeInput, setContextSizeInput] = useState("");
+  const [idleShutdownInput, setIdleShutdownInput] = useState("");
   const [proxyPortInput, setProxyPortInput] = useState("");
   const [serverPortInput, setServerPortInput] = useState("");
   const [maxQueueSizeInput, setMaxQueueSizeInput] = useState("");
   const [titlePromptInput, setTitlePromptInput] = useState("");
+  const [autoTitle, setAutoTitle] = useState(true);
+  const [encryptHistory, setEncryptHistory] = useState(false);
   const [maxToolIterationsInput, setMaxToolIterationsInput] = useState("");
   const [showFitIndicators, setShowFitIndicators] = useState(true);
+  const [warnRestrictiveLicenses, setWarnRestrictiveLicenses] = useState(false);
   const [defaultModelInput, setDefaultModelInput] = useState("");
   const [inferenceDefaultsInput, setInferenceDefaultsInput] = useState<InferenceConfig | undefined>(undefined);
+  const [startupModelsInput, setStartupModelsInput] = useState<StartupModel[]>([]);
   const [isAdvancedOpen, setIsAdvancedOpen] = useState(false);
   const [successMessage, setSuccessMessage] = useState<string | null>(null);
   const [activeTab, setActiveTab] = useState<SettingsTab>("general");
@@ -63,14 +68,19 @@ export const SettingsModal: FC<SettingsModalProps> = ({ isOpen, onClose }) => {
   useEffect(() => {
     if (settings) {
       setContextSizeInput(settings.defaultContextSize?.toString() || "");
+      setIdleShutdownInput(settings.idleShutdownMinutes?.toString() || "");
       setProxyPortInput(settings.proxyPort?.toString() || "");
       setServerPortInput(settings.llamaBasePort?.toString() || "");
       setMaxQueueSizeInput(settings.maxDownloadQueueSize?.toString() || "");
       setTitlePromptInput(settings.titleGenerationPrompt || "");
+      setAutoTitle(settings.autoTitleConversations !== false);
+      setEncryptHistory(settings.encryptChatHistory === true);
       setMaxToolIterationsInput(settings.maxToolIterations?.toString() || "");
       setShowFitIndicators(settings.showMemoryFitIndicators !== false);
+      setWarnRestrictiveLicenses(settings.warnRestrictiveLicenses === true);
       setDefaultModelInput(settings.defaultModelId?.toString() || "");
       setInferenceDefaultsInput(settings.inferenceDefaults || undefined);
+      setStartupModelsInput(settings.startupModels ?? []);
     }
   }, [settings]);
 
@@ -95,27 +105,42 @@ export const SettingsModal: FC<SettingsModalProps> = ({ isOpen, onClose }) => {
         // Update other settings
         const updates: UpdateSettingsRequest = {
           defaultContextSize: parseNumericInput(contextSizeInput),
+          idleShutdownMinutes: parseNumericInput(idleShutdownInput),
           proxyPort: parseNumericInput(proxyPortInput),
           llamaBasePort: parseNumericInput(serverPortInput),
           maxDownloadQueueSize: parseNumericInput(maxQueueSizeInput),
           titleGenerationPrompt: titlePromptInput.trim() || null,
+          autoTitleConversations: autoTitle,
+          // Only sent on change: toggling rewrites every stored message
+          encryptChatHistory:
+            encryptHistory !== (settings?.encryptChatHistory === true) ? encryptHistory : undefined,
           maxToolIterations: parseNumericInput(maxToolIterationsInput),
           showMemoryFitIndicators: showFitIndicators,
+          warnRestrictiveLicenses,
           defaultModelId: parseNumericInput(defaultModelInput),
           inferenceDefaults: inferenceDefaultsInput,
+          startupModels: startupModelsInput.length > 0 ? startupModelsInput : null,
+          // The form holds every field, so refuse to overwrite a save made
+          // elsewhere (e.g. from the CLI) since it was opened.
+          expectedVersion: settings?.version,
         };
 
         // Check if any updates were made
         const hasUpdates = 
           updates.defaultContextSize !== undefined ||
+          updates.idleShutdownMinutes !== undefined ||
           updates.proxyPort !== undefined ||
           updates.llamaBasePort !== undefined ||
           updates.maxDownloadQueueSize !== undefined ||
           updates.titleGenerationPrompt !== undefined ||
+          updates.autoTitleConversations !== undefined ||
+          updates.encryptChatHistory !== undefined ||
           updates.maxToolIterations !== undefined ||
           updates.showMemoryFitIndicators !== undefined ||
+          updates.warnRestrictiveLicenses !== undefined ||
           updates.defaultModelId !== undefined ||
-          updates.inferenceDefaults !== undefined;
+          updates.inferenceDefaults !== undefined ||
+          updates.startupModels !== undefined;
 
         if (hasUpdates) {
           await saveSettings(updates);
@@ -129,14 +154,20 @@ export const SettingsModal: FC<SettingsModalProps> = ({ isOpen, onClose }) => {
     [
       pathInput,
       contextSizeInput,
+      idleShutdownInput,
       proxyPortInput,
       serverPortInput,
       maxQueueSizeInput,
       titlePromptInput,
+      autoTitle,
+      encryptHistory,
       maxToolIterationsInput,
       showFitIndicators,
+      warnRestrictiveLicenses,
       defaultModelInput,
       inferenceDefaultsInput,
+      startupModelsInput,
+      settings,
       info,
       saveDir,
       saveSettings,
@@ -149,11 +180,14 @@ export const SettingsModal: FC<SettingsModalProps> = ({ isOpen, onClose }) => {
     }
     if (settings) {
       setContextSizeInput(settings.defaultContextSize?.toString() ?? "");
+      setIdleShutdownInput(settings.idleShutdownMinutes?.toString() ?? "");
       setProxyPortInput(settings.proxyPort?.toString() ?? "");
       setServerPortInput(settings.llamaBasePort?.toString() ?? "");
       setMaxQueueSizeInput(settings.maxDownloadQueueSize?.toString() ?? "");
       setTitlePromptInput(""); // Reset to default (empty uses DEFAULT_TITLE_GENERATION_PROMPT)
+      setAutoTitle(true); // Default is enabled
       setShowFitIndicators(true); // Default is enabled
+      setWarnRestrictiveLicenses(false); // Default is disabled
     }
   }, [info, settings]);
 
@@ -222,6 +256,8 @@ export const SettingsModal: FC<SettingsModalProps> = ({ isOpen, onClose }) => {
             sourceDescription={sourceDescription}
             contextSizeInput={contextSizeInput}
             setContextSizeInput={setContextSizeInput}
+            idleShutdownInput={idleShutdownInput}
+            setIdleShutdownInput={setIdleShutdownInput}
             proxyPortInput={proxyPortInput}
             setProxyPortInput={setProxyPortInput}
             serverPortInput={serverPortInput}
@@ -230,14 +266,23 @@ export const SettingsModal: FC<SettingsModalProps> = ({ isOpen, onClose }) => {
             setMaxQueueSizeInput={setMaxQueueSizeInput}
             showFitIndicators={showFitIndicators}
             setShowFitIndicators={setShowFitIndicators}
+            warnRestrictiveLicenses={warnRestrictiveLicenses}
+            setWarnRestrictiveLicenses={setWarnRestrictiveLicenses}
             defaultModelInput={defaultModelInput}
             setDefaultModelInput={setDefaultModelInput}
             models={models}
             loadingModels={loadingModels}
+            startupModelsInput={startupModelsInput}
+            setStartupModelsInput={setStartupModelsInput}
+            profiles={settings?.inferenceProfiles ?? []}
             isAdvancedOpen={isAdvancedOpen}
             setIsAdvancedOpen={setIsAdvancedOpen}
             maxToolIterationsInput={maxToolIterationsInput}
             setMaxToolIterationsInput={setMaxToolIterationsInput}
+            autoTitle={autoTitle}
+            setAutoTitle={setAutoTitle}
+            encryptHistory={encryptHistory}
+            setEncryptHistory={setEncryptHistory}
             titlePromptInput={titlePromptInput}
             setTitlePromptInput={setTitlePromptInput}
             inferenceDefaultsInput={inferenceDefaultsInput}
@@ -1,6 +1,6 @@
 import { FC, FormEvent } from "react";
 import { Button } from "../ui/Button";
-import type { ModelsDirectoryInfo, GgufModel, InferenceConfig } from "../../types";
+import type { ModelsDirectoryInfo, GgufModel, InferenceConfig, InferenceProfile, StartupModel } from "../../types";
 import {
   PathSettings,
   ModelDefaults,
@@ -8,6 +8,7 @@ import {
   DisplaySettings,
   AdvancedSettings,
   SetupWizardRow,
+  StartupModels,
 } from "./fields";
 
 interface GeneralSettingsProps {
@@ -28,6 +29,12 @@ interface GeneralSettingsProps {
   setMaxQueueSizeInput: (value: string) => void;
   showFitIndicators: boolean;
   setShowFitIndicators: (value: boolean) => void;
+  warnRestrictiveLicenses: boolean;
+  setWarnRestrictiveLicenses: (value: boolean) => void;
+
+  // Idle shutdown state
+  idleShutdownInput: string;
+  setIdleShutdownInput: (value: string) => void;
 
   // Default model state
   defaultModelInput: string;
@@ -35,11 +42,20 @@ interface GeneralSettingsProps {
   models: GgufModel[];
   loadingModels: boolean;
 
+  // Startup models
+  startupModelsInput: StartupModel[];
+  setStartupModelsInput: (value: StartupModel[]) => void;
+  profiles: InferenceProfile[];
+
   // Advanced settings
   isAdvancedOpen: boolean;
   setIsAdvancedOpen: (value: boolean) => void;
   maxToolIterationsInput: string;
   setMaxToolIterationsInput: (value: string) => void;
+  autoTitle: boolean;
+  setAutoTitle: (value: boolean) => void;
+  encryptHistory: boolean;
+  setEncryptHistory: (value: boolean) => void;
   titlePromptInput: string;
   setTitlePromptInput: (value: string) => void;
 
@@ -67,6 +83,8 @@ export const GeneralSettings: FC<GeneralSettingsProps> = ({
   sourceDescription,
   contextSizeInput,
   setContextSizeInput,
+  idleShutdownInput,
+  setIdleShutdownInput,
   proxyPortInput,
   setProxyPortInput,
   serverPortInput,
@@ -75,14 +93,23 @@ export const GeneralSettings: FC<GeneralSettingsProps> = ({
   setMaxQueueSizeInput,
   showFitIndicators,
   setShowFitIndicators,
+  warnRestrictiveLicenses,
+  setWarnRestrictiveLicenses,
   defaultModelInput,
   setDefaultModelInput,
   models,
   loadingModels,
+  startupModelsInput,
+  setStartupModelsInput,
+  profiles,
   isAdvancedOpen,
   setIsAdvancedOpen,
   maxToolIterationsInput,
   setMaxToolIterationsInput,
+  autoTitle,
+  setAutoTitle,
+  encryptHistory,
+  setEncryptHistory,
   titlePromptInput,
   setTitlePromptInput,
   inferenceDefaultsInput,
@@ -121,6 +148,8 @@ export const GeneralSettings: FC<GeneralSettingsProps> = ({
       <ModelDefaults
         contextSizeInput={contextSizeInput}
         setContextSizeInput={setContextSizeInput}
+        idleShutdownInput={idleShutdownInput}
+        setIdleShutdownInput={setIdleShutdownInput}
         defaultModelInput={defaultModelInput}
         setDefaultModelInput={setDefaultModelInput}
         models={models}
@@ -128,6 +157,15 @@ export const GeneralSettings: FC<GeneralSettingsProps> = ({
         saving={saving}
       />
 
+      <StartupModels
+        startupModels={startupModelsInput}
+        setStartupModels={setStartupModelsInput}
+        models={models}
+        profiles={profiles}
+        loadingModels={loadingModels}
+        saving={saving}
+      />
+
       <PortSettings
         proxyPortInput={proxyPortInput}
         setProxyPortInput={setProxyPortInput}
@@ -143,6 +181,8 @@ export const GeneralSettings: FC<GeneralSettingsProps> = ({
       <DisplaySettings
         showFitIndicators={showFitIndicators}
         setShowFitIndicators={setShowFitIndicators}
+        warnRestrictiveLicenses={warnRestrictiveLicenses}
+        setWarnRestrictiveLicenses={setWarnRestrictiveLicenses}
         saving={saving}
       />
 
@@ -153,6 +193,10 @@ export const GeneralSettings: FC<GeneralSettingsProps> = ({
         onToggle={() => setIsAdvancedOpen(!isAdvancedOpen)}
         maxToolIterationsInput={maxToolIterationsInput}
         setMaxToolIterationsInput={setMaxToolIterationsInput}
+        autoTitle={autoTitle}
+        setAutoTitle={setAutoTitle}
+        encryptHistory={encryptHistory}
+        setEncryptHistory={setEncryptHistory}
         titlePromptInput={titlePromptInput}
         setTitlePromptInput={setTitlePromptInput}
         inferenceDefaultsInput={inferenceDefaultsInput}
@@ -14,6 +14,10 @@ interface AdvancedSettingsProps {
   onToggle: () => void;
   maxToolIterationsInput: string;
   setMaxToolIterationsInput: (value: string) => void;
+  autoTitle: boolean;
+  setAutoTitle: (value: boolean) => void;
+  encryptHistory: boolean;
+  setEncryptHistory: (value: boolean) => void;
   titlePromptInput: string;
   setTitlePromptInput: (value: string) => void;
   inferenceDefaultsInput: InferenceConfig | undefined;
@@ -22,14 +26,18 @@ interface AdvancedSettingsProps {
 }
 
 /**
- * Collapsible advanced section: tool-iteration cap, title-generation prompt,
- * and global inference parameter defaults.
+ * Collapsible advanced section: tool-iteration cap, chat auto-titling and its
+ * prompt, chat history encryption, and global inference parameter defaults.
  */
 export const AdvancedSettings: FC<AdvancedSettingsProps> = ({
   isOpen,
   onToggle,
   maxToolIterationsInput,
   setMaxToolIterationsInput,
+  autoTitle,
+  setAutoTitle,
+  encryptHistory,
+  setEncryptHistory,
   titlePromptInput,
   setTitlePromptInput,
   inferenceDefaultsInput,
@@ -67,6 +75,39 @@ export const AdvancedSettings: FC<AdvancedSettingsProps> = ({
           />
         </SettingField>
 
+        <div>
+          <label className="flex items-center gap-sm cursor-pointer select-none">
+            <input
+              type="checkbox"
+              className="w-[18px] h-[18px] accent-primary cursor-pointer disabled:opacity-60 disabled:cursor-not-allowed"
+              checked={autoTitle}
+              onChange={(e) => setAutoTitle(e.target.checked)}
+              disabled={saving}
+            />
+            <span className="font-semibold text-text">Auto-title new chats</span>
+          </label>
+          <p className="text-text-secondary text-sm">
+            Ask the running model for a short title after the first reply in a new chat
+          </p>
+        </div>
+
+        <div>
+          <label className="flex items-center gap-sm cursor-pointer select-none">
+            <input
+              type="checkbox"
+              className="w-[18px] h-[18px] accent-primary cursor-pointer disabled:opacity-60 disabled:cursor-not-allowed"
+              checked={encryptHistory}
+              onChange={(e) => setEncryptHistory(e.target.checked)}
+              disabled={saving}
+            />
+            <span className="font-semibold text-text">Encrypt chat history</span>
+          </label>
+          <p className="text-text-secondary text-sm">
+            Store message content encrypted with a key kept in the OS keyring. Existing messages
+            are converted when you save; chat titles stay readable.
+          </p>
+        </div>
+
         <SettingField
           id="title-prompt-input"
           label="Chat Title Generation Prompt"
@@ -4,35 +4,60 @@ import { Row } from '../../primitives';
 interface DisplaySettingsProps {
   showFitIndicators: boolean;
   setShowFitIndicators: (value: boolean) => void;
+  warnRestrictiveLicenses: boolean;
+  setWarnRestrictiveLicenses: (value: boolean) => void;
   saving: boolean;
 }
 
 /**
- * Display-only toggles. Currently just the memory-fit indicator switch;
- * kept as its own file so future display toggles have somewhere to land
- * that isn't the ports group or the advanced section.
+ * Display toggles for the HuggingFace browser: the memory-fit indicators
+ * and the restrictive-license warning. Kept as its own file so display
+ * toggles have somewhere to land that isn't the ports group or the
+ * advanced section.
  */
 export const DisplaySettings: FC<DisplaySettingsProps> = ({
   showFitIndicators,
   setShowFitIndicators,
+  warnRestrictiveLicenses,
+  setWarnRestrictiveLicenses,
   saving,
 }) => (
-  <div>
-    <label className="flex items-center gap-sm cursor-pointer select-none">
-      <input
-        type="checkbox"
-        className="w-[18px] h-[18px] accent-primary cursor-pointer disabled:opacity-60 disabled:cursor-not-allowed"
-        checked={showFitIndicators}
-        onChange={(e) => setShowFitIndicators(e.target.checked)}
-        disabled={saving}
-      />
-      <span className="font-semibold text-text">Show memory fit indicators</span>
-    </label>
-    <Row justify="between" gap="sm" className="text-text-secondary text-sm">
-      <span>
-        Display fit status indicators in the HuggingFace browser showing if models fit in your
-        system memory
-      </span>
-    </Row>
+  <div className="flex flex-col gap-md">
+    <div>
+      <label className="flex items-center gap-sm cursor-pointer select-none">
+        <input
+          type="checkbox"
+          className="w-[18px] h-[18px] accent-primary cursor-pointer disabled:opacity-60 disabled:cursor-not-allowed"
+          checked={showFitIndicators}
+          onChange={(e) => setShowFitIndicators(e.target.checked)}
+          disabled={saving}
+        />
+        <span className="font-semibold text-text">Show memory fit indicators</span>
+      </label>
+      <Row justify="between" gap="sm" className="text-text-secondary text-sm">
+        <span>
+          Display fit status indicators in the HuggingFace browser showing if models fit in your
+          system memory
+        </span>
+      </Row>
+    </div>
+    <div>
+      <label className="flex items-center gap-sm cursor-pointer select-none">
+        <input
+          type="checkbox"
+          className="w-[18px] h-[18px] accent-primary cursor-pointer disabled:opacity-60 disabled:cursor-not-allowed"
+          checked={warnRestrictiveLicenses}
+          onChange={(e) => setWarnRestrictiveLicenses(e.target.checked)}
+          disabled={saving}
+        />
+        <span className="font-semibold text-text">Warn about restrictive licenses</span>
+      </label>
+      <Row justify="between" gap="sm" className="text-text-secondary text-sm">
+        <span>
+          Ask for confirmation before downloading models whose license forbids commercial use
+          or limits them to research
+        </span>
+      </Row>
+    </div>
   </div>
 );
@@ -7,6 +7,8 @@ import { SettingField } from './SettingField';
 interface ModelDefaultsProps {
   contextSizeInput: string;
   setContextSizeInput: (value: string) => void;
+  idleShutdownInput: string;
+  setIdleShutdownInput: (value: string) => void;
   defaultModelInput: string;
   setDefaultModelInput: (value: string) => void;
   models: GgufModel[];
@@ -15,11 +17,13 @@ interface ModelDefaultsProps {
 }
 
 /**
- * Default context size and default model selection.
+ * Default context size, idle shutdown, and default model selection.
  */
 export const ModelDefaults: FC<ModelDefaultsProps> = ({
   contextSizeInput,
   setContextSizeInput,
+  idleShutdownInput,
+  setIdleShutdownInput,
   defaultModelInput,
   setDefaultModelInput,
   models,
@@ -45,6 +49,24 @@ export const ModelDefaults: FC<ModelDefaultsProps> = ({
       />
     </SettingField>
 
+    <SettingField
+      id="idle-shutdown-input"
+      label="Idle Shutdown (minutes)"
+      defaultHint="never"
+      description="Stop a running server after this many minutes without a request. Leave empty or 0 to keep servers running."
+    >
+      <Input
+        id="idle-shutdown-input"
+        type="number"
+        value={idleShutdownInput}
+        onChange={(event) => setIdleShutdownInput(event.target.value)}
+        placeholder="0"
+        min="0"
+        max="10080"
+        disabled={saving}
+      />
+    </SettingField>
+
     <SettingField
       id="default-model-select"
       label="Default Model"
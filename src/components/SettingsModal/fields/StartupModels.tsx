@@ -0,0 +1,107 @@
+import { FC } from 'react';
+import { Button } from '../../ui/Button';
+import { Input } from '../../ui/Input';
+import { Select } from '../../ui/Select';
+import { Row } from '../../primitives';
+import type { GgufModel, InferenceProfile, StartupModel } from '../../../types';
+import { SettingField } from './SettingField';
+
+interface StartupModelsProps {
+  startupModels: StartupModel[];
+  setStartupModels: (value: StartupModel[]) => void;
+  models: GgufModel[];
+  profiles: InferenceProfile[];
+  loadingModels: boolean;
+  saving: boolean;
+}
+
+/**
+ * Models launched when the app starts, each with its own context size and
+ * inference profile. Order matters: the backend launches them top to bottom
+ * and skips any that no longer fit in memory.
+ */
+export const StartupModels: FC<StartupModelsProps> = ({
+  startupModels,
+  setStartupModels,
+  models,
+  profiles,
+  loadingModels,
+  saving,
+}) => {
+  const update = (index: number, patch: Partial<StartupModel>) =>
+    setStartupModels(startupModels.map((entry, i) => (i === index ? { ...entry, ...patch } : entry)));
+  const remove = (index: number) => setStartupModels(startupModels.filter((_, i) => i !== index));
+  const available = models.filter(
+    (model) => model.id != null && !startupModels.some((entry) => entry.modelId === model.id),
+  );
+
+  return (
+    <SettingField
+      id="startup-model-add"
+      label="Startup Models"
+      description="Launched in order when gglib starts; models that don't fit in memory are skipped. Running ones restart after a llama.cpp upgrade."
+    >
+      <div className="flex flex-col gap-sm">
+        {startupModels.map((entry, index) => {
+          const model = models.find((m) => m.id === entry.modelId);
+          return (
+            <Row key={entry.modelId} gap="sm" align="center">
+              <span className="flex-1 min-w-0 truncate text-sm text-text">
+                {model?.name ?? `Model #${entry.modelId}`}
+              </span>
+              <Input
+                aria-label="Context size"
+                type="number"
+                className="w-[110px]"
+                value={entry.contextLength?.toString() ?? ''}
+                onChange={(event) => {
+                  const parsed = parseInt(event.target.value, 10);
+                  update(index, { contextLength: isNaN(parsed) ? null : parsed });
+                }}
+                placeholder="Context"
+                min="512"
+                disabled={saving}
+              />
+              <Select
+                aria-label="Inference profile"
+                className="w-[140px]"
+                value={entry.inferenceProfile ?? ''}
+                onChange={(event) => update(index, { inferenceProfile: event.target.value || null })}
+                disabled={saving}
+              >
+                <option value="">No profile</option>
+                {profiles.map((profile) => (
+                  <option key={profile.name} value={profile.name}>
+                    {profile.name}
+                  </option>
+                ))}
+              </Select>
+              <Button type="button" variant="ghost" size="sm" onClick={() => remove(index)} disabled={saving}>
+                Remove
+              </Button>
+            </Row>
+          );
+        })}
+        <Select
+          id="startup-model-add"
+          value=""
+          onChange={(event) => {
+            const modelId = parseInt(event.target.value, 10);
+            if (!isNaN(modelId)) {
+              setStartupModels([...startupModels, { modelId }]);
+            }
+          }}
+          disabled={saving || loadingModels || available.length === 0}
+        >
+          <option value="">Add a model…</option>
+          {available.map((model) => (
+            <option key={model.id} value={model.id?.toString() ?? ''}>
+              {model.name}
+              {model.quantization ? ` (${model.quantization})` : ''}
+            </option>
+          ))}
+        </Select>
+      </div>
+    </SettingField>
+  );
+};
@@ -1,6 +1,7 @@
 export { SettingField } from './SettingField';
 export { PathSettings } from './PathSettings';
 export { ModelDefaults } from './ModelDefaults';
+export { StartupModels } from './StartupModels';
 export { PortSettings } from './PortSettings';
 export { DisplaySettings } from './DisplaySettings';
 export { AdvancedSettings } from './AdvancedSettings';
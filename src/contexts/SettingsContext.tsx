@@ -9,6 +9,7 @@ import {
 } from "react";
 import { AppSettings, UpdateSettingsRequest } from "../types";
 import { getSettings, updateSettings } from "../services/clients/settings";
+import { subscribeToEvent } from "../services/clients/events";
 
 export type ShowToastFn = (message: string, type?: "success" | "error" | "info" | "warning") => void;
 
@@ -78,6 +79,19 @@ export const SettingsProvider: FC<SettingsProviderProps> = ({ children, showToas
     load();
   }, [load]);
 
+  // Pick up saves from other windows and processes (e.g. the CLI)
+  useEffect(
+    () =>
+      subscribeToEvent("settings", () => {
+        getSettings()
+          .then(setSettings)
+          .catch(() => {
+            // Keep the current values; the next change retries.
+          });
+      }),
+    []
+  );
+
   const value: SettingsContextValue = {
     settings,
     loading,
@@ -19,7 +19,7 @@ function snapshotIsBusy(items: DownloadSummary[]): boolean {
   return items.some(i => i.status === 'queued' || i.status === 'downloading');
 }
 
-export type DownloadProgressStatus = 'started' | 'progress' | 'finalizing' | 'registering' | 'notice' | 'completed' | 'error';
+export type DownloadProgressStatus = 'started' | 'resolving' | 'progress' | 'verifying' | 'finalizing' | 'registering' | 'notice' | 'completed' | 'error';
 
 export interface DownloadProgressView {
   status: DownloadProgressStatus;
@@ -103,6 +103,35 @@ function normalizeQueueItem(item: DownloadSummary): DownloadQueueItem {
     error: (item as any).error,
     group_id: (item as any).group_id,
     shard_info: (item as any).shard_info,
+    downloaded_bytes: item.downloaded_bytes,
+    total_bytes: item.total_bytes,
+    speed_bps: item.speed_bps,
+    eta_seconds: item.eta_seconds,
+    elapsed_seconds: item.elapsed_seconds,
+  };
+}
+
+/**
+ * Progress view for the active item of a queue snapshot.
+ *
+ * A client that connects mid-download has missed every progress event so far;
+ * the snapshot carries the manager's current bytes, rate and ETA so the bar
+ * can render immediately. Returns null when the snapshot has no byte counts.
+ */
+function progressFromQueueItem(item: DownloadQueueItem): DownloadProgressView | null {
+  if (!item.total_bytes) return null;
+  const downloaded = item.downloaded_bytes ?? 0;
+  return {
+    status: 'progress',
+    id: item.id,
+    downloaded,
+    total: item.total_bytes,
+    speedBps: item.speed_bps,
+    etaSeconds: item.eta_seconds,
+    percentage: Math.min(100, (downloaded / item.total_bytes) * 100),
+    shard: item.shard_info
+      ? { index: item.shard_info.shard_index, total: item.shard_info.total_shards }
+      : undefined,
   };
 }
 
@@ -204,6 +233,28 @@ function eventToProgress(event: DownloadEvent): DownloadProgressView | null {
         speedBps: undefined,
         etaSeconds: undefined,
       };
+    case 'phase_progress':
+      // Transfer detail arrives on the progress events, and registration is
+      // already surfaced by download_status_changed.
+      if (event.phase === 'resolving') {
+        return { status: 'resolving', id: event.id, message: 'Resolving…' };
+      }
+      if (event.phase === 'verifying') {
+        // Hashing a large file takes a while: show it as its own bar rather
+        // than leaving the transfer sitting at 100%. The byte counts describe
+        // the hash, so the transfer's rate and ETA are cleared.
+        return {
+          status: 'verifying',
+          id: event.id,
+          message: 'Verifying…',
+          downloaded: event.total > 0 ? event.processed : undefined,
+          total: event.total > 0 ? event.total : undefined,
+          percentage: event.percentage,
+          speedBps: undefined,
+          etaSeconds: undefined,
+        };
+      }
+      return null;
     default:
       return null;
   }
@@ -285,6 +336,19 @@ export function useDownloadManager(options: UseDownloadManagerOptions = {}): Use
     );
   }, []);
 
+  /**
+   * Seed the view from a snapshot's active item, unless progress events for
+   * it have already arrived: those are at least as fresh and carry per-shard
+   * detail the snapshot lacks.
+   */
+  const seedProgress = useCallback((status: DownloadQueueStatus) => {
+    const seed = status.current ? progressFromQueueItem(status.current) : null;
+    if (!seed) return;
+    setCurrentProgress((prev) =>
+      prev && prev.id === seed.id && prev.downloaded != null ? prev : seed
+    );
+  }, []);
+
   // Use refs to avoid re-creating event handler and causing subscription loops
   const applyProgressRef = useRef(applyProgress);
   applyProgressRef.current = applyProgress;
@@ -296,10 +360,11 @@ export function useDownloadManager(options: UseDownloadManagerOptions = {}): Use
     try {
       const snapshot = await getDownloadQueue();
       setQueueStatus(snapshot);
+      seedProgress(snapshot);
     } catch (e) {
       setError(e instanceof Error ? e.message : 'Failed to load queue');
     }
-  }, []);
+  }, [seedProgress]);
 
   useEffect(() => {
     refreshQueue();
@@ -312,6 +377,7 @@ export function useDownloadManager(options: UseDownloadManagerOptions = {}): Use
       if (event.type === 'queue_snapshot') {
         const snapshot = snapshotToQueueStatus(event.items, event.max_size);
         setQueueStatus(snapshot);
+        seedProgress(snapshot);
         
         // Clear old summary when new run starts (prevent stale success banner)
         if (snapshotIsBusy(event.items) && lastQueueSummaryRef.current) {
@@ -371,7 +437,7 @@ export function useDownloadManager(options: UseDownloadManagerOptions = {}): Use
     return () => {
       unsubscribe();
     };
-  }, [refreshQueue, cleanupTerminal]); // Only depend on refreshQueue, not handleEvent
+  }, [refreshQueue, cleanupTerminal, seedProgress]); // Only depend on refreshQueue, not handleEvent
 
   const queueModel = useCallback(async (modelId: string, quantization?: string) => {
     const response = await queueDownload({ modelId, quantization });
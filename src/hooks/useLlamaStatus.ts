@@ -3,13 +3,63 @@ import {
   checkLlamaInstalled, 
   installLlama as platformInstallLlama, 
   listenLlamaProgress,
+  buildLlamaFromSource as platformBuildLlama,
+  cancelLlamaBuild as platformCancelBuild,
+  listenLlamaBuild,
   type LlamaStatus,
   type LlamaInstallProgress,
+  type LlamaBuildEvent,
+  type LlamaBuildPhase,
   appLogger,
 } from '../services/platform';
 
 // Re-export types for consumers
-export type { LlamaStatus, LlamaInstallProgress };
+export type { LlamaStatus, LlamaInstallProgress, LlamaBuildPhase };
+
+/** Build log lines kept for display; older lines are dropped first. */
+const MAX_BUILD_LOG_LINES = 500;
+
+/** Where a source build stands, folded from its events. */
+export interface LlamaBuildState {
+  outcome: 'running' | 'completed' | 'failed' | 'cancelled';
+  phase: LlamaBuildPhase | null;
+  current: number;
+  total: number;
+  /** cmake target being compiled, when known. */
+  target: string | null;
+  log: string[];
+  /** Failure message, or the built version once completed. */
+  message: string | null;
+}
+
+const INITIAL_BUILD: LlamaBuildState = {
+  outcome: 'running',
+  phase: null,
+  current: 0,
+  total: 0,
+  target: null,
+  log: [],
+  message: null,
+};
+
+function applyBuildEvent(state: LlamaBuildState, event: LlamaBuildEvent): LlamaBuildState {
+  switch (event.type) {
+    case 'phase_started':
+      return { ...state, phase: event.phase, current: 0, total: 0, target: null };
+    case 'progress':
+      return { ...state, phase: event.phase, current: event.current, total: event.total, target: event.target };
+    case 'log':
+      return { ...state, log: [...state.log, event.message].slice(-MAX_BUILD_LOG_LINES) };
+    case 'phase_completed':
+      return state;
+    case 'completed':
+      return { ...state, outcome: 'completed', message: `${event.version} (${event.acceleration})` };
+    case 'failed':
+      return { ...state, outcome: 'failed', message: event.message };
+    case 'cancelled':
+      return { ...state, outcome: 'cancelled' };
+  }
+}
 
 export function useLlamaStatus() {
   const [status, setStatus] = useState<LlamaStatus | null>(null);
@@ -17,6 +67,7 @@ export function useLlamaStatus() {
   const [error, setError] = useState<string | null>(null);
   const [installing, setInstalling] = useState(false);
   const [installProgress, setInstallProgress] = useState<LlamaInstallProgress | null>(null);
+  const [build, setBuild] = useState<LlamaBuildState | null>(null);
 
   const checkStatus = useCallback(async () => {
     try {
@@ -65,6 +116,36 @@ export function useLlamaStatus() {
     }
   }, [checkStatus]);
 
+  const buildFromSource = useCallback(async () => {
+    setError(null);
+    setBuild(INITIAL_BUILD);
+    let unlisten: (() => void) | null = null;
+    try {
+      // Subscribe before starting so the first phase is not missed.
+      unlisten = await listenLlamaBuild((event) => {
+        setBuild((prev) => (prev ? applyBuildEvent(prev, event) : prev));
+      });
+      await platformBuildLlama();
+      await checkStatus();
+    } catch (err) {
+      const errorMessage = err instanceof Error ? err.message : String(err);
+      setError(`Failed to build llama.cpp: ${errorMessage}`);
+      setBuild((prev) =>
+        prev && prev.outcome === 'running' ? { ...prev, outcome: 'failed', message: errorMessage } : prev
+      );
+    } finally {
+      unlisten?.();
+    }
+  }, [checkStatus]);
+
+  const cancelBuild = useCallback(async () => {
+    try {
+      await platformCancelBuild();
+    } catch (err) {
+      appLogger.error('hook.llama', 'Failed to cancel llama.cpp build', { error: err });
+    }
+  }, []);
+
   // Initial status check
   useEffect(() => {
     checkStatus();
@@ -106,7 +187,10 @@ export function useLlamaStatus() {
     error,
     installing,
     installProgress,
+    build,
     checkStatus,
     installLlama,
+    buildFromSource,
+    cancelBuild,
   };
 }
@@ -21,6 +21,7 @@ import { useConfirmContext } from '../contexts/ConfirmContext';
 import { mkUserMessage, mkAssistantMessage } from '../types/messages';
 import { useServerState } from '../services/serverEvents';
 import { getServerToolSupport } from '../services/clients/servers';
+import { subscribeToEvent } from '../services/clients/events';
 import {
   listConversations,
   createConversation,
@@ -157,13 +158,23 @@ export default function ChatPage({
       showToast(
         next === 'crashed'
           ? 'Server crashed. Chat is now read-only.'
-          : 'Server stopped. Chat is now read-only.',
+          : serverState?.stopReason === 'idle'
+            ? 'Server stopped after being idle. Chat is now read-only.'
+            : 'Server stopped. Chat is now read-only.',
         'warning'
       );
     }
 
     prevStatusRef.current = next;
-  }, [serverState?.status, showToast]);
+  }, [serverState?.status, serverState?.stopReason, showToast]);
+
+  // Warn once per announced idle shutdown
+  const idleShutdownAt = serverState?.idleShutdownAt;
+  useEffect(() => {
+    if (idleShutdownAt === undefined) return;
+    const secs = Math.max(0, Math.round((idleShutdownAt - Date.now()) / 1000));
+    showToast(`Server idle — it will stop in ${secs}s unless you send a message.`, 'warning');
+  }, [idleShutdownAt, showToast]);
 
   // Sync conversations
   const syncConversations = useCallback(
@@ -179,7 +190,7 @@ export default function ChatPage({
         if (!list.length) {
           preferredId = await createConversation(
             DEFAULT_CONVERSATION_TITLE,
-            null,
+            modelId,
             DEFAULT_SYSTEM_PROMPT,
           );
           list = await listConversations();
@@ -211,6 +222,12 @@ export default function ChatPage({
     syncConversations();
   }, [syncConversations]);
 
+  // The backend titles new chats after their first exchange
+  useEffect(
+    () => subscribeToEvent('conversation', () => void syncConversations({ silent: true })),
+    [syncConversations],
+  );
+
   // Get active conversation
   const activeConversation = conversations.find((c) => c.id === activeConversationId) ?? null;
 
@@ -267,14 +284,14 @@ export default function ChatPage({
     try {
       const title = newConversationTitle.trim() || DEFAULT_CONVERSATION_TITLE;
       const systemPrompt = newConversationPrompt.trim() || DEFAULT_SYSTEM_PROMPT;
-      const newId = await createConversation(title, null, systemPrompt);
+      const newId = await createConversation(title, modelId, systemPrompt);
       persistedMessageIds.current = new Set();
       
       // Insert new conversation locally before selecting it
       const newConversation: ConversationSummary = {
         id: newId,
         title,
-        model_id: null,
+        model_id: modelId,
         system_prompt: systemPrompt,
         settings: null,
         created_at: new Date().toISOString(),
@@ -331,7 +348,7 @@ export default function ChatPage({
       await deleteConversation(activeConversation.id);
       const newId = await createConversation(
         activeConversation.title,
-        null,
+        modelId,
         activeConversation.system_prompt ?? DEFAULT_SYSTEM_PROMPT,
       );
       persistedMessageIds.current = new Set();
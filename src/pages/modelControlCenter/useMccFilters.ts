@@ -43,6 +43,9 @@ function buildQueryParams(filters: FilterState): string {
   if (filters.selectedQuantizations.length > 0) {
     p.set('quantizations', filters.selectedQuantizations.join(','));
   }
+  if (filters.selectedLicenses.length > 0) {
+    p.set('licenses', filters.selectedLicenses.join(','));
+  }
   if (filters.selectedTags.length > 0) {
     p.set('tags', filters.selectedTags.join(','));
   }
@@ -71,6 +74,7 @@ export function useMccFilters({
     contextRange: null,
     speedRange: null,
     selectedQuantizations: [],
+    selectedLicenses: [],
     selectedTags: [],
   });
 
@@ -87,6 +91,7 @@ export function useMccFilters({
       contextRange: null,
       speedRange: null,
       selectedQuantizations: [],
+      selectedLicenses: [],
       selectedTags: [],
     }));
   }, []);
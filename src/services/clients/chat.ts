@@ -16,6 +16,7 @@ import type {
   CreateConversationParams,
   SaveMessageParams,
   DeleteMessageResult,
+  MessageRevision,
   GenerateTitleParams,
 } from '../transport/types/chat';
 
@@ -27,6 +28,7 @@ export type {
   CreateConversationParams,
   SaveMessageParams,
   DeleteMessageResult,
+  MessageRevision,
   GenerateTitleParams,
 };
 
@@ -45,18 +47,20 @@ export async function listConversations(): Promise<ConversationSummary[]> {
 }
 
 /**
- * Create a new conversation.
+ * Create a new conversation. An incognito one is held in memory only.
  * @returns The new conversation ID
  */
 export async function createConversation(
   title: string,
   modelId?: ModelId | null,
-  systemPrompt?: string | null
+  systemPrompt?: string | null,
+  incognito?: boolean
 ): Promise<ConversationId> {
   return getTransport().createConversation({
     title,
     modelId,
     systemPrompt,
+    incognito,
   });
 }
 
@@ -137,6 +141,36 @@ export async function deleteMessage(id: MessageId): Promise<DeleteMessageResult>
   return getTransport().deleteMessage(id);
 }
 
+/**
+ * Replace an assistant reply with a regenerated one.
+ * The old reply stays available via getMessageVersions.
+ */
+export async function regenerateMessage(
+  id: MessageId,
+  content: string,
+  metadata?: ChatMessageMetadata | null
+): Promise<MessageRevision> {
+  return getTransport().regenerateMessage(id, { content, metadata });
+}
+
+/**
+ * Replace a user message with an edited one, superseding everything after it.
+ */
+export async function editMessage(
+  id: MessageId,
+  content: string,
+  metadata?: ChatMessageMetadata | null
+): Promise<MessageRevision> {
+  return getTransport().editMessage(id, { content, metadata });
+}
+
+/**
+ * Get every version of a message, oldest first.
+ */
+export async function getMessageVersions(id: MessageId): Promise<ChatMessage[]> {
+  return getTransport().getMessageVersions(id);
+}
+
 // ============================================================================
 // AI Title Generation
 // ============================================================================
@@ -11,6 +11,9 @@ import { getTransport } from '../transport';
 import type { DownloadId } from '../transport/types/ids';
 import type {
   DownloadQueueStatus,
+  CollectionQueueResult,
+  GroupProgress,
+  QueueCollectionParams,
   QueueDownloadParams,
   QueueDownloadResponse,
 } from '../transport/types/downloads';
@@ -29,6 +32,15 @@ export async function queueDownload(params: QueueDownloadParams): Promise<QueueD
   return getTransport().queueDownload(params);
 }
 
+/**
+ * Queue every GGUF repository in a HuggingFace collection.
+ */
+export async function queueCollection(
+  params: QueueCollectionParams
+): Promise<CollectionQueueResult> {
+  return getTransport().queueCollection(params);
+}
+
 /**
  * Cancel an active or queued download.
  */
@@ -57,6 +69,13 @@ export async function cancelShardGroup(groupId: string): Promise<void> {
   return getTransport().cancelShardGroup(groupId);
 }
 
+/**
+ * Get aggregate byte progress of a download group.
+ */
+export async function getShardGroupProgress(groupId: string): Promise<GroupProgress> {
+  return getTransport().getShardGroupProgress(groupId);
+}
+
 /**
  * Reorder downloads in the queue.
  */
@@ -70,3 +89,24 @@ export async function reorderQueue(ids: DownloadId[]): Promise<void> {
 export async function reorderQueueItem(id: DownloadId, position: number): Promise<number> {
   return getTransport().reorderQueueItem(id, position);
 }
+
+/**
+ * Queue a failed download again.
+ */
+export async function retryDownload(id: DownloadId): Promise<number> {
+  return getTransport().retryDownload(id);
+}
+
+/**
+ * Get how many downloads the queue holds.
+ */
+export async function getMaxQueueSize(): Promise<number> {
+  return getTransport().getMaxQueueSize();
+}
+
+/**
+ * Change how many downloads the queue holds.
+ */
+export async function setMaxQueueSize(size: number): Promise<void> {
+  return getTransport().setMaxQueueSize(size);
+}
@@ -9,7 +9,7 @@
 
 import { getTransport } from '../transport';
 import type { ModelId } from '../transport/types/ids';
-import type { ServeConfig, ServerInfo, ToolSupportResponse } from '../../types';
+import type { ServeConfig, ServerInfo, ServerStats, ToolSupportResponse } from '../../types';
 import type { ServeResponse } from '../transport/types/servers';
 import type { ProxyConfig, ProxyStatus } from '../transport/types/proxy';
 
@@ -62,3 +62,7 @@ export async function stopProxy(): Promise<void> {
 export async function getServerToolSupport(modelId: ModelId): Promise<ToolSupportResponse> {
   return getTransport().getServerToolSupport(modelId);
 }
+
+export async function getServerStats(modelId: ModelId): Promise<ServerStats> {
+  return getTransport().getServerStats(modelId);
+}
@@ -28,7 +28,9 @@ const KNOWN_DOWNLOAD_EVENT_TYPES = new Set([
   'download_cancelled',
   'download_status_changed',
   'download_notice',
+  'phase_progress',
   'queue_run_complete',
+  'partial_cleanup',
 ]);
 
 /**
@@ -0,0 +1,151 @@
+/**
+ * Application log viewer utilities
+ * TRANSPORT_EXCEPTION: Uses Tauri invoke for log queries on desktop.
+ * UI components should import from 'services/platform' rather than checking isTauriApp directly.
+ */
+
+import { appLogger } from './index';
+import { getApiBaseUrl } from '../../config/api';
+import { isDesktop } from './detect';
+
+export type AppLogLevel = 'trace' | 'debug' | 'info' | 'warn' | 'error';
+
+/** A rotated log file (`gglib.log.YYYY-MM-DD`). */
+export interface AppLogFile {
+  name: string;
+  sizeBytes: number;
+  modified?: string | null;
+}
+
+/** One record read back from the log files. */
+export interface AppLogRecord {
+  /** `<file>:<line>` position; pass as `before`/`after` to page. */
+  cursor: string;
+  timestamp: string;
+  level: AppLogLevel;
+  target?: string | null;
+  spans?: string | null;
+  message: string;
+}
+
+/** Filters and paging for a log query. Every field is optional. */
+export interface AppLogQuery {
+  file?: string;
+  /** Minimum severity. */
+  level?: AppLogLevel;
+  /** Target prefix, e.g. `gglib_download`. */
+  target?: string;
+  /** Case-insensitive message substring. */
+  contains?: string;
+  /** RFC 3339 lower bound (inclusive). */
+  since?: string;
+  /** RFC 3339 upper bound (exclusive). */
+  until?: string;
+  before?: string;
+  after?: string;
+  limit?: number;
+}
+
+export interface AppLogPage {
+  records: AppLogRecord[];
+  /** Cursor for the previous page; absent when there are no older records. */
+  older?: string | null;
+  /** Cursor to pass as `after` to fetch only newer records. */
+  latest?: string | null;
+}
+
+/** How often the desktop follower polls for new records. */
+const FOLLOW_POLL_MS = 1000;
+
+function toSearchParams(query: AppLogQuery): string {
+  const params = new URLSearchParams();
+  for (const [key, value] of Object.entries(query)) {
+    if (value !== undefined && value !== null && value !== '') {
+      params.set(key, String(value));
+    }
+  }
+  const encoded = params.toString();
+  return encoded ? `?${encoded}` : '';
+}
+
+/**
+ * Query one page of application log records (newest matches by default).
+ */
+export async function queryAppLogs(query: AppLogQuery = {}): Promise<AppLogPage> {
+  if (isDesktop()) {
+    const { invoke } = await import('@tauri-apps/api/core');
+    return invoke<AppLogPage>('query_app_logs', { query });
+  }
+
+  const baseUrl = getApiBaseUrl();
+  const response = await fetch(`${baseUrl}/api/logs${toSearchParams(query)}`);
+  if (!response.ok) {
+    throw new Error(`Log query failed: ${response.status}`);
+  }
+  return (await response.json()) as AppLogPage;
+}
+
+/**
+ * List the application log files, oldest first.
+ */
+export async function listAppLogFiles(): Promise<AppLogFile[]> {
+  if (isDesktop()) {
+    const { invoke } = await import('@tauri-apps/api/core');
+    return invoke<AppLogFile[]>('list_app_log_files');
+  }
+
+  const baseUrl = getApiBaseUrl();
+  const response = await fetch(`${baseUrl}/api/logs/files`);
+  return response.ok ? ((await response.json()) as AppLogFile[]) : [];
+}
+
+/**
+ * Follow new application log records matching `query`, starting after
+ * `query.after` (or the current end of the logs). Returns an unsubscribe function.
+ */
+export async function followAppLogs(
+  query: AppLogQuery,
+  callback: (record: AppLogRecord) => void
+): Promise<() => void> {
+  if (isDesktop()) {
+    let after = query.after ?? (await queryAppLogs({ ...query, limit: 1 })).latest ?? undefined;
+    let stopped = false;
+    let timer: ReturnType<typeof setTimeout>;
+    const poll = async () => {
+      if (stopped) return;
+      try {
+        const page = await queryAppLogs({ ...query, before: undefined, after });
+        page.records.forEach(callback);
+        after = page.latest ?? after;
+      } catch (e) {
+        appLogger.error('service.logs', 'Failed to poll application logs', { error: e });
+      }
+      if (!stopped) timer = setTimeout(poll, FOLLOW_POLL_MS);
+    };
+    timer = setTimeout(poll, FOLLOW_POLL_MS);
+    return () => {
+      stopped = true;
+      clearTimeout(timer);
+    };
+  }
+
+  // Web mode: use SSE
+  const baseUrl = getApiBaseUrl();
+  const eventSource = new EventSource(`${baseUrl}/api/logs/stream${toSearchParams(query)}`);
+
+  eventSource.onmessage = (event) => {
+    try {
+      if (!event.data || event.data.trim() === '') return;
+      if (event.data === 'ping') return;
+      callback(JSON.parse(event.data) as AppLogRecord);
+    } catch (e) {
+      appLogger.error('service.logs', 'Failed to parse log event', { error: e, data: event.data });
+    }
+  };
+
+  eventSource.onerror = (err) => {
+    appLogger.error('service.logs', 'SSE Error', { error: err });
+  };
+
+  return () => eventSource.close();
+}
@@ -23,12 +23,28 @@ export type { AppLogCategory } from './logging/appLogger';
 export type { LogLevel, LogEntry, ILogger } from './logging/types';
 
 // Llama binary management
-export { checkLlamaInstalled, installLlama, listenLlamaProgress } from './llamaInstall';
-export type { LlamaStatus, LlamaInstallProgress } from './llamaInstall';
+export {
+  checkLlamaInstalled,
+  installLlama,
+  listenLlamaProgress,
+  buildLlamaFromSource,
+  cancelLlamaBuild,
+  listenLlamaBuild,
+} from './llamaInstall';
+export type {
+  LlamaStatus,
+  LlamaInstallProgress,
+  LlamaBuildEvent,
+  LlamaBuildPhase,
+} from './llamaInstall';
 
 // Server logs
 export { getServerLogs, listenToServerLogs } from './serverLogs';
 export type { ServerLogEntry } from './serverLogs';
 
+// Application logs (log viewer)
+export { queryAppLogs, listAppLogFiles, followAppLogs } from './appLogs';
+export type { AppLogLevel, AppLogFile, AppLogRecord, AppLogQuery, AppLogPage } from './appLogs';
+
 // Payload truncation utilities
 export { truncateString, truncatePayload } from './logging/truncate';
@@ -67,3 +67,67 @@ export async function listenLlamaProgress(
   });
   return unlisten;
 }
+
+/** Stage of a llama.cpp source build. */
+export type LlamaBuildPhase =
+  | 'dependency_check'
+  | 'clone_or_update_repo'
+  | 'configure'
+  | 'compile'
+  | 'install_binaries';
+
+/** Event streamed while llama.cpp builds from source. */
+export type LlamaBuildEvent =
+  | { type: 'phase_started'; phase: LlamaBuildPhase }
+  | { type: 'log'; message: string }
+  | { type: 'progress'; phase: LlamaBuildPhase; current: number; total: number; target: string | null }
+  | { type: 'phase_completed'; phase: LlamaBuildPhase }
+  | { type: 'completed'; version: string; acceleration: string }
+  | { type: 'failed'; message: string }
+  | { type: 'cancelled' };
+
+/**
+ * Build llama.cpp from source. Resolves when the build has finished or was
+ * cancelled; follow it with {@link listenLlamaBuild}.
+ * No-op on web.
+ */
+export async function buildLlamaFromSource(): Promise<void> {
+  if (!isDesktop()) {
+    return;
+  }
+
+  const { invoke } = await import('@tauri-apps/api/core');
+  await invoke('build_llama_from_source');
+}
+
+/**
+ * Cancel the running source build, killing cmake and its compilers.
+ * Returns false when no build was running. No-op on web.
+ */
+export async function cancelLlamaBuild(): Promise<boolean> {
+  if (!isDesktop()) {
+    return false;
+  }
+
+  const { invoke } = await import('@tauri-apps/api/core');
+  return invoke<boolean>('cancel_llama_build');
+}
+
+/**
+ * Listen for llama.cpp source build events.
+ * Returns an unsubscribe function.
+ * No-op on web (returns empty function).
+ */
+export async function listenLlamaBuild(
+  callback: (event: LlamaBuildEvent) => void
+): Promise<() => void> {
+  if (!isDesktop()) {
+    return () => {};
+  }
+
+  const { listen } = await import('@tauri-apps/api/event');
+  const unlisten = await listen<LlamaBuildEvent>('llama-build-progress', (event) => {
+    callback(event.payload);
+  });
+  return unlisten;
+}
@@ -12,7 +12,8 @@ export type CanonicalServerEventName =
   | 'server:started'
   | 'server:stopped'
   | 'server:error'
-  | 'server:health_changed';
+  | 'server:health_changed'
+  | 'server:idle_warning';
 
 function toRecord(payload: unknown): Record<string, unknown> | null {
   if (typeof payload !== 'object' || payload === null) return null;
@@ -99,6 +100,16 @@ function normalizeHealthChanged(data: Record<string, unknown>): ServerEvent | nu
   };
 }
 
+function normalizeIdleWarning(data: Record<string, unknown>): ServerEvent | null {
+  const modelId = String(data.modelId ?? data.model_id ?? '');
+  if (!modelId) return null;
+
+  const raw = data.shutdownInSecs ?? data.shutdown_in_secs;
+  if (typeof raw !== 'number') return null;
+
+  return { type: 'idle_warning', modelId, shutdownInSecs: raw, updatedAt: Date.now() };
+}
+
 function normalizeLifecycle(
   kind: 'running' | 'stopped' | 'crashed',
   data: Record<string, unknown>
@@ -119,7 +130,11 @@ function normalizeLifecycle(
         : Date.now();
 
   if (kind === 'running') return { type: 'running', modelId, port, updatedAt, modelName };
-  if (kind === 'stopped') return { type: 'stopped', modelId, port, updatedAt, modelName };
+  if (kind === 'stopped') {
+    const reason =
+      data.reason === 'idle' || data.reason === 'unhealthy' ? data.reason : 'requested';
+    return { type: 'stopped', modelId, port, updatedAt, modelName, reason };
+  }
 
   // server:error may omit modelId on the Rust side; ignore in that case.
   return { type: 'crashed', modelId, port, updatedAt, modelName };
@@ -146,6 +161,8 @@ export function normalizeServerEventFromNamedEvent(
       return normalizeLifecycle('crashed', data);
     case 'server:health_changed':
       return normalizeHealthChanged(data);
+    case 'server:idle_warning':
+      return normalizeIdleWarning(data);
     default:
       return null;
   }
@@ -175,6 +192,8 @@ export function normalizeServerEventFromAppEvent(payload: unknown): ServerEvent
       return normalizeLifecycle('crashed', data);
     case 'server_health_changed':
       return normalizeHealthChanged(data);
+    case 'server_idle_warning':
+      return normalizeIdleWarning(data);
     default:
       return null;
   }
@@ -10,6 +10,7 @@
  * - server:stopped  - Server stopped cleanly
  * - server:error    - Server encountered an error
  * - server:health_changed - Health status changed
+ * - server:idle_warning - Server will be stopped soon for inactivity
  */
 
 import { appLogger } from './platform';
@@ -42,6 +43,7 @@ export async function initTauriServerEvents(): Promise<void> {
       'server:stopped',
       'server:error',
       'server:health_changed',
+      'server:idle_warning',
     ];
 
     for (const eventType of eventTypes) {
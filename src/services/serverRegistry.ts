@@ -24,6 +24,9 @@ export type { ServerHealthStatus } from '../types';
 
 export type ServerStatus = 'running' | 'stopping' | 'stopped' | 'crashed';
 
+/** Why a server stopped, as reported by the backend. */
+export type ServerStopReason = 'requested' | 'idle' | 'unhealthy';
+
 export interface ServerState {
   status: ServerStatus;
   port?: number;
@@ -32,6 +35,10 @@ export interface ServerState {
   health?: ServerHealthStatus;
   /** Model name for display purposes */
   modelName?: string;
+  /** Why the server stopped (set on 'stopped' only) */
+  stopReason?: ServerStopReason;
+  /** Epoch ms when an idle shutdown is due, if one was announced */
+  idleShutdownAt?: number;
 }
 
 export interface ServerStateInfo {
@@ -48,9 +55,10 @@ export type ServerEvent =
   | { type: 'snapshot'; servers: ServerStateInfo[] }
   | { type: 'running'; modelId: string; port?: number; updatedAt: number; modelName?: string }
   | { type: 'stopping'; modelId: string; port?: number; updatedAt: number; modelName?: string }
-  | { type: 'stopped'; modelId: string; port?: number; updatedAt: number; modelName?: string }
+  | { type: 'stopped'; modelId: string; port?: number; updatedAt: number; modelName?: string; reason?: ServerStopReason }
   | { type: 'crashed'; modelId: string; port?: number; updatedAt: number; modelName?: string }
-  | { type: 'server_health_changed'; modelId: string; status: ServerHealthStatus; detail?: string; updatedAt: number };
+  | { type: 'server_health_changed'; modelId: string; status: ServerHealthStatus; detail?: string; updatedAt: number }
+  | { type: 'idle_warning'; modelId: string; shutdownInSecs: number; updatedAt: number };
 
 // ============================================================================
 // Registry State
@@ -145,6 +153,7 @@ export function ingestServerEvent(evt: ServerEvent): void {
           // Clear health on lifecycle transitions (will be updated by health monitor)
           health: evt.type === 'running' ? { status: 'healthy' } : undefined,
           modelName: evt.modelName ?? existing?.modelName,
+          stopReason: evt.type === 'stopped' ? (evt.reason ?? 'requested') : undefined,
         });
         notifyListeners();
       }
@@ -164,6 +173,18 @@ export function ingestServerEvent(evt: ServerEvent): void {
       }
       break;
     }
+
+    case 'idle_warning': {
+      const existing = state.get(evt.modelId);
+      if (existing && isRunningState(existing)) {
+        state.set(evt.modelId, {
+          ...existing,
+          idleShutdownAt: evt.updatedAt + evt.shutdownInSecs * 1000,
+        });
+        notifyListeners();
+      }
+      break;
+    }
   }
 }
 
@@ -14,6 +14,7 @@ import type {
   SaveMessageParams,
   UpdateMessageParams,
   DeleteMessageResult,
+  MessageRevision,
   GenerateTitleParams,
 } from '../types/chat';
 import { DEFAULT_TITLE_GENERATION_PROMPT } from '../types/chat';
@@ -41,6 +42,7 @@ export async function createConversation(
       title: params.title,
       model_id: params.modelId,
       system_prompt: params.systemPrompt,
+      incognito: params.incognito,
     }
   );
   return response.id;
@@ -114,6 +116,41 @@ export async function deleteMessage(id: MessageId): Promise<DeleteMessageResult>
   return del<DeleteMessageResult>(`/api/messages/${id}`);
 }
 
+/**
+ * Replace an assistant reply with a regenerated one.
+ * The old reply stays available via getMessageVersions.
+ */
+export async function regenerateMessage(
+  id: MessageId,
+  params: UpdateMessageParams
+): Promise<MessageRevision> {
+  return post<MessageRevision>(`/api/messages/${id}/regenerate`, {
+    content: params.content,
+    metadata: params.metadata ?? null,
+  });
+}
+
+/**
+ * Replace a user message with an edited one.
+ * Every message after it is superseded.
+ */
+export async function editMessage(
+  id: MessageId,
+  params: UpdateMessageParams
+): Promise<MessageRevision> {
+  return post<MessageRevision>(`/api/messages/${id}/edit`, {
+    content: params.content,
+    metadata: params.metadata ?? null,
+  });
+}
+
+/**
+ * Get every version of a message, oldest first.
+ */
+export async function getMessageVersions(id: MessageId): Promise<ChatMessage[]> {
+  return get<ChatMessage[]>(`/api/messages/${id}/versions`);
+}
+
 /**
  * Generate a chat title using the served LLM.
  */
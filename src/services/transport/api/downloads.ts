@@ -3,11 +3,14 @@
  * Handles download queue management for HuggingFace models.
  */
 
-import { get, post, del } from './client';
+import { get, post, put, del } from './client';
 import type { DownloadId } from '../types/ids';
 import type {
   DownloadQueueStatus,
   DownloadQueueItem,
+  CollectionQueueResult,
+  GroupProgress,
+  QueueCollectionParams,
   QueueDownloadParams,
   QueueDownloadResponse,
 } from '../types/downloads';
@@ -55,6 +58,18 @@ export async function queueDownload(params: QueueDownloadParams): Promise<QueueD
   });
 }
 
+/**
+ * Queue every GGUF repository in a HuggingFace collection.
+ */
+export async function queueCollection(
+  params: QueueCollectionParams
+): Promise<CollectionQueueResult> {
+  return post<CollectionQueueResult>('/api/models/downloads/collection', {
+    slug: params.slug,
+    quantization: params.quantization,
+  });
+}
+
 /**
  * Cancel an active or queued download.
  */
@@ -83,6 +98,15 @@ export async function cancelShardGroup(groupId: string): Promise<void> {
   await post<void>(`/api/models/downloads/shard-group/${encodeURIComponent(groupId)}/cancel`);
 }
 
+/**
+ * Get aggregate byte progress of a download group.
+ */
+export async function getShardGroupProgress(groupId: string): Promise<GroupProgress> {
+  return get<GroupProgress>(
+    `/api/models/downloads/shard-group/${encodeURIComponent(groupId)}/progress`
+  );
+}
+
 /**
  * Reorder downloads in the queue.
  */
@@ -103,3 +127,30 @@ export async function reorderQueueItem(id: DownloadId, position: number): Promis
   });
   return response;
 }
+
+/**
+ * Queue a failed download again. A sharded download only fetches the
+ * shards that are not already on disk.
+ * @returns 1-based position in the queue
+ */
+export async function retryDownload(id: DownloadId): Promise<number> {
+  const response = await post<{ position: number }>(
+    `/api/models/downloads/${encodeURIComponent(id)}/retry`
+  );
+  return response.position;
+}
+
+/**
+ * Get how many downloads the queue holds.
+ */
+export async function getMaxQueueSize(): Promise<number> {
+  const response = await get<{ max_size: number }>('/api/models/downloads/max-size');
+  return response.max_size;
+}
+
+/**
+ * Change how many downloads the queue holds.
+ */
+export async function setMaxQueueSize(size: number): Promise<void> {
+  await put<{ max_size: number }>('/api/models/downloads/max-size', { max_size: size });
+}
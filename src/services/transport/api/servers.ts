@@ -6,7 +6,7 @@
 import { post, get } from './client';
 import type { ModelId } from '../types/ids';
 import type { ServeConfig, ServeResponse, ServerInfo } from '../types/servers';
-import type { ToolSupportResponse } from '../../../types';
+import type { ServerStats, ToolSupportResponse } from '../../../types';
 import { toStartServerRequest } from '../mappers';
 
 /**
@@ -37,3 +37,10 @@ export async function listServers(): Promise<ServerInfo[]> {
 export async function getServerToolSupport(modelId: ModelId): Promise<ToolSupportResponse> {
   return get<ToolSupportResponse>(`/api/servers/${modelId}/tool-support`);
 }
+
+/**
+ * Retrieve latency and throughput stats for a running server's session.
+ */
+export async function getServerStats(modelId: ModelId): Promise<ServerStats> {
+  return get<ServerStats>(`/api/servers/${modelId}/stats`);
+}
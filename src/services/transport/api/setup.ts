@@ -5,7 +5,16 @@
 
 import { get, post } from './client';
 import { getApiBaseUrl, getAuthHeaders } from './client';
-import type { SetupStatus, LlamaInstallProgress, VulkanStatus } from '../../../types/setup';
+import type {
+  SetupStatus,
+  LlamaInstallProgress,
+  VulkanStatus,
+  ModelTask,
+  RecommendationReport,
+  OnboardingStatus,
+  OnboardingStep,
+  OnboardingStepRequest,
+} from '../../../types/setup';
 
 /**
  * Get the current system setup status.
@@ -14,6 +23,46 @@ export async function getSetupStatus(): Promise<SetupStatus> {
   return get<SetupStatus>('/api/config/system/setup-status');
 }
 
+/**
+ * Get the observed status of every first-run onboarding step.
+ */
+export async function getOnboardingStatus(): Promise<OnboardingStatus> {
+  return get<OnboardingStatus>('/api/config/system/onboarding');
+}
+
+/**
+ * Execute one onboarding step; progress arrives as 'onboarding' events.
+ */
+export async function runOnboardingStep(
+  step: OnboardingStep,
+  request: OnboardingStepRequest = {},
+): Promise<OnboardingStatus> {
+  return post<OnboardingStatus>(`/api/config/system/onboarding/${step}`, request);
+}
+
+/**
+ * Skip one onboarding step so it no longer blocks completion.
+ */
+export async function skipOnboardingStep(step: OnboardingStep): Promise<OnboardingStatus> {
+  return post<OnboardingStatus>(`/api/config/system/onboarding/${step}/skip`);
+}
+
+/**
+ * Get models that fit this machine, ranked, optionally for one task.
+ */
+export async function getModelRecommendations(
+  task?: ModelTask,
+  limit?: number,
+): Promise<RecommendationReport> {
+  const params = new URLSearchParams();
+  if (task) params.set('task', task);
+  if (limit !== undefined) params.set('limit', String(limit));
+  const query = params.toString();
+  return get<RecommendationReport>(
+    `/api/config/system/recommendations${query ? `?${query}` : ''}`,
+  );
+}
+
 /**
  * Get Vulkan build-readiness status.
  */
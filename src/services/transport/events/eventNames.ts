@@ -18,6 +18,8 @@ export const DOWNLOAD_EVENT_NAMES = [
   'download:cancelled',
   'download:queue_snapshot',
   'download:queue_run_complete',
+  'download:phase',
+  'download:partial_cleanup',
 ] as const;
 
 /**
@@ -28,6 +30,7 @@ export const SERVER_EVENT_NAMES = [
   'server:stopped',
   'server:error',
   'server:snapshot',
+  'server:idle_warning',
 ] as const;
 
 /**
@@ -72,6 +75,42 @@ export const PROXY_EVENT_NAMES = [
   'proxy:started',
   'proxy:stopped',
   'proxy:crashed',
+  'proxy:config_reloaded',
+] as const;
+
+/**
+ * Onboarding-related event names.
+ */
+export const ONBOARDING_EVENT_NAMES = [
+  'onboarding:progress',
+] as const;
+
+/**
+ * Conversation-related event names.
+ */
+export const CONVERSATION_EVENT_NAMES = [
+  'conversation:updated',
+] as const;
+
+/**
+ * Backup-related event names.
+ */
+export const BACKUP_EVENT_NAMES = [
+  'backup:progress',
+] as const;
+
+/**
+ * Job-related event names.
+ */
+export const JOB_EVENT_NAMES = [
+  'job:updated',
+] as const;
+
+/**
+ * Settings-related event names.
+ */
+export const SETTINGS_EVENT_NAMES = [
+  'settings:changed',
 ] as const;
 
 /**
@@ -84,3 +123,8 @@ export type McpEventName = typeof MCP_EVENT_NAMES[number];
 export type ModelEventName = typeof MODEL_EVENT_NAMES[number];
 export type VerificationEventName = typeof VERIFICATION_EVENT_NAMES[number];
 export type ProxyEventName = typeof PROXY_EVENT_NAMES[number];
+export type OnboardingEventName = typeof ONBOARDING_EVENT_NAMES[number];
+export type ConversationEventName = typeof CONVERSATION_EVENT_NAMES[number];
+export type BackupEventName = typeof BACKUP_EVENT_NAMES[number];
+export type JobEventName = typeof JOB_EVENT_NAMES[number];
+export type SettingsEventName = typeof SETTINGS_EVENT_NAMES[number];
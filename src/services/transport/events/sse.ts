@@ -192,6 +192,11 @@ function getEventCategory(outerType: string): AppEventType | null {
   if (outerType === 'log' || outerType.startsWith('log_')) return 'log';
   if (outerType.startsWith('verification_') || outerType.startsWith('verification:')) return 'verification';
   if (outerType.startsWith('proxy_')) return 'proxy';
+  if (outerType.startsWith('onboarding_')) return 'onboarding';
+  if (outerType.startsWith('conversation_')) return 'conversation';
+  if (outerType.startsWith('backup_')) return 'backup';
+  if (outerType.startsWith('job_')) return 'job';
+  if (outerType.startsWith('settings_')) return 'settings';
   return null;
 }
 
@@ -8,9 +8,14 @@ import type { UnlistenFn } from '@tauri-apps/api/event';
 import type { Unsubscribe, EventHandler } from '../types/common';
 import type { AppEventType, AppEventMap } from '../types/events';
 import {
+  BACKUP_EVENT_NAMES,
+  CONVERSATION_EVENT_NAMES,
   DOWNLOAD_EVENT_NAMES,
+  JOB_EVENT_NAMES,
+  ONBOARDING_EVENT_NAMES,
   PROXY_EVENT_NAMES,
   SERVER_EVENT_NAMES,
+  SETTINGS_EVENT_NAMES,
   LOG_EVENT_NAMES,
   VERIFICATION_EVENT_NAMES,
 } from './eventNames';
@@ -27,6 +32,11 @@ const TAURI_EVENT_NAMES: Record<AppEventType, readonly string[]> = {
   'log': LOG_EVENT_NAMES,
   'proxy': PROXY_EVENT_NAMES,
   'verification': VERIFICATION_EVENT_NAMES,
+  'onboarding': ONBOARDING_EVENT_NAMES,
+  'conversation': CONVERSATION_EVENT_NAMES,
+  'backup': BACKUP_EVENT_NAMES,
+  'job': JOB_EVENT_NAMES,
+  'settings': SETTINGS_EVENT_NAMES,
 };
 
 /**
@@ -6,6 +6,7 @@
  */
 
 import type { ServeConfig } from '../transport/types/models';
+import type { NumaStrategy, RopeConfig, SplitMode } from '../../types';
 
 /**
  * Request shape matching Rust's StartServerRequest.
@@ -21,6 +22,23 @@ export interface StartServerRequest {
   mtpDraftNMax?: number;
   /** Minimum acceptance probability for MTP draft tokens. Matches Rust mtp_draft_p_min. */
   mtpDraftPMin?: number;
+  /** RoPE scaling overrides. Matches Rust rope. */
+  rope?: RopeConfig;
+  /** Matches Rust threads / threads_batch / cpu_affinity / numa. */
+  threads?: number;
+  threadsBatch?: number;
+  cpuAffinity?: string;
+  numa?: NumaStrategy;
+  /** Matches Rust split_mode / tensor_split / main_gpu. */
+  splitMode?: SplitMode;
+  tensorSplit?: string;
+  mainGpu?: number;
+  /** Seconds between health probes. Matches Rust health_check_interval_secs. */
+  healthCheckIntervalSecs?: number;
+  /** Parallel request slots. Matches Rust parallel. */
+  parallel?: number;
+  /** Continuous batching. Matches Rust cont_batching. */
+  contBatching?: boolean;
   // Inference parameters as nested object (matches Rust's inference_params field)
   inferenceParams?: {
     temperature?: number;
@@ -65,6 +83,17 @@ export function toStartServerRequest(config: ServeConfig): StartServerRequest {
     reasoningFormat: undefined,
     mtpDraftNMax: config.specDraftNMax,
     mtpDraftPMin: config.specDraftPMin,
+    rope: config.rope,
+    threads: config.threads,
+    threadsBatch: config.threadsBatch,
+    cpuAffinity: config.cpuAffinity,
+    numa: config.numa,
+    splitMode: config.splitMode,
+    tensorSplit: config.tensorSplit,
+    mainGpu: config.mainGpu,
+    healthCheckIntervalSecs: config.healthCheckIntervalSecs,
+    parallel: config.parallel,
+    contBatching: config.contBatching,
     inferenceParams,
   };
 }
@@ -27,6 +27,8 @@ export interface ConversationSettings {
   max_parallel?: number | null;
   max_iterations?: number | null;
   no_tools?: boolean | null;
+  hf_repo_id?: string | null;
+  quantization?: string | null;
 }
 
 /**
@@ -40,6 +42,8 @@ export interface ConversationSummary {
   settings: ConversationSettings | null;
   created_at: string;
   updated_at: string;
+  /** Held in memory only; gone when the app exits. */
+  incognito?: boolean;
 }
 
 import type { SerializableContentPart } from '../../../utils/messages/contentParts';
@@ -64,6 +68,10 @@ export interface ChatMessage {
   content: string;
   created_at: string;
   metadata?: ChatMessageMetadata | null;
+  /** Original message ID when this message is a regeneration or edit. */
+  version_of?: MessageId | null;
+  /** ID of the revision that replaced this message, if any. */
+  superseded_by?: MessageId | null;
 }
 
 /**
@@ -73,6 +81,8 @@ export interface CreateConversationParams {
   title: string;
   modelId?: ModelId | null;
   systemPrompt?: string | null;
+  /** Keep the conversation in memory only, never saved to history. */
+  incognito?: boolean;
 }
 
 /**
@@ -108,6 +118,16 @@ export interface DeleteMessageResult {
   deletedCount: number;
 }
 
+/**
+ * Result of regenerating or editing a message.
+ */
+export interface MessageRevision {
+  /** ID of the newly stored message. */
+  message_id: MessageId;
+  /** Number of messages superseded, including the replaced one. */
+  superseded: number;
+}
+
 /**
  * Parameters for generating a chat title via LLM.
  */
@@ -159,6 +179,15 @@ export interface ChatTransport {
   /** Delete a message and all subsequent messages. */
   deleteMessage(id: MessageId): Promise<DeleteMessageResult>;
 
+  /** Replace an assistant reply, keeping the old one as a version. */
+  regenerateMessage(id: MessageId, params: UpdateMessageParams): Promise<MessageRevision>;
+
+  /** Replace a user message and invalidate every message after it. */
+  editMessage(id: MessageId, params: UpdateMessageParams): Promise<MessageRevision>;
+
+  /** Get every version of a message, oldest first. */
+  getMessageVersions(id: MessageId): Promise<ChatMessage[]>;
+
   /** Generate a chat title using the served LLM. */
   generateChatTitle(params: GenerateTitleParams): Promise<string>;
 }
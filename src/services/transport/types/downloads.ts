@@ -20,6 +20,20 @@ export interface ShardInfo {
   file_size?: number | null;
 }
 
+/**
+ * Aggregate byte progress of a shard group, summed from actual shard sizes.
+ */
+export interface GroupProgress {
+  group_id: string;
+  total_shards: number;
+  completed_shards: number;
+  downloaded_bytes: number;
+  total_bytes: number;
+  /** False while some shard's size is still estimated. */
+  exact: boolean;
+  percentage: number;
+}
+
 /**
  * Download queue item.
  */
@@ -31,6 +45,12 @@ export interface DownloadQueueItem {
   error?: string | null;
   group_id?: string | null;
   shard_info?: ShardInfo | null;
+  /** Live progress of the active item; see `DownloadSummary`. */
+  downloaded_bytes?: number;
+  total_bytes?: number;
+  speed_bps?: number;
+  eta_seconds?: number;
+  elapsed_seconds?: number;
 }
 
 /**
@@ -64,6 +84,27 @@ export interface QueueDownloadResponse {
   shard_count: number;
 }
 
+/**
+ * Parameters for queueing a HuggingFace collection.
+ */
+export interface QueueCollectionParams {
+  /** Collection slug, e.g. `unsloth/qwen3-680edabfb790c8c34a242f95`. */
+  slug: string;
+  /** Quantization applied to every repository. If omitted, each repo's best is picked. */
+  quantization?: string;
+}
+
+/**
+ * Result of queueing a collection. The downloads share one queue run, so
+ * their combined outcome arrives in the usual queue-run summary.
+ */
+export interface CollectionQueueResult {
+  slug: string;
+  title: string;
+  queued: { repo_id: string; quantization: string; shard_count: number }[];
+  skipped: { repo_id: string; reason: string }[];
+}
+
 /**
  * Typed payload for download completion events.
  * Used by the UI effects layer (useDownloadCompletionEffects) to trigger
@@ -90,6 +131,9 @@ export interface DownloadsTransport {
   /** Queue a new download from HuggingFace. */
   queueDownload(params: QueueDownloadParams): Promise<QueueDownloadResponse>;
 
+  /** Queue every GGUF repository in a HuggingFace collection. */
+  queueCollection(params: QueueCollectionParams): Promise<CollectionQueueResult>;
+
   /** Cancel an active or queued download. */
   cancelDownload(id: DownloadId): Promise<void>;
 
@@ -102,9 +146,21 @@ export interface DownloadsTransport {
   /** Cancel all shards in a download group. */
   cancelShardGroup(groupId: string): Promise<void>;
 
+  /** Get aggregate byte progress of a download group. */
+  getShardGroupProgress(groupId: string): Promise<GroupProgress>;
+
   /** Reorder downloads in the queue. */
   reorderQueue(ids: DownloadId[]): Promise<void>;
 
   /** Reorder a single download to a specific position. */
   reorderQueueItem(id: DownloadId, position: number): Promise<number>;
+
+  /** Queue a failed download again; resolves to its 1-based position. */
+  retryDownload(id: DownloadId): Promise<number>;
+
+  /** Get how many downloads the queue holds. */
+  getMaxQueueSize(): Promise<number>;
+
+  /** Change how many downloads the queue holds (at least 1). */
+  setMaxQueueSize(size: number): Promise<void>;
 }
@@ -43,6 +43,14 @@ export interface DownloadSummary {
     filename: string;
     file_size?: number | null;
   } | null;
+  // Live progress of the active item, so a client that connects mid-download
+  // can render it without waiting for the next progress event. Bytes are 0 and
+  // the rest absent for items that are not transferring.
+  downloaded_bytes?: number;
+  total_bytes?: number;
+  speed_bps?: number;
+  eta_seconds?: number;
+  elapsed_seconds?: number;
 }
 
 /**
@@ -111,6 +119,12 @@ export interface QueueRunSummary {
   items: CompletionDetail[];
 }
 
+/**
+ * Phase of a running download. Finer-grained than DownloadStatus: hashing a
+ * finished file is 'verifying' while the status is still 'downloading'.
+ */
+export type DownloadPhase = 'resolving' | 'transferring' | 'verifying' | 'registering';
+
 export type DownloadEvent =
   | { type: 'queue_snapshot'; items: DownloadSummary[]; max_size: number }
   | { type: 'download_started'; id: DownloadId; shard_index?: number; total_shards?: number }
@@ -127,7 +141,13 @@ export type DownloadEvent =
   // fast downloader). Unlike download_status_changed, message is free-form
   // text rather than a fixed status.
   | { type: 'download_notice'; id: DownloadId; message: string }
-  | { type: 'queue_run_complete'; summary: QueueRunSummary };
+  // Phase boundary, or progress within a phase that works through bytes of
+  // its own (hashing during 'verifying'). percentage is absent when total is 0.
+  | { type: 'phase_progress'; id: DownloadId; phase: DownloadPhase; shard_index?: number; total_shards?: number; processed: number; total: number; percentage?: number }
+  | { type: 'queue_run_complete'; summary: QueueRunSummary }
+  // Janitor sweep of orphaned partial files. reclaimable_* covers every
+  // orphan found; removed_* only those already past the grace period.
+  | { type: 'partial_cleanup'; reclaimable_files: number; reclaimable_bytes: number; removed_files: number; removed_bytes: number };
 
 // ============================================================================
 // Log Events
@@ -173,7 +193,87 @@ export type VerificationEvent = VerificationProgressEvent | VerificationComplete
 export type ProxyEvent =
   | { type: 'proxy_started'; port: number }
   | { type: 'proxy_stopped' }
-  | { type: 'proxy_crashed' };
+  | { type: 'proxy_crashed' }
+  | { type: 'proxy_config_reloaded'; defaultContext: number; profiles: string[] };
+
+// ============================================================================
+// Onboarding Events
+// ============================================================================
+
+export type OnboardingStep = 'binaries' | 'models_dir' | 'first_model' | 'proxy';
+
+export interface OnboardingProgressEvent {
+  type: 'onboarding_progress';
+  step: OnboardingStep;
+  phase: 'started' | 'progress' | 'completed' | 'failed';
+  message?: string;
+  downloaded?: number;
+  total?: number;
+}
+
+// ============================================================================
+// Conversation Events
+// ============================================================================
+
+/** A conversation was changed by the backend (e.g. auto-titled). */
+export interface ConversationUpdatedEvent {
+  type: 'conversation_updated';
+  conversationId: number;
+  title: string;
+}
+
+// ============================================================================
+// Backup Events
+// ============================================================================
+
+/** Bytes moved so far by a backup upload or restore download. */
+export interface BackupProgressEvent {
+  type: 'backup_progress';
+  direction: 'upload' | 'download';
+  key: string;
+  transferred: number;
+  total: number;
+}
+
+// ============================================================================
+// Job Events
+// ============================================================================
+
+export type JobKind = 'download' | 'verification' | 'llama_build' | 'capability_detection';
+
+export type JobState = 'running' | 'completed' | 'failed' | 'cancelled';
+
+/** A long-running operation tracked by the backend (`GET /api/jobs`). */
+export interface Job {
+  id: string;
+  kind: JobKind;
+  label: string;
+  subject: string | null;
+  state: JobState;
+  progress: { current: number; total: number | null; message: string | null };
+  cancellable: boolean;
+  cancelRequested: boolean;
+  error: string | null;
+  startedAtMs: number;
+  finishedAtMs: number | null;
+}
+
+/** A job started, progressed or finished; upsert it by `job.id`. */
+export interface JobUpdatedEvent {
+  type: 'job_updated';
+  job: Job;
+}
+
+// ============================================================================
+// Settings Events
+// ============================================================================
+
+/** Settings were saved, by this backend or another process (e.g. the CLI). */
+export interface SettingsChangedEvent {
+  type: 'settings_changed';
+  /** Stored names of the changed settings (e.g. `proxy_port`). */
+  keys: string[];
+}
 
 // ============================================================================
 // App Event Map
@@ -192,6 +292,11 @@ export interface AppEventMap {
   'log': LogEvent;
   'verification': VerificationEvent;
   'proxy': ProxyEvent;
+  'onboarding': OnboardingProgressEvent;
+  'conversation': ConversationUpdatedEvent;
+  'backup': BackupProgressEvent;
+  'job': JobUpdatedEvent;
+  'settings': SettingsChangedEvent;
 }
 
 export type AppEventType = keyof AppEventMap;
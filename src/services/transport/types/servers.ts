@@ -4,7 +4,7 @@
  */
 
 import type { ModelId } from './ids';
-import type { ServeConfig, ServerInfo, ToolSupportResponse } from '../../../types';
+import type { ServeConfig, ServerInfo, ServerStats, ToolSupportResponse } from '../../../types';
 
 // Re-export existing types
 export type { ServeConfig, ServerInfo };
@@ -32,4 +32,7 @@ export interface ServersTransport {
 
   /** Retrieve tool-calling capability for a running server's model. */
   getServerToolSupport(modelId: ModelId): Promise<ToolSupportResponse>;
+
+  /** Latency and throughput for a running server's current session. */
+  getServerStats(modelId: ModelId): Promise<ServerStats>;
 }
@@ -0,0 +1,235 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { BackupDirection } from "./BackupDirection";
+import type { DownloadEvent } from "./DownloadEvent";
+import type { Job } from "./Job";
+import type { McpErrorInfo } from "./McpErrorInfo";
+import type { McpServerSummary } from "./McpServerSummary";
+import type { ModelSummary } from "./ModelSummary";
+import type { OnboardingPhase } from "./OnboardingPhase";
+import type { OnboardingStep } from "./OnboardingStep";
+import type { OverallHealth } from "./OverallHealth";
+import type { ServerHealthStatus } from "./ServerHealthStatus";
+import type { ServerSnapshotEntry } from "./ServerSnapshotEntry";
+import type { ServerStopReason } from "./ServerStopReason";
+
+/**
+ * Canonical event types for all adapters.
+ *
+ * This enum unifies server, download, and model events into a single
+ * discriminated union. Each variant includes all necessary context
+ * for the event to be self-describing.
+ */
+export type AppEvent = { "type": "server_started", 
+/**
+ * ID of the model being served.
+ */
+modelId: number, 
+/**
+ * Name of the model being served.
+ */
+modelName: string, 
+/**
+ * Port the server is listening on.
+ */
+port: number, } | { "type": "server_stopped", 
+/**
+ * ID of the model that was being served.
+ */
+modelId: number, 
+/**
+ * Name of the model that was being served.
+ */
+modelName: string, 
+/**
+ * Why the server stopped.
+ */
+reason: ServerStopReason, } | { "type": "server_idle_warning", 
+/**
+ * ID of the model being served.
+ */
+modelId: number, 
+/**
+ * Name of the model being served.
+ */
+modelName: string, 
+/**
+ * Seconds until the server is stopped if no request arrives.
+ */
+shutdownInSecs: number, } | { "type": "server_error", 
+/**
+ * ID of the model being served (if known).
+ */
+modelId: number | null, 
+/**
+ * Name of the model being served.
+ */
+modelName: string, 
+/**
+ * Error description.
+ */
+error: string, } | { "type": "server_snapshot", 
+/**
+ * List of currently running servers.
+ */
+servers: Array<ServerSnapshotEntry>, } | { "type": "download", 
+/**
+ * The download event payload.
+ */
+event: DownloadEvent, } | { "type": "model_added", 
+/**
+ * Summary of the added model.
+ */
+model: ModelSummary, } | { "type": "model_removed", 
+/**
+ * ID of the removed model.
+ */
+modelId: number, } | { "type": "model_updated", 
+/**
+ * Summary of the updated model.
+ */
+model: ModelSummary, } | { "type": "verification_progress", 
+/**
+ * ID of the model being verified.
+ */
+modelId: number, 
+/**
+ * Name of the model being verified.
+ */
+modelName: string, 
+/**
+ * Name of the shard being verified.
+ */
+shardName: string, 
+/**
+ * Bytes processed so far.
+ */
+bytesProcessed: number, 
+/**
+ * Total bytes to process.
+ */
+totalBytes: number, } | { "type": "verification_complete", 
+/**
+ * ID of the verified model.
+ */
+modelId: number, 
+/**
+ * Name of the verified model.
+ */
+modelName: string, 
+/**
+ * Overall health status.
+ */
+overallHealth: OverallHealth, } | { "type": "server_health_changed", 
+/**
+ * Unique server instance identifier.
+ */
+serverId: number, 
+/**
+ * ID of the model being served.
+ */
+modelId: number, 
+/**
+ * New health status.
+ */
+status: ServerHealthStatus, 
+/**
+ * Optional detail message (e.g., error description).
+ */
+detail?: string, 
+/**
+ * Unix timestamp in milliseconds when status changed.
+ */
+timestamp: number, } | { "type": "mcp_server_added", 
+/**
+ * Summary of the added server.
+ */
+server: McpServerSummary, } | { "type": "mcp_server_removed", 
+/**
+ * ID of the removed server.
+ */
+serverId: number, } | { "type": "mcp_server_started", 
+/**
+ * ID of the server.
+ */
+serverId: number, 
+/**
+ * Name of the server.
+ */
+serverName: string, } | { "type": "mcp_server_stopped", 
+/**
+ * ID of the server.
+ */
+serverId: number, 
+/**
+ * Name of the server.
+ */
+serverName: string, } | { "type": "mcp_server_error", 
+/**
+ * User-safe error information.
+ */
+error: McpErrorInfo, } | { "type": "proxy_started", 
+/**
+ * Port the proxy is listening on.
+ */
+port: number, } | { "type": "proxy_stopped" } | { "type": "proxy_crashed" } | { "type": "proxy_config_reloaded", 
+/**
+ * Context size models are now launched with when a request does
+ * not ask for one.
+ */
+defaultContext: number, 
+/**
+ * Inference profiles now selectable as `{model}:{profile}`.
+ */
+profiles: Array<string>, } | { "type": "onboarding_progress", 
+/**
+ * Step being executed.
+ */
+step: OnboardingStep, 
+/**
+ * Started, progress, completed or failed.
+ */
+phase: OnboardingPhase, 
+/**
+ * Human-readable detail (or the error, when failed).
+ */
+message?: string, 
+/**
+ * Bytes done so far, for steps that transfer data.
+ */
+downloaded?: number, 
+/**
+ * Total bytes, when known.
+ */
+total?: number, } | { "type": "backup_progress", 
+/**
+ * Upload to or download from the bucket.
+ */
+direction: BackupDirection, 
+/**
+ * Object key being transferred.
+ */
+key: string, 
+/**
+ * Bytes transferred so far.
+ */
+transferred: number, 
+/**
+ * Size of the object in bytes.
+ */
+total: number, } | { "type": "job_updated", 
+/**
+ * The job as it stands after the change.
+ */
+job: Job, } | { "type": "conversation_updated", 
+/**
+ * ID of the updated conversation.
+ */
+conversationId: number, 
+/**
+ * The conversation's new title.
+ */
+title: string, } | { "type": "settings_changed", 
+/**
+ * Stored names of the changed settings (e.g. `proxy_port`), sorted.
+ */
+keys: Array<string>, };
@@ -0,0 +1,128 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { BackupConfig } from "./BackupConfig";
+import type { CachedPrompt } from "./CachedPrompt";
+import type { EnergyConfig } from "./EnergyConfig";
+import type { InferenceConfig } from "./InferenceConfig";
+import type { InferenceProfile } from "./InferenceProfile";
+import type { ModerationConfig } from "./ModerationConfig";
+import type { OtlpConfig } from "./OtlpConfig";
+import type { ProxyLimits } from "./ProxyLimits";
+import type { StartupModel } from "./StartupModel";
+import type { UrlFetchConfig } from "./UrlFetchConfig";
+import type { WebSearchConfig } from "./WebSearchConfig";
+
+/**
+ * Application settings for the settings UI.
+ */
+export type AppSettings = { defaultDownloadPath: string | null, defaultContextSize: number | null, proxyPort: number | null, 
+/**
+ * Proxy bind address; `None` = `127.0.0.1`.
+ */
+proxyHost: string | null, 
+/**
+ * Opt-in for a non-loopback `proxy_host`; `None` = disabled.
+ */
+proxyAllowPublic: boolean | null, 
+/**
+ * Origins browsers may call the proxy from; `None` = any.
+ */
+proxyAllowedOrigins: Array<string> | null, 
+/**
+ * Cut background streams short for waiting interactive requests;
+ * `None` = disabled.
+ */
+proxyPreemptBackground: boolean | null, 
+/**
+ * Drop reasoning from streamed responses instead of sending it as
+ * `reasoning_content`; `None` = disabled.
+ */
+proxyStripReasoning: boolean | null, llamaBasePort: number | null, maxDownloadQueueSize: number | null, 
+/**
+ * Hours an orphaned partial download is kept (default 24; read at startup).
+ */
+partialDownloadGraceHours: number | null, 
+/**
+ * Days a removed model stays in the trash (default 30).
+ */
+trashRetentionDays: number | null, 
+/**
+ * Trash size cap in GB; the oldest entries go first. `None` = no cap.
+ */
+trashMaxSizeGb: number | null, 
+/**
+ * Pick up `.gguf` files added to or deleted from the models directory
+ * while `gglib web` runs (default off; read at startup).
+ */
+watchModelsDir: boolean | null, showMemoryFitIndicators: boolean | null, 
+/**
+ * Warn before downloading non-commercial or research-only models
+ * (default off).
+ */
+warnRestrictiveLicenses: boolean | null, maxToolIterations: number | null, maxStagnationSteps: number | null, 
+/**
+ * Default model ID for quick commands (e.g., `gglib question`).
+ */
+defaultModelId: number | null, 
+/**
+ * Minutes without a request before a server is stopped (`0` = never).
+ */
+idleShutdownMinutes: number | null, 
+/**
+ * Models launched automatically at startup, in order.
+ */
+startupModels: Array<StartupModel> | null, 
+/**
+ * System prompts kept warm per model across server restarts.
+ */
+cachedPrompts: Array<CachedPrompt> | null, inferenceDefaults: InferenceConfig | null, 
+/**
+ * Named sampling profiles, selectable per request as `{model}:{profile}`.
+ */
+inferenceProfiles: Array<InferenceProfile> | null, 
+/**
+ * OTLP trace export; `None` = disabled.
+ */
+otlp: OtlpConfig | null, setupCompleted: boolean | null, titleGenerationPrompt: string | null, autoTitleConversations: boolean | null, 
+/**
+ * Whether message content is encrypted at rest; `None` = disabled.
+ */
+encryptChatHistory: boolean | null, maxAttachmentSizeMb: number | null, allowedAttachmentTypes: Array<string> | null, 
+/**
+ * Language tag for user-facing text; `None` follows the environment.
+ */
+locale: string | null, 
+/**
+ * Built-in web search provider and filters; `None` = disabled.
+ */
+webSearch: WebSearchConfig | null, 
+/**
+ * Built-in page fetch limits; `None` = disabled.
+ */
+urlFetch: UrlFetchConfig | null, 
+/**
+ * Moderation rules and classifier; `None` = disabled.
+ */
+moderation: ModerationConfig | null, 
+/**
+ * Caps on proxied generations; `None` = unbounded.
+ */
+proxyLimits: ProxyLimits | null, 
+/**
+ * Draw and price for energy estimates; `None` = detected draw, no cost.
+ */
+energy: EnergyConfig | null, 
+/**
+ * Cloud backup bucket; `None` = disabled. The secret access key is
+ * returned as its `secret:` reference when a secret store is in use.
+ */
+backup: BackupConfig | null, 
+/**
+ * Whether a `HuggingFace` token is kept in the secret store. The token
+ * itself is never sent back.
+ */
+hfTokenConfigured: boolean, 
+/**
+ * Version of the stored settings, bumped by every save from any
+ * process. Send it back as `expectedVersion` to update conditionally.
+ */
+version: number, };
@@ -0,0 +1,18 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Counts of attempts by result kind.
+ */
+export type AttemptCounts = { 
+/**
+ * Number of successful downloads.
+ */
+downloaded: number, 
+/**
+ * Number of failed attempts.
+ */
+failed: number, 
+/**
+ * Number of cancelled attempts.
+ */
+cancelled: number, };
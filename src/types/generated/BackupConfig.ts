@@ -0,0 +1,43 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Cloud backup settings.
+ *
+ * Stored as the `backup` setting; `None` there disables backups.
+ */
+export type BackupConfig = { 
+/**
+ * Bucket the backups are written to. It must already exist.
+ */
+bucket: string, 
+/**
+ * Endpoint of an S3-compatible service, e.g. `http://nas.local:9000`
+ * for `MinIO`. `None` means AWS S3.
+ */
+endpoint: string | null, 
+/**
+ * Region of the bucket. Defaults to [`DEFAULT_BACKUP_REGION`].
+ */
+region: string | null, 
+/**
+ * Key prefix all backup objects are stored under. Defaults to
+ * [`DEFAULT_BACKUP_PREFIX`].
+ */
+prefix: string | null, 
+/**
+ * Access key ID.
+ */
+accessKeyId: string, 
+/**
+ * Secret access key, or a `secret:` reference to it.
+ */
+secretAccessKey: string, 
+/**
+ * Address the bucket as `<endpoint>/<bucket>` instead of
+ * `<bucket>.<endpoint>`. `MinIO` needs this.
+ */
+pathStyle: boolean, 
+/**
+ * Upload model files along with the database by default.
+ */
+includeModels: boolean, };
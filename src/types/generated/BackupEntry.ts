@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * An object in the backup bucket.
+ */
+export type BackupEntry = { 
+/**
+ * Name to restore it by: `gglib-<timestamp>.db` for a database backup,
+ * the path below the models directory for a model file.
+ */
+name: string, key: string, size: number, lastModified: string | null, };
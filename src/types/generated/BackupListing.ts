@@ -0,0 +1,15 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { BackupEntry } from "./BackupEntry";
+
+/**
+ * What the backup bucket holds.
+ */
+export type BackupListing = { 
+/**
+ * Database backups, newest first.
+ */
+databases: Array<BackupEntry>, 
+/**
+ * Model files, by path.
+ */
+models: Array<BackupEntry>, };
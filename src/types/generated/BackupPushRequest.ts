@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Request to back up the database and, optionally, model files.
+ */
+export type BackupPushRequest = { 
+/**
+ * Upload model files too. Defaults to the config's `includeModels`.
+ */
+includeModels: boolean | null, };
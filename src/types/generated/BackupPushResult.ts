@@ -0,0 +1,18 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Outcome of a backup.
+ */
+export type BackupPushResult = { 
+/**
+ * Name of the database backup written.
+ */
+database: string, 
+/**
+ * Model files uploaded.
+ */
+modelsUploaded: Array<string>, 
+/**
+ * Model files the bucket already held at the same size.
+ */
+modelsUnchanged: number, };
@@ -0,0 +1,19 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Request to restore from the backup bucket.
+ */
+export type BackupRestoreRequest = { 
+/**
+ * Database backup to restore by name, or `latest`. `None` leaves the
+ * database alone.
+ */
+database: string | null, 
+/**
+ * Model files to restore, by name as listed.
+ */
+models: Array<string>, 
+/**
+ * Restore every model file in the bucket.
+ */
+allModels: boolean, };
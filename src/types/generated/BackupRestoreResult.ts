@@ -0,0 +1,15 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Outcome of a restore.
+ */
+export type BackupRestoreResult = { 
+/**
+ * Name of the database backup staged, if any. It replaces the live
+ * database the next time gglib starts.
+ */
+database: string | null, 
+/**
+ * Model files written to the models directory.
+ */
+models: Array<string>, };
@@ -0,0 +1,18 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A system prompt to keep warm for one model.
+ */
+export type CachedPrompt = { 
+/**
+ * ID of the model the prompt is warmed on.
+ */
+modelId: number, 
+/**
+ * Name the saved KV state is stored under; see [`is_valid_prompt_name`].
+ */
+name: string, 
+/**
+ * The system prompt text, exactly as clients send it.
+ */
+systemPrompt: string, };
@@ -0,0 +1,50 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Stable artifact identity for completion tracking.
+ *
+ * This key is computed at enqueue time (before download starts) and remains
+ * stable across retries, failures, and sharded downloads. It represents "what
+ * the user thinks they downloaded" from an artifact perspective, not a request
+ * perspective.
+ *
+ * # Identity Semantics
+ *
+ * - Same artifact downloaded twice → same key (deduplication)
+ * - All shards in a group → same key (one entry)
+ * - Failures before metadata available → key still valid
+ * - Survives cancellations and retries
+ */
+export type CompletionKey = { "kind": "hf_file", 
+/**
+ * Repository ID (e.g., "unsloth/Llama-3-GGUF").
+ */
+repo_id: string, 
+/**
+ * Git revision (branch, tag, or commit SHA).
+ * Stores exactly what the user requested (e.g., "main", "v1.0", or a SHA).
+ * Use "unspecified" if no revision was provided.
+ */
+revision: string, 
+/**
+ * Canonical filename (normalized for sharded models).
+ * Shard suffixes are stripped: "model-00001-of-00008.gguf" → "model.gguf"
+ */
+filename_canon: string, 
+/**
+ * Quantization type (e.g., "`Q4_K_M`").
+ * Optional since some downloads may not have a meaningful quantization.
+ */
+quantization?: string, } | { "kind": "url_file", 
+/**
+ * Source URL.
+ */
+url: string, 
+/**
+ * Target filename.
+ */
+filename: string, } | { "kind": "local_file", 
+/**
+ * Absolute path to the file.
+ */
+path: string, };
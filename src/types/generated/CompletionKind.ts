@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Result kind for a completion attempt.
+ */
+export type CompletionKind = "downloaded" | "failed" | "cancelled" | "already_present";
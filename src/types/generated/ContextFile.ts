@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A file injected into the prompt.
+ */
+export type ContextFile = { path: string, 
+/**
+ * Estimated tokens, at [`CHARS_PER_TOKEN_APPROX`] characters per token.
+ */
+tokens: number, content: string, };
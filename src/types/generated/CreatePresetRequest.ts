@@ -0,0 +1,15 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { InferenceConfig } from "./InferenceConfig";
+
+/**
+ * Request to create a preset.
+ */
+export type CreatePresetRequest = { name: string, description: string | null, systemPrompt: string | null, 
+/**
+ * Sparse sampling overrides; omitted fields fall through to defaults.
+ */
+sampling: InferenceConfig, 
+/**
+ * Tool names the model may call; empty allows all.
+ */
+tools: Array<string>, mcpServerIds: Array<number>, defaultModel: string | null, };
@@ -0,0 +1,14 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Request to create a project.
+ */
+export type CreateProjectRequest = { name: string, 
+/**
+ * Absolute path of an existing directory.
+ */
+rootPath: string, includeGlobs: Array<string>, excludeGlobs: Array<string>, autoInclude: boolean, 
+/**
+ * Token budget for injected files; omitted uses the default.
+ */
+tokenBudget: number | null, };
@@ -0,0 +1,15 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { EnergyEstimate } from "./EnergyEstimate";
+
+/**
+ * One day of usage across the library, as listed by [`daily_summary`].
+ */
+export type DailyUsage = { day: string, requests: number, promptTokens: number, completionTokens: number, 
+/**
+ * Hours servers spent on requests.
+ */
+busyHours: number, 
+/**
+ * Estimated energy for the busy time.
+ */
+energy: EnergyEstimate, };
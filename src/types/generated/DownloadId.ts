@@ -0,0 +1,9 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Canonical identifier for a download.
+ *
+ * Represents a unique download as `model_id:quantization` (or just `model_id` if no quantization).
+ * This is the single identifier format used throughout the system.
+ */
+export type DownloadId = { model_id: string, quantization: string | null, };
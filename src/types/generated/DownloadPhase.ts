@@ -0,0 +1,9 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Phase of a running download, reported by [`DownloadEvent::PhaseProgress`].
+ *
+ * Finer-grained than [`DownloadStatus`]: a download stays `Downloading`
+ * while its bytes are hashed, but moves from `Transferring` to `Verifying`.
+ */
+export type DownloadPhase = "resolving" | "transferring" | "verifying" | "registering";
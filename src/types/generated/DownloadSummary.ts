@@ -0,0 +1,60 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { DownloadStatus } from "./DownloadStatus";
+import type { ShardInfo } from "./ShardInfo";
+
+/**
+ * A summary of a download in the queue (for snapshots and API responses).
+ *
+ * The byte, rate and timing fields let a client that connects mid-download
+ * render the active item straight from the snapshot. They are zero or absent
+ * for items that are not transferring.
+ */
+export type DownloadSummary = { 
+/**
+ * Canonical ID string (`model_id:quantization` or just `model_id`).
+ */
+id: string, 
+/**
+ * Human-readable display name.
+ */
+display_name: string, 
+/**
+ * Current status of this download.
+ */
+status: DownloadStatus, 
+/**
+ * Position in queue (1 = currently downloading, 2+ = waiting).
+ */
+position: number, 
+/**
+ * Error message if status is Failed.
+ */
+error?: string, 
+/**
+ * Group ID for sharded downloads (all shards share the same `group_id`).
+ */
+group_id?: string, 
+/**
+ * Shard information if this is part of a sharded model.
+ */
+shard_info?: ShardInfo, 
+/**
+ * Bytes downloaded so far (across the whole shard group when sharded).
+ */
+downloaded_bytes: number, 
+/**
+ * Total bytes to download; `0` while unknown.
+ */
+total_bytes: number, 
+/**
+ * Current speed from the manager's `RateEstimator`; absent while it warms up.
+ */
+speed_bps?: number, 
+/**
+ * Estimated seconds remaining; absent when unknown.
+ */
+eta_seconds?: number, 
+/**
+ * Seconds since the transfer (or its first shard) started.
+ */
+elapsed_seconds?: number, };
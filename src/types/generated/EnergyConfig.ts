@@ -0,0 +1,22 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Energy estimation settings.
+ *
+ * Stored as the `energy` setting. Estimates are made without it too, using
+ * [`detected_watts`] and no price.
+ */
+export type EnergyConfig = { 
+/**
+ * Machine draw while generating, in watts. Defaults to
+ * [`detected_watts`].
+ */
+watts: number | null, 
+/**
+ * Electricity price per kWh; costs are only estimated when set.
+ */
+pricePerKwh: number | null, 
+/**
+ * Currency label shown with costs, e.g. `EUR`.
+ */
+currency: string | null, };
@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Approximate energy used by some amount of work.
+ */
+export type EnergyEstimate = { wattHours: number, 
+/**
+ * In the rate's currency; `None` when no price is configured.
+ */
+cost: number | null, };
@@ -0,0 +1,15 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { FileTreeEntry } from "./FileTreeEntry";
+
+/**
+ * A project directory listing.
+ */
+export type FileTree = { 
+/**
+ * Entries in depth-first path order.
+ */
+entries: Array<FileTreeEntry>, 
+/**
+ * True when the listing stopped at [`MAX_TREE_ENTRIES`].
+ */
+truncated: boolean, };
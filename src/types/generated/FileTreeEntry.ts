@@ -0,0 +1,18 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * One file or directory in a project tree.
+ */
+export type FileTreeEntry = { 
+/**
+ * Path relative to the project root.
+ */
+path: string, isDir: boolean, 
+/**
+ * Size in bytes (0 for directories).
+ */
+sizeBytes: number, 
+/**
+ * Whether the include rules select this file.
+ */
+included: boolean, };
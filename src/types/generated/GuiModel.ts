@@ -0,0 +1,31 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { InferenceConfig } from "./InferenceConfig";
+import type { ModelBenchmarkSummary } from "./ModelBenchmarkSummary";
+import type { ServerConfig } from "./ServerConfig";
+
+/**
+ * Frontend-friendly model structure.
+ */
+export type GuiModel = { id: number, name: string, filePath: string, paramCountB: number, architecture: string | null, quantization: string | null, contextLength: number | null, addedAt: string, hfRepoId: string | null, 
+/**
+ * License identifier (e.g. `apache-2.0`), when known.
+ */
+license?: string, tags: Array<string>, isServing: boolean, port?: number, inferenceDefaults?: InferenceConfig, 
+/**
+ * Per-model server defaults (port, URL overrides, etc.).
+ */
+serverDefaults?: ServerConfig, 
+/**
+ * Capability flags stored for this model.
+ *
+ * Serialized as a `u32` bit-field.  The frontend receives this value
+ * and may display individual flags; the `PATCH /api/models/{id}/capabilities`
+ * endpoint lets the user override them.
+ */
+capabilities: number, 
+/**
+ * Denormalised benchmark summary (speed badges).
+ *
+ * `None` if the model has never been benchmarked.
+ */
+benchmarkSummary?: ModelBenchmarkSummary, };
@@ -0,0 +1,26 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { GuiModel } from "./GuiModel";
+
+/**
+ * What changed in the model list since the client's last sync.
+ *
+ * Drop the `removed` ids first, then upsert `changed` by id; when `full`
+ * is set, `changed` replaces the whole list instead.
+ */
+export type GuiModelChanges = { 
+/**
+ * Models added or updated since then.
+ */
+changed: Array<GuiModel>, 
+/**
+ * Ids of models removed since then.
+ */
+removed: Array<number>, 
+/**
+ * `changed` is the whole list rather than a delta.
+ */
+full: boolean, 
+/**
+ * RFC 3339 time to send as `since` on the next request.
+ */
+asOf: string, };
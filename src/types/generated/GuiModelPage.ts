@@ -0,0 +1,23 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { GuiModel } from "./GuiModel";
+
+/**
+ * One page of the filtered, sorted model list.
+ */
+export type GuiModelPage = { 
+/**
+ * The models on this page, in query order.
+ */
+items: Array<GuiModel>, 
+/**
+ * Models matching the query across all pages.
+ */
+total: number, 
+/**
+ * Models skipped before this page.
+ */
+offset: number, 
+/**
+ * Page size the server applied (after clamping).
+ */
+limit: number, };
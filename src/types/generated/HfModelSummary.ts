@@ -0,0 +1,52 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Summary of a HuggingFace model from the search API.
+ */
+export type HfModelSummary = { 
+/**
+ * Model ID (e.g., "TheBloke/Llama-2-7B-GGUF")
+ */
+id: string, 
+/**
+ * Human-readable model name (derived from id)
+ */
+name: string, 
+/**
+ * Author/organization (e.g., "TheBloke")
+ */
+author: string | null, 
+/**
+ * Total download count
+ */
+downloads: number, 
+/**
+ * Like count
+ */
+likes: number, 
+/**
+ * Last modified timestamp
+ */
+last_modified: string | null, 
+/**
+ * Total parameter count in billions (from safetensors.total)
+ */
+parameters_b: number | null, 
+/**
+ * Model description/README excerpt
+ */
+description: string | null, 
+/**
+ * Model tags
+ */
+tags: Array<string>, 
+/**
+ * License from the `license:*` tag (e.g. `apache-2.0`), when present
+ */
+license: string | null, 
+/**
+ * Whether the license forbids commercial use or limits the model to
+ * research; the GUI warns before downloading such models when
+ * `warn_restrictive_licenses` is on
+ */
+restrictive_license: boolean, };
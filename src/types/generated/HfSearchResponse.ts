@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { HfModelSummary } from "./HfModelSummary";
+
+/**
+ * Response from HuggingFace model search.
+ */
+export type HfSearchResponse = { models: Array<HfModelSummary>, has_more: boolean, page: number, total_count: number | null, };
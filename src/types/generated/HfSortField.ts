@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Sort field options for HuggingFace model search.
+ */
+export type HfSortField = "downloads" | "likes" | "modified" | "created" | "id";
@@ -0,0 +1,100 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Inference parameters for LLM sampling.
+ *
+ * All fields are optional to support partial configuration and fallback chains.
+ * Intended to be shared across model defaults, global settings, and request overrides.
+ *
+ * # Hierarchy Resolution
+ *
+ * When making an inference request, parameters are resolved in this order:
+ * 1. Request-level override (user specified for this request)
+ * 2. Selected profile (`Settings.inference_profiles`, chosen as
+ *    `{model}:{profile}`; absent on surfaces without profiles)
+ * 3. Per-model defaults (stored in `Model.inference_defaults`)
+ * 4. Global settings (stored in `Settings.inference_defaults`)
+ * 5. Hardcoded fallback (e.g., temperature = 0.7)
+ *
+ * # Examples
+ *
+ * ```rust
+ * use gglib_core::domain::InferenceConfig;
+ *
+ * // Conservative settings for code generation
+ * let code_gen = InferenceConfig {
+ *     temperature: Some(0.2),
+ *     top_p: Some(0.9),
+ *     top_k: Some(40),
+ *     max_tokens: Some(2048),
+ *     repeat_penalty: Some(1.1),
+ *     presence_penalty: None,
+ *     min_p: None,
+ * };
+ *
+ * // Creative writing settings
+ * let creative = InferenceConfig {
+ *     temperature: Some(1.2),
+ *     top_p: Some(0.95),
+ *     ..Default::default()
+ * };
+ * ```
+ */
+export type InferenceConfig = { 
+/**
+ * Sampling temperature (0.0 - 2.0).
+ *
+ * Controls randomness in token selection:
+ * - Lower values (0.1-0.5): More deterministic, focused
+ * - Medium values (0.7-1.0): Balanced creativity
+ * - Higher values (1.1-2.0): More random, creative
+ */
+temperature: number | null, 
+/**
+ * Nucleus sampling threshold (0.0 - 1.0).
+ *
+ * Considers only the top tokens whose cumulative probability exceeds this threshold.
+ * Common values: 0.9 (default), 0.95 (more diverse)
+ */
+topP: number | null, 
+/**
+ * Top-K sampling limit.
+ *
+ * Considers only the K most likely next tokens.
+ * Common values: 40 (default), 10 (focused), 100 (diverse)
+ */
+topK: number | null, 
+/**
+ * Maximum tokens to generate in response.
+ *
+ * Hard limit on response length. Does not include input tokens.
+ */
+maxTokens: number | null, 
+/**
+ * Repetition penalty (> 0.0, typically 1.0 - 1.3).
+ *
+ * Penalizes repeated tokens to reduce repetitive output.
+ * - 1.0: No penalty (default)
+ * - 1.1-1.3: Moderate penalty
+ * - > 1.3: Strong penalty (may hurt coherence)
+ */
+repeatPenalty: number | null, 
+/**
+ * Presence penalty (0.0 - 2.0).
+ *
+ * Penalizes tokens that have already appeared in the output, encouraging
+ * the model to cover new ground. Effective at preventing repetitive
+ * reasoning loops in thinking models.
+ * - 0.0: No penalty (default; disabled)
+ * - 1.5: Recommended for reasoning/thinking models (e.g. `Qwen3.6`, `DeepSeek-R1`)
+ * - > 2.0: Avoid; may degrade coherence
+ */
+presencePenalty: number | null, 
+/**
+ * Minimum-probability sampling threshold (0.0 - 1.0).
+ *
+ * Removes tokens whose probability is below `min_p × P(top token)`.
+ * - 0.0: Disabled (explicit off; recommended by Qwen3.6)
+ * - 0.05: llama.cpp built-in default when the flag is omitted
+ */
+minP: number | null, };
@@ -0,0 +1,30 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { InferenceConfig } from "./InferenceConfig";
+
+/**
+ * A named sampling profile applied on top of a model's own defaults.
+ */
+export type InferenceProfile = { 
+/**
+ * Profile slug, used as the `:{suffix}` on a model id.
+ *
+ * Constrained by [`validate_name`] to lowercase alphanumerics and `-`.
+ */
+name: string, 
+/**
+ * Human-readable summary, surfaced in `/v1/models` and the settings UI.
+ */
+description: string | null, 
+/**
+ * The sampling overrides. Sparse — see the module docs.
+ */
+config: InferenceConfig, 
+/**
+ * Whether to advertise `{model}:{name}` as its own `/v1/models` entry.
+ *
+ * Off by default: with several models and several profiles the full cross
+ * product would swamp a client's model picker. Users opt in for the one or
+ * two profiles they switch between often; the rest stay addressable by
+ * name without appearing in the list.
+ */
+listInModels: boolean, };
@@ -0,0 +1,53 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { JobKind } from "./JobKind";
+import type { JobProgress } from "./JobProgress";
+import type { JobState } from "./JobState";
+
+/**
+ * A snapshot of one tracked operation.
+ */
+export type Job = { 
+/**
+ * Unique ID assigned when the job started.
+ */
+id: string, 
+/**
+ * Subsystem running the job.
+ */
+kind: JobKind, 
+/**
+ * Human-readable description, e.g. the model being downloaded.
+ */
+label: string, 
+/**
+ * The subsystem's own ID for the work (download ID, model ID), if any.
+ */
+subject: string | null, 
+/**
+ * Lifecycle state.
+ */
+state: JobState, 
+/**
+ * Latest progress report.
+ */
+progress: JobProgress, 
+/**
+ * Whether [`JobManager::cancel`] is accepted for this job.
+ */
+cancellable: boolean, 
+/**
+ * Set once a cancel has been requested, until the job stops.
+ */
+cancelRequested: boolean, 
+/**
+ * Error message when the job failed.
+ */
+error: string | null, 
+/**
+ * When the job started (milliseconds since epoch).
+ */
+startedAtMs: number, 
+/**
+ * When the job finished (milliseconds since epoch).
+ */
+finishedAtMs: number | null, };
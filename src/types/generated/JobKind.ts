@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * The subsystem running a job.
+ */
+export type JobKind = "download" | "verification" | "llama_build" | "capability_detection";
@@ -0,0 +1,21 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * How far a job has got.
+ *
+ * `current` and `total` share whatever unit the subsystem counts in —
+ * bytes for downloads and verification, compiled files for builds.
+ */
+export type JobProgress = { 
+/**
+ * Units done so far.
+ */
+current: number, 
+/**
+ * Units in total; `None` while unknown.
+ */
+total: number | null, 
+/**
+ * What the job is doing right now, e.g. a phase name.
+ */
+message: string | null, };
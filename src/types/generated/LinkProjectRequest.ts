@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Request to link a conversation to a project.
+ */
+export type LinkProjectRequest = { projectId: number, };
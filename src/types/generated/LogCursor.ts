@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Position of a record: the file it is in and the line it starts on
+ * (1-based). Serialized as `"<file>:<line>"`.
+ */
+export type LogCursor = string;
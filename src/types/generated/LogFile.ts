@@ -0,0 +1,18 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A log file in the log directory.
+ */
+export type LogFile = { 
+/**
+ * File name, e.g. `gglib.log.2026-03-14`.
+ */
+name: string, 
+/**
+ * Size on disk in bytes.
+ */
+sizeBytes: number, 
+/**
+ * Last modification time, when the platform reports it.
+ */
+modified: string | null, };
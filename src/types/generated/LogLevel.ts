@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Severity of a log record, ordered from least to most severe.
+ */
+export type LogLevel = "trace" | "debug" | "info" | "warn" | "error";
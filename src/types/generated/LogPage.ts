@@ -0,0 +1,17 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { LogCursor } from "./LogCursor";
+import type { LogRecord } from "./LogRecord";
+
+/**
+ * One page of matching records, oldest first.
+ */
+export type LogPage = { records: Array<LogRecord>, 
+/**
+ * Pass as `before` for the previous page; `None` when there are no
+ * older matches.
+ */
+older: LogCursor | null, 
+/**
+ * Pass as `after` to fetch only records written since this page.
+ */
+latest: LogCursor | null, };
@@ -0,0 +1,45 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { LogCursor } from "./LogCursor";
+import type { LogLevel } from "./LogLevel";
+
+/**
+ * Filters and paging for [`query_logs`]. Every field is optional.
+ */
+export type LogQuery = { 
+/**
+ * Only read this file (see [`list_log_files`]).
+ */
+file: string | null, 
+/**
+ * Minimum severity.
+ */
+level: LogLevel | null, 
+/**
+ * Target prefix, e.g. `gglib_download`.
+ */
+target: string | null, 
+/**
+ * Case-insensitive substring of the message.
+ */
+contains: string | null, 
+/**
+ * Only records at or after this time.
+ */
+since: string | null, 
+/**
+ * Only records before this time.
+ */
+until: string | null, 
+/**
+ * Return the newest matches older than this record.
+ */
+before: LogCursor | null, 
+/**
+ * Return the oldest matches newer than this record.
+ */
+after: LogCursor | null, 
+/**
+ * Records per page (default [`DEFAULT_LOG_PAGE_SIZE`], at most
+ * [`MAX_LOG_PAGE_SIZE`]).
+ */
+limit: number | null, };
@@ -0,0 +1,21 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { LogCursor } from "./LogCursor";
+import type { LogLevel } from "./LogLevel";
+
+/**
+ * One event read back from a log file.
+ */
+export type LogRecord = { cursor: LogCursor, timestamp: string, level: LogLevel, 
+/**
+ * Module path that emitted the event; absent in files written before
+ * targets were logged.
+ */
+target: string | null, 
+/**
+ * Enclosing spans as written, e.g. `download.job{id=3}:`.
+ */
+spans: string | null, 
+/**
+ * Message and fields; continuation lines are joined with `\n`.
+ */
+message: string, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Categories of MCP errors for UI handling.
+ */
+export type McpErrorCategory = "process" | "protocol" | "tool" | "configuration" | "unknown";
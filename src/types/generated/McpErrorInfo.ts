@@ -0,0 +1,26 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { McpErrorCategory } from "./McpErrorCategory";
+
+/**
+ * User-safe error information for MCP events.
+ *
+ * This type is used in `AppEvent::McpServerError` to provide error details
+ * that are safe to display to users (no raw process/SQL errors).
+ */
+export type McpErrorInfo = { 
+/**
+ * ID of the MCP server (if known).
+ */
+serverId?: number, 
+/**
+ * Name of the MCP server.
+ */
+serverName: string, 
+/**
+ * User-friendly error message.
+ */
+message: string, 
+/**
+ * Error category for UI handling.
+ */
+category: McpErrorCategory, };
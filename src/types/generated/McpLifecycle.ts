@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Startup lifecycle policy for an MCP server.
+ *
+ * Controls when gglib automatically starts the server process.
+ */
+export type McpLifecycle = "eager" | "lazy" | "manual";
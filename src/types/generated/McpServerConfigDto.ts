@@ -0,0 +1,26 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * MCP server configuration DTO.
+ */
+export type McpServerConfigDto = { 
+/**
+ * Command/basename to resolve (e.g., "npx" or "/usr/local/bin/python3")
+ */
+command?: string, 
+/**
+ * Cached absolute path (auto-resolved from command)
+ */
+resolved_path_cache?: string, args?: Array<string>, 
+/**
+ * Working directory (must be absolute if specified)
+ */
+working_dir?: string, 
+/**
+ * Additional PATH entries for child process
+ */
+path_extra?: string, 
+/**
+ * URL for SSE connection (required for sse)
+ */
+url?: string, };
@@ -0,0 +1,9 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { McpServerDto } from "./McpServerDto";
+import type { McpServerStatusDto } from "./McpServerStatusDto";
+import type { McpToolInfo } from "./McpToolInfo";
+
+/**
+ * MCP server info for GUI display (nested structure matching TS expectations).
+ */
+export type McpServerInfo = { server: McpServerDto, status: McpServerStatusDto, tools: Array<McpToolInfo>, };
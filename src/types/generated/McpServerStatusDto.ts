@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * MCP server status DTO.
+ */
+export type McpServerStatusDto = "stopped" | "starting" | "running" | { "error": string };
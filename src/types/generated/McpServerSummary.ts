@@ -0,0 +1,20 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Summary of an MCP server for event payloads.
+ *
+ * This is a lightweight representation for events — not the full `McpServer`.
+ */
+export type McpServerSummary = { 
+/**
+ * Database ID of the MCP server.
+ */
+id: number, 
+/**
+ * User-friendly name of the server.
+ */
+name: string, 
+/**
+ * Server type (stdio or sse).
+ */
+serverType: string, };
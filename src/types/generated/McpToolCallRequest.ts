@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { JsonValue } from "./serde_json/JsonValue";
+
+/**
+ * Request to call an MCP tool.
+ */
+export type McpToolCallRequest = { tool_name: string, arguments: { [key in string]: JsonValue }, };
@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { JsonValue } from "./serde_json/JsonValue";
+
+/**
+ * Response from an MCP tool call.
+ */
+export type McpToolCallResponse = { success: boolean, data: JsonValue | null, error: string | null, };
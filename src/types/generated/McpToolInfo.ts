@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { JsonValue } from "./serde_json/JsonValue";
+
+/**
+ * MCP tool information for GUI display.
+ */
+export type McpToolInfo = { name: string, description: string | null, input_schema: JsonValue | null, 
+/**
+ * Human-readable display title from MCP `annotations.title`.
+ */
+title?: string, };
@@ -0,0 +1,49 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Denormalised benchmark summary for a single model.
+ *
+ * Upserted in the same transaction as each new result so that the model list
+ * query can LEFT JOIN this table and show speed badges without extra round-trips.
+ */
+export type ModelBenchmarkSummary = { 
+/**
+ * Foreign key → `models.id`.
+ */
+model_id: number, 
+/**
+ * Best token-generation throughput across all perf runs.
+ */
+best_tg_tps: number | null, 
+/**
+ * Best prompt-processing throughput across all perf runs.
+ */
+best_pp_tps: number | null, 
+/**
+ * Token-generation throughput from the most recent perf run.
+ */
+latest_tg_tps: number | null, 
+/**
+ * Prompt-processing throughput from the most recent perf run.
+ */
+latest_pp_tps: number | null, 
+/**
+ * Backend reported by the most recent perf run.
+ */
+latest_backend: string | null, 
+/**
+ * Total number of perf runs recorded for this model.
+ */
+perf_run_count: number, 
+/**
+ * Total number of compare runs recorded for this model.
+ */
+compare_run_count: number, 
+/**
+ * UTC timestamp of the most recent benchmark (either type).
+ */
+last_benchmarked_at: string, 
+/**
+ * UTC timestamp of the last summary update.
+ */
+updated_at: string, };
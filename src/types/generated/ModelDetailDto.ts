@@ -0,0 +1,116 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { InferenceConfig } from "./InferenceConfig";
+
+/**
+ * Complete model details for the inspect view.
+ *
+ * This is a superset of [`GuiModel`] that includes all fields from the domain
+ * [`Model`], including raw GGUF metadata, MoE topology, and full HuggingFace
+ * provenance.  It is the single shared contract consumed by:
+ *
+ * - CLI: `gglib model inspect` (human-readable or `--json`)
+ * - Axum: `GET /api/models/:id/detail`
+ * - GUI frontend: model detail panel
+ */
+export type ModelDetailDto = { 
+/**
+ * Database ID of the model.
+ */
+id: number, 
+/**
+ * Human-readable name.
+ */
+name: string, 
+/**
+ * Absolute path to the GGUF file on disk.
+ */
+filePath: string, 
+/**
+ * Parameter count in billions.
+ */
+paramCountB: number, 
+/**
+ * Model architecture (e.g. `"llama"`, `"mistral"`).
+ */
+architecture?: string, 
+/**
+ * Quantization type (e.g. `"Q4_K_M"`, `"F16"`).
+ */
+quantization?: string, 
+/**
+ * Maximum context length in tokens.
+ */
+contextLength?: number, 
+/**
+ * Total number of experts (MoE models only).
+ */
+expertCount?: number, 
+/**
+ * Experts activated per token (MoE models only).
+ */
+expertUsedCount?: number, 
+/**
+ * Shared experts that are always active (MoE models only).
+ */
+expertSharedCount?: number, 
+/**
+ * HuggingFace repository ID (e.g. `"bartowski/Llama-3.1-8B-GGUF"`).
+ */
+hfRepoId?: string, 
+/**
+ * Original filename on HuggingFace Hub.
+ */
+hfFilename?: string, 
+/**
+ * Git commit SHA from HuggingFace Hub.
+ */
+hfCommitSha?: string, 
+/**
+ * When the model was downloaded from HuggingFace (`"%Y-%m-%d %H:%M:%S"`).
+ */
+downloadDate?: string, 
+/**
+ * Last time an update check was performed (`"%Y-%m-%d %H:%M:%S"`).
+ */
+lastUpdateCheck?: string, 
+/**
+ * License identifier (e.g. `apache-2.0`), from the HuggingFace tags or
+ * the GGUF header.
+ */
+license?: string, 
+/**
+ * User-defined and auto-generated tags.
+ */
+tags: Array<string>, 
+/**
+ * Capability flags serialized as a `u32` bit-field.
+ */
+capabilities: number, 
+/**
+ * Per-model inference parameter overrides.
+ */
+inferenceDefaults?: InferenceConfig, 
+/**
+ * When the model was first added to the database (`"%Y-%m-%d %H:%M:%S"`).
+ */
+addedAt: string, 
+/**
+ * Whether the model is currently being served.
+ */
+isServing: boolean, 
+/**
+ * Port the model is served on, if currently serving.
+ */
+port?: number, 
+/**
+ * All raw key-value pairs stored from the GGUF file.
+ *
+ * Presentation layers decide whether to surface this.  The CLI gates it
+ * behind `--metadata`; the GUI may show it in a collapsible panel.
+ */
+metadata: { [key in string]: string }, 
+/**
+ * Markdown model card. Models stored without one (added before cards
+ * existed) get a card generated on the fly from their metadata.
+ */
+card: string, };
@@ -0,0 +1,28 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Summary of a model for event payloads.
+ *
+ * This is a lightweight representation for events — not the full `Model`.
+ */
+export type ModelSummary = { 
+/**
+ * Database ID of the model.
+ */
+id: number, 
+/**
+ * Human-readable model name.
+ */
+name: string, 
+/**
+ * File path to the model.
+ */
+filePath: string, 
+/**
+ * Model architecture (e.g., "llama").
+ */
+architecture?: string, 
+/**
+ * Quantization type (e.g., "`Q4_0`").
+ */
+quantization?: string, };
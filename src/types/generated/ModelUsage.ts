@@ -0,0 +1,27 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { EnergyEstimate } from "./EnergyEstimate";
+
+/**
+ * A model's place in the usage ranking.
+ */
+export type ModelUsage = { modelId: number, name: string, requests: number, promptTokens: number, completionTokens: number, 
+/**
+ * Hours the model's server was running.
+ */
+serveHours: number, 
+/**
+ * Hours the server spent on requests.
+ */
+busyHours: number, 
+/**
+ * Estimated energy for the busy time.
+ */
+energy: EnergyEstimate, 
+/**
+ * Days with any recorded usage.
+ */
+daysUsed: number, 
+/**
+ * Latest day with any recorded usage; `None` when never used.
+ */
+lastUsed: string | null, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Current configuration for the models directory shown in settings UI.
+ */
+export type ModelsDirectoryInfo = { path: string, source: string, default_path: string, exists: boolean, writable: boolean, };
@@ -0,0 +1,30 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ModerationAction } from "./ModerationAction";
+import type { ModerationScope } from "./ModerationScope";
+
+/**
+ * A local model that labels text.
+ *
+ * The model is served like any other (for example with `gglib serve`) and
+ * reached through its OpenAI-compatible endpoint; the proxy never loads it
+ * itself, so checking a request does not swap out the model answering it.
+ */
+export type ModerationClassifier = { 
+/**
+ * Base URL of the server, e.g. `http://127.0.0.1:9100`.
+ */
+url: string, 
+/**
+ * Model name sent in the request; `None` lets the server choose.
+ */
+model: string | null, 
+/**
+ * Instruction placed before the text. Defaults to
+ * [`DEFAULT_CLASSIFIER_INSTRUCTION`].
+ */
+instruction: string | null, 
+/**
+ * Reply labels that count as a hit, compared case-insensitively against
+ * the reply's words. Defaults to [`DEFAULT_FLAGGED_LABEL`].
+ */
+flaggedLabels: Array<string>, scope: ModerationScope, action: ModerationAction, };
@@ -0,0 +1,18 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ModerationClassifier } from "./ModerationClassifier";
+import type { ModerationRule } from "./ModerationRule";
+
+/**
+ * Moderation settings.
+ *
+ * Stored as the `moderation` setting; `None` there disables moderation.
+ */
+export type ModerationConfig = { 
+/**
+ * Keyword and pattern checks, applied in order.
+ */
+rules: Array<ModerationRule>, 
+/**
+ * Model-based check, applied after the rules.
+ */
+classifier: ModerationClassifier | null, };
@@ -0,0 +1,17 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ModerationAction } from "./ModerationAction";
+import type { ModerationMatch } from "./ModerationMatch";
+import type { ModerationScope } from "./ModerationScope";
+
+/**
+ * A keyword or pattern check.
+ */
+export type ModerationRule = { 
+/**
+ * Name recorded with each decision the rule makes.
+ */
+name: string, 
+/**
+ * The keyword or regular expression.
+ */
+pattern: string, match: ModerationMatch, scope: ModerationScope, action: ModerationAction, };
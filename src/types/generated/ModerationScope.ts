@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Which side of the exchange a check looks at.
+ */
+export type ModerationScope = "prompt" | "response" | "both";
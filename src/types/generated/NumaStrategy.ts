@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A llama.cpp NUMA placement strategy, as accepted by `--numa`.
+ */
+export type NumaStrategy = "distribute" | "isolate" | "numactl";
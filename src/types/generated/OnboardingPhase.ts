@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Phase of a step being executed, reported through
+ * [`AppEvent::OnboardingProgress`](crate::events::AppEvent::OnboardingProgress).
+ */
+export type OnboardingPhase = "started" | "progress" | "completed" | "failed";
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * One onboarding step, in the order they are offered.
+ */
+export type OnboardingStep = "binaries" | "models_dir" | "first_model" | "proxy";
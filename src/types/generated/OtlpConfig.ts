@@ -0,0 +1,22 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * OTLP trace export settings.
+ *
+ * Stored as the `otlp` setting; `None` there disables export.
+ */
+export type OtlpConfig = { 
+/**
+ * Collector base URL for OTLP/HTTP, e.g. `http://localhost:4318`.
+ * `/v1/traces` is appended when the URL does not already end with it.
+ */
+endpoint: string, 
+/**
+ * `service.name` resource attribute. Defaults to `gglib`.
+ */
+serviceName: string | null, 
+/**
+ * Fraction of root traces to keep, from `0.0` to `1.0`. Defaults to
+ * `1.0`; child spans follow their parent's decision.
+ */
+sampleRatio: number | null, };
@@ -0,0 +1,24 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { PairRequest } from "./PairRequest";
+
+/**
+ * Request to pair with another gglib instance.
+ */
+export type PairPeerRequest = { 
+/**
+ * Local name for the peer.
+ */
+name: string, 
+/**
+ * Base URL of the peer's web server.
+ */
+url: string, 
+/**
+ * The peer's model share token.
+ */
+token: string, 
+/**
+ * This instance's own URL, name and share token. When given, the peer
+ * is asked to pair back so it can pull from this instance too.
+ */
+pairBack: PairRequest | null, };
@@ -0,0 +1,19 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Sent to a peer to pair with it, so it can pull from this instance in
+ * turn.
+ */
+export type PairRequest = { 
+/**
+ * Name the peer should store this instance under.
+ */
+name: string, 
+/**
+ * Base URL of this instance's web server, as reachable from the peer.
+ */
+url: string, 
+/**
+ * This instance's model share token.
+ */
+token: string, };
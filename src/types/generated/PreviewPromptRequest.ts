@@ -0,0 +1,22 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { JsonValue } from "./serde_json/JsonValue";
+
+/**
+ * Request body for previewing how a model's chat template renders a
+ * conversation.
+ */
+export type PreviewPromptRequest = { 
+/**
+ * Model whose running server renders the prompt.
+ */
+modelId: number, 
+/**
+ * Conversation in OpenAI chat format, as it would be sent to
+ * `/v1/chat/completions`.
+ */
+messages: Array<JsonValue>, 
+/**
+ * Tool definitions in OpenAI format, rendered into the prompt the way
+ * the template presents them.
+ */
+tools: Array<JsonValue>, };
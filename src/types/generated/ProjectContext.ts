@@ -0,0 +1,12 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ContextFile } from "./ContextFile";
+import type { SkippedFile } from "./SkippedFile";
+
+/**
+ * The files of a project that fit in its token budget.
+ */
+export type ProjectContext = { files: Array<ContextFile>, skipped: Array<SkippedFile>, 
+/**
+ * Sum of [`ContextFile::tokens`].
+ */
+totalTokens: number, tokenBudget: number, };
@@ -0,0 +1,25 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A conversation rendered through a model's chat template.
+ */
+export type PromptPreview = { 
+/**
+ * The prompt exactly as the server would evaluate it, generation
+ * prompt for the assistant's reply included.
+ */
+prompt: string, 
+/**
+ * Tokens in `prompt`, special tokens included.
+ */
+tokenCount: number, 
+/**
+ * Context size of one slot of the server, in tokens. `None` when the
+ * server does not report it.
+ */
+contextSize?: number | null, 
+/**
+ * Set when the prompt fills or overflows the context, leaving no room
+ * for a reply.
+ */
+warning?: string | null, };
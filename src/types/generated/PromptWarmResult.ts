@@ -0,0 +1,25 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { PromptWarmSource } from "./PromptWarmSource";
+
+/**
+ * Outcome of warming one cached prompt on a running server.
+ */
+export type PromptWarmResult = { 
+/**
+ * Name of the prompt.
+ */
+name: string, 
+/**
+ * Where the KV state came from.
+ */
+source: PromptWarmSource, 
+/**
+ * Whether the state is saved for the next start. `false` when the
+ * server has no slot directory, the model cannot restore slots, or the
+ * save failed.
+ */
+persisted: boolean, 
+/**
+ * Wall time the warm took, in milliseconds.
+ */
+elapsedMs: number, };
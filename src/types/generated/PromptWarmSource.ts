@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Where a warmed prompt's KV state came from.
+ */
+export type PromptWarmSource = "restored" | "evaluated";
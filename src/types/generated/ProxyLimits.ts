@@ -0,0 +1,23 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Limits the proxy enforces on each chat completion.
+ *
+ * Stored as the `proxy_limits` setting; every limit is optional and read
+ * per request, so changes apply without a restart.
+ */
+export type ProxyLimits = { 
+/**
+ * Most tokens one completion may generate.
+ */
+maxTokens: number | null, 
+/**
+ * Longest a request may run on the server, in seconds.
+ */
+maxRequestSeconds: number | null, 
+/**
+ * Most streams one API key may have open at once. When the proxy
+ * requires no key, tokens go unchecked and streams are counted per
+ * client IP address instead.
+ */
+maxStreamsPerKey: number | null, };
@@ -0,0 +1,55 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { CompletionDetail } from "./CompletionDetail";
+
+/**
+ * Summary of an entire queue run from start to drain.
+ *
+ * Emitted when the queue transitions from busy → idle, capturing all
+ * completions that occurred during the run regardless of timing.
+ */
+export type QueueRunSummary = { 
+/**
+ * Unique identifier for this queue run.
+ */
+run_id: string, 
+/**
+ * Unix timestamp (milliseconds since epoch) when the run started.
+ */
+started_at_ms: number, 
+/**
+ * Unix timestamp (milliseconds since epoch) when the run completed.
+ */
+completed_at_ms: number, 
+/**
+ * Total download attempts that succeeded.
+ */
+total_attempts_downloaded: number, 
+/**
+ * Total download attempts that failed.
+ */
+total_attempts_failed: number, 
+/**
+ * Total download attempts that were cancelled.
+ */
+total_attempts_cancelled: number, 
+/**
+ * Number of unique models successfully downloaded.
+ */
+unique_models_downloaded: number, 
+/**
+ * Number of unique models that failed.
+ */
+unique_models_failed: number, 
+/**
+ * Number of unique models that were cancelled.
+ */
+unique_models_cancelled: number, 
+/**
+ * True if there are more items than shown in `items`.
+ */
+truncated: boolean, 
+/**
+ * Detailed completion records, sorted by `last_completed_at_ms` (newest first).
+ * Capped at 20 items for payload size management.
+ */
+items: Array<CompletionDetail>, };
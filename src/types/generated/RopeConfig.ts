@@ -0,0 +1,27 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { RopeScalingType } from "./RopeScalingType";
+
+/**
+ * Launch-time `RoPE` overrides forwarded to llama-server.
+ *
+ * All fields are optional; `None` leaves llama-server to read the value
+ * from the GGUF metadata. A config with every field `None` emits no flags.
+ */
+export type RopeConfig = { 
+/**
+ * Scaling method (`--rope-scaling`).
+ */
+scaling: RopeScalingType | null, 
+/**
+ * Base frequency (`--rope-freq-base`).
+ */
+freqBase: number | null, 
+/**
+ * Frequency scale factor (`--rope-freq-scale`). A value of `0.25`
+ * stretches positions 4×, i.e. roughly quadruples the usable context.
+ */
+freqScale: number | null, 
+/**
+ * Original training context for `YaRN` (`--yarn-orig-ctx`).
+ */
+yarnOrigCtx: number | null, };
@@ -0,0 +1,57 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Server-level defaults for a specific model.
+ *
+ * Stores per-model server configuration parameters that override global
+ * settings but can themselves be overridden at request time. This is part
+ * of the 4-level fallback chain:
+ *
+ * 1. Runtime request / CLI flag (highest priority)
+ * 2. Model `server_defaults` (from DB, stored as JSON in `server_defaults` column)
+ * 3. Global app setting
+ * 4. Hardcoded default (lowest priority)
+ *
+ * All fields are optional to support partial configuration.
+ *
+ * # Examples
+ *
+ * ```rust
+ * use gglib_core::domain::ServerConfig;
+ *
+ * // Override only the context length for a long-context model
+ * let config = ServerConfig {
+ *     context_length: Some(32768),
+ *     ..Default::default()
+ * };
+ * ```
+ */
+export type ServerConfig = { 
+/**
+ * Context length (number of tokens) for the model server.
+ *
+ * Controls the maximum context window the server will use.
+ * Common values: 4096 (default), 8192, 32768, 131072
+ */
+contextLength: number | null, 
+/**
+ * Minutes without a request before the server is stopped.
+ *
+ * Overrides the global `idle_shutdown_minutes` setting; `0` keeps this
+ * model's server running even when a global timeout is configured.
+ */
+idleShutdownMinutes: number | null, 
+/**
+ * Parallel request slots (`--parallel`) the model's server runs.
+ *
+ * Each slot can serve one request at a time with the full context, so
+ * more slots let several chats stream at once for a matching multiple
+ * of KV cache memory. Unset, a default is derived from the context
+ * size and free memory.
+ */
+parallel: number | null, 
+/**
+ * Continuous batching (`--cont-batching`). Unset leaves llama-server's
+ * default (on); `false` disables it.
+ */
+contBatching: boolean | null, };
@@ -0,0 +1,16 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Health status of a running server process.
+ *
+ * Used by monitoring systems to track server state and emit lifecycle events.
+ */
+export type ServerHealthStatus = { "status": "healthy" } | { "status": "degraded", 
+/**
+ * Human-readable reason for degraded state.
+ */
+reason: string, } | { "status": "unreachable", 
+/**
+ * Last error message from health check attempt.
+ */
+lastError: string, } | { "status": "processdied" };
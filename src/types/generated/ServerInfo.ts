@@ -0,0 +1,18 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ServerHealthStatus } from "./ServerHealthStatus";
+import type { SlotOccupancy } from "./SlotOccupancy";
+
+/**
+ * Information about a running model server (GUI DTO).
+ */
+export type ServerInfo = { model_id: number, model_name: string, pid: number | null, port: number, started_at: number, 
+/**
+ * Latest status reported by the server's health monitor. `None` until
+ * the first probe, or for servers started outside the GUI backend.
+ */
+health?: ServerHealthStatus, 
+/**
+ * Slot occupancy from the latest poll. `None` until the first read, or
+ * when the server does not expose its slots.
+ */
+slots?: SlotOccupancy, };
@@ -0,0 +1,18 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A single log entry from the server
+ */
+export type ServerLogEntry = { 
+/**
+ * Unix timestamp in milliseconds
+ */
+timestamp: number, 
+/**
+ * The log line content
+ */
+line: string, 
+/**
+ * Server port this log belongs to
+ */
+port: number, };
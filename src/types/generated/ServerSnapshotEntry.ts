@@ -0,0 +1,26 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Entry in a server snapshot.
+ */
+export type ServerSnapshotEntry = { 
+/**
+ * Model ID being served.
+ */
+modelId: number, 
+/**
+ * Model name.
+ */
+modelName: string, 
+/**
+ * Port the server is listening on.
+ */
+port: number, 
+/**
+ * Unix timestamp (seconds) when started.
+ */
+startedAt: number, 
+/**
+ * Whether the server is healthy.
+ */
+healthy: boolean, };
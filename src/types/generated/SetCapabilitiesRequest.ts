@@ -0,0 +1,43 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Request body for overriding a model's capability flags.
+ *
+ * Each field independently sets (`true`) or clears (`false`) one flag.
+ * `None` means "leave this flag unchanged".  This lets callers toggle a
+ * single flag without knowing the current state of every other flag.
+ *
+ * # Example
+ *
+ * Force strict-turn coalescing on for a model whose GGUF shipped without
+ * a chat template:
+ *
+ * ```json
+ * { "requiresStrictTurns": true }
+ * ```
+ */
+export type SetCapabilitiesRequest = { 
+/**
+ * Override whether the model supports a `system` role in the chat template.
+ */
+supportsSystemRole: boolean | null, 
+/**
+ * Override whether the model requires strict user/assistant turn alternation.
+ */
+requiresStrictTurns: boolean | null, 
+/**
+ * Override whether the model supports tool/function calling.
+ */
+supportsToolCalls: boolean | null, 
+/**
+ * Override whether the model produces reasoning/thinking output.
+ */
+supportsReasoning: boolean | null, 
+/**
+ * Override whether the model is an embedding model.
+ */
+embedding: boolean | null, 
+/**
+ * Override whether the model is a reranker.
+ */
+reranker: boolean | null, };
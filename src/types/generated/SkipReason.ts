@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Why a selected file was left out of the prompt.
+ */
+export type SkipReason = "too_large" | "binary" | "over_budget" | "unreadable";
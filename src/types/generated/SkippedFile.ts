@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { SkipReason } from "./SkipReason";
+
+/**
+ * A selected file that was not injected.
+ */
+export type SkippedFile = { path: string, reason: SkipReason, };
@@ -0,0 +1,24 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * One llama-server slot.
+ */
+export type SlotInfo = { 
+/**
+ * Slot index, `0..parallel`.
+ */
+id: number, 
+/**
+ * Whether the slot is serving a request right now.
+ */
+processing: boolean, 
+/**
+ * Context size of the slot, in tokens. `None` when the server build
+ * does not report it.
+ */
+contextSize?: number | null, 
+/**
+ * Tokens in use in the slot's context: prompt plus generated so far.
+ * `None` when the server build does not report it.
+ */
+contextUsed?: number | null, };
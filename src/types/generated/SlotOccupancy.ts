@@ -0,0 +1,26 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Summary of a server's slots at one point in time.
+ */
+export type SlotOccupancy = { 
+/**
+ * Slots serving a request.
+ */
+processing: number, 
+/**
+ * Slots free to take the next request.
+ */
+idle: number, 
+/**
+ * Tokens held across all slots, when the server reports it.
+ */
+contextUsed?: number | null, 
+/**
+ * Context size across all slots, in tokens, when the server reports it.
+ */
+contextSize?: number | null, 
+/**
+ * Unix timestamp in milliseconds when the slots were read.
+ */
+updatedAt: number, };
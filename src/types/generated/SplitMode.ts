@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * How llama.cpp spreads a model over several GPUs, as accepted by
+ * `--split-mode`.
+ */
+export type SplitMode = "none" | "layer" | "row";
@@ -0,0 +1,81 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { InferenceConfig } from "./InferenceConfig";
+import type { NumaStrategy } from "./NumaStrategy";
+import type { RopeConfig } from "./RopeConfig";
+import type { SplitMode } from "./SplitMode";
+
+/**
+ * Request body for starting a server.
+ */
+export type StartServerRequest = { contextLength: number | null, port: number | null, jinja: boolean | null, reasoningFormat: string | null, 
+/**
+ * Number of MTP draft tokens (`--spec-draft-n-max`).
+ *
+ * `None` = auto-detect from model tags.  `Some(0)` = explicitly disabled.
+ * `Some(n > 0)` = explicitly enable with n tokens.
+ */
+mtpDraftNMax: number | null, 
+/**
+ * Minimum acceptance probability for MTP draft tokens (`--spec-draft-p-min`).
+ *
+ * Only meaningful when `mtp_draft_n_max` is `Some`.  Defaults to `0.75`.
+ */
+mtpDraftPMin: number | null, 
+/**
+ * Inference parameters for this serve session (overrides model/global defaults).
+ */
+inferenceParams: InferenceConfig | null, 
+/**
+ * `RoPE` scaling overrides (`--rope-scaling`, `--rope-freq-base`,
+ * `--rope-freq-scale`, `--yarn-orig-ctx`). `None` = use GGUF metadata.
+ */
+rope: RopeConfig | null, 
+/**
+ * Generation thread count (`--threads`). `None` = physical performance
+ * cores.
+ */
+threads: number | null, 
+/**
+ * Prompt-processing thread count (`--threads-batch`). `None` = same as
+ * `threads`.
+ */
+threadsBatch: number | null, 
+/**
+ * CPUs to pin to, as a `taskset -c` list (`0-7,12`). `None` = performance
+ * cores on a hybrid CPU, otherwise unpinned.
+ */
+cpuAffinity: string | null, 
+/**
+ * NUMA placement strategy (`--numa`). `None` = no flag.
+ */
+numa: NumaStrategy | null, 
+/**
+ * Multi-GPU split mode (`--split-mode`). `None` = llama-server's
+ * default (`layer`).
+ */
+splitMode: SplitMode | null, 
+/**
+ * Per-GPU share of the model (`--tensor-split`), as a list such as
+ * `3,1`. `None` = proportional to free VRAM on a multi-GPU machine.
+ */
+tensorSplit: string | null, 
+/**
+ * Main GPU index (`--main-gpu`). `None` = the GPU with the most free
+ * VRAM under split mode `none`, otherwise no flag.
+ */
+mainGpu: number | null, 
+/**
+ * Parallel request slots (`--parallel`), each with the full context.
+ * `None` = the model's launch defaults, then a default derived from
+ * the context size and free memory.
+ */
+parallel: number | null, 
+/**
+ * Continuous batching (`--cont-batching`). `None` = the model's launch
+ * defaults, then llama-server's default (on).
+ */
+contBatching: boolean | null, 
+/**
+ * Seconds between health probes of this server. `None` = 10.
+ */
+healthCheckIntervalSecs: number | null, };
@@ -0,0 +1,58 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { NumaStrategy } from "./NumaStrategy";
+import type { SplitMode } from "./SplitMode";
+
+/**
+ * A model to launch at startup, with the options to launch it with.
+ *
+ * Unset fields fall through the usual chain (per-model `server_defaults`,
+ * then global settings), exactly as a manual launch would.
+ */
+export type StartupModel = { 
+/**
+ * ID of the model to launch.
+ */
+modelId: number, 
+/**
+ * Context size override.
+ */
+contextLength: number | null, 
+/**
+ * Jinja chat-template override (`None` = auto-detect).
+ */
+jinja: boolean | null, 
+/**
+ * Name of an inference profile whose parameters the session uses.
+ */
+inferenceProfile: string | null, 
+/**
+ * Generation thread count (`None` = physical performance cores).
+ */
+threads: number | null, 
+/**
+ * Prompt-processing thread count (`None` = same as `threads`).
+ */
+threadsBatch: number | null, 
+/**
+ * CPUs to pin to, as a `taskset -c` list such as `0-7,12` (`None` =
+ * performance cores on a hybrid CPU, otherwise unpinned).
+ */
+cpuAffinity: string | null, 
+/**
+ * NUMA placement strategy (`None` = no `--numa` flag).
+ */
+numa: NumaStrategy | null, 
+/**
+ * Multi-GPU split mode (`None` = llama-server's default, `layer`).
+ */
+splitMode: SplitMode | null, 
+/**
+ * Per-GPU share of the model, as a list such as `3,1` (`None` = split
+ * by free VRAM on a multi-GPU machine).
+ */
+tensorSplit: string | null, 
+/**
+ * Main GPU index (`None` = the GPU with the most free VRAM under split
+ * mode `none`, otherwise device `0`).
+ */
+mainGpu: number | null, };
@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * What happened to one startup model during warm start or reconciliation.
+ */
+export type StartupOutcome = { modelId: number, 
+/**
+ * `None` when the model could not be loaded from the library.
+ */
+modelName: string | null, } & ({ "status": "started", port: number, } | { "status": "restarted", port: number, } | { "status": "already_running", port: number, } | { "status": "skipped", reason: string, } | { "status": "failed", error: string, });
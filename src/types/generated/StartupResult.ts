@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Result of launching one startup model.
+ */
+export type StartupResult = { "status": "started", port: number, } | { "status": "restarted", port: number, } | { "status": "already_running", port: number, } | { "status": "skipped", reason: string, } | { "status": "failed", error: string, };
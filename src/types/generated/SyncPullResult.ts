@@ -0,0 +1,24 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { SyncSkipped } from "./SyncSkipped";
+
+/**
+ * Outcome of pulling a peer's missing models.
+ */
+export type SyncPullResult = { 
+/**
+ * Name of the peer pulled from.
+ */
+peer: string, 
+/**
+ * Download IDs of the queued models; progress arrives as ordinary
+ * download events.
+ */
+queued: Array<string>, 
+/**
+ * Missing models that could not be queued.
+ */
+skipped: Array<SyncSkipped>, 
+/**
+ * Peer models this library already has.
+ */
+alreadyPresent: number, };
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A peer model that a pull did not queue, and why.
+ */
+export type SyncSkipped = { name: string, reason: string, };
@@ -0,0 +1,15 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { TrashedFile } from "./TrashedFile";
+
+/**
+ * A removed model waiting in the trash.
+ */
+export type TrashEntry = { modelId: number, name: string, deletedAt: string, 
+/**
+ * When the scheduled purge will delete the entry for good.
+ */
+purgeAfter: string, 
+/**
+ * Total size of the trashed files.
+ */
+sizeBytes: number, files: Array<TrashedFile>, };
@@ -0,0 +1,14 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * One file moved to the trash, and where it came from.
+ */
+export type TrashedFile = { 
+/**
+ * Path the file had before removal; restore puts it back here.
+ */
+original: string, 
+/**
+ * Path of the file inside the trash.
+ */
+trashed: string, };
@@ -0,0 +1,22 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { InferenceConfig } from "./InferenceConfig";
+import type { ServerConfig } from "./ServerConfig";
+
+/**
+ * Request body for updating a model.
+ */
+export type UpdateModelRequest = { name: string | null, quantization: string | null, filePath: string | null, inferenceDefaults: InferenceConfig | null, 
+/**
+ * Per-model server startup defaults.
+ * - Some(Some(config)) — set/replace the model's server defaults
+ * - Some(None) — clear the override (NULL in DB, revert to global default)
+ * - None — don't touch this field (key omitted from payload)
+ */
+serverDefaults?: ServerConfig | null, 
+/**
+ * Markdown model card.
+ * - Some(Some(text)) — replace the card with the user's text
+ * - Some(None) — regenerate the card from the model's metadata
+ * - None — don't touch this field (key omitted from payload)
+ */
+card?: string | null, };
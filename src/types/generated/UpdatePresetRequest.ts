@@ -0,0 +1,19 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { InferenceConfig } from "./InferenceConfig";
+
+/**
+ * Request to update a preset; omitted fields are left unchanged.
+ */
+export type UpdatePresetRequest = { name: string | null, 
+/**
+ * `null` clears the description.
+ */
+description?: string | null, 
+/**
+ * `null` clears the system prompt.
+ */
+systemPrompt?: string | null, sampling: InferenceConfig | null, tools: Array<string> | null, mcpServerIds: Array<number> | null, 
+/**
+ * `null` clears the default model.
+ */
+defaultModel?: string | null, };
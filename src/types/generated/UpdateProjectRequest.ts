@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Request to update a project; omitted fields are left unchanged.
+ */
+export type UpdateProjectRequest = { name: string | null, rootPath: string | null, includeGlobs: Array<string> | null, excludeGlobs: Array<string> | null, autoInclude: boolean | null, 
+/**
+ * `null` resets the budget to the default.
+ */
+tokenBudget?: number | null, };
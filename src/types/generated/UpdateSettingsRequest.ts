@@ -0,0 +1,94 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { BackupConfig } from "./BackupConfig";
+import type { CachedPrompt } from "./CachedPrompt";
+import type { EnergyConfig } from "./EnergyConfig";
+import type { InferenceConfig } from "./InferenceConfig";
+import type { InferenceProfile } from "./InferenceProfile";
+import type { ModerationConfig } from "./ModerationConfig";
+import type { OtlpConfig } from "./OtlpConfig";
+import type { ProxyLimits } from "./ProxyLimits";
+import type { StartupModel } from "./StartupModel";
+import type { UrlFetchConfig } from "./UrlFetchConfig";
+import type { WebSearchConfig } from "./WebSearchConfig";
+
+/**
+ * Request body for updating application settings.
+ *
+ * Every field is `Option<Option<T>>` with `serde_with::rust::double_option`
+ * so an explicit JSON `null` (clear the setting) is distinguished from an
+ * omitted key (leave unchanged) — the same pattern used by
+ * [`UpdateModelRequest::server_defaults`].
+ */
+export type UpdateSettingsRequest = { defaultDownloadPath?: string | null, defaultContextSize?: number | null, proxyPort?: number | null, proxyHost?: string | null, proxyAllowPublic?: boolean | null, proxyAllowedOrigins?: Array<string> | null, proxyPreemptBackground?: boolean | null, proxyStripReasoning?: boolean | null, llamaBasePort?: number | null, maxDownloadQueueSize?: number | null, partialDownloadGraceHours?: number | null, trashRetentionDays?: number | null, trashMaxSizeGb?: number | null, watchModelsDir?: boolean | null, showMemoryFitIndicators?: boolean | null, warnRestrictiveLicenses?: boolean | null, maxToolIterations?: number | null, maxStagnationSteps?: number | null, 
+/**
+ * Default model ID for quick commands (e.g., `gglib question`).
+ */
+defaultModelId?: number | null, 
+/**
+ * Minutes without a request before a server is stopped (`0` = never).
+ */
+idleShutdownMinutes?: number | null, 
+/**
+ * Replaces the whole startup list; `null` clears it.
+ */
+startupModels?: Array<StartupModel> | null, 
+/**
+ * Replaces the whole cached-prompt list; `null` clears it.
+ */
+cachedPrompts?: Array<CachedPrompt> | null, inferenceDefaults?: InferenceConfig | null, 
+/**
+ * Replaces the whole profile list. `null` clears it; an omitted key leaves
+ * it untouched, so a client updating an unrelated setting cannot drop
+ * profiles it never knew about.
+ */
+inferenceProfiles?: Array<InferenceProfile> | null, 
+/**
+ * OTLP trace export; `null` disables it. Applied without a restart.
+ */
+otlp?: OtlpConfig | null, setupCompleted?: boolean | null, titleGenerationPrompt?: string | null, autoTitleConversations?: boolean | null, 
+/**
+ * Encrypts (or decrypts) every stored message before the setting is
+ * saved.
+ */
+encryptChatHistory?: boolean | null, maxAttachmentSizeMb?: number | null, allowedAttachmentTypes?: Array<string> | null, 
+/**
+ * Language tag for user-facing text (e.g. `de-DE`); `null` follows the
+ * environment.
+ */
+locale?: string | null, 
+/**
+ * Built-in web search settings; `null` disables the tool.
+ */
+webSearch?: WebSearchConfig | null, 
+/**
+ * Built-in page fetch limits; `null` disables the tool.
+ */
+urlFetch?: UrlFetchConfig | null, 
+/**
+ * Moderation rules and classifier; `null` disables moderation.
+ */
+moderation?: ModerationConfig | null, 
+/**
+ * Caps on proxied generations; `null` removes them all.
+ */
+proxyLimits?: ProxyLimits | null, 
+/**
+ * Draw and price for energy estimates; `null` falls back to the
+ * detected draw without costs.
+ */
+energy?: EnergyConfig | null, 
+/**
+ * Cloud backup bucket and credentials; `null` disables backups.
+ */
+backup?: BackupConfig | null, 
+/**
+ * `HuggingFace` token, written to the secret store rather than the
+ * settings row; `null` deletes it. Downloads pick it up on restart.
+ */
+hfToken?: string | null, 
+/**
+ * Apply the update only if the settings are still at this
+ * [`AppSettings::version`]; otherwise fail with a conflict instead of
+ * overwriting another writer's changes. Omitted = unconditional.
+ */
+expectedVersion?: number, };
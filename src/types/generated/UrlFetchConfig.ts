@@ -0,0 +1,18 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Page fetch settings.
+ *
+ * Stored as the `url_fetch` setting; `None` there disables the tool.
+ */
+export type UrlFetchConfig = { 
+/**
+ * Page text kept per fetch, in estimated tokens. Defaults to
+ * [`DEFAULT_URL_FETCH_MAX_TOKENS`].
+ */
+maxTokens: number | null, 
+/**
+ * Fetches the model may make in one conversation. Defaults to
+ * [`DEFAULT_URL_FETCHES_PER_CONVERSATION`].
+ */
+maxFetchesPerConversation: number | null, };
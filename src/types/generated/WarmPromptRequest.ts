@@ -0,0 +1,15 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Request body for warming a cached prompt on a running server.
+ */
+export type WarmPromptRequest = { 
+/**
+ * Name the prompt's KV state is saved under.
+ */
+name: string, 
+/**
+ * Text to evaluate afresh. Omit to warm the model's configured cached
+ * prompt `name`, restoring its saved state when there is one.
+ */
+systemPrompt: string | null, };
@@ -0,0 +1,28 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { WebSearchProvider } from "./WebSearchProvider";
+
+/**
+ * Web search settings.
+ *
+ * Stored as the `web_search` setting; `None` there disables the tool.
+ */
+export type WebSearchConfig = { provider: WebSearchProvider, 
+/**
+ * Results returned per query. Defaults to
+ * [`DEFAULT_WEB_SEARCH_MAX_RESULTS`].
+ */
+maxResults: number | null, 
+/**
+ * Longest snippet kept per result, in characters. Defaults to
+ * [`DEFAULT_WEB_SEARCH_SNIPPET_CHARS`].
+ */
+maxSnippetChars: number | null, 
+/**
+ * When non-empty, only results from these domains (or their
+ * subdomains) are returned.
+ */
+allowDomains: Array<string>, 
+/**
+ * Results from these domains (or their subdomains) are dropped.
+ */
+denyDomains: Array<string>, };
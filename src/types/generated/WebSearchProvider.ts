@@ -0,0 +1,15 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Where search queries are sent.
+ */
+export type WebSearchProvider = { "type": "searxng", 
+/**
+ * Base URL of the instance, e.g. `http://localhost:8888`.
+ */
+url: string, } | { "type": "brave", 
+/**
+ * Subscription token sent as `X-Subscription-Token`, or a
+ * `secret:` reference to it.
+ */
+apiKey: string, } | { "type": "duckduckgo" };
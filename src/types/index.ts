@@ -63,6 +63,82 @@ export interface InferenceProfile {
 export interface ServerConfig {
   /** Context window size (e.g., 4096, 8192, 32768). */
   contextLength?: number;
+  /** Minutes without a request before the server is stopped (0 = never). */
+  idleShutdownMinutes?: number;
+  /** Parallel request slots, each with the full context. undefined = auto. */
+  parallel?: number;
+  /** Continuous batching. undefined = llama-server default (on). */
+  contBatching?: boolean;
+}
+
+/**
+ * A model launched automatically when the app starts. Unset fields fall
+ * back to the model's server defaults, then the global settings.
+ */
+export interface StartupModel {
+  modelId: number;
+  contextLength?: number | null;
+  /** `null`/omitted = auto-detect from the model. */
+  jinja?: boolean | null;
+  /** Name of an inference profile the session uses. */
+  inferenceProfile?: string | null;
+  /** Generation threads. `null`/omitted = physical performance cores. */
+  threads?: number | null;
+  /** Prompt-processing threads. `null`/omitted = same as `threads`. */
+  threadsBatch?: number | null;
+  /** CPUs to pin to, e.g. `0-7,12`. `null`/omitted = performance cores on a hybrid CPU. */
+  cpuAffinity?: string | null;
+  numa?: NumaStrategy | null;
+  splitMode?: SplitMode | null;
+  /** Share of the model per GPU, e.g. `3,1`. `null`/omitted = by free VRAM. */
+  tensorSplit?: string | null;
+  /** Main GPU index. `null`/omitted = most free VRAM under split mode `none`. */
+  mainGpu?: number | null;
+}
+
+/**
+ * A system prompt whose KV state is kept warm for one model and restored
+ * when it starts. Mirrors Rust's `CachedPrompt`.
+ */
+export interface CachedPrompt {
+  modelId: number;
+  /** 1-64 letters, digits, `-` or `_`; unique per model. */
+  name: string;
+  systemPrompt: string;
+}
+
+/** llama.cpp `--numa` strategy. Mirrors Rust's `NumaStrategy`. */
+export type NumaStrategy = 'distribute' | 'isolate' | 'numactl';
+
+/** llama.cpp `--split-mode`. Mirrors Rust's `SplitMode`. */
+export type SplitMode = 'none' | 'layer' | 'row';
+
+/** OTLP/HTTP trace export target. Mirrors Rust's `OtlpConfig`. */
+export interface OtlpConfig {
+  /** Collector base URL, e.g. `http://localhost:4318`. */
+  endpoint: string;
+  /** `service.name` reported with traces (default: `gglib`). */
+  serviceName?: string | null;
+  /** Fraction of traces to export, 0-1 (default: 1). */
+  sampleRatio?: number | null;
+}
+
+/** RoPE frequency scaling method (`--rope-scaling`). */
+export type RopeScalingType = 'none' | 'linear' | 'yarn';
+
+/**
+ * Launch-time RoPE overrides. Mirrors Rust's `RopeConfig`; every field is
+ * optional and an omitted field leaves the GGUF metadata in charge.
+ */
+export interface RopeConfig {
+  /** Scaling method (`--rope-scaling`). */
+  scaling?: RopeScalingType;
+  /** Base frequency (`--rope-freq-base`). */
+  freqBase?: number;
+  /** Frequency scale factor (`--rope-freq-scale`); 0.25 ≈ 4× context. */
+  freqScale?: number;
+  /** Original training context for YaRN (`--yarn-orig-ctx`). */
+  yarnOrigCtx?: number;
 }
 
 // ============================================================================
@@ -127,6 +203,26 @@ export interface ServeConfig {
   specDraftNMax?: number;
   /** Minimum acceptance probability for MTP draft tokens (default 0.75). */
   specDraftPMin?: number;
+  /** RoPE scaling overrides. undefined = use GGUF metadata. */
+  rope?: RopeConfig;
+  /** Generation threads. undefined = physical performance cores. */
+  threads?: number;
+  /** Prompt-processing threads. undefined = same as `threads`. */
+  threadsBatch?: number;
+  /** CPUs to pin to, e.g. `0-7,12`. undefined = performance cores on a hybrid CPU. */
+  cpuAffinity?: string;
+  numa?: NumaStrategy;
+  splitMode?: SplitMode;
+  /** Share of the model per GPU, e.g. `3,1`. undefined = by free VRAM. */
+  tensorSplit?: string;
+  /** Main GPU index. undefined = most free VRAM under split mode `none`. */
+  mainGpu?: number;
+  /** Seconds between health probes. undefined = 10. */
+  healthCheckIntervalSecs?: number;
+  /** Parallel request slots. undefined = model defaults, then auto. */
+  parallel?: number;
+  /** Continuous batching. undefined = model defaults, then on. */
+  contBatching?: boolean;
   // Inference parameters for this serve session
   temperature?: number;
   topP?: number;
@@ -140,6 +236,23 @@ export interface ServerInfo {
   modelName: string;
   port: number;
   status: string;
+  /** Latest status from the server's health monitor, if it has reported. */
+  health?: ServerHealthStatus;
+  /** Slot occupancy from the latest poll, once the server has been read. */
+  slots?: SlotOccupancy;
+}
+
+/**
+ * Slot occupancy of a running llama-server.
+ * Maps to gglib-core::domain::server_slots::SlotOccupancy.
+ * A request queues while `idle` is 0.
+ */
+export interface SlotOccupancy {
+  processing: number;
+  idle: number;
+  contextUsed?: number | null;
+  contextSize?: number | null;
+  updatedAt: number;
 }
 
 export interface ModelsDirectoryInfo {
@@ -156,34 +269,85 @@ export interface AppSettings {
   defaultDownloadPath?: string | null;
   defaultContextSize?: number | null;
   proxyPort?: number | null;
+  /** Proxy bind address (default: 127.0.0.1); a public one needs `proxyAllowPublic` */
+  proxyHost?: string | null;
+  /** Allow the proxy to bind an address other machines can reach (default: false) */
+  proxyAllowPublic?: boolean | null;
+  /** Origins browsers may call the proxy from; absent = any */
+  proxyAllowedOrigins?: string[] | null;
+  /** Cut background-priority streams short for waiting interactive requests (default: false) */
+  proxyPreemptBackground?: boolean | null;
+  /** Drop reasoning instead of streaming it as reasoning_content (default: false) */
+  proxyStripReasoning?: boolean | null;
   llamaBasePort?: number | null;
   maxDownloadQueueSize?: number | null;
+  /** Hours an orphaned partial download is kept before the janitor deletes it (default: 24) */
+  partialDownloadGraceHours?: number | null;
   titleGenerationPrompt?: string | null;
+  /** Let the model title new chats after the first exchange (default: true) */
+  autoTitleConversations?: boolean | null;
+  /** Encrypt chat message content at rest with a key from the OS keyring (default: false) */
+  encryptChatHistory?: boolean | null;
   showMemoryFitIndicators?: boolean | null;
+  /** Warn before downloading non-commercial or research-only models (default: false) */
+  warnRestrictiveLicenses?: boolean | null;
   /** Maximum iterations for tool calling agentic loop (default: 25) */
   maxToolIterations?: number | null;
   /** Default model ID for quick commands (e.g., `gglib question`) */
   defaultModelId?: number | null;
+  /** Minutes without a request before a server is stopped (0 = never) */
+  idleShutdownMinutes?: number | null;
+  /** Models launched at startup, in order, while they fit in memory */
+  startupModels?: StartupModel[] | null;
+  /** System prompts kept warm per model across server restarts */
+  cachedPrompts?: CachedPrompt[] | null;
+  /** OpenTelemetry trace export; absent = off */
+  otlp?: OtlpConfig | null;
   /** Global inference parameter defaults */
   inferenceDefaults?: InferenceConfig | null;
   /** Named sampling profiles, selectable per request as `<model>:<profile>` */
   inferenceProfiles?: InferenceProfile[] | null;
+  /** BCP 47 tag for CLI messages and number formatting; absent = from the environment */
+  locale?: string | null;
   /** Whether the setup wizard has been completed */
   setupCompleted?: boolean | null;
+  /** Whether a HuggingFace token is stored; the token itself is never returned */
+  hfTokenConfigured?: boolean;
+  /** Settings version, bumped by every save; send back as `expectedVersion` */
+  version?: number;
 }
 
 export interface UpdateSettingsRequest {
   defaultDownloadPath?: string | null | undefined;
   defaultContextSize?: number | null | undefined;
   proxyPort?: number | null | undefined;
+  proxyHost?: string | null | undefined;
+  proxyAllowPublic?: boolean | null | undefined;
+  proxyAllowedOrigins?: string[] | null | undefined;
+  proxyPreemptBackground?: boolean | null | undefined;
+  proxyStripReasoning?: boolean | null | undefined;
   llamaBasePort?: number | null | undefined;
   maxDownloadQueueSize?: number | null | undefined;
+  /** Takes effect on next startup */
+  partialDownloadGraceHours?: number | null | undefined;
   titleGenerationPrompt?: string | null | undefined;
+  autoTitleConversations?: boolean | null | undefined;
+  /** Existing messages are encrypted or decrypted before the setting is saved */
+  encryptChatHistory?: boolean | null | undefined;
   showMemoryFitIndicators?: boolean | null | undefined;
+  warnRestrictiveLicenses?: boolean | null | undefined;
   /** Maximum iterations for tool calling agentic loop (default: 25) */
   maxToolIterations?: number | null | undefined;
   /** Default model ID for quick commands (e.g., `gglib question`) */
   defaultModelId?: number | null | undefined;
+  /** Minutes without a request before a server is stopped (0 = never) */
+  idleShutdownMinutes?: number | null | undefined;
+  /** Replaces the whole startup list; `null` clears it. */
+  startupModels?: StartupModel[] | null | undefined;
+  /** Replaces the whole cached-prompt list; `null` clears it. */
+  cachedPrompts?: CachedPrompt[] | null | undefined;
+  /** OpenTelemetry trace export; `null` turns it off. Applied without a restart. */
+  otlp?: OtlpConfig | null | undefined;
   /** Global inference parameter defaults */
   inferenceDefaults?: InferenceConfig | null | undefined;
   /**
@@ -192,8 +356,14 @@ export interface UpdateSettingsRequest {
    * profiles it never knew about.
    */
   inferenceProfiles?: InferenceProfile[] | null | undefined;
+  /** BCP 47 tag such as `de-DE`; `null` falls back to the environment */
+  locale?: string | null | undefined;
   /** Whether the setup wizard has been completed */
   setupCompleted?: boolean | null | undefined;
+  /** HuggingFace token, kept in the OS keyring; `null` deletes it. Used by downloads after a restart. */
+  hfToken?: string | null | undefined;
+  /** Only apply if the settings are still at this version (409 otherwise) */
+  expectedVersion?: number;
 }
 
 // ============================================================================
@@ -321,6 +491,10 @@ export interface HfModelSummary {
   description?: string | null;
   /** Model tags */
   tags: string[];
+  /** License from the `license:*` tag (e.g. "apache-2.0") */
+  license?: string | null;
+  /** Whether the license forbids commercial use or limits the model to research */
+  restrictive_license?: boolean;
 }
 
 /**
@@ -404,6 +578,28 @@ export interface ToolSupportResponse {
   detected_format?: string | null;
 }
 
+/**
+ * Latency and throughput for a running server's current session.
+ *
+ * Mirrors `ServerStats` in `crates/gglib-core/src/domain/server_stats.rs`.
+ * Figures the proxy could not measure (e.g. TTFT for non-streaming requests)
+ * are null rather than estimated.
+ */
+export interface ServerStats {
+  /** Unix ms when this session's figures started */
+  sessionStartedAt: number;
+  /** Unix ms of the most recent recorded request */
+  lastRequestAt?: number | null;
+  requests: number;
+  completionTokens: number;
+  avgQueueWaitMs?: number | null;
+  avgTtftMs?: number | null;
+  avgTokensPerSec?: number | null;
+  /** Mean over the most recent requests — compare with the avg to spot degradation */
+  recentTtftMs?: number | null;
+  recentTokensPerSec?: number | null;
+}
+
 // ============================================================================
 // Model Filter Options Types
 // ============================================================================
@@ -430,6 +626,8 @@ export interface ModelFilterOptions {
   /** Token-generation speed range (t/s) derived from benchmark data.
    *  Only present when at least one model has been benchmarked. */
   speed_range?: RangeValues | null;
+  /** All distinct licenses present in the library */
+  licenses?: string[];
 }
 
 /**
@@ -456,5 +654,6 @@ export interface ModelListQuery {
   max_speed?: number;
   tags?: string[];
   quantizations?: string[];
+  licenses?: string[];
 }
 
@@ -3,6 +3,10 @@
  * Types for the first-run system setup status and provisioning endpoints.
  */
 
+import type { OnboardingStep } from '../services/transport/types/events';
+
+export type { OnboardingStep };
+
 /** GPU detection results. */
 export interface GpuInfo {
   hasMetal: boolean;
@@ -67,3 +71,48 @@ export interface VulkanStatus {
   readyForBuild: boolean;
   missing: MissingPackage[];
 }
+
+/** Task a recommended model is suited to. */
+export type ModelTask = 'coding' | 'chat' | 'vision' | 'embedding';
+
+/** A curated model with the best quantization that fits this machine. */
+export interface ModelRecommendation {
+  repoId: string;
+  name: string;
+  paramsB: number;
+  tasks: ModelTask[];
+  tags: string[];
+  quantization: string;
+  estimatedBytes: number;
+}
+
+/** Ranked recommendations and the memory budget they were chosen against. */
+export interface RecommendationReport {
+  task?: ModelTask | null;
+  budgetBytes: number;
+  target: 'gpu' | 'cpu';
+  items: ModelRecommendation[];
+}
+
+/** Observed state of one onboarding step. */
+export interface OnboardingStepStatus {
+  step: OnboardingStep;
+  complete: boolean;
+  skipped: boolean;
+  detail: string;
+}
+
+/** Where first-run onboarding stands. */
+export interface OnboardingStatus {
+  steps: OnboardingStepStatus[];
+  next?: OnboardingStep | null;
+  finished: boolean;
+  pendingDownload?: string | null;
+}
+
+/** Inputs for running a step; each step reads only its own. */
+export interface OnboardingStepRequest {
+  modelsDir?: string;
+  repoId?: string;
+  quantization?: string;
+}
@@ -0,0 +1,50 @@
+/**
+ * RoPE scaling helpers for the serve dialog.
+ */
+
+import type { RopeConfig } from '../types';
+
+/** Suggested context sizes are rounded down to a multiple of this. */
+const CONTEXT_SUGGESTION_ALIGN = 256;
+
+const lookup = (metadata: Record<string, string>, arch: string, suffix: string): number | undefined => {
+  const raw = metadata[`${arch}.${suffix}`];
+  if (raw === undefined) return undefined;
+  const parsed = parseInt(raw.trim(), 10);
+  return Number.isFinite(parsed) && parsed > 0 ? parsed : undefined;
+};
+
+/**
+ * Suggest the largest context size that is safe to request for a model.
+ *
+ * Without a scale override this is the GGUF's advertised context. With
+ * `freqScale` in (0, 1) it is the original training context divided by the
+ * scale, rounded down to a multiple of 256 and never below the advertised
+ * context. Returns undefined when the metadata has no context length.
+ *
+ * Must stay in sync with `suggest_max_context` in
+ * `crates/gglib-core/src/domain/rope.rs` so the CLI warning and the GUI hint
+ * agree for the same model.
+ */
+export const suggestMaxContext = (
+  metadata: Record<string, string>,
+  architecture: string | undefined,
+  rope: RopeConfig | undefined,
+): number | undefined => {
+  const arch = architecture ?? metadata['general.architecture'];
+  if (!arch) return undefined;
+  const trained = lookup(metadata, arch, 'context_length');
+  if (trained === undefined) return undefined;
+
+  const scale = rope && rope.scaling !== 'none' ? rope.freqScale : undefined;
+  if (scale === undefined || !Number.isFinite(scale) || scale <= 0 || scale >= 1) {
+    return trained;
+  }
+
+  const original = rope?.yarnOrigCtx
+    ?? lookup(metadata, arch, 'rope.scaling.original_context_length')
+    ?? trained;
+  const scaled = Math.floor(original / scale);
+  const aligned = scaled - (scaled % CONTEXT_SUGGESTION_ALIGN);
+  return Math.max(aligned, trained);
+};
@@ -97,6 +97,39 @@ describe('useDownloadManager', () => {
     vi.useRealTimers();
   });
 
+  it('renders progress from a snapshot taken mid-download', async () => {
+    const { result } = renderHook(() => useDownloadManager());
+    await waitFor(() => expect(mockSubscribeHandler).toBeTruthy());
+
+    act(() => {
+      mockSubscribeHandler?.({
+        type: 'download',
+        event: {
+          type: 'queue_snapshot',
+          max_size: 3,
+          items: [
+            {
+              id: 'model1',
+              display_name: 'model1',
+              status: 'downloading',
+              position: 1,
+              downloaded_bytes: 250,
+              total_bytes: 1000,
+              speed_bps: 50,
+              eta_seconds: 15,
+              elapsed_seconds: 5,
+            },
+          ],
+        },
+      });
+    });
+
+    await waitFor(() => expect(result.current.currentProgress?.status).toBe('progress'));
+    expect(result.current.currentProgress?.percentage).toBe(25);
+    expect(result.current.currentProgress?.speedBps).toBe(50);
+    expect(result.current.currentProgress?.etaSeconds).toBe(15);
+  });
+
   it('queues model and refreshes snapshot', async () => {
     let snapshot = emptySnapshot;
     mockGetDownloadQueue.mockImplementation(async () => snapshot);
@@ -8,11 +8,15 @@ import { describe, it, expect, vi, beforeEach, afterEach } from 'vitest';
 import {
   getDownloadQueue,
   queueDownload,
+  queueCollection,
   cancelDownload,
   removeFromQueue,
   clearFailedDownloads,
   cancelShardGroup,
+  getShardGroupProgress,
   reorderQueue,
+  retryDownload,
+  setMaxQueueSize,
 } from '../../../../src/services/clients/downloads';
 import { getTransport, _resetTransport } from '../../../../src/services/transport';
 import type { DownloadQueueStatus } from '../../../../src/services/transport/types/downloads';
@@ -22,11 +26,15 @@ vi.mock('../../../../src/services/transport', () => {
   const mockTransport = {
     getDownloadQueue: vi.fn(),
     queueDownload: vi.fn(),
+    queueCollection: vi.fn(),
     cancelDownload: vi.fn(),
     removeFromQueue: vi.fn(),
     clearFailedDownloads: vi.fn(),
     cancelShardGroup: vi.fn(),
+    getShardGroupProgress: vi.fn(),
     reorderQueue: vi.fn(),
+    retryDownload: vi.fn(),
+    setMaxQueueSize: vi.fn(),
   };
 
   return {
@@ -120,6 +128,44 @@ describe('services/clients/downloads', () => {
     });
   });
 
+  describe('queueCollection', () => {
+    it('delegates to transport.queueCollection()', async () => {
+      const params = { slug: 'org/family', quantization: 'Q4_K_M' };
+      const summary = {
+        slug: 'org/family',
+        title: 'Family',
+        queued: [{ repo_id: 'org/Family-8B-GGUF', quantization: 'Q4_K_M', shard_count: 1 }],
+        skipped: [{ repo_id: 'org/Family-1B', reason: 'no GGUF files' }],
+      };
+      vi.mocked(mockTransport.queueCollection).mockResolvedValue(summary);
+
+      const result = await queueCollection(params);
+
+      expect(mockTransport.queueCollection).toHaveBeenCalledWith(params);
+      expect(result).toEqual(summary);
+    });
+  });
+
+  describe('getShardGroupProgress', () => {
+    it('delegates to transport.getShardGroupProgress()', async () => {
+      const progress = {
+        group_id: 'group-abc',
+        total_shards: 3,
+        completed_shards: 2,
+        downloaded_bytes: 8500,
+        total_bytes: 9500,
+        exact: true,
+        percentage: 89.47,
+      };
+      vi.mocked(mockTransport.getShardGroupProgress).mockResolvedValue(progress);
+
+      const result = await getShardGroupProgress('group-abc');
+
+      expect(mockTransport.getShardGroupProgress).toHaveBeenCalledWith('group-abc');
+      expect(result).toEqual(progress);
+    });
+  });
+
   describe('reorderQueue', () => {
     it('delegates to transport.reorderQueue()', async () => {
       const ids = ['download-1', 'download-2', 'download-3'];
@@ -131,6 +177,27 @@ describe('services/clients/downloads', () => {
     });
   });
 
+  describe('retryDownload', () => {
+    it('delegates to transport.retryDownload()', async () => {
+      vi.mocked(mockTransport.retryDownload).mockResolvedValue(2);
+
+      const position = await retryDownload('org/model:Q4_K_M');
+
+      expect(mockTransport.retryDownload).toHaveBeenCalledWith('org/model:Q4_K_M');
+      expect(position).toBe(2);
+    });
+  });
+
+  describe('setMaxQueueSize', () => {
+    it('delegates to transport.setMaxQueueSize()', async () => {
+      vi.mocked(mockTransport.setMaxQueueSize).mockResolvedValue(undefined);
+
+      await setMaxQueueSize(4);
+
+      expect(mockTransport.setMaxQueueSize).toHaveBeenCalledWith(4);
+    });
+  });
+
   describe('no platform branching', () => {
     it('client module delegates all calls through transport', async () => {
       vi.mocked(mockTransport.getDownloadQueue).mockResolvedValue(mockQueueStatus);
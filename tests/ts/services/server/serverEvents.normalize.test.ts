@@ -74,6 +74,38 @@ describe('serverEvents.normalize', () => {
     });
   });
 
+  it('carries the idle stop reason and defaults others to requested', () => {
+    const idle = normalizeServerEventFromAppEvent({
+      type: 'server_stopped',
+      modelId: 123,
+      modelName: 'TestModel',
+      reason: 'idle',
+    });
+    expect(idle).toMatchObject({ type: 'stopped', reason: 'idle' });
+
+    const legacy = normalizeServerEventFromNamedEvent('server:stopped', {
+      modelId: 123,
+      modelName: 'TestModel',
+    });
+    expect(legacy).toMatchObject({ type: 'stopped', reason: 'requested' });
+  });
+
+  it('normalizes server_idle_warning', () => {
+    const evt = normalizeServerEventFromAppEvent({
+      type: 'server_idle_warning',
+      modelId: 7,
+      modelName: 'TestModel',
+      shutdownInSecs: 60,
+    });
+
+    expect(evt).toEqual({
+      type: 'idle_warning',
+      modelId: '7',
+      shutdownInSecs: 60,
+      updatedAt: Date.now(),
+    });
+  });
+
   it('ignores server_error when modelId is missing', () => {
     const evt = normalizeServerEventFromAppEvent({
       type: 'server_error',
@@ -31,12 +31,14 @@ describe('Event Names Contract', () => {
         'download:cancelled',
         'download:queue_snapshot',
         'download:queue_run_complete',
+        'download:phase',
+        'download:partial_cleanup',
       ]);
     });
 
     it('maintains consistent array length', () => {
       // This protects against accidental additions/removals
-      expect(DOWNLOAD_EVENT_NAMES).toHaveLength(7);
+      expect(DOWNLOAD_EVENT_NAMES).toHaveLength(9);
     });
 
     it('contains only string literals', () => {